@@ -0,0 +1,726 @@
+//! Generator for borrowed (zero-copy) views of the generated types.
+//!
+//! Borrowed types are derived from the generated owned types and parsers
+//! rather than from the schema, so both stay in step with the name mappings,
+//! feature gates and parser fixes applied to the owned code:
+//!
+//! - every `String` becomes `Cow<'a, str>`, and every type that contains one,
+//!   directly or through other types, gains a `'a` lifetime;
+//! - `extra_attrs` and `extra_children` are dropped, and parsers skip unknown
+//!   attributes and elements instead of capturing them;
+//! - `FromXml` impls become `FromXmlBorrowed<'a>` impls reading from a
+//!   `BorrowedReader<'a>`, and each type with a lifetime gets an `IntoOwned`
+//!   impl producing its `'static` form.
+//!
+//! Simple types carry no strings, so the borrowed module re-exports them from
+//! the owned module instead of redefining them.
+
+use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote};
+use std::collections::HashSet;
+use syn::visit::Visit;
+use syn::visit_mut::VisitMut;
+use syn::{
+    Attribute, Expr, Fields, File, Ident, ImplItem, Item, ItemEnum, ItemImpl, ItemStruct, ItemType,
+    Path, Stmt, Type, parse_quote,
+};
+
+/// Generate the borrowed module from the source of the generated types
+/// (`generated.rs`) and parsers (`generated_parsers.rs`).
+///
+/// The output refers to the owned types as `super::generated` and needs
+/// formatting with rustfmt.
+pub fn generate_borrowed(types: &str, parsers: &str) -> syn::Result<String> {
+    let types: File = syn::parse_str(types)?;
+    let parsers: File = syn::parse_str(parsers)?;
+
+    let mut structs = Vec::new();
+    let mut enums = Vec::new();
+    let mut aliases = Vec::new();
+    for item in types.items {
+        match item {
+            Item::Struct(mut s) => {
+                if let Fields::Named(fields) = &mut s.fields {
+                    fields.named = std::mem::take(&mut fields.named)
+                        .into_iter()
+                        .filter(|f| !is_extra_cfg(&f.attrs))
+                        .collect();
+                }
+                structs.push(s);
+            }
+            // Simple types without data are shared with the owned module
+            Item::Enum(e) if e.variants.iter().any(|v| !v.fields.is_empty()) => enums.push(e),
+            Item::Type(t) => aliases.push(t),
+            _ => {}
+        }
+    }
+
+    let complex: HashSet<String> = structs
+        .iter()
+        .map(|s| s.ident.to_string())
+        .chain(enums.iter().map(|e| e.ident.to_string()))
+        .collect();
+    let lifetimes = borrowing_types(&structs, &enums, &aliases);
+    // Aliases of owned complex types must point at the borrowed ones
+    aliases.retain(|t| {
+        lifetimes.contains(&t.ident.to_string()) || mentions(&t.ty, |name| complex.contains(name))
+    });
+
+    let mut out: Vec<TokenStream> = Vec::new();
+    let mut marked = HashSet::new();
+    for mut s in structs {
+        let borrowed = lifetimes.contains(&s.ident.to_string());
+        s.attrs = without_serde(s.attrs);
+        if borrowed {
+            s.generics = parse_quote!(<'a>);
+        }
+        for field in s.fields.iter_mut() {
+            field.attrs = without_serde(std::mem::take(&mut field.attrs));
+            borrow_type(&mut field.ty, &lifetimes);
+        }
+        // A lifetime only used by feature-gated fields needs a marker, or it
+        // goes unused when those features are off
+        if borrowed
+            && !s
+                .fields
+                .iter()
+                .any(|f| cfg_attrs(&f.attrs).is_empty() && borrows(&f.ty, &lifetimes))
+            && let Fields::Named(fields) = &mut s.fields
+        {
+            fields.named.push(parse_quote! {
+                #[doc(hidden)]
+                pub _borrowed: std::marker::PhantomData<&'a str>
+            });
+            marked.insert(s.ident.to_string());
+        }
+        let into_owned = borrowed.then(|| into_owned_struct(&s, &lifetimes));
+        out.push(s.into_token_stream());
+        out.extend(into_owned);
+    }
+    for mut e in enums {
+        let borrowed = lifetimes.contains(&e.ident.to_string());
+        e.attrs = without_serde(e.attrs);
+        if borrowed {
+            e.generics = parse_quote!(<'a>);
+        }
+        for variant in &mut e.variants {
+            variant.attrs = without_serde(std::mem::take(&mut variant.attrs));
+            for field in variant.fields.iter_mut() {
+                borrow_type(&mut field.ty, &lifetimes);
+            }
+        }
+        let into_owned = borrowed.then(|| into_owned_enum(&e, &lifetimes));
+        out.push(e.into_token_stream());
+        out.extend(into_owned);
+    }
+    for mut t in aliases {
+        t.attrs = without_serde(t.attrs);
+        if lifetimes.contains(&t.ident.to_string()) {
+            t.generics = parse_quote!(<'a>);
+        }
+        borrow_type(&mut t.ty, &lifetimes);
+        out.push(t.into_token_stream());
+    }
+
+    for item in parsers.items {
+        match item {
+            Item::Const(c) => out.push(c.into_token_stream()),
+            Item::Fn(f) if f.sig.ident == "decode_hex" || f.sig.ident == "decode_base64" => {
+                out.push(f.into_token_stream())
+            }
+            Item::Impl(i) if is_from_xml_impl(&i) => {
+                out.push(borrowed_parser(i, &lifetimes, &marked)?.into_token_stream())
+            }
+            _ => {}
+        }
+    }
+
+    let inner_attrs = parsers.attrs;
+    let header = quote! {
+        #(#inner_attrs)*
+        #![allow(clippy::needless_lifetimes)]
+
+        pub use super::generated::*;
+        use ooxml_xml::{
+            BorrowedReader, FromXmlBorrowed, IntoOwned, ParseError, borrowed_str, push_text,
+        };
+        use quick_xml::events::{BytesStart, Event};
+        use std::borrow::Cow;
+    };
+    // Items are separated by blank lines, which rustfmt keeps
+    let items: Vec<String> = out.iter().map(ToString::to_string).collect();
+    Ok(format!(
+        "// Borrowed views of the generated types, derived from generated.rs and\n\
+         // generated_parsers.rs.\n\
+         // Do not edit manually.\n\n{}\n\n{}\n",
+        header,
+        items.join("\n\n")
+    ))
+}
+
+/// Names of the types that borrow, found by propagating `String` through
+/// fields and alias targets until nothing changes.
+fn borrowing_types(
+    structs: &[ItemStruct],
+    enums: &[ItemEnum],
+    aliases: &[ItemType],
+) -> HashSet<String> {
+    let mut members: Vec<(String, Vec<&Type>)> = Vec::new();
+    for s in structs {
+        members.push((
+            s.ident.to_string(),
+            s.fields.iter().map(|f| &f.ty).collect(),
+        ));
+    }
+    for e in enums {
+        let types = e
+            .variants
+            .iter()
+            .flat_map(|v| v.fields.iter().map(|f| &f.ty))
+            .collect();
+        members.push((e.ident.to_string(), types));
+    }
+    for t in aliases {
+        members.push((t.ident.to_string(), vec![&*t.ty]));
+    }
+
+    let mut lifetimes = HashSet::new();
+    loop {
+        let before = lifetimes.len();
+        for (name, types) in &members {
+            if !lifetimes.contains(name)
+                && types
+                    .iter()
+                    .any(|ty| mentions(ty, |n| n == "String" || lifetimes.contains(n)))
+            {
+                lifetimes.insert(name.clone());
+            }
+        }
+        if lifetimes.len() == before {
+            return lifetimes;
+        }
+    }
+}
+
+/// Whether a type mentions a type name accepted by `pred`.
+fn mentions(ty: &Type, pred: impl Fn(&str) -> bool) -> bool {
+    struct Finder<F> {
+        pred: F,
+        found: bool,
+    }
+    impl<F: Fn(&str) -> bool> Visit<'_> for Finder<F> {
+        fn visit_path(&mut self, path: &Path) {
+            if let Some(ident) = path.get_ident() {
+                self.found |= (self.pred)(&ident.to_string());
+            }
+            syn::visit::visit_path(self, path);
+        }
+    }
+    let mut finder = Finder { pred, found: false };
+    finder.visit_type(ty);
+    finder.found
+}
+
+/// Replace `String` with `Cow<'a, str>` and add `'a` to borrowing types.
+fn borrow_type(ty: &mut Type, lifetimes: &HashSet<String>) {
+    struct Borrow<'l>(&'l HashSet<String>);
+    impl VisitMut for Borrow<'_> {
+        fn visit_type_mut(&mut self, ty: &mut Type) {
+            if let Type::Path(p) = ty
+                && p.qself.is_none()
+                && let Some(ident) = p.path.get_ident()
+            {
+                if ident == "String" {
+                    *ty = parse_quote!(Cow<'a, str>);
+                    return;
+                }
+                if self.0.contains(&ident.to_string()) {
+                    *ty = parse_quote!(#ident<'a>);
+                    return;
+                }
+            }
+            syn::visit_mut::visit_type_mut(self, ty);
+        }
+    }
+    Borrow(lifetimes).visit_type_mut(ty);
+}
+
+/// Attributes of a borrowed item, without the `serde` feature's derives and
+/// attributes.
+fn without_serde(attrs: Vec<Attribute>) -> Vec<Attribute> {
+    attrs
+        .into_iter()
+        .filter(|a| {
+            !(a.path().is_ident("cfg_attr")
+                && a.meta
+                    .to_token_stream()
+                    .to_string()
+                    .contains("feature = \"serde\""))
+        })
+        .collect()
+}
+
+/// `#[cfg(...)]` attributes among `attrs`.
+fn cfg_attrs(attrs: &[Attribute]) -> Vec<&Attribute> {
+    attrs.iter().filter(|a| a.path().is_ident("cfg")).collect()
+}
+
+/// Whether an item is only compiled with the `extra-attrs` or
+/// `extra-children` feature.
+fn is_extra_cfg(attrs: &[Attribute]) -> bool {
+    cfg_attrs(attrs).iter().any(|a| {
+        let cfg = a.meta.to_token_stream().to_string();
+        cfg.contains("\"extra-") && !cfg.contains("not")
+    })
+}
+
+/// Remove `#[cfg(not(feature = "extra-..."))]`, since borrowed parsers always
+/// take the path used without those features.
+fn strip_not_extra_cfg(attrs: &mut Vec<Attribute>) {
+    attrs.retain(|a| {
+        let cfg = a.meta.to_token_stream().to_string();
+        !(a.path().is_ident("cfg") && cfg.contains("\"extra-") && cfg.contains("not"))
+    });
+}
+
+/// `IntoOwned` for a borrowing struct.
+fn into_owned_struct(s: &ItemStruct, lifetimes: &HashSet<String>) -> TokenStream {
+    let name = &s.ident;
+    let fields = s.fields.iter().map(|f| {
+        let cfgs = cfg_attrs(&f.attrs);
+        let ident = &f.ident;
+        if ident.as_ref().is_some_and(|i| i == "_borrowed") {
+            quote!(#ident: std::marker::PhantomData)
+        } else if borrows(&f.ty, lifetimes) {
+            quote!(#(#cfgs)* #ident: IntoOwned::into_owned(self.#ident))
+        } else {
+            quote!(#(#cfgs)* #ident: self.#ident)
+        }
+    });
+    quote! {
+        impl<'a> IntoOwned for #name<'a> {
+            type Owned = #name<'static>;
+
+            fn into_owned(self) -> #name<'static> {
+                #name { #(#fields),* }
+            }
+        }
+    }
+}
+
+/// `IntoOwned` for a borrowing enum.
+fn into_owned_enum(e: &ItemEnum, lifetimes: &HashSet<String>) -> TokenStream {
+    let name = &e.ident;
+    let arms = e.variants.iter().map(|v| {
+        let cfgs = cfg_attrs(&v.attrs);
+        let variant = &v.ident;
+        match v.fields.iter().next() {
+            None => quote!(#(#cfgs)* #name::#variant => #name::#variant),
+            Some(f) if borrows(&f.ty, lifetimes) => {
+                quote!(#(#cfgs)* #name::#variant(v) => #name::#variant(IntoOwned::into_owned(v)))
+            }
+            Some(_) => quote!(#(#cfgs)* #name::#variant(v) => #name::#variant(v)),
+        }
+    });
+    quote! {
+        impl<'a> IntoOwned for #name<'a> {
+            type Owned = #name<'static>;
+
+            fn into_owned(self) -> #name<'static> {
+                match self { #(#arms),* }
+            }
+        }
+    }
+}
+
+/// Whether a (borrowed) type carries the `'a` lifetime.
+fn borrows(ty: &Type, lifetimes: &HashSet<String>) -> bool {
+    mentions(ty, |n| n == "String" || lifetimes.contains(n))
+        || ty.to_token_stream().to_string().contains("'a")
+}
+
+fn is_from_xml_impl(i: &ItemImpl) -> bool {
+    i.trait_
+        .as_ref()
+        .is_some_and(|(_, path, _)| path.is_ident("FromXml"))
+}
+
+/// Turn a `FromXml` impl into a `FromXmlBorrowed<'a>` impl.
+fn borrowed_parser(
+    mut i: ItemImpl,
+    lifetimes: &HashSet<String>,
+    marked: &HashSet<String>,
+) -> syn::Result<ItemImpl> {
+    let Type::Path(self_ty) = &*i.self_ty else {
+        return Err(syn::Error::new_spanned(
+            &i.self_ty,
+            "unexpected impl target",
+        ));
+    };
+    let name: Ident = self_ty
+        .path
+        .get_ident()
+        .cloned()
+        .ok_or_else(|| syn::Error::new_spanned(&i.self_ty, "unexpected impl target"))?;
+
+    i.generics = parse_quote!(<'a>);
+    i.trait_ = Some((None, parse_quote!(FromXmlBorrowed<'a>), Default::default()));
+    if lifetimes.contains(&name.to_string()) {
+        i.self_ty = parse_quote!(#name<'a>);
+    }
+    for item in &mut i.items {
+        let ImplItem::Fn(f) = item else {
+            continue;
+        };
+        f.sig.ident = format_ident!("from_xml_borrowed");
+        f.sig.generics = Default::default();
+        let types: [Type; 2] = [
+            parse_quote!(&mut BorrowedReader<'a>),
+            parse_quote!(&BytesStart<'a>),
+        ];
+        for (input, ty) in f.sig.inputs.iter_mut().zip(types) {
+            if let syn::FnArg::Typed(arg) = input {
+                *arg.ty = ty;
+            }
+        }
+        BorrowedBody {
+            marker: marked.contains(&name.to_string()),
+        }
+        .visit_block_mut(&mut f.block);
+    }
+    Ok(i)
+}
+
+/// Rewrites the body of an owned parser to read borrowed events.
+struct BorrowedBody {
+    /// Whether the parsed struct has a lifetime marker field.
+    marker: bool,
+}
+
+impl BorrowedBody {
+    fn keep_stmt(stmt: &mut Stmt) -> bool {
+        let tokens = stmt.to_token_stream().to_string();
+        // The event buffer is not needed when borrowing from the input
+        if tokens == "let mut buf = Vec :: new () ;" || tokens == "buf . clear () ;" {
+            return false;
+        }
+        let Some(attrs) = stmt_attrs(stmt) else {
+            return true;
+        };
+        if is_extra_cfg(attrs) {
+            return false;
+        }
+        strip_not_extra_cfg(attrs);
+        true
+    }
+}
+
+impl VisitMut for BorrowedBody {
+    fn visit_block_mut(&mut self, block: &mut syn::Block) {
+        block.stmts.retain_mut(Self::keep_stmt);
+        syn::visit_mut::visit_block_mut(self, block);
+    }
+
+    fn visit_expr_match_mut(&mut self, m: &mut syn::ExprMatch) {
+        m.arms.retain_mut(|arm| {
+            if is_extra_cfg(&arm.attrs) {
+                return false;
+            }
+            strip_not_extra_cfg(&mut arm.attrs);
+            true
+        });
+        syn::visit_mut::visit_expr_match_mut(self, m);
+    }
+
+    fn visit_expr_struct_mut(&mut self, s: &mut syn::ExprStruct) {
+        s.fields = std::mem::take(&mut s.fields)
+            .into_iter()
+            .filter(|f| !is_extra_cfg(&f.attrs))
+            .collect();
+        if self.marker && s.path.is_ident("Self") {
+            s.fields
+                .push(parse_quote!(_borrowed: std::marker::PhantomData));
+        }
+        syn::visit_mut::visit_expr_struct_mut(self, s);
+    }
+
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        if let Some(new) = borrowed_expr(expr) {
+            *expr = new;
+        }
+        syn::visit_mut::visit_expr_mut(self, expr);
+    }
+}
+
+/// Replacement for an expression of an owned parser, if it needs one.
+fn borrowed_expr(expr: &Expr) -> Option<Expr> {
+    match expr {
+        Expr::MethodCall(call) => {
+            let receiver = &call.receiver;
+            match call.method.to_string().as_str() {
+                "read_event_into" => Some(parse_quote!(#receiver.read_event())),
+                "attributes" if call.args.is_empty() => {
+                    if is_ident(receiver, "start_tag") {
+                        Some(parse_quote!(reader.attributes(#receiver)?))
+                    } else {
+                        Some(parse_quote!(reader.attributes(&#receiver)?))
+                    }
+                }
+                "into_owned" if is_ident(receiver, "val") => Some((**receiver).clone()),
+                "push_str" => {
+                    let Expr::MethodCall(inner) = &**receiver else {
+                        return None;
+                    };
+                    if inner.method != "get_or_insert_with" {
+                        return None;
+                    }
+                    let text = &inner.receiver;
+                    let arg = match call.args.first()? {
+                        Expr::Reference(r) => (*r.expr).clone(),
+                        arg => arg.clone(),
+                    };
+                    Some(parse_quote!(push_text(&mut #text, #arg)))
+                }
+                _ => None,
+            }
+        }
+        Expr::Call(call) => {
+            let Expr::Path(func) = &*call.func else {
+                return None;
+            };
+            let segments = &func.path.segments;
+            let last = segments.last()?.ident.to_string();
+            let args: Vec<&Expr> = call.args.iter().collect();
+            match (segments.len(), last.as_str(), args.as_slice()) {
+                (2, "from_utf8_lossy", [Expr::Reference(r)])
+                    if segments[0].ident == "String"
+                        && matches!(&*r.expr, Expr::Field(f) if f.member.to_token_stream().to_string() == "value") =>
+                {
+                    let value = &r.expr;
+                    Some(parse_quote!(borrowed_str(#value)))
+                }
+                (n, "from_xml", _) if n > 1 => {
+                    let mut call = call.clone();
+                    if let Expr::Path(func) = &mut *call.func {
+                        func.path.segments.last_mut()?.ident = format_ident!("from_xml_borrowed");
+                    }
+                    Some(Expr::Call(call))
+                }
+                (1, "read_text_content", [_]) => Some(parse_quote!(reader.read_text())),
+                (1, "skip_element", [_]) => Some(parse_quote!(reader.skip_element())),
+                (1, "local_name_in", [_, element, namespaces]) => {
+                    Some(parse_quote!(reader.local_name_in(#element, #namespaces)))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn is_ident(expr: &Expr, name: &str) -> bool {
+    matches!(expr, Expr::Path(p) if p.path.is_ident(name))
+}
+
+/// Outer attributes of a statement, for the statement kinds generated
+/// parsers put attributes on.
+fn stmt_attrs(stmt: &mut Stmt) -> Option<&mut Vec<Attribute>> {
+    match stmt {
+        Stmt::Local(local) => Some(&mut local.attrs),
+        Stmt::Macro(mac) => Some(&mut mac.attrs),
+        Stmt::Item(_) => None,
+        Stmt::Expr(expr, _) => match expr {
+            Expr::Assign(e) => Some(&mut e.attrs),
+            Expr::Block(e) => Some(&mut e.attrs),
+            Expr::Call(e) => Some(&mut e.attrs),
+            Expr::ForLoop(e) => Some(&mut e.attrs),
+            Expr::If(e) => Some(&mut e.attrs),
+            Expr::Loop(e) => Some(&mut e.attrs),
+            Expr::Match(e) => Some(&mut e.attrs),
+            Expr::MethodCall(e) => Some(&mut e.attrs),
+            Expr::While(e) => Some(&mut e.attrs),
+            _ => None,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TYPES: &str = r#"
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+pub type XmlString = String;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum STJc {
+    #[cfg_attr(feature = "serde", serde(rename = "left"))]
+    Left,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Text {
+    #[cfg_attr(feature = "serde", serde(rename = "$text"))]
+    pub text: Option<XmlString>,
+    #[cfg(feature = "extra-children")]
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    pub extra_children: Vec<ooxml_xml::PositionedNode>,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Empty {
+    #[cfg(feature = "extra-attrs")]
+    pub extra_attrs: std::collections::HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Font {
+    #[cfg(feature = "styling")]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RunContent {
+    #[cfg_attr(feature = "serde", serde(rename = "t"))]
+    T(Box<Text>),
+    #[cfg_attr(feature = "serde", serde(rename = "cr"))]
+    Cr(Box<Empty>),
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Run {
+    pub jc: Option<STJc>,
+    #[cfg(feature = "styling")]
+    pub style: Option<String>,
+    pub run_content: Vec<RunContent>,
+}
+"#;
+
+    const PARSERS: &str = r#"
+#![allow(unused_variables)]
+
+use super::generated::*;
+
+const NAMESPACES: &[&str] = &[];
+
+fn skip_element<R: BufRead>(reader: &mut NsReader<R>) -> Result<(), ParseError> {
+    Ok(())
+}
+
+impl FromXml for Run {
+    fn from_xml<R: BufRead>(
+        reader: &mut NsReader<R>,
+        start_tag: &BytesStart,
+        is_empty: bool,
+    ) -> Result<Self, ParseError> {
+        let mut f_style = None;
+        #[cfg(feature = "extra-attrs")]
+        let mut extra_attrs = std::collections::HashMap::new();
+        for attr in start_tag.attributes().filter_map(|a| a.ok()) {
+            let val = String::from_utf8_lossy(&attr.value);
+            match attr.key.local_name().as_ref() {
+                b"style" => {
+                    f_style = Some(val.into_owned());
+                }
+                #[cfg(feature = "extra-attrs")]
+                unknown => {}
+                #[cfg(not(feature = "extra-attrs"))]
+                _ => {}
+            }
+        }
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) => match local_name_in(reader, &e, NAMESPACES) {
+                    b"t" => f_run_content.push(RunContent::from_xml(reader, &e, false)?),
+                    _ => skip_element(reader)?,
+                },
+                Event::Text(e) => {
+                    let s = e.decode().unwrap_or_default();
+                    f_text.get_or_insert_with(String::new).push_str(&s);
+                }
+                _ => {}
+            }
+            buf.clear();
+        }
+        Ok(Self {
+            style: f_style,
+            #[cfg(feature = "extra-attrs")]
+            extra_attrs,
+        })
+    }
+}
+"#;
+
+    fn generated() -> String {
+        generate_borrowed(TYPES, PARSERS).unwrap()
+    }
+
+    #[test]
+    fn test_borrowed_types() {
+        let code = generated();
+        assert!(code.contains("pub type XmlString < 'a > = Cow < 'a , str >"));
+        assert!(code.contains("pub struct Text < 'a >"));
+        assert!(code.contains("pub text : Option < XmlString < 'a > >"));
+        // Types without strings keep no lifetime
+        assert!(code.contains("pub struct Empty {"));
+        assert!(code.contains("T (Box < Text < 'a > >)"));
+        assert!(code.contains("Cr (Box < Empty >)"));
+        assert!(code.contains("pub struct Run < 'a >"));
+        assert!(code.contains("pub style : Option < Cow < 'a , str > >"));
+        // Gated strings alone still need the lifetime to be used
+        assert!(code.contains(
+            "# [doc (hidden)] pub _borrowed : std :: marker :: PhantomData < & 'a str >"
+        ));
+        assert_eq!(code.matches("pub _borrowed").count(), 1);
+        // Simple types come from the owned module
+        assert!(!code.contains("enum STJc"));
+        assert!(!code.contains("extra_children"));
+        assert!(!code.contains("serde"));
+        assert!(!code.contains("Serialize"));
+    }
+
+    #[test]
+    fn test_into_owned() {
+        let code = generated();
+        assert!(code.contains("impl < 'a > IntoOwned for Run < 'a >"));
+        assert!(code.contains("jc : self . jc"));
+        assert!(code.contains(
+            "# [cfg (feature = \"styling\")] style : IntoOwned :: into_owned (self . style)"
+        ));
+        assert!(
+            code.contains("RunContent :: T (v) => RunContent :: T (IntoOwned :: into_owned (v))")
+        );
+        assert!(code.contains("RunContent :: Cr (v) => RunContent :: Cr (v)"));
+        assert!(!code.contains("IntoOwned for Empty"));
+        assert!(code.contains("_borrowed : std :: marker :: PhantomData }"));
+    }
+
+    #[test]
+    fn test_borrowed_parsers() {
+        let code = generated();
+        assert!(code.contains("impl < 'a > FromXmlBorrowed < 'a > for Run < 'a >"));
+        assert!(code.contains("fn from_xml_borrowed (reader : & mut BorrowedReader < 'a > , start_tag : & BytesStart < 'a > , is_empty : bool"));
+        assert!(code.contains("reader . attributes (start_tag) ?"));
+        assert!(code.contains("let val = borrowed_str (attr . value) ;"));
+        assert!(code.contains("f_style = Some (val) ;"));
+        assert!(code.contains("match reader . read_event () ?"));
+        assert!(code.contains("reader . local_name_in (& e , NAMESPACES)"));
+        assert!(code.contains("RunContent :: from_xml_borrowed (reader , & e , false)"));
+        assert!(code.contains("reader . skip_element ()"));
+        assert!(code.contains("push_text (& mut f_text , s)"));
+        assert!(code.contains("_ => { }"));
+        assert!(!code.contains("buf"));
+        assert!(!code.contains("extra_attrs"));
+        assert!(!code.contains("fn skip_element"));
+        assert!(code.contains("const NAMESPACES"));
+    }
+}
@@ -5,6 +5,16 @@ use serde::Deserialize;
 use std::collections::HashMap;
 use std::fmt::Write;
 
+/// Serde derives for generated types, behind the generated crate's `serde` feature.
+const SERDE_DERIVE: &str = "#[cfg_attr(feature = \"serde\", derive(Serialize, Deserialize))]";
+
+/// Serde attribute for `extra_attrs` fields.
+const SERDE_EXTRA_ATTRS: &str = "    #[cfg_attr(feature = \"serde\", serde(default, skip_serializing_if = \"std::collections::HashMap::is_empty\"))]";
+
+/// Serde attribute for `extra_children` fields.
+const SERDE_EXTRA_CHILDREN: &str =
+    "    #[cfg_attr(feature = \"serde\", serde(default, skip_serializing_if = \"Vec::is_empty\"))]";
+
 /// Name mappings for a single module (sml, wml, pml, dml).
 #[derive(Debug, Clone, Default, Deserialize)]
 pub struct ModuleMappings {
@@ -203,6 +213,11 @@ pub struct CodegenConfig {
     /// None = use unprefixed names (default namespace convention, used by SML/XLSX).
     /// Some("w") = use `w:` prefix (WML/DOCX), Some("p") = use `p:` prefix (PML/PPTX), etc.
     pub xml_serialize_prefix: Option<String>,
+    /// Namespace URIs of the elements parsed by the generated parsers, in both
+    /// Transitional and Strict forms. Child elements bound to any other
+    /// namespace are captured as unknown children rather than matched by local
+    /// name. Empty = match by local name only.
+    pub xml_namespaces: Vec<String>,
     /// Cross-crate imports for parser/serializer generation.
     /// Each entry is a full use path (e.g., "ooxml_dml::types::*" or "ooxml_dml::parsers::*").
     /// Used when types from another crate are referenced in this schema.
@@ -313,6 +328,7 @@ impl<'a> Generator<'a> {
         writeln!(self.output, "// Generated from ECMA-376 RELAX NG schema.").unwrap();
         writeln!(self.output, "// Do not edit manually.").unwrap();
         writeln!(self.output).unwrap();
+        writeln!(self.output, "#[cfg(feature = \"serde\")]").unwrap();
         writeln!(self.output, "use serde::{{Deserialize, Serialize}};").unwrap();
         writeln!(self.output).unwrap();
 
@@ -443,17 +459,19 @@ impl<'a> Generator<'a> {
 
                     // Enum of string literals
                     let mut code = String::new();
-                    writeln!(
-                        code,
-                        "#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]"
-                    )
-                    .unwrap();
+                    writeln!(code, "#[derive(Debug, Clone, Copy, PartialEq, Eq)]").unwrap();
+                    writeln!(code, "{}", SERDE_DERIVE).unwrap();
                     writeln!(code, "pub enum {} {{", rust_name).unwrap();
 
                     for variant in &dedup_variants {
                         let variant_name = self.to_rust_variant_name(variant);
                         // Add serde rename to preserve original XML value
-                        writeln!(code, "    #[serde(rename = \"{}\")]", variant).unwrap();
+                        writeln!(
+                            code,
+                            "    #[cfg_attr(feature = \"serde\", serde(rename = \"{}\"))]",
+                            variant
+                        )
+                        .unwrap();
                         writeln!(code, "    {},", variant_name).unwrap();
                     }
 
@@ -574,12 +592,18 @@ impl<'a> Generator<'a> {
         }
 
         let mut code = String::new();
-        writeln!(code, "#[derive(Debug, Clone, Serialize, Deserialize)]").unwrap();
+        writeln!(code, "#[derive(Debug, Clone)]").unwrap();
+        writeln!(code, "{}", SERDE_DERIVE).unwrap();
         writeln!(code, "pub enum {} {{", rust_name).unwrap();
 
         for (xml_name, inner_type) in &element_variants {
             let variant_name = self.to_rust_variant_name(xml_name);
-            writeln!(code, "    #[serde(rename = \"{}\")]", xml_name).unwrap();
+            writeln!(
+                code,
+                "    #[cfg_attr(feature = \"serde\", serde(rename = \"{}\"))]",
+                xml_name
+            )
+            .unwrap();
             writeln!(code, "    {}({}),", variant_name, inner_type).unwrap();
         }
 
@@ -735,13 +759,15 @@ impl<'a> Generator<'a> {
             let has_unresolved_children = self.has_xml_children_pattern(&def.pattern);
             let has_unresolved_attrs = self.has_xml_attr_pattern(&def.pattern);
 
-            writeln!(
-                code,
-                "#[derive(Debug, Clone, Default, Serialize, Deserialize)]"
-            )
-            .unwrap();
+            writeln!(code, "#[derive(Debug, Clone, Default)]").unwrap();
+            writeln!(code, "{}", SERDE_DERIVE).unwrap();
             if let Some(xml_name) = &element_rename {
-                writeln!(code, "#[serde(rename = \"{}\")]", xml_name).unwrap();
+                writeln!(
+                    code,
+                    "#[cfg_attr(feature = \"serde\", serde(rename = \"{}\"))]",
+                    xml_name
+                )
+                .unwrap();
             }
 
             if has_unresolved_children || has_unresolved_attrs {
@@ -753,10 +779,7 @@ impl<'a> Generator<'a> {
                     )
                     .unwrap();
                     writeln!(code, "    #[cfg(feature = \"extra-attrs\")]").unwrap();
-                    writeln!(code, "    #[serde(skip)]").unwrap();
-                    writeln!(code, "    #[cfg(feature = \"extra-attrs\")]").unwrap();
-                    writeln!(code, "    #[serde(default)]").unwrap();
-                    writeln!(code, "    #[cfg(feature = \"extra-attrs\")]").unwrap();
+                    writeln!(code, "{}", SERDE_EXTRA_ATTRS).unwrap();
                     writeln!(
                         code,
                         "    pub extra_attrs: std::collections::HashMap<String, String>,"
@@ -770,8 +793,7 @@ impl<'a> Generator<'a> {
                     )
                     .unwrap();
                     writeln!(code, "    #[cfg(feature = \"extra-children\")]").unwrap();
-                    writeln!(code, "    #[serde(skip)]").unwrap();
-                    writeln!(code, "    #[cfg(feature = \"extra-children\")]").unwrap();
+                    writeln!(code, "{}", SERDE_EXTRA_CHILDREN).unwrap();
                     writeln!(
                         code,
                         "    pub extra_children: Vec<ooxml_xml::PositionedNode>,"
@@ -789,16 +811,18 @@ impl<'a> Generator<'a> {
                 .iter()
                 .all(|f| f.is_optional || f.is_vec || self.is_eg_content_field(f));
             if all_defaultable {
+                writeln!(code, "#[derive(Debug, Clone, Default)]").unwrap();
+            } else {
+                writeln!(code, "#[derive(Debug, Clone)]").unwrap();
+            }
+            writeln!(code, "{}", SERDE_DERIVE).unwrap();
+            if let Some(xml_name) = &element_rename {
                 writeln!(
                     code,
-                    "#[derive(Debug, Clone, Default, Serialize, Deserialize)]"
+                    "#[cfg_attr(feature = \"serde\", serde(rename = \"{}\"))]",
+                    xml_name
                 )
                 .unwrap();
-            } else {
-                writeln!(code, "#[derive(Debug, Clone, Serialize, Deserialize)]").unwrap();
-            }
-            if let Some(xml_name) = &element_rename {
-                writeln!(code, "#[serde(rename = \"{}\")]", xml_name).unwrap();
             }
             writeln!(code, "pub struct {} {{", rust_name).unwrap();
 
@@ -824,24 +848,52 @@ impl<'a> Generator<'a> {
                 }
 
                 if is_eg_content {
-                    // EG content fields use serde skip — populated by FromXml parsers
-                    writeln!(code, "    #[serde(skip)]").unwrap();
-                    writeln!(code, "    #[serde(default)]").unwrap();
+                    // EG content keeps its field name; each item is tagged with
+                    // its element name by the EG enum
+                    let skip_if = if field.is_vec {
+                        "Vec::is_empty"
+                    } else {
+                        "Option::is_none"
+                    };
+                    writeln!(
+                        code,
+                        "    #[cfg_attr(feature = \"serde\", serde(default, skip_serializing_if = \"{}\"))]",
+                        skip_if
+                    )
+                    .unwrap();
                 } else {
                     // Add serde attributes
                     let xml_name = &field.xml_name;
                     if field.is_text_content {
-                        writeln!(code, "    #[serde(rename = \"$text\")]").unwrap();
+                        writeln!(
+                            code,
+                            "    #[cfg_attr(feature = \"serde\", serde(rename = \"$text\"))]"
+                        )
+                        .unwrap();
                     } else if field.is_attribute {
                         // Include namespace prefix for attributes (e.g., r:id → @r:id)
                         if let Some(prefix) = &field.xml_prefix {
-                            writeln!(code, "    #[serde(rename = \"@{}:{}\")]", prefix, xml_name)
-                                .unwrap();
+                            writeln!(
+                                code,
+                                "    #[cfg_attr(feature = \"serde\", serde(rename = \"@{}:{}\"))]",
+                                prefix, xml_name
+                            )
+                            .unwrap();
                         } else {
-                            writeln!(code, "    #[serde(rename = \"@{}\")]", xml_name).unwrap();
+                            writeln!(
+                                code,
+                                "    #[cfg_attr(feature = \"serde\", serde(rename = \"@{}\"))]",
+                                xml_name
+                            )
+                            .unwrap();
                         }
                     } else {
-                        writeln!(code, "    #[serde(rename = \"{}\")]", xml_name).unwrap();
+                        writeln!(
+                            code,
+                            "    #[cfg_attr(feature = \"serde\", serde(rename = \"{}\"))]",
+                            xml_name
+                        )
+                        .unwrap();
                     }
                 }
                 if field.is_optional {
@@ -849,27 +901,27 @@ impl<'a> Generator<'a> {
                         // OOXML booleans serialize as "1"/"0", not "true"/"false"
                         writeln!(
                             code,
-                            "    #[serde(default, skip_serializing_if = \"Option::is_none\", with = \"ooxml_xml::ooxml_bool\")]"
+                            "    #[cfg_attr(feature = \"serde\", serde(default, skip_serializing_if = \"Option::is_none\", with = \"ooxml_xml::ooxml_bool\"))]"
                         )
                         .unwrap();
                     } else if !is_eg_content {
                         writeln!(
                             code,
-                            "    #[serde(default, skip_serializing_if = \"Option::is_none\")]"
+                            "    #[cfg_attr(feature = \"serde\", serde(default, skip_serializing_if = \"Option::is_none\"))]"
                         )
                         .unwrap();
                     }
                 } else if field.is_vec && !is_eg_content {
                     writeln!(
                         code,
-                        "    #[serde(default, skip_serializing_if = \"Vec::is_empty\")]"
+                        "    #[cfg_attr(feature = \"serde\", serde(default, skip_serializing_if = \"Vec::is_empty\"))]"
                     )
                     .unwrap();
                 } else if is_bool {
                     // Required booleans also need OOXML format
                     writeln!(
                         code,
-                        "    #[serde(with = \"ooxml_xml::ooxml_bool_required\")]"
+                        "    #[cfg_attr(feature = \"serde\", serde(with = \"ooxml_xml::ooxml_bool_required\"))]"
                     )
                     .unwrap();
                 }
@@ -885,11 +937,8 @@ impl<'a> Generator<'a> {
                 )
                 .unwrap();
                 writeln!(code, "    #[cfg(feature = \"extra-attrs\")]").unwrap();
-                // Use skip instead of flatten - flatten doesn't work well with quick-xml
-                writeln!(code, "    #[serde(skip)]").unwrap();
-                writeln!(code, "    #[cfg(feature = \"extra-attrs\")]").unwrap();
-                writeln!(code, "    #[serde(default)]").unwrap();
-                writeln!(code, "    #[cfg(feature = \"extra-attrs\")]").unwrap();
+                // A named map rather than flatten, which doesn't work well with quick-xml
+                writeln!(code, "{}", SERDE_EXTRA_ATTRS).unwrap();
                 writeln!(
                     code,
                     "    pub extra_attrs: std::collections::HashMap<String, String>,"
@@ -907,8 +956,7 @@ impl<'a> Generator<'a> {
                 )
                 .unwrap();
                 writeln!(code, "    #[cfg(feature = \"extra-children\")]").unwrap();
-                writeln!(code, "    #[serde(skip)]").unwrap();
-                writeln!(code, "    #[cfg(feature = \"extra-children\")]").unwrap();
+                writeln!(code, "{}", SERDE_EXTRA_CHILDREN).unwrap();
                 writeln!(
                     code,
                     "    pub extra_children: Vec<ooxml_xml::PositionedNode>,"
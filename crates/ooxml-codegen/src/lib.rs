@@ -5,19 +5,23 @@
 
 pub mod analysis;
 pub mod ast;
+pub mod borrowed_gen;
 pub mod codegen;
 pub mod lexer;
 pub mod parser;
 pub mod parser_gen;
 pub mod serializer_gen;
+pub mod visit_gen;
 
 pub use analysis::{ModuleReport, analyze_schema};
 pub use ast::{DatatypeParam, Definition, Namespace, Pattern, QName, Schema};
+pub use borrowed_gen::generate_borrowed;
 pub use codegen::{CodegenConfig, FeatureMappings, ModuleMappings, NameMappings, generate};
 pub use lexer::{LexError, Lexer};
 pub use parser::{ParseError, Parser};
 pub use parser_gen::generate_parsers;
 pub use serializer_gen::generate_serializers;
+pub use visit_gen::generate_visitors;
 
 /// Parse an RNC schema from a string.
 pub fn parse_rnc(input: &str) -> Result<Schema, Error> {
@@ -145,11 +145,12 @@ impl<'a> ParserGenerator<'a> {
         for import in &self.config.cross_crate_imports {
             writeln!(self.output, "use {};", import).unwrap();
         }
-        writeln!(self.output, "use quick_xml::Reader;").unwrap();
+        writeln!(self.output, "use quick_xml::NsReader;").unwrap();
         writeln!(self.output, "use quick_xml::events::{{Event, BytesStart}};").unwrap();
         writeln!(self.output, "use std::io::BufRead;").unwrap();
         // Import shared traits and error types from ooxml-xml
         writeln!(self.output, "pub use ooxml_xml::{{FromXml, ParseError}};").unwrap();
+        writeln!(self.output, "use ooxml_xml::local_name_in;").unwrap();
         writeln!(self.output, "#[cfg(feature = \"extra-children\")]").unwrap();
         writeln!(
             self.output,
@@ -157,12 +158,25 @@ impl<'a> ParserGenerator<'a> {
         )
         .unwrap();
         writeln!(self.output).unwrap();
+        // Namespaces of the elements these parsers read; children in other
+        // namespaces are treated as unknown elements
+        writeln!(
+            self.output,
+            "/// Namespace URIs of the elements these parsers read."
+        )
+        .unwrap();
+        writeln!(self.output, "const NAMESPACES: &[&str] = &[").unwrap();
+        for ns in &self.config.xml_namespaces {
+            writeln!(self.output, "    {:?},", ns).unwrap();
+        }
+        writeln!(self.output, "];").unwrap();
+        writeln!(self.output).unwrap();
         // Add skip_element helper (allow dead_code since extra-children feature captures instead)
         writeln!(self.output, "#[allow(dead_code)]").unwrap();
         writeln!(self.output, "/// Skip an element and all its children.").unwrap();
         writeln!(
             self.output,
-            "fn skip_element<R: BufRead>(reader: &mut Reader<R>) -> Result<(), ParseError> {{"
+            "fn skip_element<R: BufRead>(reader: &mut NsReader<R>) -> Result<(), ParseError> {{"
         )
         .unwrap();
         writeln!(self.output, "    let mut depth = 1u32;").unwrap();
@@ -193,7 +207,7 @@ impl<'a> ParserGenerator<'a> {
             "/// Read the text content of an element until its end tag."
         )
         .unwrap();
-        writeln!(self.output, "fn read_text_content<R: BufRead>(reader: &mut Reader<R>) -> Result<String, ParseError> {{").unwrap();
+        writeln!(self.output, "fn read_text_content<R: BufRead>(reader: &mut NsReader<R>) -> Result<String, ParseError> {{").unwrap();
         writeln!(self.output, "    let mut text = String::new();").unwrap();
         writeln!(self.output, "    let mut buf = Vec::new();").unwrap();
         writeln!(self.output, "    loop {{").unwrap();
@@ -480,7 +494,7 @@ impl<'a> ParserGenerator<'a> {
         writeln!(code, "impl FromXml for {} {{", rust_name).unwrap();
         writeln!(
             code,
-            "    fn from_xml<R: BufRead>(reader: &mut Reader<R>, start_tag: &BytesStart, is_empty: bool) -> Result<Self, ParseError> {{"
+            "    fn from_xml<R: BufRead>(reader: &mut NsReader<R>, start_tag: &BytesStart, is_empty: bool) -> Result<Self, ParseError> {{"
         )
         .unwrap();
         writeln!(code, "        let tag = start_tag.local_name();").unwrap();
@@ -542,7 +556,7 @@ impl<'a> ParserGenerator<'a> {
                 // Struct with extra_children/extra_attrs — capture unknown XML
                 writeln!(
                     code,
-                    "    fn from_xml<R: BufRead>(reader: &mut Reader<R>, start_tag: &BytesStart, is_empty: bool) -> Result<Self, ParseError> {{"
+                    "    fn from_xml<R: BufRead>(reader: &mut NsReader<R>, start_tag: &BytesStart, is_empty: bool) -> Result<Self, ParseError> {{"
                 )
                 .unwrap();
                 if has_unresolved_attrs {
@@ -667,7 +681,7 @@ impl<'a> ParserGenerator<'a> {
                 // Truly empty struct - skip all children with depth tracking
                 writeln!(
                     code,
-                    "    fn from_xml<R: BufRead>(reader: &mut Reader<R>, _start: &BytesStart, is_empty: bool) -> Result<Self, ParseError> {{"
+                    "    fn from_xml<R: BufRead>(reader: &mut NsReader<R>, _start: &BytesStart, is_empty: bool) -> Result<Self, ParseError> {{"
                 )
                 .unwrap();
                 writeln!(code, "        if !is_empty {{").unwrap();
@@ -703,7 +717,7 @@ impl<'a> ParserGenerator<'a> {
         writeln!(code, "impl FromXml for {} {{", rust_name).unwrap();
         writeln!(
             code,
-            "    fn from_xml<R: BufRead>(reader: &mut Reader<R>, start_tag: &BytesStart, is_empty: bool) -> Result<Self, ParseError> {{"
+            "    fn from_xml<R: BufRead>(reader: &mut NsReader<R>, start_tag: &BytesStart, is_empty: bool) -> Result<Self, ParseError> {{"
         )
         .unwrap();
 
@@ -832,7 +846,7 @@ impl<'a> ParserGenerator<'a> {
             writeln!(code, "                    Event::Start(e) => {{").unwrap();
             writeln!(
                 code,
-                "                        match e.local_name().as_ref() {{"
+                "                        match local_name_in(reader, &e, NAMESPACES) {{"
             )
             .unwrap();
 
@@ -959,7 +973,7 @@ impl<'a> ParserGenerator<'a> {
             writeln!(code, "                    Event::Empty(e) => {{").unwrap();
             writeln!(
                 code,
-                "                        match e.local_name().as_ref() {{"
+                "                        match local_name_in(reader, &e, NAMESPACES) {{"
             )
             .unwrap();
 
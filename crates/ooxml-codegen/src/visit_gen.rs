@@ -0,0 +1,1124 @@
+//! Generator for `Visit`/`VisitMut` traversal of the generated types.
+//!
+//! Like the borrowed types, visitors are derived from the generated types
+//! rather than from the schema, so they follow the same names and feature
+//! gates:
+//!
+//! - every struct and enum gets a `visit_*` method on `Visit<'ast>` and a
+//!   `visit_*_mut` method on `VisitMut`, whose default calls the matching
+//!   `walk_*` function to visit the fields or variant in declaration order;
+//! - `extra_children` are visited as raw XML through `visit_raw_xml_node`,
+//!   which descends into raw elements;
+//! - types of another crate (such as `ooxml_dml::types::CTTextBody` in PML)
+//!   are visited through that crate's visitor, which becomes a supertrait
+//!   and also provides the raw XML methods.
+//!
+//! Simple types have methods too, but nothing to walk.
+//!
+//! The module also implements `ooxml_xml::QueryTree` for every struct and
+//! choice enum, so path queries can run over typed trees. Those impls
+//! report what the serializers write, so the element names of fields and
+//! variants are read from the generated serializers: each statement of a
+//! `write_children` body writes one field, and each arm of a choice enum's
+//! `write_element` one variant.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::{HashMap, HashSet};
+use syn::{
+    Attribute, Expr, Fields, File, Ident, ImplItem, Item, ItemEnum, ItemStruct, Lit, Member, Pat,
+    Type,
+};
+
+/// Generate the visitor module from the source of the generated types
+/// (`generated.rs`) and serializers (`generated_serializers.rs`).
+///
+/// `dependencies` lists the other crates whose types are used, as the crate
+/// name (e.g. `ooxml_dml`) with the source of its generated types. The
+/// output refers to the types as `super::generated` and to the dependencies'
+/// visitors as `<crate>::visit`, and needs formatting with rustfmt.
+pub fn generate_visitors(
+    types: &str,
+    serializers: &str,
+    dependencies: &[(&str, &str)],
+) -> syn::Result<String> {
+    let local = TypeIndex::new(syn::parse_str(types)?);
+    let serialized = Serialized::new(syn::parse_str(serializers)?);
+    let mut foreign = HashMap::new();
+    for (name, source) in dependencies {
+        foreign.insert(name.to_string(), TypeIndex::new(syn::parse_str(source)?));
+    }
+    // With dependencies, raw XML is visited through the first one's methods,
+    // so a single override covers unknown children of every crate
+    let mut deps: Vec<&String> = foreign.keys().collect();
+    deps.sort();
+    let (raw, raw_mut) = match deps.first() {
+        Some(dep) => {
+            let krate = format_ident!("{}", dep);
+            (
+                quote!(#krate::visit::Visit),
+                quote!(#krate::visit::VisitMut),
+            )
+        }
+        None => (quote!(Visit), quote!(VisitMut)),
+    };
+    let generator = Generator {
+        local: &local,
+        foreign: &foreign,
+        raw: raw.clone(),
+        raw_mut: raw_mut.clone(),
+    };
+
+    let mut names = HashSet::new();
+    for name in local.structs.keys().chain(local.enums.keys()) {
+        if !names.insert(snake_case(name)) {
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                format!("visitor method name collision for {name}"),
+            ));
+        }
+    }
+
+    let mut visit_methods = Vec::new();
+    let mut visit_mut_methods = Vec::new();
+    let mut walks = Vec::new();
+    let mut items: Vec<(&Ident, Walk)> = local
+        .structs
+        .values()
+        .map(|s| (&s.ident, Walk::Struct(s)))
+        .chain(local.enums.values().map(|e| (&e.ident, Walk::Enum(e))))
+        .collect();
+    items.sort_by_key(|(ident, _)| ident.to_string());
+    for (ident, item) in items {
+        let method = format_ident!("visit_{}", snake_case(&ident.to_string()));
+        let method_mut = format_ident!("visit_{}_mut", snake_case(&ident.to_string()));
+        let walk = format_ident!("walk_{}", snake_case(&ident.to_string()));
+        let walk_mut = format_ident!("walk_{}_mut", snake_case(&ident.to_string()));
+        let body = generator.walk_body(&item, false);
+        let body_mut = generator.walk_body(&item, true);
+        if body.is_none() {
+            visit_methods.push(quote! {
+                fn #method(&mut self, _node: &'ast #ident) {}
+            });
+            visit_mut_methods.push(quote! {
+                fn #method_mut(&mut self, _node: &mut #ident) {}
+            });
+            continue;
+        }
+        visit_methods.push(quote! {
+            fn #method(&mut self, node: &'ast #ident) {
+                #walk(self, node)
+            }
+        });
+        visit_mut_methods.push(quote! {
+            fn #method_mut(&mut self, node: &mut #ident) {
+                #walk_mut(self, node)
+            }
+        });
+        walks.push(quote! {
+            pub fn #walk<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast #ident) {
+                #body
+            }
+        });
+        walks.push(quote! {
+            pub fn #walk_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut #ident) {
+                #body_mut
+            }
+        });
+    }
+
+    let supertraits: Vec<TokenStream> = deps
+        .iter()
+        .map(|d| {
+            let krate = format_ident!("{}", d);
+            quote!(#krate::visit::Visit<'ast>)
+        })
+        .collect();
+    let supertraits_mut: Vec<TokenStream> = deps
+        .iter()
+        .map(|d| {
+            let krate = format_ident!("{}", d);
+            quote!(#krate::visit::VisitMut)
+        })
+        .collect();
+    let (bound, bound_mut) = if deps.is_empty() {
+        (quote!(), quote!())
+    } else {
+        (quote!(: #(#supertraits)+*), quote!(: #(#supertraits_mut)+*))
+    };
+
+    let raw_imports = if deps.is_empty() {
+        quote!(
+            use ooxml_xml::{RawXmlElement, RawXmlNode};
+        )
+    } else {
+        quote!()
+    };
+    let header = quote! {
+        #![allow(unused_variables)]
+        #![allow(clippy::single_match)]
+        #![allow(clippy::match_single_binding)]
+
+        use super::generated::*;
+        #raw_imports
+    };
+    let raw_methods = quote! {
+        fn visit_raw_xml_node(&mut self, node: &'ast RawXmlNode) {
+            walk_raw_xml_node(self, node)
+        }
+
+        fn visit_raw_xml_element(&mut self, node: &'ast RawXmlElement) {
+            walk_raw_xml_element(self, node)
+        }
+    };
+    let raw_methods_mut = quote! {
+        fn visit_raw_xml_node_mut(&mut self, node: &mut RawXmlNode) {
+            walk_raw_xml_node_mut(self, node)
+        }
+
+        fn visit_raw_xml_element_mut(&mut self, node: &mut RawXmlElement) {
+            walk_raw_xml_element_mut(self, node)
+        }
+    };
+    let raw_walks = quote! {
+        pub fn walk_raw_xml_node<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast RawXmlNode) {
+            if let RawXmlNode::Element(element) = node {
+                Visit::visit_raw_xml_element(v, element);
+            }
+        }
+
+        pub fn walk_raw_xml_node_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut RawXmlNode) {
+            if let RawXmlNode::Element(element) = node {
+                VisitMut::visit_raw_xml_element_mut(v, element);
+            }
+        }
+
+        pub fn walk_raw_xml_element<'ast, V: Visit<'ast> + ?Sized>(
+            v: &mut V,
+            node: &'ast RawXmlElement,
+        ) {
+            for child in &node.children {
+                Visit::visit_raw_xml_node(v, child);
+            }
+        }
+
+        pub fn walk_raw_xml_element_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut RawXmlElement) {
+            for child in &mut node.children {
+                VisitMut::visit_raw_xml_node_mut(v, child);
+            }
+        }
+    };
+    let (raw_methods, raw_methods_mut, raw_walks) = if deps.is_empty() {
+        (raw_methods, raw_methods_mut, raw_walks)
+    } else {
+        (quote!(), quote!(), quote!())
+    };
+    let traits = quote! {
+        /// Read-only traversal of the generated types.
+        ///
+        /// Every method defaults to visiting the node's children; override
+        /// the ones of interest and call the matching `walk_*` function to
+        /// keep descending.
+        pub trait Visit<'ast> #bound {
+            #(#visit_methods)*
+
+            #raw_methods
+        }
+
+        /// Mutable traversal of the generated types.
+        ///
+        /// Every method defaults to visiting the node's children; override
+        /// the ones of interest and call the matching `walk_*_mut` function
+        /// to keep descending.
+        pub trait VisitMut #bound_mut {
+            #(#visit_mut_methods)*
+
+            #raw_methods_mut
+        }
+
+        #raw_walks
+    };
+
+    let mut trees: Vec<(&Ident, TokenStream)> = local
+        .structs
+        .values()
+        .map(|s| (&s.ident, generator.struct_tree(s, &serialized)))
+        .chain(
+            local
+                .enums
+                .values()
+                .filter(|e| is_choice(e))
+                .map(|e| (&e.ident, generator.enum_tree(e, &serialized))),
+        )
+        .collect();
+    trees.sort_by_key(|(ident, _)| ident.to_string());
+
+    let items: Vec<String> = std::iter::once(traits)
+        .chain(walks)
+        .chain(trees.into_iter().map(|(_, tree)| tree))
+        .map(|t| t.to_string())
+        .collect();
+    Ok(format!(
+        "// Visitors for the generated types, derived from generated.rs.\n\
+         // Do not edit manually.\n\n{}\n\n{}\n",
+        header,
+        items.join("\n\n")
+    ))
+}
+
+/// What the generated serializers write for each type.
+#[derive(Default)]
+struct Serialized {
+    /// Types with a `ToXml` impl.
+    types: HashSet<String>,
+    /// Fields of each struct written by `write_children`, in order.
+    children: HashMap<String, Vec<(String, Child)>>,
+    /// Element of each variant of a choice enum.
+    variants: HashMap<String, HashMap<String, String>>,
+}
+
+/// How a field is written.
+enum Child {
+    /// As elements of this name (or of their variant, if empty).
+    Element(String),
+    /// As text content.
+    Text,
+}
+
+impl Serialized {
+    fn new(file: File) -> Self {
+        let mut serialized = Serialized::default();
+        for item in file.items {
+            let Item::Impl(i) = item else { continue };
+            let is_to_xml = i
+                .trait_
+                .as_ref()
+                .and_then(|(_, path, _)| path.segments.last())
+                .is_some_and(|s| s.ident == "ToXml");
+            let Type::Path(ty) = &*i.self_ty else {
+                continue;
+            };
+            let Some(name) = ty.path.segments.last().map(|s| s.ident.to_string()) else {
+                continue;
+            };
+            if !is_to_xml {
+                continue;
+            }
+            for item in &i.items {
+                let ImplItem::Fn(f) = item else { continue };
+                if f.sig.ident == "write_children" {
+                    let children = f.block.stmts.iter().filter_map(|stmt| {
+                        let mut scan = Written::default();
+                        syn::visit::Visit::visit_stmt(&mut scan, stmt);
+                        match (scan.fields.as_slice(), scan.element, scan.text) {
+                            ([field], Some(element), _) => {
+                                Some((field.clone(), Child::Element(element)))
+                            }
+                            ([field], None, true) => Some((field.clone(), Child::Text)),
+                            _ => None,
+                        }
+                    });
+                    serialized.children.insert(name.clone(), children.collect());
+                } else if f.sig.ident == "write_element" {
+                    let mut arms = Arms::default();
+                    syn::visit::Visit::visit_block(&mut arms, &f.block);
+                    serialized.variants.insert(name.clone(), arms.elements);
+                }
+            }
+            serialized.types.insert(name);
+        }
+        serialized
+    }
+}
+
+/// The fields, element name and text written by a serializer statement.
+#[derive(Default)]
+struct Written {
+    fields: Vec<String>,
+    element: Option<String>,
+    text: bool,
+}
+
+impl<'ast> syn::visit::Visit<'ast> for Written {
+    fn visit_expr_field(&mut self, e: &'ast syn::ExprField) {
+        if is_self(&e.base)
+            && let Member::Named(field) = &e.member
+            && field != "extra_children"
+            && !self.fields.iter().any(|f| field == f)
+        {
+            self.fields.push(field.to_string());
+        }
+        syn::visit::visit_expr_field(self, e);
+    }
+
+    fn visit_expr_method_call(&mut self, e: &'ast syn::ExprMethodCall) {
+        if e.method == "write_element" && self.element.is_none() {
+            self.element = e.args.first().and_then(string_literal);
+        }
+        syn::visit::visit_expr_method_call(self, e);
+    }
+
+    fn visit_expr_call(&mut self, e: &'ast syn::ExprCall) {
+        if let Expr::Path(func) = &*e.func
+            && func.path.segments.len() == 2
+            && func.path.segments[0].ident == "BytesStart"
+            && self.element.is_none()
+        {
+            self.element = e.args.first().and_then(string_literal);
+        }
+        syn::visit::visit_expr_call(self, e);
+    }
+
+    fn visit_ident(&mut self, ident: &'ast Ident) {
+        self.text |= ident == "BytesText";
+    }
+}
+
+/// The element written by each arm of a choice enum's `write_element`.
+#[derive(Default)]
+struct Arms {
+    elements: HashMap<String, String>,
+}
+
+impl<'ast> syn::visit::Visit<'ast> for Arms {
+    fn visit_arm(&mut self, arm: &'ast syn::Arm) {
+        let path = match &arm.pat {
+            Pat::TupleStruct(p) => &p.path,
+            Pat::Path(p) => &p.path,
+            _ => return,
+        };
+        let mut scan = Written::default();
+        syn::visit::Visit::visit_expr(&mut scan, &arm.body);
+        if let (Some(variant), Some(element)) = (path.segments.last(), scan.element) {
+            self.elements.insert(variant.ident.to_string(), element);
+        }
+    }
+}
+
+fn is_self(expr: &Expr) -> bool {
+    matches!(expr, Expr::Path(p) if p.path.is_ident("self"))
+}
+
+fn string_literal(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(syn::ExprLit {
+            lit: Lit::Str(s), ..
+        }) => Some(s.value()),
+        _ => None,
+    }
+}
+
+/// Whether an enum is a choice of elements rather than a simple type.
+fn is_choice(e: &ItemEnum) -> bool {
+    e.variants.iter().any(|v| !v.fields.is_empty())
+}
+
+/// Structs, enums and aliases of one crate's generated types.
+struct TypeIndex {
+    structs: HashMap<String, ItemStruct>,
+    enums: HashMap<String, ItemEnum>,
+    aliases: HashMap<String, Type>,
+}
+
+impl TypeIndex {
+    fn new(file: File) -> Self {
+        let mut index = TypeIndex {
+            structs: HashMap::new(),
+            enums: HashMap::new(),
+            aliases: HashMap::new(),
+        };
+        for item in file.items {
+            match item {
+                Item::Struct(s) => {
+                    index.structs.insert(s.ident.to_string(), s);
+                }
+                Item::Enum(e) => {
+                    index.enums.insert(e.ident.to_string(), e);
+                }
+                Item::Type(t) => {
+                    index.aliases.insert(t.ident.to_string(), *t.ty);
+                }
+                _ => {}
+            }
+        }
+        index
+    }
+}
+
+enum Walk<'a> {
+    Struct(&'a ItemStruct),
+    Enum(&'a ItemEnum),
+}
+
+struct Generator<'a> {
+    local: &'a TypeIndex,
+    foreign: &'a HashMap<String, TypeIndex>,
+    /// Trait whose methods visit raw XML.
+    raw: TokenStream,
+    raw_mut: TokenStream,
+}
+
+impl Generator<'_> {
+    /// Body of a `walk_*` function, or `None` if there is nothing to visit.
+    fn walk_body(&self, item: &Walk, mutable: bool) -> Option<TokenStream> {
+        match item {
+            Walk::Struct(s) => {
+                let Fields::Named(fields) = &s.fields else {
+                    return None;
+                };
+                let stmts: Vec<TokenStream> = fields
+                    .named
+                    .iter()
+                    .filter_map(|f| {
+                        let ident = f.ident.as_ref()?;
+                        let access = if mutable {
+                            quote!(&mut node.#ident)
+                        } else {
+                            quote!(&node.#ident)
+                        };
+                        let visit = self.visit_type(&f.ty, access, None, mutable, 0)?;
+                        let cfgs = cfg_attrs(&f.attrs);
+                        Some(if cfgs.is_empty() {
+                            visit
+                        } else {
+                            quote!(#(#cfgs)* { #visit })
+                        })
+                    })
+                    .collect();
+                (!stmts.is_empty()).then(|| quote!(#(#stmts)*))
+            }
+            Walk::Enum(e) => {
+                let name = &e.ident;
+                let mut any = false;
+                let arms: Vec<TokenStream> = e
+                    .variants
+                    .iter()
+                    .map(|v| {
+                        let cfgs = cfg_attrs(&v.attrs);
+                        let variant = &v.ident;
+                        match v.fields.iter().next() {
+                            None => quote!(#(#cfgs)* #name::#variant => {}),
+                            Some(f) => match self.visit_type(&f.ty, quote!(it), None, mutable, 0) {
+                                Some(visit) => {
+                                    any = true;
+                                    quote!(#(#cfgs)* #name::#variant(it) => { #visit })
+                                }
+                                None => quote!(#(#cfgs)* #name::#variant(_) => {}),
+                            },
+                        }
+                    })
+                    .collect();
+                any.then(|| quote!(match node { #(#arms)* }))
+            }
+        }
+    }
+
+    /// Statements visiting `access`, a reference to a value of type `ty`
+    /// defined in `krate` (or locally), or `None` if `ty` has nothing to
+    /// visit.
+    fn visit_type(
+        &self,
+        ty: &Type,
+        access: TokenStream,
+        krate: Option<&str>,
+        mutable: bool,
+        depth: usize,
+    ) -> Option<TokenStream> {
+        let Type::Path(p) = ty else {
+            return None;
+        };
+        let segments: Vec<&syn::PathSegment> = p.path.segments.iter().collect();
+        let last = segments.last()?;
+        let name = last.ident.to_string();
+        let inner = || -> Option<&Type> {
+            match &last.arguments {
+                syn::PathArguments::AngleBracketed(args) => {
+                    args.args.iter().find_map(|a| match a {
+                        syn::GenericArgument::Type(t) => Some(t),
+                        _ => None,
+                    })
+                }
+                _ => None,
+            }
+        };
+        let binding = format_ident!("it{}", depth);
+
+        // Types of a dependency, written as `<crate>::types::Name`
+        let (krate, path_crate) = match segments.first() {
+            Some(first)
+                if segments.len() > 1 && self.foreign.contains_key(&first.ident.to_string()) =>
+            {
+                (Some(first.ident.to_string()), true)
+            }
+            _ => (krate.map(str::to_string), false),
+        };
+        if segments.len() > 1 && !path_crate {
+            // ooxml_xml::PositionedNode (extra_children)
+            if name == "PositionedNode" {
+                let (raw, raw_mut) = (&self.raw, &self.raw_mut);
+                return Some(if mutable {
+                    quote!(#raw_mut::visit_raw_xml_node_mut(v, &mut #access.node);)
+                } else {
+                    quote!(#raw::visit_raw_xml_node(v, &#access.node);)
+                });
+            }
+            return None;
+        }
+
+        match name.as_str() {
+            "Option" => {
+                let body = self.visit_type(
+                    inner()?,
+                    quote!(#binding),
+                    krate.as_deref(),
+                    mutable,
+                    depth + 1,
+                )?;
+                Some(quote!(if let Some(#binding) = #access { #body }))
+            }
+            "Vec" => {
+                let body = self.visit_type(
+                    inner()?,
+                    quote!(#binding),
+                    krate.as_deref(),
+                    mutable,
+                    depth + 1,
+                )?;
+                Some(quote!(for #binding in #access { #body }))
+            }
+            // Deref coercion turns `&Box<T>` into `&T` at the call
+            "Box" => self.visit_type(inner()?, access, krate.as_deref(), mutable, depth),
+            _ => {
+                let index = match &krate {
+                    Some(k) => self.foreign.get(k)?,
+                    None => self.local,
+                };
+                if let Some(target) = index.aliases.get(&name) {
+                    return self.visit_type(target, access, krate.as_deref(), mutable, depth);
+                }
+                if !index.structs.contains_key(&name) && !index.enums.contains_key(&name) {
+                    return None;
+                }
+                let method = if mutable {
+                    format_ident!("visit_{}_mut", snake_case(&name))
+                } else {
+                    format_ident!("visit_{}", snake_case(&name))
+                };
+                let trait_path = match (&krate, mutable) {
+                    (Some(k), false) => {
+                        let k = format_ident!("{}", k);
+                        quote!(#k::visit::Visit)
+                    }
+                    (Some(k), true) => {
+                        let k = format_ident!("{}", k);
+                        quote!(#k::visit::VisitMut)
+                    }
+                    (None, false) => quote!(Visit),
+                    (None, true) => quote!(VisitMut),
+                };
+                Some(quote!(#trait_path::#method(v, #access);))
+            }
+        }
+    }
+}
+
+impl Generator<'_> {
+    /// `QueryTree` impl of a struct, reporting its attributes, the fields
+    /// its serializer writes in the same order, and its unknown children.
+    fn struct_tree(&self, s: &ItemStruct, serialized: &Serialized) -> TokenStream {
+        let ident = &s.ident;
+        let name = ident.to_string();
+        let fields: HashMap<String, &syn::Field> = s
+            .fields
+            .iter()
+            .filter_map(|f| Some((f.ident.as_ref()?.to_string(), f)))
+            .collect();
+        let mut stmts = Vec::new();
+        if serialized.types.contains(&name) {
+            stmts.push(quote!(ooxml_xml::visit_attributes(self, v);));
+        }
+        for (field, child) in serialized.children.get(&name).into_iter().flatten() {
+            let Some(f) = fields.get(field) else { continue };
+            let ident = format_ident!("{}", field);
+            let stmt = match child {
+                Child::Element(element) => {
+                    self.tree_type(&f.ty, quote!(self.#ident), false, element, None, 0)
+                }
+                Child::Text => self.text_type(&f.ty, quote!(self.#ident), false, 0),
+            };
+            if let Some(stmt) = stmt {
+                let cfgs = cfg_attrs(&f.attrs);
+                stmts.push(if cfgs.is_empty() {
+                    stmt
+                } else {
+                    quote!(#(#cfgs)* { #stmt })
+                });
+            }
+        }
+        if let Some(f) = fields.get("extra_children") {
+            let cfgs = cfg_attrs(&f.attrs);
+            stmts.push(quote! {
+                #(#cfgs)*
+                for it0 in &self.extra_children {
+                    v.raw(&it0.node);
+                }
+            });
+        }
+        quote! {
+            impl ooxml_xml::QueryTree for #ident {
+                fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+                    v.element(name, self);
+                }
+
+                fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+                    #(#stmts)*
+                }
+
+                fn as_any(&self) -> &dyn std::any::Any {
+                    self
+                }
+            }
+        }
+    }
+
+    /// `QueryTree` impl of a choice enum, reporting the element of its
+    /// variant.
+    fn enum_tree(&self, e: &ItemEnum, serialized: &Serialized) -> TokenStream {
+        let ident = &e.ident;
+        let elements = serialized.variants.get(&ident.to_string());
+        let mut element_arms = Vec::new();
+        let mut content_arms = Vec::new();
+        for variant in &e.variants {
+            let cfgs = cfg_attrs(&variant.attrs);
+            let name = &variant.ident;
+            let element = elements.and_then(|m| m.get(&name.to_string()));
+            match variant.fields.iter().next() {
+                None => {
+                    let report = element.map(|element| quote!(v.element(#element, self);));
+                    element_arms.push(quote!(#(#cfgs)* Self::#name => { #report }));
+                    content_arms.push(quote!(#(#cfgs)* Self::#name => {}));
+                }
+                Some(f) => {
+                    let report = element.and_then(|element| {
+                        self.tree_type(&f.ty, quote!(it0), true, element, None, 1)
+                    });
+                    element_arms.push(quote!(#(#cfgs)* Self::#name(it0) => { #report }));
+                    let content = self
+                        .is_tree(&f.ty, None)
+                        .then(|| quote!(it0.visit_content(v);));
+                    content_arms.push(quote!(#(#cfgs)* Self::#name(it0) => { #content }));
+                }
+            }
+        }
+        quote! {
+            impl ooxml_xml::QueryTree for #ident {
+                fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+                    match self {
+                        #(#element_arms)*
+                    }
+                }
+
+                fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+                    match self {
+                        #(#content_arms)*
+                    }
+                }
+
+                fn as_any(&self) -> &dyn std::any::Any {
+                    self
+                }
+            }
+        }
+    }
+
+    /// Statements reporting `access`, a value of type `ty` or a reference
+    /// to one (`is_ref`), as `element`, or `None` if `ty` does not implement
+    /// `QueryTree`.
+    fn tree_type(
+        &self,
+        ty: &Type,
+        access: TokenStream,
+        is_ref: bool,
+        element: &str,
+        krate: Option<&str>,
+        depth: usize,
+    ) -> Option<TokenStream> {
+        let binding = format_ident!("it{}", depth);
+        let borrowed = if is_ref {
+            access.clone()
+        } else {
+            quote!(&#access)
+        };
+        match container(ty) {
+            Some(("Option", inner)) => {
+                let body =
+                    self.tree_type(inner, quote!(#binding), true, element, krate, depth + 1)?;
+                Some(quote!(if let Some(#binding) = #borrowed { #body }))
+            }
+            Some(("Vec", inner)) => {
+                let body =
+                    self.tree_type(inner, quote!(#binding), true, element, krate, depth + 1)?;
+                Some(quote!(for #binding in #borrowed { #body }))
+            }
+            _ => self
+                .is_tree(ty, krate)
+                .then(|| quote!(#access.visit_element(#element, v);)),
+        }
+    }
+
+    /// Statements reporting `access`, a value of type `ty` or a reference
+    /// to one (`is_ref`), as text.
+    fn text_type(
+        &self,
+        ty: &Type,
+        access: TokenStream,
+        is_ref: bool,
+        depth: usize,
+    ) -> Option<TokenStream> {
+        let binding = format_ident!("it{}", depth);
+        let borrowed = if is_ref {
+            access.clone()
+        } else {
+            quote!(&#access)
+        };
+        match container(ty) {
+            Some(("Option", inner)) => {
+                let body = self.text_type(inner, quote!(#binding), true, depth + 1)?;
+                Some(quote!(if let Some(#binding) = #borrowed { #body }))
+            }
+            Some(_) => None,
+            None => match ty {
+                Type::Path(p) if p.path.is_ident("String") => Some(quote!(v.text(#borrowed);)),
+                Type::Path(p) => match p
+                    .path
+                    .get_ident()
+                    .and_then(|i| self.local.aliases.get(&i.to_string()))
+                {
+                    Some(target) => self.text_type(target, access, is_ref, depth),
+                    None => Some(quote!(v.text(&#access.to_string());)),
+                },
+                _ => None,
+            },
+        }
+    }
+
+    /// Whether `ty`, defined in `krate` (or locally), implements `QueryTree`
+    /// itself or through a `Box`.
+    fn is_tree(&self, ty: &Type, krate: Option<&str>) -> bool {
+        if let Some(("Box", inner)) = container(ty) {
+            return self.is_tree(inner, krate);
+        }
+        let Type::Path(p) = ty else {
+            return false;
+        };
+        let segments: Vec<&syn::PathSegment> = p.path.segments.iter().collect();
+        let Some(last) = segments.last() else {
+            return false;
+        };
+        let name = last.ident.to_string();
+        if name == "String" {
+            return true;
+        }
+        let krate = match segments.first() {
+            Some(first) if segments.len() > 1 => {
+                let first = first.ident.to_string();
+                if !self.foreign.contains_key(&first) {
+                    return false;
+                }
+                Some(first)
+            }
+            _ => krate.map(str::to_string),
+        };
+        let index = match &krate {
+            Some(k) => &self.foreign[k],
+            None => self.local,
+        };
+        if let Some(target) = index.aliases.get(&name) {
+            return self.is_tree(target, krate.as_deref());
+        }
+        index.structs.contains_key(&name) || index.enums.get(&name).is_some_and(is_choice)
+    }
+}
+
+/// The name and type argument of `Option<T>`, `Vec<T>` and `Box<T>`.
+fn container(ty: &Type) -> Option<(&'static str, &Type)> {
+    let Type::Path(p) = ty else {
+        return None;
+    };
+    let last = p.path.segments.last()?;
+    let name = ["Option", "Vec", "Box"]
+        .into_iter()
+        .find(|name| last.ident == name)?;
+    let syn::PathArguments::AngleBracketed(args) = &last.arguments else {
+        return None;
+    };
+    args.args.iter().find_map(|a| match a {
+        syn::GenericArgument::Type(t) => Some((name, t)),
+        _ => None,
+    })
+}
+
+/// `#[cfg(...)]` attributes among `attrs`.
+fn cfg_attrs(attrs: &[Attribute]) -> Vec<&Attribute> {
+    attrs.iter().filter(|a| a.path().is_ident("cfg")).collect()
+}
+
+/// Snake case of a type name, keeping acronyms together
+/// (`CTTxbxContent` → `ct_txbx_content`).
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if prev.is_lowercase() || prev.is_ascii_digit() || (prev.is_uppercase() && next_lower) {
+                out.push('_');
+            }
+        }
+        out.extend(c.to_lowercase());
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TYPES: &str = r#"
+pub type XmlString = String;
+pub type WText = Box<Text>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum STJc {
+    Left,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Text {
+    pub text: Option<XmlString>,
+    #[cfg(feature = "extra-children")]
+    pub extra_children: Vec<ooxml_xml::PositionedNode>,
+}
+
+#[derive(Debug, Clone)]
+pub enum RunContent {
+    T(Box<Text>),
+    Cr(Box<Empty>),
+    Tab,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Empty {
+    #[cfg(feature = "extra-attrs")]
+    pub extra_attrs: std::collections::HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Run {
+    pub jc: Option<STJc>,
+    #[cfg(feature = "styling")]
+    pub style: Option<String>,
+    pub run_content: Vec<RunContent>,
+    pub body: Option<ooxml_dml::types::CTTextBody>,
+    pub angle: Option<ooxml_dml::types::STAngle>,
+    pub alias: Option<WText>,
+}
+"#;
+
+    const DML: &str = r#"
+pub type STAngle = i32;
+
+pub struct CTTextBody {
+    pub p: Vec<String>,
+}
+"#;
+
+    const SERIALIZERS: &str = r#"
+impl ToXml for Text {
+    fn write_children<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), SerializeError> {
+        if let Some(ref text) = self.text {
+            writer.write_event(Event::Text(BytesText::new(text)))?;
+        }
+        #[cfg(feature = "extra-children")]
+        for extra in &self.extra_children {
+            extra.node.write_to(writer).map_err(SerializeError::from)?;
+        }
+        Ok(())
+    }
+}
+
+impl ToXml for RunContent {
+    fn write_element<W: Write>(&self, _tag: &str, writer: &mut Writer<W>) -> Result<(), SerializeError> {
+        match self {
+            Self::T(inner) => inner.write_element("w:t", writer)?,
+            Self::Cr(inner) => {
+                inner.write_element("w:cr", writer)?
+            }
+            Self::Tab => writer.write_event(Event::Empty(BytesStart::new("w:tab")))?,
+        }
+        Ok(())
+    }
+}
+
+impl ToXml for Run {
+    fn write_attrs<'a>(&self, start: BytesStart<'a>) -> BytesStart<'a> {
+        if let Some(ref val) = self.jc {
+            start.push_attribute(("w:jc", val.to_string().as_str()));
+        }
+        start
+    }
+
+    fn write_children<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), SerializeError> {
+        #[cfg(feature = "extra-children")]
+        let mut extra_iter = self.extra_children.iter().peekable();
+        if let Some(ref val) = self.alias {
+            val.write_element("w:alias", writer)?;
+        }
+        for item in &self.run_content {
+            item.write_element("", writer)?;
+        }
+        if let Some(ref val) = self.body {
+            val.write_element("w:txbx", writer)?;
+        }
+        #[cfg(feature = "styling")]
+        if let Some(ref val) = self.style {
+            let val_str = val.as_str();
+            let mut start = BytesStart::new("w:style");
+            writer.write_event(Event::Start(start))?;
+            writer.write_event(Event::Text(BytesText::new(val_str)))?;
+            writer.write_event(Event::End(BytesEnd::new("w:style")))?;
+        }
+        Ok(())
+    }
+}
+"#;
+
+    fn generated() -> String {
+        generate_visitors(TYPES, SERIALIZERS, &[("ooxml_dml", DML)]).expect("should generate")
+    }
+
+    #[test]
+    fn test_snake_case() {
+        assert_eq!(snake_case("CTTxbxContent"), "ct_txbx_content");
+        assert_eq!(snake_case("STJc"), "st_jc");
+        assert_eq!(snake_case("Paragraph"), "paragraph");
+        assert_eq!(
+            snake_case("CTTLCommonTimeNodeData"),
+            "cttl_common_time_node_data"
+        );
+        assert_eq!(snake_case("Ole2Link"), "ole2_link");
+    }
+
+    #[test]
+    fn test_generated_output_parses() {
+        let out = generated();
+        syn::parse_file(&out).expect("generated code should parse");
+    }
+
+    #[test]
+    fn test_methods_for_every_type() {
+        let out = generated();
+        for method in [
+            "visit_st_jc",
+            "visit_text",
+            "visit_run_content",
+            "visit_empty",
+            "visit_run",
+        ] {
+            assert!(out.contains(&format!("fn {method} (")), "missing {method}");
+            assert!(
+                out.contains(&format!("fn {method}_mut (")),
+                "missing {method}_mut"
+            );
+        }
+        // Nothing to walk in simple types and attribute-only structs
+        assert!(!out.contains("fn walk_st_jc"));
+        assert!(!out.contains("fn walk_empty <"));
+        assert!(out.contains("fn walk_run_content <"));
+    }
+
+    #[test]
+    fn test_walk_fields() {
+        let out = generated();
+        let walk = out
+            .split("pub fn walk_run <")
+            .nth(1)
+            .expect("walk_run should be generated");
+        let walk = &walk[..walk.find("pub fn").unwrap_or(walk.len())];
+        assert!(walk.contains("Visit :: visit_st_jc (v , it0)"));
+        assert!(walk.contains("for it0 in & node . run_content"));
+        assert!(walk.contains("ooxml_dml :: visit :: Visit :: visit_ct_text_body (v , it0)"));
+        // Aliases resolve to their targets; leaves are skipped
+        assert!(walk.contains("Visit :: visit_text (v , it0)"));
+        assert!(!walk.contains("angle"));
+        assert!(!walk.contains("style"));
+
+        let text = out.split("pub fn walk_text <").nth(1).unwrap();
+        assert!(text.contains("# [cfg (feature = \"extra-children\")]"));
+        assert!(text.contains("Visit :: visit_raw_xml_node (v , & it0 . node)"));
+    }
+
+    #[test]
+    fn test_dependency_supertraits() {
+        let out = generated();
+        assert!(out.contains("pub trait Visit < 'ast > : ooxml_dml :: visit :: Visit < 'ast >"));
+        assert!(out.contains("pub trait VisitMut : ooxml_dml :: visit :: VisitMut"));
+        // Raw XML goes through the dependency's methods
+        assert!(!out.contains("fn visit_raw_xml_node ("));
+        assert!(
+            out.contains("ooxml_dml :: visit :: Visit :: visit_raw_xml_node (v , & it0 . node)")
+        );
+        let plain = generate_visitors(DML, "", &[]).unwrap();
+        assert!(plain.contains("pub trait Visit < 'ast > {"));
+        assert!(plain.contains("fn visit_raw_xml_node ("));
+    }
+
+    /// The `QueryTree` impl generated for `name`.
+    fn tree(out: &str, name: &str) -> String {
+        let tree = out
+            .split(&format!("impl ooxml_xml :: QueryTree for {name} {{"))
+            .nth(1)
+            .unwrap_or_else(|| panic!("no QueryTree impl for {name}"));
+        tree[..tree.find("impl ooxml_xml").unwrap_or(tree.len())].to_string()
+    }
+
+    #[test]
+    fn test_query_tree_structs() {
+        let out = generated();
+        let run = tree(&out, "Run");
+        assert!(run.contains("ooxml_xml :: visit_attributes (self , v)"));
+        // Children in the order they are written, under their element names
+        let order: Vec<usize> = [
+            "& self . alias { it0 . visit_element (\"w:alias\" , v)",
+            "for it0 in & self . run_content { it0 . visit_element (\"\" , v)",
+            "& self . body { it0 . visit_element (\"w:txbx\" , v)",
+            "# [cfg (feature = \"styling\")] { if let Some (it0) = & self . style { it0 . visit_element (\"w:style\" , v)",
+        ]
+        .iter()
+        .map(|s| run.find(s).unwrap_or_else(|| panic!("missing {s}")))
+        .collect();
+        assert!(order.is_sorted());
+        // Fields the serializer does not write as children are left out
+        assert!(!run.contains("self . jc"));
+        assert!(!run.contains("self . angle"));
+
+        let text = tree(&out, "Text");
+        assert!(text.contains("if let Some (it0) = & self . text { v . text (it0) ; }"));
+        assert!(text.contains("for it0 in & self . extra_children { v . raw (& it0 . node) ; }"));
+        // No ToXml impl, so no attributes to report
+        assert!(!tree(&out, "Empty").contains("visit_attributes"));
+    }
+
+    #[test]
+    fn test_query_tree_enums() {
+        let out = generated();
+        let content = tree(&out, "RunContent");
+        assert!(content.contains("Self :: T (it0) => { it0 . visit_element (\"w:t\" , v) ; }"));
+        assert!(content.contains("Self :: Cr (it0) => { it0 . visit_element (\"w:cr\" , v) ; }"));
+        assert!(content.contains("Self :: Tab => { v . element (\"w:tab\" , self) ; }"));
+        assert!(content.contains("Self :: T (it0) => { it0 . visit_content (v) ; }"));
+        // Simple types are not trees
+        assert!(!out.contains("QueryTree for STJc"));
+    }
+}
@@ -32,6 +32,8 @@ pub enum ErrorCategory {
     MissingRequiredPart,
     /// Invalid content type declaration.
     InvalidContentType,
+    /// Package or part exceeds a configured resource limit.
+    LimitExceeded,
 
     // XML-level errors
     /// Malformed XML syntax.
@@ -78,6 +80,7 @@ impl ErrorCategory {
             ErrorCategory::ZipUnsupportedCompression => "zip_unsupported_compression",
             ErrorCategory::MissingRequiredPart => "missing_required_part",
             ErrorCategory::InvalidContentType => "invalid_content_type",
+            ErrorCategory::LimitExceeded => "limit_exceeded",
             ErrorCategory::XmlMalformed => "xml_malformed",
             ErrorCategory::XmlEncodingError => "xml_encoding",
             ErrorCategory::XmlNamespaceError => "xml_namespace",
@@ -100,6 +103,7 @@ impl ErrorCategory {
             ErrorCategory::ZipUnsupportedCompression => "ZIP uses unsupported compression",
             ErrorCategory::MissingRequiredPart => "Required document part is missing",
             ErrorCategory::InvalidContentType => "Invalid content type declaration",
+            ErrorCategory::LimitExceeded => "Package exceeds a resource limit",
             ErrorCategory::XmlMalformed => "XML syntax is malformed",
             ErrorCategory::XmlEncodingError => "XML encoding error (invalid UTF-8, etc)",
             ErrorCategory::XmlNamespaceError => "XML namespace error",
@@ -186,6 +190,27 @@ pub fn analyze_error(error: &ooxml_wml::Error) -> AnalyzedError {
             }),
             raw_error,
         },
+        ooxml_wml::Error::Located(parse_err) => {
+            let mut analyzed = match parse_err.kind() {
+                ooxml_xml::ParseError::Xml(xml_err) => analyze_xml_error(xml_err, &raw_error),
+                ooxml_xml::ParseError::UnexpectedElement(msg)
+                | ooxml_xml::ParseError::InvalidValue(msg) => {
+                    analyze_invalid_error(msg, &raw_error)
+                }
+                ooxml_xml::ParseError::MissingAttribute(name) => {
+                    analyze_invalid_error(&format!("missing attribute {}", name), &raw_error)
+                }
+                kind => analyze_invalid_error(&kind.to_string(), &raw_error),
+            };
+            analyzed.location = parse_err.location().map(|location| ErrorLocation {
+                part_path: location.part.clone(),
+                element_path: (!location.path.is_empty())
+                    .then(|| format!("/{}", location.path.join("/"))),
+                line: location.position.map(|p| p.line as u32),
+                column: location.position.map(|p| p.column as u32),
+            });
+            analyzed
+        }
         ooxml_wml::Error::RawXml(xml_err) => AnalyzedError {
             category: ErrorCategory::XmlMalformed,
             subcategory: Some("raw_xml".to_string()),
@@ -237,6 +262,20 @@ fn analyze_package_error(error: &ooxml_opc::Error, raw_error: &str) -> AnalyzedE
             location: None,
             raw_error: raw_error.to_string(),
         },
+        ooxml_opc::Error::LimitExceeded(msg) => AnalyzedError {
+            category: ErrorCategory::LimitExceeded,
+            subcategory: None,
+            message: msg.clone(),
+            location: None,
+            raw_error: raw_error.to_string(),
+        },
+        ooxml_opc::Error::Encrypted | ooxml_opc::Error::InvalidPassword => AnalyzedError {
+            category: ErrorCategory::UnsupportedFeature,
+            subcategory: Some("encryption".to_string()),
+            message: error.to_string(),
+            location: None,
+            raw_error: raw_error.to_string(),
+        },
         ooxml_opc::Error::Io(io_err) => AnalyzedError {
             category: ErrorCategory::IoError,
             subcategory: Some(io_err.kind().to_string()),
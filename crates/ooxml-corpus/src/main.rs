@@ -635,7 +635,7 @@ fn print_summary(analysis: &CorpusAnalysis) {
 
         // Sort by count (descending)
         let mut error_vec: Vec<_> = analysis.errors.iter().collect();
-        error_vec.sort_by(|a, b| b.1.len().cmp(&a.1.len()));
+        error_vec.sort_by_key(|(_, v)| std::cmp::Reverse(v.len()));
 
         for (category, errors) in error_vec {
             println!();
@@ -818,6 +818,7 @@ fn format_category(category: &str) -> &str {
         "zip_unsupported_compression" => "Unsupported ZIP Compression",
         "missing_required_part" => "Missing Required Part",
         "invalid_content_type" => "Invalid Content Type",
+        "limit_exceeded" => "Limit Exceeded",
         "xml_malformed" => "Malformed XML",
         "xml_encoding" => "XML Encoding Error",
         "xml_namespace" => "XML Namespace Error",
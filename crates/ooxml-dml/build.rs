@@ -1,6 +1,6 @@
 use ooxml_codegen::{
     CodegenConfig, FeatureMappings, NameMappings, Schema, analyze_schema, generate,
-    generate_parsers, generate_serializers, parse_rnc,
+    generate_parsers, generate_serializers, generate_visitors, parse_rnc,
 };
 use std::fs;
 use std::path::Path;
@@ -32,6 +32,25 @@ fn main() {
     println!("cargo::rerun-if-changed={}", names_path);
     println!("cargo::rerun-if-changed={}", features_path);
     println!("cargo::rerun-if-changed=build.rs");
+    println!("cargo::rerun-if-env-changed=OOXML_GENERATE_VISIT");
+
+    // Derive Visit/VisitMut traversal and query trees from the committed
+    // types and serializers
+    // Enable with OOXML_GENERATE_VISIT=1
+    if std::env::var("OOXML_GENERATE_VISIT").is_ok() {
+        let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let types = fs::read_to_string(src.join("generated.rs")).expect("failed to read types");
+        let serializers = fs::read_to_string(src.join("generated_serializers.rs"))
+            .expect("failed to read serializers");
+        let visit_dest = src.join("generated_visit.rs");
+        let visit_code =
+            generate_visitors(&types, &serializers, &[]).expect("failed to generate visitors");
+        fs::write(&visit_dest, visit_code).expect("failed to write visitors");
+        eprintln!(
+            "Generated {} bytes to src/generated_visit.rs",
+            visit_dest.metadata().map(|m| m.len()).unwrap_or(0)
+        );
+    }
 
     // The generated file is committed at src/generated.rs
     // Only regenerate if OOXML_REGENERATE is set and specs exist
@@ -173,6 +192,27 @@ fn main() {
         feature_mappings,
         // DML uses a: namespace prefix when embedded in other formats
         xml_serialize_prefix: Some("a".to_string()),
+        // All DrawingML schemas merged above; others are unknown children
+        xml_namespaces: vec![
+            "http://schemas.openxmlformats.org/drawingml/2006/main".to_string(),
+            "http://purl.oclc.org/ooxml/drawingml/main".to_string(),
+            "http://schemas.openxmlformats.org/drawingml/2006/chart".to_string(),
+            "http://purl.oclc.org/ooxml/drawingml/chart".to_string(),
+            "http://schemas.openxmlformats.org/drawingml/2006/chartDrawing".to_string(),
+            "http://purl.oclc.org/ooxml/drawingml/chartDrawing".to_string(),
+            "http://schemas.openxmlformats.org/drawingml/2006/diagram".to_string(),
+            "http://purl.oclc.org/ooxml/drawingml/diagram".to_string(),
+            "http://schemas.openxmlformats.org/drawingml/2006/picture".to_string(),
+            "http://purl.oclc.org/ooxml/drawingml/picture".to_string(),
+            "http://schemas.openxmlformats.org/drawingml/2006/lockedCanvas".to_string(),
+            "http://purl.oclc.org/ooxml/drawingml/lockedCanvas".to_string(),
+            "http://schemas.openxmlformats.org/drawingml/2006/compatibility".to_string(),
+            "http://purl.oclc.org/ooxml/drawingml/compatibility".to_string(),
+            "http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing".to_string(),
+            "http://purl.oclc.org/ooxml/drawingml/wordprocessingDrawing".to_string(),
+            "http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing".to_string(),
+            "http://purl.oclc.org/ooxml/drawingml/spreadsheetDrawing".to_string(),
+        ],
         warn_unmapped: true,
         ..Default::default()
     };
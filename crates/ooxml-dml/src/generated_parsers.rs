@@ -8,16 +8,39 @@
 #![allow(clippy::manual_is_multiple_of)]
 
 use super::generated::*;
+use ooxml_xml::local_name_in;
 pub use ooxml_xml::{FromXml, ParseError};
 #[cfg(feature = "extra-children")]
 use ooxml_xml::{PositionedNode, RawXmlElement, RawXmlNode};
-use quick_xml::Reader;
+use quick_xml::NsReader;
 use quick_xml::events::{BytesStart, Event};
 use std::io::BufRead;
 
+/// Namespace URIs of the elements these parsers read.
+const NAMESPACES: &[&str] = &[
+    "http://schemas.openxmlformats.org/drawingml/2006/main",
+    "http://purl.oclc.org/ooxml/drawingml/main",
+    "http://schemas.openxmlformats.org/drawingml/2006/chart",
+    "http://purl.oclc.org/ooxml/drawingml/chart",
+    "http://schemas.openxmlformats.org/drawingml/2006/chartDrawing",
+    "http://purl.oclc.org/ooxml/drawingml/chartDrawing",
+    "http://schemas.openxmlformats.org/drawingml/2006/diagram",
+    "http://purl.oclc.org/ooxml/drawingml/diagram",
+    "http://schemas.openxmlformats.org/drawingml/2006/picture",
+    "http://purl.oclc.org/ooxml/drawingml/picture",
+    "http://schemas.openxmlformats.org/drawingml/2006/lockedCanvas",
+    "http://purl.oclc.org/ooxml/drawingml/lockedCanvas",
+    "http://schemas.openxmlformats.org/drawingml/2006/compatibility",
+    "http://purl.oclc.org/ooxml/drawingml/compatibility",
+    "http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing",
+    "http://purl.oclc.org/ooxml/drawingml/wordprocessingDrawing",
+    "http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing",
+    "http://purl.oclc.org/ooxml/drawingml/spreadsheetDrawing",
+];
+
 #[allow(dead_code)]
 /// Skip an element and all its children.
-fn skip_element<R: BufRead>(reader: &mut Reader<R>) -> Result<(), ParseError> {
+fn skip_element<R: BufRead>(reader: &mut NsReader<R>) -> Result<(), ParseError> {
     let mut depth = 1u32;
     let mut buf = Vec::new();
     loop {
@@ -39,7 +62,7 @@ fn skip_element<R: BufRead>(reader: &mut Reader<R>) -> Result<(), ParseError> {
 
 #[allow(dead_code)]
 /// Read the text content of an element until its end tag.
-fn read_text_content<R: BufRead>(reader: &mut Reader<R>) -> Result<String, ParseError> {
+fn read_text_content<R: BufRead>(reader: &mut NsReader<R>) -> Result<String, ParseError> {
     let mut text = String::new();
     let mut buf = Vec::new();
     loop {
@@ -85,7 +108,7 @@ fn decode_base64(s: &str) -> Option<Vec<u8>> {
 
 impl FromXml for CTAudioFile {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -129,7 +152,7 @@ impl FromXml for CTAudioFile {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
                                     reader, &e, false,
@@ -157,7 +180,7 @@ impl FromXml for CTAudioFile {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
                                     reader, &e, true,
@@ -205,7 +228,7 @@ impl FromXml for CTAudioFile {
 
 impl FromXml for CTVideoFile {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -249,7 +272,7 @@ impl FromXml for CTVideoFile {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
                                     reader, &e, false,
@@ -277,7 +300,7 @@ impl FromXml for CTVideoFile {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
                                     reader, &e, true,
@@ -325,7 +348,7 @@ impl FromXml for CTVideoFile {
 
 impl FromXml for CTQuickTimeFile {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -361,7 +384,7 @@ impl FromXml for CTQuickTimeFile {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
                                     reader, &e, false,
@@ -389,7 +412,7 @@ impl FromXml for CTQuickTimeFile {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
                                     reader, &e, true,
@@ -434,7 +457,7 @@ impl FromXml for CTQuickTimeFile {
 
 impl FromXml for CTAudioCDTime {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -486,7 +509,7 @@ impl FromXml for CTAudioCDTime {
 
 impl FromXml for CTAudioCD {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -504,7 +527,7 @@ impl FromXml for CTAudioCD {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"st" => {
                                 f_st = Some(Box::new(CTAudioCDTime::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -546,7 +569,7 @@ impl FromXml for CTAudioCD {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"st" => {
                                 f_st = Some(Box::new(CTAudioCDTime::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -604,7 +627,7 @@ impl FromXml for CTAudioCD {
 
 impl FromXml for EGMedia {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -639,7 +662,7 @@ impl FromXml for EGMedia {
 
 impl FromXml for ColorScheme {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -702,7 +725,7 @@ impl FromXml for ColorScheme {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-colors")]
                             b"dk1" => {
                                 f_dk1 = Some(Box::new(CTColor::from_xml(reader, &e, false)?));
@@ -827,7 +850,7 @@ impl FromXml for ColorScheme {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-colors")]
                             b"dk1" => {
                                 f_dk1 = Some(Box::new(CTColor::from_xml(reader, &e, true)?));
@@ -1002,7 +1025,7 @@ impl FromXml for ColorScheme {
 
 impl FromXml for CTCustomColor {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1038,7 +1061,7 @@ impl FromXml for CTCustomColor {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice =
@@ -1066,7 +1089,7 @@ impl FromXml for CTCustomColor {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice =
@@ -1111,7 +1134,7 @@ impl FromXml for CTCustomColor {
 
 impl FromXml for CTSupplementalFont {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1164,7 +1187,7 @@ impl FromXml for CTSupplementalFont {
 
 impl FromXml for CTCustomColorList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1180,7 +1203,7 @@ impl FromXml for CTCustomColorList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"custClr" => {
                                 f_cust_clr.push(CTCustomColor::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -1206,7 +1229,7 @@ impl FromXml for CTCustomColorList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"custClr" => {
                                 f_cust_clr.push(CTCustomColor::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -1246,7 +1269,7 @@ impl FromXml for CTCustomColorList {
 
 impl FromXml for CTFontCollection {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1266,7 +1289,7 @@ impl FromXml for CTFontCollection {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"latin" => {
                                 f_latin = Some(Box::new(TextFont::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -1322,7 +1345,7 @@ impl FromXml for CTFontCollection {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"latin" => {
                                 f_latin = Some(Box::new(TextFont::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -1396,7 +1419,7 @@ impl FromXml for CTFontCollection {
 
 impl FromXml for CTEffectStyleItem {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1414,7 +1437,7 @@ impl FromXml for CTEffectStyleItem {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"effectLst" | b"effectDag" => {
                                 f_effect_properties = Some(Box::new(EGEffectProperties::from_xml(
                                     reader, &e, false,
@@ -1456,7 +1479,7 @@ impl FromXml for CTEffectStyleItem {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"effectLst" | b"effectDag" => {
                                 f_effect_properties =
                                     Some(Box::new(EGEffectProperties::from_xml(reader, &e, true)?));
@@ -1513,7 +1536,7 @@ impl FromXml for CTEffectStyleItem {
 
 impl FromXml for FontScheme {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1556,7 +1579,7 @@ impl FromXml for FontScheme {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-themes")]
                             b"majorFont" => {
                                 f_major_font =
@@ -1603,7 +1626,7 @@ impl FromXml for FontScheme {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-themes")]
                             b"majorFont" => {
                                 f_major_font =
@@ -1675,7 +1698,7 @@ impl FromXml for FontScheme {
 
 impl FromXml for CTFillStyleList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1691,7 +1714,7 @@ impl FromXml for CTFillStyleList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"noFill" | b"solidFill" | b"gradFill" | b"blipFill" | b"pattFill"
                             | b"grpFill" => {
                                 f_fill_properties
@@ -1719,7 +1742,7 @@ impl FromXml for CTFillStyleList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"noFill" | b"solidFill" | b"gradFill" | b"blipFill" | b"pattFill"
                             | b"grpFill" => {
                                 f_fill_properties
@@ -1761,7 +1784,7 @@ impl FromXml for CTFillStyleList {
 
 impl FromXml for CTLineStyleList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1777,7 +1800,7 @@ impl FromXml for CTLineStyleList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ln" => {
                                 f_line.push(LineProperties::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -1803,7 +1826,7 @@ impl FromXml for CTLineStyleList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ln" => {
                                 f_line.push(LineProperties::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -1843,7 +1866,7 @@ impl FromXml for CTLineStyleList {
 
 impl FromXml for CTEffectStyleList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1859,7 +1882,7 @@ impl FromXml for CTEffectStyleList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"effectStyle" => {
                                 f_effect_style
                                     .push(CTEffectStyleItem::from_xml(reader, &e, false)?);
@@ -1886,7 +1909,7 @@ impl FromXml for CTEffectStyleList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"effectStyle" => {
                                 f_effect_style.push(CTEffectStyleItem::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -1926,7 +1949,7 @@ impl FromXml for CTEffectStyleList {
 
 impl FromXml for CTBackgroundFillStyleList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1942,7 +1965,7 @@ impl FromXml for CTBackgroundFillStyleList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"noFill" | b"solidFill" | b"gradFill" | b"blipFill" | b"pattFill"
                             | b"grpFill" => {
                                 f_fill_properties
@@ -1970,7 +1993,7 @@ impl FromXml for CTBackgroundFillStyleList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"noFill" | b"solidFill" | b"gradFill" | b"blipFill" | b"pattFill"
                             | b"grpFill" => {
                                 f_fill_properties
@@ -2012,7 +2035,7 @@ impl FromXml for CTBackgroundFillStyleList {
 
 impl FromXml for CTStyleMatrix {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2057,7 +2080,7 @@ impl FromXml for CTStyleMatrix {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-themes")]
                             b"fillStyleLst" => {
                                 f_fill_style_lst =
@@ -2113,7 +2136,7 @@ impl FromXml for CTStyleMatrix {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-themes")]
                             b"fillStyleLst" => {
                                 f_fill_style_lst =
@@ -2198,7 +2221,7 @@ impl FromXml for CTStyleMatrix {
 
 impl FromXml for CTBaseStyles {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2221,7 +2244,7 @@ impl FromXml for CTBaseStyles {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-colors")]
                             b"clrScheme" => {
                                 f_clr_scheme =
@@ -2277,7 +2300,7 @@ impl FromXml for CTBaseStyles {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-colors")]
                             b"clrScheme" => {
                                 f_clr_scheme =
@@ -2357,7 +2380,7 @@ impl FromXml for CTBaseStyles {
 
 impl FromXml for CTOfficeArtExtension {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2404,7 +2427,7 @@ impl FromXml for CTOfficeArtExtension {
 
 impl FromXml for CTAngle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2451,7 +2474,7 @@ impl FromXml for CTAngle {
 
 impl FromXml for CTPositiveFixedAngle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2498,7 +2521,7 @@ impl FromXml for CTPositiveFixedAngle {
 
 impl FromXml for CTPercentage {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2545,7 +2568,7 @@ impl FromXml for CTPercentage {
 
 impl FromXml for PositivePercentageElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2592,7 +2615,7 @@ impl FromXml for PositivePercentageElement {
 
 impl FromXml for FixedPercentageElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2639,7 +2662,7 @@ impl FromXml for FixedPercentageElement {
 
 impl FromXml for PositiveFixedPercentageElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2686,7 +2709,7 @@ impl FromXml for PositiveFixedPercentageElement {
 
 impl FromXml for CTRatio {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2738,7 +2761,7 @@ impl FromXml for CTRatio {
 
 impl FromXml for Point2D {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2790,7 +2813,7 @@ impl FromXml for Point2D {
 
 impl FromXml for PositiveSize2D {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2842,7 +2865,7 @@ impl FromXml for PositiveSize2D {
 
 impl FromXml for CTComplementTransform {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2870,7 +2893,7 @@ impl FromXml for CTComplementTransform {
 
 impl FromXml for CTInverseTransform {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2898,7 +2921,7 @@ impl FromXml for CTInverseTransform {
 
 impl FromXml for CTGrayscaleTransform {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2926,7 +2949,7 @@ impl FromXml for CTGrayscaleTransform {
 
 impl FromXml for CTGammaTransform {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2954,7 +2977,7 @@ impl FromXml for CTGammaTransform {
 
 impl FromXml for CTInverseGammaTransform {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2982,7 +3005,7 @@ impl FromXml for CTInverseGammaTransform {
 
 impl FromXml for EGColorTransform {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3109,7 +3132,7 @@ impl FromXml for EGColorTransform {
 
 impl FromXml for CTScRgbColor {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3153,7 +3176,7 @@ impl FromXml for CTScRgbColor {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tint" | b"shade" | b"comp" | b"inv" | b"gray" | b"alpha"
                             | b"alphaOff" | b"alphaMod" | b"hue" | b"hueOff" | b"hueMod"
                             | b"sat" | b"satOff" | b"satMod" | b"lum" | b"lumOff" | b"lumMod"
@@ -3185,7 +3208,7 @@ impl FromXml for CTScRgbColor {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tint" | b"shade" | b"comp" | b"inv" | b"gray" | b"alpha"
                             | b"alphaOff" | b"alphaMod" | b"hue" | b"hueOff" | b"hueMod"
                             | b"sat" | b"satOff" | b"satMod" | b"lum" | b"lumOff" | b"lumMod"
@@ -3237,7 +3260,7 @@ impl FromXml for CTScRgbColor {
 
 impl FromXml for SrgbColor {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3275,7 +3298,7 @@ impl FromXml for SrgbColor {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tint" | b"shade" | b"comp" | b"inv" | b"gray" | b"alpha"
                             | b"alphaOff" | b"alphaMod" | b"hue" | b"hueOff" | b"hueMod"
                             | b"sat" | b"satOff" | b"satMod" | b"lum" | b"lumOff" | b"lumMod"
@@ -3307,7 +3330,7 @@ impl FromXml for SrgbColor {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tint" | b"shade" | b"comp" | b"inv" | b"gray" | b"alpha"
                             | b"alphaOff" | b"alphaMod" | b"hue" | b"hueOff" | b"hueMod"
                             | b"sat" | b"satOff" | b"satMod" | b"lum" | b"lumOff" | b"lumMod"
@@ -3357,7 +3380,7 @@ impl FromXml for SrgbColor {
 
 impl FromXml for HslColor {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3407,7 +3430,7 @@ impl FromXml for HslColor {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tint" | b"shade" | b"comp" | b"inv" | b"gray" | b"alpha"
                             | b"alphaOff" | b"alphaMod" | b"hue" | b"hueOff" | b"hueMod"
                             | b"sat" | b"satOff" | b"satMod" | b"lum" | b"lumOff" | b"lumMod"
@@ -3439,7 +3462,7 @@ impl FromXml for HslColor {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tint" | b"shade" | b"comp" | b"inv" | b"gray" | b"alpha"
                             | b"alphaOff" | b"alphaMod" | b"hue" | b"hueOff" | b"hueMod"
                             | b"sat" | b"satOff" | b"satMod" | b"lum" | b"lumOff" | b"lumMod"
@@ -3493,7 +3516,7 @@ impl FromXml for HslColor {
 
 impl FromXml for SystemColor {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3533,7 +3556,7 @@ impl FromXml for SystemColor {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tint" | b"shade" | b"comp" | b"inv" | b"gray" | b"alpha"
                             | b"alphaOff" | b"alphaMod" | b"hue" | b"hueOff" | b"hueMod"
                             | b"sat" | b"satOff" | b"satMod" | b"lum" | b"lumOff" | b"lumMod"
@@ -3565,7 +3588,7 @@ impl FromXml for SystemColor {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tint" | b"shade" | b"comp" | b"inv" | b"gray" | b"alpha"
                             | b"alphaOff" | b"alphaMod" | b"hue" | b"hueOff" | b"hueMod"
                             | b"sat" | b"satOff" | b"satMod" | b"lum" | b"lumOff" | b"lumMod"
@@ -3615,7 +3638,7 @@ impl FromXml for SystemColor {
 
 impl FromXml for SchemeColor {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3653,7 +3676,7 @@ impl FromXml for SchemeColor {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tint" | b"shade" | b"comp" | b"inv" | b"gray" | b"alpha"
                             | b"alphaOff" | b"alphaMod" | b"hue" | b"hueOff" | b"hueMod"
                             | b"sat" | b"satOff" | b"satMod" | b"lum" | b"lumOff" | b"lumMod"
@@ -3685,7 +3708,7 @@ impl FromXml for SchemeColor {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tint" | b"shade" | b"comp" | b"inv" | b"gray" | b"alpha"
                             | b"alphaOff" | b"alphaMod" | b"hue" | b"hueOff" | b"hueMod"
                             | b"sat" | b"satOff" | b"satMod" | b"lum" | b"lumOff" | b"lumMod"
@@ -3735,7 +3758,7 @@ impl FromXml for SchemeColor {
 
 impl FromXml for PresetColor {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3771,7 +3794,7 @@ impl FromXml for PresetColor {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tint" | b"shade" | b"comp" | b"inv" | b"gray" | b"alpha"
                             | b"alphaOff" | b"alphaMod" | b"hue" | b"hueOff" | b"hueMod"
                             | b"sat" | b"satOff" | b"satMod" | b"lum" | b"lumOff" | b"lumMod"
@@ -3803,7 +3826,7 @@ impl FromXml for PresetColor {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tint" | b"shade" | b"comp" | b"inv" | b"gray" | b"alpha"
                             | b"alphaOff" | b"alphaMod" | b"hue" | b"hueOff" | b"hueMod"
                             | b"sat" | b"satOff" | b"satMod" | b"lum" | b"lumOff" | b"lumMod"
@@ -3852,7 +3875,7 @@ impl FromXml for PresetColor {
 
 impl FromXml for EGOfficeArtExtensionList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3868,7 +3891,7 @@ impl FromXml for EGOfficeArtExtensionList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ext" => {
                                 f_extents.push(CTOfficeArtExtension::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -3894,7 +3917,7 @@ impl FromXml for EGOfficeArtExtensionList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ext" => {
                                 f_extents.push(CTOfficeArtExtension::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -3934,7 +3957,7 @@ impl FromXml for EGOfficeArtExtensionList {
 
 impl FromXml for CTOfficeArtExtensionList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3950,7 +3973,7 @@ impl FromXml for CTOfficeArtExtensionList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ext" => {
                                 f_extents.push(CTOfficeArtExtension::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -3976,7 +3999,7 @@ impl FromXml for CTOfficeArtExtensionList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ext" => {
                                 f_extents.push(CTOfficeArtExtension::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -4016,7 +4039,7 @@ impl FromXml for CTOfficeArtExtensionList {
 
 impl FromXml for CTScale2D {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4033,7 +4056,7 @@ impl FromXml for CTScale2D {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sx" => {
                                 f_sx = Some(Box::new(CTRatio::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -4066,7 +4089,7 @@ impl FromXml for CTScale2D {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sx" => {
                                 f_sx = Some(Box::new(CTRatio::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -4114,7 +4137,7 @@ impl FromXml for CTScale2D {
 
 impl FromXml for Transform2D {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4159,7 +4182,7 @@ impl FromXml for Transform2D {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"off" => {
                                 f_offset = Some(Box::new(Point2D::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -4193,7 +4216,7 @@ impl FromXml for Transform2D {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"off" => {
                                 f_offset = Some(Box::new(Point2D::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -4247,7 +4270,7 @@ impl FromXml for Transform2D {
 
 impl FromXml for CTGroupTransform2D {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4294,7 +4317,7 @@ impl FromXml for CTGroupTransform2D {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"off" => {
                                 f_offset = Some(Box::new(Point2D::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -4344,7 +4367,7 @@ impl FromXml for CTGroupTransform2D {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"off" => {
                                 f_offset = Some(Box::new(Point2D::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -4416,7 +4439,7 @@ impl FromXml for CTGroupTransform2D {
 
 impl FromXml for CTPoint3D {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4473,7 +4496,7 @@ impl FromXml for CTPoint3D {
 
 impl FromXml for CTVector3D {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4530,7 +4553,7 @@ impl FromXml for CTVector3D {
 
 impl FromXml for CTSphereCoords {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4587,7 +4610,7 @@ impl FromXml for CTSphereCoords {
 
 impl FromXml for CTRelativeRect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4649,7 +4672,7 @@ impl FromXml for CTRelativeRect {
 
 impl FromXml for EGColorChoice {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4688,7 +4711,7 @@ impl FromXml for EGColorChoice {
 
 impl FromXml for CTColor {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4704,7 +4727,7 @@ impl FromXml for CTColor {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice =
@@ -4732,7 +4755,7 @@ impl FromXml for CTColor {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice =
@@ -4774,7 +4797,7 @@ impl FromXml for CTColor {
 
 impl FromXml for CTColorMRU {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4790,7 +4813,7 @@ impl FromXml for CTColorMRU {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice.push(EGColorChoice::from_xml(reader, &e, false)?);
@@ -4817,7 +4840,7 @@ impl FromXml for CTColorMRU {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice.push(EGColorChoice::from_xml(reader, &e, true)?);
@@ -4858,7 +4881,7 @@ impl FromXml for CTColorMRU {
 
 impl FromXml for AAGBlob {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4910,7 +4933,7 @@ impl FromXml for AAGBlob {
 
 impl FromXml for CTEmbeddedWAVAudioFile {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4962,7 +4985,7 @@ impl FromXml for CTEmbeddedWAVAudioFile {
 
 impl FromXml for CTHyperlink {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5045,7 +5068,7 @@ impl FromXml for CTHyperlink {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-text")]
                             b"snd" => {
                                 f_snd = Some(Box::new(CTEmbeddedWAVAudioFile::from_xml(
@@ -5084,7 +5107,7 @@ impl FromXml for CTHyperlink {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-text")]
                             b"snd" => {
                                 f_snd = Some(Box::new(CTEmbeddedWAVAudioFile::from_xml(
@@ -5158,7 +5181,7 @@ impl FromXml for CTHyperlink {
 
 impl FromXml for AAGLocking {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5250,7 +5273,7 @@ impl FromXml for AAGLocking {
 
 impl FromXml for CTConnectorLocking {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5322,7 +5345,7 @@ impl FromXml for CTConnectorLocking {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
                                     reader, &e, false,
@@ -5350,7 +5373,7 @@ impl FromXml for CTConnectorLocking {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
                                     reader, &e, true,
@@ -5404,7 +5427,7 @@ impl FromXml for CTConnectorLocking {
 
 impl FromXml for CTShapeLocking {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5480,7 +5503,7 @@ impl FromXml for CTShapeLocking {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
                                     reader, &e, false,
@@ -5508,7 +5531,7 @@ impl FromXml for CTShapeLocking {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
                                     reader, &e, true,
@@ -5563,7 +5586,7 @@ impl FromXml for CTShapeLocking {
 
 impl FromXml for CTPictureLocking {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5639,7 +5662,7 @@ impl FromXml for CTPictureLocking {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
                                     reader, &e, false,
@@ -5667,7 +5690,7 @@ impl FromXml for CTPictureLocking {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
                                     reader, &e, true,
@@ -5722,7 +5745,7 @@ impl FromXml for CTPictureLocking {
 
 impl FromXml for CTGroupLocking {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5782,7 +5805,7 @@ impl FromXml for CTGroupLocking {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
                                     reader, &e, false,
@@ -5810,7 +5833,7 @@ impl FromXml for CTGroupLocking {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
                                     reader, &e, true,
@@ -5861,7 +5884,7 @@ impl FromXml for CTGroupLocking {
 
 impl FromXml for CTGraphicalObjectFrameLocking {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5917,7 +5940,7 @@ impl FromXml for CTGraphicalObjectFrameLocking {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
                                     reader, &e, false,
@@ -5945,7 +5968,7 @@ impl FromXml for CTGraphicalObjectFrameLocking {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
                                     reader, &e, true,
@@ -5995,7 +6018,7 @@ impl FromXml for CTGraphicalObjectFrameLocking {
 
 impl FromXml for CTContentPartLocking {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6067,7 +6090,7 @@ impl FromXml for CTContentPartLocking {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
                                     reader, &e, false,
@@ -6095,7 +6118,7 @@ impl FromXml for CTContentPartLocking {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
                                     reader, &e, true,
@@ -6149,7 +6172,7 @@ impl FromXml for CTContentPartLocking {
 
 impl FromXml for CTNonVisualDrawingProps {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6206,7 +6229,7 @@ impl FromXml for CTNonVisualDrawingProps {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-text")]
                             b"hlinkClick" => {
                                 f_hlink_click =
@@ -6253,7 +6276,7 @@ impl FromXml for CTNonVisualDrawingProps {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-text")]
                             b"hlinkClick" => {
                                 f_hlink_click =
@@ -6326,7 +6349,7 @@ impl FromXml for CTNonVisualDrawingProps {
 
 impl FromXml for CTNonVisualDrawingShapeProps {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6363,7 +6386,7 @@ impl FromXml for CTNonVisualDrawingShapeProps {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"spLocks" => {
                                 f_sp_locks =
                                     Some(Box::new(CTShapeLocking::from_xml(reader, &e, false)?));
@@ -6399,7 +6422,7 @@ impl FromXml for CTNonVisualDrawingShapeProps {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"spLocks" => {
                                 f_sp_locks =
                                     Some(Box::new(CTShapeLocking::from_xml(reader, &e, true)?));
@@ -6453,7 +6476,7 @@ impl FromXml for CTNonVisualDrawingShapeProps {
 
 impl FromXml for CTNonVisualConnectorProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6476,7 +6499,7 @@ impl FromXml for CTNonVisualConnectorProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-shapes")]
                             b"cxnSpLocks" => {
                                 f_cxn_sp_locks = Some(Box::new(CTConnectorLocking::from_xml(
@@ -6533,7 +6556,7 @@ impl FromXml for CTNonVisualConnectorProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-shapes")]
                             b"cxnSpLocks" => {
                                 f_cxn_sp_locks =
@@ -6610,7 +6633,7 @@ impl FromXml for CTNonVisualConnectorProperties {
 
 impl FromXml for CTNonVisualPictureProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6651,7 +6674,7 @@ impl FromXml for CTNonVisualPictureProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-shapes")]
                             b"picLocks" => {
                                 f_pic_locks =
@@ -6689,7 +6712,7 @@ impl FromXml for CTNonVisualPictureProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-shapes")]
                             b"picLocks" => {
                                 f_pic_locks =
@@ -6748,7 +6771,7 @@ impl FromXml for CTNonVisualPictureProperties {
 
 impl FromXml for CTNonVisualGroupDrawingShapeProps {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6767,7 +6790,7 @@ impl FromXml for CTNonVisualGroupDrawingShapeProps {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-shapes")]
                             b"grpSpLocks" => {
                                 f_grp_sp_locks =
@@ -6805,7 +6828,7 @@ impl FromXml for CTNonVisualGroupDrawingShapeProps {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-shapes")]
                             b"grpSpLocks" => {
                                 f_grp_sp_locks =
@@ -6860,7 +6883,7 @@ impl FromXml for CTNonVisualGroupDrawingShapeProps {
 
 impl FromXml for CTNonVisualGraphicFrameProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6879,7 +6902,7 @@ impl FromXml for CTNonVisualGraphicFrameProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-shapes")]
                             b"graphicFrameLocks" => {
                                 f_graphic_frame_locks = Some(Box::new(
@@ -6918,7 +6941,7 @@ impl FromXml for CTNonVisualGraphicFrameProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-shapes")]
                             b"graphicFrameLocks" => {
                                 f_graphic_frame_locks = Some(Box::new(
@@ -6974,7 +6997,7 @@ impl FromXml for CTNonVisualGraphicFrameProperties {
 
 impl FromXml for CTNonVisualContentPartProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7011,7 +7034,7 @@ impl FromXml for CTNonVisualContentPartProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cpLocks" => {
                                 f_cp_locks = Some(Box::new(CTContentPartLocking::from_xml(
                                     reader, &e, false,
@@ -7048,7 +7071,7 @@ impl FromXml for CTNonVisualContentPartProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cpLocks" => {
                                 f_cp_locks = Some(Box::new(CTContentPartLocking::from_xml(
                                     reader, &e, true,
@@ -7103,7 +7126,7 @@ impl FromXml for CTNonVisualContentPartProperties {
 
 impl FromXml for CTGraphicalObjectData {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7150,7 +7173,7 @@ impl FromXml for CTGraphicalObjectData {
 
 impl FromXml for CTAnimationDgmElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7202,7 +7225,7 @@ impl FromXml for CTAnimationDgmElement {
 
 impl FromXml for CTAnimationChartElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7260,7 +7283,7 @@ impl FromXml for CTAnimationChartElement {
 
 impl FromXml for CTAnimationElementChoice {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7277,7 +7300,7 @@ impl FromXml for CTAnimationElementChoice {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"dgm" => {
                                 f_dgm = Some(Box::new(CTAnimationDgmElement::from_xml(
                                     reader, &e, false,
@@ -7314,7 +7337,7 @@ impl FromXml for CTAnimationElementChoice {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"dgm" => {
                                 f_dgm = Some(Box::new(CTAnimationDgmElement::from_xml(
                                     reader, &e, true,
@@ -7366,7 +7389,7 @@ impl FromXml for CTAnimationElementChoice {
 
 impl FromXml for CTAnimationDgmBuildProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7418,7 +7441,7 @@ impl FromXml for CTAnimationDgmBuildProperties {
 
 impl FromXml for CTAnimationChartBuildProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7470,7 +7493,7 @@ impl FromXml for CTAnimationChartBuildProperties {
 
 impl FromXml for CTAnimationGraphicalObjectBuildProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7487,7 +7510,7 @@ impl FromXml for CTAnimationGraphicalObjectBuildProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"bldDgm" => {
                                 f_bld_dgm = Some(Box::new(
                                     CTAnimationDgmBuildProperties::from_xml(reader, &e, false)?,
@@ -7524,7 +7547,7 @@ impl FromXml for CTAnimationGraphicalObjectBuildProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"bldDgm" => {
                                 f_bld_dgm = Some(Box::new(
                                     CTAnimationDgmBuildProperties::from_xml(reader, &e, true)?,
@@ -7576,7 +7599,7 @@ impl FromXml for CTAnimationGraphicalObjectBuildProperties {
 
 impl FromXml for CTBackgroundFormatting {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7593,7 +7616,7 @@ impl FromXml for CTBackgroundFormatting {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"noFill" | b"solidFill" | b"gradFill" | b"blipFill" | b"pattFill"
                             | b"grpFill" => {
                                 f_fill_properties =
@@ -7630,7 +7653,7 @@ impl FromXml for CTBackgroundFormatting {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"noFill" | b"solidFill" | b"gradFill" | b"blipFill" | b"pattFill"
                             | b"grpFill" => {
                                 f_fill_properties =
@@ -7681,7 +7704,7 @@ impl FromXml for CTBackgroundFormatting {
 
 impl FromXml for CTWholeE2oFormatting {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7698,7 +7721,7 @@ impl FromXml for CTWholeE2oFormatting {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ln" => {
                                 f_line =
                                     Some(Box::new(LineProperties::from_xml(reader, &e, false)?));
@@ -7734,7 +7757,7 @@ impl FromXml for CTWholeE2oFormatting {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ln" => {
                                 f_line =
                                     Some(Box::new(LineProperties::from_xml(reader, &e, true)?));
@@ -7784,7 +7807,7 @@ impl FromXml for CTWholeE2oFormatting {
 
 impl FromXml for CTGvmlUseShapeRectangle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7812,7 +7835,7 @@ impl FromXml for CTGvmlUseShapeRectangle {
 
 impl FromXml for CTGvmlTextShape {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7831,7 +7854,7 @@ impl FromXml for CTGvmlTextShape {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"txBody" => {
                                 f_tx_body = Some(Box::new(TextBody::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -7883,7 +7906,7 @@ impl FromXml for CTGvmlTextShape {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"txBody" => {
                                 f_tx_body = Some(Box::new(TextBody::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -7952,7 +7975,7 @@ impl FromXml for CTGvmlTextShape {
 
 impl FromXml for CTGvmlShapeNonVisual {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7970,7 +7993,7 @@ impl FromXml for CTGvmlShapeNonVisual {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cNvPr" => {
                                 f_common_non_visual_properties = Some(Box::new(
                                     CTNonVisualDrawingProps::from_xml(reader, &e, false)?,
@@ -8007,7 +8030,7 @@ impl FromXml for CTGvmlShapeNonVisual {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cNvPr" => {
                                 f_common_non_visual_properties = Some(Box::new(
                                     CTNonVisualDrawingProps::from_xml(reader, &e, true)?,
@@ -8061,7 +8084,7 @@ impl FromXml for CTGvmlShapeNonVisual {
 
 impl FromXml for CTGvmlShape {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8081,7 +8104,7 @@ impl FromXml for CTGvmlShape {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"nvSpPr" => {
                                 f_nv_sp_pr = Some(Box::new(CTGvmlShapeNonVisual::from_xml(
                                     reader, &e, false,
@@ -8141,7 +8164,7 @@ impl FromXml for CTGvmlShape {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"nvSpPr" => {
                                 f_nv_sp_pr = Some(Box::new(CTGvmlShapeNonVisual::from_xml(
                                     reader, &e, true,
@@ -8220,7 +8243,7 @@ impl FromXml for CTGvmlShape {
 
 impl FromXml for CTGvmlConnectorNonVisual {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8237,7 +8260,7 @@ impl FromXml for CTGvmlConnectorNonVisual {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cNvPr" => {
                                 f_common_non_visual_properties = Some(Box::new(
                                     CTNonVisualDrawingProps::from_xml(reader, &e, false)?,
@@ -8274,7 +8297,7 @@ impl FromXml for CTGvmlConnectorNonVisual {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cNvPr" => {
                                 f_common_non_visual_properties = Some(Box::new(
                                     CTNonVisualDrawingProps::from_xml(reader, &e, true)?,
@@ -8328,7 +8351,7 @@ impl FromXml for CTGvmlConnectorNonVisual {
 
 impl FromXml for CTGvmlConnector {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8347,7 +8370,7 @@ impl FromXml for CTGvmlConnector {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"nvCxnSpPr" => {
                                 f_nv_cxn_sp_pr = Some(Box::new(
                                     CTGvmlConnectorNonVisual::from_xml(reader, &e, false)?,
@@ -8399,7 +8422,7 @@ impl FromXml for CTGvmlConnector {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"nvCxnSpPr" => {
                                 f_nv_cxn_sp_pr = Some(Box::new(
                                     CTGvmlConnectorNonVisual::from_xml(reader, &e, true)?,
@@ -8469,7 +8492,7 @@ impl FromXml for CTGvmlConnector {
 
 impl FromXml for CTGvmlPictureNonVisual {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8487,7 +8510,7 @@ impl FromXml for CTGvmlPictureNonVisual {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cNvPr" => {
                                 f_common_non_visual_properties = Some(Box::new(
                                     CTNonVisualDrawingProps::from_xml(reader, &e, false)?,
@@ -8524,7 +8547,7 @@ impl FromXml for CTGvmlPictureNonVisual {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cNvPr" => {
                                 f_common_non_visual_properties = Some(Box::new(
                                     CTNonVisualDrawingProps::from_xml(reader, &e, true)?,
@@ -8578,7 +8601,7 @@ impl FromXml for CTGvmlPictureNonVisual {
 
 impl FromXml for CTGvmlPicture {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8598,7 +8621,7 @@ impl FromXml for CTGvmlPicture {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"nvPicPr" => {
                                 f_nv_pic_pr = Some(Box::new(CTGvmlPictureNonVisual::from_xml(
                                     reader, &e, false,
@@ -8659,7 +8682,7 @@ impl FromXml for CTGvmlPicture {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"nvPicPr" => {
                                 f_nv_pic_pr = Some(Box::new(CTGvmlPictureNonVisual::from_xml(
                                     reader, &e, true,
@@ -8739,7 +8762,7 @@ impl FromXml for CTGvmlPicture {
 
 impl FromXml for CTGvmlGraphicFrameNonVisual {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8756,7 +8779,7 @@ impl FromXml for CTGvmlGraphicFrameNonVisual {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cNvPr" => {
                                 f_common_non_visual_properties = Some(Box::new(
                                     CTNonVisualDrawingProps::from_xml(reader, &e, false)?,
@@ -8793,7 +8816,7 @@ impl FromXml for CTGvmlGraphicFrameNonVisual {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cNvPr" => {
                                 f_common_non_visual_properties = Some(Box::new(
                                     CTNonVisualDrawingProps::from_xml(reader, &e, true)?,
@@ -8847,7 +8870,7 @@ impl FromXml for CTGvmlGraphicFrameNonVisual {
 
 impl FromXml for CTGvmlGraphicalObjectFrame {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8866,7 +8889,7 @@ impl FromXml for CTGvmlGraphicalObjectFrame {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"nvGraphicFramePr" => {
                                 f_nv_graphic_frame_pr = Some(Box::new(
                                     CTGvmlGraphicFrameNonVisual::from_xml(reader, &e, false)?,
@@ -8920,7 +8943,7 @@ impl FromXml for CTGvmlGraphicalObjectFrame {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"nvGraphicFramePr" => {
                                 f_nv_graphic_frame_pr = Some(Box::new(
                                     CTGvmlGraphicFrameNonVisual::from_xml(reader, &e, true)?,
@@ -8994,7 +9017,7 @@ impl FromXml for CTGvmlGraphicalObjectFrame {
 
 impl FromXml for CTGvmlGroupShapeNonVisual {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9011,7 +9034,7 @@ impl FromXml for CTGvmlGroupShapeNonVisual {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cNvPr" => {
                                 f_common_non_visual_properties = Some(Box::new(
                                     CTNonVisualDrawingProps::from_xml(reader, &e, false)?,
@@ -9048,7 +9071,7 @@ impl FromXml for CTGvmlGroupShapeNonVisual {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cNvPr" => {
                                 f_common_non_visual_properties = Some(Box::new(
                                     CTNonVisualDrawingProps::from_xml(reader, &e, true)?,
@@ -9102,7 +9125,7 @@ impl FromXml for CTGvmlGroupShapeNonVisual {
 
 impl FromXml for CTGvmlGroupShape {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9126,7 +9149,7 @@ impl FromXml for CTGvmlGroupShape {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"nvGrpSpPr" => {
                                 f_nv_grp_sp_pr = Some(Box::new(
                                     CTGvmlGroupShapeNonVisual::from_xml(reader, &e, false)?,
@@ -9215,7 +9238,7 @@ impl FromXml for CTGvmlGroupShape {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"nvGrpSpPr" => {
                                 f_nv_grp_sp_pr = Some(Box::new(
                                     CTGvmlGroupShapeNonVisual::from_xml(reader, &e, true)?,
@@ -9328,7 +9351,7 @@ impl FromXml for CTGvmlGroupShape {
 
 impl FromXml for CTCamera {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9379,7 +9402,7 @@ impl FromXml for CTCamera {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-3d")]
                             b"rot" => {
                                 f_rot =
@@ -9407,7 +9430,7 @@ impl FromXml for CTCamera {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-3d")]
                             b"rot" => {
                                 f_rot = Some(Box::new(CTSphereCoords::from_xml(reader, &e, true)?));
@@ -9457,7 +9480,7 @@ impl FromXml for CTCamera {
 
 impl FromXml for CTLightRig {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9502,7 +9525,7 @@ impl FromXml for CTLightRig {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-3d")]
                             b"rot" => {
                                 f_rot =
@@ -9530,7 +9553,7 @@ impl FromXml for CTLightRig {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-3d")]
                             b"rot" => {
                                 f_rot = Some(Box::new(CTSphereCoords::from_xml(reader, &e, true)?));
@@ -9578,7 +9601,7 @@ impl FromXml for CTLightRig {
 
 impl FromXml for CTScene3D {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9601,7 +9624,7 @@ impl FromXml for CTScene3D {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-3d")]
                             b"camera" => {
                                 f_camera = Some(Box::new(CTCamera::from_xml(reader, &e, false)?));
@@ -9656,7 +9679,7 @@ impl FromXml for CTScene3D {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-3d")]
                             b"camera" => {
                                 f_camera = Some(Box::new(CTCamera::from_xml(reader, &e, true)?));
@@ -9733,7 +9756,7 @@ impl FromXml for CTScene3D {
 
 impl FromXml for CTBackdrop {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9752,7 +9775,7 @@ impl FromXml for CTBackdrop {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"anchor" => {
                                 f_anchor = Some(Box::new(CTPoint3D::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -9801,7 +9824,7 @@ impl FromXml for CTBackdrop {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"anchor" => {
                                 f_anchor = Some(Box::new(CTPoint3D::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -9867,7 +9890,7 @@ impl FromXml for CTBackdrop {
 
 impl FromXml for CTBevel {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9933,7 +9956,7 @@ impl FromXml for CTBevel {
 
 impl FromXml for CTShape3D {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9998,7 +10021,7 @@ impl FromXml for CTShape3D {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-3d")]
                             b"bevelT" => {
                                 f_bevel_t = Some(Box::new(CTBevel::from_xml(reader, &e, false)?));
@@ -10061,7 +10084,7 @@ impl FromXml for CTShape3D {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-3d")]
                             b"bevelT" => {
                                 f_bevel_t = Some(Box::new(CTBevel::from_xml(reader, &e, true)?));
@@ -10157,7 +10180,7 @@ impl FromXml for CTShape3D {
 
 impl FromXml for CTFlatText {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10204,7 +10227,7 @@ impl FromXml for CTFlatText {
 
 impl FromXml for EGText3D {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10227,7 +10250,7 @@ impl FromXml for EGText3D {
 
 impl FromXml for CTAlphaBiLevelEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10274,7 +10297,7 @@ impl FromXml for CTAlphaBiLevelEffect {
 
 impl FromXml for CTAlphaCeilingEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10302,7 +10325,7 @@ impl FromXml for CTAlphaCeilingEffect {
 
 impl FromXml for CTAlphaFloorEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10330,7 +10353,7 @@ impl FromXml for CTAlphaFloorEffect {
 
 impl FromXml for CTAlphaInverseEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10346,7 +10369,7 @@ impl FromXml for CTAlphaInverseEffect {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice =
@@ -10374,7 +10397,7 @@ impl FromXml for CTAlphaInverseEffect {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice =
@@ -10416,7 +10439,7 @@ impl FromXml for CTAlphaInverseEffect {
 
 impl FromXml for CTAlphaModulateFixedEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10463,7 +10486,7 @@ impl FromXml for CTAlphaModulateFixedEffect {
 
 impl FromXml for CTAlphaOutsetEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10510,7 +10533,7 @@ impl FromXml for CTAlphaOutsetEffect {
 
 impl FromXml for CTAlphaReplaceEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10557,7 +10580,7 @@ impl FromXml for CTAlphaReplaceEffect {
 
 impl FromXml for CTBiLevelEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10604,7 +10627,7 @@ impl FromXml for CTBiLevelEffect {
 
 impl FromXml for CTBlurEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10662,7 +10685,7 @@ impl FromXml for CTBlurEffect {
 
 impl FromXml for CTColorChangeEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10699,7 +10722,7 @@ impl FromXml for CTColorChangeEffect {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"clrFrom" => {
                                 f_clr_from = Some(Box::new(CTColor::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -10732,7 +10755,7 @@ impl FromXml for CTColorChangeEffect {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"clrFrom" => {
                                 f_clr_from = Some(Box::new(CTColor::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -10784,7 +10807,7 @@ impl FromXml for CTColorChangeEffect {
 
 impl FromXml for CTColorReplaceEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10800,7 +10823,7 @@ impl FromXml for CTColorReplaceEffect {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice =
@@ -10828,7 +10851,7 @@ impl FromXml for CTColorReplaceEffect {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice =
@@ -10870,7 +10893,7 @@ impl FromXml for CTColorReplaceEffect {
 
 impl FromXml for CTDuotoneEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10886,7 +10909,7 @@ impl FromXml for CTDuotoneEffect {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice.push(EGColorChoice::from_xml(reader, &e, false)?);
@@ -10913,7 +10936,7 @@ impl FromXml for CTDuotoneEffect {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice.push(EGColorChoice::from_xml(reader, &e, true)?);
@@ -10954,7 +10977,7 @@ impl FromXml for CTDuotoneEffect {
 
 impl FromXml for CTGlowEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10992,7 +11015,7 @@ impl FromXml for CTGlowEffect {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice =
@@ -11020,7 +11043,7 @@ impl FromXml for CTGlowEffect {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice =
@@ -11066,7 +11089,7 @@ impl FromXml for CTGlowEffect {
 
 impl FromXml for CTGrayscaleEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11094,7 +11117,7 @@ impl FromXml for CTGrayscaleEffect {
 
 impl FromXml for CTHSLEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11151,7 +11174,7 @@ impl FromXml for CTHSLEffect {
 
 impl FromXml for CTInnerShadowEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11201,7 +11224,7 @@ impl FromXml for CTInnerShadowEffect {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice =
@@ -11229,7 +11252,7 @@ impl FromXml for CTInnerShadowEffect {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice =
@@ -11279,7 +11302,7 @@ impl FromXml for CTInnerShadowEffect {
 
 impl FromXml for CTLuminanceEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11331,7 +11354,7 @@ impl FromXml for CTLuminanceEffect {
 
 impl FromXml for CTOuterShadowEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11417,7 +11440,7 @@ impl FromXml for CTOuterShadowEffect {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice =
@@ -11445,7 +11468,7 @@ impl FromXml for CTOuterShadowEffect {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice =
@@ -11507,7 +11530,7 @@ impl FromXml for CTOuterShadowEffect {
 
 impl FromXml for CTPresetShadowEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11551,7 +11574,7 @@ impl FromXml for CTPresetShadowEffect {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice =
@@ -11579,7 +11602,7 @@ impl FromXml for CTPresetShadowEffect {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice =
@@ -11626,7 +11649,7 @@ impl FromXml for CTPresetShadowEffect {
 
 impl FromXml for CTReflectionEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11780,7 +11803,7 @@ impl FromXml for CTReflectionEffect {
 
 impl FromXml for CTRelativeOffsetEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11832,7 +11855,7 @@ impl FromXml for CTRelativeOffsetEffect {
 
 impl FromXml for CTSoftEdgesEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11882,7 +11905,7 @@ impl FromXml for CTSoftEdgesEffect {
 
 impl FromXml for CTTintEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11934,7 +11957,7 @@ impl FromXml for CTTintEffect {
 
 impl FromXml for CTTransformEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12006,7 +12029,7 @@ impl FromXml for CTTransformEffect {
 
 impl FromXml for NoFill {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12034,7 +12057,7 @@ impl FromXml for NoFill {
 
 impl FromXml for SolidColorFill {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12050,7 +12073,7 @@ impl FromXml for SolidColorFill {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice =
@@ -12078,7 +12101,7 @@ impl FromXml for SolidColorFill {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice =
@@ -12120,7 +12143,7 @@ impl FromXml for SolidColorFill {
 
 impl FromXml for CTLinearShadeProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12172,7 +12195,7 @@ impl FromXml for CTLinearShadeProperties {
 
 impl FromXml for CTPathShadeProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12208,7 +12231,7 @@ impl FromXml for CTPathShadeProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"fillToRect" => {
                                 f_fill_to_rect =
                                     Some(Box::new(CTRelativeRect::from_xml(reader, &e, false)?));
@@ -12235,7 +12258,7 @@ impl FromXml for CTPathShadeProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"fillToRect" => {
                                 f_fill_to_rect =
                                     Some(Box::new(CTRelativeRect::from_xml(reader, &e, true)?));
@@ -12279,7 +12302,7 @@ impl FromXml for CTPathShadeProperties {
 
 impl FromXml for EGShadeProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12302,7 +12325,7 @@ impl FromXml for EGShadeProperties {
 
 impl FromXml for CTGradientStop {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12338,7 +12361,7 @@ impl FromXml for CTGradientStop {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice =
@@ -12366,7 +12389,7 @@ impl FromXml for CTGradientStop {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice =
@@ -12411,7 +12434,7 @@ impl FromXml for CTGradientStop {
 
 impl FromXml for CTGradientStopList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12427,7 +12450,7 @@ impl FromXml for CTGradientStopList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"gs" => {
                                 f_gs.push(CTGradientStop::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -12453,7 +12476,7 @@ impl FromXml for CTGradientStopList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"gs" => {
                                 f_gs.push(CTGradientStop::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -12493,7 +12516,7 @@ impl FromXml for CTGradientStopList {
 
 impl FromXml for GradientFill {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12541,7 +12564,7 @@ impl FromXml for GradientFill {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-fills")]
                             b"gsLst" => {
                                 f_gs_lst = Some(Box::new(CTGradientStopList::from_xml(
@@ -12587,7 +12610,7 @@ impl FromXml for GradientFill {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-fills")]
                             b"gsLst" => {
                                 f_gs_lst =
@@ -12656,7 +12679,7 @@ impl FromXml for GradientFill {
 
 impl FromXml for CTTileInfoProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12728,7 +12751,7 @@ impl FromXml for CTTileInfoProperties {
 
 impl FromXml for CTStretchInfoProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12744,7 +12767,7 @@ impl FromXml for CTStretchInfoProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"fillRect" => {
                                 f_fill_rect =
                                     Some(Box::new(CTRelativeRect::from_xml(reader, &e, false)?));
@@ -12771,7 +12794,7 @@ impl FromXml for CTStretchInfoProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"fillRect" => {
                                 f_fill_rect =
                                     Some(Box::new(CTRelativeRect::from_xml(reader, &e, true)?));
@@ -12812,7 +12835,7 @@ impl FromXml for CTStretchInfoProperties {
 
 impl FromXml for EGFillModeProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12835,7 +12858,7 @@ impl FromXml for EGFillModeProperties {
 
 impl FromXml for Blip {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12920,7 +12943,7 @@ impl FromXml for Blip {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-fills")]
                             b"alphaBiLevel" => {
                                 f_alpha_bi_level
@@ -13094,7 +13117,7 @@ impl FromXml for Blip {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-fills")]
                             b"alphaBiLevel" => {
                                 f_alpha_bi_level
@@ -13322,7 +13345,7 @@ impl FromXml for Blip {
 
 impl FromXml for BlipFillProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13370,7 +13393,7 @@ impl FromXml for BlipFillProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-fills")]
                             b"blip" => {
                                 f_blip = Some(Box::new(Blip::from_xml(reader, &e, false)?));
@@ -13415,7 +13438,7 @@ impl FromXml for BlipFillProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-fills")]
                             b"blip" => {
                                 f_blip = Some(Box::new(Blip::from_xml(reader, &e, true)?));
@@ -13484,7 +13507,7 @@ impl FromXml for BlipFillProperties {
 
 impl FromXml for PatternFill {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13525,7 +13548,7 @@ impl FromXml for PatternFill {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-fills")]
                             b"fgClr" => {
                                 f_fg_clr = Some(Box::new(CTColor::from_xml(reader, &e, false)?));
@@ -13560,7 +13583,7 @@ impl FromXml for PatternFill {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-fills")]
                             b"fgClr" => {
                                 f_fg_clr = Some(Box::new(CTColor::from_xml(reader, &e, true)?));
@@ -13616,7 +13639,7 @@ impl FromXml for PatternFill {
 
 impl FromXml for CTGroupFillProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13644,7 +13667,7 @@ impl FromXml for CTGroupFillProperties {
 
 impl FromXml for EGFillProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13683,7 +13706,7 @@ impl FromXml for EGFillProperties {
 
 impl FromXml for CTFillProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13699,7 +13722,7 @@ impl FromXml for CTFillProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"noFill" | b"solidFill" | b"gradFill" | b"blipFill" | b"pattFill"
                             | b"grpFill" => {
                                 f_fill_properties =
@@ -13727,7 +13750,7 @@ impl FromXml for CTFillProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"noFill" | b"solidFill" | b"gradFill" | b"blipFill" | b"pattFill"
                             | b"grpFill" => {
                                 f_fill_properties =
@@ -13769,7 +13792,7 @@ impl FromXml for CTFillProperties {
 
 impl FromXml for CTFillEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13785,7 +13808,7 @@ impl FromXml for CTFillEffect {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"noFill" | b"solidFill" | b"gradFill" | b"blipFill" | b"pattFill"
                             | b"grpFill" => {
                                 f_fill_properties =
@@ -13813,7 +13836,7 @@ impl FromXml for CTFillEffect {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"noFill" | b"solidFill" | b"gradFill" | b"blipFill" | b"pattFill"
                             | b"grpFill" => {
                                 f_fill_properties =
@@ -13855,7 +13878,7 @@ impl FromXml for CTFillEffect {
 
 impl FromXml for CTFillOverlayEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13891,7 +13914,7 @@ impl FromXml for CTFillOverlayEffect {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"noFill" | b"solidFill" | b"gradFill" | b"blipFill" | b"pattFill"
                             | b"grpFill" => {
                                 f_fill_properties =
@@ -13919,7 +13942,7 @@ impl FromXml for CTFillOverlayEffect {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"noFill" | b"solidFill" | b"gradFill" | b"blipFill" | b"pattFill"
                             | b"grpFill" => {
                                 f_fill_properties =
@@ -13964,7 +13987,7 @@ impl FromXml for CTFillOverlayEffect {
 
 impl FromXml for CTEffectReference {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14011,7 +14034,7 @@ impl FromXml for CTEffectReference {
 
 impl FromXml for EGEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14146,7 +14169,7 @@ impl FromXml for EGEffect {
 
 impl FromXml for EffectContainer {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14186,7 +14209,7 @@ impl FromXml for EffectContainer {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cont" | b"effect" | b"alphaBiLevel" | b"alphaCeiling"
                             | b"alphaFloor" | b"alphaInv" | b"alphaMod" | b"alphaModFix"
                             | b"alphaOutset" | b"alphaRepl" | b"biLevel" | b"blend" | b"blur"
@@ -14218,7 +14241,7 @@ impl FromXml for EffectContainer {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cont" | b"effect" | b"alphaBiLevel" | b"alphaCeiling"
                             | b"alphaFloor" | b"alphaInv" | b"alphaMod" | b"alphaModFix"
                             | b"alphaOutset" | b"alphaRepl" | b"biLevel" | b"blend" | b"blur"
@@ -14268,7 +14291,7 @@ impl FromXml for EffectContainer {
 
 impl FromXml for CTBlendEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14304,7 +14327,7 @@ impl FromXml for CTBlendEffect {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cont" => {
                                 f_cont =
                                     Some(Box::new(EffectContainer::from_xml(reader, &e, false)?));
@@ -14331,7 +14354,7 @@ impl FromXml for CTBlendEffect {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cont" => {
                                 f_cont =
                                     Some(Box::new(EffectContainer::from_xml(reader, &e, true)?));
@@ -14375,7 +14398,7 @@ impl FromXml for CTBlendEffect {
 
 impl FromXml for EffectList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14406,7 +14429,7 @@ impl FromXml for EffectList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-effects")]
                             b"blur" => {
                                 f_blur = Some(Box::new(CTBlurEffect::from_xml(reader, &e, false)?));
@@ -14500,7 +14523,7 @@ impl FromXml for EffectList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-effects")]
                             b"blur" => {
                                 f_blur = Some(Box::new(CTBlurEffect::from_xml(reader, &e, true)?));
@@ -14622,7 +14645,7 @@ impl FromXml for EffectList {
 
 impl FromXml for EGEffectProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14645,7 +14668,7 @@ impl FromXml for EGEffectProperties {
 
 impl FromXml for CTEffectProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14661,7 +14684,7 @@ impl FromXml for CTEffectProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"effectLst" | b"effectDag" => {
                                 f_effect_properties = Some(Box::new(EGEffectProperties::from_xml(
                                     reader, &e, false,
@@ -14689,7 +14712,7 @@ impl FromXml for CTEffectProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"effectLst" | b"effectDag" => {
                                 f_effect_properties =
                                     Some(Box::new(EGEffectProperties::from_xml(reader, &e, true)?));
@@ -14730,7 +14753,7 @@ impl FromXml for CTEffectProperties {
 
 impl FromXml for CTGeomGuide {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14782,7 +14805,7 @@ impl FromXml for CTGeomGuide {
 
 impl FromXml for CTGeomGuideList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14798,7 +14821,7 @@ impl FromXml for CTGeomGuideList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"gd" => {
                                 f_gd.push(CTGeomGuide::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -14824,7 +14847,7 @@ impl FromXml for CTGeomGuideList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"gd" => {
                                 f_gd.push(CTGeomGuide::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -14864,7 +14887,7 @@ impl FromXml for CTGeomGuideList {
 
 impl FromXml for CTAdjPoint2D {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14916,7 +14939,7 @@ impl FromXml for CTAdjPoint2D {
 
 impl FromXml for CTGeomRect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14979,7 +15002,7 @@ impl FromXml for CTGeomRect {
 
 impl FromXml for CTXYAdjustHandle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15035,7 +15058,7 @@ impl FromXml for CTXYAdjustHandle {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pos" => {
                                 f_pos = Some(Box::new(CTAdjPoint2D::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -15061,7 +15084,7 @@ impl FromXml for CTXYAdjustHandle {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pos" => {
                                 f_pos = Some(Box::new(CTAdjPoint2D::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -15109,7 +15132,7 @@ impl FromXml for CTXYAdjustHandle {
 
 impl FromXml for CTPolarAdjustHandle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15165,7 +15188,7 @@ impl FromXml for CTPolarAdjustHandle {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pos" => {
                                 f_pos = Some(Box::new(CTAdjPoint2D::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -15191,7 +15214,7 @@ impl FromXml for CTPolarAdjustHandle {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pos" => {
                                 f_pos = Some(Box::new(CTAdjPoint2D::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -15239,7 +15262,7 @@ impl FromXml for CTPolarAdjustHandle {
 
 impl FromXml for CTConnectionSite {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15275,7 +15298,7 @@ impl FromXml for CTConnectionSite {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pos" => {
                                 f_pos = Some(Box::new(CTAdjPoint2D::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -15301,7 +15324,7 @@ impl FromXml for CTConnectionSite {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pos" => {
                                 f_pos = Some(Box::new(CTAdjPoint2D::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -15344,7 +15367,7 @@ impl FromXml for CTConnectionSite {
 
 impl FromXml for CTAdjustHandleList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15361,7 +15384,7 @@ impl FromXml for CTAdjustHandleList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ahXY" => {
                                 f_ah_x_y.push(CTXYAdjustHandle::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -15394,7 +15417,7 @@ impl FromXml for CTAdjustHandleList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ahXY" => {
                                 f_ah_x_y.push(CTXYAdjustHandle::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -15442,7 +15465,7 @@ impl FromXml for CTAdjustHandleList {
 
 impl FromXml for CTConnectionSiteList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15458,7 +15481,7 @@ impl FromXml for CTConnectionSiteList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cxn" => {
                                 f_cxn.push(CTConnectionSite::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -15484,7 +15507,7 @@ impl FromXml for CTConnectionSiteList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cxn" => {
                                 f_cxn.push(CTConnectionSite::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -15524,7 +15547,7 @@ impl FromXml for CTConnectionSiteList {
 
 impl FromXml for CTConnection {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15576,7 +15599,7 @@ impl FromXml for CTConnection {
 
 impl FromXml for CTPath2DArcTo {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15638,7 +15661,7 @@ impl FromXml for CTPath2DArcTo {
 
 impl FromXml for CTPath2DQuadBezierTo {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15654,7 +15677,7 @@ impl FromXml for CTPath2DQuadBezierTo {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pt" => {
                                 f_pt.push(CTAdjPoint2D::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -15680,7 +15703,7 @@ impl FromXml for CTPath2DQuadBezierTo {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pt" => {
                                 f_pt.push(CTAdjPoint2D::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -15720,7 +15743,7 @@ impl FromXml for CTPath2DQuadBezierTo {
 
 impl FromXml for CTPath2DCubicBezierTo {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15736,7 +15759,7 @@ impl FromXml for CTPath2DCubicBezierTo {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pt" => {
                                 f_pt.push(CTAdjPoint2D::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -15762,7 +15785,7 @@ impl FromXml for CTPath2DCubicBezierTo {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pt" => {
                                 f_pt.push(CTAdjPoint2D::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -15802,7 +15825,7 @@ impl FromXml for CTPath2DCubicBezierTo {
 
 impl FromXml for CTPath2DClose {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15830,7 +15853,7 @@ impl FromXml for CTPath2DClose {
 
 impl FromXml for CTPath2D {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15887,7 +15910,7 @@ impl FromXml for CTPath2D {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"close" => {
                                 f_close.push(CTPath2DClose::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -15951,7 +15974,7 @@ impl FromXml for CTPath2D {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"close" => {
                                 f_close.push(CTPath2DClose::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -16040,7 +16063,7 @@ impl FromXml for CTPath2D {
 
 impl FromXml for CTPath2DList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16056,7 +16079,7 @@ impl FromXml for CTPath2DList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"path" => {
                                 f_path.push(CTPath2D::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -16082,7 +16105,7 @@ impl FromXml for CTPath2DList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"path" => {
                                 f_path.push(CTPath2D::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -16122,7 +16145,7 @@ impl FromXml for CTPath2DList {
 
 impl FromXml for CTPresetGeometry2D {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16158,7 +16181,7 @@ impl FromXml for CTPresetGeometry2D {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"avLst" => {
                                 f_av_lst =
                                     Some(Box::new(CTGeomGuideList::from_xml(reader, &e, false)?));
@@ -16185,7 +16208,7 @@ impl FromXml for CTPresetGeometry2D {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"avLst" => {
                                 f_av_lst =
                                     Some(Box::new(CTGeomGuideList::from_xml(reader, &e, true)?));
@@ -16229,7 +16252,7 @@ impl FromXml for CTPresetGeometry2D {
 
 impl FromXml for CTPresetTextShape {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16265,7 +16288,7 @@ impl FromXml for CTPresetTextShape {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"avLst" => {
                                 f_av_lst =
                                     Some(Box::new(CTGeomGuideList::from_xml(reader, &e, false)?));
@@ -16292,7 +16315,7 @@ impl FromXml for CTPresetTextShape {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"avLst" => {
                                 f_av_lst =
                                     Some(Box::new(CTGeomGuideList::from_xml(reader, &e, true)?));
@@ -16336,7 +16359,7 @@ impl FromXml for CTPresetTextShape {
 
 impl FromXml for CTCustomGeometry2D {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16357,7 +16380,7 @@ impl FromXml for CTCustomGeometry2D {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"avLst" => {
                                 f_av_lst =
                                     Some(Box::new(CTGeomGuideList::from_xml(reader, &e, false)?));
@@ -16425,7 +16448,7 @@ impl FromXml for CTCustomGeometry2D {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"avLst" => {
                                 f_av_lst =
                                     Some(Box::new(CTGeomGuideList::from_xml(reader, &e, true)?));
@@ -16512,7 +16535,7 @@ impl FromXml for CTCustomGeometry2D {
 
 impl FromXml for EGGeometry {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16535,7 +16558,7 @@ impl FromXml for EGGeometry {
 
 impl FromXml for EGTextGeometry {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16558,7 +16581,7 @@ impl FromXml for EGTextGeometry {
 
 impl FromXml for CTLineEndProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16624,7 +16647,7 @@ impl FromXml for CTLineEndProperties {
 
 impl FromXml for EGLineFillProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16655,7 +16678,7 @@ impl FromXml for EGLineFillProperties {
 
 impl FromXml for CTLineJoinBevel {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16683,7 +16706,7 @@ impl FromXml for CTLineJoinBevel {
 
 impl FromXml for CTLineJoinRound {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16711,7 +16734,7 @@ impl FromXml for CTLineJoinRound {
 
 impl FromXml for CTLineJoinMiterProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16758,7 +16781,7 @@ impl FromXml for CTLineJoinMiterProperties {
 
 impl FromXml for EGLineJoinProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16785,7 +16808,7 @@ impl FromXml for EGLineJoinProperties {
 
 impl FromXml for CTPresetLineDashProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16835,7 +16858,7 @@ impl FromXml for CTPresetLineDashProperties {
 
 impl FromXml for CTDashStop {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16893,7 +16916,7 @@ impl FromXml for CTDashStop {
 
 impl FromXml for CTDashStopList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16909,7 +16932,7 @@ impl FromXml for CTDashStopList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ds" => {
                                 f_ds.push(CTDashStop::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -16935,7 +16958,7 @@ impl FromXml for CTDashStopList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ds" => {
                                 f_ds.push(CTDashStop::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -16975,7 +16998,7 @@ impl FromXml for CTDashStopList {
 
 impl FromXml for EGLineDashProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16998,7 +17021,7 @@ impl FromXml for EGLineDashProperties {
 
 impl FromXml for LineProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -17062,7 +17085,7 @@ impl FromXml for LineProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"noFill" | b"solidFill" | b"gradFill" | b"pattFill" => {
                                 f_line_fill_properties = Some(Box::new(
                                     EGLineFillProperties::from_xml(reader, &e, false)?,
@@ -17138,7 +17161,7 @@ impl FromXml for LineProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"noFill" | b"solidFill" | b"gradFill" | b"pattFill" => {
                                 f_line_fill_properties = Some(Box::new(
                                     EGLineFillProperties::from_xml(reader, &e, true)?,
@@ -17246,7 +17269,7 @@ impl FromXml for LineProperties {
 
 impl FromXml for CTShapeProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -17295,7 +17318,7 @@ impl FromXml for CTShapeProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"xfrm" => {
                                 f_transform =
                                     Some(Box::new(Transform2D::from_xml(reader, &e, false)?));
@@ -17383,7 +17406,7 @@ impl FromXml for CTShapeProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"xfrm" => {
                                 f_transform =
                                     Some(Box::new(Transform2D::from_xml(reader, &e, true)?));
@@ -17499,7 +17522,7 @@ impl FromXml for CTShapeProperties {
 
 impl FromXml for CTGroupShapeProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -17543,7 +17566,7 @@ impl FromXml for CTGroupShapeProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"xfrm" => {
                                 f_transform = Some(Box::new(CTGroupTransform2D::from_xml(
                                     reader, &e, false,
@@ -17607,7 +17630,7 @@ impl FromXml for CTGroupShapeProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"xfrm" => {
                                 f_transform =
                                     Some(Box::new(CTGroupTransform2D::from_xml(reader, &e, true)?));
@@ -17693,7 +17716,7 @@ impl FromXml for CTGroupShapeProperties {
 
 impl FromXml for CTStyleMatrixReference {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -17731,7 +17754,7 @@ impl FromXml for CTStyleMatrixReference {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice =
@@ -17759,7 +17782,7 @@ impl FromXml for CTStyleMatrixReference {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice =
@@ -17805,7 +17828,7 @@ impl FromXml for CTStyleMatrixReference {
 
 impl FromXml for CTFontReference {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -17843,7 +17866,7 @@ impl FromXml for CTFontReference {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice =
@@ -17871,7 +17894,7 @@ impl FromXml for CTFontReference {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
                                 f_color_choice =
@@ -17917,7 +17940,7 @@ impl FromXml for CTFontReference {
 
 impl FromXml for ShapeStyle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -17940,7 +17963,7 @@ impl FromXml for ShapeStyle {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-shapes")]
                             b"lnRef" => {
                                 f_ln_ref = Some(Box::new(CTStyleMatrixReference::from_xml(
@@ -17998,7 +18021,7 @@ impl FromXml for ShapeStyle {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-shapes")]
                             b"lnRef" => {
                                 f_ln_ref = Some(Box::new(CTStyleMatrixReference::from_xml(
@@ -18080,7 +18103,7 @@ impl FromXml for ShapeStyle {
 
 impl FromXml for CTDefaultShapeDefinition {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -18100,7 +18123,7 @@ impl FromXml for CTDefaultShapeDefinition {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"spPr" => {
                                 f_sp_pr =
                                     Some(Box::new(CTShapeProperties::from_xml(reader, &e, false)?));
@@ -18160,7 +18183,7 @@ impl FromXml for CTDefaultShapeDefinition {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"spPr" => {
                                 f_sp_pr =
                                     Some(Box::new(CTShapeProperties::from_xml(reader, &e, true)?));
@@ -18239,7 +18262,7 @@ impl FromXml for CTDefaultShapeDefinition {
 
 impl FromXml for CTObjectStyleDefaults {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -18258,7 +18281,7 @@ impl FromXml for CTObjectStyleDefaults {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"spDef" => {
                                 f_sp_def = Some(Box::new(CTDefaultShapeDefinition::from_xml(
                                     reader, &e, false,
@@ -18313,7 +18336,7 @@ impl FromXml for CTObjectStyleDefaults {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"spDef" => {
                                 f_sp_def = Some(Box::new(CTDefaultShapeDefinition::from_xml(
                                     reader, &e, true,
@@ -18385,7 +18408,7 @@ impl FromXml for CTObjectStyleDefaults {
 
 impl FromXml for CTEmptyElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -18413,7 +18436,7 @@ impl FromXml for CTEmptyElement {
 
 impl FromXml for CTColorMapping {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -18493,7 +18516,7 @@ impl FromXml for CTColorMapping {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
                                     reader, &e, false,
@@ -18521,7 +18544,7 @@ impl FromXml for CTColorMapping {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
                                     reader, &e, true,
@@ -18584,7 +18607,7 @@ impl FromXml for CTColorMapping {
 
 impl FromXml for CTColorMappingOverride {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -18603,7 +18626,7 @@ impl FromXml for CTColorMappingOverride {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-colors")]
                             b"masterClrMapping" => {
                                 f_master_clr_mapping =
@@ -18640,7 +18663,7 @@ impl FromXml for CTColorMappingOverride {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-colors")]
                             b"masterClrMapping" => {
                                 f_master_clr_mapping =
@@ -18694,7 +18717,7 @@ impl FromXml for CTColorMappingOverride {
 
 impl FromXml for CTColorSchemeAndMapping {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -18711,7 +18734,7 @@ impl FromXml for CTColorSchemeAndMapping {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"clrScheme" => {
                                 f_clr_scheme =
                                     Some(Box::new(ColorScheme::from_xml(reader, &e, false)?));
@@ -18746,7 +18769,7 @@ impl FromXml for CTColorSchemeAndMapping {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"clrScheme" => {
                                 f_clr_scheme =
                                     Some(Box::new(ColorScheme::from_xml(reader, &e, true)?));
@@ -18797,7 +18820,7 @@ impl FromXml for CTColorSchemeAndMapping {
 
 impl FromXml for CTColorSchemeList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -18813,7 +18836,7 @@ impl FromXml for CTColorSchemeList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extraClrScheme" => {
                                 f_extra_clr_scheme
                                     .push(CTColorSchemeAndMapping::from_xml(reader, &e, false)?);
@@ -18840,7 +18863,7 @@ impl FromXml for CTColorSchemeList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extraClrScheme" => {
                                 f_extra_clr_scheme
                                     .push(CTColorSchemeAndMapping::from_xml(reader, &e, true)?);
@@ -18881,7 +18904,7 @@ impl FromXml for CTColorSchemeList {
 
 impl FromXml for CTOfficeStyleSheet {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -18928,7 +18951,7 @@ impl FromXml for CTOfficeStyleSheet {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-themes")]
                             b"themeElements" => {
                                 f_theme_elements =
@@ -18994,7 +19017,7 @@ impl FromXml for CTOfficeStyleSheet {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-themes")]
                             b"themeElements" => {
                                 f_theme_elements =
@@ -19088,7 +19111,7 @@ impl FromXml for CTOfficeStyleSheet {
 
 impl FromXml for CTBaseStylesOverride {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -19106,7 +19129,7 @@ impl FromXml for CTBaseStylesOverride {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"clrScheme" => {
                                 f_clr_scheme =
                                     Some(Box::new(ColorScheme::from_xml(reader, &e, false)?));
@@ -19149,7 +19172,7 @@ impl FromXml for CTBaseStylesOverride {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"clrScheme" => {
                                 f_clr_scheme =
                                     Some(Box::new(ColorScheme::from_xml(reader, &e, true)?));
@@ -19208,7 +19231,7 @@ impl FromXml for CTBaseStylesOverride {
 
 impl FromXml for CTClipboardStyleSheet {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -19225,7 +19248,7 @@ impl FromXml for CTClipboardStyleSheet {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"themeElements" => {
                                 f_theme_elements =
                                     Some(Box::new(CTBaseStyles::from_xml(reader, &e, false)?));
@@ -19260,7 +19283,7 @@ impl FromXml for CTClipboardStyleSheet {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"themeElements" => {
                                 f_theme_elements =
                                     Some(Box::new(CTBaseStyles::from_xml(reader, &e, true)?));
@@ -19311,7 +19334,7 @@ impl FromXml for CTClipboardStyleSheet {
 
 impl FromXml for CTTableCellProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -19408,7 +19431,7 @@ impl FromXml for CTTableCellProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-tables")]
                             b"lnL" => {
                                 f_ln_l =
@@ -19515,7 +19538,7 @@ impl FromXml for CTTableCellProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-tables")]
                             b"lnL" => {
                                 f_ln_l =
@@ -19671,7 +19694,7 @@ impl FromXml for CTTableCellProperties {
 
 impl FromXml for CTHeaders {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -19687,7 +19710,7 @@ impl FromXml for CTHeaders {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"header" => {
                                 f_header.push(read_text_content(reader)?);
                                 #[cfg(feature = "extra-children")]
@@ -19713,7 +19736,7 @@ impl FromXml for CTHeaders {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"header" => {
                                 f_header.push(String::new());
                                 #[cfg(feature = "extra-children")]
@@ -19753,7 +19776,7 @@ impl FromXml for CTHeaders {
 
 impl FromXml for CTTableCol {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -19789,7 +19812,7 @@ impl FromXml for CTTableCol {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
                                     reader, &e, false,
@@ -19817,7 +19840,7 @@ impl FromXml for CTTableCol {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
                                     reader, &e, true,
@@ -19862,7 +19885,7 @@ impl FromXml for CTTableCol {
 
 impl FromXml for CTTableGrid {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -19879,7 +19902,7 @@ impl FromXml for CTTableGrid {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-tables")]
                             b"gridCol" => {
                                 f_grid_col.push(CTTableCol::from_xml(reader, &e, false)?);
@@ -19906,7 +19929,7 @@ impl FromXml for CTTableGrid {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-tables")]
                             b"gridCol" => {
                                 f_grid_col.push(CTTableCol::from_xml(reader, &e, true)?);
@@ -19948,7 +19971,7 @@ impl FromXml for CTTableGrid {
 
 impl FromXml for CTTableCell {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -20013,7 +20036,7 @@ impl FromXml for CTTableCell {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-tables")]
                             b"txBody" => {
                                 f_tx_body = Some(Box::new(TextBody::from_xml(reader, &e, false)?));
@@ -20060,7 +20083,7 @@ impl FromXml for CTTableCell {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-tables")]
                             b"txBody" => {
                                 f_tx_body = Some(Box::new(TextBody::from_xml(reader, &e, true)?));
@@ -20137,7 +20160,7 @@ impl FromXml for CTTableCell {
 
 impl FromXml for CTTableRow {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -20178,7 +20201,7 @@ impl FromXml for CTTableRow {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-tables")]
                             b"tc" => {
                                 f_tc.push(CTTableCell::from_xml(reader, &e, false)?);
@@ -20215,7 +20238,7 @@ impl FromXml for CTTableRow {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-tables")]
                             b"tc" => {
                                 f_tc.push(CTTableCell::from_xml(reader, &e, true)?);
@@ -20273,7 +20296,7 @@ impl FromXml for CTTableRow {
 
 impl FromXml for CTTableProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -20353,7 +20376,7 @@ impl FromXml for CTTableProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"noFill" | b"solidFill" | b"gradFill" | b"blipFill" | b"pattFill"
                             | b"grpFill" => {
                                 f_fill_properties =
@@ -20416,7 +20439,7 @@ impl FromXml for CTTableProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"noFill" | b"solidFill" | b"gradFill" | b"blipFill" | b"pattFill"
                             | b"grpFill" => {
                                 f_fill_properties =
@@ -20514,7 +20537,7 @@ impl FromXml for CTTableProperties {
 
 impl FromXml for CTTable {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -20535,7 +20558,7 @@ impl FromXml for CTTable {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-tables")]
                             b"tblPr" => {
                                 f_tbl_pr =
@@ -20580,7 +20603,7 @@ impl FromXml for CTTable {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-tables")]
                             b"tblPr" => {
                                 f_tbl_pr =
@@ -20645,7 +20668,7 @@ impl FromXml for CTTable {
 
 impl FromXml for CTCell3D {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -20683,7 +20706,7 @@ impl FromXml for CTCell3D {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"bevel" => {
                                 f_bevel = Some(Box::new(CTBevel::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -20726,7 +20749,7 @@ impl FromXml for CTCell3D {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"bevel" => {
                                 f_bevel = Some(Box::new(CTBevel::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -20788,7 +20811,7 @@ impl FromXml for CTCell3D {
 
 impl FromXml for EGThemeableFillStyle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -20811,7 +20834,7 @@ impl FromXml for EGThemeableFillStyle {
 
 impl FromXml for CTThemeableLineStyle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -20828,7 +20851,7 @@ impl FromXml for CTThemeableLineStyle {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ln" => {
                                 f_line =
                                     Some(Box::new(LineProperties::from_xml(reader, &e, false)?));
@@ -20864,7 +20887,7 @@ impl FromXml for CTThemeableLineStyle {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ln" => {
                                 f_line =
                                     Some(Box::new(LineProperties::from_xml(reader, &e, true)?));
@@ -20915,7 +20938,7 @@ impl FromXml for CTThemeableLineStyle {
 
 impl FromXml for EGThemeableEffectStyle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -20938,7 +20961,7 @@ impl FromXml for EGThemeableEffectStyle {
 
 impl FromXml for EGThemeableFontStyles {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -20961,7 +20984,7 @@ impl FromXml for EGThemeableFontStyles {
 
 impl FromXml for CTTableStyleTextStyle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -21003,7 +21026,7 @@ impl FromXml for CTTableStyleTextStyle {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"font" | b"fontRef" => {
                                 f_themeable_font_styles = Some(Box::new(
                                     EGThemeableFontStyles::from_xml(reader, &e, false)?,
@@ -21049,7 +21072,7 @@ impl FromXml for CTTableStyleTextStyle {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"font" | b"fontRef" => {
                                 f_themeable_font_styles = Some(Box::new(
                                     EGThemeableFontStyles::from_xml(reader, &e, true)?,
@@ -21115,7 +21138,7 @@ impl FromXml for CTTableStyleTextStyle {
 
 impl FromXml for CTTableCellBorderStyle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -21139,7 +21162,7 @@ impl FromXml for CTTableCellBorderStyle {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"left" => {
                                 f_left = Some(Box::new(CTThemeableLineStyle::from_xml(
                                     reader, &e, false,
@@ -21239,7 +21262,7 @@ impl FromXml for CTTableCellBorderStyle {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"left" => {
                                 f_left = Some(Box::new(CTThemeableLineStyle::from_xml(
                                     reader, &e, true,
@@ -21361,7 +21384,7 @@ impl FromXml for CTTableCellBorderStyle {
 
 impl FromXml for CTTableBackgroundStyle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -21378,7 +21401,7 @@ impl FromXml for CTTableBackgroundStyle {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"fill" | b"fillRef" => {
                                 f_themeable_fill_style = Some(Box::new(
                                     EGThemeableFillStyle::from_xml(reader, &e, false)?,
@@ -21415,7 +21438,7 @@ impl FromXml for CTTableBackgroundStyle {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"fill" | b"fillRef" => {
                                 f_themeable_fill_style = Some(Box::new(
                                     EGThemeableFillStyle::from_xml(reader, &e, true)?,
@@ -21467,7 +21490,7 @@ impl FromXml for CTTableBackgroundStyle {
 
 impl FromXml for CTTableStyleCellStyle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -21485,7 +21508,7 @@ impl FromXml for CTTableStyleCellStyle {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tcBdr" => {
                                 f_tc_bdr = Some(Box::new(CTTableCellBorderStyle::from_xml(
                                     reader, &e, false,
@@ -21529,7 +21552,7 @@ impl FromXml for CTTableStyleCellStyle {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tcBdr" => {
                                 f_tc_bdr = Some(Box::new(CTTableCellBorderStyle::from_xml(
                                     reader, &e, true,
@@ -21589,7 +21612,7 @@ impl FromXml for CTTableStyleCellStyle {
 
 impl FromXml for CTTablePartStyle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -21606,7 +21629,7 @@ impl FromXml for CTTablePartStyle {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tcTxStyle" => {
                                 f_tc_tx_style = Some(Box::new(CTTableStyleTextStyle::from_xml(
                                     reader, &e, false,
@@ -21643,7 +21666,7 @@ impl FromXml for CTTablePartStyle {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tcTxStyle" => {
                                 f_tc_tx_style = Some(Box::new(CTTableStyleTextStyle::from_xml(
                                     reader, &e, true,
@@ -21695,7 +21718,7 @@ impl FromXml for CTTablePartStyle {
 
 impl FromXml for CTTableStyle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -21749,7 +21772,7 @@ impl FromXml for CTTableStyle {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tblBg" => {
                                 f_tbl_bg = Some(Box::new(CTTableBackgroundStyle::from_xml(
                                     reader, &e, false,
@@ -21890,7 +21913,7 @@ impl FromXml for CTTableStyle {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tblBg" => {
                                 f_tbl_bg = Some(Box::new(CTTableBackgroundStyle::from_xml(
                                     reader, &e, true,
@@ -22065,7 +22088,7 @@ impl FromXml for CTTableStyle {
 
 impl FromXml for CTTableStyleList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -22101,7 +22124,7 @@ impl FromXml for CTTableStyleList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tblStyle" => {
                                 f_tbl_style.push(CTTableStyle::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -22127,7 +22150,7 @@ impl FromXml for CTTableStyleList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tblStyle" => {
                                 f_tbl_style.push(CTTableStyle::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -22170,7 +22193,7 @@ impl FromXml for CTTableStyleList {
 
 impl FromXml for TextParagraph {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -22190,7 +22213,7 @@ impl FromXml for TextParagraph {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-text")]
                             b"pPr" => {
                                 f_p_pr = Some(Box::new(TextParagraphProperties::from_xml(
@@ -22236,7 +22259,7 @@ impl FromXml for TextParagraph {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-text")]
                             b"pPr" => {
                                 f_p_pr = Some(Box::new(TextParagraphProperties::from_xml(
@@ -22300,7 +22323,7 @@ impl FromXml for TextParagraph {
 
 impl FromXml for CTTextListStyle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -22326,7 +22349,7 @@ impl FromXml for CTTextListStyle {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"defPPr" => {
                                 f_def_p_pr = Some(Box::new(TextParagraphProperties::from_xml(
                                     reader, &e, false,
@@ -22444,7 +22467,7 @@ impl FromXml for CTTextListStyle {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"defPPr" => {
                                 f_def_p_pr = Some(Box::new(TextParagraphProperties::from_xml(
                                     reader, &e, true,
@@ -22586,7 +22609,7 @@ impl FromXml for CTTextListStyle {
 
 impl FromXml for CTTextNormalAutofit {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -22638,7 +22661,7 @@ impl FromXml for CTTextNormalAutofit {
 
 impl FromXml for CTTextShapeAutofit {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -22666,7 +22689,7 @@ impl FromXml for CTTextShapeAutofit {
 
 impl FromXml for CTTextNoAutofit {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -22694,7 +22717,7 @@ impl FromXml for CTTextNoAutofit {
 
 impl FromXml for EGTextAutofit {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -22721,7 +22744,7 @@ impl FromXml for EGTextAutofit {
 
 impl FromXml for CTTextBodyProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -22874,7 +22897,7 @@ impl FromXml for CTTextBodyProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-text")]
                             b"prstTxWarp" => {
                                 f_prst_tx_warp =
@@ -22935,7 +22958,7 @@ impl FromXml for CTTextBodyProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-text")]
                             b"prstTxWarp" => {
                                 f_prst_tx_warp =
@@ -23057,7 +23080,7 @@ impl FromXml for CTTextBodyProperties {
 
 impl FromXml for TextBody {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23078,7 +23101,7 @@ impl FromXml for TextBody {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-text")]
                             b"bodyPr" => {
                                 f_body_pr = Some(Box::new(CTTextBodyProperties::from_xml(
@@ -23124,7 +23147,7 @@ impl FromXml for TextBody {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-text")]
                             b"bodyPr" => {
                                 f_body_pr = Some(Box::new(CTTextBodyProperties::from_xml(
@@ -23189,7 +23212,7 @@ impl FromXml for TextBody {
 
 impl FromXml for CTTextBulletColorFollowText {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23217,7 +23240,7 @@ impl FromXml for CTTextBulletColorFollowText {
 
 impl FromXml for EGTextBulletColor {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23240,7 +23263,7 @@ impl FromXml for EGTextBulletColor {
 
 impl FromXml for CTTextBulletSizeFollowText {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23268,7 +23291,7 @@ impl FromXml for CTTextBulletSizeFollowText {
 
 impl FromXml for TextBulletSizePercentElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23315,7 +23338,7 @@ impl FromXml for TextBulletSizePercentElement {
 
 impl FromXml for CTTextBulletSizePoint {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23362,7 +23385,7 @@ impl FromXml for CTTextBulletSizePoint {
 
 impl FromXml for EGTextBulletSize {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23389,7 +23412,7 @@ impl FromXml for EGTextBulletSize {
 
 impl FromXml for CTTextBulletTypefaceFollowText {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23417,7 +23440,7 @@ impl FromXml for CTTextBulletTypefaceFollowText {
 
 impl FromXml for EGTextBulletTypeface {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23440,7 +23463,7 @@ impl FromXml for EGTextBulletTypeface {
 
 impl FromXml for CTTextAutonumberBullet {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23492,7 +23515,7 @@ impl FromXml for CTTextAutonumberBullet {
 
 impl FromXml for CTTextCharBullet {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23539,7 +23562,7 @@ impl FromXml for CTTextCharBullet {
 
 impl FromXml for CTTextNoBullet {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23567,7 +23590,7 @@ impl FromXml for CTTextNoBullet {
 
 impl FromXml for EGTextBullet {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23598,7 +23621,7 @@ impl FromXml for EGTextBullet {
 
 impl FromXml for TextFont {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23673,7 +23696,7 @@ impl FromXml for TextFont {
 
 impl FromXml for CTTextUnderlineLineFollowText {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23701,7 +23724,7 @@ impl FromXml for CTTextUnderlineLineFollowText {
 
 impl FromXml for CTTextUnderlineFillFollowText {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23729,7 +23752,7 @@ impl FromXml for CTTextUnderlineFillFollowText {
 
 impl FromXml for CTTextUnderlineFillGroupWrapper {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23745,7 +23768,7 @@ impl FromXml for CTTextUnderlineFillGroupWrapper {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"noFill" | b"solidFill" | b"gradFill" | b"blipFill" | b"pattFill"
                             | b"grpFill" => {
                                 f_fill_properties =
@@ -23773,7 +23796,7 @@ impl FromXml for CTTextUnderlineFillGroupWrapper {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"noFill" | b"solidFill" | b"gradFill" | b"blipFill" | b"pattFill"
                             | b"grpFill" => {
                                 f_fill_properties =
@@ -23815,7 +23838,7 @@ impl FromXml for CTTextUnderlineFillGroupWrapper {
 
 impl FromXml for EGTextUnderlineLine {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23838,7 +23861,7 @@ impl FromXml for EGTextUnderlineLine {
 
 impl FromXml for EGTextUnderlineFill {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23861,7 +23884,7 @@ impl FromXml for EGTextUnderlineFill {
 
 impl FromXml for TextCharacterProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24030,7 +24053,7 @@ impl FromXml for TextCharacterProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-text")]
                             b"ln" => {
                                 f_line =
@@ -24170,7 +24193,7 @@ impl FromXml for TextCharacterProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-text")]
                             b"ln" => {
                                 f_line =
@@ -24386,7 +24409,7 @@ impl FromXml for TextCharacterProperties {
 
 impl FromXml for CTBoolean {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24433,7 +24456,7 @@ impl FromXml for CTBoolean {
 
 impl FromXml for CTTextSpacingPercent {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24480,7 +24503,7 @@ impl FromXml for CTTextSpacingPercent {
 
 impl FromXml for CTTextSpacingPoint {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24527,7 +24550,7 @@ impl FromXml for CTTextSpacingPoint {
 
 impl FromXml for CTTextTabStop {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24579,7 +24602,7 @@ impl FromXml for CTTextTabStop {
 
 impl FromXml for CTTextTabStopList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24595,7 +24618,7 @@ impl FromXml for CTTextTabStopList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tab" => {
                                 f_tab.push(CTTextTabStop::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -24621,7 +24644,7 @@ impl FromXml for CTTextTabStopList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tab" => {
                                 f_tab.push(CTTextTabStop::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -24661,7 +24684,7 @@ impl FromXml for CTTextTabStopList {
 
 impl FromXml for CTTextLineBreak {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24677,7 +24700,7 @@ impl FromXml for CTTextLineBreak {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rPr" => {
                                 f_r_pr = Some(Box::new(TextCharacterProperties::from_xml(
                                     reader, &e, false,
@@ -24705,7 +24728,7 @@ impl FromXml for CTTextLineBreak {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rPr" => {
                                 f_r_pr = Some(Box::new(TextCharacterProperties::from_xml(
                                     reader, &e, true,
@@ -24747,7 +24770,7 @@ impl FromXml for CTTextLineBreak {
 
 impl FromXml for CTTextSpacing {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24764,7 +24787,7 @@ impl FromXml for CTTextSpacing {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"spcPct" => {
                                 f_spc_pct = Some(Box::new(CTTextSpacingPercent::from_xml(
                                     reader, &e, false,
@@ -24801,7 +24824,7 @@ impl FromXml for CTTextSpacing {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"spcPct" => {
                                 f_spc_pct = Some(Box::new(CTTextSpacingPercent::from_xml(
                                     reader, &e, true,
@@ -24852,7 +24875,7 @@ impl FromXml for CTTextSpacing {
 
 impl FromXml for TextParagraphProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24965,7 +24988,7 @@ impl FromXml for TextParagraphProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-text")]
                             b"lnSpc" => {
                                 f_ln_spc =
@@ -25073,7 +25096,7 @@ impl FromXml for TextParagraphProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-text")]
                             b"lnSpc" => {
                                 f_ln_spc =
@@ -25234,7 +25257,7 @@ impl FromXml for TextParagraphProperties {
 
 impl FromXml for CTTextField {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25276,7 +25299,7 @@ impl FromXml for CTTextField {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rPr" => {
                                 f_r_pr = Some(Box::new(TextCharacterProperties::from_xml(
                                     reader, &e, false,
@@ -25320,7 +25343,7 @@ impl FromXml for CTTextField {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rPr" => {
                                 f_r_pr = Some(Box::new(TextCharacterProperties::from_xml(
                                     reader, &e, true,
@@ -25384,7 +25407,7 @@ impl FromXml for CTTextField {
 
 impl FromXml for EGTextRun {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25411,7 +25434,7 @@ impl FromXml for EGTextRun {
 
 impl FromXml for TextRun {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25430,7 +25453,7 @@ impl FromXml for TextRun {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-text")]
                             b"rPr" => {
                                 f_r_pr = Some(Box::new(TextCharacterProperties::from_xml(
@@ -25467,7 +25490,7 @@ impl FromXml for TextRun {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-text")]
                             b"rPr" => {
                                 f_r_pr = Some(Box::new(TextCharacterProperties::from_xml(
@@ -25521,7 +25544,7 @@ impl FromXml for TextRun {
 
 impl FromXml for CTDouble {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25568,7 +25591,7 @@ impl FromXml for CTDouble {
 
 impl FromXml for CTUnsignedInt {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25615,7 +25638,7 @@ impl FromXml for CTUnsignedInt {
 
 impl FromXml for ChartRelId {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25665,7 +25688,7 @@ impl FromXml for ChartRelId {
 
 impl FromXml for ChartExtension {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25715,7 +25738,7 @@ impl FromXml for ChartExtension {
 
 impl FromXml for ChartExtensionList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25732,7 +25755,7 @@ impl FromXml for ChartExtensionList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"ext" => {
                                 f_extents.push(ChartExtension::from_xml(reader, &e, false)?);
@@ -25759,7 +25782,7 @@ impl FromXml for ChartExtensionList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"ext" => {
                                 f_extents.push(ChartExtension::from_xml(reader, &e, true)?);
@@ -25801,7 +25824,7 @@ impl FromXml for ChartExtensionList {
 
 impl FromXml for NumericValue {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25846,7 +25869,7 @@ impl FromXml for NumericValue {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"v" => {
                                 f_v = Some(read_text_content(reader)?);
@@ -25873,7 +25896,7 @@ impl FromXml for NumericValue {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"v" => {
                                 f_v = Some(String::new());
@@ -25921,7 +25944,7 @@ impl FromXml for NumericValue {
 
 impl FromXml for NumericData {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25944,7 +25967,7 @@ impl FromXml for NumericData {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"formatCode" => {
                                 f_format_code = Some(read_text_content(reader)?);
@@ -25998,7 +26021,7 @@ impl FromXml for NumericData {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"formatCode" => {
                                 f_format_code = Some(String::new());
@@ -26072,7 +26095,7 @@ impl FromXml for NumericData {
 
 impl FromXml for NumericReference {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -26093,7 +26116,7 @@ impl FromXml for NumericReference {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"f" => {
                                 f_f = Some(read_text_content(reader)?);
@@ -26139,7 +26162,7 @@ impl FromXml for NumericReference {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"f" => {
                                 f_f = Some(String::new());
@@ -26203,7 +26226,7 @@ impl FromXml for NumericReference {
 
 impl FromXml for NumericDataSource {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -26222,7 +26245,7 @@ impl FromXml for NumericDataSource {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"numRef" => {
                                 f_num_ref =
@@ -26259,7 +26282,7 @@ impl FromXml for NumericDataSource {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"numRef" => {
                                 f_num_ref =
@@ -26313,7 +26336,7 @@ impl FromXml for NumericDataSource {
 
 impl FromXml for StringValue {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -26352,7 +26375,7 @@ impl FromXml for StringValue {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"v" => {
                                 f_v = Some(read_text_content(reader)?);
@@ -26379,7 +26402,7 @@ impl FromXml for StringValue {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"v" => {
                                 f_v = Some(String::new());
@@ -26425,7 +26448,7 @@ impl FromXml for StringValue {
 
 impl FromXml for StringData {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -26446,7 +26469,7 @@ impl FromXml for StringData {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"ptCount" => {
                                 f_pt_count =
@@ -26492,7 +26515,7 @@ impl FromXml for StringData {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"ptCount" => {
                                 f_pt_count =
@@ -26556,7 +26579,7 @@ impl FromXml for StringData {
 
 impl FromXml for StringReference {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -26577,7 +26600,7 @@ impl FromXml for StringReference {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"f" => {
                                 f_f = Some(read_text_content(reader)?);
@@ -26623,7 +26646,7 @@ impl FromXml for StringReference {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"f" => {
                                 f_f = Some(String::new());
@@ -26687,7 +26710,7 @@ impl FromXml for StringReference {
 
 impl FromXml for ChartText {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -26706,7 +26729,7 @@ impl FromXml for ChartText {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"strRef" => {
                                 f_str_ref =
@@ -26742,7 +26765,7 @@ impl FromXml for ChartText {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"strRef" => {
                                 f_str_ref =
@@ -26795,7 +26818,7 @@ impl FromXml for ChartText {
 
 impl FromXml for TextLanguageId {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -26845,7 +26868,7 @@ impl FromXml for TextLanguageId {
 
 impl FromXml for MultiLevelStrLevel {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -26862,7 +26885,7 @@ impl FromXml for MultiLevelStrLevel {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"pt" => {
                                 f_pt.push(StringValue::from_xml(reader, &e, false)?);
@@ -26889,7 +26912,7 @@ impl FromXml for MultiLevelStrLevel {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"pt" => {
                                 f_pt.push(StringValue::from_xml(reader, &e, true)?);
@@ -26931,7 +26954,7 @@ impl FromXml for MultiLevelStrLevel {
 
 impl FromXml for MultiLevelStrData {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -26952,7 +26975,7 @@ impl FromXml for MultiLevelStrData {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"ptCount" => {
                                 f_pt_count =
@@ -26998,7 +27021,7 @@ impl FromXml for MultiLevelStrData {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"ptCount" => {
                                 f_pt_count =
@@ -27062,7 +27085,7 @@ impl FromXml for MultiLevelStrData {
 
 impl FromXml for MultiLevelStrRef {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -27083,7 +27106,7 @@ impl FromXml for MultiLevelStrRef {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"f" => {
                                 f_f = Some(read_text_content(reader)?);
@@ -27129,7 +27152,7 @@ impl FromXml for MultiLevelStrRef {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"f" => {
                                 f_f = Some(String::new());
@@ -27193,7 +27216,7 @@ impl FromXml for MultiLevelStrRef {
 
 impl FromXml for AxisDataSource {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -27218,7 +27241,7 @@ impl FromXml for AxisDataSource {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"multiLvlStrRef" => {
                                 f_multi_lvl_str_ref =
@@ -27282,7 +27305,7 @@ impl FromXml for AxisDataSource {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"multiLvlStrRef" => {
                                 f_multi_lvl_str_ref =
@@ -27368,7 +27391,7 @@ impl FromXml for AxisDataSource {
 
 impl FromXml for SeriesText {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -27387,7 +27410,7 @@ impl FromXml for SeriesText {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"strRef" => {
                                 f_str_ref =
@@ -27423,7 +27446,7 @@ impl FromXml for SeriesText {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"strRef" => {
                                 f_str_ref =
@@ -27476,7 +27499,7 @@ impl FromXml for SeriesText {
 
 impl FromXml for LayoutTarget {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -27526,7 +27549,7 @@ impl FromXml for LayoutTarget {
 
 impl FromXml for LayoutMode {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -27576,7 +27599,7 @@ impl FromXml for LayoutMode {
 
 impl FromXml for ManualLayout {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -27611,7 +27634,7 @@ impl FromXml for ManualLayout {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"layoutTarget" => {
                                 f_layout_target =
@@ -27713,7 +27736,7 @@ impl FromXml for ManualLayout {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"layoutTarget" => {
                                 f_layout_target =
@@ -27847,7 +27870,7 @@ impl FromXml for ManualLayout {
 
 impl FromXml for ChartLayout {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -27866,7 +27889,7 @@ impl FromXml for ChartLayout {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"manualLayout" => {
                                 f_manual_layout =
@@ -27904,7 +27927,7 @@ impl FromXml for ChartLayout {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"manualLayout" => {
                                 f_manual_layout =
@@ -27958,7 +27981,7 @@ impl FromXml for ChartLayout {
 
 impl FromXml for ChartTitle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -27985,7 +28008,7 @@ impl FromXml for ChartTitle {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"tx" => {
                                 f_tx = Some(Box::new(ChartText::from_xml(reader, &e, false)?));
@@ -28056,7 +28079,7 @@ impl FromXml for ChartTitle {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"tx" => {
                                 f_tx = Some(Box::new(ChartText::from_xml(reader, &e, true)?));
@@ -28150,7 +28173,7 @@ impl FromXml for ChartTitle {
 
 impl FromXml for RotX {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -28200,7 +28223,7 @@ impl FromXml for RotX {
 
 impl FromXml for HPercent {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -28250,7 +28273,7 @@ impl FromXml for HPercent {
 
 impl FromXml for RotY {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -28300,7 +28323,7 @@ impl FromXml for RotY {
 
 impl FromXml for DepthPercent {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -28350,7 +28373,7 @@ impl FromXml for DepthPercent {
 
 impl FromXml for Perspective {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -28400,7 +28423,7 @@ impl FromXml for Perspective {
 
 impl FromXml for View3D {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -28429,7 +28452,7 @@ impl FromXml for View3D {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"rotX" => {
                                 f_rot_x = Some(Box::new(RotX::from_xml(reader, &e, false)?));
@@ -28510,7 +28533,7 @@ impl FromXml for View3D {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"rotX" => {
                                 f_rot_x = Some(Box::new(RotX::from_xml(reader, &e, true)?));
@@ -28615,7 +28638,7 @@ impl FromXml for View3D {
 
 impl FromXml for ChartSurface {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -28638,7 +28661,7 @@ impl FromXml for ChartSurface {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"thickness" => {
                                 f_thickness =
@@ -28694,7 +28717,7 @@ impl FromXml for ChartSurface {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"thickness" => {
                                 f_thickness =
@@ -28770,7 +28793,7 @@ impl FromXml for ChartSurface {
 
 impl FromXml for ChartThickness {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -28820,7 +28843,7 @@ impl FromXml for ChartThickness {
 
 impl FromXml for DataTable {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -28849,7 +28872,7 @@ impl FromXml for DataTable {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"showHorzBorder" => {
                                 f_show_horz_border =
@@ -28931,7 +28954,7 @@ impl FromXml for DataTable {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"showHorzBorder" => {
                                 f_show_horz_border =
@@ -29039,7 +29062,7 @@ impl FromXml for DataTable {
 
 impl FromXml for GapAmount {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29089,7 +29112,7 @@ impl FromXml for GapAmount {
 
 impl FromXml for OverlapAmount {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29139,7 +29162,7 @@ impl FromXml for OverlapAmount {
 
 impl FromXml for BubbleScale {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29189,7 +29212,7 @@ impl FromXml for BubbleScale {
 
 impl FromXml for SizeRepresents {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29239,7 +29262,7 @@ impl FromXml for SizeRepresents {
 
 impl FromXml for FirstSliceAngle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29289,7 +29312,7 @@ impl FromXml for FirstSliceAngle {
 
 impl FromXml for HoleSize {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29339,7 +29362,7 @@ impl FromXml for HoleSize {
 
 impl FromXml for SplitType {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29389,7 +29412,7 @@ impl FromXml for SplitType {
 
 impl FromXml for CustomSplit {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29406,7 +29429,7 @@ impl FromXml for CustomSplit {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"secondPiePt" => {
                                 f_second_pie_pt.push(CTUnsignedInt::from_xml(reader, &e, false)?);
@@ -29433,7 +29456,7 @@ impl FromXml for CustomSplit {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"secondPiePt" => {
                                 f_second_pie_pt.push(CTUnsignedInt::from_xml(reader, &e, true)?);
@@ -29475,7 +29498,7 @@ impl FromXml for CustomSplit {
 
 impl FromXml for SecondPieSize {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29525,7 +29548,7 @@ impl FromXml for SecondPieSize {
 
 impl FromXml for ChartNumFmt {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29584,7 +29607,7 @@ impl FromXml for ChartNumFmt {
 
 impl FromXml for LabelAlignment {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29634,7 +29657,7 @@ impl FromXml for LabelAlignment {
 
 impl FromXml for DataLabelPosition {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29684,7 +29707,7 @@ impl FromXml for DataLabelPosition {
 
 impl FromXml for EGDLblShared {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29710,7 +29733,7 @@ impl FromXml for EGDLblShared {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"numFmt" => {
                                 f_num_fmt =
                                     Some(Box::new(ChartNumFmt::from_xml(reader, &e, false)?));
@@ -29815,7 +29838,7 @@ impl FromXml for EGDLblShared {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"numFmt" => {
                                 f_num_fmt =
                                     Some(Box::new(ChartNumFmt::from_xml(reader, &e, true)?));
@@ -29943,7 +29966,7 @@ impl FromXml for EGDLblShared {
 
 impl FromXml for DchrtGroupDLbl {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29971,7 +29994,7 @@ impl FromXml for DchrtGroupDLbl {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"layout" => {
                                 f_layout =
                                     Some(Box::new(ChartLayout::from_xml(reader, &e, false)?));
@@ -30091,7 +30114,7 @@ impl FromXml for DchrtGroupDLbl {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"layout" => {
                                 f_layout = Some(Box::new(ChartLayout::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -30235,7 +30258,7 @@ impl FromXml for DchrtGroupDLbl {
 
 impl FromXml for DataLabel {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -30282,7 +30305,7 @@ impl FromXml for DataLabel {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, false)?));
@@ -30441,7 +30464,7 @@ impl FromXml for DataLabel {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, true)?));
@@ -30642,7 +30665,7 @@ impl FromXml for DataLabel {
 
 impl FromXml for DchrtGroupDLbls {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -30670,7 +30693,7 @@ impl FromXml for DchrtGroupDLbls {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"numFmt" => {
                                 f_num_fmt =
                                     Some(Box::new(ChartNumFmt::from_xml(reader, &e, false)?));
@@ -30791,7 +30814,7 @@ impl FromXml for DchrtGroupDLbls {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"numFmt" => {
                                 f_num_fmt =
                                     Some(Box::new(ChartNumFmt::from_xml(reader, &e, true)?));
@@ -30937,7 +30960,7 @@ impl FromXml for DchrtGroupDLbls {
 
 impl FromXml for DataLabels {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -30984,7 +31007,7 @@ impl FromXml for DataLabels {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"dLbl" => {
                                 f_d_lbl.push(DataLabel::from_xml(reader, &e, false)?);
@@ -31144,7 +31167,7 @@ impl FromXml for DataLabels {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"dLbl" => {
                                 f_d_lbl.push(DataLabel::from_xml(reader, &e, true)?);
@@ -31347,7 +31370,7 @@ impl FromXml for DataLabels {
 
 impl FromXml for ChartMarkerStyle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31397,7 +31420,7 @@ impl FromXml for ChartMarkerStyle {
 
 impl FromXml for ChartMarkerSize {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31447,7 +31470,7 @@ impl FromXml for ChartMarkerSize {
 
 impl FromXml for ChartMarker {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31470,7 +31493,7 @@ impl FromXml for ChartMarker {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"symbol" => {
                                 f_symbol =
@@ -31526,7 +31549,7 @@ impl FromXml for ChartMarker {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"symbol" => {
                                 f_symbol =
@@ -31602,7 +31625,7 @@ impl FromXml for ChartMarker {
 
 impl FromXml for DataPoint {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31633,7 +31656,7 @@ impl FromXml for DataPoint {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, false)?));
@@ -31724,7 +31747,7 @@ impl FromXml for DataPoint {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, true)?));
@@ -31842,7 +31865,7 @@ impl FromXml for DataPoint {
 
 impl FromXml for TrendlineType {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31892,7 +31915,7 @@ impl FromXml for TrendlineType {
 
 impl FromXml for TrendlineOrder {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31942,7 +31965,7 @@ impl FromXml for TrendlineOrder {
 
 impl FromXml for TrendlinePeriod {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31992,7 +32015,7 @@ impl FromXml for TrendlinePeriod {
 
 impl FromXml for TrendlineLabel {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -32019,7 +32042,7 @@ impl FromXml for TrendlineLabel {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"layout" => {
                                 f_layout =
@@ -32091,7 +32114,7 @@ impl FromXml for TrendlineLabel {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"layout" => {
                                 f_layout = Some(Box::new(ChartLayout::from_xml(reader, &e, true)?));
@@ -32186,7 +32209,7 @@ impl FromXml for TrendlineLabel {
 
 impl FromXml for Trendline {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -32225,7 +32248,7 @@ impl FromXml for Trendline {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"name" => {
                                 f_name = Some(read_text_content(reader)?);
@@ -32349,7 +32372,7 @@ impl FromXml for Trendline {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"name" => {
                                 f_name = Some(String::new());
@@ -32509,7 +32532,7 @@ impl FromXml for Trendline {
 
 impl FromXml for ErrorDirection {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -32559,7 +32582,7 @@ impl FromXml for ErrorDirection {
 
 impl FromXml for ErrorBarType {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -32609,7 +32632,7 @@ impl FromXml for ErrorBarType {
 
 impl FromXml for ErrorValueType {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -32659,7 +32682,7 @@ impl FromXml for ErrorValueType {
 
 impl FromXml for ErrorBars {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -32692,7 +32715,7 @@ impl FromXml for ErrorBars {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"errDir" => {
                                 f_err_dir =
@@ -32792,7 +32815,7 @@ impl FromXml for ErrorBars {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"errDir" => {
                                 f_err_dir =
@@ -32924,7 +32947,7 @@ impl FromXml for ErrorBars {
 
 impl FromXml for UpDownBar {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -32941,7 +32964,7 @@ impl FromXml for UpDownBar {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"spPr" => {
                                 f_sp_pr =
@@ -32969,7 +32992,7 @@ impl FromXml for UpDownBar {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"spPr" => {
                                 f_sp_pr =
@@ -33012,7 +33035,7 @@ impl FromXml for UpDownBar {
 
 impl FromXml for UpDownBars {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -33035,7 +33058,7 @@ impl FromXml for UpDownBars {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"gapWidth" => {
                                 f_gap_width =
@@ -33090,7 +33113,7 @@ impl FromXml for UpDownBars {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"gapWidth" => {
                                 f_gap_width =
@@ -33165,7 +33188,7 @@ impl FromXml for UpDownBars {
 
 impl FromXml for EGSerShared {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -33184,7 +33207,7 @@ impl FromXml for EGSerShared {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -33233,7 +33256,7 @@ impl FromXml for EGSerShared {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -33299,7 +33322,7 @@ impl FromXml for EGSerShared {
 
 impl FromXml for LineSeries {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -33340,7 +33363,7 @@ impl FromXml for LineSeries {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, false)?));
@@ -33471,7 +33494,7 @@ impl FromXml for LineSeries {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, true)?));
@@ -33637,7 +33660,7 @@ impl FromXml for LineSeries {
 
 impl FromXml for ScatterSeries {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -33678,7 +33701,7 @@ impl FromXml for ScatterSeries {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, false)?));
@@ -33808,7 +33831,7 @@ impl FromXml for ScatterSeries {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, true)?));
@@ -33975,7 +33998,7 @@ impl FromXml for ScatterSeries {
 
 impl FromXml for RadarSeries {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -34010,7 +34033,7 @@ impl FromXml for RadarSeries {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, false)?));
@@ -34116,7 +34139,7 @@ impl FromXml for RadarSeries {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, true)?));
@@ -34252,7 +34275,7 @@ impl FromXml for RadarSeries {
 
 impl FromXml for BarSeries {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -34295,7 +34318,7 @@ impl FromXml for BarSeries {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, false)?));
@@ -34436,7 +34459,7 @@ impl FromXml for BarSeries {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, true)?));
@@ -34614,7 +34637,7 @@ impl FromXml for BarSeries {
 
 impl FromXml for AreaSeries {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -34653,7 +34676,7 @@ impl FromXml for AreaSeries {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, false)?));
@@ -34775,7 +34798,7 @@ impl FromXml for AreaSeries {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, true)?));
@@ -34932,7 +34955,7 @@ impl FromXml for AreaSeries {
 
 impl FromXml for PieSeries {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -34967,7 +34990,7 @@ impl FromXml for PieSeries {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, false)?));
@@ -35073,7 +35096,7 @@ impl FromXml for PieSeries {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, true)?));
@@ -35210,7 +35233,7 @@ impl FromXml for PieSeries {
 
 impl FromXml for BubbleSeries {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -35253,7 +35276,7 @@ impl FromXml for BubbleSeries {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, false)?));
@@ -35393,7 +35416,7 @@ impl FromXml for BubbleSeries {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, true)?));
@@ -35573,7 +35596,7 @@ impl FromXml for BubbleSeries {
 
 impl FromXml for SurfaceSeries {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -35602,7 +35625,7 @@ impl FromXml for SurfaceSeries {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, false)?));
@@ -35683,7 +35706,7 @@ impl FromXml for SurfaceSeries {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, true)?));
@@ -35789,7 +35812,7 @@ impl FromXml for SurfaceSeries {
 
 impl FromXml for ChartGrouping {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -35839,7 +35862,7 @@ impl FromXml for ChartGrouping {
 
 impl FromXml for ChartLines {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -35856,7 +35879,7 @@ impl FromXml for ChartLines {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"spPr" => {
                                 f_sp_pr =
@@ -35884,7 +35907,7 @@ impl FromXml for ChartLines {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"spPr" => {
                                 f_sp_pr =
@@ -35927,7 +35950,7 @@ impl FromXml for ChartLines {
 
 impl FromXml for EGLineChartShared {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -35947,7 +35970,7 @@ impl FromXml for EGLineChartShared {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"grouping" => {
                                 f_grouping =
                                     Some(Box::new(ChartGrouping::from_xml(reader, &e, false)?));
@@ -36004,7 +36027,7 @@ impl FromXml for EGLineChartShared {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"grouping" => {
                                 f_grouping =
                                     Some(Box::new(ChartGrouping::from_xml(reader, &e, true)?));
@@ -36080,7 +36103,7 @@ impl FromXml for EGLineChartShared {
 
 impl FromXml for LineChart {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -36117,7 +36140,7 @@ impl FromXml for LineChart {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"grouping" => {
                                 f_grouping =
@@ -36231,7 +36254,7 @@ impl FromXml for LineChart {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"grouping" => {
                                 f_grouping =
@@ -36380,7 +36403,7 @@ impl FromXml for LineChart {
 
 impl FromXml for Line3DChart {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -36411,7 +36434,7 @@ impl FromXml for Line3DChart {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"grouping" => {
                                 f_grouping =
@@ -36500,7 +36523,7 @@ impl FromXml for Line3DChart {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"grouping" => {
                                 f_grouping =
@@ -36618,7 +36641,7 @@ impl FromXml for Line3DChart {
 
 impl FromXml for StockChart {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -36647,7 +36670,7 @@ impl FromXml for StockChart {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"ser" => {
                                 f_ser.push(LineSeries::from_xml(reader, &e, false)?);
@@ -36727,7 +36750,7 @@ impl FromXml for StockChart {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"ser" => {
                                 f_ser.push(LineSeries::from_xml(reader, &e, true)?);
@@ -36833,7 +36856,7 @@ impl FromXml for StockChart {
 
 impl FromXml for ScatterStyle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -36883,7 +36906,7 @@ impl FromXml for ScatterStyle {
 
 impl FromXml for ScatterChart {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -36910,7 +36933,7 @@ impl FromXml for ScatterChart {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"scatterStyle" => {
                                 f_scatter_style =
@@ -36981,7 +37004,7 @@ impl FromXml for ScatterChart {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"scatterStyle" => {
                                 f_scatter_style =
@@ -37077,7 +37100,7 @@ impl FromXml for ScatterChart {
 
 impl FromXml for RadarStyle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -37127,7 +37150,7 @@ impl FromXml for RadarStyle {
 
 impl FromXml for RadarChart {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -37154,7 +37177,7 @@ impl FromXml for RadarChart {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"radarStyle" => {
                                 f_radar_style =
@@ -37225,7 +37248,7 @@ impl FromXml for RadarChart {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"radarStyle" => {
                                 f_radar_style =
@@ -37321,7 +37344,7 @@ impl FromXml for RadarChart {
 
 impl FromXml for BarGrouping {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -37371,7 +37394,7 @@ impl FromXml for BarGrouping {
 
 impl FromXml for BarDirection {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -37421,7 +37444,7 @@ impl FromXml for BarDirection {
 
 impl FromXml for DiagramShape {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -37471,7 +37494,7 @@ impl FromXml for DiagramShape {
 
 impl FromXml for EGBarChartShared {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -37491,7 +37514,7 @@ impl FromXml for EGBarChartShared {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"barDir" => {
                                 f_bar_dir =
                                     Some(Box::new(BarDirection::from_xml(reader, &e, false)?));
@@ -37548,7 +37571,7 @@ impl FromXml for EGBarChartShared {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"barDir" => {
                                 f_bar_dir =
                                     Some(Box::new(BarDirection::from_xml(reader, &e, true)?));
@@ -37623,7 +37646,7 @@ impl FromXml for EGBarChartShared {
 
 impl FromXml for BarChart {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -37658,7 +37681,7 @@ impl FromXml for BarChart {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"barDir" => {
                                 f_bar_dir =
@@ -37764,7 +37787,7 @@ impl FromXml for BarChart {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"barDir" => {
                                 f_bar_dir =
@@ -37902,7 +37925,7 @@ impl FromXml for BarChart {
 
 impl FromXml for Bar3DChart {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -37937,7 +37960,7 @@ impl FromXml for Bar3DChart {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"barDir" => {
                                 f_bar_dir =
@@ -38044,7 +38067,7 @@ impl FromXml for Bar3DChart {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"barDir" => {
                                 f_bar_dir =
@@ -38182,7 +38205,7 @@ impl FromXml for Bar3DChart {
 
 impl FromXml for EGAreaChartShared {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -38202,7 +38225,7 @@ impl FromXml for EGAreaChartShared {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"grouping" => {
                                 f_grouping =
                                     Some(Box::new(ChartGrouping::from_xml(reader, &e, false)?));
@@ -38259,7 +38282,7 @@ impl FromXml for EGAreaChartShared {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"grouping" => {
                                 f_grouping =
                                     Some(Box::new(ChartGrouping::from_xml(reader, &e, true)?));
@@ -38334,7 +38357,7 @@ impl FromXml for EGAreaChartShared {
 
 impl FromXml for AreaChart {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -38363,7 +38386,7 @@ impl FromXml for AreaChart {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"grouping" => {
                                 f_grouping =
@@ -38443,7 +38466,7 @@ impl FromXml for AreaChart {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"grouping" => {
                                 f_grouping =
@@ -38549,7 +38572,7 @@ impl FromXml for AreaChart {
 
 impl FromXml for Area3DChart {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -38580,7 +38603,7 @@ impl FromXml for Area3DChart {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"grouping" => {
                                 f_grouping =
@@ -38669,7 +38692,7 @@ impl FromXml for Area3DChart {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"grouping" => {
                                 f_grouping =
@@ -38786,7 +38809,7 @@ impl FromXml for Area3DChart {
 
 impl FromXml for EGPieChartShared {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -38804,7 +38827,7 @@ impl FromXml for EGPieChartShared {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"varyColors" => {
                                 f_vary_colors =
                                     Some(Box::new(CTBoolean::from_xml(reader, &e, false)?));
@@ -38845,7 +38868,7 @@ impl FromXml for EGPieChartShared {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"varyColors" => {
                                 f_vary_colors =
                                     Some(Box::new(CTBoolean::from_xml(reader, &e, true)?));
@@ -38902,7 +38925,7 @@ impl FromXml for EGPieChartShared {
 
 impl FromXml for PieChart {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -38927,7 +38950,7 @@ impl FromXml for PieChart {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"varyColors" => {
                                 f_vary_colors =
@@ -38990,7 +39013,7 @@ impl FromXml for PieChart {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"varyColors" => {
                                 f_vary_colors =
@@ -39075,7 +39098,7 @@ impl FromXml for PieChart {
 
 impl FromXml for Pie3DChart {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -39098,7 +39121,7 @@ impl FromXml for Pie3DChart {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"varyColors" => {
                                 f_vary_colors =
@@ -39152,7 +39175,7 @@ impl FromXml for Pie3DChart {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"varyColors" => {
                                 f_vary_colors =
@@ -39226,7 +39249,7 @@ impl FromXml for Pie3DChart {
 
 impl FromXml for DoughnutChart {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -39253,7 +39276,7 @@ impl FromXml for DoughnutChart {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"varyColors" => {
                                 f_vary_colors =
@@ -39325,7 +39348,7 @@ impl FromXml for DoughnutChart {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"varyColors" => {
                                 f_vary_colors =
@@ -39420,7 +39443,7 @@ impl FromXml for DoughnutChart {
 
 impl FromXml for OfPieType {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -39470,7 +39493,7 @@ impl FromXml for OfPieType {
 
 impl FromXml for OfPieChart {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -39507,7 +39530,7 @@ impl FromXml for OfPieChart {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"ofPieType" => {
                                 f_of_pie_type =
@@ -39623,7 +39646,7 @@ impl FromXml for OfPieChart {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"ofPieType" => {
                                 f_of_pie_type =
@@ -39773,7 +39796,7 @@ impl FromXml for OfPieChart {
 
 impl FromXml for BubbleChart {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -39806,7 +39829,7 @@ impl FromXml for BubbleChart {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"varyColors" => {
                                 f_vary_colors =
@@ -39904,7 +39927,7 @@ impl FromXml for BubbleChart {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"varyColors" => {
                                 f_vary_colors =
@@ -40032,7 +40055,7 @@ impl FromXml for BubbleChart {
 
 impl FromXml for BandFormat {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -40051,7 +40074,7 @@ impl FromXml for BandFormat {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, false)?));
@@ -40087,7 +40110,7 @@ impl FromXml for BandFormat {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, true)?));
@@ -40140,7 +40163,7 @@ impl FromXml for BandFormat {
 
 impl FromXml for BandFormats {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -40157,7 +40180,7 @@ impl FromXml for BandFormats {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"bandFmt" => {
                                 f_band_fmt.push(BandFormat::from_xml(reader, &e, false)?);
@@ -40184,7 +40207,7 @@ impl FromXml for BandFormats {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"bandFmt" => {
                                 f_band_fmt.push(BandFormat::from_xml(reader, &e, true)?);
@@ -40226,7 +40249,7 @@ impl FromXml for BandFormats {
 
 impl FromXml for EGSurfaceChartShared {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -40244,7 +40267,7 @@ impl FromXml for EGSurfaceChartShared {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"wireframe" => {
                                 f_wireframe =
                                     Some(Box::new(CTBoolean::from_xml(reader, &e, false)?));
@@ -40286,7 +40309,7 @@ impl FromXml for EGSurfaceChartShared {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"wireframe" => {
                                 f_wireframe =
                                     Some(Box::new(CTBoolean::from_xml(reader, &e, true)?));
@@ -40344,7 +40367,7 @@ impl FromXml for EGSurfaceChartShared {
 
 impl FromXml for SurfaceChart {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -40369,7 +40392,7 @@ impl FromXml for SurfaceChart {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"wireframe" => {
                                 f_wireframe =
@@ -40432,7 +40455,7 @@ impl FromXml for SurfaceChart {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"wireframe" => {
                                 f_wireframe =
@@ -40517,7 +40540,7 @@ impl FromXml for SurfaceChart {
 
 impl FromXml for Surface3DChart {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -40542,7 +40565,7 @@ impl FromXml for Surface3DChart {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"wireframe" => {
                                 f_wireframe =
@@ -40605,7 +40628,7 @@ impl FromXml for Surface3DChart {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"wireframe" => {
                                 f_wireframe =
@@ -40690,7 +40713,7 @@ impl FromXml for Surface3DChart {
 
 impl FromXml for AxisPosition {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -40740,7 +40763,7 @@ impl FromXml for AxisPosition {
 
 impl FromXml for AxisCrosses {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -40790,7 +40813,7 @@ impl FromXml for AxisCrosses {
 
 impl FromXml for CrossBetween {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -40840,7 +40863,7 @@ impl FromXml for CrossBetween {
 
 impl FromXml for TickMark {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -40890,7 +40913,7 @@ impl FromXml for TickMark {
 
 impl FromXml for TickLabelPosition {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -40940,7 +40963,7 @@ impl FromXml for TickLabelPosition {
 
 impl FromXml for AxisSkip {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -40990,7 +41013,7 @@ impl FromXml for AxisSkip {
 
 impl FromXml for TimeUnit {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -41040,7 +41063,7 @@ impl FromXml for TimeUnit {
 
 impl FromXml for AxisUnit {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -41090,7 +41113,7 @@ impl FromXml for AxisUnit {
 
 impl FromXml for BuiltInUnit {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -41140,7 +41163,7 @@ impl FromXml for BuiltInUnit {
 
 impl FromXml for ChartPictureFormat {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -41190,7 +41213,7 @@ impl FromXml for ChartPictureFormat {
 
 impl FromXml for PictureStackUnit {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -41240,7 +41263,7 @@ impl FromXml for PictureStackUnit {
 
 impl FromXml for PictureOptions {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -41265,7 +41288,7 @@ impl FromXml for PictureOptions {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"applyToFront" => {
                                 f_apply_to_front =
@@ -41330,7 +41353,7 @@ impl FromXml for PictureOptions {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"applyToFront" => {
                                 f_apply_to_front =
@@ -41417,7 +41440,7 @@ impl FromXml for PictureOptions {
 
 impl FromXml for DisplayUnitsLabel {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -41440,7 +41463,7 @@ impl FromXml for DisplayUnitsLabel {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"layout" => {
                                 f_layout =
@@ -41493,7 +41516,7 @@ impl FromXml for DisplayUnitsLabel {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"layout" => {
                                 f_layout = Some(Box::new(ChartLayout::from_xml(reader, &e, true)?));
@@ -41566,7 +41589,7 @@ impl FromXml for DisplayUnitsLabel {
 
 impl FromXml for DisplayUnits {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -41589,7 +41612,7 @@ impl FromXml for DisplayUnits {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"custUnit" => {
                                 f_cust_unit =
@@ -41645,7 +41668,7 @@ impl FromXml for DisplayUnits {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"custUnit" => {
                                 f_cust_unit = Some(Box::new(CTDouble::from_xml(reader, &e, true)?));
@@ -41720,7 +41743,7 @@ impl FromXml for DisplayUnits {
 
 impl FromXml for AxisOrientation {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -41770,7 +41793,7 @@ impl FromXml for AxisOrientation {
 
 impl FromXml for LogBase {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -41820,7 +41843,7 @@ impl FromXml for LogBase {
 
 impl FromXml for AxisScaling {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -41845,7 +41868,7 @@ impl FromXml for AxisScaling {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"logBase" => {
                                 f_log_base = Some(Box::new(LogBase::from_xml(reader, &e, false)?));
@@ -41907,7 +41930,7 @@ impl FromXml for AxisScaling {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"logBase" => {
                                 f_log_base = Some(Box::new(LogBase::from_xml(reader, &e, true)?));
@@ -41991,7 +42014,7 @@ impl FromXml for AxisScaling {
 
 impl FromXml for LabelOffset {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -42041,7 +42064,7 @@ impl FromXml for LabelOffset {
 
 impl FromXml for EGAxShared {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -42072,7 +42095,7 @@ impl FromXml for EGAxShared {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"axId" => {
                                 f_ax_id =
                                     Some(Box::new(CTUnsignedInt::from_xml(reader, &e, false)?));
@@ -42216,7 +42239,7 @@ impl FromXml for EGAxShared {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"axId" => {
                                 f_ax_id =
                                     Some(Box::new(CTUnsignedInt::from_xml(reader, &e, true)?));
@@ -42391,7 +42414,7 @@ impl FromXml for EGAxShared {
 
 impl FromXml for CategoryAxis {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -42452,7 +42475,7 @@ impl FromXml for CategoryAxis {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"axId" => {
                                 f_ax_id =
@@ -42675,7 +42698,7 @@ impl FromXml for CategoryAxis {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"axId" => {
                                 f_ax_id =
@@ -42958,7 +42981,7 @@ impl FromXml for CategoryAxis {
 
 impl FromXml for DateAxis {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -43021,7 +43044,7 @@ impl FromXml for DateAxis {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"axId" => {
                                 f_ax_id =
@@ -43253,7 +43276,7 @@ impl FromXml for DateAxis {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"axId" => {
                                 f_ax_id =
@@ -43547,7 +43570,7 @@ impl FromXml for DateAxis {
 
 impl FromXml for SeriesAxis {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -43600,7 +43623,7 @@ impl FromXml for SeriesAxis {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"axId" => {
                                 f_ax_id =
@@ -43788,7 +43811,7 @@ impl FromXml for SeriesAxis {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"axId" => {
                                 f_ax_id =
@@ -44028,7 +44051,7 @@ impl FromXml for SeriesAxis {
 
 impl FromXml for ValueAxis {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -44085,7 +44108,7 @@ impl FromXml for ValueAxis {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"axId" => {
                                 f_ax_id =
@@ -44291,7 +44314,7 @@ impl FromXml for ValueAxis {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"axId" => {
                                 f_ax_id =
@@ -44553,7 +44576,7 @@ impl FromXml for ValueAxis {
 
 impl FromXml for PlotArea {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -44616,7 +44639,7 @@ impl FromXml for PlotArea {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"layout" => {
                                 f_layout =
@@ -44832,7 +44855,7 @@ impl FromXml for PlotArea {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"layout" => {
                                 f_layout = Some(Box::new(ChartLayout::from_xml(reader, &e, true)?));
@@ -45107,7 +45130,7 @@ impl FromXml for PlotArea {
 
 impl FromXml for PivotFormat {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -45134,7 +45157,7 @@ impl FromXml for PivotFormat {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, false)?));
@@ -45205,7 +45228,7 @@ impl FromXml for PivotFormat {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, true)?));
@@ -45299,7 +45322,7 @@ impl FromXml for PivotFormat {
 
 impl FromXml for PivotFormats {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -45316,7 +45339,7 @@ impl FromXml for PivotFormats {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"pivotFmt" => {
                                 f_pivot_fmt.push(PivotFormat::from_xml(reader, &e, false)?);
@@ -45343,7 +45366,7 @@ impl FromXml for PivotFormats {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"pivotFmt" => {
                                 f_pivot_fmt.push(PivotFormat::from_xml(reader, &e, true)?);
@@ -45385,7 +45408,7 @@ impl FromXml for PivotFormats {
 
 impl FromXml for LegendPosition {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -45435,7 +45458,7 @@ impl FromXml for LegendPosition {
 
 impl FromXml for EGLegendEntryData {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -45451,7 +45474,7 @@ impl FromXml for EGLegendEntryData {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"txPr" => {
                                 f_tx_pr = Some(Box::new(TextBody::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -45477,7 +45500,7 @@ impl FromXml for EGLegendEntryData {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"txPr" => {
                                 f_tx_pr = Some(Box::new(TextBody::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -45517,7 +45540,7 @@ impl FromXml for EGLegendEntryData {
 
 impl FromXml for LegendEntry {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -45540,7 +45563,7 @@ impl FromXml for LegendEntry {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, false)?));
@@ -45593,7 +45616,7 @@ impl FromXml for LegendEntry {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"idx" => {
                                 f_idx = Some(Box::new(CTUnsignedInt::from_xml(reader, &e, true)?));
@@ -45666,7 +45689,7 @@ impl FromXml for LegendEntry {
 
 impl FromXml for Legend {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -45695,7 +45718,7 @@ impl FromXml for Legend {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"legendPos" => {
                                 f_legend_pos =
@@ -45775,7 +45798,7 @@ impl FromXml for Legend {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"legendPos" => {
                                 f_legend_pos =
@@ -45880,7 +45903,7 @@ impl FromXml for Legend {
 
 impl FromXml for DisplayBlanksAs {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -45930,7 +45953,7 @@ impl FromXml for DisplayBlanksAs {
 
 impl FromXml for Chart {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -45971,7 +45994,7 @@ impl FromXml for Chart {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"title" => {
                                 f_title = Some(Box::new(ChartTitle::from_xml(reader, &e, false)?));
@@ -46105,7 +46128,7 @@ impl FromXml for Chart {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"title" => {
                                 f_title = Some(Box::new(ChartTitle::from_xml(reader, &e, true)?));
@@ -46276,7 +46299,7 @@ impl FromXml for Chart {
 
 impl FromXml for ChartStyle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -46326,7 +46349,7 @@ impl FromXml for ChartStyle {
 
 impl FromXml for PivotSource {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -46347,7 +46370,7 @@ impl FromXml for PivotSource {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"name" => {
                                 f_name = Some(read_text_content(reader)?);
@@ -46391,7 +46414,7 @@ impl FromXml for PivotSource {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"name" => {
                                 f_name = Some(String::new());
@@ -46454,7 +46477,7 @@ impl FromXml for PivotSource {
 
 impl FromXml for ChartProtection {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -46479,7 +46502,7 @@ impl FromXml for ChartProtection {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"chartObject" => {
                                 f_chart_object =
@@ -46542,7 +46565,7 @@ impl FromXml for ChartProtection {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"chartObject" => {
                                 f_chart_object =
@@ -46628,7 +46651,7 @@ impl FromXml for ChartProtection {
 
 impl FromXml for ChartHeaderFooter {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -46689,7 +46712,7 @@ impl FromXml for ChartHeaderFooter {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"oddHeader" => {
                                 f_odd_header = Some(read_text_content(reader)?);
@@ -46756,7 +46779,7 @@ impl FromXml for ChartHeaderFooter {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"oddHeader" => {
                                 f_odd_header = Some(String::new());
@@ -46856,7 +46879,7 @@ impl FromXml for ChartHeaderFooter {
 
 impl FromXml for ChartPageMargins {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -46947,7 +46970,7 @@ impl FromXml for ChartPageMargins {
 
 impl FromXml for ExternalData {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -46986,7 +47009,7 @@ impl FromXml for ExternalData {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"autoUpdate" => {
                                 f_auto_update =
@@ -47014,7 +47037,7 @@ impl FromXml for ExternalData {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"autoUpdate" => {
                                 f_auto_update =
@@ -47061,7 +47084,7 @@ impl FromXml for ExternalData {
 
 impl FromXml for ChartPageSetup {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -47191,7 +47214,7 @@ impl FromXml for ChartPageSetup {
 
 impl FromXml for PrintSettings {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -47214,7 +47237,7 @@ impl FromXml for PrintSettings {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"headerFooter" => {
                                 f_header_footer =
@@ -47269,7 +47292,7 @@ impl FromXml for PrintSettings {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"headerFooter" => {
                                 f_header_footer =
@@ -47345,7 +47368,7 @@ impl FromXml for PrintSettings {
 
 impl FromXml for ChartSpace {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -47388,7 +47411,7 @@ impl FromXml for ChartSpace {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"date1904" => {
                                 f_date1904 =
@@ -47531,7 +47554,7 @@ impl FromXml for ChartSpace {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-charts")]
                             b"date1904" => {
                                 f_date1904 = Some(Box::new(CTBoolean::from_xml(reader, &e, true)?));
@@ -47713,7 +47736,7 @@ impl FromXml for ChartSpace {
 
 impl FromXml for ColorTransformName {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -47771,7 +47794,7 @@ impl FromXml for ColorTransformName {
 
 impl FromXml for ColorTransformDescription {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -47829,7 +47852,7 @@ impl FromXml for ColorTransformDescription {
 
 impl FromXml for DiagramColorCategory {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -47887,7 +47910,7 @@ impl FromXml for DiagramColorCategory {
 
 impl FromXml for DiagramColorCategories {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -47904,7 +47927,7 @@ impl FromXml for DiagramColorCategories {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"cat" => {
                                 f_cat.push(DiagramColorCategory::from_xml(reader, &e, false)?);
@@ -47931,7 +47954,7 @@ impl FromXml for DiagramColorCategories {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"cat" => {
                                 f_cat.push(DiagramColorCategory::from_xml(reader, &e, true)?);
@@ -47973,7 +47996,7 @@ impl FromXml for DiagramColorCategories {
 
 impl FromXml for DiagramColors {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -48018,7 +48041,7 @@ impl FromXml for DiagramColors {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
@@ -48046,7 +48069,7 @@ impl FromXml for DiagramColors {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"scrgbClr" | b"srgbClr" | b"hslClr" | b"sysClr" | b"schemeClr"
                             | b"prstClr" => {
@@ -48095,7 +48118,7 @@ impl FromXml for DiagramColors {
 
 impl FromXml for DiagramColorStyleLabel {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -48146,7 +48169,7 @@ impl FromXml for DiagramColorStyleLabel {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"fillClrLst" => {
                                 f_fill_clr_lst =
@@ -48229,7 +48252,7 @@ impl FromXml for DiagramColorStyleLabel {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"fillClrLst" => {
                                 f_fill_clr_lst =
@@ -48343,7 +48366,7 @@ impl FromXml for DiagramColorStyleLabel {
 
 impl FromXml for DiagramColorTransform {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -48396,7 +48419,7 @@ impl FromXml for DiagramColorTransform {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"title" => {
                                 f_title.push(ColorTransformName::from_xml(reader, &e, false)?);
@@ -48461,7 +48484,7 @@ impl FromXml for DiagramColorTransform {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"title" => {
                                 f_title.push(ColorTransformName::from_xml(reader, &e, true)?);
@@ -48554,7 +48577,7 @@ impl FromXml for DiagramColorTransform {
 
 impl FromXml for DiagramColorTransformHeader {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -48611,7 +48634,7 @@ impl FromXml for DiagramColorTransformHeader {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"title" => {
                                 f_title.push(ColorTransformName::from_xml(reader, &e, false)?);
@@ -48667,7 +48690,7 @@ impl FromXml for DiagramColorTransformHeader {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"title" => {
                                 f_title.push(ColorTransformName::from_xml(reader, &e, true)?);
@@ -48752,7 +48775,7 @@ impl FromXml for DiagramColorTransformHeader {
 
 impl FromXml for DiagramColorTransformHeaderList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -48769,7 +48792,7 @@ impl FromXml for DiagramColorTransformHeaderList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"colorsDefHdr" => {
                                 f_colors_def_hdr.push(DiagramColorTransformHeader::from_xml(
@@ -48798,7 +48821,7 @@ impl FromXml for DiagramColorTransformHeaderList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"colorsDefHdr" => {
                                 f_colors_def_hdr
@@ -48841,7 +48864,7 @@ impl FromXml for DiagramColorTransformHeaderList {
 
 impl FromXml for DiagramPoint {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -48898,7 +48921,7 @@ impl FromXml for DiagramPoint {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"prSet" => {
                                 f_pr_set = Some(Box::new(DiagramElementProperties::from_xml(
@@ -48954,7 +48977,7 @@ impl FromXml for DiagramPoint {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"prSet" => {
                                 f_pr_set = Some(Box::new(DiagramElementProperties::from_xml(
@@ -49040,7 +49063,7 @@ impl FromXml for DiagramPoint {
 
 impl FromXml for DiagramPointList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -49057,7 +49080,7 @@ impl FromXml for DiagramPointList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"pt" => {
                                 f_pt.push(DiagramPoint::from_xml(reader, &e, false)?);
@@ -49084,7 +49107,7 @@ impl FromXml for DiagramPointList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"pt" => {
                                 f_pt.push(DiagramPoint::from_xml(reader, &e, true)?);
@@ -49126,7 +49149,7 @@ impl FromXml for DiagramPointList {
 
 impl FromXml for DiagramConnection {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -49213,7 +49236,7 @@ impl FromXml for DiagramConnection {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
@@ -49242,7 +49265,7 @@ impl FromXml for DiagramConnection {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
@@ -49308,7 +49331,7 @@ impl FromXml for DiagramConnection {
 
 impl FromXml for DiagramConnectionList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -49325,7 +49348,7 @@ impl FromXml for DiagramConnectionList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"cxn" => {
                                 f_cxn.push(DiagramConnection::from_xml(reader, &e, false)?);
@@ -49352,7 +49375,7 @@ impl FromXml for DiagramConnectionList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"cxn" => {
                                 f_cxn.push(DiagramConnection::from_xml(reader, &e, true)?);
@@ -49394,7 +49417,7 @@ impl FromXml for DiagramConnectionList {
 
 impl FromXml for DataModel {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -49419,7 +49442,7 @@ impl FromXml for DataModel {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"ptLst" => {
                                 f_pt_lst =
@@ -49487,7 +49510,7 @@ impl FromXml for DataModel {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"ptLst" => {
                                 f_pt_lst =
@@ -49578,7 +49601,7 @@ impl FromXml for DataModel {
 
 impl FromXml for DdgrmAGIteratorAttributes {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -49650,7 +49673,7 @@ impl FromXml for DdgrmAGIteratorAttributes {
 
 impl FromXml for DdgrmAGConstraintAttributes {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -49712,7 +49735,7 @@ impl FromXml for DdgrmAGConstraintAttributes {
 
 impl FromXml for DdgrmAGConstraintRefAttributes {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -49774,7 +49797,7 @@ impl FromXml for DdgrmAGConstraintRefAttributes {
 
 impl FromXml for LayoutConstraint {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -49873,7 +49896,7 @@ impl FromXml for LayoutConstraint {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
@@ -49902,7 +49925,7 @@ impl FromXml for LayoutConstraint {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
@@ -49970,7 +49993,7 @@ impl FromXml for LayoutConstraint {
 
 impl FromXml for LayoutConstraints {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -49987,7 +50010,7 @@ impl FromXml for LayoutConstraints {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"constr" => {
                                 f_constr.push(LayoutConstraint::from_xml(reader, &e, false)?);
@@ -50014,7 +50037,7 @@ impl FromXml for LayoutConstraints {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"constr" => {
                                 f_constr.push(LayoutConstraint::from_xml(reader, &e, true)?);
@@ -50056,7 +50079,7 @@ impl FromXml for LayoutConstraints {
 
 impl FromXml for NumericRule {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -50131,7 +50154,7 @@ impl FromXml for NumericRule {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
@@ -50160,7 +50183,7 @@ impl FromXml for NumericRule {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
@@ -50220,7 +50243,7 @@ impl FromXml for NumericRule {
 
 impl FromXml for LayoutRules {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -50237,7 +50260,7 @@ impl FromXml for LayoutRules {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"rule" => {
                                 f_rule.push(NumericRule::from_xml(reader, &e, false)?);
@@ -50264,7 +50287,7 @@ impl FromXml for LayoutRules {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"rule" => {
                                 f_rule.push(NumericRule::from_xml(reader, &e, true)?);
@@ -50306,7 +50329,7 @@ impl FromXml for LayoutRules {
 
 impl FromXml for PresentationOf {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -50375,7 +50398,7 @@ impl FromXml for PresentationOf {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
@@ -50404,7 +50427,7 @@ impl FromXml for PresentationOf {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTOfficeArtExtensionList::from_xml(
@@ -50462,7 +50485,7 @@ impl FromXml for PresentationOf {
 
 impl FromXml for LayoutAdjustment {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -50520,7 +50543,7 @@ impl FromXml for LayoutAdjustment {
 
 impl FromXml for LayoutAdjustmentList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -50537,7 +50560,7 @@ impl FromXml for LayoutAdjustmentList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"adj" => {
                                 f_adj.push(LayoutAdjustment::from_xml(reader, &e, false)?);
@@ -50564,7 +50587,7 @@ impl FromXml for LayoutAdjustmentList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"adj" => {
                                 f_adj.push(LayoutAdjustment::from_xml(reader, &e, true)?);
@@ -50606,7 +50629,7 @@ impl FromXml for LayoutAdjustmentList {
 
 impl FromXml for AlgorithmParameter {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -50664,7 +50687,7 @@ impl FromXml for AlgorithmParameter {
 
 impl FromXml for LayoutAlgorithm {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -50711,7 +50734,7 @@ impl FromXml for LayoutAlgorithm {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"param" => {
                                 f_param.push(AlgorithmParameter::from_xml(reader, &e, false)?);
@@ -50748,7 +50771,7 @@ impl FromXml for LayoutAlgorithm {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"param" => {
                                 f_param.push(AlgorithmParameter::from_xml(reader, &e, true)?);
@@ -50808,7 +50831,7 @@ impl FromXml for LayoutAlgorithm {
 
 impl FromXml for LayoutNode {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -50883,7 +50906,7 @@ impl FromXml for LayoutNode {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"alg" => {
                                 f_alg =
@@ -50991,7 +51014,7 @@ impl FromXml for LayoutNode {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"alg" => {
                                 f_alg =
@@ -51141,7 +51164,7 @@ impl FromXml for LayoutNode {
 
 impl FromXml for LayoutForEach {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -51238,7 +51261,7 @@ impl FromXml for LayoutForEach {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"alg" => {
                                 f_alg =
@@ -51336,7 +51359,7 @@ impl FromXml for LayoutForEach {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"alg" => {
                                 f_alg =
@@ -51482,7 +51505,7 @@ impl FromXml for LayoutForEach {
 
 impl FromXml for LayoutWhen {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -51597,7 +51620,7 @@ impl FromXml for LayoutWhen {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"alg" => {
                                 f_alg =
@@ -51695,7 +51718,7 @@ impl FromXml for LayoutWhen {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"alg" => {
                                 f_alg =
@@ -51847,7 +51870,7 @@ impl FromXml for LayoutWhen {
 
 impl FromXml for LayoutOtherwise {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -51902,7 +51925,7 @@ impl FromXml for LayoutOtherwise {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"alg" => {
                                 f_alg =
@@ -52000,7 +52023,7 @@ impl FromXml for LayoutOtherwise {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"alg" => {
                                 f_alg =
@@ -52132,7 +52155,7 @@ impl FromXml for LayoutOtherwise {
 
 impl FromXml for LayoutChoose {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -52173,7 +52196,7 @@ impl FromXml for LayoutChoose {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"if" => {
                                 f_if.push(LayoutWhen::from_xml(reader, &e, false)?);
@@ -52209,7 +52232,7 @@ impl FromXml for LayoutChoose {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"if" => {
                                 f_if.push(LayoutWhen::from_xml(reader, &e, true)?);
@@ -52266,7 +52289,7 @@ impl FromXml for LayoutChoose {
 
 impl FromXml for DiagramSampleData {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -52305,7 +52328,7 @@ impl FromXml for DiagramSampleData {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"dataModel" => {
                                 f_data_model =
@@ -52333,7 +52356,7 @@ impl FromXml for DiagramSampleData {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"dataModel" => {
                                 f_data_model =
@@ -52380,7 +52403,7 @@ impl FromXml for DiagramSampleData {
 
 impl FromXml for DiagramCategory {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -52438,7 +52461,7 @@ impl FromXml for DiagramCategory {
 
 impl FromXml for DiagramCategories {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -52455,7 +52478,7 @@ impl FromXml for DiagramCategories {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"cat" => {
                                 f_cat.push(DiagramCategory::from_xml(reader, &e, false)?);
@@ -52482,7 +52505,7 @@ impl FromXml for DiagramCategories {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"cat" => {
                                 f_cat.push(DiagramCategory::from_xml(reader, &e, true)?);
@@ -52524,7 +52547,7 @@ impl FromXml for DiagramCategories {
 
 impl FromXml for DiagramName {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -52582,7 +52605,7 @@ impl FromXml for DiagramName {
 
 impl FromXml for DiagramDescription {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -52640,7 +52663,7 @@ impl FromXml for DiagramDescription {
 
 impl FromXml for DiagramDefinition {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -52705,7 +52728,7 @@ impl FromXml for DiagramDefinition {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"title" => {
                                 f_title.push(DiagramName::from_xml(reader, &e, false)?);
@@ -52795,7 +52818,7 @@ impl FromXml for DiagramDefinition {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"title" => {
                                 f_title.push(DiagramName::from_xml(reader, &e, true)?);
@@ -52923,7 +52946,7 @@ impl FromXml for DiagramDefinition {
 
 impl FromXml for DiagramDefinitionHeader {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -52986,7 +53009,7 @@ impl FromXml for DiagramDefinitionHeader {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"title" => {
                                 f_title.push(DiagramName::from_xml(reader, &e, false)?);
@@ -53040,7 +53063,7 @@ impl FromXml for DiagramDefinitionHeader {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"title" => {
                                 f_title.push(DiagramName::from_xml(reader, &e, true)?);
@@ -53126,7 +53149,7 @@ impl FromXml for DiagramDefinitionHeader {
 
 impl FromXml for DiagramDefinitionHeaderList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -53143,7 +53166,7 @@ impl FromXml for DiagramDefinitionHeaderList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"layoutDefHdr" => {
                                 f_layout_def_hdr
@@ -53171,7 +53194,7 @@ impl FromXml for DiagramDefinitionHeaderList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"layoutDefHdr" => {
                                 f_layout_def_hdr
@@ -53214,7 +53237,7 @@ impl FromXml for DiagramDefinitionHeaderList {
 
 impl FromXml for DiagramRelationshipIds {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -53288,7 +53311,7 @@ impl FromXml for DiagramRelationshipIds {
 
 impl FromXml for DiagramElementProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -53491,7 +53514,7 @@ impl FromXml for DiagramElementProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"presLayoutVars" => {
                                 f_pres_layout_vars = Some(Box::new(
@@ -53528,7 +53551,7 @@ impl FromXml for DiagramElementProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"presLayoutVars" => {
                                 f_pres_layout_vars = Some(Box::new(
@@ -53640,7 +53663,7 @@ impl FromXml for DiagramElementProperties {
 
 impl FromXml for OrgChartProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -53690,7 +53713,7 @@ impl FromXml for OrgChartProperties {
 
 impl FromXml for ChildMaximum {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -53740,7 +53763,7 @@ impl FromXml for ChildMaximum {
 
 impl FromXml for ChildPreference {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -53790,7 +53813,7 @@ impl FromXml for ChildPreference {
 
 impl FromXml for BulletEnabled {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -53840,7 +53863,7 @@ impl FromXml for BulletEnabled {
 
 impl FromXml for LayoutDirection {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -53890,7 +53913,7 @@ impl FromXml for LayoutDirection {
 
 impl FromXml for HierarchyBranchStyle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -53940,7 +53963,7 @@ impl FromXml for HierarchyBranchStyle {
 
 impl FromXml for AnimateOneByOne {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -53990,7 +54013,7 @@ impl FromXml for AnimateOneByOne {
 
 impl FromXml for AnimateLevel {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -54040,7 +54063,7 @@ impl FromXml for AnimateLevel {
 
 impl FromXml for ResizeHandles {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -54090,7 +54113,7 @@ impl FromXml for ResizeHandles {
 
 impl FromXml for LayoutVariableProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -54123,7 +54146,7 @@ impl FromXml for LayoutVariableProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"orgChart" => {
                                 f_org_chart = Some(Box::new(OrgChartProperties::from_xml(
@@ -54225,7 +54248,7 @@ impl FromXml for LayoutVariableProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"orgChart" => {
                                 f_org_chart =
@@ -54357,7 +54380,7 @@ impl FromXml for LayoutVariableProperties {
 
 impl FromXml for StyleDefinitionName {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -54415,7 +54438,7 @@ impl FromXml for StyleDefinitionName {
 
 impl FromXml for StyleDefinitionDescription {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -54473,7 +54496,7 @@ impl FromXml for StyleDefinitionDescription {
 
 impl FromXml for DiagramStyleCategory {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -54531,7 +54554,7 @@ impl FromXml for DiagramStyleCategory {
 
 impl FromXml for DiagramStyleCategories {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -54548,7 +54571,7 @@ impl FromXml for DiagramStyleCategories {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"cat" => {
                                 f_cat.push(DiagramStyleCategory::from_xml(reader, &e, false)?);
@@ -54575,7 +54598,7 @@ impl FromXml for DiagramStyleCategories {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"cat" => {
                                 f_cat.push(DiagramStyleCategory::from_xml(reader, &e, true)?);
@@ -54617,7 +54640,7 @@ impl FromXml for DiagramStyleCategories {
 
 impl FromXml for DiagramTextProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -54634,7 +54657,7 @@ impl FromXml for DiagramTextProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"sp3d" | b"flatTx" => {
                                 f_text3_d = Some(Box::new(EGText3D::from_xml(reader, &e, false)?));
@@ -54661,7 +54684,7 @@ impl FromXml for DiagramTextProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"sp3d" | b"flatTx" => {
                                 f_text3_d = Some(Box::new(EGText3D::from_xml(reader, &e, true)?));
@@ -54703,7 +54726,7 @@ impl FromXml for DiagramTextProperties {
 
 impl FromXml for DiagramStyleLabel {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -54750,7 +54773,7 @@ impl FromXml for DiagramStyleLabel {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"scene3d" => {
                                 f_scene3d = Some(Box::new(CTScene3D::from_xml(reader, &e, false)?));
@@ -54813,7 +54836,7 @@ impl FromXml for DiagramStyleLabel {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"scene3d" => {
                                 f_scene3d = Some(Box::new(CTScene3D::from_xml(reader, &e, true)?));
@@ -54903,7 +54926,7 @@ impl FromXml for DiagramStyleLabel {
 
 impl FromXml for DiagramStyleDefinition {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -54958,7 +54981,7 @@ impl FromXml for DiagramStyleDefinition {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"title" => {
                                 f_title.push(StyleDefinitionName::from_xml(reader, &e, false)?);
@@ -55030,7 +55053,7 @@ impl FromXml for DiagramStyleDefinition {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"title" => {
                                 f_title.push(StyleDefinitionName::from_xml(reader, &e, true)?);
@@ -55133,7 +55156,7 @@ impl FromXml for DiagramStyleDefinition {
 
 impl FromXml for DiagramStyleDefinitionHeader {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -55190,7 +55213,7 @@ impl FromXml for DiagramStyleDefinitionHeader {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"title" => {
                                 f_title.push(StyleDefinitionName::from_xml(reader, &e, false)?);
@@ -55246,7 +55269,7 @@ impl FromXml for DiagramStyleDefinitionHeader {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"title" => {
                                 f_title.push(StyleDefinitionName::from_xml(reader, &e, true)?);
@@ -55332,7 +55355,7 @@ impl FromXml for DiagramStyleDefinitionHeader {
 
 impl FromXml for DiagramStyleDefinitionHeaderList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -55349,7 +55372,7 @@ impl FromXml for DiagramStyleDefinitionHeaderList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"styleDefHdr" => {
                                 f_style_def_hdr.push(DiagramStyleDefinitionHeader::from_xml(
@@ -55378,7 +55401,7 @@ impl FromXml for DiagramStyleDefinitionHeaderList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "dml-diagrams")]
                             b"styleDefHdr" => {
                                 f_style_def_hdr.push(DiagramStyleDefinitionHeader::from_xml(
@@ -56,6 +56,25 @@ pub mod generated_serializers;
 /// Serializers for the generated ECMA-376 types. See [`generated_serializers`] for details.
 pub use generated_serializers as serializers;
 
+/// `Visit`/`VisitMut` traversal of the generated types.
+///
+/// Each trait has a method per generated type that defaults to visiting its
+/// fields, variants and unknown children (as raw XML) in declaration order;
+/// override the methods of interest and call the matching `walk_*` function
+/// to keep descending.
+///
+/// The module also implements [`ooxml_xml::QueryTree`] for the generated
+/// types, so [`ooxml_xml::Query::select_tree`] can run path queries over them
+/// as the XML they serialize to. Derived from [`generated`] and
+/// [`generated_serializers`] by `ooxml-codegen`.
+///
+/// Re-exported as [`visit`].
+#[cfg(feature = "visit")]
+pub mod generated_visit;
+/// Traversal of the generated ECMA-376 types. See [`generated_visit`] for details.
+#[cfg(feature = "visit")]
+pub use generated_visit as visit;
+
 pub use error::{Error, Result};
 #[cfg(feature = "dml-diagrams")]
 pub use ext::DataModelExt;
@@ -6,13 +6,13 @@
 
 use ooxml_dml::parsers::{FromXml, ParseError};
 use ooxml_dml::types::*;
-use quick_xml::Reader;
+use quick_xml::NsReader;
 use quick_xml::events::Event;
 use std::io::Cursor;
 
 /// Helper to parse an XML string using the FromXml trait.
 fn parse_from_xml<T: FromXml>(xml: &str) -> Result<T, ParseError> {
-    let mut reader = Reader::from_reader(Cursor::new(xml.as_bytes()));
+    let mut reader = NsReader::from_reader(Cursor::new(xml.as_bytes()));
     let mut buf = Vec::new();
     loop {
         match reader.read_event_into(&mut buf)? {
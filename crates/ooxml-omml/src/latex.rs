@@ -0,0 +1,601 @@
+//! LaTeX output for math zones.
+//!
+//! [`MathZone::to_latex`] writes the LaTeX math-mode source of an equation,
+//! as understood by MathJax and KaTeX, e.g. for Markdown export. Constructs
+//! without a LaTeX equivalent degrade to the closest one: skewed and linear
+//! fractions become `a/b`, border boxes become `\boxed`, equation arrays
+//! become `gathered` environments.
+
+use crate::math::{
+    Accent, Delimiter, FractionType, Function, GroupChar, Limit, MathElement, MathRun, MathScript,
+    MathStyle, MathZone, Nary, VerticalPosition,
+};
+
+impl MathZone {
+    /// LaTeX math-mode source for this zone, without `$` delimiters.
+    ///
+    /// ```
+    /// use ooxml_omml::parse_math_zone;
+    ///
+    /// let xml = r#"<m:oMath xmlns:m="http://schemas.openxmlformats.org/officeDocument/2006/math">
+    ///     <m:f>
+    ///         <m:num><m:r><m:t>π</m:t></m:r></m:num>
+    ///         <m:den><m:r><m:t>2</m:t></m:r></m:den>
+    ///     </m:f>
+    /// </m:oMath>"#;
+    ///
+    /// let zone = parse_math_zone(xml.as_bytes()).unwrap();
+    /// assert_eq!(zone.to_latex(), r"\frac{\pi}{2}");
+    /// ```
+    pub fn to_latex(&self) -> String {
+        let mut out = String::new();
+        for element in &self.elements {
+            append(&mut out, &element.to_latex());
+        }
+        out
+    }
+}
+
+impl MathElement {
+    /// LaTeX math-mode source for this element.
+    pub fn to_latex(&self) -> String {
+        match self {
+            MathElement::Run(run) => run_latex(run),
+            MathElement::Fraction(f) => {
+                let (num, den) = (f.numerator.to_latex(), f.denominator.to_latex());
+                match f.fraction_type {
+                    Some(FractionType::Skewed) | Some(FractionType::Linear) => {
+                        format!("{}/{}", group(&num), group(&den))
+                    }
+                    Some(FractionType::NoBar) => format!("\\binom{{{num}}}{{{den}}}"),
+                    Some(FractionType::Bar) | None => format!("\\frac{{{num}}}{{{den}}}"),
+                }
+            }
+            MathElement::Radical(r) => {
+                let base = r.base.to_latex();
+                if r.hide_degree || r.degree.elements.is_empty() {
+                    format!("\\sqrt{{{base}}}")
+                } else {
+                    format!("\\sqrt[{}]{{{base}}}", r.degree.to_latex())
+                }
+            }
+            MathElement::Nary(n) => nary_latex(n),
+            MathElement::Subscript(s) => {
+                format!("{}_{{{}}}", group(&s.base.to_latex()), s.script.to_latex())
+            }
+            MathElement::Superscript(s) => {
+                format!("{}^{{{}}}", group(&s.base.to_latex()), s.script.to_latex())
+            }
+            MathElement::SubSuperscript(s) => format!(
+                "{}_{{{}}}^{{{}}}",
+                group(&s.base.to_latex()),
+                s.subscript.to_latex(),
+                s.superscript.to_latex()
+            ),
+            MathElement::PreScript(p) => format!(
+                "{{}}_{{{}}}^{{{}}}{}",
+                p.subscript.to_latex(),
+                p.superscript.to_latex(),
+                group(&p.base.to_latex())
+            ),
+            MathElement::Delimiter(d) => delimiter_latex(d),
+            MathElement::Matrix(m) => {
+                let rows: Vec<String> = m
+                    .rows
+                    .iter()
+                    .map(|row| {
+                        let cells: Vec<String> = row.iter().map(MathZone::to_latex).collect();
+                        cells.join(" & ")
+                    })
+                    .collect();
+                format!("\\begin{{matrix}}{}\\end{{matrix}}", rows.join(" \\\\ "))
+            }
+            MathElement::Function(f) => function_latex(f),
+            MathElement::Accent(a) => accent_latex(a),
+            MathElement::Bar(b) => match b.position {
+                Some(VerticalPosition::Top) => format!("\\overline{{{}}}", b.base.to_latex()),
+                // Bars are drawn below the base by default (§22.1.2.82)
+                _ => format!("\\underline{{{}}}", b.base.to_latex()),
+            },
+            MathElement::Box(b) => b.content.to_latex(),
+            MathElement::BorderBox(b) => format!("\\boxed{{{}}}", b.content.to_latex()),
+            MathElement::EquationArray(e) => {
+                let rows: Vec<String> = e.equations.iter().map(MathZone::to_latex).collect();
+                format!(
+                    "\\begin{{gathered}}{}\\end{{gathered}}",
+                    rows.join(" \\\\ ")
+                )
+            }
+            MathElement::LowerLimit(l) => limit_latex(l, "_", "\\underset"),
+            MathElement::UpperLimit(l) => limit_latex(l, "^", "\\overset"),
+            MathElement::GroupChar(g) => group_char_latex(g),
+            MathElement::Phantom(p) => {
+                if p.show {
+                    p.content.to_latex()
+                } else {
+                    format!("\\phantom{{{}}}", p.content.to_latex())
+                }
+            }
+        }
+    }
+}
+
+/// Operators written as commands when they name a function or a limit.
+const OPERATOR_NAMES: &[&str] = &[
+    "arccos", "arcsin", "arctan", "arg", "cos", "cosh", "cot", "coth", "csc", "deg", "det", "dim",
+    "exp", "gcd", "hom", "inf", "ker", "lg", "lim", "liminf", "limsup", "ln", "log", "max", "min",
+    "Pr", "sec", "sin", "sinh", "sup", "tan", "tanh",
+];
+
+fn run_latex(run: &MathRun) -> String {
+    let props = run.properties.as_ref();
+    if props.is_some_and(|p| p.normal) {
+        return format!("\\text{{{}}}", escape_text(&run.text));
+    }
+    let mut body = chr_latex(&run.text);
+    let has_letters = run.text.chars().any(|c| c.is_ascii_alphabetic());
+    let alphabet = match props.and_then(|p| p.script) {
+        Some(MathScript::DoubleStruck) => Some("\\mathbb"),
+        Some(MathScript::Script) => Some("\\mathcal"),
+        Some(MathScript::Fraktur) => Some("\\mathfrak"),
+        Some(MathScript::SansSerif) => Some("\\mathsf"),
+        Some(MathScript::Monospace) => Some("\\mathtt"),
+        Some(MathScript::Roman) | None => None,
+    };
+    let style = match props.and_then(|p| p.style) {
+        Some(MathStyle::Bold) => Some("\\mathbf"),
+        Some(MathStyle::BoldItalic) => Some("\\boldsymbol"),
+        Some(MathStyle::Plain) if has_letters => Some("\\mathrm"),
+        _ => None,
+    };
+    for command in [alphabet, style].into_iter().flatten() {
+        body = format!("{command}{{{body}}}");
+    }
+    body
+}
+
+fn nary_latex(n: &Nary) -> String {
+    // An n-ary without a character is an integral (§22.1.2.20)
+    let op = n.operator.as_deref().unwrap_or("∫");
+    let mut out = match op.chars().next().and_then(symbol) {
+        Some(command) if op.chars().count() == 1 => command.to_string(),
+        _ => op.to_string(),
+    };
+    if !n.subscript.elements.is_empty() {
+        out.push_str(&format!("_{{{}}}", n.subscript.to_latex()));
+    }
+    if !n.superscript.elements.is_empty() {
+        out.push_str(&format!("^{{{}}}", n.superscript.to_latex()));
+    }
+    out.push_str(&format!("{{{}}}", n.base.to_latex()));
+    out
+}
+
+fn delimiter_latex(d: &Delimiter) -> String {
+    let begin = fence(d.begin_char.as_deref().unwrap_or("("));
+    let end = fence(d.end_char.as_deref().unwrap_or(")"));
+    let separator = match d.separator_char.as_deref().unwrap_or("|") {
+        "|" => "\\middle|".to_string(),
+        other => other
+            .chars()
+            .map(|c| symbol(c).map_or(c.to_string(), str::to_string))
+            .collect(),
+    };
+    let inner: Vec<String> = d.elements.iter().map(MathZone::to_latex).collect();
+    format!(
+        "\\left{begin}{}\\right{end}",
+        inner.join(&format!(" {separator} "))
+    )
+}
+
+/// A delimiter character as it follows `\left` or `\right`.
+fn fence(chr: &str) -> String {
+    match chr {
+        "" => ".".to_string(),
+        "{" => "\\{".to_string(),
+        "}" => "\\}".to_string(),
+        "‖" => "\\|".to_string(),
+        "⟨" | "〈" => "\\langle".to_string(),
+        "⟩" | "〉" => "\\rangle".to_string(),
+        "⌊" => "\\lfloor".to_string(),
+        "⌋" => "\\rfloor".to_string(),
+        "⌈" => "\\lceil".to_string(),
+        "⌉" => "\\rceil".to_string(),
+        other => other.to_string(),
+    }
+}
+
+fn function_latex(f: &Function) -> String {
+    let name = match f.name.elements.as_slice() {
+        [MathElement::Run(run)] if OPERATOR_NAMES.contains(&run.text.trim()) => {
+            format!("\\{}", run.text.trim())
+        }
+        _ => f.name.to_latex(),
+    };
+    let mut out = name;
+    append(&mut out, &format!("{{{}}}", f.argument.to_latex()));
+    out
+}
+
+fn accent_latex(a: &Accent) -> String {
+    let command = match a.character.as_deref() {
+        // Circumflex is the default accent (§22.1.2.20)
+        None | Some("\u{302}") | Some("^") => "\\hat",
+        Some("\u{303}") | Some("~") => "\\tilde",
+        Some("\u{304}") | Some("\u{305}") | Some("¯") => "\\bar",
+        Some("\u{306}") => "\\breve",
+        Some("\u{307}") => "\\dot",
+        Some("\u{308}") => "\\ddot",
+        Some("\u{301}") => "\\acute",
+        Some("\u{300}") => "\\grave",
+        Some("\u{30C}") => "\\check",
+        Some("\u{20D7}") | Some("\u{20D1}") | Some("→") => "\\vec",
+        Some(_) => "\\hat",
+    };
+    format!("{command}{{{}}}", a.base.to_latex())
+}
+
+fn limit_latex(l: &Limit, script: &str, stack: &str) -> String {
+    let limit = l.limit.to_latex();
+    // `lim` and similar operators take limits as scripts
+    if let [MathElement::Function(f)] = l.base.elements.as_slice()
+        && f.argument.elements.is_empty()
+    {
+        return format!(
+            "{}{script}{{{limit}}}",
+            function_latex(f).trim_end_matches("{}")
+        );
+    }
+    if let [MathElement::Run(run)] = l.base.elements.as_slice()
+        && OPERATOR_NAMES.contains(&run.text.trim())
+    {
+        return format!("\\{}{script}{{{limit}}}", run.text.trim());
+    }
+    format!("{stack}{{{limit}}}{{{}}}", l.base.to_latex())
+}
+
+fn group_char_latex(g: &GroupChar) -> String {
+    let base = g.base.to_latex();
+    match (g.character.as_deref(), g.position) {
+        // Underbrace below the base is the default (§22.1.2.41)
+        (None | Some("⏟"), _) => format!("\\underbrace{{{base}}}"),
+        (Some("⏞"), _) => format!("\\overbrace{{{base}}}"),
+        (Some(chr), Some(VerticalPosition::Top)) => {
+            format!("\\overset{{{}}}{{{base}}}", chr_latex(chr))
+        }
+        (Some(chr), _) => format!("\\underset{{{}}}{{{base}}}", chr_latex(chr)),
+    }
+}
+
+/// LaTeX for text, with characters mapped to commands.
+fn chr_latex(chr: &str) -> String {
+    let mut out = String::new();
+    for c in chr.chars() {
+        match symbol(c) {
+            Some(command) => append(&mut out, command),
+            None => append(&mut out, c.encode_utf8(&mut [0; 4])),
+        }
+    }
+    out
+}
+
+/// Append LaTeX to `out`, separating a command from letters that follow it.
+fn append(out: &mut String, latex: &str) {
+    let ends_with_command = {
+        let letters = out.len()
+            - out
+                .trim_end_matches(|c: char| c.is_ascii_alphabetic())
+                .len();
+        letters > 0 && out[..out.len() - letters].ends_with('\\')
+    };
+    if ends_with_command && latex.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        out.push(' ');
+    }
+    out.push_str(latex);
+}
+
+/// Wrap LaTeX in braces unless it is a single character or command.
+fn group(latex: &str) -> String {
+    let single_command = latex.starts_with('\\')
+        && latex.len() > 1
+        && latex[1..].chars().all(|c| c.is_ascii_alphabetic());
+    if latex.chars().count() == 1 || single_command {
+        latex.to_string()
+    } else {
+        format!("{{{latex}}}")
+    }
+}
+
+/// Escape text for a `\text` argument.
+fn escape_text(text: &str) -> String {
+    let mut out = String::new();
+    for c in text.chars() {
+        match c {
+            '#' | '$' | '%' | '&' | '_' | '{' | '}' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\\' => out.push_str("\\textbackslash{}"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// LaTeX for a character that is not written as itself in math mode.
+fn symbol(c: char) -> Option<&'static str> {
+    Some(match c {
+        // Characters special to LaTeX
+        '#' => "\\#",
+        '$' => "\\$",
+        '%' => "\\%",
+        '&' => "\\&",
+        '_' => "\\_",
+        '{' => "\\{",
+        '}' => "\\}",
+        '\\' => "\\backslash",
+        '~' => "\\sim",
+        '^' => "\\wedge",
+        // Greek
+        'α' => "\\alpha",
+        'β' => "\\beta",
+        'γ' => "\\gamma",
+        'δ' => "\\delta",
+        'ε' => "\\varepsilon",
+        'ϵ' => "\\epsilon",
+        'ζ' => "\\zeta",
+        'η' => "\\eta",
+        'θ' => "\\theta",
+        'ϑ' => "\\vartheta",
+        'ι' => "\\iota",
+        'κ' => "\\kappa",
+        'λ' => "\\lambda",
+        'μ' => "\\mu",
+        'ν' => "\\nu",
+        'ξ' => "\\xi",
+        'π' => "\\pi",
+        'ρ' => "\\rho",
+        'σ' => "\\sigma",
+        'ς' => "\\varsigma",
+        'τ' => "\\tau",
+        'υ' => "\\upsilon",
+        'φ' => "\\varphi",
+        'ϕ' => "\\phi",
+        'χ' => "\\chi",
+        'ψ' => "\\psi",
+        'ω' => "\\omega",
+        'Γ' => "\\Gamma",
+        'Δ' => "\\Delta",
+        'Θ' => "\\Theta",
+        'Λ' => "\\Lambda",
+        'Ξ' => "\\Xi",
+        'Π' => "\\Pi",
+        'Σ' => "\\Sigma",
+        'Υ' => "\\Upsilon",
+        'Φ' => "\\Phi",
+        'Ψ' => "\\Psi",
+        'Ω' => "\\Omega",
+        // Operators and relations
+        '−' => "-",
+        '±' => "\\pm",
+        '∓' => "\\mp",
+        '×' => "\\times",
+        '÷' => "\\div",
+        '·' | '⋅' | '∙' => "\\cdot",
+        '∘' => "\\circ",
+        '⊕' => "\\oplus",
+        '⊗' => "\\otimes",
+        '≤' => "\\leq",
+        '≥' => "\\geq",
+        '≪' => "\\ll",
+        '≫' => "\\gg",
+        '≠' => "\\neq",
+        '≈' => "\\approx",
+        '≡' => "\\equiv",
+        '∼' => "\\sim",
+        '≅' => "\\cong",
+        '∝' => "\\propto",
+        '∣' => "\\mid",
+        '∥' => "\\parallel",
+        '⊥' => "\\perp",
+        '∈' => "\\in",
+        '∉' => "\\notin",
+        '∋' => "\\ni",
+        '⊂' => "\\subset",
+        '⊆' => "\\subseteq",
+        '⊃' => "\\supset",
+        '⊇' => "\\supseteq",
+        '∪' => "\\cup",
+        '∩' => "\\cap",
+        '∅' => "\\emptyset",
+        '∀' => "\\forall",
+        '∃' => "\\exists",
+        '¬' => "\\neg",
+        '∧' => "\\wedge",
+        '∨' => "\\vee",
+        '→' => "\\to",
+        '←' => "\\leftarrow",
+        '↔' => "\\leftrightarrow",
+        '⇒' => "\\Rightarrow",
+        '⇐' => "\\Leftarrow",
+        '⇔' => "\\Leftrightarrow",
+        '↦' => "\\mapsto",
+        '∠' => "\\angle",
+        '′' => "'",
+        '″' => "''",
+        '°' => "^{\\circ}",
+        // Large operators
+        '∑' => "\\sum",
+        '∏' => "\\prod",
+        '∐' => "\\coprod",
+        '∫' => "\\int",
+        '∬' => "\\iint",
+        '∭' => "\\iiint",
+        '∮' => "\\oint",
+        '⋃' => "\\bigcup",
+        '⋂' => "\\bigcap",
+        '⋁' => "\\bigvee",
+        '⋀' => "\\bigwedge",
+        // Miscellaneous
+        '∞' => "\\infty",
+        '∂' => "\\partial",
+        '∇' => "\\nabla",
+        'ℏ' => "\\hbar",
+        'ℓ' => "\\ell",
+        '…' => "\\ldots",
+        '⋯' => "\\cdots",
+        '⋮' => "\\vdots",
+        '⋱' => "\\ddots",
+        '√' => "\\surd",
+        'ℝ' => "\\mathbb{R}",
+        'ℕ' => "\\mathbb{N}",
+        'ℤ' => "\\mathbb{Z}",
+        'ℚ' => "\\mathbb{Q}",
+        'ℂ' => "\\mathbb{C}",
+        '\u{a0}' => "~",
+        // Invisible function application, times and separator
+        '\u{2061}' | '\u{2062}' => "",
+        '\u{2063}' => ",",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{MathElement, MathRun, MathRunProperties, MathScript, MathStyle, parse_math_zone};
+
+    fn latex(body: &str) -> String {
+        let xml = format!(
+            r#"<m:oMath xmlns:m="http://schemas.openxmlformats.org/officeDocument/2006/math">{body}</m:oMath>"#
+        );
+        parse_math_zone(xml.as_bytes()).unwrap().to_latex()
+    }
+
+    #[test]
+    fn test_runs() {
+        assert_eq!(latex("<m:r><m:t>x+y=2</m:t></m:r>"), "x+y=2");
+        assert_eq!(latex("<m:r><m:t>αx≤β</m:t></m:r>"), r"\alpha x\leq\beta");
+        assert_eq!(
+            latex("<m:r><m:t>α</m:t></m:r><m:r><m:t>b</m:t></m:r>"),
+            r"\alpha b"
+        );
+        assert_eq!(latex("<m:r><m:t>50%</m:t></m:r>"), r"50\%");
+
+        let run = |text: &str, script, style| {
+            MathElement::Run(MathRun {
+                text: text.to_string(),
+                properties: Some(MathRunProperties {
+                    script,
+                    style,
+                    ..Default::default()
+                }),
+            })
+            .to_latex()
+        };
+        assert_eq!(
+            run("R", Some(MathScript::DoubleStruck), None),
+            r"\mathbb{R}"
+        );
+        assert_eq!(run("d", None, Some(MathStyle::Plain)), r"\mathrm{d}");
+        assert_eq!(run("=", None, Some(MathStyle::Plain)), "=");
+        assert_eq!(
+            run("v", Some(MathScript::Script), Some(MathStyle::Bold)),
+            r"\mathbf{\mathcal{v}}"
+        );
+    }
+
+    #[test]
+    fn test_fraction_and_radical() {
+        assert_eq!(
+            latex(
+                "<m:f><m:num><m:r><m:t>a</m:t></m:r></m:num>\
+                 <m:den><m:rad><m:deg/>\
+                 <m:e><m:r><m:t>b</m:t></m:r></m:e></m:rad></m:den></m:f>"
+            ),
+            r"\frac{a}{\sqrt{b}}"
+        );
+        assert_eq!(
+            latex(
+                "<m:rad><m:deg><m:r><m:t>3</m:t></m:r></m:deg>\
+                 <m:e><m:r><m:t>x</m:t></m:r></m:e></m:rad>"
+            ),
+            r"\sqrt[3]{x}"
+        );
+    }
+
+    #[test]
+    fn test_scripts_and_nary() {
+        assert_eq!(
+            latex(
+                "<m:sSup><m:e><m:r><m:t>x</m:t></m:r></m:e>\
+                 <m:sup><m:r><m:t>2</m:t></m:r></m:sup></m:sSup>"
+            ),
+            "x^{2}"
+        );
+        assert_eq!(
+            latex(
+                "<m:sSub><m:e><m:r><m:t>ab</m:t></m:r></m:e>\
+                 <m:sub><m:r><m:t>i</m:t></m:r></m:sub></m:sSub>"
+            ),
+            "{ab}_{i}"
+        );
+        assert_eq!(
+            latex(
+                "<m:nary><m:naryPr><m:chr m:val=\"∑\"/></m:naryPr>\
+                 <m:sub><m:r><m:t>i=1</m:t></m:r></m:sub>\
+                 <m:sup><m:r><m:t>n</m:t></m:r></m:sup>\
+                 <m:e><m:r><m:t>i</m:t></m:r></m:e></m:nary>"
+            ),
+            r"\sum_{i=1}^{n}{i}"
+        );
+    }
+
+    #[test]
+    fn test_delimiters_and_matrices() {
+        assert_eq!(
+            latex(
+                "<m:d><m:e><m:r><m:t>a</m:t></m:r></m:e><m:e><m:r><m:t>b</m:t></m:r></m:e></m:d>"
+            ),
+            r"\left(a \middle| b\right)"
+        );
+        assert_eq!(
+            latex(
+                "<m:d><m:dPr><m:begChr m:val=\"{\"/><m:endChr m:val=\"\"/></m:dPr>\
+                 <m:e><m:r><m:t>x</m:t></m:r></m:e></m:d>"
+            ),
+            r"\left\{x\right."
+        );
+        assert_eq!(
+            latex(
+                "<m:m><m:mr><m:e><m:r><m:t>1</m:t></m:r></m:e><m:e><m:r><m:t>0</m:t></m:r></m:e></m:mr>\
+                 <m:mr><m:e><m:r><m:t>0</m:t></m:r></m:e><m:e><m:r><m:t>1</m:t></m:r></m:e></m:mr></m:m>"
+            ),
+            r"\begin{matrix}1 & 0 \\ 0 & 1\end{matrix}"
+        );
+    }
+
+    #[test]
+    fn test_functions_and_limits() {
+        assert_eq!(
+            latex(
+                "<m:func><m:fName><m:r><m:t>sin</m:t></m:r></m:fName>\
+                 <m:e><m:r><m:t>x</m:t></m:r></m:e></m:func>"
+            ),
+            r"\sin{x}"
+        );
+        assert_eq!(
+            latex(
+                "<m:limLow><m:e><m:r><m:t>lim</m:t></m:r></m:e>\
+                 <m:lim><m:r><m:t>n→∞</m:t></m:r></m:lim></m:limLow>"
+            ),
+            r"\lim_{n\to\infty}"
+        );
+        assert_eq!(
+            latex(
+                "<m:acc><m:accPr><m:chr m:val=\"\u{20D7}\"/></m:accPr>\
+                 <m:e><m:r><m:t>v</m:t></m:r></m:e></m:acc>"
+            ),
+            r"\vec{v}"
+        );
+    }
+}
@@ -17,6 +17,7 @@
 //!
 //! let zone = parse_math_zone(xml.as_bytes()).unwrap();
 //! assert_eq!(zone.text(), "(1)/(2)");
+//! assert_eq!(zone.to_latex(), r"\frac{1}{2}");
 //! ```
 //!
 //! # Supported Elements
@@ -33,6 +34,7 @@
 
 pub mod error;
 pub mod ext;
+mod latex;
 pub mod math;
 
 pub use error::{Error, Result};
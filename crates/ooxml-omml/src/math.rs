@@ -11,7 +11,7 @@
 //! - Various math constructs: fractions, radicals, scripts, etc.
 
 use crate::error::{Error, Result};
-use quick_xml::Reader;
+use quick_xml::NsReader;
 use quick_xml::events::Event;
 use std::io::{BufRead, Cursor};
 
@@ -19,6 +19,7 @@ use std::io::{BufRead, Cursor};
 ///
 /// Contains a sequence of math elements that form a mathematical expression.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MathZone {
     /// Math elements in this zone.
     pub elements: Vec<MathElement>,
@@ -38,7 +39,7 @@ impl MathZone {
 
 impl ooxml_xml::FromXml for MathZone {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start_tag: &quick_xml::events::BytesStart,
         is_empty: bool,
     ) -> std::result::Result<Self, ooxml_xml::ParseError> {
@@ -54,6 +55,7 @@ impl ooxml_xml::FromXml for MathZone {
 
 /// A math element - one of the possible OMML constructs.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MathElement {
     /// Text run (`m:r`).
     Run(MathRun),
@@ -185,6 +187,7 @@ impl MathElement {
 
 /// A math text run (`<m:r>`).
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MathRun {
     /// Text content.
     pub text: String,
@@ -194,6 +197,7 @@ pub struct MathRun {
 
 /// Math run properties (`<m:rPr>`).
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MathRunProperties {
     /// Script type (roman, script, fraktur, etc.).
     pub script: Option<MathScript>,
@@ -210,6 +214,7 @@ pub struct MathRunProperties {
 /// Controls the mathematical alphabet used for a run's characters.
 /// Defined in ECMA-376 §22.1.2.87 (ST_Script).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MathScript {
     /// Serif (roman) alphabet — the default mathematical alphabet.
     Roman,
@@ -230,6 +235,7 @@ pub enum MathScript {
 /// Controls whether characters in a run are rendered plain, bold, italic, or bold-italic.
 /// Defined in ECMA-376 §22.1.2.90 (ST_Style).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MathStyle {
     /// Plain (upright, not bold) — value `"p"`.
     Plain,
@@ -243,6 +249,7 @@ pub enum MathStyle {
 
 /// A fraction (`<m:f>`).
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Fraction {
     /// Numerator.
     pub numerator: MathZone,
@@ -254,6 +261,7 @@ pub struct Fraction {
 
 /// Fraction display types.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FractionType {
     /// Normal fraction bar.
     Bar,
@@ -267,6 +275,7 @@ pub enum FractionType {
 
 /// A radical/root (`<m:rad>`).
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Radical {
     /// Base expression (under the radical).
     pub base: MathZone,
@@ -278,6 +287,7 @@ pub struct Radical {
 
 /// An n-ary operator (`<m:nary>`).
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Nary {
     /// Operator character (∑, ∫, ∏, etc.).
     pub operator: Option<String>,
@@ -295,6 +305,7 @@ pub struct Nary {
 
 /// Limit location for n-ary operators.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LimitLocation {
     /// Under and over the operator.
     UnderOver,
@@ -304,6 +315,7 @@ pub enum LimitLocation {
 
 /// A script (subscript or superscript).
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Script {
     /// Base expression.
     pub base: MathZone,
@@ -313,6 +325,7 @@ pub struct Script {
 
 /// Combined subscript and superscript (`<m:sSubSup>`).
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SubSuperscript {
     /// Base expression.
     pub base: MathZone,
@@ -324,6 +337,7 @@ pub struct SubSuperscript {
 
 /// Pre-script (subscript/superscript before base) (`<m:sPre>`).
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PreScript {
     /// Subscript.
     pub subscript: MathZone,
@@ -335,6 +349,7 @@ pub struct PreScript {
 
 /// A delimiter/parentheses (`<m:d>`).
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Delimiter {
     /// Beginning character (default: '(').
     pub begin_char: Option<String>,
@@ -350,6 +365,7 @@ pub struct Delimiter {
 
 /// A matrix (`<m:m>`).
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Matrix {
     /// Rows of the matrix.
     pub rows: Vec<Vec<MathZone>>,
@@ -357,6 +373,7 @@ pub struct Matrix {
 
 /// A function application (`<m:func>`).
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Function {
     /// Function name.
     pub name: MathZone,
@@ -366,6 +383,7 @@ pub struct Function {
 
 /// An accent (`<m:acc>`).
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Accent {
     /// Accent character (default: combining circumflex).
     pub character: Option<String>,
@@ -375,6 +393,7 @@ pub struct Accent {
 
 /// A bar over or under expression (`<m:bar>`).
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Bar {
     /// Base expression.
     pub base: MathZone,
@@ -386,6 +405,7 @@ pub struct Bar {
 ///
 /// Determines whether a bar or grouping character appears above or below the base expression.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VerticalPosition {
     /// Position above the base expression — value `"top"`.
     Top,
@@ -395,6 +415,7 @@ pub enum VerticalPosition {
 
 /// A box around content (`<m:box>`).
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MathBox {
     /// Box content.
     pub content: MathZone,
@@ -402,6 +423,7 @@ pub struct MathBox {
 
 /// A bordered box (`<m:borderBox>`).
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BorderBox {
     /// Box content.
     pub content: MathZone,
@@ -414,6 +436,7 @@ pub struct BorderBox {
 
 /// An equation array (`<m:eqArr>`).
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EquationArray {
     /// Equations in the array.
     pub equations: Vec<MathZone>,
@@ -421,6 +444,7 @@ pub struct EquationArray {
 
 /// A limit expression (`<m:limLow>` or `<m:limUpp>`).
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Limit {
     /// Base expression.
     pub base: MathZone,
@@ -430,6 +454,7 @@ pub struct Limit {
 
 /// A group character/brace (`<m:groupChr>`).
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GroupChar {
     /// Grouping character (default: underbrace).
     pub character: Option<String>,
@@ -441,6 +466,7 @@ pub struct GroupChar {
 
 /// A phantom (invisible content for spacing) (`<m:phant>`).
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Phantom {
     /// Content.
     pub content: MathZone,
@@ -454,12 +480,12 @@ pub struct Phantom {
 
 /// Parse an OMML math zone from XML.
 pub fn parse_math_zone(xml: &[u8]) -> Result<MathZone> {
-    let mut reader = Reader::from_reader(Cursor::new(xml));
+    let mut reader = NsReader::from_reader(Cursor::new(xml));
     parse_math_zone_from_reader(&mut reader)
 }
 
 /// Parse an OMML math zone from a reader.
-pub fn parse_math_zone_from_reader<R: BufRead>(reader: &mut Reader<R>) -> Result<MathZone> {
+pub fn parse_math_zone_from_reader<R: BufRead>(reader: &mut NsReader<R>) -> Result<MathZone> {
     let mut buf = Vec::new();
     let mut zone = MathZone::new();
 
@@ -495,7 +521,7 @@ pub fn parse_math_zone_from_reader<R: BufRead>(reader: &mut Reader<R>) -> Result
 /// Parse a math element from its start tag.
 fn parse_math_element_start<R: BufRead>(
     name: &[u8],
-    reader: &mut Reader<R>,
+    reader: &mut NsReader<R>,
 ) -> Result<Option<MathElement>> {
     match name {
         b"m:r" | b"r" => Ok(Some(MathElement::Run(parse_math_run(reader)?))),
@@ -539,7 +565,7 @@ fn parse_math_element_start<R: BufRead>(
 }
 
 /// Parse a math run.
-fn parse_math_run<R: BufRead>(reader: &mut Reader<R>) -> Result<MathRun> {
+fn parse_math_run<R: BufRead>(reader: &mut NsReader<R>) -> Result<MathRun> {
     let mut buf = Vec::new();
     let mut run = MathRun::default();
     let mut in_text = false;
@@ -552,10 +578,8 @@ fn parse_math_run<R: BufRead>(reader: &mut Reader<R>) -> Result<MathRun> {
                     in_text = true;
                 }
             }
-            Ok(Event::Text(e)) => {
-                if in_text {
-                    run.text.push_str(&e.decode().unwrap_or_default());
-                }
+            Ok(Event::Text(e)) if in_text => {
+                run.text.push_str(&e.decode().unwrap_or_default());
             }
             Ok(Event::End(e)) => {
                 let name = e.name();
@@ -577,7 +601,7 @@ fn parse_math_run<R: BufRead>(reader: &mut Reader<R>) -> Result<MathRun> {
 }
 
 /// Parse math argument (content inside m:e, m:num, m:den, etc.).
-fn parse_math_arg<R: BufRead>(reader: &mut Reader<R>, end_tag: &[u8]) -> Result<MathZone> {
+fn parse_math_arg<R: BufRead>(reader: &mut NsReader<R>, end_tag: &[u8]) -> Result<MathZone> {
     let mut buf = Vec::new();
     let mut zone = MathZone::new();
 
@@ -607,7 +631,7 @@ fn parse_math_arg<R: BufRead>(reader: &mut Reader<R>, end_tag: &[u8]) -> Result<
 }
 
 /// Parse a fraction.
-fn parse_fraction<R: BufRead>(reader: &mut Reader<R>) -> Result<Fraction> {
+fn parse_fraction<R: BufRead>(reader: &mut NsReader<R>) -> Result<Fraction> {
     let mut buf = Vec::new();
     let mut fraction = Fraction::default();
 
@@ -639,7 +663,7 @@ fn parse_fraction<R: BufRead>(reader: &mut Reader<R>) -> Result<Fraction> {
 }
 
 /// Parse a radical.
-fn parse_radical<R: BufRead>(reader: &mut Reader<R>) -> Result<Radical> {
+fn parse_radical<R: BufRead>(reader: &mut NsReader<R>) -> Result<Radical> {
     let mut buf = Vec::new();
     let mut radical = Radical::default();
 
@@ -671,7 +695,7 @@ fn parse_radical<R: BufRead>(reader: &mut Reader<R>) -> Result<Radical> {
 }
 
 /// Parse an n-ary operator.
-fn parse_nary<R: BufRead>(reader: &mut Reader<R>) -> Result<Nary> {
+fn parse_nary<R: BufRead>(reader: &mut NsReader<R>) -> Result<Nary> {
     let mut buf = Vec::new();
     let mut nary = Nary::default();
 
@@ -714,7 +738,7 @@ fn parse_nary<R: BufRead>(reader: &mut Reader<R>) -> Result<Nary> {
 }
 
 /// Parse a script (subscript or superscript).
-fn parse_script<R: BufRead>(reader: &mut Reader<R>, end_tag: &[u8]) -> Result<Script> {
+fn parse_script<R: BufRead>(reader: &mut NsReader<R>, end_tag: &[u8]) -> Result<Script> {
     let mut buf = Vec::new();
     let mut script = Script::default();
     let end_local = if end_tag.starts_with(b"m:") {
@@ -754,7 +778,7 @@ fn parse_script<R: BufRead>(reader: &mut Reader<R>, end_tag: &[u8]) -> Result<Sc
 }
 
 /// Parse combined subscript and superscript.
-fn parse_sub_superscript<R: BufRead>(reader: &mut Reader<R>) -> Result<SubSuperscript> {
+fn parse_sub_superscript<R: BufRead>(reader: &mut NsReader<R>) -> Result<SubSuperscript> {
     let mut buf = Vec::new();
     let mut result = SubSuperscript::default();
 
@@ -787,7 +811,7 @@ fn parse_sub_superscript<R: BufRead>(reader: &mut Reader<R>) -> Result<SubSupers
 }
 
 /// Parse pre-script.
-fn parse_pre_script<R: BufRead>(reader: &mut Reader<R>) -> Result<PreScript> {
+fn parse_pre_script<R: BufRead>(reader: &mut NsReader<R>) -> Result<PreScript> {
     let mut buf = Vec::new();
     let mut result = PreScript::default();
 
@@ -820,7 +844,7 @@ fn parse_pre_script<R: BufRead>(reader: &mut Reader<R>) -> Result<PreScript> {
 }
 
 /// Parse a delimiter.
-fn parse_delimiter<R: BufRead>(reader: &mut Reader<R>) -> Result<Delimiter> {
+fn parse_delimiter<R: BufRead>(reader: &mut NsReader<R>) -> Result<Delimiter> {
     let mut buf = Vec::new();
     let mut delimiter = Delimiter::default();
 
@@ -865,7 +889,7 @@ fn parse_delimiter<R: BufRead>(reader: &mut Reader<R>) -> Result<Delimiter> {
 }
 
 /// Parse a matrix.
-fn parse_matrix<R: BufRead>(reader: &mut Reader<R>) -> Result<Matrix> {
+fn parse_matrix<R: BufRead>(reader: &mut NsReader<R>) -> Result<Matrix> {
     let mut buf = Vec::new();
     let mut matrix = Matrix::default();
     let mut current_row: Vec<MathZone> = Vec::new();
@@ -910,7 +934,7 @@ fn parse_matrix<R: BufRead>(reader: &mut Reader<R>) -> Result<Matrix> {
 }
 
 /// Parse a function.
-fn parse_function<R: BufRead>(reader: &mut Reader<R>) -> Result<Function> {
+fn parse_function<R: BufRead>(reader: &mut NsReader<R>) -> Result<Function> {
     let mut buf = Vec::new();
     let mut function = Function::default();
 
@@ -942,7 +966,7 @@ fn parse_function<R: BufRead>(reader: &mut Reader<R>) -> Result<Function> {
 }
 
 /// Parse an accent.
-fn parse_accent<R: BufRead>(reader: &mut Reader<R>) -> Result<Accent> {
+fn parse_accent<R: BufRead>(reader: &mut NsReader<R>) -> Result<Accent> {
     let mut buf = Vec::new();
     let mut accent = Accent::default();
 
@@ -983,7 +1007,7 @@ fn parse_accent<R: BufRead>(reader: &mut Reader<R>) -> Result<Accent> {
 }
 
 /// Parse a bar.
-fn parse_bar<R: BufRead>(reader: &mut Reader<R>) -> Result<Bar> {
+fn parse_bar<R: BufRead>(reader: &mut NsReader<R>) -> Result<Bar> {
     let mut buf = Vec::new();
     let mut bar = Bar::default();
 
@@ -1013,7 +1037,7 @@ fn parse_bar<R: BufRead>(reader: &mut Reader<R>) -> Result<Bar> {
 }
 
 /// Parse a math box.
-fn parse_math_box<R: BufRead>(reader: &mut Reader<R>) -> Result<MathBox> {
+fn parse_math_box<R: BufRead>(reader: &mut NsReader<R>) -> Result<MathBox> {
     let mut buf = Vec::new();
     let mut result = MathBox::default();
 
@@ -1043,7 +1067,7 @@ fn parse_math_box<R: BufRead>(reader: &mut Reader<R>) -> Result<MathBox> {
 }
 
 /// Parse a border box.
-fn parse_border_box<R: BufRead>(reader: &mut Reader<R>) -> Result<BorderBox> {
+fn parse_border_box<R: BufRead>(reader: &mut NsReader<R>) -> Result<BorderBox> {
     let mut buf = Vec::new();
     let mut result = BorderBox::default();
 
@@ -1073,7 +1097,7 @@ fn parse_border_box<R: BufRead>(reader: &mut Reader<R>) -> Result<BorderBox> {
 }
 
 /// Parse an equation array.
-fn parse_equation_array<R: BufRead>(reader: &mut Reader<R>) -> Result<EquationArray> {
+fn parse_equation_array<R: BufRead>(reader: &mut NsReader<R>) -> Result<EquationArray> {
     let mut buf = Vec::new();
     let mut result = EquationArray::default();
 
@@ -1103,7 +1127,7 @@ fn parse_equation_array<R: BufRead>(reader: &mut Reader<R>) -> Result<EquationAr
 }
 
 /// Parse a limit.
-fn parse_limit<R: BufRead>(reader: &mut Reader<R>, end_tag: &[u8]) -> Result<Limit> {
+fn parse_limit<R: BufRead>(reader: &mut NsReader<R>, end_tag: &[u8]) -> Result<Limit> {
     let mut buf = Vec::new();
     let mut result = Limit::default();
     let end_local = if end_tag.starts_with(b"m:") {
@@ -1141,7 +1165,7 @@ fn parse_limit<R: BufRead>(reader: &mut Reader<R>, end_tag: &[u8]) -> Result<Lim
 }
 
 /// Parse a group character.
-fn parse_group_char<R: BufRead>(reader: &mut Reader<R>) -> Result<GroupChar> {
+fn parse_group_char<R: BufRead>(reader: &mut NsReader<R>) -> Result<GroupChar> {
     let mut buf = Vec::new();
     let mut result = GroupChar::default();
 
@@ -1182,7 +1206,7 @@ fn parse_group_char<R: BufRead>(reader: &mut Reader<R>) -> Result<GroupChar> {
 }
 
 /// Parse a phantom.
-fn parse_phantom<R: BufRead>(reader: &mut Reader<R>) -> Result<Phantom> {
+fn parse_phantom<R: BufRead>(reader: &mut NsReader<R>) -> Result<Phantom> {
     let mut buf = Vec::new();
     let mut result = Phantom::default();
 
@@ -1604,4 +1628,33 @@ mod tests {
         assert!(text.contains("900"));
         assert!(text.contains("*100=8%"));
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_math_zone_json_roundtrip() {
+        let xml = r#"<m:oMath xmlns:m="http://schemas.openxmlformats.org/officeDocument/2006/math">
+            <m:sSup>
+                <m:e><m:r><m:t>x</m:t></m:r></m:e>
+                <m:sup><m:r><m:t>2</m:t></m:r></m:sup>
+            </m:sSup>
+            <m:r><m:t>+</m:t></m:r>
+            <m:f>
+                <m:num><m:r><m:t>1</m:t></m:r></m:num>
+                <m:den><m:rad><m:deg/><m:e><m:r><m:t>y</m:t></m:r></m:e></m:rad></m:den>
+            </m:f>
+        </m:oMath>"#;
+
+        let zone = parse_math_zone(xml.as_bytes()).unwrap();
+        let json = serde_json::to_string(&zone).unwrap();
+        let loaded: MathZone = serde_json::from_str(&json).unwrap();
+
+        let written = serialize_math_zone(&loaded);
+        assert_eq!(written, serialize_math_zone(&zone));
+        let reparsed = parse_math_zone(written.as_bytes()).unwrap();
+        assert_eq!(reparsed.text(), "x^2+(1)/(sqrt(y))");
+        assert_eq!(
+            serde_json::to_value(&reparsed).unwrap(),
+            serde_json::to_value(&zone).unwrap()
+        );
+    }
 }
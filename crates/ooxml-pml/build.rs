@@ -1,6 +1,6 @@
 use ooxml_codegen::{
     CodegenConfig, FeatureMappings, NameMappings, Schema, analyze_schema, generate,
-    generate_parsers, generate_serializers, parse_rnc,
+    generate_parsers, generate_serializers, generate_visitors, parse_rnc,
 };
 use std::collections::HashMap;
 use std::fs;
@@ -30,6 +30,28 @@ fn main() {
     println!("cargo::rerun-if-changed={}", names_path);
     println!("cargo::rerun-if-changed={}", features_path);
     println!("cargo::rerun-if-changed=build.rs");
+    println!("cargo::rerun-if-env-changed=OOXML_GENERATE_VISIT");
+
+    // Derive Visit/VisitMut traversal and query trees from the committed
+    // types and serializers
+    // Enable with OOXML_GENERATE_VISIT=1
+    if std::env::var("OOXML_GENERATE_VISIT").is_ok() {
+        let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let types = fs::read_to_string(src.join("generated.rs")).expect("failed to read types");
+        let serializers = fs::read_to_string(src.join("generated_serializers.rs"))
+            .expect("failed to read serializers");
+        // PML types embed DML types, whose visitors the PML ones extend
+        let dml_types = fs::read_to_string(src.join("../../ooxml-dml/src/generated.rs"))
+            .expect("failed to read DML types");
+        let visit_dest = src.join("generated_visit.rs");
+        let visit_code = generate_visitors(&types, &serializers, &[("ooxml_dml", &dml_types)])
+            .expect("failed to generate visitors");
+        fs::write(&visit_dest, visit_code).expect("failed to write visitors");
+        eprintln!(
+            "Generated {} bytes to src/generated_visit.rs",
+            visit_dest.metadata().map(|m| m.len()).unwrap_or(0)
+        );
+    }
 
     // The generated file is committed at src/generated.rs
     // Only regenerate if OOXML_REGENERATE is set and specs exist
@@ -127,6 +149,13 @@ fn main() {
         feature_mappings,
         // PML uses p: namespace prefix in real PPTX files
         xml_serialize_prefix: Some("p".to_string()),
+        // PML elements contain DML elements directly; others are unknown children
+        xml_namespaces: vec![
+            "http://schemas.openxmlformats.org/presentationml/2006/main".to_string(),
+            "http://purl.oclc.org/ooxml/presentationml/main".to_string(),
+            "http://schemas.openxmlformats.org/drawingml/2006/main".to_string(),
+            "http://purl.oclc.org/ooxml/drawingml/main".to_string(),
+        ],
         // PML references DML types for shared drawing elements
         cross_crate_imports: vec!["ooxml_dml::types::*".to_string()],
         // Map DML schema names to ooxml_dml crate types
@@ -17,6 +17,11 @@ pub enum Error {
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
 
+    /// Error from a generated parser, with the part, line and column and
+    /// element path it occurred at; see [`Error::location`].
+    #[error("Parse error: {0}")]
+    Parse(#[from] ooxml_xml::ParseError),
+
     /// Invalid or malformed content.
     #[error("Invalid content: {0}")]
     Invalid(String),
@@ -30,5 +35,15 @@ pub enum Error {
     Serialize(#[from] crate::generated_serializers::SerializeError),
 }
 
+impl Error {
+    /// Where in the package a parse error occurred, if known.
+    pub fn location(&self) -> Option<&ooxml_xml::SourceLocation> {
+        match self {
+            Self::Parse(e) => e.location(),
+            _ => None,
+        }
+    }
+}
+
 /// Result type for PresentationML operations.
 pub type Result<T> = std::result::Result<T, Error>;
@@ -511,3 +511,162 @@ impl NotesSlideExt for NotesSlide {
         self.common_slide_data.text()
     }
 }
+
+/// Mutation helpers for GroupShape (p:grpSp and p:spTree elements).
+///
+/// Used when editing slides of an existing presentation.
+pub trait GroupShapeMutExt {
+    /// Replace text in all shapes of this group (recursively), including
+    /// table cells inside graphic frames. Returns the number of replacements.
+    ///
+    /// Matching is done per text run, so text split across runs with
+    /// different formatting is not found.
+    fn replace_text(&mut self, find: &str, replace: &str) -> usize;
+
+    /// Get the largest drawing element ID (`cNvPr@id`) in this group (recursively).
+    fn max_shape_id(&self) -> u32;
+
+    /// Remove the first direct child (shape, picture, connector, graphic
+    /// frame or group) with the given `cNvPr@name`. Returns whether one was removed.
+    fn remove_by_name(&mut self, name: &str) -> bool;
+}
+
+impl GroupShapeMutExt for GroupShape {
+    fn replace_text(&mut self, find: &str, replace: &str) -> usize {
+        if find.is_empty() {
+            return 0;
+        }
+        let mut count = 0;
+        for shape in &mut self.shape {
+            if let Some(body) = shape.text_body.as_deref_mut() {
+                count += replace_in_paragraphs(&mut body.p, find, replace);
+            }
+        }
+        for group in &mut self.group_shape {
+            count += group.replace_text(find, replace);
+        }
+        #[cfg(feature = "extra-children")]
+        for frame in &mut self.graphic_frame {
+            for child in &mut frame.extra_children {
+                count += replace_in_raw_text(&mut child.node, find, replace);
+            }
+        }
+        count
+    }
+
+    fn max_shape_id(&self) -> u32 {
+        let ids = std::iter::once(self.non_visual_group_properties.c_nv_pr.id)
+            .chain(
+                self.shape
+                    .iter()
+                    .map(|s| s.non_visual_properties.c_nv_pr.id),
+            )
+            .chain(
+                self.picture
+                    .iter()
+                    .map(|p| p.non_visual_picture_properties.c_nv_pr.id),
+            )
+            .chain(
+                self.connector
+                    .iter()
+                    .map(|c| c.non_visual_connector_properties.c_nv_pr.id),
+            )
+            .chain(
+                self.graphic_frame
+                    .iter()
+                    .map(|f| f.nv_graphic_frame_pr.c_nv_pr.id),
+            )
+            .chain(self.group_shape.iter().map(|g| g.max_shape_id()));
+        ids.max().unwrap_or(0)
+    }
+
+    fn remove_by_name(&mut self, name: &str) -> bool {
+        if let Some(pos) = self.shape.iter().position(|s| s.name() == name) {
+            self.shape.remove(pos);
+        } else if let Some(pos) = self.picture.iter().position(|p| p.name() == name) {
+            self.picture.remove(pos);
+        } else if let Some(pos) = self.connector.iter().position(|c| c.name() == name) {
+            self.connector.remove(pos);
+        } else if let Some(pos) = self.graphic_frame.iter().position(|f| f.name() == name) {
+            self.graphic_frame.remove(pos);
+        } else if let Some(pos) = self.group_shape.iter().position(|g| g.name() == name) {
+            self.group_shape.remove(pos);
+        } else {
+            return false;
+        }
+        true
+    }
+}
+
+/// Mutation helpers for Slide (p:sld element).
+pub trait SlideMutExt {
+    /// Replace text in all shapes on the slide. See [`GroupShapeMutExt::replace_text`].
+    fn replace_text(&mut self, find: &str, replace: &str) -> usize;
+
+    /// Add a shape on top of the existing ones, giving it an unused drawing
+    /// element ID. Returns the assigned ID.
+    fn add_shape(&mut self, shape: Shape) -> u32;
+
+    /// Remove the top-level element with the given name. Returns whether one was removed.
+    fn remove_shape(&mut self, name: &str) -> bool;
+}
+
+impl SlideMutExt for Slide {
+    fn replace_text(&mut self, find: &str, replace: &str) -> usize {
+        self.common_slide_data
+            .shape_tree
+            .replace_text(find, replace)
+    }
+
+    fn add_shape(&mut self, mut shape: Shape) -> u32 {
+        let tree = &mut self.common_slide_data.shape_tree;
+        let id = tree.max_shape_id() + 1;
+        shape.non_visual_properties.c_nv_pr.id = id;
+        tree.shape.push(shape);
+        id
+    }
+
+    fn remove_shape(&mut self, name: &str) -> bool {
+        self.common_slide_data.shape_tree.remove_by_name(name)
+    }
+}
+
+/// Replace text within the runs of the given paragraphs.
+fn replace_in_paragraphs(paragraphs: &mut [TextParagraph], find: &str, replace: &str) -> usize {
+    let mut count = 0;
+    for para in paragraphs {
+        for run in &mut para.text_run {
+            if let ooxml_dml::types::EGTextRun::R(run) = run {
+                let n = run.t.matches(find).count();
+                if n > 0 {
+                    run.t = run.t.replace(find, replace);
+                    count += n;
+                }
+            }
+        }
+    }
+    count
+}
+
+/// Replace text inside `a:t` elements of raw (unparsed) XML, such as tables.
+#[cfg(feature = "extra-children")]
+fn replace_in_raw_text(node: &mut ooxml_xml::RawXmlNode, find: &str, replace: &str) -> usize {
+    let ooxml_xml::RawXmlNode::Element(elem) = node else {
+        return 0;
+    };
+    let is_text = elem.name.rsplit(':').next() == Some("t");
+    let mut count = 0;
+    for child in &mut elem.children {
+        match child {
+            ooxml_xml::RawXmlNode::Text(text) if is_text => {
+                let n = text.matches(find).count();
+                if n > 0 {
+                    *text = text.replace(find, replace);
+                    count += n;
+                }
+            }
+            _ => count += replace_in_raw_text(child, find, replace),
+        }
+    }
+    count
+}
@@ -1,6 +1,7 @@
 // Generated from ECMA-376 RELAX NG schema.
 // Do not edit manually.
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// XML namespace URIs used in this schema.
@@ -29,35 +30,36 @@ pub type HexColorRgb = Vec<u8>;
 
 pub type Panose = Vec<u8>;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CalendarType {
-    #[serde(rename = "gregorian")]
+    #[cfg_attr(feature = "serde", serde(rename = "gregorian"))]
     Gregorian,
-    #[serde(rename = "gregorianUs")]
+    #[cfg_attr(feature = "serde", serde(rename = "gregorianUs"))]
     GregorianUs,
-    #[serde(rename = "gregorianMeFrench")]
+    #[cfg_attr(feature = "serde", serde(rename = "gregorianMeFrench"))]
     GregorianMeFrench,
-    #[serde(rename = "gregorianArabic")]
+    #[cfg_attr(feature = "serde", serde(rename = "gregorianArabic"))]
     GregorianArabic,
-    #[serde(rename = "hijri")]
+    #[cfg_attr(feature = "serde", serde(rename = "hijri"))]
     Hijri,
-    #[serde(rename = "hebrew")]
+    #[cfg_attr(feature = "serde", serde(rename = "hebrew"))]
     Hebrew,
-    #[serde(rename = "taiwan")]
+    #[cfg_attr(feature = "serde", serde(rename = "taiwan"))]
     Taiwan,
-    #[serde(rename = "japan")]
+    #[cfg_attr(feature = "serde", serde(rename = "japan"))]
     Japan,
-    #[serde(rename = "thai")]
+    #[cfg_attr(feature = "serde", serde(rename = "thai"))]
     Thai,
-    #[serde(rename = "korea")]
+    #[cfg_attr(feature = "serde", serde(rename = "korea"))]
     Korea,
-    #[serde(rename = "saka")]
+    #[cfg_attr(feature = "serde", serde(rename = "saka"))]
     Saka,
-    #[serde(rename = "gregorianXlitEnglish")]
+    #[cfg_attr(feature = "serde", serde(rename = "gregorianXlitEnglish"))]
     GregorianXlitEnglish,
-    #[serde(rename = "gregorianXlitFrench")]
+    #[cfg_attr(feature = "serde", serde(rename = "gregorianXlitFrench"))]
     GregorianXlitFrench,
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
 }
 
@@ -106,11 +108,12 @@ impl std::str::FromStr for CalendarType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STAlgClass {
-    #[serde(rename = "hash")]
+    #[cfg_attr(feature = "serde", serde(rename = "hash"))]
     Hash,
-    #[serde(rename = "custom")]
+    #[cfg_attr(feature = "serde", serde(rename = "custom"))]
     Custom,
 }
 
@@ -135,13 +138,14 @@ impl std::str::FromStr for STAlgClass {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STCryptProv {
-    #[serde(rename = "rsaAES")]
+    #[cfg_attr(feature = "serde", serde(rename = "rsaAES"))]
     RsaAES,
-    #[serde(rename = "rsaFull")]
+    #[cfg_attr(feature = "serde", serde(rename = "rsaFull"))]
     RsaFull,
-    #[serde(rename = "custom")]
+    #[cfg_attr(feature = "serde", serde(rename = "custom"))]
     Custom,
 }
 
@@ -168,11 +172,12 @@ impl std::str::FromStr for STCryptProv {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STAlgType {
-    #[serde(rename = "typeAny")]
+    #[cfg_attr(feature = "serde", serde(rename = "typeAny"))]
     TypeAny,
-    #[serde(rename = "custom")]
+    #[cfg_attr(feature = "serde", serde(rename = "custom"))]
     Custom,
 }
 
@@ -203,11 +208,12 @@ pub type Guid = String;
 
 pub type OnOff = String;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STOnOff1 {
-    #[serde(rename = "on")]
+    #[cfg_attr(feature = "serde", serde(rename = "on"))]
     On,
-    #[serde(rename = "off")]
+    #[cfg_attr(feature = "serde", serde(rename = "off"))]
     Off,
 }
 
@@ -236,15 +242,16 @@ pub type STString = String;
 
 pub type STXmlName = String;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TrueFalse {
-    #[serde(rename = "t")]
+    #[cfg_attr(feature = "serde", serde(rename = "t"))]
     T,
-    #[serde(rename = "f")]
+    #[cfg_attr(feature = "serde", serde(rename = "f"))]
     F,
-    #[serde(rename = "true")]
+    #[cfg_attr(feature = "serde", serde(rename = "true"))]
     True,
-    #[serde(rename = "false")]
+    #[cfg_attr(feature = "serde", serde(rename = "false"))]
     False,
 }
 
@@ -273,17 +280,18 @@ impl std::str::FromStr for TrueFalse {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTrueFalseBlank {
-    #[serde(rename = "t")]
+    #[cfg_attr(feature = "serde", serde(rename = "t"))]
     T,
-    #[serde(rename = "f")]
+    #[cfg_attr(feature = "serde", serde(rename = "f"))]
     F,
-    #[serde(rename = "true")]
+    #[cfg_attr(feature = "serde", serde(rename = "true"))]
     True,
-    #[serde(rename = "false")]
+    #[cfg_attr(feature = "serde", serde(rename = "false"))]
     False,
-    #[serde(rename = "")]
+    #[cfg_attr(feature = "serde", serde(rename = ""))]
     Empty,
 }
 
@@ -320,13 +328,14 @@ pub type STUnsignedDecimalNumber = u64;
 
 pub type STTwipsMeasure = String;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STVerticalAlignRun {
-    #[serde(rename = "baseline")]
+    #[cfg_attr(feature = "serde", serde(rename = "baseline"))]
     Baseline,
-    #[serde(rename = "superscript")]
+    #[cfg_attr(feature = "serde", serde(rename = "superscript"))]
     Superscript,
-    #[serde(rename = "subscript")]
+    #[cfg_attr(feature = "serde", serde(rename = "subscript"))]
     Subscript,
 }
 
@@ -355,17 +364,18 @@ impl std::str::FromStr for STVerticalAlignRun {
 
 pub type XmlString = String;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STXAlign {
-    #[serde(rename = "left")]
+    #[cfg_attr(feature = "serde", serde(rename = "left"))]
     Left,
-    #[serde(rename = "center")]
+    #[cfg_attr(feature = "serde", serde(rename = "center"))]
     Center,
-    #[serde(rename = "right")]
+    #[cfg_attr(feature = "serde", serde(rename = "right"))]
     Right,
-    #[serde(rename = "inside")]
+    #[cfg_attr(feature = "serde", serde(rename = "inside"))]
     Inside,
-    #[serde(rename = "outside")]
+    #[cfg_attr(feature = "serde", serde(rename = "outside"))]
     Outside,
 }
 
@@ -396,19 +406,20 @@ impl std::str::FromStr for STXAlign {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STYAlign {
-    #[serde(rename = "inline")]
+    #[cfg_attr(feature = "serde", serde(rename = "inline"))]
     Inline,
-    #[serde(rename = "top")]
+    #[cfg_attr(feature = "serde", serde(rename = "top"))]
     Top,
-    #[serde(rename = "center")]
+    #[cfg_attr(feature = "serde", serde(rename = "center"))]
     Center,
-    #[serde(rename = "bottom")]
+    #[cfg_attr(feature = "serde", serde(rename = "bottom"))]
     Bottom,
-    #[serde(rename = "inside")]
+    #[cfg_attr(feature = "serde", serde(rename = "inside"))]
     Inside,
-    #[serde(rename = "outside")]
+    #[cfg_attr(feature = "serde", serde(rename = "outside"))]
     Outside,
 }
 
@@ -441,11 +452,12 @@ impl std::str::FromStr for STYAlign {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STConformanceClass {
-    #[serde(rename = "strict")]
+    #[cfg_attr(feature = "serde", serde(rename = "strict"))]
     Strict,
-    #[serde(rename = "transitional")]
+    #[cfg_attr(feature = "serde", serde(rename = "transitional"))]
     Transitional,
 }
 
@@ -482,15 +494,16 @@ pub type STPositivePercentage = String;
 
 pub type STPositiveFixedPercentage = String;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTransitionSideDirectionType {
-    #[serde(rename = "l")]
+    #[cfg_attr(feature = "serde", serde(rename = "l"))]
     L,
-    #[serde(rename = "u")]
+    #[cfg_attr(feature = "serde", serde(rename = "u"))]
     U,
-    #[serde(rename = "r")]
+    #[cfg_attr(feature = "serde", serde(rename = "r"))]
     R,
-    #[serde(rename = "d")]
+    #[cfg_attr(feature = "serde", serde(rename = "d"))]
     D,
 }
 
@@ -522,15 +535,16 @@ impl std::str::FromStr for STTransitionSideDirectionType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTransitionCornerDirectionType {
-    #[serde(rename = "lu")]
+    #[cfg_attr(feature = "serde", serde(rename = "lu"))]
     Lu,
-    #[serde(rename = "ru")]
+    #[cfg_attr(feature = "serde", serde(rename = "ru"))]
     Ru,
-    #[serde(rename = "ld")]
+    #[cfg_attr(feature = "serde", serde(rename = "ld"))]
     Ld,
-    #[serde(rename = "rd")]
+    #[cfg_attr(feature = "serde", serde(rename = "rd"))]
     Rd,
 }
 
@@ -562,11 +576,12 @@ impl std::str::FromStr for STTransitionCornerDirectionType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTransitionInOutDirectionType {
-    #[serde(rename = "out")]
+    #[cfg_attr(feature = "serde", serde(rename = "out"))]
     Out,
-    #[serde(rename = "in")]
+    #[cfg_attr(feature = "serde", serde(rename = "in"))]
     In,
 }
 
@@ -596,13 +611,14 @@ impl std::str::FromStr for STTransitionInOutDirectionType {
 
 pub type STTransitionEightDirectionType = String;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTransitionSpeed {
-    #[serde(rename = "slow")]
+    #[cfg_attr(feature = "serde", serde(rename = "slow"))]
     Slow,
-    #[serde(rename = "med")]
+    #[cfg_attr(feature = "serde", serde(rename = "med"))]
     Med,
-    #[serde(rename = "fast")]
+    #[cfg_attr(feature = "serde", serde(rename = "fast"))]
     Fast,
 }
 
@@ -633,13 +649,14 @@ pub type STTLTime = String;
 
 pub type STTLTimeNodeID = u32;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STIterateType {
-    #[serde(rename = "el")]
+    #[cfg_attr(feature = "serde", serde(rename = "el"))]
     El,
-    #[serde(rename = "wd")]
+    #[cfg_attr(feature = "serde", serde(rename = "wd"))]
     Wd,
-    #[serde(rename = "lt")]
+    #[cfg_attr(feature = "serde", serde(rename = "lt"))]
     Lt,
 }
 
@@ -666,17 +683,18 @@ impl std::str::FromStr for STIterateType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTLChartSubelementType {
-    #[serde(rename = "gridLegend")]
+    #[cfg_attr(feature = "serde", serde(rename = "gridLegend"))]
     GridLegend,
-    #[serde(rename = "series")]
+    #[cfg_attr(feature = "serde", serde(rename = "series"))]
     Series,
-    #[serde(rename = "category")]
+    #[cfg_attr(feature = "serde", serde(rename = "category"))]
     Category,
-    #[serde(rename = "ptInSeries")]
+    #[cfg_attr(feature = "serde", serde(rename = "ptInSeries"))]
     PtInSeries,
-    #[serde(rename = "ptInCategory")]
+    #[cfg_attr(feature = "serde", serde(rename = "ptInCategory"))]
     PtInCategory,
 }
 
@@ -707,13 +725,14 @@ impl std::str::FromStr for STTLChartSubelementType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTLTriggerRuntimeNode {
-    #[serde(rename = "first")]
+    #[cfg_attr(feature = "serde", serde(rename = "first"))]
     First,
-    #[serde(rename = "last")]
+    #[cfg_attr(feature = "serde", serde(rename = "last"))]
     Last,
-    #[serde(rename = "all")]
+    #[cfg_attr(feature = "serde", serde(rename = "all"))]
     All,
 }
 
@@ -740,29 +759,30 @@ impl std::str::FromStr for STTLTriggerRuntimeNode {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTLTriggerEvent {
-    #[serde(rename = "onBegin")]
+    #[cfg_attr(feature = "serde", serde(rename = "onBegin"))]
     OnBegin,
-    #[serde(rename = "onEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "onEnd"))]
     OnEnd,
-    #[serde(rename = "begin")]
+    #[cfg_attr(feature = "serde", serde(rename = "begin"))]
     Begin,
-    #[serde(rename = "end")]
+    #[cfg_attr(feature = "serde", serde(rename = "end"))]
     End,
-    #[serde(rename = "onClick")]
+    #[cfg_attr(feature = "serde", serde(rename = "onClick"))]
     OnClick,
-    #[serde(rename = "onDblClick")]
+    #[cfg_attr(feature = "serde", serde(rename = "onDblClick"))]
     OnDblClick,
-    #[serde(rename = "onMouseOver")]
+    #[cfg_attr(feature = "serde", serde(rename = "onMouseOver"))]
     OnMouseOver,
-    #[serde(rename = "onMouseOut")]
+    #[cfg_attr(feature = "serde", serde(rename = "onMouseOut"))]
     OnMouseOut,
-    #[serde(rename = "onNext")]
+    #[cfg_attr(feature = "serde", serde(rename = "onNext"))]
     OnNext,
-    #[serde(rename = "onPrev")]
+    #[cfg_attr(feature = "serde", serde(rename = "onPrev"))]
     OnPrev,
-    #[serde(rename = "onStopAudio")]
+    #[cfg_attr(feature = "serde", serde(rename = "onStopAudio"))]
     OnStopAudio,
 }
 
@@ -805,19 +825,20 @@ impl std::str::FromStr for STTLTriggerEvent {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTLTimeNodePresetClassType {
-    #[serde(rename = "entr")]
+    #[cfg_attr(feature = "serde", serde(rename = "entr"))]
     Entr,
-    #[serde(rename = "exit")]
+    #[cfg_attr(feature = "serde", serde(rename = "exit"))]
     Exit,
-    #[serde(rename = "emph")]
+    #[cfg_attr(feature = "serde", serde(rename = "emph"))]
     Emph,
-    #[serde(rename = "path")]
+    #[cfg_attr(feature = "serde", serde(rename = "path"))]
     Path,
-    #[serde(rename = "verb")]
+    #[cfg_attr(feature = "serde", serde(rename = "verb"))]
     Verb,
-    #[serde(rename = "mediacall")]
+    #[cfg_attr(feature = "serde", serde(rename = "mediacall"))]
     Mediacall,
 }
 
@@ -850,13 +871,14 @@ impl std::str::FromStr for STTLTimeNodePresetClassType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTLTimeNodeRestartType {
-    #[serde(rename = "always")]
+    #[cfg_attr(feature = "serde", serde(rename = "always"))]
     Always,
-    #[serde(rename = "whenNotActive")]
+    #[cfg_attr(feature = "serde", serde(rename = "whenNotActive"))]
     WhenNotActive,
-    #[serde(rename = "never")]
+    #[cfg_attr(feature = "serde", serde(rename = "never"))]
     Never,
 }
 
@@ -883,15 +905,16 @@ impl std::str::FromStr for STTLTimeNodeRestartType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTLTimeNodeFillType {
-    #[serde(rename = "remove")]
+    #[cfg_attr(feature = "serde", serde(rename = "remove"))]
     Remove,
-    #[serde(rename = "freeze")]
+    #[cfg_attr(feature = "serde", serde(rename = "freeze"))]
     Freeze,
-    #[serde(rename = "hold")]
+    #[cfg_attr(feature = "serde", serde(rename = "hold"))]
     Hold,
-    #[serde(rename = "transition")]
+    #[cfg_attr(feature = "serde", serde(rename = "transition"))]
     Transition,
 }
 
@@ -920,11 +943,12 @@ impl std::str::FromStr for STTLTimeNodeFillType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTLTimeNodeSyncType {
-    #[serde(rename = "canSlip")]
+    #[cfg_attr(feature = "serde", serde(rename = "canSlip"))]
     CanSlip,
-    #[serde(rename = "locked")]
+    #[cfg_attr(feature = "serde", serde(rename = "locked"))]
     Locked,
 }
 
@@ -949,13 +973,14 @@ impl std::str::FromStr for STTLTimeNodeSyncType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTLTimeNodeMasterRelation {
-    #[serde(rename = "sameClick")]
+    #[cfg_attr(feature = "serde", serde(rename = "sameClick"))]
     SameClick,
-    #[serde(rename = "lastClick")]
+    #[cfg_attr(feature = "serde", serde(rename = "lastClick"))]
     LastClick,
-    #[serde(rename = "nextClick")]
+    #[cfg_attr(feature = "serde", serde(rename = "nextClick"))]
     NextClick,
 }
 
@@ -982,25 +1007,26 @@ impl std::str::FromStr for STTLTimeNodeMasterRelation {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTLTimeNodeType {
-    #[serde(rename = "clickEffect")]
+    #[cfg_attr(feature = "serde", serde(rename = "clickEffect"))]
     ClickEffect,
-    #[serde(rename = "withEffect")]
+    #[cfg_attr(feature = "serde", serde(rename = "withEffect"))]
     WithEffect,
-    #[serde(rename = "afterEffect")]
+    #[cfg_attr(feature = "serde", serde(rename = "afterEffect"))]
     AfterEffect,
-    #[serde(rename = "mainSeq")]
+    #[cfg_attr(feature = "serde", serde(rename = "mainSeq"))]
     MainSeq,
-    #[serde(rename = "interactiveSeq")]
+    #[cfg_attr(feature = "serde", serde(rename = "interactiveSeq"))]
     InteractiveSeq,
-    #[serde(rename = "clickPar")]
+    #[cfg_attr(feature = "serde", serde(rename = "clickPar"))]
     ClickPar,
-    #[serde(rename = "withGroup")]
+    #[cfg_attr(feature = "serde", serde(rename = "withGroup"))]
     WithGroup,
-    #[serde(rename = "afterGroup")]
+    #[cfg_attr(feature = "serde", serde(rename = "afterGroup"))]
     AfterGroup,
-    #[serde(rename = "tmRoot")]
+    #[cfg_attr(feature = "serde", serde(rename = "tmRoot"))]
     TmRoot,
 }
 
@@ -1039,11 +1065,12 @@ impl std::str::FromStr for STTLTimeNodeType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTLNextActionType {
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
-    #[serde(rename = "seek")]
+    #[cfg_attr(feature = "serde", serde(rename = "seek"))]
     Seek,
 }
 
@@ -1068,11 +1095,12 @@ impl std::str::FromStr for STTLNextActionType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTLPreviousActionType {
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
-    #[serde(rename = "skipTimed")]
+    #[cfg_attr(feature = "serde", serde(rename = "skipTimed"))]
     SkipTimed,
 }
 
@@ -1097,17 +1125,18 @@ impl std::str::FromStr for STTLPreviousActionType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTLBehaviorAdditiveType {
-    #[serde(rename = "base")]
+    #[cfg_attr(feature = "serde", serde(rename = "base"))]
     Base,
-    #[serde(rename = "sum")]
+    #[cfg_attr(feature = "serde", serde(rename = "sum"))]
     Sum,
-    #[serde(rename = "repl")]
+    #[cfg_attr(feature = "serde", serde(rename = "repl"))]
     Repl,
-    #[serde(rename = "mult")]
+    #[cfg_attr(feature = "serde", serde(rename = "mult"))]
     Mult,
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
 }
 
@@ -1138,11 +1167,12 @@ impl std::str::FromStr for STTLBehaviorAdditiveType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTLBehaviorAccumulateType {
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
-    #[serde(rename = "always")]
+    #[cfg_attr(feature = "serde", serde(rename = "always"))]
     Always,
 }
 
@@ -1167,11 +1197,12 @@ impl std::str::FromStr for STTLBehaviorAccumulateType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTLBehaviorTransformType {
-    #[serde(rename = "pt")]
+    #[cfg_attr(feature = "serde", serde(rename = "pt"))]
     Pt,
-    #[serde(rename = "img")]
+    #[cfg_attr(feature = "serde", serde(rename = "img"))]
     Img,
 }
 
@@ -1196,11 +1227,12 @@ impl std::str::FromStr for STTLBehaviorTransformType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTLBehaviorOverrideType {
-    #[serde(rename = "normal")]
+    #[cfg_attr(feature = "serde", serde(rename = "normal"))]
     Normal,
-    #[serde(rename = "childStyle")]
+    #[cfg_attr(feature = "serde", serde(rename = "childStyle"))]
     ChildStyle,
 }
 
@@ -1227,13 +1259,14 @@ impl std::str::FromStr for STTLBehaviorOverrideType {
 
 pub type STTLTimeAnimateValueTime = String;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTLAnimateBehaviorCalcMode {
-    #[serde(rename = "discrete")]
+    #[cfg_attr(feature = "serde", serde(rename = "discrete"))]
     Discrete,
-    #[serde(rename = "lin")]
+    #[cfg_attr(feature = "serde", serde(rename = "lin"))]
     Lin,
-    #[serde(rename = "fmla")]
+    #[cfg_attr(feature = "serde", serde(rename = "fmla"))]
     Fmla,
 }
 
@@ -1260,13 +1293,14 @@ impl std::str::FromStr for STTLAnimateBehaviorCalcMode {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTLAnimateBehaviorValueType {
-    #[serde(rename = "str")]
+    #[cfg_attr(feature = "serde", serde(rename = "str"))]
     Str,
-    #[serde(rename = "num")]
+    #[cfg_attr(feature = "serde", serde(rename = "num"))]
     Num,
-    #[serde(rename = "clr")]
+    #[cfg_attr(feature = "serde", serde(rename = "clr"))]
     Clr,
 }
 
@@ -1293,11 +1327,12 @@ impl std::str::FromStr for STTLAnimateBehaviorValueType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTLAnimateColorSpace {
-    #[serde(rename = "rgb")]
+    #[cfg_attr(feature = "serde", serde(rename = "rgb"))]
     Rgb,
-    #[serde(rename = "hsl")]
+    #[cfg_attr(feature = "serde", serde(rename = "hsl"))]
     Hsl,
 }
 
@@ -1322,11 +1357,12 @@ impl std::str::FromStr for STTLAnimateColorSpace {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTLAnimateColorDirection {
-    #[serde(rename = "cw")]
+    #[cfg_attr(feature = "serde", serde(rename = "cw"))]
     Cw,
-    #[serde(rename = "ccw")]
+    #[cfg_attr(feature = "serde", serde(rename = "ccw"))]
     Ccw,
 }
 
@@ -1351,13 +1387,14 @@ impl std::str::FromStr for STTLAnimateColorDirection {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTLAnimateEffectTransition {
-    #[serde(rename = "in")]
+    #[cfg_attr(feature = "serde", serde(rename = "in"))]
     In,
-    #[serde(rename = "out")]
+    #[cfg_attr(feature = "serde", serde(rename = "out"))]
     Out,
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
 }
 
@@ -1384,11 +1421,12 @@ impl std::str::FromStr for STTLAnimateEffectTransition {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTLAnimateMotionBehaviorOrigin {
-    #[serde(rename = "parent")]
+    #[cfg_attr(feature = "serde", serde(rename = "parent"))]
     Parent,
-    #[serde(rename = "layout")]
+    #[cfg_attr(feature = "serde", serde(rename = "layout"))]
     Layout,
 }
 
@@ -1416,11 +1454,12 @@ impl std::str::FromStr for STTLAnimateMotionBehaviorOrigin {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTLAnimateMotionPathEditMode {
-    #[serde(rename = "relative")]
+    #[cfg_attr(feature = "serde", serde(rename = "relative"))]
     Relative,
-    #[serde(rename = "fixed")]
+    #[cfg_attr(feature = "serde", serde(rename = "fixed"))]
     Fixed,
 }
 
@@ -1448,13 +1487,14 @@ impl std::str::FromStr for STTLAnimateMotionPathEditMode {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTLCommandType {
-    #[serde(rename = "evt")]
+    #[cfg_attr(feature = "serde", serde(rename = "evt"))]
     Evt,
-    #[serde(rename = "call")]
+    #[cfg_attr(feature = "serde", serde(rename = "call"))]
     Call,
-    #[serde(rename = "verb")]
+    #[cfg_attr(feature = "serde", serde(rename = "verb"))]
     Verb,
 }
 
@@ -1481,15 +1521,16 @@ impl std::str::FromStr for STTLCommandType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTLParaBuildType {
-    #[serde(rename = "allAtOnce")]
+    #[cfg_attr(feature = "serde", serde(rename = "allAtOnce"))]
     AllAtOnce,
-    #[serde(rename = "p")]
+    #[cfg_attr(feature = "serde", serde(rename = "p"))]
     P,
-    #[serde(rename = "cust")]
+    #[cfg_attr(feature = "serde", serde(rename = "cust"))]
     Cust,
-    #[serde(rename = "whole")]
+    #[cfg_attr(feature = "serde", serde(rename = "whole"))]
     Whole,
 }
 
@@ -1518,41 +1559,42 @@ impl std::str::FromStr for STTLParaBuildType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTLDiagramBuildType {
-    #[serde(rename = "whole")]
+    #[cfg_attr(feature = "serde", serde(rename = "whole"))]
     Whole,
-    #[serde(rename = "depthByNode")]
+    #[cfg_attr(feature = "serde", serde(rename = "depthByNode"))]
     DepthByNode,
-    #[serde(rename = "depthByBranch")]
+    #[cfg_attr(feature = "serde", serde(rename = "depthByBranch"))]
     DepthByBranch,
-    #[serde(rename = "breadthByNode")]
+    #[cfg_attr(feature = "serde", serde(rename = "breadthByNode"))]
     BreadthByNode,
-    #[serde(rename = "breadthByLvl")]
+    #[cfg_attr(feature = "serde", serde(rename = "breadthByLvl"))]
     BreadthByLvl,
-    #[serde(rename = "cw")]
+    #[cfg_attr(feature = "serde", serde(rename = "cw"))]
     Cw,
-    #[serde(rename = "cwIn")]
+    #[cfg_attr(feature = "serde", serde(rename = "cwIn"))]
     CwIn,
-    #[serde(rename = "cwOut")]
+    #[cfg_attr(feature = "serde", serde(rename = "cwOut"))]
     CwOut,
-    #[serde(rename = "ccw")]
+    #[cfg_attr(feature = "serde", serde(rename = "ccw"))]
     Ccw,
-    #[serde(rename = "ccwIn")]
+    #[cfg_attr(feature = "serde", serde(rename = "ccwIn"))]
     CcwIn,
-    #[serde(rename = "ccwOut")]
+    #[cfg_attr(feature = "serde", serde(rename = "ccwOut"))]
     CcwOut,
-    #[serde(rename = "inByRing")]
+    #[cfg_attr(feature = "serde", serde(rename = "inByRing"))]
     InByRing,
-    #[serde(rename = "outByRing")]
+    #[cfg_attr(feature = "serde", serde(rename = "outByRing"))]
     OutByRing,
-    #[serde(rename = "up")]
+    #[cfg_attr(feature = "serde", serde(rename = "up"))]
     Up,
-    #[serde(rename = "down")]
+    #[cfg_attr(feature = "serde", serde(rename = "down"))]
     Down,
-    #[serde(rename = "allAtOnce")]
+    #[cfg_attr(feature = "serde", serde(rename = "allAtOnce"))]
     AllAtOnce,
-    #[serde(rename = "cust")]
+    #[cfg_attr(feature = "serde", serde(rename = "cust"))]
     Cust,
 }
 
@@ -1607,17 +1649,18 @@ impl std::str::FromStr for STTLDiagramBuildType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTLOleChartBuildType {
-    #[serde(rename = "allAtOnce")]
+    #[cfg_attr(feature = "serde", serde(rename = "allAtOnce"))]
     AllAtOnce,
-    #[serde(rename = "series")]
+    #[cfg_attr(feature = "serde", serde(rename = "series"))]
     Series,
-    #[serde(rename = "category")]
+    #[cfg_attr(feature = "serde", serde(rename = "category"))]
     Category,
-    #[serde(rename = "seriesEl")]
+    #[cfg_attr(feature = "serde", serde(rename = "seriesEl"))]
     SeriesEl,
-    #[serde(rename = "categoryEl")]
+    #[cfg_attr(feature = "serde", serde(rename = "categoryEl"))]
     CategoryEl,
 }
 
@@ -1650,11 +1693,12 @@ impl std::str::FromStr for STTLOleChartBuildType {
 
 pub type STName = String;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STDirection {
-    #[serde(rename = "horz")]
+    #[cfg_attr(feature = "serde", serde(rename = "horz"))]
     Horz,
-    #[serde(rename = "vert")]
+    #[cfg_attr(feature = "serde", serde(rename = "vert"))]
     Vert,
 }
 
@@ -1681,13 +1725,14 @@ impl std::str::FromStr for STDirection {
 
 pub type STIndex = u32;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STOleObjectFollowColorScheme {
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
-    #[serde(rename = "full")]
+    #[cfg_attr(feature = "serde", serde(rename = "full"))]
     Full,
-    #[serde(rename = "textAndBackground")]
+    #[cfg_attr(feature = "serde", serde(rename = "textAndBackground"))]
     TextAndBackground,
 }
 
@@ -1718,21 +1763,22 @@ pub type STSlideId = u32;
 
 pub type STSlideMasterId = u32;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STPhotoAlbumLayout {
-    #[serde(rename = "fitToSlide")]
+    #[cfg_attr(feature = "serde", serde(rename = "fitToSlide"))]
     FitToSlide,
-    #[serde(rename = "1pic")]
+    #[cfg_attr(feature = "serde", serde(rename = "1pic"))]
     _1pic,
-    #[serde(rename = "2pic")]
+    #[cfg_attr(feature = "serde", serde(rename = "2pic"))]
     _2pic,
-    #[serde(rename = "4pic")]
+    #[cfg_attr(feature = "serde", serde(rename = "4pic"))]
     _4pic,
-    #[serde(rename = "1picTitle")]
+    #[cfg_attr(feature = "serde", serde(rename = "1picTitle"))]
     _1picTitle,
-    #[serde(rename = "2picTitle")]
+    #[cfg_attr(feature = "serde", serde(rename = "2picTitle"))]
     _2picTitle,
-    #[serde(rename = "4picTitle")]
+    #[cfg_attr(feature = "serde", serde(rename = "4picTitle"))]
     _4picTitle,
 }
 
@@ -1767,21 +1813,22 @@ impl std::str::FromStr for STPhotoAlbumLayout {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STPhotoAlbumFrameShape {
-    #[serde(rename = "frameStyle1")]
+    #[cfg_attr(feature = "serde", serde(rename = "frameStyle1"))]
     FrameStyle1,
-    #[serde(rename = "frameStyle2")]
+    #[cfg_attr(feature = "serde", serde(rename = "frameStyle2"))]
     FrameStyle2,
-    #[serde(rename = "frameStyle3")]
+    #[cfg_attr(feature = "serde", serde(rename = "frameStyle3"))]
     FrameStyle3,
-    #[serde(rename = "frameStyle4")]
+    #[cfg_attr(feature = "serde", serde(rename = "frameStyle4"))]
     FrameStyle4,
-    #[serde(rename = "frameStyle5")]
+    #[cfg_attr(feature = "serde", serde(rename = "frameStyle5"))]
     FrameStyle5,
-    #[serde(rename = "frameStyle6")]
+    #[cfg_attr(feature = "serde", serde(rename = "frameStyle6"))]
     FrameStyle6,
-    #[serde(rename = "frameStyle7")]
+    #[cfg_attr(feature = "serde", serde(rename = "frameStyle7"))]
     FrameStyle7,
 }
 
@@ -1818,39 +1865,40 @@ impl std::str::FromStr for STPhotoAlbumFrameShape {
 
 pub type STSlideSizeCoordinate = i32;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STSlideSizeType {
-    #[serde(rename = "screen4x3")]
+    #[cfg_attr(feature = "serde", serde(rename = "screen4x3"))]
     Screen4x3,
-    #[serde(rename = "letter")]
+    #[cfg_attr(feature = "serde", serde(rename = "letter"))]
     Letter,
-    #[serde(rename = "A4")]
+    #[cfg_attr(feature = "serde", serde(rename = "A4"))]
     A4,
-    #[serde(rename = "35mm")]
+    #[cfg_attr(feature = "serde", serde(rename = "35mm"))]
     _35mm,
-    #[serde(rename = "overhead")]
+    #[cfg_attr(feature = "serde", serde(rename = "overhead"))]
     Overhead,
-    #[serde(rename = "banner")]
+    #[cfg_attr(feature = "serde", serde(rename = "banner"))]
     Banner,
-    #[serde(rename = "custom")]
+    #[cfg_attr(feature = "serde", serde(rename = "custom"))]
     Custom,
-    #[serde(rename = "ledger")]
+    #[cfg_attr(feature = "serde", serde(rename = "ledger"))]
     Ledger,
-    #[serde(rename = "A3")]
+    #[cfg_attr(feature = "serde", serde(rename = "A3"))]
     A3,
-    #[serde(rename = "B4ISO")]
+    #[cfg_attr(feature = "serde", serde(rename = "B4ISO"))]
     B4ISO,
-    #[serde(rename = "B5ISO")]
+    #[cfg_attr(feature = "serde", serde(rename = "B5ISO"))]
     B5ISO,
-    #[serde(rename = "B4JIS")]
+    #[cfg_attr(feature = "serde", serde(rename = "B4JIS"))]
     B4JIS,
-    #[serde(rename = "B5JIS")]
+    #[cfg_attr(feature = "serde", serde(rename = "B5JIS"))]
     B5JIS,
-    #[serde(rename = "hagakiCard")]
+    #[cfg_attr(feature = "serde", serde(rename = "hagakiCard"))]
     HagakiCard,
-    #[serde(rename = "screen16x9")]
+    #[cfg_attr(feature = "serde", serde(rename = "screen16x9"))]
     Screen16x9,
-    #[serde(rename = "screen16x10")]
+    #[cfg_attr(feature = "serde", serde(rename = "screen16x10"))]
     Screen16x10,
 }
 
@@ -1905,19 +1953,20 @@ impl std::str::FromStr for STSlideSizeType {
 
 pub type STBookmarkIdSeed = u32;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STWebColorType {
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
-    #[serde(rename = "browser")]
+    #[cfg_attr(feature = "serde", serde(rename = "browser"))]
     Browser,
-    #[serde(rename = "presentationText")]
+    #[cfg_attr(feature = "serde", serde(rename = "presentationText"))]
     PresentationText,
-    #[serde(rename = "presentationAccent")]
+    #[cfg_attr(feature = "serde", serde(rename = "presentationAccent"))]
     PresentationAccent,
-    #[serde(rename = "whiteTextOnBlack")]
+    #[cfg_attr(feature = "serde", serde(rename = "whiteTextOnBlack"))]
     WhiteTextOnBlack,
-    #[serde(rename = "blackTextOnWhite")]
+    #[cfg_attr(feature = "serde", serde(rename = "blackTextOnWhite"))]
     BlackTextOnWhite,
 }
 
@@ -1950,29 +1999,30 @@ impl std::str::FromStr for STWebColorType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STWebScreenSize {
-    #[serde(rename = "544x376")]
+    #[cfg_attr(feature = "serde", serde(rename = "544x376"))]
     _544x376,
-    #[serde(rename = "640x480")]
+    #[cfg_attr(feature = "serde", serde(rename = "640x480"))]
     _640x480,
-    #[serde(rename = "720x512")]
+    #[cfg_attr(feature = "serde", serde(rename = "720x512"))]
     _720x512,
-    #[serde(rename = "800x600")]
+    #[cfg_attr(feature = "serde", serde(rename = "800x600"))]
     _800x600,
-    #[serde(rename = "1024x768")]
+    #[cfg_attr(feature = "serde", serde(rename = "1024x768"))]
     _1024x768,
-    #[serde(rename = "1152x882")]
+    #[cfg_attr(feature = "serde", serde(rename = "1152x882"))]
     _1152x882,
-    #[serde(rename = "1152x900")]
+    #[cfg_attr(feature = "serde", serde(rename = "1152x900"))]
     _1152x900,
-    #[serde(rename = "1280x1024")]
+    #[cfg_attr(feature = "serde", serde(rename = "1280x1024"))]
     _1280x1024,
-    #[serde(rename = "1600x1200")]
+    #[cfg_attr(feature = "serde", serde(rename = "1600x1200"))]
     _1600x1200,
-    #[serde(rename = "1800x1400")]
+    #[cfg_attr(feature = "serde", serde(rename = "1800x1400"))]
     _1800x1400,
-    #[serde(rename = "1920x1200")]
+    #[cfg_attr(feature = "serde", serde(rename = "1920x1200"))]
     _1920x1200,
 }
 
@@ -2017,25 +2067,26 @@ impl std::str::FromStr for STWebScreenSize {
 
 pub type STWebEncoding = String;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STPrintWhat {
-    #[serde(rename = "slides")]
+    #[cfg_attr(feature = "serde", serde(rename = "slides"))]
     Slides,
-    #[serde(rename = "handouts1")]
+    #[cfg_attr(feature = "serde", serde(rename = "handouts1"))]
     Handouts1,
-    #[serde(rename = "handouts2")]
+    #[cfg_attr(feature = "serde", serde(rename = "handouts2"))]
     Handouts2,
-    #[serde(rename = "handouts3")]
+    #[cfg_attr(feature = "serde", serde(rename = "handouts3"))]
     Handouts3,
-    #[serde(rename = "handouts4")]
+    #[cfg_attr(feature = "serde", serde(rename = "handouts4"))]
     Handouts4,
-    #[serde(rename = "handouts6")]
+    #[cfg_attr(feature = "serde", serde(rename = "handouts6"))]
     Handouts6,
-    #[serde(rename = "handouts9")]
+    #[cfg_attr(feature = "serde", serde(rename = "handouts9"))]
     Handouts9,
-    #[serde(rename = "notes")]
+    #[cfg_attr(feature = "serde", serde(rename = "notes"))]
     Notes,
-    #[serde(rename = "outline")]
+    #[cfg_attr(feature = "serde", serde(rename = "outline"))]
     Outline,
 }
 
@@ -2074,13 +2125,14 @@ impl std::str::FromStr for STPrintWhat {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STPrintColorMode {
-    #[serde(rename = "bw")]
+    #[cfg_attr(feature = "serde", serde(rename = "bw"))]
     Bw,
-    #[serde(rename = "gray")]
+    #[cfg_attr(feature = "serde", serde(rename = "gray"))]
     Gray,
-    #[serde(rename = "clr")]
+    #[cfg_attr(feature = "serde", serde(rename = "clr"))]
     Clr,
 }
 
@@ -2107,39 +2159,40 @@ impl std::str::FromStr for STPrintColorMode {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STPlaceholderType {
-    #[serde(rename = "title")]
+    #[cfg_attr(feature = "serde", serde(rename = "title"))]
     Title,
-    #[serde(rename = "body")]
+    #[cfg_attr(feature = "serde", serde(rename = "body"))]
     Body,
-    #[serde(rename = "ctrTitle")]
+    #[cfg_attr(feature = "serde", serde(rename = "ctrTitle"))]
     CtrTitle,
-    #[serde(rename = "subTitle")]
+    #[cfg_attr(feature = "serde", serde(rename = "subTitle"))]
     SubTitle,
-    #[serde(rename = "dt")]
+    #[cfg_attr(feature = "serde", serde(rename = "dt"))]
     Dt,
-    #[serde(rename = "sldNum")]
+    #[cfg_attr(feature = "serde", serde(rename = "sldNum"))]
     SldNum,
-    #[serde(rename = "ftr")]
+    #[cfg_attr(feature = "serde", serde(rename = "ftr"))]
     Ftr,
-    #[serde(rename = "hdr")]
+    #[cfg_attr(feature = "serde", serde(rename = "hdr"))]
     Hdr,
-    #[serde(rename = "obj")]
+    #[cfg_attr(feature = "serde", serde(rename = "obj"))]
     Obj,
-    #[serde(rename = "chart")]
+    #[cfg_attr(feature = "serde", serde(rename = "chart"))]
     Chart,
-    #[serde(rename = "tbl")]
+    #[cfg_attr(feature = "serde", serde(rename = "tbl"))]
     Tbl,
-    #[serde(rename = "clipArt")]
+    #[cfg_attr(feature = "serde", serde(rename = "clipArt"))]
     ClipArt,
-    #[serde(rename = "dgm")]
+    #[cfg_attr(feature = "serde", serde(rename = "dgm"))]
     Dgm,
-    #[serde(rename = "media")]
+    #[cfg_attr(feature = "serde", serde(rename = "media"))]
     Media,
-    #[serde(rename = "sldImg")]
+    #[cfg_attr(feature = "serde", serde(rename = "sldImg"))]
     SldImg,
-    #[serde(rename = "pic")]
+    #[cfg_attr(feature = "serde", serde(rename = "pic"))]
     Pic,
 }
 
@@ -2192,13 +2245,14 @@ impl std::str::FromStr for STPlaceholderType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STPlaceholderSize {
-    #[serde(rename = "full")]
+    #[cfg_attr(feature = "serde", serde(rename = "full"))]
     Full,
-    #[serde(rename = "half")]
+    #[cfg_attr(feature = "serde", serde(rename = "half"))]
     Half,
-    #[serde(rename = "quarter")]
+    #[cfg_attr(feature = "serde", serde(rename = "quarter"))]
     Quarter,
 }
 
@@ -2225,79 +2279,80 @@ impl std::str::FromStr for STPlaceholderSize {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STSlideLayoutType {
-    #[serde(rename = "title")]
+    #[cfg_attr(feature = "serde", serde(rename = "title"))]
     Title,
-    #[serde(rename = "tx")]
+    #[cfg_attr(feature = "serde", serde(rename = "tx"))]
     Tx,
-    #[serde(rename = "twoColTx")]
+    #[cfg_attr(feature = "serde", serde(rename = "twoColTx"))]
     TwoColTx,
-    #[serde(rename = "tbl")]
+    #[cfg_attr(feature = "serde", serde(rename = "tbl"))]
     Tbl,
-    #[serde(rename = "txAndChart")]
+    #[cfg_attr(feature = "serde", serde(rename = "txAndChart"))]
     TxAndChart,
-    #[serde(rename = "chartAndTx")]
+    #[cfg_attr(feature = "serde", serde(rename = "chartAndTx"))]
     ChartAndTx,
-    #[serde(rename = "dgm")]
+    #[cfg_attr(feature = "serde", serde(rename = "dgm"))]
     Dgm,
-    #[serde(rename = "chart")]
+    #[cfg_attr(feature = "serde", serde(rename = "chart"))]
     Chart,
-    #[serde(rename = "txAndClipArt")]
+    #[cfg_attr(feature = "serde", serde(rename = "txAndClipArt"))]
     TxAndClipArt,
-    #[serde(rename = "clipArtAndTx")]
+    #[cfg_attr(feature = "serde", serde(rename = "clipArtAndTx"))]
     ClipArtAndTx,
-    #[serde(rename = "titleOnly")]
+    #[cfg_attr(feature = "serde", serde(rename = "titleOnly"))]
     TitleOnly,
-    #[serde(rename = "blank")]
+    #[cfg_attr(feature = "serde", serde(rename = "blank"))]
     Blank,
-    #[serde(rename = "txAndObj")]
+    #[cfg_attr(feature = "serde", serde(rename = "txAndObj"))]
     TxAndObj,
-    #[serde(rename = "objAndTx")]
+    #[cfg_attr(feature = "serde", serde(rename = "objAndTx"))]
     ObjAndTx,
-    #[serde(rename = "objOnly")]
+    #[cfg_attr(feature = "serde", serde(rename = "objOnly"))]
     ObjOnly,
-    #[serde(rename = "obj")]
+    #[cfg_attr(feature = "serde", serde(rename = "obj"))]
     Obj,
-    #[serde(rename = "txAndMedia")]
+    #[cfg_attr(feature = "serde", serde(rename = "txAndMedia"))]
     TxAndMedia,
-    #[serde(rename = "mediaAndTx")]
+    #[cfg_attr(feature = "serde", serde(rename = "mediaAndTx"))]
     MediaAndTx,
-    #[serde(rename = "objOverTx")]
+    #[cfg_attr(feature = "serde", serde(rename = "objOverTx"))]
     ObjOverTx,
-    #[serde(rename = "txOverObj")]
+    #[cfg_attr(feature = "serde", serde(rename = "txOverObj"))]
     TxOverObj,
-    #[serde(rename = "txAndTwoObj")]
+    #[cfg_attr(feature = "serde", serde(rename = "txAndTwoObj"))]
     TxAndTwoObj,
-    #[serde(rename = "twoObjAndTx")]
+    #[cfg_attr(feature = "serde", serde(rename = "twoObjAndTx"))]
     TwoObjAndTx,
-    #[serde(rename = "twoObjOverTx")]
+    #[cfg_attr(feature = "serde", serde(rename = "twoObjOverTx"))]
     TwoObjOverTx,
-    #[serde(rename = "fourObj")]
+    #[cfg_attr(feature = "serde", serde(rename = "fourObj"))]
     FourObj,
-    #[serde(rename = "vertTx")]
+    #[cfg_attr(feature = "serde", serde(rename = "vertTx"))]
     VertTx,
-    #[serde(rename = "clipArtAndVertTx")]
+    #[cfg_attr(feature = "serde", serde(rename = "clipArtAndVertTx"))]
     ClipArtAndVertTx,
-    #[serde(rename = "vertTitleAndTx")]
+    #[cfg_attr(feature = "serde", serde(rename = "vertTitleAndTx"))]
     VertTitleAndTx,
-    #[serde(rename = "vertTitleAndTxOverChart")]
+    #[cfg_attr(feature = "serde", serde(rename = "vertTitleAndTxOverChart"))]
     VertTitleAndTxOverChart,
-    #[serde(rename = "twoObj")]
+    #[cfg_attr(feature = "serde", serde(rename = "twoObj"))]
     TwoObj,
-    #[serde(rename = "objAndTwoObj")]
+    #[cfg_attr(feature = "serde", serde(rename = "objAndTwoObj"))]
     ObjAndTwoObj,
-    #[serde(rename = "twoObjAndObj")]
+    #[cfg_attr(feature = "serde", serde(rename = "twoObjAndObj"))]
     TwoObjAndObj,
-    #[serde(rename = "cust")]
+    #[cfg_attr(feature = "serde", serde(rename = "cust"))]
     Cust,
-    #[serde(rename = "secHead")]
+    #[cfg_attr(feature = "serde", serde(rename = "secHead"))]
     SecHead,
-    #[serde(rename = "twoTxTwoObj")]
+    #[cfg_attr(feature = "serde", serde(rename = "twoTxTwoObj"))]
     TwoTxTwoObj,
-    #[serde(rename = "objTx")]
+    #[cfg_attr(feature = "serde", serde(rename = "objTx"))]
     ObjTx,
-    #[serde(rename = "picTx")]
+    #[cfg_attr(feature = "serde", serde(rename = "picTx"))]
     PicTx,
 }
 
@@ -2392,13 +2447,14 @@ impl std::str::FromStr for STSlideLayoutType {
 
 pub type STSlideLayoutId = u32;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STSplitterBarState {
-    #[serde(rename = "minimized")]
+    #[cfg_attr(feature = "serde", serde(rename = "minimized"))]
     Minimized,
-    #[serde(rename = "restored")]
+    #[cfg_attr(feature = "serde", serde(rename = "restored"))]
     Restored,
-    #[serde(rename = "maximized")]
+    #[cfg_attr(feature = "serde", serde(rename = "maximized"))]
     Maximized,
 }
 
@@ -2425,23 +2481,24 @@ impl std::str::FromStr for STSplitterBarState {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STViewType {
-    #[serde(rename = "sldView")]
+    #[cfg_attr(feature = "serde", serde(rename = "sldView"))]
     SldView,
-    #[serde(rename = "sldMasterView")]
+    #[cfg_attr(feature = "serde", serde(rename = "sldMasterView"))]
     SldMasterView,
-    #[serde(rename = "notesView")]
+    #[cfg_attr(feature = "serde", serde(rename = "notesView"))]
     NotesView,
-    #[serde(rename = "handoutView")]
+    #[cfg_attr(feature = "serde", serde(rename = "handoutView"))]
     HandoutView,
-    #[serde(rename = "notesMasterView")]
+    #[cfg_attr(feature = "serde", serde(rename = "notesMasterView"))]
     NotesMasterView,
-    #[serde(rename = "outlineView")]
+    #[cfg_attr(feature = "serde", serde(rename = "outlineView"))]
     OutlineView,
-    #[serde(rename = "sldSorterView")]
+    #[cfg_attr(feature = "serde", serde(rename = "sldSorterView"))]
     SldSorterView,
-    #[serde(rename = "sldThumbnailView")]
+    #[cfg_attr(feature = "serde", serde(rename = "sldThumbnailView"))]
     SldThumbnailView,
 }
 
@@ -2478,3969 +2535,5711 @@ impl std::str::FromStr for STViewType {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum EGSlideListChoice {
-    #[serde(rename = "sldAll")]
+    #[cfg_attr(feature = "serde", serde(rename = "sldAll"))]
     SldAll(Box<CTEmpty>),
-    #[serde(rename = "sldRg")]
+    #[cfg_attr(feature = "serde", serde(rename = "sldRg"))]
     SldRg(Box<CTIndexRange>),
-    #[serde(rename = "custShow")]
+    #[cfg_attr(feature = "serde", serde(rename = "custShow"))]
     CustShow(Box<CTCustomShowId>),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum EGShowType {
-    #[serde(rename = "present")]
+    #[cfg_attr(feature = "serde", serde(rename = "present"))]
     Present(Box<CTEmpty>),
-    #[serde(rename = "browse")]
+    #[cfg_attr(feature = "serde", serde(rename = "browse"))]
     Browse(Box<CTShowInfoBrowse>),
-    #[serde(rename = "kiosk")]
+    #[cfg_attr(feature = "serde", serde(rename = "kiosk"))]
     Kiosk(Box<CTShowInfoKiosk>),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum EGBackground {
-    #[serde(rename = "bgPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "bgPr"))]
     BgPr(Box<CTBackgroundProperties>),
-    #[serde(rename = "bgRef")]
+    #[cfg_attr(feature = "serde", serde(rename = "bgRef"))]
     BgRef(Box<ooxml_dml::types::CTStyleMatrixReference>),
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSideDirectionTransition {
-    #[serde(rename = "@dir")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@dir"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub dir: Option<STTransitionSideDirectionType>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTCornerDirectionTransition {
-    #[serde(rename = "@dir")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@dir"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub dir: Option<STTransitionCornerDirectionType>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTEightDirectionTransition {
-    #[serde(rename = "@dir")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@dir"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub dir: Option<STTransitionEightDirectionType>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTOrientationTransition {
-    #[serde(rename = "@dir")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@dir"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub dir: Option<STDirection>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTInOutTransition {
-    #[serde(rename = "@dir")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@dir"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub dir: Option<STTransitionInOutDirectionType>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTOptionalBlackTransition {
-    #[serde(rename = "@thruBlk")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@thruBlk"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub thru_blk: Option<bool>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSplitTransition {
-    #[serde(rename = "@orient")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@orient"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub orient: Option<STDirection>,
-    #[serde(rename = "@dir")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@dir"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub dir: Option<STTransitionInOutDirectionType>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTWheelTransition {
-    #[serde(rename = "@spokes")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@spokes"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spokes: Option<u32>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTransitionStartSoundAction {
-    #[serde(rename = "@loop")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@loop"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub r#loop: Option<bool>,
-    #[serde(rename = "snd")]
+    #[cfg_attr(feature = "serde", serde(rename = "snd"))]
     pub snd: Box<ooxml_dml::types::CTEmbeddedWAVAudioFile>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTransitionSoundAction {
-    #[serde(rename = "stSnd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "stSnd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub st_snd: Option<Box<CTTransitionStartSoundAction>>,
-    #[serde(rename = "endSnd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "endSnd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub end_snd: Option<Box<CTEmpty>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SlideTransition {
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "@spd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@spd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spd: Option<STTransitionSpeed>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "@advClick")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@advClick"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub adv_click: Option<bool>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "@advTm")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@advTm"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub adv_tm: Option<u32>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "blinds")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "blinds"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub blinds: Option<Box<CTOrientationTransition>>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "checker")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "checker"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub checker: Option<Box<CTOrientationTransition>>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "circle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "circle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub circle: Option<Box<CTEmpty>>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "dissolve")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "dissolve"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub dissolve: Option<Box<CTEmpty>>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "comb")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "comb"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub comb: Option<Box<CTOrientationTransition>>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "cover")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "cover"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cover: Option<Box<CTEightDirectionTransition>>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "cut")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "cut"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cut: Option<Box<CTOptionalBlackTransition>>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "diamond")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "diamond"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub diamond: Option<Box<CTEmpty>>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "fade")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "fade"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub fade: Option<Box<CTOptionalBlackTransition>>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "newsflash")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "newsflash"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub newsflash: Option<Box<CTEmpty>>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "plus")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "plus"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub plus: Option<Box<CTEmpty>>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "pull")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pull"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub pull: Option<Box<CTEightDirectionTransition>>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "push")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "push"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub push: Option<Box<CTSideDirectionTransition>>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "random")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "random"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub random: Option<Box<CTEmpty>>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "randomBar")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "randomBar"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub random_bar: Option<Box<CTOrientationTransition>>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "split")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "split"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub split: Option<Box<CTSplitTransition>>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "strips")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "strips"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub strips: Option<Box<CTCornerDirectionTransition>>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "wedge")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "wedge"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub wedge: Option<Box<CTEmpty>>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "wheel")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "wheel"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub wheel: Option<Box<CTWheelTransition>>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "wipe")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "wipe"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub wipe: Option<Box<CTSideDirectionTransition>>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "zoom")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "zoom"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub zoom: Option<Box<CTInOutTransition>>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "sndAc")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sndAc"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub snd_ac: Option<Box<CTTransitionSoundAction>>,
     #[cfg(feature = "pml-extensions")]
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionListModify>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLIterateIntervalTime {
-    #[serde(rename = "@val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@val"))]
     pub value: STTLTime,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLIterateIntervalPercentage {
-    #[serde(rename = "@val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@val"))]
     pub value: ooxml_dml::types::STPositivePercentage,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLIterateData {
-    #[serde(rename = "@type")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@type"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r#type: Option<STIterateType>,
-    #[serde(rename = "@backwards")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@backwards"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub backwards: Option<bool>,
-    #[serde(rename = "tmAbs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tmAbs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tm_abs: Option<Box<CTTLIterateIntervalTime>>,
-    #[serde(rename = "tmPct")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tmPct"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tm_pct: Option<Box<CTTLIterateIntervalPercentage>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLSubShapeId {
-    #[serde(rename = "@spid")]
+    #[cfg_attr(feature = "serde", serde(rename = "@spid"))]
     pub spid: ooxml_dml::types::STShapeID,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLTextTargetElement {
-    #[serde(rename = "charRg")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "charRg"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub char_rg: Option<Box<CTIndexRange>>,
-    #[serde(rename = "pRg")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pRg"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub p_rg: Option<Box<CTIndexRange>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLOleChartTargetElement {
-    #[serde(rename = "@type")]
+    #[cfg_attr(feature = "serde", serde(rename = "@type"))]
     pub r#type: STTLChartSubelementType,
-    #[serde(rename = "@lvl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@lvl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub lvl: Option<u32>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLShapeTargetElement {
-    #[serde(rename = "@spid")]
+    #[cfg_attr(feature = "serde", serde(rename = "@spid"))]
     pub spid: ooxml_dml::types::STDrawingElementId,
-    #[serde(rename = "bg")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bg"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bg: Option<Box<CTEmpty>>,
-    #[serde(rename = "subSp")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "subSp"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sub_sp: Option<Box<CTTLSubShapeId>>,
-    #[serde(rename = "oleChartEl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "oleChartEl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ole_chart_el: Option<Box<CTTLOleChartTargetElement>>,
-    #[serde(rename = "txEl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "txEl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tx_el: Option<Box<CTTLTextTargetElement>>,
-    #[serde(rename = "graphicEl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "graphicEl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub graphic_el: Option<Box<ooxml_dml::types::CTAnimationElementChoice>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLTimeTargetElement {
-    #[serde(rename = "sldTgt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sldTgt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sld_tgt: Option<Box<CTEmpty>>,
-    #[serde(rename = "sndTgt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sndTgt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub snd_tgt: Option<Box<ooxml_dml::types::CTEmbeddedWAVAudioFile>>,
-    #[serde(rename = "spTgt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "spTgt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sp_tgt: Option<Box<CTTLShapeTargetElement>>,
-    #[serde(rename = "inkTgt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "inkTgt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ink_tgt: Option<Box<CTTLSubShapeId>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLTriggerTimeNodeID {
-    #[serde(rename = "@val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@val"))]
     pub value: STTLTimeNodeID,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLTriggerRuntimeNode {
-    #[serde(rename = "@val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@val"))]
     pub value: STTLTriggerRuntimeNode,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLTimeCondition {
-    #[serde(rename = "@evt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@evt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub evt: Option<STTLTriggerEvent>,
-    #[serde(rename = "@delay")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@delay"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub delay: Option<STTLTime>,
-    #[serde(rename = "tgtEl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tgtEl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tgt_el: Option<Box<CTTLTimeTargetElement>>,
-    #[serde(rename = "tn")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tn"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tn: Option<Box<CTTLTriggerTimeNodeID>>,
-    #[serde(rename = "rtn")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rtn"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rtn: Option<Box<CTTLTriggerRuntimeNode>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLTimeConditionList {
-    #[serde(rename = "cond")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "cond"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub cond: Vec<CTTLTimeCondition>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTimeNodeList {
-    #[serde(rename = "par")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "par"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub par: Vec<TLTimeNodeParallelElement>,
-    #[serde(rename = "seq")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "seq"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub seq: Vec<CTTLTimeNodeSequence>,
-    #[serde(rename = "excl")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "excl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub excl: Vec<TLTimeNodeExclusiveElement>,
-    #[serde(rename = "anim")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "anim"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub anim: Vec<CTTLAnimateBehavior>,
-    #[serde(rename = "animClr")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "animClr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub anim_clr: Vec<CTTLAnimateColorBehavior>,
-    #[serde(rename = "animEffect")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "animEffect"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub anim_effect: Vec<CTTLAnimateEffectBehavior>,
-    #[serde(rename = "animMotion")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "animMotion"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub anim_motion: Vec<CTTLAnimateMotionBehavior>,
-    #[serde(rename = "animRot")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "animRot"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub anim_rot: Vec<CTTLAnimateRotationBehavior>,
-    #[serde(rename = "animScale")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "animScale"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub anim_scale: Vec<CTTLAnimateScaleBehavior>,
-    #[serde(rename = "cmd")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "cmd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub cmd: Vec<CTTLCommandBehavior>,
-    #[serde(rename = "set")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "set"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub set: Vec<CTTLSetBehavior>,
-    #[serde(rename = "audio")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "audio"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub audio: Vec<CTTLMediaNodeAudio>,
-    #[serde(rename = "video")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "video"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub video: Vec<CTTLMediaNodeVideo>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLCommonTimeNodeData {
-    #[serde(rename = "@id")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@id"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub id: Option<STTLTimeNodeID>,
-    #[serde(rename = "@presetID")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@presetID"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub preset_i_d: Option<i32>,
-    #[serde(rename = "@presetClass")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@presetClass"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub preset_class: Option<STTLTimeNodePresetClassType>,
-    #[serde(rename = "@presetSubtype")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@presetSubtype"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub preset_subtype: Option<i32>,
-    #[serde(rename = "@dur")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@dur"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub dur: Option<STTLTime>,
-    #[serde(rename = "@repeatCount")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@repeatCount"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub repeat_count: Option<STTLTime>,
-    #[serde(rename = "@repeatDur")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@repeatDur"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub repeat_dur: Option<STTLTime>,
-    #[serde(rename = "@spd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@spd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spd: Option<ooxml_dml::types::STPercentage>,
-    #[serde(rename = "@accel")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@accel"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub accel: Option<ooxml_dml::types::STPositiveFixedPercentage>,
-    #[serde(rename = "@decel")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@decel"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub decel: Option<ooxml_dml::types::STPositiveFixedPercentage>,
-    #[serde(rename = "@autoRev")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@autoRev"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub auto_rev: Option<bool>,
-    #[serde(rename = "@restart")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@restart"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub restart: Option<STTLTimeNodeRestartType>,
-    #[serde(rename = "@fill")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@fill"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub fill: Option<STTLTimeNodeFillType>,
-    #[serde(rename = "@syncBehavior")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@syncBehavior"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sync_behavior: Option<STTLTimeNodeSyncType>,
-    #[serde(rename = "@tmFilter")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@tmFilter"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tm_filter: Option<String>,
-    #[serde(rename = "@evtFilter")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@evtFilter"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub evt_filter: Option<String>,
-    #[serde(rename = "@display")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@display"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub display: Option<bool>,
-    #[serde(rename = "@masterRel")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@masterRel"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub master_rel: Option<STTLTimeNodeMasterRelation>,
-    #[serde(rename = "@bldLvl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@bldLvl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bld_lvl: Option<i32>,
-    #[serde(rename = "@grpId")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@grpId"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub grp_id: Option<u32>,
-    #[serde(rename = "@afterEffect")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@afterEffect"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub after_effect: Option<bool>,
-    #[serde(rename = "@nodeType")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@nodeType"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub node_type: Option<STTLTimeNodeType>,
-    #[serde(rename = "@nodePh")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@nodePh"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub node_ph: Option<bool>,
-    #[serde(rename = "stCondLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "stCondLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub st_cond_lst: Option<Box<CTTLTimeConditionList>>,
-    #[serde(rename = "endCondLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "endCondLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub end_cond_lst: Option<Box<CTTLTimeConditionList>>,
-    #[serde(rename = "endSync")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "endSync"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub end_sync: Option<Box<CTTLTimeCondition>>,
-    #[serde(rename = "iterate")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "iterate"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub iterate: Option<Box<CTTLIterateData>>,
-    #[serde(rename = "childTnLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "childTnLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub child_tn_lst: Option<Box<CTTimeNodeList>>,
-    #[serde(rename = "subTnLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "subTnLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sub_tn_lst: Option<Box<CTTimeNodeList>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
 pub type TLTimeNodeParallelElement = Box<CTTLCommonTimeNodeData>;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLTimeNodeSequence {
-    #[serde(rename = "@concurrent")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@concurrent"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub concurrent: Option<bool>,
-    #[serde(rename = "@prevAc")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@prevAc"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub prev_ac: Option<STTLPreviousActionType>,
-    #[serde(rename = "@nextAc")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@nextAc"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub next_ac: Option<STTLNextActionType>,
-    #[serde(rename = "cTn")]
+    #[cfg_attr(feature = "serde", serde(rename = "cTn"))]
     pub c_tn: Box<CTTLCommonTimeNodeData>,
-    #[serde(rename = "prevCondLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "prevCondLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub prev_cond_lst: Option<Box<CTTLTimeConditionList>>,
-    #[serde(rename = "nextCondLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "nextCondLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub next_cond_lst: Option<Box<CTTLTimeConditionList>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
 pub type TLTimeNodeExclusiveElement = Box<CTTLCommonTimeNodeData>;
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLBehaviorAttributeNameList {
-    #[serde(rename = "attrName")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "attrName"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub attr_name: Vec<String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLCommonBehaviorData {
-    #[serde(rename = "@additive")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@additive"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub additive: Option<STTLBehaviorAdditiveType>,
-    #[serde(rename = "@accumulate")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@accumulate"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub accumulate: Option<STTLBehaviorAccumulateType>,
-    #[serde(rename = "@xfrmType")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@xfrmType"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub xfrm_type: Option<STTLBehaviorTransformType>,
-    #[serde(rename = "@from")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@from"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub from: Option<String>,
-    #[serde(rename = "@to")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@to"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub to: Option<String>,
-    #[serde(rename = "@by")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@by"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub by: Option<String>,
-    #[serde(rename = "@rctx")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@rctx"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rctx: Option<String>,
-    #[serde(rename = "@override")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@override"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r#override: Option<STTLBehaviorOverrideType>,
-    #[serde(rename = "cTn")]
+    #[cfg_attr(feature = "serde", serde(rename = "cTn"))]
     pub c_tn: Box<CTTLCommonTimeNodeData>,
-    #[serde(rename = "tgtEl")]
+    #[cfg_attr(feature = "serde", serde(rename = "tgtEl"))]
     pub tgt_el: Box<CTTLTimeTargetElement>,
-    #[serde(rename = "attrNameLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "attrNameLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub attr_name_lst: Option<Box<CTTLBehaviorAttributeNameList>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLAnimVariantBooleanVal {
-    #[serde(rename = "@val")]
-    #[serde(with = "ooxml_xml::ooxml_bool_required")]
+    #[cfg_attr(feature = "serde", serde(rename = "@val"))]
+    #[cfg_attr(feature = "serde", serde(with = "ooxml_xml::ooxml_bool_required"))]
     pub value: bool,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLAnimVariantIntegerVal {
-    #[serde(rename = "@val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@val"))]
     pub value: i32,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLAnimVariantFloatVal {
-    #[serde(rename = "@val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@val"))]
     pub value: f32,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLAnimVariantStringVal {
-    #[serde(rename = "@val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@val"))]
     pub value: String,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLAnimVariant {
-    #[serde(rename = "boolVal")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "boolVal"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bool_val: Option<Box<CTTLAnimVariantBooleanVal>>,
-    #[serde(rename = "intVal")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "intVal"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub int_val: Option<Box<CTTLAnimVariantIntegerVal>>,
-    #[serde(rename = "fltVal")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "fltVal"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub flt_val: Option<Box<CTTLAnimVariantFloatVal>>,
-    #[serde(rename = "strVal")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "strVal"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub str_val: Option<Box<CTTLAnimVariantStringVal>>,
-    #[serde(rename = "clrVal")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "clrVal"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub clr_val: Option<Box<ooxml_dml::types::CTColor>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLTimeAnimateValue {
-    #[serde(rename = "@tm")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@tm"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tm: Option<STTLTimeAnimateValueTime>,
-    #[serde(rename = "@fmla")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@fmla"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub fmla: Option<String>,
-    #[serde(rename = "val")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "val"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<Box<CTTLAnimVariant>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLTimeAnimateValueList {
-    #[serde(rename = "tav")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "tav"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub tav: Vec<CTTLTimeAnimateValue>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLAnimateBehavior {
-    #[serde(rename = "@by")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@by"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub by: Option<String>,
-    #[serde(rename = "@from")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@from"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub from: Option<String>,
-    #[serde(rename = "@to")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@to"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub to: Option<String>,
-    #[serde(rename = "@calcmode")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@calcmode"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub calcmode: Option<STTLAnimateBehaviorCalcMode>,
-    #[serde(rename = "@valueType")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@valueType"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value_type: Option<STTLAnimateBehaviorValueType>,
-    #[serde(rename = "cBhvr")]
+    #[cfg_attr(feature = "serde", serde(rename = "cBhvr"))]
     pub c_bhvr: Box<CTTLCommonBehaviorData>,
-    #[serde(rename = "tavLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tavLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tav_lst: Option<Box<CTTLTimeAnimateValueList>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLByRgbColorTransform {
-    #[serde(rename = "@r")]
+    #[cfg_attr(feature = "serde", serde(rename = "@r"))]
     pub reference: ooxml_dml::types::STFixedPercentage,
-    #[serde(rename = "@g")]
+    #[cfg_attr(feature = "serde", serde(rename = "@g"))]
     pub g: ooxml_dml::types::STFixedPercentage,
-    #[serde(rename = "@b")]
+    #[cfg_attr(feature = "serde", serde(rename = "@b"))]
     pub b: ooxml_dml::types::STFixedPercentage,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLByHslColorTransform {
-    #[serde(rename = "@h")]
+    #[cfg_attr(feature = "serde", serde(rename = "@h"))]
     pub height: ooxml_dml::types::STAngle,
-    #[serde(rename = "@s")]
+    #[cfg_attr(feature = "serde", serde(rename = "@s"))]
     pub s: ooxml_dml::types::STFixedPercentage,
-    #[serde(rename = "@l")]
+    #[cfg_attr(feature = "serde", serde(rename = "@l"))]
     pub l: ooxml_dml::types::STFixedPercentage,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLByAnimateColorTransform {
-    #[serde(rename = "rgb")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rgb"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rgb: Option<Box<CTTLByRgbColorTransform>>,
-    #[serde(rename = "hsl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "hsl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hsl: Option<Box<CTTLByHslColorTransform>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLAnimateColorBehavior {
-    #[serde(rename = "@clrSpc")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@clrSpc"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub clr_spc: Option<STTLAnimateColorSpace>,
-    #[serde(rename = "@dir")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@dir"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub dir: Option<STTLAnimateColorDirection>,
-    #[serde(rename = "cBhvr")]
+    #[cfg_attr(feature = "serde", serde(rename = "cBhvr"))]
     pub c_bhvr: Box<CTTLCommonBehaviorData>,
-    #[serde(rename = "by")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "by"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub by: Option<Box<CTTLByAnimateColorTransform>>,
-    #[serde(rename = "from")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "from"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub from: Option<Box<ooxml_dml::types::CTColor>>,
-    #[serde(rename = "to")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "to"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub to: Option<Box<ooxml_dml::types::CTColor>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLAnimateEffectBehavior {
-    #[serde(rename = "@transition")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@transition"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub transition: Option<STTLAnimateEffectTransition>,
-    #[serde(rename = "@filter")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@filter"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub filter: Option<String>,
-    #[serde(rename = "@prLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@prLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub pr_lst: Option<String>,
-    #[serde(rename = "cBhvr")]
+    #[cfg_attr(feature = "serde", serde(rename = "cBhvr"))]
     pub c_bhvr: Box<CTTLCommonBehaviorData>,
-    #[serde(rename = "progress")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "progress"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub progress: Option<Box<CTTLAnimVariant>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLPoint {
-    #[serde(rename = "@x")]
+    #[cfg_attr(feature = "serde", serde(rename = "@x"))]
     pub x: ooxml_dml::types::STPercentage,
-    #[serde(rename = "@y")]
+    #[cfg_attr(feature = "serde", serde(rename = "@y"))]
     pub y: ooxml_dml::types::STPercentage,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLAnimateMotionBehavior {
-    #[serde(rename = "@origin")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@origin"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub origin: Option<STTLAnimateMotionBehaviorOrigin>,
-    #[serde(rename = "@path")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@path"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub path: Option<String>,
-    #[serde(rename = "@pathEditMode")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@pathEditMode"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub path_edit_mode: Option<STTLAnimateMotionPathEditMode>,
-    #[serde(rename = "@rAng")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@rAng"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r_ang: Option<ooxml_dml::types::STAngle>,
-    #[serde(rename = "@ptsTypes")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@ptsTypes"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub pts_types: Option<String>,
-    #[serde(rename = "cBhvr")]
+    #[cfg_attr(feature = "serde", serde(rename = "cBhvr"))]
     pub c_bhvr: Box<CTTLCommonBehaviorData>,
-    #[serde(rename = "by")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "by"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub by: Option<Box<CTTLPoint>>,
-    #[serde(rename = "from")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "from"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub from: Option<Box<CTTLPoint>>,
-    #[serde(rename = "to")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "to"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub to: Option<Box<CTTLPoint>>,
-    #[serde(rename = "rCtr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rCtr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r_ctr: Option<Box<CTTLPoint>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLAnimateRotationBehavior {
-    #[serde(rename = "@by")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@by"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub by: Option<ooxml_dml::types::STAngle>,
-    #[serde(rename = "@from")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@from"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub from: Option<ooxml_dml::types::STAngle>,
-    #[serde(rename = "@to")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@to"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub to: Option<ooxml_dml::types::STAngle>,
-    #[serde(rename = "cBhvr")]
+    #[cfg_attr(feature = "serde", serde(rename = "cBhvr"))]
     pub c_bhvr: Box<CTTLCommonBehaviorData>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLAnimateScaleBehavior {
-    #[serde(rename = "@zoomContents")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@zoomContents"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub zoom_contents: Option<bool>,
-    #[serde(rename = "cBhvr")]
+    #[cfg_attr(feature = "serde", serde(rename = "cBhvr"))]
     pub c_bhvr: Box<CTTLCommonBehaviorData>,
-    #[serde(rename = "by")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "by"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub by: Option<Box<CTTLPoint>>,
-    #[serde(rename = "from")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "from"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub from: Option<Box<CTTLPoint>>,
-    #[serde(rename = "to")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "to"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub to: Option<Box<CTTLPoint>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLCommandBehavior {
-    #[serde(rename = "@type")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@type"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r#type: Option<STTLCommandType>,
-    #[serde(rename = "@cmd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@cmd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cmd: Option<String>,
-    #[serde(rename = "cBhvr")]
+    #[cfg_attr(feature = "serde", serde(rename = "cBhvr"))]
     pub c_bhvr: Box<CTTLCommonBehaviorData>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLSetBehavior {
-    #[serde(rename = "cBhvr")]
+    #[cfg_attr(feature = "serde", serde(rename = "cBhvr"))]
     pub c_bhvr: Box<CTTLCommonBehaviorData>,
-    #[serde(rename = "to")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "to"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub to: Option<Box<CTTLAnimVariant>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLCommonMediaNodeData {
-    #[serde(rename = "@vol")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@vol"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub vol: Option<ooxml_dml::types::STPositiveFixedPercentage>,
-    #[serde(rename = "@mute")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@mute"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub mute: Option<bool>,
-    #[serde(rename = "@numSld")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@numSld"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub num_sld: Option<u32>,
-    #[serde(rename = "@showWhenStopped")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@showWhenStopped"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub show_when_stopped: Option<bool>,
-    #[serde(rename = "cTn")]
+    #[cfg_attr(feature = "serde", serde(rename = "cTn"))]
     pub c_tn: Box<CTTLCommonTimeNodeData>,
-    #[serde(rename = "tgtEl")]
+    #[cfg_attr(feature = "serde", serde(rename = "tgtEl"))]
     pub tgt_el: Box<CTTLTimeTargetElement>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLMediaNodeAudio {
-    #[serde(rename = "@isNarration")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@isNarration"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub is_narration: Option<bool>,
-    #[serde(rename = "cMediaNode")]
+    #[cfg_attr(feature = "serde", serde(rename = "cMediaNode"))]
     pub c_media_node: Box<CTTLCommonMediaNodeData>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLMediaNodeVideo {
-    #[serde(rename = "@fullScrn")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@fullScrn"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub full_scrn: Option<bool>,
-    #[serde(rename = "cMediaNode")]
+    #[cfg_attr(feature = "serde", serde(rename = "cMediaNode"))]
     pub c_media_node: Box<CTTLCommonMediaNodeData>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PAGTLBuild {
-    #[serde(rename = "@spid")]
+    #[cfg_attr(feature = "serde", serde(rename = "@spid"))]
     pub spid: ooxml_dml::types::STDrawingElementId,
-    #[serde(rename = "@grpId")]
+    #[cfg_attr(feature = "serde", serde(rename = "@grpId"))]
     pub grp_id: u32,
-    #[serde(rename = "@uiExpand")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@uiExpand"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub ui_expand: Option<bool>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLTemplate {
-    #[serde(rename = "@lvl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@lvl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub lvl: Option<u32>,
-    #[serde(rename = "tnLst")]
+    #[cfg_attr(feature = "serde", serde(rename = "tnLst"))]
     pub tn_lst: Box<CTTimeNodeList>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLTemplateList {
-    #[serde(rename = "tmpl")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "tmpl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub tmpl: Vec<CTTLTemplate>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLBuildParagraph {
-    #[serde(rename = "@spid")]
+    #[cfg_attr(feature = "serde", serde(rename = "@spid"))]
     pub spid: ooxml_dml::types::STDrawingElementId,
-    #[serde(rename = "@grpId")]
+    #[cfg_attr(feature = "serde", serde(rename = "@grpId"))]
     pub grp_id: u32,
-    #[serde(rename = "@uiExpand")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@uiExpand"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub ui_expand: Option<bool>,
-    #[serde(rename = "@build")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@build"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub build: Option<STTLParaBuildType>,
-    #[serde(rename = "@bldLvl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@bldLvl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bld_lvl: Option<u32>,
-    #[serde(rename = "@animBg")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@animBg"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub anim_bg: Option<bool>,
-    #[serde(rename = "@autoUpdateAnimBg")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@autoUpdateAnimBg"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub auto_update_anim_bg: Option<bool>,
-    #[serde(rename = "@rev")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@rev"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub rev: Option<bool>,
-    #[serde(rename = "@advAuto")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@advAuto"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub adv_auto: Option<STTLTime>,
-    #[serde(rename = "tmplLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tmplLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tmpl_lst: Option<Box<CTTLTemplateList>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLBuildDiagram {
-    #[serde(rename = "@spid")]
+    #[cfg_attr(feature = "serde", serde(rename = "@spid"))]
     pub spid: ooxml_dml::types::STDrawingElementId,
-    #[serde(rename = "@grpId")]
+    #[cfg_attr(feature = "serde", serde(rename = "@grpId"))]
     pub grp_id: u32,
-    #[serde(rename = "@uiExpand")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@uiExpand"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub ui_expand: Option<bool>,
-    #[serde(rename = "@bld")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@bld"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bld: Option<STTLDiagramBuildType>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLOleBuildChart {
-    #[serde(rename = "@spid")]
+    #[cfg_attr(feature = "serde", serde(rename = "@spid"))]
     pub spid: ooxml_dml::types::STDrawingElementId,
-    #[serde(rename = "@grpId")]
+    #[cfg_attr(feature = "serde", serde(rename = "@grpId"))]
     pub grp_id: u32,
-    #[serde(rename = "@uiExpand")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@uiExpand"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub ui_expand: Option<bool>,
-    #[serde(rename = "@bld")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@bld"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bld: Option<STTLOleChartBuildType>,
-    #[serde(rename = "@animBg")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@animBg"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub anim_bg: Option<bool>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTLGraphicalObjectBuild {
-    #[serde(rename = "@spid")]
+    #[cfg_attr(feature = "serde", serde(rename = "@spid"))]
     pub spid: ooxml_dml::types::STDrawingElementId,
-    #[serde(rename = "@grpId")]
+    #[cfg_attr(feature = "serde", serde(rename = "@grpId"))]
     pub grp_id: u32,
-    #[serde(rename = "@uiExpand")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@uiExpand"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub ui_expand: Option<bool>,
-    #[serde(rename = "bldAsOne")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bldAsOne"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bld_as_one: Option<Box<CTEmpty>>,
-    #[serde(rename = "bldSub")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bldSub"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bld_sub: Option<Box<ooxml_dml::types::CTAnimationGraphicalObjectBuildProperties>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTBuildList {
-    #[serde(rename = "bldP")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "bldP"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub bld_p: Vec<CTTLBuildParagraph>,
-    #[serde(rename = "bldDgm")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "bldDgm"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub bld_dgm: Vec<CTTLBuildDiagram>,
-    #[serde(rename = "bldOleChart")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "bldOleChart"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub bld_ole_chart: Vec<CTTLOleBuildChart>,
-    #[serde(rename = "bldGraphic")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "bldGraphic"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub bld_graphic: Vec<CTTLGraphicalObjectBuild>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SlideTiming {
     #[cfg(feature = "pml-animations")]
-    #[serde(rename = "tnLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tnLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tn_lst: Option<Box<CTTimeNodeList>>,
     #[cfg(feature = "pml-animations")]
-    #[serde(rename = "bldLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bldLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bld_lst: Option<Box<CTBuildList>>,
     #[cfg(feature = "pml-extensions")]
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionListModify>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTEmpty;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTIndexRange {
-    #[serde(rename = "@st")]
+    #[cfg_attr(feature = "serde", serde(rename = "@st"))]
     pub st: STIndex,
-    #[serde(rename = "@end")]
+    #[cfg_attr(feature = "serde", serde(rename = "@end"))]
     pub end: STIndex,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSlideRelationshipListEntry {
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSlideRelationshipList {
-    #[serde(rename = "sld")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "sld"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub sld: Vec<CTSlideRelationshipListEntry>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTCustomShowId {
-    #[serde(rename = "@id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@id"))]
     pub id: u32,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTCustomerData {
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTagsData {
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTCustomerDataList {
-    #[serde(rename = "custData")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "custData"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub cust_data: Vec<CTCustomerData>,
-    #[serde(rename = "tags")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tags"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tags: Option<Box<CTTagsData>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTExtension {
-    #[serde(rename = "@uri")]
+    #[cfg_attr(feature = "serde", serde(rename = "@uri"))]
     pub uri: String,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
 pub type ExtensionAnyElement = String;
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EGExtensionList {
-    #[serde(rename = "ext")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "ext"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub ext: Vec<CTExtension>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTExtensionList {
-    #[serde(rename = "ext")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "ext"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub ext: Vec<CTExtension>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTExtensionListModify {
-    #[serde(rename = "@mod")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@mod"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub r#mod: Option<bool>,
-    #[serde(rename = "ext")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "ext"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub ext: Vec<CTExtension>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTCommentAuthor {
     #[cfg(feature = "pml-comments")]
-    #[serde(rename = "@id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@id"))]
     pub id: u32,
     #[cfg(feature = "pml-comments")]
-    #[serde(rename = "@name")]
+    #[cfg_attr(feature = "serde", serde(rename = "@name"))]
     pub name: STName,
     #[cfg(feature = "pml-comments")]
-    #[serde(rename = "@initials")]
+    #[cfg_attr(feature = "serde", serde(rename = "@initials"))]
     pub initials: STName,
     #[cfg(feature = "pml-comments")]
-    #[serde(rename = "@lastIdx")]
+    #[cfg_attr(feature = "serde", serde(rename = "@lastIdx"))]
     pub last_idx: u32,
     #[cfg(feature = "pml-comments")]
-    #[serde(rename = "@clrIdx")]
+    #[cfg_attr(feature = "serde", serde(rename = "@clrIdx"))]
     pub clr_idx: u32,
     #[cfg(feature = "pml-extensions")]
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTCommentAuthorList {
-    #[serde(rename = "cmAuthor")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "cmAuthor"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub cm_author: Vec<CTCommentAuthor>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
 pub type PCmAuthorLst = Box<CTCommentAuthorList>;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTComment {
     #[cfg(feature = "pml-comments")]
-    #[serde(rename = "@authorId")]
+    #[cfg_attr(feature = "serde", serde(rename = "@authorId"))]
     pub author_id: u32,
     #[cfg(feature = "pml-comments")]
-    #[serde(rename = "@dt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@dt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub dt: Option<String>,
     #[cfg(feature = "pml-comments")]
-    #[serde(rename = "@idx")]
+    #[cfg_attr(feature = "serde", serde(rename = "@idx"))]
     pub idx: STIndex,
     #[cfg(feature = "pml-comments")]
-    #[serde(rename = "pos")]
+    #[cfg_attr(feature = "serde", serde(rename = "pos"))]
     pub pos: Box<ooxml_dml::types::Point2D>,
     #[cfg(feature = "pml-comments")]
-    #[serde(rename = "text")]
+    #[cfg_attr(feature = "serde", serde(rename = "text"))]
     pub text: String,
     #[cfg(feature = "pml-extensions")]
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionListModify>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTCommentList {
-    #[serde(rename = "cm")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "cm"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub cm: Vec<CTComment>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
 pub type PCmLst = Box<CTCommentList>;
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PAGOle {
-    #[serde(rename = "@name")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@name"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub name: Option<String>,
-    #[serde(rename = "@showAsIcon")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@showAsIcon"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub show_as_icon: Option<bool>,
-    #[serde(rename = "@imgW")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@imgW"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub img_w: Option<ooxml_dml::types::STPositiveCoordinate32>,
-    #[serde(rename = "@imgH")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@imgH"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub img_h: Option<ooxml_dml::types::STPositiveCoordinate32>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTOleObjectEmbed {
-    #[serde(rename = "@followColorScheme")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@followColorScheme"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub follow_color_scheme: Option<STOleObjectFollowColorScheme>,
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTOleObjectLink {
-    #[serde(rename = "@updateAutomatic")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@updateAutomatic"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub update_automatic: Option<bool>,
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTOleObject {
-    #[serde(rename = "@name")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@name"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub name: Option<String>,
-    #[serde(rename = "@showAsIcon")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@showAsIcon"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub show_as_icon: Option<bool>,
-    #[serde(rename = "@imgW")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@imgW"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub img_w: Option<ooxml_dml::types::STPositiveCoordinate32>,
-    #[serde(rename = "@imgH")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@imgH"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub img_h: Option<ooxml_dml::types::STPositiveCoordinate32>,
-    #[serde(rename = "@progId")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@progId"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub prog_id: Option<String>,
-    #[serde(rename = "embed")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "embed"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub embed: Option<Box<CTOleObjectEmbed>>,
-    #[serde(rename = "link")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "link"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub link: Option<Box<CTOleObjectLink>>,
-    #[serde(rename = "@spid")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@spid"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spid: Option<ooxml_dml::types::STShapeID>,
-    #[serde(rename = "pic")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pic"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub picture: Option<Box<Picture>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
 pub type POleObj = Box<CTOleObject>;
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTControl {
-    #[serde(rename = "@name")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@name"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub name: Option<String>,
-    #[serde(rename = "@showAsIcon")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@showAsIcon"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub show_as_icon: Option<bool>,
-    #[serde(rename = "@imgW")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@imgW"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub img_w: Option<ooxml_dml::types::STPositiveCoordinate32>,
-    #[serde(rename = "@imgH")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@imgH"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub img_h: Option<ooxml_dml::types::STPositiveCoordinate32>,
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
-    #[serde(rename = "@spid")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@spid"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spid: Option<ooxml_dml::types::STShapeID>,
-    #[serde(rename = "pic")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pic"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub picture: Option<Box<Picture>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTControlList {
-    #[serde(rename = "control")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "control"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub control: Vec<CTControl>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSlideIdListEntry {
-    #[serde(rename = "@id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@id"))]
     pub id: STSlideId,
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SlideIdList {
-    #[serde(rename = "sldId")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "sldId"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub sld_id: Vec<CTSlideIdListEntry>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSlideMasterIdListEntry {
-    #[serde(rename = "@id")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@id"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub id: Option<STSlideMasterId>,
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSlideMasterIdList {
-    #[serde(rename = "sldMasterId")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "sldMasterId"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub sld_master_id: Vec<CTSlideMasterIdListEntry>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTNotesMasterIdListEntry {
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTNotesMasterIdList {
-    #[serde(rename = "notesMasterId")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "notesMasterId"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub notes_master_id: Option<Box<CTNotesMasterIdListEntry>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTHandoutMasterIdListEntry {
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTHandoutMasterIdList {
-    #[serde(rename = "handoutMasterId")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "handoutMasterId"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub handout_master_id: Option<Box<CTHandoutMasterIdListEntry>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTEmbeddedFontDataId {
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTEmbeddedFontListEntry {
-    #[serde(rename = "font")]
+    #[cfg_attr(feature = "serde", serde(rename = "font"))]
     pub font: Box<ooxml_dml::types::TextFont>,
-    #[serde(rename = "regular")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "regular"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub regular: Option<Box<CTEmbeddedFontDataId>>,
-    #[serde(rename = "bold")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bold"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bold: Option<Box<CTEmbeddedFontDataId>>,
-    #[serde(rename = "italic")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "italic"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub italic: Option<Box<CTEmbeddedFontDataId>>,
-    #[serde(rename = "boldItalic")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "boldItalic"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bold_italic: Option<Box<CTEmbeddedFontDataId>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTEmbeddedFontList {
-    #[serde(rename = "embeddedFont")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "embeddedFont"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub embedded_font: Vec<CTEmbeddedFontListEntry>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSmartTags {
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTCustomShow {
-    #[serde(rename = "@name")]
+    #[cfg_attr(feature = "serde", serde(rename = "@name"))]
     pub name: STName,
-    #[serde(rename = "@id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@id"))]
     pub id: u32,
-    #[serde(rename = "sldLst")]
+    #[cfg_attr(feature = "serde", serde(rename = "sldLst"))]
     pub sld_lst: Box<CTSlideRelationshipList>,
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTCustomShowList {
-    #[serde(rename = "custShow")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "custShow"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub cust_show: Vec<CTCustomShow>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTPhotoAlbum {
-    #[serde(rename = "@bw")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@bw"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub bw: Option<bool>,
-    #[serde(rename = "@showCaptions")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@showCaptions"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub show_captions: Option<bool>,
-    #[serde(rename = "@layout")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@layout"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub layout: Option<STPhotoAlbumLayout>,
-    #[serde(rename = "@frame")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@frame"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub frame: Option<STPhotoAlbumFrameShape>,
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSlideSize {
-    #[serde(rename = "@cx")]
+    #[cfg_attr(feature = "serde", serde(rename = "@cx"))]
     pub cx: STSlideSizeCoordinate,
-    #[serde(rename = "@cy")]
+    #[cfg_attr(feature = "serde", serde(rename = "@cy"))]
     pub cy: STSlideSizeCoordinate,
-    #[serde(rename = "@type")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@type"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r#type: Option<STSlideSizeType>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTKinsoku {
-    #[serde(rename = "@lang")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@lang"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub lang: Option<String>,
-    #[serde(rename = "@invalStChars")]
+    #[cfg_attr(feature = "serde", serde(rename = "@invalStChars"))]
     pub inval_st_chars: String,
-    #[serde(rename = "@invalEndChars")]
+    #[cfg_attr(feature = "serde", serde(rename = "@invalEndChars"))]
     pub inval_end_chars: String,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTModifyVerifier {
-    #[serde(rename = "@algorithmName")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@algorithmName"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub algorithm_name: Option<String>,
-    #[serde(rename = "@hashValue")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@hashValue"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hash_value: Option<Vec<u8>>,
-    #[serde(rename = "@saltValue")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@saltValue"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub salt_value: Option<Vec<u8>>,
-    #[serde(rename = "@spinValue")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@spinValue"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spin_value: Option<u32>,
-    #[serde(rename = "@cryptProviderType")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@cryptProviderType"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_provider_type: Option<STCryptProv>,
-    #[serde(rename = "@cryptAlgorithmClass")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@cryptAlgorithmClass"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_algorithm_class: Option<STAlgClass>,
-    #[serde(rename = "@cryptAlgorithmType")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@cryptAlgorithmType"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_algorithm_type: Option<STAlgType>,
-    #[serde(rename = "@cryptAlgorithmSid")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@cryptAlgorithmSid"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_algorithm_sid: Option<u32>,
-    #[serde(rename = "@spinCount")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@spinCount"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spin_count: Option<u32>,
-    #[serde(rename = "@saltData")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@saltData"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub salt_data: Option<Vec<u8>>,
-    #[serde(rename = "@hashData")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@hashData"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hash_data: Option<Vec<u8>>,
-    #[serde(rename = "@cryptProvider")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@cryptProvider"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_provider: Option<String>,
-    #[serde(rename = "@algIdExt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@algIdExt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub alg_id_ext: Option<u32>,
-    #[serde(rename = "@algIdExtSource")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@algIdExtSource"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub alg_id_ext_source: Option<String>,
-    #[serde(rename = "@cryptProviderTypeExt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@cryptProviderTypeExt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_provider_type_ext: Option<u32>,
-    #[serde(rename = "@cryptProviderTypeExtSource")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@cryptProviderTypeExtSource"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_provider_type_ext_source: Option<String>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Presentation {
     #[cfg(feature = "pml-styling")]
-    #[serde(rename = "@serverZoom")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@serverZoom"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub server_zoom: Option<ooxml_dml::types::STPercentage>,
-    #[serde(rename = "@firstSlideNum")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@firstSlideNum"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub first_slide_num: Option<i32>,
     #[cfg(feature = "pml-styling")]
-    #[serde(rename = "@showSpecialPlsOnTitleSld")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@showSpecialPlsOnTitleSld"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub show_special_pls_on_title_sld: Option<bool>,
     #[cfg(feature = "pml-styling")]
-    #[serde(rename = "@rtl")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@rtl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub rtl: Option<bool>,
-    #[serde(rename = "@removePersonalInfoOnSave")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@removePersonalInfoOnSave"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub remove_personal_info_on_save: Option<bool>,
-    #[serde(rename = "@compatMode")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@compatMode"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub compat_mode: Option<bool>,
     #[cfg(feature = "pml-styling")]
-    #[serde(rename = "@strictFirstAndLastChars")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@strictFirstAndLastChars"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub strict_first_and_last_chars: Option<bool>,
     #[cfg(feature = "pml-styling")]
-    #[serde(rename = "@embedTrueTypeFonts")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@embedTrueTypeFonts"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub embed_true_type_fonts: Option<bool>,
     #[cfg(feature = "pml-styling")]
-    #[serde(rename = "@saveSubsetFonts")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@saveSubsetFonts"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub save_subset_fonts: Option<bool>,
     #[cfg(feature = "pml-styling")]
-    #[serde(rename = "@autoCompressPictures")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@autoCompressPictures"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub auto_compress_pictures: Option<bool>,
-    #[serde(rename = "@bookmarkIdSeed")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@bookmarkIdSeed"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bookmark_id_seed: Option<STBookmarkIdSeed>,
-    #[serde(rename = "@conformance")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@conformance"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub conformance: Option<STConformanceClass>,
-    #[serde(rename = "sldMasterIdLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sldMasterIdLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sld_master_id_lst: Option<Box<CTSlideMasterIdList>>,
     #[cfg(feature = "pml-notes")]
-    #[serde(rename = "notesMasterIdLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "notesMasterIdLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub notes_master_id_lst: Option<Box<CTNotesMasterIdList>>,
     #[cfg(feature = "pml-masters")]
-    #[serde(rename = "handoutMasterIdLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "handoutMasterIdLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub handout_master_id_lst: Option<Box<CTHandoutMasterIdList>>,
-    #[serde(rename = "sldIdLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sldIdLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sld_id_lst: Option<Box<SlideIdList>>,
-    #[serde(rename = "sldSz")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sldSz"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sld_sz: Option<Box<CTSlideSize>>,
     #[cfg(feature = "pml-notes")]
-    #[serde(rename = "notesSz")]
+    #[cfg_attr(feature = "serde", serde(rename = "notesSz"))]
     pub notes_sz: Box<ooxml_dml::types::PositiveSize2D>,
     #[cfg(feature = "pml-external")]
-    #[serde(rename = "smartTags")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "smartTags"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub smart_tags: Option<Box<CTSmartTags>>,
     #[cfg(feature = "pml-styling")]
-    #[serde(rename = "embeddedFontLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "embeddedFontLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub embedded_font_lst: Option<Box<CTEmbeddedFontList>>,
-    #[serde(rename = "custShowLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "custShowLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cust_show_lst: Option<Box<CTCustomShowList>>,
     #[cfg(feature = "pml-media")]
-    #[serde(rename = "photoAlbum")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "photoAlbum"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub photo_album: Option<Box<CTPhotoAlbum>>,
     #[cfg(feature = "pml-external")]
-    #[serde(rename = "custDataLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "custDataLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cust_data_lst: Option<Box<CTCustomerDataList>>,
     #[cfg(feature = "pml-styling")]
-    #[serde(rename = "kinsoku")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "kinsoku"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub kinsoku: Option<Box<CTKinsoku>>,
     #[cfg(feature = "pml-styling")]
-    #[serde(rename = "defaultTextStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "defaultTextStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub default_text_style: Option<Box<ooxml_dml::types::CTTextListStyle>>,
-    #[serde(rename = "modifyVerifier")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "modifyVerifier"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub modify_verifier: Option<Box<CTModifyVerifier>>,
     #[cfg(feature = "pml-extensions")]
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
 pub type PPresentation = Box<Presentation>;
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTHtmlPublishProperties {
-    #[serde(rename = "@showSpeakerNotes")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@showSpeakerNotes"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub show_speaker_notes: Option<bool>,
-    #[serde(rename = "@target")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@target"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub target: Option<String>,
-    #[serde(rename = "@title")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@title"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub title: Option<String>,
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub slide_list_choice: Option<Box<EGSlideListChoice>>,
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTWebProperties {
-    #[serde(rename = "@showAnimation")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@showAnimation"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub show_animation: Option<bool>,
-    #[serde(rename = "@resizeGraphics")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@resizeGraphics"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub resize_graphics: Option<bool>,
-    #[serde(rename = "@allowPng")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@allowPng"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub allow_png: Option<bool>,
-    #[serde(rename = "@relyOnVml")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@relyOnVml"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub rely_on_vml: Option<bool>,
-    #[serde(rename = "@organizeInFolders")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@organizeInFolders"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub organize_in_folders: Option<bool>,
-    #[serde(rename = "@useLongFilenames")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@useLongFilenames"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub use_long_filenames: Option<bool>,
-    #[serde(rename = "@imgSz")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@imgSz"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub img_sz: Option<STWebScreenSize>,
-    #[serde(rename = "@encoding")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@encoding"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub encoding: Option<STWebEncoding>,
-    #[serde(rename = "@clr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@clr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub clr: Option<STWebColorType>,
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTPrintProperties {
-    #[serde(rename = "@prnWhat")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@prnWhat"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub prn_what: Option<STPrintWhat>,
-    #[serde(rename = "@clrMode")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@clrMode"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub clr_mode: Option<STPrintColorMode>,
-    #[serde(rename = "@hiddenSlides")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@hiddenSlides"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub hidden_slides: Option<bool>,
-    #[serde(rename = "@scaleToFitPaper")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@scaleToFitPaper"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub scale_to_fit_paper: Option<bool>,
-    #[serde(rename = "@frameSlides")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@frameSlides"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub frame_slides: Option<bool>,
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTShowInfoBrowse {
-    #[serde(rename = "@showScrollbar")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@showScrollbar"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub show_scrollbar: Option<bool>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTShowInfoKiosk {
-    #[serde(rename = "@restart")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@restart"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub restart: Option<u32>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTShowProperties {
-    #[serde(rename = "@loop")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@loop"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub r#loop: Option<bool>,
-    #[serde(rename = "@showNarration")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@showNarration"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub show_narration: Option<bool>,
-    #[serde(rename = "@showAnimation")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@showAnimation"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub show_animation: Option<bool>,
-    #[serde(rename = "@useTimings")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@useTimings"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub use_timings: Option<bool>,
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub show_type: Option<Box<EGShowType>>,
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub slide_list_choice: Option<Box<EGSlideListChoice>>,
-    #[serde(rename = "penClr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "penClr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub pen_clr: Option<Box<ooxml_dml::types::CTColor>>,
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTPresentationProperties {
     #[cfg(feature = "pml-external")]
-    #[serde(rename = "htmlPubPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "htmlPubPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub html_pub_pr: Option<Box<CTHtmlPublishProperties>>,
     #[cfg(feature = "pml-external")]
-    #[serde(rename = "webPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "webPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub web_pr: Option<Box<CTWebProperties>>,
-    #[serde(rename = "prnPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "prnPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub prn_pr: Option<Box<CTPrintProperties>>,
-    #[serde(rename = "showPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "showPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub show_pr: Option<Box<CTShowProperties>>,
     #[cfg(feature = "pml-styling")]
-    #[serde(rename = "clrMru")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "clrMru"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub clr_mru: Option<Box<ooxml_dml::types::CTColorMRU>>,
     #[cfg(feature = "pml-extensions")]
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
 pub type PPresentationPr = Box<CTPresentationProperties>;
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTHeaderFooter {
     #[cfg(feature = "pml-masters")]
-    #[serde(rename = "@sldNum")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@sldNum"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub sld_num: Option<bool>,
     #[cfg(feature = "pml-masters")]
-    #[serde(rename = "@hdr")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@hdr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub hdr: Option<bool>,
     #[cfg(feature = "pml-masters")]
-    #[serde(rename = "@ftr")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@ftr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub ftr: Option<bool>,
     #[cfg(feature = "pml-masters")]
-    #[serde(rename = "@dt")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@dt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub dt: Option<bool>,
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionListModify>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTPlaceholder {
-    #[serde(rename = "@type")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@type"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r#type: Option<STPlaceholderType>,
-    #[serde(rename = "@orient")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@orient"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub orient: Option<STDirection>,
-    #[serde(rename = "@sz")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@sz"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sz: Option<STPlaceholderSize>,
-    #[serde(rename = "@idx")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@idx"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub idx: Option<u32>,
-    #[serde(rename = "@hasCustomPrompt")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@hasCustomPrompt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub has_custom_prompt: Option<bool>,
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionListModify>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTApplicationNonVisualDrawingProps {
-    #[serde(rename = "@isPhoto")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@isPhoto"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub is_photo: Option<bool>,
-    #[serde(rename = "@userDrawn")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@userDrawn"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub user_drawn: Option<bool>,
-    #[serde(rename = "ph")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "ph"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ph: Option<Box<CTPlaceholder>>,
-    #[serde(rename = "custDataLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "custDataLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cust_data_lst: Option<Box<CTCustomerDataList>>,
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ShapeNonVisual {
-    #[serde(rename = "cNvPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "cNvPr"))]
     pub c_nv_pr: Box<ooxml_dml::types::CTNonVisualDrawingProps>,
-    #[serde(rename = "cNvSpPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "cNvSpPr"))]
     pub c_nv_sp_pr: Box<ooxml_dml::types::CTNonVisualDrawingShapeProps>,
-    #[serde(rename = "nvPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "nvPr"))]
     pub nv_pr: Box<CTApplicationNonVisualDrawingProps>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Shape {
     #[cfg(feature = "pml-styling")]
-    #[serde(rename = "@useBgFill")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@useBgFill"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub use_bg_fill: Option<bool>,
-    #[serde(rename = "nvSpPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "nvSpPr"))]
     pub non_visual_properties: Box<ShapeNonVisual>,
-    #[serde(rename = "spPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "spPr"))]
     pub shape_properties: Box<ooxml_dml::types::CTShapeProperties>,
     #[cfg(feature = "pml-styling")]
-    #[serde(rename = "style")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "style"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub style: Option<Box<ooxml_dml::types::ShapeStyle>>,
-    #[serde(rename = "txBody")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "txBody"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub text_body: Option<Box<ooxml_dml::types::TextBody>>,
     #[cfg(feature = "pml-extensions")]
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionListModify>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTConnectorNonVisual {
-    #[serde(rename = "cNvPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "cNvPr"))]
     pub c_nv_pr: Box<ooxml_dml::types::CTNonVisualDrawingProps>,
-    #[serde(rename = "cNvCxnSpPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "cNvCxnSpPr"))]
     pub c_nv_cxn_sp_pr: Box<ooxml_dml::types::CTNonVisualConnectorProperties>,
-    #[serde(rename = "nvPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "nvPr"))]
     pub nv_pr: Box<CTApplicationNonVisualDrawingProps>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Connector {
-    #[serde(rename = "nvCxnSpPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "nvCxnSpPr"))]
     pub non_visual_connector_properties: Box<CTConnectorNonVisual>,
-    #[serde(rename = "spPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "spPr"))]
     pub shape_properties: Box<ooxml_dml::types::CTShapeProperties>,
     #[cfg(feature = "pml-styling")]
-    #[serde(rename = "style")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "style"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub style: Option<Box<ooxml_dml::types::ShapeStyle>>,
     #[cfg(feature = "pml-extensions")]
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionListModify>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTPictureNonVisual {
-    #[serde(rename = "cNvPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "cNvPr"))]
     pub c_nv_pr: Box<ooxml_dml::types::CTNonVisualDrawingProps>,
-    #[serde(rename = "cNvPicPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "cNvPicPr"))]
     pub c_nv_pic_pr: Box<ooxml_dml::types::CTNonVisualPictureProperties>,
-    #[serde(rename = "nvPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "nvPr"))]
     pub nv_pr: Box<CTApplicationNonVisualDrawingProps>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Picture {
-    #[serde(rename = "nvPicPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "nvPicPr"))]
     pub non_visual_picture_properties: Box<CTPictureNonVisual>,
-    #[serde(rename = "blipFill")]
+    #[cfg_attr(feature = "serde", serde(rename = "blipFill"))]
     pub blip_fill: Box<ooxml_dml::types::BlipFillProperties>,
-    #[serde(rename = "spPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "spPr"))]
     pub shape_properties: Box<ooxml_dml::types::CTShapeProperties>,
     #[cfg(feature = "pml-styling")]
-    #[serde(rename = "style")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "style"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub style: Option<Box<ooxml_dml::types::ShapeStyle>>,
     #[cfg(feature = "pml-extensions")]
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionListModify>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTGraphicalObjectFrameNonVisual {
-    #[serde(rename = "cNvPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "cNvPr"))]
     pub c_nv_pr: Box<ooxml_dml::types::CTNonVisualDrawingProps>,
-    #[serde(rename = "cNvGraphicFramePr")]
+    #[cfg_attr(feature = "serde", serde(rename = "cNvGraphicFramePr"))]
     pub c_nv_graphic_frame_pr: Box<ooxml_dml::types::CTNonVisualGraphicFrameProperties>,
-    #[serde(rename = "nvPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "nvPr"))]
     pub nv_pr: Box<CTApplicationNonVisualDrawingProps>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GraphicalObjectFrame {
     #[cfg(feature = "pml-styling")]
-    #[serde(rename = "@bwMode")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@bwMode"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bw_mode: Option<ooxml_dml::types::STBlackWhiteMode>,
-    #[serde(rename = "nvGraphicFramePr")]
+    #[cfg_attr(feature = "serde", serde(rename = "nvGraphicFramePr"))]
     pub nv_graphic_frame_pr: Box<CTGraphicalObjectFrameNonVisual>,
-    #[serde(rename = "xfrm")]
+    #[cfg_attr(feature = "serde", serde(rename = "xfrm"))]
     pub xfrm: Box<ooxml_dml::types::Transform2D>,
     #[cfg(feature = "pml-extensions")]
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionListModify>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTGroupShapeNonVisual {
-    #[serde(rename = "cNvPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "cNvPr"))]
     pub c_nv_pr: Box<ooxml_dml::types::CTNonVisualDrawingProps>,
-    #[serde(rename = "cNvGrpSpPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "cNvGrpSpPr"))]
     pub c_nv_grp_sp_pr: Box<ooxml_dml::types::CTNonVisualGroupDrawingShapeProps>,
-    #[serde(rename = "nvPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "nvPr"))]
     pub nv_pr: Box<CTApplicationNonVisualDrawingProps>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GroupShape {
-    #[serde(rename = "nvGrpSpPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "nvGrpSpPr"))]
     pub non_visual_group_properties: Box<CTGroupShapeNonVisual>,
-    #[serde(rename = "grpSpPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "grpSpPr"))]
     pub grp_sp_pr: Box<ooxml_dml::types::CTGroupShapeProperties>,
-    #[serde(rename = "sp")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "sp"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub shape: Vec<Shape>,
-    #[serde(rename = "grpSp")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "grpSp"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub group_shape: Vec<GroupShape>,
-    #[serde(rename = "graphicFrame")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "graphicFrame"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub graphic_frame: Vec<GraphicalObjectFrame>,
-    #[serde(rename = "cxnSp")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "cxnSp"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub connector: Vec<Connector>,
-    #[serde(rename = "pic")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "pic"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub picture: Vec<Picture>,
     #[cfg(feature = "pml-external")]
-    #[serde(rename = "contentPart")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "contentPart"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub content_part: Vec<CTRel>,
     #[cfg(feature = "pml-extensions")]
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionListModify>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTRel {
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
 pub type EGTopLevelSlide = Box<ooxml_dml::types::CTColorMapping>;
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EGChildSlide {
-    #[serde(rename = "clrMapOvr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "clrMapOvr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub clr_map_ovr: Option<Box<ooxml_dml::types::CTColorMappingOverride>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PAGChildSlide {
-    #[serde(rename = "@showMasterSp")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@showMasterSp"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub show_master_sp: Option<bool>,
-    #[serde(rename = "@showMasterPhAnim")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@showMasterPhAnim"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub show_master_ph_anim: Option<bool>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTBackgroundProperties {
-    #[serde(rename = "@shadeToTitle")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@shadeToTitle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub shade_to_title: Option<bool>,
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTBackground {
     #[cfg(feature = "pml-styling")]
-    #[serde(rename = "@bwMode")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@bwMode"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bw_mode: Option<ooxml_dml::types::STBlackWhiteMode>,
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub background: Option<Box<EGBackground>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CommonSlideData {
-    #[serde(rename = "@name")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@name"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub name: Option<String>,
     #[cfg(feature = "pml-styling")]
-    #[serde(rename = "bg")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bg"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bg: Option<Box<CTBackground>>,
-    #[serde(rename = "spTree")]
+    #[cfg_attr(feature = "serde", serde(rename = "spTree"))]
     pub shape_tree: Box<GroupShape>,
     #[cfg(feature = "pml-external")]
-    #[serde(rename = "custDataLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "custDataLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cust_data_lst: Option<Box<CTCustomerDataList>>,
     #[cfg(feature = "pml-external")]
-    #[serde(rename = "controls")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "controls"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub controls: Option<Box<CTControlList>>,
     #[cfg(feature = "pml-extensions")]
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Slide {
     #[cfg(feature = "pml-masters")]
-    #[serde(rename = "@showMasterSp")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@showMasterSp"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub show_master_sp: Option<bool>,
     #[cfg(feature = "pml-masters")]
-    #[serde(rename = "@showMasterPhAnim")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@showMasterPhAnim"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub show_master_ph_anim: Option<bool>,
-    #[serde(rename = "@show")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@show"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub show: Option<bool>,
-    #[serde(rename = "cSld")]
+    #[cfg_attr(feature = "serde", serde(rename = "cSld"))]
     pub common_slide_data: Box<CommonSlideData>,
     #[cfg(feature = "pml-styling")]
-    #[serde(rename = "clrMapOvr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "clrMapOvr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub clr_map_ovr: Option<Box<ooxml_dml::types::CTColorMappingOverride>>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "transition")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "transition"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub transition: Option<Box<SlideTransition>>,
     #[cfg(feature = "pml-animations")]
-    #[serde(rename = "timing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "timing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub timing: Option<Box<SlideTiming>>,
     #[cfg(feature = "pml-extensions")]
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionListModify>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
 pub type PSld = Box<Slide>;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SlideLayout {
     #[cfg(feature = "pml-masters")]
-    #[serde(rename = "@showMasterSp")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@showMasterSp"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub show_master_sp: Option<bool>,
     #[cfg(feature = "pml-masters")]
-    #[serde(rename = "@showMasterPhAnim")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@showMasterPhAnim"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub show_master_ph_anim: Option<bool>,
     #[cfg(feature = "pml-masters")]
-    #[serde(rename = "@matchingName")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@matchingName"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub matching_name: Option<String>,
     #[cfg(feature = "pml-masters")]
-    #[serde(rename = "@type")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@type"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r#type: Option<STSlideLayoutType>,
     #[cfg(feature = "pml-masters")]
-    #[serde(rename = "@preserve")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@preserve"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub preserve: Option<bool>,
     #[cfg(feature = "pml-masters")]
-    #[serde(rename = "@userDrawn")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@userDrawn"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub user_drawn: Option<bool>,
-    #[serde(rename = "cSld")]
+    #[cfg_attr(feature = "serde", serde(rename = "cSld"))]
     pub common_slide_data: Box<CommonSlideData>,
     #[cfg(feature = "pml-styling")]
-    #[serde(rename = "clrMapOvr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "clrMapOvr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub clr_map_ovr: Option<Box<ooxml_dml::types::CTColorMappingOverride>>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "transition")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "transition"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub transition: Option<Box<SlideTransition>>,
     #[cfg(feature = "pml-animations")]
-    #[serde(rename = "timing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "timing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub timing: Option<Box<SlideTiming>>,
     #[cfg(feature = "pml-masters")]
-    #[serde(rename = "hf")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "hf"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hf: Option<Box<CTHeaderFooter>>,
     #[cfg(feature = "pml-extensions")]
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionListModify>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
 pub type PSldLayout = Box<SlideLayout>;
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSlideMasterTextStyles {
-    #[serde(rename = "titleStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "titleStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub title_style: Option<Box<ooxml_dml::types::CTTextListStyle>>,
-    #[serde(rename = "bodyStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bodyStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub body_style: Option<Box<ooxml_dml::types::CTTextListStyle>>,
-    #[serde(rename = "otherStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "otherStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub other_style: Option<Box<ooxml_dml::types::CTTextListStyle>>,
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSlideLayoutIdListEntry {
-    #[serde(rename = "@id")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@id"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub id: Option<STSlideLayoutId>,
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSlideLayoutIdList {
-    #[serde(rename = "sldLayoutId")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "sldLayoutId"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub sld_layout_id: Vec<CTSlideLayoutIdListEntry>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SlideMaster {
     #[cfg(feature = "pml-masters")]
-    #[serde(rename = "@preserve")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@preserve"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub preserve: Option<bool>,
-    #[serde(rename = "cSld")]
+    #[cfg_attr(feature = "serde", serde(rename = "cSld"))]
     pub common_slide_data: Box<CommonSlideData>,
-    #[serde(rename = "clrMap")]
+    #[cfg_attr(feature = "serde", serde(rename = "clrMap"))]
     pub clr_map: Box<ooxml_dml::types::CTColorMapping>,
     #[cfg(feature = "pml-masters")]
-    #[serde(rename = "sldLayoutIdLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sldLayoutIdLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sld_layout_id_lst: Option<Box<CTSlideLayoutIdList>>,
     #[cfg(feature = "pml-transitions")]
-    #[serde(rename = "transition")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "transition"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub transition: Option<Box<SlideTransition>>,
     #[cfg(feature = "pml-animations")]
-    #[serde(rename = "timing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "timing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub timing: Option<Box<SlideTiming>>,
     #[cfg(feature = "pml-masters")]
-    #[serde(rename = "hf")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "hf"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hf: Option<Box<CTHeaderFooter>>,
     #[cfg(feature = "pml-styling")]
-    #[serde(rename = "txStyles")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "txStyles"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tx_styles: Option<Box<CTSlideMasterTextStyles>>,
     #[cfg(feature = "pml-extensions")]
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionListModify>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
 pub type PSldMaster = Box<SlideMaster>;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct HandoutMaster {
-    #[serde(rename = "cSld")]
+    #[cfg_attr(feature = "serde", serde(rename = "cSld"))]
     pub common_slide_data: Box<CommonSlideData>,
-    #[serde(rename = "clrMap")]
+    #[cfg_attr(feature = "serde", serde(rename = "clrMap"))]
     pub clr_map: Box<ooxml_dml::types::CTColorMapping>,
     #[cfg(feature = "pml-masters")]
-    #[serde(rename = "hf")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "hf"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hf: Option<Box<CTHeaderFooter>>,
     #[cfg(feature = "pml-extensions")]
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionListModify>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
 pub type PHandoutMaster = Box<HandoutMaster>;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NotesMaster {
-    #[serde(rename = "cSld")]
+    #[cfg_attr(feature = "serde", serde(rename = "cSld"))]
     pub common_slide_data: Box<CommonSlideData>,
-    #[serde(rename = "clrMap")]
+    #[cfg_attr(feature = "serde", serde(rename = "clrMap"))]
     pub clr_map: Box<ooxml_dml::types::CTColorMapping>,
     #[cfg(feature = "pml-notes")]
-    #[serde(rename = "hf")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "hf"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hf: Option<Box<CTHeaderFooter>>,
     #[cfg(feature = "pml-styling")]
-    #[serde(rename = "notesStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "notesStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub notes_style: Option<Box<ooxml_dml::types::CTTextListStyle>>,
     #[cfg(feature = "pml-extensions")]
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionListModify>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
 pub type PNotesMaster = Box<NotesMaster>;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NotesSlide {
     #[cfg(feature = "pml-notes")]
-    #[serde(rename = "@showMasterSp")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@showMasterSp"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub show_master_sp: Option<bool>,
     #[cfg(feature = "pml-notes")]
-    #[serde(rename = "@showMasterPhAnim")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@showMasterPhAnim"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub show_master_ph_anim: Option<bool>,
-    #[serde(rename = "cSld")]
+    #[cfg_attr(feature = "serde", serde(rename = "cSld"))]
     pub common_slide_data: Box<CommonSlideData>,
     #[cfg(feature = "pml-styling")]
-    #[serde(rename = "clrMapOvr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "clrMapOvr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub clr_map_ovr: Option<Box<ooxml_dml::types::CTColorMappingOverride>>,
     #[cfg(feature = "pml-extensions")]
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionListModify>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
 pub type PNotes = Box<NotesSlide>;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSlideSyncProperties {
-    #[serde(rename = "@serverSldId")]
+    #[cfg_attr(feature = "serde", serde(rename = "@serverSldId"))]
     pub server_sld_id: String,
-    #[serde(rename = "@serverSldModifiedTime")]
+    #[cfg_attr(feature = "serde", serde(rename = "@serverSldModifiedTime"))]
     pub server_sld_modified_time: String,
-    #[serde(rename = "@clientInsertedTime")]
+    #[cfg_attr(feature = "serde", serde(rename = "@clientInsertedTime"))]
     pub client_inserted_time: String,
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
 pub type PSldSyncPr = Box<CTSlideSyncProperties>;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTStringTag {
-    #[serde(rename = "@name")]
+    #[cfg_attr(feature = "serde", serde(rename = "@name"))]
     pub name: String,
-    #[serde(rename = "@val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@val"))]
     pub value: String,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTagList {
-    #[serde(rename = "tag")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "tag"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub tag: Vec<CTStringTag>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
 pub type PTagLst = Box<CTTagList>;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTNormalViewPortion {
-    #[serde(rename = "@sz")]
+    #[cfg_attr(feature = "serde", serde(rename = "@sz"))]
     pub sz: ooxml_dml::types::STPositiveFixedPercentage,
-    #[serde(rename = "@autoAdjust")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@autoAdjust"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub auto_adjust: Option<bool>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTNormalViewProperties {
-    #[serde(rename = "@showOutlineIcons")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@showOutlineIcons"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub show_outline_icons: Option<bool>,
-    #[serde(rename = "@snapVertSplitter")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@snapVertSplitter"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub snap_vert_splitter: Option<bool>,
-    #[serde(rename = "@vertBarState")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@vertBarState"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub vert_bar_state: Option<STSplitterBarState>,
-    #[serde(rename = "@horzBarState")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@horzBarState"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub horz_bar_state: Option<STSplitterBarState>,
-    #[serde(rename = "@preferSingleView")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@preferSingleView"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub prefer_single_view: Option<bool>,
-    #[serde(rename = "restoredLeft")]
+    #[cfg_attr(feature = "serde", serde(rename = "restoredLeft"))]
     pub restored_left: Box<CTNormalViewPortion>,
-    #[serde(rename = "restoredTop")]
+    #[cfg_attr(feature = "serde", serde(rename = "restoredTop"))]
     pub restored_top: Box<CTNormalViewPortion>,
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTCommonViewProperties {
-    #[serde(rename = "@varScale")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@varScale"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub var_scale: Option<bool>,
-    #[serde(rename = "scale")]
+    #[cfg_attr(feature = "serde", serde(rename = "scale"))]
     pub scale: Box<ooxml_dml::types::CTScale2D>,
-    #[serde(rename = "origin")]
+    #[cfg_attr(feature = "serde", serde(rename = "origin"))]
     pub origin: Box<ooxml_dml::types::Point2D>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTNotesTextViewProperties {
-    #[serde(rename = "cViewPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "cViewPr"))]
     pub c_view_pr: Box<CTCommonViewProperties>,
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTOutlineViewSlideEntry {
-    #[serde(rename = "@collapse")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@collapse"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub collapse: Option<bool>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTOutlineViewSlideList {
-    #[serde(rename = "sld")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "sld"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub sld: Vec<CTOutlineViewSlideEntry>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTOutlineViewProperties {
-    #[serde(rename = "cViewPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "cViewPr"))]
     pub c_view_pr: Box<CTCommonViewProperties>,
-    #[serde(rename = "sldLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sldLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sld_lst: Option<Box<CTOutlineViewSlideList>>,
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSlideSorterViewProperties {
-    #[serde(rename = "@showFormatting")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@showFormatting"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub show_formatting: Option<bool>,
-    #[serde(rename = "cViewPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "cViewPr"))]
     pub c_view_pr: Box<CTCommonViewProperties>,
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTGuide {
-    #[serde(rename = "@orient")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@orient"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub orient: Option<STDirection>,
-    #[serde(rename = "@pos")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@pos"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub pos: Option<ooxml_dml::types::STCoordinate32>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTGuideList {
-    #[serde(rename = "guide")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "guide"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub guide: Vec<CTGuide>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTCommonSlideViewProperties {
-    #[serde(rename = "@snapToGrid")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@snapToGrid"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub snap_to_grid: Option<bool>,
-    #[serde(rename = "@snapToObjects")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@snapToObjects"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub snap_to_objects: Option<bool>,
-    #[serde(rename = "@showGuides")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@showGuides"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub show_guides: Option<bool>,
-    #[serde(rename = "cViewPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "cViewPr"))]
     pub c_view_pr: Box<CTCommonViewProperties>,
-    #[serde(rename = "guideLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "guideLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub guide_lst: Option<Box<CTGuideList>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSlideViewProperties {
-    #[serde(rename = "cSldViewPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "cSldViewPr"))]
     pub c_sld_view_pr: Box<CTCommonSlideViewProperties>,
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTNotesViewProperties {
-    #[serde(rename = "cSldViewPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "cSldViewPr"))]
     pub c_sld_view_pr: Box<CTCommonSlideViewProperties>,
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTViewProperties {
-    #[serde(rename = "@lastView")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@lastView"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub last_view: Option<STViewType>,
     #[cfg(feature = "pml-comments")]
-    #[serde(rename = "@showComments")]
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        with = "ooxml_xml::ooxml_bool"
+    #[cfg_attr(feature = "serde", serde(rename = "@showComments"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "ooxml_xml::ooxml_bool"
+        )
     )]
     pub show_comments: Option<bool>,
-    #[serde(rename = "normalViewPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "normalViewPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub normal_view_pr: Option<Box<CTNormalViewProperties>>,
-    #[serde(rename = "slideViewPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "slideViewPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub slide_view_pr: Option<Box<CTSlideViewProperties>>,
-    #[serde(rename = "outlineViewPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "outlineViewPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub outline_view_pr: Option<Box<CTOutlineViewProperties>>,
     #[cfg(feature = "pml-notes")]
-    #[serde(rename = "notesTextViewPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "notesTextViewPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub notes_text_view_pr: Option<Box<CTNotesTextViewProperties>>,
-    #[serde(rename = "sorterViewPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sorterViewPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sorter_view_pr: Option<Box<CTSlideSorterViewProperties>>,
     #[cfg(feature = "pml-notes")]
-    #[serde(rename = "notesViewPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "notesViewPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub notes_view_pr: Option<Box<CTNotesViewProperties>>,
-    #[serde(rename = "gridSpacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "gridSpacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub grid_spacing: Option<Box<ooxml_dml::types::PositiveSize2D>>,
     #[cfg(feature = "pml-extensions")]
-    #[serde(rename = "extLst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "extLst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ext_lst: Option<Box<CTExtensionList>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
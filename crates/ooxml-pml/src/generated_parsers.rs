@@ -9,16 +9,25 @@
 
 use super::generated::*;
 use ooxml_dml::types::*;
+use ooxml_xml::local_name_in;
 pub use ooxml_xml::{FromXml, ParseError};
 #[cfg(feature = "extra-children")]
 use ooxml_xml::{PositionedNode, RawXmlElement, RawXmlNode};
-use quick_xml::Reader;
+use quick_xml::NsReader;
 use quick_xml::events::{BytesStart, Event};
 use std::io::BufRead;
 
+/// Namespace URIs of the elements these parsers read.
+const NAMESPACES: &[&str] = &[
+    "http://schemas.openxmlformats.org/presentationml/2006/main",
+    "http://purl.oclc.org/ooxml/presentationml/main",
+    "http://schemas.openxmlformats.org/drawingml/2006/main",
+    "http://purl.oclc.org/ooxml/drawingml/main",
+];
+
 #[allow(dead_code)]
 /// Skip an element and all its children.
-fn skip_element<R: BufRead>(reader: &mut Reader<R>) -> Result<(), ParseError> {
+fn skip_element<R: BufRead>(reader: &mut NsReader<R>) -> Result<(), ParseError> {
     let mut depth = 1u32;
     let mut buf = Vec::new();
     loop {
@@ -40,7 +49,7 @@ fn skip_element<R: BufRead>(reader: &mut Reader<R>) -> Result<(), ParseError> {
 
 #[allow(dead_code)]
 /// Read the text content of an element until its end tag.
-fn read_text_content<R: BufRead>(reader: &mut Reader<R>) -> Result<String, ParseError> {
+fn read_text_content<R: BufRead>(reader: &mut NsReader<R>) -> Result<String, ParseError> {
     let mut text = String::new();
     let mut buf = Vec::new();
     loop {
@@ -86,7 +95,7 @@ fn decode_base64(s: &str) -> Option<Vec<u8>> {
 
 impl FromXml for CTSideDirectionTransition {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -133,7 +142,7 @@ impl FromXml for CTSideDirectionTransition {
 
 impl FromXml for CTCornerDirectionTransition {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -180,7 +189,7 @@ impl FromXml for CTCornerDirectionTransition {
 
 impl FromXml for CTEightDirectionTransition {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -227,7 +236,7 @@ impl FromXml for CTEightDirectionTransition {
 
 impl FromXml for CTOrientationTransition {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -274,7 +283,7 @@ impl FromXml for CTOrientationTransition {
 
 impl FromXml for CTInOutTransition {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -321,7 +330,7 @@ impl FromXml for CTInOutTransition {
 
 impl FromXml for CTOptionalBlackTransition {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -368,7 +377,7 @@ impl FromXml for CTOptionalBlackTransition {
 
 impl FromXml for CTSplitTransition {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -420,7 +429,7 @@ impl FromXml for CTSplitTransition {
 
 impl FromXml for CTWheelTransition {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -467,7 +476,7 @@ impl FromXml for CTWheelTransition {
 
 impl FromXml for CTTransitionStartSoundAction {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -503,7 +512,7 @@ impl FromXml for CTTransitionStartSoundAction {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"snd" => {
                                 f_snd = Some(Box::new(
                                     ooxml_dml::types::CTEmbeddedWAVAudioFile::from_xml(
@@ -533,7 +542,7 @@ impl FromXml for CTTransitionStartSoundAction {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"snd" => {
                                 f_snd = Some(Box::new(
                                     ooxml_dml::types::CTEmbeddedWAVAudioFile::from_xml(
@@ -580,7 +589,7 @@ impl FromXml for CTTransitionStartSoundAction {
 
 impl FromXml for CTTransitionSoundAction {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -597,7 +606,7 @@ impl FromXml for CTTransitionSoundAction {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"stSnd" => {
                                 f_st_snd = Some(Box::new(CTTransitionStartSoundAction::from_xml(
                                     reader, &e, false,
@@ -632,7 +641,7 @@ impl FromXml for CTTransitionSoundAction {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"stSnd" => {
                                 f_st_snd = Some(Box::new(CTTransitionStartSoundAction::from_xml(
                                     reader, &e, true,
@@ -682,7 +691,7 @@ impl FromXml for CTTransitionSoundAction {
 
 impl FromXml for SlideTransition {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -777,7 +786,7 @@ impl FromXml for SlideTransition {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "pml-transitions")]
                             b"blinds" => {
                                 f_blinds = Some(Box::new(CTOrientationTransition::from_xml(
@@ -1009,7 +1018,7 @@ impl FromXml for SlideTransition {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "pml-transitions")]
                             b"blinds" => {
                                 f_blinds = Some(Box::new(CTOrientationTransition::from_xml(
@@ -1308,7 +1317,7 @@ impl FromXml for SlideTransition {
 
 impl FromXml for CTTLIterateIntervalTime {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1355,7 +1364,7 @@ impl FromXml for CTTLIterateIntervalTime {
 
 impl FromXml for CTTLIterateIntervalPercentage {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1402,7 +1411,7 @@ impl FromXml for CTTLIterateIntervalPercentage {
 
 impl FromXml for CTTLIterateData {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1443,7 +1452,7 @@ impl FromXml for CTTLIterateData {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tmAbs" => {
                                 f_tm_abs = Some(Box::new(CTTLIterateIntervalTime::from_xml(
                                     reader, &e, false,
@@ -1480,7 +1489,7 @@ impl FromXml for CTTLIterateData {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tmAbs" => {
                                 f_tm_abs = Some(Box::new(CTTLIterateIntervalTime::from_xml(
                                     reader, &e, true,
@@ -1536,7 +1545,7 @@ impl FromXml for CTTLIterateData {
 
 impl FromXml for CTTLSubShapeId {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1583,7 +1592,7 @@ impl FromXml for CTTLSubShapeId {
 
 impl FromXml for CTTLTextTargetElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1600,7 +1609,7 @@ impl FromXml for CTTLTextTargetElement {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"charRg" => {
                                 f_char_rg =
                                     Some(Box::new(CTIndexRange::from_xml(reader, &e, false)?));
@@ -1634,7 +1643,7 @@ impl FromXml for CTTLTextTargetElement {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"charRg" => {
                                 f_char_rg =
                                     Some(Box::new(CTIndexRange::from_xml(reader, &e, true)?));
@@ -1683,7 +1692,7 @@ impl FromXml for CTTLTextTargetElement {
 
 impl FromXml for CTTLOleChartTargetElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1735,7 +1744,7 @@ impl FromXml for CTTLOleChartTargetElement {
 
 impl FromXml for CTTLShapeTargetElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1775,7 +1784,7 @@ impl FromXml for CTTLShapeTargetElement {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"bg" => {
                                 f_bg = Some(Box::new(CTEmpty::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -1838,7 +1847,7 @@ impl FromXml for CTTLShapeTargetElement {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"bg" => {
                                 f_bg = Some(Box::new(CTEmpty::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -1922,7 +1931,7 @@ impl FromXml for CTTLShapeTargetElement {
 
 impl FromXml for CTTLTimeTargetElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1941,7 +1950,7 @@ impl FromXml for CTTLTimeTargetElement {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sldTgt" => {
                                 f_sld_tgt = Some(Box::new(CTEmpty::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -1995,7 +2004,7 @@ impl FromXml for CTTLTimeTargetElement {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sldTgt" => {
                                 f_sld_tgt = Some(Box::new(CTEmpty::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -2066,7 +2075,7 @@ impl FromXml for CTTLTimeTargetElement {
 
 impl FromXml for CTTLTriggerTimeNodeID {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2113,7 +2122,7 @@ impl FromXml for CTTLTriggerTimeNodeID {
 
 impl FromXml for CTTLTriggerRuntimeNode {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2160,7 +2169,7 @@ impl FromXml for CTTLTriggerRuntimeNode {
 
 impl FromXml for CTTLTimeCondition {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2202,7 +2211,7 @@ impl FromXml for CTTLTimeCondition {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tgtEl" => {
                                 f_tgt_el = Some(Box::new(CTTLTimeTargetElement::from_xml(
                                     reader, &e, false,
@@ -2248,7 +2257,7 @@ impl FromXml for CTTLTimeCondition {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tgtEl" => {
                                 f_tgt_el = Some(Box::new(CTTLTimeTargetElement::from_xml(
                                     reader, &e, true,
@@ -2314,7 +2323,7 @@ impl FromXml for CTTLTimeCondition {
 
 impl FromXml for CTTLTimeConditionList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2330,7 +2339,7 @@ impl FromXml for CTTLTimeConditionList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cond" => {
                                 f_cond.push(CTTLTimeCondition::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -2356,7 +2365,7 @@ impl FromXml for CTTLTimeConditionList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cond" => {
                                 f_cond.push(CTTLTimeCondition::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -2396,7 +2405,7 @@ impl FromXml for CTTLTimeConditionList {
 
 impl FromXml for CTTimeNodeList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2424,7 +2433,7 @@ impl FromXml for CTTimeNodeList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"par" => {
                                 f_par.push(Box::new(CTTLCommonTimeNodeData::from_xml(
                                     reader, &e, false,
@@ -2544,7 +2553,7 @@ impl FromXml for CTTimeNodeList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"par" => {
                                 f_par.push(Box::new(CTTLCommonTimeNodeData::from_xml(
                                     reader, &e, true,
@@ -2689,7 +2698,7 @@ impl FromXml for CTTimeNodeList {
 
 impl FromXml for CTTLCommonTimeNodeData {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2818,7 +2827,7 @@ impl FromXml for CTTLCommonTimeNodeData {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"stCondLst" => {
                                 f_st_cond_lst = Some(Box::new(CTTLTimeConditionList::from_xml(
                                     reader, &e, false,
@@ -2887,7 +2896,7 @@ impl FromXml for CTTLCommonTimeNodeData {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"stCondLst" => {
                                 f_st_cond_lst = Some(Box::new(CTTLTimeConditionList::from_xml(
                                     reader, &e, true,
@@ -3000,7 +3009,7 @@ impl FromXml for CTTLCommonTimeNodeData {
 
 impl FromXml for CTTLTimeNodeSequence {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3046,7 +3055,7 @@ impl FromXml for CTTLTimeNodeSequence {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cTn" => {
                                 f_c_tn = Some(Box::new(CTTLCommonTimeNodeData::from_xml(
                                     reader, &e, false,
@@ -3092,7 +3101,7 @@ impl FromXml for CTTLTimeNodeSequence {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cTn" => {
                                 f_c_tn = Some(Box::new(CTTLCommonTimeNodeData::from_xml(
                                     reader, &e, true,
@@ -3159,7 +3168,7 @@ impl FromXml for CTTLTimeNodeSequence {
 
 impl FromXml for CTTLBehaviorAttributeNameList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3175,7 +3184,7 @@ impl FromXml for CTTLBehaviorAttributeNameList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"attrName" => {
                                 f_attr_name.push(read_text_content(reader)?);
                                 #[cfg(feature = "extra-children")]
@@ -3201,7 +3210,7 @@ impl FromXml for CTTLBehaviorAttributeNameList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"attrName" => {
                                 f_attr_name.push(String::new());
                                 #[cfg(feature = "extra-children")]
@@ -3241,7 +3250,7 @@ impl FromXml for CTTLBehaviorAttributeNameList {
 
 impl FromXml for CTTLCommonBehaviorData {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3307,7 +3316,7 @@ impl FromXml for CTTLCommonBehaviorData {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cTn" => {
                                 f_c_tn = Some(Box::new(CTTLCommonTimeNodeData::from_xml(
                                     reader, &e, false,
@@ -3353,7 +3362,7 @@ impl FromXml for CTTLCommonBehaviorData {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cTn" => {
                                 f_c_tn = Some(Box::new(CTTLCommonTimeNodeData::from_xml(
                                     reader, &e, true,
@@ -3425,7 +3434,7 @@ impl FromXml for CTTLCommonBehaviorData {
 
 impl FromXml for CTTLAnimVariantBooleanVal {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3472,7 +3481,7 @@ impl FromXml for CTTLAnimVariantBooleanVal {
 
 impl FromXml for CTTLAnimVariantIntegerVal {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3519,7 +3528,7 @@ impl FromXml for CTTLAnimVariantIntegerVal {
 
 impl FromXml for CTTLAnimVariantFloatVal {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3566,7 +3575,7 @@ impl FromXml for CTTLAnimVariantFloatVal {
 
 impl FromXml for CTTLAnimVariantStringVal {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3613,7 +3622,7 @@ impl FromXml for CTTLAnimVariantStringVal {
 
 impl FromXml for CTTLAnimVariant {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3633,7 +3642,7 @@ impl FromXml for CTTLAnimVariant {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"boolVal" => {
                                 f_bool_val = Some(Box::new(CTTLAnimVariantBooleanVal::from_xml(
                                     reader, &e, false,
@@ -3697,7 +3706,7 @@ impl FromXml for CTTLAnimVariant {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"boolVal" => {
                                 f_bool_val = Some(Box::new(CTTLAnimVariantBooleanVal::from_xml(
                                     reader, &e, true,
@@ -3779,7 +3788,7 @@ impl FromXml for CTTLAnimVariant {
 
 impl FromXml for CTTLTimeAnimateValue {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3819,7 +3828,7 @@ impl FromXml for CTTLTimeAnimateValue {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"val" => {
                                 f_value =
                                     Some(Box::new(CTTLAnimVariant::from_xml(reader, &e, false)?));
@@ -3846,7 +3855,7 @@ impl FromXml for CTTLTimeAnimateValue {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"val" => {
                                 f_value =
                                     Some(Box::new(CTTLAnimVariant::from_xml(reader, &e, true)?));
@@ -3891,7 +3900,7 @@ impl FromXml for CTTLTimeAnimateValue {
 
 impl FromXml for CTTLTimeAnimateValueList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3907,7 +3916,7 @@ impl FromXml for CTTLTimeAnimateValueList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tav" => {
                                 f_tav.push(CTTLTimeAnimateValue::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -3933,7 +3942,7 @@ impl FromXml for CTTLTimeAnimateValueList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tav" => {
                                 f_tav.push(CTTLTimeAnimateValue::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -3973,7 +3982,7 @@ impl FromXml for CTTLTimeAnimateValueList {
 
 impl FromXml for CTTLAnimateBehavior {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4026,7 +4035,7 @@ impl FromXml for CTTLAnimateBehavior {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cBhvr" => {
                                 f_c_bhvr = Some(Box::new(CTTLCommonBehaviorData::from_xml(
                                     reader, &e, false,
@@ -4063,7 +4072,7 @@ impl FromXml for CTTLAnimateBehavior {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cBhvr" => {
                                 f_c_bhvr = Some(Box::new(CTTLCommonBehaviorData::from_xml(
                                     reader, &e, true,
@@ -4122,7 +4131,7 @@ impl FromXml for CTTLAnimateBehavior {
 
 impl FromXml for CTTLByRgbColorTransform {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4179,7 +4188,7 @@ impl FromXml for CTTLByRgbColorTransform {
 
 impl FromXml for CTTLByHslColorTransform {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4236,7 +4245,7 @@ impl FromXml for CTTLByHslColorTransform {
 
 impl FromXml for CTTLByAnimateColorTransform {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4253,7 +4262,7 @@ impl FromXml for CTTLByAnimateColorTransform {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rgb" => {
                                 f_rgb = Some(Box::new(CTTLByRgbColorTransform::from_xml(
                                     reader, &e, false,
@@ -4290,7 +4299,7 @@ impl FromXml for CTTLByAnimateColorTransform {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rgb" => {
                                 f_rgb = Some(Box::new(CTTLByRgbColorTransform::from_xml(
                                     reader, &e, true,
@@ -4342,7 +4351,7 @@ impl FromXml for CTTLByAnimateColorTransform {
 
 impl FromXml for CTTLAnimateColorBehavior {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4385,7 +4394,7 @@ impl FromXml for CTTLAnimateColorBehavior {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cBhvr" => {
                                 f_c_bhvr = Some(Box::new(CTTLCommonBehaviorData::from_xml(
                                     reader, &e, false,
@@ -4440,7 +4449,7 @@ impl FromXml for CTTLAnimateColorBehavior {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cBhvr" => {
                                 f_c_bhvr = Some(Box::new(CTTLCommonBehaviorData::from_xml(
                                     reader, &e, true,
@@ -4516,7 +4525,7 @@ impl FromXml for CTTLAnimateColorBehavior {
 
 impl FromXml for CTTLAnimateEffectBehavior {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4561,7 +4570,7 @@ impl FromXml for CTTLAnimateEffectBehavior {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cBhvr" => {
                                 f_c_bhvr = Some(Box::new(CTTLCommonBehaviorData::from_xml(
                                     reader, &e, false,
@@ -4597,7 +4606,7 @@ impl FromXml for CTTLAnimateEffectBehavior {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cBhvr" => {
                                 f_c_bhvr = Some(Box::new(CTTLCommonBehaviorData::from_xml(
                                     reader, &e, true,
@@ -4653,7 +4662,7 @@ impl FromXml for CTTLAnimateEffectBehavior {
 
 impl FromXml for CTTLPoint {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4705,7 +4714,7 @@ impl FromXml for CTTLPoint {
 
 impl FromXml for CTTLAnimateMotionBehavior {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4761,7 +4770,7 @@ impl FromXml for CTTLAnimateMotionBehavior {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cBhvr" => {
                                 f_c_bhvr = Some(Box::new(CTTLCommonBehaviorData::from_xml(
                                     reader, &e, false,
@@ -4817,7 +4826,7 @@ impl FromXml for CTTLAnimateMotionBehavior {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cBhvr" => {
                                 f_c_bhvr = Some(Box::new(CTTLCommonBehaviorData::from_xml(
                                     reader, &e, true,
@@ -4898,7 +4907,7 @@ impl FromXml for CTTLAnimateMotionBehavior {
 
 impl FromXml for CTTLAnimateRotationBehavior {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4942,7 +4951,7 @@ impl FromXml for CTTLAnimateRotationBehavior {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cBhvr" => {
                                 f_c_bhvr = Some(Box::new(CTTLCommonBehaviorData::from_xml(
                                     reader, &e, false,
@@ -4970,7 +4979,7 @@ impl FromXml for CTTLAnimateRotationBehavior {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cBhvr" => {
                                 f_c_bhvr = Some(Box::new(CTTLCommonBehaviorData::from_xml(
                                     reader, &e, true,
@@ -5017,7 +5026,7 @@ impl FromXml for CTTLAnimateRotationBehavior {
 
 impl FromXml for CTTLAnimateScaleBehavior {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5056,7 +5065,7 @@ impl FromXml for CTTLAnimateScaleBehavior {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cBhvr" => {
                                 f_c_bhvr = Some(Box::new(CTTLCommonBehaviorData::from_xml(
                                     reader, &e, false,
@@ -5105,7 +5114,7 @@ impl FromXml for CTTLAnimateScaleBehavior {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cBhvr" => {
                                 f_c_bhvr = Some(Box::new(CTTLCommonBehaviorData::from_xml(
                                     reader, &e, true,
@@ -5174,7 +5183,7 @@ impl FromXml for CTTLAnimateScaleBehavior {
 
 impl FromXml for CTTLCommandBehavior {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5214,7 +5223,7 @@ impl FromXml for CTTLCommandBehavior {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cBhvr" => {
                                 f_c_bhvr = Some(Box::new(CTTLCommonBehaviorData::from_xml(
                                     reader, &e, false,
@@ -5242,7 +5251,7 @@ impl FromXml for CTTLCommandBehavior {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cBhvr" => {
                                 f_c_bhvr = Some(Box::new(CTTLCommonBehaviorData::from_xml(
                                     reader, &e, true,
@@ -5288,7 +5297,7 @@ impl FromXml for CTTLCommandBehavior {
 
 impl FromXml for CTTLSetBehavior {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5305,7 +5314,7 @@ impl FromXml for CTTLSetBehavior {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cBhvr" => {
                                 f_c_bhvr = Some(Box::new(CTTLCommonBehaviorData::from_xml(
                                     reader, &e, false,
@@ -5341,7 +5350,7 @@ impl FromXml for CTTLSetBehavior {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cBhvr" => {
                                 f_c_bhvr = Some(Box::new(CTTLCommonBehaviorData::from_xml(
                                     reader, &e, true,
@@ -5391,7 +5400,7 @@ impl FromXml for CTTLSetBehavior {
 
 impl FromXml for CTTLCommonMediaNodeData {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5440,7 +5449,7 @@ impl FromXml for CTTLCommonMediaNodeData {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cTn" => {
                                 f_c_tn = Some(Box::new(CTTLCommonTimeNodeData::from_xml(
                                     reader, &e, false,
@@ -5477,7 +5486,7 @@ impl FromXml for CTTLCommonMediaNodeData {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cTn" => {
                                 f_c_tn = Some(Box::new(CTTLCommonTimeNodeData::from_xml(
                                     reader, &e, true,
@@ -5535,7 +5544,7 @@ impl FromXml for CTTLCommonMediaNodeData {
 
 impl FromXml for CTTLMediaNodeAudio {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5571,7 +5580,7 @@ impl FromXml for CTTLMediaNodeAudio {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cMediaNode" => {
                                 f_c_media_node = Some(Box::new(CTTLCommonMediaNodeData::from_xml(
                                     reader, &e, false,
@@ -5599,7 +5608,7 @@ impl FromXml for CTTLMediaNodeAudio {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cMediaNode" => {
                                 f_c_media_node = Some(Box::new(CTTLCommonMediaNodeData::from_xml(
                                     reader, &e, true,
@@ -5645,7 +5654,7 @@ impl FromXml for CTTLMediaNodeAudio {
 
 impl FromXml for CTTLMediaNodeVideo {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5681,7 +5690,7 @@ impl FromXml for CTTLMediaNodeVideo {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cMediaNode" => {
                                 f_c_media_node = Some(Box::new(CTTLCommonMediaNodeData::from_xml(
                                     reader, &e, false,
@@ -5709,7 +5718,7 @@ impl FromXml for CTTLMediaNodeVideo {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cMediaNode" => {
                                 f_c_media_node = Some(Box::new(CTTLCommonMediaNodeData::from_xml(
                                     reader, &e, true,
@@ -5755,7 +5764,7 @@ impl FromXml for CTTLMediaNodeVideo {
 
 impl FromXml for PAGTLBuild {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5812,7 +5821,7 @@ impl FromXml for PAGTLBuild {
 
 impl FromXml for CTTLTemplate {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5848,7 +5857,7 @@ impl FromXml for CTTLTemplate {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tnLst" => {
                                 f_tn_lst =
                                     Some(Box::new(CTTimeNodeList::from_xml(reader, &e, false)?));
@@ -5875,7 +5884,7 @@ impl FromXml for CTTLTemplate {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tnLst" => {
                                 f_tn_lst =
                                     Some(Box::new(CTTimeNodeList::from_xml(reader, &e, true)?));
@@ -5919,7 +5928,7 @@ impl FromXml for CTTLTemplate {
 
 impl FromXml for CTTLTemplateList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5935,7 +5944,7 @@ impl FromXml for CTTLTemplateList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tmpl" => {
                                 f_tmpl.push(CTTLTemplate::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -5961,7 +5970,7 @@ impl FromXml for CTTLTemplateList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tmpl" => {
                                 f_tmpl.push(CTTLTemplate::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -6001,7 +6010,7 @@ impl FromXml for CTTLTemplateList {
 
 impl FromXml for CTTLBuildParagraph {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6069,7 +6078,7 @@ impl FromXml for CTTLBuildParagraph {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tmplLst" => {
                                 f_tmpl_lst =
                                     Some(Box::new(CTTLTemplateList::from_xml(reader, &e, false)?));
@@ -6096,7 +6105,7 @@ impl FromXml for CTTLBuildParagraph {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tmplLst" => {
                                 f_tmpl_lst =
                                     Some(Box::new(CTTLTemplateList::from_xml(reader, &e, true)?));
@@ -6148,7 +6157,7 @@ impl FromXml for CTTLBuildParagraph {
 
 impl FromXml for CTTLBuildDiagram {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6210,7 +6219,7 @@ impl FromXml for CTTLBuildDiagram {
 
 impl FromXml for CTTLOleBuildChart {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6277,7 +6286,7 @@ impl FromXml for CTTLOleBuildChart {
 
 impl FromXml for CTTLGraphicalObjectBuild {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6322,7 +6331,7 @@ impl FromXml for CTTLGraphicalObjectBuild {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"bldAsOne" => {
                                 f_bld_as_one =
                                     Some(Box::new(CTEmpty::from_xml(reader, &e, false)?));
@@ -6356,7 +6365,7 @@ impl FromXml for CTTLGraphicalObjectBuild {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"bldAsOne" => {
                                 f_bld_as_one = Some(Box::new(CTEmpty::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -6409,7 +6418,7 @@ impl FromXml for CTTLGraphicalObjectBuild {
 
 impl FromXml for CTBuildList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6428,7 +6437,7 @@ impl FromXml for CTBuildList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"bldP" => {
                                 f_bld_p.push(CTTLBuildParagraph::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -6477,7 +6486,7 @@ impl FromXml for CTBuildList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"bldP" => {
                                 f_bld_p.push(CTTLBuildParagraph::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -6543,7 +6552,7 @@ impl FromXml for CTBuildList {
 
 impl FromXml for SlideTiming {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6564,7 +6573,7 @@ impl FromXml for SlideTiming {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "pml-animations")]
                             b"tnLst" => {
                                 f_tn_lst =
@@ -6611,7 +6620,7 @@ impl FromXml for SlideTiming {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "pml-animations")]
                             b"tnLst" => {
                                 f_tn_lst =
@@ -6677,7 +6686,7 @@ impl FromXml for SlideTiming {
 
 impl FromXml for CTEmpty {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6705,7 +6714,7 @@ impl FromXml for CTEmpty {
 
 impl FromXml for CTIndexRange {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6757,7 +6766,7 @@ impl FromXml for CTIndexRange {
 
 impl FromXml for CTSlideRelationshipListEntry {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6805,7 +6814,7 @@ impl FromXml for CTSlideRelationshipListEntry {
 
 impl FromXml for CTSlideRelationshipList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6821,7 +6830,7 @@ impl FromXml for CTSlideRelationshipList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sld" => {
                                 f_sld.push(CTSlideRelationshipListEntry::from_xml(
                                     reader, &e, false,
@@ -6849,7 +6858,7 @@ impl FromXml for CTSlideRelationshipList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sld" => {
                                 f_sld.push(CTSlideRelationshipListEntry::from_xml(
                                     reader, &e, true,
@@ -6891,7 +6900,7 @@ impl FromXml for CTSlideRelationshipList {
 
 impl FromXml for CTCustomShowId {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6938,7 +6947,7 @@ impl FromXml for CTCustomShowId {
 
 impl FromXml for EGSlideListChoice {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6965,7 +6974,7 @@ impl FromXml for EGSlideListChoice {
 
 impl FromXml for CTCustomerData {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7013,7 +7022,7 @@ impl FromXml for CTCustomerData {
 
 impl FromXml for CTTagsData {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7061,7 +7070,7 @@ impl FromXml for CTTagsData {
 
 impl FromXml for CTCustomerDataList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7078,7 +7087,7 @@ impl FromXml for CTCustomerDataList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"custData" => {
                                 f_cust_data.push(CTCustomerData::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -7111,7 +7120,7 @@ impl FromXml for CTCustomerDataList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"custData" => {
                                 f_cust_data.push(CTCustomerData::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -7159,7 +7168,7 @@ impl FromXml for CTCustomerDataList {
 
 impl FromXml for CTExtension {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7206,7 +7215,7 @@ impl FromXml for CTExtension {
 
 impl FromXml for EGExtensionList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7222,7 +7231,7 @@ impl FromXml for EGExtensionList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ext" => {
                                 f_ext.push(CTExtension::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -7248,7 +7257,7 @@ impl FromXml for EGExtensionList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ext" => {
                                 f_ext.push(CTExtension::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -7288,7 +7297,7 @@ impl FromXml for EGExtensionList {
 
 impl FromXml for CTExtensionList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7304,7 +7313,7 @@ impl FromXml for CTExtensionList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ext" => {
                                 f_ext.push(CTExtension::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -7330,7 +7339,7 @@ impl FromXml for CTExtensionList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ext" => {
                                 f_ext.push(CTExtension::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -7370,7 +7379,7 @@ impl FromXml for CTExtensionList {
 
 impl FromXml for CTExtensionListModify {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7406,7 +7415,7 @@ impl FromXml for CTExtensionListModify {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ext" => {
                                 f_ext.push(CTExtension::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -7432,7 +7441,7 @@ impl FromXml for CTExtensionListModify {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ext" => {
                                 f_ext.push(CTExtension::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -7475,7 +7484,7 @@ impl FromXml for CTExtensionListModify {
 
 impl FromXml for CTCommentAuthor {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7538,7 +7547,7 @@ impl FromXml for CTCommentAuthor {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "pml-extensions")]
                             b"extLst" => {
                                 f_ext_lst =
@@ -7566,7 +7575,7 @@ impl FromXml for CTCommentAuthor {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "pml-extensions")]
                             b"extLst" => {
                                 f_ext_lst =
@@ -7623,7 +7632,7 @@ impl FromXml for CTCommentAuthor {
 
 impl FromXml for CTCommentAuthorList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7639,7 +7648,7 @@ impl FromXml for CTCommentAuthorList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cmAuthor" => {
                                 f_cm_author.push(CTCommentAuthor::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -7665,7 +7674,7 @@ impl FromXml for CTCommentAuthorList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cmAuthor" => {
                                 f_cm_author.push(CTCommentAuthor::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -7705,7 +7714,7 @@ impl FromXml for CTCommentAuthorList {
 
 impl FromXml for CTComment {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7760,7 +7769,7 @@ impl FromXml for CTComment {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "pml-comments")]
                             b"pos" => {
                                 f_pos = Some(Box::new(ooxml_dml::types::Point2D::from_xml(
@@ -7807,7 +7816,7 @@ impl FromXml for CTComment {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "pml-comments")]
                             b"pos" => {
                                 f_pos = Some(Box::new(ooxml_dml::types::Point2D::from_xml(
@@ -7882,7 +7891,7 @@ impl FromXml for CTComment {
 
 impl FromXml for CTCommentList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7898,7 +7907,7 @@ impl FromXml for CTCommentList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cm" => {
                                 f_cm.push(CTComment::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -7924,7 +7933,7 @@ impl FromXml for CTCommentList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cm" => {
                                 f_cm.push(CTComment::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -7964,7 +7973,7 @@ impl FromXml for CTCommentList {
 
 impl FromXml for PAGOle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8026,7 +8035,7 @@ impl FromXml for PAGOle {
 
 impl FromXml for CTOleObjectEmbed {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8062,7 +8071,7 @@ impl FromXml for CTOleObjectEmbed {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst =
                                     Some(Box::new(CTExtensionList::from_xml(reader, &e, false)?));
@@ -8089,7 +8098,7 @@ impl FromXml for CTOleObjectEmbed {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst =
                                     Some(Box::new(CTExtensionList::from_xml(reader, &e, true)?));
@@ -8133,7 +8142,7 @@ impl FromXml for CTOleObjectEmbed {
 
 impl FromXml for CTOleObjectLink {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8169,7 +8178,7 @@ impl FromXml for CTOleObjectLink {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst =
                                     Some(Box::new(CTExtensionList::from_xml(reader, &e, false)?));
@@ -8196,7 +8205,7 @@ impl FromXml for CTOleObjectLink {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst =
                                     Some(Box::new(CTExtensionList::from_xml(reader, &e, true)?));
@@ -8240,7 +8249,7 @@ impl FromXml for CTOleObjectLink {
 
 impl FromXml for CTOleObject {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8298,7 +8307,7 @@ impl FromXml for CTOleObject {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"embed" => {
                                 f_embed =
                                     Some(Box::new(CTOleObjectEmbed::from_xml(reader, &e, false)?));
@@ -8340,7 +8349,7 @@ impl FromXml for CTOleObject {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"embed" => {
                                 f_embed =
                                     Some(Box::new(CTOleObjectEmbed::from_xml(reader, &e, true)?));
@@ -8406,7 +8415,7 @@ impl FromXml for CTOleObject {
 
 impl FromXml for CTControl {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8459,7 +8468,7 @@ impl FromXml for CTControl {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst =
                                     Some(Box::new(CTExtensionList::from_xml(reader, &e, false)?));
@@ -8493,7 +8502,7 @@ impl FromXml for CTControl {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst =
                                     Some(Box::new(CTExtensionList::from_xml(reader, &e, true)?));
@@ -8549,7 +8558,7 @@ impl FromXml for CTControl {
 
 impl FromXml for CTControlList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8565,7 +8574,7 @@ impl FromXml for CTControlList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"control" => {
                                 f_control.push(CTControl::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -8591,7 +8600,7 @@ impl FromXml for CTControlList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"control" => {
                                 f_control.push(CTControl::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -8631,7 +8640,7 @@ impl FromXml for CTControlList {
 
 impl FromXml for CTSlideIdListEntry {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8667,7 +8676,7 @@ impl FromXml for CTSlideIdListEntry {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst =
                                     Some(Box::new(CTExtensionList::from_xml(reader, &e, false)?));
@@ -8694,7 +8703,7 @@ impl FromXml for CTSlideIdListEntry {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst =
                                     Some(Box::new(CTExtensionList::from_xml(reader, &e, true)?));
@@ -8738,7 +8747,7 @@ impl FromXml for CTSlideIdListEntry {
 
 impl FromXml for SlideIdList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8754,7 +8763,7 @@ impl FromXml for SlideIdList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sldId" => {
                                 f_sld_id.push(CTSlideIdListEntry::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -8780,7 +8789,7 @@ impl FromXml for SlideIdList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sldId" => {
                                 f_sld_id.push(CTSlideIdListEntry::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -8820,7 +8829,7 @@ impl FromXml for SlideIdList {
 
 impl FromXml for CTSlideMasterIdListEntry {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8856,7 +8865,7 @@ impl FromXml for CTSlideMasterIdListEntry {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst =
                                     Some(Box::new(CTExtensionList::from_xml(reader, &e, false)?));
@@ -8883,7 +8892,7 @@ impl FromXml for CTSlideMasterIdListEntry {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst =
                                     Some(Box::new(CTExtensionList::from_xml(reader, &e, true)?));
@@ -8927,7 +8936,7 @@ impl FromXml for CTSlideMasterIdListEntry {
 
 impl FromXml for CTSlideMasterIdList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8943,7 +8952,7 @@ impl FromXml for CTSlideMasterIdList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sldMasterId" => {
                                 f_sld_master_id
                                     .push(CTSlideMasterIdListEntry::from_xml(reader, &e, false)?);
@@ -8970,7 +8979,7 @@ impl FromXml for CTSlideMasterIdList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sldMasterId" => {
                                 f_sld_master_id
                                     .push(CTSlideMasterIdListEntry::from_xml(reader, &e, true)?);
@@ -9011,7 +9020,7 @@ impl FromXml for CTSlideMasterIdList {
 
 impl FromXml for CTNotesMasterIdListEntry {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9027,7 +9036,7 @@ impl FromXml for CTNotesMasterIdListEntry {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst =
                                     Some(Box::new(CTExtensionList::from_xml(reader, &e, false)?));
@@ -9054,7 +9063,7 @@ impl FromXml for CTNotesMasterIdListEntry {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst =
                                     Some(Box::new(CTExtensionList::from_xml(reader, &e, true)?));
@@ -9095,7 +9104,7 @@ impl FromXml for CTNotesMasterIdListEntry {
 
 impl FromXml for CTNotesMasterIdList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9111,7 +9120,7 @@ impl FromXml for CTNotesMasterIdList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"notesMasterId" => {
                                 f_notes_master_id = Some(Box::new(
                                     CTNotesMasterIdListEntry::from_xml(reader, &e, false)?,
@@ -9139,7 +9148,7 @@ impl FromXml for CTNotesMasterIdList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"notesMasterId" => {
                                 f_notes_master_id = Some(Box::new(
                                     CTNotesMasterIdListEntry::from_xml(reader, &e, true)?,
@@ -9181,7 +9190,7 @@ impl FromXml for CTNotesMasterIdList {
 
 impl FromXml for CTHandoutMasterIdListEntry {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9197,7 +9206,7 @@ impl FromXml for CTHandoutMasterIdListEntry {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst =
                                     Some(Box::new(CTExtensionList::from_xml(reader, &e, false)?));
@@ -9224,7 +9233,7 @@ impl FromXml for CTHandoutMasterIdListEntry {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst =
                                     Some(Box::new(CTExtensionList::from_xml(reader, &e, true)?));
@@ -9265,7 +9274,7 @@ impl FromXml for CTHandoutMasterIdListEntry {
 
 impl FromXml for CTHandoutMasterIdList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9281,7 +9290,7 @@ impl FromXml for CTHandoutMasterIdList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"handoutMasterId" => {
                                 f_handout_master_id = Some(Box::new(
                                     CTHandoutMasterIdListEntry::from_xml(reader, &e, false)?,
@@ -9309,7 +9318,7 @@ impl FromXml for CTHandoutMasterIdList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"handoutMasterId" => {
                                 f_handout_master_id = Some(Box::new(
                                     CTHandoutMasterIdListEntry::from_xml(reader, &e, true)?,
@@ -9351,7 +9360,7 @@ impl FromXml for CTHandoutMasterIdList {
 
 impl FromXml for CTEmbeddedFontDataId {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9399,7 +9408,7 @@ impl FromXml for CTEmbeddedFontDataId {
 
 impl FromXml for CTEmbeddedFontListEntry {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9419,7 +9428,7 @@ impl FromXml for CTEmbeddedFontListEntry {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"font" => {
                                 f_font = Some(Box::new(ooxml_dml::types::TextFont::from_xml(
                                     reader, &e, false,
@@ -9483,7 +9492,7 @@ impl FromXml for CTEmbeddedFontListEntry {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"font" => {
                                 f_font = Some(Box::new(ooxml_dml::types::TextFont::from_xml(
                                     reader, &e, true,
@@ -9565,7 +9574,7 @@ impl FromXml for CTEmbeddedFontListEntry {
 
 impl FromXml for CTEmbeddedFontList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9581,7 +9590,7 @@ impl FromXml for CTEmbeddedFontList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"embeddedFont" => {
                                 f_embedded_font
                                     .push(CTEmbeddedFontListEntry::from_xml(reader, &e, false)?);
@@ -9608,7 +9617,7 @@ impl FromXml for CTEmbeddedFontList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"embeddedFont" => {
                                 f_embedded_font
                                     .push(CTEmbeddedFontListEntry::from_xml(reader, &e, true)?);
@@ -9649,7 +9658,7 @@ impl FromXml for CTEmbeddedFontList {
 
 impl FromXml for CTSmartTags {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9697,7 +9706,7 @@ impl FromXml for CTSmartTags {
 
 impl FromXml for CTCustomShow {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9738,7 +9747,7 @@ impl FromXml for CTCustomShow {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sldLst" => {
                                 f_sld_lst = Some(Box::new(CTSlideRelationshipList::from_xml(
                                     reader, &e, false,
@@ -9774,7 +9783,7 @@ impl FromXml for CTCustomShow {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sldLst" => {
                                 f_sld_lst = Some(Box::new(CTSlideRelationshipList::from_xml(
                                     reader, &e, true,
@@ -9829,7 +9838,7 @@ impl FromXml for CTCustomShow {
 
 impl FromXml for CTCustomShowList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9845,7 +9854,7 @@ impl FromXml for CTCustomShowList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"custShow" => {
                                 f_cust_show.push(CTCustomShow::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -9871,7 +9880,7 @@ impl FromXml for CTCustomShowList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"custShow" => {
                                 f_cust_show.push(CTCustomShow::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -9911,7 +9920,7 @@ impl FromXml for CTCustomShowList {
 
 impl FromXml for CTPhotoAlbum {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9959,7 +9968,7 @@ impl FromXml for CTPhotoAlbum {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst =
                                     Some(Box::new(CTExtensionList::from_xml(reader, &e, false)?));
@@ -9986,7 +9995,7 @@ impl FromXml for CTPhotoAlbum {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst =
                                     Some(Box::new(CTExtensionList::from_xml(reader, &e, true)?));
@@ -10033,7 +10042,7 @@ impl FromXml for CTPhotoAlbum {
 
 impl FromXml for CTSlideSize {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10090,7 +10099,7 @@ impl FromXml for CTSlideSize {
 
 impl FromXml for CTKinsoku {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10149,7 +10158,7 @@ impl FromXml for CTKinsoku {
 
 impl FromXml for CTModifyVerifier {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10271,7 +10280,7 @@ impl FromXml for CTModifyVerifier {
 
 impl FromXml for Presentation {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10389,7 +10398,7 @@ impl FromXml for Presentation {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sldMasterIdLst" => {
                                 f_sld_master_id_lst = Some(Box::new(
                                     CTSlideMasterIdList::from_xml(reader, &e, false)?,
@@ -10544,7 +10553,7 @@ impl FromXml for Presentation {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sldMasterIdLst" => {
                                 f_sld_master_id_lst = Some(Box::new(
                                     CTSlideMasterIdList::from_xml(reader, &e, true)?,
@@ -10756,7 +10765,7 @@ impl FromXml for Presentation {
 
 impl FromXml for CTHtmlPublishProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10801,7 +10810,7 @@ impl FromXml for CTHtmlPublishProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sldAll" | b"sldRg" | b"custShow" => {
                                 f_slide_list_choice =
                                     Some(Box::new(EGSlideListChoice::from_xml(reader, &e, false)?));
@@ -10836,7 +10845,7 @@ impl FromXml for CTHtmlPublishProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sldAll" | b"sldRg" | b"custShow" => {
                                 f_slide_list_choice =
                                     Some(Box::new(EGSlideListChoice::from_xml(reader, &e, true)?));
@@ -10891,7 +10900,7 @@ impl FromXml for CTHtmlPublishProperties {
 
 impl FromXml for CTWebProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10959,7 +10968,7 @@ impl FromXml for CTWebProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst =
                                     Some(Box::new(CTExtensionList::from_xml(reader, &e, false)?));
@@ -10986,7 +10995,7 @@ impl FromXml for CTWebProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst =
                                     Some(Box::new(CTExtensionList::from_xml(reader, &e, true)?));
@@ -11038,7 +11047,7 @@ impl FromXml for CTWebProperties {
 
 impl FromXml for CTPrintProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11090,7 +11099,7 @@ impl FromXml for CTPrintProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst =
                                     Some(Box::new(CTExtensionList::from_xml(reader, &e, false)?));
@@ -11117,7 +11126,7 @@ impl FromXml for CTPrintProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst =
                                     Some(Box::new(CTExtensionList::from_xml(reader, &e, true)?));
@@ -11165,7 +11174,7 @@ impl FromXml for CTPrintProperties {
 
 impl FromXml for CTShowInfoBrowse {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11212,7 +11221,7 @@ impl FromXml for CTShowInfoBrowse {
 
 impl FromXml for CTShowInfoKiosk {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11259,7 +11268,7 @@ impl FromXml for CTShowInfoKiosk {
 
 impl FromXml for EGShowType {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11286,7 +11295,7 @@ impl FromXml for EGShowType {
 
 impl FromXml for CTShowProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11337,7 +11346,7 @@ impl FromXml for CTShowProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"present" | b"browse" | b"kiosk" => {
                                 f_show_type =
                                     Some(Box::new(EGShowType::from_xml(reader, &e, false)?));
@@ -11389,7 +11398,7 @@ impl FromXml for CTShowProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"present" | b"browse" | b"kiosk" => {
                                 f_show_type =
                                     Some(Box::new(EGShowType::from_xml(reader, &e, true)?));
@@ -11464,7 +11473,7 @@ impl FromXml for CTShowProperties {
 
 impl FromXml for CTPresentationProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11489,7 +11498,7 @@ impl FromXml for CTPresentationProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "pml-external")]
                             b"htmlPubPr" => {
                                 f_html_pub_pr = Some(Box::new(CTHtmlPublishProperties::from_xml(
@@ -11562,7 +11571,7 @@ impl FromXml for CTPresentationProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "pml-external")]
                             b"htmlPubPr" => {
                                 f_html_pub_pr = Some(Box::new(CTHtmlPublishProperties::from_xml(
@@ -11658,7 +11667,7 @@ impl FromXml for CTPresentationProperties {
 
 impl FromXml for CTHeaderFooter {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11714,7 +11723,7 @@ impl FromXml for CTHeaderFooter {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTExtensionListModify::from_xml(
                                     reader, &e, false,
@@ -11742,7 +11751,7 @@ impl FromXml for CTHeaderFooter {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTExtensionListModify::from_xml(
                                     reader, &e, true,
@@ -11794,7 +11803,7 @@ impl FromXml for CTHeaderFooter {
 
 impl FromXml for CTPlaceholder {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11846,7 +11855,7 @@ impl FromXml for CTPlaceholder {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTExtensionListModify::from_xml(
                                     reader, &e, false,
@@ -11874,7 +11883,7 @@ impl FromXml for CTPlaceholder {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst = Some(Box::new(CTExtensionListModify::from_xml(
                                     reader, &e, true,
@@ -11923,7 +11932,7 @@ impl FromXml for CTPlaceholder {
 
 impl FromXml for CTApplicationNonVisualDrawingProps {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11965,7 +11974,7 @@ impl FromXml for CTApplicationNonVisualDrawingProps {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ph" => {
                                 f_ph = Some(Box::new(CTPlaceholder::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -12008,7 +12017,7 @@ impl FromXml for CTApplicationNonVisualDrawingProps {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ph" => {
                                 f_ph = Some(Box::new(CTPlaceholder::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -12070,7 +12079,7 @@ impl FromXml for CTApplicationNonVisualDrawingProps {
 
 impl FromXml for ShapeNonVisual {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12088,7 +12097,7 @@ impl FromXml for ShapeNonVisual {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cNvPr" => {
                                 f_c_nv_pr = Some(Box::new(
                                     ooxml_dml::types::CTNonVisualDrawingProps::from_xml(
@@ -12139,7 +12148,7 @@ impl FromXml for ShapeNonVisual {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cNvPr" => {
                                 f_c_nv_pr = Some(Box::new(
                                     ooxml_dml::types::CTNonVisualDrawingProps::from_xml(
@@ -12206,7 +12215,7 @@ impl FromXml for ShapeNonVisual {
 
 impl FromXml for Shape {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12250,7 +12259,7 @@ impl FromXml for Shape {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"nvSpPr" => {
                                 f_non_visual_properties =
                                     Some(Box::new(ShapeNonVisual::from_xml(reader, &e, false)?));
@@ -12316,7 +12325,7 @@ impl FromXml for Shape {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"nvSpPr" => {
                                 f_non_visual_properties =
                                     Some(Box::new(ShapeNonVisual::from_xml(reader, &e, true)?));
@@ -12408,7 +12417,7 @@ impl FromXml for Shape {
 
 impl FromXml for CTConnectorNonVisual {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12427,7 +12436,7 @@ impl FromXml for CTConnectorNonVisual {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cNvPr" => {
                                 f_c_nv_pr = Some(Box::new(
                                     ooxml_dml::types::CTNonVisualDrawingProps::from_xml(
@@ -12478,7 +12487,7 @@ impl FromXml for CTConnectorNonVisual {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cNvPr" => {
                                 f_c_nv_pr = Some(Box::new(
                                     ooxml_dml::types::CTNonVisualDrawingProps::from_xml(
@@ -12545,7 +12554,7 @@ impl FromXml for CTConnectorNonVisual {
 
 impl FromXml for Connector {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12566,7 +12575,7 @@ impl FromXml for Connector {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"nvCxnSpPr" => {
                                 f_non_visual_connector_properties = Some(Box::new(
                                     CTConnectorNonVisual::from_xml(reader, &e, false)?,
@@ -12624,7 +12633,7 @@ impl FromXml for Connector {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"nvCxnSpPr" => {
                                 f_non_visual_connector_properties = Some(Box::new(
                                     CTConnectorNonVisual::from_xml(reader, &e, true)?,
@@ -12703,7 +12712,7 @@ impl FromXml for Connector {
 
 impl FromXml for CTPictureNonVisual {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12721,7 +12730,7 @@ impl FromXml for CTPictureNonVisual {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cNvPr" => {
                                 f_c_nv_pr = Some(Box::new(
                                     ooxml_dml::types::CTNonVisualDrawingProps::from_xml(
@@ -12772,7 +12781,7 @@ impl FromXml for CTPictureNonVisual {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cNvPr" => {
                                 f_c_nv_pr = Some(Box::new(
                                     ooxml_dml::types::CTNonVisualDrawingProps::from_xml(
@@ -12839,7 +12848,7 @@ impl FromXml for CTPictureNonVisual {
 
 impl FromXml for Picture {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12861,7 +12870,7 @@ impl FromXml for Picture {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"nvPicPr" => {
                                 f_non_visual_picture_properties = Some(Box::new(
                                     CTPictureNonVisual::from_xml(reader, &e, false)?,
@@ -12929,7 +12938,7 @@ impl FromXml for Picture {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"nvPicPr" => {
                                 f_non_visual_picture_properties =
                                     Some(Box::new(CTPictureNonVisual::from_xml(reader, &e, true)?));
@@ -13019,7 +13028,7 @@ impl FromXml for Picture {
 
 impl FromXml for CTGraphicalObjectFrameNonVisual {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13039,7 +13048,7 @@ impl FromXml for CTGraphicalObjectFrameNonVisual {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cNvPr" => {
                                 f_c_nv_pr = Some(Box::new(
                                     ooxml_dml::types::CTNonVisualDrawingProps::from_xml(
@@ -13090,7 +13099,7 @@ impl FromXml for CTGraphicalObjectFrameNonVisual {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cNvPr" => {
                                 f_c_nv_pr = Some(Box::new(
                                     ooxml_dml::types::CTNonVisualDrawingProps::from_xml(
@@ -13157,7 +13166,7 @@ impl FromXml for CTGraphicalObjectFrameNonVisual {
 
 impl FromXml for GraphicalObjectFrame {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13198,7 +13207,7 @@ impl FromXml for GraphicalObjectFrame {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"nvGraphicFramePr" => {
                                 f_nv_graphic_frame_pr = Some(Box::new(
                                     CTGraphicalObjectFrameNonVisual::from_xml(reader, &e, false)?,
@@ -13245,7 +13254,7 @@ impl FromXml for GraphicalObjectFrame {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"nvGraphicFramePr" => {
                                 f_nv_graphic_frame_pr = Some(Box::new(
                                     CTGraphicalObjectFrameNonVisual::from_xml(reader, &e, true)?,
@@ -13314,7 +13323,7 @@ impl FromXml for GraphicalObjectFrame {
 
 impl FromXml for CTGroupShapeNonVisual {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13333,7 +13342,7 @@ impl FromXml for CTGroupShapeNonVisual {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cNvPr" => {
                                 f_c_nv_pr = Some(Box::new(
                                     ooxml_dml::types::CTNonVisualDrawingProps::from_xml(
@@ -13384,7 +13393,7 @@ impl FromXml for CTGroupShapeNonVisual {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cNvPr" => {
                                 f_c_nv_pr = Some(Box::new(
                                     ooxml_dml::types::CTNonVisualDrawingProps::from_xml(
@@ -13451,7 +13460,7 @@ impl FromXml for CTGroupShapeNonVisual {
 
 impl FromXml for GroupShape {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13477,7 +13486,7 @@ impl FromXml for GroupShape {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"nvGrpSpPr" => {
                                 f_non_visual_group_properties = Some(Box::new(
                                     CTGroupShapeNonVisual::from_xml(reader, &e, false)?,
@@ -13570,7 +13579,7 @@ impl FromXml for GroupShape {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"nvGrpSpPr" => {
                                 f_non_visual_group_properties = Some(Box::new(
                                     CTGroupShapeNonVisual::from_xml(reader, &e, true)?,
@@ -13689,7 +13698,7 @@ impl FromXml for GroupShape {
 
 impl FromXml for CTRel {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13737,7 +13746,7 @@ impl FromXml for CTRel {
 
 impl FromXml for EGChildSlide {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13753,7 +13762,7 @@ impl FromXml for EGChildSlide {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"clrMapOvr" => {
                                 f_clr_map_ovr = Some(Box::new(
                                     ooxml_dml::types::CTColorMappingOverride::from_xml(
@@ -13783,7 +13792,7 @@ impl FromXml for EGChildSlide {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"clrMapOvr" => {
                                 f_clr_map_ovr = Some(Box::new(
                                     ooxml_dml::types::CTColorMappingOverride::from_xml(
@@ -13827,7 +13836,7 @@ impl FromXml for EGChildSlide {
 
 impl FromXml for PAGChildSlide {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13879,7 +13888,7 @@ impl FromXml for PAGChildSlide {
 
 impl FromXml for CTBackgroundProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13915,7 +13924,7 @@ impl FromXml for CTBackgroundProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst =
                                     Some(Box::new(CTExtensionList::from_xml(reader, &e, false)?));
@@ -13942,7 +13951,7 @@ impl FromXml for CTBackgroundProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst =
                                     Some(Box::new(CTExtensionList::from_xml(reader, &e, true)?));
@@ -13986,7 +13995,7 @@ impl FromXml for CTBackgroundProperties {
 
 impl FromXml for EGBackground {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14011,7 +14020,7 @@ impl FromXml for EGBackground {
 
 impl FromXml for CTBackground {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14049,7 +14058,7 @@ impl FromXml for CTBackground {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"bgPr" | b"bgRef" => {
                                 f_background =
                                     Some(Box::new(EGBackground::from_xml(reader, &e, false)?));
@@ -14076,7 +14085,7 @@ impl FromXml for CTBackground {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"bgPr" | b"bgRef" => {
                                 f_background =
                                     Some(Box::new(EGBackground::from_xml(reader, &e, true)?));
@@ -14121,7 +14130,7 @@ impl FromXml for CTBackground {
 
 impl FromXml for CommonSlideData {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14165,7 +14174,7 @@ impl FromXml for CommonSlideData {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "pml-styling")]
                             b"bg" => {
                                 f_bg = Some(Box::new(CTBackground::from_xml(reader, &e, false)?));
@@ -14228,7 +14237,7 @@ impl FromXml for CommonSlideData {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "pml-styling")]
                             b"bg" => {
                                 f_bg = Some(Box::new(CTBackground::from_xml(reader, &e, true)?));
@@ -14316,7 +14325,7 @@ impl FromXml for CommonSlideData {
 
 impl FromXml for Slide {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14372,7 +14381,7 @@ impl FromXml for Slide {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cSld" => {
                                 f_common_slide_data =
                                     Some(Box::new(CommonSlideData::from_xml(reader, &e, false)?));
@@ -14439,7 +14448,7 @@ impl FromXml for Slide {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cSld" => {
                                 f_common_slide_data =
                                     Some(Box::new(CommonSlideData::from_xml(reader, &e, true)?));
@@ -14535,7 +14544,7 @@ impl FromXml for Slide {
 
 impl FromXml for SlideLayout {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14613,7 +14622,7 @@ impl FromXml for SlideLayout {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cSld" => {
                                 f_common_slide_data =
                                     Some(Box::new(CommonSlideData::from_xml(reader, &e, false)?));
@@ -14688,7 +14697,7 @@ impl FromXml for SlideLayout {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cSld" => {
                                 f_common_slide_data =
                                     Some(Box::new(CommonSlideData::from_xml(reader, &e, true)?));
@@ -14801,7 +14810,7 @@ impl FromXml for SlideLayout {
 
 impl FromXml for CTSlideMasterTextStyles {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14820,7 +14829,7 @@ impl FromXml for CTSlideMasterTextStyles {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"titleStyle" => {
                                 f_title_style = Some(Box::new(
                                     ooxml_dml::types::CTTextListStyle::from_xml(reader, &e, false)?,
@@ -14874,7 +14883,7 @@ impl FromXml for CTSlideMasterTextStyles {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"titleStyle" => {
                                 f_title_style = Some(Box::new(
                                     ooxml_dml::types::CTTextListStyle::from_xml(reader, &e, true)?,
@@ -14945,7 +14954,7 @@ impl FromXml for CTSlideMasterTextStyles {
 
 impl FromXml for CTSlideLayoutIdListEntry {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14981,7 +14990,7 @@ impl FromXml for CTSlideLayoutIdListEntry {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst =
                                     Some(Box::new(CTExtensionList::from_xml(reader, &e, false)?));
@@ -15008,7 +15017,7 @@ impl FromXml for CTSlideLayoutIdListEntry {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst =
                                     Some(Box::new(CTExtensionList::from_xml(reader, &e, true)?));
@@ -15052,7 +15061,7 @@ impl FromXml for CTSlideLayoutIdListEntry {
 
 impl FromXml for CTSlideLayoutIdList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15068,7 +15077,7 @@ impl FromXml for CTSlideLayoutIdList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sldLayoutId" => {
                                 f_sld_layout_id
                                     .push(CTSlideLayoutIdListEntry::from_xml(reader, &e, false)?);
@@ -15095,7 +15104,7 @@ impl FromXml for CTSlideLayoutIdList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sldLayoutId" => {
                                 f_sld_layout_id
                                     .push(CTSlideLayoutIdListEntry::from_xml(reader, &e, true)?);
@@ -15136,7 +15145,7 @@ impl FromXml for CTSlideLayoutIdList {
 
 impl FromXml for SlideMaster {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15187,7 +15196,7 @@ impl FromXml for SlideMaster {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cSld" => {
                                 f_common_slide_data =
                                     Some(Box::new(CommonSlideData::from_xml(reader, &e, false)?));
@@ -15279,7 +15288,7 @@ impl FromXml for SlideMaster {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cSld" => {
                                 f_common_slide_data =
                                     Some(Box::new(CommonSlideData::from_xml(reader, &e, true)?));
@@ -15402,7 +15411,7 @@ impl FromXml for SlideMaster {
 
 impl FromXml for HandoutMaster {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15423,7 +15432,7 @@ impl FromXml for HandoutMaster {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cSld" => {
                                 f_common_slide_data =
                                     Some(Box::new(CommonSlideData::from_xml(reader, &e, false)?));
@@ -15477,7 +15486,7 @@ impl FromXml for HandoutMaster {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cSld" => {
                                 f_common_slide_data =
                                     Some(Box::new(CommonSlideData::from_xml(reader, &e, true)?));
@@ -15551,7 +15560,7 @@ impl FromXml for HandoutMaster {
 
 impl FromXml for NotesMaster {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15574,7 +15583,7 @@ impl FromXml for NotesMaster {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cSld" => {
                                 f_common_slide_data =
                                     Some(Box::new(CommonSlideData::from_xml(reader, &e, false)?));
@@ -15638,7 +15647,7 @@ impl FromXml for NotesMaster {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cSld" => {
                                 f_common_slide_data =
                                     Some(Box::new(CommonSlideData::from_xml(reader, &e, true)?));
@@ -15724,7 +15733,7 @@ impl FromXml for NotesMaster {
 
 impl FromXml for NotesSlide {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15772,7 +15781,7 @@ impl FromXml for NotesSlide {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cSld" => {
                                 f_common_slide_data =
                                     Some(Box::new(CommonSlideData::from_xml(reader, &e, false)?));
@@ -15821,7 +15830,7 @@ impl FromXml for NotesSlide {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cSld" => {
                                 f_common_slide_data =
                                     Some(Box::new(CommonSlideData::from_xml(reader, &e, true)?));
@@ -15895,7 +15904,7 @@ impl FromXml for NotesSlide {
 
 impl FromXml for CTSlideSyncProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15939,7 +15948,7 @@ impl FromXml for CTSlideSyncProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst =
                                     Some(Box::new(CTExtensionList::from_xml(reader, &e, false)?));
@@ -15966,7 +15975,7 @@ impl FromXml for CTSlideSyncProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_ext_lst =
                                     Some(Box::new(CTExtensionList::from_xml(reader, &e, true)?));
@@ -16015,7 +16024,7 @@ impl FromXml for CTSlideSyncProperties {
 
 impl FromXml for CTStringTag {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16067,7 +16076,7 @@ impl FromXml for CTStringTag {
 
 impl FromXml for CTTagList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16083,7 +16092,7 @@ impl FromXml for CTTagList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tag" => {
                                 f_tag.push(CTStringTag::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -16109,7 +16118,7 @@ impl FromXml for CTTagList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tag" => {
                                 f_tag.push(CTStringTag::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -16149,7 +16158,7 @@ impl FromXml for CTTagList {
 
 impl FromXml for CTNormalViewPortion {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16201,7 +16210,7 @@ impl FromXml for CTNormalViewPortion {
 
 impl FromXml for CTNormalViewProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16255,7 +16264,7 @@ impl FromXml for CTNormalViewProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"restoredLeft" => {
                                 f_restored_left = Some(Box::new(CTNormalViewPortion::from_xml(
                                     reader, &e, false,
@@ -16300,7 +16309,7 @@ impl FromXml for CTNormalViewProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"restoredLeft" => {
                                 f_restored_left = Some(Box::new(CTNormalViewPortion::from_xml(
                                     reader, &e, true,
@@ -16370,7 +16379,7 @@ impl FromXml for CTNormalViewProperties {
 
 impl FromXml for CTCommonViewProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16407,7 +16416,7 @@ impl FromXml for CTCommonViewProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scale" => {
                                 f_scale = Some(Box::new(ooxml_dml::types::CTScale2D::from_xml(
                                     reader, &e, false,
@@ -16444,7 +16453,7 @@ impl FromXml for CTCommonViewProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scale" => {
                                 f_scale = Some(Box::new(ooxml_dml::types::CTScale2D::from_xml(
                                     reader, &e, true,
@@ -16499,7 +16508,7 @@ impl FromXml for CTCommonViewProperties {
 
 impl FromXml for CTNotesTextViewProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16516,7 +16525,7 @@ impl FromXml for CTNotesTextViewProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cViewPr" => {
                                 f_c_view_pr = Some(Box::new(CTCommonViewProperties::from_xml(
                                     reader, &e, false,
@@ -16552,7 +16561,7 @@ impl FromXml for CTNotesTextViewProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cViewPr" => {
                                 f_c_view_pr = Some(Box::new(CTCommonViewProperties::from_xml(
                                     reader, &e, true,
@@ -16604,7 +16613,7 @@ impl FromXml for CTNotesTextViewProperties {
 
 impl FromXml for CTOutlineViewSlideEntry {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16651,7 +16660,7 @@ impl FromXml for CTOutlineViewSlideEntry {
 
 impl FromXml for CTOutlineViewSlideList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16667,7 +16676,7 @@ impl FromXml for CTOutlineViewSlideList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sld" => {
                                 f_sld.push(CTOutlineViewSlideEntry::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -16693,7 +16702,7 @@ impl FromXml for CTOutlineViewSlideList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sld" => {
                                 f_sld.push(CTOutlineViewSlideEntry::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -16733,7 +16742,7 @@ impl FromXml for CTOutlineViewSlideList {
 
 impl FromXml for CTOutlineViewProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16751,7 +16760,7 @@ impl FromXml for CTOutlineViewProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cViewPr" => {
                                 f_c_view_pr = Some(Box::new(CTCommonViewProperties::from_xml(
                                     reader, &e, false,
@@ -16796,7 +16805,7 @@ impl FromXml for CTOutlineViewProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cViewPr" => {
                                 f_c_view_pr = Some(Box::new(CTCommonViewProperties::from_xml(
                                     reader, &e, true,
@@ -16858,7 +16867,7 @@ impl FromXml for CTOutlineViewProperties {
 
 impl FromXml for CTSlideSorterViewProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16895,7 +16904,7 @@ impl FromXml for CTSlideSorterViewProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cViewPr" => {
                                 f_c_view_pr = Some(Box::new(CTCommonViewProperties::from_xml(
                                     reader, &e, false,
@@ -16931,7 +16940,7 @@ impl FromXml for CTSlideSorterViewProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cViewPr" => {
                                 f_c_view_pr = Some(Box::new(CTCommonViewProperties::from_xml(
                                     reader, &e, true,
@@ -16986,7 +16995,7 @@ impl FromXml for CTSlideSorterViewProperties {
 
 impl FromXml for CTGuide {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -17038,7 +17047,7 @@ impl FromXml for CTGuide {
 
 impl FromXml for CTGuideList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -17054,7 +17063,7 @@ impl FromXml for CTGuideList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"guide" => {
                                 f_guide.push(CTGuide::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -17080,7 +17089,7 @@ impl FromXml for CTGuideList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"guide" => {
                                 f_guide.push(CTGuide::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -17120,7 +17129,7 @@ impl FromXml for CTGuideList {
 
 impl FromXml for CTCommonSlideViewProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -17165,7 +17174,7 @@ impl FromXml for CTCommonSlideViewProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cViewPr" => {
                                 f_c_view_pr = Some(Box::new(CTCommonViewProperties::from_xml(
                                     reader, &e, false,
@@ -17201,7 +17210,7 @@ impl FromXml for CTCommonSlideViewProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cViewPr" => {
                                 f_c_view_pr = Some(Box::new(CTCommonViewProperties::from_xml(
                                     reader, &e, true,
@@ -17258,7 +17267,7 @@ impl FromXml for CTCommonSlideViewProperties {
 
 impl FromXml for CTSlideViewProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -17275,7 +17284,7 @@ impl FromXml for CTSlideViewProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cSldViewPr" => {
                                 f_c_sld_view_pr = Some(Box::new(
                                     CTCommonSlideViewProperties::from_xml(reader, &e, false)?,
@@ -17311,7 +17320,7 @@ impl FromXml for CTSlideViewProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cSldViewPr" => {
                                 f_c_sld_view_pr = Some(Box::new(
                                     CTCommonSlideViewProperties::from_xml(reader, &e, true)?,
@@ -17363,7 +17372,7 @@ impl FromXml for CTSlideViewProperties {
 
 impl FromXml for CTNotesViewProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -17380,7 +17389,7 @@ impl FromXml for CTNotesViewProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cSldViewPr" => {
                                 f_c_sld_view_pr = Some(Box::new(
                                     CTCommonSlideViewProperties::from_xml(reader, &e, false)?,
@@ -17416,7 +17425,7 @@ impl FromXml for CTNotesViewProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cSldViewPr" => {
                                 f_c_sld_view_pr = Some(Box::new(
                                     CTCommonSlideViewProperties::from_xml(reader, &e, true)?,
@@ -17468,7 +17477,7 @@ impl FromXml for CTNotesViewProperties {
 
 impl FromXml for CTViewProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -17520,7 +17529,7 @@ impl FromXml for CTViewProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"normalViewPr" => {
                                 f_normal_view_pr = Some(Box::new(
                                     CTNormalViewProperties::from_xml(reader, &e, false)?,
@@ -17613,7 +17622,7 @@ impl FromXml for CTViewProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"normalViewPr" => {
                                 f_normal_view_pr = Some(Box::new(
                                     CTNormalViewProperties::from_xml(reader, &e, true)?,
@@ -0,0 +1,9520 @@
+// Visitors for the generated types, derived from generated.rs.
+// Do not edit manually.
+
+#![allow(unused_variables)]
+#![allow(clippy::single_match)]
+#![allow(clippy::match_single_binding)]
+use super::generated::*;
+
+#[doc = r" Read-only traversal of the generated types."]
+#[doc = r""]
+#[doc = r" Every method defaults to visiting the node's children; override"]
+#[doc = r" the ones of interest and call the matching `walk_*` function to"]
+#[doc = r" keep descending."]
+pub trait Visit<'ast>: ooxml_dml::visit::Visit<'ast> {
+    fn visit_ct_application_non_visual_drawing_props(
+        &mut self,
+        node: &'ast CTApplicationNonVisualDrawingProps,
+    ) {
+        walk_ct_application_non_visual_drawing_props(self, node)
+    }
+    fn visit_ct_background(&mut self, node: &'ast CTBackground) {
+        walk_ct_background(self, node)
+    }
+    fn visit_ct_background_properties(&mut self, node: &'ast CTBackgroundProperties) {
+        walk_ct_background_properties(self, node)
+    }
+    fn visit_ct_build_list(&mut self, node: &'ast CTBuildList) {
+        walk_ct_build_list(self, node)
+    }
+    fn visit_ct_comment(&mut self, node: &'ast CTComment) {
+        walk_ct_comment(self, node)
+    }
+    fn visit_ct_comment_author(&mut self, node: &'ast CTCommentAuthor) {
+        walk_ct_comment_author(self, node)
+    }
+    fn visit_ct_comment_author_list(&mut self, node: &'ast CTCommentAuthorList) {
+        walk_ct_comment_author_list(self, node)
+    }
+    fn visit_ct_comment_list(&mut self, node: &'ast CTCommentList) {
+        walk_ct_comment_list(self, node)
+    }
+    fn visit_ct_common_slide_view_properties(&mut self, node: &'ast CTCommonSlideViewProperties) {
+        walk_ct_common_slide_view_properties(self, node)
+    }
+    fn visit_ct_common_view_properties(&mut self, node: &'ast CTCommonViewProperties) {
+        walk_ct_common_view_properties(self, node)
+    }
+    fn visit_ct_connector_non_visual(&mut self, node: &'ast CTConnectorNonVisual) {
+        walk_ct_connector_non_visual(self, node)
+    }
+    fn visit_ct_control(&mut self, node: &'ast CTControl) {
+        walk_ct_control(self, node)
+    }
+    fn visit_ct_control_list(&mut self, node: &'ast CTControlList) {
+        walk_ct_control_list(self, node)
+    }
+    fn visit_ct_corner_direction_transition(&mut self, node: &'ast CTCornerDirectionTransition) {
+        walk_ct_corner_direction_transition(self, node)
+    }
+    fn visit_ct_custom_show(&mut self, node: &'ast CTCustomShow) {
+        walk_ct_custom_show(self, node)
+    }
+    fn visit_ct_custom_show_id(&mut self, _node: &'ast CTCustomShowId) {}
+    fn visit_ct_custom_show_list(&mut self, node: &'ast CTCustomShowList) {
+        walk_ct_custom_show_list(self, node)
+    }
+    fn visit_ct_customer_data(&mut self, node: &'ast CTCustomerData) {
+        walk_ct_customer_data(self, node)
+    }
+    fn visit_ct_customer_data_list(&mut self, node: &'ast CTCustomerDataList) {
+        walk_ct_customer_data_list(self, node)
+    }
+    fn visit_ct_eight_direction_transition(&mut self, _node: &'ast CTEightDirectionTransition) {}
+    fn visit_ct_embedded_font_data_id(&mut self, node: &'ast CTEmbeddedFontDataId) {
+        walk_ct_embedded_font_data_id(self, node)
+    }
+    fn visit_ct_embedded_font_list(&mut self, node: &'ast CTEmbeddedFontList) {
+        walk_ct_embedded_font_list(self, node)
+    }
+    fn visit_ct_embedded_font_list_entry(&mut self, node: &'ast CTEmbeddedFontListEntry) {
+        walk_ct_embedded_font_list_entry(self, node)
+    }
+    fn visit_ct_empty(&mut self, _node: &'ast CTEmpty) {}
+    fn visit_ct_extension(&mut self, _node: &'ast CTExtension) {}
+    fn visit_ct_extension_list(&mut self, node: &'ast CTExtensionList) {
+        walk_ct_extension_list(self, node)
+    }
+    fn visit_ct_extension_list_modify(&mut self, node: &'ast CTExtensionListModify) {
+        walk_ct_extension_list_modify(self, node)
+    }
+    fn visit_ct_graphical_object_frame_non_visual(
+        &mut self,
+        node: &'ast CTGraphicalObjectFrameNonVisual,
+    ) {
+        walk_ct_graphical_object_frame_non_visual(self, node)
+    }
+    fn visit_ct_group_shape_non_visual(&mut self, node: &'ast CTGroupShapeNonVisual) {
+        walk_ct_group_shape_non_visual(self, node)
+    }
+    fn visit_ct_guide(&mut self, node: &'ast CTGuide) {
+        walk_ct_guide(self, node)
+    }
+    fn visit_ct_guide_list(&mut self, node: &'ast CTGuideList) {
+        walk_ct_guide_list(self, node)
+    }
+    fn visit_ct_handout_master_id_list(&mut self, node: &'ast CTHandoutMasterIdList) {
+        walk_ct_handout_master_id_list(self, node)
+    }
+    fn visit_ct_handout_master_id_list_entry(&mut self, node: &'ast CTHandoutMasterIdListEntry) {
+        walk_ct_handout_master_id_list_entry(self, node)
+    }
+    fn visit_ct_header_footer(&mut self, node: &'ast CTHeaderFooter) {
+        walk_ct_header_footer(self, node)
+    }
+    fn visit_ct_html_publish_properties(&mut self, node: &'ast CTHtmlPublishProperties) {
+        walk_ct_html_publish_properties(self, node)
+    }
+    fn visit_ct_in_out_transition(&mut self, node: &'ast CTInOutTransition) {
+        walk_ct_in_out_transition(self, node)
+    }
+    fn visit_ct_index_range(&mut self, _node: &'ast CTIndexRange) {}
+    fn visit_ct_kinsoku(&mut self, _node: &'ast CTKinsoku) {}
+    fn visit_ct_modify_verifier(&mut self, node: &'ast CTModifyVerifier) {
+        walk_ct_modify_verifier(self, node)
+    }
+    fn visit_ct_normal_view_portion(&mut self, _node: &'ast CTNormalViewPortion) {}
+    fn visit_ct_normal_view_properties(&mut self, node: &'ast CTNormalViewProperties) {
+        walk_ct_normal_view_properties(self, node)
+    }
+    fn visit_ct_notes_master_id_list(&mut self, node: &'ast CTNotesMasterIdList) {
+        walk_ct_notes_master_id_list(self, node)
+    }
+    fn visit_ct_notes_master_id_list_entry(&mut self, node: &'ast CTNotesMasterIdListEntry) {
+        walk_ct_notes_master_id_list_entry(self, node)
+    }
+    fn visit_ct_notes_text_view_properties(&mut self, node: &'ast CTNotesTextViewProperties) {
+        walk_ct_notes_text_view_properties(self, node)
+    }
+    fn visit_ct_notes_view_properties(&mut self, node: &'ast CTNotesViewProperties) {
+        walk_ct_notes_view_properties(self, node)
+    }
+    fn visit_ct_ole_object(&mut self, node: &'ast CTOleObject) {
+        walk_ct_ole_object(self, node)
+    }
+    fn visit_ct_ole_object_embed(&mut self, node: &'ast CTOleObjectEmbed) {
+        walk_ct_ole_object_embed(self, node)
+    }
+    fn visit_ct_ole_object_link(&mut self, node: &'ast CTOleObjectLink) {
+        walk_ct_ole_object_link(self, node)
+    }
+    fn visit_ct_optional_black_transition(&mut self, _node: &'ast CTOptionalBlackTransition) {}
+    fn visit_ct_orientation_transition(&mut self, node: &'ast CTOrientationTransition) {
+        walk_ct_orientation_transition(self, node)
+    }
+    fn visit_ct_outline_view_properties(&mut self, node: &'ast CTOutlineViewProperties) {
+        walk_ct_outline_view_properties(self, node)
+    }
+    fn visit_ct_outline_view_slide_entry(&mut self, _node: &'ast CTOutlineViewSlideEntry) {}
+    fn visit_ct_outline_view_slide_list(&mut self, node: &'ast CTOutlineViewSlideList) {
+        walk_ct_outline_view_slide_list(self, node)
+    }
+    fn visit_ct_photo_album(&mut self, node: &'ast CTPhotoAlbum) {
+        walk_ct_photo_album(self, node)
+    }
+    fn visit_ct_picture_non_visual(&mut self, node: &'ast CTPictureNonVisual) {
+        walk_ct_picture_non_visual(self, node)
+    }
+    fn visit_ct_placeholder(&mut self, node: &'ast CTPlaceholder) {
+        walk_ct_placeholder(self, node)
+    }
+    fn visit_ct_presentation_properties(&mut self, node: &'ast CTPresentationProperties) {
+        walk_ct_presentation_properties(self, node)
+    }
+    fn visit_ct_print_properties(&mut self, node: &'ast CTPrintProperties) {
+        walk_ct_print_properties(self, node)
+    }
+    fn visit_ct_rel(&mut self, node: &'ast CTRel) {
+        walk_ct_rel(self, node)
+    }
+    fn visit_ct_show_info_browse(&mut self, _node: &'ast CTShowInfoBrowse) {}
+    fn visit_ct_show_info_kiosk(&mut self, _node: &'ast CTShowInfoKiosk) {}
+    fn visit_ct_show_properties(&mut self, node: &'ast CTShowProperties) {
+        walk_ct_show_properties(self, node)
+    }
+    fn visit_ct_side_direction_transition(&mut self, node: &'ast CTSideDirectionTransition) {
+        walk_ct_side_direction_transition(self, node)
+    }
+    fn visit_ct_slide_id_list_entry(&mut self, node: &'ast CTSlideIdListEntry) {
+        walk_ct_slide_id_list_entry(self, node)
+    }
+    fn visit_ct_slide_layout_id_list(&mut self, node: &'ast CTSlideLayoutIdList) {
+        walk_ct_slide_layout_id_list(self, node)
+    }
+    fn visit_ct_slide_layout_id_list_entry(&mut self, node: &'ast CTSlideLayoutIdListEntry) {
+        walk_ct_slide_layout_id_list_entry(self, node)
+    }
+    fn visit_ct_slide_master_id_list(&mut self, node: &'ast CTSlideMasterIdList) {
+        walk_ct_slide_master_id_list(self, node)
+    }
+    fn visit_ct_slide_master_id_list_entry(&mut self, node: &'ast CTSlideMasterIdListEntry) {
+        walk_ct_slide_master_id_list_entry(self, node)
+    }
+    fn visit_ct_slide_master_text_styles(&mut self, node: &'ast CTSlideMasterTextStyles) {
+        walk_ct_slide_master_text_styles(self, node)
+    }
+    fn visit_ct_slide_relationship_list(&mut self, node: &'ast CTSlideRelationshipList) {
+        walk_ct_slide_relationship_list(self, node)
+    }
+    fn visit_ct_slide_relationship_list_entry(&mut self, node: &'ast CTSlideRelationshipListEntry) {
+        walk_ct_slide_relationship_list_entry(self, node)
+    }
+    fn visit_ct_slide_size(&mut self, node: &'ast CTSlideSize) {
+        walk_ct_slide_size(self, node)
+    }
+    fn visit_ct_slide_sorter_view_properties(&mut self, node: &'ast CTSlideSorterViewProperties) {
+        walk_ct_slide_sorter_view_properties(self, node)
+    }
+    fn visit_ct_slide_sync_properties(&mut self, node: &'ast CTSlideSyncProperties) {
+        walk_ct_slide_sync_properties(self, node)
+    }
+    fn visit_ct_slide_view_properties(&mut self, node: &'ast CTSlideViewProperties) {
+        walk_ct_slide_view_properties(self, node)
+    }
+    fn visit_ct_smart_tags(&mut self, node: &'ast CTSmartTags) {
+        walk_ct_smart_tags(self, node)
+    }
+    fn visit_ct_split_transition(&mut self, node: &'ast CTSplitTransition) {
+        walk_ct_split_transition(self, node)
+    }
+    fn visit_ct_string_tag(&mut self, _node: &'ast CTStringTag) {}
+    fn visit_cttl_anim_variant(&mut self, node: &'ast CTTLAnimVariant) {
+        walk_cttl_anim_variant(self, node)
+    }
+    fn visit_cttl_anim_variant_boolean_val(&mut self, _node: &'ast CTTLAnimVariantBooleanVal) {}
+    fn visit_cttl_anim_variant_float_val(&mut self, _node: &'ast CTTLAnimVariantFloatVal) {}
+    fn visit_cttl_anim_variant_integer_val(&mut self, _node: &'ast CTTLAnimVariantIntegerVal) {}
+    fn visit_cttl_anim_variant_string_val(&mut self, _node: &'ast CTTLAnimVariantStringVal) {}
+    fn visit_cttl_animate_behavior(&mut self, node: &'ast CTTLAnimateBehavior) {
+        walk_cttl_animate_behavior(self, node)
+    }
+    fn visit_cttl_animate_color_behavior(&mut self, node: &'ast CTTLAnimateColorBehavior) {
+        walk_cttl_animate_color_behavior(self, node)
+    }
+    fn visit_cttl_animate_effect_behavior(&mut self, node: &'ast CTTLAnimateEffectBehavior) {
+        walk_cttl_animate_effect_behavior(self, node)
+    }
+    fn visit_cttl_animate_motion_behavior(&mut self, node: &'ast CTTLAnimateMotionBehavior) {
+        walk_cttl_animate_motion_behavior(self, node)
+    }
+    fn visit_cttl_animate_rotation_behavior(&mut self, node: &'ast CTTLAnimateRotationBehavior) {
+        walk_cttl_animate_rotation_behavior(self, node)
+    }
+    fn visit_cttl_animate_scale_behavior(&mut self, node: &'ast CTTLAnimateScaleBehavior) {
+        walk_cttl_animate_scale_behavior(self, node)
+    }
+    fn visit_cttl_behavior_attribute_name_list(
+        &mut self,
+        node: &'ast CTTLBehaviorAttributeNameList,
+    ) {
+        walk_cttl_behavior_attribute_name_list(self, node)
+    }
+    fn visit_cttl_build_diagram(&mut self, node: &'ast CTTLBuildDiagram) {
+        walk_cttl_build_diagram(self, node)
+    }
+    fn visit_cttl_build_paragraph(&mut self, node: &'ast CTTLBuildParagraph) {
+        walk_cttl_build_paragraph(self, node)
+    }
+    fn visit_cttl_by_animate_color_transform(&mut self, node: &'ast CTTLByAnimateColorTransform) {
+        walk_cttl_by_animate_color_transform(self, node)
+    }
+    fn visit_cttl_by_hsl_color_transform(&mut self, _node: &'ast CTTLByHslColorTransform) {}
+    fn visit_cttl_by_rgb_color_transform(&mut self, _node: &'ast CTTLByRgbColorTransform) {}
+    fn visit_cttl_command_behavior(&mut self, node: &'ast CTTLCommandBehavior) {
+        walk_cttl_command_behavior(self, node)
+    }
+    fn visit_cttl_common_behavior_data(&mut self, node: &'ast CTTLCommonBehaviorData) {
+        walk_cttl_common_behavior_data(self, node)
+    }
+    fn visit_cttl_common_media_node_data(&mut self, node: &'ast CTTLCommonMediaNodeData) {
+        walk_cttl_common_media_node_data(self, node)
+    }
+    fn visit_cttl_common_time_node_data(&mut self, node: &'ast CTTLCommonTimeNodeData) {
+        walk_cttl_common_time_node_data(self, node)
+    }
+    fn visit_cttl_graphical_object_build(&mut self, node: &'ast CTTLGraphicalObjectBuild) {
+        walk_cttl_graphical_object_build(self, node)
+    }
+    fn visit_cttl_iterate_data(&mut self, node: &'ast CTTLIterateData) {
+        walk_cttl_iterate_data(self, node)
+    }
+    fn visit_cttl_iterate_interval_percentage(
+        &mut self,
+        _node: &'ast CTTLIterateIntervalPercentage,
+    ) {
+    }
+    fn visit_cttl_iterate_interval_time(&mut self, _node: &'ast CTTLIterateIntervalTime) {}
+    fn visit_cttl_media_node_audio(&mut self, node: &'ast CTTLMediaNodeAudio) {
+        walk_cttl_media_node_audio(self, node)
+    }
+    fn visit_cttl_media_node_video(&mut self, node: &'ast CTTLMediaNodeVideo) {
+        walk_cttl_media_node_video(self, node)
+    }
+    fn visit_cttl_ole_build_chart(&mut self, node: &'ast CTTLOleBuildChart) {
+        walk_cttl_ole_build_chart(self, node)
+    }
+    fn visit_cttl_ole_chart_target_element(&mut self, node: &'ast CTTLOleChartTargetElement) {
+        walk_cttl_ole_chart_target_element(self, node)
+    }
+    fn visit_cttl_point(&mut self, _node: &'ast CTTLPoint) {}
+    fn visit_cttl_set_behavior(&mut self, node: &'ast CTTLSetBehavior) {
+        walk_cttl_set_behavior(self, node)
+    }
+    fn visit_cttl_shape_target_element(&mut self, node: &'ast CTTLShapeTargetElement) {
+        walk_cttl_shape_target_element(self, node)
+    }
+    fn visit_cttl_sub_shape_id(&mut self, _node: &'ast CTTLSubShapeId) {}
+    fn visit_cttl_template(&mut self, node: &'ast CTTLTemplate) {
+        walk_cttl_template(self, node)
+    }
+    fn visit_cttl_template_list(&mut self, node: &'ast CTTLTemplateList) {
+        walk_cttl_template_list(self, node)
+    }
+    fn visit_cttl_text_target_element(&mut self, node: &'ast CTTLTextTargetElement) {
+        walk_cttl_text_target_element(self, node)
+    }
+    fn visit_cttl_time_animate_value(&mut self, node: &'ast CTTLTimeAnimateValue) {
+        walk_cttl_time_animate_value(self, node)
+    }
+    fn visit_cttl_time_animate_value_list(&mut self, node: &'ast CTTLTimeAnimateValueList) {
+        walk_cttl_time_animate_value_list(self, node)
+    }
+    fn visit_cttl_time_condition(&mut self, node: &'ast CTTLTimeCondition) {
+        walk_cttl_time_condition(self, node)
+    }
+    fn visit_cttl_time_condition_list(&mut self, node: &'ast CTTLTimeConditionList) {
+        walk_cttl_time_condition_list(self, node)
+    }
+    fn visit_cttl_time_node_sequence(&mut self, node: &'ast CTTLTimeNodeSequence) {
+        walk_cttl_time_node_sequence(self, node)
+    }
+    fn visit_cttl_time_target_element(&mut self, node: &'ast CTTLTimeTargetElement) {
+        walk_cttl_time_target_element(self, node)
+    }
+    fn visit_cttl_trigger_runtime_node(&mut self, node: &'ast CTTLTriggerRuntimeNode) {
+        walk_cttl_trigger_runtime_node(self, node)
+    }
+    fn visit_cttl_trigger_time_node_id(&mut self, _node: &'ast CTTLTriggerTimeNodeID) {}
+    fn visit_ct_tag_list(&mut self, node: &'ast CTTagList) {
+        walk_ct_tag_list(self, node)
+    }
+    fn visit_ct_tags_data(&mut self, node: &'ast CTTagsData) {
+        walk_ct_tags_data(self, node)
+    }
+    fn visit_ct_time_node_list(&mut self, node: &'ast CTTimeNodeList) {
+        walk_ct_time_node_list(self, node)
+    }
+    fn visit_ct_transition_sound_action(&mut self, node: &'ast CTTransitionSoundAction) {
+        walk_ct_transition_sound_action(self, node)
+    }
+    fn visit_ct_transition_start_sound_action(&mut self, node: &'ast CTTransitionStartSoundAction) {
+        walk_ct_transition_start_sound_action(self, node)
+    }
+    fn visit_ct_view_properties(&mut self, node: &'ast CTViewProperties) {
+        walk_ct_view_properties(self, node)
+    }
+    fn visit_ct_web_properties(&mut self, node: &'ast CTWebProperties) {
+        walk_ct_web_properties(self, node)
+    }
+    fn visit_ct_wheel_transition(&mut self, _node: &'ast CTWheelTransition) {}
+    fn visit_calendar_type(&mut self, _node: &'ast CalendarType) {}
+    fn visit_common_slide_data(&mut self, node: &'ast CommonSlideData) {
+        walk_common_slide_data(self, node)
+    }
+    fn visit_connector(&mut self, node: &'ast Connector) {
+        walk_connector(self, node)
+    }
+    fn visit_eg_background(&mut self, node: &'ast EGBackground) {
+        walk_eg_background(self, node)
+    }
+    fn visit_eg_child_slide(&mut self, node: &'ast EGChildSlide) {
+        walk_eg_child_slide(self, node)
+    }
+    fn visit_eg_extension_list(&mut self, node: &'ast EGExtensionList) {
+        walk_eg_extension_list(self, node)
+    }
+    fn visit_eg_show_type(&mut self, node: &'ast EGShowType) {
+        walk_eg_show_type(self, node)
+    }
+    fn visit_eg_slide_list_choice(&mut self, node: &'ast EGSlideListChoice) {
+        walk_eg_slide_list_choice(self, node)
+    }
+    fn visit_graphical_object_frame(&mut self, node: &'ast GraphicalObjectFrame) {
+        walk_graphical_object_frame(self, node)
+    }
+    fn visit_group_shape(&mut self, node: &'ast GroupShape) {
+        walk_group_shape(self, node)
+    }
+    fn visit_handout_master(&mut self, node: &'ast HandoutMaster) {
+        walk_handout_master(self, node)
+    }
+    fn visit_notes_master(&mut self, node: &'ast NotesMaster) {
+        walk_notes_master(self, node)
+    }
+    fn visit_notes_slide(&mut self, node: &'ast NotesSlide) {
+        walk_notes_slide(self, node)
+    }
+    fn visit_pag_child_slide(&mut self, _node: &'ast PAGChildSlide) {}
+    fn visit_pag_ole(&mut self, _node: &'ast PAGOle) {}
+    fn visit_pagtl_build(&mut self, _node: &'ast PAGTLBuild) {}
+    fn visit_picture(&mut self, node: &'ast Picture) {
+        walk_picture(self, node)
+    }
+    fn visit_presentation(&mut self, node: &'ast Presentation) {
+        walk_presentation(self, node)
+    }
+    fn visit_st_alg_class(&mut self, _node: &'ast STAlgClass) {}
+    fn visit_st_alg_type(&mut self, _node: &'ast STAlgType) {}
+    fn visit_st_conformance_class(&mut self, _node: &'ast STConformanceClass) {}
+    fn visit_st_crypt_prov(&mut self, _node: &'ast STCryptProv) {}
+    fn visit_st_direction(&mut self, _node: &'ast STDirection) {}
+    fn visit_st_iterate_type(&mut self, _node: &'ast STIterateType) {}
+    fn visit_st_ole_object_follow_color_scheme(
+        &mut self,
+        _node: &'ast STOleObjectFollowColorScheme,
+    ) {
+    }
+    fn visit_st_on_off1(&mut self, _node: &'ast STOnOff1) {}
+    fn visit_st_photo_album_frame_shape(&mut self, _node: &'ast STPhotoAlbumFrameShape) {}
+    fn visit_st_photo_album_layout(&mut self, _node: &'ast STPhotoAlbumLayout) {}
+    fn visit_st_placeholder_size(&mut self, _node: &'ast STPlaceholderSize) {}
+    fn visit_st_placeholder_type(&mut self, _node: &'ast STPlaceholderType) {}
+    fn visit_st_print_color_mode(&mut self, _node: &'ast STPrintColorMode) {}
+    fn visit_st_print_what(&mut self, _node: &'ast STPrintWhat) {}
+    fn visit_st_slide_layout_type(&mut self, _node: &'ast STSlideLayoutType) {}
+    fn visit_st_slide_size_type(&mut self, _node: &'ast STSlideSizeType) {}
+    fn visit_st_splitter_bar_state(&mut self, _node: &'ast STSplitterBarState) {}
+    fn visit_sttl_animate_behavior_calc_mode(&mut self, _node: &'ast STTLAnimateBehaviorCalcMode) {}
+    fn visit_sttl_animate_behavior_value_type(
+        &mut self,
+        _node: &'ast STTLAnimateBehaviorValueType,
+    ) {
+    }
+    fn visit_sttl_animate_color_direction(&mut self, _node: &'ast STTLAnimateColorDirection) {}
+    fn visit_sttl_animate_color_space(&mut self, _node: &'ast STTLAnimateColorSpace) {}
+    fn visit_sttl_animate_effect_transition(&mut self, _node: &'ast STTLAnimateEffectTransition) {}
+    fn visit_sttl_animate_motion_behavior_origin(
+        &mut self,
+        _node: &'ast STTLAnimateMotionBehaviorOrigin,
+    ) {
+    }
+    fn visit_sttl_animate_motion_path_edit_mode(
+        &mut self,
+        _node: &'ast STTLAnimateMotionPathEditMode,
+    ) {
+    }
+    fn visit_sttl_behavior_accumulate_type(&mut self, _node: &'ast STTLBehaviorAccumulateType) {}
+    fn visit_sttl_behavior_additive_type(&mut self, _node: &'ast STTLBehaviorAdditiveType) {}
+    fn visit_sttl_behavior_override_type(&mut self, _node: &'ast STTLBehaviorOverrideType) {}
+    fn visit_sttl_behavior_transform_type(&mut self, _node: &'ast STTLBehaviorTransformType) {}
+    fn visit_sttl_chart_subelement_type(&mut self, _node: &'ast STTLChartSubelementType) {}
+    fn visit_sttl_command_type(&mut self, _node: &'ast STTLCommandType) {}
+    fn visit_sttl_diagram_build_type(&mut self, _node: &'ast STTLDiagramBuildType) {}
+    fn visit_sttl_next_action_type(&mut self, _node: &'ast STTLNextActionType) {}
+    fn visit_sttl_ole_chart_build_type(&mut self, _node: &'ast STTLOleChartBuildType) {}
+    fn visit_sttl_para_build_type(&mut self, _node: &'ast STTLParaBuildType) {}
+    fn visit_sttl_previous_action_type(&mut self, _node: &'ast STTLPreviousActionType) {}
+    fn visit_sttl_time_node_fill_type(&mut self, _node: &'ast STTLTimeNodeFillType) {}
+    fn visit_sttl_time_node_master_relation(&mut self, _node: &'ast STTLTimeNodeMasterRelation) {}
+    fn visit_sttl_time_node_preset_class_type(&mut self, _node: &'ast STTLTimeNodePresetClassType) {
+    }
+    fn visit_sttl_time_node_restart_type(&mut self, _node: &'ast STTLTimeNodeRestartType) {}
+    fn visit_sttl_time_node_sync_type(&mut self, _node: &'ast STTLTimeNodeSyncType) {}
+    fn visit_sttl_time_node_type(&mut self, _node: &'ast STTLTimeNodeType) {}
+    fn visit_sttl_trigger_event(&mut self, _node: &'ast STTLTriggerEvent) {}
+    fn visit_sttl_trigger_runtime_node(&mut self, _node: &'ast STTLTriggerRuntimeNode) {}
+    fn visit_st_transition_corner_direction_type(
+        &mut self,
+        _node: &'ast STTransitionCornerDirectionType,
+    ) {
+    }
+    fn visit_st_transition_in_out_direction_type(
+        &mut self,
+        _node: &'ast STTransitionInOutDirectionType,
+    ) {
+    }
+    fn visit_st_transition_side_direction_type(
+        &mut self,
+        _node: &'ast STTransitionSideDirectionType,
+    ) {
+    }
+    fn visit_st_transition_speed(&mut self, _node: &'ast STTransitionSpeed) {}
+    fn visit_st_true_false_blank(&mut self, _node: &'ast STTrueFalseBlank) {}
+    fn visit_st_vertical_align_run(&mut self, _node: &'ast STVerticalAlignRun) {}
+    fn visit_st_view_type(&mut self, _node: &'ast STViewType) {}
+    fn visit_st_web_color_type(&mut self, _node: &'ast STWebColorType) {}
+    fn visit_st_web_screen_size(&mut self, _node: &'ast STWebScreenSize) {}
+    fn visit_stx_align(&mut self, _node: &'ast STXAlign) {}
+    fn visit_sty_align(&mut self, _node: &'ast STYAlign) {}
+    fn visit_shape(&mut self, node: &'ast Shape) {
+        walk_shape(self, node)
+    }
+    fn visit_shape_non_visual(&mut self, node: &'ast ShapeNonVisual) {
+        walk_shape_non_visual(self, node)
+    }
+    fn visit_slide(&mut self, node: &'ast Slide) {
+        walk_slide(self, node)
+    }
+    fn visit_slide_id_list(&mut self, node: &'ast SlideIdList) {
+        walk_slide_id_list(self, node)
+    }
+    fn visit_slide_layout(&mut self, node: &'ast SlideLayout) {
+        walk_slide_layout(self, node)
+    }
+    fn visit_slide_master(&mut self, node: &'ast SlideMaster) {
+        walk_slide_master(self, node)
+    }
+    fn visit_slide_timing(&mut self, node: &'ast SlideTiming) {
+        walk_slide_timing(self, node)
+    }
+    fn visit_slide_transition(&mut self, node: &'ast SlideTransition) {
+        walk_slide_transition(self, node)
+    }
+    fn visit_true_false(&mut self, _node: &'ast TrueFalse) {}
+}
+#[doc = r" Mutable traversal of the generated types."]
+#[doc = r""]
+#[doc = r" Every method defaults to visiting the node's children; override"]
+#[doc = r" the ones of interest and call the matching `walk_*_mut` function"]
+#[doc = r" to keep descending."]
+pub trait VisitMut: ooxml_dml::visit::VisitMut {
+    fn visit_ct_application_non_visual_drawing_props_mut(
+        &mut self,
+        node: &mut CTApplicationNonVisualDrawingProps,
+    ) {
+        walk_ct_application_non_visual_drawing_props_mut(self, node)
+    }
+    fn visit_ct_background_mut(&mut self, node: &mut CTBackground) {
+        walk_ct_background_mut(self, node)
+    }
+    fn visit_ct_background_properties_mut(&mut self, node: &mut CTBackgroundProperties) {
+        walk_ct_background_properties_mut(self, node)
+    }
+    fn visit_ct_build_list_mut(&mut self, node: &mut CTBuildList) {
+        walk_ct_build_list_mut(self, node)
+    }
+    fn visit_ct_comment_mut(&mut self, node: &mut CTComment) {
+        walk_ct_comment_mut(self, node)
+    }
+    fn visit_ct_comment_author_mut(&mut self, node: &mut CTCommentAuthor) {
+        walk_ct_comment_author_mut(self, node)
+    }
+    fn visit_ct_comment_author_list_mut(&mut self, node: &mut CTCommentAuthorList) {
+        walk_ct_comment_author_list_mut(self, node)
+    }
+    fn visit_ct_comment_list_mut(&mut self, node: &mut CTCommentList) {
+        walk_ct_comment_list_mut(self, node)
+    }
+    fn visit_ct_common_slide_view_properties_mut(
+        &mut self,
+        node: &mut CTCommonSlideViewProperties,
+    ) {
+        walk_ct_common_slide_view_properties_mut(self, node)
+    }
+    fn visit_ct_common_view_properties_mut(&mut self, node: &mut CTCommonViewProperties) {
+        walk_ct_common_view_properties_mut(self, node)
+    }
+    fn visit_ct_connector_non_visual_mut(&mut self, node: &mut CTConnectorNonVisual) {
+        walk_ct_connector_non_visual_mut(self, node)
+    }
+    fn visit_ct_control_mut(&mut self, node: &mut CTControl) {
+        walk_ct_control_mut(self, node)
+    }
+    fn visit_ct_control_list_mut(&mut self, node: &mut CTControlList) {
+        walk_ct_control_list_mut(self, node)
+    }
+    fn visit_ct_corner_direction_transition_mut(&mut self, node: &mut CTCornerDirectionTransition) {
+        walk_ct_corner_direction_transition_mut(self, node)
+    }
+    fn visit_ct_custom_show_mut(&mut self, node: &mut CTCustomShow) {
+        walk_ct_custom_show_mut(self, node)
+    }
+    fn visit_ct_custom_show_id_mut(&mut self, _node: &mut CTCustomShowId) {}
+    fn visit_ct_custom_show_list_mut(&mut self, node: &mut CTCustomShowList) {
+        walk_ct_custom_show_list_mut(self, node)
+    }
+    fn visit_ct_customer_data_mut(&mut self, node: &mut CTCustomerData) {
+        walk_ct_customer_data_mut(self, node)
+    }
+    fn visit_ct_customer_data_list_mut(&mut self, node: &mut CTCustomerDataList) {
+        walk_ct_customer_data_list_mut(self, node)
+    }
+    fn visit_ct_eight_direction_transition_mut(&mut self, _node: &mut CTEightDirectionTransition) {}
+    fn visit_ct_embedded_font_data_id_mut(&mut self, node: &mut CTEmbeddedFontDataId) {
+        walk_ct_embedded_font_data_id_mut(self, node)
+    }
+    fn visit_ct_embedded_font_list_mut(&mut self, node: &mut CTEmbeddedFontList) {
+        walk_ct_embedded_font_list_mut(self, node)
+    }
+    fn visit_ct_embedded_font_list_entry_mut(&mut self, node: &mut CTEmbeddedFontListEntry) {
+        walk_ct_embedded_font_list_entry_mut(self, node)
+    }
+    fn visit_ct_empty_mut(&mut self, _node: &mut CTEmpty) {}
+    fn visit_ct_extension_mut(&mut self, _node: &mut CTExtension) {}
+    fn visit_ct_extension_list_mut(&mut self, node: &mut CTExtensionList) {
+        walk_ct_extension_list_mut(self, node)
+    }
+    fn visit_ct_extension_list_modify_mut(&mut self, node: &mut CTExtensionListModify) {
+        walk_ct_extension_list_modify_mut(self, node)
+    }
+    fn visit_ct_graphical_object_frame_non_visual_mut(
+        &mut self,
+        node: &mut CTGraphicalObjectFrameNonVisual,
+    ) {
+        walk_ct_graphical_object_frame_non_visual_mut(self, node)
+    }
+    fn visit_ct_group_shape_non_visual_mut(&mut self, node: &mut CTGroupShapeNonVisual) {
+        walk_ct_group_shape_non_visual_mut(self, node)
+    }
+    fn visit_ct_guide_mut(&mut self, node: &mut CTGuide) {
+        walk_ct_guide_mut(self, node)
+    }
+    fn visit_ct_guide_list_mut(&mut self, node: &mut CTGuideList) {
+        walk_ct_guide_list_mut(self, node)
+    }
+    fn visit_ct_handout_master_id_list_mut(&mut self, node: &mut CTHandoutMasterIdList) {
+        walk_ct_handout_master_id_list_mut(self, node)
+    }
+    fn visit_ct_handout_master_id_list_entry_mut(&mut self, node: &mut CTHandoutMasterIdListEntry) {
+        walk_ct_handout_master_id_list_entry_mut(self, node)
+    }
+    fn visit_ct_header_footer_mut(&mut self, node: &mut CTHeaderFooter) {
+        walk_ct_header_footer_mut(self, node)
+    }
+    fn visit_ct_html_publish_properties_mut(&mut self, node: &mut CTHtmlPublishProperties) {
+        walk_ct_html_publish_properties_mut(self, node)
+    }
+    fn visit_ct_in_out_transition_mut(&mut self, node: &mut CTInOutTransition) {
+        walk_ct_in_out_transition_mut(self, node)
+    }
+    fn visit_ct_index_range_mut(&mut self, _node: &mut CTIndexRange) {}
+    fn visit_ct_kinsoku_mut(&mut self, _node: &mut CTKinsoku) {}
+    fn visit_ct_modify_verifier_mut(&mut self, node: &mut CTModifyVerifier) {
+        walk_ct_modify_verifier_mut(self, node)
+    }
+    fn visit_ct_normal_view_portion_mut(&mut self, _node: &mut CTNormalViewPortion) {}
+    fn visit_ct_normal_view_properties_mut(&mut self, node: &mut CTNormalViewProperties) {
+        walk_ct_normal_view_properties_mut(self, node)
+    }
+    fn visit_ct_notes_master_id_list_mut(&mut self, node: &mut CTNotesMasterIdList) {
+        walk_ct_notes_master_id_list_mut(self, node)
+    }
+    fn visit_ct_notes_master_id_list_entry_mut(&mut self, node: &mut CTNotesMasterIdListEntry) {
+        walk_ct_notes_master_id_list_entry_mut(self, node)
+    }
+    fn visit_ct_notes_text_view_properties_mut(&mut self, node: &mut CTNotesTextViewProperties) {
+        walk_ct_notes_text_view_properties_mut(self, node)
+    }
+    fn visit_ct_notes_view_properties_mut(&mut self, node: &mut CTNotesViewProperties) {
+        walk_ct_notes_view_properties_mut(self, node)
+    }
+    fn visit_ct_ole_object_mut(&mut self, node: &mut CTOleObject) {
+        walk_ct_ole_object_mut(self, node)
+    }
+    fn visit_ct_ole_object_embed_mut(&mut self, node: &mut CTOleObjectEmbed) {
+        walk_ct_ole_object_embed_mut(self, node)
+    }
+    fn visit_ct_ole_object_link_mut(&mut self, node: &mut CTOleObjectLink) {
+        walk_ct_ole_object_link_mut(self, node)
+    }
+    fn visit_ct_optional_black_transition_mut(&mut self, _node: &mut CTOptionalBlackTransition) {}
+    fn visit_ct_orientation_transition_mut(&mut self, node: &mut CTOrientationTransition) {
+        walk_ct_orientation_transition_mut(self, node)
+    }
+    fn visit_ct_outline_view_properties_mut(&mut self, node: &mut CTOutlineViewProperties) {
+        walk_ct_outline_view_properties_mut(self, node)
+    }
+    fn visit_ct_outline_view_slide_entry_mut(&mut self, _node: &mut CTOutlineViewSlideEntry) {}
+    fn visit_ct_outline_view_slide_list_mut(&mut self, node: &mut CTOutlineViewSlideList) {
+        walk_ct_outline_view_slide_list_mut(self, node)
+    }
+    fn visit_ct_photo_album_mut(&mut self, node: &mut CTPhotoAlbum) {
+        walk_ct_photo_album_mut(self, node)
+    }
+    fn visit_ct_picture_non_visual_mut(&mut self, node: &mut CTPictureNonVisual) {
+        walk_ct_picture_non_visual_mut(self, node)
+    }
+    fn visit_ct_placeholder_mut(&mut self, node: &mut CTPlaceholder) {
+        walk_ct_placeholder_mut(self, node)
+    }
+    fn visit_ct_presentation_properties_mut(&mut self, node: &mut CTPresentationProperties) {
+        walk_ct_presentation_properties_mut(self, node)
+    }
+    fn visit_ct_print_properties_mut(&mut self, node: &mut CTPrintProperties) {
+        walk_ct_print_properties_mut(self, node)
+    }
+    fn visit_ct_rel_mut(&mut self, node: &mut CTRel) {
+        walk_ct_rel_mut(self, node)
+    }
+    fn visit_ct_show_info_browse_mut(&mut self, _node: &mut CTShowInfoBrowse) {}
+    fn visit_ct_show_info_kiosk_mut(&mut self, _node: &mut CTShowInfoKiosk) {}
+    fn visit_ct_show_properties_mut(&mut self, node: &mut CTShowProperties) {
+        walk_ct_show_properties_mut(self, node)
+    }
+    fn visit_ct_side_direction_transition_mut(&mut self, node: &mut CTSideDirectionTransition) {
+        walk_ct_side_direction_transition_mut(self, node)
+    }
+    fn visit_ct_slide_id_list_entry_mut(&mut self, node: &mut CTSlideIdListEntry) {
+        walk_ct_slide_id_list_entry_mut(self, node)
+    }
+    fn visit_ct_slide_layout_id_list_mut(&mut self, node: &mut CTSlideLayoutIdList) {
+        walk_ct_slide_layout_id_list_mut(self, node)
+    }
+    fn visit_ct_slide_layout_id_list_entry_mut(&mut self, node: &mut CTSlideLayoutIdListEntry) {
+        walk_ct_slide_layout_id_list_entry_mut(self, node)
+    }
+    fn visit_ct_slide_master_id_list_mut(&mut self, node: &mut CTSlideMasterIdList) {
+        walk_ct_slide_master_id_list_mut(self, node)
+    }
+    fn visit_ct_slide_master_id_list_entry_mut(&mut self, node: &mut CTSlideMasterIdListEntry) {
+        walk_ct_slide_master_id_list_entry_mut(self, node)
+    }
+    fn visit_ct_slide_master_text_styles_mut(&mut self, node: &mut CTSlideMasterTextStyles) {
+        walk_ct_slide_master_text_styles_mut(self, node)
+    }
+    fn visit_ct_slide_relationship_list_mut(&mut self, node: &mut CTSlideRelationshipList) {
+        walk_ct_slide_relationship_list_mut(self, node)
+    }
+    fn visit_ct_slide_relationship_list_entry_mut(
+        &mut self,
+        node: &mut CTSlideRelationshipListEntry,
+    ) {
+        walk_ct_slide_relationship_list_entry_mut(self, node)
+    }
+    fn visit_ct_slide_size_mut(&mut self, node: &mut CTSlideSize) {
+        walk_ct_slide_size_mut(self, node)
+    }
+    fn visit_ct_slide_sorter_view_properties_mut(
+        &mut self,
+        node: &mut CTSlideSorterViewProperties,
+    ) {
+        walk_ct_slide_sorter_view_properties_mut(self, node)
+    }
+    fn visit_ct_slide_sync_properties_mut(&mut self, node: &mut CTSlideSyncProperties) {
+        walk_ct_slide_sync_properties_mut(self, node)
+    }
+    fn visit_ct_slide_view_properties_mut(&mut self, node: &mut CTSlideViewProperties) {
+        walk_ct_slide_view_properties_mut(self, node)
+    }
+    fn visit_ct_smart_tags_mut(&mut self, node: &mut CTSmartTags) {
+        walk_ct_smart_tags_mut(self, node)
+    }
+    fn visit_ct_split_transition_mut(&mut self, node: &mut CTSplitTransition) {
+        walk_ct_split_transition_mut(self, node)
+    }
+    fn visit_ct_string_tag_mut(&mut self, _node: &mut CTStringTag) {}
+    fn visit_cttl_anim_variant_mut(&mut self, node: &mut CTTLAnimVariant) {
+        walk_cttl_anim_variant_mut(self, node)
+    }
+    fn visit_cttl_anim_variant_boolean_val_mut(&mut self, _node: &mut CTTLAnimVariantBooleanVal) {}
+    fn visit_cttl_anim_variant_float_val_mut(&mut self, _node: &mut CTTLAnimVariantFloatVal) {}
+    fn visit_cttl_anim_variant_integer_val_mut(&mut self, _node: &mut CTTLAnimVariantIntegerVal) {}
+    fn visit_cttl_anim_variant_string_val_mut(&mut self, _node: &mut CTTLAnimVariantStringVal) {}
+    fn visit_cttl_animate_behavior_mut(&mut self, node: &mut CTTLAnimateBehavior) {
+        walk_cttl_animate_behavior_mut(self, node)
+    }
+    fn visit_cttl_animate_color_behavior_mut(&mut self, node: &mut CTTLAnimateColorBehavior) {
+        walk_cttl_animate_color_behavior_mut(self, node)
+    }
+    fn visit_cttl_animate_effect_behavior_mut(&mut self, node: &mut CTTLAnimateEffectBehavior) {
+        walk_cttl_animate_effect_behavior_mut(self, node)
+    }
+    fn visit_cttl_animate_motion_behavior_mut(&mut self, node: &mut CTTLAnimateMotionBehavior) {
+        walk_cttl_animate_motion_behavior_mut(self, node)
+    }
+    fn visit_cttl_animate_rotation_behavior_mut(&mut self, node: &mut CTTLAnimateRotationBehavior) {
+        walk_cttl_animate_rotation_behavior_mut(self, node)
+    }
+    fn visit_cttl_animate_scale_behavior_mut(&mut self, node: &mut CTTLAnimateScaleBehavior) {
+        walk_cttl_animate_scale_behavior_mut(self, node)
+    }
+    fn visit_cttl_behavior_attribute_name_list_mut(
+        &mut self,
+        node: &mut CTTLBehaviorAttributeNameList,
+    ) {
+        walk_cttl_behavior_attribute_name_list_mut(self, node)
+    }
+    fn visit_cttl_build_diagram_mut(&mut self, node: &mut CTTLBuildDiagram) {
+        walk_cttl_build_diagram_mut(self, node)
+    }
+    fn visit_cttl_build_paragraph_mut(&mut self, node: &mut CTTLBuildParagraph) {
+        walk_cttl_build_paragraph_mut(self, node)
+    }
+    fn visit_cttl_by_animate_color_transform_mut(
+        &mut self,
+        node: &mut CTTLByAnimateColorTransform,
+    ) {
+        walk_cttl_by_animate_color_transform_mut(self, node)
+    }
+    fn visit_cttl_by_hsl_color_transform_mut(&mut self, _node: &mut CTTLByHslColorTransform) {}
+    fn visit_cttl_by_rgb_color_transform_mut(&mut self, _node: &mut CTTLByRgbColorTransform) {}
+    fn visit_cttl_command_behavior_mut(&mut self, node: &mut CTTLCommandBehavior) {
+        walk_cttl_command_behavior_mut(self, node)
+    }
+    fn visit_cttl_common_behavior_data_mut(&mut self, node: &mut CTTLCommonBehaviorData) {
+        walk_cttl_common_behavior_data_mut(self, node)
+    }
+    fn visit_cttl_common_media_node_data_mut(&mut self, node: &mut CTTLCommonMediaNodeData) {
+        walk_cttl_common_media_node_data_mut(self, node)
+    }
+    fn visit_cttl_common_time_node_data_mut(&mut self, node: &mut CTTLCommonTimeNodeData) {
+        walk_cttl_common_time_node_data_mut(self, node)
+    }
+    fn visit_cttl_graphical_object_build_mut(&mut self, node: &mut CTTLGraphicalObjectBuild) {
+        walk_cttl_graphical_object_build_mut(self, node)
+    }
+    fn visit_cttl_iterate_data_mut(&mut self, node: &mut CTTLIterateData) {
+        walk_cttl_iterate_data_mut(self, node)
+    }
+    fn visit_cttl_iterate_interval_percentage_mut(
+        &mut self,
+        _node: &mut CTTLIterateIntervalPercentage,
+    ) {
+    }
+    fn visit_cttl_iterate_interval_time_mut(&mut self, _node: &mut CTTLIterateIntervalTime) {}
+    fn visit_cttl_media_node_audio_mut(&mut self, node: &mut CTTLMediaNodeAudio) {
+        walk_cttl_media_node_audio_mut(self, node)
+    }
+    fn visit_cttl_media_node_video_mut(&mut self, node: &mut CTTLMediaNodeVideo) {
+        walk_cttl_media_node_video_mut(self, node)
+    }
+    fn visit_cttl_ole_build_chart_mut(&mut self, node: &mut CTTLOleBuildChart) {
+        walk_cttl_ole_build_chart_mut(self, node)
+    }
+    fn visit_cttl_ole_chart_target_element_mut(&mut self, node: &mut CTTLOleChartTargetElement) {
+        walk_cttl_ole_chart_target_element_mut(self, node)
+    }
+    fn visit_cttl_point_mut(&mut self, _node: &mut CTTLPoint) {}
+    fn visit_cttl_set_behavior_mut(&mut self, node: &mut CTTLSetBehavior) {
+        walk_cttl_set_behavior_mut(self, node)
+    }
+    fn visit_cttl_shape_target_element_mut(&mut self, node: &mut CTTLShapeTargetElement) {
+        walk_cttl_shape_target_element_mut(self, node)
+    }
+    fn visit_cttl_sub_shape_id_mut(&mut self, _node: &mut CTTLSubShapeId) {}
+    fn visit_cttl_template_mut(&mut self, node: &mut CTTLTemplate) {
+        walk_cttl_template_mut(self, node)
+    }
+    fn visit_cttl_template_list_mut(&mut self, node: &mut CTTLTemplateList) {
+        walk_cttl_template_list_mut(self, node)
+    }
+    fn visit_cttl_text_target_element_mut(&mut self, node: &mut CTTLTextTargetElement) {
+        walk_cttl_text_target_element_mut(self, node)
+    }
+    fn visit_cttl_time_animate_value_mut(&mut self, node: &mut CTTLTimeAnimateValue) {
+        walk_cttl_time_animate_value_mut(self, node)
+    }
+    fn visit_cttl_time_animate_value_list_mut(&mut self, node: &mut CTTLTimeAnimateValueList) {
+        walk_cttl_time_animate_value_list_mut(self, node)
+    }
+    fn visit_cttl_time_condition_mut(&mut self, node: &mut CTTLTimeCondition) {
+        walk_cttl_time_condition_mut(self, node)
+    }
+    fn visit_cttl_time_condition_list_mut(&mut self, node: &mut CTTLTimeConditionList) {
+        walk_cttl_time_condition_list_mut(self, node)
+    }
+    fn visit_cttl_time_node_sequence_mut(&mut self, node: &mut CTTLTimeNodeSequence) {
+        walk_cttl_time_node_sequence_mut(self, node)
+    }
+    fn visit_cttl_time_target_element_mut(&mut self, node: &mut CTTLTimeTargetElement) {
+        walk_cttl_time_target_element_mut(self, node)
+    }
+    fn visit_cttl_trigger_runtime_node_mut(&mut self, node: &mut CTTLTriggerRuntimeNode) {
+        walk_cttl_trigger_runtime_node_mut(self, node)
+    }
+    fn visit_cttl_trigger_time_node_id_mut(&mut self, _node: &mut CTTLTriggerTimeNodeID) {}
+    fn visit_ct_tag_list_mut(&mut self, node: &mut CTTagList) {
+        walk_ct_tag_list_mut(self, node)
+    }
+    fn visit_ct_tags_data_mut(&mut self, node: &mut CTTagsData) {
+        walk_ct_tags_data_mut(self, node)
+    }
+    fn visit_ct_time_node_list_mut(&mut self, node: &mut CTTimeNodeList) {
+        walk_ct_time_node_list_mut(self, node)
+    }
+    fn visit_ct_transition_sound_action_mut(&mut self, node: &mut CTTransitionSoundAction) {
+        walk_ct_transition_sound_action_mut(self, node)
+    }
+    fn visit_ct_transition_start_sound_action_mut(
+        &mut self,
+        node: &mut CTTransitionStartSoundAction,
+    ) {
+        walk_ct_transition_start_sound_action_mut(self, node)
+    }
+    fn visit_ct_view_properties_mut(&mut self, node: &mut CTViewProperties) {
+        walk_ct_view_properties_mut(self, node)
+    }
+    fn visit_ct_web_properties_mut(&mut self, node: &mut CTWebProperties) {
+        walk_ct_web_properties_mut(self, node)
+    }
+    fn visit_ct_wheel_transition_mut(&mut self, _node: &mut CTWheelTransition) {}
+    fn visit_calendar_type_mut(&mut self, _node: &mut CalendarType) {}
+    fn visit_common_slide_data_mut(&mut self, node: &mut CommonSlideData) {
+        walk_common_slide_data_mut(self, node)
+    }
+    fn visit_connector_mut(&mut self, node: &mut Connector) {
+        walk_connector_mut(self, node)
+    }
+    fn visit_eg_background_mut(&mut self, node: &mut EGBackground) {
+        walk_eg_background_mut(self, node)
+    }
+    fn visit_eg_child_slide_mut(&mut self, node: &mut EGChildSlide) {
+        walk_eg_child_slide_mut(self, node)
+    }
+    fn visit_eg_extension_list_mut(&mut self, node: &mut EGExtensionList) {
+        walk_eg_extension_list_mut(self, node)
+    }
+    fn visit_eg_show_type_mut(&mut self, node: &mut EGShowType) {
+        walk_eg_show_type_mut(self, node)
+    }
+    fn visit_eg_slide_list_choice_mut(&mut self, node: &mut EGSlideListChoice) {
+        walk_eg_slide_list_choice_mut(self, node)
+    }
+    fn visit_graphical_object_frame_mut(&mut self, node: &mut GraphicalObjectFrame) {
+        walk_graphical_object_frame_mut(self, node)
+    }
+    fn visit_group_shape_mut(&mut self, node: &mut GroupShape) {
+        walk_group_shape_mut(self, node)
+    }
+    fn visit_handout_master_mut(&mut self, node: &mut HandoutMaster) {
+        walk_handout_master_mut(self, node)
+    }
+    fn visit_notes_master_mut(&mut self, node: &mut NotesMaster) {
+        walk_notes_master_mut(self, node)
+    }
+    fn visit_notes_slide_mut(&mut self, node: &mut NotesSlide) {
+        walk_notes_slide_mut(self, node)
+    }
+    fn visit_pag_child_slide_mut(&mut self, _node: &mut PAGChildSlide) {}
+    fn visit_pag_ole_mut(&mut self, _node: &mut PAGOle) {}
+    fn visit_pagtl_build_mut(&mut self, _node: &mut PAGTLBuild) {}
+    fn visit_picture_mut(&mut self, node: &mut Picture) {
+        walk_picture_mut(self, node)
+    }
+    fn visit_presentation_mut(&mut self, node: &mut Presentation) {
+        walk_presentation_mut(self, node)
+    }
+    fn visit_st_alg_class_mut(&mut self, _node: &mut STAlgClass) {}
+    fn visit_st_alg_type_mut(&mut self, _node: &mut STAlgType) {}
+    fn visit_st_conformance_class_mut(&mut self, _node: &mut STConformanceClass) {}
+    fn visit_st_crypt_prov_mut(&mut self, _node: &mut STCryptProv) {}
+    fn visit_st_direction_mut(&mut self, _node: &mut STDirection) {}
+    fn visit_st_iterate_type_mut(&mut self, _node: &mut STIterateType) {}
+    fn visit_st_ole_object_follow_color_scheme_mut(
+        &mut self,
+        _node: &mut STOleObjectFollowColorScheme,
+    ) {
+    }
+    fn visit_st_on_off1_mut(&mut self, _node: &mut STOnOff1) {}
+    fn visit_st_photo_album_frame_shape_mut(&mut self, _node: &mut STPhotoAlbumFrameShape) {}
+    fn visit_st_photo_album_layout_mut(&mut self, _node: &mut STPhotoAlbumLayout) {}
+    fn visit_st_placeholder_size_mut(&mut self, _node: &mut STPlaceholderSize) {}
+    fn visit_st_placeholder_type_mut(&mut self, _node: &mut STPlaceholderType) {}
+    fn visit_st_print_color_mode_mut(&mut self, _node: &mut STPrintColorMode) {}
+    fn visit_st_print_what_mut(&mut self, _node: &mut STPrintWhat) {}
+    fn visit_st_slide_layout_type_mut(&mut self, _node: &mut STSlideLayoutType) {}
+    fn visit_st_slide_size_type_mut(&mut self, _node: &mut STSlideSizeType) {}
+    fn visit_st_splitter_bar_state_mut(&mut self, _node: &mut STSplitterBarState) {}
+    fn visit_sttl_animate_behavior_calc_mode_mut(
+        &mut self,
+        _node: &mut STTLAnimateBehaviorCalcMode,
+    ) {
+    }
+    fn visit_sttl_animate_behavior_value_type_mut(
+        &mut self,
+        _node: &mut STTLAnimateBehaviorValueType,
+    ) {
+    }
+    fn visit_sttl_animate_color_direction_mut(&mut self, _node: &mut STTLAnimateColorDirection) {}
+    fn visit_sttl_animate_color_space_mut(&mut self, _node: &mut STTLAnimateColorSpace) {}
+    fn visit_sttl_animate_effect_transition_mut(
+        &mut self,
+        _node: &mut STTLAnimateEffectTransition,
+    ) {
+    }
+    fn visit_sttl_animate_motion_behavior_origin_mut(
+        &mut self,
+        _node: &mut STTLAnimateMotionBehaviorOrigin,
+    ) {
+    }
+    fn visit_sttl_animate_motion_path_edit_mode_mut(
+        &mut self,
+        _node: &mut STTLAnimateMotionPathEditMode,
+    ) {
+    }
+    fn visit_sttl_behavior_accumulate_type_mut(&mut self, _node: &mut STTLBehaviorAccumulateType) {}
+    fn visit_sttl_behavior_additive_type_mut(&mut self, _node: &mut STTLBehaviorAdditiveType) {}
+    fn visit_sttl_behavior_override_type_mut(&mut self, _node: &mut STTLBehaviorOverrideType) {}
+    fn visit_sttl_behavior_transform_type_mut(&mut self, _node: &mut STTLBehaviorTransformType) {}
+    fn visit_sttl_chart_subelement_type_mut(&mut self, _node: &mut STTLChartSubelementType) {}
+    fn visit_sttl_command_type_mut(&mut self, _node: &mut STTLCommandType) {}
+    fn visit_sttl_diagram_build_type_mut(&mut self, _node: &mut STTLDiagramBuildType) {}
+    fn visit_sttl_next_action_type_mut(&mut self, _node: &mut STTLNextActionType) {}
+    fn visit_sttl_ole_chart_build_type_mut(&mut self, _node: &mut STTLOleChartBuildType) {}
+    fn visit_sttl_para_build_type_mut(&mut self, _node: &mut STTLParaBuildType) {}
+    fn visit_sttl_previous_action_type_mut(&mut self, _node: &mut STTLPreviousActionType) {}
+    fn visit_sttl_time_node_fill_type_mut(&mut self, _node: &mut STTLTimeNodeFillType) {}
+    fn visit_sttl_time_node_master_relation_mut(&mut self, _node: &mut STTLTimeNodeMasterRelation) {
+    }
+    fn visit_sttl_time_node_preset_class_type_mut(
+        &mut self,
+        _node: &mut STTLTimeNodePresetClassType,
+    ) {
+    }
+    fn visit_sttl_time_node_restart_type_mut(&mut self, _node: &mut STTLTimeNodeRestartType) {}
+    fn visit_sttl_time_node_sync_type_mut(&mut self, _node: &mut STTLTimeNodeSyncType) {}
+    fn visit_sttl_time_node_type_mut(&mut self, _node: &mut STTLTimeNodeType) {}
+    fn visit_sttl_trigger_event_mut(&mut self, _node: &mut STTLTriggerEvent) {}
+    fn visit_sttl_trigger_runtime_node_mut(&mut self, _node: &mut STTLTriggerRuntimeNode) {}
+    fn visit_st_transition_corner_direction_type_mut(
+        &mut self,
+        _node: &mut STTransitionCornerDirectionType,
+    ) {
+    }
+    fn visit_st_transition_in_out_direction_type_mut(
+        &mut self,
+        _node: &mut STTransitionInOutDirectionType,
+    ) {
+    }
+    fn visit_st_transition_side_direction_type_mut(
+        &mut self,
+        _node: &mut STTransitionSideDirectionType,
+    ) {
+    }
+    fn visit_st_transition_speed_mut(&mut self, _node: &mut STTransitionSpeed) {}
+    fn visit_st_true_false_blank_mut(&mut self, _node: &mut STTrueFalseBlank) {}
+    fn visit_st_vertical_align_run_mut(&mut self, _node: &mut STVerticalAlignRun) {}
+    fn visit_st_view_type_mut(&mut self, _node: &mut STViewType) {}
+    fn visit_st_web_color_type_mut(&mut self, _node: &mut STWebColorType) {}
+    fn visit_st_web_screen_size_mut(&mut self, _node: &mut STWebScreenSize) {}
+    fn visit_stx_align_mut(&mut self, _node: &mut STXAlign) {}
+    fn visit_sty_align_mut(&mut self, _node: &mut STYAlign) {}
+    fn visit_shape_mut(&mut self, node: &mut Shape) {
+        walk_shape_mut(self, node)
+    }
+    fn visit_shape_non_visual_mut(&mut self, node: &mut ShapeNonVisual) {
+        walk_shape_non_visual_mut(self, node)
+    }
+    fn visit_slide_mut(&mut self, node: &mut Slide) {
+        walk_slide_mut(self, node)
+    }
+    fn visit_slide_id_list_mut(&mut self, node: &mut SlideIdList) {
+        walk_slide_id_list_mut(self, node)
+    }
+    fn visit_slide_layout_mut(&mut self, node: &mut SlideLayout) {
+        walk_slide_layout_mut(self, node)
+    }
+    fn visit_slide_master_mut(&mut self, node: &mut SlideMaster) {
+        walk_slide_master_mut(self, node)
+    }
+    fn visit_slide_timing_mut(&mut self, node: &mut SlideTiming) {
+        walk_slide_timing_mut(self, node)
+    }
+    fn visit_slide_transition_mut(&mut self, node: &mut SlideTransition) {
+        walk_slide_transition_mut(self, node)
+    }
+    fn visit_true_false_mut(&mut self, _node: &mut TrueFalse) {}
+}
+
+pub fn walk_ct_application_non_visual_drawing_props<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTApplicationNonVisualDrawingProps,
+) {
+    if let Some(it0) = &node.ph {
+        Visit::visit_ct_placeholder(v, it0);
+    }
+    if let Some(it0) = &node.cust_data_lst {
+        Visit::visit_ct_customer_data_list(v, it0);
+    }
+    if let Some(it0) = &node.ext_lst {
+        Visit::visit_ct_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_application_non_visual_drawing_props_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTApplicationNonVisualDrawingProps,
+) {
+    if let Some(it0) = &mut node.ph {
+        VisitMut::visit_ct_placeholder_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.cust_data_lst {
+        VisitMut::visit_ct_customer_data_list_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.ext_lst {
+        VisitMut::visit_ct_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_background<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTBackground) {
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &node.bw_mode {
+            ooxml_dml::visit::Visit::visit_st_black_white_mode(v, it0);
+        }
+    }
+    if let Some(it0) = &node.background {
+        Visit::visit_eg_background(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_background_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTBackground) {
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &mut node.bw_mode {
+            ooxml_dml::visit::VisitMut::visit_st_black_white_mode_mut(v, it0);
+        }
+    }
+    if let Some(it0) = &mut node.background {
+        VisitMut::visit_eg_background_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_background_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTBackgroundProperties,
+) {
+    if let Some(it0) = &node.ext_lst {
+        Visit::visit_ct_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_background_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTBackgroundProperties,
+) {
+    if let Some(it0) = &mut node.ext_lst {
+        VisitMut::visit_ct_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_build_list<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTBuildList) {
+    for it0 in &node.bld_p {
+        Visit::visit_cttl_build_paragraph(v, it0);
+    }
+    for it0 in &node.bld_dgm {
+        Visit::visit_cttl_build_diagram(v, it0);
+    }
+    for it0 in &node.bld_ole_chart {
+        Visit::visit_cttl_ole_build_chart(v, it0);
+    }
+    for it0 in &node.bld_graphic {
+        Visit::visit_cttl_graphical_object_build(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_build_list_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTBuildList) {
+    for it0 in &mut node.bld_p {
+        VisitMut::visit_cttl_build_paragraph_mut(v, it0);
+    }
+    for it0 in &mut node.bld_dgm {
+        VisitMut::visit_cttl_build_diagram_mut(v, it0);
+    }
+    for it0 in &mut node.bld_ole_chart {
+        VisitMut::visit_cttl_ole_build_chart_mut(v, it0);
+    }
+    for it0 in &mut node.bld_graphic {
+        VisitMut::visit_cttl_graphical_object_build_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_comment<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTComment) {
+    #[cfg(feature = "pml-comments")]
+    {
+        ooxml_dml::visit::Visit::visit_point2_d(v, &node.pos);
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &node.ext_lst {
+            Visit::visit_ct_extension_list_modify(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_comment_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTComment) {
+    #[cfg(feature = "pml-comments")]
+    {
+        ooxml_dml::visit::VisitMut::visit_point2_d_mut(v, &mut node.pos);
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &mut node.ext_lst {
+            VisitMut::visit_ct_extension_list_modify_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_comment_author<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTCommentAuthor,
+) {
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &node.ext_lst {
+            Visit::visit_ct_extension_list(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_comment_author_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTCommentAuthor) {
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &mut node.ext_lst {
+            VisitMut::visit_ct_extension_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_comment_author_list<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTCommentAuthorList,
+) {
+    for it0 in &node.cm_author {
+        Visit::visit_ct_comment_author(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_comment_author_list_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTCommentAuthorList,
+) {
+    for it0 in &mut node.cm_author {
+        VisitMut::visit_ct_comment_author_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_comment_list<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTCommentList) {
+    for it0 in &node.cm {
+        Visit::visit_ct_comment(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_comment_list_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTCommentList) {
+    for it0 in &mut node.cm {
+        VisitMut::visit_ct_comment_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_common_slide_view_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTCommonSlideViewProperties,
+) {
+    Visit::visit_ct_common_view_properties(v, &node.c_view_pr);
+    if let Some(it0) = &node.guide_lst {
+        Visit::visit_ct_guide_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_common_slide_view_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTCommonSlideViewProperties,
+) {
+    VisitMut::visit_ct_common_view_properties_mut(v, &mut node.c_view_pr);
+    if let Some(it0) = &mut node.guide_lst {
+        VisitMut::visit_ct_guide_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_common_view_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTCommonViewProperties,
+) {
+    ooxml_dml::visit::Visit::visit_ct_scale2_d(v, &node.scale);
+    ooxml_dml::visit::Visit::visit_point2_d(v, &node.origin);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_common_view_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTCommonViewProperties,
+) {
+    ooxml_dml::visit::VisitMut::visit_ct_scale2_d_mut(v, &mut node.scale);
+    ooxml_dml::visit::VisitMut::visit_point2_d_mut(v, &mut node.origin);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_connector_non_visual<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTConnectorNonVisual,
+) {
+    ooxml_dml::visit::Visit::visit_ct_non_visual_drawing_props(v, &node.c_nv_pr);
+    ooxml_dml::visit::Visit::visit_ct_non_visual_connector_properties(v, &node.c_nv_cxn_sp_pr);
+    Visit::visit_ct_application_non_visual_drawing_props(v, &node.nv_pr);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_connector_non_visual_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTConnectorNonVisual,
+) {
+    ooxml_dml::visit::VisitMut::visit_ct_non_visual_drawing_props_mut(v, &mut node.c_nv_pr);
+    ooxml_dml::visit::VisitMut::visit_ct_non_visual_connector_properties_mut(
+        v,
+        &mut node.c_nv_cxn_sp_pr,
+    );
+    VisitMut::visit_ct_application_non_visual_drawing_props_mut(v, &mut node.nv_pr);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_control<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTControl) {
+    if let Some(it0) = &node.ext_lst {
+        Visit::visit_ct_extension_list(v, it0);
+    }
+    if let Some(it0) = &node.picture {
+        Visit::visit_picture(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_control_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTControl) {
+    if let Some(it0) = &mut node.ext_lst {
+        VisitMut::visit_ct_extension_list_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.picture {
+        VisitMut::visit_picture_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_control_list<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTControlList) {
+    for it0 in &node.control {
+        Visit::visit_ct_control(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_control_list_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTControlList) {
+    for it0 in &mut node.control {
+        VisitMut::visit_ct_control_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_corner_direction_transition<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTCornerDirectionTransition,
+) {
+    if let Some(it0) = &node.dir {
+        Visit::visit_st_transition_corner_direction_type(v, it0);
+    }
+}
+
+pub fn walk_ct_corner_direction_transition_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTCornerDirectionTransition,
+) {
+    if let Some(it0) = &mut node.dir {
+        VisitMut::visit_st_transition_corner_direction_type_mut(v, it0);
+    }
+}
+
+pub fn walk_ct_custom_show<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTCustomShow) {
+    Visit::visit_ct_slide_relationship_list(v, &node.sld_lst);
+    if let Some(it0) = &node.ext_lst {
+        Visit::visit_ct_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_custom_show_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTCustomShow) {
+    VisitMut::visit_ct_slide_relationship_list_mut(v, &mut node.sld_lst);
+    if let Some(it0) = &mut node.ext_lst {
+        VisitMut::visit_ct_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_custom_show_list<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTCustomShowList,
+) {
+    for it0 in &node.cust_show {
+        Visit::visit_ct_custom_show(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_custom_show_list_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTCustomShowList) {
+    for it0 in &mut node.cust_show {
+        VisitMut::visit_ct_custom_show_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_customer_data<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTCustomerData) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_customer_data_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTCustomerData) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_customer_data_list<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTCustomerDataList,
+) {
+    for it0 in &node.cust_data {
+        Visit::visit_ct_customer_data(v, it0);
+    }
+    if let Some(it0) = &node.tags {
+        Visit::visit_ct_tags_data(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_customer_data_list_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTCustomerDataList,
+) {
+    for it0 in &mut node.cust_data {
+        VisitMut::visit_ct_customer_data_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.tags {
+        VisitMut::visit_ct_tags_data_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_embedded_font_data_id<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTEmbeddedFontDataId,
+) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_embedded_font_data_id_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTEmbeddedFontDataId,
+) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_embedded_font_list<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTEmbeddedFontList,
+) {
+    for it0 in &node.embedded_font {
+        Visit::visit_ct_embedded_font_list_entry(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_embedded_font_list_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTEmbeddedFontList,
+) {
+    for it0 in &mut node.embedded_font {
+        VisitMut::visit_ct_embedded_font_list_entry_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_embedded_font_list_entry<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTEmbeddedFontListEntry,
+) {
+    ooxml_dml::visit::Visit::visit_text_font(v, &node.font);
+    if let Some(it0) = &node.regular {
+        Visit::visit_ct_embedded_font_data_id(v, it0);
+    }
+    if let Some(it0) = &node.bold {
+        Visit::visit_ct_embedded_font_data_id(v, it0);
+    }
+    if let Some(it0) = &node.italic {
+        Visit::visit_ct_embedded_font_data_id(v, it0);
+    }
+    if let Some(it0) = &node.bold_italic {
+        Visit::visit_ct_embedded_font_data_id(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_embedded_font_list_entry_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTEmbeddedFontListEntry,
+) {
+    ooxml_dml::visit::VisitMut::visit_text_font_mut(v, &mut node.font);
+    if let Some(it0) = &mut node.regular {
+        VisitMut::visit_ct_embedded_font_data_id_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.bold {
+        VisitMut::visit_ct_embedded_font_data_id_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.italic {
+        VisitMut::visit_ct_embedded_font_data_id_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.bold_italic {
+        VisitMut::visit_ct_embedded_font_data_id_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_extension_list<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTExtensionList,
+) {
+    for it0 in &node.ext {
+        Visit::visit_ct_extension(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_extension_list_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTExtensionList) {
+    for it0 in &mut node.ext {
+        VisitMut::visit_ct_extension_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_extension_list_modify<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTExtensionListModify,
+) {
+    for it0 in &node.ext {
+        Visit::visit_ct_extension(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_extension_list_modify_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTExtensionListModify,
+) {
+    for it0 in &mut node.ext {
+        VisitMut::visit_ct_extension_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_graphical_object_frame_non_visual<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTGraphicalObjectFrameNonVisual,
+) {
+    ooxml_dml::visit::Visit::visit_ct_non_visual_drawing_props(v, &node.c_nv_pr);
+    ooxml_dml::visit::Visit::visit_ct_non_visual_graphic_frame_properties(
+        v,
+        &node.c_nv_graphic_frame_pr,
+    );
+    Visit::visit_ct_application_non_visual_drawing_props(v, &node.nv_pr);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_graphical_object_frame_non_visual_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTGraphicalObjectFrameNonVisual,
+) {
+    ooxml_dml::visit::VisitMut::visit_ct_non_visual_drawing_props_mut(v, &mut node.c_nv_pr);
+    ooxml_dml::visit::VisitMut::visit_ct_non_visual_graphic_frame_properties_mut(
+        v,
+        &mut node.c_nv_graphic_frame_pr,
+    );
+    VisitMut::visit_ct_application_non_visual_drawing_props_mut(v, &mut node.nv_pr);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_group_shape_non_visual<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTGroupShapeNonVisual,
+) {
+    ooxml_dml::visit::Visit::visit_ct_non_visual_drawing_props(v, &node.c_nv_pr);
+    ooxml_dml::visit::Visit::visit_ct_non_visual_group_drawing_shape_props(v, &node.c_nv_grp_sp_pr);
+    Visit::visit_ct_application_non_visual_drawing_props(v, &node.nv_pr);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_group_shape_non_visual_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTGroupShapeNonVisual,
+) {
+    ooxml_dml::visit::VisitMut::visit_ct_non_visual_drawing_props_mut(v, &mut node.c_nv_pr);
+    ooxml_dml::visit::VisitMut::visit_ct_non_visual_group_drawing_shape_props_mut(
+        v,
+        &mut node.c_nv_grp_sp_pr,
+    );
+    VisitMut::visit_ct_application_non_visual_drawing_props_mut(v, &mut node.nv_pr);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_guide<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTGuide) {
+    if let Some(it0) = &node.orient {
+        Visit::visit_st_direction(v, it0);
+    }
+}
+
+pub fn walk_ct_guide_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTGuide) {
+    if let Some(it0) = &mut node.orient {
+        VisitMut::visit_st_direction_mut(v, it0);
+    }
+}
+
+pub fn walk_ct_guide_list<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTGuideList) {
+    for it0 in &node.guide {
+        Visit::visit_ct_guide(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_guide_list_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTGuideList) {
+    for it0 in &mut node.guide {
+        VisitMut::visit_ct_guide_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_handout_master_id_list<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTHandoutMasterIdList,
+) {
+    if let Some(it0) = &node.handout_master_id {
+        Visit::visit_ct_handout_master_id_list_entry(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_handout_master_id_list_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTHandoutMasterIdList,
+) {
+    if let Some(it0) = &mut node.handout_master_id {
+        VisitMut::visit_ct_handout_master_id_list_entry_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_handout_master_id_list_entry<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTHandoutMasterIdListEntry,
+) {
+    if let Some(it0) = &node.ext_lst {
+        Visit::visit_ct_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_handout_master_id_list_entry_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTHandoutMasterIdListEntry,
+) {
+    if let Some(it0) = &mut node.ext_lst {
+        VisitMut::visit_ct_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_header_footer<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTHeaderFooter) {
+    if let Some(it0) = &node.ext_lst {
+        Visit::visit_ct_extension_list_modify(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_header_footer_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTHeaderFooter) {
+    if let Some(it0) = &mut node.ext_lst {
+        VisitMut::visit_ct_extension_list_modify_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_html_publish_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTHtmlPublishProperties,
+) {
+    if let Some(it0) = &node.slide_list_choice {
+        Visit::visit_eg_slide_list_choice(v, it0);
+    }
+    if let Some(it0) = &node.ext_lst {
+        Visit::visit_ct_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_html_publish_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTHtmlPublishProperties,
+) {
+    if let Some(it0) = &mut node.slide_list_choice {
+        VisitMut::visit_eg_slide_list_choice_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.ext_lst {
+        VisitMut::visit_ct_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_in_out_transition<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTInOutTransition,
+) {
+    if let Some(it0) = &node.dir {
+        Visit::visit_st_transition_in_out_direction_type(v, it0);
+    }
+}
+
+pub fn walk_ct_in_out_transition_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTInOutTransition,
+) {
+    if let Some(it0) = &mut node.dir {
+        VisitMut::visit_st_transition_in_out_direction_type_mut(v, it0);
+    }
+}
+
+pub fn walk_ct_modify_verifier<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTModifyVerifier,
+) {
+    if let Some(it0) = &node.crypt_provider_type {
+        Visit::visit_st_crypt_prov(v, it0);
+    }
+    if let Some(it0) = &node.crypt_algorithm_class {
+        Visit::visit_st_alg_class(v, it0);
+    }
+    if let Some(it0) = &node.crypt_algorithm_type {
+        Visit::visit_st_alg_type(v, it0);
+    }
+}
+
+pub fn walk_ct_modify_verifier_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTModifyVerifier) {
+    if let Some(it0) = &mut node.crypt_provider_type {
+        VisitMut::visit_st_crypt_prov_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.crypt_algorithm_class {
+        VisitMut::visit_st_alg_class_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.crypt_algorithm_type {
+        VisitMut::visit_st_alg_type_mut(v, it0);
+    }
+}
+
+pub fn walk_ct_normal_view_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTNormalViewProperties,
+) {
+    if let Some(it0) = &node.vert_bar_state {
+        Visit::visit_st_splitter_bar_state(v, it0);
+    }
+    if let Some(it0) = &node.horz_bar_state {
+        Visit::visit_st_splitter_bar_state(v, it0);
+    }
+    Visit::visit_ct_normal_view_portion(v, &node.restored_left);
+    Visit::visit_ct_normal_view_portion(v, &node.restored_top);
+    if let Some(it0) = &node.ext_lst {
+        Visit::visit_ct_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_normal_view_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTNormalViewProperties,
+) {
+    if let Some(it0) = &mut node.vert_bar_state {
+        VisitMut::visit_st_splitter_bar_state_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.horz_bar_state {
+        VisitMut::visit_st_splitter_bar_state_mut(v, it0);
+    }
+    VisitMut::visit_ct_normal_view_portion_mut(v, &mut node.restored_left);
+    VisitMut::visit_ct_normal_view_portion_mut(v, &mut node.restored_top);
+    if let Some(it0) = &mut node.ext_lst {
+        VisitMut::visit_ct_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_notes_master_id_list<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTNotesMasterIdList,
+) {
+    if let Some(it0) = &node.notes_master_id {
+        Visit::visit_ct_notes_master_id_list_entry(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_notes_master_id_list_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTNotesMasterIdList,
+) {
+    if let Some(it0) = &mut node.notes_master_id {
+        VisitMut::visit_ct_notes_master_id_list_entry_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_notes_master_id_list_entry<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTNotesMasterIdListEntry,
+) {
+    if let Some(it0) = &node.ext_lst {
+        Visit::visit_ct_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_notes_master_id_list_entry_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTNotesMasterIdListEntry,
+) {
+    if let Some(it0) = &mut node.ext_lst {
+        VisitMut::visit_ct_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_notes_text_view_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTNotesTextViewProperties,
+) {
+    Visit::visit_ct_common_view_properties(v, &node.c_view_pr);
+    if let Some(it0) = &node.ext_lst {
+        Visit::visit_ct_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_notes_text_view_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTNotesTextViewProperties,
+) {
+    VisitMut::visit_ct_common_view_properties_mut(v, &mut node.c_view_pr);
+    if let Some(it0) = &mut node.ext_lst {
+        VisitMut::visit_ct_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_notes_view_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTNotesViewProperties,
+) {
+    Visit::visit_ct_common_slide_view_properties(v, &node.c_sld_view_pr);
+    if let Some(it0) = &node.ext_lst {
+        Visit::visit_ct_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_notes_view_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTNotesViewProperties,
+) {
+    VisitMut::visit_ct_common_slide_view_properties_mut(v, &mut node.c_sld_view_pr);
+    if let Some(it0) = &mut node.ext_lst {
+        VisitMut::visit_ct_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_ole_object<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTOleObject) {
+    if let Some(it0) = &node.embed {
+        Visit::visit_ct_ole_object_embed(v, it0);
+    }
+    if let Some(it0) = &node.link {
+        Visit::visit_ct_ole_object_link(v, it0);
+    }
+    if let Some(it0) = &node.picture {
+        Visit::visit_picture(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_ole_object_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTOleObject) {
+    if let Some(it0) = &mut node.embed {
+        VisitMut::visit_ct_ole_object_embed_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.link {
+        VisitMut::visit_ct_ole_object_link_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.picture {
+        VisitMut::visit_picture_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_ole_object_embed<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTOleObjectEmbed,
+) {
+    if let Some(it0) = &node.follow_color_scheme {
+        Visit::visit_st_ole_object_follow_color_scheme(v, it0);
+    }
+    if let Some(it0) = &node.ext_lst {
+        Visit::visit_ct_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_ole_object_embed_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTOleObjectEmbed) {
+    if let Some(it0) = &mut node.follow_color_scheme {
+        VisitMut::visit_st_ole_object_follow_color_scheme_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.ext_lst {
+        VisitMut::visit_ct_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_ole_object_link<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTOleObjectLink,
+) {
+    if let Some(it0) = &node.ext_lst {
+        Visit::visit_ct_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_ole_object_link_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTOleObjectLink) {
+    if let Some(it0) = &mut node.ext_lst {
+        VisitMut::visit_ct_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_orientation_transition<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTOrientationTransition,
+) {
+    if let Some(it0) = &node.dir {
+        Visit::visit_st_direction(v, it0);
+    }
+}
+
+pub fn walk_ct_orientation_transition_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTOrientationTransition,
+) {
+    if let Some(it0) = &mut node.dir {
+        VisitMut::visit_st_direction_mut(v, it0);
+    }
+}
+
+pub fn walk_ct_outline_view_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTOutlineViewProperties,
+) {
+    Visit::visit_ct_common_view_properties(v, &node.c_view_pr);
+    if let Some(it0) = &node.sld_lst {
+        Visit::visit_ct_outline_view_slide_list(v, it0);
+    }
+    if let Some(it0) = &node.ext_lst {
+        Visit::visit_ct_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_outline_view_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTOutlineViewProperties,
+) {
+    VisitMut::visit_ct_common_view_properties_mut(v, &mut node.c_view_pr);
+    if let Some(it0) = &mut node.sld_lst {
+        VisitMut::visit_ct_outline_view_slide_list_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.ext_lst {
+        VisitMut::visit_ct_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_outline_view_slide_list<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTOutlineViewSlideList,
+) {
+    for it0 in &node.sld {
+        Visit::visit_ct_outline_view_slide_entry(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_outline_view_slide_list_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTOutlineViewSlideList,
+) {
+    for it0 in &mut node.sld {
+        VisitMut::visit_ct_outline_view_slide_entry_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_photo_album<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTPhotoAlbum) {
+    if let Some(it0) = &node.layout {
+        Visit::visit_st_photo_album_layout(v, it0);
+    }
+    if let Some(it0) = &node.frame {
+        Visit::visit_st_photo_album_frame_shape(v, it0);
+    }
+    if let Some(it0) = &node.ext_lst {
+        Visit::visit_ct_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_photo_album_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTPhotoAlbum) {
+    if let Some(it0) = &mut node.layout {
+        VisitMut::visit_st_photo_album_layout_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.frame {
+        VisitMut::visit_st_photo_album_frame_shape_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.ext_lst {
+        VisitMut::visit_ct_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_picture_non_visual<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTPictureNonVisual,
+) {
+    ooxml_dml::visit::Visit::visit_ct_non_visual_drawing_props(v, &node.c_nv_pr);
+    ooxml_dml::visit::Visit::visit_ct_non_visual_picture_properties(v, &node.c_nv_pic_pr);
+    Visit::visit_ct_application_non_visual_drawing_props(v, &node.nv_pr);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_picture_non_visual_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTPictureNonVisual,
+) {
+    ooxml_dml::visit::VisitMut::visit_ct_non_visual_drawing_props_mut(v, &mut node.c_nv_pr);
+    ooxml_dml::visit::VisitMut::visit_ct_non_visual_picture_properties_mut(
+        v,
+        &mut node.c_nv_pic_pr,
+    );
+    VisitMut::visit_ct_application_non_visual_drawing_props_mut(v, &mut node.nv_pr);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_placeholder<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTPlaceholder) {
+    if let Some(it0) = &node.r#type {
+        Visit::visit_st_placeholder_type(v, it0);
+    }
+    if let Some(it0) = &node.orient {
+        Visit::visit_st_direction(v, it0);
+    }
+    if let Some(it0) = &node.sz {
+        Visit::visit_st_placeholder_size(v, it0);
+    }
+    if let Some(it0) = &node.ext_lst {
+        Visit::visit_ct_extension_list_modify(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_placeholder_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTPlaceholder) {
+    if let Some(it0) = &mut node.r#type {
+        VisitMut::visit_st_placeholder_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.orient {
+        VisitMut::visit_st_direction_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.sz {
+        VisitMut::visit_st_placeholder_size_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.ext_lst {
+        VisitMut::visit_ct_extension_list_modify_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_presentation_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTPresentationProperties,
+) {
+    #[cfg(feature = "pml-external")]
+    {
+        if let Some(it0) = &node.html_pub_pr {
+            Visit::visit_ct_html_publish_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-external")]
+    {
+        if let Some(it0) = &node.web_pr {
+            Visit::visit_ct_web_properties(v, it0);
+        }
+    }
+    if let Some(it0) = &node.prn_pr {
+        Visit::visit_ct_print_properties(v, it0);
+    }
+    if let Some(it0) = &node.show_pr {
+        Visit::visit_ct_show_properties(v, it0);
+    }
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &node.clr_mru {
+            ooxml_dml::visit::Visit::visit_ct_color_mru(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &node.ext_lst {
+            Visit::visit_ct_extension_list(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_presentation_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTPresentationProperties,
+) {
+    #[cfg(feature = "pml-external")]
+    {
+        if let Some(it0) = &mut node.html_pub_pr {
+            VisitMut::visit_ct_html_publish_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-external")]
+    {
+        if let Some(it0) = &mut node.web_pr {
+            VisitMut::visit_ct_web_properties_mut(v, it0);
+        }
+    }
+    if let Some(it0) = &mut node.prn_pr {
+        VisitMut::visit_ct_print_properties_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.show_pr {
+        VisitMut::visit_ct_show_properties_mut(v, it0);
+    }
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &mut node.clr_mru {
+            ooxml_dml::visit::VisitMut::visit_ct_color_mru_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &mut node.ext_lst {
+            VisitMut::visit_ct_extension_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_print_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTPrintProperties,
+) {
+    if let Some(it0) = &node.prn_what {
+        Visit::visit_st_print_what(v, it0);
+    }
+    if let Some(it0) = &node.clr_mode {
+        Visit::visit_st_print_color_mode(v, it0);
+    }
+    if let Some(it0) = &node.ext_lst {
+        Visit::visit_ct_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_print_properties_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTPrintProperties) {
+    if let Some(it0) = &mut node.prn_what {
+        VisitMut::visit_st_print_what_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.clr_mode {
+        VisitMut::visit_st_print_color_mode_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.ext_lst {
+        VisitMut::visit_ct_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_rel<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTRel) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_rel_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTRel) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_show_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTShowProperties,
+) {
+    if let Some(it0) = &node.show_type {
+        Visit::visit_eg_show_type(v, it0);
+    }
+    if let Some(it0) = &node.slide_list_choice {
+        Visit::visit_eg_slide_list_choice(v, it0);
+    }
+    if let Some(it0) = &node.pen_clr {
+        ooxml_dml::visit::Visit::visit_ct_color(v, it0);
+    }
+    if let Some(it0) = &node.ext_lst {
+        Visit::visit_ct_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_show_properties_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTShowProperties) {
+    if let Some(it0) = &mut node.show_type {
+        VisitMut::visit_eg_show_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.slide_list_choice {
+        VisitMut::visit_eg_slide_list_choice_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.pen_clr {
+        ooxml_dml::visit::VisitMut::visit_ct_color_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.ext_lst {
+        VisitMut::visit_ct_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_side_direction_transition<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTSideDirectionTransition,
+) {
+    if let Some(it0) = &node.dir {
+        Visit::visit_st_transition_side_direction_type(v, it0);
+    }
+}
+
+pub fn walk_ct_side_direction_transition_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTSideDirectionTransition,
+) {
+    if let Some(it0) = &mut node.dir {
+        VisitMut::visit_st_transition_side_direction_type_mut(v, it0);
+    }
+}
+
+pub fn walk_ct_slide_id_list_entry<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTSlideIdListEntry,
+) {
+    if let Some(it0) = &node.ext_lst {
+        Visit::visit_ct_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_slide_id_list_entry_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTSlideIdListEntry,
+) {
+    if let Some(it0) = &mut node.ext_lst {
+        VisitMut::visit_ct_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_slide_layout_id_list<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTSlideLayoutIdList,
+) {
+    for it0 in &node.sld_layout_id {
+        Visit::visit_ct_slide_layout_id_list_entry(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_slide_layout_id_list_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTSlideLayoutIdList,
+) {
+    for it0 in &mut node.sld_layout_id {
+        VisitMut::visit_ct_slide_layout_id_list_entry_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_slide_layout_id_list_entry<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTSlideLayoutIdListEntry,
+) {
+    if let Some(it0) = &node.ext_lst {
+        Visit::visit_ct_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_slide_layout_id_list_entry_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTSlideLayoutIdListEntry,
+) {
+    if let Some(it0) = &mut node.ext_lst {
+        VisitMut::visit_ct_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_slide_master_id_list<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTSlideMasterIdList,
+) {
+    for it0 in &node.sld_master_id {
+        Visit::visit_ct_slide_master_id_list_entry(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_slide_master_id_list_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTSlideMasterIdList,
+) {
+    for it0 in &mut node.sld_master_id {
+        VisitMut::visit_ct_slide_master_id_list_entry_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_slide_master_id_list_entry<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTSlideMasterIdListEntry,
+) {
+    if let Some(it0) = &node.ext_lst {
+        Visit::visit_ct_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_slide_master_id_list_entry_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTSlideMasterIdListEntry,
+) {
+    if let Some(it0) = &mut node.ext_lst {
+        VisitMut::visit_ct_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_slide_master_text_styles<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTSlideMasterTextStyles,
+) {
+    if let Some(it0) = &node.title_style {
+        ooxml_dml::visit::Visit::visit_ct_text_list_style(v, it0);
+    }
+    if let Some(it0) = &node.body_style {
+        ooxml_dml::visit::Visit::visit_ct_text_list_style(v, it0);
+    }
+    if let Some(it0) = &node.other_style {
+        ooxml_dml::visit::Visit::visit_ct_text_list_style(v, it0);
+    }
+    if let Some(it0) = &node.ext_lst {
+        Visit::visit_ct_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_slide_master_text_styles_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTSlideMasterTextStyles,
+) {
+    if let Some(it0) = &mut node.title_style {
+        ooxml_dml::visit::VisitMut::visit_ct_text_list_style_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.body_style {
+        ooxml_dml::visit::VisitMut::visit_ct_text_list_style_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.other_style {
+        ooxml_dml::visit::VisitMut::visit_ct_text_list_style_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.ext_lst {
+        VisitMut::visit_ct_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_slide_relationship_list<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTSlideRelationshipList,
+) {
+    for it0 in &node.sld {
+        Visit::visit_ct_slide_relationship_list_entry(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_slide_relationship_list_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTSlideRelationshipList,
+) {
+    for it0 in &mut node.sld {
+        VisitMut::visit_ct_slide_relationship_list_entry_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_slide_relationship_list_entry<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTSlideRelationshipListEntry,
+) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_slide_relationship_list_entry_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTSlideRelationshipListEntry,
+) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_slide_size<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTSlideSize) {
+    if let Some(it0) = &node.r#type {
+        Visit::visit_st_slide_size_type(v, it0);
+    }
+}
+
+pub fn walk_ct_slide_size_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSlideSize) {
+    if let Some(it0) = &mut node.r#type {
+        VisitMut::visit_st_slide_size_type_mut(v, it0);
+    }
+}
+
+pub fn walk_ct_slide_sorter_view_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTSlideSorterViewProperties,
+) {
+    Visit::visit_ct_common_view_properties(v, &node.c_view_pr);
+    if let Some(it0) = &node.ext_lst {
+        Visit::visit_ct_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_slide_sorter_view_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTSlideSorterViewProperties,
+) {
+    VisitMut::visit_ct_common_view_properties_mut(v, &mut node.c_view_pr);
+    if let Some(it0) = &mut node.ext_lst {
+        VisitMut::visit_ct_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_slide_sync_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTSlideSyncProperties,
+) {
+    if let Some(it0) = &node.ext_lst {
+        Visit::visit_ct_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_slide_sync_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTSlideSyncProperties,
+) {
+    if let Some(it0) = &mut node.ext_lst {
+        VisitMut::visit_ct_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_slide_view_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTSlideViewProperties,
+) {
+    Visit::visit_ct_common_slide_view_properties(v, &node.c_sld_view_pr);
+    if let Some(it0) = &node.ext_lst {
+        Visit::visit_ct_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_slide_view_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTSlideViewProperties,
+) {
+    VisitMut::visit_ct_common_slide_view_properties_mut(v, &mut node.c_sld_view_pr);
+    if let Some(it0) = &mut node.ext_lst {
+        VisitMut::visit_ct_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_smart_tags<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTSmartTags) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_smart_tags_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSmartTags) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_split_transition<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTSplitTransition,
+) {
+    if let Some(it0) = &node.orient {
+        Visit::visit_st_direction(v, it0);
+    }
+    if let Some(it0) = &node.dir {
+        Visit::visit_st_transition_in_out_direction_type(v, it0);
+    }
+}
+
+pub fn walk_ct_split_transition_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSplitTransition) {
+    if let Some(it0) = &mut node.orient {
+        VisitMut::visit_st_direction_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.dir {
+        VisitMut::visit_st_transition_in_out_direction_type_mut(v, it0);
+    }
+}
+
+pub fn walk_cttl_anim_variant<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLAnimVariant,
+) {
+    if let Some(it0) = &node.bool_val {
+        Visit::visit_cttl_anim_variant_boolean_val(v, it0);
+    }
+    if let Some(it0) = &node.int_val {
+        Visit::visit_cttl_anim_variant_integer_val(v, it0);
+    }
+    if let Some(it0) = &node.flt_val {
+        Visit::visit_cttl_anim_variant_float_val(v, it0);
+    }
+    if let Some(it0) = &node.str_val {
+        Visit::visit_cttl_anim_variant_string_val(v, it0);
+    }
+    if let Some(it0) = &node.clr_val {
+        ooxml_dml::visit::Visit::visit_ct_color(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_anim_variant_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTLAnimVariant) {
+    if let Some(it0) = &mut node.bool_val {
+        VisitMut::visit_cttl_anim_variant_boolean_val_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.int_val {
+        VisitMut::visit_cttl_anim_variant_integer_val_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.flt_val {
+        VisitMut::visit_cttl_anim_variant_float_val_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.str_val {
+        VisitMut::visit_cttl_anim_variant_string_val_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.clr_val {
+        ooxml_dml::visit::VisitMut::visit_ct_color_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_animate_behavior<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLAnimateBehavior,
+) {
+    if let Some(it0) = &node.calcmode {
+        Visit::visit_sttl_animate_behavior_calc_mode(v, it0);
+    }
+    if let Some(it0) = &node.value_type {
+        Visit::visit_sttl_animate_behavior_value_type(v, it0);
+    }
+    Visit::visit_cttl_common_behavior_data(v, &node.c_bhvr);
+    if let Some(it0) = &node.tav_lst {
+        Visit::visit_cttl_time_animate_value_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_animate_behavior_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTLAnimateBehavior,
+) {
+    if let Some(it0) = &mut node.calcmode {
+        VisitMut::visit_sttl_animate_behavior_calc_mode_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.value_type {
+        VisitMut::visit_sttl_animate_behavior_value_type_mut(v, it0);
+    }
+    VisitMut::visit_cttl_common_behavior_data_mut(v, &mut node.c_bhvr);
+    if let Some(it0) = &mut node.tav_lst {
+        VisitMut::visit_cttl_time_animate_value_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_animate_color_behavior<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLAnimateColorBehavior,
+) {
+    if let Some(it0) = &node.clr_spc {
+        Visit::visit_sttl_animate_color_space(v, it0);
+    }
+    if let Some(it0) = &node.dir {
+        Visit::visit_sttl_animate_color_direction(v, it0);
+    }
+    Visit::visit_cttl_common_behavior_data(v, &node.c_bhvr);
+    if let Some(it0) = &node.by {
+        Visit::visit_cttl_by_animate_color_transform(v, it0);
+    }
+    if let Some(it0) = &node.from {
+        ooxml_dml::visit::Visit::visit_ct_color(v, it0);
+    }
+    if let Some(it0) = &node.to {
+        ooxml_dml::visit::Visit::visit_ct_color(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_animate_color_behavior_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTLAnimateColorBehavior,
+) {
+    if let Some(it0) = &mut node.clr_spc {
+        VisitMut::visit_sttl_animate_color_space_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.dir {
+        VisitMut::visit_sttl_animate_color_direction_mut(v, it0);
+    }
+    VisitMut::visit_cttl_common_behavior_data_mut(v, &mut node.c_bhvr);
+    if let Some(it0) = &mut node.by {
+        VisitMut::visit_cttl_by_animate_color_transform_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.from {
+        ooxml_dml::visit::VisitMut::visit_ct_color_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.to {
+        ooxml_dml::visit::VisitMut::visit_ct_color_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_animate_effect_behavior<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLAnimateEffectBehavior,
+) {
+    if let Some(it0) = &node.transition {
+        Visit::visit_sttl_animate_effect_transition(v, it0);
+    }
+    Visit::visit_cttl_common_behavior_data(v, &node.c_bhvr);
+    if let Some(it0) = &node.progress {
+        Visit::visit_cttl_anim_variant(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_animate_effect_behavior_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTLAnimateEffectBehavior,
+) {
+    if let Some(it0) = &mut node.transition {
+        VisitMut::visit_sttl_animate_effect_transition_mut(v, it0);
+    }
+    VisitMut::visit_cttl_common_behavior_data_mut(v, &mut node.c_bhvr);
+    if let Some(it0) = &mut node.progress {
+        VisitMut::visit_cttl_anim_variant_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_animate_motion_behavior<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLAnimateMotionBehavior,
+) {
+    if let Some(it0) = &node.origin {
+        Visit::visit_sttl_animate_motion_behavior_origin(v, it0);
+    }
+    if let Some(it0) = &node.path_edit_mode {
+        Visit::visit_sttl_animate_motion_path_edit_mode(v, it0);
+    }
+    Visit::visit_cttl_common_behavior_data(v, &node.c_bhvr);
+    if let Some(it0) = &node.by {
+        Visit::visit_cttl_point(v, it0);
+    }
+    if let Some(it0) = &node.from {
+        Visit::visit_cttl_point(v, it0);
+    }
+    if let Some(it0) = &node.to {
+        Visit::visit_cttl_point(v, it0);
+    }
+    if let Some(it0) = &node.r_ctr {
+        Visit::visit_cttl_point(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_animate_motion_behavior_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTLAnimateMotionBehavior,
+) {
+    if let Some(it0) = &mut node.origin {
+        VisitMut::visit_sttl_animate_motion_behavior_origin_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.path_edit_mode {
+        VisitMut::visit_sttl_animate_motion_path_edit_mode_mut(v, it0);
+    }
+    VisitMut::visit_cttl_common_behavior_data_mut(v, &mut node.c_bhvr);
+    if let Some(it0) = &mut node.by {
+        VisitMut::visit_cttl_point_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.from {
+        VisitMut::visit_cttl_point_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.to {
+        VisitMut::visit_cttl_point_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.r_ctr {
+        VisitMut::visit_cttl_point_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_animate_rotation_behavior<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLAnimateRotationBehavior,
+) {
+    Visit::visit_cttl_common_behavior_data(v, &node.c_bhvr);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_animate_rotation_behavior_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTLAnimateRotationBehavior,
+) {
+    VisitMut::visit_cttl_common_behavior_data_mut(v, &mut node.c_bhvr);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_animate_scale_behavior<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLAnimateScaleBehavior,
+) {
+    Visit::visit_cttl_common_behavior_data(v, &node.c_bhvr);
+    if let Some(it0) = &node.by {
+        Visit::visit_cttl_point(v, it0);
+    }
+    if let Some(it0) = &node.from {
+        Visit::visit_cttl_point(v, it0);
+    }
+    if let Some(it0) = &node.to {
+        Visit::visit_cttl_point(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_animate_scale_behavior_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTLAnimateScaleBehavior,
+) {
+    VisitMut::visit_cttl_common_behavior_data_mut(v, &mut node.c_bhvr);
+    if let Some(it0) = &mut node.by {
+        VisitMut::visit_cttl_point_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.from {
+        VisitMut::visit_cttl_point_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.to {
+        VisitMut::visit_cttl_point_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_behavior_attribute_name_list<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLBehaviorAttributeNameList,
+) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_behavior_attribute_name_list_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTLBehaviorAttributeNameList,
+) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_build_diagram<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLBuildDiagram,
+) {
+    if let Some(it0) = &node.bld {
+        Visit::visit_sttl_diagram_build_type(v, it0);
+    }
+}
+
+pub fn walk_cttl_build_diagram_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTLBuildDiagram) {
+    if let Some(it0) = &mut node.bld {
+        VisitMut::visit_sttl_diagram_build_type_mut(v, it0);
+    }
+}
+
+pub fn walk_cttl_build_paragraph<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLBuildParagraph,
+) {
+    if let Some(it0) = &node.build {
+        Visit::visit_sttl_para_build_type(v, it0);
+    }
+    if let Some(it0) = &node.tmpl_lst {
+        Visit::visit_cttl_template_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_build_paragraph_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTLBuildParagraph,
+) {
+    if let Some(it0) = &mut node.build {
+        VisitMut::visit_sttl_para_build_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.tmpl_lst {
+        VisitMut::visit_cttl_template_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_by_animate_color_transform<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLByAnimateColorTransform,
+) {
+    if let Some(it0) = &node.rgb {
+        Visit::visit_cttl_by_rgb_color_transform(v, it0);
+    }
+    if let Some(it0) = &node.hsl {
+        Visit::visit_cttl_by_hsl_color_transform(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_by_animate_color_transform_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTLByAnimateColorTransform,
+) {
+    if let Some(it0) = &mut node.rgb {
+        VisitMut::visit_cttl_by_rgb_color_transform_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.hsl {
+        VisitMut::visit_cttl_by_hsl_color_transform_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_command_behavior<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLCommandBehavior,
+) {
+    if let Some(it0) = &node.r#type {
+        Visit::visit_sttl_command_type(v, it0);
+    }
+    Visit::visit_cttl_common_behavior_data(v, &node.c_bhvr);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_command_behavior_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTLCommandBehavior,
+) {
+    if let Some(it0) = &mut node.r#type {
+        VisitMut::visit_sttl_command_type_mut(v, it0);
+    }
+    VisitMut::visit_cttl_common_behavior_data_mut(v, &mut node.c_bhvr);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_common_behavior_data<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLCommonBehaviorData,
+) {
+    if let Some(it0) = &node.additive {
+        Visit::visit_sttl_behavior_additive_type(v, it0);
+    }
+    if let Some(it0) = &node.accumulate {
+        Visit::visit_sttl_behavior_accumulate_type(v, it0);
+    }
+    if let Some(it0) = &node.xfrm_type {
+        Visit::visit_sttl_behavior_transform_type(v, it0);
+    }
+    if let Some(it0) = &node.r#override {
+        Visit::visit_sttl_behavior_override_type(v, it0);
+    }
+    Visit::visit_cttl_common_time_node_data(v, &node.c_tn);
+    Visit::visit_cttl_time_target_element(v, &node.tgt_el);
+    if let Some(it0) = &node.attr_name_lst {
+        Visit::visit_cttl_behavior_attribute_name_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_common_behavior_data_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTLCommonBehaviorData,
+) {
+    if let Some(it0) = &mut node.additive {
+        VisitMut::visit_sttl_behavior_additive_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.accumulate {
+        VisitMut::visit_sttl_behavior_accumulate_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.xfrm_type {
+        VisitMut::visit_sttl_behavior_transform_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.r#override {
+        VisitMut::visit_sttl_behavior_override_type_mut(v, it0);
+    }
+    VisitMut::visit_cttl_common_time_node_data_mut(v, &mut node.c_tn);
+    VisitMut::visit_cttl_time_target_element_mut(v, &mut node.tgt_el);
+    if let Some(it0) = &mut node.attr_name_lst {
+        VisitMut::visit_cttl_behavior_attribute_name_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_common_media_node_data<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLCommonMediaNodeData,
+) {
+    Visit::visit_cttl_common_time_node_data(v, &node.c_tn);
+    Visit::visit_cttl_time_target_element(v, &node.tgt_el);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_common_media_node_data_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTLCommonMediaNodeData,
+) {
+    VisitMut::visit_cttl_common_time_node_data_mut(v, &mut node.c_tn);
+    VisitMut::visit_cttl_time_target_element_mut(v, &mut node.tgt_el);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_common_time_node_data<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLCommonTimeNodeData,
+) {
+    if let Some(it0) = &node.preset_class {
+        Visit::visit_sttl_time_node_preset_class_type(v, it0);
+    }
+    if let Some(it0) = &node.restart {
+        Visit::visit_sttl_time_node_restart_type(v, it0);
+    }
+    if let Some(it0) = &node.fill {
+        Visit::visit_sttl_time_node_fill_type(v, it0);
+    }
+    if let Some(it0) = &node.sync_behavior {
+        Visit::visit_sttl_time_node_sync_type(v, it0);
+    }
+    if let Some(it0) = &node.master_rel {
+        Visit::visit_sttl_time_node_master_relation(v, it0);
+    }
+    if let Some(it0) = &node.node_type {
+        Visit::visit_sttl_time_node_type(v, it0);
+    }
+    if let Some(it0) = &node.st_cond_lst {
+        Visit::visit_cttl_time_condition_list(v, it0);
+    }
+    if let Some(it0) = &node.end_cond_lst {
+        Visit::visit_cttl_time_condition_list(v, it0);
+    }
+    if let Some(it0) = &node.end_sync {
+        Visit::visit_cttl_time_condition(v, it0);
+    }
+    if let Some(it0) = &node.iterate {
+        Visit::visit_cttl_iterate_data(v, it0);
+    }
+    if let Some(it0) = &node.child_tn_lst {
+        Visit::visit_ct_time_node_list(v, it0);
+    }
+    if let Some(it0) = &node.sub_tn_lst {
+        Visit::visit_ct_time_node_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_common_time_node_data_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTLCommonTimeNodeData,
+) {
+    if let Some(it0) = &mut node.preset_class {
+        VisitMut::visit_sttl_time_node_preset_class_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.restart {
+        VisitMut::visit_sttl_time_node_restart_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.fill {
+        VisitMut::visit_sttl_time_node_fill_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.sync_behavior {
+        VisitMut::visit_sttl_time_node_sync_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.master_rel {
+        VisitMut::visit_sttl_time_node_master_relation_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.node_type {
+        VisitMut::visit_sttl_time_node_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.st_cond_lst {
+        VisitMut::visit_cttl_time_condition_list_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.end_cond_lst {
+        VisitMut::visit_cttl_time_condition_list_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.end_sync {
+        VisitMut::visit_cttl_time_condition_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.iterate {
+        VisitMut::visit_cttl_iterate_data_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.child_tn_lst {
+        VisitMut::visit_ct_time_node_list_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.sub_tn_lst {
+        VisitMut::visit_ct_time_node_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_graphical_object_build<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLGraphicalObjectBuild,
+) {
+    if let Some(it0) = &node.bld_as_one {
+        Visit::visit_ct_empty(v, it0);
+    }
+    if let Some(it0) = &node.bld_sub {
+        ooxml_dml::visit::Visit::visit_ct_animation_graphical_object_build_properties(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_graphical_object_build_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTLGraphicalObjectBuild,
+) {
+    if let Some(it0) = &mut node.bld_as_one {
+        VisitMut::visit_ct_empty_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.bld_sub {
+        ooxml_dml::visit::VisitMut::visit_ct_animation_graphical_object_build_properties_mut(
+            v, it0,
+        );
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_iterate_data<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLIterateData,
+) {
+    if let Some(it0) = &node.r#type {
+        Visit::visit_st_iterate_type(v, it0);
+    }
+    if let Some(it0) = &node.tm_abs {
+        Visit::visit_cttl_iterate_interval_time(v, it0);
+    }
+    if let Some(it0) = &node.tm_pct {
+        Visit::visit_cttl_iterate_interval_percentage(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_iterate_data_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTLIterateData) {
+    if let Some(it0) = &mut node.r#type {
+        VisitMut::visit_st_iterate_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.tm_abs {
+        VisitMut::visit_cttl_iterate_interval_time_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.tm_pct {
+        VisitMut::visit_cttl_iterate_interval_percentage_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_media_node_audio<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLMediaNodeAudio,
+) {
+    Visit::visit_cttl_common_media_node_data(v, &node.c_media_node);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_media_node_audio_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTLMediaNodeAudio,
+) {
+    VisitMut::visit_cttl_common_media_node_data_mut(v, &mut node.c_media_node);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_media_node_video<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLMediaNodeVideo,
+) {
+    Visit::visit_cttl_common_media_node_data(v, &node.c_media_node);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_media_node_video_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTLMediaNodeVideo,
+) {
+    VisitMut::visit_cttl_common_media_node_data_mut(v, &mut node.c_media_node);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_ole_build_chart<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLOleBuildChart,
+) {
+    if let Some(it0) = &node.bld {
+        Visit::visit_sttl_ole_chart_build_type(v, it0);
+    }
+}
+
+pub fn walk_cttl_ole_build_chart_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTLOleBuildChart,
+) {
+    if let Some(it0) = &mut node.bld {
+        VisitMut::visit_sttl_ole_chart_build_type_mut(v, it0);
+    }
+}
+
+pub fn walk_cttl_ole_chart_target_element<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLOleChartTargetElement,
+) {
+    Visit::visit_sttl_chart_subelement_type(v, &node.r#type);
+}
+
+pub fn walk_cttl_ole_chart_target_element_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTLOleChartTargetElement,
+) {
+    VisitMut::visit_sttl_chart_subelement_type_mut(v, &mut node.r#type);
+}
+
+pub fn walk_cttl_set_behavior<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLSetBehavior,
+) {
+    Visit::visit_cttl_common_behavior_data(v, &node.c_bhvr);
+    if let Some(it0) = &node.to {
+        Visit::visit_cttl_anim_variant(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_set_behavior_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTLSetBehavior) {
+    VisitMut::visit_cttl_common_behavior_data_mut(v, &mut node.c_bhvr);
+    if let Some(it0) = &mut node.to {
+        VisitMut::visit_cttl_anim_variant_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_shape_target_element<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLShapeTargetElement,
+) {
+    if let Some(it0) = &node.bg {
+        Visit::visit_ct_empty(v, it0);
+    }
+    if let Some(it0) = &node.sub_sp {
+        Visit::visit_cttl_sub_shape_id(v, it0);
+    }
+    if let Some(it0) = &node.ole_chart_el {
+        Visit::visit_cttl_ole_chart_target_element(v, it0);
+    }
+    if let Some(it0) = &node.tx_el {
+        Visit::visit_cttl_text_target_element(v, it0);
+    }
+    if let Some(it0) = &node.graphic_el {
+        ooxml_dml::visit::Visit::visit_ct_animation_element_choice(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_shape_target_element_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTLShapeTargetElement,
+) {
+    if let Some(it0) = &mut node.bg {
+        VisitMut::visit_ct_empty_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.sub_sp {
+        VisitMut::visit_cttl_sub_shape_id_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.ole_chart_el {
+        VisitMut::visit_cttl_ole_chart_target_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.tx_el {
+        VisitMut::visit_cttl_text_target_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.graphic_el {
+        ooxml_dml::visit::VisitMut::visit_ct_animation_element_choice_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_template<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTTLTemplate) {
+    Visit::visit_ct_time_node_list(v, &node.tn_lst);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_template_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTLTemplate) {
+    VisitMut::visit_ct_time_node_list_mut(v, &mut node.tn_lst);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_template_list<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLTemplateList,
+) {
+    for it0 in &node.tmpl {
+        Visit::visit_cttl_template(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_template_list_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTLTemplateList) {
+    for it0 in &mut node.tmpl {
+        VisitMut::visit_cttl_template_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_text_target_element<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLTextTargetElement,
+) {
+    if let Some(it0) = &node.char_rg {
+        Visit::visit_ct_index_range(v, it0);
+    }
+    if let Some(it0) = &node.p_rg {
+        Visit::visit_ct_index_range(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_text_target_element_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTLTextTargetElement,
+) {
+    if let Some(it0) = &mut node.char_rg {
+        VisitMut::visit_ct_index_range_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.p_rg {
+        VisitMut::visit_ct_index_range_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_time_animate_value<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLTimeAnimateValue,
+) {
+    if let Some(it0) = &node.value {
+        Visit::visit_cttl_anim_variant(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_time_animate_value_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTLTimeAnimateValue,
+) {
+    if let Some(it0) = &mut node.value {
+        VisitMut::visit_cttl_anim_variant_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_time_animate_value_list<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLTimeAnimateValueList,
+) {
+    for it0 in &node.tav {
+        Visit::visit_cttl_time_animate_value(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_time_animate_value_list_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTLTimeAnimateValueList,
+) {
+    for it0 in &mut node.tav {
+        VisitMut::visit_cttl_time_animate_value_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_time_condition<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLTimeCondition,
+) {
+    if let Some(it0) = &node.evt {
+        Visit::visit_sttl_trigger_event(v, it0);
+    }
+    if let Some(it0) = &node.tgt_el {
+        Visit::visit_cttl_time_target_element(v, it0);
+    }
+    if let Some(it0) = &node.tn {
+        Visit::visit_cttl_trigger_time_node_id(v, it0);
+    }
+    if let Some(it0) = &node.rtn {
+        Visit::visit_cttl_trigger_runtime_node(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_time_condition_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTLTimeCondition) {
+    if let Some(it0) = &mut node.evt {
+        VisitMut::visit_sttl_trigger_event_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.tgt_el {
+        VisitMut::visit_cttl_time_target_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.tn {
+        VisitMut::visit_cttl_trigger_time_node_id_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.rtn {
+        VisitMut::visit_cttl_trigger_runtime_node_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_time_condition_list<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLTimeConditionList,
+) {
+    for it0 in &node.cond {
+        Visit::visit_cttl_time_condition(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_time_condition_list_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTLTimeConditionList,
+) {
+    for it0 in &mut node.cond {
+        VisitMut::visit_cttl_time_condition_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_time_node_sequence<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLTimeNodeSequence,
+) {
+    if let Some(it0) = &node.prev_ac {
+        Visit::visit_sttl_previous_action_type(v, it0);
+    }
+    if let Some(it0) = &node.next_ac {
+        Visit::visit_sttl_next_action_type(v, it0);
+    }
+    Visit::visit_cttl_common_time_node_data(v, &node.c_tn);
+    if let Some(it0) = &node.prev_cond_lst {
+        Visit::visit_cttl_time_condition_list(v, it0);
+    }
+    if let Some(it0) = &node.next_cond_lst {
+        Visit::visit_cttl_time_condition_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_time_node_sequence_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTLTimeNodeSequence,
+) {
+    if let Some(it0) = &mut node.prev_ac {
+        VisitMut::visit_sttl_previous_action_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.next_ac {
+        VisitMut::visit_sttl_next_action_type_mut(v, it0);
+    }
+    VisitMut::visit_cttl_common_time_node_data_mut(v, &mut node.c_tn);
+    if let Some(it0) = &mut node.prev_cond_lst {
+        VisitMut::visit_cttl_time_condition_list_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.next_cond_lst {
+        VisitMut::visit_cttl_time_condition_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_time_target_element<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLTimeTargetElement,
+) {
+    if let Some(it0) = &node.sld_tgt {
+        Visit::visit_ct_empty(v, it0);
+    }
+    if let Some(it0) = &node.snd_tgt {
+        ooxml_dml::visit::Visit::visit_ct_embedded_wav_audio_file(v, it0);
+    }
+    if let Some(it0) = &node.sp_tgt {
+        Visit::visit_cttl_shape_target_element(v, it0);
+    }
+    if let Some(it0) = &node.ink_tgt {
+        Visit::visit_cttl_sub_shape_id(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_time_target_element_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTLTimeTargetElement,
+) {
+    if let Some(it0) = &mut node.sld_tgt {
+        VisitMut::visit_ct_empty_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.snd_tgt {
+        ooxml_dml::visit::VisitMut::visit_ct_embedded_wav_audio_file_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.sp_tgt {
+        VisitMut::visit_cttl_shape_target_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.ink_tgt {
+        VisitMut::visit_cttl_sub_shape_id_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cttl_trigger_runtime_node<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTLTriggerRuntimeNode,
+) {
+    Visit::visit_sttl_trigger_runtime_node(v, &node.value);
+}
+
+pub fn walk_cttl_trigger_runtime_node_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTLTriggerRuntimeNode,
+) {
+    VisitMut::visit_sttl_trigger_runtime_node_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_tag_list<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTTagList) {
+    for it0 in &node.tag {
+        Visit::visit_ct_string_tag(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tag_list_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTagList) {
+    for it0 in &mut node.tag {
+        VisitMut::visit_ct_string_tag_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tags_data<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTTagsData) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tags_data_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTagsData) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_time_node_list<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTimeNodeList,
+) {
+    for it0 in &node.par {
+        Visit::visit_cttl_common_time_node_data(v, it0);
+    }
+    for it0 in &node.seq {
+        Visit::visit_cttl_time_node_sequence(v, it0);
+    }
+    for it0 in &node.excl {
+        Visit::visit_cttl_common_time_node_data(v, it0);
+    }
+    for it0 in &node.anim {
+        Visit::visit_cttl_animate_behavior(v, it0);
+    }
+    for it0 in &node.anim_clr {
+        Visit::visit_cttl_animate_color_behavior(v, it0);
+    }
+    for it0 in &node.anim_effect {
+        Visit::visit_cttl_animate_effect_behavior(v, it0);
+    }
+    for it0 in &node.anim_motion {
+        Visit::visit_cttl_animate_motion_behavior(v, it0);
+    }
+    for it0 in &node.anim_rot {
+        Visit::visit_cttl_animate_rotation_behavior(v, it0);
+    }
+    for it0 in &node.anim_scale {
+        Visit::visit_cttl_animate_scale_behavior(v, it0);
+    }
+    for it0 in &node.cmd {
+        Visit::visit_cttl_command_behavior(v, it0);
+    }
+    for it0 in &node.set {
+        Visit::visit_cttl_set_behavior(v, it0);
+    }
+    for it0 in &node.audio {
+        Visit::visit_cttl_media_node_audio(v, it0);
+    }
+    for it0 in &node.video {
+        Visit::visit_cttl_media_node_video(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_time_node_list_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTimeNodeList) {
+    for it0 in &mut node.par {
+        VisitMut::visit_cttl_common_time_node_data_mut(v, it0);
+    }
+    for it0 in &mut node.seq {
+        VisitMut::visit_cttl_time_node_sequence_mut(v, it0);
+    }
+    for it0 in &mut node.excl {
+        VisitMut::visit_cttl_common_time_node_data_mut(v, it0);
+    }
+    for it0 in &mut node.anim {
+        VisitMut::visit_cttl_animate_behavior_mut(v, it0);
+    }
+    for it0 in &mut node.anim_clr {
+        VisitMut::visit_cttl_animate_color_behavior_mut(v, it0);
+    }
+    for it0 in &mut node.anim_effect {
+        VisitMut::visit_cttl_animate_effect_behavior_mut(v, it0);
+    }
+    for it0 in &mut node.anim_motion {
+        VisitMut::visit_cttl_animate_motion_behavior_mut(v, it0);
+    }
+    for it0 in &mut node.anim_rot {
+        VisitMut::visit_cttl_animate_rotation_behavior_mut(v, it0);
+    }
+    for it0 in &mut node.anim_scale {
+        VisitMut::visit_cttl_animate_scale_behavior_mut(v, it0);
+    }
+    for it0 in &mut node.cmd {
+        VisitMut::visit_cttl_command_behavior_mut(v, it0);
+    }
+    for it0 in &mut node.set {
+        VisitMut::visit_cttl_set_behavior_mut(v, it0);
+    }
+    for it0 in &mut node.audio {
+        VisitMut::visit_cttl_media_node_audio_mut(v, it0);
+    }
+    for it0 in &mut node.video {
+        VisitMut::visit_cttl_media_node_video_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_transition_sound_action<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTransitionSoundAction,
+) {
+    if let Some(it0) = &node.st_snd {
+        Visit::visit_ct_transition_start_sound_action(v, it0);
+    }
+    if let Some(it0) = &node.end_snd {
+        Visit::visit_ct_empty(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_transition_sound_action_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTransitionSoundAction,
+) {
+    if let Some(it0) = &mut node.st_snd {
+        VisitMut::visit_ct_transition_start_sound_action_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.end_snd {
+        VisitMut::visit_ct_empty_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_transition_start_sound_action<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTransitionStartSoundAction,
+) {
+    ooxml_dml::visit::Visit::visit_ct_embedded_wav_audio_file(v, &node.snd);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_transition_start_sound_action_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTransitionStartSoundAction,
+) {
+    ooxml_dml::visit::VisitMut::visit_ct_embedded_wav_audio_file_mut(v, &mut node.snd);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_view_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTViewProperties,
+) {
+    if let Some(it0) = &node.last_view {
+        Visit::visit_st_view_type(v, it0);
+    }
+    if let Some(it0) = &node.normal_view_pr {
+        Visit::visit_ct_normal_view_properties(v, it0);
+    }
+    if let Some(it0) = &node.slide_view_pr {
+        Visit::visit_ct_slide_view_properties(v, it0);
+    }
+    if let Some(it0) = &node.outline_view_pr {
+        Visit::visit_ct_outline_view_properties(v, it0);
+    }
+    #[cfg(feature = "pml-notes")]
+    {
+        if let Some(it0) = &node.notes_text_view_pr {
+            Visit::visit_ct_notes_text_view_properties(v, it0);
+        }
+    }
+    if let Some(it0) = &node.sorter_view_pr {
+        Visit::visit_ct_slide_sorter_view_properties(v, it0);
+    }
+    #[cfg(feature = "pml-notes")]
+    {
+        if let Some(it0) = &node.notes_view_pr {
+            Visit::visit_ct_notes_view_properties(v, it0);
+        }
+    }
+    if let Some(it0) = &node.grid_spacing {
+        ooxml_dml::visit::Visit::visit_positive_size2_d(v, it0);
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &node.ext_lst {
+            Visit::visit_ct_extension_list(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_view_properties_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTViewProperties) {
+    if let Some(it0) = &mut node.last_view {
+        VisitMut::visit_st_view_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.normal_view_pr {
+        VisitMut::visit_ct_normal_view_properties_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.slide_view_pr {
+        VisitMut::visit_ct_slide_view_properties_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.outline_view_pr {
+        VisitMut::visit_ct_outline_view_properties_mut(v, it0);
+    }
+    #[cfg(feature = "pml-notes")]
+    {
+        if let Some(it0) = &mut node.notes_text_view_pr {
+            VisitMut::visit_ct_notes_text_view_properties_mut(v, it0);
+        }
+    }
+    if let Some(it0) = &mut node.sorter_view_pr {
+        VisitMut::visit_ct_slide_sorter_view_properties_mut(v, it0);
+    }
+    #[cfg(feature = "pml-notes")]
+    {
+        if let Some(it0) = &mut node.notes_view_pr {
+            VisitMut::visit_ct_notes_view_properties_mut(v, it0);
+        }
+    }
+    if let Some(it0) = &mut node.grid_spacing {
+        ooxml_dml::visit::VisitMut::visit_positive_size2_d_mut(v, it0);
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &mut node.ext_lst {
+            VisitMut::visit_ct_extension_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_web_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTWebProperties,
+) {
+    if let Some(it0) = &node.img_sz {
+        Visit::visit_st_web_screen_size(v, it0);
+    }
+    if let Some(it0) = &node.clr {
+        Visit::visit_st_web_color_type(v, it0);
+    }
+    if let Some(it0) = &node.ext_lst {
+        Visit::visit_ct_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_web_properties_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTWebProperties) {
+    if let Some(it0) = &mut node.img_sz {
+        VisitMut::visit_st_web_screen_size_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.clr {
+        VisitMut::visit_st_web_color_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.ext_lst {
+        VisitMut::visit_ct_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_common_slide_data<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CommonSlideData,
+) {
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &node.bg {
+            Visit::visit_ct_background(v, it0);
+        }
+    }
+    Visit::visit_group_shape(v, &node.shape_tree);
+    #[cfg(feature = "pml-external")]
+    {
+        if let Some(it0) = &node.cust_data_lst {
+            Visit::visit_ct_customer_data_list(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-external")]
+    {
+        if let Some(it0) = &node.controls {
+            Visit::visit_ct_control_list(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &node.ext_lst {
+            Visit::visit_ct_extension_list(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_common_slide_data_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CommonSlideData) {
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &mut node.bg {
+            VisitMut::visit_ct_background_mut(v, it0);
+        }
+    }
+    VisitMut::visit_group_shape_mut(v, &mut node.shape_tree);
+    #[cfg(feature = "pml-external")]
+    {
+        if let Some(it0) = &mut node.cust_data_lst {
+            VisitMut::visit_ct_customer_data_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-external")]
+    {
+        if let Some(it0) = &mut node.controls {
+            VisitMut::visit_ct_control_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &mut node.ext_lst {
+            VisitMut::visit_ct_extension_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_connector<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Connector) {
+    Visit::visit_ct_connector_non_visual(v, &node.non_visual_connector_properties);
+    ooxml_dml::visit::Visit::visit_ct_shape_properties(v, &node.shape_properties);
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &node.style {
+            ooxml_dml::visit::Visit::visit_shape_style(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &node.ext_lst {
+            Visit::visit_ct_extension_list_modify(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_connector_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Connector) {
+    VisitMut::visit_ct_connector_non_visual_mut(v, &mut node.non_visual_connector_properties);
+    ooxml_dml::visit::VisitMut::visit_ct_shape_properties_mut(v, &mut node.shape_properties);
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &mut node.style {
+            ooxml_dml::visit::VisitMut::visit_shape_style_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &mut node.ext_lst {
+            VisitMut::visit_ct_extension_list_modify_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_eg_background<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast EGBackground) {
+    match node {
+        EGBackground::BgPr(it) => {
+            Visit::visit_ct_background_properties(v, it);
+        }
+        EGBackground::BgRef(it) => {
+            ooxml_dml::visit::Visit::visit_ct_style_matrix_reference(v, it);
+        }
+    }
+}
+
+pub fn walk_eg_background_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut EGBackground) {
+    match node {
+        EGBackground::BgPr(it) => {
+            VisitMut::visit_ct_background_properties_mut(v, it);
+        }
+        EGBackground::BgRef(it) => {
+            ooxml_dml::visit::VisitMut::visit_ct_style_matrix_reference_mut(v, it);
+        }
+    }
+}
+
+pub fn walk_eg_child_slide<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast EGChildSlide) {
+    if let Some(it0) = &node.clr_map_ovr {
+        ooxml_dml::visit::Visit::visit_ct_color_mapping_override(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_eg_child_slide_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut EGChildSlide) {
+    if let Some(it0) = &mut node.clr_map_ovr {
+        ooxml_dml::visit::VisitMut::visit_ct_color_mapping_override_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_eg_extension_list<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast EGExtensionList,
+) {
+    for it0 in &node.ext {
+        Visit::visit_ct_extension(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_eg_extension_list_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut EGExtensionList) {
+    for it0 in &mut node.ext {
+        VisitMut::visit_ct_extension_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_eg_show_type<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast EGShowType) {
+    match node {
+        EGShowType::Present(it) => {
+            Visit::visit_ct_empty(v, it);
+        }
+        EGShowType::Browse(it) => {
+            Visit::visit_ct_show_info_browse(v, it);
+        }
+        EGShowType::Kiosk(it) => {
+            Visit::visit_ct_show_info_kiosk(v, it);
+        }
+    }
+}
+
+pub fn walk_eg_show_type_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut EGShowType) {
+    match node {
+        EGShowType::Present(it) => {
+            VisitMut::visit_ct_empty_mut(v, it);
+        }
+        EGShowType::Browse(it) => {
+            VisitMut::visit_ct_show_info_browse_mut(v, it);
+        }
+        EGShowType::Kiosk(it) => {
+            VisitMut::visit_ct_show_info_kiosk_mut(v, it);
+        }
+    }
+}
+
+pub fn walk_eg_slide_list_choice<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast EGSlideListChoice,
+) {
+    match node {
+        EGSlideListChoice::SldAll(it) => {
+            Visit::visit_ct_empty(v, it);
+        }
+        EGSlideListChoice::SldRg(it) => {
+            Visit::visit_ct_index_range(v, it);
+        }
+        EGSlideListChoice::CustShow(it) => {
+            Visit::visit_ct_custom_show_id(v, it);
+        }
+    }
+}
+
+pub fn walk_eg_slide_list_choice_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut EGSlideListChoice,
+) {
+    match node {
+        EGSlideListChoice::SldAll(it) => {
+            VisitMut::visit_ct_empty_mut(v, it);
+        }
+        EGSlideListChoice::SldRg(it) => {
+            VisitMut::visit_ct_index_range_mut(v, it);
+        }
+        EGSlideListChoice::CustShow(it) => {
+            VisitMut::visit_ct_custom_show_id_mut(v, it);
+        }
+    }
+}
+
+pub fn walk_graphical_object_frame<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast GraphicalObjectFrame,
+) {
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &node.bw_mode {
+            ooxml_dml::visit::Visit::visit_st_black_white_mode(v, it0);
+        }
+    }
+    Visit::visit_ct_graphical_object_frame_non_visual(v, &node.nv_graphic_frame_pr);
+    ooxml_dml::visit::Visit::visit_transform2_d(v, &node.xfrm);
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &node.ext_lst {
+            Visit::visit_ct_extension_list_modify(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_graphical_object_frame_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut GraphicalObjectFrame,
+) {
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &mut node.bw_mode {
+            ooxml_dml::visit::VisitMut::visit_st_black_white_mode_mut(v, it0);
+        }
+    }
+    VisitMut::visit_ct_graphical_object_frame_non_visual_mut(v, &mut node.nv_graphic_frame_pr);
+    ooxml_dml::visit::VisitMut::visit_transform2_d_mut(v, &mut node.xfrm);
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &mut node.ext_lst {
+            VisitMut::visit_ct_extension_list_modify_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_group_shape<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast GroupShape) {
+    Visit::visit_ct_group_shape_non_visual(v, &node.non_visual_group_properties);
+    ooxml_dml::visit::Visit::visit_ct_group_shape_properties(v, &node.grp_sp_pr);
+    for it0 in &node.shape {
+        Visit::visit_shape(v, it0);
+    }
+    for it0 in &node.group_shape {
+        Visit::visit_group_shape(v, it0);
+    }
+    for it0 in &node.graphic_frame {
+        Visit::visit_graphical_object_frame(v, it0);
+    }
+    for it0 in &node.connector {
+        Visit::visit_connector(v, it0);
+    }
+    for it0 in &node.picture {
+        Visit::visit_picture(v, it0);
+    }
+    #[cfg(feature = "pml-external")]
+    {
+        for it0 in &node.content_part {
+            Visit::visit_ct_rel(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &node.ext_lst {
+            Visit::visit_ct_extension_list_modify(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_group_shape_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut GroupShape) {
+    VisitMut::visit_ct_group_shape_non_visual_mut(v, &mut node.non_visual_group_properties);
+    ooxml_dml::visit::VisitMut::visit_ct_group_shape_properties_mut(v, &mut node.grp_sp_pr);
+    for it0 in &mut node.shape {
+        VisitMut::visit_shape_mut(v, it0);
+    }
+    for it0 in &mut node.group_shape {
+        VisitMut::visit_group_shape_mut(v, it0);
+    }
+    for it0 in &mut node.graphic_frame {
+        VisitMut::visit_graphical_object_frame_mut(v, it0);
+    }
+    for it0 in &mut node.connector {
+        VisitMut::visit_connector_mut(v, it0);
+    }
+    for it0 in &mut node.picture {
+        VisitMut::visit_picture_mut(v, it0);
+    }
+    #[cfg(feature = "pml-external")]
+    {
+        for it0 in &mut node.content_part {
+            VisitMut::visit_ct_rel_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &mut node.ext_lst {
+            VisitMut::visit_ct_extension_list_modify_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_handout_master<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast HandoutMaster) {
+    Visit::visit_common_slide_data(v, &node.common_slide_data);
+    ooxml_dml::visit::Visit::visit_ct_color_mapping(v, &node.clr_map);
+    #[cfg(feature = "pml-masters")]
+    {
+        if let Some(it0) = &node.hf {
+            Visit::visit_ct_header_footer(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &node.ext_lst {
+            Visit::visit_ct_extension_list_modify(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_handout_master_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut HandoutMaster) {
+    VisitMut::visit_common_slide_data_mut(v, &mut node.common_slide_data);
+    ooxml_dml::visit::VisitMut::visit_ct_color_mapping_mut(v, &mut node.clr_map);
+    #[cfg(feature = "pml-masters")]
+    {
+        if let Some(it0) = &mut node.hf {
+            VisitMut::visit_ct_header_footer_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &mut node.ext_lst {
+            VisitMut::visit_ct_extension_list_modify_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_notes_master<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast NotesMaster) {
+    Visit::visit_common_slide_data(v, &node.common_slide_data);
+    ooxml_dml::visit::Visit::visit_ct_color_mapping(v, &node.clr_map);
+    #[cfg(feature = "pml-notes")]
+    {
+        if let Some(it0) = &node.hf {
+            Visit::visit_ct_header_footer(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &node.notes_style {
+            ooxml_dml::visit::Visit::visit_ct_text_list_style(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &node.ext_lst {
+            Visit::visit_ct_extension_list_modify(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_notes_master_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut NotesMaster) {
+    VisitMut::visit_common_slide_data_mut(v, &mut node.common_slide_data);
+    ooxml_dml::visit::VisitMut::visit_ct_color_mapping_mut(v, &mut node.clr_map);
+    #[cfg(feature = "pml-notes")]
+    {
+        if let Some(it0) = &mut node.hf {
+            VisitMut::visit_ct_header_footer_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &mut node.notes_style {
+            ooxml_dml::visit::VisitMut::visit_ct_text_list_style_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &mut node.ext_lst {
+            VisitMut::visit_ct_extension_list_modify_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_notes_slide<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast NotesSlide) {
+    Visit::visit_common_slide_data(v, &node.common_slide_data);
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &node.clr_map_ovr {
+            ooxml_dml::visit::Visit::visit_ct_color_mapping_override(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &node.ext_lst {
+            Visit::visit_ct_extension_list_modify(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_notes_slide_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut NotesSlide) {
+    VisitMut::visit_common_slide_data_mut(v, &mut node.common_slide_data);
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &mut node.clr_map_ovr {
+            ooxml_dml::visit::VisitMut::visit_ct_color_mapping_override_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &mut node.ext_lst {
+            VisitMut::visit_ct_extension_list_modify_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_picture<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Picture) {
+    Visit::visit_ct_picture_non_visual(v, &node.non_visual_picture_properties);
+    ooxml_dml::visit::Visit::visit_blip_fill_properties(v, &node.blip_fill);
+    ooxml_dml::visit::Visit::visit_ct_shape_properties(v, &node.shape_properties);
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &node.style {
+            ooxml_dml::visit::Visit::visit_shape_style(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &node.ext_lst {
+            Visit::visit_ct_extension_list_modify(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_picture_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Picture) {
+    VisitMut::visit_ct_picture_non_visual_mut(v, &mut node.non_visual_picture_properties);
+    ooxml_dml::visit::VisitMut::visit_blip_fill_properties_mut(v, &mut node.blip_fill);
+    ooxml_dml::visit::VisitMut::visit_ct_shape_properties_mut(v, &mut node.shape_properties);
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &mut node.style {
+            ooxml_dml::visit::VisitMut::visit_shape_style_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &mut node.ext_lst {
+            VisitMut::visit_ct_extension_list_modify_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_presentation<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Presentation) {
+    if let Some(it0) = &node.conformance {
+        Visit::visit_st_conformance_class(v, it0);
+    }
+    if let Some(it0) = &node.sld_master_id_lst {
+        Visit::visit_ct_slide_master_id_list(v, it0);
+    }
+    #[cfg(feature = "pml-notes")]
+    {
+        if let Some(it0) = &node.notes_master_id_lst {
+            Visit::visit_ct_notes_master_id_list(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-masters")]
+    {
+        if let Some(it0) = &node.handout_master_id_lst {
+            Visit::visit_ct_handout_master_id_list(v, it0);
+        }
+    }
+    if let Some(it0) = &node.sld_id_lst {
+        Visit::visit_slide_id_list(v, it0);
+    }
+    if let Some(it0) = &node.sld_sz {
+        Visit::visit_ct_slide_size(v, it0);
+    }
+    #[cfg(feature = "pml-notes")]
+    {
+        ooxml_dml::visit::Visit::visit_positive_size2_d(v, &node.notes_sz);
+    }
+    #[cfg(feature = "pml-external")]
+    {
+        if let Some(it0) = &node.smart_tags {
+            Visit::visit_ct_smart_tags(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &node.embedded_font_lst {
+            Visit::visit_ct_embedded_font_list(v, it0);
+        }
+    }
+    if let Some(it0) = &node.cust_show_lst {
+        Visit::visit_ct_custom_show_list(v, it0);
+    }
+    #[cfg(feature = "pml-media")]
+    {
+        if let Some(it0) = &node.photo_album {
+            Visit::visit_ct_photo_album(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-external")]
+    {
+        if let Some(it0) = &node.cust_data_lst {
+            Visit::visit_ct_customer_data_list(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &node.kinsoku {
+            Visit::visit_ct_kinsoku(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &node.default_text_style {
+            ooxml_dml::visit::Visit::visit_ct_text_list_style(v, it0);
+        }
+    }
+    if let Some(it0) = &node.modify_verifier {
+        Visit::visit_ct_modify_verifier(v, it0);
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &node.ext_lst {
+            Visit::visit_ct_extension_list(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_presentation_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Presentation) {
+    if let Some(it0) = &mut node.conformance {
+        VisitMut::visit_st_conformance_class_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.sld_master_id_lst {
+        VisitMut::visit_ct_slide_master_id_list_mut(v, it0);
+    }
+    #[cfg(feature = "pml-notes")]
+    {
+        if let Some(it0) = &mut node.notes_master_id_lst {
+            VisitMut::visit_ct_notes_master_id_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-masters")]
+    {
+        if let Some(it0) = &mut node.handout_master_id_lst {
+            VisitMut::visit_ct_handout_master_id_list_mut(v, it0);
+        }
+    }
+    if let Some(it0) = &mut node.sld_id_lst {
+        VisitMut::visit_slide_id_list_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.sld_sz {
+        VisitMut::visit_ct_slide_size_mut(v, it0);
+    }
+    #[cfg(feature = "pml-notes")]
+    {
+        ooxml_dml::visit::VisitMut::visit_positive_size2_d_mut(v, &mut node.notes_sz);
+    }
+    #[cfg(feature = "pml-external")]
+    {
+        if let Some(it0) = &mut node.smart_tags {
+            VisitMut::visit_ct_smart_tags_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &mut node.embedded_font_lst {
+            VisitMut::visit_ct_embedded_font_list_mut(v, it0);
+        }
+    }
+    if let Some(it0) = &mut node.cust_show_lst {
+        VisitMut::visit_ct_custom_show_list_mut(v, it0);
+    }
+    #[cfg(feature = "pml-media")]
+    {
+        if let Some(it0) = &mut node.photo_album {
+            VisitMut::visit_ct_photo_album_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-external")]
+    {
+        if let Some(it0) = &mut node.cust_data_lst {
+            VisitMut::visit_ct_customer_data_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &mut node.kinsoku {
+            VisitMut::visit_ct_kinsoku_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &mut node.default_text_style {
+            ooxml_dml::visit::VisitMut::visit_ct_text_list_style_mut(v, it0);
+        }
+    }
+    if let Some(it0) = &mut node.modify_verifier {
+        VisitMut::visit_ct_modify_verifier_mut(v, it0);
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &mut node.ext_lst {
+            VisitMut::visit_ct_extension_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_shape<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Shape) {
+    Visit::visit_shape_non_visual(v, &node.non_visual_properties);
+    ooxml_dml::visit::Visit::visit_ct_shape_properties(v, &node.shape_properties);
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &node.style {
+            ooxml_dml::visit::Visit::visit_shape_style(v, it0);
+        }
+    }
+    if let Some(it0) = &node.text_body {
+        ooxml_dml::visit::Visit::visit_text_body(v, it0);
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &node.ext_lst {
+            Visit::visit_ct_extension_list_modify(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_shape_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Shape) {
+    VisitMut::visit_shape_non_visual_mut(v, &mut node.non_visual_properties);
+    ooxml_dml::visit::VisitMut::visit_ct_shape_properties_mut(v, &mut node.shape_properties);
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &mut node.style {
+            ooxml_dml::visit::VisitMut::visit_shape_style_mut(v, it0);
+        }
+    }
+    if let Some(it0) = &mut node.text_body {
+        ooxml_dml::visit::VisitMut::visit_text_body_mut(v, it0);
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &mut node.ext_lst {
+            VisitMut::visit_ct_extension_list_modify_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_shape_non_visual<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast ShapeNonVisual) {
+    ooxml_dml::visit::Visit::visit_ct_non_visual_drawing_props(v, &node.c_nv_pr);
+    ooxml_dml::visit::Visit::visit_ct_non_visual_drawing_shape_props(v, &node.c_nv_sp_pr);
+    Visit::visit_ct_application_non_visual_drawing_props(v, &node.nv_pr);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_shape_non_visual_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ShapeNonVisual) {
+    ooxml_dml::visit::VisitMut::visit_ct_non_visual_drawing_props_mut(v, &mut node.c_nv_pr);
+    ooxml_dml::visit::VisitMut::visit_ct_non_visual_drawing_shape_props_mut(
+        v,
+        &mut node.c_nv_sp_pr,
+    );
+    VisitMut::visit_ct_application_non_visual_drawing_props_mut(v, &mut node.nv_pr);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_slide<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Slide) {
+    Visit::visit_common_slide_data(v, &node.common_slide_data);
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &node.clr_map_ovr {
+            ooxml_dml::visit::Visit::visit_ct_color_mapping_override(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &node.transition {
+            Visit::visit_slide_transition(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-animations")]
+    {
+        if let Some(it0) = &node.timing {
+            Visit::visit_slide_timing(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &node.ext_lst {
+            Visit::visit_ct_extension_list_modify(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_slide_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Slide) {
+    VisitMut::visit_common_slide_data_mut(v, &mut node.common_slide_data);
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &mut node.clr_map_ovr {
+            ooxml_dml::visit::VisitMut::visit_ct_color_mapping_override_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &mut node.transition {
+            VisitMut::visit_slide_transition_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-animations")]
+    {
+        if let Some(it0) = &mut node.timing {
+            VisitMut::visit_slide_timing_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &mut node.ext_lst {
+            VisitMut::visit_ct_extension_list_modify_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_slide_id_list<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast SlideIdList) {
+    for it0 in &node.sld_id {
+        Visit::visit_ct_slide_id_list_entry(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_slide_id_list_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SlideIdList) {
+    for it0 in &mut node.sld_id {
+        VisitMut::visit_ct_slide_id_list_entry_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_slide_layout<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast SlideLayout) {
+    #[cfg(feature = "pml-masters")]
+    {
+        if let Some(it0) = &node.r#type {
+            Visit::visit_st_slide_layout_type(v, it0);
+        }
+    }
+    Visit::visit_common_slide_data(v, &node.common_slide_data);
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &node.clr_map_ovr {
+            ooxml_dml::visit::Visit::visit_ct_color_mapping_override(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &node.transition {
+            Visit::visit_slide_transition(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-animations")]
+    {
+        if let Some(it0) = &node.timing {
+            Visit::visit_slide_timing(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-masters")]
+    {
+        if let Some(it0) = &node.hf {
+            Visit::visit_ct_header_footer(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &node.ext_lst {
+            Visit::visit_ct_extension_list_modify(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_slide_layout_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SlideLayout) {
+    #[cfg(feature = "pml-masters")]
+    {
+        if let Some(it0) = &mut node.r#type {
+            VisitMut::visit_st_slide_layout_type_mut(v, it0);
+        }
+    }
+    VisitMut::visit_common_slide_data_mut(v, &mut node.common_slide_data);
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &mut node.clr_map_ovr {
+            ooxml_dml::visit::VisitMut::visit_ct_color_mapping_override_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &mut node.transition {
+            VisitMut::visit_slide_transition_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-animations")]
+    {
+        if let Some(it0) = &mut node.timing {
+            VisitMut::visit_slide_timing_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-masters")]
+    {
+        if let Some(it0) = &mut node.hf {
+            VisitMut::visit_ct_header_footer_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &mut node.ext_lst {
+            VisitMut::visit_ct_extension_list_modify_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_slide_master<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast SlideMaster) {
+    Visit::visit_common_slide_data(v, &node.common_slide_data);
+    ooxml_dml::visit::Visit::visit_ct_color_mapping(v, &node.clr_map);
+    #[cfg(feature = "pml-masters")]
+    {
+        if let Some(it0) = &node.sld_layout_id_lst {
+            Visit::visit_ct_slide_layout_id_list(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &node.transition {
+            Visit::visit_slide_transition(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-animations")]
+    {
+        if let Some(it0) = &node.timing {
+            Visit::visit_slide_timing(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-masters")]
+    {
+        if let Some(it0) = &node.hf {
+            Visit::visit_ct_header_footer(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &node.tx_styles {
+            Visit::visit_ct_slide_master_text_styles(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &node.ext_lst {
+            Visit::visit_ct_extension_list_modify(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_slide_master_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SlideMaster) {
+    VisitMut::visit_common_slide_data_mut(v, &mut node.common_slide_data);
+    ooxml_dml::visit::VisitMut::visit_ct_color_mapping_mut(v, &mut node.clr_map);
+    #[cfg(feature = "pml-masters")]
+    {
+        if let Some(it0) = &mut node.sld_layout_id_lst {
+            VisitMut::visit_ct_slide_layout_id_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &mut node.transition {
+            VisitMut::visit_slide_transition_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-animations")]
+    {
+        if let Some(it0) = &mut node.timing {
+            VisitMut::visit_slide_timing_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-masters")]
+    {
+        if let Some(it0) = &mut node.hf {
+            VisitMut::visit_ct_header_footer_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-styling")]
+    {
+        if let Some(it0) = &mut node.tx_styles {
+            VisitMut::visit_ct_slide_master_text_styles_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &mut node.ext_lst {
+            VisitMut::visit_ct_extension_list_modify_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_slide_timing<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast SlideTiming) {
+    #[cfg(feature = "pml-animations")]
+    {
+        if let Some(it0) = &node.tn_lst {
+            Visit::visit_ct_time_node_list(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-animations")]
+    {
+        if let Some(it0) = &node.bld_lst {
+            Visit::visit_ct_build_list(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &node.ext_lst {
+            Visit::visit_ct_extension_list_modify(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_slide_timing_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SlideTiming) {
+    #[cfg(feature = "pml-animations")]
+    {
+        if let Some(it0) = &mut node.tn_lst {
+            VisitMut::visit_ct_time_node_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-animations")]
+    {
+        if let Some(it0) = &mut node.bld_lst {
+            VisitMut::visit_ct_build_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &mut node.ext_lst {
+            VisitMut::visit_ct_extension_list_modify_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_slide_transition<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast SlideTransition,
+) {
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &node.spd {
+            Visit::visit_st_transition_speed(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &node.blinds {
+            Visit::visit_ct_orientation_transition(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &node.checker {
+            Visit::visit_ct_orientation_transition(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &node.circle {
+            Visit::visit_ct_empty(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &node.dissolve {
+            Visit::visit_ct_empty(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &node.comb {
+            Visit::visit_ct_orientation_transition(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &node.cover {
+            Visit::visit_ct_eight_direction_transition(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &node.cut {
+            Visit::visit_ct_optional_black_transition(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &node.diamond {
+            Visit::visit_ct_empty(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &node.fade {
+            Visit::visit_ct_optional_black_transition(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &node.newsflash {
+            Visit::visit_ct_empty(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &node.plus {
+            Visit::visit_ct_empty(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &node.pull {
+            Visit::visit_ct_eight_direction_transition(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &node.push {
+            Visit::visit_ct_side_direction_transition(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &node.random {
+            Visit::visit_ct_empty(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &node.random_bar {
+            Visit::visit_ct_orientation_transition(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &node.split {
+            Visit::visit_ct_split_transition(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &node.strips {
+            Visit::visit_ct_corner_direction_transition(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &node.wedge {
+            Visit::visit_ct_empty(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &node.wheel {
+            Visit::visit_ct_wheel_transition(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &node.wipe {
+            Visit::visit_ct_side_direction_transition(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &node.zoom {
+            Visit::visit_ct_in_out_transition(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &node.snd_ac {
+            Visit::visit_ct_transition_sound_action(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &node.ext_lst {
+            Visit::visit_ct_extension_list_modify(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            ooxml_dml::visit::Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_slide_transition_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SlideTransition) {
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &mut node.spd {
+            VisitMut::visit_st_transition_speed_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &mut node.blinds {
+            VisitMut::visit_ct_orientation_transition_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &mut node.checker {
+            VisitMut::visit_ct_orientation_transition_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &mut node.circle {
+            VisitMut::visit_ct_empty_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &mut node.dissolve {
+            VisitMut::visit_ct_empty_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &mut node.comb {
+            VisitMut::visit_ct_orientation_transition_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &mut node.cover {
+            VisitMut::visit_ct_eight_direction_transition_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &mut node.cut {
+            VisitMut::visit_ct_optional_black_transition_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &mut node.diamond {
+            VisitMut::visit_ct_empty_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &mut node.fade {
+            VisitMut::visit_ct_optional_black_transition_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &mut node.newsflash {
+            VisitMut::visit_ct_empty_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &mut node.plus {
+            VisitMut::visit_ct_empty_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &mut node.pull {
+            VisitMut::visit_ct_eight_direction_transition_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &mut node.push {
+            VisitMut::visit_ct_side_direction_transition_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &mut node.random {
+            VisitMut::visit_ct_empty_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &mut node.random_bar {
+            VisitMut::visit_ct_orientation_transition_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &mut node.split {
+            VisitMut::visit_ct_split_transition_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &mut node.strips {
+            VisitMut::visit_ct_corner_direction_transition_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &mut node.wedge {
+            VisitMut::visit_ct_empty_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &mut node.wheel {
+            VisitMut::visit_ct_wheel_transition_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &mut node.wipe {
+            VisitMut::visit_ct_side_direction_transition_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &mut node.zoom {
+            VisitMut::visit_ct_in_out_transition_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-transitions")]
+    {
+        if let Some(it0) = &mut node.snd_ac {
+            VisitMut::visit_ct_transition_sound_action_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "pml-extensions")]
+    {
+        if let Some(it0) = &mut node.ext_lst {
+            VisitMut::visit_ct_extension_list_modify_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            ooxml_dml::visit::VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+impl ooxml_xml::QueryTree for CTApplicationNonVisualDrawingProps {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.ph {
+            it0.visit_element("p:ph", v);
+        }
+        if let Some(it0) = &self.cust_data_lst {
+            it0.visit_element("p:custDataLst", v);
+        }
+        if let Some(it0) = &self.ext_lst {
+            it0.visit_element("p:extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTBackground {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.background {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTBackgroundProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.ext_lst {
+            it0.visit_element("p:extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTBuildList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.bld_p {
+            it0.visit_element("p:bldP", v);
+        }
+        for it0 in &self.bld_dgm {
+            it0.visit_element("p:bldDgm", v);
+        }
+        for it0 in &self.bld_ole_chart {
+            it0.visit_element("p:bldOleChart", v);
+        }
+        for it0 in &self.bld_graphic {
+            it0.visit_element("p:bldGraphic", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTComment {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "pml-comments")]
+        {
+            self.pos.visit_element("p:pos", v);
+        }
+        #[cfg(feature = "pml-comments")]
+        {
+            self.text.visit_element("p:text", v);
+        }
+        #[cfg(feature = "pml-extensions")]
+        {
+            if let Some(it0) = &self.ext_lst {
+                it0.visit_element("p:extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCommentAuthor {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "pml-extensions")]
+        {
+            if let Some(it0) = &self.ext_lst {
+                it0.visit_element("p:extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCommentAuthorList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.cm_author {
+            it0.visit_element("p:cmAuthor", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCommentList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.cm {
+            it0.visit_element("p:cm", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCommonSlideViewProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.c_view_pr.visit_element("p:cViewPr", v);
+        if let Some(it0) = &self.guide_lst {
+            it0.visit_element("p:guideLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCommonViewProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.scale.visit_element("p:scale", v);
+        self.origin.visit_element("p:origin", v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTConnectorNonVisual {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.c_nv_pr.visit_element("p:cNvPr", v);
+        self.c_nv_cxn_sp_pr.visit_element("p:cNvCxnSpPr", v);
+        self.nv_pr.visit_element("p:nvPr", v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTControl {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.ext_lst {
+            it0.visit_element("p:extLst", v);
+        }
+        if let Some(it0) = &self.picture {
+            it0.visit_element("p:pic", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTControlList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.control {
+            it0.visit_element("p:control", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCornerDirectionTransition {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCustomShow {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.sld_lst.visit_element("p:sldLst", v);
+        if let Some(it0) = &self.ext_lst {
+            it0.visit_element("p:extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCustomShowId {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCustomShowList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.cust_show {
+            it0.visit_element("p:custShow", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCustomerData {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCustomerDataList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.cust_data {
+            it0.visit_element("p:custData", v);
+        }
+        if let Some(it0) = &self.tags {
+            it0.visit_element("p:tags", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTEightDirectionTransition {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTEmbeddedFontDataId {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTEmbeddedFontList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.embedded_font {
+            it0.visit_element("p:embeddedFont", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTEmbeddedFontListEntry {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.font.visit_element("p:font", v);
+        if let Some(it0) = &self.regular {
+            it0.visit_element("p:regular", v);
+        }
+        if let Some(it0) = &self.bold {
+            it0.visit_element("p:bold", v);
+        }
+        if let Some(it0) = &self.italic {
+            it0.visit_element("p:italic", v);
+        }
+        if let Some(it0) = &self.bold_italic {
+            it0.visit_element("p:boldItalic", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTEmpty {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTExtension {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTExtensionList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.ext {
+            it0.visit_element("p:ext", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTExtensionListModify {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.ext {
+            it0.visit_element("p:ext", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTGraphicalObjectFrameNonVisual {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.c_nv_pr.visit_element("p:cNvPr", v);
+        self.c_nv_graphic_frame_pr
+            .visit_element("p:cNvGraphicFramePr", v);
+        self.nv_pr.visit_element("p:nvPr", v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTGroupShapeNonVisual {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.c_nv_pr.visit_element("p:cNvPr", v);
+        self.c_nv_grp_sp_pr.visit_element("p:cNvGrpSpPr", v);
+        self.nv_pr.visit_element("p:nvPr", v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTGuide {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTGuideList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.guide {
+            it0.visit_element("p:guide", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTHandoutMasterIdList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.handout_master_id {
+            it0.visit_element("p:handoutMasterId", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTHandoutMasterIdListEntry {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.ext_lst {
+            it0.visit_element("p:extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTHeaderFooter {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.ext_lst {
+            it0.visit_element("p:extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTHtmlPublishProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.slide_list_choice {
+            it0.visit_element("", v);
+        }
+        if let Some(it0) = &self.ext_lst {
+            it0.visit_element("p:extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTInOutTransition {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTIndexRange {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTKinsoku {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTModifyVerifier {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTNormalViewPortion {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTNormalViewProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.restored_left.visit_element("p:restoredLeft", v);
+        self.restored_top.visit_element("p:restoredTop", v);
+        if let Some(it0) = &self.ext_lst {
+            it0.visit_element("p:extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTNotesMasterIdList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.notes_master_id {
+            it0.visit_element("p:notesMasterId", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTNotesMasterIdListEntry {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.ext_lst {
+            it0.visit_element("p:extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTNotesTextViewProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.c_view_pr.visit_element("p:cViewPr", v);
+        if let Some(it0) = &self.ext_lst {
+            it0.visit_element("p:extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTNotesViewProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.c_sld_view_pr.visit_element("p:cSldViewPr", v);
+        if let Some(it0) = &self.ext_lst {
+            it0.visit_element("p:extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTOleObject {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.embed {
+            it0.visit_element("p:embed", v);
+        }
+        if let Some(it0) = &self.link {
+            it0.visit_element("p:link", v);
+        }
+        if let Some(it0) = &self.picture {
+            it0.visit_element("p:pic", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTOleObjectEmbed {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.ext_lst {
+            it0.visit_element("p:extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTOleObjectLink {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.ext_lst {
+            it0.visit_element("p:extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTOptionalBlackTransition {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTOrientationTransition {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTOutlineViewProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.c_view_pr.visit_element("p:cViewPr", v);
+        if let Some(it0) = &self.sld_lst {
+            it0.visit_element("p:sldLst", v);
+        }
+        if let Some(it0) = &self.ext_lst {
+            it0.visit_element("p:extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTOutlineViewSlideEntry {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTOutlineViewSlideList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.sld {
+            it0.visit_element("p:sld", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPhotoAlbum {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.ext_lst {
+            it0.visit_element("p:extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPictureNonVisual {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.c_nv_pr.visit_element("p:cNvPr", v);
+        self.c_nv_pic_pr.visit_element("p:cNvPicPr", v);
+        self.nv_pr.visit_element("p:nvPr", v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPlaceholder {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.ext_lst {
+            it0.visit_element("p:extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPresentationProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "pml-external")]
+        {
+            if let Some(it0) = &self.html_pub_pr {
+                it0.visit_element("p:htmlPubPr", v);
+            }
+        }
+        #[cfg(feature = "pml-external")]
+        {
+            if let Some(it0) = &self.web_pr {
+                it0.visit_element("p:webPr", v);
+            }
+        }
+        if let Some(it0) = &self.prn_pr {
+            it0.visit_element("p:prnPr", v);
+        }
+        if let Some(it0) = &self.show_pr {
+            it0.visit_element("p:showPr", v);
+        }
+        #[cfg(feature = "pml-styling")]
+        {
+            if let Some(it0) = &self.clr_mru {
+                it0.visit_element("p:clrMru", v);
+            }
+        }
+        #[cfg(feature = "pml-extensions")]
+        {
+            if let Some(it0) = &self.ext_lst {
+                it0.visit_element("p:extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPrintProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.ext_lst {
+            it0.visit_element("p:extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTRel {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTShowInfoBrowse {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTShowInfoKiosk {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTShowProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.show_type {
+            it0.visit_element("", v);
+        }
+        if let Some(it0) = &self.slide_list_choice {
+            it0.visit_element("", v);
+        }
+        if let Some(it0) = &self.pen_clr {
+            it0.visit_element("p:penClr", v);
+        }
+        if let Some(it0) = &self.ext_lst {
+            it0.visit_element("p:extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSideDirectionTransition {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSlideIdListEntry {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.ext_lst {
+            it0.visit_element("p:extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSlideLayoutIdList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.sld_layout_id {
+            it0.visit_element("p:sldLayoutId", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSlideLayoutIdListEntry {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.ext_lst {
+            it0.visit_element("p:extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSlideMasterIdList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.sld_master_id {
+            it0.visit_element("p:sldMasterId", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSlideMasterIdListEntry {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.ext_lst {
+            it0.visit_element("p:extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSlideMasterTextStyles {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.title_style {
+            it0.visit_element("p:titleStyle", v);
+        }
+        if let Some(it0) = &self.body_style {
+            it0.visit_element("p:bodyStyle", v);
+        }
+        if let Some(it0) = &self.other_style {
+            it0.visit_element("p:otherStyle", v);
+        }
+        if let Some(it0) = &self.ext_lst {
+            it0.visit_element("p:extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSlideRelationshipList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.sld {
+            it0.visit_element("p:sld", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSlideRelationshipListEntry {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSlideSize {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSlideSorterViewProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.c_view_pr.visit_element("p:cViewPr", v);
+        if let Some(it0) = &self.ext_lst {
+            it0.visit_element("p:extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSlideSyncProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.ext_lst {
+            it0.visit_element("p:extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSlideViewProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.c_sld_view_pr.visit_element("p:cSldViewPr", v);
+        if let Some(it0) = &self.ext_lst {
+            it0.visit_element("p:extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSmartTags {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSplitTransition {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTStringTag {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLAnimVariant {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.bool_val {
+            it0.visit_element("p:boolVal", v);
+        }
+        if let Some(it0) = &self.int_val {
+            it0.visit_element("p:intVal", v);
+        }
+        if let Some(it0) = &self.flt_val {
+            it0.visit_element("p:fltVal", v);
+        }
+        if let Some(it0) = &self.str_val {
+            it0.visit_element("p:strVal", v);
+        }
+        if let Some(it0) = &self.clr_val {
+            it0.visit_element("p:clrVal", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLAnimVariantBooleanVal {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLAnimVariantFloatVal {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLAnimVariantIntegerVal {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLAnimVariantStringVal {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLAnimateBehavior {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.c_bhvr.visit_element("p:cBhvr", v);
+        if let Some(it0) = &self.tav_lst {
+            it0.visit_element("p:tavLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLAnimateColorBehavior {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.c_bhvr.visit_element("p:cBhvr", v);
+        if let Some(it0) = &self.by {
+            it0.visit_element("p:by", v);
+        }
+        if let Some(it0) = &self.from {
+            it0.visit_element("p:from", v);
+        }
+        if let Some(it0) = &self.to {
+            it0.visit_element("p:to", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLAnimateEffectBehavior {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.c_bhvr.visit_element("p:cBhvr", v);
+        if let Some(it0) = &self.progress {
+            it0.visit_element("p:progress", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLAnimateMotionBehavior {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.c_bhvr.visit_element("p:cBhvr", v);
+        if let Some(it0) = &self.by {
+            it0.visit_element("p:by", v);
+        }
+        if let Some(it0) = &self.from {
+            it0.visit_element("p:from", v);
+        }
+        if let Some(it0) = &self.to {
+            it0.visit_element("p:to", v);
+        }
+        if let Some(it0) = &self.r_ctr {
+            it0.visit_element("p:rCtr", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLAnimateRotationBehavior {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.c_bhvr.visit_element("p:cBhvr", v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLAnimateScaleBehavior {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.c_bhvr.visit_element("p:cBhvr", v);
+        if let Some(it0) = &self.by {
+            it0.visit_element("p:by", v);
+        }
+        if let Some(it0) = &self.from {
+            it0.visit_element("p:from", v);
+        }
+        if let Some(it0) = &self.to {
+            it0.visit_element("p:to", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLBehaviorAttributeNameList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.attr_name {
+            it0.visit_element("p:attrName", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLBuildDiagram {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLBuildParagraph {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.tmpl_lst {
+            it0.visit_element("p:tmplLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLByAnimateColorTransform {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.rgb {
+            it0.visit_element("p:rgb", v);
+        }
+        if let Some(it0) = &self.hsl {
+            it0.visit_element("p:hsl", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLByHslColorTransform {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLByRgbColorTransform {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLCommandBehavior {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.c_bhvr.visit_element("p:cBhvr", v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLCommonBehaviorData {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.c_tn.visit_element("p:cTn", v);
+        self.tgt_el.visit_element("p:tgtEl", v);
+        if let Some(it0) = &self.attr_name_lst {
+            it0.visit_element("p:attrNameLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLCommonMediaNodeData {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.c_tn.visit_element("p:cTn", v);
+        self.tgt_el.visit_element("p:tgtEl", v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLCommonTimeNodeData {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.st_cond_lst {
+            it0.visit_element("p:stCondLst", v);
+        }
+        if let Some(it0) = &self.end_cond_lst {
+            it0.visit_element("p:endCondLst", v);
+        }
+        if let Some(it0) = &self.end_sync {
+            it0.visit_element("p:endSync", v);
+        }
+        if let Some(it0) = &self.iterate {
+            it0.visit_element("p:iterate", v);
+        }
+        if let Some(it0) = &self.child_tn_lst {
+            it0.visit_element("p:childTnLst", v);
+        }
+        if let Some(it0) = &self.sub_tn_lst {
+            it0.visit_element("p:subTnLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLGraphicalObjectBuild {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.bld_as_one {
+            it0.visit_element("p:bldAsOne", v);
+        }
+        if let Some(it0) = &self.bld_sub {
+            it0.visit_element("p:bldSub", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLIterateData {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.tm_abs {
+            it0.visit_element("p:tmAbs", v);
+        }
+        if let Some(it0) = &self.tm_pct {
+            it0.visit_element("p:tmPct", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLIterateIntervalPercentage {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLIterateIntervalTime {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLMediaNodeAudio {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.c_media_node.visit_element("p:cMediaNode", v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLMediaNodeVideo {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.c_media_node.visit_element("p:cMediaNode", v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLOleBuildChart {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLOleChartTargetElement {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLPoint {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLSetBehavior {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.c_bhvr.visit_element("p:cBhvr", v);
+        if let Some(it0) = &self.to {
+            it0.visit_element("p:to", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLShapeTargetElement {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.bg {
+            it0.visit_element("p:bg", v);
+        }
+        if let Some(it0) = &self.sub_sp {
+            it0.visit_element("p:subSp", v);
+        }
+        if let Some(it0) = &self.ole_chart_el {
+            it0.visit_element("p:oleChartEl", v);
+        }
+        if let Some(it0) = &self.tx_el {
+            it0.visit_element("p:txEl", v);
+        }
+        if let Some(it0) = &self.graphic_el {
+            it0.visit_element("p:graphicEl", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLSubShapeId {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLTemplate {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.tn_lst.visit_element("p:tnLst", v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLTemplateList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.tmpl {
+            it0.visit_element("p:tmpl", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLTextTargetElement {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.char_rg {
+            it0.visit_element("p:charRg", v);
+        }
+        if let Some(it0) = &self.p_rg {
+            it0.visit_element("p:pRg", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLTimeAnimateValue {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.value {
+            it0.visit_element("p:val", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLTimeAnimateValueList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.tav {
+            it0.visit_element("p:tav", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLTimeCondition {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.tgt_el {
+            it0.visit_element("p:tgtEl", v);
+        }
+        if let Some(it0) = &self.tn {
+            it0.visit_element("p:tn", v);
+        }
+        if let Some(it0) = &self.rtn {
+            it0.visit_element("p:rtn", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLTimeConditionList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.cond {
+            it0.visit_element("p:cond", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLTimeNodeSequence {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.c_tn.visit_element("p:cTn", v);
+        if let Some(it0) = &self.prev_cond_lst {
+            it0.visit_element("p:prevCondLst", v);
+        }
+        if let Some(it0) = &self.next_cond_lst {
+            it0.visit_element("p:nextCondLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLTimeTargetElement {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.sld_tgt {
+            it0.visit_element("p:sldTgt", v);
+        }
+        if let Some(it0) = &self.snd_tgt {
+            it0.visit_element("p:sndTgt", v);
+        }
+        if let Some(it0) = &self.sp_tgt {
+            it0.visit_element("p:spTgt", v);
+        }
+        if let Some(it0) = &self.ink_tgt {
+            it0.visit_element("p:inkTgt", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLTriggerRuntimeNode {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTLTriggerTimeNodeID {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTagList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.tag {
+            it0.visit_element("p:tag", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTagsData {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTimeNodeList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.par {
+            it0.visit_element("p:par", v);
+        }
+        for it0 in &self.seq {
+            it0.visit_element("p:seq", v);
+        }
+        for it0 in &self.excl {
+            it0.visit_element("p:excl", v);
+        }
+        for it0 in &self.anim {
+            it0.visit_element("p:anim", v);
+        }
+        for it0 in &self.anim_clr {
+            it0.visit_element("p:animClr", v);
+        }
+        for it0 in &self.anim_effect {
+            it0.visit_element("p:animEffect", v);
+        }
+        for it0 in &self.anim_motion {
+            it0.visit_element("p:animMotion", v);
+        }
+        for it0 in &self.anim_rot {
+            it0.visit_element("p:animRot", v);
+        }
+        for it0 in &self.anim_scale {
+            it0.visit_element("p:animScale", v);
+        }
+        for it0 in &self.cmd {
+            it0.visit_element("p:cmd", v);
+        }
+        for it0 in &self.set {
+            it0.visit_element("p:set", v);
+        }
+        for it0 in &self.audio {
+            it0.visit_element("p:audio", v);
+        }
+        for it0 in &self.video {
+            it0.visit_element("p:video", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTransitionSoundAction {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.st_snd {
+            it0.visit_element("p:stSnd", v);
+        }
+        if let Some(it0) = &self.end_snd {
+            it0.visit_element("p:endSnd", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTransitionStartSoundAction {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.snd.visit_element("p:snd", v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTViewProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.normal_view_pr {
+            it0.visit_element("p:normalViewPr", v);
+        }
+        if let Some(it0) = &self.slide_view_pr {
+            it0.visit_element("p:slideViewPr", v);
+        }
+        if let Some(it0) = &self.outline_view_pr {
+            it0.visit_element("p:outlineViewPr", v);
+        }
+        #[cfg(feature = "pml-notes")]
+        {
+            if let Some(it0) = &self.notes_text_view_pr {
+                it0.visit_element("p:notesTextViewPr", v);
+            }
+        }
+        if let Some(it0) = &self.sorter_view_pr {
+            it0.visit_element("p:sorterViewPr", v);
+        }
+        #[cfg(feature = "pml-notes")]
+        {
+            if let Some(it0) = &self.notes_view_pr {
+                it0.visit_element("p:notesViewPr", v);
+            }
+        }
+        if let Some(it0) = &self.grid_spacing {
+            it0.visit_element("p:gridSpacing", v);
+        }
+        #[cfg(feature = "pml-extensions")]
+        {
+            if let Some(it0) = &self.ext_lst {
+                it0.visit_element("p:extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTWebProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.ext_lst {
+            it0.visit_element("p:extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTWheelTransition {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CommonSlideData {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "pml-styling")]
+        {
+            if let Some(it0) = &self.bg {
+                it0.visit_element("p:bg", v);
+            }
+        }
+        self.shape_tree.visit_element("p:spTree", v);
+        #[cfg(feature = "pml-external")]
+        {
+            if let Some(it0) = &self.cust_data_lst {
+                it0.visit_element("p:custDataLst", v);
+            }
+        }
+        #[cfg(feature = "pml-external")]
+        {
+            if let Some(it0) = &self.controls {
+                it0.visit_element("p:controls", v);
+            }
+        }
+        #[cfg(feature = "pml-extensions")]
+        {
+            if let Some(it0) = &self.ext_lst {
+                it0.visit_element("p:extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Connector {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.non_visual_connector_properties
+            .visit_element("p:nvCxnSpPr", v);
+        self.shape_properties.visit_element("p:spPr", v);
+        #[cfg(feature = "pml-styling")]
+        {
+            if let Some(it0) = &self.style {
+                it0.visit_element("p:style", v);
+            }
+        }
+        #[cfg(feature = "pml-extensions")]
+        {
+            if let Some(it0) = &self.ext_lst {
+                it0.visit_element("p:extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for EGBackground {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::BgPr(it0) => {
+                it0.visit_element("p:bgPr", v);
+            }
+            Self::BgRef(it0) => {
+                it0.visit_element("p:bgRef", v);
+            }
+        }
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::BgPr(it0) => {
+                it0.visit_content(v);
+            }
+            Self::BgRef(it0) => {
+                it0.visit_content(v);
+            }
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for EGChildSlide {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.clr_map_ovr {
+            it0.visit_element("p:clrMapOvr", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for EGExtensionList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.ext {
+            it0.visit_element("p:ext", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for EGShowType {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::Present(it0) => {
+                it0.visit_element("p:present", v);
+            }
+            Self::Browse(it0) => {
+                it0.visit_element("p:browse", v);
+            }
+            Self::Kiosk(it0) => {
+                it0.visit_element("p:kiosk", v);
+            }
+        }
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::Present(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Browse(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Kiosk(it0) => {
+                it0.visit_content(v);
+            }
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for EGSlideListChoice {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::SldAll(it0) => {
+                it0.visit_element("p:sldAll", v);
+            }
+            Self::SldRg(it0) => {
+                it0.visit_element("p:sldRg", v);
+            }
+            Self::CustShow(it0) => {
+                it0.visit_element("p:custShow", v);
+            }
+        }
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::SldAll(it0) => {
+                it0.visit_content(v);
+            }
+            Self::SldRg(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustShow(it0) => {
+                it0.visit_content(v);
+            }
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for GraphicalObjectFrame {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.nv_graphic_frame_pr
+            .visit_element("p:nvGraphicFramePr", v);
+        self.xfrm.visit_element("p:xfrm", v);
+        #[cfg(feature = "pml-extensions")]
+        {
+            if let Some(it0) = &self.ext_lst {
+                it0.visit_element("p:extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for GroupShape {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.non_visual_group_properties
+            .visit_element("p:nvGrpSpPr", v);
+        self.grp_sp_pr.visit_element("p:grpSpPr", v);
+        for it0 in &self.shape {
+            it0.visit_element("p:sp", v);
+        }
+        for it0 in &self.group_shape {
+            it0.visit_element("p:grpSp", v);
+        }
+        for it0 in &self.graphic_frame {
+            it0.visit_element("p:graphicFrame", v);
+        }
+        for it0 in &self.connector {
+            it0.visit_element("p:cxnSp", v);
+        }
+        for it0 in &self.picture {
+            it0.visit_element("p:pic", v);
+        }
+        #[cfg(feature = "pml-external")]
+        {
+            for it0 in &self.content_part {
+                it0.visit_element("p:contentPart", v);
+            }
+        }
+        #[cfg(feature = "pml-extensions")]
+        {
+            if let Some(it0) = &self.ext_lst {
+                it0.visit_element("p:extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for HandoutMaster {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.common_slide_data.visit_element("p:cSld", v);
+        self.clr_map.visit_element("p:clrMap", v);
+        #[cfg(feature = "pml-masters")]
+        {
+            if let Some(it0) = &self.hf {
+                it0.visit_element("p:hf", v);
+            }
+        }
+        #[cfg(feature = "pml-extensions")]
+        {
+            if let Some(it0) = &self.ext_lst {
+                it0.visit_element("p:extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for NotesMaster {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.common_slide_data.visit_element("p:cSld", v);
+        self.clr_map.visit_element("p:clrMap", v);
+        #[cfg(feature = "pml-notes")]
+        {
+            if let Some(it0) = &self.hf {
+                it0.visit_element("p:hf", v);
+            }
+        }
+        #[cfg(feature = "pml-styling")]
+        {
+            if let Some(it0) = &self.notes_style {
+                it0.visit_element("p:notesStyle", v);
+            }
+        }
+        #[cfg(feature = "pml-extensions")]
+        {
+            if let Some(it0) = &self.ext_lst {
+                it0.visit_element("p:extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for NotesSlide {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.common_slide_data.visit_element("p:cSld", v);
+        #[cfg(feature = "pml-styling")]
+        {
+            if let Some(it0) = &self.clr_map_ovr {
+                it0.visit_element("p:clrMapOvr", v);
+            }
+        }
+        #[cfg(feature = "pml-extensions")]
+        {
+            if let Some(it0) = &self.ext_lst {
+                it0.visit_element("p:extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PAGChildSlide {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PAGOle {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PAGTLBuild {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Picture {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.non_visual_picture_properties
+            .visit_element("p:nvPicPr", v);
+        self.blip_fill.visit_element("p:blipFill", v);
+        self.shape_properties.visit_element("p:spPr", v);
+        #[cfg(feature = "pml-styling")]
+        {
+            if let Some(it0) = &self.style {
+                it0.visit_element("p:style", v);
+            }
+        }
+        #[cfg(feature = "pml-extensions")]
+        {
+            if let Some(it0) = &self.ext_lst {
+                it0.visit_element("p:extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Presentation {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.sld_master_id_lst {
+            it0.visit_element("p:sldMasterIdLst", v);
+        }
+        #[cfg(feature = "pml-notes")]
+        {
+            if let Some(it0) = &self.notes_master_id_lst {
+                it0.visit_element("p:notesMasterIdLst", v);
+            }
+        }
+        #[cfg(feature = "pml-masters")]
+        {
+            if let Some(it0) = &self.handout_master_id_lst {
+                it0.visit_element("p:handoutMasterIdLst", v);
+            }
+        }
+        if let Some(it0) = &self.sld_id_lst {
+            it0.visit_element("p:sldIdLst", v);
+        }
+        if let Some(it0) = &self.sld_sz {
+            it0.visit_element("p:sldSz", v);
+        }
+        #[cfg(feature = "pml-notes")]
+        {
+            self.notes_sz.visit_element("p:notesSz", v);
+        }
+        #[cfg(feature = "pml-external")]
+        {
+            if let Some(it0) = &self.smart_tags {
+                it0.visit_element("p:smartTags", v);
+            }
+        }
+        #[cfg(feature = "pml-styling")]
+        {
+            if let Some(it0) = &self.embedded_font_lst {
+                it0.visit_element("p:embeddedFontLst", v);
+            }
+        }
+        if let Some(it0) = &self.cust_show_lst {
+            it0.visit_element("p:custShowLst", v);
+        }
+        #[cfg(feature = "pml-media")]
+        {
+            if let Some(it0) = &self.photo_album {
+                it0.visit_element("p:photoAlbum", v);
+            }
+        }
+        #[cfg(feature = "pml-external")]
+        {
+            if let Some(it0) = &self.cust_data_lst {
+                it0.visit_element("p:custDataLst", v);
+            }
+        }
+        #[cfg(feature = "pml-styling")]
+        {
+            if let Some(it0) = &self.kinsoku {
+                it0.visit_element("p:kinsoku", v);
+            }
+        }
+        #[cfg(feature = "pml-styling")]
+        {
+            if let Some(it0) = &self.default_text_style {
+                it0.visit_element("p:defaultTextStyle", v);
+            }
+        }
+        if let Some(it0) = &self.modify_verifier {
+            it0.visit_element("p:modifyVerifier", v);
+        }
+        #[cfg(feature = "pml-extensions")]
+        {
+            if let Some(it0) = &self.ext_lst {
+                it0.visit_element("p:extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Shape {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.non_visual_properties.visit_element("p:nvSpPr", v);
+        self.shape_properties.visit_element("p:spPr", v);
+        #[cfg(feature = "pml-styling")]
+        {
+            if let Some(it0) = &self.style {
+                it0.visit_element("p:style", v);
+            }
+        }
+        if let Some(it0) = &self.text_body {
+            it0.visit_element("p:txBody", v);
+        }
+        #[cfg(feature = "pml-extensions")]
+        {
+            if let Some(it0) = &self.ext_lst {
+                it0.visit_element("p:extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ShapeNonVisual {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.c_nv_pr.visit_element("p:cNvPr", v);
+        self.c_nv_sp_pr.visit_element("p:cNvSpPr", v);
+        self.nv_pr.visit_element("p:nvPr", v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Slide {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.common_slide_data.visit_element("p:cSld", v);
+        #[cfg(feature = "pml-styling")]
+        {
+            if let Some(it0) = &self.clr_map_ovr {
+                it0.visit_element("p:clrMapOvr", v);
+            }
+        }
+        #[cfg(feature = "pml-transitions")]
+        {
+            if let Some(it0) = &self.transition {
+                it0.visit_element("p:transition", v);
+            }
+        }
+        #[cfg(feature = "pml-animations")]
+        {
+            if let Some(it0) = &self.timing {
+                it0.visit_element("p:timing", v);
+            }
+        }
+        #[cfg(feature = "pml-extensions")]
+        {
+            if let Some(it0) = &self.ext_lst {
+                it0.visit_element("p:extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SlideIdList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.sld_id {
+            it0.visit_element("p:sldId", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SlideLayout {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.common_slide_data.visit_element("p:cSld", v);
+        #[cfg(feature = "pml-styling")]
+        {
+            if let Some(it0) = &self.clr_map_ovr {
+                it0.visit_element("p:clrMapOvr", v);
+            }
+        }
+        #[cfg(feature = "pml-transitions")]
+        {
+            if let Some(it0) = &self.transition {
+                it0.visit_element("p:transition", v);
+            }
+        }
+        #[cfg(feature = "pml-animations")]
+        {
+            if let Some(it0) = &self.timing {
+                it0.visit_element("p:timing", v);
+            }
+        }
+        #[cfg(feature = "pml-masters")]
+        {
+            if let Some(it0) = &self.hf {
+                it0.visit_element("p:hf", v);
+            }
+        }
+        #[cfg(feature = "pml-extensions")]
+        {
+            if let Some(it0) = &self.ext_lst {
+                it0.visit_element("p:extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SlideMaster {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.common_slide_data.visit_element("p:cSld", v);
+        self.clr_map.visit_element("p:clrMap", v);
+        #[cfg(feature = "pml-masters")]
+        {
+            if let Some(it0) = &self.sld_layout_id_lst {
+                it0.visit_element("p:sldLayoutIdLst", v);
+            }
+        }
+        #[cfg(feature = "pml-transitions")]
+        {
+            if let Some(it0) = &self.transition {
+                it0.visit_element("p:transition", v);
+            }
+        }
+        #[cfg(feature = "pml-animations")]
+        {
+            if let Some(it0) = &self.timing {
+                it0.visit_element("p:timing", v);
+            }
+        }
+        #[cfg(feature = "pml-masters")]
+        {
+            if let Some(it0) = &self.hf {
+                it0.visit_element("p:hf", v);
+            }
+        }
+        #[cfg(feature = "pml-styling")]
+        {
+            if let Some(it0) = &self.tx_styles {
+                it0.visit_element("p:txStyles", v);
+            }
+        }
+        #[cfg(feature = "pml-extensions")]
+        {
+            if let Some(it0) = &self.ext_lst {
+                it0.visit_element("p:extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SlideTiming {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "pml-animations")]
+        {
+            if let Some(it0) = &self.tn_lst {
+                it0.visit_element("p:tnLst", v);
+            }
+        }
+        #[cfg(feature = "pml-animations")]
+        {
+            if let Some(it0) = &self.bld_lst {
+                it0.visit_element("p:bldLst", v);
+            }
+        }
+        #[cfg(feature = "pml-extensions")]
+        {
+            if let Some(it0) = &self.ext_lst {
+                it0.visit_element("p:extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SlideTransition {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "pml-transitions")]
+        {
+            if let Some(it0) = &self.blinds {
+                it0.visit_element("p:blinds", v);
+            }
+        }
+        #[cfg(feature = "pml-transitions")]
+        {
+            if let Some(it0) = &self.checker {
+                it0.visit_element("p:checker", v);
+            }
+        }
+        #[cfg(feature = "pml-transitions")]
+        {
+            if let Some(it0) = &self.circle {
+                it0.visit_element("p:circle", v);
+            }
+        }
+        #[cfg(feature = "pml-transitions")]
+        {
+            if let Some(it0) = &self.dissolve {
+                it0.visit_element("p:dissolve", v);
+            }
+        }
+        #[cfg(feature = "pml-transitions")]
+        {
+            if let Some(it0) = &self.comb {
+                it0.visit_element("p:comb", v);
+            }
+        }
+        #[cfg(feature = "pml-transitions")]
+        {
+            if let Some(it0) = &self.cover {
+                it0.visit_element("p:cover", v);
+            }
+        }
+        #[cfg(feature = "pml-transitions")]
+        {
+            if let Some(it0) = &self.cut {
+                it0.visit_element("p:cut", v);
+            }
+        }
+        #[cfg(feature = "pml-transitions")]
+        {
+            if let Some(it0) = &self.diamond {
+                it0.visit_element("p:diamond", v);
+            }
+        }
+        #[cfg(feature = "pml-transitions")]
+        {
+            if let Some(it0) = &self.fade {
+                it0.visit_element("p:fade", v);
+            }
+        }
+        #[cfg(feature = "pml-transitions")]
+        {
+            if let Some(it0) = &self.newsflash {
+                it0.visit_element("p:newsflash", v);
+            }
+        }
+        #[cfg(feature = "pml-transitions")]
+        {
+            if let Some(it0) = &self.plus {
+                it0.visit_element("p:plus", v);
+            }
+        }
+        #[cfg(feature = "pml-transitions")]
+        {
+            if let Some(it0) = &self.pull {
+                it0.visit_element("p:pull", v);
+            }
+        }
+        #[cfg(feature = "pml-transitions")]
+        {
+            if let Some(it0) = &self.push {
+                it0.visit_element("p:push", v);
+            }
+        }
+        #[cfg(feature = "pml-transitions")]
+        {
+            if let Some(it0) = &self.random {
+                it0.visit_element("p:random", v);
+            }
+        }
+        #[cfg(feature = "pml-transitions")]
+        {
+            if let Some(it0) = &self.random_bar {
+                it0.visit_element("p:randomBar", v);
+            }
+        }
+        #[cfg(feature = "pml-transitions")]
+        {
+            if let Some(it0) = &self.split {
+                it0.visit_element("p:split", v);
+            }
+        }
+        #[cfg(feature = "pml-transitions")]
+        {
+            if let Some(it0) = &self.strips {
+                it0.visit_element("p:strips", v);
+            }
+        }
+        #[cfg(feature = "pml-transitions")]
+        {
+            if let Some(it0) = &self.wedge {
+                it0.visit_element("p:wedge", v);
+            }
+        }
+        #[cfg(feature = "pml-transitions")]
+        {
+            if let Some(it0) = &self.wheel {
+                it0.visit_element("p:wheel", v);
+            }
+        }
+        #[cfg(feature = "pml-transitions")]
+        {
+            if let Some(it0) = &self.wipe {
+                it0.visit_element("p:wipe", v);
+            }
+        }
+        #[cfg(feature = "pml-transitions")]
+        {
+            if let Some(it0) = &self.zoom {
+                it0.visit_element("p:zoom", v);
+            }
+        }
+        #[cfg(feature = "pml-transitions")]
+        {
+            if let Some(it0) = &self.snd_ac {
+                it0.visit_element("p:sndAc", v);
+            }
+        }
+        #[cfg(feature = "pml-extensions")]
+        {
+            if let Some(it0) = &self.ext_lst {
+                it0.visit_element("p:extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
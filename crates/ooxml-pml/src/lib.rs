@@ -32,6 +32,24 @@
 //! pres.save("output.pptx")?;
 //! # Ok::<(), ooxml_pml::Error>(())
 //! ```
+//!
+//! # Editing Presentations
+//!
+//! Existing files can be modified in place. Slides that are not edited, along
+//! with masters, layouts, media and charts, are copied to the output unchanged.
+//!
+//! ```no_run
+//! use ooxml_pml::Presentation;
+//!
+//! let mut pres = Presentation::open("template.pptx")?;
+//! pres.replace_text("{{customer}}", "Contoso")?;
+//! let copy = pres.duplicate_slide(0)?;
+//! pres.add_text_box(copy, "Appendix", 457200, 457200, 4572000, 914400)?;
+//! pres.move_slide(copy, pres.slide_count() - 1)?;
+//! pres.remove_slide(1)?;
+//! pres.save("report.pptx")?;
+//! # Ok::<(), ooxml_pml::Error>(())
+//! ```
 
 pub mod error;
 pub mod ext;
@@ -64,7 +82,30 @@ pub mod generated_serializers;
 /// Serializers for the generated ECMA-376 types. See [`generated_serializers`] for details.
 pub use generated_serializers as serializers;
 
+/// `Visit`/`VisitMut` traversal of the generated types.
+///
+/// Each trait has a method per generated type that defaults to visiting its
+/// fields, variants and unknown children (as raw XML) in declaration order;
+/// override the methods of interest and call the matching `walk_*` function
+/// to keep descending.
+///
+/// Visitors of embedded DrawingML types come from `ooxml_dml::visit`,
+/// whose traits are supertraits of these and also visit raw XML.
+///
+/// The module also implements [`ooxml_xml::QueryTree`] for the generated
+/// types, so [`ooxml_xml::Query::select_tree`] can run path queries over them
+/// as the XML they serialize to. Derived from [`generated`] and
+/// [`generated_serializers`] by `ooxml-codegen`.
+///
+/// Re-exported as [`visit`].
+#[cfg(feature = "visit")]
+pub mod generated_visit;
+/// Traversal of the generated ECMA-376 types. See [`generated_visit`] for details.
+#[cfg(feature = "visit")]
+pub use generated_visit as visit;
+
 pub use error::{Error, Result};
+pub use ooxml_opc::{Conformance, PackageLimits, ParseLimits, SerializeOptions, VbaProject};
 #[cfg(feature = "pml-charts")]
 pub use presentation::SmartArtParts;
 pub use presentation::{
@@ -85,6 +126,6 @@ pub use writer::{
 #[cfg(feature = "pml-notes")]
 pub use ext::NotesSlideExt;
 pub use ext::{
-    CommonSlideDataExt, ConnectorExt, GraphicalObjectFrameExt, GroupShapeExt, PictureExt, ShapeExt,
-    SlideExt, SlideLayoutExt, SlideMasterExt,
+    CommonSlideDataExt, ConnectorExt, GraphicalObjectFrameExt, GroupShapeExt, GroupShapeMutExt,
+    PictureExt, ShapeExt, SlideExt, SlideLayoutExt, SlideMasterExt, SlideMutExt,
 };
@@ -3,15 +3,23 @@
 //! This module provides the main entry point for working with PPTX files.
 
 use crate::error::{Error, Result};
-use crate::ext::{CommonSlideDataExt, GroupShapeExt, PictureExt, ShapeExt};
+use crate::ext::{CommonSlideDataExt, GroupShapeExt, PictureExt, ShapeExt, SlideMutExt};
 use crate::parsers::FromXml;
+use crate::serializers::SerializeError;
 use crate::types;
+use crate::writer::{build_text_box, serialize_with_ns_decls};
 use ooxml_dml::ext::{TextBodyExt, TextParagraphExt, TextRunExt};
-use ooxml_opc::{Package, Relationships};
-use quick_xml::Reader;
+use ooxml_opc::{
+    Conformance, Package, PackageLimits, PackageWriter, Relationship, Relationships, VbaProject,
+    rel_type, relative_target, rels_path_for, resolve_target,
+};
+use ooxml_xml::{Alternates, MceProcessor, RawXmlElement, RawXmlNode, root_attributes};
 use quick_xml::events::Event;
+use quick_xml::{NsReader, Reader};
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::fs::File;
-use std::io::{BufReader, Cursor, Read, Seek};
+use std::io::{BufReader, Cursor, Read, Seek, Write};
 use std::path::Path;
 
 // Relationship types (ECMA-376 Part 1)
@@ -24,30 +32,90 @@ const REL_SLIDE_MASTER: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/slideMaster";
 const REL_SLIDE_LAYOUT: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/slideLayout";
+const REL_VBA_PROJECT: &str = "http://schemas.microsoft.com/office/2006/relationships/vbaProject";
+const REL_MODERN_COMMENTS: &str =
+    "http://schemas.microsoft.com/office/2018/10/relationships/comments";
+
+/// Relationship types of parts that belong to a single slide. Duplicating a
+/// slide copies these parts and removing a slide removes them.
+const PER_SLIDE_RELS: &[&str] = &[REL_NOTES_SLIDE, rel_type::COMMENTS, REL_MODERN_COMMENTS];
+
+// Content types (ECMA-376 Part 1)
+const CT_PRESENTATION: &str =
+    "application/vnd.openxmlformats-officedocument.presentationml.presentation.main+xml";
+const CT_SLIDE: &str = "application/vnd.openxmlformats-officedocument.presentationml.slide+xml";
+const CT_RELATIONSHIPS: &str = "application/vnd.openxmlformats-package.relationships+xml";
+
+// Namespaces
+const NS_PRESENTATION: &str = "http://schemas.openxmlformats.org/presentationml/2006/main";
+const NS_DRAWING: &str = "http://schemas.openxmlformats.org/drawingml/2006/main";
+const NS_RELATIONSHIPS: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships";
+
+/// Namespace declarations for re-serialized slide parts.
+const NS_SLIDE_DECLS: &[(&str, &str)] = &[
+    ("xmlns:a", NS_DRAWING),
+    ("xmlns:r", NS_RELATIONSHIPS),
+    ("xmlns:p", NS_PRESENTATION),
+];
+
+const XML_DECLARATION: &[u8] = b"<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n";
 
 /// A PowerPoint presentation.
 ///
-/// This is the main entry point for reading PPTX files.
+/// This is the main entry point for reading PPTX files. Opened presentations
+/// can also be edited and written back with [`save`](Self::save) or
+/// [`write`](Self::write); parts that were not edited are copied unchanged.
 pub struct Presentation<R: Read + Seek> {
     package: Package<R>,
     /// Path to the presentation part.
-    #[allow(dead_code)]
     presentation_path: String,
     /// Presentation-level relationships.
-    #[allow(dead_code)]
     pres_rels: Relationships,
+    /// Root element of the presentation part. It is kept as raw XML so that
+    /// editing the slide list leaves everything else untouched.
+    pres_root: RawXmlElement,
     /// Slide metadata (relationship ID, path).
     slide_info: Vec<SlideInfo>,
     /// Slide masters in the presentation.
     slide_masters: Vec<SlideMaster>,
     /// Slide layouts in the presentation.
     slide_layouts: Vec<SlideLayout>,
+    /// Pending modifications, applied by [`write`](Self::write).
+    edits: Edits,
+}
+
+/// Modifications made to an opened presentation that have not been written yet.
+#[derive(Debug, Default)]
+struct Edits {
+    /// Slides loaded for editing, keyed by part path.
+    slides: HashMap<String, types::Slide>,
+    /// Root element attributes (namespace declarations, `mc:Ignorable`, ...)
+    /// of slides that will be re-serialized, keyed by part path.
+    root_attrs: HashMap<String, Vec<(String, String)>>,
+    /// Stacking order of the shape trees of slides that will be
+    /// re-serialized, keyed by part path.
+    shape_order: HashMap<String, ShapeOrder>,
+    /// The `mc:AlternateContent` elements of slides that will be
+    /// re-serialized, keyed by part path.
+    alternates: HashMap<String, Alternates>,
+    /// Slides created by duplication, keyed by part path.
+    copied_slides: HashMap<String, Vec<u8>>,
+    /// Notes and comments copied along with duplicated slides, keyed by part
+    /// path, with their data and content type.
+    copied_parts: HashMap<String, (Vec<u8>, String)>,
+    /// Relationships of the duplicated slides and copied parts, keyed by
+    /// part path.
+    rels: HashMap<String, Relationships>,
+    /// Parts to leave out of the written package.
+    removed_parts: Vec<String>,
+    presentation: bool,
+    pres_rels: bool,
 }
 
 /// Metadata about a slide.
 #[derive(Debug, Clone)]
 struct SlideInfo {
-    #[allow(dead_code)]
     rel_id: String,
     path: String,
     index: usize,
@@ -188,14 +256,24 @@ impl Presentation<BufReader<File>> {
 impl<R: Read + Seek> Presentation<R> {
     /// Open a presentation from a reader.
     pub fn from_reader(reader: R) -> Result<Self> {
-        let mut package = Package::open(reader)?;
+        Self::from_package(Package::open(reader)?)
+    }
 
+    /// Open an untrusted presentation from a reader, enforcing resource limits.
+    ///
+    /// Fails with [`ooxml_opc::Error::LimitExceeded`] if the package or any
+    /// part read from it exceeds the limits.
+    pub fn from_reader_with_limits(reader: R, limits: &PackageLimits) -> Result<Self> {
+        Self::from_package(Package::open_with_limits(reader, limits)?)
+    }
+
+    fn from_package(mut package: Package<R>) -> Result<Self> {
         // Find the presentation part via root relationships
         let root_rels = package.read_relationships()?;
         let pres_rel = root_rels
             .get_by_type(REL_OFFICE_DOCUMENT)
             .ok_or_else(|| Error::Invalid("Missing presentation relationship".into()))?;
-        let presentation_path = pres_rel.target.clone();
+        let presentation_path = resolve_target("", &pres_rel.target);
 
         // Load presentation relationships
         let pres_rels = package
@@ -205,14 +283,15 @@ impl<R: Read + Seek> Presentation<R> {
         // Parse presentation.xml to get slide list
         let pres_xml = package.read_part(&presentation_path)?;
         let slide_order = parse_presentation_slides(&pres_xml)?;
+        let pres_root = parse_raw_root(&pres_xml)?;
 
         // Load slide masters
         let mut slide_masters: Vec<SlideMaster> = Vec::new();
         let mut slide_layouts: Vec<SlideLayout> = Vec::new();
 
         for rel in pres_rels.iter() {
-            if rel.relationship_type == REL_SLIDE_MASTER {
-                let path = resolve_path(&presentation_path, &rel.target);
+            if rel.has_type(REL_SLIDE_MASTER) {
+                let path = resolve_target(&presentation_path, &rel.target);
                 if let Ok(master_xml) = package.read_part(&path) {
                     let master = parse_slide_master(&master_xml, &path);
                     let master_path = path.clone();
@@ -220,8 +299,8 @@ impl<R: Read + Seek> Presentation<R> {
                     // Load layouts for this master
                     if let Ok(master_rels) = package.read_part_relationships(&path) {
                         for layout_rel in master_rels.iter() {
-                            if layout_rel.relationship_type == REL_SLIDE_LAYOUT {
-                                let layout_path = resolve_path(&master_path, &layout_rel.target);
+                            if layout_rel.has_type(REL_SLIDE_LAYOUT) {
+                                let layout_path = resolve_target(&master_path, &layout_rel.target);
                                 if let Ok(layout_xml) = package.read_part(&layout_path) {
                                     let layout = parse_slide_layout(
                                         &layout_xml,
@@ -242,8 +321,8 @@ impl<R: Read + Seek> Presentation<R> {
         // Build slide info from relationships, getting layout references from slide XML
         let mut slide_info: Vec<SlideInfo> = Vec::new();
         for rel in pres_rels.iter() {
-            if rel.relationship_type == REL_SLIDE {
-                let path = resolve_path(&presentation_path, &rel.target);
+            if rel.has_type(REL_SLIDE) {
+                let path = resolve_target(&presentation_path, &rel.target);
                 // Find index from slide order
                 let index = slide_order
                     .iter()
@@ -275,9 +354,11 @@ impl<R: Read + Seek> Presentation<R> {
             package,
             presentation_path,
             pres_rels,
+            pres_root,
             slide_info,
             slide_masters,
             slide_layouts,
+            edits: Edits::default(),
         })
     }
 
@@ -303,6 +384,24 @@ impl<R: Read + Seek> Presentation<R> {
             .find(|l| l.name.as_deref() == Some(name))
     }
 
+    /// Check whether the presentation is macro-enabled (`.pptm`, `.potm` or `.ppsm`).
+    pub fn is_macro_enabled(&self) -> bool {
+        self.package
+            .content_type(&self.presentation_path)
+            .is_some_and(|ct| ct.contains(".macroEnabled"))
+    }
+
+    /// Load the VBA project (`vbaProject.bin`) of a macro-enabled presentation.
+    ///
+    /// The project is carried through unchanged when the presentation is saved.
+    pub fn vba_project(&mut self) -> Result<Option<VbaProject>> {
+        let Some(rel) = self.pres_rels.get_by_type(REL_VBA_PROJECT) else {
+            return Ok(None);
+        };
+        let path = resolve_target(&self.presentation_path, &rel.target);
+        Ok(Some(VbaProject::new(self.package.read_part(&path)?)))
+    }
+
     /// Get a slide by index (0-based).
     pub fn slide(&mut self, index: usize) -> Result<Slide> {
         let info = self
@@ -322,10 +421,11 @@ impl<R: Read + Seek> Presentation<R> {
 
     /// Load a slide's data.
     fn load_slide(&mut self, info: &SlideInfo) -> Result<Slide> {
-        let data = self.package.read_part(&info.path)?;
-
-        // Parse slide using generated FromXml parser
-        let inner = parse_slide_xml(&data)?;
+        // Parse slide using generated FromXml parser, unless it is being edited
+        let inner = match self.edits.slides.get(&info.path) {
+            Some(slide) => slide.clone(),
+            None => parse_slide_compat(&self.read_slide_part(&info.path)?, &info.path)?,
+        };
 
         // Extract tables from graphic frames
         let tables = extract_tables_from_slide(&inner);
@@ -346,11 +446,11 @@ impl<R: Read + Seek> Presentation<R> {
         };
 
         // Try to load speaker notes
-        if let Ok(slide_rels) = self.package.read_part_relationships(&info.path)
+        if let Ok(slide_rels) = self.slide_relationships(&info.path)
             && let Some(notes_rel) = slide_rels.get_by_type(REL_NOTES_SLIDE)
         {
-            let notes_path = resolve_path(&info.path, &notes_rel.target);
-            if let Ok(notes_data) = self.package.read_part(&notes_path) {
+            let notes_path = resolve_target(&info.path, &notes_rel.target);
+            if let Ok(notes_data) = self.read_slide_part(&notes_path) {
                 slide.notes = parse_notes_slide(&notes_data);
             }
         }
@@ -369,8 +469,7 @@ impl<R: Read + Seek> Presentation<R> {
 
         // Get slide relationships
         let slide_rels = self
-            .package
-            .read_part_relationships(slide.slide_path())
+            .slide_relationships(slide.slide_path())
             .map_err(|_| Error::Invalid("Failed to read slide relationships".into()))?;
 
         // Find the image relationship
@@ -379,7 +478,7 @@ impl<R: Read + Seek> Presentation<R> {
             .ok_or_else(|| Error::Invalid(format!("Image relationship {} not found", rel_id)))?;
 
         // Resolve the image path
-        let image_path = resolve_path(slide.slide_path(), &rel.target);
+        let image_path = resolve_target(slide.slide_path(), &rel.target);
 
         // Read image data
         let data = self.package.read_part(&image_path)?;
@@ -401,8 +500,7 @@ impl<R: Read + Seek> Presentation<R> {
     pub fn resolve_hyperlink(&mut self, slide: &Slide, rel_id: &str) -> Result<String> {
         // Get slide relationships
         let slide_rels = self
-            .package
-            .read_part_relationships(slide.slide_path())
+            .slide_relationships(slide.slide_path())
             .map_err(|_| Error::Invalid("Failed to read slide relationships".into()))?;
 
         // Find the hyperlink relationship
@@ -445,18 +543,17 @@ impl<R: Read + Seek> Presentation<R> {
     ) -> Result<ooxml_dml::types::ChartSpace> {
         // Resolve the chart part path via slide relationships
         let slide_rels = self
-            .package
-            .read_part_relationships(slide.slide_path())
+            .slide_relationships(slide.slide_path())
             .map_err(|_| Error::Invalid("Failed to read slide relationships".into()))?;
 
         let rel = slide_rels
             .get(rel_id)
             .ok_or_else(|| Error::Invalid(format!("Chart relationship {} not found", rel_id)))?;
 
-        let chart_path = resolve_path(slide.slide_path(), &rel.target);
+        let chart_path = resolve_target(slide.slide_path(), &rel.target);
         let chart_xml = self.package.read_part(&chart_path)?;
 
-        parse_chart(&chart_xml)
+        parse_chart(&chart_xml, &chart_path)
     }
 
     /// Load all four SmartArt parts for a diagram.
@@ -478,15 +575,14 @@ impl<R: Read + Seek> Presentation<R> {
         rel_ids: &DiagramRelIds,
     ) -> Result<SmartArtParts> {
         let slide_rels = self
-            .package
-            .read_part_relationships(slide.slide_path())
+            .slide_relationships(slide.slide_path())
             .map_err(|_| Error::Invalid("Failed to read slide relationships".into()))?;
 
         // Helper: resolve a relationship ID to a path.
         let resolve_rel = |rel_id: &str| -> Option<String> {
             slide_rels
                 .get(rel_id)
-                .map(|r| resolve_path(slide.slide_path(), &r.target))
+                .map(|r| resolve_target(slide.slide_path(), &r.target))
         };
 
         // Data model (dm) — required.
@@ -497,22 +593,25 @@ impl<R: Read + Seek> Presentation<R> {
             ))
         })?;
         let dm_xml = self.package.read_part(&dm_path)?;
-        let data = parse_data_model(&dm_xml)?;
+        let data = parse_data_model(&dm_xml, &dm_path)?;
 
         // Layout definition (lo) — optional/graceful.
-        let layout = resolve_rel(&rel_ids.lo)
-            .and_then(|path| self.package.read_part(&path).ok())
-            .and_then(|xml| parse_diagram_definition(&xml).ok());
+        let layout = resolve_rel(&rel_ids.lo).and_then(|path| {
+            let xml = self.package.read_part(&path).ok()?;
+            parse_diagram_definition(&xml, &path).ok()
+        });
 
         // Colors (cs) — optional/graceful.
-        let colors = resolve_rel(&rel_ids.cs)
-            .and_then(|path| self.package.read_part(&path).ok())
-            .and_then(|xml| parse_diagram_colors(&xml).ok());
+        let colors = resolve_rel(&rel_ids.cs).and_then(|path| {
+            let xml = self.package.read_part(&path).ok()?;
+            parse_diagram_colors(&xml, &path).ok()
+        });
 
         // Quick style (qs) — optional/graceful.
-        let style = resolve_rel(&rel_ids.qs)
-            .and_then(|path| self.package.read_part(&path).ok())
-            .and_then(|xml| parse_diagram_style(&xml).ok());
+        let style = resolve_rel(&rel_ids.qs).and_then(|path| {
+            let xml = self.package.read_part(&path).ok()?;
+            parse_diagram_style(&xml, &path).ok()
+        });
 
         Ok(SmartArtParts {
             data,
@@ -523,6 +622,496 @@ impl<R: Read + Seek> Presentation<R> {
     }
 }
 
+impl<R: Read + Seek> Presentation<R> {
+    /// Get a slide for editing.
+    ///
+    /// The slide is parsed on first access and kept in memory until the
+    /// presentation is written; later calls (and [`slide`](Self::slide)) see
+    /// the edited version.
+    ///
+    /// The generated types store each kind of element separately, so the
+    /// stacking order of a shape tree is recorded when the slide is loaded and
+    /// restored when it is written: elements keep their original order, and
+    /// elements added while editing go on top in the order of their kinds.
+    pub fn slide_mut(&mut self, index: usize) -> Result<&mut types::Slide> {
+        let path = self.slide_part_path(index)?.to_string();
+        if !self.edits.slides.contains_key(&path) {
+            let data = self.read_slide_part(&path)?;
+            let (slide, compat_xml, alternates) = parse_slide_for_editing(&data, &path)?;
+            self.insert_edited_slide(&path, &compat_xml, slide, alternates)?;
+        }
+        Ok(self
+            .edits
+            .slides
+            .get_mut(&path)
+            .expect("slide loaded above"))
+    }
+
+    /// Replace text on every slide. Returns the number of replacements.
+    ///
+    /// Only slides that contain a match are re-serialized. See
+    /// [`GroupShapeMutExt::replace_text`](crate::GroupShapeMutExt::replace_text)
+    /// for how text is matched.
+    pub fn replace_text(&mut self, find: &str, replace: &str) -> Result<usize> {
+        let mut count = 0;
+        for index in 0..self.slide_info.len() {
+            count += self.replace_text_in_slide(index, find, replace)?;
+        }
+        Ok(count)
+    }
+
+    /// Replace text on a single slide. Returns the number of replacements.
+    pub fn replace_text_in_slide(
+        &mut self,
+        index: usize,
+        find: &str,
+        replace: &str,
+    ) -> Result<usize> {
+        let path = self.slide_part_path(index)?.to_string();
+        if let Some(slide) = self.edits.slides.get_mut(&path) {
+            return Ok(slide.replace_text(find, replace));
+        }
+
+        let data = self.read_slide_part(&path)?;
+        let (mut slide, compat_xml, alternates) = parse_slide_for_editing(&data, &path)?;
+        let count = slide.replace_text(find, replace);
+        if count > 0 {
+            self.insert_edited_slide(&path, &compat_xml, slide, alternates)?;
+        }
+        Ok(count)
+    }
+
+    /// Keep a slide for re-serialization, recording what the generated types
+    /// don't model from the XML it was parsed from.
+    fn insert_edited_slide(
+        &mut self,
+        path: &str,
+        data: &[u8],
+        slide: types::Slide,
+        alternates: Alternates,
+    ) -> Result<()> {
+        let mut order = ShapeOrder::new();
+        if let Some(tree) = shape_tree(&parse_raw_root(data)?) {
+            record_shape_order(tree, &mut order);
+        }
+        self.edits.shape_order.insert(path.to_string(), order);
+        self.edits
+            .root_attrs
+            .insert(path.to_string(), root_attributes(data));
+        self.edits.alternates.insert(path.to_string(), alternates);
+        self.edits.slides.insert(path.to_string(), slide);
+        Ok(())
+    }
+
+    /// Add a shape to a slide, giving it an unused drawing element ID.
+    /// Returns the assigned ID.
+    pub fn add_shape(&mut self, index: usize, shape: types::Shape) -> Result<u32> {
+        Ok(self.slide_mut(index)?.add_shape(shape))
+    }
+
+    /// Add a text box to a slide. Position and size are in EMUs.
+    /// Returns the drawing element ID of the new shape.
+    pub fn add_text_box(
+        &mut self,
+        index: usize,
+        text: &str,
+        x: i64,
+        y: i64,
+        width: i64,
+        height: i64,
+    ) -> Result<u32> {
+        let slide = self.slide_mut(index)?;
+        let id = slide.add_shape(build_text_box(text, x, y, width, height, 0));
+        if let Some(shape) = slide.common_slide_data.shape_tree.shape.last_mut() {
+            shape.non_visual_properties.c_nv_pr.name = format!("TextBox {}", id);
+        }
+        Ok(id)
+    }
+
+    /// Remove the top-level shape, picture, connector, graphic frame or group
+    /// with the given name from a slide. Returns whether one was removed.
+    pub fn remove_shape(&mut self, index: usize, name: &str) -> Result<bool> {
+        Ok(self.slide_mut(index)?.remove_shape(name))
+    }
+
+    /// Move a slide to a new position (0-based). Other slides shift to make room.
+    pub fn move_slide(&mut self, from: usize, to: usize) -> Result<()> {
+        self.slide_part_path(from)?;
+        self.slide_part_path(to)?;
+        if from == to {
+            return Ok(());
+        }
+
+        let info = self.slide_info.remove(from);
+        self.slide_info.insert(to, info);
+        self.sync_slide_list();
+
+        // Keep the section list in the same order as the slide list
+        if let Some(id) = self.slide_id(to) {
+            let previous = to.checked_sub(1).and_then(|i| self.slide_id(i));
+            place_section_entry(&mut self.pres_root, id, previous);
+        }
+        self.edits.presentation = true;
+        Ok(())
+    }
+
+    /// Remove a slide together with its speaker notes and comments.
+    ///
+    /// The slide is also dropped from custom shows and sections. Other parts
+    /// that only the slide used (images, charts) stay in the package, and
+    /// links from other slides to the removed slide are not updated.
+    pub fn remove_slide(&mut self, index: usize) -> Result<()> {
+        let path = self.slide_part_path(index)?.to_string();
+        let slide_rels = self.slide_relationships(&path)?;
+        let slide_id = self.slide_id(index).map(|id| id.to_string());
+        let rel_attr = relationship_id_attr(&self.pres_root);
+        let info = self.slide_info.remove(index);
+
+        // Drop the slide from custom shows (`p:sld`) and sections (`p14:sldId`)
+        remove_elements(&mut self.pres_root, &|e| match local_name(&e.name) {
+            "sld" => attribute(e, &rel_attr) == Some(info.rel_id.as_str()),
+            "sldId" => slide_id.is_some() && attribute(e, "id") == slide_id.as_deref(),
+            _ => false,
+        });
+        self.pres_rels.remove(&info.rel_id);
+        self.sync_slide_list();
+
+        let mut parts = vec![info.path.clone()];
+        for rel in slide_rels.iter().filter(|rel| is_per_slide(rel)) {
+            parts.push(resolve_target(&info.path, &rel.target));
+        }
+        for part in parts {
+            self.edits.slides.remove(&part);
+            self.edits.root_attrs.remove(&part);
+            self.edits.shape_order.remove(&part);
+            self.edits.alternates.remove(&part);
+            self.edits.copied_slides.remove(&part);
+            self.edits.copied_parts.remove(&part);
+            self.edits.rels.remove(&part);
+            for path in [rels_path_for(&part), part] {
+                if self.package.has_part(&path) {
+                    self.edits.removed_parts.push(path);
+                }
+            }
+        }
+
+        self.edits.presentation = true;
+        self.edits.pres_rels = true;
+        Ok(())
+    }
+
+    /// Duplicate a slide, inserting the copy right after the original.
+    /// Returns the index of the new slide.
+    ///
+    /// The copy gets its own speaker notes and comments; images, charts and
+    /// other related parts are shared with the original.
+    pub fn duplicate_slide(&mut self, index: usize) -> Result<usize> {
+        let source = self.slide_part_path(index)?.to_string();
+        let data = match self.edits.slides.get(&source) {
+            Some(slide) => self.serialize_slide(&source, slide)?,
+            None => self.read_slide_part(&source)?,
+        };
+        let mut rels = self.slide_relationships(&source)?;
+
+        // Pick an unused part name next to the existing slides
+        let (target, path) = (1..)
+            .map(|n| {
+                let target = format!("slides/slide{}.xml", n);
+                let path = resolve_target(&self.presentation_path, &target);
+                (target, path)
+            })
+            .find(|(_, path)| {
+                !self.package.has_part(path) && !self.edits.copied_slides.contains_key(path)
+            })
+            .expect("unbounded range");
+
+        // Copy the notes and comments and point the copy at them
+        let per_slide: Vec<Relationship> = rels
+            .iter()
+            .filter(|rel| is_per_slide(rel))
+            .cloned()
+            .collect();
+        for rel in per_slide {
+            let part = resolve_target(&source, &rel.target);
+            let copy = self.copy_part(&part, &source, &path)?;
+            rels.remove(&rel.id);
+            rels.add(Relationship::new(
+                rel.id,
+                rel.relationship_type,
+                relative_target(&path, &copy),
+            ));
+        }
+
+        let rel_id = self.pres_rels.next_id();
+        self.pres_rels
+            .add(Relationship::new(rel_id.clone(), REL_SLIDE, target));
+        self.edits.copied_slides.insert(path.clone(), data);
+        self.edits.rels.insert(path.clone(), rels);
+
+        let source_id = self.slide_id(index);
+        let layout_rel_id = self.slide_info[index].layout_rel_id.clone();
+        self.slide_info.insert(
+            index + 1,
+            SlideInfo {
+                rel_id,
+                path,
+                index: index + 1,
+                layout_rel_id,
+            },
+        );
+        self.sync_slide_list();
+        if let (Some(source_id), Some(id)) = (source_id, self.slide_id(index + 1)) {
+            place_section_entry(&mut self.pres_root, id, Some(source_id));
+        }
+
+        self.edits.presentation = true;
+        self.edits.pres_rels = true;
+        Ok(index + 1)
+    }
+
+    /// Save the presentation to a file, applying all pending edits.
+    pub fn save<P: AsRef<Path>>(&mut self, path: P) -> Result<()> {
+        let file = File::create(path)?;
+        self.write(file)
+    }
+
+    /// Write the presentation to a writer, applying all pending edits.
+    ///
+    /// Edited slides are serialized with the generated `ToXml` serializers and
+    /// replace their originals; all other parts are copied unchanged.
+    pub fn write<W: Write + Seek>(&mut self, writer: W) -> Result<()> {
+        // (path, content type for new parts, bytes)
+        let mut parts: Vec<(String, &str, Vec<u8>)> = Vec::new();
+
+        if self.edits.presentation {
+            let xml = serialize_raw_root(&self.pres_root)?;
+            parts.push((self.presentation_path.clone(), CT_PRESENTATION, xml));
+        }
+        if self.edits.pres_rels {
+            let xml = self.pres_rels.serialize().into_bytes();
+            parts.push((
+                rels_path_for(&self.presentation_path),
+                CT_RELATIONSHIPS,
+                xml,
+            ));
+        }
+        for (path, rels) in &self.edits.rels {
+            let xml = rels.serialize().into_bytes();
+            parts.push((rels_path_for(path), CT_RELATIONSHIPS, xml));
+        }
+        for (path, data) in &self.edits.copied_slides {
+            if !self.edits.slides.contains_key(path) {
+                parts.push((path.clone(), CT_SLIDE, data.clone()));
+            }
+        }
+        for (path, (data, content_type)) in &self.edits.copied_parts {
+            parts.push((path.clone(), content_type.as_str(), data.clone()));
+        }
+        for (path, slide) in &self.edits.slides {
+            let xml = self.serialize_slide(path, slide)?;
+            parts.push((path.clone(), CT_SLIDE, xml));
+        }
+
+        let replacements: HashMap<&str, &[u8]> = parts
+            .iter()
+            .filter(|(path, _, _)| self.package.has_part(path))
+            .map(|(path, _, data)| (path.as_str(), data.as_slice()))
+            .collect();
+        let removed: Vec<&str> = self
+            .edits
+            .removed_parts
+            .iter()
+            .map(String::as_str)
+            .collect();
+
+        let mut pkg_writer = PackageWriter::new(writer);
+        // Parts added or rewritten here follow the class of the source document
+        if self.package.conformance()? == Conformance::Strict {
+            pkg_writer.set_conformance(Conformance::Strict);
+        }
+        self.package
+            .copy_to_writer_except(&mut pkg_writer, &replacements, &removed)?;
+        for (path, content_type, data) in &parts {
+            if !replacements.contains_key(path.as_str()) {
+                pkg_writer.add_part(path, content_type, data)?;
+            }
+        }
+        pkg_writer.finish()?;
+
+        Ok(())
+    }
+
+    /// Get the part path of a slide, checking the index.
+    fn slide_part_path(&self, index: usize) -> Result<&str> {
+        self.slide_info
+            .get(index)
+            .map(|info| info.path.as_str())
+            .ok_or_else(|| Error::Invalid(format!("Slide index {} out of range", index)))
+    }
+
+    /// Read a slide part as it currently is in the package or among the
+    /// duplicated slides and their copied parts. Edits held in memory are not
+    /// applied.
+    fn read_slide_part(&mut self, path: &str) -> Result<Vec<u8>> {
+        if let Some(data) = self.edits.copied_slides.get(path) {
+            return Ok(data.clone());
+        }
+        match self.edits.copied_parts.get(path) {
+            Some((data, _)) => Ok(data.clone()),
+            None => Ok(self.package.read_part(path)?),
+        }
+    }
+
+    /// Copy a part that belongs to the slide `source` for its duplicate
+    /// `slide`, returning the name of the copy.
+    ///
+    /// The copy is stored next to the original. Its relationships are copied
+    /// too, with links back to `source` pointed at `slide`.
+    fn copy_part(&mut self, part: &str, source: &str, slide: &str) -> Result<String> {
+        let data = self.read_slide_part(part)?;
+        let content_type = match self.edits.copied_parts.get(part) {
+            Some((_, content_type)) => content_type.clone(),
+            None => self
+                .package
+                .content_type(part)
+                .map(str::to_string)
+                .unwrap_or_else(|| content_type_from_path(part)),
+        };
+
+        // Pick an unused name with the same stem, e.g. notesSlide3.xml
+        let (stem, ext) = part.rsplit_once('.').unwrap_or((part, ""));
+        let stem = stem.trim_end_matches(|c: char| c.is_ascii_digit());
+        let copy = (1..)
+            .map(|n| format!("{}{}.{}", stem, n, ext))
+            .find(|path| {
+                !self.package.has_part(path)
+                    && !self.edits.copied_slides.contains_key(path)
+                    && !self.edits.copied_parts.contains_key(path)
+            })
+            .expect("unbounded range");
+
+        let mut rels = self.slide_relationships(part)?;
+        let back_links: Vec<Relationship> = rels
+            .iter()
+            .filter(|rel| !rel.is_external() && resolve_target(part, &rel.target) == source)
+            .cloned()
+            .collect();
+        for rel in back_links {
+            rels.remove(&rel.id);
+            rels.add(Relationship::new(
+                rel.id,
+                rel.relationship_type,
+                relative_target(&copy, slide),
+            ));
+        }
+        if !rels.is_empty() {
+            self.edits.rels.insert(copy.clone(), rels);
+        }
+        self.edits
+            .copied_parts
+            .insert(copy.clone(), (data, content_type));
+        Ok(copy)
+    }
+
+    /// Read the relationships of a slide or copied part.
+    fn slide_relationships(&mut self, path: &str) -> Result<Relationships> {
+        match self.edits.rels.get(path) {
+            Some(rels) => Ok(rels.clone()),
+            None => Ok(self.package.read_part_relationships(path)?),
+        }
+    }
+
+    /// Serialize an edited slide, restoring the root attributes, stacking
+    /// order and alternate content of the original.
+    fn serialize_slide(&self, path: &str, slide: &types::Slide) -> Result<Vec<u8>> {
+        let mut decls: Vec<(&str, &str)> = self
+            .edits
+            .root_attrs
+            .get(path)
+            .map(|attrs| {
+                attrs
+                    .iter()
+                    .map(|(k, v)| (k.as_str(), v.as_str()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        for &(key, val) in NS_SLIDE_DECLS {
+            if !decls.iter().any(|(k, _)| *k == key) {
+                decls.push((key, val));
+            }
+        }
+        let mut xml = serialize_with_ns_decls(slide, "p:sld", &decls)?;
+        if let Some(order) = self.edits.shape_order.get(path) {
+            let mut root = parse_raw_root(&xml)?;
+            if let Some(tree) = shape_tree_mut(&mut root) {
+                restore_shape_order(tree, order);
+            }
+            xml = serialize_raw_root(&root)?;
+        }
+        match self.edits.alternates.get(path) {
+            Some(alternates) => Ok(alternates
+                .restore(&xml)
+                .map_err(|e| Error::Invalid(format!("Failed to restore alternate content: {}", e)))?
+                .into_owned()),
+            None => Ok(xml),
+        }
+    }
+
+    /// Get the numeric slide ID (`p:sldId@id`) of a slide.
+    fn slide_id(&self, index: usize) -> Option<u32> {
+        let rel_id = &self.slide_info.get(index)?.rel_id;
+        let rel_attr = relationship_id_attr(&self.pres_root);
+        let list = child_elements(&self.pres_root, "sldIdLst").next()?;
+        child_elements(list, "sldId")
+            .find(|e| attribute(e, &rel_attr) == Some(rel_id.as_str()))
+            .and_then(|e| attribute(e, "id")?.parse().ok())
+    }
+
+    /// Rewrite `p:sldIdLst` to follow the current slide order, giving new
+    /// slides an unused slide ID, and renumber the slides.
+    fn sync_slide_list(&mut self) {
+        let rel_attr = relationship_id_attr(&self.pres_root);
+        let list = slide_id_list(&mut self.pres_root);
+        let mut entries: Vec<RawXmlElement> = list
+            .children
+            .drain(..)
+            .filter_map(|node| match node {
+                RawXmlNode::Element(e) => Some(e),
+                _ => None,
+            })
+            .collect();
+        // Slide IDs start at 256 (ECMA-376 Part 1, §19.2.1.33)
+        let mut next_id = entries
+            .iter()
+            .filter_map(|e| attribute(e, "id")?.parse::<u32>().ok())
+            .max()
+            .map_or(256, |id| id.max(255) + 1);
+        let entry_name = format!("{}sldId", prefix(&list.name));
+
+        for (index, info) in self.slide_info.iter_mut().enumerate() {
+            info.index = index;
+            let entry = match entries
+                .iter()
+                .position(|e| attribute(e, &rel_attr) == Some(info.rel_id.as_str()))
+            {
+                Some(pos) => entries.swap_remove(pos),
+                None => {
+                    let mut entry = RawXmlElement::new(entry_name.clone());
+                    entry.attributes = vec![
+                        ("id".to_string(), next_id.to_string()),
+                        (rel_attr.clone(), info.rel_id.clone()),
+                    ];
+                    entry.self_closing = true;
+                    next_id += 1;
+                    entry
+                }
+            };
+            list.children.push(RawXmlNode::Element(entry));
+        }
+        list.self_closing = false;
+    }
+}
+
 /// Relationship IDs for a SmartArt diagram's four constituent parts.
 ///
 /// SmartArt in a slide is represented by four separate XML parts:
@@ -974,7 +1563,7 @@ fn parse_notes_slide(xml: &[u8]) -> Option<String> {
     use ooxml_dml::parsers::FromXml as DmlFromXml;
     use ooxml_dml::types::TextBody;
 
-    let mut reader = Reader::from_reader(Cursor::new(xml));
+    let mut reader = NsReader::from_reader(Cursor::new(xml));
     let mut buf = Vec::new();
     let mut all_text = Vec::new();
 
@@ -1013,36 +1602,9 @@ fn parse_notes_slide(xml: &[u8]) -> Option<String> {
 // Utilities
 // ============================================================================
 
-/// Resolve a relative path against a base path, normalizing `..` segments.
-fn resolve_path(base: &str, target: &str) -> String {
-    if target.starts_with('/') {
-        // Absolute target — return as-is (preserve leading slash per OPC spec).
-        return target.to_string();
-    }
-
-    // Get the directory of the base path
-    let base_dir = if let Some(idx) = base.rfind('/') {
-        &base[..idx + 1]
-    } else {
-        ""
-    };
-
-    normalize_path(&format!("{}{}", base_dir, target))
-}
-
-/// Normalize a path by resolving `..` and `.` segments.
-fn normalize_path(path: &str) -> String {
-    let mut parts: Vec<&str> = Vec::new();
-    for segment in path.split('/') {
-        match segment {
-            ".." => {
-                parts.pop();
-            }
-            "." | "" => {}
-            _ => parts.push(segment),
-        }
-    }
-    parts.join("/")
+/// Whether a relationship points to a part that belongs to a single slide.
+fn is_per_slide(rel: &Relationship) -> bool {
+    !rel.is_external() && PER_SLIDE_RELS.iter().any(|t| rel.has_type(t))
 }
 
 /// Determine content type from file path extension.
@@ -1064,6 +1626,303 @@ fn content_type_from_path(path: &str) -> String {
     .to_string()
 }
 
+/// Parse the root element of a part into a raw element tree.
+///
+/// Attribute values are unescaped so that writing the tree back does not
+/// escape them a second time.
+fn parse_raw_root(xml: &[u8]) -> Result<RawXmlElement> {
+    let mut reader = NsReader::from_reader(Cursor::new(xml));
+    let mut buf = Vec::new();
+    loop {
+        let mut root = match reader.read_event_into(&mut buf)? {
+            Event::Start(e) => RawXmlElement::from_reader(&mut reader, &e)
+                .map_err(|e| Error::Invalid(format!("Parse error: {}", e)))?,
+            Event::Empty(e) => RawXmlElement::from_empty(&e),
+            Event::Eof => return Err(Error::Invalid("Missing root element".into())),
+            _ => {
+                buf.clear();
+                continue;
+            }
+        };
+        unescape_attributes(&mut root);
+        return Ok(root);
+    }
+}
+
+/// Unescape the attribute values of an element and its descendants.
+fn unescape_attributes(element: &mut RawXmlElement) {
+    for (_, value) in &mut element.attributes {
+        if let Ok(unescaped) = quick_xml::escape::unescape(value)
+            && let std::borrow::Cow::Owned(unescaped) = unescaped
+        {
+            *value = unescaped;
+        }
+    }
+    for child in &mut element.children {
+        if let RawXmlNode::Element(child) = child {
+            unescape_attributes(child);
+        }
+    }
+}
+
+/// Serialize a raw element tree as a complete XML part.
+fn serialize_raw_root(root: &RawXmlElement) -> Result<Vec<u8>> {
+    let mut writer = quick_xml::Writer::new(XML_DECLARATION.to_vec());
+    root.write_to(&mut writer).map_err(SerializeError::from)?;
+    Ok(writer.into_inner())
+}
+
+/// Drawing element IDs of the children of a slide's shape tree and of its
+/// groups, in document order, keyed by the ID of the tree or group.
+type ShapeOrder = HashMap<u32, Vec<u32>>;
+
+/// Local names of the elements a shape tree stacks.
+const SHAPE_TREE_CHILDREN: &[&str] =
+    &["sp", "grpSp", "graphicFrame", "cxnSp", "pic", "contentPart"];
+
+fn is_shape_tree_child(node: &RawXmlNode) -> bool {
+    matches!(node, RawXmlNode::Element(e) if SHAPE_TREE_CHILDREN.contains(&local_name(&e.name)))
+}
+
+/// Get the `p:cSld/p:spTree` element of a slide.
+fn shape_tree(root: &RawXmlElement) -> Option<&RawXmlElement> {
+    child_elements(child_elements(root, "cSld").next()?, "spTree").next()
+}
+
+fn shape_tree_mut(root: &mut RawXmlElement) -> Option<&mut RawXmlElement> {
+    child_elements_mut(child_elements_mut(root, "cSld").next()?, "spTree").next()
+}
+
+/// Get the drawing element ID (`cNvPr@id`) of a shape tree, group or one of
+/// their children, from its non-visual properties (`p:nvSpPr`, ...).
+fn drawing_id(element: &RawXmlElement) -> Option<u32> {
+    let properties = element.children.iter().find_map(|node| match node {
+        RawXmlNode::Element(e) if local_name(&e.name).starts_with("nv") => Some(e),
+        _ => None,
+    })?;
+    attribute(child_elements(properties, "cNvPr").next()?, "id")?
+        .parse()
+        .ok()
+}
+
+/// Record the stacking order of a shape tree or group and its subgroups.
+fn record_shape_order(tree: &RawXmlElement, order: &mut ShapeOrder) {
+    let mut ids = Vec::new();
+    for node in tree
+        .children
+        .iter()
+        .filter(|node| is_shape_tree_child(node))
+    {
+        let RawXmlNode::Element(child) = node else {
+            continue;
+        };
+        ids.extend(drawing_id(child));
+        if local_name(&child.name) == "grpSp" {
+            record_shape_order(child, order);
+        }
+    }
+    if let Some(id) = drawing_id(tree) {
+        order.insert(id, ids);
+    }
+}
+
+/// Put the children of a shape tree or group and its subgroups back in their
+/// recorded order. Children that weren't recorded go on top, in the order
+/// they were written.
+fn restore_shape_order(tree: &mut RawXmlElement, order: &ShapeOrder) {
+    if let Some(recorded) = drawing_id(tree).and_then(|id| order.get(&id)) {
+        let slots: Vec<usize> = (0..tree.children.len())
+            .filter(|&i| is_shape_tree_child(&tree.children[i]))
+            .collect();
+        let mut children: Vec<RawXmlNode> = slots
+            .iter()
+            .map(|&i| std::mem::replace(&mut tree.children[i], RawXmlNode::Text(String::new())))
+            .collect();
+        // The sort is stable, so unrecorded children keep their order
+        children.sort_by_cached_key(|node| {
+            let id = match node {
+                RawXmlNode::Element(e) => drawing_id(e),
+                _ => None,
+            };
+            recorded
+                .iter()
+                .position(|&recorded| Some(recorded) == id)
+                .unwrap_or(recorded.len())
+        });
+        for (i, child) in slots.into_iter().zip(children) {
+            tree.children[i] = child;
+        }
+    }
+    for group in child_elements_mut(tree, "grpSp") {
+        restore_shape_order(group, order);
+    }
+}
+
+/// Get the local part of a qualified element or attribute name.
+fn local_name(name: &str) -> &str {
+    name.rsplit(':').next().unwrap_or(name)
+}
+
+/// Get the prefix of a qualified name, including the colon (or `""`).
+fn prefix(name: &str) -> &str {
+    &name[..name.len() - local_name(name).len()]
+}
+
+/// Get an attribute value of a raw element.
+fn attribute<'a>(element: &'a RawXmlElement, key: &str) -> Option<&'a str> {
+    element
+        .attributes
+        .iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, v)| v.as_str())
+}
+
+/// Iterate over the child elements with the given local name.
+fn child_elements<'a>(
+    parent: &'a RawXmlElement,
+    local: &'a str,
+) -> impl Iterator<Item = &'a RawXmlElement> {
+    parent.children.iter().filter_map(move |node| match node {
+        RawXmlNode::Element(e) if local_name(&e.name) == local => Some(e),
+        _ => None,
+    })
+}
+
+/// Iterate mutably over the child elements with the given local name.
+fn child_elements_mut<'a>(
+    parent: &'a mut RawXmlElement,
+    local: &'a str,
+) -> impl Iterator<Item = &'a mut RawXmlElement> {
+    parent
+        .children
+        .iter_mut()
+        .filter_map(move |node| match node {
+            RawXmlNode::Element(e) if local_name(&e.name) == local => Some(e),
+            _ => None,
+        })
+}
+
+/// Get the qualified name of the `r:id` attribute, using the prefix the
+/// presentation part declares for the relationships namespace.
+fn relationship_id_attr(root: &RawXmlElement) -> String {
+    root.attributes
+        .iter()
+        .find_map(|(k, v)| {
+            let prefix = k.strip_prefix("xmlns:")?;
+            (Conformance::Transitional.namespace(v) == NS_RELATIONSHIPS)
+                .then(|| format!("{}:id", prefix))
+        })
+        .unwrap_or_else(|| "r:id".to_string())
+}
+
+/// Get the `p:sldIdLst` element of the presentation, creating it if needed.
+fn slide_id_list(root: &mut RawXmlElement) -> &mut RawXmlElement {
+    let is_element = |node: &RawXmlNode, local: &str| matches!(node, RawXmlNode::Element(e) if local_name(&e.name) == local);
+    let pos = match root
+        .children
+        .iter()
+        .position(|node| is_element(node, "sldIdLst"))
+    {
+        Some(pos) => pos,
+        None => {
+            // The list follows the master ID lists (ECMA-376 Part 1, §19.2.1.26)
+            let pos = root
+                .children
+                .iter()
+                .rposition(|node| {
+                    ["sldMasterIdLst", "notesMasterIdLst", "handoutMasterIdLst"]
+                        .iter()
+                        .any(|local| is_element(node, local))
+                })
+                .map_or(0, |pos| pos + 1);
+            let name = format!("{}sldIdLst", prefix(&root.name));
+            root.children
+                .insert(pos, RawXmlNode::Element(RawXmlElement::new(name)));
+            pos
+        }
+    };
+    match &mut root.children[pos] {
+        RawXmlNode::Element(e) => e,
+        _ => unreachable!("position of an element"),
+    }
+}
+
+/// Remove all descendant elements matching a predicate.
+fn remove_elements(element: &mut RawXmlElement, matches: &dyn Fn(&RawXmlElement) -> bool) {
+    element.children.retain(|node| match node {
+        RawXmlNode::Element(e) => !matches(e),
+        _ => true,
+    });
+    for child in &mut element.children {
+        if let RawXmlNode::Element(child) = child {
+            remove_elements(child, matches);
+        }
+    }
+}
+
+/// Move (or add) a slide's entry in the section list (`p14:sectionLst`) so
+/// that it follows the entry of slide `after`, or starts the first section
+/// when `after` is `None`. Does nothing if the presentation has no sections.
+fn place_section_entry(root: &mut RawXmlElement, slide_id: u32, after: Option<u32>) {
+    let Some(sections) = find_element_mut(root, "sectionLst") else {
+        return;
+    };
+    let entry_name = format!("{}sldId", prefix(&sections.name));
+    let mut lists: Vec<&mut RawXmlElement> = child_elements_mut(sections, "section")
+        .flat_map(|section| child_elements_mut(section, "sldIdLst"))
+        .collect();
+    if lists.is_empty() {
+        return;
+    }
+
+    let id = slide_id.to_string();
+    let mut entry = None;
+    for list in lists.iter_mut() {
+        if let Some(pos) = list.children.iter().position(
+            |node| matches!(node, RawXmlNode::Element(e) if attribute(e, "id") == Some(id.as_str())),
+        ) && let RawXmlNode::Element(e) = list.children.remove(pos)
+        {
+            entry = Some(e);
+        }
+    }
+    let entry = entry.unwrap_or_else(|| {
+        let mut entry = RawXmlElement::new(entry_name);
+        entry.attributes = vec![("id".to_string(), id)];
+        entry.self_closing = true;
+        entry
+    });
+
+    if let Some(after) = after.map(|id| id.to_string()) {
+        for list in lists.iter_mut() {
+            if let Some(pos) = list.children.iter().position(
+                |node| matches!(node, RawXmlNode::Element(e) if attribute(e, "id") == Some(after.as_str())),
+            ) {
+                list.children.insert(pos + 1, RawXmlNode::Element(entry));
+                return;
+            }
+        }
+    }
+    lists[0].children.insert(0, RawXmlNode::Element(entry));
+}
+
+/// Find the first descendant element with the given local name.
+fn find_element_mut<'a>(
+    element: &'a mut RawXmlElement,
+    local: &str,
+) -> Option<&'a mut RawXmlElement> {
+    for child in &mut element.children {
+        if let RawXmlNode::Element(child) = child {
+            if local_name(&child.name) == local {
+                return Some(child);
+            }
+            if let Some(found) = find_element_mut(child, local) {
+                return Some(found);
+            }
+        }
+    }
+    None
+}
+
 /// Parse a slide master XML file.
 fn parse_slide_master(xml: &[u8], path: &str) -> SlideMaster {
     let mut reader = Reader::from_reader(Cursor::new(xml));
@@ -1101,13 +1960,11 @@ fn parse_slide_master(xml: &[u8], path: &str) -> SlideMaster {
                             }
                         }
                     }
-                    b"a:srgbClr" => {
-                        if background_color.is_none() {
-                            for attr in e.attributes().filter_map(|a| a.ok()) {
-                                if attr.key.as_ref() == b"val" {
-                                    background_color =
-                                        Some(String::from_utf8_lossy(&attr.value).into_owned());
-                                }
+                    b"a:srgbClr" if background_color.is_none() => {
+                        for attr in e.attributes().filter_map(|a| a.ok()) {
+                            if attr.key.as_ref() == b"val" {
+                                background_color =
+                                    Some(String::from_utf8_lossy(&attr.value).into_owned());
                             }
                         }
                     }
@@ -1187,29 +2044,43 @@ fn parse_slide_layout(xml: &[u8], path: &str, master_rel_id: Option<String>) ->
 // Generated parser helpers
 // ============================================================================
 
-/// Parse a slide using the generated FromXml parser.
-fn parse_slide_xml(xml: &[u8]) -> Result<types::Slide> {
-    let mut reader = Reader::from_reader(Cursor::new(xml));
-    let mut buf = Vec::new();
+/// Markup compatibility processor for the namespaces this crate reads.
+fn markup_compatibility() -> MceProcessor {
+    MceProcessor::new([NS_PRESENTATION, NS_DRAWING, NS_RELATIONSHIPS])
+}
 
-    // Find the root p:sld element and parse it
-    loop {
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Start(e)) => {
-                let local_name = e.local_name();
-                if local_name.as_ref() == b"sld" {
-                    return types::Slide::from_xml(&mut reader, &e, false)
-                        .map_err(|e| Error::Invalid(format!("Failed to parse slide: {}", e)));
-                }
-            }
-            Ok(Event::Eof) => break,
-            Err(e) => return Err(Error::Xml(e)),
-            _ => {}
-        }
-        buf.clear();
-    }
+/// Parse a slide after selecting the `mc:AlternateContent` branches this
+/// crate can read and dropping ignorable markup (ECMA-376 Part 3), locating
+/// parse errors in the slide as stored. Only used for reading, so that saving
+/// an unedited slide keeps the alternatives.
+fn parse_slide_compat(xml: &[u8], path: &str) -> Result<types::Slide> {
+    let (compat_xml, offsets) = markup_compatibility()
+        .process_with_offsets(xml)
+        .map_err(|e| Error::Invalid(format!("Failed to process markup compatibility: {}", e)))?;
+    ooxml_xml::parse_root(&compat_xml)
+        .map_err(|e| Error::Parse(e.in_original(xml, &offsets).in_part(path)))
+}
 
-    Err(Error::Invalid("No p:sld element found in slide XML".into()))
+/// Parse a slide for editing, with the same `mc:AlternateContent` branches
+/// selected as for reading but ignorable markup kept, so that the
+/// alternatives can be restored when the slide is written. Also returns the
+/// processed XML.
+fn parse_slide_for_editing<'a>(
+    xml: &'a [u8],
+    path: &str,
+) -> Result<(types::Slide, Cow<'a, [u8]>, Alternates)> {
+    let (compat_xml, offsets, alternates) = markup_compatibility()
+        .process_for_editing(xml)
+        .map_err(|e| Error::Invalid(format!("Failed to process markup compatibility: {}", e)))?;
+    let slide = ooxml_xml::parse_root(&compat_xml)
+        .map_err(|e| Error::Parse(e.in_original(xml, &offsets).in_part(path)))?;
+    Ok((slide, compat_xml, alternates))
+}
+
+/// Parse a package part with a generated parser, naming the part in parse
+/// errors.
+fn parse_part<T: FromXml>(xml: &[u8], path: &str) -> Result<T> {
+    ooxml_xml::parse_root(xml).map_err(|e| Error::Parse(e.in_part(path)))
 }
 
 /// Convert a generated SlideTransition to the handwritten Transition type.
@@ -1282,6 +2153,7 @@ fn convert_transition(trans: &types::SlideTransition) -> Transition {
 fn extract_tables_from_slide(slide: &types::Slide) -> Vec<Table> {
     use crate::ext::GraphicalObjectFrameExt;
 
+    static TABLES: ooxml_xml::LazyQuery = ooxml_xml::LazyQuery::new("//tbl");
     let mut tables = Vec::new();
 
     // Tables are in graphic frames in the shape tree
@@ -1289,9 +2161,14 @@ fn extract_tables_from_slide(slide: &types::Slide) -> Vec<Table> {
         // Get the frame name for the table
         let frame_name = Some(frame.name().to_string()).filter(|s| !s.is_empty());
 
-        // Look through extra_children for the a:graphic element
-        for node in &frame.extra_children {
-            if let Some(table) = find_table_in_node(&node.node, frame_name.clone()) {
+        // The a:graphic element is kept in extra_children
+        let nodes = frame.extra_children.iter().map(|node| &node.node);
+        for elem in TABLES
+            .select_nodes(nodes)
+            .iter()
+            .filter_map(|m| m.as_element())
+        {
+            if let Some(table) = parse_table_element(elem, frame_name.clone()) {
                 tables.push(table);
             }
         }
@@ -1306,31 +2183,6 @@ fn extract_tables_from_slide(_slide: &types::Slide) -> Vec<Table> {
     Vec::new()
 }
 
-/// Recursively search for a table element in an XML node tree.
-#[cfg(feature = "extra-children")]
-fn find_table_in_node(node: &ooxml_xml::RawXmlNode, frame_name: Option<String>) -> Option<Table> {
-    use ooxml_xml::RawXmlNode;
-
-    match node {
-        RawXmlNode::Element(elem) => {
-            // Check if this is a table element (a:tbl or just tbl)
-            let local_name = elem.name.split(':').next_back().unwrap_or(&elem.name);
-            if local_name == "tbl" {
-                return parse_table_element(elem, frame_name);
-            }
-
-            // Recursively search children
-            for child in &elem.children {
-                if let Some(table) = find_table_in_node(child, frame_name.clone()) {
-                    return Some(table);
-                }
-            }
-            None
-        }
-        _ => None,
-    }
-}
-
 /// Parse a table from a RawXmlElement representing a:tbl.
 #[cfg(feature = "extra-children")]
 fn parse_table_element(
@@ -1447,173 +2299,46 @@ fn collect_chart_and_smartart_ids(
 /// Requires the `pml-charts` feature (which enables `ooxml-dml/dml-charts`).
 /// ECMA-376 Part 1, §21.2.2.29 (CT_ChartSpace).
 #[cfg(feature = "pml-charts")]
-fn parse_chart(xml: &[u8]) -> Result<ooxml_dml::types::ChartSpace> {
-    use ooxml_dml::parsers::FromXml as DmlFromXml;
-    let mut reader = Reader::from_reader(Cursor::new(xml));
-    let mut buf = Vec::new();
-
-    loop {
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Start(e)) => {
-                return ooxml_dml::types::ChartSpace::from_xml(&mut reader, &e, false)
-                    .map_err(|e| Error::Invalid(format!("Failed to parse chartSpace: {}", e)));
-            }
-            Ok(Event::Empty(e)) => {
-                return ooxml_dml::types::ChartSpace::from_xml(&mut reader, &e, true)
-                    .map_err(|e| Error::Invalid(format!("Failed to parse chartSpace: {}", e)));
-            }
-            Ok(Event::Eof) => break,
-            Err(e) => return Err(Error::Xml(e)),
-            _ => {}
-        }
-        buf.clear();
-    }
-    Err(Error::Invalid("No chartSpace element found".into()))
+fn parse_chart(xml: &[u8], path: &str) -> Result<ooxml_dml::types::ChartSpace> {
+    parse_part(xml, path)
 }
 
 /// Parse a SmartArt data model XML part into a `DataModel`.
 ///
 /// ECMA-376 Part 1, §21.4.2.8 (CT_DataModel).
 #[cfg(feature = "pml-charts")]
-fn parse_data_model(xml: &[u8]) -> Result<ooxml_dml::types::DataModel> {
-    use ooxml_dml::parsers::FromXml as DmlFromXml;
-    let mut reader = Reader::from_reader(Cursor::new(xml));
-    let mut buf = Vec::new();
-
-    loop {
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Start(e)) => {
-                return ooxml_dml::types::DataModel::from_xml(&mut reader, &e, false)
-                    .map_err(|e| Error::Invalid(format!("Failed to parse dataModel: {}", e)));
-            }
-            Ok(Event::Empty(e)) => {
-                return ooxml_dml::types::DataModel::from_xml(&mut reader, &e, true)
-                    .map_err(|e| Error::Invalid(format!("Failed to parse dataModel: {}", e)));
-            }
-            Ok(Event::Eof) => break,
-            Err(e) => return Err(Error::Xml(e)),
-            _ => {}
-        }
-        buf.clear();
-    }
-    Err(Error::Invalid("No dataModel element found".into()))
+fn parse_data_model(xml: &[u8], path: &str) -> Result<ooxml_dml::types::DataModel> {
+    parse_part(xml, path)
 }
 
 /// Parse a SmartArt layout definition XML part into a `DiagramDefinition`.
 ///
 /// ECMA-376 Part 1, §21.4.3 (layoutDef).
 #[cfg(feature = "pml-charts")]
-fn parse_diagram_definition(xml: &[u8]) -> Result<ooxml_dml::types::DiagramDefinition> {
-    use ooxml_dml::parsers::FromXml as DmlFromXml;
-    let mut reader = Reader::from_reader(Cursor::new(xml));
-    let mut buf = Vec::new();
-
-    loop {
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Start(e)) => {
-                return ooxml_dml::types::DiagramDefinition::from_xml(&mut reader, &e, false)
-                    .map_err(|e| Error::Invalid(format!("Failed to parse layoutDef: {}", e)));
-            }
-            Ok(Event::Empty(e)) => {
-                return ooxml_dml::types::DiagramDefinition::from_xml(&mut reader, &e, true)
-                    .map_err(|e| Error::Invalid(format!("Failed to parse layoutDef: {}", e)));
-            }
-            Ok(Event::Eof) => break,
-            Err(e) => return Err(Error::Xml(e)),
-            _ => {}
-        }
-        buf.clear();
-    }
-    Err(Error::Invalid("No layoutDef element found".into()))
+fn parse_diagram_definition(xml: &[u8], path: &str) -> Result<ooxml_dml::types::DiagramDefinition> {
+    parse_part(xml, path)
 }
 
 /// Parse a SmartArt colors XML part into a `DiagramColorTransform`.
 ///
 /// ECMA-376 Part 1, §21.4.4 (colorsDef).
 #[cfg(feature = "pml-charts")]
-fn parse_diagram_colors(xml: &[u8]) -> Result<ooxml_dml::types::DiagramColorTransform> {
-    use ooxml_dml::parsers::FromXml as DmlFromXml;
-    let mut reader = Reader::from_reader(Cursor::new(xml));
-    let mut buf = Vec::new();
-
-    loop {
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Start(e)) => {
-                return ooxml_dml::types::DiagramColorTransform::from_xml(&mut reader, &e, false)
-                    .map_err(|e| Error::Invalid(format!("Failed to parse colorsDef: {}", e)));
-            }
-            Ok(Event::Empty(e)) => {
-                return ooxml_dml::types::DiagramColorTransform::from_xml(&mut reader, &e, true)
-                    .map_err(|e| Error::Invalid(format!("Failed to parse colorsDef: {}", e)));
-            }
-            Ok(Event::Eof) => break,
-            Err(e) => return Err(Error::Xml(e)),
-            _ => {}
-        }
-        buf.clear();
-    }
-    Err(Error::Invalid("No colorsDef element found".into()))
+fn parse_diagram_colors(xml: &[u8], path: &str) -> Result<ooxml_dml::types::DiagramColorTransform> {
+    parse_part(xml, path)
 }
 
 /// Parse a SmartArt style definition XML part into a `DiagramStyleDefinition`.
 ///
 /// ECMA-376 Part 1, §21.4.5 (styleDef).
 #[cfg(feature = "pml-charts")]
-fn parse_diagram_style(xml: &[u8]) -> Result<ooxml_dml::types::DiagramStyleDefinition> {
-    use ooxml_dml::parsers::FromXml as DmlFromXml;
-    let mut reader = Reader::from_reader(Cursor::new(xml));
-    let mut buf = Vec::new();
-
-    loop {
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Start(e)) => {
-                return ooxml_dml::types::DiagramStyleDefinition::from_xml(&mut reader, &e, false)
-                    .map_err(|e| Error::Invalid(format!("Failed to parse styleDef: {}", e)));
-            }
-            Ok(Event::Empty(e)) => {
-                return ooxml_dml::types::DiagramStyleDefinition::from_xml(&mut reader, &e, true)
-                    .map_err(|e| Error::Invalid(format!("Failed to parse styleDef: {}", e)));
-            }
-            Ok(Event::Eof) => break,
-            Err(e) => return Err(Error::Xml(e)),
-            _ => {}
-        }
-        buf.clear();
-    }
-    Err(Error::Invalid("No styleDef element found".into()))
+fn parse_diagram_style(xml: &[u8], path: &str) -> Result<ooxml_dml::types::DiagramStyleDefinition> {
+    parse_part(xml, path)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_resolve_path() {
-        assert_eq!(
-            resolve_path("ppt/presentation.xml", "slides/slide1.xml"),
-            "ppt/slides/slide1.xml"
-        );
-        assert_eq!(
-            resolve_path("ppt/presentation.xml", "/ppt/slides/slide1.xml"),
-            "/ppt/slides/slide1.xml"
-        );
-        // Relative path with parent navigation (../ used in master → layout refs)
-        assert_eq!(
-            resolve_path(
-                "ppt/slideMasters/slideMaster1.xml",
-                "../slideLayouts/slideLayout1.xml"
-            ),
-            "ppt/slideLayouts/slideLayout1.xml"
-        );
-        assert_eq!(
-            resolve_path(
-                "ppt/slideLayouts/slideLayout1.xml",
-                "../slideMasters/slideMaster1.xml"
-            ),
-            "ppt/slideMasters/slideMaster1.xml"
-        );
-    }
-
     /// Build a `RawXmlElement` by parsing a simple XML string using quick-xml.
     ///
     /// This is a test helper only — it handles single top-level elements without
@@ -19,7 +19,7 @@ use crate::error::Result;
 use crate::generated_serializers::ToXml;
 use crate::types;
 use ooxml_dml::types as dml;
-use ooxml_opc::PackageWriter;
+use ooxml_opc::{Conformance, PackageWriter, SerializeOptions, VbaProject};
 use std::fs::File;
 use std::io::{BufWriter, Seek, Write};
 use std::path::Path;
@@ -27,6 +27,9 @@ use std::path::Path;
 // Content types
 const CT_PRESENTATION: &str =
     "application/vnd.openxmlformats-officedocument.presentationml.presentation.main+xml";
+const CT_PRESENTATION_MACRO_ENABLED: &str =
+    "application/vnd.ms-powerpoint.presentation.macroEnabled.main+xml";
+const CT_VBA_PROJECT: &str = "application/vnd.ms-office.vbaProject";
 const CT_SLIDE: &str = "application/vnd.openxmlformats-officedocument.presentationml.slide+xml";
 const CT_NOTES_SLIDE: &str =
     "application/vnd.openxmlformats-officedocument.presentationml.notesSlide+xml";
@@ -61,6 +64,7 @@ const REL_SLIDE_LAYOUT: &str =
 const REL_NOTES_MASTER: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/notesMaster";
 const REL_CHART: &str = "http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart";
+const REL_VBA_PROJECT: &str = "http://schemas.microsoft.com/office/2006/relationships/vbaProject";
 const REL_DIAGRAM_DATA: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/diagramData";
 const REL_DIAGRAM_LAYOUT: &str =
@@ -441,6 +445,18 @@ struct SmartArtElement {
 
 /// Serialize any `ToXml` type to XML bytes with a given tag name and PML namespace declarations.
 fn serialize_pml_xml<T: ToXml>(value: &T, tag: &str) -> Result<Vec<u8>> {
+    serialize_with_ns_decls(value, tag, NS_DECLS_SLIDE)
+}
+
+/// Serialize any `ToXml` type to XML bytes with the given root attributes.
+///
+/// Attributes already written by the value itself (e.g. namespace declarations
+/// captured in `extra_attrs` when a part was parsed) are not repeated.
+pub(crate) fn serialize_with_ns_decls<T: ToXml>(
+    value: &T,
+    tag: &str,
+    ns_decls: &[(&str, &str)],
+) -> Result<Vec<u8>> {
     use quick_xml::Writer;
     use quick_xml::events::{BytesEnd, BytesStart, Event};
 
@@ -450,8 +466,10 @@ fn serialize_pml_xml<T: ToXml>(value: &T, tag: &str) -> Result<Vec<u8>> {
     let start = BytesStart::new(tag);
     let start = value.write_attrs(start);
     let mut start = start;
-    for &(key, val) in NS_DECLS_SLIDE {
-        start.push_attribute((key, val));
+    for &(key, val) in ns_decls {
+        if start.try_get_attribute(key).ok().flatten().is_none() {
+            start.push_attribute((key, val));
+        }
     }
 
     if value.is_empty_element() {
@@ -1365,6 +1383,19 @@ fn build_dml_runs(
         .collect()
 }
 
+/// Build a plain text box shape, as used when editing existing slides.
+pub(crate) fn build_text_box(
+    text: &str,
+    x: i64,
+    y: i64,
+    width: i64,
+    height: i64,
+    shape_id: usize,
+) -> types::Shape {
+    let element = TextElement::simple(text.to_string(), false, x, y, width, height);
+    build_shape_impl(&element, shape_id, None)
+}
+
 /// Build a `types::Shape` from a `TextElement`, resolving hyperlink rel IDs if provided.
 fn build_shape_impl(
     element: &TextElement,
@@ -1859,10 +1890,10 @@ fn build_table_graphic_xml(table: &TableElement) -> String {
 /// has no `graphic` field in the generated PML type — the element is captured
 /// as an unknown child during parsing.
 fn parse_extra_child_xml(xml: &str) -> Vec<ooxml_xml::PositionedNode> {
-    use quick_xml::Reader;
+    use quick_xml::NsReader;
     use quick_xml::events::Event;
 
-    let mut reader = Reader::from_str(xml);
+    let mut reader = NsReader::from_str(xml);
     reader.config_mut().trim_text(false);
 
     let mut buf = Vec::new();
@@ -2916,6 +2947,12 @@ pub struct PresentationBuilder {
     slide_height: i64,
     /// Additional slide masters (beyond the default minimal master).
     extra_masters: Vec<SlideMasterEntry>,
+    /// Conformance class to write.
+    conformance: Conformance,
+    /// Options to reformat XML parts with, if any.
+    serialize_options: Option<SerializeOptions>,
+    /// VBA project, making the presentation macro-enabled.
+    vba_project: Option<VbaProject>,
 }
 
 impl Default for PresentationBuilder {
@@ -2932,6 +2969,9 @@ impl PresentationBuilder {
             slide_width: 9144000,
             slide_height: 6858000,
             extra_masters: Vec::new(),
+            conformance: Conformance::Transitional,
+            serialize_options: None,
+            vba_project: None,
         }
     }
 
@@ -2964,6 +3004,33 @@ impl PresentationBuilder {
         self
     }
 
+    /// Set the conformance class to write (ISO/IEC 29500-1, §2).
+    ///
+    /// Strict presentations use the `http://purl.oclc.org/ooxml/...` namespaces
+    /// and relationship types. Defaults to Transitional.
+    pub fn set_conformance(&mut self, conformance: Conformance) -> &mut Self {
+        self.conformance = conformance;
+        self
+    }
+
+    /// Set the output options for the XML parts.
+    ///
+    /// Defaults to compact output; use [`SerializeOptions::pretty`] for
+    /// indented, deterministic parts that diff well under version control.
+    pub fn set_serialize_options(&mut self, options: SerializeOptions) -> &mut Self {
+        self.serialize_options = Some(options);
+        self
+    }
+
+    /// Attach a VBA project, making the presentation macro-enabled.
+    ///
+    /// The presentation is written with the `.pptm` content type, so it should
+    /// be saved with a `.pptm` extension.
+    pub fn set_vba_project(&mut self, project: VbaProject) -> &mut Self {
+        self.vba_project = Some(project);
+        self
+    }
+
     /// Set slide size to widescreen (16:9).
     pub fn set_widescreen(&mut self) -> &mut Self {
         self.slide_width = 12192000;
@@ -2992,6 +3059,12 @@ impl PresentationBuilder {
     /// Write the presentation to a writer.
     pub fn write<W: Write + Seek>(self, writer: W) -> Result<()> {
         let mut pkg = PackageWriter::new(writer);
+        if self.conformance == Conformance::Strict {
+            pkg.set_conformance(Conformance::Strict);
+        }
+        if let Some(options) = self.serialize_options {
+            pkg.set_serialize_options(options);
+        }
 
         pkg.add_default_content_type("rels", CT_RELATIONSHIPS);
         pkg.add_default_content_type("xml", CT_XML);
@@ -3020,7 +3093,8 @@ impl PresentationBuilder {
         // IDs:   rId1..rIdN  = slides
         //        rId{N+1}    = default slide master (master 1)
         //        rId{N+2..}  = extra masters (if any)
-        //        last entry  = notes master (if any slides have notes)
+        //        then        = notes master (if any slides have notes)
+        //        last entry  = VBA project (if macro-enabled)
         // -----------------------------------------------------------------------
         let n_slides = self.slides.len();
         let first_master_rel_id = n_slides + 1;
@@ -3066,6 +3140,15 @@ impl PresentationBuilder {
             ));
             pres_rels.push('\n');
         }
+        // VBA project (macro-enabled presentations)
+        if self.vba_project.is_some() {
+            pres_rels.push_str(&format!(
+                r#"  <Relationship Id="rId{}" Type="{}" Target="vbaProject.bin"/>"#,
+                notes_master_rel_id + 1,
+                REL_VBA_PROJECT
+            ));
+            pres_rels.push('\n');
+        }
         pres_rels.push_str("</Relationships>");
 
         let presentation_xml = serialize_pml_xml(
@@ -3079,7 +3162,18 @@ impl PresentationBuilder {
             CT_RELATIONSHIPS,
             pres_rels.as_bytes(),
         )?;
-        pkg.add_part("ppt/presentation.xml", CT_PRESENTATION, &presentation_xml)?;
+        let presentation_content_type = match &self.vba_project {
+            Some(_) => CT_PRESENTATION_MACRO_ENABLED,
+            None => CT_PRESENTATION,
+        };
+        pkg.add_part(
+            "ppt/presentation.xml",
+            presentation_content_type,
+            &presentation_xml,
+        )?;
+        if let Some(project) = &self.vba_project {
+            pkg.add_part("ppt/vbaProject.bin", CT_VBA_PROJECT, project.data())?;
+        }
 
         // -----------------------------------------------------------------------
         // Slide master / layout constants
@@ -3471,7 +3565,8 @@ impl PresentationBuilder {
             remove_personal_info_on_save: None,
             compat_mode: None,
             bookmark_id_seed: None,
-            conformance: None,
+            conformance: (self.conformance == Conformance::Strict)
+                .then_some(types::STConformanceClass::Strict),
             sld_master_id_lst: Some(Box::new(sld_master_id_lst)),
             sld_id_lst: Some(Box::new(sld_id_lst)),
             sld_sz: Some(Box::new(types::CTSlideSize {
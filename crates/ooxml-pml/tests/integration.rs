@@ -720,3 +720,44 @@ fn test_slides_returns_all() {
         assert_eq!(slide.index(), i);
     }
 }
+
+// ---------------------------------------------------------------------------
+// 23. Strict conformance
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_pretty_roundtrip() {
+    let mut builder = PresentationBuilder::new();
+    builder.set_serialize_options(ooxml_pml::SerializeOptions::pretty());
+    builder.add_slide().add_title("Pretty");
+
+    let mut buf = Cursor::new(Vec::new());
+    builder.write(&mut buf).expect("write should succeed");
+    let mut package = ooxml_opc::Package::open(Cursor::new(buf.get_ref().clone())).unwrap();
+    let xml = package.read_part_string("ppt/slides/slide1.xml").unwrap();
+    assert!(xml.contains("\n  <p:cSld>"), "{xml}");
+
+    buf.set_position(0);
+    let mut pres = Presentation::from_reader(buf).expect("read should succeed");
+    assert_eq!(pres.slide(0).unwrap().text(), "Pretty");
+}
+
+#[test]
+fn test_strict_roundtrip() {
+    let mut builder = PresentationBuilder::new();
+    builder.set_conformance(ooxml_pml::Conformance::Strict);
+    builder.add_slide().add_title("Strict");
+
+    let mut buf = Cursor::new(Vec::new());
+    builder.write(&mut buf).expect("write should succeed");
+    let mut package = ooxml_opc::Package::open(Cursor::new(buf.get_ref().clone())).unwrap();
+    assert_eq!(
+        package.conformance().unwrap(),
+        ooxml_pml::Conformance::Strict
+    );
+
+    buf.set_position(0);
+    let mut pres = Presentation::from_reader(buf).expect("read should succeed");
+    assert_eq!(pres.slide_count(), 1);
+    assert_eq!(pres.slide(0).unwrap().text(), "Strict");
+}
@@ -61,3 +61,61 @@ fn test_missing_presentation_xml() {
         "should fail when presentation.xml is missing"
     );
 }
+
+/// Replace one part of a package, keeping the others.
+fn with_part(package: Vec<u8>, name: &str, data: &[u8]) -> Vec<u8> {
+    use std::io::Write;
+    let mut archive = zip::ZipArchive::new(Cursor::new(package)).unwrap();
+    let mut buf = Cursor::new(Vec::new());
+    {
+        let mut writer = zip::ZipWriter::new(&mut buf);
+        for i in 0..archive.len() {
+            let file = archive.by_index(i).unwrap();
+            if file.name() == name {
+                let options = zip::write::SimpleFileOptions::default();
+                writer.start_file(name, options).unwrap();
+                writer.write_all(data).unwrap();
+            } else {
+                writer.raw_copy_file(file).unwrap();
+            }
+        }
+        writer.finish().unwrap();
+    }
+    buf.into_inner()
+}
+
+/// Errors from the generated parsers name the part, line, column and
+/// element path of the offending markup.
+#[test]
+fn test_parse_error_location() {
+    let mut builder = ooxml_pml::PresentationBuilder::new();
+    builder.add_slide().add_title("Title");
+    let mut buffer = Cursor::new(Vec::new());
+    builder.write(&mut buffer).unwrap();
+    let slide = br#"<?xml version="1.0" encoding="UTF-8"?>
+<p:sld xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main">
+  <p:cSld>
+    <p:spTree>
+      <p:nvGrpSpPr><p:cNvPr id="1" name=""/><p:cNvGrpSpPr/><p:nvPr/></p:nvGrpSpPr>
+      <p:grpSpPr/>
+      <p:sp>
+        <p:nvSpPr><p:cNvPr id="2"/><p:cNvSpPr/><p:nvPr/></p:nvSpPr>
+        <p:spPr/>
+      </p:sp>
+    </p:spTree>
+  </p:cSld>
+</p:sld>"#;
+    let bytes = with_part(buffer.into_inner(), "ppt/slides/slide1.xml", slide);
+
+    let mut presentation = Pres::from_reader(Cursor::new(bytes)).unwrap();
+    let err = presentation.slides().unwrap_err();
+    let location = err.location().expect("parse errors are located");
+    assert_eq!(location.part.as_deref(), Some("ppt/slides/slide1.xml"));
+    let position = location.position.unwrap();
+    assert_eq!((position.line, position.column), (8, 19));
+    assert_eq!(
+        location.path,
+        ["p:sld", "p:cSld", "p:spTree", "p:sp", "p:nvSpPr", "p:cNvPr"]
+    );
+    assert!(err.to_string().contains("missing attribute: name"), "{err}");
+}
@@ -0,0 +1,73 @@
+//! Tests for the JSON document model of the PML generated types.
+//!
+//! These tests verify that a parsed slide survives a trip through JSON and
+//! still serializes to the same XML.
+
+#![cfg(all(feature = "serde", feature = "extra-attrs", feature = "extra-children"))]
+
+use ooxml_pml::parsers::FromXml;
+use ooxml_pml::serializers::ToXml;
+use ooxml_pml::types::*;
+use quick_xml::events::Event;
+use quick_xml::{NsReader, Writer};
+
+/// Parse the root element of an XML string using the FromXml trait.
+fn parse_from_xml<T: FromXml>(xml: &str) -> T {
+    let mut reader = NsReader::from_str(xml);
+    loop {
+        match reader.read_event().expect("should read event") {
+            Event::Start(e) => return T::from_xml(&mut reader, &e, false).expect("should parse"),
+            Event::Empty(e) => return T::from_xml(&mut reader, &e, true).expect("should parse"),
+            Event::Eof => panic!("no element found"),
+            _ => {}
+        }
+    }
+}
+
+/// Serialize a value to an XML string using the ToXml trait.
+fn serialize_to_xml<T: ToXml>(value: &T, tag: &str) -> String {
+    let mut writer = Writer::new(Vec::new());
+    value
+        .write_element(tag, &mut writer)
+        .expect("should serialize");
+    String::from_utf8(writer.into_inner()).expect("valid utf-8")
+}
+
+const SLIDE: &str = r#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:x="urn:custom">
+    <p:cSld>
+        <p:spTree>
+            <p:nvGrpSpPr><p:cNvPr id="1" name=""/><p:cNvGrpSpPr/><p:nvPr/></p:nvGrpSpPr>
+            <p:grpSpPr/>
+            <p:sp x:tag="1">
+                <p:nvSpPr><p:cNvPr id="2" name="Title 1"/><p:cNvSpPr/><p:nvPr><p:ph type="title"/></p:nvPr></p:nvSpPr>
+                <p:spPr/>
+                <p:txBody>
+                    <a:bodyPr/>
+                    <a:p><a:r><a:rPr lang="en-US" b="1"/><a:t>Quarterly results</a:t></a:r></a:p>
+                </p:txBody>
+            </p:sp>
+        </p:spTree>
+    </p:cSld>
+    <x:note>kept</x:note>
+</p:sld>"#;
+
+#[test]
+fn test_slide_json_roundtrip() {
+    let slide: Slide = parse_from_xml(SLIDE);
+    let json = serde_json::to_string(&slide).expect("should serialize to JSON");
+    let loaded: Slide = serde_json::from_str(&json).expect("should deserialize from JSON");
+
+    let written = serialize_to_xml(&loaded, "p:sld");
+    assert!(written.contains("<a:t>Quarterly results</a:t>"));
+    assert!(written.contains(r#"x:tag="1""#));
+    assert!(written.contains("<x:note>kept</x:note>"));
+
+    // Namespace declarations live in a map, so compare the re-parsed XML
+    // rather than the raw strings.
+    let reparsed: Slide = parse_from_xml(&written);
+    let original: Slide = parse_from_xml(&serialize_to_xml(&slide, "p:sld"));
+    assert_eq!(
+        serde_json::to_value(&reparsed).unwrap(),
+        serde_json::to_value(&original).unwrap()
+    );
+}
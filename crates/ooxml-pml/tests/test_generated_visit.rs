@@ -0,0 +1,63 @@
+//! Tests for path queries over the PML generated types.
+//!
+//! These tests verify that queries descend from slide types into the
+//! embedded DrawingML types under the element names they are written with.
+
+#![cfg(all(feature = "visit", feature = "extra-children"))]
+
+use ooxml_pml::parsers::FromXml;
+use ooxml_pml::types::*;
+use ooxml_xml::Query;
+use quick_xml::NsReader;
+use quick_xml::events::Event;
+
+/// Parse the root element of an XML string using the FromXml trait.
+fn parse_from_xml<T: FromXml>(xml: &str) -> T {
+    let mut reader = NsReader::from_str(xml);
+    loop {
+        match reader.read_event().expect("should read event") {
+            Event::Start(e) => return T::from_xml(&mut reader, &e, false).expect("should parse"),
+            Event::Empty(e) => return T::from_xml(&mut reader, &e, true).expect("should parse"),
+            Event::Eof => panic!("no element found"),
+            _ => {}
+        }
+    }
+}
+
+const SLIDE: &str = r#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+    <p:cSld>
+        <p:spTree>
+            <p:nvGrpSpPr><p:cNvPr id="1" name=""/><p:cNvGrpSpPr/><p:nvPr/></p:nvGrpSpPr>
+            <p:grpSpPr/>
+            <p:sp>
+                <p:nvSpPr><p:cNvPr id="2" name="Title 1"/><p:cNvSpPr/><p:nvPr><p:ph type="title"/></p:nvPr></p:nvSpPr>
+                <p:spPr/>
+                <p:txBody>
+                    <a:bodyPr/>
+                    <a:p><a:r><a:rPr lang="en-US" b="1"/><a:t>Quarterly results</a:t></a:r></a:p>
+                </p:txBody>
+            </p:sp>
+        </p:spTree>
+    </p:cSld>
+</p:sld>"#;
+
+#[test]
+fn test_query_into_drawingml() {
+    let slide: Slide = parse_from_xml(SLIDE);
+    let query = |expr| Query::new(expr).expect("valid query");
+
+    assert_eq!(
+        query("//p:sp/p:txBody/a:p/a:r/a:t/text()").tree_values(&slide),
+        ["Quarterly results"]
+    );
+    assert_eq!(
+        query("//a:rPr[@b='1']/@lang").tree_values(&slide),
+        ["en-US"]
+    );
+    assert_eq!(
+        query("//p:cNvPr[@id='2']/@name").tree_values(&slide),
+        ["Title 1"]
+    );
+    let bodies = query("//p:txBody").select_tree(&slide);
+    assert!(bodies[0].downcast::<ooxml_dml::types::TextBody>().is_some());
+}
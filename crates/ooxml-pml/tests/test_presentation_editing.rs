@@ -0,0 +1,360 @@
+//! Integration tests for editing existing presentations (open-modify-save).
+
+use ooxml_opc::{Package, PackageEditor};
+use ooxml_pml::{Presentation, PresentationBuilder, ShapeExt, TableBuilder};
+use std::io::Cursor;
+
+/// Build a three-slide presentation in memory and open it for editing.
+fn open_template() -> Presentation<Cursor<Vec<u8>>> {
+    let mut builder = PresentationBuilder::new();
+    let first = builder.add_slide();
+    first.add_title("Welcome {{name}}");
+    first.add_text("Prepared for {{name}}");
+    first.set_notes("Greet the audience");
+    let second = builder.add_slide();
+    second.add_title("Agenda");
+    second.add_table(
+        TableBuilder::new().add_row(["Owner", "{{name}}"]),
+        457200,
+        1600200,
+        4572000,
+        914400,
+    );
+    let third = builder.add_slide();
+    third.add_title("Questions");
+
+    let mut buffer = Cursor::new(Vec::new());
+    builder.write(&mut buffer).expect("write template");
+    buffer.set_position(0);
+    Presentation::from_reader(buffer).expect("open template")
+}
+
+/// Write an edited presentation to memory and return the raw bytes.
+fn write_bytes(pres: &mut Presentation<Cursor<Vec<u8>>>) -> Cursor<Vec<u8>> {
+    let mut buffer = Cursor::new(Vec::new());
+    pres.write(&mut buffer).expect("write edited presentation");
+    buffer.set_position(0);
+    buffer
+}
+
+/// Write an edited presentation to memory and read it back.
+fn reopen(pres: &mut Presentation<Cursor<Vec<u8>>>) -> Presentation<Cursor<Vec<u8>>> {
+    Presentation::from_reader(write_bytes(pres)).expect("reopen edited presentation")
+}
+
+fn titles(pres: &mut Presentation<Cursor<Vec<u8>>>) -> Vec<String> {
+    pres.slides()
+        .unwrap()
+        .iter()
+        .map(|s| s.shapes()[0].text().unwrap_or_default())
+        .collect()
+}
+
+#[test]
+fn test_replace_text() {
+    let mut pres = open_template();
+    let count = pres.replace_text("{{name}}", "Contoso").unwrap();
+    assert_eq!(count, 3, "two shapes and one table cell");
+
+    let mut reopened = reopen(&mut pres);
+    let first = reopened.slide(0).unwrap();
+    assert_eq!(first.text(), "Welcome Contoso\nPrepared for Contoso");
+    assert_eq!(first.notes(), Some("Greet the audience"));
+    let second = reopened.slide(1).unwrap();
+    let grid = second.table(0).unwrap().to_text_grid();
+    assert_eq!(grid, vec![vec!["Owner".to_string(), "Contoso".to_string()]]);
+}
+
+#[test]
+fn test_untouched_slides_are_copied() {
+    let mut pres = open_template();
+    let mut original = Package::open(write_bytes(&mut pres)).unwrap();
+    let before = original.read_part("ppt/slides/slide3.xml").unwrap();
+
+    pres.replace_text("{{name}}", "Contoso").unwrap();
+    let mut edited = Package::open(write_bytes(&mut pres)).unwrap();
+    assert_eq!(edited.read_part("ppt/slides/slide3.xml").unwrap(), before);
+
+    let xml = edited.read_part_string("ppt/slides/slide1.xml").unwrap();
+    assert_eq!(xml.matches("xmlns:p=").count(), 1);
+}
+
+#[test]
+fn test_add_and_remove_shapes() {
+    let mut pres = open_template();
+    let id = pres
+        .add_text_box(2, "Thank you", 457200, 457200, 4572000, 914400)
+        .unwrap();
+    let existing_max = pres
+        .slide(2)
+        .unwrap()
+        .shapes()
+        .iter()
+        .filter(|s| s.shape_id() != id)
+        .map(|s| s.shape_id())
+        .max()
+        .unwrap();
+    assert!(id > existing_max);
+    assert!(pres.remove_shape(0, "Title").unwrap());
+    assert!(!pres.remove_shape(0, "Missing").unwrap());
+
+    let mut reopened = reopen(&mut pres);
+    let third = reopened.slide(2).unwrap();
+    assert!(third.text().contains("Thank you"));
+    assert_eq!(
+        third.shapes().last().unwrap().name(),
+        format!("TextBox {}", id)
+    );
+    let first = reopened.slide(0).unwrap();
+    assert!(first.shapes().iter().all(|s| s.name() != "Title"));
+}
+
+#[test]
+fn test_move_slide() {
+    let mut pres = open_template();
+    pres.move_slide(2, 0).unwrap();
+    assert!(pres.move_slide(0, 3).is_err());
+
+    let mut reopened = reopen(&mut pres);
+    assert_eq!(
+        titles(&mut reopened),
+        vec!["Questions", "Welcome {{name}}", "Agenda"]
+    );
+    assert_eq!(reopened.slide(1).unwrap().index(), 1);
+}
+
+#[test]
+fn test_remove_slide() {
+    let mut pres = open_template();
+    pres.remove_slide(0).unwrap();
+    assert_eq!(pres.slide_count(), 2);
+
+    let buffer = write_bytes(&mut pres);
+    let package = Package::open(buffer.clone()).unwrap();
+    assert!(!package.has_part("ppt/slides/slide1.xml"));
+    assert!(!package.has_part("ppt/slides/_rels/slide1.xml.rels"));
+    assert!(!package.has_part("ppt/notesSlides/notesSlide1.xml"));
+
+    let mut reopened = Presentation::from_reader(buffer).unwrap();
+    assert_eq!(titles(&mut reopened), vec!["Agenda", "Questions"]);
+}
+
+#[test]
+fn test_duplicate_slide() {
+    let mut pres = open_template();
+    pres.replace_text_in_slide(0, "{{name}}", "Fabrikam")
+        .unwrap();
+    let copy = pres.duplicate_slide(0).unwrap();
+    assert_eq!(copy, 1);
+    pres.replace_text_in_slide(copy, "Fabrikam", "Contoso")
+        .unwrap();
+
+    let buffer = write_bytes(&mut pres);
+    let mut package = Package::open(buffer.clone()).unwrap();
+    assert!(package.has_part("ppt/slides/slide4.xml"));
+    let pres_xml = package.read_part_string("ppt/presentation.xml").unwrap();
+    assert_eq!(pres_xml.matches("<p:sldId ").count(), 4);
+    assert!(pres_xml.contains("<p:sldSz"), "other content is preserved");
+
+    let mut reopened = Presentation::from_reader(buffer).unwrap();
+    assert_eq!(reopened.slide_count(), 4);
+    let original = reopened.slide(0).unwrap();
+    let duplicate = reopened.slide(1).unwrap();
+    assert_eq!(original.text(), "Welcome Fabrikam\nPrepared for Fabrikam");
+    assert_eq!(duplicate.text(), "Welcome Contoso\nPrepared for Contoso");
+    assert_eq!(duplicate.notes(), Some("Greet the audience"));
+    assert_eq!(duplicate.layout_rel_id(), original.layout_rel_id());
+
+    // The copy has its own notes slide, which links back to the copy
+    let rels = package
+        .read_part_string("ppt/slides/_rels/slide4.xml.rels")
+        .unwrap();
+    assert!(rels.contains(r#"Target="../notesSlides/notesSlide2.xml""#));
+    let rels = package
+        .read_part_string("ppt/notesSlides/_rels/notesSlide2.xml.rels")
+        .unwrap();
+    assert!(rels.contains(r#"Target="../slides/slide4.xml""#));
+}
+
+#[test]
+fn test_duplicate_slide_copies_comments() {
+    const COMMENTS: &str = r#"<p:cmLst xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main"><p:cm authorId="0" idx="1"><p:pos x="10" y="10"/><p:text>Check the figures</p:text></p:cm></p:cmLst>"#;
+    let mut buffer = write_bytes(&mut open_template());
+    let mut editor = PackageEditor::new(Package::open(buffer).unwrap()).unwrap();
+    editor
+        .add_part(
+            "ppt/slides/slide1.xml",
+            ooxml_opc::rel_type::COMMENTS,
+            "ppt/comments/comment1.xml",
+            "application/vnd.openxmlformats-officedocument.presentationml.comments+xml",
+            COMMENTS.as_bytes().to_vec(),
+        )
+        .unwrap();
+    buffer = Cursor::new(Vec::new());
+    editor.write(&mut buffer).unwrap();
+    buffer.set_position(0);
+
+    let mut pres = Presentation::from_reader(buffer).unwrap();
+    pres.duplicate_slide(0).unwrap();
+    let mut package = Package::open(write_bytes(&mut pres)).unwrap();
+    assert_eq!(
+        package
+            .read_part_string("ppt/comments/comment2.xml")
+            .unwrap(),
+        COMMENTS
+    );
+    assert_eq!(
+        package.content_type("ppt/comments/comment2.xml"),
+        Some("application/vnd.openxmlformats-officedocument.presentationml.comments+xml")
+    );
+    let rels = package
+        .read_part_string("ppt/slides/_rels/slide4.xml.rels")
+        .unwrap();
+    assert!(rels.contains(r#"Target="../comments/comment2.xml""#));
+
+    // Removing the copy takes its comments along and leaves the original's
+    pres.remove_slide(1).unwrap();
+    let package = Package::open(write_bytes(&mut pres)).unwrap();
+    assert!(package.has_part("ppt/comments/comment1.xml"));
+    assert!(!package.has_part("ppt/comments/comment2.xml"));
+    assert!(!package.has_part("ppt/notesSlides/notesSlide2.xml"));
+}
+
+#[test]
+fn test_macro_enabled_presentation_is_preserved() {
+    let project = ooxml_pml::VbaProject::new(b"vbaProject.bin bytes".to_vec());
+    let mut builder = PresentationBuilder::new();
+    builder.set_vba_project(project.clone());
+    builder.add_slide().add_title("Macros");
+    let mut buffer = Cursor::new(Vec::new());
+    builder.write(&mut buffer).unwrap();
+    buffer.set_position(0);
+
+    let mut pres = Presentation::from_reader(buffer).unwrap();
+    assert!(pres.is_macro_enabled());
+    assert_eq!(pres.vba_project().unwrap(), Some(project.clone()));
+    pres.replace_text("Macros", "Edited").unwrap();
+
+    let buffer = write_bytes(&mut pres);
+    let package = Package::open(buffer.clone()).unwrap();
+    assert_eq!(
+        package.content_type("ppt/presentation.xml"),
+        Some("application/vnd.ms-powerpoint.presentation.macroEnabled.main+xml")
+    );
+    let mut reopened = Presentation::from_reader(buffer).unwrap();
+    assert_eq!(reopened.vba_project().unwrap(), Some(project));
+    assert!(!open_template().is_macro_enabled());
+}
+
+#[test]
+fn test_edits_keep_stacking_order() {
+    // Smallest valid PNG (1x1 pixel)
+    const PNG: &[u8] = &[
+        0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x00, 0x00, 0x0D, 0x49, 0x48, 0x44,
+        0x52, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0x00, 0x00, 0x00, 0x1F,
+        0x15, 0xC4, 0x89, 0x00, 0x00, 0x00, 0x0A, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9C, 0x62, 0x00,
+        0x00, 0x00, 0x02, 0x00, 0x01, 0xE2, 0x21, 0xBC, 0x33, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45,
+        0x4E, 0x44, 0xAE, 0x42, 0x60, 0x82,
+    ];
+    let mut builder = PresentationBuilder::new();
+    let slide = builder.add_slide();
+    slide.add_title("Back");
+    slide.add_text("Front");
+    slide.add_image(PNG.to_vec(), 0, 0, 914400, 914400);
+    let mut buffer = Cursor::new(Vec::new());
+    builder.write(&mut buffer).unwrap();
+
+    // Put the picture between the two shapes
+    let path = "ppt/slides/slide1.xml";
+    let mut editor = PackageEditor::new(Package::open(buffer).unwrap()).unwrap();
+    let xml = String::from_utf8(editor.read_part(path).unwrap()).unwrap();
+    let picture = &xml[xml.find("<p:pic>").unwrap()..xml.find("</p:spTree>").unwrap()];
+    let xml = xml.replacen(picture, "", 1);
+    let front = xml.find(r#"<p:sp><p:nvSpPr><p:cNvPr id="3""#).unwrap();
+    let xml = format!("{}{}{}", &xml[..front], picture, &xml[front..]);
+    let content_type = editor.content_type(path).unwrap().to_string();
+    editor.set_part(path, &content_type, xml.into_bytes());
+    let mut buffer = Cursor::new(Vec::new());
+    editor.write(&mut buffer).unwrap();
+    buffer.set_position(0);
+
+    let mut pres = Presentation::from_reader(buffer).unwrap();
+    pres.replace_text("Front", "Top").unwrap();
+    pres.add_text_box(0, "Added", 0, 0, 914400, 914400).unwrap();
+    let mut package = Package::open(write_bytes(&mut pres)).unwrap();
+    let xml = package.read_part_string(path).unwrap();
+    let positions: Vec<usize> = ["Back", "<p:pic>", "Top", "Added"]
+        .iter()
+        .map(|text| xml.find(text).unwrap())
+        .collect();
+    assert!(positions.is_sorted(), "{}", xml);
+}
+
+#[test]
+fn test_edits_keep_alternate_content() {
+    let mut builder = PresentationBuilder::new();
+    let slide = builder.add_slide();
+    slide.add_title("Back");
+    slide.add_text("Front");
+    let mut buffer = Cursor::new(Vec::new());
+    builder.write(&mut buffer).unwrap();
+
+    // Offer the second shape in a newer form, and tag the first one with an
+    // ignorable attribute
+    let path = "ppt/slides/slide1.xml";
+    let mut editor = PackageEditor::new(Package::open(buffer).unwrap()).unwrap();
+    let xml = String::from_utf8(editor.read_part(path).unwrap()).unwrap();
+    let xml = xml.replacen(
+        "<p:sld ",
+        r#"<p:sld xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:p14="http://schemas.microsoft.com/office/powerpoint/2010/main" mc:Ignorable="p14" "#,
+        1,
+    );
+    let xml = xml.replacen("<p:sp>", r#"<p:sp p14:tag="1">"#, 1);
+    let front = xml.find(r#"<p:sp><p:nvSpPr><p:cNvPr id="3""#).unwrap();
+    let end = front + xml[front..].find("</p:sp>").unwrap() + "</p:sp>".len();
+    let shape = &xml[front..end];
+    let alternate = format!(
+        r#"<mc:AlternateContent><mc:Choice Requires="p14">{}</mc:Choice><mc:Fallback>{}</mc:Fallback></mc:AlternateContent>"#,
+        shape.replace("Front", "Modern"),
+        shape
+    );
+    let xml = format!("{}{}{}", &xml[..front], alternate, &xml[end..]);
+    let content_type = editor.content_type(path).unwrap().to_string();
+    editor.set_part(path, &content_type, xml.into_bytes());
+    let mut buffer = Cursor::new(Vec::new());
+    editor.write(&mut buffer).unwrap();
+    buffer.set_position(0);
+
+    let mut pres = Presentation::from_reader(buffer).unwrap();
+    assert_eq!(pres.slide(0).unwrap().text(), "Back\nFront");
+    assert_eq!(pres.replace_text("Front", "Top").unwrap(), 1);
+    assert_eq!(pres.slide(0).unwrap().text(), "Back\nTop");
+    pres.add_text_box(0, "Added", 0, 0, 914400, 914400).unwrap();
+
+    let written = write_bytes(&mut pres);
+    let xml = Package::open(written.clone())
+        .unwrap()
+        .read_part_string(path)
+        .unwrap();
+    let positions: Vec<usize> = [
+        r#"p14:tag="1""#,
+        "Back",
+        r#"<mc:AlternateContent><mc:Choice Requires="p14">"#,
+        "Modern",
+        "<mc:Fallback>",
+        "Top",
+        "</mc:AlternateContent>",
+        "Added",
+    ]
+    .iter()
+    .map(|text| {
+        xml.find(text)
+            .unwrap_or_else(|| panic!("{text} missing from {xml}"))
+    })
+    .collect();
+    assert!(positions.is_sorted(), "{}", xml);
+    assert!(!xml.contains(r#"mc:AlternateContent=""#), "{}", xml);
+
+    let mut reopened = Presentation::from_reader(written).unwrap();
+    assert_eq!(reopened.slide(0).unwrap().text(), "Back\nTop\nAdded");
+}
@@ -8,7 +8,7 @@
 use criterion::{BenchmarkId, Criterion, Throughput, black_box, criterion_group, criterion_main};
 use ooxml_sml::parsers::{FromXml, ParseError};
 use ooxml_sml::types::{Cell, Row, SheetData};
-use quick_xml::Reader;
+use quick_xml::NsReader;
 use quick_xml::de::from_str;
 use quick_xml::events::Event;
 use std::io::Cursor;
@@ -77,7 +77,7 @@ fn parse_serde(xml: &str) -> SheetData {
 
 /// Parse using the generated FromXml trait (event-based, generated code).
 fn parse_fromxml<T: FromXml>(xml: &[u8]) -> Result<T, ParseError> {
-    let mut reader = Reader::from_reader(Cursor::new(xml));
+    let mut reader = NsReader::from_reader(Cursor::new(xml));
     let mut buf = Vec::new();
 
     loop {
@@ -98,7 +98,7 @@ fn parse_fromxml<T: FromXml>(xml: &[u8]) -> Result<T, ParseError> {
 /// Parse sheet data using event-based parsing (simplified version).
 /// This mimics the approach used in the hand-written workbook parser.
 fn parse_events(xml: &[u8]) -> (usize, usize) {
-    let mut reader = Reader::from_reader(Cursor::new(xml));
+    let mut reader = NsReader::from_reader(Cursor::new(xml));
     let mut buf = Vec::new();
 
     let mut row_count = 0;
@@ -1,6 +1,6 @@
 use ooxml_codegen::{
     CodegenConfig, FeatureMappings, NameMappings, Schema, analyze_schema, generate,
-    generate_parsers, generate_serializers, parse_rnc,
+    generate_parsers, generate_serializers, generate_visitors, parse_rnc,
 };
 use std::fs;
 use std::path::Path;
@@ -28,6 +28,25 @@ fn main() {
     println!("cargo::rerun-if-changed={}", names_path);
     println!("cargo::rerun-if-changed={}", features_path);
     println!("cargo::rerun-if-changed=build.rs");
+    println!("cargo::rerun-if-env-changed=OOXML_GENERATE_VISIT");
+
+    // Derive Visit/VisitMut traversal and query trees from the committed
+    // types and serializers
+    // Enable with OOXML_GENERATE_VISIT=1
+    if std::env::var("OOXML_GENERATE_VISIT").is_ok() {
+        let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let types = fs::read_to_string(src.join("generated.rs")).expect("failed to read types");
+        let serializers = fs::read_to_string(src.join("generated_serializers.rs"))
+            .expect("failed to read serializers");
+        let visit_dest = src.join("generated_visit.rs");
+        let visit_code =
+            generate_visitors(&types, &serializers, &[]).expect("failed to generate visitors");
+        fs::write(&visit_dest, visit_code).expect("failed to write visitors");
+        eprintln!(
+            "Generated {} bytes to src/generated_visit.rs",
+            visit_dest.metadata().map(|m| m.len()).unwrap_or(0)
+        );
+    }
 
     // The generated file is committed at src/generated.rs
     // Only regenerate if OOXML_REGENERATE is set and specs exist
@@ -135,6 +154,11 @@ fn main() {
         feature_mappings,
         // SML uses unprefixed elements in real XLSX files (default namespace convention)
         xml_serialize_prefix: None,
+        // Elements in other namespaces (extensions) are kept as unknown children
+        xml_namespaces: vec![
+            "http://schemas.openxmlformats.org/spreadsheetml/2006/main".to_string(),
+            "http://purl.oclc.org/ooxml/spreadsheetml/main".to_string(),
+        ],
         warn_unmapped: true,
         ..Default::default()
     };
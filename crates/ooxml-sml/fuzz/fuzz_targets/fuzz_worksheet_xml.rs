@@ -8,13 +8,13 @@
 use libfuzzer_sys::fuzz_target;
 use ooxml_sml::parsers::{FromXml, ParseError};
 use ooxml_sml::types::Worksheet;
-use quick_xml::Reader;
+use quick_xml::NsReader;
 use quick_xml::events::Event;
 use std::io::Cursor;
 
 /// Parse worksheet XML from bytes using the FromXml trait.
 fn parse_worksheet(xml: &[u8]) -> Result<Worksheet, ParseError> {
-    let mut reader = Reader::from_reader(Cursor::new(xml));
+    let mut reader = NsReader::from_reader(Cursor::new(xml));
     let mut buf = Vec::new();
 
     loop {
@@ -21,6 +21,11 @@ pub enum Error {
     #[error("Serialization error: {0}")]
     Serialize(#[from] crate::generated_serializers::SerializeError),
 
+    /// Error from a generated parser, with the part, line and column and
+    /// element path it occurred at; see [`Error::location`].
+    #[error("Parse error: {0}")]
+    Parse(#[from] ooxml_xml::ParseError),
+
     /// Invalid or malformed content.
     #[error("Invalid content: {0}")]
     Invalid(String),
@@ -30,5 +35,15 @@ pub enum Error {
     Unsupported(String),
 }
 
+impl Error {
+    /// Where in the package a parse error occurred, if known.
+    pub fn location(&self) -> Option<&ooxml_xml::SourceLocation> {
+        match self {
+            Self::Parse(e) => e.location(),
+            _ => None,
+        }
+    }
+}
+
 /// Result type for SpreadsheetML operations.
 pub type Result<T> = std::result::Result<T, Error>;
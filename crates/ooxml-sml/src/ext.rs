@@ -28,11 +28,8 @@
 //! let value = cell.value_as_string(&ctx);
 //! ```
 
-use crate::parsers::{FromXml, ParseError};
+use crate::parsers::ParseError;
 use crate::types::{Cell, CellType, Row, SheetData, Worksheet};
-use quick_xml::Reader;
-use quick_xml::events::Event;
-use std::io::Cursor;
 
 /// Resolved cell value (typed).
 #[derive(Debug, Clone, PartialEq)]
@@ -332,22 +329,7 @@ impl RowExt for Row {
 /// This is the recommended way to parse worksheet XML, as it uses the
 /// spec-compliant generated types and is faster than serde.
 pub fn parse_worksheet(xml: &[u8]) -> Result<Worksheet, ParseError> {
-    let mut reader = Reader::from_reader(Cursor::new(xml));
-    let mut buf = Vec::new();
-
-    loop {
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Start(e)) => return Worksheet::from_xml(&mut reader, &e, false),
-            Ok(Event::Empty(e)) => return Worksheet::from_xml(&mut reader, &e, true),
-            Ok(Event::Eof) => break,
-            Err(e) => return Err(ParseError::Xml(e)),
-            _ => {}
-        }
-        buf.clear();
-    }
-    Err(ParseError::UnexpectedElement(
-        "no worksheet element found".to_string(),
-    ))
+    ooxml_xml::parse_root(xml)
 }
 
 // =============================================================================
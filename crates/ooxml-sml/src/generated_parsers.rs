@@ -8,16 +8,23 @@
 #![allow(clippy::manual_is_multiple_of)]
 
 use super::generated::*;
+use ooxml_xml::local_name_in;
 pub use ooxml_xml::{FromXml, ParseError};
 #[cfg(feature = "extra-children")]
 use ooxml_xml::{PositionedNode, RawXmlElement, RawXmlNode};
-use quick_xml::Reader;
+use quick_xml::NsReader;
 use quick_xml::events::{BytesStart, Event};
 use std::io::BufRead;
 
+/// Namespace URIs of the elements these parsers read.
+const NAMESPACES: &[&str] = &[
+    "http://schemas.openxmlformats.org/spreadsheetml/2006/main",
+    "http://purl.oclc.org/ooxml/spreadsheetml/main",
+];
+
 #[allow(dead_code)]
 /// Skip an element and all its children.
-fn skip_element<R: BufRead>(reader: &mut Reader<R>) -> Result<(), ParseError> {
+fn skip_element<R: BufRead>(reader: &mut NsReader<R>) -> Result<(), ParseError> {
     let mut depth = 1u32;
     let mut buf = Vec::new();
     loop {
@@ -39,7 +46,7 @@ fn skip_element<R: BufRead>(reader: &mut Reader<R>) -> Result<(), ParseError> {
 
 #[allow(dead_code)]
 /// Read the text content of an element until its end tag.
-fn read_text_content<R: BufRead>(reader: &mut Reader<R>) -> Result<String, ParseError> {
+fn read_text_content<R: BufRead>(reader: &mut NsReader<R>) -> Result<String, ParseError> {
     let mut text = String::new();
     let mut buf = Vec::new();
     loop {
@@ -85,7 +92,7 @@ fn decode_base64(s: &str) -> Option<Vec<u8>> {
 
 impl FromXml for AutoFilter {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -128,7 +135,7 @@ impl FromXml for AutoFilter {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-filtering")]
                             b"filterColumn" => {
                                 f_filter_column.push(FilterColumn::from_xml(reader, &e, false)?);
@@ -173,7 +180,7 @@ impl FromXml for AutoFilter {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-filtering")]
                             b"filterColumn" => {
                                 f_filter_column.push(FilterColumn::from_xml(reader, &e, true)?);
@@ -241,7 +248,7 @@ impl FromXml for AutoFilter {
 
 impl FromXml for FilterColumn {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -304,7 +311,7 @@ impl FromXml for FilterColumn {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-filtering")]
                             b"filters" => {
                                 f_filters = Some(Box::new(Filters::from_xml(reader, &e, false)?));
@@ -384,7 +391,7 @@ impl FromXml for FilterColumn {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-filtering")]
                             b"filters" => {
                                 f_filters = Some(Box::new(Filters::from_xml(reader, &e, true)?));
@@ -500,7 +507,7 @@ impl FromXml for FilterColumn {
 
 impl FromXml for Filters {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -541,7 +548,7 @@ impl FromXml for Filters {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"filter" => {
                                 f_filter.push(Filter::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -574,7 +581,7 @@ impl FromXml for Filters {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"filter" => {
                                 f_filter.push(Filter::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -626,7 +633,7 @@ impl FromXml for Filters {
 
 impl FromXml for Filter {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -673,7 +680,7 @@ impl FromXml for Filter {
 
 impl FromXml for CustomFilters {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -709,7 +716,7 @@ impl FromXml for CustomFilters {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customFilter" => {
                                 f_custom_filter.push(CustomFilter::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -735,7 +742,7 @@ impl FromXml for CustomFilters {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customFilter" => {
                                 f_custom_filter.push(CustomFilter::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -778,7 +785,7 @@ impl FromXml for CustomFilters {
 
 impl FromXml for CustomFilter {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -830,7 +837,7 @@ impl FromXml for CustomFilter {
 
 impl FromXml for Top10Filter {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -892,7 +899,7 @@ impl FromXml for Top10Filter {
 
 impl FromXml for ColorFilter {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -944,7 +951,7 @@ impl FromXml for ColorFilter {
 
 impl FromXml for IconFilter {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -997,7 +1004,7 @@ impl FromXml for IconFilter {
 
 impl FromXml for DynamicFilter {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1064,7 +1071,7 @@ impl FromXml for DynamicFilter {
 
 impl FromXml for SortState {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1113,7 +1120,7 @@ impl FromXml for SortState {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sortCondition" => {
                                 f_sort_condition.push(SortCondition::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -1147,7 +1154,7 @@ impl FromXml for SortState {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sortCondition" => {
                                 f_sort_condition.push(SortCondition::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -1203,7 +1210,7 @@ impl FromXml for SortState {
 
 impl FromXml for SortCondition {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1281,7 +1288,7 @@ impl FromXml for SortCondition {
 
 impl FromXml for DateGroupItem {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1359,7 +1366,7 @@ impl FromXml for DateGroupItem {
 
 impl FromXml for CTXStringElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1406,7 +1413,7 @@ impl FromXml for CTXStringElement {
 
 impl FromXml for Extension {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1453,7 +1460,7 @@ impl FromXml for Extension {
 
 impl FromXml for ObjectAnchor {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1505,7 +1512,7 @@ impl FromXml for ObjectAnchor {
 
 impl FromXml for EGExtensionList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1521,7 +1528,7 @@ impl FromXml for EGExtensionList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ext" => {
                                 f_ext.push(Extension::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -1547,7 +1554,7 @@ impl FromXml for EGExtensionList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ext" => {
                                 f_ext.push(Extension::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -1587,7 +1594,7 @@ impl FromXml for EGExtensionList {
 
 impl FromXml for ExtensionList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1603,7 +1610,7 @@ impl FromXml for ExtensionList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ext" => {
                                 f_ext.push(Extension::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -1629,7 +1636,7 @@ impl FromXml for ExtensionList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ext" => {
                                 f_ext.push(Extension::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -1669,7 +1676,7 @@ impl FromXml for ExtensionList {
 
 impl FromXml for CalcChain {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1688,7 +1695,7 @@ impl FromXml for CalcChain {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-formulas")]
                             b"c" => {
                                 f_cells.push(CalcCell::from_xml(reader, &e, false)?);
@@ -1724,7 +1731,7 @@ impl FromXml for CalcChain {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-formulas")]
                             b"c" => {
                                 f_cells.push(CalcCell::from_xml(reader, &e, true)?);
@@ -1777,7 +1784,7 @@ impl FromXml for CalcChain {
 
 impl FromXml for CalcCell {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1864,7 +1871,7 @@ impl FromXml for CalcCell {
 
 impl FromXml for Comments {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1882,7 +1889,7 @@ impl FromXml for Comments {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"authors" => {
                                 f_authors = Some(Box::new(Authors::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -1924,7 +1931,7 @@ impl FromXml for Comments {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"authors" => {
                                 f_authors = Some(Box::new(Authors::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -1984,7 +1991,7 @@ impl FromXml for Comments {
 
 impl FromXml for Authors {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2000,7 +2007,7 @@ impl FromXml for Authors {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"author" => {
                                 f_author.push(read_text_content(reader)?);
                                 #[cfg(feature = "extra-children")]
@@ -2026,7 +2033,7 @@ impl FromXml for Authors {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"author" => {
                                 f_author.push(String::new());
                                 #[cfg(feature = "extra-children")]
@@ -2066,7 +2073,7 @@ impl FromXml for Authors {
 
 impl FromXml for CommentList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2082,7 +2089,7 @@ impl FromXml for CommentList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"comment" => {
                                 f_comment.push(Comment::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -2108,7 +2115,7 @@ impl FromXml for CommentList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"comment" => {
                                 f_comment.push(Comment::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -2148,7 +2155,7 @@ impl FromXml for CommentList {
 
 impl FromXml for Comment {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2206,7 +2213,7 @@ impl FromXml for Comment {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-comments")]
                             b"text" => {
                                 f_text = Some(Box::new(RichString::from_xml(reader, &e, false)?));
@@ -2241,7 +2248,7 @@ impl FromXml for Comment {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-comments")]
                             b"text" => {
                                 f_text = Some(Box::new(RichString::from_xml(reader, &e, true)?));
@@ -2304,7 +2311,7 @@ impl FromXml for Comment {
 
 impl FromXml for CTCommentPr {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2384,7 +2391,7 @@ impl FromXml for CTCommentPr {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"anchor" => {
                                 f_anchor =
                                     Some(Box::new(ObjectAnchor::from_xml(reader, &e, false)?));
@@ -2411,7 +2418,7 @@ impl FromXml for CTCommentPr {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"anchor" => {
                                 f_anchor =
                                     Some(Box::new(ObjectAnchor::from_xml(reader, &e, true)?));
@@ -2466,7 +2473,7 @@ impl FromXml for CTCommentPr {
 
 impl FromXml for MapInfo {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2503,7 +2510,7 @@ impl FromXml for MapInfo {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"Schema" => {
                                 f_schema.push(XmlSchema::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -2536,7 +2543,7 @@ impl FromXml for MapInfo {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"Schema" => {
                                 f_schema.push(XmlSchema::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -2588,7 +2595,7 @@ impl FromXml for MapInfo {
 
 impl FromXml for XmlSchema {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2636,7 +2643,7 @@ impl FromXml for XmlSchema {
 
 impl FromXml for XmlMap {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2704,7 +2711,7 @@ impl FromXml for XmlMap {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"DataBinding" => {
                                 f_data_binding =
                                     Some(Box::new(DataBinding::from_xml(reader, &e, false)?));
@@ -2731,7 +2738,7 @@ impl FromXml for XmlMap {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"DataBinding" => {
                                 f_data_binding =
                                     Some(Box::new(DataBinding::from_xml(reader, &e, true)?));
@@ -2791,7 +2798,7 @@ impl FromXml for XmlMap {
 
 impl FromXml for DataBinding {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2859,7 +2866,7 @@ impl FromXml for DataBinding {
 
 impl FromXml for Connections {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2875,7 +2882,7 @@ impl FromXml for Connections {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"connection" => {
                                 f_connection.push(Connection::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -2901,7 +2908,7 @@ impl FromXml for Connections {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"connection" => {
                                 f_connection.push(Connection::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -2941,7 +2948,7 @@ impl FromXml for Connections {
 
 impl FromXml for Connection {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3058,7 +3065,7 @@ impl FromXml for Connection {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"dbPr" => {
                                 f_db_pr = Some(Box::new(DatabaseProperties::from_xml(
                                     reader, &e, false,
@@ -3128,7 +3135,7 @@ impl FromXml for Connection {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"dbPr" => {
                                 f_db_pr =
                                     Some(Box::new(DatabaseProperties::from_xml(reader, &e, true)?));
@@ -3238,7 +3245,7 @@ impl FromXml for Connection {
 
 impl FromXml for DatabaseProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3301,7 +3308,7 @@ impl FromXml for DatabaseProperties {
 
 impl FromXml for OlapProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3388,7 +3395,7 @@ impl FromXml for OlapProperties {
 
 impl FromXml for WebQueryProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3472,7 +3479,7 @@ impl FromXml for WebQueryProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tables" => {
                                 f_tables = Some(Box::new(DataTables::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -3498,7 +3505,7 @@ impl FromXml for WebQueryProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tables" => {
                                 f_tables = Some(Box::new(DataTables::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -3553,7 +3560,7 @@ impl FromXml for WebQueryProperties {
 
 impl FromXml for Parameters {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3589,7 +3596,7 @@ impl FromXml for Parameters {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"parameter" => {
                                 f_parameter.push(Parameter::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -3615,7 +3622,7 @@ impl FromXml for Parameters {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"parameter" => {
                                 f_parameter.push(Parameter::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -3658,7 +3665,7 @@ impl FromXml for Parameters {
 
 impl FromXml for Parameter {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3750,7 +3757,7 @@ impl FromXml for Parameter {
 
 impl FromXml for DataTables {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3788,7 +3795,7 @@ impl FromXml for DataTables {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"m" => {
                                 f_m.push(TableMissing::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -3828,7 +3835,7 @@ impl FromXml for DataTables {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"m" => {
                                 f_m.push(TableMissing::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -3887,7 +3894,7 @@ impl FromXml for DataTables {
 
 impl FromXml for TableMissing {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3915,7 +3922,7 @@ impl FromXml for TableMissing {
 
 impl FromXml for TextImportProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4011,7 +4018,7 @@ impl FromXml for TextImportProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"textFields" => {
                                 f_text_fields =
                                     Some(Box::new(TextFields::from_xml(reader, &e, false)?));
@@ -4038,7 +4045,7 @@ impl FromXml for TextImportProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"textFields" => {
                                 f_text_fields =
                                     Some(Box::new(TextFields::from_xml(reader, &e, true)?));
@@ -4097,7 +4104,7 @@ impl FromXml for TextImportProperties {
 
 impl FromXml for TextFields {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4133,7 +4140,7 @@ impl FromXml for TextFields {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"textField" => {
                                 f_text_field.push(TextField::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -4159,7 +4166,7 @@ impl FromXml for TextFields {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"textField" => {
                                 f_text_field.push(TextField::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -4202,7 +4209,7 @@ impl FromXml for TextFields {
 
 impl FromXml for TextField {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4254,7 +4261,7 @@ impl FromXml for TextField {
 
 impl FromXml for PivotCacheDefinition {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4371,7 +4378,7 @@ impl FromXml for PivotCacheDefinition {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cacheSource" => {
                                 f_cache_source =
                                     Some(Box::new(CacheSource::from_xml(reader, &e, false)?));
@@ -4472,7 +4479,7 @@ impl FromXml for PivotCacheDefinition {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cacheSource" => {
                                 f_cache_source =
                                     Some(Box::new(CacheSource::from_xml(reader, &e, true)?));
@@ -4618,7 +4625,7 @@ impl FromXml for PivotCacheDefinition {
 
 impl FromXml for CacheFields {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4654,7 +4661,7 @@ impl FromXml for CacheFields {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cacheField" => {
                                 f_cache_field.push(CacheField::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -4680,7 +4687,7 @@ impl FromXml for CacheFields {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cacheField" => {
                                 f_cache_field.push(CacheField::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -4723,7 +4730,7 @@ impl FromXml for CacheFields {
 
 impl FromXml for CacheField {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4810,7 +4817,7 @@ impl FromXml for CacheField {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sharedItems" => {
                                 f_shared_items =
                                     Some(Box::new(SharedItems::from_xml(reader, &e, false)?));
@@ -4860,7 +4867,7 @@ impl FromXml for CacheField {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sharedItems" => {
                                 f_shared_items =
                                     Some(Box::new(SharedItems::from_xml(reader, &e, true)?));
@@ -4942,7 +4949,7 @@ impl FromXml for CacheField {
 
 impl FromXml for CacheSource {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4984,7 +4991,7 @@ impl FromXml for CacheSource {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"worksheetSource" => {
                                 f_worksheet_source =
                                     Some(Box::new(WorksheetSource::from_xml(reader, &e, false)?));
@@ -5027,7 +5034,7 @@ impl FromXml for CacheSource {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"worksheetSource" => {
                                 f_worksheet_source =
                                     Some(Box::new(WorksheetSource::from_xml(reader, &e, true)?));
@@ -5090,7 +5097,7 @@ impl FromXml for CacheSource {
 
 impl FromXml for WorksheetSource {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5152,7 +5159,7 @@ impl FromXml for WorksheetSource {
 
 impl FromXml for Consolidation {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5189,7 +5196,7 @@ impl FromXml for Consolidation {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pages" => {
                                 f_pages = Some(Box::new(CTPages::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -5223,7 +5230,7 @@ impl FromXml for Consolidation {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pages" => {
                                 f_pages = Some(Box::new(CTPages::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -5276,7 +5283,7 @@ impl FromXml for Consolidation {
 
 impl FromXml for CTPages {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5312,7 +5319,7 @@ impl FromXml for CTPages {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"page" => {
                                 f_page.push(CTPCDSCPage::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -5338,7 +5345,7 @@ impl FromXml for CTPages {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"page" => {
                                 f_page.push(CTPCDSCPage::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -5381,7 +5388,7 @@ impl FromXml for CTPages {
 
 impl FromXml for CTPCDSCPage {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5417,7 +5424,7 @@ impl FromXml for CTPCDSCPage {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pageItem" => {
                                 f_page_item.push(CTPageItem::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -5443,7 +5450,7 @@ impl FromXml for CTPCDSCPage {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pageItem" => {
                                 f_page_item.push(CTPageItem::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -5486,7 +5493,7 @@ impl FromXml for CTPCDSCPage {
 
 impl FromXml for CTPageItem {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5533,7 +5540,7 @@ impl FromXml for CTPageItem {
 
 impl FromXml for CTRangeSets {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5569,7 +5576,7 @@ impl FromXml for CTRangeSets {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rangeSet" => {
                                 f_range_set.push(CTRangeSet::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -5595,7 +5602,7 @@ impl FromXml for CTRangeSets {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rangeSet" => {
                                 f_range_set.push(CTRangeSet::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -5638,7 +5645,7 @@ impl FromXml for CTRangeSets {
 
 impl FromXml for CTRangeSet {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5720,7 +5727,7 @@ impl FromXml for CTRangeSet {
 
 impl FromXml for SharedItems {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5813,7 +5820,7 @@ impl FromXml for SharedItems {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"m" => {
                                 f_m.push(CTMissing::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -5874,7 +5881,7 @@ impl FromXml for SharedItems {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"m" => {
                                 f_m.push(CTMissing::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -5970,7 +5977,7 @@ impl FromXml for SharedItems {
 
 impl FromXml for CTMissing {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6047,7 +6054,7 @@ impl FromXml for CTMissing {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tpls" => {
                                 f_tpls.push(CTTuples::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -6080,7 +6087,7 @@ impl FromXml for CTMissing {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tpls" => {
                                 f_tpls.push(CTTuples::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -6141,7 +6148,7 @@ impl FromXml for CTMissing {
 
 impl FromXml for CTNumber {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6222,7 +6229,7 @@ impl FromXml for CTNumber {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tpls" => {
                                 f_tpls.push(CTTuples::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -6255,7 +6262,7 @@ impl FromXml for CTNumber {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tpls" => {
                                 f_tpls.push(CTTuples::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -6317,7 +6324,7 @@ impl FromXml for CTNumber {
 
 impl FromXml for CTBoolean {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6369,7 +6376,7 @@ impl FromXml for CTBoolean {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"x" => {
                                 f_x.push(CTX::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -6395,7 +6402,7 @@ impl FromXml for CTBoolean {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"x" => {
                                 f_x.push(CTX::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -6442,7 +6449,7 @@ impl FromXml for CTBoolean {
 
 impl FromXml for CTError {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6523,7 +6530,7 @@ impl FromXml for CTError {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tpls" => {
                                 f_tpls = Some(Box::new(CTTuples::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -6556,7 +6563,7 @@ impl FromXml for CTError {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tpls" => {
                                 f_tpls = Some(Box::new(CTTuples::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -6618,7 +6625,7 @@ impl FromXml for CTError {
 
 impl FromXml for CTString {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6699,7 +6706,7 @@ impl FromXml for CTString {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tpls" => {
                                 f_tpls.push(CTTuples::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -6732,7 +6739,7 @@ impl FromXml for CTString {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tpls" => {
                                 f_tpls.push(CTTuples::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -6794,7 +6801,7 @@ impl FromXml for CTString {
 
 impl FromXml for CTDateTime {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6846,7 +6853,7 @@ impl FromXml for CTDateTime {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"x" => {
                                 f_x.push(CTX::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -6872,7 +6879,7 @@ impl FromXml for CTDateTime {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"x" => {
                                 f_x.push(CTX::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -6919,7 +6926,7 @@ impl FromXml for CTDateTime {
 
 impl FromXml for FieldGroup {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6961,7 +6968,7 @@ impl FromXml for FieldGroup {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rangePr" => {
                                 f_range_pr =
                                     Some(Box::new(CTRangePr::from_xml(reader, &e, false)?));
@@ -7004,7 +7011,7 @@ impl FromXml for FieldGroup {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rangePr" => {
                                 f_range_pr = Some(Box::new(CTRangePr::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -7066,7 +7073,7 @@ impl FromXml for FieldGroup {
 
 impl FromXml for CTRangePr {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7148,7 +7155,7 @@ impl FromXml for CTRangePr {
 
 impl FromXml for CTDiscretePr {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7184,7 +7191,7 @@ impl FromXml for CTDiscretePr {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"x" => {
                                 f_x.push(CTIndex::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -7210,7 +7217,7 @@ impl FromXml for CTDiscretePr {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"x" => {
                                 f_x.push(CTIndex::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -7253,7 +7260,7 @@ impl FromXml for CTDiscretePr {
 
 impl FromXml for GroupItems {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7294,7 +7301,7 @@ impl FromXml for GroupItems {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"m" => {
                                 f_m.push(CTMissing::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -7355,7 +7362,7 @@ impl FromXml for GroupItems {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"m" => {
                                 f_m.push(CTMissing::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -7438,7 +7445,7 @@ impl FromXml for GroupItems {
 
 impl FromXml for PivotCacheRecords {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7475,7 +7482,7 @@ impl FromXml for PivotCacheRecords {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"r" => {
                                 f_reference.push(CTRecord::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -7509,7 +7516,7 @@ impl FromXml for PivotCacheRecords {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"r" => {
                                 f_reference.push(CTRecord::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -7561,7 +7568,7 @@ impl FromXml for PivotCacheRecords {
 
 impl FromXml for CTRecord {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7583,7 +7590,7 @@ impl FromXml for CTRecord {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"m" => {
                                 f_m.push(CTMissing::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -7651,7 +7658,7 @@ impl FromXml for CTRecord {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"m" => {
                                 f_m.push(CTMissing::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -7739,7 +7746,7 @@ impl FromXml for CTRecord {
 
 impl FromXml for CTPCDKPIs {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7775,7 +7782,7 @@ impl FromXml for CTPCDKPIs {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"kpi" => {
                                 f_kpi.push(CTPCDKPI::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -7801,7 +7808,7 @@ impl FromXml for CTPCDKPIs {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"kpi" => {
                                 f_kpi.push(CTPCDKPI::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -7844,7 +7851,7 @@ impl FromXml for CTPCDKPIs {
 
 impl FromXml for CTPCDKPI {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7942,7 +7949,7 @@ impl FromXml for CTPCDKPI {
 
 impl FromXml for CTCacheHierarchies {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7978,7 +7985,7 @@ impl FromXml for CTCacheHierarchies {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cacheHierarchy" => {
                                 f_cache_hierarchy
                                     .push(CTCacheHierarchy::from_xml(reader, &e, false)?);
@@ -8005,7 +8012,7 @@ impl FromXml for CTCacheHierarchies {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cacheHierarchy" => {
                                 f_cache_hierarchy
                                     .push(CTCacheHierarchy::from_xml(reader, &e, true)?);
@@ -8049,7 +8056,7 @@ impl FromXml for CTCacheHierarchies {
 
 impl FromXml for CTCacheHierarchy {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8171,7 +8178,7 @@ impl FromXml for CTCacheHierarchy {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"fieldsUsage" => {
                                 f_fields_usage =
                                     Some(Box::new(CTFieldsUsage::from_xml(reader, &e, false)?));
@@ -8214,7 +8221,7 @@ impl FromXml for CTCacheHierarchy {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"fieldsUsage" => {
                                 f_fields_usage =
                                     Some(Box::new(CTFieldsUsage::from_xml(reader, &e, true)?));
@@ -8298,7 +8305,7 @@ impl FromXml for CTCacheHierarchy {
 
 impl FromXml for CTFieldsUsage {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8334,7 +8341,7 @@ impl FromXml for CTFieldsUsage {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"fieldUsage" => {
                                 f_field_usage.push(CTFieldUsage::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -8360,7 +8367,7 @@ impl FromXml for CTFieldsUsage {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"fieldUsage" => {
                                 f_field_usage.push(CTFieldUsage::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -8403,7 +8410,7 @@ impl FromXml for CTFieldsUsage {
 
 impl FromXml for CTFieldUsage {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8450,7 +8457,7 @@ impl FromXml for CTFieldUsage {
 
 impl FromXml for CTGroupLevels {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8486,7 +8493,7 @@ impl FromXml for CTGroupLevels {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"groupLevel" => {
                                 f_group_level.push(CTGroupLevel::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -8512,7 +8519,7 @@ impl FromXml for CTGroupLevels {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"groupLevel" => {
                                 f_group_level.push(CTGroupLevel::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -8555,7 +8562,7 @@ impl FromXml for CTGroupLevels {
 
 impl FromXml for CTGroupLevel {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8604,7 +8611,7 @@ impl FromXml for CTGroupLevel {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"groups" => {
                                 f_groups = Some(Box::new(CTGroups::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -8638,7 +8645,7 @@ impl FromXml for CTGroupLevel {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"groups" => {
                                 f_groups = Some(Box::new(CTGroups::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -8695,7 +8702,7 @@ impl FromXml for CTGroupLevel {
 
 impl FromXml for CTGroups {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8731,7 +8738,7 @@ impl FromXml for CTGroups {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"group" => {
                                 f_group.push(CTLevelGroup::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -8757,7 +8764,7 @@ impl FromXml for CTGroups {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"group" => {
                                 f_group.push(CTLevelGroup::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -8800,7 +8807,7 @@ impl FromXml for CTGroups {
 
 impl FromXml for CTLevelGroup {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8852,7 +8859,7 @@ impl FromXml for CTLevelGroup {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"groupMembers" => {
                                 f_group_members =
                                     Some(Box::new(CTGroupMembers::from_xml(reader, &e, false)?));
@@ -8879,7 +8886,7 @@ impl FromXml for CTLevelGroup {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"groupMembers" => {
                                 f_group_members =
                                     Some(Box::new(CTGroupMembers::from_xml(reader, &e, true)?));
@@ -8930,7 +8937,7 @@ impl FromXml for CTLevelGroup {
 
 impl FromXml for CTGroupMembers {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8966,7 +8973,7 @@ impl FromXml for CTGroupMembers {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"groupMember" => {
                                 f_group_member.push(CTGroupMember::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -8992,7 +8999,7 @@ impl FromXml for CTGroupMembers {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"groupMember" => {
                                 f_group_member.push(CTGroupMember::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -9035,7 +9042,7 @@ impl FromXml for CTGroupMembers {
 
 impl FromXml for CTGroupMember {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9088,7 +9095,7 @@ impl FromXml for CTGroupMember {
 
 impl FromXml for CTTupleCache {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9108,7 +9115,7 @@ impl FromXml for CTTupleCache {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"entries" => {
                                 f_entries =
                                     Some(Box::new(CTPCDSDTCEntries::from_xml(reader, &e, false)?));
@@ -9166,7 +9173,7 @@ impl FromXml for CTTupleCache {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"entries" => {
                                 f_entries =
                                     Some(Box::new(CTPCDSDTCEntries::from_xml(reader, &e, true)?));
@@ -9242,7 +9249,7 @@ impl FromXml for CTTupleCache {
 
 impl FromXml for CTServerFormat {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9294,7 +9301,7 @@ impl FromXml for CTServerFormat {
 
 impl FromXml for CTServerFormats {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9330,7 +9337,7 @@ impl FromXml for CTServerFormats {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"serverFormat" => {
                                 f_server_format.push(CTServerFormat::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -9356,7 +9363,7 @@ impl FromXml for CTServerFormats {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"serverFormat" => {
                                 f_server_format.push(CTServerFormat::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -9399,7 +9406,7 @@ impl FromXml for CTServerFormats {
 
 impl FromXml for CTPCDSDTCEntries {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9438,7 +9445,7 @@ impl FromXml for CTPCDSDTCEntries {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"m" => {
                                 f_m.push(CTMissing::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -9485,7 +9492,7 @@ impl FromXml for CTPCDSDTCEntries {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"m" => {
                                 f_m.push(CTMissing::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -9552,7 +9559,7 @@ impl FromXml for CTPCDSDTCEntries {
 
 impl FromXml for CTTuples {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9588,7 +9595,7 @@ impl FromXml for CTTuples {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tpl" => {
                                 f_tpl.push(CTTuple::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -9614,7 +9621,7 @@ impl FromXml for CTTuples {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tpl" => {
                                 f_tpl.push(CTTuple::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -9657,7 +9664,7 @@ impl FromXml for CTTuples {
 
 impl FromXml for CTTuple {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9714,7 +9721,7 @@ impl FromXml for CTTuple {
 
 impl FromXml for CTSets {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9750,7 +9757,7 @@ impl FromXml for CTSets {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"set" => {
                                 f_set.push(CTSet::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -9776,7 +9783,7 @@ impl FromXml for CTSets {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"set" => {
                                 f_set.push(CTSet::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -9819,7 +9826,7 @@ impl FromXml for CTSets {
 
 impl FromXml for CTSet {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9872,7 +9879,7 @@ impl FromXml for CTSet {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tpls" => {
                                 f_tpls.push(CTTuples::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -9906,7 +9913,7 @@ impl FromXml for CTSet {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tpls" => {
                                 f_tpls.push(CTTuples::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -9964,7 +9971,7 @@ impl FromXml for CTSet {
 
 impl FromXml for CTQueryCache {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10000,7 +10007,7 @@ impl FromXml for CTQueryCache {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"query" => {
                                 f_query.push(CTQuery::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -10026,7 +10033,7 @@ impl FromXml for CTQueryCache {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"query" => {
                                 f_query.push(CTQuery::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -10069,7 +10076,7 @@ impl FromXml for CTQueryCache {
 
 impl FromXml for CTQuery {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10105,7 +10112,7 @@ impl FromXml for CTQuery {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tpls" => {
                                 f_tpls = Some(Box::new(CTTuples::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -10131,7 +10138,7 @@ impl FromXml for CTQuery {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tpls" => {
                                 f_tpls = Some(Box::new(CTTuples::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -10174,7 +10181,7 @@ impl FromXml for CTQuery {
 
 impl FromXml for CTCalculatedItems {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10210,7 +10217,7 @@ impl FromXml for CTCalculatedItems {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"calculatedItem" => {
                                 f_calculated_item
                                     .push(CTCalculatedItem::from_xml(reader, &e, false)?);
@@ -10237,7 +10244,7 @@ impl FromXml for CTCalculatedItems {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"calculatedItem" => {
                                 f_calculated_item
                                     .push(CTCalculatedItem::from_xml(reader, &e, true)?);
@@ -10281,7 +10288,7 @@ impl FromXml for CTCalculatedItems {
 
 impl FromXml for CTCalculatedItem {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10322,7 +10329,7 @@ impl FromXml for CTCalculatedItem {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pivotArea" => {
                                 f_pivot_area =
                                     Some(Box::new(PivotArea::from_xml(reader, &e, false)?));
@@ -10357,7 +10364,7 @@ impl FromXml for CTCalculatedItem {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pivotArea" => {
                                 f_pivot_area =
                                     Some(Box::new(PivotArea::from_xml(reader, &e, true)?));
@@ -10412,7 +10419,7 @@ impl FromXml for CTCalculatedItem {
 
 impl FromXml for CTCalculatedMembers {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10448,7 +10455,7 @@ impl FromXml for CTCalculatedMembers {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"calculatedMember" => {
                                 f_calculated_member
                                     .push(CTCalculatedMember::from_xml(reader, &e, false)?);
@@ -10475,7 +10482,7 @@ impl FromXml for CTCalculatedMembers {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"calculatedMember" => {
                                 f_calculated_member
                                     .push(CTCalculatedMember::from_xml(reader, &e, true)?);
@@ -10519,7 +10526,7 @@ impl FromXml for CTCalculatedMembers {
 
 impl FromXml for CTCalculatedMember {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10579,7 +10586,7 @@ impl FromXml for CTCalculatedMember {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_extension_list =
                                     Some(Box::new(ExtensionList::from_xml(reader, &e, false)?));
@@ -10606,7 +10613,7 @@ impl FromXml for CTCalculatedMember {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_extension_list =
                                     Some(Box::new(ExtensionList::from_xml(reader, &e, true)?));
@@ -10656,7 +10663,7 @@ impl FromXml for CTCalculatedMember {
 
 impl FromXml for CTPivotTableDefinition {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10976,7 +10983,7 @@ impl FromXml for CTPivotTableDefinition {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"location" => {
                                 f_location =
                                     Some(Box::new(PivotLocation::from_xml(reader, &e, false)?));
@@ -11134,7 +11141,7 @@ impl FromXml for CTPivotTableDefinition {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"location" => {
                                 f_location =
                                     Some(Box::new(PivotLocation::from_xml(reader, &e, true)?));
@@ -11394,7 +11401,7 @@ impl FromXml for CTPivotTableDefinition {
 
 impl FromXml for PivotLocation {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11470,7 +11477,7 @@ impl FromXml for PivotLocation {
 
 impl FromXml for PivotFields {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11506,7 +11513,7 @@ impl FromXml for PivotFields {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pivotField" => {
                                 f_pivot_field.push(PivotField::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -11532,7 +11539,7 @@ impl FromXml for PivotFields {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pivotField" => {
                                 f_pivot_field.push(PivotField::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -11575,7 +11582,7 @@ impl FromXml for PivotFields {
 
 impl FromXml for PivotField {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11801,7 +11808,7 @@ impl FromXml for PivotField {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"items" => {
                                 f_items = Some(Box::new(PivotItems::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -11843,7 +11850,7 @@ impl FromXml for PivotField {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"items" => {
                                 f_items = Some(Box::new(PivotItems::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -11951,7 +11958,7 @@ impl FromXml for PivotField {
 
 impl FromXml for PivotItems {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11987,7 +11994,7 @@ impl FromXml for PivotItems {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"item" => {
                                 f_item.push(PivotItem::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -12013,7 +12020,7 @@ impl FromXml for PivotItems {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"item" => {
                                 f_item.push(PivotItem::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -12056,7 +12063,7 @@ impl FromXml for PivotItems {
 
 impl FromXml for PivotItem {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12153,7 +12160,7 @@ impl FromXml for PivotItem {
 
 impl FromXml for PageFields {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12189,7 +12196,7 @@ impl FromXml for PageFields {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pageField" => {
                                 f_page_field.push(PageField::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -12215,7 +12222,7 @@ impl FromXml for PageFields {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pageField" => {
                                 f_page_field.push(PageField::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -12258,7 +12265,7 @@ impl FromXml for PageFields {
 
 impl FromXml for PageField {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12310,7 +12317,7 @@ impl FromXml for PageField {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_extension_list =
                                     Some(Box::new(ExtensionList::from_xml(reader, &e, false)?));
@@ -12337,7 +12344,7 @@ impl FromXml for PageField {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_extension_list =
                                     Some(Box::new(ExtensionList::from_xml(reader, &e, true)?));
@@ -12385,7 +12392,7 @@ impl FromXml for PageField {
 
 impl FromXml for DataFields {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12421,7 +12428,7 @@ impl FromXml for DataFields {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"dataField" => {
                                 f_data_field.push(DataField::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -12447,7 +12454,7 @@ impl FromXml for DataFields {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"dataField" => {
                                 f_data_field.push(DataField::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -12490,7 +12497,7 @@ impl FromXml for DataFields {
 
 impl FromXml for DataField {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12550,7 +12557,7 @@ impl FromXml for DataField {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_extension_list =
                                     Some(Box::new(ExtensionList::from_xml(reader, &e, false)?));
@@ -12577,7 +12584,7 @@ impl FromXml for DataField {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_extension_list =
                                     Some(Box::new(ExtensionList::from_xml(reader, &e, true)?));
@@ -12627,7 +12634,7 @@ impl FromXml for DataField {
 
 impl FromXml for CTRowItems {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12663,7 +12670,7 @@ impl FromXml for CTRowItems {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"i" => {
                                 f_i.push(CTI::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -12689,7 +12696,7 @@ impl FromXml for CTRowItems {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"i" => {
                                 f_i.push(CTI::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -12732,7 +12739,7 @@ impl FromXml for CTRowItems {
 
 impl FromXml for CTColItems {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12768,7 +12775,7 @@ impl FromXml for CTColItems {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"i" => {
                                 f_i.push(CTI::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -12794,7 +12801,7 @@ impl FromXml for CTColItems {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"i" => {
                                 f_i.push(CTI::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -12837,7 +12844,7 @@ impl FromXml for CTColItems {
 
 impl FromXml for CTI {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12881,7 +12888,7 @@ impl FromXml for CTI {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"x" => {
                                 f_x.push(CTX::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -12907,7 +12914,7 @@ impl FromXml for CTI {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"x" => {
                                 f_x.push(CTX::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -12952,7 +12959,7 @@ impl FromXml for CTI {
 
 impl FromXml for CTX {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12999,7 +13006,7 @@ impl FromXml for CTX {
 
 impl FromXml for RowFields {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13035,7 +13042,7 @@ impl FromXml for RowFields {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"field" => {
                                 f_field.push(CTField::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -13061,7 +13068,7 @@ impl FromXml for RowFields {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"field" => {
                                 f_field.push(CTField::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -13104,7 +13111,7 @@ impl FromXml for RowFields {
 
 impl FromXml for ColFields {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13140,7 +13147,7 @@ impl FromXml for ColFields {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"field" => {
                                 f_field.push(CTField::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -13166,7 +13173,7 @@ impl FromXml for ColFields {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"field" => {
                                 f_field.push(CTField::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -13209,7 +13216,7 @@ impl FromXml for ColFields {
 
 impl FromXml for CTField {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13256,7 +13263,7 @@ impl FromXml for CTField {
 
 impl FromXml for CTFormats {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13292,7 +13299,7 @@ impl FromXml for CTFormats {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"format" => {
                                 f_format.push(CTFormat::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -13318,7 +13325,7 @@ impl FromXml for CTFormats {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"format" => {
                                 f_format.push(CTFormat::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -13361,7 +13368,7 @@ impl FromXml for CTFormats {
 
 impl FromXml for CTFormat {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13402,7 +13409,7 @@ impl FromXml for CTFormat {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pivotArea" => {
                                 f_pivot_area =
                                     Some(Box::new(PivotArea::from_xml(reader, &e, false)?));
@@ -13437,7 +13444,7 @@ impl FromXml for CTFormat {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pivotArea" => {
                                 f_pivot_area =
                                     Some(Box::new(PivotArea::from_xml(reader, &e, true)?));
@@ -13492,7 +13499,7 @@ impl FromXml for CTFormat {
 
 impl FromXml for CTConditionalFormats {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13528,7 +13535,7 @@ impl FromXml for CTConditionalFormats {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"conditionalFormat" => {
                                 f_conditional_format
                                     .push(CTConditionalFormat::from_xml(reader, &e, false)?);
@@ -13555,7 +13562,7 @@ impl FromXml for CTConditionalFormats {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"conditionalFormat" => {
                                 f_conditional_format
                                     .push(CTConditionalFormat::from_xml(reader, &e, true)?);
@@ -13599,7 +13606,7 @@ impl FromXml for CTConditionalFormats {
 
 impl FromXml for CTConditionalFormat {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13644,7 +13651,7 @@ impl FromXml for CTConditionalFormat {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pivotAreas" => {
                                 f_pivot_areas =
                                     Some(Box::new(PivotAreas::from_xml(reader, &e, false)?));
@@ -13679,7 +13686,7 @@ impl FromXml for CTConditionalFormat {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pivotAreas" => {
                                 f_pivot_areas =
                                     Some(Box::new(PivotAreas::from_xml(reader, &e, true)?));
@@ -13736,7 +13743,7 @@ impl FromXml for CTConditionalFormat {
 
 impl FromXml for PivotAreas {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13772,7 +13779,7 @@ impl FromXml for PivotAreas {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pivotArea" => {
                                 f_pivot_area.push(PivotArea::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -13798,7 +13805,7 @@ impl FromXml for PivotAreas {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pivotArea" => {
                                 f_pivot_area.push(PivotArea::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -13841,7 +13848,7 @@ impl FromXml for PivotAreas {
 
 impl FromXml for CTChartFormats {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13877,7 +13884,7 @@ impl FromXml for CTChartFormats {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"chartFormat" => {
                                 f_chart_format.push(CTChartFormat::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -13903,7 +13910,7 @@ impl FromXml for CTChartFormats {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"chartFormat" => {
                                 f_chart_format.push(CTChartFormat::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -13946,7 +13953,7 @@ impl FromXml for CTChartFormats {
 
 impl FromXml for CTChartFormat {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13990,7 +13997,7 @@ impl FromXml for CTChartFormat {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pivotArea" => {
                                 f_pivot_area =
                                     Some(Box::new(PivotArea::from_xml(reader, &e, false)?));
@@ -14017,7 +14024,7 @@ impl FromXml for CTChartFormat {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pivotArea" => {
                                 f_pivot_area =
                                     Some(Box::new(PivotArea::from_xml(reader, &e, true)?));
@@ -14064,7 +14071,7 @@ impl FromXml for CTChartFormat {
 
 impl FromXml for CTPivotHierarchies {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14100,7 +14107,7 @@ impl FromXml for CTPivotHierarchies {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pivotHierarchy" => {
                                 f_pivot_hierarchy
                                     .push(CTPivotHierarchy::from_xml(reader, &e, false)?);
@@ -14127,7 +14134,7 @@ impl FromXml for CTPivotHierarchies {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pivotHierarchy" => {
                                 f_pivot_hierarchy
                                     .push(CTPivotHierarchy::from_xml(reader, &e, true)?);
@@ -14171,7 +14178,7 @@ impl FromXml for CTPivotHierarchies {
 
 impl FromXml for CTPivotHierarchy {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14249,7 +14256,7 @@ impl FromXml for CTPivotHierarchy {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"mps" => {
                                 f_mps = Some(Box::new(CTMemberProperties::from_xml(
                                     reader, &e, false,
@@ -14292,7 +14299,7 @@ impl FromXml for CTPivotHierarchy {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"mps" => {
                                 f_mps =
                                     Some(Box::new(CTMemberProperties::from_xml(reader, &e, true)?));
@@ -14363,7 +14370,7 @@ impl FromXml for CTPivotHierarchy {
 
 impl FromXml for CTRowHierarchiesUsage {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14399,7 +14406,7 @@ impl FromXml for CTRowHierarchiesUsage {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rowHierarchyUsage" => {
                                 f_row_hierarchy_usage
                                     .push(CTHierarchyUsage::from_xml(reader, &e, false)?);
@@ -14426,7 +14433,7 @@ impl FromXml for CTRowHierarchiesUsage {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rowHierarchyUsage" => {
                                 f_row_hierarchy_usage
                                     .push(CTHierarchyUsage::from_xml(reader, &e, true)?);
@@ -14470,7 +14477,7 @@ impl FromXml for CTRowHierarchiesUsage {
 
 impl FromXml for CTColHierarchiesUsage {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14506,7 +14513,7 @@ impl FromXml for CTColHierarchiesUsage {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"colHierarchyUsage" => {
                                 f_col_hierarchy_usage
                                     .push(CTHierarchyUsage::from_xml(reader, &e, false)?);
@@ -14533,7 +14540,7 @@ impl FromXml for CTColHierarchiesUsage {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"colHierarchyUsage" => {
                                 f_col_hierarchy_usage
                                     .push(CTHierarchyUsage::from_xml(reader, &e, true)?);
@@ -14577,7 +14584,7 @@ impl FromXml for CTColHierarchiesUsage {
 
 impl FromXml for CTHierarchyUsage {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14625,7 +14632,7 @@ impl FromXml for CTHierarchyUsage {
 
 impl FromXml for CTMemberProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14661,7 +14668,7 @@ impl FromXml for CTMemberProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"mp" => {
                                 f_mp.push(CTMemberProperty::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -14687,7 +14694,7 @@ impl FromXml for CTMemberProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"mp" => {
                                 f_mp.push(CTMemberProperty::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -14730,7 +14737,7 @@ impl FromXml for CTMemberProperties {
 
 impl FromXml for CTMemberProperty {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14817,7 +14824,7 @@ impl FromXml for CTMemberProperty {
 
 impl FromXml for CTMembers {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14857,7 +14864,7 @@ impl FromXml for CTMembers {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"member" => {
                                 f_member.push(CTMember::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -14883,7 +14890,7 @@ impl FromXml for CTMembers {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"member" => {
                                 f_member.push(CTMember::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -14927,7 +14934,7 @@ impl FromXml for CTMembers {
 
 impl FromXml for CTMember {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14974,7 +14981,7 @@ impl FromXml for CTMember {
 
 impl FromXml for CTDimensions {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15010,7 +15017,7 @@ impl FromXml for CTDimensions {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"dimension" => {
                                 f_dimension.push(CTPivotDimension::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -15036,7 +15043,7 @@ impl FromXml for CTDimensions {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"dimension" => {
                                 f_dimension.push(CTPivotDimension::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -15079,7 +15086,7 @@ impl FromXml for CTDimensions {
 
 impl FromXml for CTPivotDimension {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15143,7 +15150,7 @@ impl FromXml for CTPivotDimension {
 
 impl FromXml for CTMeasureGroups {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15179,7 +15186,7 @@ impl FromXml for CTMeasureGroups {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"measureGroup" => {
                                 f_measure_group.push(CTMeasureGroup::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -15205,7 +15212,7 @@ impl FromXml for CTMeasureGroups {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"measureGroup" => {
                                 f_measure_group.push(CTMeasureGroup::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -15248,7 +15255,7 @@ impl FromXml for CTMeasureGroups {
 
 impl FromXml for CTMeasureDimensionMaps {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15284,7 +15291,7 @@ impl FromXml for CTMeasureDimensionMaps {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"map" => {
                                 f_map.push(CTMeasureDimensionMap::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -15310,7 +15317,7 @@ impl FromXml for CTMeasureDimensionMaps {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"map" => {
                                 f_map.push(CTMeasureDimensionMap::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -15353,7 +15360,7 @@ impl FromXml for CTMeasureDimensionMaps {
 
 impl FromXml for CTMeasureGroup {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15406,7 +15413,7 @@ impl FromXml for CTMeasureGroup {
 
 impl FromXml for CTMeasureDimensionMap {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15458,7 +15465,7 @@ impl FromXml for CTMeasureDimensionMap {
 
 impl FromXml for CTPivotTableStyle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15530,7 +15537,7 @@ impl FromXml for CTPivotTableStyle {
 
 impl FromXml for PivotFilters {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15566,7 +15573,7 @@ impl FromXml for PivotFilters {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"filter" => {
                                 f_filter.push(PivotFilter::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -15592,7 +15599,7 @@ impl FromXml for PivotFilters {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"filter" => {
                                 f_filter.push(PivotFilter::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -15635,7 +15642,7 @@ impl FromXml for PivotFilters {
 
 impl FromXml for PivotFilter {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15712,7 +15719,7 @@ impl FromXml for PivotFilter {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"autoFilter" => {
                                 f_auto_filter =
                                     Some(Box::new(AutoFilter::from_xml(reader, &e, false)?));
@@ -15747,7 +15754,7 @@ impl FromXml for PivotFilter {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"autoFilter" => {
                                 f_auto_filter =
                                     Some(Box::new(AutoFilter::from_xml(reader, &e, true)?));
@@ -15811,7 +15818,7 @@ impl FromXml for PivotFilter {
 
 impl FromXml for PivotArea {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15892,7 +15899,7 @@ impl FromXml for PivotArea {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"references" => {
                                 f_references = Some(Box::new(CTPivotAreaReferences::from_xml(
                                     reader, &e, false,
@@ -15928,7 +15935,7 @@ impl FromXml for PivotArea {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"references" => {
                                 f_references = Some(Box::new(CTPivotAreaReferences::from_xml(
                                     reader, &e, true,
@@ -15993,7 +16000,7 @@ impl FromXml for PivotArea {
 
 impl FromXml for CTPivotAreaReferences {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16029,7 +16036,7 @@ impl FromXml for CTPivotAreaReferences {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"reference" => {
                                 f_reference
                                     .push(CTPivotAreaReference::from_xml(reader, &e, false)?);
@@ -16056,7 +16063,7 @@ impl FromXml for CTPivotAreaReferences {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"reference" => {
                                 f_reference.push(CTPivotAreaReference::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -16099,7 +16106,7 @@ impl FromXml for CTPivotAreaReferences {
 
 impl FromXml for CTPivotAreaReference {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16200,7 +16207,7 @@ impl FromXml for CTPivotAreaReference {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"x" => {
                                 f_x.push(CTIndex::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -16234,7 +16241,7 @@ impl FromXml for CTPivotAreaReference {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"x" => {
                                 f_x.push(CTIndex::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -16302,7 +16309,7 @@ impl FromXml for CTPivotAreaReference {
 
 impl FromXml for CTIndex {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16349,7 +16356,7 @@ impl FromXml for CTIndex {
 
 impl FromXml for QueryTable {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16470,7 +16477,7 @@ impl FromXml for QueryTable {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"queryTableRefresh" => {
                                 f_query_table_refresh =
                                     Some(Box::new(QueryTableRefresh::from_xml(reader, &e, false)?));
@@ -16505,7 +16512,7 @@ impl FromXml for QueryTable {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"queryTableRefresh" => {
                                 f_query_table_refresh =
                                     Some(Box::new(QueryTableRefresh::from_xml(reader, &e, true)?));
@@ -16580,7 +16587,7 @@ impl FromXml for QueryTable {
 
 impl FromXml for QueryTableRefresh {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16643,7 +16650,7 @@ impl FromXml for QueryTableRefresh {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"queryTableFields" => {
                                 f_query_table_fields =
                                     Some(Box::new(QueryTableFields::from_xml(reader, &e, false)?));
@@ -16695,7 +16702,7 @@ impl FromXml for QueryTableRefresh {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"queryTableFields" => {
                                 f_query_table_fields =
                                     Some(Box::new(QueryTableFields::from_xml(reader, &e, true)?));
@@ -16774,7 +16781,7 @@ impl FromXml for QueryTableRefresh {
 
 impl FromXml for QueryTableDeletedFields {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16810,7 +16817,7 @@ impl FromXml for QueryTableDeletedFields {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"deletedField" => {
                                 f_deleted_field.push(CTDeletedField::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -16836,7 +16843,7 @@ impl FromXml for QueryTableDeletedFields {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"deletedField" => {
                                 f_deleted_field.push(CTDeletedField::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -16879,7 +16886,7 @@ impl FromXml for QueryTableDeletedFields {
 
 impl FromXml for CTDeletedField {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16926,7 +16933,7 @@ impl FromXml for CTDeletedField {
 
 impl FromXml for QueryTableFields {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16962,7 +16969,7 @@ impl FromXml for QueryTableFields {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"queryTableField" => {
                                 f_query_table_field
                                     .push(QueryTableField::from_xml(reader, &e, false)?);
@@ -16989,7 +16996,7 @@ impl FromXml for QueryTableFields {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"queryTableField" => {
                                 f_query_table_field
                                     .push(QueryTableField::from_xml(reader, &e, true)?);
@@ -17033,7 +17040,7 @@ impl FromXml for QueryTableFields {
 
 impl FromXml for QueryTableField {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -17093,7 +17100,7 @@ impl FromXml for QueryTableField {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_extension_list =
                                     Some(Box::new(ExtensionList::from_xml(reader, &e, false)?));
@@ -17120,7 +17127,7 @@ impl FromXml for QueryTableField {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_extension_list =
                                     Some(Box::new(ExtensionList::from_xml(reader, &e, true)?));
@@ -17170,7 +17177,7 @@ impl FromXml for QueryTableField {
 
 impl FromXml for SharedStrings {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -17211,7 +17218,7 @@ impl FromXml for SharedStrings {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"si" => {
                                 f_si.push(RichString::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -17245,7 +17252,7 @@ impl FromXml for SharedStrings {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"si" => {
                                 f_si.push(RichString::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -17298,7 +17305,7 @@ impl FromXml for SharedStrings {
 
 impl FromXml for PhoneticRun {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -17338,7 +17345,7 @@ impl FromXml for PhoneticRun {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"t" => {
                                 f_cell_type = Some(read_text_content(reader)?);
                                 #[cfg(feature = "extra-children")]
@@ -17364,7 +17371,7 @@ impl FromXml for PhoneticRun {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"t" => {
                                 f_cell_type = Some(String::new());
                                 #[cfg(feature = "extra-children")]
@@ -17408,7 +17415,7 @@ impl FromXml for PhoneticRun {
 
 impl FromXml for RichTextElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -17425,7 +17432,7 @@ impl FromXml for RichTextElement {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rPr" => {
                                 f_r_pr = Some(Box::new(RichTextRunProperties::from_xml(
                                     reader, &e, false,
@@ -17460,7 +17467,7 @@ impl FromXml for RichTextElement {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rPr" => {
                                 f_r_pr = Some(Box::new(RichTextRunProperties::from_xml(
                                     reader, &e, true,
@@ -17510,7 +17517,7 @@ impl FromXml for RichTextElement {
 
 impl FromXml for RichTextRunProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -17540,7 +17547,7 @@ impl FromXml for RichTextRunProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rFont" => {
                                 f_r_font = Some(Box::new(FontName::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -17676,7 +17683,7 @@ impl FromXml for RichTextRunProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rFont" => {
                                 f_r_font = Some(Box::new(FontName::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -17838,7 +17845,7 @@ impl FromXml for RichTextRunProperties {
 
 impl FromXml for RichString {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -17857,7 +17864,7 @@ impl FromXml for RichString {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"t" => {
                                 f_cell_type = Some(read_text_content(reader)?);
                                 #[cfg(feature = "extra-children")]
@@ -17906,7 +17913,7 @@ impl FromXml for RichString {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"t" => {
                                 f_cell_type = Some(String::new());
                                 #[cfg(feature = "extra-children")]
@@ -17971,7 +17978,7 @@ impl FromXml for RichString {
 
 impl FromXml for PhoneticProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -18028,7 +18035,7 @@ impl FromXml for PhoneticProperties {
 
 impl FromXml for RevisionHeaders {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -18108,7 +18115,7 @@ impl FromXml for RevisionHeaders {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"header" => {
                                 f_header.push(RevisionHeader::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -18134,7 +18141,7 @@ impl FromXml for RevisionHeaders {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"header" => {
                                 f_header.push(RevisionHeader::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -18188,7 +18195,7 @@ impl FromXml for RevisionHeaders {
 
 impl FromXml for Revisions {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -18236,7 +18243,7 @@ impl FromXml for Revisions {
 
 impl FromXml for SmlAGRevData {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -18293,7 +18300,7 @@ impl FromXml for SmlAGRevData {
 
 impl FromXml for RevisionHeader {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -18355,7 +18362,7 @@ impl FromXml for RevisionHeader {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sheetIdMap" => {
                                 f_sheet_id_map =
                                     Some(Box::new(CTSheetIdMap::from_xml(reader, &e, false)?));
@@ -18398,7 +18405,7 @@ impl FromXml for RevisionHeader {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sheetIdMap" => {
                                 f_sheet_id_map =
                                     Some(Box::new(CTSheetIdMap::from_xml(reader, &e, true)?));
@@ -18470,7 +18477,7 @@ impl FromXml for RevisionHeader {
 
 impl FromXml for CTSheetIdMap {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -18506,7 +18513,7 @@ impl FromXml for CTSheetIdMap {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sheetId" => {
                                 f_sheet_id.push(CTSheetId::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -18532,7 +18539,7 @@ impl FromXml for CTSheetIdMap {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sheetId" => {
                                 f_sheet_id.push(CTSheetId::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -18575,7 +18582,7 @@ impl FromXml for CTSheetIdMap {
 
 impl FromXml for CTSheetId {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -18622,7 +18629,7 @@ impl FromXml for CTSheetId {
 
 impl FromXml for ReviewedRevisions {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -18658,7 +18665,7 @@ impl FromXml for ReviewedRevisions {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"reviewed" => {
                                 f_reviewed.push(Reviewed::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -18684,7 +18691,7 @@ impl FromXml for ReviewedRevisions {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"reviewed" => {
                                 f_reviewed.push(Reviewed::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -18727,7 +18734,7 @@ impl FromXml for ReviewedRevisions {
 
 impl FromXml for Reviewed {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -18774,7 +18781,7 @@ impl FromXml for Reviewed {
 
 impl FromXml for UndoInfo {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -18871,7 +18878,7 @@ impl FromXml for UndoInfo {
 
 impl FromXml for RevisionRowColumn {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -18954,7 +18961,7 @@ impl FromXml for RevisionRowColumn {
 
 impl FromXml for RevisionMove {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -19033,7 +19040,7 @@ impl FromXml for RevisionMove {
 
 impl FromXml for RevisionCustomView {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -19085,7 +19092,7 @@ impl FromXml for RevisionCustomView {
 
 impl FromXml for RevisionSheetRename {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -19141,7 +19148,7 @@ impl FromXml for RevisionSheetRename {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_extension_list =
                                     Some(Box::new(ExtensionList::from_xml(reader, &e, false)?));
@@ -19168,7 +19175,7 @@ impl FromXml for RevisionSheetRename {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_extension_list =
                                     Some(Box::new(ExtensionList::from_xml(reader, &e, true)?));
@@ -19220,7 +19227,7 @@ impl FromXml for RevisionSheetRename {
 
 impl FromXml for RevisionInsertSheet {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -19294,7 +19301,7 @@ impl FromXml for RevisionInsertSheet {
 
 impl FromXml for RevisionCellChange {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -19385,7 +19392,7 @@ impl FromXml for RevisionCellChange {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"oc" => {
                                 f_oc = Some(Box::new(Cell::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -19435,7 +19442,7 @@ impl FromXml for RevisionCellChange {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"oc" => {
                                 f_oc = Some(Box::new(Cell::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -19517,7 +19524,7 @@ impl FromXml for RevisionCellChange {
 
 impl FromXml for RevisionFormatting {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -19574,7 +19581,7 @@ impl FromXml for RevisionFormatting {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"dxf" => {
                                 f_dxf = Some(Box::new(DifferentialFormat::from_xml(
                                     reader, &e, false,
@@ -19610,7 +19617,7 @@ impl FromXml for RevisionFormatting {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"dxf" => {
                                 f_dxf =
                                     Some(Box::new(DifferentialFormat::from_xml(reader, &e, true)?));
@@ -19670,7 +19677,7 @@ impl FromXml for RevisionFormatting {
 
 impl FromXml for RevisionAutoFormatting {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -19759,7 +19766,7 @@ impl FromXml for RevisionAutoFormatting {
 
 impl FromXml for RevisionComment {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -19857,7 +19864,7 @@ impl FromXml for RevisionComment {
 
 impl FromXml for RevisionDefinedName {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -19987,7 +19994,7 @@ impl FromXml for RevisionDefinedName {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"formula" => {
                                 f_formula = Some(read_text_content(reader)?);
                                 #[cfg(feature = "extra-children")]
@@ -20028,7 +20035,7 @@ impl FromXml for RevisionDefinedName {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"formula" => {
                                 f_formula = Some(String::new());
                                 #[cfg(feature = "extra-children")]
@@ -20111,7 +20118,7 @@ impl FromXml for RevisionDefinedName {
 
 impl FromXml for RevisionConflict {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -20173,7 +20180,7 @@ impl FromXml for RevisionConflict {
 
 impl FromXml for RevisionQueryTableField {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -20233,7 +20240,7 @@ impl FromXml for RevisionQueryTableField {
 
 impl FromXml for Users {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -20269,7 +20276,7 @@ impl FromXml for Users {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"userInfo" => {
                                 f_user_info.push(SharedUser::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -20295,7 +20302,7 @@ impl FromXml for Users {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"userInfo" => {
                                 f_user_info.push(SharedUser::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -20338,7 +20345,7 @@ impl FromXml for Users {
 
 impl FromXml for SharedUser {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -20386,7 +20393,7 @@ impl FromXml for SharedUser {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_extension_list =
                                     Some(Box::new(ExtensionList::from_xml(reader, &e, false)?));
@@ -20413,7 +20420,7 @@ impl FromXml for SharedUser {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_extension_list =
                                     Some(Box::new(ExtensionList::from_xml(reader, &e, true)?));
@@ -20461,7 +20468,7 @@ impl FromXml for SharedUser {
 
 impl FromXml for CTMacrosheet {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -20503,7 +20510,7 @@ impl FromXml for CTMacrosheet {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sheetPr" => {
                                 f_sheet_properties =
                                     Some(Box::new(SheetProperties::from_xml(reader, &e, false)?));
@@ -20740,7 +20747,7 @@ impl FromXml for CTMacrosheet {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sheetPr" => {
                                 f_sheet_properties =
                                     Some(Box::new(SheetProperties::from_xml(reader, &e, true)?));
@@ -21016,7 +21023,7 @@ impl FromXml for CTMacrosheet {
 
 impl FromXml for CTDialogsheet {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -21047,7 +21054,7 @@ impl FromXml for CTDialogsheet {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sheetPr" => {
                                 f_sheet_properties =
                                     Some(Box::new(SheetProperties::from_xml(reader, &e, false)?));
@@ -21193,7 +21200,7 @@ impl FromXml for CTDialogsheet {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sheetPr" => {
                                 f_sheet_properties =
                                     Some(Box::new(SheetProperties::from_xml(reader, &e, true)?));
@@ -21368,7 +21375,7 @@ impl FromXml for CTDialogsheet {
 
 impl FromXml for Worksheet {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -21457,7 +21464,7 @@ impl FromXml for Worksheet {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-styling")]
                             b"sheetPr" => {
                                 f_sheet_properties =
@@ -21825,7 +21832,7 @@ impl FromXml for Worksheet {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-styling")]
                             b"sheetPr" => {
                                 f_sheet_properties =
@@ -22279,7 +22286,7 @@ impl FromXml for Worksheet {
 
 impl FromXml for SheetData {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -22295,7 +22302,7 @@ impl FromXml for SheetData {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"row" => {
                                 f_row.push(Row::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -22321,7 +22328,7 @@ impl FromXml for SheetData {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"row" => {
                                 f_row.push(Row::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -22361,7 +22368,7 @@ impl FromXml for SheetData {
 
 impl FromXml for SheetCalcProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -22408,7 +22415,7 @@ impl FromXml for SheetCalcProperties {
 
 impl FromXml for SheetFormat {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -22496,7 +22503,7 @@ impl FromXml for SheetFormat {
 
 impl FromXml for Columns {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -22512,7 +22519,7 @@ impl FromXml for Columns {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"col" => {
                                 f_col.push(Column::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -22538,7 +22545,7 @@ impl FromXml for Columns {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"col" => {
                                 f_col.push(Column::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -22578,7 +22585,7 @@ impl FromXml for Columns {
 
 impl FromXml for Column {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -22702,7 +22709,7 @@ impl FromXml for Column {
 
 impl FromXml for Row {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -22802,7 +22809,7 @@ impl FromXml for Row {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"c" => {
                                 f_cells.push(Cell::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -22837,7 +22844,7 @@ impl FromXml for Row {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"c" => {
                                 f_cells.push(Cell::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -22911,7 +22918,7 @@ impl FromXml for Row {
 
 impl FromXml for Cell {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -22977,7 +22984,7 @@ impl FromXml for Cell {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"f" => {
                                 f_formula =
                                     Some(Box::new(CellFormula::from_xml(reader, &e, false)?));
@@ -23027,7 +23034,7 @@ impl FromXml for Cell {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"f" => {
                                 f_formula =
                                     Some(Box::new(CellFormula::from_xml(reader, &e, true)?));
@@ -23106,7 +23113,7 @@ impl FromXml for Cell {
 
 impl FromXml for SheetProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23197,7 +23204,7 @@ impl FromXml for SheetProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-styling")]
                             b"tabColor" => {
                                 f_tab_color = Some(Box::new(Color::from_xml(reader, &e, false)?));
@@ -23243,7 +23250,7 @@ impl FromXml for SheetProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-styling")]
                             b"tabColor" => {
                                 f_tab_color = Some(Box::new(Color::from_xml(reader, &e, true)?));
@@ -23328,7 +23335,7 @@ impl FromXml for SheetProperties {
 
 impl FromXml for SheetDimension {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23376,7 +23383,7 @@ impl FromXml for SheetDimension {
 
 impl FromXml for SheetViews {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23393,7 +23400,7 @@ impl FromXml for SheetViews {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sheetView" => {
                                 f_sheet_view.push(SheetView::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -23427,7 +23434,7 @@ impl FromXml for SheetViews {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sheetView" => {
                                 f_sheet_view.push(SheetView::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -23476,7 +23483,7 @@ impl FromXml for SheetViews {
 
 impl FromXml for SheetView {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23616,7 +23623,7 @@ impl FromXml for SheetView {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-structure")]
                             b"pane" => {
                                 f_pane = Some(Box::new(Pane::from_xml(reader, &e, false)?));
@@ -23668,7 +23675,7 @@ impl FromXml for SheetView {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-structure")]
                             b"pane" => {
                                 f_pane = Some(Box::new(Pane::from_xml(reader, &e, true)?));
@@ -23775,7 +23782,7 @@ impl FromXml for SheetView {
 
 impl FromXml for Pane {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23857,7 +23864,7 @@ impl FromXml for Pane {
 
 impl FromXml for CTPivotSelection {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23957,7 +23964,7 @@ impl FromXml for CTPivotSelection {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pivotArea" => {
                                 f_pivot_area =
                                     Some(Box::new(PivotArea::from_xml(reader, &e, false)?));
@@ -23984,7 +23991,7 @@ impl FromXml for CTPivotSelection {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pivotArea" => {
                                 f_pivot_area =
                                     Some(Box::new(PivotArea::from_xml(reader, &e, true)?));
@@ -24045,7 +24052,7 @@ impl FromXml for CTPivotSelection {
 
 impl FromXml for Selection {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24107,7 +24114,7 @@ impl FromXml for Selection {
 
 impl FromXml for PageBreaks {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24152,7 +24159,7 @@ impl FromXml for PageBreaks {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-layout")]
                             b"brk" => {
                                 f_brk.push(PageBreak::from_xml(reader, &e, false)?);
@@ -24179,7 +24186,7 @@ impl FromXml for PageBreaks {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-layout")]
                             b"brk" => {
                                 f_brk.push(PageBreak::from_xml(reader, &e, true)?);
@@ -24227,7 +24234,7 @@ impl FromXml for PageBreaks {
 
 impl FromXml for PageBreak {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24294,7 +24301,7 @@ impl FromXml for PageBreak {
 
 impl FromXml for OutlineProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24356,7 +24363,7 @@ impl FromXml for OutlineProperties {
 
 impl FromXml for PageSetupProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24408,7 +24415,7 @@ impl FromXml for PageSetupProperties {
 
 impl FromXml for CTDataConsolidate {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24460,7 +24467,7 @@ impl FromXml for CTDataConsolidate {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"dataRefs" => {
                                 f_data_refs =
                                     Some(Box::new(CTDataRefs::from_xml(reader, &e, false)?));
@@ -24487,7 +24494,7 @@ impl FromXml for CTDataConsolidate {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"dataRefs" => {
                                 f_data_refs =
                                     Some(Box::new(CTDataRefs::from_xml(reader, &e, true)?));
@@ -24535,7 +24542,7 @@ impl FromXml for CTDataConsolidate {
 
 impl FromXml for CTDataRefs {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24571,7 +24578,7 @@ impl FromXml for CTDataRefs {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"dataRef" => {
                                 f_data_ref.push(CTDataRef::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -24597,7 +24604,7 @@ impl FromXml for CTDataRefs {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"dataRef" => {
                                 f_data_ref.push(CTDataRef::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -24640,7 +24647,7 @@ impl FromXml for CTDataRefs {
 
 impl FromXml for CTDataRef {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24702,7 +24709,7 @@ impl FromXml for CTDataRef {
 
 impl FromXml for MergedCells {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24738,7 +24745,7 @@ impl FromXml for MergedCells {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"mergeCell" => {
                                 f_merge_cell.push(MergedCell::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -24764,7 +24771,7 @@ impl FromXml for MergedCells {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"mergeCell" => {
                                 f_merge_cell.push(MergedCell::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -24807,7 +24814,7 @@ impl FromXml for MergedCells {
 
 impl FromXml for MergedCell {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24855,7 +24862,7 @@ impl FromXml for MergedCell {
 
 impl FromXml for SmartTags {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24871,7 +24878,7 @@ impl FromXml for SmartTags {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cellSmartTags" => {
                                 f_cell_smart_tags.push(CellSmartTags::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -24897,7 +24904,7 @@ impl FromXml for SmartTags {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cellSmartTags" => {
                                 f_cell_smart_tags.push(CellSmartTags::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -24937,7 +24944,7 @@ impl FromXml for SmartTags {
 
 impl FromXml for CellSmartTags {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24973,7 +24980,7 @@ impl FromXml for CellSmartTags {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cellSmartTag" => {
                                 f_cell_smart_tag.push(CellSmartTag::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -24999,7 +25006,7 @@ impl FromXml for CellSmartTags {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cellSmartTag" => {
                                 f_cell_smart_tag.push(CellSmartTag::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -25042,7 +25049,7 @@ impl FromXml for CellSmartTags {
 
 impl FromXml for CellSmartTag {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25086,7 +25093,7 @@ impl FromXml for CellSmartTag {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cellSmartTagPr" => {
                                 f_cell_smart_tag_pr
                                     .push(CTCellSmartTagPr::from_xml(reader, &e, false)?);
@@ -25113,7 +25120,7 @@ impl FromXml for CellSmartTag {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cellSmartTagPr" => {
                                 f_cell_smart_tag_pr
                                     .push(CTCellSmartTagPr::from_xml(reader, &e, true)?);
@@ -25159,7 +25166,7 @@ impl FromXml for CellSmartTag {
 
 impl FromXml for CTCellSmartTagPr {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25211,7 +25218,7 @@ impl FromXml for CTCellSmartTagPr {
 
 impl FromXml for Drawing {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25258,7 +25265,7 @@ impl FromXml for Drawing {
 
 impl FromXml for LegacyDrawing {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25305,7 +25312,7 @@ impl FromXml for LegacyDrawing {
 
 impl FromXml for DrawingHeaderFooter {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25442,7 +25449,7 @@ impl FromXml for DrawingHeaderFooter {
 
 impl FromXml for CustomSheetViews {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25458,7 +25465,7 @@ impl FromXml for CustomSheetViews {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customSheetView" => {
                                 f_custom_sheet_view
                                     .push(CustomSheetView::from_xml(reader, &e, false)?);
@@ -25485,7 +25492,7 @@ impl FromXml for CustomSheetViews {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customSheetView" => {
                                 f_custom_sheet_view
                                     .push(CustomSheetView::from_xml(reader, &e, true)?);
@@ -25526,7 +25533,7 @@ impl FromXml for CustomSheetViews {
 
 impl FromXml for CustomSheetView {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25647,7 +25654,7 @@ impl FromXml for CustomSheetView {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pane" => {
                                 f_pane = Some(Box::new(Pane::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -25745,7 +25752,7 @@ impl FromXml for CustomSheetView {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pane" => {
                                 f_pane = Some(Box::new(Pane::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -25888,7 +25895,7 @@ impl FromXml for CustomSheetView {
 
 impl FromXml for DataValidations {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25936,7 +25943,7 @@ impl FromXml for DataValidations {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"dataValidation" => {
                                 f_data_validation
                                     .push(DataValidation::from_xml(reader, &e, false)?);
@@ -25963,7 +25970,7 @@ impl FromXml for DataValidations {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"dataValidation" => {
                                 f_data_validation.push(DataValidation::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -26009,7 +26016,7 @@ impl FromXml for DataValidations {
 
 impl FromXml for DataValidation {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -26122,7 +26129,7 @@ impl FromXml for DataValidation {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-validation")]
                             b"formula1" => {
                                 f_formula1 = Some(read_text_content(reader)?);
@@ -26157,7 +26164,7 @@ impl FromXml for DataValidation {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-validation")]
                             b"formula1" => {
                                 f_formula1 = Some(String::new());
@@ -26238,7 +26245,7 @@ impl FromXml for DataValidation {
 
 impl FromXml for ConditionalFormatting {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -26285,7 +26292,7 @@ impl FromXml for ConditionalFormatting {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-styling")]
                             b"cfRule" => {
                                 f_cf_rule.push(ConditionalRule::from_xml(reader, &e, false)?);
@@ -26321,7 +26328,7 @@ impl FromXml for ConditionalFormatting {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-styling")]
                             b"cfRule" => {
                                 f_cf_rule.push(ConditionalRule::from_xml(reader, &e, true)?);
@@ -26380,7 +26387,7 @@ impl FromXml for ConditionalFormatting {
 
 impl FromXml for ConditionalRule {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -26499,7 +26506,7 @@ impl FromXml for ConditionalRule {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-styling")]
                             b"formula" => {
                                 f_formula.push(read_text_content(reader)?);
@@ -26560,7 +26567,7 @@ impl FromXml for ConditionalRule {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-styling")]
                             b"formula" => {
                                 f_formula.push(String::new());
@@ -26673,7 +26680,7 @@ impl FromXml for ConditionalRule {
 
 impl FromXml for Hyperlinks {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -26689,7 +26696,7 @@ impl FromXml for Hyperlinks {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"hyperlink" => {
                                 f_hyperlink.push(Hyperlink::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -26715,7 +26722,7 @@ impl FromXml for Hyperlinks {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"hyperlink" => {
                                 f_hyperlink.push(Hyperlink::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -26755,7 +26762,7 @@ impl FromXml for Hyperlinks {
 
 impl FromXml for Hyperlink {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -26835,7 +26842,7 @@ impl FromXml for Hyperlink {
 
 impl FromXml for CellFormula {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -26933,7 +26940,7 @@ impl FromXml for CellFormula {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "extra-children")]
                             _ => {
                                 // Capture unknown element for roundtrip
@@ -26952,7 +26959,7 @@ impl FromXml for CellFormula {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "extra-children")]
                             _ => {
                                 // Capture unknown empty element for roundtrip
@@ -27018,7 +27025,7 @@ impl FromXml for CellFormula {
 
 impl FromXml for ColorScale {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -27037,7 +27044,7 @@ impl FromXml for ColorScale {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-styling")]
                             b"cfvo" => {
                                 f_cfvo.push(ConditionalFormatValue::from_xml(reader, &e, false)?);
@@ -27072,7 +27079,7 @@ impl FromXml for ColorScale {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-styling")]
                             b"cfvo" => {
                                 f_cfvo.push(ConditionalFormatValue::from_xml(reader, &e, true)?);
@@ -27124,7 +27131,7 @@ impl FromXml for ColorScale {
 
 impl FromXml for DataBar {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -27177,7 +27184,7 @@ impl FromXml for DataBar {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-styling")]
                             b"cfvo" => {
                                 f_cfvo.push(ConditionalFormatValue::from_xml(reader, &e, false)?);
@@ -27212,7 +27219,7 @@ impl FromXml for DataBar {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-styling")]
                             b"cfvo" => {
                                 f_cfvo.push(ConditionalFormatValue::from_xml(reader, &e, true)?);
@@ -27272,7 +27279,7 @@ impl FromXml for DataBar {
 
 impl FromXml for IconSet {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -27329,7 +27336,7 @@ impl FromXml for IconSet {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-styling")]
                             b"cfvo" => {
                                 f_cfvo.push(ConditionalFormatValue::from_xml(reader, &e, false)?);
@@ -27356,7 +27363,7 @@ impl FromXml for IconSet {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-styling")]
                             b"cfvo" => {
                                 f_cfvo.push(ConditionalFormatValue::from_xml(reader, &e, true)?);
@@ -27408,7 +27415,7 @@ impl FromXml for IconSet {
 
 impl FromXml for ConditionalFormatValue {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -27458,7 +27465,7 @@ impl FromXml for ConditionalFormatValue {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_extension_list =
                                     Some(Box::new(ExtensionList::from_xml(reader, &e, false)?));
@@ -27485,7 +27492,7 @@ impl FromXml for ConditionalFormatValue {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_extension_list =
                                     Some(Box::new(ExtensionList::from_xml(reader, &e, true)?));
@@ -27534,7 +27541,7 @@ impl FromXml for ConditionalFormatValue {
 
 impl FromXml for PageMargins {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -27624,7 +27631,7 @@ impl FromXml for PageMargins {
 
 impl FromXml for PrintOptions {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -27691,7 +27698,7 @@ impl FromXml for PrintOptions {
 
 impl FromXml for PageSetup {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -27882,7 +27889,7 @@ impl FromXml for PageSetup {
 
 impl FromXml for HeaderFooter {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -27949,7 +27956,7 @@ impl FromXml for HeaderFooter {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-layout")]
                             b"oddHeader" => {
                                 f_odd_header = Some(read_text_content(reader)?);
@@ -28016,7 +28023,7 @@ impl FromXml for HeaderFooter {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-layout")]
                             b"oddHeader" => {
                                 f_odd_header = Some(String::new());
@@ -28118,7 +28125,7 @@ impl FromXml for HeaderFooter {
 
 impl FromXml for Scenarios {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -28162,7 +28169,7 @@ impl FromXml for Scenarios {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scenario" => {
                                 f_scenario.push(Scenario::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -28188,7 +28195,7 @@ impl FromXml for Scenarios {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"scenario" => {
                                 f_scenario.push(Scenario::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -28233,7 +28240,7 @@ impl FromXml for Scenarios {
 
 impl FromXml for SheetProtection {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -28380,7 +28387,7 @@ impl FromXml for SheetProtection {
 
 impl FromXml for ProtectedRanges {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -28396,7 +28403,7 @@ impl FromXml for ProtectedRanges {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"protectedRange" => {
                                 f_protected_range
                                     .push(ProtectedRange::from_xml(reader, &e, false)?);
@@ -28423,7 +28430,7 @@ impl FromXml for ProtectedRanges {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"protectedRange" => {
                                 f_protected_range.push(ProtectedRange::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -28463,7 +28470,7 @@ impl FromXml for ProtectedRanges {
 
 impl FromXml for ProtectedRange {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -28546,7 +28553,7 @@ impl FromXml for ProtectedRange {
 
 impl FromXml for Scenario {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -28602,7 +28609,7 @@ impl FromXml for Scenario {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"inputCells" => {
                                 f_input_cells.push(InputCells::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -28628,7 +28635,7 @@ impl FromXml for Scenario {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"inputCells" => {
                                 f_input_cells.push(InputCells::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -28676,7 +28683,7 @@ impl FromXml for Scenario {
 
 impl FromXml for InputCells {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -28743,7 +28750,7 @@ impl FromXml for InputCells {
 
 impl FromXml for CellWatches {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -28759,7 +28766,7 @@ impl FromXml for CellWatches {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cellWatch" => {
                                 f_cell_watch.push(CellWatch::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -28785,7 +28792,7 @@ impl FromXml for CellWatches {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cellWatch" => {
                                 f_cell_watch.push(CellWatch::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -28825,7 +28832,7 @@ impl FromXml for CellWatches {
 
 impl FromXml for CellWatch {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -28872,7 +28879,7 @@ impl FromXml for CellWatch {
 
 impl FromXml for Chartsheet {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -28901,7 +28908,7 @@ impl FromXml for Chartsheet {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sheetPr" => {
                                 f_sheet_properties = Some(Box::new(
                                     ChartsheetProperties::from_xml(reader, &e, false)?,
@@ -29037,7 +29044,7 @@ impl FromXml for Chartsheet {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sheetPr" => {
                                 f_sheet_properties = Some(Box::new(
                                     ChartsheetProperties::from_xml(reader, &e, true)?,
@@ -29202,7 +29209,7 @@ impl FromXml for Chartsheet {
 
 impl FromXml for ChartsheetProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29242,7 +29249,7 @@ impl FromXml for ChartsheetProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tabColor" => {
                                 f_tab_color = Some(Box::new(Color::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -29268,7 +29275,7 @@ impl FromXml for ChartsheetProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tabColor" => {
                                 f_tab_color = Some(Box::new(Color::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -29312,7 +29319,7 @@ impl FromXml for ChartsheetProperties {
 
 impl FromXml for ChartsheetViews {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29329,7 +29336,7 @@ impl FromXml for ChartsheetViews {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sheetView" => {
                                 f_sheet_view.push(ChartsheetView::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -29363,7 +29370,7 @@ impl FromXml for ChartsheetViews {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sheetView" => {
                                 f_sheet_view.push(ChartsheetView::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -29412,7 +29419,7 @@ impl FromXml for ChartsheetViews {
 
 impl FromXml for ChartsheetView {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29460,7 +29467,7 @@ impl FromXml for ChartsheetView {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_extension_list =
                                     Some(Box::new(ExtensionList::from_xml(reader, &e, false)?));
@@ -29487,7 +29494,7 @@ impl FromXml for ChartsheetView {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_extension_list =
                                     Some(Box::new(ExtensionList::from_xml(reader, &e, true)?));
@@ -29535,7 +29542,7 @@ impl FromXml for ChartsheetView {
 
 impl FromXml for ChartsheetProtection {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29612,7 +29619,7 @@ impl FromXml for ChartsheetProtection {
 
 impl FromXml for ChartsheetPageSetup {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29719,7 +29726,7 @@ impl FromXml for ChartsheetPageSetup {
 
 impl FromXml for CustomChartsheetViews {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29735,7 +29742,7 @@ impl FromXml for CustomChartsheetViews {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customSheetView" => {
                                 f_custom_sheet_view
                                     .push(CustomChartsheetView::from_xml(reader, &e, false)?);
@@ -29762,7 +29769,7 @@ impl FromXml for CustomChartsheetViews {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customSheetView" => {
                                 f_custom_sheet_view
                                     .push(CustomChartsheetView::from_xml(reader, &e, true)?);
@@ -29803,7 +29810,7 @@ impl FromXml for CustomChartsheetViews {
 
 impl FromXml for CustomChartsheetView {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29853,7 +29860,7 @@ impl FromXml for CustomChartsheetView {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pageMargins" => {
                                 f_page_margins =
                                     Some(Box::new(PageMargins::from_xml(reader, &e, false)?));
@@ -29897,7 +29904,7 @@ impl FromXml for CustomChartsheetView {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pageMargins" => {
                                 f_page_margins =
                                     Some(Box::new(PageMargins::from_xml(reader, &e, true)?));
@@ -29963,7 +29970,7 @@ impl FromXml for CustomChartsheetView {
 
 impl FromXml for CTCustomProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29979,7 +29986,7 @@ impl FromXml for CTCustomProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customPr" => {
                                 f_custom_pr.push(CTCustomProperty::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -30005,7 +30012,7 @@ impl FromXml for CTCustomProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customPr" => {
                                 f_custom_pr.push(CTCustomProperty::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -30045,7 +30052,7 @@ impl FromXml for CTCustomProperties {
 
 impl FromXml for CTCustomProperty {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -30097,7 +30104,7 @@ impl FromXml for CTCustomProperty {
 
 impl FromXml for OleObjects {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -30113,7 +30120,7 @@ impl FromXml for OleObjects {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"oleObject" => {
                                 f_ole_object.push(OleObject::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -30139,7 +30146,7 @@ impl FromXml for OleObjects {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"oleObject" => {
                                 f_ole_object.push(OleObject::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -30179,7 +30186,7 @@ impl FromXml for OleObjects {
 
 impl FromXml for OleObject {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -30239,7 +30246,7 @@ impl FromXml for OleObject {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"objectPr" => {
                                 f_object_pr =
                                     Some(Box::new(ObjectProperties::from_xml(reader, &e, false)?));
@@ -30266,7 +30273,7 @@ impl FromXml for OleObject {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"objectPr" => {
                                 f_object_pr =
                                     Some(Box::new(ObjectProperties::from_xml(reader, &e, true)?));
@@ -30317,7 +30324,7 @@ impl FromXml for OleObject {
 
 impl FromXml for ObjectProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -30397,7 +30404,7 @@ impl FromXml for ObjectProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"anchor" => {
                                 f_anchor =
                                     Some(Box::new(ObjectAnchor::from_xml(reader, &e, false)?));
@@ -30424,7 +30431,7 @@ impl FromXml for ObjectProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"anchor" => {
                                 f_anchor =
                                     Some(Box::new(ObjectAnchor::from_xml(reader, &e, true)?));
@@ -30479,7 +30486,7 @@ impl FromXml for ObjectProperties {
 
 impl FromXml for WebPublishItems {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -30515,7 +30522,7 @@ impl FromXml for WebPublishItems {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"webPublishItem" => {
                                 f_web_publish_item
                                     .push(WebPublishItem::from_xml(reader, &e, false)?);
@@ -30542,7 +30549,7 @@ impl FromXml for WebPublishItems {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"webPublishItem" => {
                                 f_web_publish_item
                                     .push(WebPublishItem::from_xml(reader, &e, true)?);
@@ -30586,7 +30593,7 @@ impl FromXml for WebPublishItems {
 
 impl FromXml for WebPublishItem {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -30670,7 +30677,7 @@ impl FromXml for WebPublishItem {
 
 impl FromXml for Controls {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -30686,7 +30693,7 @@ impl FromXml for Controls {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"control" => {
                                 f_control.push(Control::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -30712,7 +30719,7 @@ impl FromXml for Controls {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"control" => {
                                 f_control.push(Control::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -30752,7 +30759,7 @@ impl FromXml for Controls {
 
 impl FromXml for Control {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -30796,7 +30803,7 @@ impl FromXml for Control {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"controlPr" => {
                                 f_control_pr =
                                     Some(Box::new(CTControlPr::from_xml(reader, &e, false)?));
@@ -30823,7 +30830,7 @@ impl FromXml for Control {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"controlPr" => {
                                 f_control_pr =
                                     Some(Box::new(CTControlPr::from_xml(reader, &e, true)?));
@@ -30870,7 +30877,7 @@ impl FromXml for Control {
 
 impl FromXml for CTControlPr {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -30962,7 +30969,7 @@ impl FromXml for CTControlPr {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"anchor" => {
                                 f_anchor =
                                     Some(Box::new(ObjectAnchor::from_xml(reader, &e, false)?));
@@ -30989,7 +30996,7 @@ impl FromXml for CTControlPr {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"anchor" => {
                                 f_anchor =
                                     Some(Box::new(ObjectAnchor::from_xml(reader, &e, true)?));
@@ -31047,7 +31054,7 @@ impl FromXml for CTControlPr {
 
 impl FromXml for IgnoredErrors {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31064,7 +31071,7 @@ impl FromXml for IgnoredErrors {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ignoredError" => {
                                 f_ignored_error.push(IgnoredError::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -31098,7 +31105,7 @@ impl FromXml for IgnoredErrors {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ignoredError" => {
                                 f_ignored_error.push(IgnoredError::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -31147,7 +31154,7 @@ impl FromXml for IgnoredErrors {
 
 impl FromXml for IgnoredError {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31240,7 +31247,7 @@ impl FromXml for IgnoredError {
 
 impl FromXml for TableParts {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31276,7 +31283,7 @@ impl FromXml for TableParts {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tablePart" => {
                                 f_table_part.push(TablePart::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -31302,7 +31309,7 @@ impl FromXml for TableParts {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tablePart" => {
                                 f_table_part.push(TablePart::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -31345,7 +31352,7 @@ impl FromXml for TableParts {
 
 impl FromXml for TablePart {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31392,7 +31399,7 @@ impl FromXml for TablePart {
 
 impl FromXml for Metadata {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31414,7 +31421,7 @@ impl FromXml for Metadata {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"metadataTypes" => {
                                 f_metadata_types =
                                     Some(Box::new(MetadataTypes::from_xml(reader, &e, false)?));
@@ -31489,7 +31496,7 @@ impl FromXml for Metadata {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"metadataTypes" => {
                                 f_metadata_types =
                                     Some(Box::new(MetadataTypes::from_xml(reader, &e, true)?));
@@ -31584,7 +31591,7 @@ impl FromXml for Metadata {
 
 impl FromXml for MetadataTypes {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31620,7 +31627,7 @@ impl FromXml for MetadataTypes {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"metadataType" => {
                                 f_metadata_type.push(MetadataType::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -31646,7 +31653,7 @@ impl FromXml for MetadataTypes {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"metadataType" => {
                                 f_metadata_type.push(MetadataType::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -31689,7 +31696,7 @@ impl FromXml for MetadataTypes {
 
 impl FromXml for MetadataType {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31872,7 +31879,7 @@ impl FromXml for MetadataType {
 
 impl FromXml for MetadataBlocks {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31908,7 +31915,7 @@ impl FromXml for MetadataBlocks {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"bk" => {
                                 f_bk.push(MetadataBlock::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -31934,7 +31941,7 @@ impl FromXml for MetadataBlocks {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"bk" => {
                                 f_bk.push(MetadataBlock::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -31977,7 +31984,7 @@ impl FromXml for MetadataBlocks {
 
 impl FromXml for MetadataBlock {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31993,7 +32000,7 @@ impl FromXml for MetadataBlock {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rc" => {
                                 f_rc.push(MetadataRecord::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -32019,7 +32026,7 @@ impl FromXml for MetadataBlock {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rc" => {
                                 f_rc.push(MetadataRecord::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -32059,7 +32066,7 @@ impl FromXml for MetadataBlock {
 
 impl FromXml for MetadataRecord {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -32111,7 +32118,7 @@ impl FromXml for MetadataRecord {
 
 impl FromXml for CTFutureMetadata {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -32152,7 +32159,7 @@ impl FromXml for CTFutureMetadata {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"bk" => {
                                 f_bk.push(CTFutureMetadataBlock::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -32186,7 +32193,7 @@ impl FromXml for CTFutureMetadata {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"bk" => {
                                 f_bk.push(CTFutureMetadataBlock::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -32239,7 +32246,7 @@ impl FromXml for CTFutureMetadata {
 
 impl FromXml for CTFutureMetadataBlock {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -32255,7 +32262,7 @@ impl FromXml for CTFutureMetadataBlock {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_extension_list =
                                     Some(Box::new(ExtensionList::from_xml(reader, &e, false)?));
@@ -32282,7 +32289,7 @@ impl FromXml for CTFutureMetadataBlock {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_extension_list =
                                     Some(Box::new(ExtensionList::from_xml(reader, &e, true)?));
@@ -32323,7 +32330,7 @@ impl FromXml for CTFutureMetadataBlock {
 
 impl FromXml for CTMdxMetadata {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -32359,7 +32366,7 @@ impl FromXml for CTMdxMetadata {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"mdx" => {
                                 f_mdx.push(CTMdx::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -32385,7 +32392,7 @@ impl FromXml for CTMdxMetadata {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"mdx" => {
                                 f_mdx.push(CTMdx::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -32428,7 +32435,7 @@ impl FromXml for CTMdxMetadata {
 
 impl FromXml for CTMdx {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -32471,7 +32478,7 @@ impl FromXml for CTMdx {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"t" => {
                                 f_cell_type =
                                     Some(Box::new(CTMdxTuple::from_xml(reader, &e, false)?));
@@ -32520,7 +32527,7 @@ impl FromXml for CTMdx {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"t" => {
                                 f_cell_type =
                                     Some(Box::new(CTMdxTuple::from_xml(reader, &e, true)?));
@@ -32589,7 +32596,7 @@ impl FromXml for CTMdx {
 
 impl FromXml for CTMdxTuple {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -32661,7 +32668,7 @@ impl FromXml for CTMdxTuple {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"n" => {
                                 f_n.push(CTMetadataStringIndex::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -32687,7 +32694,7 @@ impl FromXml for CTMdxTuple {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"n" => {
                                 f_n.push(CTMetadataStringIndex::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -32739,7 +32746,7 @@ impl FromXml for CTMdxTuple {
 
 impl FromXml for CTMdxSet {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -32783,7 +32790,7 @@ impl FromXml for CTMdxSet {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"n" => {
                                 f_n.push(CTMetadataStringIndex::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -32809,7 +32816,7 @@ impl FromXml for CTMdxSet {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"n" => {
                                 f_n.push(CTMetadataStringIndex::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -32854,7 +32861,7 @@ impl FromXml for CTMdxSet {
 
 impl FromXml for CTMdxMemeberProp {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -32906,7 +32913,7 @@ impl FromXml for CTMdxMemeberProp {
 
 impl FromXml for CTMdxKPI {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -32963,7 +32970,7 @@ impl FromXml for CTMdxKPI {
 
 impl FromXml for CTMetadataStringIndex {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -33015,7 +33022,7 @@ impl FromXml for CTMetadataStringIndex {
 
 impl FromXml for MetadataStrings {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -33051,7 +33058,7 @@ impl FromXml for MetadataStrings {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"s" => {
                                 f_style_index.push(CTXStringElement::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -33077,7 +33084,7 @@ impl FromXml for MetadataStrings {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"s" => {
                                 f_style_index.push(CTXStringElement::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -33120,7 +33127,7 @@ impl FromXml for MetadataStrings {
 
 impl FromXml for SingleXmlCells {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -33136,7 +33143,7 @@ impl FromXml for SingleXmlCells {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"singleXmlCell" => {
                                 f_single_xml_cell.push(SingleXmlCell::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -33162,7 +33169,7 @@ impl FromXml for SingleXmlCells {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"singleXmlCell" => {
                                 f_single_xml_cell.push(SingleXmlCell::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -33202,7 +33209,7 @@ impl FromXml for SingleXmlCells {
 
 impl FromXml for SingleXmlCell {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -33247,7 +33254,7 @@ impl FromXml for SingleXmlCell {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"xmlCellPr" => {
                                 f_xml_cell_pr =
                                     Some(Box::new(XmlCellProperties::from_xml(reader, &e, false)?));
@@ -33282,7 +33289,7 @@ impl FromXml for SingleXmlCell {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"xmlCellPr" => {
                                 f_xml_cell_pr =
                                     Some(Box::new(XmlCellProperties::from_xml(reader, &e, true)?));
@@ -33339,7 +33346,7 @@ impl FromXml for SingleXmlCell {
 
 impl FromXml for XmlCellProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -33380,7 +33387,7 @@ impl FromXml for XmlCellProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"xmlPr" => {
                                 f_xml_pr =
                                     Some(Box::new(XmlProperties::from_xml(reader, &e, false)?));
@@ -33415,7 +33422,7 @@ impl FromXml for XmlCellProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"xmlPr" => {
                                 f_xml_pr =
                                     Some(Box::new(XmlProperties::from_xml(reader, &e, true)?));
@@ -33469,7 +33476,7 @@ impl FromXml for XmlCellProperties {
 
 impl FromXml for XmlProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -33513,7 +33520,7 @@ impl FromXml for XmlProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_extension_list =
                                     Some(Box::new(ExtensionList::from_xml(reader, &e, false)?));
@@ -33540,7 +33547,7 @@ impl FromXml for XmlProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_extension_list =
                                     Some(Box::new(ExtensionList::from_xml(reader, &e, true)?));
@@ -33587,7 +33594,7 @@ impl FromXml for XmlProperties {
 
 impl FromXml for Stylesheet {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -33624,7 +33631,7 @@ impl FromXml for Stylesheet {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-styling")]
                             b"numFmts" => {
                                 f_num_fmts =
@@ -33739,7 +33746,7 @@ impl FromXml for Stylesheet {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-styling")]
                             b"numFmts" => {
                                 f_num_fmts =
@@ -33889,7 +33896,7 @@ impl FromXml for Stylesheet {
 
 impl FromXml for CellAlignment {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -34003,7 +34010,7 @@ impl FromXml for CellAlignment {
 
 impl FromXml for Borders {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -34039,7 +34046,7 @@ impl FromXml for Borders {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"border" => {
                                 f_border.push(Border::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -34065,7 +34072,7 @@ impl FromXml for Borders {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"border" => {
                                 f_border.push(Border::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -34108,7 +34115,7 @@ impl FromXml for Borders {
 
 impl FromXml for Border {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -34173,7 +34180,7 @@ impl FromXml for Border {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"start" => {
                                 f_start =
                                     Some(Box::new(BorderProperties::from_xml(reader, &e, false)?));
@@ -34271,7 +34278,7 @@ impl FromXml for Border {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"start" => {
                                 f_start =
                                     Some(Box::new(BorderProperties::from_xml(reader, &e, true)?));
@@ -34406,7 +34413,7 @@ impl FromXml for Border {
 
 impl FromXml for BorderProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -34442,7 +34449,7 @@ impl FromXml for BorderProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"color" => {
                                 f_color = Some(Box::new(Color::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -34468,7 +34475,7 @@ impl FromXml for BorderProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"color" => {
                                 f_color = Some(Box::new(Color::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -34511,7 +34518,7 @@ impl FromXml for BorderProperties {
 
 impl FromXml for CellProtection {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -34569,7 +34576,7 @@ impl FromXml for CellProtection {
 
 impl FromXml for Fonts {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -34605,7 +34612,7 @@ impl FromXml for Fonts {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"font" => {
                                 f_font.push(Font::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -34631,7 +34638,7 @@ impl FromXml for Fonts {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"font" => {
                                 f_font.push(Font::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -34674,7 +34681,7 @@ impl FromXml for Fonts {
 
 impl FromXml for Fills {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -34710,7 +34717,7 @@ impl FromXml for Fills {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"fill" => {
                                 f_fill.push(Fill::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -34736,7 +34743,7 @@ impl FromXml for Fills {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"fill" => {
                                 f_fill.push(Fill::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -34779,7 +34786,7 @@ impl FromXml for Fills {
 
 impl FromXml for Fill {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -34798,7 +34805,7 @@ impl FromXml for Fill {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-styling")]
                             b"patternFill" => {
                                 f_pattern_fill =
@@ -34835,7 +34842,7 @@ impl FromXml for Fill {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-styling")]
                             b"patternFill" => {
                                 f_pattern_fill =
@@ -34889,7 +34896,7 @@ impl FromXml for Fill {
 
 impl FromXml for PatternFill {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -34926,7 +34933,7 @@ impl FromXml for PatternFill {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"fgColor" => {
                                 f_fg_color = Some(Box::new(Color::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -34959,7 +34966,7 @@ impl FromXml for PatternFill {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"fgColor" => {
                                 f_fg_color = Some(Box::new(Color::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -35010,7 +35017,7 @@ impl FromXml for PatternFill {
 
 impl FromXml for Color {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -35092,7 +35099,7 @@ impl FromXml for Color {
 
 impl FromXml for GradientFill {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -35148,7 +35155,7 @@ impl FromXml for GradientFill {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"stop" => {
                                 f_stop.push(GradientStop::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -35174,7 +35181,7 @@ impl FromXml for GradientFill {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"stop" => {
                                 f_stop.push(GradientStop::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -35222,7 +35229,7 @@ impl FromXml for GradientFill {
 
 impl FromXml for GradientStop {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -35258,7 +35265,7 @@ impl FromXml for GradientStop {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"color" => {
                                 f_color = Some(Box::new(Color::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -35284,7 +35291,7 @@ impl FromXml for GradientStop {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"color" => {
                                 f_color = Some(Box::new(Color::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -35328,7 +35335,7 @@ impl FromXml for GradientStop {
 
 impl FromXml for NumberFormats {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -35364,7 +35371,7 @@ impl FromXml for NumberFormats {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"numFmt" => {
                                 f_num_fmt.push(NumberFormat::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -35390,7 +35397,7 @@ impl FromXml for NumberFormats {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"numFmt" => {
                                 f_num_fmt.push(NumberFormat::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -35433,7 +35440,7 @@ impl FromXml for NumberFormats {
 
 impl FromXml for NumberFormat {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -35493,7 +35500,7 @@ impl FromXml for NumberFormat {
 
 impl FromXml for CellStyleFormats {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -35529,7 +35536,7 @@ impl FromXml for CellStyleFormats {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"xf" => {
                                 f_xf.push(Format::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -35555,7 +35562,7 @@ impl FromXml for CellStyleFormats {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"xf" => {
                                 f_xf.push(Format::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -35598,7 +35605,7 @@ impl FromXml for CellStyleFormats {
 
 impl FromXml for CellFormats {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -35634,7 +35641,7 @@ impl FromXml for CellFormats {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"xf" => {
                                 f_xf.push(Format::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -35660,7 +35667,7 @@ impl FromXml for CellFormats {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"xf" => {
                                 f_xf.push(Format::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -35703,7 +35710,7 @@ impl FromXml for CellFormats {
 
 impl FromXml for Format {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -35818,7 +35825,7 @@ impl FromXml for Format {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-styling")]
                             b"alignment" => {
                                 f_alignment =
@@ -35864,7 +35871,7 @@ impl FromXml for Format {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-styling")]
                             b"alignment" => {
                                 f_alignment =
@@ -35957,7 +35964,7 @@ impl FromXml for Format {
 
 impl FromXml for CellStyles {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -35993,7 +36000,7 @@ impl FromXml for CellStyles {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cellStyle" => {
                                 f_cell_style.push(CellStyle::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -36019,7 +36026,7 @@ impl FromXml for CellStyles {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cellStyle" => {
                                 f_cell_style.push(CellStyle::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -36062,7 +36069,7 @@ impl FromXml for CellStyles {
 
 impl FromXml for CellStyle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -36118,7 +36125,7 @@ impl FromXml for CellStyle {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_extension_list =
                                     Some(Box::new(ExtensionList::from_xml(reader, &e, false)?));
@@ -36145,7 +36152,7 @@ impl FromXml for CellStyle {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_extension_list =
                                     Some(Box::new(ExtensionList::from_xml(reader, &e, true)?));
@@ -36195,7 +36202,7 @@ impl FromXml for CellStyle {
 
 impl FromXml for DifferentialFormats {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -36231,7 +36238,7 @@ impl FromXml for DifferentialFormats {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"dxf" => {
                                 f_dxf.push(DifferentialFormat::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -36257,7 +36264,7 @@ impl FromXml for DifferentialFormats {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"dxf" => {
                                 f_dxf.push(DifferentialFormat::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -36300,7 +36307,7 @@ impl FromXml for DifferentialFormats {
 
 impl FromXml for DifferentialFormat {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -36322,7 +36329,7 @@ impl FromXml for DifferentialFormat {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"font" => {
                                 f_font = Some(Box::new(Font::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -36394,7 +36401,7 @@ impl FromXml for DifferentialFormat {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"font" => {
                                 f_font = Some(Box::new(Font::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -36486,7 +36493,7 @@ impl FromXml for DifferentialFormat {
 
 impl FromXml for Colors {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -36505,7 +36512,7 @@ impl FromXml for Colors {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-styling")]
                             b"indexedColors" => {
                                 f_indexed_colors =
@@ -36542,7 +36549,7 @@ impl FromXml for Colors {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-styling")]
                             b"indexedColors" => {
                                 f_indexed_colors =
@@ -36596,7 +36603,7 @@ impl FromXml for Colors {
 
 impl FromXml for IndexedColors {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -36612,7 +36619,7 @@ impl FromXml for IndexedColors {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rgbColor" => {
                                 f_rgb_color.push(RgbColor::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -36638,7 +36645,7 @@ impl FromXml for IndexedColors {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rgbColor" => {
                                 f_rgb_color.push(RgbColor::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -36678,7 +36685,7 @@ impl FromXml for IndexedColors {
 
 impl FromXml for MostRecentColors {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -36694,7 +36701,7 @@ impl FromXml for MostRecentColors {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"color" => {
                                 f_color.push(Color::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -36720,7 +36727,7 @@ impl FromXml for MostRecentColors {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"color" => {
                                 f_color.push(Color::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -36760,7 +36767,7 @@ impl FromXml for MostRecentColors {
 
 impl FromXml for RgbColor {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -36807,7 +36814,7 @@ impl FromXml for RgbColor {
 
 impl FromXml for TableStyles {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -36851,7 +36858,7 @@ impl FromXml for TableStyles {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tableStyle" => {
                                 f_table_style.push(TableStyle::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -36877,7 +36884,7 @@ impl FromXml for TableStyles {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tableStyle" => {
                                 f_table_style.push(TableStyle::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -36922,7 +36929,7 @@ impl FromXml for TableStyles {
 
 impl FromXml for TableStyle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -36970,7 +36977,7 @@ impl FromXml for TableStyle {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tableStyleElement" => {
                                 f_table_style_element
                                     .push(TableStyleElement::from_xml(reader, &e, false)?);
@@ -36997,7 +37004,7 @@ impl FromXml for TableStyle {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tableStyleElement" => {
                                 f_table_style_element
                                     .push(TableStyleElement::from_xml(reader, &e, true)?);
@@ -37044,7 +37051,7 @@ impl FromXml for TableStyle {
 
 impl FromXml for TableStyleElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -37101,7 +37108,7 @@ impl FromXml for TableStyleElement {
 
 impl FromXml for BooleanProperty {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -37148,7 +37155,7 @@ impl FromXml for BooleanProperty {
 
 impl FromXml for FontSize {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -37195,7 +37202,7 @@ impl FromXml for FontSize {
 
 impl FromXml for IntProperty {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -37242,7 +37249,7 @@ impl FromXml for IntProperty {
 
 impl FromXml for FontName {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -37289,7 +37296,7 @@ impl FromXml for FontName {
 
 impl FromXml for VerticalAlignFontProperty {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -37336,7 +37343,7 @@ impl FromXml for VerticalAlignFontProperty {
 
 impl FromXml for FontSchemeProperty {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -37383,7 +37390,7 @@ impl FromXml for FontSchemeProperty {
 
 impl FromXml for UnderlineProperty {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -37430,7 +37437,7 @@ impl FromXml for UnderlineProperty {
 
 impl FromXml for Font {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -37475,7 +37482,7 @@ impl FromXml for Font {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-styling")]
                             b"name" => {
                                 f_name = Some(Box::new(FontName::from_xml(reader, &e, false)?));
@@ -37625,7 +37632,7 @@ impl FromXml for Font {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-styling")]
                             b"name" => {
                                 f_name = Some(Box::new(FontName::from_xml(reader, &e, true)?));
@@ -37817,7 +37824,7 @@ impl FromXml for Font {
 
 impl FromXml for FontFamily {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -37864,7 +37871,7 @@ impl FromXml for FontFamily {
 
 impl FromXml for SmlAGAutoFormat {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -37941,7 +37948,7 @@ impl FromXml for SmlAGAutoFormat {
 
 impl FromXml for ExternalLink {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -37960,7 +37967,7 @@ impl FromXml for ExternalLink {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"externalBook" => {
                                 f_external_book =
                                     Some(Box::new(ExternalBook::from_xml(reader, &e, false)?));
@@ -38009,7 +38016,7 @@ impl FromXml for ExternalLink {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"externalBook" => {
                                 f_external_book =
                                     Some(Box::new(ExternalBook::from_xml(reader, &e, true)?));
@@ -38075,7 +38082,7 @@ impl FromXml for ExternalLink {
 
 impl FromXml for ExternalBook {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -38113,7 +38120,7 @@ impl FromXml for ExternalBook {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sheetNames" => {
                                 f_sheet_names = Some(Box::new(CTExternalSheetNames::from_xml(
                                     reader, &e, false,
@@ -38159,7 +38166,7 @@ impl FromXml for ExternalBook {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sheetNames" => {
                                 f_sheet_names = Some(Box::new(CTExternalSheetNames::from_xml(
                                     reader, &e, true,
@@ -38224,7 +38231,7 @@ impl FromXml for ExternalBook {
 
 impl FromXml for CTExternalSheetNames {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -38240,7 +38247,7 @@ impl FromXml for CTExternalSheetNames {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sheetName" => {
                                 f_sheet_name
                                     .push(CTExternalSheetName::from_xml(reader, &e, false)?);
@@ -38267,7 +38274,7 @@ impl FromXml for CTExternalSheetNames {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sheetName" => {
                                 f_sheet_name.push(CTExternalSheetName::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -38307,7 +38314,7 @@ impl FromXml for CTExternalSheetNames {
 
 impl FromXml for CTExternalSheetName {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -38354,7 +38361,7 @@ impl FromXml for CTExternalSheetName {
 
 impl FromXml for CTExternalDefinedNames {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -38370,7 +38377,7 @@ impl FromXml for CTExternalDefinedNames {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"definedName" => {
                                 f_defined_name
                                     .push(CTExternalDefinedName::from_xml(reader, &e, false)?);
@@ -38397,7 +38404,7 @@ impl FromXml for CTExternalDefinedNames {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"definedName" => {
                                 f_defined_name
                                     .push(CTExternalDefinedName::from_xml(reader, &e, true)?);
@@ -38438,7 +38445,7 @@ impl FromXml for CTExternalDefinedNames {
 
 impl FromXml for CTExternalDefinedName {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -38495,7 +38502,7 @@ impl FromXml for CTExternalDefinedName {
 
 impl FromXml for ExternalSheetDataSet {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -38511,7 +38518,7 @@ impl FromXml for ExternalSheetDataSet {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sheetData" => {
                                 f_sheet_data.push(ExternalSheetData::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -38537,7 +38544,7 @@ impl FromXml for ExternalSheetDataSet {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sheetData" => {
                                 f_sheet_data.push(ExternalSheetData::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -38577,7 +38584,7 @@ impl FromXml for ExternalSheetDataSet {
 
 impl FromXml for ExternalSheetData {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -38617,7 +38624,7 @@ impl FromXml for ExternalSheetData {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"row" => {
                                 f_row.push(ExternalRow::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -38643,7 +38650,7 @@ impl FromXml for ExternalSheetData {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"row" => {
                                 f_row.push(ExternalRow::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -38688,7 +38695,7 @@ impl FromXml for ExternalSheetData {
 
 impl FromXml for ExternalRow {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -38724,7 +38731,7 @@ impl FromXml for ExternalRow {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cell" => {
                                 f_cell.push(ExternalCell::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -38750,7 +38757,7 @@ impl FromXml for ExternalRow {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cell" => {
                                 f_cell.push(ExternalCell::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -38793,7 +38800,7 @@ impl FromXml for ExternalRow {
 
 impl FromXml for ExternalCell {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -38837,7 +38844,7 @@ impl FromXml for ExternalCell {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"v" => {
                                 f_value = Some(read_text_content(reader)?);
                                 #[cfg(feature = "extra-children")]
@@ -38863,7 +38870,7 @@ impl FromXml for ExternalCell {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"v" => {
                                 f_value = Some(String::new());
                                 #[cfg(feature = "extra-children")]
@@ -38908,7 +38915,7 @@ impl FromXml for ExternalCell {
 
 impl FromXml for DdeLink {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -38948,7 +38955,7 @@ impl FromXml for DdeLink {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ddeItems" => {
                                 f_dde_items =
                                     Some(Box::new(DdeItems::from_xml(reader, &e, false)?));
@@ -38975,7 +38982,7 @@ impl FromXml for DdeLink {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ddeItems" => {
                                 f_dde_items = Some(Box::new(DdeItems::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -39021,7 +39028,7 @@ impl FromXml for DdeLink {
 
 impl FromXml for DdeItems {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -39037,7 +39044,7 @@ impl FromXml for DdeItems {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ddeItem" => {
                                 f_dde_item.push(DdeItem::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -39063,7 +39070,7 @@ impl FromXml for DdeItems {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ddeItem" => {
                                 f_dde_item.push(DdeItem::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -39103,7 +39110,7 @@ impl FromXml for DdeItems {
 
 impl FromXml for DdeItem {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -39151,7 +39158,7 @@ impl FromXml for DdeItem {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"values" => {
                                 f_values =
                                     Some(Box::new(CTDdeValues::from_xml(reader, &e, false)?));
@@ -39178,7 +39185,7 @@ impl FromXml for DdeItem {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"values" => {
                                 f_values = Some(Box::new(CTDdeValues::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -39224,7 +39231,7 @@ impl FromXml for DdeItem {
 
 impl FromXml for CTDdeValues {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -39264,7 +39271,7 @@ impl FromXml for CTDdeValues {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"value" => {
                                 f_value.push(CTDdeValue::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -39290,7 +39297,7 @@ impl FromXml for CTDdeValues {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"value" => {
                                 f_value.push(CTDdeValue::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -39334,7 +39341,7 @@ impl FromXml for CTDdeValues {
 
 impl FromXml for CTDdeValue {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -39370,7 +39377,7 @@ impl FromXml for CTDdeValue {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"val" => {
                                 f_value = Some(read_text_content(reader)?);
                                 #[cfg(feature = "extra-children")]
@@ -39396,7 +39403,7 @@ impl FromXml for CTDdeValue {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"val" => {
                                 f_value = Some(String::new());
                                 #[cfg(feature = "extra-children")]
@@ -39439,7 +39446,7 @@ impl FromXml for CTDdeValue {
 
 impl FromXml for OleLink {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -39479,7 +39486,7 @@ impl FromXml for OleLink {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"oleItems" => {
                                 f_ole_items =
                                     Some(Box::new(OleItems::from_xml(reader, &e, false)?));
@@ -39506,7 +39513,7 @@ impl FromXml for OleLink {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"oleItems" => {
                                 f_ole_items = Some(Box::new(OleItems::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -39550,7 +39557,7 @@ impl FromXml for OleLink {
 
 impl FromXml for OleItems {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -39566,7 +39573,7 @@ impl FromXml for OleItems {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"oleItem" => {
                                 f_ole_item.push(OleItem::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -39592,7 +39599,7 @@ impl FromXml for OleItems {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"oleItem" => {
                                 f_ole_item.push(OleItem::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -39632,7 +39639,7 @@ impl FromXml for OleItems {
 
 impl FromXml for OleItem {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -39694,7 +39701,7 @@ impl FromXml for OleItem {
 
 impl FromXml for Table {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -39867,7 +39874,7 @@ impl FromXml for Table {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-tables")]
                             b"autoFilter" => {
                                 f_auto_filter =
@@ -39931,7 +39938,7 @@ impl FromXml for Table {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-tables")]
                             b"autoFilter" => {
                                 f_auto_filter =
@@ -40067,7 +40074,7 @@ impl FromXml for Table {
 
 impl FromXml for TableStyleInfo {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -40134,7 +40141,7 @@ impl FromXml for TableStyleInfo {
 
 impl FromXml for TableColumns {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -40170,7 +40177,7 @@ impl FromXml for TableColumns {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tableColumn" => {
                                 f_table_column.push(TableColumn::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -40196,7 +40203,7 @@ impl FromXml for TableColumns {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tableColumn" => {
                                 f_table_column.push(TableColumn::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -40239,7 +40246,7 @@ impl FromXml for TableColumns {
 
 impl FromXml for TableColumn {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -40322,7 +40329,7 @@ impl FromXml for TableColumn {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"calculatedColumnFormula" => {
                                 f_calculated_column_formula =
                                     Some(Box::new(TableFormula::from_xml(reader, &e, false)?));
@@ -40374,7 +40381,7 @@ impl FromXml for TableColumn {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"calculatedColumnFormula" => {
                                 f_calculated_column_formula =
                                     Some(Box::new(TableFormula::from_xml(reader, &e, true)?));
@@ -40457,7 +40464,7 @@ impl FromXml for TableColumn {
 
 impl FromXml for TableFormula {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -40493,7 +40500,7 @@ impl FromXml for TableFormula {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "extra-children")]
                             _ => {
                                 // Capture unknown element for roundtrip
@@ -40512,7 +40519,7 @@ impl FromXml for TableFormula {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "extra-children")]
                             _ => {
                                 // Capture unknown empty element for roundtrip
@@ -40558,7 +40565,7 @@ impl FromXml for TableFormula {
 
 impl FromXml for XmlColumnProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -40606,7 +40613,7 @@ impl FromXml for XmlColumnProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_extension_list =
                                     Some(Box::new(ExtensionList::from_xml(reader, &e, false)?));
@@ -40633,7 +40640,7 @@ impl FromXml for XmlColumnProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_extension_list =
                                     Some(Box::new(ExtensionList::from_xml(reader, &e, true)?));
@@ -40681,7 +40688,7 @@ impl FromXml for XmlColumnProperties {
 
 impl FromXml for CTVolTypes {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -40698,7 +40705,7 @@ impl FromXml for CTVolTypes {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"volType" => {
                                 f_vol_type.push(CTVolType::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -40732,7 +40739,7 @@ impl FromXml for CTVolTypes {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"volType" => {
                                 f_vol_type.push(CTVolType::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -40781,7 +40788,7 @@ impl FromXml for CTVolTypes {
 
 impl FromXml for CTVolType {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -40817,7 +40824,7 @@ impl FromXml for CTVolType {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"main" => {
                                 f_main.push(CTVolMain::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -40843,7 +40850,7 @@ impl FromXml for CTVolType {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"main" => {
                                 f_main.push(CTVolMain::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -40886,7 +40893,7 @@ impl FromXml for CTVolType {
 
 impl FromXml for CTVolMain {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -40922,7 +40929,7 @@ impl FromXml for CTVolMain {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tp" => {
                                 f_tp.push(CTVolTopic::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -40948,7 +40955,7 @@ impl FromXml for CTVolMain {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tp" => {
                                 f_tp.push(CTVolTopic::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -40991,7 +40998,7 @@ impl FromXml for CTVolMain {
 
 impl FromXml for CTVolTopic {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -41029,7 +41036,7 @@ impl FromXml for CTVolTopic {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"v" => {
                                 f_value = Some(read_text_content(reader)?);
                                 #[cfg(feature = "extra-children")]
@@ -41069,7 +41076,7 @@ impl FromXml for CTVolTopic {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"v" => {
                                 f_value = Some(String::new());
                                 #[cfg(feature = "extra-children")]
@@ -41128,7 +41135,7 @@ impl FromXml for CTVolTopic {
 
 impl FromXml for CTVolTopicRef {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -41181,7 +41188,7 @@ impl FromXml for CTVolTopicRef {
 
 impl FromXml for Workbook {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -41248,7 +41255,7 @@ impl FromXml for Workbook {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"fileVersion" => {
                                 f_file_version =
                                     Some(Box::new(FileVersion::from_xml(reader, &e, false)?));
@@ -41437,7 +41444,7 @@ impl FromXml for Workbook {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"fileVersion" => {
                                 f_file_version =
                                     Some(Box::new(FileVersion::from_xml(reader, &e, true)?));
@@ -41670,7 +41677,7 @@ impl FromXml for Workbook {
 
 impl FromXml for FileVersion {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -41737,7 +41744,7 @@ impl FromXml for FileVersion {
 
 impl FromXml for BookViews {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -41753,7 +41760,7 @@ impl FromXml for BookViews {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"workbookView" => {
                                 f_workbook_view.push(BookView::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -41779,7 +41786,7 @@ impl FromXml for BookViews {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"workbookView" => {
                                 f_workbook_view.push(BookView::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -41819,7 +41826,7 @@ impl FromXml for BookViews {
 
 impl FromXml for BookView {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -41928,7 +41935,7 @@ impl FromXml for BookView {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-extensions")]
                             b"extLst" => {
                                 f_extension_list =
@@ -41956,7 +41963,7 @@ impl FromXml for BookView {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "sml-extensions")]
                             b"extLst" => {
                                 f_extension_list =
@@ -42026,7 +42033,7 @@ impl FromXml for BookView {
 
 impl FromXml for CustomWorkbookViews {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -42042,7 +42049,7 @@ impl FromXml for CustomWorkbookViews {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customWorkbookView" => {
                                 f_custom_workbook_view
                                     .push(CustomWorkbookView::from_xml(reader, &e, false)?);
@@ -42069,7 +42076,7 @@ impl FromXml for CustomWorkbookViews {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customWorkbookView" => {
                                 f_custom_workbook_view
                                     .push(CustomWorkbookView::from_xml(reader, &e, true)?);
@@ -42110,7 +42117,7 @@ impl FromXml for CustomWorkbookViews {
 
 impl FromXml for CustomWorkbookView {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -42238,7 +42245,7 @@ impl FromXml for CustomWorkbookView {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_extension_list =
                                     Some(Box::new(ExtensionList::from_xml(reader, &e, false)?));
@@ -42265,7 +42272,7 @@ impl FromXml for CustomWorkbookView {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"extLst" => {
                                 f_extension_list =
                                     Some(Box::new(ExtensionList::from_xml(reader, &e, true)?));
@@ -42335,7 +42342,7 @@ impl FromXml for CustomWorkbookView {
 
 impl FromXml for Sheets {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -42351,7 +42358,7 @@ impl FromXml for Sheets {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sheet" => {
                                 f_sheet.push(Sheet::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -42377,7 +42384,7 @@ impl FromXml for Sheets {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"sheet" => {
                                 f_sheet.push(Sheet::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -42417,7 +42424,7 @@ impl FromXml for Sheets {
 
 impl FromXml for Sheet {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -42483,7 +42490,7 @@ impl FromXml for Sheet {
 
 impl FromXml for WorkbookProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -42615,7 +42622,7 @@ impl FromXml for WorkbookProperties {
 
 impl FromXml for CTSmartTagPr {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -42667,7 +42674,7 @@ impl FromXml for CTSmartTagPr {
 
 impl FromXml for CTSmartTagTypes {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -42683,7 +42690,7 @@ impl FromXml for CTSmartTagTypes {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"smartTagType" => {
                                 f_smart_tag_type.push(CTSmartTagType::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -42709,7 +42716,7 @@ impl FromXml for CTSmartTagTypes {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"smartTagType" => {
                                 f_smart_tag_type.push(CTSmartTagType::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -42749,7 +42756,7 @@ impl FromXml for CTSmartTagTypes {
 
 impl FromXml for CTSmartTagType {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -42806,7 +42813,7 @@ impl FromXml for CTSmartTagType {
 
 impl FromXml for FileRecoveryProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -42868,7 +42875,7 @@ impl FromXml for FileRecoveryProperties {
 
 impl FromXml for CalculationProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -43014,7 +43021,7 @@ impl FromXml for CalculationProperties {
 
 impl FromXml for DefinedNames {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -43030,7 +43037,7 @@ impl FromXml for DefinedNames {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"definedName" => {
                                 f_defined_name.push(DefinedName::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -43056,7 +43063,7 @@ impl FromXml for DefinedNames {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"definedName" => {
                                 f_defined_name.push(DefinedName::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -43096,7 +43103,7 @@ impl FromXml for DefinedNames {
 
 impl FromXml for DefinedName {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -43210,7 +43217,7 @@ impl FromXml for DefinedName {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "extra-children")]
                             _ => {
                                 // Capture unknown element for roundtrip
@@ -43229,7 +43236,7 @@ impl FromXml for DefinedName {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "extra-children")]
                             _ => {
                                 // Capture unknown empty element for roundtrip
@@ -43300,7 +43307,7 @@ impl FromXml for DefinedName {
 
 impl FromXml for ExternalReferences {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -43316,7 +43323,7 @@ impl FromXml for ExternalReferences {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"externalReference" => {
                                 f_external_reference
                                     .push(ExternalReference::from_xml(reader, &e, false)?);
@@ -43343,7 +43350,7 @@ impl FromXml for ExternalReferences {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"externalReference" => {
                                 f_external_reference
                                     .push(ExternalReference::from_xml(reader, &e, true)?);
@@ -43384,7 +43391,7 @@ impl FromXml for ExternalReferences {
 
 impl FromXml for ExternalReference {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -43431,7 +43438,7 @@ impl FromXml for ExternalReference {
 
 impl FromXml for SheetBackgroundPicture {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -43478,7 +43485,7 @@ impl FromXml for SheetBackgroundPicture {
 
 impl FromXml for PivotCaches {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -43494,7 +43501,7 @@ impl FromXml for PivotCaches {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pivotCache" => {
                                 f_pivot_cache.push(CTPivotCache::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -43520,7 +43527,7 @@ impl FromXml for PivotCaches {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pivotCache" => {
                                 f_pivot_cache.push(CTPivotCache::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -43560,7 +43567,7 @@ impl FromXml for PivotCaches {
 
 impl FromXml for CTPivotCache {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -43613,7 +43620,7 @@ impl FromXml for CTPivotCache {
 
 impl FromXml for FileSharing {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -43690,7 +43697,7 @@ impl FromXml for FileSharing {
 
 impl FromXml for CTOleSize {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -43738,7 +43745,7 @@ impl FromXml for CTOleSize {
 
 impl FromXml for WorkbookProtection {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -43855,7 +43862,7 @@ impl FromXml for WorkbookProtection {
 
 impl FromXml for WebPublishing {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -43942,7 +43949,7 @@ impl FromXml for WebPublishing {
 
 impl FromXml for CTFunctionGroups {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -43978,7 +43985,7 @@ impl FromXml for CTFunctionGroups {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"functionGroup" => {
                                 f_function_group
                                     .push(CTFunctionGroup::from_xml(reader, &e, false)?);
@@ -44005,7 +44012,7 @@ impl FromXml for CTFunctionGroups {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"functionGroup" => {
                                 f_function_group.push(CTFunctionGroup::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -44048,7 +44055,7 @@ impl FromXml for CTFunctionGroups {
 
 impl FromXml for CTFunctionGroup {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -44095,7 +44102,7 @@ impl FromXml for CTFunctionGroup {
 
 impl FromXml for CTWebPublishObjects {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -44131,7 +44138,7 @@ impl FromXml for CTWebPublishObjects {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"webPublishObject" => {
                                 f_web_publish_object
                                     .push(CTWebPublishObject::from_xml(reader, &e, false)?);
@@ -44158,7 +44165,7 @@ impl FromXml for CTWebPublishObjects {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"webPublishObject" => {
                                 f_web_publish_object
                                     .push(CTWebPublishObject::from_xml(reader, &e, true)?);
@@ -44202,7 +44209,7 @@ impl FromXml for CTWebPublishObjects {
 
 impl FromXml for CTWebPublishObject {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -0,0 +1,19268 @@
+// Visitors for the generated types, derived from generated.rs.
+// Do not edit manually.
+
+#![allow(unused_variables)]
+#![allow(clippy::single_match)]
+#![allow(clippy::match_single_binding)]
+use super::generated::*;
+use ooxml_xml::{RawXmlElement, RawXmlNode};
+
+#[doc = r" Read-only traversal of the generated types."]
+#[doc = r""]
+#[doc = r" Every method defaults to visiting the node's children; override"]
+#[doc = r" the ones of interest and call the matching `walk_*` function to"]
+#[doc = r" keep descending."]
+pub trait Visit<'ast> {
+    fn visit_authors(&mut self, node: &'ast Authors) {
+        walk_authors(self, node)
+    }
+    fn visit_auto_filter(&mut self, node: &'ast AutoFilter) {
+        walk_auto_filter(self, node)
+    }
+    fn visit_book_view(&mut self, node: &'ast BookView) {
+        walk_book_view(self, node)
+    }
+    fn visit_book_views(&mut self, node: &'ast BookViews) {
+        walk_book_views(self, node)
+    }
+    fn visit_boolean_property(&mut self, _node: &'ast BooleanProperty) {}
+    fn visit_border(&mut self, node: &'ast Border) {
+        walk_border(self, node)
+    }
+    fn visit_border_properties(&mut self, node: &'ast BorderProperties) {
+        walk_border_properties(self, node)
+    }
+    fn visit_border_style(&mut self, _node: &'ast BorderStyle) {}
+    fn visit_borders(&mut self, node: &'ast Borders) {
+        walk_borders(self, node)
+    }
+    fn visit_ct_boolean(&mut self, node: &'ast CTBoolean) {
+        walk_ct_boolean(self, node)
+    }
+    fn visit_ct_cache_hierarchies(&mut self, node: &'ast CTCacheHierarchies) {
+        walk_ct_cache_hierarchies(self, node)
+    }
+    fn visit_ct_cache_hierarchy(&mut self, node: &'ast CTCacheHierarchy) {
+        walk_ct_cache_hierarchy(self, node)
+    }
+    fn visit_ct_calculated_item(&mut self, node: &'ast CTCalculatedItem) {
+        walk_ct_calculated_item(self, node)
+    }
+    fn visit_ct_calculated_items(&mut self, node: &'ast CTCalculatedItems) {
+        walk_ct_calculated_items(self, node)
+    }
+    fn visit_ct_calculated_member(&mut self, node: &'ast CTCalculatedMember) {
+        walk_ct_calculated_member(self, node)
+    }
+    fn visit_ct_calculated_members(&mut self, node: &'ast CTCalculatedMembers) {
+        walk_ct_calculated_members(self, node)
+    }
+    fn visit_ct_cell_smart_tag_pr(&mut self, _node: &'ast CTCellSmartTagPr) {}
+    fn visit_ct_chart_format(&mut self, node: &'ast CTChartFormat) {
+        walk_ct_chart_format(self, node)
+    }
+    fn visit_ct_chart_formats(&mut self, node: &'ast CTChartFormats) {
+        walk_ct_chart_formats(self, node)
+    }
+    fn visit_ct_col_hierarchies_usage(&mut self, node: &'ast CTColHierarchiesUsage) {
+        walk_ct_col_hierarchies_usage(self, node)
+    }
+    fn visit_ct_col_items(&mut self, node: &'ast CTColItems) {
+        walk_ct_col_items(self, node)
+    }
+    fn visit_ct_comment_pr(&mut self, node: &'ast CTCommentPr) {
+        walk_ct_comment_pr(self, node)
+    }
+    fn visit_ct_conditional_format(&mut self, node: &'ast CTConditionalFormat) {
+        walk_ct_conditional_format(self, node)
+    }
+    fn visit_ct_conditional_formats(&mut self, node: &'ast CTConditionalFormats) {
+        walk_ct_conditional_formats(self, node)
+    }
+    fn visit_ct_control_pr(&mut self, node: &'ast CTControlPr) {
+        walk_ct_control_pr(self, node)
+    }
+    fn visit_ct_custom_properties(&mut self, node: &'ast CTCustomProperties) {
+        walk_ct_custom_properties(self, node)
+    }
+    fn visit_ct_custom_property(&mut self, _node: &'ast CTCustomProperty) {}
+    fn visit_ct_data_consolidate(&mut self, node: &'ast CTDataConsolidate) {
+        walk_ct_data_consolidate(self, node)
+    }
+    fn visit_ct_data_ref(&mut self, _node: &'ast CTDataRef) {}
+    fn visit_ct_data_refs(&mut self, node: &'ast CTDataRefs) {
+        walk_ct_data_refs(self, node)
+    }
+    fn visit_ct_date_time(&mut self, node: &'ast CTDateTime) {
+        walk_ct_date_time(self, node)
+    }
+    fn visit_ct_dde_value(&mut self, node: &'ast CTDdeValue) {
+        walk_ct_dde_value(self, node)
+    }
+    fn visit_ct_dde_values(&mut self, node: &'ast CTDdeValues) {
+        walk_ct_dde_values(self, node)
+    }
+    fn visit_ct_deleted_field(&mut self, _node: &'ast CTDeletedField) {}
+    fn visit_ct_dialogsheet(&mut self, node: &'ast CTDialogsheet) {
+        walk_ct_dialogsheet(self, node)
+    }
+    fn visit_ct_dimensions(&mut self, node: &'ast CTDimensions) {
+        walk_ct_dimensions(self, node)
+    }
+    fn visit_ct_discrete_pr(&mut self, node: &'ast CTDiscretePr) {
+        walk_ct_discrete_pr(self, node)
+    }
+    fn visit_ct_error(&mut self, node: &'ast CTError) {
+        walk_ct_error(self, node)
+    }
+    fn visit_ct_external_defined_name(&mut self, _node: &'ast CTExternalDefinedName) {}
+    fn visit_ct_external_defined_names(&mut self, node: &'ast CTExternalDefinedNames) {
+        walk_ct_external_defined_names(self, node)
+    }
+    fn visit_ct_external_sheet_name(&mut self, _node: &'ast CTExternalSheetName) {}
+    fn visit_ct_external_sheet_names(&mut self, node: &'ast CTExternalSheetNames) {
+        walk_ct_external_sheet_names(self, node)
+    }
+    fn visit_ct_field(&mut self, _node: &'ast CTField) {}
+    fn visit_ct_field_usage(&mut self, _node: &'ast CTFieldUsage) {}
+    fn visit_ct_fields_usage(&mut self, node: &'ast CTFieldsUsage) {
+        walk_ct_fields_usage(self, node)
+    }
+    fn visit_ct_format(&mut self, node: &'ast CTFormat) {
+        walk_ct_format(self, node)
+    }
+    fn visit_ct_formats(&mut self, node: &'ast CTFormats) {
+        walk_ct_formats(self, node)
+    }
+    fn visit_ct_function_group(&mut self, _node: &'ast CTFunctionGroup) {}
+    fn visit_ct_function_groups(&mut self, node: &'ast CTFunctionGroups) {
+        walk_ct_function_groups(self, node)
+    }
+    fn visit_ct_future_metadata(&mut self, node: &'ast CTFutureMetadata) {
+        walk_ct_future_metadata(self, node)
+    }
+    fn visit_ct_future_metadata_block(&mut self, node: &'ast CTFutureMetadataBlock) {
+        walk_ct_future_metadata_block(self, node)
+    }
+    fn visit_ct_group_level(&mut self, node: &'ast CTGroupLevel) {
+        walk_ct_group_level(self, node)
+    }
+    fn visit_ct_group_levels(&mut self, node: &'ast CTGroupLevels) {
+        walk_ct_group_levels(self, node)
+    }
+    fn visit_ct_group_member(&mut self, _node: &'ast CTGroupMember) {}
+    fn visit_ct_group_members(&mut self, node: &'ast CTGroupMembers) {
+        walk_ct_group_members(self, node)
+    }
+    fn visit_ct_groups(&mut self, node: &'ast CTGroups) {
+        walk_ct_groups(self, node)
+    }
+    fn visit_ct_hierarchy_usage(&mut self, _node: &'ast CTHierarchyUsage) {}
+    fn visit_cti(&mut self, node: &'ast CTI) {
+        walk_cti(self, node)
+    }
+    fn visit_ct_index(&mut self, _node: &'ast CTIndex) {}
+    fn visit_ct_level_group(&mut self, node: &'ast CTLevelGroup) {
+        walk_ct_level_group(self, node)
+    }
+    fn visit_ct_macrosheet(&mut self, node: &'ast CTMacrosheet) {
+        walk_ct_macrosheet(self, node)
+    }
+    fn visit_ct_mdx(&mut self, node: &'ast CTMdx) {
+        walk_ct_mdx(self, node)
+    }
+    fn visit_ct_mdx_kpi(&mut self, node: &'ast CTMdxKPI) {
+        walk_ct_mdx_kpi(self, node)
+    }
+    fn visit_ct_mdx_memeber_prop(&mut self, _node: &'ast CTMdxMemeberProp) {}
+    fn visit_ct_mdx_metadata(&mut self, node: &'ast CTMdxMetadata) {
+        walk_ct_mdx_metadata(self, node)
+    }
+    fn visit_ct_mdx_set(&mut self, node: &'ast CTMdxSet) {
+        walk_ct_mdx_set(self, node)
+    }
+    fn visit_ct_mdx_tuple(&mut self, node: &'ast CTMdxTuple) {
+        walk_ct_mdx_tuple(self, node)
+    }
+    fn visit_ct_measure_dimension_map(&mut self, _node: &'ast CTMeasureDimensionMap) {}
+    fn visit_ct_measure_dimension_maps(&mut self, node: &'ast CTMeasureDimensionMaps) {
+        walk_ct_measure_dimension_maps(self, node)
+    }
+    fn visit_ct_measure_group(&mut self, _node: &'ast CTMeasureGroup) {}
+    fn visit_ct_measure_groups(&mut self, node: &'ast CTMeasureGroups) {
+        walk_ct_measure_groups(self, node)
+    }
+    fn visit_ct_member(&mut self, _node: &'ast CTMember) {}
+    fn visit_ct_member_properties(&mut self, node: &'ast CTMemberProperties) {
+        walk_ct_member_properties(self, node)
+    }
+    fn visit_ct_member_property(&mut self, _node: &'ast CTMemberProperty) {}
+    fn visit_ct_members(&mut self, node: &'ast CTMembers) {
+        walk_ct_members(self, node)
+    }
+    fn visit_ct_metadata_string_index(&mut self, _node: &'ast CTMetadataStringIndex) {}
+    fn visit_ct_missing(&mut self, node: &'ast CTMissing) {
+        walk_ct_missing(self, node)
+    }
+    fn visit_ct_number(&mut self, node: &'ast CTNumber) {
+        walk_ct_number(self, node)
+    }
+    fn visit_ct_ole_size(&mut self, _node: &'ast CTOleSize) {}
+    fn visit_ctpcdkpi(&mut self, _node: &'ast CTPCDKPI) {}
+    fn visit_ctpcdkp_is(&mut self, node: &'ast CTPCDKPIs) {
+        walk_ctpcdkp_is(self, node)
+    }
+    fn visit_ctpcdsc_page(&mut self, node: &'ast CTPCDSCPage) {
+        walk_ctpcdsc_page(self, node)
+    }
+    fn visit_ctpcdsdtc_entries(&mut self, node: &'ast CTPCDSDTCEntries) {
+        walk_ctpcdsdtc_entries(self, node)
+    }
+    fn visit_ct_page_item(&mut self, _node: &'ast CTPageItem) {}
+    fn visit_ct_pages(&mut self, node: &'ast CTPages) {
+        walk_ct_pages(self, node)
+    }
+    fn visit_ct_pivot_area_reference(&mut self, node: &'ast CTPivotAreaReference) {
+        walk_ct_pivot_area_reference(self, node)
+    }
+    fn visit_ct_pivot_area_references(&mut self, node: &'ast CTPivotAreaReferences) {
+        walk_ct_pivot_area_references(self, node)
+    }
+    fn visit_ct_pivot_cache(&mut self, _node: &'ast CTPivotCache) {}
+    fn visit_ct_pivot_dimension(&mut self, _node: &'ast CTPivotDimension) {}
+    fn visit_ct_pivot_hierarchies(&mut self, node: &'ast CTPivotHierarchies) {
+        walk_ct_pivot_hierarchies(self, node)
+    }
+    fn visit_ct_pivot_hierarchy(&mut self, node: &'ast CTPivotHierarchy) {
+        walk_ct_pivot_hierarchy(self, node)
+    }
+    fn visit_ct_pivot_selection(&mut self, node: &'ast CTPivotSelection) {
+        walk_ct_pivot_selection(self, node)
+    }
+    fn visit_ct_pivot_table_definition(&mut self, node: &'ast CTPivotTableDefinition) {
+        walk_ct_pivot_table_definition(self, node)
+    }
+    fn visit_ct_pivot_table_style(&mut self, _node: &'ast CTPivotTableStyle) {}
+    fn visit_ct_query(&mut self, node: &'ast CTQuery) {
+        walk_ct_query(self, node)
+    }
+    fn visit_ct_query_cache(&mut self, node: &'ast CTQueryCache) {
+        walk_ct_query_cache(self, node)
+    }
+    fn visit_ct_range_pr(&mut self, node: &'ast CTRangePr) {
+        walk_ct_range_pr(self, node)
+    }
+    fn visit_ct_range_set(&mut self, _node: &'ast CTRangeSet) {}
+    fn visit_ct_range_sets(&mut self, node: &'ast CTRangeSets) {
+        walk_ct_range_sets(self, node)
+    }
+    fn visit_ct_record(&mut self, node: &'ast CTRecord) {
+        walk_ct_record(self, node)
+    }
+    fn visit_ct_row_hierarchies_usage(&mut self, node: &'ast CTRowHierarchiesUsage) {
+        walk_ct_row_hierarchies_usage(self, node)
+    }
+    fn visit_ct_row_items(&mut self, node: &'ast CTRowItems) {
+        walk_ct_row_items(self, node)
+    }
+    fn visit_ct_server_format(&mut self, _node: &'ast CTServerFormat) {}
+    fn visit_ct_server_formats(&mut self, node: &'ast CTServerFormats) {
+        walk_ct_server_formats(self, node)
+    }
+    fn visit_ct_set(&mut self, node: &'ast CTSet) {
+        walk_ct_set(self, node)
+    }
+    fn visit_ct_sets(&mut self, node: &'ast CTSets) {
+        walk_ct_sets(self, node)
+    }
+    fn visit_ct_sheet_id(&mut self, _node: &'ast CTSheetId) {}
+    fn visit_ct_sheet_id_map(&mut self, node: &'ast CTSheetIdMap) {
+        walk_ct_sheet_id_map(self, node)
+    }
+    fn visit_ct_smart_tag_pr(&mut self, node: &'ast CTSmartTagPr) {
+        walk_ct_smart_tag_pr(self, node)
+    }
+    fn visit_ct_smart_tag_type(&mut self, _node: &'ast CTSmartTagType) {}
+    fn visit_ct_smart_tag_types(&mut self, node: &'ast CTSmartTagTypes) {
+        walk_ct_smart_tag_types(self, node)
+    }
+    fn visit_ct_string(&mut self, node: &'ast CTString) {
+        walk_ct_string(self, node)
+    }
+    fn visit_ct_tuple(&mut self, _node: &'ast CTTuple) {}
+    fn visit_ct_tuple_cache(&mut self, node: &'ast CTTupleCache) {
+        walk_ct_tuple_cache(self, node)
+    }
+    fn visit_ct_tuples(&mut self, node: &'ast CTTuples) {
+        walk_ct_tuples(self, node)
+    }
+    fn visit_ct_vol_main(&mut self, node: &'ast CTVolMain) {
+        walk_ct_vol_main(self, node)
+    }
+    fn visit_ct_vol_topic(&mut self, node: &'ast CTVolTopic) {
+        walk_ct_vol_topic(self, node)
+    }
+    fn visit_ct_vol_topic_ref(&mut self, _node: &'ast CTVolTopicRef) {}
+    fn visit_ct_vol_type(&mut self, node: &'ast CTVolType) {
+        walk_ct_vol_type(self, node)
+    }
+    fn visit_ct_vol_types(&mut self, node: &'ast CTVolTypes) {
+        walk_ct_vol_types(self, node)
+    }
+    fn visit_ct_web_publish_object(&mut self, _node: &'ast CTWebPublishObject) {}
+    fn visit_ct_web_publish_objects(&mut self, node: &'ast CTWebPublishObjects) {
+        walk_ct_web_publish_objects(self, node)
+    }
+    fn visit_ctx(&mut self, _node: &'ast CTX) {}
+    fn visit_ctx_string_element(&mut self, _node: &'ast CTXStringElement) {}
+    fn visit_cache_field(&mut self, node: &'ast CacheField) {
+        walk_cache_field(self, node)
+    }
+    fn visit_cache_fields(&mut self, node: &'ast CacheFields) {
+        walk_cache_fields(self, node)
+    }
+    fn visit_cache_source(&mut self, node: &'ast CacheSource) {
+        walk_cache_source(self, node)
+    }
+    fn visit_calc_cell(&mut self, _node: &'ast CalcCell) {}
+    fn visit_calc_chain(&mut self, node: &'ast CalcChain) {
+        walk_calc_chain(self, node)
+    }
+    fn visit_calculation_mode(&mut self, _node: &'ast CalculationMode) {}
+    fn visit_calculation_properties(&mut self, node: &'ast CalculationProperties) {
+        walk_calculation_properties(self, node)
+    }
+    fn visit_calendar_type(&mut self, _node: &'ast CalendarType) {}
+    fn visit_cell(&mut self, node: &'ast Cell) {
+        walk_cell(self, node)
+    }
+    fn visit_cell_alignment(&mut self, node: &'ast CellAlignment) {
+        walk_cell_alignment(self, node)
+    }
+    fn visit_cell_formats(&mut self, node: &'ast CellFormats) {
+        walk_cell_formats(self, node)
+    }
+    fn visit_cell_formula(&mut self, node: &'ast CellFormula) {
+        walk_cell_formula(self, node)
+    }
+    fn visit_cell_protection(&mut self, _node: &'ast CellProtection) {}
+    fn visit_cell_smart_tag(&mut self, node: &'ast CellSmartTag) {
+        walk_cell_smart_tag(self, node)
+    }
+    fn visit_cell_smart_tags(&mut self, node: &'ast CellSmartTags) {
+        walk_cell_smart_tags(self, node)
+    }
+    fn visit_cell_style(&mut self, node: &'ast CellStyle) {
+        walk_cell_style(self, node)
+    }
+    fn visit_cell_style_formats(&mut self, node: &'ast CellStyleFormats) {
+        walk_cell_style_formats(self, node)
+    }
+    fn visit_cell_styles(&mut self, node: &'ast CellStyles) {
+        walk_cell_styles(self, node)
+    }
+    fn visit_cell_type(&mut self, _node: &'ast CellType) {}
+    fn visit_cell_watch(&mut self, _node: &'ast CellWatch) {}
+    fn visit_cell_watches(&mut self, node: &'ast CellWatches) {
+        walk_cell_watches(self, node)
+    }
+    fn visit_chartsheet(&mut self, node: &'ast Chartsheet) {
+        walk_chartsheet(self, node)
+    }
+    fn visit_chartsheet_page_setup(&mut self, node: &'ast ChartsheetPageSetup) {
+        walk_chartsheet_page_setup(self, node)
+    }
+    fn visit_chartsheet_properties(&mut self, node: &'ast ChartsheetProperties) {
+        walk_chartsheet_properties(self, node)
+    }
+    fn visit_chartsheet_protection(&mut self, _node: &'ast ChartsheetProtection) {}
+    fn visit_chartsheet_view(&mut self, node: &'ast ChartsheetView) {
+        walk_chartsheet_view(self, node)
+    }
+    fn visit_chartsheet_views(&mut self, node: &'ast ChartsheetViews) {
+        walk_chartsheet_views(self, node)
+    }
+    fn visit_col_fields(&mut self, node: &'ast ColFields) {
+        walk_col_fields(self, node)
+    }
+    fn visit_color(&mut self, _node: &'ast Color) {}
+    fn visit_color_filter(&mut self, _node: &'ast ColorFilter) {}
+    fn visit_color_scale(&mut self, node: &'ast ColorScale) {
+        walk_color_scale(self, node)
+    }
+    fn visit_colors(&mut self, node: &'ast Colors) {
+        walk_colors(self, node)
+    }
+    fn visit_column(&mut self, _node: &'ast Column) {}
+    fn visit_columns(&mut self, node: &'ast Columns) {
+        walk_columns(self, node)
+    }
+    fn visit_comment(&mut self, node: &'ast Comment) {
+        walk_comment(self, node)
+    }
+    fn visit_comment_list(&mut self, node: &'ast CommentList) {
+        walk_comment_list(self, node)
+    }
+    fn visit_comment_visibility(&mut self, _node: &'ast CommentVisibility) {}
+    fn visit_comments(&mut self, node: &'ast Comments) {
+        walk_comments(self, node)
+    }
+    fn visit_conditional_format_value(&mut self, node: &'ast ConditionalFormatValue) {
+        walk_conditional_format_value(self, node)
+    }
+    fn visit_conditional_formatting(&mut self, node: &'ast ConditionalFormatting) {
+        walk_conditional_formatting(self, node)
+    }
+    fn visit_conditional_operator(&mut self, _node: &'ast ConditionalOperator) {}
+    fn visit_conditional_rule(&mut self, node: &'ast ConditionalRule) {
+        walk_conditional_rule(self, node)
+    }
+    fn visit_conditional_type(&mut self, _node: &'ast ConditionalType) {}
+    fn visit_conditional_value_type(&mut self, _node: &'ast ConditionalValueType) {}
+    fn visit_connection(&mut self, node: &'ast Connection) {
+        walk_connection(self, node)
+    }
+    fn visit_connections(&mut self, node: &'ast Connections) {
+        walk_connections(self, node)
+    }
+    fn visit_consolidation(&mut self, node: &'ast Consolidation) {
+        walk_consolidation(self, node)
+    }
+    fn visit_control(&mut self, node: &'ast Control) {
+        walk_control(self, node)
+    }
+    fn visit_controls(&mut self, node: &'ast Controls) {
+        walk_controls(self, node)
+    }
+    fn visit_custom_chartsheet_view(&mut self, node: &'ast CustomChartsheetView) {
+        walk_custom_chartsheet_view(self, node)
+    }
+    fn visit_custom_chartsheet_views(&mut self, node: &'ast CustomChartsheetViews) {
+        walk_custom_chartsheet_views(self, node)
+    }
+    fn visit_custom_filter(&mut self, node: &'ast CustomFilter) {
+        walk_custom_filter(self, node)
+    }
+    fn visit_custom_filters(&mut self, node: &'ast CustomFilters) {
+        walk_custom_filters(self, node)
+    }
+    fn visit_custom_sheet_view(&mut self, node: &'ast CustomSheetView) {
+        walk_custom_sheet_view(self, node)
+    }
+    fn visit_custom_sheet_views(&mut self, node: &'ast CustomSheetViews) {
+        walk_custom_sheet_views(self, node)
+    }
+    fn visit_custom_workbook_view(&mut self, node: &'ast CustomWorkbookView) {
+        walk_custom_workbook_view(self, node)
+    }
+    fn visit_custom_workbook_views(&mut self, node: &'ast CustomWorkbookViews) {
+        walk_custom_workbook_views(self, node)
+    }
+    fn visit_data_bar(&mut self, node: &'ast DataBar) {
+        walk_data_bar(self, node)
+    }
+    fn visit_data_binding(&mut self, _node: &'ast DataBinding) {}
+    fn visit_data_field(&mut self, node: &'ast DataField) {
+        walk_data_field(self, node)
+    }
+    fn visit_data_fields(&mut self, node: &'ast DataFields) {
+        walk_data_fields(self, node)
+    }
+    fn visit_data_tables(&mut self, node: &'ast DataTables) {
+        walk_data_tables(self, node)
+    }
+    fn visit_data_validation(&mut self, node: &'ast DataValidation) {
+        walk_data_validation(self, node)
+    }
+    fn visit_data_validations(&mut self, node: &'ast DataValidations) {
+        walk_data_validations(self, node)
+    }
+    fn visit_database_properties(&mut self, _node: &'ast DatabaseProperties) {}
+    fn visit_date_group_item(&mut self, node: &'ast DateGroupItem) {
+        walk_date_group_item(self, node)
+    }
+    fn visit_dde_item(&mut self, node: &'ast DdeItem) {
+        walk_dde_item(self, node)
+    }
+    fn visit_dde_items(&mut self, node: &'ast DdeItems) {
+        walk_dde_items(self, node)
+    }
+    fn visit_dde_link(&mut self, node: &'ast DdeLink) {
+        walk_dde_link(self, node)
+    }
+    fn visit_defined_name(&mut self, node: &'ast DefinedName) {
+        walk_defined_name(self, node)
+    }
+    fn visit_defined_names(&mut self, node: &'ast DefinedNames) {
+        walk_defined_names(self, node)
+    }
+    fn visit_differential_format(&mut self, node: &'ast DifferentialFormat) {
+        walk_differential_format(self, node)
+    }
+    fn visit_differential_formats(&mut self, node: &'ast DifferentialFormats) {
+        walk_differential_formats(self, node)
+    }
+    fn visit_drawing(&mut self, _node: &'ast Drawing) {}
+    fn visit_drawing_header_footer(&mut self, _node: &'ast DrawingHeaderFooter) {}
+    fn visit_dynamic_filter(&mut self, node: &'ast DynamicFilter) {
+        walk_dynamic_filter(self, node)
+    }
+    fn visit_dynamic_filter_type(&mut self, _node: &'ast DynamicFilterType) {}
+    fn visit_eg_extension_list(&mut self, node: &'ast EGExtensionList) {
+        walk_eg_extension_list(self, node)
+    }
+    fn visit_extension(&mut self, _node: &'ast Extension) {}
+    fn visit_extension_list(&mut self, node: &'ast ExtensionList) {
+        walk_extension_list(self, node)
+    }
+    fn visit_external_book(&mut self, node: &'ast ExternalBook) {
+        walk_external_book(self, node)
+    }
+    fn visit_external_cell(&mut self, node: &'ast ExternalCell) {
+        walk_external_cell(self, node)
+    }
+    fn visit_external_link(&mut self, node: &'ast ExternalLink) {
+        walk_external_link(self, node)
+    }
+    fn visit_external_reference(&mut self, _node: &'ast ExternalReference) {}
+    fn visit_external_references(&mut self, node: &'ast ExternalReferences) {
+        walk_external_references(self, node)
+    }
+    fn visit_external_row(&mut self, node: &'ast ExternalRow) {
+        walk_external_row(self, node)
+    }
+    fn visit_external_sheet_data(&mut self, node: &'ast ExternalSheetData) {
+        walk_external_sheet_data(self, node)
+    }
+    fn visit_external_sheet_data_set(&mut self, node: &'ast ExternalSheetDataSet) {
+        walk_external_sheet_data_set(self, node)
+    }
+    fn visit_field_group(&mut self, node: &'ast FieldGroup) {
+        walk_field_group(self, node)
+    }
+    fn visit_file_recovery_properties(&mut self, _node: &'ast FileRecoveryProperties) {}
+    fn visit_file_sharing(&mut self, _node: &'ast FileSharing) {}
+    fn visit_file_version(&mut self, _node: &'ast FileVersion) {}
+    fn visit_fill(&mut self, node: &'ast Fill) {
+        walk_fill(self, node)
+    }
+    fn visit_fills(&mut self, node: &'ast Fills) {
+        walk_fills(self, node)
+    }
+    fn visit_filter(&mut self, _node: &'ast Filter) {}
+    fn visit_filter_column(&mut self, node: &'ast FilterColumn) {
+        walk_filter_column(self, node)
+    }
+    fn visit_filter_operator(&mut self, _node: &'ast FilterOperator) {}
+    fn visit_filters(&mut self, node: &'ast Filters) {
+        walk_filters(self, node)
+    }
+    fn visit_font(&mut self, node: &'ast Font) {
+        walk_font(self, node)
+    }
+    fn visit_font_family(&mut self, _node: &'ast FontFamily) {}
+    fn visit_font_name(&mut self, _node: &'ast FontName) {}
+    fn visit_font_scheme(&mut self, _node: &'ast FontScheme) {}
+    fn visit_font_scheme_property(&mut self, node: &'ast FontSchemeProperty) {
+        walk_font_scheme_property(self, node)
+    }
+    fn visit_font_size(&mut self, _node: &'ast FontSize) {}
+    fn visit_fonts(&mut self, node: &'ast Fonts) {
+        walk_fonts(self, node)
+    }
+    fn visit_format(&mut self, node: &'ast Format) {
+        walk_format(self, node)
+    }
+    fn visit_formula_type(&mut self, _node: &'ast FormulaType) {}
+    fn visit_gradient_fill(&mut self, node: &'ast GradientFill) {
+        walk_gradient_fill(self, node)
+    }
+    fn visit_gradient_stop(&mut self, node: &'ast GradientStop) {
+        walk_gradient_stop(self, node)
+    }
+    fn visit_gradient_type(&mut self, _node: &'ast GradientType) {}
+    fn visit_group_items(&mut self, node: &'ast GroupItems) {
+        walk_group_items(self, node)
+    }
+    fn visit_header_footer(&mut self, node: &'ast HeaderFooter) {
+        walk_header_footer(self, node)
+    }
+    fn visit_horizontal_alignment(&mut self, _node: &'ast HorizontalAlignment) {}
+    fn visit_hyperlink(&mut self, _node: &'ast Hyperlink) {}
+    fn visit_hyperlinks(&mut self, node: &'ast Hyperlinks) {
+        walk_hyperlinks(self, node)
+    }
+    fn visit_icon_filter(&mut self, node: &'ast IconFilter) {
+        walk_icon_filter(self, node)
+    }
+    fn visit_icon_set(&mut self, node: &'ast IconSet) {
+        walk_icon_set(self, node)
+    }
+    fn visit_icon_set_type(&mut self, _node: &'ast IconSetType) {}
+    fn visit_ignored_error(&mut self, _node: &'ast IgnoredError) {}
+    fn visit_ignored_errors(&mut self, node: &'ast IgnoredErrors) {
+        walk_ignored_errors(self, node)
+    }
+    fn visit_indexed_colors(&mut self, node: &'ast IndexedColors) {
+        walk_indexed_colors(self, node)
+    }
+    fn visit_input_cells(&mut self, _node: &'ast InputCells) {}
+    fn visit_int_property(&mut self, _node: &'ast IntProperty) {}
+    fn visit_legacy_drawing(&mut self, _node: &'ast LegacyDrawing) {}
+    fn visit_map_info(&mut self, node: &'ast MapInfo) {
+        walk_map_info(self, node)
+    }
+    fn visit_merged_cell(&mut self, _node: &'ast MergedCell) {}
+    fn visit_merged_cells(&mut self, node: &'ast MergedCells) {
+        walk_merged_cells(self, node)
+    }
+    fn visit_metadata(&mut self, node: &'ast Metadata) {
+        walk_metadata(self, node)
+    }
+    fn visit_metadata_block(&mut self, node: &'ast MetadataBlock) {
+        walk_metadata_block(self, node)
+    }
+    fn visit_metadata_blocks(&mut self, node: &'ast MetadataBlocks) {
+        walk_metadata_blocks(self, node)
+    }
+    fn visit_metadata_record(&mut self, _node: &'ast MetadataRecord) {}
+    fn visit_metadata_strings(&mut self, node: &'ast MetadataStrings) {
+        walk_metadata_strings(self, node)
+    }
+    fn visit_metadata_type(&mut self, _node: &'ast MetadataType) {}
+    fn visit_metadata_types(&mut self, node: &'ast MetadataTypes) {
+        walk_metadata_types(self, node)
+    }
+    fn visit_most_recent_colors(&mut self, node: &'ast MostRecentColors) {
+        walk_most_recent_colors(self, node)
+    }
+    fn visit_number_format(&mut self, _node: &'ast NumberFormat) {}
+    fn visit_number_formats(&mut self, node: &'ast NumberFormats) {
+        walk_number_formats(self, node)
+    }
+    fn visit_object_anchor(&mut self, _node: &'ast ObjectAnchor) {}
+    fn visit_object_properties(&mut self, node: &'ast ObjectProperties) {
+        walk_object_properties(self, node)
+    }
+    fn visit_object_visibility(&mut self, _node: &'ast ObjectVisibility) {}
+    fn visit_olap_properties(&mut self, _node: &'ast OlapProperties) {}
+    fn visit_ole_item(&mut self, _node: &'ast OleItem) {}
+    fn visit_ole_items(&mut self, node: &'ast OleItems) {
+        walk_ole_items(self, node)
+    }
+    fn visit_ole_link(&mut self, node: &'ast OleLink) {
+        walk_ole_link(self, node)
+    }
+    fn visit_ole_object(&mut self, node: &'ast OleObject) {
+        walk_ole_object(self, node)
+    }
+    fn visit_ole_objects(&mut self, node: &'ast OleObjects) {
+        walk_ole_objects(self, node)
+    }
+    fn visit_outline_properties(&mut self, _node: &'ast OutlineProperties) {}
+    fn visit_page_break(&mut self, _node: &'ast PageBreak) {}
+    fn visit_page_breaks(&mut self, node: &'ast PageBreaks) {
+        walk_page_breaks(self, node)
+    }
+    fn visit_page_field(&mut self, node: &'ast PageField) {
+        walk_page_field(self, node)
+    }
+    fn visit_page_fields(&mut self, node: &'ast PageFields) {
+        walk_page_fields(self, node)
+    }
+    fn visit_page_margins(&mut self, _node: &'ast PageMargins) {}
+    fn visit_page_setup(&mut self, node: &'ast PageSetup) {
+        walk_page_setup(self, node)
+    }
+    fn visit_page_setup_properties(&mut self, _node: &'ast PageSetupProperties) {}
+    fn visit_pane(&mut self, node: &'ast Pane) {
+        walk_pane(self, node)
+    }
+    fn visit_pane_state(&mut self, _node: &'ast PaneState) {}
+    fn visit_pane_type(&mut self, _node: &'ast PaneType) {}
+    fn visit_parameter(&mut self, node: &'ast Parameter) {
+        walk_parameter(self, node)
+    }
+    fn visit_parameters(&mut self, node: &'ast Parameters) {
+        walk_parameters(self, node)
+    }
+    fn visit_pattern_fill(&mut self, node: &'ast PatternFill) {
+        walk_pattern_fill(self, node)
+    }
+    fn visit_pattern_type(&mut self, _node: &'ast PatternType) {}
+    fn visit_phonetic_properties(&mut self, node: &'ast PhoneticProperties) {
+        walk_phonetic_properties(self, node)
+    }
+    fn visit_phonetic_run(&mut self, node: &'ast PhoneticRun) {
+        walk_phonetic_run(self, node)
+    }
+    fn visit_pivot_area(&mut self, node: &'ast PivotArea) {
+        walk_pivot_area(self, node)
+    }
+    fn visit_pivot_areas(&mut self, node: &'ast PivotAreas) {
+        walk_pivot_areas(self, node)
+    }
+    fn visit_pivot_cache_definition(&mut self, node: &'ast PivotCacheDefinition) {
+        walk_pivot_cache_definition(self, node)
+    }
+    fn visit_pivot_cache_records(&mut self, node: &'ast PivotCacheRecords) {
+        walk_pivot_cache_records(self, node)
+    }
+    fn visit_pivot_caches(&mut self, node: &'ast PivotCaches) {
+        walk_pivot_caches(self, node)
+    }
+    fn visit_pivot_field(&mut self, node: &'ast PivotField) {
+        walk_pivot_field(self, node)
+    }
+    fn visit_pivot_fields(&mut self, node: &'ast PivotFields) {
+        walk_pivot_fields(self, node)
+    }
+    fn visit_pivot_filter(&mut self, node: &'ast PivotFilter) {
+        walk_pivot_filter(self, node)
+    }
+    fn visit_pivot_filters(&mut self, node: &'ast PivotFilters) {
+        walk_pivot_filters(self, node)
+    }
+    fn visit_pivot_item(&mut self, node: &'ast PivotItem) {
+        walk_pivot_item(self, node)
+    }
+    fn visit_pivot_items(&mut self, node: &'ast PivotItems) {
+        walk_pivot_items(self, node)
+    }
+    fn visit_pivot_location(&mut self, _node: &'ast PivotLocation) {}
+    fn visit_print_options(&mut self, _node: &'ast PrintOptions) {}
+    fn visit_protected_range(&mut self, _node: &'ast ProtectedRange) {}
+    fn visit_protected_ranges(&mut self, node: &'ast ProtectedRanges) {
+        walk_protected_ranges(self, node)
+    }
+    fn visit_query_table(&mut self, node: &'ast QueryTable) {
+        walk_query_table(self, node)
+    }
+    fn visit_query_table_deleted_fields(&mut self, node: &'ast QueryTableDeletedFields) {
+        walk_query_table_deleted_fields(self, node)
+    }
+    fn visit_query_table_field(&mut self, node: &'ast QueryTableField) {
+        walk_query_table_field(self, node)
+    }
+    fn visit_query_table_fields(&mut self, node: &'ast QueryTableFields) {
+        walk_query_table_fields(self, node)
+    }
+    fn visit_query_table_refresh(&mut self, node: &'ast QueryTableRefresh) {
+        walk_query_table_refresh(self, node)
+    }
+    fn visit_reference_mode(&mut self, _node: &'ast ReferenceMode) {}
+    fn visit_reviewed(&mut self, _node: &'ast Reviewed) {}
+    fn visit_reviewed_revisions(&mut self, node: &'ast ReviewedRevisions) {
+        walk_reviewed_revisions(self, node)
+    }
+    fn visit_revision_auto_formatting(&mut self, _node: &'ast RevisionAutoFormatting) {}
+    fn visit_revision_cell_change(&mut self, node: &'ast RevisionCellChange) {
+        walk_revision_cell_change(self, node)
+    }
+    fn visit_revision_comment(&mut self, node: &'ast RevisionComment) {
+        walk_revision_comment(self, node)
+    }
+    fn visit_revision_conflict(&mut self, _node: &'ast RevisionConflict) {}
+    fn visit_revision_custom_view(&mut self, node: &'ast RevisionCustomView) {
+        walk_revision_custom_view(self, node)
+    }
+    fn visit_revision_defined_name(&mut self, node: &'ast RevisionDefinedName) {
+        walk_revision_defined_name(self, node)
+    }
+    fn visit_revision_formatting(&mut self, node: &'ast RevisionFormatting) {
+        walk_revision_formatting(self, node)
+    }
+    fn visit_revision_header(&mut self, node: &'ast RevisionHeader) {
+        walk_revision_header(self, node)
+    }
+    fn visit_revision_headers(&mut self, node: &'ast RevisionHeaders) {
+        walk_revision_headers(self, node)
+    }
+    fn visit_revision_insert_sheet(&mut self, _node: &'ast RevisionInsertSheet) {}
+    fn visit_revision_move(&mut self, _node: &'ast RevisionMove) {}
+    fn visit_revision_query_table_field(&mut self, _node: &'ast RevisionQueryTableField) {}
+    fn visit_revision_row_column(&mut self, node: &'ast RevisionRowColumn) {
+        walk_revision_row_column(self, node)
+    }
+    fn visit_revision_sheet_rename(&mut self, node: &'ast RevisionSheetRename) {
+        walk_revision_sheet_rename(self, node)
+    }
+    fn visit_revisions(&mut self, node: &'ast Revisions) {
+        walk_revisions(self, node)
+    }
+    fn visit_rgb_color(&mut self, _node: &'ast RgbColor) {}
+    fn visit_rich_string(&mut self, node: &'ast RichString) {
+        walk_rich_string(self, node)
+    }
+    fn visit_rich_text_element(&mut self, node: &'ast RichTextElement) {
+        walk_rich_text_element(self, node)
+    }
+    fn visit_rich_text_run_properties(&mut self, node: &'ast RichTextRunProperties) {
+        walk_rich_text_run_properties(self, node)
+    }
+    fn visit_row(&mut self, node: &'ast Row) {
+        walk_row(self, node)
+    }
+    fn visit_row_fields(&mut self, node: &'ast RowFields) {
+        walk_row_fields(self, node)
+    }
+    fn visit_st_alg_class(&mut self, _node: &'ast STAlgClass) {}
+    fn visit_st_alg_type(&mut self, _node: &'ast STAlgType) {}
+    fn visit_st_axis(&mut self, _node: &'ast STAxis) {}
+    fn visit_st_cell_comments(&mut self, _node: &'ast STCellComments) {}
+    fn visit_st_conformance_class(&mut self, _node: &'ast STConformanceClass) {}
+    fn visit_st_cred_method(&mut self, _node: &'ast STCredMethod) {}
+    fn visit_st_crypt_prov(&mut self, _node: &'ast STCryptProv) {}
+    fn visit_st_data_consolidate_function(&mut self, _node: &'ast STDataConsolidateFunction) {}
+    fn visit_st_data_validation_ime_mode(&mut self, _node: &'ast STDataValidationImeMode) {}
+    fn visit_st_date_time_grouping(&mut self, _node: &'ast STDateTimeGrouping) {}
+    fn visit_st_dde_value_type(&mut self, _node: &'ast STDdeValueType) {}
+    fn visit_st_dv_aspect(&mut self, _node: &'ast STDvAspect) {}
+    fn visit_st_external_connection_type(&mut self, _node: &'ast STExternalConnectionType) {}
+    fn visit_st_field_sort_type(&mut self, _node: &'ast STFieldSortType) {}
+    fn visit_st_file_type(&mut self, _node: &'ast STFileType) {}
+    fn visit_st_format_action(&mut self, _node: &'ast STFormatAction) {}
+    fn visit_st_formula_expression(&mut self, _node: &'ast STFormulaExpression) {}
+    fn visit_st_group_by(&mut self, _node: &'ast STGroupBy) {}
+    fn visit_st_grow_shrink_type(&mut self, _node: &'ast STGrowShrinkType) {}
+    fn visit_st_html_fmt(&mut self, _node: &'ast STHtmlFmt) {}
+    fn visit_st_item_type(&mut self, _node: &'ast STItemType) {}
+    fn visit_st_mdx_function_type(&mut self, _node: &'ast STMdxFunctionType) {}
+    fn visit_st_mdx_kpi_property(&mut self, _node: &'ast STMdxKPIProperty) {}
+    fn visit_st_mdx_set_order(&mut self, _node: &'ast STMdxSetOrder) {}
+    fn visit_st_ole_update(&mut self, _node: &'ast STOleUpdate) {}
+    fn visit_st_on_off1(&mut self, _node: &'ast STOnOff1) {}
+    fn visit_st_orientation(&mut self, _node: &'ast STOrientation) {}
+    fn visit_st_page_order(&mut self, _node: &'ast STPageOrder) {}
+    fn visit_st_parameter_type(&mut self, _node: &'ast STParameterType) {}
+    fn visit_st_phonetic_alignment(&mut self, _node: &'ast STPhoneticAlignment) {}
+    fn visit_st_phonetic_type(&mut self, _node: &'ast STPhoneticType) {}
+    fn visit_st_pivot_area_type(&mut self, _node: &'ast STPivotAreaType) {}
+    fn visit_st_pivot_filter_type(&mut self, _node: &'ast STPivotFilterType) {}
+    fn visit_st_print_error(&mut self, _node: &'ast STPrintError) {}
+    fn visit_st_qualifier(&mut self, _node: &'ast STQualifier) {}
+    fn visit_st_revision_action(&mut self, _node: &'ast STRevisionAction) {}
+    fn visit_st_rw_col_action_type(&mut self, _node: &'ast STRwColActionType) {}
+    fn visit_st_scope(&mut self, _node: &'ast STScope) {}
+    fn visit_st_show_data_as(&mut self, _node: &'ast STShowDataAs) {}
+    fn visit_st_smart_tag_show(&mut self, _node: &'ast STSmartTagShow) {}
+    fn visit_st_sort_type(&mut self, _node: &'ast STSortType) {}
+    fn visit_st_source_type(&mut self, _node: &'ast STSourceType) {}
+    fn visit_st_table_style_type(&mut self, _node: &'ast STTableStyleType) {}
+    fn visit_st_table_type(&mut self, _node: &'ast STTableType) {}
+    fn visit_st_target_screen_size(&mut self, _node: &'ast STTargetScreenSize) {}
+    fn visit_st_text_h_align(&mut self, _node: &'ast STTextHAlign) {}
+    fn visit_st_text_v_align(&mut self, _node: &'ast STTextVAlign) {}
+    fn visit_st_time_period(&mut self, _node: &'ast STTimePeriod) {}
+    fn visit_st_totals_row_function(&mut self, _node: &'ast STTotalsRowFunction) {}
+    fn visit_st_true_false_blank(&mut self, _node: &'ast STTrueFalseBlank) {}
+    fn visit_st_type(&mut self, _node: &'ast STType) {}
+    fn visit_st_vol_dep_type(&mut self, _node: &'ast STVolDepType) {}
+    fn visit_st_vol_value_type(&mut self, _node: &'ast STVolValueType) {}
+    fn visit_st_web_source_type(&mut self, _node: &'ast STWebSourceType) {}
+    fn visit_stx_align(&mut self, _node: &'ast STXAlign) {}
+    fn visit_sty_align(&mut self, _node: &'ast STYAlign) {}
+    fn visit_scenario(&mut self, node: &'ast Scenario) {
+        walk_scenario(self, node)
+    }
+    fn visit_scenarios(&mut self, node: &'ast Scenarios) {
+        walk_scenarios(self, node)
+    }
+    fn visit_selection(&mut self, node: &'ast Selection) {
+        walk_selection(self, node)
+    }
+    fn visit_shared_items(&mut self, node: &'ast SharedItems) {
+        walk_shared_items(self, node)
+    }
+    fn visit_shared_strings(&mut self, node: &'ast SharedStrings) {
+        walk_shared_strings(self, node)
+    }
+    fn visit_shared_user(&mut self, node: &'ast SharedUser) {
+        walk_shared_user(self, node)
+    }
+    fn visit_sheet(&mut self, node: &'ast Sheet) {
+        walk_sheet(self, node)
+    }
+    fn visit_sheet_background_picture(&mut self, _node: &'ast SheetBackgroundPicture) {}
+    fn visit_sheet_calc_properties(&mut self, _node: &'ast SheetCalcProperties) {}
+    fn visit_sheet_data(&mut self, node: &'ast SheetData) {
+        walk_sheet_data(self, node)
+    }
+    fn visit_sheet_dimension(&mut self, _node: &'ast SheetDimension) {}
+    fn visit_sheet_format(&mut self, _node: &'ast SheetFormat) {}
+    fn visit_sheet_properties(&mut self, node: &'ast SheetProperties) {
+        walk_sheet_properties(self, node)
+    }
+    fn visit_sheet_protection(&mut self, _node: &'ast SheetProtection) {}
+    fn visit_sheet_state(&mut self, _node: &'ast SheetState) {}
+    fn visit_sheet_view(&mut self, node: &'ast SheetView) {
+        walk_sheet_view(self, node)
+    }
+    fn visit_sheet_view_type(&mut self, _node: &'ast SheetViewType) {}
+    fn visit_sheet_views(&mut self, node: &'ast SheetViews) {
+        walk_sheet_views(self, node)
+    }
+    fn visit_sheets(&mut self, node: &'ast Sheets) {
+        walk_sheets(self, node)
+    }
+    fn visit_single_xml_cell(&mut self, node: &'ast SingleXmlCell) {
+        walk_single_xml_cell(self, node)
+    }
+    fn visit_single_xml_cells(&mut self, node: &'ast SingleXmlCells) {
+        walk_single_xml_cells(self, node)
+    }
+    fn visit_smart_tags(&mut self, node: &'ast SmartTags) {
+        walk_smart_tags(self, node)
+    }
+    fn visit_sml_ag_auto_format(&mut self, _node: &'ast SmlAGAutoFormat) {}
+    fn visit_sml_ag_rev_data(&mut self, _node: &'ast SmlAGRevData) {}
+    fn visit_sort_by(&mut self, _node: &'ast SortBy) {}
+    fn visit_sort_condition(&mut self, node: &'ast SortCondition) {
+        walk_sort_condition(self, node)
+    }
+    fn visit_sort_method(&mut self, _node: &'ast SortMethod) {}
+    fn visit_sort_state(&mut self, node: &'ast SortState) {
+        walk_sort_state(self, node)
+    }
+    fn visit_stylesheet(&mut self, node: &'ast Stylesheet) {
+        walk_stylesheet(self, node)
+    }
+    fn visit_table(&mut self, node: &'ast Table) {
+        walk_table(self, node)
+    }
+    fn visit_table_column(&mut self, node: &'ast TableColumn) {
+        walk_table_column(self, node)
+    }
+    fn visit_table_columns(&mut self, node: &'ast TableColumns) {
+        walk_table_columns(self, node)
+    }
+    fn visit_table_formula(&mut self, node: &'ast TableFormula) {
+        walk_table_formula(self, node)
+    }
+    fn visit_table_missing(&mut self, _node: &'ast TableMissing) {}
+    fn visit_table_part(&mut self, _node: &'ast TablePart) {}
+    fn visit_table_parts(&mut self, node: &'ast TableParts) {
+        walk_table_parts(self, node)
+    }
+    fn visit_table_style(&mut self, node: &'ast TableStyle) {
+        walk_table_style(self, node)
+    }
+    fn visit_table_style_element(&mut self, node: &'ast TableStyleElement) {
+        walk_table_style_element(self, node)
+    }
+    fn visit_table_style_info(&mut self, _node: &'ast TableStyleInfo) {}
+    fn visit_table_styles(&mut self, node: &'ast TableStyles) {
+        walk_table_styles(self, node)
+    }
+    fn visit_text_field(&mut self, node: &'ast TextField) {
+        walk_text_field(self, node)
+    }
+    fn visit_text_fields(&mut self, node: &'ast TextFields) {
+        walk_text_fields(self, node)
+    }
+    fn visit_text_import_properties(&mut self, node: &'ast TextImportProperties) {
+        walk_text_import_properties(self, node)
+    }
+    fn visit_top10_filter(&mut self, _node: &'ast Top10Filter) {}
+    fn visit_true_false(&mut self, _node: &'ast TrueFalse) {}
+    fn visit_underline_property(&mut self, node: &'ast UnderlineProperty) {
+        walk_underline_property(self, node)
+    }
+    fn visit_underline_style(&mut self, _node: &'ast UnderlineStyle) {}
+    fn visit_undo_info(&mut self, node: &'ast UndoInfo) {
+        walk_undo_info(self, node)
+    }
+    fn visit_update_links(&mut self, _node: &'ast UpdateLinks) {}
+    fn visit_users(&mut self, node: &'ast Users) {
+        walk_users(self, node)
+    }
+    fn visit_validation_error_style(&mut self, _node: &'ast ValidationErrorStyle) {}
+    fn visit_validation_operator(&mut self, _node: &'ast ValidationOperator) {}
+    fn visit_validation_type(&mut self, _node: &'ast ValidationType) {}
+    fn visit_vertical_align_font_property(&mut self, node: &'ast VerticalAlignFontProperty) {
+        walk_vertical_align_font_property(self, node)
+    }
+    fn visit_vertical_align_run(&mut self, _node: &'ast VerticalAlignRun) {}
+    fn visit_vertical_alignment(&mut self, _node: &'ast VerticalAlignment) {}
+    fn visit_visibility(&mut self, _node: &'ast Visibility) {}
+    fn visit_web_publish_item(&mut self, node: &'ast WebPublishItem) {
+        walk_web_publish_item(self, node)
+    }
+    fn visit_web_publish_items(&mut self, node: &'ast WebPublishItems) {
+        walk_web_publish_items(self, node)
+    }
+    fn visit_web_publishing(&mut self, node: &'ast WebPublishing) {
+        walk_web_publishing(self, node)
+    }
+    fn visit_web_query_properties(&mut self, node: &'ast WebQueryProperties) {
+        walk_web_query_properties(self, node)
+    }
+    fn visit_workbook(&mut self, node: &'ast Workbook) {
+        walk_workbook(self, node)
+    }
+    fn visit_workbook_properties(&mut self, node: &'ast WorkbookProperties) {
+        walk_workbook_properties(self, node)
+    }
+    fn visit_workbook_protection(&mut self, _node: &'ast WorkbookProtection) {}
+    fn visit_worksheet(&mut self, node: &'ast Worksheet) {
+        walk_worksheet(self, node)
+    }
+    fn visit_worksheet_source(&mut self, _node: &'ast WorksheetSource) {}
+    fn visit_xml_cell_properties(&mut self, node: &'ast XmlCellProperties) {
+        walk_xml_cell_properties(self, node)
+    }
+    fn visit_xml_column_properties(&mut self, node: &'ast XmlColumnProperties) {
+        walk_xml_column_properties(self, node)
+    }
+    fn visit_xml_map(&mut self, node: &'ast XmlMap) {
+        walk_xml_map(self, node)
+    }
+    fn visit_xml_properties(&mut self, node: &'ast XmlProperties) {
+        walk_xml_properties(self, node)
+    }
+    fn visit_xml_schema(&mut self, node: &'ast XmlSchema) {
+        walk_xml_schema(self, node)
+    }
+    fn visit_raw_xml_node(&mut self, node: &'ast RawXmlNode) {
+        walk_raw_xml_node(self, node)
+    }
+    fn visit_raw_xml_element(&mut self, node: &'ast RawXmlElement) {
+        walk_raw_xml_element(self, node)
+    }
+}
+#[doc = r" Mutable traversal of the generated types."]
+#[doc = r""]
+#[doc = r" Every method defaults to visiting the node's children; override"]
+#[doc = r" the ones of interest and call the matching `walk_*_mut` function"]
+#[doc = r" to keep descending."]
+pub trait VisitMut {
+    fn visit_authors_mut(&mut self, node: &mut Authors) {
+        walk_authors_mut(self, node)
+    }
+    fn visit_auto_filter_mut(&mut self, node: &mut AutoFilter) {
+        walk_auto_filter_mut(self, node)
+    }
+    fn visit_book_view_mut(&mut self, node: &mut BookView) {
+        walk_book_view_mut(self, node)
+    }
+    fn visit_book_views_mut(&mut self, node: &mut BookViews) {
+        walk_book_views_mut(self, node)
+    }
+    fn visit_boolean_property_mut(&mut self, _node: &mut BooleanProperty) {}
+    fn visit_border_mut(&mut self, node: &mut Border) {
+        walk_border_mut(self, node)
+    }
+    fn visit_border_properties_mut(&mut self, node: &mut BorderProperties) {
+        walk_border_properties_mut(self, node)
+    }
+    fn visit_border_style_mut(&mut self, _node: &mut BorderStyle) {}
+    fn visit_borders_mut(&mut self, node: &mut Borders) {
+        walk_borders_mut(self, node)
+    }
+    fn visit_ct_boolean_mut(&mut self, node: &mut CTBoolean) {
+        walk_ct_boolean_mut(self, node)
+    }
+    fn visit_ct_cache_hierarchies_mut(&mut self, node: &mut CTCacheHierarchies) {
+        walk_ct_cache_hierarchies_mut(self, node)
+    }
+    fn visit_ct_cache_hierarchy_mut(&mut self, node: &mut CTCacheHierarchy) {
+        walk_ct_cache_hierarchy_mut(self, node)
+    }
+    fn visit_ct_calculated_item_mut(&mut self, node: &mut CTCalculatedItem) {
+        walk_ct_calculated_item_mut(self, node)
+    }
+    fn visit_ct_calculated_items_mut(&mut self, node: &mut CTCalculatedItems) {
+        walk_ct_calculated_items_mut(self, node)
+    }
+    fn visit_ct_calculated_member_mut(&mut self, node: &mut CTCalculatedMember) {
+        walk_ct_calculated_member_mut(self, node)
+    }
+    fn visit_ct_calculated_members_mut(&mut self, node: &mut CTCalculatedMembers) {
+        walk_ct_calculated_members_mut(self, node)
+    }
+    fn visit_ct_cell_smart_tag_pr_mut(&mut self, _node: &mut CTCellSmartTagPr) {}
+    fn visit_ct_chart_format_mut(&mut self, node: &mut CTChartFormat) {
+        walk_ct_chart_format_mut(self, node)
+    }
+    fn visit_ct_chart_formats_mut(&mut self, node: &mut CTChartFormats) {
+        walk_ct_chart_formats_mut(self, node)
+    }
+    fn visit_ct_col_hierarchies_usage_mut(&mut self, node: &mut CTColHierarchiesUsage) {
+        walk_ct_col_hierarchies_usage_mut(self, node)
+    }
+    fn visit_ct_col_items_mut(&mut self, node: &mut CTColItems) {
+        walk_ct_col_items_mut(self, node)
+    }
+    fn visit_ct_comment_pr_mut(&mut self, node: &mut CTCommentPr) {
+        walk_ct_comment_pr_mut(self, node)
+    }
+    fn visit_ct_conditional_format_mut(&mut self, node: &mut CTConditionalFormat) {
+        walk_ct_conditional_format_mut(self, node)
+    }
+    fn visit_ct_conditional_formats_mut(&mut self, node: &mut CTConditionalFormats) {
+        walk_ct_conditional_formats_mut(self, node)
+    }
+    fn visit_ct_control_pr_mut(&mut self, node: &mut CTControlPr) {
+        walk_ct_control_pr_mut(self, node)
+    }
+    fn visit_ct_custom_properties_mut(&mut self, node: &mut CTCustomProperties) {
+        walk_ct_custom_properties_mut(self, node)
+    }
+    fn visit_ct_custom_property_mut(&mut self, _node: &mut CTCustomProperty) {}
+    fn visit_ct_data_consolidate_mut(&mut self, node: &mut CTDataConsolidate) {
+        walk_ct_data_consolidate_mut(self, node)
+    }
+    fn visit_ct_data_ref_mut(&mut self, _node: &mut CTDataRef) {}
+    fn visit_ct_data_refs_mut(&mut self, node: &mut CTDataRefs) {
+        walk_ct_data_refs_mut(self, node)
+    }
+    fn visit_ct_date_time_mut(&mut self, node: &mut CTDateTime) {
+        walk_ct_date_time_mut(self, node)
+    }
+    fn visit_ct_dde_value_mut(&mut self, node: &mut CTDdeValue) {
+        walk_ct_dde_value_mut(self, node)
+    }
+    fn visit_ct_dde_values_mut(&mut self, node: &mut CTDdeValues) {
+        walk_ct_dde_values_mut(self, node)
+    }
+    fn visit_ct_deleted_field_mut(&mut self, _node: &mut CTDeletedField) {}
+    fn visit_ct_dialogsheet_mut(&mut self, node: &mut CTDialogsheet) {
+        walk_ct_dialogsheet_mut(self, node)
+    }
+    fn visit_ct_dimensions_mut(&mut self, node: &mut CTDimensions) {
+        walk_ct_dimensions_mut(self, node)
+    }
+    fn visit_ct_discrete_pr_mut(&mut self, node: &mut CTDiscretePr) {
+        walk_ct_discrete_pr_mut(self, node)
+    }
+    fn visit_ct_error_mut(&mut self, node: &mut CTError) {
+        walk_ct_error_mut(self, node)
+    }
+    fn visit_ct_external_defined_name_mut(&mut self, _node: &mut CTExternalDefinedName) {}
+    fn visit_ct_external_defined_names_mut(&mut self, node: &mut CTExternalDefinedNames) {
+        walk_ct_external_defined_names_mut(self, node)
+    }
+    fn visit_ct_external_sheet_name_mut(&mut self, _node: &mut CTExternalSheetName) {}
+    fn visit_ct_external_sheet_names_mut(&mut self, node: &mut CTExternalSheetNames) {
+        walk_ct_external_sheet_names_mut(self, node)
+    }
+    fn visit_ct_field_mut(&mut self, _node: &mut CTField) {}
+    fn visit_ct_field_usage_mut(&mut self, _node: &mut CTFieldUsage) {}
+    fn visit_ct_fields_usage_mut(&mut self, node: &mut CTFieldsUsage) {
+        walk_ct_fields_usage_mut(self, node)
+    }
+    fn visit_ct_format_mut(&mut self, node: &mut CTFormat) {
+        walk_ct_format_mut(self, node)
+    }
+    fn visit_ct_formats_mut(&mut self, node: &mut CTFormats) {
+        walk_ct_formats_mut(self, node)
+    }
+    fn visit_ct_function_group_mut(&mut self, _node: &mut CTFunctionGroup) {}
+    fn visit_ct_function_groups_mut(&mut self, node: &mut CTFunctionGroups) {
+        walk_ct_function_groups_mut(self, node)
+    }
+    fn visit_ct_future_metadata_mut(&mut self, node: &mut CTFutureMetadata) {
+        walk_ct_future_metadata_mut(self, node)
+    }
+    fn visit_ct_future_metadata_block_mut(&mut self, node: &mut CTFutureMetadataBlock) {
+        walk_ct_future_metadata_block_mut(self, node)
+    }
+    fn visit_ct_group_level_mut(&mut self, node: &mut CTGroupLevel) {
+        walk_ct_group_level_mut(self, node)
+    }
+    fn visit_ct_group_levels_mut(&mut self, node: &mut CTGroupLevels) {
+        walk_ct_group_levels_mut(self, node)
+    }
+    fn visit_ct_group_member_mut(&mut self, _node: &mut CTGroupMember) {}
+    fn visit_ct_group_members_mut(&mut self, node: &mut CTGroupMembers) {
+        walk_ct_group_members_mut(self, node)
+    }
+    fn visit_ct_groups_mut(&mut self, node: &mut CTGroups) {
+        walk_ct_groups_mut(self, node)
+    }
+    fn visit_ct_hierarchy_usage_mut(&mut self, _node: &mut CTHierarchyUsage) {}
+    fn visit_cti_mut(&mut self, node: &mut CTI) {
+        walk_cti_mut(self, node)
+    }
+    fn visit_ct_index_mut(&mut self, _node: &mut CTIndex) {}
+    fn visit_ct_level_group_mut(&mut self, node: &mut CTLevelGroup) {
+        walk_ct_level_group_mut(self, node)
+    }
+    fn visit_ct_macrosheet_mut(&mut self, node: &mut CTMacrosheet) {
+        walk_ct_macrosheet_mut(self, node)
+    }
+    fn visit_ct_mdx_mut(&mut self, node: &mut CTMdx) {
+        walk_ct_mdx_mut(self, node)
+    }
+    fn visit_ct_mdx_kpi_mut(&mut self, node: &mut CTMdxKPI) {
+        walk_ct_mdx_kpi_mut(self, node)
+    }
+    fn visit_ct_mdx_memeber_prop_mut(&mut self, _node: &mut CTMdxMemeberProp) {}
+    fn visit_ct_mdx_metadata_mut(&mut self, node: &mut CTMdxMetadata) {
+        walk_ct_mdx_metadata_mut(self, node)
+    }
+    fn visit_ct_mdx_set_mut(&mut self, node: &mut CTMdxSet) {
+        walk_ct_mdx_set_mut(self, node)
+    }
+    fn visit_ct_mdx_tuple_mut(&mut self, node: &mut CTMdxTuple) {
+        walk_ct_mdx_tuple_mut(self, node)
+    }
+    fn visit_ct_measure_dimension_map_mut(&mut self, _node: &mut CTMeasureDimensionMap) {}
+    fn visit_ct_measure_dimension_maps_mut(&mut self, node: &mut CTMeasureDimensionMaps) {
+        walk_ct_measure_dimension_maps_mut(self, node)
+    }
+    fn visit_ct_measure_group_mut(&mut self, _node: &mut CTMeasureGroup) {}
+    fn visit_ct_measure_groups_mut(&mut self, node: &mut CTMeasureGroups) {
+        walk_ct_measure_groups_mut(self, node)
+    }
+    fn visit_ct_member_mut(&mut self, _node: &mut CTMember) {}
+    fn visit_ct_member_properties_mut(&mut self, node: &mut CTMemberProperties) {
+        walk_ct_member_properties_mut(self, node)
+    }
+    fn visit_ct_member_property_mut(&mut self, _node: &mut CTMemberProperty) {}
+    fn visit_ct_members_mut(&mut self, node: &mut CTMembers) {
+        walk_ct_members_mut(self, node)
+    }
+    fn visit_ct_metadata_string_index_mut(&mut self, _node: &mut CTMetadataStringIndex) {}
+    fn visit_ct_missing_mut(&mut self, node: &mut CTMissing) {
+        walk_ct_missing_mut(self, node)
+    }
+    fn visit_ct_number_mut(&mut self, node: &mut CTNumber) {
+        walk_ct_number_mut(self, node)
+    }
+    fn visit_ct_ole_size_mut(&mut self, _node: &mut CTOleSize) {}
+    fn visit_ctpcdkpi_mut(&mut self, _node: &mut CTPCDKPI) {}
+    fn visit_ctpcdkp_is_mut(&mut self, node: &mut CTPCDKPIs) {
+        walk_ctpcdkp_is_mut(self, node)
+    }
+    fn visit_ctpcdsc_page_mut(&mut self, node: &mut CTPCDSCPage) {
+        walk_ctpcdsc_page_mut(self, node)
+    }
+    fn visit_ctpcdsdtc_entries_mut(&mut self, node: &mut CTPCDSDTCEntries) {
+        walk_ctpcdsdtc_entries_mut(self, node)
+    }
+    fn visit_ct_page_item_mut(&mut self, _node: &mut CTPageItem) {}
+    fn visit_ct_pages_mut(&mut self, node: &mut CTPages) {
+        walk_ct_pages_mut(self, node)
+    }
+    fn visit_ct_pivot_area_reference_mut(&mut self, node: &mut CTPivotAreaReference) {
+        walk_ct_pivot_area_reference_mut(self, node)
+    }
+    fn visit_ct_pivot_area_references_mut(&mut self, node: &mut CTPivotAreaReferences) {
+        walk_ct_pivot_area_references_mut(self, node)
+    }
+    fn visit_ct_pivot_cache_mut(&mut self, _node: &mut CTPivotCache) {}
+    fn visit_ct_pivot_dimension_mut(&mut self, _node: &mut CTPivotDimension) {}
+    fn visit_ct_pivot_hierarchies_mut(&mut self, node: &mut CTPivotHierarchies) {
+        walk_ct_pivot_hierarchies_mut(self, node)
+    }
+    fn visit_ct_pivot_hierarchy_mut(&mut self, node: &mut CTPivotHierarchy) {
+        walk_ct_pivot_hierarchy_mut(self, node)
+    }
+    fn visit_ct_pivot_selection_mut(&mut self, node: &mut CTPivotSelection) {
+        walk_ct_pivot_selection_mut(self, node)
+    }
+    fn visit_ct_pivot_table_definition_mut(&mut self, node: &mut CTPivotTableDefinition) {
+        walk_ct_pivot_table_definition_mut(self, node)
+    }
+    fn visit_ct_pivot_table_style_mut(&mut self, _node: &mut CTPivotTableStyle) {}
+    fn visit_ct_query_mut(&mut self, node: &mut CTQuery) {
+        walk_ct_query_mut(self, node)
+    }
+    fn visit_ct_query_cache_mut(&mut self, node: &mut CTQueryCache) {
+        walk_ct_query_cache_mut(self, node)
+    }
+    fn visit_ct_range_pr_mut(&mut self, node: &mut CTRangePr) {
+        walk_ct_range_pr_mut(self, node)
+    }
+    fn visit_ct_range_set_mut(&mut self, _node: &mut CTRangeSet) {}
+    fn visit_ct_range_sets_mut(&mut self, node: &mut CTRangeSets) {
+        walk_ct_range_sets_mut(self, node)
+    }
+    fn visit_ct_record_mut(&mut self, node: &mut CTRecord) {
+        walk_ct_record_mut(self, node)
+    }
+    fn visit_ct_row_hierarchies_usage_mut(&mut self, node: &mut CTRowHierarchiesUsage) {
+        walk_ct_row_hierarchies_usage_mut(self, node)
+    }
+    fn visit_ct_row_items_mut(&mut self, node: &mut CTRowItems) {
+        walk_ct_row_items_mut(self, node)
+    }
+    fn visit_ct_server_format_mut(&mut self, _node: &mut CTServerFormat) {}
+    fn visit_ct_server_formats_mut(&mut self, node: &mut CTServerFormats) {
+        walk_ct_server_formats_mut(self, node)
+    }
+    fn visit_ct_set_mut(&mut self, node: &mut CTSet) {
+        walk_ct_set_mut(self, node)
+    }
+    fn visit_ct_sets_mut(&mut self, node: &mut CTSets) {
+        walk_ct_sets_mut(self, node)
+    }
+    fn visit_ct_sheet_id_mut(&mut self, _node: &mut CTSheetId) {}
+    fn visit_ct_sheet_id_map_mut(&mut self, node: &mut CTSheetIdMap) {
+        walk_ct_sheet_id_map_mut(self, node)
+    }
+    fn visit_ct_smart_tag_pr_mut(&mut self, node: &mut CTSmartTagPr) {
+        walk_ct_smart_tag_pr_mut(self, node)
+    }
+    fn visit_ct_smart_tag_type_mut(&mut self, _node: &mut CTSmartTagType) {}
+    fn visit_ct_smart_tag_types_mut(&mut self, node: &mut CTSmartTagTypes) {
+        walk_ct_smart_tag_types_mut(self, node)
+    }
+    fn visit_ct_string_mut(&mut self, node: &mut CTString) {
+        walk_ct_string_mut(self, node)
+    }
+    fn visit_ct_tuple_mut(&mut self, _node: &mut CTTuple) {}
+    fn visit_ct_tuple_cache_mut(&mut self, node: &mut CTTupleCache) {
+        walk_ct_tuple_cache_mut(self, node)
+    }
+    fn visit_ct_tuples_mut(&mut self, node: &mut CTTuples) {
+        walk_ct_tuples_mut(self, node)
+    }
+    fn visit_ct_vol_main_mut(&mut self, node: &mut CTVolMain) {
+        walk_ct_vol_main_mut(self, node)
+    }
+    fn visit_ct_vol_topic_mut(&mut self, node: &mut CTVolTopic) {
+        walk_ct_vol_topic_mut(self, node)
+    }
+    fn visit_ct_vol_topic_ref_mut(&mut self, _node: &mut CTVolTopicRef) {}
+    fn visit_ct_vol_type_mut(&mut self, node: &mut CTVolType) {
+        walk_ct_vol_type_mut(self, node)
+    }
+    fn visit_ct_vol_types_mut(&mut self, node: &mut CTVolTypes) {
+        walk_ct_vol_types_mut(self, node)
+    }
+    fn visit_ct_web_publish_object_mut(&mut self, _node: &mut CTWebPublishObject) {}
+    fn visit_ct_web_publish_objects_mut(&mut self, node: &mut CTWebPublishObjects) {
+        walk_ct_web_publish_objects_mut(self, node)
+    }
+    fn visit_ctx_mut(&mut self, _node: &mut CTX) {}
+    fn visit_ctx_string_element_mut(&mut self, _node: &mut CTXStringElement) {}
+    fn visit_cache_field_mut(&mut self, node: &mut CacheField) {
+        walk_cache_field_mut(self, node)
+    }
+    fn visit_cache_fields_mut(&mut self, node: &mut CacheFields) {
+        walk_cache_fields_mut(self, node)
+    }
+    fn visit_cache_source_mut(&mut self, node: &mut CacheSource) {
+        walk_cache_source_mut(self, node)
+    }
+    fn visit_calc_cell_mut(&mut self, _node: &mut CalcCell) {}
+    fn visit_calc_chain_mut(&mut self, node: &mut CalcChain) {
+        walk_calc_chain_mut(self, node)
+    }
+    fn visit_calculation_mode_mut(&mut self, _node: &mut CalculationMode) {}
+    fn visit_calculation_properties_mut(&mut self, node: &mut CalculationProperties) {
+        walk_calculation_properties_mut(self, node)
+    }
+    fn visit_calendar_type_mut(&mut self, _node: &mut CalendarType) {}
+    fn visit_cell_mut(&mut self, node: &mut Cell) {
+        walk_cell_mut(self, node)
+    }
+    fn visit_cell_alignment_mut(&mut self, node: &mut CellAlignment) {
+        walk_cell_alignment_mut(self, node)
+    }
+    fn visit_cell_formats_mut(&mut self, node: &mut CellFormats) {
+        walk_cell_formats_mut(self, node)
+    }
+    fn visit_cell_formula_mut(&mut self, node: &mut CellFormula) {
+        walk_cell_formula_mut(self, node)
+    }
+    fn visit_cell_protection_mut(&mut self, _node: &mut CellProtection) {}
+    fn visit_cell_smart_tag_mut(&mut self, node: &mut CellSmartTag) {
+        walk_cell_smart_tag_mut(self, node)
+    }
+    fn visit_cell_smart_tags_mut(&mut self, node: &mut CellSmartTags) {
+        walk_cell_smart_tags_mut(self, node)
+    }
+    fn visit_cell_style_mut(&mut self, node: &mut CellStyle) {
+        walk_cell_style_mut(self, node)
+    }
+    fn visit_cell_style_formats_mut(&mut self, node: &mut CellStyleFormats) {
+        walk_cell_style_formats_mut(self, node)
+    }
+    fn visit_cell_styles_mut(&mut self, node: &mut CellStyles) {
+        walk_cell_styles_mut(self, node)
+    }
+    fn visit_cell_type_mut(&mut self, _node: &mut CellType) {}
+    fn visit_cell_watch_mut(&mut self, _node: &mut CellWatch) {}
+    fn visit_cell_watches_mut(&mut self, node: &mut CellWatches) {
+        walk_cell_watches_mut(self, node)
+    }
+    fn visit_chartsheet_mut(&mut self, node: &mut Chartsheet) {
+        walk_chartsheet_mut(self, node)
+    }
+    fn visit_chartsheet_page_setup_mut(&mut self, node: &mut ChartsheetPageSetup) {
+        walk_chartsheet_page_setup_mut(self, node)
+    }
+    fn visit_chartsheet_properties_mut(&mut self, node: &mut ChartsheetProperties) {
+        walk_chartsheet_properties_mut(self, node)
+    }
+    fn visit_chartsheet_protection_mut(&mut self, _node: &mut ChartsheetProtection) {}
+    fn visit_chartsheet_view_mut(&mut self, node: &mut ChartsheetView) {
+        walk_chartsheet_view_mut(self, node)
+    }
+    fn visit_chartsheet_views_mut(&mut self, node: &mut ChartsheetViews) {
+        walk_chartsheet_views_mut(self, node)
+    }
+    fn visit_col_fields_mut(&mut self, node: &mut ColFields) {
+        walk_col_fields_mut(self, node)
+    }
+    fn visit_color_mut(&mut self, _node: &mut Color) {}
+    fn visit_color_filter_mut(&mut self, _node: &mut ColorFilter) {}
+    fn visit_color_scale_mut(&mut self, node: &mut ColorScale) {
+        walk_color_scale_mut(self, node)
+    }
+    fn visit_colors_mut(&mut self, node: &mut Colors) {
+        walk_colors_mut(self, node)
+    }
+    fn visit_column_mut(&mut self, _node: &mut Column) {}
+    fn visit_columns_mut(&mut self, node: &mut Columns) {
+        walk_columns_mut(self, node)
+    }
+    fn visit_comment_mut(&mut self, node: &mut Comment) {
+        walk_comment_mut(self, node)
+    }
+    fn visit_comment_list_mut(&mut self, node: &mut CommentList) {
+        walk_comment_list_mut(self, node)
+    }
+    fn visit_comment_visibility_mut(&mut self, _node: &mut CommentVisibility) {}
+    fn visit_comments_mut(&mut self, node: &mut Comments) {
+        walk_comments_mut(self, node)
+    }
+    fn visit_conditional_format_value_mut(&mut self, node: &mut ConditionalFormatValue) {
+        walk_conditional_format_value_mut(self, node)
+    }
+    fn visit_conditional_formatting_mut(&mut self, node: &mut ConditionalFormatting) {
+        walk_conditional_formatting_mut(self, node)
+    }
+    fn visit_conditional_operator_mut(&mut self, _node: &mut ConditionalOperator) {}
+    fn visit_conditional_rule_mut(&mut self, node: &mut ConditionalRule) {
+        walk_conditional_rule_mut(self, node)
+    }
+    fn visit_conditional_type_mut(&mut self, _node: &mut ConditionalType) {}
+    fn visit_conditional_value_type_mut(&mut self, _node: &mut ConditionalValueType) {}
+    fn visit_connection_mut(&mut self, node: &mut Connection) {
+        walk_connection_mut(self, node)
+    }
+    fn visit_connections_mut(&mut self, node: &mut Connections) {
+        walk_connections_mut(self, node)
+    }
+    fn visit_consolidation_mut(&mut self, node: &mut Consolidation) {
+        walk_consolidation_mut(self, node)
+    }
+    fn visit_control_mut(&mut self, node: &mut Control) {
+        walk_control_mut(self, node)
+    }
+    fn visit_controls_mut(&mut self, node: &mut Controls) {
+        walk_controls_mut(self, node)
+    }
+    fn visit_custom_chartsheet_view_mut(&mut self, node: &mut CustomChartsheetView) {
+        walk_custom_chartsheet_view_mut(self, node)
+    }
+    fn visit_custom_chartsheet_views_mut(&mut self, node: &mut CustomChartsheetViews) {
+        walk_custom_chartsheet_views_mut(self, node)
+    }
+    fn visit_custom_filter_mut(&mut self, node: &mut CustomFilter) {
+        walk_custom_filter_mut(self, node)
+    }
+    fn visit_custom_filters_mut(&mut self, node: &mut CustomFilters) {
+        walk_custom_filters_mut(self, node)
+    }
+    fn visit_custom_sheet_view_mut(&mut self, node: &mut CustomSheetView) {
+        walk_custom_sheet_view_mut(self, node)
+    }
+    fn visit_custom_sheet_views_mut(&mut self, node: &mut CustomSheetViews) {
+        walk_custom_sheet_views_mut(self, node)
+    }
+    fn visit_custom_workbook_view_mut(&mut self, node: &mut CustomWorkbookView) {
+        walk_custom_workbook_view_mut(self, node)
+    }
+    fn visit_custom_workbook_views_mut(&mut self, node: &mut CustomWorkbookViews) {
+        walk_custom_workbook_views_mut(self, node)
+    }
+    fn visit_data_bar_mut(&mut self, node: &mut DataBar) {
+        walk_data_bar_mut(self, node)
+    }
+    fn visit_data_binding_mut(&mut self, _node: &mut DataBinding) {}
+    fn visit_data_field_mut(&mut self, node: &mut DataField) {
+        walk_data_field_mut(self, node)
+    }
+    fn visit_data_fields_mut(&mut self, node: &mut DataFields) {
+        walk_data_fields_mut(self, node)
+    }
+    fn visit_data_tables_mut(&mut self, node: &mut DataTables) {
+        walk_data_tables_mut(self, node)
+    }
+    fn visit_data_validation_mut(&mut self, node: &mut DataValidation) {
+        walk_data_validation_mut(self, node)
+    }
+    fn visit_data_validations_mut(&mut self, node: &mut DataValidations) {
+        walk_data_validations_mut(self, node)
+    }
+    fn visit_database_properties_mut(&mut self, _node: &mut DatabaseProperties) {}
+    fn visit_date_group_item_mut(&mut self, node: &mut DateGroupItem) {
+        walk_date_group_item_mut(self, node)
+    }
+    fn visit_dde_item_mut(&mut self, node: &mut DdeItem) {
+        walk_dde_item_mut(self, node)
+    }
+    fn visit_dde_items_mut(&mut self, node: &mut DdeItems) {
+        walk_dde_items_mut(self, node)
+    }
+    fn visit_dde_link_mut(&mut self, node: &mut DdeLink) {
+        walk_dde_link_mut(self, node)
+    }
+    fn visit_defined_name_mut(&mut self, node: &mut DefinedName) {
+        walk_defined_name_mut(self, node)
+    }
+    fn visit_defined_names_mut(&mut self, node: &mut DefinedNames) {
+        walk_defined_names_mut(self, node)
+    }
+    fn visit_differential_format_mut(&mut self, node: &mut DifferentialFormat) {
+        walk_differential_format_mut(self, node)
+    }
+    fn visit_differential_formats_mut(&mut self, node: &mut DifferentialFormats) {
+        walk_differential_formats_mut(self, node)
+    }
+    fn visit_drawing_mut(&mut self, _node: &mut Drawing) {}
+    fn visit_drawing_header_footer_mut(&mut self, _node: &mut DrawingHeaderFooter) {}
+    fn visit_dynamic_filter_mut(&mut self, node: &mut DynamicFilter) {
+        walk_dynamic_filter_mut(self, node)
+    }
+    fn visit_dynamic_filter_type_mut(&mut self, _node: &mut DynamicFilterType) {}
+    fn visit_eg_extension_list_mut(&mut self, node: &mut EGExtensionList) {
+        walk_eg_extension_list_mut(self, node)
+    }
+    fn visit_extension_mut(&mut self, _node: &mut Extension) {}
+    fn visit_extension_list_mut(&mut self, node: &mut ExtensionList) {
+        walk_extension_list_mut(self, node)
+    }
+    fn visit_external_book_mut(&mut self, node: &mut ExternalBook) {
+        walk_external_book_mut(self, node)
+    }
+    fn visit_external_cell_mut(&mut self, node: &mut ExternalCell) {
+        walk_external_cell_mut(self, node)
+    }
+    fn visit_external_link_mut(&mut self, node: &mut ExternalLink) {
+        walk_external_link_mut(self, node)
+    }
+    fn visit_external_reference_mut(&mut self, _node: &mut ExternalReference) {}
+    fn visit_external_references_mut(&mut self, node: &mut ExternalReferences) {
+        walk_external_references_mut(self, node)
+    }
+    fn visit_external_row_mut(&mut self, node: &mut ExternalRow) {
+        walk_external_row_mut(self, node)
+    }
+    fn visit_external_sheet_data_mut(&mut self, node: &mut ExternalSheetData) {
+        walk_external_sheet_data_mut(self, node)
+    }
+    fn visit_external_sheet_data_set_mut(&mut self, node: &mut ExternalSheetDataSet) {
+        walk_external_sheet_data_set_mut(self, node)
+    }
+    fn visit_field_group_mut(&mut self, node: &mut FieldGroup) {
+        walk_field_group_mut(self, node)
+    }
+    fn visit_file_recovery_properties_mut(&mut self, _node: &mut FileRecoveryProperties) {}
+    fn visit_file_sharing_mut(&mut self, _node: &mut FileSharing) {}
+    fn visit_file_version_mut(&mut self, _node: &mut FileVersion) {}
+    fn visit_fill_mut(&mut self, node: &mut Fill) {
+        walk_fill_mut(self, node)
+    }
+    fn visit_fills_mut(&mut self, node: &mut Fills) {
+        walk_fills_mut(self, node)
+    }
+    fn visit_filter_mut(&mut self, _node: &mut Filter) {}
+    fn visit_filter_column_mut(&mut self, node: &mut FilterColumn) {
+        walk_filter_column_mut(self, node)
+    }
+    fn visit_filter_operator_mut(&mut self, _node: &mut FilterOperator) {}
+    fn visit_filters_mut(&mut self, node: &mut Filters) {
+        walk_filters_mut(self, node)
+    }
+    fn visit_font_mut(&mut self, node: &mut Font) {
+        walk_font_mut(self, node)
+    }
+    fn visit_font_family_mut(&mut self, _node: &mut FontFamily) {}
+    fn visit_font_name_mut(&mut self, _node: &mut FontName) {}
+    fn visit_font_scheme_mut(&mut self, _node: &mut FontScheme) {}
+    fn visit_font_scheme_property_mut(&mut self, node: &mut FontSchemeProperty) {
+        walk_font_scheme_property_mut(self, node)
+    }
+    fn visit_font_size_mut(&mut self, _node: &mut FontSize) {}
+    fn visit_fonts_mut(&mut self, node: &mut Fonts) {
+        walk_fonts_mut(self, node)
+    }
+    fn visit_format_mut(&mut self, node: &mut Format) {
+        walk_format_mut(self, node)
+    }
+    fn visit_formula_type_mut(&mut self, _node: &mut FormulaType) {}
+    fn visit_gradient_fill_mut(&mut self, node: &mut GradientFill) {
+        walk_gradient_fill_mut(self, node)
+    }
+    fn visit_gradient_stop_mut(&mut self, node: &mut GradientStop) {
+        walk_gradient_stop_mut(self, node)
+    }
+    fn visit_gradient_type_mut(&mut self, _node: &mut GradientType) {}
+    fn visit_group_items_mut(&mut self, node: &mut GroupItems) {
+        walk_group_items_mut(self, node)
+    }
+    fn visit_header_footer_mut(&mut self, node: &mut HeaderFooter) {
+        walk_header_footer_mut(self, node)
+    }
+    fn visit_horizontal_alignment_mut(&mut self, _node: &mut HorizontalAlignment) {}
+    fn visit_hyperlink_mut(&mut self, _node: &mut Hyperlink) {}
+    fn visit_hyperlinks_mut(&mut self, node: &mut Hyperlinks) {
+        walk_hyperlinks_mut(self, node)
+    }
+    fn visit_icon_filter_mut(&mut self, node: &mut IconFilter) {
+        walk_icon_filter_mut(self, node)
+    }
+    fn visit_icon_set_mut(&mut self, node: &mut IconSet) {
+        walk_icon_set_mut(self, node)
+    }
+    fn visit_icon_set_type_mut(&mut self, _node: &mut IconSetType) {}
+    fn visit_ignored_error_mut(&mut self, _node: &mut IgnoredError) {}
+    fn visit_ignored_errors_mut(&mut self, node: &mut IgnoredErrors) {
+        walk_ignored_errors_mut(self, node)
+    }
+    fn visit_indexed_colors_mut(&mut self, node: &mut IndexedColors) {
+        walk_indexed_colors_mut(self, node)
+    }
+    fn visit_input_cells_mut(&mut self, _node: &mut InputCells) {}
+    fn visit_int_property_mut(&mut self, _node: &mut IntProperty) {}
+    fn visit_legacy_drawing_mut(&mut self, _node: &mut LegacyDrawing) {}
+    fn visit_map_info_mut(&mut self, node: &mut MapInfo) {
+        walk_map_info_mut(self, node)
+    }
+    fn visit_merged_cell_mut(&mut self, _node: &mut MergedCell) {}
+    fn visit_merged_cells_mut(&mut self, node: &mut MergedCells) {
+        walk_merged_cells_mut(self, node)
+    }
+    fn visit_metadata_mut(&mut self, node: &mut Metadata) {
+        walk_metadata_mut(self, node)
+    }
+    fn visit_metadata_block_mut(&mut self, node: &mut MetadataBlock) {
+        walk_metadata_block_mut(self, node)
+    }
+    fn visit_metadata_blocks_mut(&mut self, node: &mut MetadataBlocks) {
+        walk_metadata_blocks_mut(self, node)
+    }
+    fn visit_metadata_record_mut(&mut self, _node: &mut MetadataRecord) {}
+    fn visit_metadata_strings_mut(&mut self, node: &mut MetadataStrings) {
+        walk_metadata_strings_mut(self, node)
+    }
+    fn visit_metadata_type_mut(&mut self, _node: &mut MetadataType) {}
+    fn visit_metadata_types_mut(&mut self, node: &mut MetadataTypes) {
+        walk_metadata_types_mut(self, node)
+    }
+    fn visit_most_recent_colors_mut(&mut self, node: &mut MostRecentColors) {
+        walk_most_recent_colors_mut(self, node)
+    }
+    fn visit_number_format_mut(&mut self, _node: &mut NumberFormat) {}
+    fn visit_number_formats_mut(&mut self, node: &mut NumberFormats) {
+        walk_number_formats_mut(self, node)
+    }
+    fn visit_object_anchor_mut(&mut self, _node: &mut ObjectAnchor) {}
+    fn visit_object_properties_mut(&mut self, node: &mut ObjectProperties) {
+        walk_object_properties_mut(self, node)
+    }
+    fn visit_object_visibility_mut(&mut self, _node: &mut ObjectVisibility) {}
+    fn visit_olap_properties_mut(&mut self, _node: &mut OlapProperties) {}
+    fn visit_ole_item_mut(&mut self, _node: &mut OleItem) {}
+    fn visit_ole_items_mut(&mut self, node: &mut OleItems) {
+        walk_ole_items_mut(self, node)
+    }
+    fn visit_ole_link_mut(&mut self, node: &mut OleLink) {
+        walk_ole_link_mut(self, node)
+    }
+    fn visit_ole_object_mut(&mut self, node: &mut OleObject) {
+        walk_ole_object_mut(self, node)
+    }
+    fn visit_ole_objects_mut(&mut self, node: &mut OleObjects) {
+        walk_ole_objects_mut(self, node)
+    }
+    fn visit_outline_properties_mut(&mut self, _node: &mut OutlineProperties) {}
+    fn visit_page_break_mut(&mut self, _node: &mut PageBreak) {}
+    fn visit_page_breaks_mut(&mut self, node: &mut PageBreaks) {
+        walk_page_breaks_mut(self, node)
+    }
+    fn visit_page_field_mut(&mut self, node: &mut PageField) {
+        walk_page_field_mut(self, node)
+    }
+    fn visit_page_fields_mut(&mut self, node: &mut PageFields) {
+        walk_page_fields_mut(self, node)
+    }
+    fn visit_page_margins_mut(&mut self, _node: &mut PageMargins) {}
+    fn visit_page_setup_mut(&mut self, node: &mut PageSetup) {
+        walk_page_setup_mut(self, node)
+    }
+    fn visit_page_setup_properties_mut(&mut self, _node: &mut PageSetupProperties) {}
+    fn visit_pane_mut(&mut self, node: &mut Pane) {
+        walk_pane_mut(self, node)
+    }
+    fn visit_pane_state_mut(&mut self, _node: &mut PaneState) {}
+    fn visit_pane_type_mut(&mut self, _node: &mut PaneType) {}
+    fn visit_parameter_mut(&mut self, node: &mut Parameter) {
+        walk_parameter_mut(self, node)
+    }
+    fn visit_parameters_mut(&mut self, node: &mut Parameters) {
+        walk_parameters_mut(self, node)
+    }
+    fn visit_pattern_fill_mut(&mut self, node: &mut PatternFill) {
+        walk_pattern_fill_mut(self, node)
+    }
+    fn visit_pattern_type_mut(&mut self, _node: &mut PatternType) {}
+    fn visit_phonetic_properties_mut(&mut self, node: &mut PhoneticProperties) {
+        walk_phonetic_properties_mut(self, node)
+    }
+    fn visit_phonetic_run_mut(&mut self, node: &mut PhoneticRun) {
+        walk_phonetic_run_mut(self, node)
+    }
+    fn visit_pivot_area_mut(&mut self, node: &mut PivotArea) {
+        walk_pivot_area_mut(self, node)
+    }
+    fn visit_pivot_areas_mut(&mut self, node: &mut PivotAreas) {
+        walk_pivot_areas_mut(self, node)
+    }
+    fn visit_pivot_cache_definition_mut(&mut self, node: &mut PivotCacheDefinition) {
+        walk_pivot_cache_definition_mut(self, node)
+    }
+    fn visit_pivot_cache_records_mut(&mut self, node: &mut PivotCacheRecords) {
+        walk_pivot_cache_records_mut(self, node)
+    }
+    fn visit_pivot_caches_mut(&mut self, node: &mut PivotCaches) {
+        walk_pivot_caches_mut(self, node)
+    }
+    fn visit_pivot_field_mut(&mut self, node: &mut PivotField) {
+        walk_pivot_field_mut(self, node)
+    }
+    fn visit_pivot_fields_mut(&mut self, node: &mut PivotFields) {
+        walk_pivot_fields_mut(self, node)
+    }
+    fn visit_pivot_filter_mut(&mut self, node: &mut PivotFilter) {
+        walk_pivot_filter_mut(self, node)
+    }
+    fn visit_pivot_filters_mut(&mut self, node: &mut PivotFilters) {
+        walk_pivot_filters_mut(self, node)
+    }
+    fn visit_pivot_item_mut(&mut self, node: &mut PivotItem) {
+        walk_pivot_item_mut(self, node)
+    }
+    fn visit_pivot_items_mut(&mut self, node: &mut PivotItems) {
+        walk_pivot_items_mut(self, node)
+    }
+    fn visit_pivot_location_mut(&mut self, _node: &mut PivotLocation) {}
+    fn visit_print_options_mut(&mut self, _node: &mut PrintOptions) {}
+    fn visit_protected_range_mut(&mut self, _node: &mut ProtectedRange) {}
+    fn visit_protected_ranges_mut(&mut self, node: &mut ProtectedRanges) {
+        walk_protected_ranges_mut(self, node)
+    }
+    fn visit_query_table_mut(&mut self, node: &mut QueryTable) {
+        walk_query_table_mut(self, node)
+    }
+    fn visit_query_table_deleted_fields_mut(&mut self, node: &mut QueryTableDeletedFields) {
+        walk_query_table_deleted_fields_mut(self, node)
+    }
+    fn visit_query_table_field_mut(&mut self, node: &mut QueryTableField) {
+        walk_query_table_field_mut(self, node)
+    }
+    fn visit_query_table_fields_mut(&mut self, node: &mut QueryTableFields) {
+        walk_query_table_fields_mut(self, node)
+    }
+    fn visit_query_table_refresh_mut(&mut self, node: &mut QueryTableRefresh) {
+        walk_query_table_refresh_mut(self, node)
+    }
+    fn visit_reference_mode_mut(&mut self, _node: &mut ReferenceMode) {}
+    fn visit_reviewed_mut(&mut self, _node: &mut Reviewed) {}
+    fn visit_reviewed_revisions_mut(&mut self, node: &mut ReviewedRevisions) {
+        walk_reviewed_revisions_mut(self, node)
+    }
+    fn visit_revision_auto_formatting_mut(&mut self, _node: &mut RevisionAutoFormatting) {}
+    fn visit_revision_cell_change_mut(&mut self, node: &mut RevisionCellChange) {
+        walk_revision_cell_change_mut(self, node)
+    }
+    fn visit_revision_comment_mut(&mut self, node: &mut RevisionComment) {
+        walk_revision_comment_mut(self, node)
+    }
+    fn visit_revision_conflict_mut(&mut self, _node: &mut RevisionConflict) {}
+    fn visit_revision_custom_view_mut(&mut self, node: &mut RevisionCustomView) {
+        walk_revision_custom_view_mut(self, node)
+    }
+    fn visit_revision_defined_name_mut(&mut self, node: &mut RevisionDefinedName) {
+        walk_revision_defined_name_mut(self, node)
+    }
+    fn visit_revision_formatting_mut(&mut self, node: &mut RevisionFormatting) {
+        walk_revision_formatting_mut(self, node)
+    }
+    fn visit_revision_header_mut(&mut self, node: &mut RevisionHeader) {
+        walk_revision_header_mut(self, node)
+    }
+    fn visit_revision_headers_mut(&mut self, node: &mut RevisionHeaders) {
+        walk_revision_headers_mut(self, node)
+    }
+    fn visit_revision_insert_sheet_mut(&mut self, _node: &mut RevisionInsertSheet) {}
+    fn visit_revision_move_mut(&mut self, _node: &mut RevisionMove) {}
+    fn visit_revision_query_table_field_mut(&mut self, _node: &mut RevisionQueryTableField) {}
+    fn visit_revision_row_column_mut(&mut self, node: &mut RevisionRowColumn) {
+        walk_revision_row_column_mut(self, node)
+    }
+    fn visit_revision_sheet_rename_mut(&mut self, node: &mut RevisionSheetRename) {
+        walk_revision_sheet_rename_mut(self, node)
+    }
+    fn visit_revisions_mut(&mut self, node: &mut Revisions) {
+        walk_revisions_mut(self, node)
+    }
+    fn visit_rgb_color_mut(&mut self, _node: &mut RgbColor) {}
+    fn visit_rich_string_mut(&mut self, node: &mut RichString) {
+        walk_rich_string_mut(self, node)
+    }
+    fn visit_rich_text_element_mut(&mut self, node: &mut RichTextElement) {
+        walk_rich_text_element_mut(self, node)
+    }
+    fn visit_rich_text_run_properties_mut(&mut self, node: &mut RichTextRunProperties) {
+        walk_rich_text_run_properties_mut(self, node)
+    }
+    fn visit_row_mut(&mut self, node: &mut Row) {
+        walk_row_mut(self, node)
+    }
+    fn visit_row_fields_mut(&mut self, node: &mut RowFields) {
+        walk_row_fields_mut(self, node)
+    }
+    fn visit_st_alg_class_mut(&mut self, _node: &mut STAlgClass) {}
+    fn visit_st_alg_type_mut(&mut self, _node: &mut STAlgType) {}
+    fn visit_st_axis_mut(&mut self, _node: &mut STAxis) {}
+    fn visit_st_cell_comments_mut(&mut self, _node: &mut STCellComments) {}
+    fn visit_st_conformance_class_mut(&mut self, _node: &mut STConformanceClass) {}
+    fn visit_st_cred_method_mut(&mut self, _node: &mut STCredMethod) {}
+    fn visit_st_crypt_prov_mut(&mut self, _node: &mut STCryptProv) {}
+    fn visit_st_data_consolidate_function_mut(&mut self, _node: &mut STDataConsolidateFunction) {}
+    fn visit_st_data_validation_ime_mode_mut(&mut self, _node: &mut STDataValidationImeMode) {}
+    fn visit_st_date_time_grouping_mut(&mut self, _node: &mut STDateTimeGrouping) {}
+    fn visit_st_dde_value_type_mut(&mut self, _node: &mut STDdeValueType) {}
+    fn visit_st_dv_aspect_mut(&mut self, _node: &mut STDvAspect) {}
+    fn visit_st_external_connection_type_mut(&mut self, _node: &mut STExternalConnectionType) {}
+    fn visit_st_field_sort_type_mut(&mut self, _node: &mut STFieldSortType) {}
+    fn visit_st_file_type_mut(&mut self, _node: &mut STFileType) {}
+    fn visit_st_format_action_mut(&mut self, _node: &mut STFormatAction) {}
+    fn visit_st_formula_expression_mut(&mut self, _node: &mut STFormulaExpression) {}
+    fn visit_st_group_by_mut(&mut self, _node: &mut STGroupBy) {}
+    fn visit_st_grow_shrink_type_mut(&mut self, _node: &mut STGrowShrinkType) {}
+    fn visit_st_html_fmt_mut(&mut self, _node: &mut STHtmlFmt) {}
+    fn visit_st_item_type_mut(&mut self, _node: &mut STItemType) {}
+    fn visit_st_mdx_function_type_mut(&mut self, _node: &mut STMdxFunctionType) {}
+    fn visit_st_mdx_kpi_property_mut(&mut self, _node: &mut STMdxKPIProperty) {}
+    fn visit_st_mdx_set_order_mut(&mut self, _node: &mut STMdxSetOrder) {}
+    fn visit_st_ole_update_mut(&mut self, _node: &mut STOleUpdate) {}
+    fn visit_st_on_off1_mut(&mut self, _node: &mut STOnOff1) {}
+    fn visit_st_orientation_mut(&mut self, _node: &mut STOrientation) {}
+    fn visit_st_page_order_mut(&mut self, _node: &mut STPageOrder) {}
+    fn visit_st_parameter_type_mut(&mut self, _node: &mut STParameterType) {}
+    fn visit_st_phonetic_alignment_mut(&mut self, _node: &mut STPhoneticAlignment) {}
+    fn visit_st_phonetic_type_mut(&mut self, _node: &mut STPhoneticType) {}
+    fn visit_st_pivot_area_type_mut(&mut self, _node: &mut STPivotAreaType) {}
+    fn visit_st_pivot_filter_type_mut(&mut self, _node: &mut STPivotFilterType) {}
+    fn visit_st_print_error_mut(&mut self, _node: &mut STPrintError) {}
+    fn visit_st_qualifier_mut(&mut self, _node: &mut STQualifier) {}
+    fn visit_st_revision_action_mut(&mut self, _node: &mut STRevisionAction) {}
+    fn visit_st_rw_col_action_type_mut(&mut self, _node: &mut STRwColActionType) {}
+    fn visit_st_scope_mut(&mut self, _node: &mut STScope) {}
+    fn visit_st_show_data_as_mut(&mut self, _node: &mut STShowDataAs) {}
+    fn visit_st_smart_tag_show_mut(&mut self, _node: &mut STSmartTagShow) {}
+    fn visit_st_sort_type_mut(&mut self, _node: &mut STSortType) {}
+    fn visit_st_source_type_mut(&mut self, _node: &mut STSourceType) {}
+    fn visit_st_table_style_type_mut(&mut self, _node: &mut STTableStyleType) {}
+    fn visit_st_table_type_mut(&mut self, _node: &mut STTableType) {}
+    fn visit_st_target_screen_size_mut(&mut self, _node: &mut STTargetScreenSize) {}
+    fn visit_st_text_h_align_mut(&mut self, _node: &mut STTextHAlign) {}
+    fn visit_st_text_v_align_mut(&mut self, _node: &mut STTextVAlign) {}
+    fn visit_st_time_period_mut(&mut self, _node: &mut STTimePeriod) {}
+    fn visit_st_totals_row_function_mut(&mut self, _node: &mut STTotalsRowFunction) {}
+    fn visit_st_true_false_blank_mut(&mut self, _node: &mut STTrueFalseBlank) {}
+    fn visit_st_type_mut(&mut self, _node: &mut STType) {}
+    fn visit_st_vol_dep_type_mut(&mut self, _node: &mut STVolDepType) {}
+    fn visit_st_vol_value_type_mut(&mut self, _node: &mut STVolValueType) {}
+    fn visit_st_web_source_type_mut(&mut self, _node: &mut STWebSourceType) {}
+    fn visit_stx_align_mut(&mut self, _node: &mut STXAlign) {}
+    fn visit_sty_align_mut(&mut self, _node: &mut STYAlign) {}
+    fn visit_scenario_mut(&mut self, node: &mut Scenario) {
+        walk_scenario_mut(self, node)
+    }
+    fn visit_scenarios_mut(&mut self, node: &mut Scenarios) {
+        walk_scenarios_mut(self, node)
+    }
+    fn visit_selection_mut(&mut self, node: &mut Selection) {
+        walk_selection_mut(self, node)
+    }
+    fn visit_shared_items_mut(&mut self, node: &mut SharedItems) {
+        walk_shared_items_mut(self, node)
+    }
+    fn visit_shared_strings_mut(&mut self, node: &mut SharedStrings) {
+        walk_shared_strings_mut(self, node)
+    }
+    fn visit_shared_user_mut(&mut self, node: &mut SharedUser) {
+        walk_shared_user_mut(self, node)
+    }
+    fn visit_sheet_mut(&mut self, node: &mut Sheet) {
+        walk_sheet_mut(self, node)
+    }
+    fn visit_sheet_background_picture_mut(&mut self, _node: &mut SheetBackgroundPicture) {}
+    fn visit_sheet_calc_properties_mut(&mut self, _node: &mut SheetCalcProperties) {}
+    fn visit_sheet_data_mut(&mut self, node: &mut SheetData) {
+        walk_sheet_data_mut(self, node)
+    }
+    fn visit_sheet_dimension_mut(&mut self, _node: &mut SheetDimension) {}
+    fn visit_sheet_format_mut(&mut self, _node: &mut SheetFormat) {}
+    fn visit_sheet_properties_mut(&mut self, node: &mut SheetProperties) {
+        walk_sheet_properties_mut(self, node)
+    }
+    fn visit_sheet_protection_mut(&mut self, _node: &mut SheetProtection) {}
+    fn visit_sheet_state_mut(&mut self, _node: &mut SheetState) {}
+    fn visit_sheet_view_mut(&mut self, node: &mut SheetView) {
+        walk_sheet_view_mut(self, node)
+    }
+    fn visit_sheet_view_type_mut(&mut self, _node: &mut SheetViewType) {}
+    fn visit_sheet_views_mut(&mut self, node: &mut SheetViews) {
+        walk_sheet_views_mut(self, node)
+    }
+    fn visit_sheets_mut(&mut self, node: &mut Sheets) {
+        walk_sheets_mut(self, node)
+    }
+    fn visit_single_xml_cell_mut(&mut self, node: &mut SingleXmlCell) {
+        walk_single_xml_cell_mut(self, node)
+    }
+    fn visit_single_xml_cells_mut(&mut self, node: &mut SingleXmlCells) {
+        walk_single_xml_cells_mut(self, node)
+    }
+    fn visit_smart_tags_mut(&mut self, node: &mut SmartTags) {
+        walk_smart_tags_mut(self, node)
+    }
+    fn visit_sml_ag_auto_format_mut(&mut self, _node: &mut SmlAGAutoFormat) {}
+    fn visit_sml_ag_rev_data_mut(&mut self, _node: &mut SmlAGRevData) {}
+    fn visit_sort_by_mut(&mut self, _node: &mut SortBy) {}
+    fn visit_sort_condition_mut(&mut self, node: &mut SortCondition) {
+        walk_sort_condition_mut(self, node)
+    }
+    fn visit_sort_method_mut(&mut self, _node: &mut SortMethod) {}
+    fn visit_sort_state_mut(&mut self, node: &mut SortState) {
+        walk_sort_state_mut(self, node)
+    }
+    fn visit_stylesheet_mut(&mut self, node: &mut Stylesheet) {
+        walk_stylesheet_mut(self, node)
+    }
+    fn visit_table_mut(&mut self, node: &mut Table) {
+        walk_table_mut(self, node)
+    }
+    fn visit_table_column_mut(&mut self, node: &mut TableColumn) {
+        walk_table_column_mut(self, node)
+    }
+    fn visit_table_columns_mut(&mut self, node: &mut TableColumns) {
+        walk_table_columns_mut(self, node)
+    }
+    fn visit_table_formula_mut(&mut self, node: &mut TableFormula) {
+        walk_table_formula_mut(self, node)
+    }
+    fn visit_table_missing_mut(&mut self, _node: &mut TableMissing) {}
+    fn visit_table_part_mut(&mut self, _node: &mut TablePart) {}
+    fn visit_table_parts_mut(&mut self, node: &mut TableParts) {
+        walk_table_parts_mut(self, node)
+    }
+    fn visit_table_style_mut(&mut self, node: &mut TableStyle) {
+        walk_table_style_mut(self, node)
+    }
+    fn visit_table_style_element_mut(&mut self, node: &mut TableStyleElement) {
+        walk_table_style_element_mut(self, node)
+    }
+    fn visit_table_style_info_mut(&mut self, _node: &mut TableStyleInfo) {}
+    fn visit_table_styles_mut(&mut self, node: &mut TableStyles) {
+        walk_table_styles_mut(self, node)
+    }
+    fn visit_text_field_mut(&mut self, node: &mut TextField) {
+        walk_text_field_mut(self, node)
+    }
+    fn visit_text_fields_mut(&mut self, node: &mut TextFields) {
+        walk_text_fields_mut(self, node)
+    }
+    fn visit_text_import_properties_mut(&mut self, node: &mut TextImportProperties) {
+        walk_text_import_properties_mut(self, node)
+    }
+    fn visit_top10_filter_mut(&mut self, _node: &mut Top10Filter) {}
+    fn visit_true_false_mut(&mut self, _node: &mut TrueFalse) {}
+    fn visit_underline_property_mut(&mut self, node: &mut UnderlineProperty) {
+        walk_underline_property_mut(self, node)
+    }
+    fn visit_underline_style_mut(&mut self, _node: &mut UnderlineStyle) {}
+    fn visit_undo_info_mut(&mut self, node: &mut UndoInfo) {
+        walk_undo_info_mut(self, node)
+    }
+    fn visit_update_links_mut(&mut self, _node: &mut UpdateLinks) {}
+    fn visit_users_mut(&mut self, node: &mut Users) {
+        walk_users_mut(self, node)
+    }
+    fn visit_validation_error_style_mut(&mut self, _node: &mut ValidationErrorStyle) {}
+    fn visit_validation_operator_mut(&mut self, _node: &mut ValidationOperator) {}
+    fn visit_validation_type_mut(&mut self, _node: &mut ValidationType) {}
+    fn visit_vertical_align_font_property_mut(&mut self, node: &mut VerticalAlignFontProperty) {
+        walk_vertical_align_font_property_mut(self, node)
+    }
+    fn visit_vertical_align_run_mut(&mut self, _node: &mut VerticalAlignRun) {}
+    fn visit_vertical_alignment_mut(&mut self, _node: &mut VerticalAlignment) {}
+    fn visit_visibility_mut(&mut self, _node: &mut Visibility) {}
+    fn visit_web_publish_item_mut(&mut self, node: &mut WebPublishItem) {
+        walk_web_publish_item_mut(self, node)
+    }
+    fn visit_web_publish_items_mut(&mut self, node: &mut WebPublishItems) {
+        walk_web_publish_items_mut(self, node)
+    }
+    fn visit_web_publishing_mut(&mut self, node: &mut WebPublishing) {
+        walk_web_publishing_mut(self, node)
+    }
+    fn visit_web_query_properties_mut(&mut self, node: &mut WebQueryProperties) {
+        walk_web_query_properties_mut(self, node)
+    }
+    fn visit_workbook_mut(&mut self, node: &mut Workbook) {
+        walk_workbook_mut(self, node)
+    }
+    fn visit_workbook_properties_mut(&mut self, node: &mut WorkbookProperties) {
+        walk_workbook_properties_mut(self, node)
+    }
+    fn visit_workbook_protection_mut(&mut self, _node: &mut WorkbookProtection) {}
+    fn visit_worksheet_mut(&mut self, node: &mut Worksheet) {
+        walk_worksheet_mut(self, node)
+    }
+    fn visit_worksheet_source_mut(&mut self, _node: &mut WorksheetSource) {}
+    fn visit_xml_cell_properties_mut(&mut self, node: &mut XmlCellProperties) {
+        walk_xml_cell_properties_mut(self, node)
+    }
+    fn visit_xml_column_properties_mut(&mut self, node: &mut XmlColumnProperties) {
+        walk_xml_column_properties_mut(self, node)
+    }
+    fn visit_xml_map_mut(&mut self, node: &mut XmlMap) {
+        walk_xml_map_mut(self, node)
+    }
+    fn visit_xml_properties_mut(&mut self, node: &mut XmlProperties) {
+        walk_xml_properties_mut(self, node)
+    }
+    fn visit_xml_schema_mut(&mut self, node: &mut XmlSchema) {
+        walk_xml_schema_mut(self, node)
+    }
+    fn visit_raw_xml_node_mut(&mut self, node: &mut RawXmlNode) {
+        walk_raw_xml_node_mut(self, node)
+    }
+    fn visit_raw_xml_element_mut(&mut self, node: &mut RawXmlElement) {
+        walk_raw_xml_element_mut(self, node)
+    }
+}
+pub fn walk_raw_xml_node<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast RawXmlNode) {
+    if let RawXmlNode::Element(element) = node {
+        Visit::visit_raw_xml_element(v, element);
+    }
+}
+pub fn walk_raw_xml_node_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut RawXmlNode) {
+    if let RawXmlNode::Element(element) = node {
+        VisitMut::visit_raw_xml_element_mut(v, element);
+    }
+}
+pub fn walk_raw_xml_element<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast RawXmlElement) {
+    for child in &node.children {
+        Visit::visit_raw_xml_node(v, child);
+    }
+}
+pub fn walk_raw_xml_element_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut RawXmlElement) {
+    for child in &mut node.children {
+        VisitMut::visit_raw_xml_node_mut(v, child);
+    }
+}
+
+pub fn walk_authors<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Authors) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_authors_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Authors) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_auto_filter<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast AutoFilter) {
+    #[cfg(feature = "sml-filtering")]
+    {
+        for it0 in &node.filter_column {
+            Visit::visit_filter_column(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-filtering")]
+    {
+        if let Some(it0) = &node.sort_state {
+            Visit::visit_sort_state(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &node.extension_list {
+            Visit::visit_extension_list(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_auto_filter_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut AutoFilter) {
+    #[cfg(feature = "sml-filtering")]
+    {
+        for it0 in &mut node.filter_column {
+            VisitMut::visit_filter_column_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-filtering")]
+    {
+        if let Some(it0) = &mut node.sort_state {
+            VisitMut::visit_sort_state_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &mut node.extension_list {
+            VisitMut::visit_extension_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_book_view<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast BookView) {
+    #[cfg(feature = "sml-structure")]
+    {
+        if let Some(it0) = &node.visibility {
+            Visit::visit_visibility(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &node.extension_list {
+            Visit::visit_extension_list(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_book_view_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut BookView) {
+    #[cfg(feature = "sml-structure")]
+    {
+        if let Some(it0) = &mut node.visibility {
+            VisitMut::visit_visibility_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &mut node.extension_list {
+            VisitMut::visit_extension_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_book_views<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast BookViews) {
+    for it0 in &node.workbook_view {
+        Visit::visit_book_view(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_book_views_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut BookViews) {
+    for it0 in &mut node.workbook_view {
+        VisitMut::visit_book_view_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_border<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Border) {
+    if let Some(it0) = &node.start {
+        Visit::visit_border_properties(v, it0);
+    }
+    if let Some(it0) = &node.end {
+        Visit::visit_border_properties(v, it0);
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.left {
+            Visit::visit_border_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.right {
+            Visit::visit_border_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.top {
+            Visit::visit_border_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.bottom {
+            Visit::visit_border_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.diagonal {
+            Visit::visit_border_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.vertical {
+            Visit::visit_border_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.horizontal {
+            Visit::visit_border_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_border_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Border) {
+    if let Some(it0) = &mut node.start {
+        VisitMut::visit_border_properties_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.end {
+        VisitMut::visit_border_properties_mut(v, it0);
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.left {
+            VisitMut::visit_border_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.right {
+            VisitMut::visit_border_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.top {
+            VisitMut::visit_border_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.bottom {
+            VisitMut::visit_border_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.diagonal {
+            VisitMut::visit_border_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.vertical {
+            VisitMut::visit_border_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.horizontal {
+            VisitMut::visit_border_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_border_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast BorderProperties,
+) {
+    if let Some(it0) = &node.style {
+        Visit::visit_border_style(v, it0);
+    }
+    if let Some(it0) = &node.color {
+        Visit::visit_color(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_border_properties_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut BorderProperties) {
+    if let Some(it0) = &mut node.style {
+        VisitMut::visit_border_style_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.color {
+        VisitMut::visit_color_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_borders<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Borders) {
+    for it0 in &node.border {
+        Visit::visit_border(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_borders_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Borders) {
+    for it0 in &mut node.border {
+        VisitMut::visit_border_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_boolean<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTBoolean) {
+    for it0 in &node.x {
+        Visit::visit_ctx(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_boolean_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTBoolean) {
+    for it0 in &mut node.x {
+        VisitMut::visit_ctx_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_cache_hierarchies<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTCacheHierarchies,
+) {
+    for it0 in &node.cache_hierarchy {
+        Visit::visit_ct_cache_hierarchy(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_cache_hierarchies_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTCacheHierarchies,
+) {
+    for it0 in &mut node.cache_hierarchy {
+        VisitMut::visit_ct_cache_hierarchy_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_cache_hierarchy<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTCacheHierarchy,
+) {
+    if let Some(it0) = &node.fields_usage {
+        Visit::visit_ct_fields_usage(v, it0);
+    }
+    if let Some(it0) = &node.group_levels {
+        Visit::visit_ct_group_levels(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_cache_hierarchy_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTCacheHierarchy) {
+    if let Some(it0) = &mut node.fields_usage {
+        VisitMut::visit_ct_fields_usage_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.group_levels {
+        VisitMut::visit_ct_group_levels_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_calculated_item<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTCalculatedItem,
+) {
+    Visit::visit_pivot_area(v, &node.pivot_area);
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_calculated_item_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTCalculatedItem) {
+    VisitMut::visit_pivot_area_mut(v, &mut node.pivot_area);
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_calculated_items<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTCalculatedItems,
+) {
+    for it0 in &node.calculated_item {
+        Visit::visit_ct_calculated_item(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_calculated_items_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTCalculatedItems) {
+    for it0 in &mut node.calculated_item {
+        VisitMut::visit_ct_calculated_item_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_calculated_member<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTCalculatedMember,
+) {
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_calculated_member_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTCalculatedMember,
+) {
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_calculated_members<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTCalculatedMembers,
+) {
+    for it0 in &node.calculated_member {
+        Visit::visit_ct_calculated_member(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_calculated_members_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTCalculatedMembers,
+) {
+    for it0 in &mut node.calculated_member {
+        VisitMut::visit_ct_calculated_member_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_chart_format<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTChartFormat) {
+    Visit::visit_pivot_area(v, &node.pivot_area);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_chart_format_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTChartFormat) {
+    VisitMut::visit_pivot_area_mut(v, &mut node.pivot_area);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_chart_formats<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTChartFormats) {
+    for it0 in &node.chart_format {
+        Visit::visit_ct_chart_format(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_chart_formats_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTChartFormats) {
+    for it0 in &mut node.chart_format {
+        VisitMut::visit_ct_chart_format_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_col_hierarchies_usage<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTColHierarchiesUsage,
+) {
+    for it0 in &node.col_hierarchy_usage {
+        Visit::visit_ct_hierarchy_usage(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_col_hierarchies_usage_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTColHierarchiesUsage,
+) {
+    for it0 in &mut node.col_hierarchy_usage {
+        VisitMut::visit_ct_hierarchy_usage_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_col_items<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTColItems) {
+    for it0 in &node.i {
+        Visit::visit_cti(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_col_items_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTColItems) {
+    for it0 in &mut node.i {
+        VisitMut::visit_cti_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_comment_pr<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTCommentPr) {
+    if let Some(it0) = &node.text_h_align {
+        Visit::visit_st_text_h_align(v, it0);
+    }
+    if let Some(it0) = &node.text_v_align {
+        Visit::visit_st_text_v_align(v, it0);
+    }
+    Visit::visit_object_anchor(v, &node.anchor);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_comment_pr_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTCommentPr) {
+    if let Some(it0) = &mut node.text_h_align {
+        VisitMut::visit_st_text_h_align_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.text_v_align {
+        VisitMut::visit_st_text_v_align_mut(v, it0);
+    }
+    VisitMut::visit_object_anchor_mut(v, &mut node.anchor);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_conditional_format<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTConditionalFormat,
+) {
+    if let Some(it0) = &node.scope {
+        Visit::visit_st_scope(v, it0);
+    }
+    if let Some(it0) = &node.r#type {
+        Visit::visit_st_type(v, it0);
+    }
+    Visit::visit_pivot_areas(v, &node.pivot_areas);
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_conditional_format_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTConditionalFormat,
+) {
+    if let Some(it0) = &mut node.scope {
+        VisitMut::visit_st_scope_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.r#type {
+        VisitMut::visit_st_type_mut(v, it0);
+    }
+    VisitMut::visit_pivot_areas_mut(v, &mut node.pivot_areas);
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_conditional_formats<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTConditionalFormats,
+) {
+    for it0 in &node.conditional_format {
+        Visit::visit_ct_conditional_format(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_conditional_formats_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTConditionalFormats,
+) {
+    for it0 in &mut node.conditional_format {
+        VisitMut::visit_ct_conditional_format_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_control_pr<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTControlPr) {
+    Visit::visit_object_anchor(v, &node.anchor);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_control_pr_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTControlPr) {
+    VisitMut::visit_object_anchor_mut(v, &mut node.anchor);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_custom_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTCustomProperties,
+) {
+    for it0 in &node.custom_pr {
+        Visit::visit_ct_custom_property(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_custom_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTCustomProperties,
+) {
+    for it0 in &mut node.custom_pr {
+        VisitMut::visit_ct_custom_property_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_data_consolidate<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTDataConsolidate,
+) {
+    if let Some(it0) = &node.function {
+        Visit::visit_st_data_consolidate_function(v, it0);
+    }
+    if let Some(it0) = &node.data_refs {
+        Visit::visit_ct_data_refs(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_data_consolidate_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTDataConsolidate) {
+    if let Some(it0) = &mut node.function {
+        VisitMut::visit_st_data_consolidate_function_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.data_refs {
+        VisitMut::visit_ct_data_refs_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_data_refs<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTDataRefs) {
+    for it0 in &node.data_ref {
+        Visit::visit_ct_data_ref(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_data_refs_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTDataRefs) {
+    for it0 in &mut node.data_ref {
+        VisitMut::visit_ct_data_ref_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_date_time<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTDateTime) {
+    for it0 in &node.x {
+        Visit::visit_ctx(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_date_time_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTDateTime) {
+    for it0 in &mut node.x {
+        VisitMut::visit_ctx_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_dde_value<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTDdeValue) {
+    if let Some(it0) = &node.cell_type {
+        Visit::visit_st_dde_value_type(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_dde_value_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTDdeValue) {
+    if let Some(it0) = &mut node.cell_type {
+        VisitMut::visit_st_dde_value_type_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_dde_values<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTDdeValues) {
+    for it0 in &node.value {
+        Visit::visit_ct_dde_value(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_dde_values_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTDdeValues) {
+    for it0 in &mut node.value {
+        VisitMut::visit_ct_dde_value_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_dialogsheet<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTDialogsheet) {
+    if let Some(it0) = &node.sheet_properties {
+        Visit::visit_sheet_properties(v, it0);
+    }
+    if let Some(it0) = &node.sheet_views {
+        Visit::visit_sheet_views(v, it0);
+    }
+    if let Some(it0) = &node.sheet_format {
+        Visit::visit_sheet_format(v, it0);
+    }
+    if let Some(it0) = &node.sheet_protection {
+        Visit::visit_sheet_protection(v, it0);
+    }
+    if let Some(it0) = &node.custom_sheet_views {
+        Visit::visit_custom_sheet_views(v, it0);
+    }
+    if let Some(it0) = &node.print_options {
+        Visit::visit_print_options(v, it0);
+    }
+    if let Some(it0) = &node.page_margins {
+        Visit::visit_page_margins(v, it0);
+    }
+    if let Some(it0) = &node.page_setup {
+        Visit::visit_page_setup(v, it0);
+    }
+    if let Some(it0) = &node.header_footer {
+        Visit::visit_header_footer(v, it0);
+    }
+    if let Some(it0) = &node.drawing {
+        Visit::visit_drawing(v, it0);
+    }
+    if let Some(it0) = &node.legacy_drawing {
+        Visit::visit_legacy_drawing(v, it0);
+    }
+    if let Some(it0) = &node.legacy_drawing_h_f {
+        Visit::visit_legacy_drawing(v, it0);
+    }
+    if let Some(it0) = &node.drawing_h_f {
+        Visit::visit_drawing_header_footer(v, it0);
+    }
+    if let Some(it0) = &node.ole_objects {
+        Visit::visit_ole_objects(v, it0);
+    }
+    if let Some(it0) = &node.controls {
+        Visit::visit_controls(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_dialogsheet_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTDialogsheet) {
+    if let Some(it0) = &mut node.sheet_properties {
+        VisitMut::visit_sheet_properties_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.sheet_views {
+        VisitMut::visit_sheet_views_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.sheet_format {
+        VisitMut::visit_sheet_format_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.sheet_protection {
+        VisitMut::visit_sheet_protection_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.custom_sheet_views {
+        VisitMut::visit_custom_sheet_views_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.print_options {
+        VisitMut::visit_print_options_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.page_margins {
+        VisitMut::visit_page_margins_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.page_setup {
+        VisitMut::visit_page_setup_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.header_footer {
+        VisitMut::visit_header_footer_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.drawing {
+        VisitMut::visit_drawing_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.legacy_drawing {
+        VisitMut::visit_legacy_drawing_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.legacy_drawing_h_f {
+        VisitMut::visit_legacy_drawing_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.drawing_h_f {
+        VisitMut::visit_drawing_header_footer_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.ole_objects {
+        VisitMut::visit_ole_objects_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.controls {
+        VisitMut::visit_controls_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_dimensions<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTDimensions) {
+    for it0 in &node.dimension {
+        Visit::visit_ct_pivot_dimension(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_dimensions_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTDimensions) {
+    for it0 in &mut node.dimension {
+        VisitMut::visit_ct_pivot_dimension_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_discrete_pr<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTDiscretePr) {
+    for it0 in &node.x {
+        Visit::visit_ct_index(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_discrete_pr_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTDiscretePr) {
+    for it0 in &mut node.x {
+        VisitMut::visit_ct_index_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_error<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTError) {
+    if let Some(it0) = &node.tpls {
+        Visit::visit_ct_tuples(v, it0);
+    }
+    for it0 in &node.x {
+        Visit::visit_ctx(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_error_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTError) {
+    if let Some(it0) = &mut node.tpls {
+        VisitMut::visit_ct_tuples_mut(v, it0);
+    }
+    for it0 in &mut node.x {
+        VisitMut::visit_ctx_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_external_defined_names<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTExternalDefinedNames,
+) {
+    for it0 in &node.defined_name {
+        Visit::visit_ct_external_defined_name(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_external_defined_names_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTExternalDefinedNames,
+) {
+    for it0 in &mut node.defined_name {
+        VisitMut::visit_ct_external_defined_name_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_external_sheet_names<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTExternalSheetNames,
+) {
+    for it0 in &node.sheet_name {
+        Visit::visit_ct_external_sheet_name(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_external_sheet_names_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTExternalSheetNames,
+) {
+    for it0 in &mut node.sheet_name {
+        VisitMut::visit_ct_external_sheet_name_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_fields_usage<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTFieldsUsage) {
+    for it0 in &node.field_usage {
+        Visit::visit_ct_field_usage(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_fields_usage_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTFieldsUsage) {
+    for it0 in &mut node.field_usage {
+        VisitMut::visit_ct_field_usage_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_format<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTFormat) {
+    if let Some(it0) = &node.action {
+        Visit::visit_st_format_action(v, it0);
+    }
+    Visit::visit_pivot_area(v, &node.pivot_area);
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_format_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTFormat) {
+    if let Some(it0) = &mut node.action {
+        VisitMut::visit_st_format_action_mut(v, it0);
+    }
+    VisitMut::visit_pivot_area_mut(v, &mut node.pivot_area);
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_formats<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTFormats) {
+    for it0 in &node.format {
+        Visit::visit_ct_format(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_formats_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTFormats) {
+    for it0 in &mut node.format {
+        VisitMut::visit_ct_format_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_function_groups<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTFunctionGroups,
+) {
+    for it0 in &node.function_group {
+        Visit::visit_ct_function_group(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_function_groups_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTFunctionGroups) {
+    for it0 in &mut node.function_group {
+        VisitMut::visit_ct_function_group_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_future_metadata<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTFutureMetadata,
+) {
+    for it0 in &node.bk {
+        Visit::visit_ct_future_metadata_block(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_future_metadata_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTFutureMetadata) {
+    for it0 in &mut node.bk {
+        VisitMut::visit_ct_future_metadata_block_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_future_metadata_block<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTFutureMetadataBlock,
+) {
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_future_metadata_block_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTFutureMetadataBlock,
+) {
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_group_level<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTGroupLevel) {
+    if let Some(it0) = &node.groups {
+        Visit::visit_ct_groups(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_group_level_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTGroupLevel) {
+    if let Some(it0) = &mut node.groups {
+        VisitMut::visit_ct_groups_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_group_levels<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTGroupLevels) {
+    for it0 in &node.group_level {
+        Visit::visit_ct_group_level(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_group_levels_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTGroupLevels) {
+    for it0 in &mut node.group_level {
+        VisitMut::visit_ct_group_level_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_group_members<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTGroupMembers) {
+    for it0 in &node.group_member {
+        Visit::visit_ct_group_member(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_group_members_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTGroupMembers) {
+    for it0 in &mut node.group_member {
+        VisitMut::visit_ct_group_member_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_groups<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTGroups) {
+    for it0 in &node.group {
+        Visit::visit_ct_level_group(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_groups_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTGroups) {
+    for it0 in &mut node.group {
+        VisitMut::visit_ct_level_group_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cti<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTI) {
+    if let Some(it0) = &node.cell_type {
+        Visit::visit_st_item_type(v, it0);
+    }
+    for it0 in &node.x {
+        Visit::visit_ctx(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cti_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTI) {
+    if let Some(it0) = &mut node.cell_type {
+        VisitMut::visit_st_item_type_mut(v, it0);
+    }
+    for it0 in &mut node.x {
+        VisitMut::visit_ctx_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_level_group<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTLevelGroup) {
+    Visit::visit_ct_group_members(v, &node.group_members);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_level_group_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTLevelGroup) {
+    VisitMut::visit_ct_group_members_mut(v, &mut node.group_members);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_macrosheet<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTMacrosheet) {
+    if let Some(it0) = &node.sheet_properties {
+        Visit::visit_sheet_properties(v, it0);
+    }
+    if let Some(it0) = &node.dimension {
+        Visit::visit_sheet_dimension(v, it0);
+    }
+    if let Some(it0) = &node.sheet_views {
+        Visit::visit_sheet_views(v, it0);
+    }
+    if let Some(it0) = &node.sheet_format {
+        Visit::visit_sheet_format(v, it0);
+    }
+    for it0 in &node.cols {
+        Visit::visit_columns(v, it0);
+    }
+    Visit::visit_sheet_data(v, &node.sheet_data);
+    if let Some(it0) = &node.sheet_protection {
+        Visit::visit_sheet_protection(v, it0);
+    }
+    if let Some(it0) = &node.auto_filter {
+        Visit::visit_auto_filter(v, it0);
+    }
+    if let Some(it0) = &node.sort_state {
+        Visit::visit_sort_state(v, it0);
+    }
+    if let Some(it0) = &node.data_consolidate {
+        Visit::visit_ct_data_consolidate(v, it0);
+    }
+    if let Some(it0) = &node.custom_sheet_views {
+        Visit::visit_custom_sheet_views(v, it0);
+    }
+    if let Some(it0) = &node.phonetic_pr {
+        Visit::visit_phonetic_properties(v, it0);
+    }
+    for it0 in &node.conditional_formatting {
+        Visit::visit_conditional_formatting(v, it0);
+    }
+    if let Some(it0) = &node.print_options {
+        Visit::visit_print_options(v, it0);
+    }
+    if let Some(it0) = &node.page_margins {
+        Visit::visit_page_margins(v, it0);
+    }
+    if let Some(it0) = &node.page_setup {
+        Visit::visit_page_setup(v, it0);
+    }
+    if let Some(it0) = &node.header_footer {
+        Visit::visit_header_footer(v, it0);
+    }
+    if let Some(it0) = &node.row_breaks {
+        Visit::visit_page_breaks(v, it0);
+    }
+    if let Some(it0) = &node.col_breaks {
+        Visit::visit_page_breaks(v, it0);
+    }
+    if let Some(it0) = &node.custom_properties {
+        Visit::visit_ct_custom_properties(v, it0);
+    }
+    if let Some(it0) = &node.drawing {
+        Visit::visit_drawing(v, it0);
+    }
+    if let Some(it0) = &node.legacy_drawing {
+        Visit::visit_legacy_drawing(v, it0);
+    }
+    if let Some(it0) = &node.legacy_drawing_h_f {
+        Visit::visit_legacy_drawing(v, it0);
+    }
+    if let Some(it0) = &node.drawing_h_f {
+        Visit::visit_drawing_header_footer(v, it0);
+    }
+    if let Some(it0) = &node.picture {
+        Visit::visit_sheet_background_picture(v, it0);
+    }
+    if let Some(it0) = &node.ole_objects {
+        Visit::visit_ole_objects(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_macrosheet_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTMacrosheet) {
+    if let Some(it0) = &mut node.sheet_properties {
+        VisitMut::visit_sheet_properties_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.dimension {
+        VisitMut::visit_sheet_dimension_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.sheet_views {
+        VisitMut::visit_sheet_views_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.sheet_format {
+        VisitMut::visit_sheet_format_mut(v, it0);
+    }
+    for it0 in &mut node.cols {
+        VisitMut::visit_columns_mut(v, it0);
+    }
+    VisitMut::visit_sheet_data_mut(v, &mut node.sheet_data);
+    if let Some(it0) = &mut node.sheet_protection {
+        VisitMut::visit_sheet_protection_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.auto_filter {
+        VisitMut::visit_auto_filter_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.sort_state {
+        VisitMut::visit_sort_state_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.data_consolidate {
+        VisitMut::visit_ct_data_consolidate_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.custom_sheet_views {
+        VisitMut::visit_custom_sheet_views_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.phonetic_pr {
+        VisitMut::visit_phonetic_properties_mut(v, it0);
+    }
+    for it0 in &mut node.conditional_formatting {
+        VisitMut::visit_conditional_formatting_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.print_options {
+        VisitMut::visit_print_options_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.page_margins {
+        VisitMut::visit_page_margins_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.page_setup {
+        VisitMut::visit_page_setup_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.header_footer {
+        VisitMut::visit_header_footer_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.row_breaks {
+        VisitMut::visit_page_breaks_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.col_breaks {
+        VisitMut::visit_page_breaks_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.custom_properties {
+        VisitMut::visit_ct_custom_properties_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.drawing {
+        VisitMut::visit_drawing_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.legacy_drawing {
+        VisitMut::visit_legacy_drawing_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.legacy_drawing_h_f {
+        VisitMut::visit_legacy_drawing_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.drawing_h_f {
+        VisitMut::visit_drawing_header_footer_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.picture {
+        VisitMut::visit_sheet_background_picture_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.ole_objects {
+        VisitMut::visit_ole_objects_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_mdx<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTMdx) {
+    Visit::visit_st_mdx_function_type(v, &node.formula);
+    if let Some(it0) = &node.cell_type {
+        Visit::visit_ct_mdx_tuple(v, it0);
+    }
+    if let Some(it0) = &node.ms {
+        Visit::visit_ct_mdx_set(v, it0);
+    }
+    if let Some(it0) = &node.p {
+        Visit::visit_ct_mdx_memeber_prop(v, it0);
+    }
+    if let Some(it0) = &node.k {
+        Visit::visit_ct_mdx_kpi(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_mdx_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTMdx) {
+    VisitMut::visit_st_mdx_function_type_mut(v, &mut node.formula);
+    if let Some(it0) = &mut node.cell_type {
+        VisitMut::visit_ct_mdx_tuple_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.ms {
+        VisitMut::visit_ct_mdx_set_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.p {
+        VisitMut::visit_ct_mdx_memeber_prop_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.k {
+        VisitMut::visit_ct_mdx_kpi_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_mdx_kpi<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTMdxKPI) {
+    Visit::visit_st_mdx_kpi_property(v, &node.p);
+}
+
+pub fn walk_ct_mdx_kpi_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTMdxKPI) {
+    VisitMut::visit_st_mdx_kpi_property_mut(v, &mut node.p);
+}
+
+pub fn walk_ct_mdx_metadata<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTMdxMetadata) {
+    for it0 in &node.mdx {
+        Visit::visit_ct_mdx(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_mdx_metadata_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTMdxMetadata) {
+    for it0 in &mut node.mdx {
+        VisitMut::visit_ct_mdx_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_mdx_set<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTMdxSet) {
+    if let Some(it0) = &node.o {
+        Visit::visit_st_mdx_set_order(v, it0);
+    }
+    for it0 in &node.n {
+        Visit::visit_ct_metadata_string_index(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_mdx_set_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTMdxSet) {
+    if let Some(it0) = &mut node.o {
+        VisitMut::visit_st_mdx_set_order_mut(v, it0);
+    }
+    for it0 in &mut node.n {
+        VisitMut::visit_ct_metadata_string_index_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_mdx_tuple<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTMdxTuple) {
+    for it0 in &node.n {
+        Visit::visit_ct_metadata_string_index(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_mdx_tuple_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTMdxTuple) {
+    for it0 in &mut node.n {
+        VisitMut::visit_ct_metadata_string_index_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_measure_dimension_maps<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTMeasureDimensionMaps,
+) {
+    for it0 in &node.map {
+        Visit::visit_ct_measure_dimension_map(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_measure_dimension_maps_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTMeasureDimensionMaps,
+) {
+    for it0 in &mut node.map {
+        VisitMut::visit_ct_measure_dimension_map_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_measure_groups<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTMeasureGroups,
+) {
+    for it0 in &node.measure_group {
+        Visit::visit_ct_measure_group(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_measure_groups_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTMeasureGroups) {
+    for it0 in &mut node.measure_group {
+        VisitMut::visit_ct_measure_group_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_member_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTMemberProperties,
+) {
+    for it0 in &node.mp {
+        Visit::visit_ct_member_property(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_member_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTMemberProperties,
+) {
+    for it0 in &mut node.mp {
+        VisitMut::visit_ct_member_property_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_members<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTMembers) {
+    for it0 in &node.member {
+        Visit::visit_ct_member(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_members_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTMembers) {
+    for it0 in &mut node.member {
+        VisitMut::visit_ct_member_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_missing<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTMissing) {
+    for it0 in &node.tpls {
+        Visit::visit_ct_tuples(v, it0);
+    }
+    for it0 in &node.x {
+        Visit::visit_ctx(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_missing_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTMissing) {
+    for it0 in &mut node.tpls {
+        VisitMut::visit_ct_tuples_mut(v, it0);
+    }
+    for it0 in &mut node.x {
+        VisitMut::visit_ctx_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_number<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTNumber) {
+    for it0 in &node.tpls {
+        Visit::visit_ct_tuples(v, it0);
+    }
+    for it0 in &node.x {
+        Visit::visit_ctx(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_number_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTNumber) {
+    for it0 in &mut node.tpls {
+        VisitMut::visit_ct_tuples_mut(v, it0);
+    }
+    for it0 in &mut node.x {
+        VisitMut::visit_ctx_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ctpcdkp_is<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTPCDKPIs) {
+    for it0 in &node.kpi {
+        Visit::visit_ctpcdkpi(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ctpcdkp_is_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTPCDKPIs) {
+    for it0 in &mut node.kpi {
+        VisitMut::visit_ctpcdkpi_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ctpcdsc_page<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTPCDSCPage) {
+    for it0 in &node.page_item {
+        Visit::visit_ct_page_item(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ctpcdsc_page_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTPCDSCPage) {
+    for it0 in &mut node.page_item {
+        VisitMut::visit_ct_page_item_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ctpcdsdtc_entries<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTPCDSDTCEntries,
+) {
+    for it0 in &node.m {
+        Visit::visit_ct_missing(v, it0);
+    }
+    for it0 in &node.n {
+        Visit::visit_ct_number(v, it0);
+    }
+    for it0 in &node.e {
+        Visit::visit_ct_error(v, it0);
+    }
+    for it0 in &node.style_index {
+        Visit::visit_ct_string(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ctpcdsdtc_entries_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTPCDSDTCEntries) {
+    for it0 in &mut node.m {
+        VisitMut::visit_ct_missing_mut(v, it0);
+    }
+    for it0 in &mut node.n {
+        VisitMut::visit_ct_number_mut(v, it0);
+    }
+    for it0 in &mut node.e {
+        VisitMut::visit_ct_error_mut(v, it0);
+    }
+    for it0 in &mut node.style_index {
+        VisitMut::visit_ct_string_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_pages<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTPages) {
+    for it0 in &node.page {
+        Visit::visit_ctpcdsc_page(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_pages_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTPages) {
+    for it0 in &mut node.page {
+        VisitMut::visit_ctpcdsc_page_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_pivot_area_reference<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTPivotAreaReference,
+) {
+    for it0 in &node.x {
+        Visit::visit_ct_index(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_pivot_area_reference_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTPivotAreaReference,
+) {
+    for it0 in &mut node.x {
+        VisitMut::visit_ct_index_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_pivot_area_references<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTPivotAreaReferences,
+) {
+    for it0 in &node.reference {
+        Visit::visit_ct_pivot_area_reference(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_pivot_area_references_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTPivotAreaReferences,
+) {
+    for it0 in &mut node.reference {
+        VisitMut::visit_ct_pivot_area_reference_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_pivot_hierarchies<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTPivotHierarchies,
+) {
+    for it0 in &node.pivot_hierarchy {
+        Visit::visit_ct_pivot_hierarchy(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_pivot_hierarchies_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTPivotHierarchies,
+) {
+    for it0 in &mut node.pivot_hierarchy {
+        VisitMut::visit_ct_pivot_hierarchy_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_pivot_hierarchy<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTPivotHierarchy,
+) {
+    if let Some(it0) = &node.mps {
+        Visit::visit_ct_member_properties(v, it0);
+    }
+    for it0 in &node.members {
+        Visit::visit_ct_members(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_pivot_hierarchy_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTPivotHierarchy) {
+    if let Some(it0) = &mut node.mps {
+        VisitMut::visit_ct_member_properties_mut(v, it0);
+    }
+    for it0 in &mut node.members {
+        VisitMut::visit_ct_members_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_pivot_selection<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTPivotSelection,
+) {
+    if let Some(it0) = &node.pane {
+        Visit::visit_pane_type(v, it0);
+    }
+    if let Some(it0) = &node.axis {
+        Visit::visit_st_axis(v, it0);
+    }
+    Visit::visit_pivot_area(v, &node.pivot_area);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_pivot_selection_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTPivotSelection) {
+    if let Some(it0) = &mut node.pane {
+        VisitMut::visit_pane_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.axis {
+        VisitMut::visit_st_axis_mut(v, it0);
+    }
+    VisitMut::visit_pivot_area_mut(v, &mut node.pivot_area);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_pivot_table_definition<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTPivotTableDefinition,
+) {
+    Visit::visit_pivot_location(v, &node.location);
+    if let Some(it0) = &node.pivot_fields {
+        Visit::visit_pivot_fields(v, it0);
+    }
+    if let Some(it0) = &node.row_fields {
+        Visit::visit_row_fields(v, it0);
+    }
+    if let Some(it0) = &node.row_items {
+        Visit::visit_ct_row_items(v, it0);
+    }
+    if let Some(it0) = &node.col_fields {
+        Visit::visit_col_fields(v, it0);
+    }
+    if let Some(it0) = &node.col_items {
+        Visit::visit_ct_col_items(v, it0);
+    }
+    if let Some(it0) = &node.page_fields {
+        Visit::visit_page_fields(v, it0);
+    }
+    if let Some(it0) = &node.data_fields {
+        Visit::visit_data_fields(v, it0);
+    }
+    if let Some(it0) = &node.formats {
+        Visit::visit_ct_formats(v, it0);
+    }
+    if let Some(it0) = &node.conditional_formats {
+        Visit::visit_ct_conditional_formats(v, it0);
+    }
+    if let Some(it0) = &node.chart_formats {
+        Visit::visit_ct_chart_formats(v, it0);
+    }
+    if let Some(it0) = &node.pivot_hierarchies {
+        Visit::visit_ct_pivot_hierarchies(v, it0);
+    }
+    if let Some(it0) = &node.pivot_table_style_info {
+        Visit::visit_ct_pivot_table_style(v, it0);
+    }
+    if let Some(it0) = &node.filters {
+        Visit::visit_pivot_filters(v, it0);
+    }
+    if let Some(it0) = &node.row_hierarchies_usage {
+        Visit::visit_ct_row_hierarchies_usage(v, it0);
+    }
+    if let Some(it0) = &node.col_hierarchies_usage {
+        Visit::visit_ct_col_hierarchies_usage(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_pivot_table_definition_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTPivotTableDefinition,
+) {
+    VisitMut::visit_pivot_location_mut(v, &mut node.location);
+    if let Some(it0) = &mut node.pivot_fields {
+        VisitMut::visit_pivot_fields_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.row_fields {
+        VisitMut::visit_row_fields_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.row_items {
+        VisitMut::visit_ct_row_items_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.col_fields {
+        VisitMut::visit_col_fields_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.col_items {
+        VisitMut::visit_ct_col_items_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.page_fields {
+        VisitMut::visit_page_fields_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.data_fields {
+        VisitMut::visit_data_fields_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.formats {
+        VisitMut::visit_ct_formats_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.conditional_formats {
+        VisitMut::visit_ct_conditional_formats_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.chart_formats {
+        VisitMut::visit_ct_chart_formats_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.pivot_hierarchies {
+        VisitMut::visit_ct_pivot_hierarchies_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.pivot_table_style_info {
+        VisitMut::visit_ct_pivot_table_style_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.filters {
+        VisitMut::visit_pivot_filters_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.row_hierarchies_usage {
+        VisitMut::visit_ct_row_hierarchies_usage_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.col_hierarchies_usage {
+        VisitMut::visit_ct_col_hierarchies_usage_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_query<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTQuery) {
+    if let Some(it0) = &node.tpls {
+        Visit::visit_ct_tuples(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_query_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTQuery) {
+    if let Some(it0) = &mut node.tpls {
+        VisitMut::visit_ct_tuples_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_query_cache<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTQueryCache) {
+    for it0 in &node.query {
+        Visit::visit_ct_query(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_query_cache_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTQueryCache) {
+    for it0 in &mut node.query {
+        VisitMut::visit_ct_query_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_range_pr<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTRangePr) {
+    if let Some(it0) = &node.group_by {
+        Visit::visit_st_group_by(v, it0);
+    }
+}
+
+pub fn walk_ct_range_pr_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTRangePr) {
+    if let Some(it0) = &mut node.group_by {
+        VisitMut::visit_st_group_by_mut(v, it0);
+    }
+}
+
+pub fn walk_ct_range_sets<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTRangeSets) {
+    for it0 in &node.range_set {
+        Visit::visit_ct_range_set(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_range_sets_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTRangeSets) {
+    for it0 in &mut node.range_set {
+        VisitMut::visit_ct_range_set_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_record<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTRecord) {
+    for it0 in &node.m {
+        Visit::visit_ct_missing(v, it0);
+    }
+    for it0 in &node.n {
+        Visit::visit_ct_number(v, it0);
+    }
+    for it0 in &node.b {
+        Visit::visit_ct_boolean(v, it0);
+    }
+    for it0 in &node.e {
+        Visit::visit_ct_error(v, it0);
+    }
+    for it0 in &node.style_index {
+        Visit::visit_ct_string(v, it0);
+    }
+    for it0 in &node.d {
+        Visit::visit_ct_date_time(v, it0);
+    }
+    for it0 in &node.x {
+        Visit::visit_ct_index(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_record_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTRecord) {
+    for it0 in &mut node.m {
+        VisitMut::visit_ct_missing_mut(v, it0);
+    }
+    for it0 in &mut node.n {
+        VisitMut::visit_ct_number_mut(v, it0);
+    }
+    for it0 in &mut node.b {
+        VisitMut::visit_ct_boolean_mut(v, it0);
+    }
+    for it0 in &mut node.e {
+        VisitMut::visit_ct_error_mut(v, it0);
+    }
+    for it0 in &mut node.style_index {
+        VisitMut::visit_ct_string_mut(v, it0);
+    }
+    for it0 in &mut node.d {
+        VisitMut::visit_ct_date_time_mut(v, it0);
+    }
+    for it0 in &mut node.x {
+        VisitMut::visit_ct_index_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_row_hierarchies_usage<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTRowHierarchiesUsage,
+) {
+    for it0 in &node.row_hierarchy_usage {
+        Visit::visit_ct_hierarchy_usage(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_row_hierarchies_usage_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTRowHierarchiesUsage,
+) {
+    for it0 in &mut node.row_hierarchy_usage {
+        VisitMut::visit_ct_hierarchy_usage_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_row_items<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTRowItems) {
+    for it0 in &node.i {
+        Visit::visit_cti(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_row_items_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTRowItems) {
+    for it0 in &mut node.i {
+        VisitMut::visit_cti_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_server_formats<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTServerFormats,
+) {
+    for it0 in &node.server_format {
+        Visit::visit_ct_server_format(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_server_formats_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTServerFormats) {
+    for it0 in &mut node.server_format {
+        VisitMut::visit_ct_server_format_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_set<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTSet) {
+    if let Some(it0) = &node.sort_type {
+        Visit::visit_st_sort_type(v, it0);
+    }
+    for it0 in &node.tpls {
+        Visit::visit_ct_tuples(v, it0);
+    }
+    if let Some(it0) = &node.sort_by_tuple {
+        Visit::visit_ct_tuples(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_set_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSet) {
+    if let Some(it0) = &mut node.sort_type {
+        VisitMut::visit_st_sort_type_mut(v, it0);
+    }
+    for it0 in &mut node.tpls {
+        VisitMut::visit_ct_tuples_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.sort_by_tuple {
+        VisitMut::visit_ct_tuples_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sets<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTSets) {
+    for it0 in &node.set {
+        Visit::visit_ct_set(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sets_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSets) {
+    for it0 in &mut node.set {
+        VisitMut::visit_ct_set_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sheet_id_map<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTSheetIdMap) {
+    for it0 in &node.sheet_id {
+        Visit::visit_ct_sheet_id(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sheet_id_map_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSheetIdMap) {
+    for it0 in &mut node.sheet_id {
+        VisitMut::visit_ct_sheet_id_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_smart_tag_pr<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTSmartTagPr) {
+    if let Some(it0) = &node.show {
+        Visit::visit_st_smart_tag_show(v, it0);
+    }
+}
+
+pub fn walk_ct_smart_tag_pr_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSmartTagPr) {
+    if let Some(it0) = &mut node.show {
+        VisitMut::visit_st_smart_tag_show_mut(v, it0);
+    }
+}
+
+pub fn walk_ct_smart_tag_types<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTSmartTagTypes,
+) {
+    for it0 in &node.smart_tag_type {
+        Visit::visit_ct_smart_tag_type(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_smart_tag_types_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSmartTagTypes) {
+    for it0 in &mut node.smart_tag_type {
+        VisitMut::visit_ct_smart_tag_type_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_string<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTString) {
+    for it0 in &node.tpls {
+        Visit::visit_ct_tuples(v, it0);
+    }
+    for it0 in &node.x {
+        Visit::visit_ctx(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_string_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTString) {
+    for it0 in &mut node.tpls {
+        VisitMut::visit_ct_tuples_mut(v, it0);
+    }
+    for it0 in &mut node.x {
+        VisitMut::visit_ctx_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tuple_cache<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTTupleCache) {
+    if let Some(it0) = &node.entries {
+        Visit::visit_ctpcdsdtc_entries(v, it0);
+    }
+    if let Some(it0) = &node.sets {
+        Visit::visit_ct_sets(v, it0);
+    }
+    if let Some(it0) = &node.query_cache {
+        Visit::visit_ct_query_cache(v, it0);
+    }
+    if let Some(it0) = &node.server_formats {
+        Visit::visit_ct_server_formats(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tuple_cache_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTupleCache) {
+    if let Some(it0) = &mut node.entries {
+        VisitMut::visit_ctpcdsdtc_entries_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.sets {
+        VisitMut::visit_ct_sets_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.query_cache {
+        VisitMut::visit_ct_query_cache_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.server_formats {
+        VisitMut::visit_ct_server_formats_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tuples<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTTuples) {
+    for it0 in &node.tpl {
+        Visit::visit_ct_tuple(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tuples_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTuples) {
+    for it0 in &mut node.tpl {
+        VisitMut::visit_ct_tuple_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_vol_main<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTVolMain) {
+    for it0 in &node.tp {
+        Visit::visit_ct_vol_topic(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_vol_main_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTVolMain) {
+    for it0 in &mut node.tp {
+        VisitMut::visit_ct_vol_topic_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_vol_topic<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTVolTopic) {
+    if let Some(it0) = &node.cell_type {
+        Visit::visit_st_vol_value_type(v, it0);
+    }
+    for it0 in &node.tr {
+        Visit::visit_ct_vol_topic_ref(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_vol_topic_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTVolTopic) {
+    if let Some(it0) = &mut node.cell_type {
+        VisitMut::visit_st_vol_value_type_mut(v, it0);
+    }
+    for it0 in &mut node.tr {
+        VisitMut::visit_ct_vol_topic_ref_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_vol_type<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTVolType) {
+    Visit::visit_st_vol_dep_type(v, &node.r#type);
+    for it0 in &node.main {
+        Visit::visit_ct_vol_main(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_vol_type_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTVolType) {
+    VisitMut::visit_st_vol_dep_type_mut(v, &mut node.r#type);
+    for it0 in &mut node.main {
+        VisitMut::visit_ct_vol_main_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_vol_types<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTVolTypes) {
+    for it0 in &node.vol_type {
+        Visit::visit_ct_vol_type(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_vol_types_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTVolTypes) {
+    for it0 in &mut node.vol_type {
+        VisitMut::visit_ct_vol_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_web_publish_objects<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTWebPublishObjects,
+) {
+    for it0 in &node.web_publish_object {
+        Visit::visit_ct_web_publish_object(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_web_publish_objects_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTWebPublishObjects,
+) {
+    for it0 in &mut node.web_publish_object {
+        VisitMut::visit_ct_web_publish_object_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cache_field<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CacheField) {
+    if let Some(it0) = &node.shared_items {
+        Visit::visit_shared_items(v, it0);
+    }
+    if let Some(it0) = &node.field_group {
+        Visit::visit_field_group(v, it0);
+    }
+    for it0 in &node.mp_map {
+        Visit::visit_ctx(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cache_field_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CacheField) {
+    if let Some(it0) = &mut node.shared_items {
+        VisitMut::visit_shared_items_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.field_group {
+        VisitMut::visit_field_group_mut(v, it0);
+    }
+    for it0 in &mut node.mp_map {
+        VisitMut::visit_ctx_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cache_fields<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CacheFields) {
+    for it0 in &node.cache_field {
+        Visit::visit_cache_field(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cache_fields_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CacheFields) {
+    for it0 in &mut node.cache_field {
+        VisitMut::visit_cache_field_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cache_source<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CacheSource) {
+    Visit::visit_st_source_type(v, &node.r#type);
+    if let Some(it0) = &node.worksheet_source {
+        Visit::visit_worksheet_source(v, it0);
+    }
+    if let Some(it0) = &node.consolidation {
+        Visit::visit_consolidation(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cache_source_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CacheSource) {
+    VisitMut::visit_st_source_type_mut(v, &mut node.r#type);
+    if let Some(it0) = &mut node.worksheet_source {
+        VisitMut::visit_worksheet_source_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.consolidation {
+        VisitMut::visit_consolidation_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_calc_chain<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CalcChain) {
+    #[cfg(feature = "sml-formulas")]
+    {
+        for it0 in &node.cells {
+            Visit::visit_calc_cell(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &node.extension_list {
+            Visit::visit_extension_list(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_calc_chain_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CalcChain) {
+    #[cfg(feature = "sml-formulas")]
+    {
+        for it0 in &mut node.cells {
+            VisitMut::visit_calc_cell_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &mut node.extension_list {
+            VisitMut::visit_extension_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_calculation_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CalculationProperties,
+) {
+    #[cfg(feature = "sml-formulas")]
+    {
+        if let Some(it0) = &node.calc_mode {
+            Visit::visit_calculation_mode(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-formulas")]
+    {
+        if let Some(it0) = &node.ref_mode {
+            Visit::visit_reference_mode(v, it0);
+        }
+    }
+}
+
+pub fn walk_calculation_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CalculationProperties,
+) {
+    #[cfg(feature = "sml-formulas")]
+    {
+        if let Some(it0) = &mut node.calc_mode {
+            VisitMut::visit_calculation_mode_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-formulas")]
+    {
+        if let Some(it0) = &mut node.ref_mode {
+            VisitMut::visit_reference_mode_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_cell<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Cell) {
+    if let Some(it0) = &node.cell_type {
+        Visit::visit_cell_type(v, it0);
+    }
+    if let Some(it0) = &node.formula {
+        Visit::visit_cell_formula(v, it0);
+    }
+    if let Some(it0) = &node.is {
+        Visit::visit_rich_string(v, it0);
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &node.extension_list {
+            Visit::visit_extension_list(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cell_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Cell) {
+    if let Some(it0) = &mut node.cell_type {
+        VisitMut::visit_cell_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.formula {
+        VisitMut::visit_cell_formula_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.is {
+        VisitMut::visit_rich_string_mut(v, it0);
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &mut node.extension_list {
+            VisitMut::visit_extension_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cell_alignment<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CellAlignment) {
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.horizontal {
+            Visit::visit_horizontal_alignment(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.vertical {
+            Visit::visit_vertical_alignment(v, it0);
+        }
+    }
+}
+
+pub fn walk_cell_alignment_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CellAlignment) {
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.horizontal {
+            VisitMut::visit_horizontal_alignment_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.vertical {
+            VisitMut::visit_vertical_alignment_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_cell_formats<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CellFormats) {
+    for it0 in &node.xf {
+        Visit::visit_format(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cell_formats_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CellFormats) {
+    for it0 in &mut node.xf {
+        VisitMut::visit_format_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cell_formula<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CellFormula) {
+    if let Some(it0) = &node.cell_type {
+        Visit::visit_formula_type(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cell_formula_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CellFormula) {
+    if let Some(it0) = &mut node.cell_type {
+        VisitMut::visit_formula_type_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cell_smart_tag<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CellSmartTag) {
+    for it0 in &node.cell_smart_tag_pr {
+        Visit::visit_ct_cell_smart_tag_pr(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cell_smart_tag_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CellSmartTag) {
+    for it0 in &mut node.cell_smart_tag_pr {
+        VisitMut::visit_ct_cell_smart_tag_pr_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cell_smart_tags<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CellSmartTags) {
+    for it0 in &node.cell_smart_tag {
+        Visit::visit_cell_smart_tag(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cell_smart_tags_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CellSmartTags) {
+    for it0 in &mut node.cell_smart_tag {
+        VisitMut::visit_cell_smart_tag_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cell_style<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CellStyle) {
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cell_style_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CellStyle) {
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cell_style_formats<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CellStyleFormats,
+) {
+    for it0 in &node.xf {
+        Visit::visit_format(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cell_style_formats_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CellStyleFormats) {
+    for it0 in &mut node.xf {
+        VisitMut::visit_format_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cell_styles<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CellStyles) {
+    for it0 in &node.cell_style {
+        Visit::visit_cell_style(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cell_styles_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CellStyles) {
+    for it0 in &mut node.cell_style {
+        VisitMut::visit_cell_style_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cell_watches<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CellWatches) {
+    for it0 in &node.cell_watch {
+        Visit::visit_cell_watch(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_cell_watches_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CellWatches) {
+    for it0 in &mut node.cell_watch {
+        VisitMut::visit_cell_watch_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_chartsheet<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Chartsheet) {
+    if let Some(it0) = &node.sheet_properties {
+        Visit::visit_chartsheet_properties(v, it0);
+    }
+    Visit::visit_chartsheet_views(v, &node.sheet_views);
+    if let Some(it0) = &node.sheet_protection {
+        Visit::visit_chartsheet_protection(v, it0);
+    }
+    if let Some(it0) = &node.custom_sheet_views {
+        Visit::visit_custom_chartsheet_views(v, it0);
+    }
+    if let Some(it0) = &node.page_margins {
+        Visit::visit_page_margins(v, it0);
+    }
+    if let Some(it0) = &node.page_setup {
+        Visit::visit_chartsheet_page_setup(v, it0);
+    }
+    if let Some(it0) = &node.header_footer {
+        Visit::visit_header_footer(v, it0);
+    }
+    Visit::visit_drawing(v, &node.drawing);
+    if let Some(it0) = &node.legacy_drawing {
+        Visit::visit_legacy_drawing(v, it0);
+    }
+    if let Some(it0) = &node.legacy_drawing_h_f {
+        Visit::visit_legacy_drawing(v, it0);
+    }
+    if let Some(it0) = &node.drawing_h_f {
+        Visit::visit_drawing_header_footer(v, it0);
+    }
+    if let Some(it0) = &node.picture {
+        Visit::visit_sheet_background_picture(v, it0);
+    }
+    if let Some(it0) = &node.web_publish_items {
+        Visit::visit_web_publish_items(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_chartsheet_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Chartsheet) {
+    if let Some(it0) = &mut node.sheet_properties {
+        VisitMut::visit_chartsheet_properties_mut(v, it0);
+    }
+    VisitMut::visit_chartsheet_views_mut(v, &mut node.sheet_views);
+    if let Some(it0) = &mut node.sheet_protection {
+        VisitMut::visit_chartsheet_protection_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.custom_sheet_views {
+        VisitMut::visit_custom_chartsheet_views_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.page_margins {
+        VisitMut::visit_page_margins_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.page_setup {
+        VisitMut::visit_chartsheet_page_setup_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.header_footer {
+        VisitMut::visit_header_footer_mut(v, it0);
+    }
+    VisitMut::visit_drawing_mut(v, &mut node.drawing);
+    if let Some(it0) = &mut node.legacy_drawing {
+        VisitMut::visit_legacy_drawing_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.legacy_drawing_h_f {
+        VisitMut::visit_legacy_drawing_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.drawing_h_f {
+        VisitMut::visit_drawing_header_footer_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.picture {
+        VisitMut::visit_sheet_background_picture_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.web_publish_items {
+        VisitMut::visit_web_publish_items_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_chartsheet_page_setup<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast ChartsheetPageSetup,
+) {
+    if let Some(it0) = &node.orientation {
+        Visit::visit_st_orientation(v, it0);
+    }
+}
+
+pub fn walk_chartsheet_page_setup_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut ChartsheetPageSetup,
+) {
+    if let Some(it0) = &mut node.orientation {
+        VisitMut::visit_st_orientation_mut(v, it0);
+    }
+}
+
+pub fn walk_chartsheet_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast ChartsheetProperties,
+) {
+    if let Some(it0) = &node.tab_color {
+        Visit::visit_color(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_chartsheet_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut ChartsheetProperties,
+) {
+    if let Some(it0) = &mut node.tab_color {
+        VisitMut::visit_color_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_chartsheet_view<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast ChartsheetView) {
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_chartsheet_view_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ChartsheetView) {
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_chartsheet_views<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast ChartsheetViews,
+) {
+    for it0 in &node.sheet_view {
+        Visit::visit_chartsheet_view(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_chartsheet_views_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ChartsheetViews) {
+    for it0 in &mut node.sheet_view {
+        VisitMut::visit_chartsheet_view_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_col_fields<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast ColFields) {
+    for it0 in &node.field {
+        Visit::visit_ct_field(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_col_fields_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ColFields) {
+    for it0 in &mut node.field {
+        VisitMut::visit_ct_field_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_color_scale<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast ColorScale) {
+    #[cfg(feature = "sml-styling")]
+    {
+        for it0 in &node.cfvo {
+            Visit::visit_conditional_format_value(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        for it0 in &node.color {
+            Visit::visit_color(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_color_scale_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ColorScale) {
+    #[cfg(feature = "sml-styling")]
+    {
+        for it0 in &mut node.cfvo {
+            VisitMut::visit_conditional_format_value_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        for it0 in &mut node.color {
+            VisitMut::visit_color_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_colors<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Colors) {
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.indexed_colors {
+            Visit::visit_indexed_colors(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.mru_colors {
+            Visit::visit_most_recent_colors(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_colors_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Colors) {
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.indexed_colors {
+            VisitMut::visit_indexed_colors_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.mru_colors {
+            VisitMut::visit_most_recent_colors_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_columns<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Columns) {
+    for it0 in &node.col {
+        Visit::visit_column(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_columns_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Columns) {
+    for it0 in &mut node.col {
+        VisitMut::visit_column_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_comment<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Comment) {
+    #[cfg(feature = "sml-comments")]
+    {
+        Visit::visit_rich_string(v, &node.text);
+    }
+    if let Some(it0) = &node.comment_pr {
+        Visit::visit_ct_comment_pr(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_comment_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Comment) {
+    #[cfg(feature = "sml-comments")]
+    {
+        VisitMut::visit_rich_string_mut(v, &mut node.text);
+    }
+    if let Some(it0) = &mut node.comment_pr {
+        VisitMut::visit_ct_comment_pr_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_comment_list<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CommentList) {
+    for it0 in &node.comment {
+        Visit::visit_comment(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_comment_list_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CommentList) {
+    for it0 in &mut node.comment {
+        VisitMut::visit_comment_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_comments<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Comments) {
+    Visit::visit_authors(v, &node.authors);
+    Visit::visit_comment_list(v, &node.comment_list);
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_comments_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Comments) {
+    VisitMut::visit_authors_mut(v, &mut node.authors);
+    VisitMut::visit_comment_list_mut(v, &mut node.comment_list);
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_conditional_format_value<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast ConditionalFormatValue,
+) {
+    #[cfg(feature = "sml-styling")]
+    {
+        Visit::visit_conditional_value_type(v, &node.r#type);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_conditional_format_value_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut ConditionalFormatValue,
+) {
+    #[cfg(feature = "sml-styling")]
+    {
+        VisitMut::visit_conditional_value_type_mut(v, &mut node.r#type);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_conditional_formatting<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast ConditionalFormatting,
+) {
+    #[cfg(feature = "sml-styling")]
+    {
+        for it0 in &node.cf_rule {
+            Visit::visit_conditional_rule(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &node.extension_list {
+            Visit::visit_extension_list(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_conditional_formatting_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut ConditionalFormatting,
+) {
+    #[cfg(feature = "sml-styling")]
+    {
+        for it0 in &mut node.cf_rule {
+            VisitMut::visit_conditional_rule_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &mut node.extension_list {
+            VisitMut::visit_extension_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_conditional_rule<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast ConditionalRule,
+) {
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.r#type {
+            Visit::visit_conditional_type(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.operator {
+            Visit::visit_conditional_operator(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.time_period {
+            Visit::visit_st_time_period(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.color_scale {
+            Visit::visit_color_scale(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.data_bar {
+            Visit::visit_data_bar(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.icon_set {
+            Visit::visit_icon_set(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &node.extension_list {
+            Visit::visit_extension_list(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_conditional_rule_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ConditionalRule) {
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.r#type {
+            VisitMut::visit_conditional_type_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.operator {
+            VisitMut::visit_conditional_operator_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.time_period {
+            VisitMut::visit_st_time_period_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.color_scale {
+            VisitMut::visit_color_scale_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.data_bar {
+            VisitMut::visit_data_bar_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.icon_set {
+            VisitMut::visit_icon_set_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &mut node.extension_list {
+            VisitMut::visit_extension_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_connection<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Connection) {
+    if let Some(it0) = &node.credentials {
+        Visit::visit_st_cred_method(v, it0);
+    }
+    if let Some(it0) = &node.db_pr {
+        Visit::visit_database_properties(v, it0);
+    }
+    if let Some(it0) = &node.olap_pr {
+        Visit::visit_olap_properties(v, it0);
+    }
+    if let Some(it0) = &node.web_pr {
+        Visit::visit_web_query_properties(v, it0);
+    }
+    if let Some(it0) = &node.text_pr {
+        Visit::visit_text_import_properties(v, it0);
+    }
+    if let Some(it0) = &node.parameters {
+        Visit::visit_parameters(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_connection_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Connection) {
+    if let Some(it0) = &mut node.credentials {
+        VisitMut::visit_st_cred_method_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.db_pr {
+        VisitMut::visit_database_properties_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.olap_pr {
+        VisitMut::visit_olap_properties_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.web_pr {
+        VisitMut::visit_web_query_properties_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.text_pr {
+        VisitMut::visit_text_import_properties_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.parameters {
+        VisitMut::visit_parameters_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_connections<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Connections) {
+    for it0 in &node.connection {
+        Visit::visit_connection(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_connections_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Connections) {
+    for it0 in &mut node.connection {
+        VisitMut::visit_connection_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_consolidation<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Consolidation) {
+    if let Some(it0) = &node.pages {
+        Visit::visit_ct_pages(v, it0);
+    }
+    Visit::visit_ct_range_sets(v, &node.range_sets);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_consolidation_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Consolidation) {
+    if let Some(it0) = &mut node.pages {
+        VisitMut::visit_ct_pages_mut(v, it0);
+    }
+    VisitMut::visit_ct_range_sets_mut(v, &mut node.range_sets);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_control<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Control) {
+    if let Some(it0) = &node.control_pr {
+        Visit::visit_ct_control_pr(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_control_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Control) {
+    if let Some(it0) = &mut node.control_pr {
+        VisitMut::visit_ct_control_pr_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_controls<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Controls) {
+    for it0 in &node.control {
+        Visit::visit_control(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_controls_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Controls) {
+    for it0 in &mut node.control {
+        VisitMut::visit_control_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_custom_chartsheet_view<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CustomChartsheetView,
+) {
+    if let Some(it0) = &node.state {
+        Visit::visit_sheet_state(v, it0);
+    }
+    if let Some(it0) = &node.page_margins {
+        Visit::visit_page_margins(v, it0);
+    }
+    if let Some(it0) = &node.page_setup {
+        Visit::visit_chartsheet_page_setup(v, it0);
+    }
+    if let Some(it0) = &node.header_footer {
+        Visit::visit_header_footer(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_custom_chartsheet_view_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CustomChartsheetView,
+) {
+    if let Some(it0) = &mut node.state {
+        VisitMut::visit_sheet_state_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.page_margins {
+        VisitMut::visit_page_margins_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.page_setup {
+        VisitMut::visit_chartsheet_page_setup_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.header_footer {
+        VisitMut::visit_header_footer_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_custom_chartsheet_views<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CustomChartsheetViews,
+) {
+    for it0 in &node.custom_sheet_view {
+        Visit::visit_custom_chartsheet_view(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_custom_chartsheet_views_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CustomChartsheetViews,
+) {
+    for it0 in &mut node.custom_sheet_view {
+        VisitMut::visit_custom_chartsheet_view_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_custom_filter<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CustomFilter) {
+    if let Some(it0) = &node.operator {
+        Visit::visit_filter_operator(v, it0);
+    }
+}
+
+pub fn walk_custom_filter_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CustomFilter) {
+    if let Some(it0) = &mut node.operator {
+        VisitMut::visit_filter_operator_mut(v, it0);
+    }
+}
+
+pub fn walk_custom_filters<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CustomFilters) {
+    for it0 in &node.custom_filter {
+        Visit::visit_custom_filter(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_custom_filters_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CustomFilters) {
+    for it0 in &mut node.custom_filter {
+        VisitMut::visit_custom_filter_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_custom_sheet_view<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CustomSheetView,
+) {
+    if let Some(it0) = &node.state {
+        Visit::visit_sheet_state(v, it0);
+    }
+    if let Some(it0) = &node.view {
+        Visit::visit_sheet_view_type(v, it0);
+    }
+    if let Some(it0) = &node.pane {
+        Visit::visit_pane(v, it0);
+    }
+    if let Some(it0) = &node.selection {
+        Visit::visit_selection(v, it0);
+    }
+    if let Some(it0) = &node.row_breaks {
+        Visit::visit_page_breaks(v, it0);
+    }
+    if let Some(it0) = &node.col_breaks {
+        Visit::visit_page_breaks(v, it0);
+    }
+    if let Some(it0) = &node.page_margins {
+        Visit::visit_page_margins(v, it0);
+    }
+    if let Some(it0) = &node.print_options {
+        Visit::visit_print_options(v, it0);
+    }
+    if let Some(it0) = &node.page_setup {
+        Visit::visit_page_setup(v, it0);
+    }
+    if let Some(it0) = &node.header_footer {
+        Visit::visit_header_footer(v, it0);
+    }
+    if let Some(it0) = &node.auto_filter {
+        Visit::visit_auto_filter(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_custom_sheet_view_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CustomSheetView) {
+    if let Some(it0) = &mut node.state {
+        VisitMut::visit_sheet_state_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.view {
+        VisitMut::visit_sheet_view_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.pane {
+        VisitMut::visit_pane_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.selection {
+        VisitMut::visit_selection_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.row_breaks {
+        VisitMut::visit_page_breaks_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.col_breaks {
+        VisitMut::visit_page_breaks_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.page_margins {
+        VisitMut::visit_page_margins_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.print_options {
+        VisitMut::visit_print_options_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.page_setup {
+        VisitMut::visit_page_setup_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.header_footer {
+        VisitMut::visit_header_footer_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.auto_filter {
+        VisitMut::visit_auto_filter_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_custom_sheet_views<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CustomSheetViews,
+) {
+    for it0 in &node.custom_sheet_view {
+        Visit::visit_custom_sheet_view(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_custom_sheet_views_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CustomSheetViews) {
+    for it0 in &mut node.custom_sheet_view {
+        VisitMut::visit_custom_sheet_view_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_custom_workbook_view<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CustomWorkbookView,
+) {
+    if let Some(it0) = &node.show_comments {
+        Visit::visit_comment_visibility(v, it0);
+    }
+    if let Some(it0) = &node.show_objects {
+        Visit::visit_object_visibility(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_custom_workbook_view_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CustomWorkbookView,
+) {
+    if let Some(it0) = &mut node.show_comments {
+        VisitMut::visit_comment_visibility_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.show_objects {
+        VisitMut::visit_object_visibility_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_custom_workbook_views<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CustomWorkbookViews,
+) {
+    for it0 in &node.custom_workbook_view {
+        Visit::visit_custom_workbook_view(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_custom_workbook_views_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CustomWorkbookViews,
+) {
+    for it0 in &mut node.custom_workbook_view {
+        VisitMut::visit_custom_workbook_view_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_data_bar<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast DataBar) {
+    #[cfg(feature = "sml-styling")]
+    {
+        for it0 in &node.cfvo {
+            Visit::visit_conditional_format_value(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        Visit::visit_color(v, &node.color);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_data_bar_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DataBar) {
+    #[cfg(feature = "sml-styling")]
+    {
+        for it0 in &mut node.cfvo {
+            VisitMut::visit_conditional_format_value_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        VisitMut::visit_color_mut(v, &mut node.color);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_data_field<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast DataField) {
+    if let Some(it0) = &node.subtotal {
+        Visit::visit_st_data_consolidate_function(v, it0);
+    }
+    if let Some(it0) = &node.show_data_as {
+        Visit::visit_st_show_data_as(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_data_field_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DataField) {
+    if let Some(it0) = &mut node.subtotal {
+        VisitMut::visit_st_data_consolidate_function_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.show_data_as {
+        VisitMut::visit_st_show_data_as_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_data_fields<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast DataFields) {
+    for it0 in &node.data_field {
+        Visit::visit_data_field(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_data_fields_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DataFields) {
+    for it0 in &mut node.data_field {
+        VisitMut::visit_data_field_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_data_tables<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast DataTables) {
+    for it0 in &node.m {
+        Visit::visit_table_missing(v, it0);
+    }
+    for it0 in &node.style_index {
+        Visit::visit_ctx_string_element(v, it0);
+    }
+    for it0 in &node.x {
+        Visit::visit_ct_index(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_data_tables_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DataTables) {
+    for it0 in &mut node.m {
+        VisitMut::visit_table_missing_mut(v, it0);
+    }
+    for it0 in &mut node.style_index {
+        VisitMut::visit_ctx_string_element_mut(v, it0);
+    }
+    for it0 in &mut node.x {
+        VisitMut::visit_ct_index_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_data_validation<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast DataValidation) {
+    #[cfg(feature = "sml-validation")]
+    {
+        if let Some(it0) = &node.r#type {
+            Visit::visit_validation_type(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-validation")]
+    {
+        if let Some(it0) = &node.error_style {
+            Visit::visit_validation_error_style(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-validation")]
+    {
+        if let Some(it0) = &node.ime_mode {
+            Visit::visit_st_data_validation_ime_mode(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-validation")]
+    {
+        if let Some(it0) = &node.operator {
+            Visit::visit_validation_operator(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_data_validation_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DataValidation) {
+    #[cfg(feature = "sml-validation")]
+    {
+        if let Some(it0) = &mut node.r#type {
+            VisitMut::visit_validation_type_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-validation")]
+    {
+        if let Some(it0) = &mut node.error_style {
+            VisitMut::visit_validation_error_style_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-validation")]
+    {
+        if let Some(it0) = &mut node.ime_mode {
+            VisitMut::visit_st_data_validation_ime_mode_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-validation")]
+    {
+        if let Some(it0) = &mut node.operator {
+            VisitMut::visit_validation_operator_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_data_validations<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast DataValidations,
+) {
+    for it0 in &node.data_validation {
+        Visit::visit_data_validation(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_data_validations_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DataValidations) {
+    for it0 in &mut node.data_validation {
+        VisitMut::visit_data_validation_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_date_group_item<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast DateGroupItem) {
+    Visit::visit_st_date_time_grouping(v, &node.date_time_grouping);
+}
+
+pub fn walk_date_group_item_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DateGroupItem) {
+    VisitMut::visit_st_date_time_grouping_mut(v, &mut node.date_time_grouping);
+}
+
+pub fn walk_dde_item<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast DdeItem) {
+    if let Some(it0) = &node.values {
+        Visit::visit_ct_dde_values(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_dde_item_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DdeItem) {
+    if let Some(it0) = &mut node.values {
+        VisitMut::visit_ct_dde_values_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_dde_items<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast DdeItems) {
+    for it0 in &node.dde_item {
+        Visit::visit_dde_item(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_dde_items_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DdeItems) {
+    for it0 in &mut node.dde_item {
+        VisitMut::visit_dde_item_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_dde_link<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast DdeLink) {
+    if let Some(it0) = &node.dde_items {
+        Visit::visit_dde_items(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_dde_link_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DdeLink) {
+    if let Some(it0) = &mut node.dde_items {
+        VisitMut::visit_dde_items_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_defined_name<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast DefinedName) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_defined_name_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DefinedName) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_defined_names<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast DefinedNames) {
+    for it0 in &node.defined_name {
+        Visit::visit_defined_name(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_defined_names_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DefinedNames) {
+    for it0 in &mut node.defined_name {
+        VisitMut::visit_defined_name_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_differential_format<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast DifferentialFormat,
+) {
+    if let Some(it0) = &node.font {
+        Visit::visit_font(v, it0);
+    }
+    if let Some(it0) = &node.num_fmt {
+        Visit::visit_number_format(v, it0);
+    }
+    if let Some(it0) = &node.fill {
+        Visit::visit_fill(v, it0);
+    }
+    if let Some(it0) = &node.alignment {
+        Visit::visit_cell_alignment(v, it0);
+    }
+    if let Some(it0) = &node.border {
+        Visit::visit_border(v, it0);
+    }
+    if let Some(it0) = &node.protection {
+        Visit::visit_cell_protection(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_differential_format_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut DifferentialFormat,
+) {
+    if let Some(it0) = &mut node.font {
+        VisitMut::visit_font_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.num_fmt {
+        VisitMut::visit_number_format_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.fill {
+        VisitMut::visit_fill_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.alignment {
+        VisitMut::visit_cell_alignment_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.border {
+        VisitMut::visit_border_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.protection {
+        VisitMut::visit_cell_protection_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_differential_formats<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast DifferentialFormats,
+) {
+    for it0 in &node.dxf {
+        Visit::visit_differential_format(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_differential_formats_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut DifferentialFormats,
+) {
+    for it0 in &mut node.dxf {
+        VisitMut::visit_differential_format_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_dynamic_filter<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast DynamicFilter) {
+    Visit::visit_dynamic_filter_type(v, &node.r#type);
+}
+
+pub fn walk_dynamic_filter_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DynamicFilter) {
+    VisitMut::visit_dynamic_filter_type_mut(v, &mut node.r#type);
+}
+
+pub fn walk_eg_extension_list<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast EGExtensionList,
+) {
+    for it0 in &node.ext {
+        Visit::visit_extension(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_eg_extension_list_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut EGExtensionList) {
+    for it0 in &mut node.ext {
+        VisitMut::visit_extension_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_extension_list<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast ExtensionList) {
+    for it0 in &node.ext {
+        Visit::visit_extension(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_extension_list_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ExtensionList) {
+    for it0 in &mut node.ext {
+        VisitMut::visit_extension_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_external_book<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast ExternalBook) {
+    if let Some(it0) = &node.sheet_names {
+        Visit::visit_ct_external_sheet_names(v, it0);
+    }
+    if let Some(it0) = &node.defined_names {
+        Visit::visit_ct_external_defined_names(v, it0);
+    }
+    if let Some(it0) = &node.sheet_data_set {
+        Visit::visit_external_sheet_data_set(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_external_book_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ExternalBook) {
+    if let Some(it0) = &mut node.sheet_names {
+        VisitMut::visit_ct_external_sheet_names_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.defined_names {
+        VisitMut::visit_ct_external_defined_names_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.sheet_data_set {
+        VisitMut::visit_external_sheet_data_set_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_external_cell<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast ExternalCell) {
+    if let Some(it0) = &node.cell_type {
+        Visit::visit_cell_type(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_external_cell_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ExternalCell) {
+    if let Some(it0) = &mut node.cell_type {
+        VisitMut::visit_cell_type_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_external_link<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast ExternalLink) {
+    if let Some(it0) = &node.external_book {
+        Visit::visit_external_book(v, it0);
+    }
+    if let Some(it0) = &node.dde_link {
+        Visit::visit_dde_link(v, it0);
+    }
+    if let Some(it0) = &node.ole_link {
+        Visit::visit_ole_link(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_external_link_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ExternalLink) {
+    if let Some(it0) = &mut node.external_book {
+        VisitMut::visit_external_book_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.dde_link {
+        VisitMut::visit_dde_link_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.ole_link {
+        VisitMut::visit_ole_link_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_external_references<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast ExternalReferences,
+) {
+    for it0 in &node.external_reference {
+        Visit::visit_external_reference(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_external_references_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut ExternalReferences,
+) {
+    for it0 in &mut node.external_reference {
+        VisitMut::visit_external_reference_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_external_row<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast ExternalRow) {
+    for it0 in &node.cell {
+        Visit::visit_external_cell(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_external_row_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ExternalRow) {
+    for it0 in &mut node.cell {
+        VisitMut::visit_external_cell_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_external_sheet_data<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast ExternalSheetData,
+) {
+    for it0 in &node.row {
+        Visit::visit_external_row(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_external_sheet_data_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ExternalSheetData) {
+    for it0 in &mut node.row {
+        VisitMut::visit_external_row_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_external_sheet_data_set<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast ExternalSheetDataSet,
+) {
+    for it0 in &node.sheet_data {
+        Visit::visit_external_sheet_data(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_external_sheet_data_set_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut ExternalSheetDataSet,
+) {
+    for it0 in &mut node.sheet_data {
+        VisitMut::visit_external_sheet_data_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_field_group<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast FieldGroup) {
+    if let Some(it0) = &node.range_pr {
+        Visit::visit_ct_range_pr(v, it0);
+    }
+    if let Some(it0) = &node.discrete_pr {
+        Visit::visit_ct_discrete_pr(v, it0);
+    }
+    if let Some(it0) = &node.group_items {
+        Visit::visit_group_items(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_field_group_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut FieldGroup) {
+    if let Some(it0) = &mut node.range_pr {
+        VisitMut::visit_ct_range_pr_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.discrete_pr {
+        VisitMut::visit_ct_discrete_pr_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.group_items {
+        VisitMut::visit_group_items_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_fill<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Fill) {
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.pattern_fill {
+            Visit::visit_pattern_fill(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.gradient_fill {
+            Visit::visit_gradient_fill(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_fill_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Fill) {
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.pattern_fill {
+            VisitMut::visit_pattern_fill_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.gradient_fill {
+            VisitMut::visit_gradient_fill_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_fills<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Fills) {
+    for it0 in &node.fill {
+        Visit::visit_fill(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_fills_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Fills) {
+    for it0 in &mut node.fill {
+        VisitMut::visit_fill_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_filter_column<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast FilterColumn) {
+    #[cfg(feature = "sml-filtering")]
+    {
+        if let Some(it0) = &node.filters {
+            Visit::visit_filters(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-filtering")]
+    {
+        if let Some(it0) = &node.top10 {
+            Visit::visit_top10_filter(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-filtering")]
+    {
+        if let Some(it0) = &node.custom_filters {
+            Visit::visit_custom_filters(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-filtering")]
+    {
+        if let Some(it0) = &node.dynamic_filter {
+            Visit::visit_dynamic_filter(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-filtering")]
+    {
+        if let Some(it0) = &node.color_filter {
+            Visit::visit_color_filter(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-filtering")]
+    {
+        if let Some(it0) = &node.icon_filter {
+            Visit::visit_icon_filter(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &node.extension_list {
+            Visit::visit_extension_list(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_filter_column_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut FilterColumn) {
+    #[cfg(feature = "sml-filtering")]
+    {
+        if let Some(it0) = &mut node.filters {
+            VisitMut::visit_filters_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-filtering")]
+    {
+        if let Some(it0) = &mut node.top10 {
+            VisitMut::visit_top10_filter_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-filtering")]
+    {
+        if let Some(it0) = &mut node.custom_filters {
+            VisitMut::visit_custom_filters_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-filtering")]
+    {
+        if let Some(it0) = &mut node.dynamic_filter {
+            VisitMut::visit_dynamic_filter_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-filtering")]
+    {
+        if let Some(it0) = &mut node.color_filter {
+            VisitMut::visit_color_filter_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-filtering")]
+    {
+        if let Some(it0) = &mut node.icon_filter {
+            VisitMut::visit_icon_filter_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &mut node.extension_list {
+            VisitMut::visit_extension_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_filters<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Filters) {
+    if let Some(it0) = &node.calendar_type {
+        Visit::visit_calendar_type(v, it0);
+    }
+    for it0 in &node.filter {
+        Visit::visit_filter(v, it0);
+    }
+    for it0 in &node.date_group_item {
+        Visit::visit_date_group_item(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_filters_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Filters) {
+    if let Some(it0) = &mut node.calendar_type {
+        VisitMut::visit_calendar_type_mut(v, it0);
+    }
+    for it0 in &mut node.filter {
+        VisitMut::visit_filter_mut(v, it0);
+    }
+    for it0 in &mut node.date_group_item {
+        VisitMut::visit_date_group_item_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_font<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Font) {
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.name {
+            Visit::visit_font_name(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.charset {
+            Visit::visit_int_property(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.family {
+            Visit::visit_font_family(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.b {
+            Visit::visit_boolean_property(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.i {
+            Visit::visit_boolean_property(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.strike {
+            Visit::visit_boolean_property(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.outline {
+            Visit::visit_boolean_property(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.shadow {
+            Visit::visit_boolean_property(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.condense {
+            Visit::visit_boolean_property(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.extend {
+            Visit::visit_boolean_property(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.color {
+            Visit::visit_color(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.sz {
+            Visit::visit_font_size(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.u {
+            Visit::visit_underline_property(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.vert_align {
+            Visit::visit_vertical_align_font_property(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.scheme {
+            Visit::visit_font_scheme_property(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_font_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Font) {
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.name {
+            VisitMut::visit_font_name_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.charset {
+            VisitMut::visit_int_property_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.family {
+            VisitMut::visit_font_family_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.b {
+            VisitMut::visit_boolean_property_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.i {
+            VisitMut::visit_boolean_property_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.strike {
+            VisitMut::visit_boolean_property_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.outline {
+            VisitMut::visit_boolean_property_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.shadow {
+            VisitMut::visit_boolean_property_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.condense {
+            VisitMut::visit_boolean_property_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.extend {
+            VisitMut::visit_boolean_property_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.color {
+            VisitMut::visit_color_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.sz {
+            VisitMut::visit_font_size_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.u {
+            VisitMut::visit_underline_property_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.vert_align {
+            VisitMut::visit_vertical_align_font_property_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.scheme {
+            VisitMut::visit_font_scheme_property_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_font_scheme_property<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast FontSchemeProperty,
+) {
+    Visit::visit_font_scheme(v, &node.value);
+}
+
+pub fn walk_font_scheme_property_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut FontSchemeProperty,
+) {
+    VisitMut::visit_font_scheme_mut(v, &mut node.value);
+}
+
+pub fn walk_fonts<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Fonts) {
+    for it0 in &node.font {
+        Visit::visit_font(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_fonts_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Fonts) {
+    for it0 in &mut node.font {
+        VisitMut::visit_font_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_format<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Format) {
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.alignment {
+            Visit::visit_cell_alignment(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-protection")]
+    {
+        if let Some(it0) = &node.protection {
+            Visit::visit_cell_protection(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &node.extension_list {
+            Visit::visit_extension_list(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_format_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Format) {
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.alignment {
+            VisitMut::visit_cell_alignment_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-protection")]
+    {
+        if let Some(it0) = &mut node.protection {
+            VisitMut::visit_cell_protection_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &mut node.extension_list {
+            VisitMut::visit_extension_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_gradient_fill<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast GradientFill) {
+    if let Some(it0) = &node.r#type {
+        Visit::visit_gradient_type(v, it0);
+    }
+    for it0 in &node.stop {
+        Visit::visit_gradient_stop(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_gradient_fill_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut GradientFill) {
+    if let Some(it0) = &mut node.r#type {
+        VisitMut::visit_gradient_type_mut(v, it0);
+    }
+    for it0 in &mut node.stop {
+        VisitMut::visit_gradient_stop_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_gradient_stop<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast GradientStop) {
+    Visit::visit_color(v, &node.color);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_gradient_stop_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut GradientStop) {
+    VisitMut::visit_color_mut(v, &mut node.color);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_group_items<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast GroupItems) {
+    for it0 in &node.m {
+        Visit::visit_ct_missing(v, it0);
+    }
+    for it0 in &node.n {
+        Visit::visit_ct_number(v, it0);
+    }
+    for it0 in &node.b {
+        Visit::visit_ct_boolean(v, it0);
+    }
+    for it0 in &node.e {
+        Visit::visit_ct_error(v, it0);
+    }
+    for it0 in &node.style_index {
+        Visit::visit_ct_string(v, it0);
+    }
+    for it0 in &node.d {
+        Visit::visit_ct_date_time(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_group_items_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut GroupItems) {
+    for it0 in &mut node.m {
+        VisitMut::visit_ct_missing_mut(v, it0);
+    }
+    for it0 in &mut node.n {
+        VisitMut::visit_ct_number_mut(v, it0);
+    }
+    for it0 in &mut node.b {
+        VisitMut::visit_ct_boolean_mut(v, it0);
+    }
+    for it0 in &mut node.e {
+        VisitMut::visit_ct_error_mut(v, it0);
+    }
+    for it0 in &mut node.style_index {
+        VisitMut::visit_ct_string_mut(v, it0);
+    }
+    for it0 in &mut node.d {
+        VisitMut::visit_ct_date_time_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_header_footer<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast HeaderFooter) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_header_footer_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut HeaderFooter) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_hyperlinks<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Hyperlinks) {
+    for it0 in &node.hyperlink {
+        Visit::visit_hyperlink(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_hyperlinks_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Hyperlinks) {
+    for it0 in &mut node.hyperlink {
+        VisitMut::visit_hyperlink_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_icon_filter<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast IconFilter) {
+    Visit::visit_icon_set_type(v, &node.icon_set);
+}
+
+pub fn walk_icon_filter_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut IconFilter) {
+    VisitMut::visit_icon_set_type_mut(v, &mut node.icon_set);
+}
+
+pub fn walk_icon_set<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast IconSet) {
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.icon_set {
+            Visit::visit_icon_set_type(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        for it0 in &node.cfvo {
+            Visit::visit_conditional_format_value(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_icon_set_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut IconSet) {
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.icon_set {
+            VisitMut::visit_icon_set_type_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        for it0 in &mut node.cfvo {
+            VisitMut::visit_conditional_format_value_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ignored_errors<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast IgnoredErrors) {
+    for it0 in &node.ignored_error {
+        Visit::visit_ignored_error(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ignored_errors_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut IgnoredErrors) {
+    for it0 in &mut node.ignored_error {
+        VisitMut::visit_ignored_error_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_indexed_colors<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast IndexedColors) {
+    for it0 in &node.rgb_color {
+        Visit::visit_rgb_color(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_indexed_colors_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut IndexedColors) {
+    for it0 in &mut node.rgb_color {
+        VisitMut::visit_rgb_color_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_map_info<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast MapInfo) {
+    for it0 in &node.schema {
+        Visit::visit_xml_schema(v, it0);
+    }
+    for it0 in &node.map {
+        Visit::visit_xml_map(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_map_info_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut MapInfo) {
+    for it0 in &mut node.schema {
+        VisitMut::visit_xml_schema_mut(v, it0);
+    }
+    for it0 in &mut node.map {
+        VisitMut::visit_xml_map_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_merged_cells<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast MergedCells) {
+    for it0 in &node.merge_cell {
+        Visit::visit_merged_cell(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_merged_cells_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut MergedCells) {
+    for it0 in &mut node.merge_cell {
+        VisitMut::visit_merged_cell_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_metadata<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Metadata) {
+    if let Some(it0) = &node.metadata_types {
+        Visit::visit_metadata_types(v, it0);
+    }
+    if let Some(it0) = &node.metadata_strings {
+        Visit::visit_metadata_strings(v, it0);
+    }
+    if let Some(it0) = &node.mdx_metadata {
+        Visit::visit_ct_mdx_metadata(v, it0);
+    }
+    for it0 in &node.future_metadata {
+        Visit::visit_ct_future_metadata(v, it0);
+    }
+    if let Some(it0) = &node.cell_metadata {
+        Visit::visit_metadata_blocks(v, it0);
+    }
+    if let Some(it0) = &node.value_metadata {
+        Visit::visit_metadata_blocks(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_metadata_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Metadata) {
+    if let Some(it0) = &mut node.metadata_types {
+        VisitMut::visit_metadata_types_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.metadata_strings {
+        VisitMut::visit_metadata_strings_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.mdx_metadata {
+        VisitMut::visit_ct_mdx_metadata_mut(v, it0);
+    }
+    for it0 in &mut node.future_metadata {
+        VisitMut::visit_ct_future_metadata_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.cell_metadata {
+        VisitMut::visit_metadata_blocks_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.value_metadata {
+        VisitMut::visit_metadata_blocks_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_metadata_block<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast MetadataBlock) {
+    for it0 in &node.rc {
+        Visit::visit_metadata_record(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_metadata_block_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut MetadataBlock) {
+    for it0 in &mut node.rc {
+        VisitMut::visit_metadata_record_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_metadata_blocks<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast MetadataBlocks) {
+    for it0 in &node.bk {
+        Visit::visit_metadata_block(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_metadata_blocks_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut MetadataBlocks) {
+    for it0 in &mut node.bk {
+        VisitMut::visit_metadata_block_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_metadata_strings<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast MetadataStrings,
+) {
+    for it0 in &node.style_index {
+        Visit::visit_ctx_string_element(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_metadata_strings_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut MetadataStrings) {
+    for it0 in &mut node.style_index {
+        VisitMut::visit_ctx_string_element_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_metadata_types<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast MetadataTypes) {
+    for it0 in &node.metadata_type {
+        Visit::visit_metadata_type(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_metadata_types_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut MetadataTypes) {
+    for it0 in &mut node.metadata_type {
+        VisitMut::visit_metadata_type_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_most_recent_colors<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast MostRecentColors,
+) {
+    for it0 in &node.color {
+        Visit::visit_color(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_most_recent_colors_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut MostRecentColors) {
+    for it0 in &mut node.color {
+        VisitMut::visit_color_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_number_formats<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast NumberFormats) {
+    for it0 in &node.num_fmt {
+        Visit::visit_number_format(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_number_formats_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut NumberFormats) {
+    for it0 in &mut node.num_fmt {
+        VisitMut::visit_number_format_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_object_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast ObjectProperties,
+) {
+    Visit::visit_object_anchor(v, &node.anchor);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_object_properties_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ObjectProperties) {
+    VisitMut::visit_object_anchor_mut(v, &mut node.anchor);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ole_items<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast OleItems) {
+    for it0 in &node.ole_item {
+        Visit::visit_ole_item(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ole_items_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut OleItems) {
+    for it0 in &mut node.ole_item {
+        VisitMut::visit_ole_item_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ole_link<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast OleLink) {
+    if let Some(it0) = &node.ole_items {
+        Visit::visit_ole_items(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ole_link_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut OleLink) {
+    if let Some(it0) = &mut node.ole_items {
+        VisitMut::visit_ole_items_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ole_object<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast OleObject) {
+    if let Some(it0) = &node.dv_aspect {
+        Visit::visit_st_dv_aspect(v, it0);
+    }
+    if let Some(it0) = &node.ole_update {
+        Visit::visit_st_ole_update(v, it0);
+    }
+    if let Some(it0) = &node.object_pr {
+        Visit::visit_object_properties(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ole_object_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut OleObject) {
+    if let Some(it0) = &mut node.dv_aspect {
+        VisitMut::visit_st_dv_aspect_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.ole_update {
+        VisitMut::visit_st_ole_update_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.object_pr {
+        VisitMut::visit_object_properties_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ole_objects<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast OleObjects) {
+    for it0 in &node.ole_object {
+        Visit::visit_ole_object(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ole_objects_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut OleObjects) {
+    for it0 in &mut node.ole_object {
+        VisitMut::visit_ole_object_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_page_breaks<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast PageBreaks) {
+    #[cfg(feature = "sml-layout")]
+    {
+        for it0 in &node.brk {
+            Visit::visit_page_break(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_page_breaks_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut PageBreaks) {
+    #[cfg(feature = "sml-layout")]
+    {
+        for it0 in &mut node.brk {
+            VisitMut::visit_page_break_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_page_field<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast PageField) {
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_page_field_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut PageField) {
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_page_fields<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast PageFields) {
+    for it0 in &node.page_field {
+        Visit::visit_page_field(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_page_fields_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut PageFields) {
+    for it0 in &mut node.page_field {
+        VisitMut::visit_page_field_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_page_setup<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast PageSetup) {
+    #[cfg(feature = "sml-layout")]
+    {
+        if let Some(it0) = &node.page_order {
+            Visit::visit_st_page_order(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-layout")]
+    {
+        if let Some(it0) = &node.orientation {
+            Visit::visit_st_orientation(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-layout")]
+    {
+        if let Some(it0) = &node.cell_comments {
+            Visit::visit_st_cell_comments(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-layout")]
+    {
+        if let Some(it0) = &node.errors {
+            Visit::visit_st_print_error(v, it0);
+        }
+    }
+}
+
+pub fn walk_page_setup_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut PageSetup) {
+    #[cfg(feature = "sml-layout")]
+    {
+        if let Some(it0) = &mut node.page_order {
+            VisitMut::visit_st_page_order_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-layout")]
+    {
+        if let Some(it0) = &mut node.orientation {
+            VisitMut::visit_st_orientation_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-layout")]
+    {
+        if let Some(it0) = &mut node.cell_comments {
+            VisitMut::visit_st_cell_comments_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-layout")]
+    {
+        if let Some(it0) = &mut node.errors {
+            VisitMut::visit_st_print_error_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_pane<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Pane) {
+    #[cfg(feature = "sml-structure")]
+    {
+        if let Some(it0) = &node.active_pane {
+            Visit::visit_pane_type(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-structure")]
+    {
+        if let Some(it0) = &node.state {
+            Visit::visit_pane_state(v, it0);
+        }
+    }
+}
+
+pub fn walk_pane_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Pane) {
+    #[cfg(feature = "sml-structure")]
+    {
+        if let Some(it0) = &mut node.active_pane {
+            VisitMut::visit_pane_type_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-structure")]
+    {
+        if let Some(it0) = &mut node.state {
+            VisitMut::visit_pane_state_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_parameter<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Parameter) {
+    if let Some(it0) = &node.parameter_type {
+        Visit::visit_st_parameter_type(v, it0);
+    }
+}
+
+pub fn walk_parameter_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Parameter) {
+    if let Some(it0) = &mut node.parameter_type {
+        VisitMut::visit_st_parameter_type_mut(v, it0);
+    }
+}
+
+pub fn walk_parameters<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Parameters) {
+    for it0 in &node.parameter {
+        Visit::visit_parameter(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_parameters_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Parameters) {
+    for it0 in &mut node.parameter {
+        VisitMut::visit_parameter_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_pattern_fill<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast PatternFill) {
+    if let Some(it0) = &node.pattern_type {
+        Visit::visit_pattern_type(v, it0);
+    }
+    if let Some(it0) = &node.fg_color {
+        Visit::visit_color(v, it0);
+    }
+    if let Some(it0) = &node.bg_color {
+        Visit::visit_color(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_pattern_fill_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut PatternFill) {
+    if let Some(it0) = &mut node.pattern_type {
+        VisitMut::visit_pattern_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.fg_color {
+        VisitMut::visit_color_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.bg_color {
+        VisitMut::visit_color_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_phonetic_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast PhoneticProperties,
+) {
+    if let Some(it0) = &node.r#type {
+        Visit::visit_st_phonetic_type(v, it0);
+    }
+    if let Some(it0) = &node.alignment {
+        Visit::visit_st_phonetic_alignment(v, it0);
+    }
+}
+
+pub fn walk_phonetic_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut PhoneticProperties,
+) {
+    if let Some(it0) = &mut node.r#type {
+        VisitMut::visit_st_phonetic_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.alignment {
+        VisitMut::visit_st_phonetic_alignment_mut(v, it0);
+    }
+}
+
+pub fn walk_phonetic_run<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast PhoneticRun) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_phonetic_run_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut PhoneticRun) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_pivot_area<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast PivotArea) {
+    if let Some(it0) = &node.r#type {
+        Visit::visit_st_pivot_area_type(v, it0);
+    }
+    if let Some(it0) = &node.axis {
+        Visit::visit_st_axis(v, it0);
+    }
+    if let Some(it0) = &node.references {
+        Visit::visit_ct_pivot_area_references(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_pivot_area_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut PivotArea) {
+    if let Some(it0) = &mut node.r#type {
+        VisitMut::visit_st_pivot_area_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.axis {
+        VisitMut::visit_st_axis_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.references {
+        VisitMut::visit_ct_pivot_area_references_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_pivot_areas<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast PivotAreas) {
+    for it0 in &node.pivot_area {
+        Visit::visit_pivot_area(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_pivot_areas_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut PivotAreas) {
+    for it0 in &mut node.pivot_area {
+        VisitMut::visit_pivot_area_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_pivot_cache_definition<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast PivotCacheDefinition,
+) {
+    Visit::visit_cache_source(v, &node.cache_source);
+    Visit::visit_cache_fields(v, &node.cache_fields);
+    if let Some(it0) = &node.cache_hierarchies {
+        Visit::visit_ct_cache_hierarchies(v, it0);
+    }
+    if let Some(it0) = &node.kpis {
+        Visit::visit_ctpcdkp_is(v, it0);
+    }
+    if let Some(it0) = &node.calculated_items {
+        Visit::visit_ct_calculated_items(v, it0);
+    }
+    if let Some(it0) = &node.calculated_members {
+        Visit::visit_ct_calculated_members(v, it0);
+    }
+    if let Some(it0) = &node.dimensions {
+        Visit::visit_ct_dimensions(v, it0);
+    }
+    if let Some(it0) = &node.measure_groups {
+        Visit::visit_ct_measure_groups(v, it0);
+    }
+    if let Some(it0) = &node.maps {
+        Visit::visit_ct_measure_dimension_maps(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_pivot_cache_definition_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut PivotCacheDefinition,
+) {
+    VisitMut::visit_cache_source_mut(v, &mut node.cache_source);
+    VisitMut::visit_cache_fields_mut(v, &mut node.cache_fields);
+    if let Some(it0) = &mut node.cache_hierarchies {
+        VisitMut::visit_ct_cache_hierarchies_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.kpis {
+        VisitMut::visit_ctpcdkp_is_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.calculated_items {
+        VisitMut::visit_ct_calculated_items_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.calculated_members {
+        VisitMut::visit_ct_calculated_members_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.dimensions {
+        VisitMut::visit_ct_dimensions_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.measure_groups {
+        VisitMut::visit_ct_measure_groups_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.maps {
+        VisitMut::visit_ct_measure_dimension_maps_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_pivot_cache_records<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast PivotCacheRecords,
+) {
+    for it0 in &node.reference {
+        Visit::visit_ct_record(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_pivot_cache_records_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut PivotCacheRecords) {
+    for it0 in &mut node.reference {
+        VisitMut::visit_ct_record_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_pivot_caches<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast PivotCaches) {
+    for it0 in &node.pivot_cache {
+        Visit::visit_ct_pivot_cache(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_pivot_caches_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut PivotCaches) {
+    for it0 in &mut node.pivot_cache {
+        VisitMut::visit_ct_pivot_cache_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_pivot_field<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast PivotField) {
+    if let Some(it0) = &node.axis {
+        Visit::visit_st_axis(v, it0);
+    }
+    if let Some(it0) = &node.sort_type {
+        Visit::visit_st_field_sort_type(v, it0);
+    }
+    if let Some(it0) = &node.items {
+        Visit::visit_pivot_items(v, it0);
+    }
+    if let Some(it0) = &node.auto_sort_scope {
+        Visit::visit_pivot_area(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_pivot_field_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut PivotField) {
+    if let Some(it0) = &mut node.axis {
+        VisitMut::visit_st_axis_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.sort_type {
+        VisitMut::visit_st_field_sort_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.items {
+        VisitMut::visit_pivot_items_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.auto_sort_scope {
+        VisitMut::visit_pivot_area_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_pivot_fields<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast PivotFields) {
+    for it0 in &node.pivot_field {
+        Visit::visit_pivot_field(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_pivot_fields_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut PivotFields) {
+    for it0 in &mut node.pivot_field {
+        VisitMut::visit_pivot_field_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_pivot_filter<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast PivotFilter) {
+    Visit::visit_st_pivot_filter_type(v, &node.r#type);
+    Visit::visit_auto_filter(v, &node.auto_filter);
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_pivot_filter_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut PivotFilter) {
+    VisitMut::visit_st_pivot_filter_type_mut(v, &mut node.r#type);
+    VisitMut::visit_auto_filter_mut(v, &mut node.auto_filter);
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_pivot_filters<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast PivotFilters) {
+    for it0 in &node.filter {
+        Visit::visit_pivot_filter(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_pivot_filters_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut PivotFilters) {
+    for it0 in &mut node.filter {
+        VisitMut::visit_pivot_filter_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_pivot_item<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast PivotItem) {
+    if let Some(it0) = &node.cell_type {
+        Visit::visit_st_item_type(v, it0);
+    }
+}
+
+pub fn walk_pivot_item_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut PivotItem) {
+    if let Some(it0) = &mut node.cell_type {
+        VisitMut::visit_st_item_type_mut(v, it0);
+    }
+}
+
+pub fn walk_pivot_items<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast PivotItems) {
+    for it0 in &node.item {
+        Visit::visit_pivot_item(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_pivot_items_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut PivotItems) {
+    for it0 in &mut node.item {
+        VisitMut::visit_pivot_item_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_protected_ranges<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast ProtectedRanges,
+) {
+    for it0 in &node.protected_range {
+        Visit::visit_protected_range(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_protected_ranges_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ProtectedRanges) {
+    for it0 in &mut node.protected_range {
+        VisitMut::visit_protected_range_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_query_table<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast QueryTable) {
+    if let Some(it0) = &node.grow_shrink_type {
+        Visit::visit_st_grow_shrink_type(v, it0);
+    }
+    if let Some(it0) = &node.query_table_refresh {
+        Visit::visit_query_table_refresh(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_query_table_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut QueryTable) {
+    if let Some(it0) = &mut node.grow_shrink_type {
+        VisitMut::visit_st_grow_shrink_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.query_table_refresh {
+        VisitMut::visit_query_table_refresh_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_query_table_deleted_fields<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast QueryTableDeletedFields,
+) {
+    for it0 in &node.deleted_field {
+        Visit::visit_ct_deleted_field(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_query_table_deleted_fields_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut QueryTableDeletedFields,
+) {
+    for it0 in &mut node.deleted_field {
+        VisitMut::visit_ct_deleted_field_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_query_table_field<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast QueryTableField,
+) {
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_query_table_field_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut QueryTableField) {
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_query_table_fields<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast QueryTableFields,
+) {
+    for it0 in &node.query_table_field {
+        Visit::visit_query_table_field(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_query_table_fields_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut QueryTableFields) {
+    for it0 in &mut node.query_table_field {
+        VisitMut::visit_query_table_field_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_query_table_refresh<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast QueryTableRefresh,
+) {
+    Visit::visit_query_table_fields(v, &node.query_table_fields);
+    if let Some(it0) = &node.query_table_deleted_fields {
+        Visit::visit_query_table_deleted_fields(v, it0);
+    }
+    if let Some(it0) = &node.sort_state {
+        Visit::visit_sort_state(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_query_table_refresh_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut QueryTableRefresh) {
+    VisitMut::visit_query_table_fields_mut(v, &mut node.query_table_fields);
+    if let Some(it0) = &mut node.query_table_deleted_fields {
+        VisitMut::visit_query_table_deleted_fields_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.sort_state {
+        VisitMut::visit_sort_state_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_reviewed_revisions<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast ReviewedRevisions,
+) {
+    for it0 in &node.reviewed {
+        Visit::visit_reviewed(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_reviewed_revisions_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ReviewedRevisions) {
+    for it0 in &mut node.reviewed {
+        VisitMut::visit_reviewed_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_revision_cell_change<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast RevisionCellChange,
+) {
+    if let Some(it0) = &node.oc {
+        Visit::visit_cell(v, it0);
+    }
+    Visit::visit_cell(v, &node.nc);
+    if let Some(it0) = &node.ndxf {
+        Visit::visit_differential_format(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_revision_cell_change_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut RevisionCellChange,
+) {
+    if let Some(it0) = &mut node.oc {
+        VisitMut::visit_cell_mut(v, it0);
+    }
+    VisitMut::visit_cell_mut(v, &mut node.nc);
+    if let Some(it0) = &mut node.ndxf {
+        VisitMut::visit_differential_format_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_revision_comment<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast RevisionComment,
+) {
+    if let Some(it0) = &node.action {
+        Visit::visit_st_revision_action(v, it0);
+    }
+}
+
+pub fn walk_revision_comment_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut RevisionComment) {
+    if let Some(it0) = &mut node.action {
+        VisitMut::visit_st_revision_action_mut(v, it0);
+    }
+}
+
+pub fn walk_revision_custom_view<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast RevisionCustomView,
+) {
+    Visit::visit_st_revision_action(v, &node.action);
+}
+
+pub fn walk_revision_custom_view_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut RevisionCustomView,
+) {
+    VisitMut::visit_st_revision_action_mut(v, &mut node.action);
+}
+
+pub fn walk_revision_defined_name<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast RevisionDefinedName,
+) {
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_revision_defined_name_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut RevisionDefinedName,
+) {
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_revision_formatting<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast RevisionFormatting,
+) {
+    if let Some(it0) = &node.dxf {
+        Visit::visit_differential_format(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_revision_formatting_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut RevisionFormatting,
+) {
+    if let Some(it0) = &mut node.dxf {
+        VisitMut::visit_differential_format_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_revision_header<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast RevisionHeader) {
+    Visit::visit_ct_sheet_id_map(v, &node.sheet_id_map);
+    if let Some(it0) = &node.reviewed_list {
+        Visit::visit_reviewed_revisions(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_revision_header_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut RevisionHeader) {
+    VisitMut::visit_ct_sheet_id_map_mut(v, &mut node.sheet_id_map);
+    if let Some(it0) = &mut node.reviewed_list {
+        VisitMut::visit_reviewed_revisions_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_revision_headers<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast RevisionHeaders,
+) {
+    for it0 in &node.header {
+        Visit::visit_revision_header(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_revision_headers_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut RevisionHeaders) {
+    for it0 in &mut node.header {
+        VisitMut::visit_revision_header_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_revision_row_column<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast RevisionRowColumn,
+) {
+    Visit::visit_st_rw_col_action_type(v, &node.action);
+}
+
+pub fn walk_revision_row_column_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut RevisionRowColumn) {
+    VisitMut::visit_st_rw_col_action_type_mut(v, &mut node.action);
+}
+
+pub fn walk_revision_sheet_rename<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast RevisionSheetRename,
+) {
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_revision_sheet_rename_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut RevisionSheetRename,
+) {
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_revisions<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Revisions) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_revisions_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Revisions) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_rich_string<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast RichString) {
+    for it0 in &node.reference {
+        Visit::visit_rich_text_element(v, it0);
+    }
+    for it0 in &node.r_ph {
+        Visit::visit_phonetic_run(v, it0);
+    }
+    if let Some(it0) = &node.phonetic_pr {
+        Visit::visit_phonetic_properties(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_rich_string_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut RichString) {
+    for it0 in &mut node.reference {
+        VisitMut::visit_rich_text_element_mut(v, it0);
+    }
+    for it0 in &mut node.r_ph {
+        VisitMut::visit_phonetic_run_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.phonetic_pr {
+        VisitMut::visit_phonetic_properties_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_rich_text_element<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast RichTextElement,
+) {
+    if let Some(it0) = &node.r_pr {
+        Visit::visit_rich_text_run_properties(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_rich_text_element_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut RichTextElement) {
+    if let Some(it0) = &mut node.r_pr {
+        VisitMut::visit_rich_text_run_properties_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_rich_text_run_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast RichTextRunProperties,
+) {
+    if let Some(it0) = &node.r_font {
+        Visit::visit_font_name(v, it0);
+    }
+    if let Some(it0) = &node.charset {
+        Visit::visit_int_property(v, it0);
+    }
+    if let Some(it0) = &node.family {
+        Visit::visit_int_property(v, it0);
+    }
+    if let Some(it0) = &node.b {
+        Visit::visit_boolean_property(v, it0);
+    }
+    if let Some(it0) = &node.i {
+        Visit::visit_boolean_property(v, it0);
+    }
+    if let Some(it0) = &node.strike {
+        Visit::visit_boolean_property(v, it0);
+    }
+    if let Some(it0) = &node.outline {
+        Visit::visit_boolean_property(v, it0);
+    }
+    if let Some(it0) = &node.shadow {
+        Visit::visit_boolean_property(v, it0);
+    }
+    if let Some(it0) = &node.condense {
+        Visit::visit_boolean_property(v, it0);
+    }
+    if let Some(it0) = &node.extend {
+        Visit::visit_boolean_property(v, it0);
+    }
+    if let Some(it0) = &node.color {
+        Visit::visit_color(v, it0);
+    }
+    if let Some(it0) = &node.sz {
+        Visit::visit_font_size(v, it0);
+    }
+    if let Some(it0) = &node.u {
+        Visit::visit_underline_property(v, it0);
+    }
+    if let Some(it0) = &node.vert_align {
+        Visit::visit_vertical_align_font_property(v, it0);
+    }
+    if let Some(it0) = &node.scheme {
+        Visit::visit_font_scheme_property(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_rich_text_run_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut RichTextRunProperties,
+) {
+    if let Some(it0) = &mut node.r_font {
+        VisitMut::visit_font_name_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.charset {
+        VisitMut::visit_int_property_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.family {
+        VisitMut::visit_int_property_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.b {
+        VisitMut::visit_boolean_property_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.i {
+        VisitMut::visit_boolean_property_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.strike {
+        VisitMut::visit_boolean_property_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.outline {
+        VisitMut::visit_boolean_property_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.shadow {
+        VisitMut::visit_boolean_property_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.condense {
+        VisitMut::visit_boolean_property_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extend {
+        VisitMut::visit_boolean_property_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.color {
+        VisitMut::visit_color_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.sz {
+        VisitMut::visit_font_size_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.u {
+        VisitMut::visit_underline_property_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.vert_align {
+        VisitMut::visit_vertical_align_font_property_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.scheme {
+        VisitMut::visit_font_scheme_property_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_row<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Row) {
+    for it0 in &node.cells {
+        Visit::visit_cell(v, it0);
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &node.extension_list {
+            Visit::visit_extension_list(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_row_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Row) {
+    for it0 in &mut node.cells {
+        VisitMut::visit_cell_mut(v, it0);
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &mut node.extension_list {
+            VisitMut::visit_extension_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_row_fields<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast RowFields) {
+    for it0 in &node.field {
+        Visit::visit_ct_field(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_row_fields_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut RowFields) {
+    for it0 in &mut node.field {
+        VisitMut::visit_ct_field_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_scenario<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Scenario) {
+    for it0 in &node.input_cells {
+        Visit::visit_input_cells(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_scenario_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Scenario) {
+    for it0 in &mut node.input_cells {
+        VisitMut::visit_input_cells_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_scenarios<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Scenarios) {
+    for it0 in &node.scenario {
+        Visit::visit_scenario(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_scenarios_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Scenarios) {
+    for it0 in &mut node.scenario {
+        VisitMut::visit_scenario_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_selection<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Selection) {
+    if let Some(it0) = &node.pane {
+        Visit::visit_pane_type(v, it0);
+    }
+}
+
+pub fn walk_selection_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Selection) {
+    if let Some(it0) = &mut node.pane {
+        VisitMut::visit_pane_type_mut(v, it0);
+    }
+}
+
+pub fn walk_shared_items<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast SharedItems) {
+    for it0 in &node.m {
+        Visit::visit_ct_missing(v, it0);
+    }
+    for it0 in &node.n {
+        Visit::visit_ct_number(v, it0);
+    }
+    for it0 in &node.b {
+        Visit::visit_ct_boolean(v, it0);
+    }
+    for it0 in &node.e {
+        Visit::visit_ct_error(v, it0);
+    }
+    for it0 in &node.style_index {
+        Visit::visit_ct_string(v, it0);
+    }
+    for it0 in &node.d {
+        Visit::visit_ct_date_time(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_shared_items_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SharedItems) {
+    for it0 in &mut node.m {
+        VisitMut::visit_ct_missing_mut(v, it0);
+    }
+    for it0 in &mut node.n {
+        VisitMut::visit_ct_number_mut(v, it0);
+    }
+    for it0 in &mut node.b {
+        VisitMut::visit_ct_boolean_mut(v, it0);
+    }
+    for it0 in &mut node.e {
+        VisitMut::visit_ct_error_mut(v, it0);
+    }
+    for it0 in &mut node.style_index {
+        VisitMut::visit_ct_string_mut(v, it0);
+    }
+    for it0 in &mut node.d {
+        VisitMut::visit_ct_date_time_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_shared_strings<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast SharedStrings) {
+    for it0 in &node.si {
+        Visit::visit_rich_string(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_shared_strings_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SharedStrings) {
+    for it0 in &mut node.si {
+        VisitMut::visit_rich_string_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_shared_user<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast SharedUser) {
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_shared_user_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SharedUser) {
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_sheet<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Sheet) {
+    #[cfg(feature = "sml-structure")]
+    {
+        if let Some(it0) = &node.state {
+            Visit::visit_sheet_state(v, it0);
+        }
+    }
+}
+
+pub fn walk_sheet_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Sheet) {
+    #[cfg(feature = "sml-structure")]
+    {
+        if let Some(it0) = &mut node.state {
+            VisitMut::visit_sheet_state_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_sheet_data<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast SheetData) {
+    for it0 in &node.row {
+        Visit::visit_row(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_sheet_data_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SheetData) {
+    for it0 in &mut node.row {
+        VisitMut::visit_row_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_sheet_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast SheetProperties,
+) {
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.tab_color {
+            Visit::visit_color(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-structure")]
+    {
+        if let Some(it0) = &node.outline_pr {
+            Visit::visit_outline_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-layout")]
+    {
+        if let Some(it0) = &node.page_set_up_pr {
+            Visit::visit_page_setup_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_sheet_properties_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SheetProperties) {
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.tab_color {
+            VisitMut::visit_color_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-structure")]
+    {
+        if let Some(it0) = &mut node.outline_pr {
+            VisitMut::visit_outline_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-layout")]
+    {
+        if let Some(it0) = &mut node.page_set_up_pr {
+            VisitMut::visit_page_setup_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_sheet_view<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast SheetView) {
+    if let Some(it0) = &node.view {
+        Visit::visit_sheet_view_type(v, it0);
+    }
+    #[cfg(feature = "sml-structure")]
+    {
+        if let Some(it0) = &node.pane {
+            Visit::visit_pane(v, it0);
+        }
+    }
+    for it0 in &node.selection {
+        Visit::visit_selection(v, it0);
+    }
+    #[cfg(feature = "sml-pivot")]
+    {
+        for it0 in &node.pivot_selection {
+            Visit::visit_ct_pivot_selection(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &node.extension_list {
+            Visit::visit_extension_list(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_sheet_view_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SheetView) {
+    if let Some(it0) = &mut node.view {
+        VisitMut::visit_sheet_view_type_mut(v, it0);
+    }
+    #[cfg(feature = "sml-structure")]
+    {
+        if let Some(it0) = &mut node.pane {
+            VisitMut::visit_pane_mut(v, it0);
+        }
+    }
+    for it0 in &mut node.selection {
+        VisitMut::visit_selection_mut(v, it0);
+    }
+    #[cfg(feature = "sml-pivot")]
+    {
+        for it0 in &mut node.pivot_selection {
+            VisitMut::visit_ct_pivot_selection_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &mut node.extension_list {
+            VisitMut::visit_extension_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_sheet_views<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast SheetViews) {
+    for it0 in &node.sheet_view {
+        Visit::visit_sheet_view(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_sheet_views_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SheetViews) {
+    for it0 in &mut node.sheet_view {
+        VisitMut::visit_sheet_view_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_sheets<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Sheets) {
+    for it0 in &node.sheet {
+        Visit::visit_sheet(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_sheets_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Sheets) {
+    for it0 in &mut node.sheet {
+        VisitMut::visit_sheet_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_single_xml_cell<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast SingleXmlCell) {
+    Visit::visit_xml_cell_properties(v, &node.xml_cell_pr);
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_single_xml_cell_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SingleXmlCell) {
+    VisitMut::visit_xml_cell_properties_mut(v, &mut node.xml_cell_pr);
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_single_xml_cells<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast SingleXmlCells) {
+    for it0 in &node.single_xml_cell {
+        Visit::visit_single_xml_cell(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_single_xml_cells_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SingleXmlCells) {
+    for it0 in &mut node.single_xml_cell {
+        VisitMut::visit_single_xml_cell_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_smart_tags<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast SmartTags) {
+    for it0 in &node.cell_smart_tags {
+        Visit::visit_cell_smart_tags(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_smart_tags_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SmartTags) {
+    for it0 in &mut node.cell_smart_tags {
+        VisitMut::visit_cell_smart_tags_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_sort_condition<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast SortCondition) {
+    if let Some(it0) = &node.sort_by {
+        Visit::visit_sort_by(v, it0);
+    }
+    if let Some(it0) = &node.icon_set {
+        Visit::visit_icon_set_type(v, it0);
+    }
+}
+
+pub fn walk_sort_condition_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SortCondition) {
+    if let Some(it0) = &mut node.sort_by {
+        VisitMut::visit_sort_by_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.icon_set {
+        VisitMut::visit_icon_set_type_mut(v, it0);
+    }
+}
+
+pub fn walk_sort_state<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast SortState) {
+    if let Some(it0) = &node.sort_method {
+        Visit::visit_sort_method(v, it0);
+    }
+    for it0 in &node.sort_condition {
+        Visit::visit_sort_condition(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_sort_state_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SortState) {
+    if let Some(it0) = &mut node.sort_method {
+        VisitMut::visit_sort_method_mut(v, it0);
+    }
+    for it0 in &mut node.sort_condition {
+        VisitMut::visit_sort_condition_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_stylesheet<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Stylesheet) {
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.num_fmts {
+            Visit::visit_number_formats(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.fonts {
+            Visit::visit_fonts(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.fills {
+            Visit::visit_fills(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.borders {
+            Visit::visit_borders(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.cell_style_xfs {
+            Visit::visit_cell_style_formats(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.cell_xfs {
+            Visit::visit_cell_formats(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.cell_styles {
+            Visit::visit_cell_styles(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.dxfs {
+            Visit::visit_differential_formats(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.table_styles {
+            Visit::visit_table_styles(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.colors {
+            Visit::visit_colors(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &node.extension_list {
+            Visit::visit_extension_list(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_stylesheet_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Stylesheet) {
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.num_fmts {
+            VisitMut::visit_number_formats_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.fonts {
+            VisitMut::visit_fonts_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.fills {
+            VisitMut::visit_fills_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.borders {
+            VisitMut::visit_borders_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.cell_style_xfs {
+            VisitMut::visit_cell_style_formats_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.cell_xfs {
+            VisitMut::visit_cell_formats_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.cell_styles {
+            VisitMut::visit_cell_styles_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.dxfs {
+            VisitMut::visit_differential_formats_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.table_styles {
+            VisitMut::visit_table_styles_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.colors {
+            VisitMut::visit_colors_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &mut node.extension_list {
+            VisitMut::visit_extension_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_table<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Table) {
+    #[cfg(feature = "sml-tables")]
+    {
+        if let Some(it0) = &node.table_type {
+            Visit::visit_st_table_type(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-tables")]
+    {
+        if let Some(it0) = &node.auto_filter {
+            Visit::visit_auto_filter(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-tables")]
+    {
+        if let Some(it0) = &node.sort_state {
+            Visit::visit_sort_state(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-tables")]
+    {
+        Visit::visit_table_columns(v, &node.table_columns);
+    }
+    #[cfg(feature = "sml-tables")]
+    {
+        if let Some(it0) = &node.table_style_info {
+            Visit::visit_table_style_info(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &node.extension_list {
+            Visit::visit_extension_list(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_table_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Table) {
+    #[cfg(feature = "sml-tables")]
+    {
+        if let Some(it0) = &mut node.table_type {
+            VisitMut::visit_st_table_type_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-tables")]
+    {
+        if let Some(it0) = &mut node.auto_filter {
+            VisitMut::visit_auto_filter_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-tables")]
+    {
+        if let Some(it0) = &mut node.sort_state {
+            VisitMut::visit_sort_state_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-tables")]
+    {
+        VisitMut::visit_table_columns_mut(v, &mut node.table_columns);
+    }
+    #[cfg(feature = "sml-tables")]
+    {
+        if let Some(it0) = &mut node.table_style_info {
+            VisitMut::visit_table_style_info_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &mut node.extension_list {
+            VisitMut::visit_extension_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_table_column<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast TableColumn) {
+    if let Some(it0) = &node.totals_row_function {
+        Visit::visit_st_totals_row_function(v, it0);
+    }
+    if let Some(it0) = &node.calculated_column_formula {
+        Visit::visit_table_formula(v, it0);
+    }
+    if let Some(it0) = &node.totals_row_formula {
+        Visit::visit_table_formula(v, it0);
+    }
+    if let Some(it0) = &node.xml_column_pr {
+        Visit::visit_xml_column_properties(v, it0);
+    }
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_table_column_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut TableColumn) {
+    if let Some(it0) = &mut node.totals_row_function {
+        VisitMut::visit_st_totals_row_function_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.calculated_column_formula {
+        VisitMut::visit_table_formula_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.totals_row_formula {
+        VisitMut::visit_table_formula_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.xml_column_pr {
+        VisitMut::visit_xml_column_properties_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_table_columns<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast TableColumns) {
+    for it0 in &node.table_column {
+        Visit::visit_table_column(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_table_columns_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut TableColumns) {
+    for it0 in &mut node.table_column {
+        VisitMut::visit_table_column_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_table_formula<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast TableFormula) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_table_formula_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut TableFormula) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_table_parts<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast TableParts) {
+    for it0 in &node.table_part {
+        Visit::visit_table_part(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_table_parts_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut TableParts) {
+    for it0 in &mut node.table_part {
+        VisitMut::visit_table_part_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_table_style<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast TableStyle) {
+    for it0 in &node.table_style_element {
+        Visit::visit_table_style_element(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_table_style_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut TableStyle) {
+    for it0 in &mut node.table_style_element {
+        VisitMut::visit_table_style_element_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_table_style_element<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast TableStyleElement,
+) {
+    Visit::visit_st_table_style_type(v, &node.r#type);
+}
+
+pub fn walk_table_style_element_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut TableStyleElement) {
+    VisitMut::visit_st_table_style_type_mut(v, &mut node.r#type);
+}
+
+pub fn walk_table_styles<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast TableStyles) {
+    for it0 in &node.table_style {
+        Visit::visit_table_style(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_table_styles_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut TableStyles) {
+    for it0 in &mut node.table_style {
+        VisitMut::visit_table_style_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_text_field<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast TextField) {
+    if let Some(it0) = &node.r#type {
+        Visit::visit_st_external_connection_type(v, it0);
+    }
+}
+
+pub fn walk_text_field_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut TextField) {
+    if let Some(it0) = &mut node.r#type {
+        VisitMut::visit_st_external_connection_type_mut(v, it0);
+    }
+}
+
+pub fn walk_text_fields<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast TextFields) {
+    for it0 in &node.text_field {
+        Visit::visit_text_field(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_text_fields_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut TextFields) {
+    for it0 in &mut node.text_field {
+        VisitMut::visit_text_field_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_text_import_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast TextImportProperties,
+) {
+    if let Some(it0) = &node.file_type {
+        Visit::visit_st_file_type(v, it0);
+    }
+    if let Some(it0) = &node.qualifier {
+        Visit::visit_st_qualifier(v, it0);
+    }
+    if let Some(it0) = &node.text_fields {
+        Visit::visit_text_fields(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_text_import_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut TextImportProperties,
+) {
+    if let Some(it0) = &mut node.file_type {
+        VisitMut::visit_st_file_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.qualifier {
+        VisitMut::visit_st_qualifier_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.text_fields {
+        VisitMut::visit_text_fields_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_underline_property<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast UnderlineProperty,
+) {
+    if let Some(it0) = &node.value {
+        Visit::visit_underline_style(v, it0);
+    }
+}
+
+pub fn walk_underline_property_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut UnderlineProperty) {
+    if let Some(it0) = &mut node.value {
+        VisitMut::visit_underline_style_mut(v, it0);
+    }
+}
+
+pub fn walk_undo_info<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast UndoInfo) {
+    Visit::visit_st_formula_expression(v, &node.exp);
+}
+
+pub fn walk_undo_info_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut UndoInfo) {
+    VisitMut::visit_st_formula_expression_mut(v, &mut node.exp);
+}
+
+pub fn walk_users<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Users) {
+    for it0 in &node.user_info {
+        Visit::visit_shared_user(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_users_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Users) {
+    for it0 in &mut node.user_info {
+        VisitMut::visit_shared_user_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_vertical_align_font_property<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast VerticalAlignFontProperty,
+) {
+    Visit::visit_vertical_align_run(v, &node.value);
+}
+
+pub fn walk_vertical_align_font_property_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut VerticalAlignFontProperty,
+) {
+    VisitMut::visit_vertical_align_run_mut(v, &mut node.value);
+}
+
+pub fn walk_web_publish_item<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast WebPublishItem) {
+    Visit::visit_st_web_source_type(v, &node.source_type);
+}
+
+pub fn walk_web_publish_item_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut WebPublishItem) {
+    VisitMut::visit_st_web_source_type_mut(v, &mut node.source_type);
+}
+
+pub fn walk_web_publish_items<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast WebPublishItems,
+) {
+    for it0 in &node.web_publish_item {
+        Visit::visit_web_publish_item(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_web_publish_items_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut WebPublishItems) {
+    for it0 in &mut node.web_publish_item {
+        VisitMut::visit_web_publish_item_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_web_publishing<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast WebPublishing) {
+    if let Some(it0) = &node.target_screen_size {
+        Visit::visit_st_target_screen_size(v, it0);
+    }
+}
+
+pub fn walk_web_publishing_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut WebPublishing) {
+    if let Some(it0) = &mut node.target_screen_size {
+        VisitMut::visit_st_target_screen_size_mut(v, it0);
+    }
+}
+
+pub fn walk_web_query_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast WebQueryProperties,
+) {
+    if let Some(it0) = &node.html_format {
+        Visit::visit_st_html_fmt(v, it0);
+    }
+    if let Some(it0) = &node.tables {
+        Visit::visit_data_tables(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_web_query_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut WebQueryProperties,
+) {
+    if let Some(it0) = &mut node.html_format {
+        VisitMut::visit_st_html_fmt_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.tables {
+        VisitMut::visit_data_tables_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_workbook<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Workbook) {
+    if let Some(it0) = &node.conformance {
+        Visit::visit_st_conformance_class(v, it0);
+    }
+    if let Some(it0) = &node.file_version {
+        Visit::visit_file_version(v, it0);
+    }
+    #[cfg(feature = "sml-protection")]
+    {
+        if let Some(it0) = &node.file_sharing {
+            Visit::visit_file_sharing(v, it0);
+        }
+    }
+    if let Some(it0) = &node.workbook_pr {
+        Visit::visit_workbook_properties(v, it0);
+    }
+    #[cfg(feature = "sml-protection")]
+    {
+        if let Some(it0) = &node.workbook_protection {
+            Visit::visit_workbook_protection(v, it0);
+        }
+    }
+    if let Some(it0) = &node.book_views {
+        Visit::visit_book_views(v, it0);
+    }
+    Visit::visit_sheets(v, &node.sheets);
+    #[cfg(feature = "sml-formulas-advanced")]
+    {
+        if let Some(it0) = &node.function_groups {
+            Visit::visit_ct_function_groups(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-external")]
+    {
+        if let Some(it0) = &node.external_references {
+            Visit::visit_external_references(v, it0);
+        }
+    }
+    if let Some(it0) = &node.defined_names {
+        Visit::visit_defined_names(v, it0);
+    }
+    #[cfg(feature = "sml-formulas")]
+    {
+        if let Some(it0) = &node.calc_pr {
+            Visit::visit_calculation_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-external")]
+    {
+        if let Some(it0) = &node.ole_size {
+            Visit::visit_ct_ole_size(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-structure")]
+    {
+        if let Some(it0) = &node.custom_workbook_views {
+            Visit::visit_custom_workbook_views(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-pivot")]
+    {
+        if let Some(it0) = &node.pivot_caches {
+            Visit::visit_pivot_caches(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-metadata")]
+    {
+        if let Some(it0) = &node.smart_tag_pr {
+            Visit::visit_ct_smart_tag_pr(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-metadata")]
+    {
+        if let Some(it0) = &node.smart_tag_types {
+            Visit::visit_ct_smart_tag_types(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-external")]
+    {
+        if let Some(it0) = &node.web_publishing {
+            Visit::visit_web_publishing(v, it0);
+        }
+    }
+    for it0 in &node.file_recovery_pr {
+        Visit::visit_file_recovery_properties(v, it0);
+    }
+    #[cfg(feature = "sml-external")]
+    {
+        if let Some(it0) = &node.web_publish_objects {
+            Visit::visit_ct_web_publish_objects(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &node.extension_list {
+            Visit::visit_extension_list(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_workbook_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Workbook) {
+    if let Some(it0) = &mut node.conformance {
+        VisitMut::visit_st_conformance_class_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.file_version {
+        VisitMut::visit_file_version_mut(v, it0);
+    }
+    #[cfg(feature = "sml-protection")]
+    {
+        if let Some(it0) = &mut node.file_sharing {
+            VisitMut::visit_file_sharing_mut(v, it0);
+        }
+    }
+    if let Some(it0) = &mut node.workbook_pr {
+        VisitMut::visit_workbook_properties_mut(v, it0);
+    }
+    #[cfg(feature = "sml-protection")]
+    {
+        if let Some(it0) = &mut node.workbook_protection {
+            VisitMut::visit_workbook_protection_mut(v, it0);
+        }
+    }
+    if let Some(it0) = &mut node.book_views {
+        VisitMut::visit_book_views_mut(v, it0);
+    }
+    VisitMut::visit_sheets_mut(v, &mut node.sheets);
+    #[cfg(feature = "sml-formulas-advanced")]
+    {
+        if let Some(it0) = &mut node.function_groups {
+            VisitMut::visit_ct_function_groups_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-external")]
+    {
+        if let Some(it0) = &mut node.external_references {
+            VisitMut::visit_external_references_mut(v, it0);
+        }
+    }
+    if let Some(it0) = &mut node.defined_names {
+        VisitMut::visit_defined_names_mut(v, it0);
+    }
+    #[cfg(feature = "sml-formulas")]
+    {
+        if let Some(it0) = &mut node.calc_pr {
+            VisitMut::visit_calculation_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-external")]
+    {
+        if let Some(it0) = &mut node.ole_size {
+            VisitMut::visit_ct_ole_size_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-structure")]
+    {
+        if let Some(it0) = &mut node.custom_workbook_views {
+            VisitMut::visit_custom_workbook_views_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-pivot")]
+    {
+        if let Some(it0) = &mut node.pivot_caches {
+            VisitMut::visit_pivot_caches_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-metadata")]
+    {
+        if let Some(it0) = &mut node.smart_tag_pr {
+            VisitMut::visit_ct_smart_tag_pr_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-metadata")]
+    {
+        if let Some(it0) = &mut node.smart_tag_types {
+            VisitMut::visit_ct_smart_tag_types_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-external")]
+    {
+        if let Some(it0) = &mut node.web_publishing {
+            VisitMut::visit_web_publishing_mut(v, it0);
+        }
+    }
+    for it0 in &mut node.file_recovery_pr {
+        VisitMut::visit_file_recovery_properties_mut(v, it0);
+    }
+    #[cfg(feature = "sml-external")]
+    {
+        if let Some(it0) = &mut node.web_publish_objects {
+            VisitMut::visit_ct_web_publish_objects_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &mut node.extension_list {
+            VisitMut::visit_extension_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_workbook_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast WorkbookProperties,
+) {
+    if let Some(it0) = &node.show_objects {
+        Visit::visit_object_visibility(v, it0);
+    }
+    if let Some(it0) = &node.update_links {
+        Visit::visit_update_links(v, it0);
+    }
+}
+
+pub fn walk_workbook_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut WorkbookProperties,
+) {
+    if let Some(it0) = &mut node.show_objects {
+        VisitMut::visit_object_visibility_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.update_links {
+        VisitMut::visit_update_links_mut(v, it0);
+    }
+}
+
+pub fn walk_worksheet<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Worksheet) {
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.sheet_properties {
+            Visit::visit_sheet_properties(v, it0);
+        }
+    }
+    if let Some(it0) = &node.dimension {
+        Visit::visit_sheet_dimension(v, it0);
+    }
+    if let Some(it0) = &node.sheet_views {
+        Visit::visit_sheet_views(v, it0);
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &node.sheet_format {
+            Visit::visit_sheet_format(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        for it0 in &node.cols {
+            Visit::visit_columns(v, it0);
+        }
+    }
+    Visit::visit_sheet_data(v, &node.sheet_data);
+    #[cfg(feature = "sml-formulas")]
+    {
+        if let Some(it0) = &node.sheet_calc_pr {
+            Visit::visit_sheet_calc_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-protection")]
+    {
+        if let Some(it0) = &node.sheet_protection {
+            Visit::visit_sheet_protection(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-protection")]
+    {
+        if let Some(it0) = &node.protected_ranges {
+            Visit::visit_protected_ranges(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-formulas-advanced")]
+    {
+        if let Some(it0) = &node.scenarios {
+            Visit::visit_scenarios(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-filtering")]
+    {
+        if let Some(it0) = &node.auto_filter {
+            Visit::visit_auto_filter(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-filtering")]
+    {
+        if let Some(it0) = &node.sort_state {
+            Visit::visit_sort_state(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-formulas-advanced")]
+    {
+        if let Some(it0) = &node.data_consolidate {
+            Visit::visit_ct_data_consolidate(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-structure")]
+    {
+        if let Some(it0) = &node.custom_sheet_views {
+            Visit::visit_custom_sheet_views(v, it0);
+        }
+    }
+    if let Some(it0) = &node.merged_cells {
+        Visit::visit_merged_cells(v, it0);
+    }
+    #[cfg(feature = "sml-i18n")]
+    {
+        if let Some(it0) = &node.phonetic_pr {
+            Visit::visit_phonetic_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        for it0 in &node.conditional_formatting {
+            Visit::visit_conditional_formatting(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-validation")]
+    {
+        if let Some(it0) = &node.data_validations {
+            Visit::visit_data_validations(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-hyperlinks")]
+    {
+        if let Some(it0) = &node.hyperlinks {
+            Visit::visit_hyperlinks(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-layout")]
+    {
+        if let Some(it0) = &node.print_options {
+            Visit::visit_print_options(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-layout")]
+    {
+        if let Some(it0) = &node.page_margins {
+            Visit::visit_page_margins(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-layout")]
+    {
+        if let Some(it0) = &node.page_setup {
+            Visit::visit_page_setup(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-layout")]
+    {
+        if let Some(it0) = &node.header_footer {
+            Visit::visit_header_footer(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-layout")]
+    {
+        if let Some(it0) = &node.row_breaks {
+            Visit::visit_page_breaks(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-layout")]
+    {
+        if let Some(it0) = &node.col_breaks {
+            Visit::visit_page_breaks(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-metadata")]
+    {
+        if let Some(it0) = &node.custom_properties {
+            Visit::visit_ct_custom_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-formulas-advanced")]
+    {
+        if let Some(it0) = &node.cell_watches {
+            Visit::visit_cell_watches(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-validation")]
+    {
+        if let Some(it0) = &node.ignored_errors {
+            Visit::visit_ignored_errors(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-metadata")]
+    {
+        if let Some(it0) = &node.smart_tags {
+            Visit::visit_smart_tags(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-drawings")]
+    {
+        if let Some(it0) = &node.drawing {
+            Visit::visit_drawing(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-comments")]
+    {
+        if let Some(it0) = &node.legacy_drawing {
+            Visit::visit_legacy_drawing(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-layout")]
+    {
+        if let Some(it0) = &node.legacy_drawing_h_f {
+            Visit::visit_legacy_drawing(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-drawings")]
+    {
+        if let Some(it0) = &node.drawing_h_f {
+            Visit::visit_drawing_header_footer(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-drawings")]
+    {
+        if let Some(it0) = &node.picture {
+            Visit::visit_sheet_background_picture(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-external")]
+    {
+        if let Some(it0) = &node.ole_objects {
+            Visit::visit_ole_objects(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-external")]
+    {
+        if let Some(it0) = &node.controls {
+            Visit::visit_controls(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-external")]
+    {
+        if let Some(it0) = &node.web_publish_items {
+            Visit::visit_web_publish_items(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-tables")]
+    {
+        if let Some(it0) = &node.table_parts {
+            Visit::visit_table_parts(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &node.extension_list {
+            Visit::visit_extension_list(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_worksheet_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Worksheet) {
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.sheet_properties {
+            VisitMut::visit_sheet_properties_mut(v, it0);
+        }
+    }
+    if let Some(it0) = &mut node.dimension {
+        VisitMut::visit_sheet_dimension_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.sheet_views {
+        VisitMut::visit_sheet_views_mut(v, it0);
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        if let Some(it0) = &mut node.sheet_format {
+            VisitMut::visit_sheet_format_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        for it0 in &mut node.cols {
+            VisitMut::visit_columns_mut(v, it0);
+        }
+    }
+    VisitMut::visit_sheet_data_mut(v, &mut node.sheet_data);
+    #[cfg(feature = "sml-formulas")]
+    {
+        if let Some(it0) = &mut node.sheet_calc_pr {
+            VisitMut::visit_sheet_calc_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-protection")]
+    {
+        if let Some(it0) = &mut node.sheet_protection {
+            VisitMut::visit_sheet_protection_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-protection")]
+    {
+        if let Some(it0) = &mut node.protected_ranges {
+            VisitMut::visit_protected_ranges_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-formulas-advanced")]
+    {
+        if let Some(it0) = &mut node.scenarios {
+            VisitMut::visit_scenarios_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-filtering")]
+    {
+        if let Some(it0) = &mut node.auto_filter {
+            VisitMut::visit_auto_filter_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-filtering")]
+    {
+        if let Some(it0) = &mut node.sort_state {
+            VisitMut::visit_sort_state_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-formulas-advanced")]
+    {
+        if let Some(it0) = &mut node.data_consolidate {
+            VisitMut::visit_ct_data_consolidate_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-structure")]
+    {
+        if let Some(it0) = &mut node.custom_sheet_views {
+            VisitMut::visit_custom_sheet_views_mut(v, it0);
+        }
+    }
+    if let Some(it0) = &mut node.merged_cells {
+        VisitMut::visit_merged_cells_mut(v, it0);
+    }
+    #[cfg(feature = "sml-i18n")]
+    {
+        if let Some(it0) = &mut node.phonetic_pr {
+            VisitMut::visit_phonetic_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-styling")]
+    {
+        for it0 in &mut node.conditional_formatting {
+            VisitMut::visit_conditional_formatting_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-validation")]
+    {
+        if let Some(it0) = &mut node.data_validations {
+            VisitMut::visit_data_validations_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-hyperlinks")]
+    {
+        if let Some(it0) = &mut node.hyperlinks {
+            VisitMut::visit_hyperlinks_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-layout")]
+    {
+        if let Some(it0) = &mut node.print_options {
+            VisitMut::visit_print_options_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-layout")]
+    {
+        if let Some(it0) = &mut node.page_margins {
+            VisitMut::visit_page_margins_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-layout")]
+    {
+        if let Some(it0) = &mut node.page_setup {
+            VisitMut::visit_page_setup_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-layout")]
+    {
+        if let Some(it0) = &mut node.header_footer {
+            VisitMut::visit_header_footer_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-layout")]
+    {
+        if let Some(it0) = &mut node.row_breaks {
+            VisitMut::visit_page_breaks_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-layout")]
+    {
+        if let Some(it0) = &mut node.col_breaks {
+            VisitMut::visit_page_breaks_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-metadata")]
+    {
+        if let Some(it0) = &mut node.custom_properties {
+            VisitMut::visit_ct_custom_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-formulas-advanced")]
+    {
+        if let Some(it0) = &mut node.cell_watches {
+            VisitMut::visit_cell_watches_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-validation")]
+    {
+        if let Some(it0) = &mut node.ignored_errors {
+            VisitMut::visit_ignored_errors_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-metadata")]
+    {
+        if let Some(it0) = &mut node.smart_tags {
+            VisitMut::visit_smart_tags_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-drawings")]
+    {
+        if let Some(it0) = &mut node.drawing {
+            VisitMut::visit_drawing_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-comments")]
+    {
+        if let Some(it0) = &mut node.legacy_drawing {
+            VisitMut::visit_legacy_drawing_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-layout")]
+    {
+        if let Some(it0) = &mut node.legacy_drawing_h_f {
+            VisitMut::visit_legacy_drawing_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-drawings")]
+    {
+        if let Some(it0) = &mut node.drawing_h_f {
+            VisitMut::visit_drawing_header_footer_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-drawings")]
+    {
+        if let Some(it0) = &mut node.picture {
+            VisitMut::visit_sheet_background_picture_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-external")]
+    {
+        if let Some(it0) = &mut node.ole_objects {
+            VisitMut::visit_ole_objects_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-external")]
+    {
+        if let Some(it0) = &mut node.controls {
+            VisitMut::visit_controls_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-external")]
+    {
+        if let Some(it0) = &mut node.web_publish_items {
+            VisitMut::visit_web_publish_items_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-tables")]
+    {
+        if let Some(it0) = &mut node.table_parts {
+            VisitMut::visit_table_parts_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "sml-extensions")]
+    {
+        if let Some(it0) = &mut node.extension_list {
+            VisitMut::visit_extension_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_xml_cell_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast XmlCellProperties,
+) {
+    Visit::visit_xml_properties(v, &node.xml_pr);
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_xml_cell_properties_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut XmlCellProperties) {
+    VisitMut::visit_xml_properties_mut(v, &mut node.xml_pr);
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_xml_column_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast XmlColumnProperties,
+) {
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_xml_column_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut XmlColumnProperties,
+) {
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_xml_map<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast XmlMap) {
+    if let Some(it0) = &node.data_binding {
+        Visit::visit_data_binding(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_xml_map_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut XmlMap) {
+    if let Some(it0) = &mut node.data_binding {
+        VisitMut::visit_data_binding_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_xml_properties<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast XmlProperties) {
+    if let Some(it0) = &node.extension_list {
+        Visit::visit_extension_list(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_xml_properties_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut XmlProperties) {
+    if let Some(it0) = &mut node.extension_list {
+        VisitMut::visit_extension_list_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_xml_schema<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast XmlSchema) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_xml_schema_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut XmlSchema) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+impl ooxml_xml::QueryTree for Authors {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.author {
+            it0.visit_element("author", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for AutoFilter {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "sml-filtering")]
+        {
+            for it0 in &self.filter_column {
+                it0.visit_element("filterColumn", v);
+            }
+        }
+        #[cfg(feature = "sml-filtering")]
+        {
+            if let Some(it0) = &self.sort_state {
+                it0.visit_element("sortState", v);
+            }
+        }
+        #[cfg(feature = "sml-extensions")]
+        {
+            if let Some(it0) = &self.extension_list {
+                it0.visit_element("extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for BookView {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "sml-extensions")]
+        {
+            if let Some(it0) = &self.extension_list {
+                it0.visit_element("extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for BookViews {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.workbook_view {
+            it0.visit_element("workbookView", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for BooleanProperty {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Border {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.start {
+            it0.visit_element("start", v);
+        }
+        if let Some(it0) = &self.end {
+            it0.visit_element("end", v);
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.left {
+                it0.visit_element("left", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.right {
+                it0.visit_element("right", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.top {
+                it0.visit_element("top", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.bottom {
+                it0.visit_element("bottom", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.diagonal {
+                it0.visit_element("diagonal", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.vertical {
+                it0.visit_element("vertical", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.horizontal {
+                it0.visit_element("horizontal", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for BorderProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.color {
+            it0.visit_element("color", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Borders {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.border {
+            it0.visit_element("border", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTBoolean {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.x {
+            it0.visit_element("x", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCacheHierarchies {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.cache_hierarchy {
+            it0.visit_element("cacheHierarchy", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCacheHierarchy {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.fields_usage {
+            it0.visit_element("fieldsUsage", v);
+        }
+        if let Some(it0) = &self.group_levels {
+            it0.visit_element("groupLevels", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCalculatedItem {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.pivot_area.visit_element("pivotArea", v);
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCalculatedItems {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.calculated_item {
+            it0.visit_element("calculatedItem", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCalculatedMember {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCalculatedMembers {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.calculated_member {
+            it0.visit_element("calculatedMember", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCellSmartTagPr {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTChartFormat {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.pivot_area.visit_element("pivotArea", v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTChartFormats {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.chart_format {
+            it0.visit_element("chartFormat", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTColHierarchiesUsage {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.col_hierarchy_usage {
+            it0.visit_element("colHierarchyUsage", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTColItems {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.i {
+            it0.visit_element("i", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCommentPr {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.anchor.visit_element("anchor", v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTConditionalFormat {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.pivot_areas.visit_element("pivotAreas", v);
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTConditionalFormats {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.conditional_format {
+            it0.visit_element("conditionalFormat", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTControlPr {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.anchor.visit_element("anchor", v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCustomProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.custom_pr {
+            it0.visit_element("customPr", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCustomProperty {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDataConsolidate {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.data_refs {
+            it0.visit_element("dataRefs", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDataRef {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDataRefs {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.data_ref {
+            it0.visit_element("dataRef", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDateTime {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.x {
+            it0.visit_element("x", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDdeValue {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.value.visit_element("val", v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDdeValues {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.value {
+            it0.visit_element("value", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDeletedField {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDialogsheet {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.sheet_properties {
+            it0.visit_element("sheetPr", v);
+        }
+        if let Some(it0) = &self.sheet_views {
+            it0.visit_element("sheetViews", v);
+        }
+        if let Some(it0) = &self.sheet_format {
+            it0.visit_element("sheetFormatPr", v);
+        }
+        if let Some(it0) = &self.sheet_protection {
+            it0.visit_element("sheetProtection", v);
+        }
+        if let Some(it0) = &self.custom_sheet_views {
+            it0.visit_element("customSheetViews", v);
+        }
+        if let Some(it0) = &self.print_options {
+            it0.visit_element("printOptions", v);
+        }
+        if let Some(it0) = &self.page_margins {
+            it0.visit_element("pageMargins", v);
+        }
+        if let Some(it0) = &self.page_setup {
+            it0.visit_element("pageSetup", v);
+        }
+        if let Some(it0) = &self.header_footer {
+            it0.visit_element("headerFooter", v);
+        }
+        if let Some(it0) = &self.drawing {
+            it0.visit_element("drawing", v);
+        }
+        if let Some(it0) = &self.legacy_drawing {
+            it0.visit_element("legacyDrawing", v);
+        }
+        if let Some(it0) = &self.legacy_drawing_h_f {
+            it0.visit_element("legacyDrawingHF", v);
+        }
+        if let Some(it0) = &self.drawing_h_f {
+            it0.visit_element("drawingHF", v);
+        }
+        if let Some(it0) = &self.ole_objects {
+            it0.visit_element("oleObjects", v);
+        }
+        if let Some(it0) = &self.controls {
+            it0.visit_element("controls", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDimensions {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.dimension {
+            it0.visit_element("dimension", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDiscretePr {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.x {
+            it0.visit_element("x", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTError {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.tpls {
+            it0.visit_element("tpls", v);
+        }
+        for it0 in &self.x {
+            it0.visit_element("x", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTExternalDefinedName {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTExternalDefinedNames {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.defined_name {
+            it0.visit_element("definedName", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTExternalSheetName {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTExternalSheetNames {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.sheet_name {
+            it0.visit_element("sheetName", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTField {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFieldUsage {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFieldsUsage {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.field_usage {
+            it0.visit_element("fieldUsage", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFormat {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.pivot_area.visit_element("pivotArea", v);
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFormats {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.format {
+            it0.visit_element("format", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFunctionGroup {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFunctionGroups {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.function_group {
+            it0.visit_element("functionGroup", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFutureMetadata {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.bk {
+            it0.visit_element("bk", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFutureMetadataBlock {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTGroupLevel {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.groups {
+            it0.visit_element("groups", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTGroupLevels {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.group_level {
+            it0.visit_element("groupLevel", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTGroupMember {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTGroupMembers {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.group_member {
+            it0.visit_element("groupMember", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTGroups {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.group {
+            it0.visit_element("group", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTHierarchyUsage {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTI {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.x {
+            it0.visit_element("x", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTIndex {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTLevelGroup {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.group_members.visit_element("groupMembers", v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMacrosheet {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.sheet_properties {
+            it0.visit_element("sheetPr", v);
+        }
+        if let Some(it0) = &self.dimension {
+            it0.visit_element("dimension", v);
+        }
+        if let Some(it0) = &self.sheet_views {
+            it0.visit_element("sheetViews", v);
+        }
+        if let Some(it0) = &self.sheet_format {
+            it0.visit_element("sheetFormatPr", v);
+        }
+        for it0 in &self.cols {
+            it0.visit_element("cols", v);
+        }
+        self.sheet_data.visit_element("sheetData", v);
+        if let Some(it0) = &self.sheet_protection {
+            it0.visit_element("sheetProtection", v);
+        }
+        if let Some(it0) = &self.auto_filter {
+            it0.visit_element("autoFilter", v);
+        }
+        if let Some(it0) = &self.sort_state {
+            it0.visit_element("sortState", v);
+        }
+        if let Some(it0) = &self.data_consolidate {
+            it0.visit_element("dataConsolidate", v);
+        }
+        if let Some(it0) = &self.custom_sheet_views {
+            it0.visit_element("customSheetViews", v);
+        }
+        if let Some(it0) = &self.phonetic_pr {
+            it0.visit_element("phoneticPr", v);
+        }
+        for it0 in &self.conditional_formatting {
+            it0.visit_element("conditionalFormatting", v);
+        }
+        if let Some(it0) = &self.print_options {
+            it0.visit_element("printOptions", v);
+        }
+        if let Some(it0) = &self.page_margins {
+            it0.visit_element("pageMargins", v);
+        }
+        if let Some(it0) = &self.page_setup {
+            it0.visit_element("pageSetup", v);
+        }
+        if let Some(it0) = &self.header_footer {
+            it0.visit_element("headerFooter", v);
+        }
+        if let Some(it0) = &self.row_breaks {
+            it0.visit_element("rowBreaks", v);
+        }
+        if let Some(it0) = &self.col_breaks {
+            it0.visit_element("colBreaks", v);
+        }
+        if let Some(it0) = &self.custom_properties {
+            it0.visit_element("customProperties", v);
+        }
+        if let Some(it0) = &self.drawing {
+            it0.visit_element("drawing", v);
+        }
+        if let Some(it0) = &self.legacy_drawing {
+            it0.visit_element("legacyDrawing", v);
+        }
+        if let Some(it0) = &self.legacy_drawing_h_f {
+            it0.visit_element("legacyDrawingHF", v);
+        }
+        if let Some(it0) = &self.drawing_h_f {
+            it0.visit_element("drawingHF", v);
+        }
+        if let Some(it0) = &self.picture {
+            it0.visit_element("picture", v);
+        }
+        if let Some(it0) = &self.ole_objects {
+            it0.visit_element("oleObjects", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMdx {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.cell_type {
+            it0.visit_element("t", v);
+        }
+        if let Some(it0) = &self.ms {
+            it0.visit_element("ms", v);
+        }
+        if let Some(it0) = &self.p {
+            it0.visit_element("p", v);
+        }
+        if let Some(it0) = &self.k {
+            it0.visit_element("k", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMdxKPI {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMdxMemeberProp {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMdxMetadata {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.mdx {
+            it0.visit_element("mdx", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMdxSet {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.n {
+            it0.visit_element("n", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMdxTuple {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.n {
+            it0.visit_element("n", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMeasureDimensionMap {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMeasureDimensionMaps {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.map {
+            it0.visit_element("map", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMeasureGroup {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMeasureGroups {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.measure_group {
+            it0.visit_element("measureGroup", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMember {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMemberProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.mp {
+            it0.visit_element("mp", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMemberProperty {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMembers {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.member {
+            it0.visit_element("member", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMetadataStringIndex {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMissing {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.tpls {
+            it0.visit_element("tpls", v);
+        }
+        for it0 in &self.x {
+            it0.visit_element("x", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTNumber {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.tpls {
+            it0.visit_element("tpls", v);
+        }
+        for it0 in &self.x {
+            it0.visit_element("x", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTOleSize {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPCDKPI {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPCDKPIs {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.kpi {
+            it0.visit_element("kpi", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPCDSCPage {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.page_item {
+            it0.visit_element("pageItem", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPCDSDTCEntries {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.m {
+            it0.visit_element("m", v);
+        }
+        for it0 in &self.n {
+            it0.visit_element("n", v);
+        }
+        for it0 in &self.e {
+            it0.visit_element("e", v);
+        }
+        for it0 in &self.style_index {
+            it0.visit_element("s", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPageItem {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPages {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.page {
+            it0.visit_element("page", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPivotAreaReference {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.x {
+            it0.visit_element("x", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPivotAreaReferences {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.reference {
+            it0.visit_element("reference", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPivotCache {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPivotDimension {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPivotHierarchies {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.pivot_hierarchy {
+            it0.visit_element("pivotHierarchy", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPivotHierarchy {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.mps {
+            it0.visit_element("mps", v);
+        }
+        for it0 in &self.members {
+            it0.visit_element("members", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPivotSelection {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.pivot_area.visit_element("pivotArea", v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPivotTableDefinition {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.location.visit_element("location", v);
+        if let Some(it0) = &self.pivot_fields {
+            it0.visit_element("pivotFields", v);
+        }
+        if let Some(it0) = &self.row_fields {
+            it0.visit_element("rowFields", v);
+        }
+        if let Some(it0) = &self.row_items {
+            it0.visit_element("rowItems", v);
+        }
+        if let Some(it0) = &self.col_fields {
+            it0.visit_element("colFields", v);
+        }
+        if let Some(it0) = &self.col_items {
+            it0.visit_element("colItems", v);
+        }
+        if let Some(it0) = &self.page_fields {
+            it0.visit_element("pageFields", v);
+        }
+        if let Some(it0) = &self.data_fields {
+            it0.visit_element("dataFields", v);
+        }
+        if let Some(it0) = &self.formats {
+            it0.visit_element("formats", v);
+        }
+        if let Some(it0) = &self.conditional_formats {
+            it0.visit_element("conditionalFormats", v);
+        }
+        if let Some(it0) = &self.chart_formats {
+            it0.visit_element("chartFormats", v);
+        }
+        if let Some(it0) = &self.pivot_hierarchies {
+            it0.visit_element("pivotHierarchies", v);
+        }
+        if let Some(it0) = &self.pivot_table_style_info {
+            it0.visit_element("pivotTableStyleInfo", v);
+        }
+        if let Some(it0) = &self.filters {
+            it0.visit_element("filters", v);
+        }
+        if let Some(it0) = &self.row_hierarchies_usage {
+            it0.visit_element("rowHierarchiesUsage", v);
+        }
+        if let Some(it0) = &self.col_hierarchies_usage {
+            it0.visit_element("colHierarchiesUsage", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPivotTableStyle {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTQuery {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.tpls {
+            it0.visit_element("tpls", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTQueryCache {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.query {
+            it0.visit_element("query", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTRangePr {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTRangeSet {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTRangeSets {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.range_set {
+            it0.visit_element("rangeSet", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTRecord {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.m {
+            it0.visit_element("m", v);
+        }
+        for it0 in &self.n {
+            it0.visit_element("n", v);
+        }
+        for it0 in &self.b {
+            it0.visit_element("b", v);
+        }
+        for it0 in &self.e {
+            it0.visit_element("e", v);
+        }
+        for it0 in &self.style_index {
+            it0.visit_element("s", v);
+        }
+        for it0 in &self.d {
+            it0.visit_element("d", v);
+        }
+        for it0 in &self.x {
+            it0.visit_element("x", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTRowHierarchiesUsage {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.row_hierarchy_usage {
+            it0.visit_element("rowHierarchyUsage", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTRowItems {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.i {
+            it0.visit_element("i", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTServerFormat {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTServerFormats {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.server_format {
+            it0.visit_element("serverFormat", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSet {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.tpls {
+            it0.visit_element("tpls", v);
+        }
+        if let Some(it0) = &self.sort_by_tuple {
+            it0.visit_element("sortByTuple", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSets {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.set {
+            it0.visit_element("set", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSheetId {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSheetIdMap {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.sheet_id {
+            it0.visit_element("sheetId", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSmartTagPr {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSmartTagType {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSmartTagTypes {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.smart_tag_type {
+            it0.visit_element("smartTagType", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTString {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.tpls {
+            it0.visit_element("tpls", v);
+        }
+        for it0 in &self.x {
+            it0.visit_element("x", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTuple {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTupleCache {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.entries {
+            it0.visit_element("entries", v);
+        }
+        if let Some(it0) = &self.sets {
+            it0.visit_element("sets", v);
+        }
+        if let Some(it0) = &self.query_cache {
+            it0.visit_element("queryCache", v);
+        }
+        if let Some(it0) = &self.server_formats {
+            it0.visit_element("serverFormats", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTuples {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.tpl {
+            it0.visit_element("tpl", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTVolMain {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.tp {
+            it0.visit_element("tp", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTVolTopic {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.value.visit_element("v", v);
+        for it0 in &self.stp {
+            it0.visit_element("stp", v);
+        }
+        for it0 in &self.tr {
+            it0.visit_element("tr", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTVolTopicRef {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTVolType {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.main {
+            it0.visit_element("main", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTVolTypes {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.vol_type {
+            it0.visit_element("volType", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTWebPublishObject {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTWebPublishObjects {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.web_publish_object {
+            it0.visit_element("webPublishObject", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTX {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTXStringElement {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CacheField {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.shared_items {
+            it0.visit_element("sharedItems", v);
+        }
+        if let Some(it0) = &self.field_group {
+            it0.visit_element("fieldGroup", v);
+        }
+        for it0 in &self.mp_map {
+            it0.visit_element("mpMap", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CacheFields {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.cache_field {
+            it0.visit_element("cacheField", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CacheSource {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.worksheet_source {
+            it0.visit_element("worksheetSource", v);
+        }
+        if let Some(it0) = &self.consolidation {
+            it0.visit_element("consolidation", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CalcCell {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CalcChain {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "sml-formulas")]
+        {
+            for it0 in &self.cells {
+                it0.visit_element("c", v);
+            }
+        }
+        #[cfg(feature = "sml-extensions")]
+        {
+            if let Some(it0) = &self.extension_list {
+                it0.visit_element("extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CalculationProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Cell {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.formula {
+            it0.visit_element("f", v);
+        }
+        if let Some(it0) = &self.value {
+            it0.visit_element("v", v);
+        }
+        if let Some(it0) = &self.is {
+            it0.visit_element("is", v);
+        }
+        #[cfg(feature = "sml-extensions")]
+        {
+            if let Some(it0) = &self.extension_list {
+                it0.visit_element("extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CellAlignment {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CellFormats {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.xf {
+            it0.visit_element("xf", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CellFormula {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.text {
+            v.text(it0);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CellProtection {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CellSmartTag {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.cell_smart_tag_pr {
+            it0.visit_element("cellSmartTagPr", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CellSmartTags {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.cell_smart_tag {
+            it0.visit_element("cellSmartTag", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CellStyle {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CellStyleFormats {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.xf {
+            it0.visit_element("xf", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CellStyles {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.cell_style {
+            it0.visit_element("cellStyle", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CellWatch {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CellWatches {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.cell_watch {
+            it0.visit_element("cellWatch", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Chartsheet {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.sheet_properties {
+            it0.visit_element("sheetPr", v);
+        }
+        self.sheet_views.visit_element("sheetViews", v);
+        if let Some(it0) = &self.sheet_protection {
+            it0.visit_element("sheetProtection", v);
+        }
+        if let Some(it0) = &self.custom_sheet_views {
+            it0.visit_element("customSheetViews", v);
+        }
+        if let Some(it0) = &self.page_margins {
+            it0.visit_element("pageMargins", v);
+        }
+        if let Some(it0) = &self.page_setup {
+            it0.visit_element("pageSetup", v);
+        }
+        if let Some(it0) = &self.header_footer {
+            it0.visit_element("headerFooter", v);
+        }
+        self.drawing.visit_element("drawing", v);
+        if let Some(it0) = &self.legacy_drawing {
+            it0.visit_element("legacyDrawing", v);
+        }
+        if let Some(it0) = &self.legacy_drawing_h_f {
+            it0.visit_element("legacyDrawingHF", v);
+        }
+        if let Some(it0) = &self.drawing_h_f {
+            it0.visit_element("drawingHF", v);
+        }
+        if let Some(it0) = &self.picture {
+            it0.visit_element("picture", v);
+        }
+        if let Some(it0) = &self.web_publish_items {
+            it0.visit_element("webPublishItems", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ChartsheetPageSetup {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ChartsheetProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.tab_color {
+            it0.visit_element("tabColor", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ChartsheetProtection {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ChartsheetView {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ChartsheetViews {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.sheet_view {
+            it0.visit_element("sheetView", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ColFields {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.field {
+            it0.visit_element("field", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Color {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ColorFilter {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ColorScale {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "sml-styling")]
+        {
+            for it0 in &self.cfvo {
+                it0.visit_element("cfvo", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            for it0 in &self.color {
+                it0.visit_element("color", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Colors {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.indexed_colors {
+                it0.visit_element("indexedColors", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.mru_colors {
+                it0.visit_element("mruColors", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Column {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Columns {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.col {
+            it0.visit_element("col", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Comment {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "sml-comments")]
+        {
+            self.text.visit_element("text", v);
+        }
+        if let Some(it0) = &self.comment_pr {
+            it0.visit_element("commentPr", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CommentList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.comment {
+            it0.visit_element("comment", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Comments {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.authors.visit_element("authors", v);
+        self.comment_list.visit_element("commentList", v);
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ConditionalFormatValue {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ConditionalFormatting {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "sml-styling")]
+        {
+            for it0 in &self.cf_rule {
+                it0.visit_element("cfRule", v);
+            }
+        }
+        #[cfg(feature = "sml-extensions")]
+        {
+            if let Some(it0) = &self.extension_list {
+                it0.visit_element("extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ConditionalRule {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "sml-styling")]
+        {
+            for it0 in &self.formula {
+                it0.visit_element("formula", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.color_scale {
+                it0.visit_element("colorScale", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.data_bar {
+                it0.visit_element("dataBar", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.icon_set {
+                it0.visit_element("iconSet", v);
+            }
+        }
+        #[cfg(feature = "sml-extensions")]
+        {
+            if let Some(it0) = &self.extension_list {
+                it0.visit_element("extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Connection {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.db_pr {
+            it0.visit_element("dbPr", v);
+        }
+        if let Some(it0) = &self.olap_pr {
+            it0.visit_element("olapPr", v);
+        }
+        if let Some(it0) = &self.web_pr {
+            it0.visit_element("webPr", v);
+        }
+        if let Some(it0) = &self.text_pr {
+            it0.visit_element("textPr", v);
+        }
+        if let Some(it0) = &self.parameters {
+            it0.visit_element("parameters", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Connections {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.connection {
+            it0.visit_element("connection", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Consolidation {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.pages {
+            it0.visit_element("pages", v);
+        }
+        self.range_sets.visit_element("rangeSets", v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Control {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.control_pr {
+            it0.visit_element("controlPr", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Controls {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.control {
+            it0.visit_element("control", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CustomChartsheetView {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.page_margins {
+            it0.visit_element("pageMargins", v);
+        }
+        if let Some(it0) = &self.page_setup {
+            it0.visit_element("pageSetup", v);
+        }
+        if let Some(it0) = &self.header_footer {
+            it0.visit_element("headerFooter", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CustomChartsheetViews {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.custom_sheet_view {
+            it0.visit_element("customSheetView", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CustomFilter {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CustomFilters {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.custom_filter {
+            it0.visit_element("customFilter", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CustomSheetView {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.pane {
+            it0.visit_element("pane", v);
+        }
+        if let Some(it0) = &self.selection {
+            it0.visit_element("selection", v);
+        }
+        if let Some(it0) = &self.row_breaks {
+            it0.visit_element("rowBreaks", v);
+        }
+        if let Some(it0) = &self.col_breaks {
+            it0.visit_element("colBreaks", v);
+        }
+        if let Some(it0) = &self.page_margins {
+            it0.visit_element("pageMargins", v);
+        }
+        if let Some(it0) = &self.print_options {
+            it0.visit_element("printOptions", v);
+        }
+        if let Some(it0) = &self.page_setup {
+            it0.visit_element("pageSetup", v);
+        }
+        if let Some(it0) = &self.header_footer {
+            it0.visit_element("headerFooter", v);
+        }
+        if let Some(it0) = &self.auto_filter {
+            it0.visit_element("autoFilter", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CustomSheetViews {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.custom_sheet_view {
+            it0.visit_element("customSheetView", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CustomWorkbookView {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CustomWorkbookViews {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.custom_workbook_view {
+            it0.visit_element("customWorkbookView", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for DataBar {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "sml-styling")]
+        {
+            for it0 in &self.cfvo {
+                it0.visit_element("cfvo", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            self.color.visit_element("color", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for DataBinding {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for DataField {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for DataFields {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.data_field {
+            it0.visit_element("dataField", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for DataTables {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.m {
+            it0.visit_element("m", v);
+        }
+        for it0 in &self.style_index {
+            it0.visit_element("s", v);
+        }
+        for it0 in &self.x {
+            it0.visit_element("x", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for DataValidation {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "sml-validation")]
+        {
+            if let Some(it0) = &self.formula1 {
+                it0.visit_element("formula1", v);
+            }
+        }
+        #[cfg(feature = "sml-validation")]
+        {
+            if let Some(it0) = &self.formula2 {
+                it0.visit_element("formula2", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for DataValidations {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.data_validation {
+            it0.visit_element("dataValidation", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for DatabaseProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for DateGroupItem {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for DdeItem {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.values {
+            it0.visit_element("values", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for DdeItems {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.dde_item {
+            it0.visit_element("ddeItem", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for DdeLink {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.dde_items {
+            it0.visit_element("ddeItems", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for DefinedName {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.text {
+            v.text(it0);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for DefinedNames {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.defined_name {
+            it0.visit_element("definedName", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for DifferentialFormat {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.font {
+            it0.visit_element("font", v);
+        }
+        if let Some(it0) = &self.num_fmt {
+            it0.visit_element("numFmt", v);
+        }
+        if let Some(it0) = &self.fill {
+            it0.visit_element("fill", v);
+        }
+        if let Some(it0) = &self.alignment {
+            it0.visit_element("alignment", v);
+        }
+        if let Some(it0) = &self.border {
+            it0.visit_element("border", v);
+        }
+        if let Some(it0) = &self.protection {
+            it0.visit_element("protection", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for DifferentialFormats {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.dxf {
+            it0.visit_element("dxf", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Drawing {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for DrawingHeaderFooter {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for DynamicFilter {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for EGExtensionList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.ext {
+            it0.visit_element("ext", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Extension {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ExtensionList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.ext {
+            it0.visit_element("ext", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ExternalBook {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.sheet_names {
+            it0.visit_element("sheetNames", v);
+        }
+        if let Some(it0) = &self.defined_names {
+            it0.visit_element("definedNames", v);
+        }
+        if let Some(it0) = &self.sheet_data_set {
+            it0.visit_element("sheetDataSet", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ExternalCell {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.value {
+            it0.visit_element("v", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ExternalLink {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.external_book {
+            it0.visit_element("externalBook", v);
+        }
+        if let Some(it0) = &self.dde_link {
+            it0.visit_element("ddeLink", v);
+        }
+        if let Some(it0) = &self.ole_link {
+            it0.visit_element("oleLink", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ExternalReference {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ExternalReferences {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.external_reference {
+            it0.visit_element("externalReference", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ExternalRow {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.cell {
+            it0.visit_element("cell", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ExternalSheetData {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.row {
+            it0.visit_element("row", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ExternalSheetDataSet {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.sheet_data {
+            it0.visit_element("sheetData", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for FieldGroup {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.range_pr {
+            it0.visit_element("rangePr", v);
+        }
+        if let Some(it0) = &self.discrete_pr {
+            it0.visit_element("discretePr", v);
+        }
+        if let Some(it0) = &self.group_items {
+            it0.visit_element("groupItems", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for FileRecoveryProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for FileSharing {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for FileVersion {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Fill {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.pattern_fill {
+                it0.visit_element("patternFill", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.gradient_fill {
+                it0.visit_element("gradientFill", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Fills {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.fill {
+            it0.visit_element("fill", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Filter {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for FilterColumn {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "sml-filtering")]
+        {
+            if let Some(it0) = &self.filters {
+                it0.visit_element("filters", v);
+            }
+        }
+        #[cfg(feature = "sml-filtering")]
+        {
+            if let Some(it0) = &self.top10 {
+                it0.visit_element("top10", v);
+            }
+        }
+        #[cfg(feature = "sml-filtering")]
+        {
+            if let Some(it0) = &self.custom_filters {
+                it0.visit_element("customFilters", v);
+            }
+        }
+        #[cfg(feature = "sml-filtering")]
+        {
+            if let Some(it0) = &self.dynamic_filter {
+                it0.visit_element("dynamicFilter", v);
+            }
+        }
+        #[cfg(feature = "sml-filtering")]
+        {
+            if let Some(it0) = &self.color_filter {
+                it0.visit_element("colorFilter", v);
+            }
+        }
+        #[cfg(feature = "sml-filtering")]
+        {
+            if let Some(it0) = &self.icon_filter {
+                it0.visit_element("iconFilter", v);
+            }
+        }
+        #[cfg(feature = "sml-extensions")]
+        {
+            if let Some(it0) = &self.extension_list {
+                it0.visit_element("extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Filters {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.filter {
+            it0.visit_element("filter", v);
+        }
+        for it0 in &self.date_group_item {
+            it0.visit_element("dateGroupItem", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Font {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.name {
+                it0.visit_element("name", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.charset {
+                it0.visit_element("charset", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.family {
+                it0.visit_element("family", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.b {
+                it0.visit_element("b", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.i {
+                it0.visit_element("i", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.strike {
+                it0.visit_element("strike", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.outline {
+                it0.visit_element("outline", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.shadow {
+                it0.visit_element("shadow", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.condense {
+                it0.visit_element("condense", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.extend {
+                it0.visit_element("extend", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.color {
+                it0.visit_element("color", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.sz {
+                it0.visit_element("sz", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.u {
+                it0.visit_element("u", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.vert_align {
+                it0.visit_element("vertAlign", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.scheme {
+                it0.visit_element("scheme", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for FontFamily {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for FontName {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for FontSchemeProperty {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for FontSize {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Fonts {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.font {
+            it0.visit_element("font", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Format {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.alignment {
+                it0.visit_element("alignment", v);
+            }
+        }
+        #[cfg(feature = "sml-protection")]
+        {
+            if let Some(it0) = &self.protection {
+                it0.visit_element("protection", v);
+            }
+        }
+        #[cfg(feature = "sml-extensions")]
+        {
+            if let Some(it0) = &self.extension_list {
+                it0.visit_element("extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for GradientFill {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.stop {
+            it0.visit_element("stop", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for GradientStop {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.color.visit_element("color", v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for GroupItems {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.m {
+            it0.visit_element("m", v);
+        }
+        for it0 in &self.n {
+            it0.visit_element("n", v);
+        }
+        for it0 in &self.b {
+            it0.visit_element("b", v);
+        }
+        for it0 in &self.e {
+            it0.visit_element("e", v);
+        }
+        for it0 in &self.style_index {
+            it0.visit_element("s", v);
+        }
+        for it0 in &self.d {
+            it0.visit_element("d", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for HeaderFooter {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "sml-layout")]
+        {
+            if let Some(it0) = &self.odd_header {
+                it0.visit_element("oddHeader", v);
+            }
+        }
+        #[cfg(feature = "sml-layout")]
+        {
+            if let Some(it0) = &self.odd_footer {
+                it0.visit_element("oddFooter", v);
+            }
+        }
+        #[cfg(feature = "sml-layout")]
+        {
+            if let Some(it0) = &self.even_header {
+                it0.visit_element("evenHeader", v);
+            }
+        }
+        #[cfg(feature = "sml-layout")]
+        {
+            if let Some(it0) = &self.even_footer {
+                it0.visit_element("evenFooter", v);
+            }
+        }
+        #[cfg(feature = "sml-layout")]
+        {
+            if let Some(it0) = &self.first_header {
+                it0.visit_element("firstHeader", v);
+            }
+        }
+        #[cfg(feature = "sml-layout")]
+        {
+            if let Some(it0) = &self.first_footer {
+                it0.visit_element("firstFooter", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Hyperlink {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Hyperlinks {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.hyperlink {
+            it0.visit_element("hyperlink", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for IconFilter {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for IconSet {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "sml-styling")]
+        {
+            for it0 in &self.cfvo {
+                it0.visit_element("cfvo", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for IgnoredError {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for IgnoredErrors {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.ignored_error {
+            it0.visit_element("ignoredError", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for IndexedColors {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.rgb_color {
+            it0.visit_element("rgbColor", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for InputCells {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for IntProperty {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for LegacyDrawing {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for MapInfo {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.schema {
+            it0.visit_element("Schema", v);
+        }
+        for it0 in &self.map {
+            it0.visit_element("Map", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for MergedCell {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for MergedCells {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.merge_cell {
+            it0.visit_element("mergeCell", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Metadata {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.metadata_types {
+            it0.visit_element("metadataTypes", v);
+        }
+        if let Some(it0) = &self.metadata_strings {
+            it0.visit_element("metadataStrings", v);
+        }
+        if let Some(it0) = &self.mdx_metadata {
+            it0.visit_element("mdxMetadata", v);
+        }
+        for it0 in &self.future_metadata {
+            it0.visit_element("futureMetadata", v);
+        }
+        if let Some(it0) = &self.cell_metadata {
+            it0.visit_element("cellMetadata", v);
+        }
+        if let Some(it0) = &self.value_metadata {
+            it0.visit_element("valueMetadata", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for MetadataBlock {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.rc {
+            it0.visit_element("rc", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for MetadataBlocks {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.bk {
+            it0.visit_element("bk", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for MetadataRecord {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for MetadataStrings {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.style_index {
+            it0.visit_element("s", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for MetadataType {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for MetadataTypes {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.metadata_type {
+            it0.visit_element("metadataType", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for MostRecentColors {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.color {
+            it0.visit_element("color", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for NumberFormat {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for NumberFormats {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.num_fmt {
+            it0.visit_element("numFmt", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ObjectAnchor {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ObjectProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.anchor.visit_element("anchor", v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for OlapProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for OleItem {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for OleItems {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.ole_item {
+            it0.visit_element("oleItem", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for OleLink {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.ole_items {
+            it0.visit_element("oleItems", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for OleObject {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.object_pr {
+            it0.visit_element("objectPr", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for OleObjects {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.ole_object {
+            it0.visit_element("oleObject", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for OutlineProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PageBreak {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PageBreaks {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "sml-layout")]
+        {
+            for it0 in &self.brk {
+                it0.visit_element("brk", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PageField {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PageFields {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.page_field {
+            it0.visit_element("pageField", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PageMargins {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PageSetup {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PageSetupProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Pane {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Parameter {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Parameters {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.parameter {
+            it0.visit_element("parameter", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PatternFill {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.fg_color {
+            it0.visit_element("fgColor", v);
+        }
+        if let Some(it0) = &self.bg_color {
+            it0.visit_element("bgColor", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PhoneticProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PhoneticRun {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.cell_type.visit_element("t", v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PivotArea {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.references {
+            it0.visit_element("references", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PivotAreas {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.pivot_area {
+            it0.visit_element("pivotArea", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PivotCacheDefinition {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.cache_source.visit_element("cacheSource", v);
+        self.cache_fields.visit_element("cacheFields", v);
+        if let Some(it0) = &self.cache_hierarchies {
+            it0.visit_element("cacheHierarchies", v);
+        }
+        if let Some(it0) = &self.kpis {
+            it0.visit_element("kpis", v);
+        }
+        if let Some(it0) = &self.calculated_items {
+            it0.visit_element("calculatedItems", v);
+        }
+        if let Some(it0) = &self.calculated_members {
+            it0.visit_element("calculatedMembers", v);
+        }
+        if let Some(it0) = &self.dimensions {
+            it0.visit_element("dimensions", v);
+        }
+        if let Some(it0) = &self.measure_groups {
+            it0.visit_element("measureGroups", v);
+        }
+        if let Some(it0) = &self.maps {
+            it0.visit_element("maps", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PivotCacheRecords {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.reference {
+            it0.visit_element("r", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PivotCaches {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.pivot_cache {
+            it0.visit_element("pivotCache", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PivotField {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.items {
+            it0.visit_element("items", v);
+        }
+        if let Some(it0) = &self.auto_sort_scope {
+            it0.visit_element("autoSortScope", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PivotFields {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.pivot_field {
+            it0.visit_element("pivotField", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PivotFilter {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.auto_filter.visit_element("autoFilter", v);
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PivotFilters {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.filter {
+            it0.visit_element("filter", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PivotItem {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PivotItems {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.item {
+            it0.visit_element("item", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PivotLocation {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PrintOptions {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ProtectedRange {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ProtectedRanges {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.protected_range {
+            it0.visit_element("protectedRange", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for QueryTable {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.query_table_refresh {
+            it0.visit_element("queryTableRefresh", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for QueryTableDeletedFields {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.deleted_field {
+            it0.visit_element("deletedField", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for QueryTableField {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for QueryTableFields {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.query_table_field {
+            it0.visit_element("queryTableField", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for QueryTableRefresh {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.query_table_fields.visit_element("queryTableFields", v);
+        if let Some(it0) = &self.query_table_deleted_fields {
+            it0.visit_element("queryTableDeletedFields", v);
+        }
+        if let Some(it0) = &self.sort_state {
+            it0.visit_element("sortState", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Reviewed {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ReviewedRevisions {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.reviewed {
+            it0.visit_element("reviewed", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RevisionAutoFormatting {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RevisionCellChange {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.oc {
+            it0.visit_element("oc", v);
+        }
+        self.nc.visit_element("nc", v);
+        if let Some(it0) = &self.ndxf {
+            it0.visit_element("ndxf", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RevisionComment {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RevisionConflict {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RevisionCustomView {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RevisionDefinedName {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.formula {
+            it0.visit_element("formula", v);
+        }
+        if let Some(it0) = &self.old_formula {
+            it0.visit_element("oldFormula", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RevisionFormatting {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.dxf {
+            it0.visit_element("dxf", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RevisionHeader {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.sheet_id_map.visit_element("sheetIdMap", v);
+        if let Some(it0) = &self.reviewed_list {
+            it0.visit_element("reviewedList", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RevisionHeaders {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.header {
+            it0.visit_element("header", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RevisionInsertSheet {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RevisionMove {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RevisionQueryTableField {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RevisionRowColumn {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RevisionSheetRename {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Revisions {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RgbColor {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RichString {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.cell_type {
+            it0.visit_element("t", v);
+        }
+        for it0 in &self.reference {
+            it0.visit_element("r", v);
+        }
+        for it0 in &self.r_ph {
+            it0.visit_element("rPh", v);
+        }
+        if let Some(it0) = &self.phonetic_pr {
+            it0.visit_element("phoneticPr", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RichTextElement {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.r_pr {
+            it0.visit_element("rPr", v);
+        }
+        self.cell_type.visit_element("t", v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RichTextRunProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.r_font {
+            it0.visit_element("rFont", v);
+        }
+        if let Some(it0) = &self.charset {
+            it0.visit_element("charset", v);
+        }
+        if let Some(it0) = &self.family {
+            it0.visit_element("family", v);
+        }
+        if let Some(it0) = &self.b {
+            it0.visit_element("b", v);
+        }
+        if let Some(it0) = &self.i {
+            it0.visit_element("i", v);
+        }
+        if let Some(it0) = &self.strike {
+            it0.visit_element("strike", v);
+        }
+        if let Some(it0) = &self.outline {
+            it0.visit_element("outline", v);
+        }
+        if let Some(it0) = &self.shadow {
+            it0.visit_element("shadow", v);
+        }
+        if let Some(it0) = &self.condense {
+            it0.visit_element("condense", v);
+        }
+        if let Some(it0) = &self.extend {
+            it0.visit_element("extend", v);
+        }
+        if let Some(it0) = &self.color {
+            it0.visit_element("color", v);
+        }
+        if let Some(it0) = &self.sz {
+            it0.visit_element("sz", v);
+        }
+        if let Some(it0) = &self.u {
+            it0.visit_element("u", v);
+        }
+        if let Some(it0) = &self.vert_align {
+            it0.visit_element("vertAlign", v);
+        }
+        if let Some(it0) = &self.scheme {
+            it0.visit_element("scheme", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Row {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.cells {
+            it0.visit_element("c", v);
+        }
+        #[cfg(feature = "sml-extensions")]
+        {
+            if let Some(it0) = &self.extension_list {
+                it0.visit_element("extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RowFields {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.field {
+            it0.visit_element("field", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Scenario {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.input_cells {
+            it0.visit_element("inputCells", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Scenarios {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.scenario {
+            it0.visit_element("scenario", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Selection {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SharedItems {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.m {
+            it0.visit_element("m", v);
+        }
+        for it0 in &self.n {
+            it0.visit_element("n", v);
+        }
+        for it0 in &self.b {
+            it0.visit_element("b", v);
+        }
+        for it0 in &self.e {
+            it0.visit_element("e", v);
+        }
+        for it0 in &self.style_index {
+            it0.visit_element("s", v);
+        }
+        for it0 in &self.d {
+            it0.visit_element("d", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SharedStrings {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.si {
+            it0.visit_element("si", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SharedUser {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Sheet {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SheetBackgroundPicture {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SheetCalcProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SheetData {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.row {
+            it0.visit_element("row", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SheetDimension {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SheetFormat {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SheetProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.tab_color {
+                it0.visit_element("tabColor", v);
+            }
+        }
+        #[cfg(feature = "sml-structure")]
+        {
+            if let Some(it0) = &self.outline_pr {
+                it0.visit_element("outlinePr", v);
+            }
+        }
+        #[cfg(feature = "sml-layout")]
+        {
+            if let Some(it0) = &self.page_set_up_pr {
+                it0.visit_element("pageSetUpPr", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SheetProtection {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SheetView {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "sml-structure")]
+        {
+            if let Some(it0) = &self.pane {
+                it0.visit_element("pane", v);
+            }
+        }
+        for it0 in &self.selection {
+            it0.visit_element("selection", v);
+        }
+        #[cfg(feature = "sml-pivot")]
+        {
+            for it0 in &self.pivot_selection {
+                it0.visit_element("pivotSelection", v);
+            }
+        }
+        #[cfg(feature = "sml-extensions")]
+        {
+            if let Some(it0) = &self.extension_list {
+                it0.visit_element("extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SheetViews {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.sheet_view {
+            it0.visit_element("sheetView", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Sheets {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.sheet {
+            it0.visit_element("sheet", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SingleXmlCell {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.xml_cell_pr.visit_element("xmlCellPr", v);
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SingleXmlCells {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.single_xml_cell {
+            it0.visit_element("singleXmlCell", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SmartTags {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.cell_smart_tags {
+            it0.visit_element("cellSmartTags", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SmlAGAutoFormat {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SmlAGRevData {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SortCondition {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SortState {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.sort_condition {
+            it0.visit_element("sortCondition", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Stylesheet {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.num_fmts {
+                it0.visit_element("numFmts", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.fonts {
+                it0.visit_element("fonts", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.fills {
+                it0.visit_element("fills", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.borders {
+                it0.visit_element("borders", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.cell_style_xfs {
+                it0.visit_element("cellStyleXfs", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.cell_xfs {
+                it0.visit_element("cellXfs", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.cell_styles {
+                it0.visit_element("cellStyles", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.dxfs {
+                it0.visit_element("dxfs", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.table_styles {
+                it0.visit_element("tableStyles", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.colors {
+                it0.visit_element("colors", v);
+            }
+        }
+        #[cfg(feature = "sml-extensions")]
+        {
+            if let Some(it0) = &self.extension_list {
+                it0.visit_element("extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Table {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "sml-tables")]
+        {
+            if let Some(it0) = &self.auto_filter {
+                it0.visit_element("autoFilter", v);
+            }
+        }
+        #[cfg(feature = "sml-tables")]
+        {
+            if let Some(it0) = &self.sort_state {
+                it0.visit_element("sortState", v);
+            }
+        }
+        #[cfg(feature = "sml-tables")]
+        {
+            self.table_columns.visit_element("tableColumns", v);
+        }
+        #[cfg(feature = "sml-tables")]
+        {
+            if let Some(it0) = &self.table_style_info {
+                it0.visit_element("tableStyleInfo", v);
+            }
+        }
+        #[cfg(feature = "sml-extensions")]
+        {
+            if let Some(it0) = &self.extension_list {
+                it0.visit_element("extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for TableColumn {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.calculated_column_formula {
+            it0.visit_element("calculatedColumnFormula", v);
+        }
+        if let Some(it0) = &self.totals_row_formula {
+            it0.visit_element("totalsRowFormula", v);
+        }
+        if let Some(it0) = &self.xml_column_pr {
+            it0.visit_element("xmlColumnPr", v);
+        }
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for TableColumns {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.table_column {
+            it0.visit_element("tableColumn", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for TableFormula {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.text {
+            v.text(it0);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for TableMissing {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for TablePart {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for TableParts {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.table_part {
+            it0.visit_element("tablePart", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for TableStyle {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.table_style_element {
+            it0.visit_element("tableStyleElement", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for TableStyleElement {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for TableStyleInfo {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for TableStyles {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.table_style {
+            it0.visit_element("tableStyle", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for TextField {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for TextFields {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.text_field {
+            it0.visit_element("textField", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for TextImportProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.text_fields {
+            it0.visit_element("textFields", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Top10Filter {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for UnderlineProperty {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for UndoInfo {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Users {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.user_info {
+            it0.visit_element("userInfo", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for VerticalAlignFontProperty {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for WebPublishItem {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for WebPublishItems {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.web_publish_item {
+            it0.visit_element("webPublishItem", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for WebPublishing {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for WebQueryProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.tables {
+            it0.visit_element("tables", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Workbook {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.file_version {
+            it0.visit_element("fileVersion", v);
+        }
+        #[cfg(feature = "sml-protection")]
+        {
+            if let Some(it0) = &self.file_sharing {
+                it0.visit_element("fileSharing", v);
+            }
+        }
+        if let Some(it0) = &self.workbook_pr {
+            it0.visit_element("workbookPr", v);
+        }
+        #[cfg(feature = "sml-protection")]
+        {
+            if let Some(it0) = &self.workbook_protection {
+                it0.visit_element("workbookProtection", v);
+            }
+        }
+        if let Some(it0) = &self.book_views {
+            it0.visit_element("bookViews", v);
+        }
+        self.sheets.visit_element("sheets", v);
+        #[cfg(feature = "sml-formulas-advanced")]
+        {
+            if let Some(it0) = &self.function_groups {
+                it0.visit_element("functionGroups", v);
+            }
+        }
+        #[cfg(feature = "sml-external")]
+        {
+            if let Some(it0) = &self.external_references {
+                it0.visit_element("externalReferences", v);
+            }
+        }
+        if let Some(it0) = &self.defined_names {
+            it0.visit_element("definedNames", v);
+        }
+        #[cfg(feature = "sml-formulas")]
+        {
+            if let Some(it0) = &self.calc_pr {
+                it0.visit_element("calcPr", v);
+            }
+        }
+        #[cfg(feature = "sml-external")]
+        {
+            if let Some(it0) = &self.ole_size {
+                it0.visit_element("oleSize", v);
+            }
+        }
+        #[cfg(feature = "sml-structure")]
+        {
+            if let Some(it0) = &self.custom_workbook_views {
+                it0.visit_element("customWorkbookViews", v);
+            }
+        }
+        #[cfg(feature = "sml-pivot")]
+        {
+            if let Some(it0) = &self.pivot_caches {
+                it0.visit_element("pivotCaches", v);
+            }
+        }
+        #[cfg(feature = "sml-metadata")]
+        {
+            if let Some(it0) = &self.smart_tag_pr {
+                it0.visit_element("smartTagPr", v);
+            }
+        }
+        #[cfg(feature = "sml-metadata")]
+        {
+            if let Some(it0) = &self.smart_tag_types {
+                it0.visit_element("smartTagTypes", v);
+            }
+        }
+        #[cfg(feature = "sml-external")]
+        {
+            if let Some(it0) = &self.web_publishing {
+                it0.visit_element("webPublishing", v);
+            }
+        }
+        for it0 in &self.file_recovery_pr {
+            it0.visit_element("fileRecoveryPr", v);
+        }
+        #[cfg(feature = "sml-external")]
+        {
+            if let Some(it0) = &self.web_publish_objects {
+                it0.visit_element("webPublishObjects", v);
+            }
+        }
+        #[cfg(feature = "sml-extensions")]
+        {
+            if let Some(it0) = &self.extension_list {
+                it0.visit_element("extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for WorkbookProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for WorkbookProtection {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Worksheet {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.sheet_properties {
+                it0.visit_element("sheetPr", v);
+            }
+        }
+        if let Some(it0) = &self.dimension {
+            it0.visit_element("dimension", v);
+        }
+        if let Some(it0) = &self.sheet_views {
+            it0.visit_element("sheetViews", v);
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            if let Some(it0) = &self.sheet_format {
+                it0.visit_element("sheetFormatPr", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            for it0 in &self.cols {
+                it0.visit_element("cols", v);
+            }
+        }
+        self.sheet_data.visit_element("sheetData", v);
+        #[cfg(feature = "sml-formulas")]
+        {
+            if let Some(it0) = &self.sheet_calc_pr {
+                it0.visit_element("sheetCalcPr", v);
+            }
+        }
+        #[cfg(feature = "sml-protection")]
+        {
+            if let Some(it0) = &self.sheet_protection {
+                it0.visit_element("sheetProtection", v);
+            }
+        }
+        #[cfg(feature = "sml-protection")]
+        {
+            if let Some(it0) = &self.protected_ranges {
+                it0.visit_element("protectedRanges", v);
+            }
+        }
+        #[cfg(feature = "sml-formulas-advanced")]
+        {
+            if let Some(it0) = &self.scenarios {
+                it0.visit_element("scenarios", v);
+            }
+        }
+        #[cfg(feature = "sml-filtering")]
+        {
+            if let Some(it0) = &self.auto_filter {
+                it0.visit_element("autoFilter", v);
+            }
+        }
+        #[cfg(feature = "sml-filtering")]
+        {
+            if let Some(it0) = &self.sort_state {
+                it0.visit_element("sortState", v);
+            }
+        }
+        #[cfg(feature = "sml-formulas-advanced")]
+        {
+            if let Some(it0) = &self.data_consolidate {
+                it0.visit_element("dataConsolidate", v);
+            }
+        }
+        #[cfg(feature = "sml-structure")]
+        {
+            if let Some(it0) = &self.custom_sheet_views {
+                it0.visit_element("customSheetViews", v);
+            }
+        }
+        if let Some(it0) = &self.merged_cells {
+            it0.visit_element("mergeCells", v);
+        }
+        #[cfg(feature = "sml-i18n")]
+        {
+            if let Some(it0) = &self.phonetic_pr {
+                it0.visit_element("phoneticPr", v);
+            }
+        }
+        #[cfg(feature = "sml-styling")]
+        {
+            for it0 in &self.conditional_formatting {
+                it0.visit_element("conditionalFormatting", v);
+            }
+        }
+        #[cfg(feature = "sml-validation")]
+        {
+            if let Some(it0) = &self.data_validations {
+                it0.visit_element("dataValidations", v);
+            }
+        }
+        #[cfg(feature = "sml-hyperlinks")]
+        {
+            if let Some(it0) = &self.hyperlinks {
+                it0.visit_element("hyperlinks", v);
+            }
+        }
+        #[cfg(feature = "sml-layout")]
+        {
+            if let Some(it0) = &self.print_options {
+                it0.visit_element("printOptions", v);
+            }
+        }
+        #[cfg(feature = "sml-layout")]
+        {
+            if let Some(it0) = &self.page_margins {
+                it0.visit_element("pageMargins", v);
+            }
+        }
+        #[cfg(feature = "sml-layout")]
+        {
+            if let Some(it0) = &self.page_setup {
+                it0.visit_element("pageSetup", v);
+            }
+        }
+        #[cfg(feature = "sml-layout")]
+        {
+            if let Some(it0) = &self.header_footer {
+                it0.visit_element("headerFooter", v);
+            }
+        }
+        #[cfg(feature = "sml-layout")]
+        {
+            if let Some(it0) = &self.row_breaks {
+                it0.visit_element("rowBreaks", v);
+            }
+        }
+        #[cfg(feature = "sml-layout")]
+        {
+            if let Some(it0) = &self.col_breaks {
+                it0.visit_element("colBreaks", v);
+            }
+        }
+        #[cfg(feature = "sml-metadata")]
+        {
+            if let Some(it0) = &self.custom_properties {
+                it0.visit_element("customProperties", v);
+            }
+        }
+        #[cfg(feature = "sml-formulas-advanced")]
+        {
+            if let Some(it0) = &self.cell_watches {
+                it0.visit_element("cellWatches", v);
+            }
+        }
+        #[cfg(feature = "sml-validation")]
+        {
+            if let Some(it0) = &self.ignored_errors {
+                it0.visit_element("ignoredErrors", v);
+            }
+        }
+        #[cfg(feature = "sml-metadata")]
+        {
+            if let Some(it0) = &self.smart_tags {
+                it0.visit_element("smartTags", v);
+            }
+        }
+        #[cfg(feature = "sml-drawings")]
+        {
+            if let Some(it0) = &self.drawing {
+                it0.visit_element("drawing", v);
+            }
+        }
+        #[cfg(feature = "sml-comments")]
+        {
+            if let Some(it0) = &self.legacy_drawing {
+                it0.visit_element("legacyDrawing", v);
+            }
+        }
+        #[cfg(feature = "sml-layout")]
+        {
+            if let Some(it0) = &self.legacy_drawing_h_f {
+                it0.visit_element("legacyDrawingHF", v);
+            }
+        }
+        #[cfg(feature = "sml-drawings")]
+        {
+            if let Some(it0) = &self.drawing_h_f {
+                it0.visit_element("drawingHF", v);
+            }
+        }
+        #[cfg(feature = "sml-drawings")]
+        {
+            if let Some(it0) = &self.picture {
+                it0.visit_element("picture", v);
+            }
+        }
+        #[cfg(feature = "sml-external")]
+        {
+            if let Some(it0) = &self.ole_objects {
+                it0.visit_element("oleObjects", v);
+            }
+        }
+        #[cfg(feature = "sml-external")]
+        {
+            if let Some(it0) = &self.controls {
+                it0.visit_element("controls", v);
+            }
+        }
+        #[cfg(feature = "sml-external")]
+        {
+            if let Some(it0) = &self.web_publish_items {
+                it0.visit_element("webPublishItems", v);
+            }
+        }
+        #[cfg(feature = "sml-tables")]
+        {
+            if let Some(it0) = &self.table_parts {
+                it0.visit_element("tableParts", v);
+            }
+        }
+        #[cfg(feature = "sml-extensions")]
+        {
+            if let Some(it0) = &self.extension_list {
+                it0.visit_element("extLst", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for WorksheetSource {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for XmlCellProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.xml_pr.visit_element("xmlPr", v);
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for XmlColumnProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for XmlMap {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.data_binding {
+            it0.visit_element("DataBinding", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for XmlProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.extension_list {
+            it0.visit_element("extLst", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for XmlSchema {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
@@ -128,12 +128,10 @@ impl<R: BufRead> Iterator for RowIterator<R> {
                         }
                     }
                 }
-                Ok(Event::End(e)) => {
-                    if e.name().as_ref() == b"sheetData" {
-                        self.in_sheet_data = false;
-                        self.finished = true;
-                        return None;
-                    }
+                Ok(Event::End(e)) if e.name().as_ref() == b"sheetData" => {
+                    self.in_sheet_data = false;
+                    self.finished = true;
+                    return None;
                 }
                 Ok(Event::Eof) => {
                     self.finished = true;
@@ -297,16 +295,10 @@ fn parse_lazy_row<R: BufRead>(
                     skip_element(reader)?;
                 }
             }
-            Event::Empty(e) => {
-                if e.name().as_ref() == b"c" {
-                    row.cells.push(parse_lazy_cell_from_empty(&e)?);
-                }
-            }
-            Event::End(e) => {
-                if e.name().as_ref() == b"row" {
-                    break;
-                }
+            Event::Empty(e) if e.name().as_ref() == b"c" => {
+                row.cells.push(parse_lazy_cell_from_empty(&e)?);
             }
+            Event::End(e) if e.name().as_ref() == b"row" => break,
             Event::Eof => break,
             _ => {}
         }
@@ -380,11 +372,7 @@ fn parse_lazy_cell<R: BufRead>(
             Event::Empty(_) => {
                 // Empty v or f element - no content
             }
-            Event::End(e) => {
-                if e.name().as_ref() == b"c" {
-                    break;
-                }
-            }
+            Event::End(e) if e.name().as_ref() == b"c" => break,
             Event::Eof => break,
             _ => {}
         }
@@ -33,6 +33,23 @@
 //! wb.save("output.xlsx")?;
 //! # Ok::<(), ooxml_sml::Error>(())
 //! ```
+//!
+//! # Editing Workbooks
+//!
+//! Existing files can be modified in place. Parts that are not edited (charts,
+//! pivot tables, drawings, ...) are copied to the output unchanged.
+//!
+//! ```no_run
+//! use ooxml_sml::Workbook;
+//!
+//! let mut workbook = Workbook::open("template.xlsx")?;
+//! workbook.set_cell(0, "B2", "Filled in")?;
+//! workbook.set_cell(0, "C2", 1234.5)?;
+//! let report = workbook.add_sheet("Report")?;
+//! workbook.set_cell(report, "A1", "Generated")?;
+//! workbook.save("report.xlsx")?;
+//! # Ok::<(), ooxml_sml::Error>(())
+//! ```
 
 pub mod error;
 pub mod workbook;
@@ -64,6 +81,25 @@ pub mod generated_serializers;
 /// Serializers for the generated ECMA-376 types. See [`generated_serializers`] for details.
 pub use generated_serializers as serializers;
 
+/// `Visit`/`VisitMut` traversal of the generated types.
+///
+/// Each trait has a method per generated type that defaults to visiting its
+/// fields, variants and unknown children (as raw XML) in declaration order;
+/// override the methods of interest and call the matching `walk_*` function
+/// to keep descending.
+///
+/// The module also implements [`ooxml_xml::QueryTree`] for the generated
+/// types, so [`ooxml_xml::Query::select_tree`] can run path queries over them
+/// as the XML they serialize to. Derived from [`generated`] and
+/// [`generated_serializers`] by `ooxml-codegen`.
+///
+/// Re-exported as [`visit`].
+#[cfg(feature = "visit")]
+pub mod generated_visit;
+/// Traversal of the generated ECMA-376 types. See [`generated_visit`] for details.
+#[cfg(feature = "visit")]
+pub use generated_visit as visit;
+
 // Lazy/streaming API for memory-efficient parsing
 pub mod lazy;
 pub use lazy::{LazyCell, LazyRow, LazyWorksheet};
@@ -80,6 +116,7 @@ pub use ext::{
 pub use ext::{ConditionalFormattingExt, ConditionalRuleExt, WorksheetConditionalFormattingExt};
 
 pub use error::{Error, Result};
+pub use ooxml_opc::{Conformance, PackageLimits, ParseLimits, SerializeOptions, VbaProject};
 // Writer-required types from workbook module
 pub use workbook::{
     ConditionalRuleType, DataValidationErrorStyle, DataValidationOperator, DataValidationType,
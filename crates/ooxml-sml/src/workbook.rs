@@ -8,11 +8,20 @@ use crate::ext::{
     parse_worksheet,
 };
 use crate::parsers::FromXml;
-use ooxml_opc::{Package, Relationships};
+use crate::serializers::ToXml;
+use crate::writer::{
+    WriteCellValue, column_to_letter, init_worksheet, parse_cell_reference, serialize_with_ns_decls,
+};
+use ooxml_opc::{
+    Conformance, Package, PackageLimits, PackageWriter, Relationship, Relationships, VbaProject,
+    rel_type, rels_path_for, resolve_target,
+};
+use ooxml_xml::root_attributes;
 use quick_xml::Reader;
 use quick_xml::events::Event;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs::File;
-use std::io::{BufReader, Cursor, Read, Seek};
+use std::io::{BufReader, Cursor, Read, Seek, Write};
 use std::path::Path;
 
 // Relationship types (ECMA-376 Part 1)
@@ -33,10 +42,33 @@ const REL_PIVOT_TABLE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/pivotTable";
 const REL_PIVOT_CACHE_DEFINITION: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/pivotCacheDefinition";
+const REL_WORKSHEET: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet";
+const REL_CALC_CHAIN: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/calcChain";
+
+// Content types for parts created while editing
+const CT_WORKBOOK: &str =
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml";
+const CT_WORKSHEET: &str =
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml";
+const CT_SHARED_STRINGS: &str =
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.sharedStrings+xml";
+const CT_STYLES: &str = "application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml";
+const CT_RELATIONSHIPS: &str = "application/vnd.openxmlformats-package.relationships+xml";
+
+// Namespaces written on root elements of re-serialized parts
+const NS_SPREADSHEET: &str = "http://schemas.openxmlformats.org/spreadsheetml/2006/main";
+const NS_RELATIONSHIPS: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships";
 
 /// An Excel workbook.
 ///
-/// This is the main entry point for reading XLSX files.
+/// This is the main entry point for reading XLSX files. An opened workbook can
+/// also be edited in place (cells, sheets, shared strings, styles) and written
+/// back with [`save`](Self::save) or [`write`](Self::write); every part that was
+/// not edited is copied unchanged, so charts, pivot tables and other content
+/// this crate does not model survive the round trip.
 pub struct Workbook<R: Read + Seek> {
     package: Package<R>,
     /// Path to the workbook part (e.g., "xl/workbook.xml").
@@ -54,6 +86,34 @@ pub struct Workbook<R: Read + Seek> {
     /// Workbook protection settings (if any).
     #[cfg(feature = "sml-protection")]
     workbook_protection: Option<crate::types::WorkbookProtection>,
+    /// Parsed workbook part, re-serialized on write when the sheet list changes.
+    workbook: crate::types::Workbook,
+    /// Parsed shared string table (rich text runs preserved).
+    sst: crate::types::SharedStrings,
+    /// Path to the shared strings part, if the workbook has one.
+    sst_path: Option<String>,
+    /// Path to the styles part, if the workbook has one.
+    styles_path: Option<String>,
+    /// Pending edits, applied on write.
+    edits: Edits,
+}
+
+/// Modifications made to an opened workbook that have not been written yet.
+#[derive(Debug, Default)]
+struct Edits {
+    /// Worksheets loaded for editing, keyed by part path.
+    worksheets: BTreeMap<String, crate::types::Worksheet>,
+    /// Root element attributes (namespace declarations, `mc:Ignorable`, ...)
+    /// of parts that may be re-serialized, keyed by part path.
+    root_attrs: HashMap<String, Vec<(String, String)>>,
+    /// Parts that do not exist in the source package.
+    new_parts: HashSet<String>,
+    /// Parts to leave out of the written package.
+    removed_parts: Vec<String>,
+    workbook: bool,
+    workbook_rels: bool,
+    shared_strings: bool,
+    styles: bool,
 }
 
 /// Metadata about a sheet.
@@ -76,14 +136,24 @@ impl Workbook<BufReader<File>> {
 impl<R: Read + Seek> Workbook<R> {
     /// Open a workbook from a reader.
     pub fn from_reader(reader: R) -> Result<Self> {
-        let mut package = Package::open(reader)?;
+        Self::from_package(Package::open(reader)?)
+    }
+
+    /// Open an untrusted workbook from a reader, enforcing resource limits.
+    ///
+    /// Fails with [`ooxml_opc::Error::LimitExceeded`] if the package or any
+    /// part read from it exceeds the limits.
+    pub fn from_reader_with_limits(reader: R, limits: &PackageLimits) -> Result<Self> {
+        Self::from_package(Package::open_with_limits(reader, limits)?)
+    }
 
+    fn from_package(mut package: Package<R>) -> Result<Self> {
         // Find the workbook part via root relationships
         let root_rels = package.read_relationships()?;
         let workbook_rel = root_rels
             .get_by_type(REL_OFFICE_DOCUMENT)
             .ok_or_else(|| Error::Invalid("Missing workbook relationship".into()))?;
-        let workbook_path = workbook_rel.target.clone();
+        let workbook_path = resolve_target("", &workbook_rel.target);
 
         // Load workbook relationships
         let workbook_rels = package
@@ -92,7 +162,11 @@ impl<R: Read + Seek> Workbook<R> {
 
         // Parse workbook.xml once to get sheet list and defined names
         let workbook_xml = package.read_part(&workbook_path)?;
-        let wb: crate::types::Workbook = bootstrap(&workbook_xml)?;
+        let wb: crate::types::Workbook = parse_part(&workbook_xml, &workbook_path)?;
+        let mut edits = Edits::default();
+        edits
+            .root_attrs
+            .insert(workbook_path.clone(), root_attributes(&workbook_xml));
         let sheet_info = wb
             .sheets
             .sheet
@@ -105,38 +179,40 @@ impl<R: Read + Seek> Workbook<R> {
             .collect();
         let defined_names = wb
             .defined_names
-            .map(|dn| {
-                let inner = *dn;
-                inner.defined_name
-            })
+            .as_ref()
+            .map(|dn| dn.defined_name.clone())
             .unwrap_or_default();
         #[cfg(feature = "sml-protection")]
-        let workbook_protection = wb.workbook_protection.map(|b| *b);
+        let workbook_protection = wb.workbook_protection.as_deref().cloned();
 
         // Load shared strings if present
-        let shared_strings = if let Some(rel) = workbook_rels.get_by_type(REL_SHARED_STRINGS) {
-            let path = resolve_path(&workbook_path, &rel.target);
+        let mut sst_path = None;
+        let mut sst = crate::types::SharedStrings::default();
+        if let Some(rel) = workbook_rels.get_by_type(REL_SHARED_STRINGS) {
+            let path = resolve_target(&workbook_path, &rel.target);
             if let Ok(data) = package.read_part(&path) {
-                let sst: crate::types::SharedStrings = bootstrap(&data)?;
-                extract_shared_strings(&sst)
-            } else {
-                Vec::new()
+                sst = parse_part(&data, &path)?;
+                edits
+                    .root_attrs
+                    .insert(path.clone(), root_attributes(&data));
+                sst_path = Some(path);
             }
-        } else {
-            Vec::new()
-        };
+        }
+        let shared_strings = extract_shared_strings(&sst);
 
         // Load styles if present
-        let styles = if let Some(rel) = workbook_rels.get_by_type(REL_STYLES) {
-            let path = resolve_path(&workbook_path, &rel.target);
+        let mut styles_path = None;
+        let mut styles = crate::types::Stylesheet::default();
+        if let Some(rel) = workbook_rels.get_by_type(REL_STYLES) {
+            let path = resolve_target(&workbook_path, &rel.target);
             if let Ok(data) = package.read_part(&path) {
-                bootstrap(&data)?
-            } else {
-                crate::types::Stylesheet::default()
+                styles = parse_part(&data, &path)?;
+                edits
+                    .root_attrs
+                    .insert(path.clone(), root_attributes(&data));
+                styles_path = Some(path);
             }
-        } else {
-            crate::types::Stylesheet::default()
-        };
+        }
 
         Ok(Self {
             package,
@@ -148,6 +224,11 @@ impl<R: Read + Seek> Workbook<R> {
             defined_names,
             #[cfg(feature = "sml-protection")]
             workbook_protection,
+            workbook: wb,
+            sst,
+            sst_path,
+            styles_path,
+            edits,
         })
     }
 
@@ -231,6 +312,24 @@ impl<R: Read + Seek> Workbook<R> {
             .filter(move |d| d.local_sheet_id == Some(sheet_index))
     }
 
+    /// Check whether the workbook is macro-enabled (`.xlsm`, `.xltm` or `.xlam`).
+    pub fn is_macro_enabled(&self) -> bool {
+        self.package
+            .content_type(&self.workbook_path)
+            .is_some_and(|ct| ct.contains(".macroEnabled"))
+    }
+
+    /// Load the VBA project (`vbaProject.bin`) of a macro-enabled workbook.
+    ///
+    /// The project is carried through unchanged when the workbook is saved.
+    pub fn vba_project(&mut self) -> Result<Option<VbaProject>> {
+        let Some(rel) = self.workbook_rels.get_by_type(rel_type::VBA_PROJECT) else {
+            return Ok(None);
+        };
+        let path = resolve_target(&self.workbook_path, &rel.target);
+        Ok(Some(VbaProject::new(self.package.read_part(&path)?)))
+    }
+
     /// Load all pivot cache definitions from workbook relationships.
     ///
     /// Pivot cache definitions are workbook-level parts linked via relationships of type
@@ -246,7 +345,7 @@ impl<R: Read + Seek> Workbook<R> {
             .map(|r| r.target.clone())
             .collect();
         for target in rel_targets {
-            let path = resolve_path(&self.workbook_path, &target);
+            let path = resolve_target(&self.workbook_path, &target);
             if let Ok(data) = self.package.read_part(&path)
                 && let Ok(cache) = bootstrap::<crate::types::PivotCacheDefinition>(&data)
             {
@@ -332,8 +431,8 @@ impl<R: Read + Seek> Workbook<R> {
             Error::Invalid(format!("Missing relationship for sheet '{}'", info.name))
         })?;
 
-        let path = resolve_path(&self.workbook_path, &rel.target);
-        Ok(self.package.read_part(&path)?)
+        let path = resolve_target(&self.workbook_path, &rel.target);
+        self.read_sheet_part(&path)
     }
 
     /// Get raw worksheet XML bytes by sheet name.
@@ -351,8 +450,8 @@ impl<R: Read + Seek> Workbook<R> {
             Error::Invalid(format!("Missing relationship for sheet '{}'", info.name))
         })?;
 
-        let path = resolve_path(&self.workbook_path, &rel.target);
-        Ok(self.package.read_part(&path)?)
+        let path = resolve_target(&self.workbook_path, &rel.target);
+        self.read_sheet_part(&path)
     }
 
     /// Load a sheet using the generated parser.
@@ -362,21 +461,22 @@ impl<R: Read + Seek> Workbook<R> {
             Error::Invalid(format!("Missing relationship for sheet '{}'", info.name))
         })?;
 
-        let path = resolve_path(&self.workbook_path, &rel.target);
-        let data = self.package.read_part(&path)?;
+        let path = resolve_target(&self.workbook_path, &rel.target);
 
         // Check if this is a chartsheet or regular worksheet
-        let is_chartsheet = rel.relationship_type == REL_CHARTSHEET;
+        let is_chartsheet = rel.has_type(REL_CHARTSHEET);
 
-        // Parse the worksheet using generated FromXml parser
-        let worksheet = if is_chartsheet {
+        // Parse the worksheet using generated FromXml parser, preferring pending edits
+        let worksheet = if let Some(worksheet) = self.edits.worksheets.get(&path) {
+            worksheet.clone()
+        } else if is_chartsheet {
             // Chartsheets don't have the same structure - parse minimal empty worksheet XML
             // This ensures feature-gated fields are handled correctly by the generated parser
             let minimal_xml = br#"<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetData/></worksheet>"#;
-            parse_worksheet(minimal_xml)
-                .map_err(|e| Error::Invalid(format!("Chartsheet parse error: {:?}", e)))?
+            parse_worksheet(minimal_xml)?
         } else {
-            parse_worksheet(&data).map_err(|e| Error::Invalid(format!("Parse error: {:?}", e)))?
+            let data = self.package.read_part(&path)?;
+            parse_worksheet(&data).map_err(|e| e.in_part(&path))?
         };
 
         // Load comments, charts, and pivot tables
@@ -388,19 +488,19 @@ impl<R: Read + Seek> Workbook<R> {
         if let Ok(sheet_rels) = self.package.read_part_relationships(&path) {
             // Load comments
             if !is_chartsheet && let Some(comments_rel) = sheet_rels.get_by_type(REL_COMMENTS) {
-                let comments_path = resolve_path(&path, &comments_rel.target);
+                let comments_path = resolve_target(&path, &comments_rel.target);
                 if let Ok(comments_data) = self.package.read_part(&comments_path) {
-                    comments = parse_comments_xml(&comments_data)?;
+                    comments = parse_comments_xml(&comments_data, &comments_path)?;
                 }
             }
 
             // Load charts via drawing relationships
             if let Some(drawing_rel) = sheet_rels.get_by_type(REL_DRAWING) {
-                let drawing_path = resolve_path(&path, &drawing_rel.target);
+                let drawing_path = resolve_target(&path, &drawing_rel.target);
                 if let Ok(drawing_rels) = self.package.read_part_relationships(&drawing_path) {
                     for rel in drawing_rels.iter() {
-                        let chart_path = resolve_path(&drawing_path, &rel.target);
-                        if rel.relationship_type == REL_CHART
+                        let chart_path = resolve_target(&drawing_path, &rel.target);
+                        if rel.has_type(REL_CHART)
                             && let Ok(chart_data) = self.package.read_part(&chart_path)
                             && let Ok(chart) = parse_chart_ext(&chart_data)
                         {
@@ -413,7 +513,7 @@ impl<R: Read + Seek> Workbook<R> {
             // Load pivot tables from sheet relationships
             #[cfg(feature = "sml-pivot")]
             for rel in sheet_rels.get_all_by_type(REL_PIVOT_TABLE) {
-                let pt_path = resolve_path(&path, &rel.target);
+                let pt_path = resolve_target(&path, &rel.target);
                 if let Ok(pt_data) = self.package.read_part(&pt_path)
                     && let Ok(pt) = bootstrap::<crate::types::CTPivotTableDefinition>(&pt_data)
                 {
@@ -434,9 +534,593 @@ impl<R: Read + Seek> Workbook<R> {
     }
 }
 
+// =========================================================================
+// Editing (open-modify-save)
+// =========================================================================
+
+/// Namespace declarations for re-serialized workbook and worksheet parts.
+const NS_PART_DECLS: &[(&str, &str)] = &[("xmlns", NS_SPREADSHEET), ("xmlns:r", NS_RELATIONSHIPS)];
+
+/// Namespace declarations for re-serialized shared strings and styles parts.
+const NS_SML_DECLS: &[(&str, &str)] = &[("xmlns", NS_SPREADSHEET)];
+
+impl<R: Read + Seek> Workbook<R> {
+    /// Get a worksheet for editing.
+    ///
+    /// The worksheet is parsed on first access and kept in memory until the
+    /// workbook is written; later calls (and [`resolved_sheet`](Self::resolved_sheet))
+    /// see the edited version. Chartsheets cannot be edited.
+    pub fn worksheet_mut(&mut self, index: usize) -> Result<&mut crate::types::Worksheet> {
+        let path = self.sheet_part_path(index)?;
+        if !self.edits.worksheets.contains_key(&path) {
+            let data = self.package.read_part(&path)?;
+            let mut worksheet = parse_worksheet(&data).map_err(|e| e.in_part(&path))?;
+            fill_implicit_references(&mut worksheet);
+            self.edits
+                .root_attrs
+                .insert(path.clone(), root_attributes(&data));
+            self.edits.worksheets.insert(path.clone(), worksheet);
+        }
+        Ok(self
+            .edits
+            .worksheets
+            .get_mut(&path)
+            .expect("worksheet loaded above"))
+    }
+
+    /// Set the value of a cell, creating the row and cell if needed.
+    ///
+    /// Strings are stored in the shared string table. Setting a formula clears
+    /// the cached value; [`WriteCellValue::Empty`] clears the value but keeps
+    /// the cell and its style. Excel is asked to recalculate the workbook when
+    /// it is next opened. Numbers must be finite; NaN and infinities cannot be
+    /// stored in a cell.
+    pub fn set_cell(
+        &mut self,
+        sheet: usize,
+        reference: &str,
+        value: impl Into<WriteCellValue>,
+    ) -> Result<()> {
+        let (row, col) = parse_cell_reference(reference)
+            .ok_or_else(|| Error::Invalid(format!("Invalid cell reference '{}'", reference)))?;
+        let value = value.into();
+        if let WriteCellValue::Number(n) = value
+            && !n.is_finite()
+        {
+            return Err(Error::Invalid(format!(
+                "Cell value {} is not a finite number",
+                n
+            )));
+        }
+        // Load the sheet first so an invalid index does not leave a stray shared string
+        self.worksheet_mut(sheet)?;
+
+        let (cell_type, value, formula) = match value {
+            WriteCellValue::String(s) => (
+                Some(crate::types::CellType::SharedString),
+                Some(self.add_shared_string(&s).to_string()),
+                None,
+            ),
+            WriteCellValue::Number(n) => (None, Some(n.to_string()), None),
+            WriteCellValue::Boolean(b) => {
+                let val = if b { "1" } else { "0" };
+                (
+                    Some(crate::types::CellType::Boolean),
+                    Some(val.to_string()),
+                    None,
+                )
+            }
+            WriteCellValue::Formula(f) => (
+                None,
+                None,
+                Some(Box::new(crate::types::CellFormula {
+                    text: Some(f),
+                    ..Default::default()
+                })),
+            ),
+            WriteCellValue::Empty => (None, None, None),
+        };
+
+        let cell = cell_mut(self.worksheet_mut(sheet)?, row, col);
+        cell.cell_type = cell_type;
+        cell.value = value;
+        cell.formula = formula;
+        cell.is = None;
+
+        self.invalidate_calculation();
+        Ok(())
+    }
+
+    /// Set the style of a cell, creating the row and cell if needed.
+    ///
+    /// `style_index` is an index into the stylesheet's cell formats (`cellXfs`);
+    /// new formats can be added through [`styles_mut`](Self::styles_mut).
+    pub fn set_cell_style(
+        &mut self,
+        sheet: usize,
+        reference: &str,
+        style_index: u32,
+    ) -> Result<()> {
+        let (row, col) = parse_cell_reference(reference)
+            .ok_or_else(|| Error::Invalid(format!("Invalid cell reference '{}'", reference)))?;
+        let cell = cell_mut(self.worksheet_mut(sheet)?, row, col);
+        cell.style_index = (style_index > 0).then_some(style_index);
+        Ok(())
+    }
+
+    /// Add a string to the shared string table and return its index.
+    ///
+    /// If the table already holds the same plain-text string, its index is
+    /// returned instead. A shared strings part is created if the workbook does
+    /// not have one.
+    pub fn add_shared_string(&mut self, text: &str) -> usize {
+        if let Some(idx) = self
+            .sst
+            .si
+            .iter()
+            .position(|si| si.cell_type.as_deref() == Some(text))
+        {
+            return idx;
+        }
+
+        if self.sst_path.is_none() {
+            self.sst_path = Some(self.add_workbook_part(REL_SHARED_STRINGS, "sharedStrings.xml"));
+        }
+
+        self.sst.si.push(crate::types::RichString {
+            cell_type: Some(text.to_string()),
+            ..Default::default()
+        });
+        self.shared_strings.push(text.to_string());
+
+        let unique = self.sst.si.len() as u32;
+        self.sst.unique_count = Some(unique);
+        self.sst.count = Some(self.sst.count.unwrap_or(0).max(unique));
+        self.edits.shared_strings = true;
+        self.sst.si.len() - 1
+    }
+
+    /// Get the stylesheet for editing.
+    ///
+    /// The stylesheet is written back when the workbook is saved. A styles part
+    /// is created if the workbook does not have one.
+    pub fn styles_mut(&mut self) -> &mut crate::types::Stylesheet {
+        if self.styles_path.is_none() {
+            self.styles_path = Some(self.add_workbook_part(REL_STYLES, "styles.xml"));
+        }
+        self.edits.styles = true;
+        &mut self.styles
+    }
+
+    /// Append a new, empty worksheet and return its index.
+    pub fn add_sheet(&mut self, name: &str) -> Result<usize> {
+        validate_sheet_name(name)?;
+        if self
+            .sheet_info
+            .iter()
+            .any(|s| s.name.eq_ignore_ascii_case(name))
+        {
+            return Err(Error::Invalid(format!("Sheet '{}' already exists", name)));
+        }
+
+        // Pick a part name that is not taken in the source package or by earlier edits
+        let mut n = self.sheet_info.len() + 1;
+        let (target, path) = loop {
+            let target = format!("worksheets/sheet{}.xml", n);
+            let path = resolve_target(&self.workbook_path, &target);
+            if !self.package.has_part(&path) && !self.edits.new_parts.contains(&path) {
+                break (target, path);
+            }
+            n += 1;
+        };
+
+        let rel_id = self.workbook_rels.next_id();
+        self.workbook_rels
+            .add(Relationship::new(&rel_id, REL_WORKSHEET, target));
+
+        let sheet_id = self
+            .workbook
+            .sheets
+            .sheet
+            .iter()
+            .map(|s| s.sheet_id)
+            .max()
+            .unwrap_or(0)
+            + 1;
+        self.workbook.sheets.sheet.push(crate::types::Sheet {
+            name: name.to_string(),
+            sheet_id,
+            #[cfg(feature = "sml-structure")]
+            state: None,
+            id: rel_id.clone(),
+            #[cfg(feature = "extra-attrs")]
+            extra_attrs: Default::default(),
+        });
+        self.sheet_info.push(SheetInfo {
+            name: name.to_string(),
+            sheet_id,
+            rel_id,
+        });
+
+        self.edits.worksheets.insert(path.clone(), init_worksheet());
+        self.edits.new_parts.insert(path);
+        self.edits.workbook = true;
+        self.edits.workbook_rels = true;
+        Ok(self.sheet_info.len() - 1)
+    }
+
+    /// Remove a sheet from the workbook.
+    ///
+    /// The sheet part and its relationships are dropped on write. Defined names
+    /// scoped to the sheet are removed and names scoped to later sheets are
+    /// re-indexed. Formulas that refer to the sheet by name are not rewritten.
+    pub fn remove_sheet(&mut self, index: usize) -> Result<()> {
+        if index >= self.sheet_info.len() {
+            return Err(Error::Invalid(format!(
+                "Sheet index {} out of range",
+                index
+            )));
+        }
+        if self.sheet_info.len() == 1 {
+            return Err(Error::Invalid(
+                "Cannot remove the only sheet in a workbook".into(),
+            ));
+        }
+
+        let info = self.sheet_info.remove(index);
+        self.workbook.sheets.sheet.remove(index);
+        if let Some(rel) = self.workbook_rels.remove(&info.rel_id) {
+            let path = resolve_target(&self.workbook_path, &rel.target);
+            self.edits.worksheets.remove(&path);
+            self.edits.root_attrs.remove(&path);
+            if !self.edits.new_parts.remove(&path) {
+                let rels_path = rels_path_for(&path);
+                if self.package.has_part(&rels_path) {
+                    self.edits.removed_parts.push(rels_path);
+                }
+                self.edits.removed_parts.push(path);
+            }
+        }
+
+        // Sheet-scoped defined names use the sheet's position as their scope
+        let removed = index as u32;
+        self.defined_names
+            .retain(|d| d.local_sheet_id != Some(removed));
+        for name in &mut self.defined_names {
+            if let Some(id) = name.local_sheet_id
+                && id > removed
+            {
+                name.local_sheet_id = Some(id - 1);
+            }
+        }
+        if self.defined_names.is_empty() {
+            self.workbook.defined_names = None;
+        } else {
+            self.workbook
+                .defined_names
+                .get_or_insert_with(Default::default)
+                .defined_name = self.defined_names.clone();
+        }
+
+        // Keep workbook views pointing at existing tabs
+        let last = (self.sheet_info.len() - 1) as u32;
+        let shift = |tab: u32| if tab > removed { tab - 1 } else { tab }.min(last);
+        if let Some(views) = self.workbook.book_views.as_mut() {
+            for view in &mut views.workbook_view {
+                view.active_tab = view.active_tab.map(shift);
+                #[cfg(feature = "sml-structure")]
+                {
+                    view.first_sheet = view.first_sheet.map(shift);
+                }
+            }
+        }
+
+        // The calculation chain refers to sheets by position
+        self.invalidate_calculation();
+        self.edits.workbook = true;
+        self.edits.workbook_rels = true;
+        Ok(())
+    }
+
+    /// Save the workbook to a file, applying all pending edits.
+    ///
+    /// Parts that were not edited are copied verbatim from the source package.
+    pub fn save<P: AsRef<Path>>(&mut self, path: P) -> Result<()> {
+        let file = File::create(path)?;
+        self.write(file)
+    }
+
+    /// Write the workbook to a writer, applying all pending edits.
+    ///
+    /// Edited parts are serialized with the generated `ToXml` serializers and
+    /// replace their originals; all other parts are copied unchanged.
+    pub fn write<W: Write + Seek>(&mut self, writer: W) -> Result<()> {
+        // (path, content type for new parts, bytes)
+        let mut parts: Vec<(String, &str, Vec<u8>)> = Vec::new();
+
+        if self.edits.workbook {
+            let xml = self.serialize_part(
+                &self.workbook_path,
+                &self.workbook,
+                "workbook",
+                NS_PART_DECLS,
+            )?;
+            parts.push((self.workbook_path.clone(), CT_WORKBOOK, xml));
+        }
+        if self.edits.workbook_rels {
+            let xml = self.workbook_rels.serialize().into_bytes();
+            parts.push((rels_path_for(&self.workbook_path), CT_RELATIONSHIPS, xml));
+        }
+        if self.edits.shared_strings
+            && let Some(path) = &self.sst_path
+        {
+            let xml = self.serialize_part(path, &self.sst, "sst", NS_SML_DECLS)?;
+            parts.push((path.clone(), CT_SHARED_STRINGS, xml));
+        }
+        if self.edits.styles
+            && let Some(path) = &self.styles_path
+        {
+            let xml = self.serialize_part(path, &self.styles, "styleSheet", NS_SML_DECLS)?;
+            parts.push((path.clone(), CT_STYLES, xml));
+        }
+        for (path, worksheet) in &self.edits.worksheets {
+            let xml = self.serialize_part(path, worksheet, "worksheet", NS_PART_DECLS)?;
+            parts.push((path.clone(), CT_WORKSHEET, xml));
+        }
+
+        let replacements: HashMap<&str, &[u8]> = parts
+            .iter()
+            .filter(|(path, _, _)| self.package.has_part(path))
+            .map(|(path, _, data)| (path.as_str(), data.as_slice()))
+            .collect();
+        let removed: Vec<&str> = self
+            .edits
+            .removed_parts
+            .iter()
+            .map(String::as_str)
+            .collect();
+
+        let mut pkg_writer = PackageWriter::new(writer);
+        // Parts added or rewritten here follow the class of the source document
+        if self.package.conformance()? == Conformance::Strict {
+            pkg_writer.set_conformance(Conformance::Strict);
+        }
+        self.package
+            .copy_to_writer_except(&mut pkg_writer, &replacements, &removed)?;
+        for (path, content_type, data) in &parts {
+            if !replacements.contains_key(path.as_str()) {
+                pkg_writer.add_part(path, content_type, data)?;
+            }
+        }
+        pkg_writer.finish()?;
+
+        Ok(())
+    }
+
+    /// Read a sheet part, serializing the edited worksheet if there is one.
+    fn read_sheet_part(&mut self, path: &str) -> Result<Vec<u8>> {
+        match self.edits.worksheets.get(path) {
+            Some(worksheet) => self.serialize_part(path, worksheet, "worksheet", NS_PART_DECLS),
+            None => Ok(self.package.read_part(path)?),
+        }
+    }
+
+    /// Resolve the part path of a worksheet, rejecting chartsheets.
+    fn sheet_part_path(&self, index: usize) -> Result<String> {
+        let info = self
+            .sheet_info
+            .get(index)
+            .ok_or_else(|| Error::Invalid(format!("Sheet index {} out of range", index)))?;
+        let rel = self.workbook_rels.get(&info.rel_id).ok_or_else(|| {
+            Error::Invalid(format!("Missing relationship for sheet '{}'", info.name))
+        })?;
+        if rel.has_type(REL_CHARTSHEET) {
+            return Err(Error::Unsupported(format!(
+                "Sheet '{}' is a chartsheet and cannot be edited",
+                info.name
+            )));
+        }
+        Ok(resolve_target(&self.workbook_path, &rel.target))
+    }
+
+    /// Register a new workbook-level part and return its path.
+    fn add_workbook_part(&mut self, rel_type: &str, target: &str) -> String {
+        let rel_id = self.workbook_rels.next_id();
+        self.workbook_rels
+            .add(Relationship::new(rel_id, rel_type, target));
+        let path = resolve_target(&self.workbook_path, target);
+        self.edits.new_parts.insert(path.clone());
+        self.edits.workbook_rels = true;
+        path
+    }
+
+    /// Drop the calculation chain and ask Excel to recalculate on load.
+    ///
+    /// The chain lists formula cells in evaluation order; Excel reports the file
+    /// as corrupt if it names cells that no longer hold formulas, and cached
+    /// results of dependent formulas are stale after an edit.
+    fn invalidate_calculation(&mut self) {
+        let calc_chain_ids: Vec<String> = self
+            .workbook_rels
+            .get_all_by_type(REL_CALC_CHAIN)
+            .map(|r| r.id.clone())
+            .collect();
+        for id in calc_chain_ids {
+            if let Some(rel) = self.workbook_rels.remove(&id) {
+                let path = resolve_target(&self.workbook_path, &rel.target);
+                self.edits.removed_parts.push(path);
+                self.edits.workbook_rels = true;
+            }
+        }
+
+        #[cfg(feature = "sml-formulas")]
+        {
+            let calc_pr = self.workbook.calc_pr.get_or_insert_with(Default::default);
+            if calc_pr.full_calc_on_load != Some(true) {
+                calc_pr.full_calc_on_load = Some(true);
+                self.edits.workbook = true;
+            }
+        }
+    }
+
+    /// Serialize a parsed part, restoring the root attributes of the original.
+    fn serialize_part(
+        &self,
+        path: &str,
+        value: &impl ToXml,
+        tag: &str,
+        default_decls: &[(&str, &str)],
+    ) -> Result<Vec<u8>> {
+        let mut decls: Vec<(&str, &str)> = self
+            .edits
+            .root_attrs
+            .get(path)
+            .map(|attrs| {
+                attrs
+                    .iter()
+                    .map(|(k, v)| (k.as_str(), v.as_str()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        for &(key, val) in default_decls {
+            if !decls.iter().any(|(k, _)| *k == key) {
+                decls.push((key, val));
+            }
+        }
+        serialize_with_ns_decls(value, tag, &decls)
+    }
+}
+
+/// Check a sheet name against Excel's rules.
+fn validate_sheet_name(name: &str) -> Result<()> {
+    if name.is_empty() || name.chars().count() > 31 {
+        return Err(Error::Invalid(format!(
+            "Sheet name '{}' must be 1 to 31 characters long",
+            name
+        )));
+    }
+    if name.contains(['[', ']', ':', '*', '?', '/', '\\'])
+        || name.starts_with('\'')
+        || name.ends_with('\'')
+    {
+        return Err(Error::Invalid(format!(
+            "Sheet name '{}' contains invalid characters",
+            name
+        )));
+    }
+    Ok(())
+}
+
+/// Give every row and cell an explicit reference.
+///
+/// The `r` attributes are optional; when absent, rows and cells follow their
+/// predecessor. Making them explicit lets edits insert rows and cells by position.
+fn fill_implicit_references(worksheet: &mut crate::types::Worksheet) {
+    let mut row_num = 0;
+    for row in &mut worksheet.sheet_data.row {
+        row_num = row.reference.unwrap_or(row_num + 1);
+        row.reference = Some(row_num);
+        let mut col = 0;
+        for cell in &mut row.cells {
+            col = cell
+                .reference
+                .as_deref()
+                .and_then(parse_cell_reference)
+                .map(|(_, c)| c)
+                .unwrap_or(col + 1);
+            cell.reference
+                .get_or_insert_with(|| format!("{}{}", column_to_letter(col), row_num));
+        }
+    }
+}
+
+/// Find the cell at `(row, col)`, inserting the row and cell in order if missing.
+fn cell_mut(
+    worksheet: &mut crate::types::Worksheet,
+    row: u32,
+    col: u32,
+) -> &mut crate::types::Cell {
+    expand_dimension(worksheet, row, col);
+
+    let rows = &mut worksheet.sheet_data.row;
+    let row_idx = match rows
+        .iter()
+        .position(|r| r.reference.is_some_and(|n| n >= row))
+    {
+        Some(i) if rows[i].reference == Some(row) => i,
+        pos => {
+            let i = pos.unwrap_or(rows.len());
+            rows.insert(
+                i,
+                crate::types::Row {
+                    reference: Some(row),
+                    ..Default::default()
+                },
+            );
+            i
+        }
+    };
+
+    let target = &mut rows[row_idx];
+    let column_of = |c: &crate::types::Cell| {
+        c.reference
+            .as_deref()
+            .and_then(parse_cell_reference)
+            .map(|(_, c)| c)
+    };
+    let cell_idx = match target
+        .cells
+        .iter()
+        .position(|c| column_of(c).is_some_and(|n| n >= col))
+    {
+        Some(i) if column_of(&target.cells[i]) == Some(col) => i,
+        pos => {
+            let i = pos.unwrap_or(target.cells.len());
+            target.cells.insert(
+                i,
+                crate::types::Cell {
+                    reference: Some(format!("{}{}", column_to_letter(col), row)),
+                    ..Default::default()
+                },
+            );
+            // Spans are an optimization hint and may no longer cover the row
+            target.cell_spans = None;
+            i
+        }
+    };
+    &mut target.cells[cell_idx]
+}
+
+/// Grow the worksheet's used range (`<dimension>`) to include a cell.
+fn expand_dimension(worksheet: &mut crate::types::Worksheet, row: u32, col: u32) {
+    let Some(dimension) = worksheet.dimension.as_mut() else {
+        return;
+    };
+    let (start, end) = dimension
+        .reference
+        .split_once(':')
+        .unwrap_or((&dimension.reference, &dimension.reference));
+    let (Some((r1, c1)), Some((r2, c2))) = (parse_cell_reference(start), parse_cell_reference(end))
+    else {
+        return;
+    };
+    let (r1, c1, r2, c2) = (r1.min(row), c1.min(col), r2.max(row), c2.max(col));
+    dimension.reference = if (r1, c1) == (r2, c2) {
+        format!("{}{}", column_to_letter(c1), r1)
+    } else {
+        format!(
+            "{}{}:{}{}",
+            column_to_letter(c1),
+            r1,
+            column_to_letter(c2),
+            r2
+        )
+    };
+}
+
 /// Parse comments using the generated FromXml parser.
-fn parse_comments_xml(xml: &[u8]) -> Result<Vec<ExtComment>> {
-    let comments: crate::types::Comments = bootstrap(xml)?;
+fn parse_comments_xml(xml: &[u8], path: &str) -> Result<Vec<ExtComment>> {
+    let comments: crate::types::Comments = parse_part(xml, path)?;
     let authors = comments.authors.author.clone();
     #[cfg(not(feature = "sml-comments"))]
     {
@@ -1067,25 +1751,13 @@ fn is_date_format_code(code: &str) -> bool {
 ///
 /// Scans the XML for the first element and calls `T::from_xml` on it.
 pub(crate) fn bootstrap<T: FromXml>(xml: &[u8]) -> Result<T> {
-    let mut reader = Reader::from_reader(Cursor::new(xml));
-    let mut buf = Vec::new();
-    loop {
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Start(e)) => {
-                return T::from_xml(&mut reader, &e, false)
-                    .map_err(|e| Error::Invalid(format!("{e:?}")));
-            }
-            Ok(Event::Empty(e)) => {
-                return T::from_xml(&mut reader, &e, true)
-                    .map_err(|e| Error::Invalid(format!("{e:?}")));
-            }
-            Ok(Event::Eof) => break,
-            Err(e) => return Err(Error::Xml(e)),
-            _ => {}
-        }
-        buf.clear();
-    }
-    Err(Error::Invalid("element not found".into()))
+    Ok(ooxml_xml::parse_root(xml)?)
+}
+
+/// Parse a package part with the generated parser, naming the part in
+/// parse errors.
+fn parse_part<T: FromXml>(xml: &[u8], path: &str) -> Result<T> {
+    ooxml_xml::parse_root(xml).map_err(|e| Error::Parse(e.in_part(path)))
 }
 
 /// Extract plain strings from a SharedStrings SST.
@@ -1358,69 +2030,10 @@ fn parse_chart(xml: &[u8]) -> Result<Chart> {
     })
 }
 
-// ============================================================================
-/// Resolve a relative path against a base path.
-fn resolve_path(base: &str, target: &str) -> String {
-    let has_leading_slash = base.starts_with('/');
-
-    if target.starts_with('/') {
-        return target.to_string();
-    }
-
-    // Get the directory of the base path
-    let base_dir = if let Some(idx) = base.rfind('/') {
-        &base[..idx]
-    } else {
-        ""
-    };
-
-    // Build path segments, handling ".." for parent directory
-    let mut parts: Vec<&str> = base_dir.split('/').filter(|s| !s.is_empty()).collect();
-    for segment in target.split('/') {
-        match segment {
-            ".." => {
-                parts.pop();
-            }
-            "." | "" => {}
-            _ => {
-                parts.push(segment);
-            }
-        }
-    }
-
-    let result = parts.join("/");
-    if has_leading_slash {
-        format!("/{}", result)
-    } else {
-        result
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_resolve_path() {
-        assert_eq!(
-            resolve_path("/xl/workbook.xml", "worksheets/sheet1.xml"),
-            "/xl/worksheets/sheet1.xml"
-        );
-        assert_eq!(
-            resolve_path("/xl/workbook.xml", "/xl/sharedStrings.xml"),
-            "/xl/sharedStrings.xml"
-        );
-        // Parent directory handling
-        assert_eq!(
-            resolve_path("/xl/chartsheets/sheet1.xml", "../drawings/drawing1.xml"),
-            "/xl/drawings/drawing1.xml"
-        );
-        assert_eq!(
-            resolve_path("/xl/worksheets/sheet1.xml", "../comments1.xml"),
-            "/xl/comments1.xml"
-        );
-    }
-
     #[test]
     fn test_bootstrap_shared_strings() {
         let xml = r#"<?xml version="1.0"?>
@@ -1483,7 +2096,7 @@ mod tests {
             </commentList>
         </comments>"#;
 
-        let comments = parse_comments_xml(xml.as_bytes()).unwrap();
+        let comments = parse_comments_xml(xml.as_bytes(), "xl/comments1.xml").unwrap();
         assert_eq!(comments.len(), 2);
 
         assert_eq!(comments[0].reference, "A1");
@@ -19,7 +19,7 @@
 use crate::error::Result;
 use crate::generated_serializers::ToXml;
 use crate::types;
-use ooxml_opc::PackageWriter;
+use ooxml_opc::{Conformance, PackageWriter, SerializeOptions, VbaProject};
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufWriter, Seek, Write};
@@ -28,6 +28,8 @@ use std::path::Path;
 // Content types
 const CT_WORKBOOK: &str =
     "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml";
+const CT_WORKBOOK_MACRO_ENABLED: &str = "application/vnd.ms-excel.sheet.macroEnabled.main+xml";
+const CT_VBA_PROJECT: &str = "application/vnd.ms-office.vbaProject";
 const CT_WORKSHEET: &str =
     "application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml";
 const CT_SHARED_STRINGS: &str =
@@ -64,6 +66,7 @@ const REL_COMMENTS: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/comments";
 const REL_HYPERLINK: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink";
+const REL_VBA_PROJECT: &str = "http://schemas.microsoft.com/office/2006/relationships/vbaProject";
 #[cfg(feature = "sml-charts")]
 const REL_DRAWING: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/drawing";
@@ -1423,7 +1426,7 @@ pub struct SheetBuilder {
 }
 
 /// Create a default empty Worksheet, ready to be filled in by SheetBuilder methods.
-fn init_worksheet() -> types::Worksheet {
+pub(crate) fn init_worksheet() -> types::Worksheet {
     types::Worksheet {
         #[cfg(feature = "sml-styling")]
         sheet_properties: None,
@@ -2493,6 +2496,12 @@ pub struct WorkbookBuilder {
     /// Optional workbook protection (sml-protection).
     #[cfg(feature = "sml-protection")]
     workbook_protection: Option<types::WorkbookProtection>,
+    /// Conformance class to write.
+    conformance: Conformance,
+    /// Options to reformat XML parts with, if any.
+    serialize_options: Option<SerializeOptions>,
+    /// VBA project, making the workbook macro-enabled.
+    vba_project: Option<VbaProject>,
 }
 
 // Helper types for style deduplication
@@ -2619,9 +2628,36 @@ impl WorkbookBuilder {
             extra_cell_styles: Vec::new(),
             #[cfg(feature = "sml-protection")]
             workbook_protection: None,
+            conformance: Conformance::Transitional,
+            serialize_options: None,
+            vba_project: None,
         }
     }
 
+    /// Set the conformance class to write (ISO/IEC 29500-1, §2).
+    ///
+    /// Strict workbooks use the `http://purl.oclc.org/ooxml/...` namespaces and
+    /// relationship types. Defaults to Transitional.
+    pub fn set_conformance(&mut self, conformance: Conformance) {
+        self.conformance = conformance;
+    }
+
+    /// Set the output options for the XML parts.
+    ///
+    /// Defaults to compact output; use [`SerializeOptions::pretty`] for
+    /// indented, deterministic parts that diff well under version control.
+    pub fn set_serialize_options(&mut self, options: SerializeOptions) {
+        self.serialize_options = Some(options);
+    }
+
+    /// Attach a VBA project, making the workbook macro-enabled.
+    ///
+    /// The workbook is written with the `.xlsm` content type, so it should be
+    /// saved with an `.xlsm` extension.
+    pub fn set_vba_project(&mut self, project: VbaProject) {
+        self.vba_project = Some(project);
+    }
+
     /// Add a new sheet to the workbook.
     pub fn add_sheet(&mut self, name: impl Into<String>) -> &mut SheetBuilder {
         self.sheets.push(SheetBuilder::new(name));
@@ -2820,6 +2856,12 @@ impl WorkbookBuilder {
         let has_styles = !self.cell_formats.is_empty() || !self.extra_cell_styles.is_empty();
 
         let mut pkg = PackageWriter::new(writer);
+        if self.conformance == Conformance::Strict {
+            pkg.set_conformance(Conformance::Strict);
+        }
+        if let Some(options) = self.serialize_options {
+            pkg.set_serialize_options(options);
+        }
 
         // Add default content types
         pkg.add_default_content_type("rels", CT_RELATIONSHIPS);
@@ -2872,6 +2914,16 @@ impl WorkbookBuilder {
                 next_rel_id, REL_SHARED_STRINGS
             ));
             wb_rels.push('\n');
+            next_rel_id += 1;
+        }
+
+        // Add VBA project relationship for macro-enabled workbooks
+        if self.vba_project.is_some() {
+            wb_rels.push_str(&format!(
+                r#"  <Relationship Id="rId{}" Type="{}" Target="vbaProject.bin"/>"#,
+                next_rel_id, REL_VBA_PROJECT
+            ));
+            wb_rels.push('\n');
         }
 
         wb_rels.push_str("</Relationships>");
@@ -2887,7 +2939,14 @@ impl WorkbookBuilder {
             CT_RELATIONSHIPS,
             wb_rels.as_bytes(),
         )?;
-        pkg.add_part("xl/workbook.xml", CT_WORKBOOK, &workbook_xml)?;
+        let workbook_content_type = match &self.vba_project {
+            Some(_) => CT_WORKBOOK_MACRO_ENABLED,
+            None => CT_WORKBOOK,
+        };
+        pkg.add_part("xl/workbook.xml", workbook_content_type, &workbook_xml)?;
+        if let Some(project) = &self.vba_project {
+            pkg.add_part("xl/vbaProject.bin", CT_VBA_PROJECT, project.data())?;
+        }
 
         // Write styles if any
         if has_styles {
@@ -3004,15 +3063,14 @@ impl WorkbookBuilder {
                 // Pivot table rels: one per pivot entry for this sheet.
                 #[cfg(feature = "sml-pivot")]
                 {
-                    let mut prel = pivot_rel_id_start;
                     for (pi, _pt) in sheet.pivot_tables.iter().enumerate() {
+                        let prel = pivot_rel_id_start + pi;
                         let global_pivot = next_pivot_num + pi;
                         sheet_rels.push_str(&format!(
                             r#"  <Relationship Id="rId{}" Type="{}" Target="../pivotTables/pivotTable{}.xml"/>"#,
                             prel, REL_PIVOT_TABLE, global_pivot
                         ));
                         sheet_rels.push('\n');
-                        prel += 1;
                     }
                 }
 
@@ -3842,11 +3900,11 @@ impl WorkbookBuilder {
 
         // Clone pre-built worksheet and fill in the rows
         let mut worksheet = sheet.worksheet.clone();
-        worksheet.sheet_data = Box::new(types::SheetData {
+        *worksheet.sheet_data = types::SheetData {
             row: rows,
             #[cfg(feature = "extra-children")]
             extra_children: Vec::new(),
-        });
+        };
 
         // Apply column outline levels and collapsed flags (if any).
         // We update existing Column entries in place; if a column has outline/collapsed
@@ -4190,7 +4248,8 @@ impl WorkbookBuilder {
         };
 
         types::Workbook {
-            conformance: None,
+            conformance: (self.conformance == Conformance::Strict)
+                .then_some(types::STConformanceClass::Strict),
             file_version: None,
             #[cfg(feature = "sml-protection")]
             file_sharing: None,
@@ -4745,7 +4804,7 @@ fn map_validation_error_style(
 }
 
 /// Parse a cell reference like "A1" into (row, col).
-fn parse_cell_reference(reference: &str) -> Option<(u32, u32)> {
+pub(crate) fn parse_cell_reference(reference: &str) -> Option<(u32, u32)> {
     let mut col_part = String::new();
     let mut row_part = String::new();
 
@@ -4788,7 +4847,7 @@ fn column_letter_to_number(letters: &str) -> Option<u32> {
 }
 
 /// Convert column number to letters (1=A, 2=B, ..., 26=Z, 27=AA).
-fn column_to_letter(mut col: u32) -> String {
+pub(crate) fn column_to_letter(mut col: u32) -> String {
     let mut result = String::new();
     while col > 0 {
         col -= 1;
@@ -4820,7 +4879,9 @@ fn serialize_workbook(value: &impl ToXml) -> Result<Vec<u8>> {
 }
 
 /// Serialize a ToXml value with custom namespace declarations and XML declaration.
-fn serialize_with_ns_decls(
+///
+/// Declarations whose key is already written by the value's own attributes are skipped.
+pub(crate) fn serialize_with_ns_decls(
     value: &impl ToXml,
     tag: &str,
     ns_decls: &[(&str, &str)],
@@ -4836,7 +4897,9 @@ fn serialize_with_ns_decls(
     let start = value.write_attrs(start);
     let mut start = start;
     for &(key, val) in ns_decls {
-        start.push_attribute((key, val));
+        if start.try_get_attribute(key).ok().flatten().is_none() {
+            start.push_attribute((key, val));
+        }
     }
 
     if value.is_empty_element() {
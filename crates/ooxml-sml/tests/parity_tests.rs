@@ -10,7 +10,7 @@
 use ooxml_corpus::roundtrip::{CompareOptions, DifferenceKind, compare_xml};
 use ooxml_sml::parsers::{FromXml, ParseError};
 use ooxml_sml::types::*;
-use quick_xml::Reader;
+use quick_xml::NsReader;
 use quick_xml::events::Event;
 use quick_xml::se::Serializer;
 use serde::Serialize;
@@ -18,7 +18,7 @@ use std::io::Cursor;
 
 /// Parse worksheet XML from bytes using the generated parser.
 fn parse_worksheet(xml: &[u8]) -> Result<Worksheet, ParseError> {
-    let mut reader = Reader::from_reader(Cursor::new(xml));
+    let mut reader = NsReader::from_reader(Cursor::new(xml));
     let mut buf = Vec::new();
 
     loop {
@@ -65,10 +65,8 @@ fn load_fixture(name: &str) -> Vec<u8> {
 fn parity_options() -> CompareOptions {
     CompareOptions {
         ignore_whitespace: true,
-        ignore_attribute_order: true,
-        ignore_element_order: false,
-        ignore_attributes: vec!["xmlns".to_string(), "xmlns:".to_string()],
-        ignore_elements: vec![],
+        ignore_namespace_declarations: true,
+        ..CompareOptions::strict()
     }
 }
 
@@ -95,7 +93,8 @@ fn run_parity_test(
         &original_xml,
         roundtripped_xml.as_bytes(),
         &parity_options(),
-    );
+    )
+    .unwrap_or_else(|e| panic!("Failed to compare {}: {}", fixture_name, e));
 
     (ws, roundtripped_xml, diffs)
 }
@@ -136,10 +135,7 @@ fn report_diffs(
         } else {
             "info"
         };
-        eprintln!(
-            "  [{}] {:?} at {}: {}",
-            marker, diff.kind, diff.path, diff.description
-        );
+        eprintln!("  [{}] {:?} at {}", marker, diff.kind, diff);
     }
 
     if diffs.len() > 15 {
@@ -424,7 +420,7 @@ fn test_serialize_produces_valid_xml() {
     let serialized = serialize_worksheet(&ws).expect("should serialize");
 
     // Try to parse the serialized output
-    let mut reader = Reader::from_str(&serialized);
+    let mut reader = NsReader::from_str(&serialized);
     let mut buf = Vec::new();
     let mut event_count = 0;
 
@@ -8,7 +8,7 @@
 
 use ooxml_sml::parsers::{FromXml, ParseError};
 use ooxml_sml::types::Worksheet;
-use quick_xml::Reader;
+use quick_xml::NsReader;
 use quick_xml::events::Event;
 use std::io::Cursor;
 
@@ -18,7 +18,7 @@ use std::io::Cursor;
 
 /// Parse a worksheet from raw XML bytes (mirrors the helper in fixture_tests.rs).
 fn parse_worksheet_xml(xml: &[u8]) -> Result<Worksheet, ParseError> {
-    let mut reader = Reader::from_reader(Cursor::new(xml));
+    let mut reader = NsReader::from_reader(Cursor::new(xml));
     let mut buf = Vec::new();
     loop {
         buf.clear();
@@ -44,7 +44,7 @@ fn parse_worksheet_xml(xml: &[u8]) -> Result<Worksheet, ParseError> {
 ///
 /// Scans for the first Start or Empty event and calls `T::from_xml` on it.
 fn bootstrap<T: FromXml>(xml: &[u8]) -> T {
-    let mut reader = Reader::from_reader(Cursor::new(xml));
+    let mut reader = NsReader::from_reader(Cursor::new(xml));
     let mut buf = Vec::new();
     loop {
         buf.clear();
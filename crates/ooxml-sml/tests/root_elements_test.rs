@@ -7,7 +7,7 @@
 
 use ooxml_sml::parsers::{FromXml, ParseError};
 use ooxml_sml::types::*;
-use quick_xml::Reader;
+use quick_xml::NsReader;
 use quick_xml::events::Event;
 use quick_xml::se::Serializer;
 use serde::Serialize;
@@ -25,7 +25,7 @@ fn load_fixture(name: &str) -> Vec<u8> {
 
 /// Generic parser for any root element type.
 fn parse_root<T: FromXml>(xml: &[u8], element_name: &[u8]) -> Result<T, ParseError> {
-    let mut reader = Reader::from_reader(Cursor::new(xml));
+    let mut reader = NsReader::from_reader(Cursor::new(xml));
     let mut buf = Vec::new();
 
     loop {
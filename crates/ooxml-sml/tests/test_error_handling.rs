@@ -58,3 +58,55 @@ fn test_missing_workbook_xml() {
     let result = Wb::from_reader(Cursor::new(bytes));
     assert!(result.is_err(), "should fail when workbook.xml is missing");
 }
+
+/// Replace one part of a package, keeping the others.
+fn with_part(package: Vec<u8>, name: &str, data: &[u8]) -> Vec<u8> {
+    use std::io::Write;
+    let mut archive = zip::ZipArchive::new(Cursor::new(package)).unwrap();
+    let mut buf = Cursor::new(Vec::new());
+    {
+        let mut writer = zip::ZipWriter::new(&mut buf);
+        for i in 0..archive.len() {
+            let file = archive.by_index(i).unwrap();
+            if file.name() == name {
+                let options = zip::write::SimpleFileOptions::default();
+                writer.start_file(name, options).unwrap();
+                writer.write_all(data).unwrap();
+            } else {
+                writer.raw_copy_file(file).unwrap();
+            }
+        }
+        writer.finish().unwrap();
+    }
+    buf.into_inner()
+}
+
+/// Errors from the generated parsers name the part, line, column and
+/// element path of the offending markup.
+#[test]
+fn test_parse_error_location() {
+    let mut wb = ooxml_sml::WorkbookBuilder::new();
+    wb.add_sheet("Data").set_cell("A1", "x");
+    let mut buffer = Cursor::new(Vec::new());
+    wb.write(&mut buffer).unwrap();
+    let sheet = br#"<?xml version="1.0" encoding="UTF-8"?>
+<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">
+  <sheetPr/>
+  <dimension/>
+  <sheetData/>
+</worksheet>"#;
+    let bytes = with_part(buffer.into_inner(), "xl/worksheets/sheet1.xml", sheet);
+
+    let mut workbook = Wb::from_reader(Cursor::new(bytes)).unwrap();
+    let err = workbook.resolved_sheet(0).unwrap_err();
+    let location = err.location().expect("parse errors are located");
+    assert_eq!(location.part.as_deref(), Some("xl/worksheets/sheet1.xml"));
+    let position = location.position.unwrap();
+    assert_eq!((position.line, position.column), (4, 3));
+    assert_eq!(location.path, ["worksheet", "dimension"]);
+    assert!(
+        err.to_string()
+            .contains("xl/worksheets/sheet1.xml:4:3 in <worksheet/dimension>"),
+        "{err}"
+    );
+}
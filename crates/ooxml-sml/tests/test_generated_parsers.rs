@@ -8,14 +8,14 @@
 
 use ooxml_sml::parsers::{FromXml, ParseError};
 use ooxml_sml::types::*;
-use quick_xml::Reader;
+use quick_xml::NsReader;
 use quick_xml::de::from_str;
 use quick_xml::events::Event;
 use std::io::Cursor;
 
 /// Helper to parse XML using the generated FromXml trait.
 fn parse_from_xml<T: FromXml>(xml: &str) -> Result<T, ParseError> {
-    let mut reader = Reader::from_reader(Cursor::new(xml.as_bytes()));
+    let mut reader = NsReader::from_reader(Cursor::new(xml.as_bytes()));
     let mut buf = Vec::new();
 
     loop {
@@ -1,8 +1,34 @@
 // Requires full feature set
 #![cfg(feature = "full")]
 
+use ooxml_sml::parsers::FromXml;
+use ooxml_sml::serializers::ToXml;
 use ooxml_sml::types::*;
 use quick_xml::de::from_str;
+use quick_xml::events::Event;
+use quick_xml::{NsReader, Writer};
+
+/// Parse the root element of an XML string using the FromXml trait.
+fn parse_from_xml<T: FromXml>(xml: &str) -> T {
+    let mut reader = NsReader::from_str(xml);
+    loop {
+        match reader.read_event().expect("should read event") {
+            Event::Start(e) => return T::from_xml(&mut reader, &e, false).expect("should parse"),
+            Event::Empty(e) => return T::from_xml(&mut reader, &e, true).expect("should parse"),
+            Event::Eof => panic!("no element found"),
+            _ => {}
+        }
+    }
+}
+
+/// Serialize a value to an XML string using the ToXml trait.
+fn serialize_to_xml<T: ToXml>(value: &T, tag: &str) -> String {
+    let mut writer = Writer::new(Vec::new());
+    value
+        .write_element(tag, &mut writer)
+        .expect("should serialize");
+    String::from_utf8(writer.into_inner()).expect("valid utf-8")
+}
 
 #[test]
 fn test_autofilter_deserialize() {
@@ -107,3 +133,35 @@ fn test_real_xlsx_sheet_data() {
     let result: Result<SheetData, _> = from_str(xml);
     println!("Sheet data: {:?}", result);
 }
+
+#[test]
+fn test_worksheet_json_roundtrip() {
+    let xml = r#"<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:x="urn:custom">
+        <sheetData>
+            <row r="1">
+                <c r="A1" t="s"><v>0</v></c>
+                <c r="B1" x:tag="1"><v>42</v></c>
+            </row>
+            <row r="2"><c r="A2"><f>B1*2</f><v>84</v></c></row>
+        </sheetData>
+        <mergeCells count="1"><mergeCell ref="A3:B3"/></mergeCells>
+        <x:note>kept</x:note>
+    </worksheet>"#;
+
+    let sheet: Worksheet = parse_from_xml(xml);
+    let json = serde_json::to_string(&sheet).expect("should serialize to JSON");
+    let loaded: Worksheet = serde_json::from_str(&json).expect("should deserialize from JSON");
+
+    let written = serialize_to_xml(&loaded, "worksheet");
+    assert!(written.contains(r#"<c r="B1" x:tag="1"><v>42</v></c>"#));
+    assert!(written.contains("<f>B1*2</f>"));
+    assert!(written.contains("<x:note>kept</x:note>"));
+
+    // Compare re-parsed XML so extra children positions match on both sides
+    let reparsed: Worksheet = parse_from_xml(&written);
+    let original: Worksheet = parse_from_xml(&serialize_to_xml(&sheet, "worksheet"));
+    assert_eq!(
+        serde_json::to_value(&reparsed).unwrap(),
+        serde_json::to_value(&original).unwrap()
+    );
+}
@@ -8,14 +8,14 @@
 use ooxml_sml::parsers::{FromXml, ParseError};
 use ooxml_sml::serializers::{SerializeError, ToXml};
 use ooxml_sml::types::*;
-use quick_xml::Reader;
+use quick_xml::NsReader;
 use quick_xml::Writer;
 use quick_xml::events::Event;
 use std::io::Cursor;
 
 /// Parse an XML string using the FromXml trait.
 fn parse_from_xml<T: FromXml>(xml: &str) -> Result<T, ParseError> {
-    let mut reader = Reader::from_reader(Cursor::new(xml.as_bytes()));
+    let mut reader = NsReader::from_reader(Cursor::new(xml.as_bytes()));
     let mut buf = Vec::new();
     loop {
         match reader.read_event_into(&mut buf)? {
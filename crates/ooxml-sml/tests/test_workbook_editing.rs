@@ -0,0 +1,293 @@
+// Requires full feature set
+#![cfg(feature = "full")]
+
+//! Integration tests for editing existing workbooks (open-modify-save).
+
+use ooxml_opc::Package;
+use ooxml_sml::{CellStyle, FontStyle, Workbook, WorkbookBuilder, WriteCellValue};
+use std::io::Cursor;
+
+/// Build a small workbook in memory and open it for editing.
+fn open_template() -> Workbook<Cursor<Vec<u8>>> {
+    let mut wb = WorkbookBuilder::new();
+    let data = wb.add_sheet("Data");
+    let header = CellStyle::new().with_font(FontStyle::new().bold());
+    data.set_cell_styled("A1", "Name", header.clone());
+    data.set_cell_styled("B1", "Amount", header);
+    data.set_cell("A2", "Widget");
+    data.set_cell("B2", 10.0);
+    data.set_formula("B3", "SUM(B2:B2)");
+    data.add_comment("A1", "Header row");
+    let summary = wb.add_sheet("Summary");
+    summary.set_cell("A1", "Total");
+    let notes = wb.add_sheet("Notes");
+    notes.set_cell("A1", "Draft");
+    wb.add_defined_name("Amounts", "Data!$B$2:$B$3");
+    wb.add_defined_name_with_scope("LocalNote", "Notes!$A$1", 2);
+    wb.add_defined_name_with_scope("LocalSummary", "Summary!$A$1", 1);
+
+    let mut buffer = Cursor::new(Vec::new());
+    wb.write(&mut buffer).expect("write template");
+    buffer.set_position(0);
+    Workbook::from_reader(buffer).expect("open template")
+}
+
+/// Write an edited workbook to memory and read it back.
+fn reopen(workbook: &mut Workbook<Cursor<Vec<u8>>>) -> Workbook<Cursor<Vec<u8>>> {
+    let mut buffer = Cursor::new(Vec::new());
+    workbook.write(&mut buffer).expect("write edited workbook");
+    buffer.set_position(0);
+    Workbook::from_reader(buffer).expect("reopen edited workbook")
+}
+
+#[test]
+fn test_edit_cells_and_save() {
+    let mut workbook = open_template();
+    workbook.set_cell(0, "A2", "Gadget").unwrap();
+    workbook.set_cell(0, "B2", 25.5).unwrap();
+    workbook.set_cell(0, "C5", true).unwrap();
+    workbook.set_cell(0, "A4", "Name").unwrap();
+    workbook
+        .set_cell(0, "D1", WriteCellValue::Formula("B2*2".into()))
+        .unwrap();
+
+    let mut reopened = reopen(&mut workbook);
+    let sheet = reopened.resolved_sheet(0).unwrap();
+    assert_eq!(sheet.value_at("A1"), Some("Name".to_string()));
+    assert_eq!(sheet.value_at("A2"), Some("Gadget".to_string()));
+    assert_eq!(sheet.number_at("B2"), Some(25.5));
+    assert_eq!(sheet.value_at("C5"), Some("TRUE".to_string()));
+    assert_eq!(sheet.value_at("A4"), Some("Name".to_string()));
+    let formula = sheet.cell("D1").and_then(|c| c.formula.as_ref());
+    assert_eq!(
+        formula.and_then(|f| f.text.as_deref()),
+        Some("B2*2"),
+        "formula should be written"
+    );
+
+    // Rows and cells stay in sheet order
+    let rows: Vec<u32> = sheet.rows().filter_map(|r| r.reference).collect();
+    assert_eq!(rows, vec![1, 2, 3, 4, 5]);
+    let row1: Vec<&str> = sheet
+        .row(1)
+        .unwrap()
+        .cells
+        .iter()
+        .filter_map(|c| c.reference.as_deref())
+        .collect();
+    assert_eq!(row1, vec!["A1", "B1", "D1"]);
+
+    // Untouched comments survive the rewrite of their sheet
+    assert_eq!(sheet.comments().len(), 1);
+}
+
+#[test]
+fn test_non_finite_numbers_are_rejected() {
+    let mut workbook = open_template();
+    for n in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        assert!(workbook.set_cell(0, "B2", n).is_err());
+    }
+    let mut reopened = reopen(&mut workbook);
+    let sheet = reopened.resolved_sheet(0).unwrap();
+    assert_eq!(sheet.number_at("B2"), Some(10.0), "cell is left unchanged");
+}
+
+#[test]
+fn test_shared_strings_are_reused() {
+    let mut workbook = open_template();
+    let existing = workbook.add_shared_string("Widget");
+    assert_eq!(workbook.add_shared_string("Widget"), existing);
+    let added = workbook.add_shared_string("Brand new");
+    assert_eq!(workbook.add_shared_string("Brand new"), added);
+    assert_ne!(existing, added);
+}
+
+#[test]
+fn test_add_sheet() {
+    let mut workbook = open_template();
+    let index = workbook.add_sheet("Report").unwrap();
+    assert_eq!(index, 3);
+    workbook.set_cell(index, "B2", "Quarterly").unwrap();
+
+    assert!(workbook.add_sheet("report").is_err(), "names are unique");
+    assert!(workbook.add_sheet("Bad/Name").is_err());
+
+    let mut reopened = reopen(&mut workbook);
+    assert_eq!(
+        reopened.sheet_names(),
+        vec!["Data", "Summary", "Notes", "Report"]
+    );
+    let sheet = reopened.resolved_sheet_by_name("Report").unwrap();
+    assert_eq!(sheet.value_at("B2"), Some("Quarterly".to_string()));
+}
+
+#[test]
+fn test_remove_sheet_updates_defined_names() {
+    let mut workbook = open_template();
+    workbook.remove_sheet(1).unwrap();
+    assert_eq!(workbook.sheet_names(), vec!["Data", "Notes"]);
+
+    let mut buffer = Cursor::new(Vec::new());
+    workbook.write(&mut buffer).unwrap();
+    buffer.set_position(0);
+
+    let package = Package::open(buffer.clone()).unwrap();
+    assert!(!package.has_part("xl/worksheets/sheet2.xml"));
+    assert!(package.has_part("xl/worksheets/sheet1.xml"));
+    assert!(package.has_part("xl/worksheets/sheet3.xml"));
+
+    let reopened = Workbook::from_reader(buffer).unwrap();
+    assert_eq!(reopened.sheet_names(), vec!["Data", "Notes"]);
+    assert!(reopened.defined_name("Amounts").is_some());
+    assert!(reopened.defined_name_in_sheet("LocalSummary", 1).is_none());
+    assert!(reopened.defined_name_in_sheet("LocalNote", 1).is_some());
+
+    let mut workbook = reopened;
+    workbook.remove_sheet(1).unwrap();
+    assert!(
+        workbook.remove_sheet(0).is_err(),
+        "the last sheet cannot be removed"
+    );
+}
+
+#[test]
+fn test_untouched_parts_are_preserved() {
+    let mut original = Cursor::new(Vec::new());
+    let mut workbook = open_template();
+    workbook.write(&mut original).unwrap();
+    original.set_position(0);
+    let mut before: Vec<String> = Package::open(original)
+        .unwrap()
+        .parts()
+        .map(String::from)
+        .collect();
+
+    workbook.set_cell(0, "B2", 99.0).unwrap();
+    let mut buffer = Cursor::new(Vec::new());
+    workbook.write(&mut buffer).unwrap();
+    buffer.set_position(0);
+    let package = Package::open(buffer).unwrap();
+    let mut after: Vec<String> = package.parts().map(String::from).collect();
+
+    before.sort();
+    after.sort();
+    assert_eq!(before, after);
+    assert!(package.has_part("xl/comments1.xml"));
+    assert!(package.has_part("xl/styles.xml"));
+}
+
+#[test]
+fn test_root_namespaces_are_preserved() {
+    let mut workbook = open_template();
+    workbook.set_cell(0, "A1", "Renamed").unwrap();
+    let xml = String::from_utf8(workbook.sheet_xml(0).unwrap()).unwrap();
+    assert!(xml.contains(r#"xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main""#));
+    assert_eq!(xml.matches("xmlns=").count(), 1);
+}
+
+#[test]
+fn test_styles_can_be_edited() {
+    let mut workbook = open_template();
+    let cell_xfs = workbook
+        .styles_mut()
+        .cell_xfs
+        .get_or_insert_with(Default::default);
+    cell_xfs.xf.push(Default::default());
+    let new_index = (cell_xfs.xf.len() - 1) as u32;
+    workbook.set_cell_style(0, "A1", new_index).unwrap();
+
+    let mut reopened = reopen(&mut workbook);
+    let count = reopened
+        .styles()
+        .cell_xfs
+        .as_ref()
+        .map_or(0, |xfs| xfs.xf.len());
+    assert_eq!(count as u32, new_index + 1);
+    let sheet = reopened.resolved_sheet(0).unwrap();
+    assert_eq!(sheet.cell("A1").unwrap().style_index, Some(new_index));
+    assert_eq!(sheet.value_at("A1"), Some("Name".to_string()));
+}
+
+#[test]
+fn test_strict_workbook_stays_strict() {
+    let mut wb = WorkbookBuilder::new();
+    wb.set_conformance(ooxml_sml::Conformance::Strict);
+    wb.add_sheet("Data").set_cell("A1", "Strict");
+    let mut buffer = Cursor::new(Vec::new());
+    wb.write(&mut buffer).unwrap();
+    buffer.set_position(0);
+
+    let mut workbook = Workbook::from_reader(buffer).unwrap();
+    assert_eq!(
+        workbook.resolved_sheet(0).unwrap().value_at("A1"),
+        Some("Strict".to_string())
+    );
+    workbook.set_cell(0, "A2", "Edited").unwrap();
+
+    let mut out = Cursor::new(Vec::new());
+    workbook.write(&mut out).unwrap();
+    out.set_position(0);
+    let mut package = Package::open(out.clone()).unwrap();
+    assert_eq!(
+        package.conformance().unwrap(),
+        ooxml_sml::Conformance::Strict
+    );
+    let xml = package
+        .read_part_string("xl/worksheets/sheet1.xml")
+        .unwrap();
+    assert!(xml.contains("http://purl.oclc.org/ooxml/spreadsheetml/main"));
+
+    let mut reopened = Workbook::from_reader(out).unwrap();
+    let sheet = reopened.resolved_sheet(0).unwrap();
+    assert_eq!(sheet.value_at("A2"), Some("Edited".to_string()));
+}
+
+#[test]
+fn test_pretty_workbook_parts() {
+    let mut wb = WorkbookBuilder::new();
+    wb.set_serialize_options(ooxml_sml::SerializeOptions::pretty());
+    wb.add_sheet("Data").set_cell("A1", "Pretty");
+    let mut buffer = Cursor::new(Vec::new());
+    wb.write(&mut buffer).unwrap();
+    buffer.set_position(0);
+
+    let mut package = Package::open(buffer.clone()).unwrap();
+    let xml = package
+        .read_part_string("xl/worksheets/sheet1.xml")
+        .unwrap();
+    assert!(xml.contains("\n  <sheetData>\n    <row"), "{xml}");
+
+    let mut workbook = Workbook::from_reader(buffer).unwrap();
+    let sheet = workbook.resolved_sheet(0).unwrap();
+    assert_eq!(sheet.value_at("A1"), Some("Pretty".to_string()));
+}
+
+#[test]
+fn test_macro_enabled_workbook_is_preserved() {
+    let project = ooxml_sml::VbaProject::new(b"vbaProject.bin bytes".to_vec());
+    let mut wb = WorkbookBuilder::new();
+    wb.set_vba_project(project.clone());
+    wb.add_sheet("Data").set_cell("A1", "Macros");
+    let mut buffer = Cursor::new(Vec::new());
+    wb.write(&mut buffer).unwrap();
+    buffer.set_position(0);
+
+    let mut workbook = Workbook::from_reader(buffer).unwrap();
+    assert!(workbook.is_macro_enabled());
+    assert_eq!(workbook.vba_project().unwrap(), Some(project.clone()));
+    workbook.set_cell(0, "A2", "Edited").unwrap();
+
+    let mut out = Cursor::new(Vec::new());
+    workbook.write(&mut out).unwrap();
+    out.set_position(0);
+    let package = Package::open(out.clone()).unwrap();
+    assert_eq!(
+        package.content_type("xl/workbook.xml"),
+        Some("application/vnd.ms-excel.sheet.macroEnabled.main+xml")
+    );
+
+    let mut reopened = Workbook::from_reader(out).unwrap();
+    assert!(reopened.is_macro_enabled());
+    assert_eq!(reopened.vba_project().unwrap(), Some(project));
+    assert!(!open_template().is_macro_enabled());
+}
@@ -1,6 +1,6 @@
 use ooxml_codegen::{
     CodegenConfig, FeatureMappings, NameMappings, Schema, analyze_schema, generate,
-    generate_parsers, generate_serializers, parse_rnc,
+    generate_borrowed, generate_parsers, generate_serializers, generate_visitors, parse_rnc,
 };
 use std::fs;
 use std::path::Path;
@@ -28,19 +28,69 @@ fn main() {
     println!("cargo::rerun-if-changed={}", names_path);
     println!("cargo::rerun-if-changed={}", features_path);
     println!("cargo::rerun-if-changed=build.rs");
+    println!("cargo::rerun-if-env-changed=OOXML_GENERATE_BORROWED");
+    println!("cargo::rerun-if-env-changed=OOXML_GENERATE_VISIT");
 
     // The generated file is committed at src/generated.rs
     // Only regenerate if OOXML_REGENERATE is set and specs exist
-    let should_regenerate = std::env::var("OOXML_REGENERATE").is_ok();
-    let dest_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/generated.rs");
+    if std::env::var("OOXML_REGENERATE").is_ok() {
+        regenerate(
+            &wml_path,
+            &shared_path,
+            &rel_path,
+            names_path,
+            features_path,
+        );
+    }
 
-    if !should_regenerate {
-        // Use the committed generated.rs - nothing to do
-        return;
+    // Derive borrowed types from the committed types and parsers, so this
+    // does not need the schemas
+    // Enable with OOXML_GENERATE_BORROWED=1
+    if std::env::var("OOXML_GENERATE_BORROWED").is_ok() {
+        let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let types = fs::read_to_string(src.join("generated.rs")).expect("failed to read types");
+        let parsers =
+            fs::read_to_string(src.join("generated_parsers.rs")).expect("failed to read parsers");
+        let borrowed_dest = src.join("generated_borrowed.rs");
+        let borrowed_code =
+            generate_borrowed(&types, &parsers).expect("failed to generate borrowed types");
+        fs::write(&borrowed_dest, borrowed_code).expect("failed to write borrowed types");
+        eprintln!(
+            "Generated {} bytes to src/generated_borrowed.rs",
+            borrowed_dest.metadata().map(|m| m.len()).unwrap_or(0)
+        );
     }
 
+    // Derive Visit/VisitMut traversal and query trees from the committed
+    // types and serializers
+    // Enable with OOXML_GENERATE_VISIT=1
+    if std::env::var("OOXML_GENERATE_VISIT").is_ok() {
+        let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let types = fs::read_to_string(src.join("generated.rs")).expect("failed to read types");
+        let serializers = fs::read_to_string(src.join("generated_serializers.rs"))
+            .expect("failed to read serializers");
+        let visit_dest = src.join("generated_visit.rs");
+        let visit_code =
+            generate_visitors(&types, &serializers, &[]).expect("failed to generate visitors");
+        fs::write(&visit_dest, visit_code).expect("failed to write visitors");
+        eprintln!(
+            "Generated {} bytes to src/generated_visit.rs",
+            visit_dest.metadata().map(|m| m.len()).unwrap_or(0)
+        );
+    }
+}
+
+fn regenerate(
+    wml_path: &str,
+    shared_path: &str,
+    rel_path: &str,
+    names_path: &str,
+    features_path: &str,
+) {
+    let dest_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/generated.rs");
+
     // Check if schema exists
-    if !Path::new(&wml_path).exists() {
+    if !Path::new(wml_path).exists() {
         eprintln!(
             "Warning: Schema not found at {}. Run scripts/download-spec.sh first.",
             wml_path
@@ -51,8 +101,8 @@ fn main() {
     eprintln!("Regenerating src/generated.rs from schemas...");
 
     // Parse the shared types schema first
-    let mut combined_schema = if Path::new(&shared_path).exists() {
-        let shared_input = fs::read_to_string(&shared_path).expect("failed to read shared types");
+    let mut combined_schema = if Path::new(shared_path).exists() {
+        let shared_input = fs::read_to_string(shared_path).expect("failed to read shared types");
         parse_rnc(&shared_input).expect("failed to parse shared types")
     } else {
         Schema {
@@ -62,9 +112,9 @@ fn main() {
     };
 
     // Parse and merge the relationship reference schema (for r:id, r:embed, etc.)
-    if Path::new(&rel_path).exists() {
+    if Path::new(rel_path).exists() {
         let rel_input =
-            fs::read_to_string(&rel_path).expect("failed to read relationship references");
+            fs::read_to_string(rel_path).expect("failed to read relationship references");
         let rel_schema = parse_rnc(&rel_input).expect("failed to parse relationship references");
         for ns in rel_schema.namespaces {
             if !combined_schema
@@ -79,7 +129,7 @@ fn main() {
     }
 
     // Parse and merge the WML schema
-    let wml_input = fs::read_to_string(&wml_path).expect("failed to read wml.rnc");
+    let wml_input = fs::read_to_string(wml_path).expect("failed to read wml.rnc");
     let wml_schema = parse_rnc(&wml_input).expect("failed to parse wml.rnc");
 
     // Merge: add WML namespaces and definitions (WML takes precedence for duplicates)
@@ -134,6 +184,11 @@ fn main() {
         feature_mappings,
         // WML uses w: namespace prefix in real DOCX files
         xml_serialize_prefix: Some("w".to_string()),
+        // Elements in other namespaces (extensions) are kept as unknown children
+        xml_namespaces: vec![
+            "http://schemas.openxmlformats.org/wordprocessingml/2006/main".to_string(),
+            "http://purl.oclc.org/ooxml/wordprocessingml/main".to_string(),
+        ],
         warn_unmapped: true,
         ..Default::default()
     };
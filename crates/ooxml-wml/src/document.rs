@@ -13,10 +13,13 @@ use crate::error::{Error, Result};
 use crate::ext;
 use crate::generated as types;
 use crate::generated_serializers::ToXml;
-use ooxml_opc::{Package, PackageWriter, Relationships, rel_type, rels_path_for};
-use ooxml_xml::{PositionedNode, RawXmlElement, RawXmlNode};
-use quick_xml::Reader;
+use ooxml_opc::{
+    Conformance, Package, PackageLimits, PackageWriter, Relationships, SerializeOptions,
+    VbaProject, rel_type, rels_path_for, resolve_target,
+};
+use ooxml_xml::{Alternates, MceProcessor, ParseError, PositionedNode, RawXmlElement, RawXmlNode};
 use quick_xml::events::Event;
+use quick_xml::{NsReader, Reader};
 use std::fs::File;
 use std::io::{BufReader, Read, Seek};
 use std::path::Path;
@@ -31,6 +34,9 @@ use std::path::Path;
 pub struct Document<R> {
     package: Package<R>,
     gen_doc: types::Document,
+    /// The `mc:AlternateContent` elements of the document part, to restore
+    /// when writing.
+    doc_alternates: Alternates,
     gen_styles: types::Styles,
     /// Document part relationships (for images, hyperlinks, etc.)
     doc_rels: Relationships,
@@ -42,6 +48,8 @@ pub struct Document<R> {
     core_properties: Option<CoreProperties>,
     /// Extended application properties (word count, etc.)
     app_properties: Option<AppProperties>,
+    /// Options to reformat XML parts with when saving, if any.
+    serialize_options: Option<SerializeOptions>,
 }
 
 impl Document<BufReader<File>> {
@@ -56,19 +64,34 @@ impl Document<BufReader<File>> {
 impl<R: Read + Seek> Document<R> {
     /// Open a Word document from a reader.
     pub fn from_reader(reader: R) -> Result<Self> {
-        let mut package = Package::open(reader)?;
+        Self::from_package(Package::open(reader)?)
+    }
+
+    /// Open an untrusted Word document from a reader, enforcing resource limits.
+    ///
+    /// Fails with [`ooxml_opc::Error::LimitExceeded`] if the package or any
+    /// part read from it exceeds the limits.
+    pub fn from_reader_with_limits(reader: R, limits: &PackageLimits) -> Result<Self> {
+        Self::from_package(Package::open_with_limits(reader, limits)?)
+    }
 
+    fn from_package(mut package: Package<R>) -> Result<Self> {
         // Find the main document part via relationships
         let rels = package.read_relationships()?;
         let doc_rel = rels
             .get_by_type(rel_type::OFFICE_DOCUMENT)
             .ok_or_else(|| Error::MissingPart("main document relationship".into()))?;
 
-        let doc_path = doc_rel.target.clone();
+        let doc_path = resolve_target("", &doc_rel.target);
 
-        // Parse the document XML using the generated parser
+        // Parse the document XML using the generated parser, with the
+        // markup compatibility rules applied in a way that can be undone
+        // when the document is written back
         let doc_xml = package.read_part(&doc_path)?;
-        let gen_doc = ext::parse_document(&doc_xml)?;
+        let (compat_xml, offsets, doc_alternates) =
+            markup_compatibility().process_for_editing(&doc_xml)?;
+        let gen_doc = ext::parse_document(&compat_xml)
+            .map_err(|e| e.in_original(&doc_xml, &offsets).in_part(&doc_path))?;
 
         // Load document-level relationships (for images, hyperlinks, etc.)
         let doc_rels_path = rels_path_for(&doc_path);
@@ -86,11 +109,17 @@ impl<R: Read + Seek> Document<R> {
         {
             let path = styles_rel.target.clone();
             let styles_xml = package.read_part(&path)?;
-            (ext::parse_styles(&styles_xml)?, Some(path))
+            (
+                ext::parse_styles(&styles_xml).map_err(|e| e.in_part(&path))?,
+                Some(path),
+            )
         } else if let Some(styles_rel) = doc_rels.get_by_type(rel_type::STYLES) {
-            let path = resolve_path(&doc_path, &styles_rel.target);
+            let path = resolve_target(&doc_path, &styles_rel.target);
             let styles_xml = package.read_part(&path)?;
-            (ext::parse_styles(&styles_xml)?, Some(path))
+            (
+                ext::parse_styles(&styles_xml).map_err(|e| e.in_part(&path))?,
+                Some(path),
+            )
         } else {
             (types::Styles::default(), None)
         };
@@ -115,12 +144,14 @@ impl<R: Read + Seek> Document<R> {
         Ok(Self {
             package,
             gen_doc,
+            doc_alternates,
             gen_styles,
             doc_rels,
             doc_path,
             styles_path,
             core_properties,
             app_properties,
+            serialize_options: None,
         })
     }
 
@@ -158,6 +189,24 @@ impl<R: Read + Seek> Document<R> {
         &mut self.package
     }
 
+    /// Check whether the document is macro-enabled (`.docm` or `.dotm`).
+    pub fn is_macro_enabled(&self) -> bool {
+        self.package
+            .content_type(&self.doc_path)
+            .is_some_and(|ct| ct.contains(".macroEnabled"))
+    }
+
+    /// Load the VBA project (`vbaProject.bin`) of a macro-enabled document.
+    ///
+    /// The project is carried through unchanged when the document is saved.
+    pub fn vba_project(&mut self) -> Result<Option<VbaProject>> {
+        let Some(rel) = self.doc_rels.get_by_type(rel_type::VBA_PROJECT) else {
+            return Ok(None);
+        };
+        let path = resolve_target(&self.doc_path, &rel.target);
+        Ok(Some(VbaProject::new(self.package.read_part(&path)?)))
+    }
+
     /// Get the document styles (generated types).
     pub fn styles(&self) -> &types::Styles {
         &self.gen_styles
@@ -194,22 +243,28 @@ impl<R: Read + Seek> Document<R> {
     /// Looks up the relationship, reads the image file from the package,
     /// and returns the image data with its content type.
     pub fn get_image_data(&mut self, rel_id: &str) -> Result<ImageData> {
-        // Look up the relationship
-        let rel = self
-            .doc_rels
-            .get(rel_id)
-            .ok_or_else(|| Error::MissingPart(format!("image relationship {}", rel_id)))?;
-
-        // Resolve the target path relative to the document
-        let image_path = resolve_path(&self.doc_path, &rel.target);
-
-        // Read the image data from the package
-        let data = self.package.read_part(&image_path)?;
-
-        // Determine content type from extension
-        let content_type = content_type_from_path(&image_path);
+        read_image(&mut self.package, &self.doc_rels, &self.doc_path, rel_id)
+    }
 
-        Ok(ImageData { content_type, data })
+    /// The document body, its relationships and a loader for the images it
+    /// references, so that images can be read while the body is walked.
+    #[cfg_attr(not(feature = "html"), allow(dead_code))]
+    pub(crate) fn body_with_images(
+        &mut self,
+    ) -> (
+        &types::Body,
+        &Relationships,
+        impl FnMut(&str) -> Result<ImageData> + '_,
+    ) {
+        let body = self
+            .gen_doc
+            .body
+            .as_deref()
+            .expect("document has no body element");
+        let (package, rels, doc_path) = (&mut self.package, &self.doc_rels, &self.doc_path);
+        (body, rels, move |rel_id: &str| {
+            read_image(package, rels, doc_path, rel_id)
+        })
     }
 
     /// Get the URL for a hyperlink by its relationship ID.
@@ -233,9 +288,9 @@ impl<R: Read + Seek> Document<R> {
             .get(rel_id)
             .ok_or_else(|| Error::MissingPart(format!("header relationship {}", rel_id)))?;
 
-        let header_path = resolve_path(&self.doc_path, &rel.target);
+        let header_path = resolve_target(&self.doc_path, &rel.target);
         let header_xml = self.package.read_part(&header_path)?;
-        Ok(ext::parse_hdr_ftr(&header_xml)?)
+        parse_compat(&header_xml, &header_path, ext::parse_hdr_ftr)
     }
 
     /// Load a footer part by its relationship ID.
@@ -247,9 +302,9 @@ impl<R: Read + Seek> Document<R> {
             .get(rel_id)
             .ok_or_else(|| Error::MissingPart(format!("footer relationship {}", rel_id)))?;
 
-        let footer_path = resolve_path(&self.doc_path, &rel.target);
+        let footer_path = resolve_target(&self.doc_path, &rel.target);
         let footer_xml = self.package.read_part(&footer_path)?;
-        Ok(ext::parse_hdr_ftr(&footer_xml)?)
+        parse_compat(&footer_xml, &footer_path, ext::parse_hdr_ftr)
     }
 
     /// Load the footnotes part.
@@ -265,9 +320,9 @@ impl<R: Read + Seek> Document<R> {
             )
             .ok_or_else(|| Error::MissingPart("footnotes relationship".into()))?;
 
-        let footnotes_path = resolve_path(&self.doc_path, &footnotes_rel.target);
+        let footnotes_path = resolve_target(&self.doc_path, &footnotes_rel.target);
         let footnotes_xml = self.package.read_part(&footnotes_path)?;
-        Ok(ext::parse_footnotes(&footnotes_xml)?)
+        parse_compat(&footnotes_xml, &footnotes_path, ext::parse_footnotes)
     }
 
     /// Load the endnotes part.
@@ -283,9 +338,9 @@ impl<R: Read + Seek> Document<R> {
             )
             .ok_or_else(|| Error::MissingPart("endnotes relationship".into()))?;
 
-        let endnotes_path = resolve_path(&self.doc_path, &endnotes_rel.target);
+        let endnotes_path = resolve_target(&self.doc_path, &endnotes_rel.target);
         let endnotes_xml = self.package.read_part(&endnotes_path)?;
-        Ok(ext::parse_endnotes(&endnotes_xml)?)
+        parse_compat(&endnotes_xml, &endnotes_path, ext::parse_endnotes)
     }
 
     /// Load the comments part.
@@ -301,9 +356,40 @@ impl<R: Read + Seek> Document<R> {
             )
             .ok_or_else(|| Error::MissingPart("comments relationship".into()))?;
 
-        let comments_path = resolve_path(&self.doc_path, &comments_rel.target);
+        let comments_path = resolve_target(&self.doc_path, &comments_rel.target);
         let comments_xml = self.package.read_part(&comments_path)?;
-        Ok(ext::parse_comments(&comments_xml)?)
+        parse_compat(&comments_xml, &comments_path, ext::parse_comments)
+    }
+
+    /// Load the numbering definitions part.
+    ///
+    /// Returns the parsed numbering definitions as a generated `Numbering` type.
+    ///
+    /// Returns `Error::MissingPart` if the document has no numbering.xml.
+    pub fn get_numbering(&mut self) -> Result<types::Numbering> {
+        let numbering_rel = self
+            .doc_rels
+            .get_by_type(rel_type::NUMBERING)
+            .ok_or_else(|| Error::MissingPart("numbering relationship".into()))?;
+
+        let numbering_path = resolve_target(&self.doc_path, &numbering_rel.target);
+        let numbering_xml = self.package.read_part(&numbering_path)?;
+        parse_compat(&numbering_xml, &numbering_path, ext::parse_numbering)
+    }
+
+    /// Load the numbering definitions into a [`NumberingContext`] for
+    /// computing list labels.
+    ///
+    /// A document without numbering.xml gets an empty context.
+    ///
+    /// [`NumberingContext`]: crate::ext::NumberingContext
+    #[cfg(feature = "wml-numbering")]
+    pub fn numbering_context(&mut self) -> Result<ext::NumberingContext> {
+        match self.get_numbering() {
+            Ok(numbering) => Ok(ext::NumberingContext::from_numbering(&numbering)),
+            Err(Error::MissingPart(_)) => Ok(ext::NumberingContext::default()),
+            Err(e) => Err(e),
+        }
     }
 
     /// Load the document settings.
@@ -319,7 +405,7 @@ impl<R: Read + Seek> Document<R> {
             )
             .ok_or_else(|| Error::MissingPart("settings relationship".into()))?;
 
-        let settings_path = resolve_path(&self.doc_path, &settings_rel.target);
+        let settings_path = resolve_target(&self.doc_path, &settings_rel.target);
         let settings_xml = self.package.read_part(&settings_path)?;
         parse_settings(&settings_xml)
     }
@@ -343,9 +429,19 @@ impl<R: Read + Seek> Document<R> {
             .get(rel_id)
             .ok_or_else(|| Error::MissingPart(format!("chart relationship {}", rel_id)))?;
 
-        let chart_path = resolve_path(&self.doc_path, &rel.target);
+        let chart_path = resolve_target(&self.doc_path, &rel.target);
         let chart_xml = self.package.read_part(&chart_path)?;
-        ext::parse_chart(&chart_xml).map_err(|e| Error::Invalid(e.to_string()))
+        Ok(ext::parse_chart(&chart_xml).map_err(|e| e.in_part(chart_path))?)
+    }
+
+    /// Reformat the XML parts of the package with the given options when
+    /// saving, e.g. [`SerializeOptions::pretty`] for parts that diff well.
+    ///
+    /// By default, edited parts are written compactly and all other parts
+    /// verbatim.
+    pub fn set_serialize_options(&mut self, options: SerializeOptions) -> &mut Self {
+        self.serialize_options = Some(options);
+        self
     }
 
     /// Save the document to a file.
@@ -363,12 +459,13 @@ impl<R: Read + Seek> Document<R> {
     /// then copies all package parts to the output, replacing only the modified
     /// parts.
     pub fn write<W: std::io::Write + Seek>(&mut self, writer: W) -> Result<()> {
-        // Serialize document XML
+        // Serialize document XML, with the alternate content put back
         let doc_xml = serialize_xml(&self.gen_doc, "w:document")?;
+        let doc_xml = self.doc_alternates.restore(&doc_xml)?;
 
         // Build replacements map
         let mut replacements = std::collections::HashMap::new();
-        replacements.insert(self.doc_path.as_str(), doc_xml.as_slice());
+        replacements.insert(self.doc_path.as_str(), &*doc_xml);
 
         // Serialize styles if we have a styles path
         let styles_xml;
@@ -379,6 +476,13 @@ impl<R: Read + Seek> Document<R> {
 
         // Create package writer and copy all parts with replacements
         let mut pkg_writer = PackageWriter::new(writer);
+        // Parts added or rewritten here follow the class of the source document
+        if self.package.conformance()? == Conformance::Strict {
+            pkg_writer.set_conformance(Conformance::Strict);
+        }
+        if let Some(options) = self.serialize_options {
+            pkg_writer.set_serialize_options(options);
+        }
         self.package
             .copy_to_writer(&mut pkg_writer, &replacements)?;
         pkg_writer.finish()?;
@@ -387,6 +491,22 @@ impl<R: Read + Seek> Document<R> {
     }
 }
 
+/// Markup compatibility processor for the namespaces this crate reads.
+fn markup_compatibility() -> MceProcessor {
+    MceProcessor::new(ext::UNDERSTOOD_NAMESPACES.iter().copied())
+}
+
+/// Parse a part with the markup compatibility rules applied, locating parse
+/// errors in the part as stored.
+fn parse_compat<T>(
+    xml: &[u8],
+    path: &str,
+    parse: fn(&[u8]) -> std::result::Result<T, ParseError>,
+) -> Result<T> {
+    let (compat_xml, offsets) = markup_compatibility().process_with_offsets(xml)?;
+    Ok(parse(&compat_xml).map_err(|e| e.in_original(xml, &offsets).in_part(path))?)
+}
+
 // =============================================================================
 // Serialization helper
 // =============================================================================
@@ -411,18 +531,28 @@ pub(crate) fn serialize_xml(value: &impl ToXml, tag: &str) -> Result<Vec<u8>> {
 // =============================================================================
 
 /// Resolve a relative path against a base path.
-pub(crate) fn resolve_path(base: &str, relative: &str) -> String {
-    // If the target is absolute (starts with /), use it directly (without the /)
-    if let Some(stripped) = relative.strip_prefix('/') {
-        return stripped.to_string();
-    }
-
-    // Otherwise, resolve relative to the base directory
-    if let Some(slash_pos) = base.rfind('/') {
-        format!("{}/{}", &base[..slash_pos], relative)
-    } else {
-        relative.to_string()
-    }
+/// Read an image part by the relationship ID of the document part.
+fn read_image<R: Read + Seek>(
+    package: &mut Package<R>,
+    rels: &Relationships,
+    doc_path: &str,
+    rel_id: &str,
+) -> Result<ImageData> {
+    // Look up the relationship
+    let rel = rels
+        .get(rel_id)
+        .ok_or_else(|| Error::MissingPart(format!("image relationship {}", rel_id)))?;
+
+    // Resolve the target path relative to the document
+    let image_path = resolve_target(doc_path, &rel.target);
+
+    // Read the image data from the package
+    let data = package.read_part(&image_path)?;
+
+    // Determine content type from extension
+    let content_type = content_type_from_path(&image_path);
+
+    Ok(ImageData { content_type, data })
 }
 
 /// Determine MIME content type from file extension.
@@ -599,7 +729,7 @@ const EL_COMPAT_SETTING: &[u8] = b"compatSetting";
 ///
 /// The structure is `<w:settings>` containing various setting elements.
 fn parse_settings(xml: &[u8]) -> Result<DocumentSettings> {
-    let mut reader = Reader::from_reader(xml);
+    let mut reader = NsReader::from_reader(xml);
     reader.config_mut().trim_text(false);
 
     let mut buf = Vec::new();
@@ -1130,25 +1260,6 @@ pub(crate) fn serialize_app_properties(props: &AppProperties) -> Result<Vec<u8>>
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_resolve_path() {
-        // Relative path resolution
-        assert_eq!(
-            resolve_path("word/document.xml", "media/image1.png"),
-            "word/media/image1.png"
-        );
-        assert_eq!(
-            resolve_path("word/document.xml", "../media/image1.png"),
-            "word/../media/image1.png"
-        );
-
-        // Absolute path
-        assert_eq!(
-            resolve_path("word/document.xml", "/word/media/image1.png"),
-            "word/media/image1.png"
-        );
-    }
-
     #[test]
     fn test_content_type_from_path() {
         assert_eq!(content_type_from_path("word/media/image1.png"), "image/png");
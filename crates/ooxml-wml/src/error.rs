@@ -27,6 +27,11 @@ pub enum Error {
         position: Option<u64>,
     },
 
+    /// Error from a generated parser, with the part, line and column and
+    /// element path it occurred at; see [`Error::location`].
+    #[error("parse error: {0}")]
+    Located(ooxml_xml::ParseError),
+
     /// Invalid or malformed document structure.
     #[error("invalid document: {0}")]
     Invalid(String),
@@ -70,11 +75,25 @@ impl From<crate::generated_parsers::ParseError> for Error {
             crate::generated_parsers::ParseError::UnexpectedElement(msg) => Error::Invalid(msg),
             crate::generated_parsers::ParseError::MissingAttribute(msg) => Error::Invalid(msg),
             crate::generated_parsers::ParseError::InvalidValue(msg) => Error::Invalid(msg),
+            crate::generated_parsers::ParseError::LimitExceeded(msg) => {
+                Error::Package(ooxml_opc::Error::LimitExceeded(msg))
+            }
+            located @ crate::generated_parsers::ParseError::Located { .. } => {
+                Error::Located(located)
+            }
         }
     }
 }
 
 impl Error {
+    /// Where in the package a parse error occurred, if known.
+    pub fn location(&self) -> Option<&ooxml_xml::SourceLocation> {
+        match self {
+            Self::Located(e) => e.location(),
+            _ => None,
+        }
+    }
+
     /// Create a parse error with context.
     pub fn parse(context: impl Into<String>, message: impl Into<String>) -> Self {
         Self::Parse {
@@ -191,28 +210,11 @@ impl ParseContext {
 
 /// Convert a byte position to line and column numbers.
 ///
-/// Returns (line, column) where both are 1-indexed.
+/// Returns (line, column) where both are 1-indexed. Columns count
+/// characters, as in [`ooxml_xml::SourcePosition`].
 pub fn position_to_line_col(content: &[u8], position: u64) -> (usize, usize) {
-    let position = position as usize;
-    let content = if position <= content.len() {
-        &content[..position]
-    } else {
-        content
-    };
-
-    let mut line = 1;
-    let mut col = 1;
-
-    for &byte in content {
-        if byte == b'\n' {
-            line += 1;
-            col = 1;
-        } else {
-            col += 1;
-        }
-    }
-
-    (line, col)
+    let position = ooxml_xml::SourcePosition::new(content, position);
+    (position.line, position.column)
 }
 
 #[cfg(test)]
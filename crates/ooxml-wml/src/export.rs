@@ -0,0 +1,212 @@
+//! Helpers shared by the document exporters ([`html`](crate::html) and
+//! [`markdown`](crate::markdown)).
+
+use crate::error::{Error, Result};
+use crate::ext::StyleContext;
+use crate::types;
+
+/// Treat a missing optional part as absent rather than as an error.
+pub(crate) fn optional_part<T>(result: Result<T>) -> Result<Option<T>> {
+    match result {
+        Ok(part) => Ok(Some(part)),
+        Err(Error::MissingPart(_)) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Heading level (1–6) of a paragraph, from its outline level or that of its
+/// style, or from a "heading N" style name.
+pub(crate) fn heading_level(styles: &StyleContext, para: &types::Paragraph) -> Option<usize> {
+    let p_pr = para.p_pr.as_deref();
+    let chain = p_pr
+        .and_then(|p| p.paragraph_style.as_deref())
+        .map(|style| styles.style_chain(&style.value))
+        .unwrap_or_default();
+    let outline_level = p_pr
+        .and_then(|p| p.outline_lvl.as_deref())
+        .or_else(|| {
+            chain
+                .iter()
+                .find_map(|style| style.p_pr.as_deref()?.outline_lvl.as_deref())
+        })
+        .map(|lvl| lvl.value);
+    if let Some(level) = outline_level {
+        // Level 9 is body text (§17.3.1.20)
+        return (0..9).contains(&level).then(|| (level as usize + 1).min(6));
+    }
+    let name = chain.first()?.name.as_deref()?.value.to_ascii_lowercase();
+    let level: usize = name.strip_prefix("heading ")?.trim().parse().ok()?;
+    (1..=9).contains(&level).then(|| level.min(6))
+}
+
+/// A paragraph or table, with content controls and custom XML unwrapped.
+pub(crate) enum Block<'b> {
+    Paragraph(&'b types::Paragraph),
+    Table(&'b types::Table),
+}
+
+/// Paragraphs and tables of block-level content, in document order.
+pub(crate) fn blocks(content: &[types::BlockContent]) -> Vec<Block<'_>> {
+    let mut result = Vec::new();
+    for block in content {
+        match block {
+            types::BlockContent::P(para) => result.push(Block::Paragraph(para)),
+            types::BlockContent::Tbl(table) => result.push(Block::Table(table)),
+            types::BlockContent::Sdt(sdt) => {
+                if let Some(content) = &sdt.sdt_content {
+                    block_choices(&content.block_content, &mut result);
+                }
+            }
+            types::BlockContent::CustomXml(custom) => {
+                block_choices(&custom.block_content, &mut result)
+            }
+            _ => {}
+        }
+    }
+    result
+}
+
+fn block_choices<'b>(content: &'b [types::BlockContentChoice], result: &mut Vec<Block<'b>>) {
+    for block in content {
+        match block {
+            types::BlockContentChoice::P(para) => result.push(Block::Paragraph(para)),
+            types::BlockContentChoice::Tbl(table) => result.push(Block::Table(table)),
+            types::BlockContentChoice::Sdt(sdt) => {
+                if let Some(content) = &sdt.sdt_content {
+                    block_choices(&content.block_content, result);
+                }
+            }
+            types::BlockContentChoice::CustomXml(custom) => {
+                block_choices(&custom.block_content, result)
+            }
+            _ => {}
+        }
+    }
+}
+
+/// A table cell placed on the table grid.
+pub(crate) struct GridCell<'t> {
+    pub col: usize,
+    pub span: usize,
+    pub merge: Option<types::STMerge>,
+    pub cell: &'t types::TableCell,
+}
+
+/// Lay the cells of a table out on its grid, row by row.
+pub(crate) fn table_grid(table: &types::Table) -> Vec<Vec<GridCell<'_>>> {
+    let mut grid = Vec::new();
+    for row in table_rows(&table.rows) {
+        let mut col = 0;
+        let mut cells = Vec::new();
+        for cell in row_cells(&row.cells) {
+            let props = cell.cell_properties.as_deref();
+            let span = props
+                .and_then(|p| p.grid_span.as_deref())
+                .map_or(1, |span| span.value.max(1) as usize);
+            let merge = props
+                .and_then(|p| p.vertical_merge.as_deref())
+                .map(|merge| merge.value.unwrap_or(types::STMerge::Continue));
+            cells.push(GridCell {
+                col,
+                span,
+                merge,
+                cell,
+            });
+            col += span;
+        }
+        grid.push(cells);
+    }
+    grid
+}
+
+/// Number of rows a cell at `row` spans through vertical merging.
+#[cfg_attr(not(feature = "html"), allow(dead_code))]
+pub(crate) fn row_span(grid: &[Vec<GridCell>], row: usize, cell: &GridCell) -> usize {
+    if cell.merge != Some(types::STMerge::Restart) {
+        return 1;
+    }
+    1 + grid[row + 1..]
+        .iter()
+        .take_while(|next| {
+            next.iter()
+                .any(|other| other.col == cell.col && other.merge == Some(types::STMerge::Continue))
+        })
+        .count()
+}
+
+/// Rows of a table, including those in content controls and custom XML.
+fn table_rows(rows: &[types::RowContent]) -> Vec<&types::CTRow> {
+    let mut result = Vec::new();
+    for row in rows {
+        match row {
+            types::RowContent::Tr(tr) => result.push(tr.as_ref()),
+            types::RowContent::Sdt(sdt) => {
+                if let Some(content) = &sdt.sdt_content {
+                    result.extend(table_rows(&content.rows));
+                }
+            }
+            types::RowContent::CustomXml(custom) => result.extend(table_rows(&custom.rows)),
+            _ => {}
+        }
+    }
+    result
+}
+
+/// Cells of a row, including those in content controls and custom XML.
+fn row_cells(cells: &[types::CellContent]) -> Vec<&types::TableCell> {
+    let mut result = Vec::new();
+    for cell in cells {
+        match cell {
+            types::CellContent::Tc(tc) => result.push(tc.as_ref()),
+            types::CellContent::Sdt(sdt) => {
+                if let Some(content) = &sdt.sdt_content {
+                    result.extend(row_cells(&content.cells));
+                }
+            }
+            types::CellContent::CustomXml(custom) => result.extend(row_cells(&custom.cells)),
+            _ => {}
+        }
+    }
+    result
+}
+
+/// Number of a note or comment in order of first reference, starting at 1.
+#[cfg_attr(not(feature = "html"), allow(dead_code))]
+pub(crate) fn reference_number(refs: &mut Vec<i64>, id: i64) -> usize {
+    match refs.iter().position(|&r| r == id) {
+        Some(i) => i + 1,
+        None => {
+            refs.push(id);
+            refs.len()
+        }
+    }
+}
+
+/// Queries for the description and size of drawings.
+pub(crate) static DRAWING_DESCRIPTION: ooxml_xml::LazyQuery =
+    ooxml_xml::LazyQuery::new("*/docPr/@descr");
+#[cfg(feature = "html")]
+pub(crate) static DRAWING_WIDTH: ooxml_xml::LazyQuery = ooxml_xml::LazyQuery::new("*/extent/@cx");
+#[cfg(feature = "html")]
+pub(crate) static DRAWING_HEIGHT: ooxml_xml::LazyQuery = ooxml_xml::LazyQuery::new("*/extent/@cy");
+
+/// First value selected by `query` from the raw children of a drawing.
+pub(crate) fn drawing_value<'d>(
+    drawing: &'d types::CTDrawing,
+    query: &ooxml_xml::Query,
+) -> Option<&'d str> {
+    query
+        .select_nodes(drawing.extra_children.iter().map(|child| &child.node))
+        .iter()
+        .find_map(ooxml_xml::QueryMatch::value)
+}
+
+/// Character a `w:sym` element stands for.
+pub(crate) fn symbol_char(sym: &types::CTSym) -> Option<char> {
+    let code = sym
+        .char
+        .iter()
+        .flatten()
+        .fold(0u32, |code, &b| code << 8 | u32::from(b));
+    char::from_u32(code)
+}
@@ -11,6 +11,12 @@
 //!   don't need external context
 //! - **Resolve traits** (`RunResolveExt`): Methods that need `StyleContext` for
 //!   style chain walking
+//! - **Numbering** (`NumberingContext`): List labels, which depend on the
+//!   numbering definitions and on the numbered paragraphs before them
+//! - **Effective formatting** (`StyleContext::resolve_paragraph_properties`,
+//!   `resolve_run_properties`, `resolve_cell_properties`): Formatting through
+//!   docDefaults, table styles and their conditional regions, numbering
+//!   levels, paragraph and character styles, and direct formatting
 //!
 //! # Example
 //!
@@ -25,11 +31,8 @@
 //! }
 //! ```
 
-use crate::parsers::{FromXml, ParseError};
+use crate::parsers::ParseError;
 use crate::types;
-use quick_xml::Reader;
-use quick_xml::events::Event;
-use std::io::Cursor;
 
 // =============================================================================
 // Helpers (private)
@@ -55,7 +58,7 @@ fn is_on(field: &Option<Box<types::OnOffElement>>) -> bool {
 
 /// Tri-state check for style resolution: `None` = not specified, `Some(true/false)` = explicit.
 #[cfg_attr(not(feature = "wml-styling"), allow(dead_code))]
-fn check_toggle(field: &Option<Box<types::OnOffElement>>) -> Option<bool> {
+pub(crate) fn check_toggle(field: &Option<Box<types::OnOffElement>>) -> Option<bool> {
     field.as_ref().map(|ct| match &ct.value {
         None => true,
         Some(v) => matches!(v.as_str(), "1" | "true" | "on"),
@@ -604,6 +607,8 @@ pub trait RunExt {
     /// Extract text from this run.
     ///
     /// Collects `T` (text), `Tab` (→ `\t`), `Cr`/`Br`(non-page) (→ `\n`).
+    /// With `wml-drawings` and `extra-children`, the text of text boxes in
+    /// drawings and VML pictures is included on lines of its own.
     fn text(&self) -> String;
 
     /// Get run properties.
@@ -656,15 +661,19 @@ impl RunExt for types::Run {
                 }
                 types::RunContent::Tab(_) => out.push('\t'),
                 types::RunContent::Cr(_) => out.push('\n'),
-                types::RunContent::Br(br) => {
-                    // Page/column breaks aren't text; only text-wrapping breaks produce newlines
+                // Page/column breaks aren't text; only text-wrapping breaks produce newlines
+                types::RunContent::Br(br)
                     if !matches!(
                         br.r#type,
                         Some(types::STBrType::Page) | Some(types::STBrType::Column)
-                    ) {
-                        out.push('\n');
-                    }
+                    ) =>
+                {
+                    out.push('\n');
                 }
+                #[cfg(all(feature = "wml-drawings", feature = "extra-children"))]
+                types::RunContent::Drawing(d) => push_text_boxes(&mut out, d.text_box_texts()),
+                #[cfg(all(feature = "wml-drawings", feature = "extra-children"))]
+                types::RunContent::Pict(p) => push_text_boxes(&mut out, p.text_box_texts()),
                 _ => {}
             }
         }
@@ -744,8 +753,8 @@ impl RunExt for types::Run {
 
 /// Extension methods for `CTDrawing` — extract image relationship IDs from raw XML.
 ///
-/// Since `CTDrawing` captures its children as raw XML, this trait walks the tree
-/// to find `<a:blip r:embed="..."/>` inside `<wp:inline>` and `<wp:anchor>` elements.
+/// Since `CTDrawing` captures its children as raw XML, this trait queries the tree
+/// for `<a:blip r:embed="..."/>` inside `<wp:inline>` and `<wp:anchor>` elements.
 #[cfg(all(feature = "wml-drawings", feature = "extra-children"))]
 pub trait DrawingExt {
     /// Get relationship IDs for inline images (`<wp:inline>` → `<a:blip r:embed="rId"/>`).
@@ -761,27 +770,11 @@ pub trait DrawingExt {
 #[cfg(all(feature = "wml-drawings", feature = "extra-children"))]
 impl DrawingExt for types::CTDrawing {
     fn inline_image_rel_ids(&self) -> Vec<&str> {
-        let mut ids = Vec::new();
-        for child in &self.extra_children {
-            if let ooxml_xml::RawXmlNode::Element(elem) = &child.node
-                && local_name_of(&elem.name) == "inline"
-            {
-                collect_blip_rel_ids(elem, &mut ids);
-            }
-        }
-        ids
+        drawing_values(self, &INLINE_IMAGES)
     }
 
     fn anchored_image_rel_ids(&self) -> Vec<&str> {
-        let mut ids = Vec::new();
-        for child in &self.extra_children {
-            if let ooxml_xml::RawXmlNode::Element(elem) = &child.node
-                && local_name_of(&elem.name) == "anchor"
-            {
-                collect_blip_rel_ids(elem, &mut ids);
-            }
-        }
-        ids
+        drawing_values(self, &ANCHORED_IMAGES)
     }
 
     fn all_image_rel_ids(&self) -> Vec<&str> {
@@ -798,24 +791,37 @@ fn local_name_of(name: &str) -> &str {
     name.rsplit(':').next().unwrap_or(name)
 }
 
-/// Recursively walk a raw XML element tree and collect `r:embed` attribute values
-/// from `<a:blip>` elements.
+/// Append text box texts to run text, each starting on a new line.
 #[cfg(all(feature = "wml-drawings", feature = "extra-children"))]
-fn collect_blip_rel_ids<'a>(elem: &'a ooxml_xml::RawXmlElement, ids: &mut Vec<&'a str>) {
-    if local_name_of(&elem.name) == "blip" {
-        for (attr_name, attr_val) in &elem.attributes {
-            if attr_name == "r:embed" || local_name_of(attr_name) == "embed" {
-                ids.push(attr_val.as_str());
-            }
-        }
-    }
-    for child in &elem.children {
-        if let ooxml_xml::RawXmlNode::Element(child_elem) = child {
-            collect_blip_rel_ids(child_elem, ids);
+fn push_text_boxes(out: &mut String, texts: Vec<String>) {
+    for text in texts {
+        if !out.is_empty() && !out.ends_with('\n') {
+            out.push('\n');
         }
+        out.push_str(&text);
     }
 }
 
+/// Queries for the relationship IDs of images and charts in drawings.
+#[cfg(all(feature = "wml-drawings", feature = "extra-children"))]
+static INLINE_IMAGES: ooxml_xml::LazyQuery = ooxml_xml::LazyQuery::new("inline//blip/@embed");
+#[cfg(all(feature = "wml-drawings", feature = "extra-children"))]
+static ANCHORED_IMAGES: ooxml_xml::LazyQuery = ooxml_xml::LazyQuery::new("anchor//blip/@embed");
+#[cfg(all(feature = "wml-drawings", feature = "extra-children"))]
+static INLINE_CHARTS: ooxml_xml::LazyQuery = ooxml_xml::LazyQuery::new("inline//chart/@id");
+#[cfg(all(feature = "wml-drawings", feature = "extra-children"))]
+static ANCHORED_CHARTS: ooxml_xml::LazyQuery = ooxml_xml::LazyQuery::new("anchor//chart/@id");
+
+/// Attribute values selected by `query` from the raw children of a drawing.
+#[cfg(all(feature = "wml-drawings", feature = "extra-children"))]
+fn drawing_values<'a>(drawing: &'a types::CTDrawing, query: &ooxml_xml::Query) -> Vec<&'a str> {
+    query
+        .select_nodes(drawing.extra_children.iter().map(|child| &child.node))
+        .iter()
+        .filter_map(ooxml_xml::QueryMatch::value)
+        .collect()
+}
+
 // =============================================================================
 // DrawingChartExt — extract chart relationship IDs from CTDrawing
 // =============================================================================
@@ -841,27 +847,11 @@ pub trait DrawingChartExt {
 #[cfg(all(feature = "wml-drawings", feature = "extra-children"))]
 impl DrawingChartExt for types::CTDrawing {
     fn inline_chart_rel_ids(&self) -> Vec<&str> {
-        let mut ids = Vec::new();
-        for child in &self.extra_children {
-            if let ooxml_xml::RawXmlNode::Element(elem) = &child.node
-                && local_name_of(&elem.name) == "inline"
-            {
-                collect_chart_rel_ids(elem, &mut ids);
-            }
-        }
-        ids
+        drawing_values(self, &INLINE_CHARTS)
     }
 
     fn anchored_chart_rel_ids(&self) -> Vec<&str> {
-        let mut ids = Vec::new();
-        for child in &self.extra_children {
-            if let ooxml_xml::RawXmlNode::Element(elem) = &child.node
-                && local_name_of(&elem.name) == "anchor"
-            {
-                collect_chart_rel_ids(elem, &mut ids);
-            }
-        }
-        ids
+        drawing_values(self, &ANCHORED_CHARTS)
     }
 
     fn all_chart_rel_ids(&self) -> Vec<&str> {
@@ -871,24 +861,6 @@ impl DrawingChartExt for types::CTDrawing {
     }
 }
 
-/// Recursively walk a raw XML element tree and collect `r:id` attribute values
-/// from `<c:chart>` elements.
-#[cfg(all(feature = "wml-drawings", feature = "extra-children"))]
-fn collect_chart_rel_ids<'a>(elem: &'a ooxml_xml::RawXmlElement, ids: &mut Vec<&'a str>) {
-    if local_name_of(&elem.name) == "chart" {
-        for (attr_name, attr_val) in &elem.attributes {
-            if attr_name == "r:id" || local_name_of(attr_name) == "id" {
-                ids.push(attr_val.as_str());
-            }
-        }
-    }
-    for child in &elem.children {
-        if let ooxml_xml::RawXmlNode::Element(child_elem) = child {
-            collect_chart_rel_ids(child_elem, ids);
-        }
-    }
-}
-
 // =============================================================================
 // TextBoxExt (DrawingML — modern text boxes)
 // =============================================================================
@@ -1092,7 +1064,10 @@ fn parse_math_zone_from_element(elem: &ooxml_xml::RawXmlElement) -> ooxml_omml::
 }
 
 #[cfg(feature = "extra-children")]
-fn collect_math_from_raw(elem: &ooxml_xml::RawXmlElement, out: &mut Vec<MathExpression>) {
+pub(crate) fn collect_math_from_raw(
+    elem: &ooxml_xml::RawXmlElement,
+    out: &mut Vec<MathExpression>,
+) {
     let local = math_local_name(&elem.name);
     match local {
         "oMathPara" => {
@@ -1691,6 +1666,8 @@ pub struct StyleContext {
     pub styles: std::collections::HashMap<String, types::Style>,
     /// Default run properties from `docDefaults`.
     pub default_run_properties: Option<types::RunProperties>,
+    /// Default paragraph properties from `docDefaults`.
+    pub default_paragraph_properties: Option<types::CTPPrGeneral>,
 }
 
 #[cfg(feature = "wml-styling")]
@@ -1710,10 +1687,17 @@ impl StyleContext {
             .and_then(|dd| dd.r_pr_default.as_ref())
             .and_then(|rpd| rpd.r_pr.as_ref())
             .map(|rp| rp.as_ref().clone());
+        let default_paragraph_properties = styles_doc
+            .doc_defaults
+            .as_ref()
+            .and_then(|dd| dd.p_pr_default.as_ref())
+            .and_then(|ppd| ppd.p_pr.as_ref())
+            .map(|pp| pp.as_ref().clone());
 
         Self {
             styles,
             default_run_properties,
+            default_paragraph_properties,
         }
     }
 
@@ -1722,30 +1706,69 @@ impl StyleContext {
         self.styles.get(id)
     }
 
-    /// Walk the `basedOn` chain for a style, collecting run properties.
-    /// Returns properties in order from most derived to least derived.
+    /// Walk the `basedOn` chain for a style, starting with the style itself.
+    /// Returns styles in order from most derived to least derived.
     /// Depth-limited to 20 to prevent infinite loops.
-    fn collect_style_chain_rpr(&self, style_id: &str) -> Vec<&types::RunProperties> {
+    pub fn style_chain(&self, style_id: &str) -> Vec<&types::Style> {
         let mut result = Vec::new();
-        let mut current_id = Some(style_id.to_string());
-        let mut depth = 0;
+        let mut current_id = Some(style_id);
 
-        while let Some(ref id) = current_id {
-            if depth >= 20 {
+        while let Some(id) = current_id {
+            if result.len() >= 20 {
                 break;
             }
-            if let Some(style) = self.styles.get(id) {
-                if let Some(ref rpr) = style.r_pr {
-                    result.push(rpr.as_ref());
-                }
-                current_id = style.based_on.as_ref().map(|b| b.value.clone());
-            } else {
+            let Some(style) = self.styles.get(id) else {
                 break;
-            }
-            depth += 1;
+            };
+            result.push(style);
+            current_id = style.based_on.as_ref().map(|b| b.value.as_str());
         }
         result
     }
+
+    /// Numbering instance and level of a paragraph, if it is numbered
+    /// directly or by its style. The instance and the level are each taken
+    /// from the paragraph if it sets them, otherwise from its style chain; the
+    /// level defaults to 0.
+    #[cfg(feature = "wml-numbering")]
+    pub fn paragraph_numbering(&self, para: &types::Paragraph) -> Option<(i64, i64)> {
+        let p_pr = para.p_pr.as_deref();
+        let styles = p_pr
+            .and_then(|p| p.paragraph_style.as_deref())
+            .map(|style| self.style_chain(&style.value))
+            .unwrap_or_default();
+        let num_prs: Vec<&types::NumberingProperties> = p_pr
+            .and_then(|p| p.num_pr.as_deref())
+            .into_iter()
+            .chain(
+                styles
+                    .into_iter()
+                    .filter_map(|style| style.p_pr.as_deref()?.num_pr.as_deref()),
+            )
+            .collect();
+        let num_id = num_prs
+            .iter()
+            .find_map(|num_pr| num_pr.num_id.as_deref())?
+            .value;
+        // numId 0 removes numbering (§17.9.18)
+        if num_id == 0 {
+            return None;
+        }
+        let ilvl = num_prs
+            .iter()
+            .find_map(|num_pr| num_pr.ilvl.as_deref())
+            .map_or(0, |ilvl| ilvl.value);
+        Some((num_id, ilvl))
+    }
+
+    /// Walk the `basedOn` chain for a style, collecting run properties.
+    /// Returns properties in order from most derived to least derived.
+    fn collect_style_chain_rpr(&self, style_id: &str) -> Vec<&types::RunProperties> {
+        self.style_chain(style_id)
+            .into_iter()
+            .filter_map(|style| style.r_pr.as_deref())
+            .collect()
+    }
 }
 
 /// Extension methods for `Run` that resolve formatting through the style chain.
@@ -1929,132 +1952,1381 @@ fn resolve_option<T>(
     None
 }
 
+// =============================================================================
+// Numbering Resolution
+// =============================================================================
+
+/// Context for computing list labels ("3.2.a)", "•") from numbering
+/// definitions (ECMA-376 §17.9).
+///
+/// A numbering instance (`w:num`) refers to an abstract definition
+/// (`w:abstractNum`), possibly through a numbering style (`numStyleLink`),
+/// and may override its levels (`lvlOverride`) or their start values
+/// (`startOverride`). Counters are kept per list: instances of the same
+/// abstract definition continue one sequence, while an instance with start
+/// overrides is a list of its own, as Word numbers them. Using a level
+/// restarts the levels below it, unless their `lvlRestart` says otherwise.
+///
+/// Labels depend on every numbered paragraph before them, so paragraphs must
+/// be passed in document order:
+///
+/// ```ignore
+/// let mut numbering = doc.numbering_context()?;
+/// let styles = StyleContext::from_styles(doc.styles());
+/// for para in doc.body().paragraphs() {
+///     match numbering.paragraph_label(para, &styles) {
+///         Some(label) => println!("{label} {}", para.text()),
+///         None => println!("{}", para.text()),
+///     }
+/// }
+/// ```
+#[cfg(feature = "wml-numbering")]
+#[derive(Debug, Clone, Default)]
+pub struct NumberingContext {
+    /// Numbering instances indexed by numId.
+    pub instances: std::collections::HashMap<i64, types::NumberingInstance>,
+    /// Abstract numbering definitions indexed by abstractNumId.
+    pub abstract_nums: std::collections::HashMap<i64, types::AbstractNumbering>,
+    /// Current value of each level per list, `None` until the level is used.
+    counters: std::collections::HashMap<ListKey, [Option<i64>; 9]>,
+}
+
+/// The sequence a numbering instance counts in.
+#[cfg(feature = "wml-numbering")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ListKey {
+    Abstract(i64),
+    Instance(i64),
+}
+
+#[cfg(feature = "wml-numbering")]
+impl NumberingContext {
+    /// Build a `NumberingContext` from a parsed `Numbering` part.
+    pub fn from_numbering(numbering: &types::Numbering) -> Self {
+        Self {
+            instances: numbering
+                .num
+                .iter()
+                .map(|num| (num.num_id, num.clone()))
+                .collect(),
+            abstract_nums: numbering
+                .abstract_num
+                .iter()
+                .map(|abs| (abs.abstract_num_id, abs.clone()))
+                .collect(),
+            counters: std::collections::HashMap::new(),
+        }
+    }
+
+    /// The abstract definition of a numbering instance, following
+    /// `numStyleLink` to the definition of the numbering style.
+    pub fn abstract_num(&self, num_id: i64) -> Option<&types::AbstractNumbering> {
+        let num = self.instances.get(&num_id)?;
+        let mut abs = self.abstract_nums.get(&num.abstract_num_id.value)?;
+        // Depth-limited, as a style may link back to itself
+        for _ in 0..10 {
+            let Some(link) = abs.num_style_link.as_deref() else {
+                break;
+            };
+            match self.abstract_nums.values().find(|other| {
+                other
+                    .style_link
+                    .as_deref()
+                    .is_some_and(|style| style.value == link.value)
+            }) {
+                Some(linked) => abs = linked,
+                None => break,
+            }
+        }
+        Some(abs)
+    }
+
+    /// The definition of a level of a numbering instance, from its
+    /// `lvlOverride` if it replaces the level.
+    pub fn level(&self, num_id: i64, ilvl: i64) -> Option<&types::Level> {
+        self.level_override(num_id, ilvl)
+            .and_then(|over| over.lvl.as_deref())
+            .or_else(|| {
+                self.abstract_num(num_id)?
+                    .lvl
+                    .iter()
+                    .find(|lvl| lvl.ilvl == ilvl)
+            })
+    }
+
+    /// The value a level of a numbering instance starts at: its
+    /// `startOverride`, or the level's `start` (0 if omitted, §17.9.25).
+    pub fn start(&self, num_id: i64, ilvl: i64) -> i64 {
+        self.level_override(num_id, ilvl)
+            .and_then(|over| over.start_override.as_deref())
+            .or_else(|| self.level(num_id, ilvl)?.start.as_deref())
+            .map_or(0, |start| start.value)
+    }
+
+    /// Count an item at a level of a numbering instance, returning its
+    /// number. Returns `None` for an unknown instance or a level outside
+    /// 0–8.
+    pub fn next(&mut self, num_id: i64, ilvl: i64) -> Option<i64> {
+        let key = self.list_key(num_id)?;
+        let index = usize::try_from(ilvl).ok().filter(|&i| i < 9)?;
+        let start = self.start(num_id, ilvl);
+        // A level restarts after a level above its lvlRestart is used;
+        // 0 means never, and by default any level above restarts it
+        let restarts: Vec<bool> = (0..9)
+            .map(|level| {
+                let restart = self
+                    .level(num_id, level)
+                    .and_then(|lvl| lvl.lvl_restart.as_deref())
+                    .map(|restart| restart.value);
+                level > ilvl && restart.is_none_or(|restart| ilvl < restart)
+            })
+            .collect();
+
+        let counters = self.counters.entry(key).or_default();
+        let value = counters[index].map_or(start, |value| value.saturating_add(1));
+        counters[index] = Some(value);
+        for (counter, restart) in counters.iter_mut().zip(restarts) {
+            if restart {
+                *counter = None;
+            }
+        }
+        Some(value)
+    }
+
+    /// The label of the current item at a level of a numbering instance: the
+    /// level's `lvlText` with each `%N` replaced by the current value of
+    /// level N in that level's format, or the bullet character.
+    ///
+    /// Levels not used yet show their start value. Does not advance the
+    /// counters; see [`next_label`](Self::next_label).
+    pub fn label(&self, num_id: i64, ilvl: i64) -> Option<String> {
+        let level = self.level(num_id, ilvl)?;
+        let format = level
+            .num_fmt
+            .as_deref()
+            .map_or(types::STNumberFormat::Decimal, |fmt| fmt.value);
+        let text = level
+            .lvl_text
+            .as_deref()
+            .and_then(|text| text.value.as_deref())
+            .unwrap_or_default();
+        if format == types::STNumberFormat::Bullet {
+            return Some(text.chars().map(bullet_char).collect());
+        }
+        // Legal numbering shows every level in decimal (§17.9.4)
+        let legal = is_on(&level.is_lgl);
+        let counters = self
+            .list_key(num_id)
+            .and_then(|key| self.counters.get(&key));
+
+        let mut label = String::new();
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            let Some(digit) = chars
+                .peek()
+                .and_then(|next| next.to_digit(10))
+                .filter(|&digit| c == '%' && (1..=9).contains(&digit))
+            else {
+                label.push(c);
+                continue;
+            };
+            chars.next();
+            let referenced = i64::from(digit) - 1;
+            let value = counters
+                .and_then(|counters| counters[referenced as usize])
+                .unwrap_or_else(|| self.start(num_id, referenced));
+            let format = match legal {
+                true => types::STNumberFormat::Decimal,
+                false => self
+                    .level(num_id, referenced)
+                    .and_then(|lvl| lvl.num_fmt.as_deref())
+                    .map_or(types::STNumberFormat::Decimal, |fmt| fmt.value),
+            };
+            label.push_str(&format_number(value, format));
+        }
+        Some(label)
+    }
+
+    /// Count an item at a level of a numbering instance and return its
+    /// label.
+    pub fn next_label(&mut self, num_id: i64, ilvl: i64) -> Option<String> {
+        self.next(num_id, ilvl)?;
+        self.label(num_id, ilvl)
+    }
+
+    /// Numbering instance and level of a paragraph, if it is numbered
+    /// directly or by its style.
+    ///
+    /// A paragraph numbered by its style without a level uses the level
+    /// linked to that style (`w:lvl/w:pStyle`).
+    #[cfg(feature = "wml-styling")]
+    pub fn paragraph_numbering(
+        &self,
+        para: &types::Paragraph,
+        styles: &StyleContext,
+    ) -> Option<(i64, i64)> {
+        let (num_id, ilvl) = styles.paragraph_numbering(para)?;
+        let p_pr = para.p_pr.as_deref();
+        if p_pr
+            .and_then(|p| p.num_pr.as_deref())
+            .is_some_and(|num_pr| num_pr.ilvl.is_some())
+        {
+            return Some((num_id, ilvl));
+        }
+        let linked = p_pr
+            .and_then(|p| p.paragraph_style.as_deref())
+            .and_then(|style| {
+                self.abstract_num(num_id)?.lvl.iter().find(|lvl| {
+                    lvl.paragraph_style
+                        .as_deref()
+                        .is_some_and(|linked| linked.value == style.value)
+                })
+            });
+        Some((num_id, linked.map_or(ilvl, |lvl| lvl.ilvl)))
+    }
+
+    /// Count a paragraph if it is numbered, directly or by its style, and
+    /// return its label. See [`paragraph_numbering`](Self::paragraph_numbering).
+    #[cfg(feature = "wml-styling")]
+    pub fn paragraph_label(
+        &mut self,
+        para: &types::Paragraph,
+        styles: &StyleContext,
+    ) -> Option<String> {
+        let (num_id, ilvl) = self.paragraph_numbering(para, styles)?;
+        self.next_label(num_id, ilvl)
+    }
+
+    /// Forget all counters, so every list starts again.
+    pub fn reset(&mut self) {
+        self.counters.clear();
+    }
+
+    fn level_override(&self, num_id: i64, ilvl: i64) -> Option<&types::CTNumLvl> {
+        self.instances
+            .get(&num_id)?
+            .lvl_override
+            .iter()
+            .find(|over| over.ilvl == ilvl)
+    }
+
+    fn list_key(&self, num_id: i64) -> Option<ListKey> {
+        let num = self.instances.get(&num_id)?;
+        if num
+            .lvl_override
+            .iter()
+            .any(|over| over.start_override.is_some())
+        {
+            return Some(ListKey::Instance(num_id));
+        }
+        let abs = self.abstract_num(num_id)?;
+        Some(ListKey::Abstract(abs.abstract_num_id))
+    }
+}
+
+/// Format a list number as a numbering level with `numFmt` would show it
+/// (ECMA-376 §17.18.59).
+///
+/// Decimal, roman, letter, ordinal, word, Chicago and enclosed formats are
+/// supported; other formats, and values outside a format's range (such as
+/// letters above 780, as in Word), fall back to decimal. `bullet` and `none`
+/// show no number.
+#[cfg(feature = "wml-numbering")]
+pub fn format_number(value: i64, format: types::STNumberFormat) -> String {
+    use types::STNumberFormat as Fmt;
+    match format {
+        Fmt::None | Fmt::Bullet => String::new(),
+        Fmt::DecimalZero if (0..10).contains(&value) => format!("0{value}"),
+        Fmt::UpperRoman | Fmt::LowerRoman if (1..4000).contains(&value) => {
+            let roman = roman_numeral(value);
+            match format {
+                Fmt::LowerRoman => roman.to_lowercase(),
+                _ => roman,
+            }
+        }
+        Fmt::UpperLetter | Fmt::LowerLetter if (1..=MAX_REPEATED_NUMBER).contains(&value) => {
+            // a–z, then aa–zz, aaa–zzz, ...
+            let base = match format {
+                Fmt::UpperLetter => b'A',
+                _ => b'a',
+            };
+            let letter = char::from(base + ((value - 1) % 26) as u8);
+            std::iter::repeat_n(letter, ((value - 1) / 26 + 1) as usize).collect()
+        }
+        Fmt::Ordinal if value >= 0 => {
+            let suffix = match (value % 10, value % 100) {
+                (_, 11..=13) => "th",
+                (1, _) => "st",
+                (2, _) => "nd",
+                (3, _) => "rd",
+                _ => "th",
+            };
+            format!("{value}{suffix}")
+        }
+        Fmt::CardinalText if (0..1_000_000).contains(&value) => capitalize(&number_words(value)),
+        Fmt::OrdinalText if (0..1_000_000).contains(&value) => {
+            capitalize(&ordinal_words(&number_words(value)))
+        }
+        Fmt::Hex if value >= 0 => format!("{value:X}"),
+        Fmt::Chicago if (1..=MAX_REPEATED_NUMBER).contains(&value) => {
+            // *, †, ‡, §, then doubled, tripled, ...
+            let symbol = ['*', '†', '‡', '§'][((value - 1) % 4) as usize];
+            std::iter::repeat_n(symbol, ((value - 1) / 4 + 1) as usize).collect()
+        }
+        Fmt::DecimalEnclosedCircle | Fmt::DecimalEnclosedCircleChinese
+            if (1..=20).contains(&value) =>
+        {
+            enclosed('\u{2460}', value)
+        }
+        Fmt::DecimalEnclosedParen if (1..=20).contains(&value) => enclosed('\u{2474}', value),
+        Fmt::DecimalEnclosedFullstop if (1..=20).contains(&value) => enclosed('\u{2488}', value),
+        Fmt::DecimalFullWidth | Fmt::DecimalFullWidth2 => value
+            .to_string()
+            .chars()
+            .map(|c| match c.to_digit(10) {
+                Some(digit) => char::from_u32(0xFF10 + digit).unwrap_or(c),
+                None => c,
+            })
+            .collect(),
+        _ => value.to_string(),
+    }
+}
+
+/// Largest number shown in the letter and Chicago formats, which repeat a
+/// symbol once more every cycle: Word stops at 780 (30 repeated letters).
+#[cfg(feature = "wml-numbering")]
+const MAX_REPEATED_NUMBER: i64 = 780;
+
+/// Unicode equivalent of a bullet character in a symbol font's private use
+/// area, as `lvlText` of bullet levels usually holds.
+#[cfg(feature = "wml-numbering")]
+fn bullet_char(c: char) -> char {
+    match c {
+        '\u{F0A7}' => '▪',
+        '\u{F0D8}' => '➢',
+        '\u{F0FC}' => '✓',
+        '\u{F076}' => '❖',
+        '\u{F000}'..='\u{F0FF}' => '•',
+        _ => c,
+    }
+}
+
+#[cfg(feature = "wml-numbering")]
+fn roman_numeral(mut value: i64) -> String {
+    const NUMERALS: [(i64, &str); 13] = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+    let mut out = String::new();
+    for (amount, numeral) in NUMERALS {
+        while value >= amount {
+            out.push_str(numeral);
+            value -= amount;
+        }
+    }
+    out
+}
+
+/// A number in English words, such as "twenty-one" (below one million).
+#[cfg(feature = "wml-numbering")]
+fn number_words(value: i64) -> String {
+    const ONES: [&str; 20] = [
+        "zero",
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ];
+    const TENS: [&str; 10] = [
+        "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+    ];
+    match value {
+        0..20 => ONES[value as usize].to_string(),
+        20..100 => match value % 10 {
+            0 => TENS[(value / 10) as usize].to_string(),
+            ones => format!("{}-{}", TENS[(value / 10) as usize], ONES[ones as usize]),
+        },
+        100..1000 => match value % 100 {
+            0 => format!("{} hundred", ONES[(value / 100) as usize]),
+            rest => format!(
+                "{} hundred {}",
+                ONES[(value / 100) as usize],
+                number_words(rest)
+            ),
+        },
+        _ => match value % 1000 {
+            0 => format!("{} thousand", number_words(value / 1000)),
+            rest => format!(
+                "{} thousand {}",
+                number_words(value / 1000),
+                number_words(rest)
+            ),
+        },
+    }
+}
+
+/// The ordinal form of a number in words: "twenty-one" to "twenty-first".
+#[cfg(feature = "wml-numbering")]
+fn ordinal_words(words: &str) -> String {
+    let split = words.rfind([' ', '-']).map_or(0, |i| i + 1);
+    let (head, last) = words.split_at(split);
+    let last = match last {
+        "one" => "first".to_string(),
+        "two" => "second".to_string(),
+        "three" => "third".to_string(),
+        "five" => "fifth".to_string(),
+        "eight" => "eighth".to_string(),
+        "nine" => "ninth".to_string(),
+        "twelve" => "twelfth".to_string(),
+        _ => match last.strip_suffix('y') {
+            Some(stem) => format!("{stem}ieth"),
+            None => format!("{last}th"),
+        },
+    };
+    format!("{head}{last}")
+}
+
+#[cfg(feature = "wml-numbering")]
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// A number from 1 to 20 in a run of enclosed Unicode digits.
+#[cfg(feature = "wml-numbering")]
+fn enclosed(first: char, value: i64) -> String {
+    char::from_u32(first as u32 + value as u32 - 1).map_or_else(|| value.to_string(), String::from)
+}
+
+// =============================================================================
+// Effective Formatting
+// =============================================================================
+
+/// Where a cell sits in its table, for the conditional formatting of table
+/// styles (`tblStylePr`).
+///
+/// Rows count the rows of the table and columns the cells of the cell's row,
+/// as Word applies first/last column formatting to the first and last cell
+/// of each row.
+#[cfg(all(
+    feature = "wml-styling",
+    feature = "wml-tables",
+    feature = "wml-numbering"
+))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CellPosition {
+    /// Index of the row in the table.
+    pub row: usize,
+    /// Index of the cell in its row.
+    pub column: usize,
+    /// Number of rows in the table.
+    pub rows: usize,
+    /// Number of cells in the row.
+    pub columns: usize,
+}
+
+#[cfg(all(
+    feature = "wml-styling",
+    feature = "wml-tables",
+    feature = "wml-numbering"
+))]
+impl CellPosition {
+    /// The position of the cell at `row` and `column` of a table, if there is
+    /// one.
+    pub fn of(table: &types::Table, row: usize, column: usize) -> Option<Self> {
+        let rows = table.rows();
+        let columns = rows.get(row)?.cells().len();
+        (column < columns).then_some(Self {
+            row,
+            column,
+            rows: rows.len(),
+            columns,
+        })
+    }
+
+    fn cell<'t>(&self, table: &'t types::Table) -> Option<&'t types::TableCell> {
+        table
+            .rows()
+            .get(self.row)?
+            .cells()
+            .get(self.column)
+            .copied()
+    }
+}
+
+/// Surroundings of a paragraph that contribute to its formatting.
+#[cfg(all(
+    feature = "wml-styling",
+    feature = "wml-tables",
+    feature = "wml-numbering"
+))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ParagraphScope<'a> {
+    /// Numbering definitions, for the paragraph properties of the level a
+    /// numbered paragraph is on.
+    pub numbering: Option<&'a NumberingContext>,
+    /// The table the paragraph is in and the position of its cell, for the
+    /// table style.
+    pub cell: Option<(&'a types::Table, CellPosition)>,
+}
+
+/// Effective formatting, through every level of the style hierarchy.
+///
+/// Properties are combined in the order of ECMA-376 §17.7.2, each level
+/// overriding the ones before it:
+///
+/// 1. Document defaults (`docDefaults`)
+/// 2. The table style: the whole table, then the conditional regions the
+///    cell is in (`tblStylePr`) as enabled by `tblLook`, in the order of
+///    §17.7.6
+/// 3. The paragraph properties of the numbering level (`lvl/pPr`)
+/// 4. The paragraph style, or the default paragraph style
+/// 5. For runs, the character style
+/// 6. Direct formatting
+///
+/// Each style contributes its `basedOn` chain, most derived last. Spacing,
+/// indentation, borders, fonts and languages are combined attribute by
+/// attribute, and tab stops accumulate. Toggle properties (bold, italic,
+/// caps, ...) set by more than one style type cancel out (§17.7.3); direct
+/// formatting sets them as given.
+///
+/// Resolved toggle properties are present when on and absent when off.
+#[cfg(all(
+    feature = "wml-styling",
+    feature = "wml-tables",
+    feature = "wml-numbering"
+))]
+impl StyleContext {
+    /// The default style of a type (`w:default="1"`), used where no style
+    /// is referenced.
+    pub fn default_style(&self, style_type: types::STStyleType) -> Option<&types::Style> {
+        self.styles.values().find(|style| {
+            style.r#type == Some(style_type)
+                && style
+                    .default
+                    .as_deref()
+                    .is_some_and(|default| matches!(default, "1" | "true" | "on"))
+        })
+    }
+
+    /// Effective properties of a paragraph.
+    pub fn resolve_paragraph_properties(
+        &self,
+        para: &types::Paragraph,
+        scope: &ParagraphScope,
+    ) -> types::CTPPrGeneral {
+        let mut p_pr = self
+            .default_paragraph_properties
+            .clone()
+            .unwrap_or_default();
+        if let Some((table, position)) = scope.cell {
+            for layer in self.table_style_layers(table, position) {
+                if let Some(source) = layer.p_pr {
+                    merge_paragraph_properties(&mut p_pr, source);
+                }
+            }
+        }
+        if let Some(numbering) = scope.numbering
+            && let Some((num_id, ilvl)) = numbering.paragraph_numbering(para, self)
+            && let Some(source) = numbering
+                .level(num_id, ilvl)
+                .and_then(|lvl| lvl.p_pr.as_deref())
+        {
+            merge_paragraph_properties(&mut p_pr, source);
+        }
+        let style = self.paragraph_style(para);
+        for style in style
+            .map(|style| self.chain_base_first(style))
+            .unwrap_or_default()
+        {
+            if let Some(source) = style.p_pr.as_deref() {
+                merge_paragraph_properties(&mut p_pr, source);
+            }
+        }
+        if let Some(direct) = para.p_pr.as_deref() {
+            merge_paragraph_properties(&mut p_pr, &general_paragraph_properties(direct));
+        }
+        p_pr.paragraph_style = style.and_then(|style| style.style_id.clone()).map(|id| {
+            Box::new(types::CTString {
+                value: id,
+                #[cfg(feature = "extra-attrs")]
+                extra_attrs: Default::default(),
+            })
+        });
+        p_pr
+    }
+
+    /// Effective properties of a run in a paragraph, including the run
+    /// properties of the paragraph's style.
+    pub fn resolve_run_properties(
+        &self,
+        para: &types::Paragraph,
+        run: &types::Run,
+        scope: &ParagraphScope,
+    ) -> types::RunProperties {
+        // Each style type merged over its own chain
+        let mut styles = Vec::new();
+        if let Some((table, position)) = scope.cell {
+            let mut table_r_pr = types::RunProperties::default();
+            for layer in self.table_style_layers(table, position) {
+                if let Some(source) = layer.r_pr {
+                    merge_run_properties(&mut table_r_pr, source);
+                }
+            }
+            styles.push(table_r_pr);
+        }
+        if let Some(style) = self.paragraph_style(para) {
+            styles.push(self.chain_run_properties(style));
+        }
+        let direct = run.r_pr.as_deref();
+        let character = match direct.and_then(|r| r.run_style.as_deref()) {
+            Some(id) => self.style(&id.value),
+            None => self.default_style(types::STStyleType::Character),
+        };
+        if let Some(style) = character {
+            styles.push(self.chain_run_properties(style));
+        }
+
+        let mut r_pr = self.default_run_properties.clone().unwrap_or_default();
+        let defaults = r_pr.clone();
+        for style in &styles {
+            merge_run_properties(&mut r_pr, style);
+        }
+        if let Some(direct) = direct {
+            merge_run_properties(&mut r_pr, direct);
+        }
+        macro_rules! toggles {
+            ($($field:ident),* $(,)?) => {$(
+                let from_styles = styles.iter().fold(None, |value, style| {
+                    match check_toggle(&style.$field) {
+                        Some(on) => Some(value.unwrap_or(false) ^ on),
+                        None => value,
+                    }
+                });
+                let on = direct
+                    .and_then(|direct| check_toggle(&direct.$field))
+                    .or(from_styles)
+                    .or_else(|| check_toggle(&defaults.$field))
+                    .unwrap_or(false);
+                r_pr.$field = on.then(|| Box::new(types::OnOffElement {
+                    value: None,
+                    #[cfg(feature = "extra-attrs")]
+                    extra_attrs: Default::default(),
+                }));
+            )*};
+        }
+        toggles!(
+            bold,
+            b_cs,
+            italic,
+            i_cs,
+            caps,
+            small_caps,
+            strikethrough,
+            outline,
+            shadow,
+            emboss,
+            imprint,
+            vanish,
+        );
+        r_pr
+    }
+
+    /// Effective table-level properties of a table: its style's, then its
+    /// own.
+    pub fn resolve_table_properties(&self, table: &types::Table) -> types::CTTblPrBase {
+        let mut tbl_pr = types::CTTblPrBase::default();
+        for style in self
+            .table_style(table)
+            .map(|style| self.chain_base_first(style))
+            .unwrap_or_default()
+        {
+            if let Some(source) = style.table_properties.as_deref() {
+                merge_table_properties(&mut tbl_pr, source);
+            }
+        }
+        let direct = &table.table_properties;
+        let direct = types::CTTblPrBase {
+            tbl_style: direct.tbl_style.clone(),
+            tblp_pr: direct.tblp_pr.clone(),
+            tbl_overlap: direct.tbl_overlap.clone(),
+            bidi_visual: direct.bidi_visual.clone(),
+            tbl_style_row_band_size: direct.tbl_style_row_band_size.clone(),
+            tbl_style_col_band_size: direct.tbl_style_col_band_size.clone(),
+            tbl_w: direct.tbl_w.clone(),
+            justification: direct.justification.clone(),
+            tbl_cell_spacing: direct.tbl_cell_spacing.clone(),
+            tbl_ind: direct.tbl_ind.clone(),
+            tbl_borders: direct.tbl_borders.clone(),
+            shading: direct.shading.clone(),
+            tbl_layout: direct.tbl_layout.clone(),
+            tbl_cell_mar: direct.tbl_cell_mar.clone(),
+            tbl_look: direct.tbl_look.clone(),
+            tbl_caption: direct.tbl_caption.clone(),
+            tbl_description: direct.tbl_description.clone(),
+            #[cfg(feature = "extra-children")]
+            extra_children: Vec::new(),
+        };
+        merge_table_properties(&mut tbl_pr, &direct);
+        tbl_pr
+    }
+
+    /// Effective properties of a table cell.
+    ///
+    /// Borders start from the table borders for the cell's edges (outer
+    /// borders on the table's edges, inside borders elsewhere), then take the
+    /// table style's cell borders and the cell's own. Margins start from the
+    /// table's cell margins.
+    pub fn resolve_cell_properties(
+        &self,
+        table: &types::Table,
+        position: CellPosition,
+    ) -> types::TableCellProperties {
+        let tbl_pr = self.resolve_table_properties(table);
+        let mut tc_pr = types::TableCellProperties::default();
+        if let Some(borders) = tbl_pr.tbl_borders.as_deref() {
+            let edge = |outer: bool, border: &Option<Box<types::CTBorder>>, inside| match outer {
+                true => border.clone(),
+                false => inside,
+            };
+            let first_row = position.row == 0;
+            let last_row = position.row + 1 >= position.rows;
+            let first_column = position.column == 0;
+            let last_column = position.column + 1 >= position.columns;
+            let left = borders.start.clone().or_else(|| borders.left.clone());
+            let right = borders.end.clone().or_else(|| borders.right.clone());
+            let left = edge(first_column, &left, borders.inside_v.clone());
+            let right = edge(last_column, &right, borders.inside_v.clone());
+            tc_pr.tc_borders = Some(Box::new(types::CTTcBorders {
+                top: edge(first_row, &borders.top, borders.inside_h.clone()),
+                bottom: edge(last_row, &borders.bottom, borders.inside_h.clone()),
+                start: left.clone(),
+                left,
+                end: right.clone(),
+                right,
+                ..Default::default()
+            }));
+        }
+        if let Some(margins) = tbl_pr.tbl_cell_mar.as_deref() {
+            tc_pr.tc_mar = Some(Box::new(types::CTTcMar {
+                top: margins.top.clone(),
+                start: margins.start.clone(),
+                left: margins.left.clone(),
+                bottom: margins.bottom.clone(),
+                end: margins.end.clone(),
+                right: margins.right.clone(),
+                #[cfg(feature = "extra-children")]
+                extra_children: Vec::new(),
+            }));
+        }
+        for layer in self.table_style_layers(table, position) {
+            if let Some(source) = layer.tc_pr {
+                merge_cell_properties(&mut tc_pr, source);
+            }
+        }
+        if let Some(direct) = position
+            .cell(table)
+            .and_then(|cell| cell.cell_properties.as_deref())
+        {
+            merge_cell_properties(&mut tc_pr, direct);
+        }
+        tc_pr
+    }
+
+    /// The conditional regions of a table's style that apply to a cell, in
+    /// the order they are applied (§17.7.6).
+    ///
+    /// `tblLook` enables the first/last row and column regions and disables
+    /// banding; a table without one uses banding only. Header and footer
+    /// rows and columns are not counted in bands, whose size comes from
+    /// `tblStyleRowBandSize` and `tblStyleColBandSize`.
+    pub fn table_style_regions(
+        &self,
+        table: &types::Table,
+        position: CellPosition,
+    ) -> Vec<types::STTblStyleOverrideType> {
+        use types::STTblStyleOverrideType as Region;
+        let tbl_pr = self.resolve_table_properties(table);
+        let look = tbl_pr.tbl_look.as_deref().map_or(0, table_look);
+        let flag = |mask: u16| look & mask != 0;
+        let (first_row, last_row) = (flag(0x0020), flag(0x0040));
+        let (first_column, last_column) = (flag(0x0080), flag(0x0100));
+        let is_first_row = first_row && position.row == 0;
+        let is_last_row = last_row && position.row + 1 == position.rows;
+        let is_first_column = first_column && position.column == 0;
+        let is_last_column = last_column && position.column + 1 == position.columns;
+        let band_size = |size: Option<&types::CTDecimalNumber>| {
+            size.map_or(1, |size| size.value).max(1) as usize
+        };
+
+        let mut regions = vec![Region::WholeTable];
+        if !flag(0x0400) && !is_first_column && !is_last_column {
+            let size = band_size(tbl_pr.tbl_style_col_band_size.as_deref());
+            let index = position.column - usize::from(first_column);
+            regions.push(match (index / size) % 2 {
+                0 => Region::Band1Vert,
+                _ => Region::Band2Vert,
+            });
+        }
+        if !flag(0x0200) && !is_first_row && !is_last_row {
+            let size = band_size(tbl_pr.tbl_style_row_band_size.as_deref());
+            let index = position.row - usize::from(first_row);
+            regions.push(match (index / size) % 2 {
+                0 => Region::Band1Horz,
+                _ => Region::Band2Horz,
+            });
+        }
+        for (applies, region) in [
+            (is_first_row, Region::FirstRow),
+            (is_last_row, Region::LastRow),
+            (is_first_column, Region::FirstCol),
+            (is_last_column, Region::LastCol),
+            (is_first_row && is_first_column, Region::NwCell),
+            (is_first_row && is_last_column, Region::NeCell),
+            (is_last_row && is_first_column, Region::SwCell),
+            (is_last_row && is_last_column, Region::SeCell),
+        ] {
+            if applies {
+                regions.push(region);
+            }
+        }
+        regions
+    }
+
+    /// The style of a paragraph, or the default paragraph style.
+    fn paragraph_style(&self, para: &types::Paragraph) -> Option<&types::Style> {
+        match para
+            .p_pr
+            .as_deref()
+            .and_then(|p| p.paragraph_style.as_deref())
+        {
+            Some(id) => self.style(&id.value),
+            None => self.default_style(types::STStyleType::Paragraph),
+        }
+    }
+
+    /// The style of a table, or the default table style.
+    fn table_style(&self, table: &types::Table) -> Option<&types::Style> {
+        match table.table_properties.tbl_style.as_deref() {
+            Some(id) => self.style(&id.value),
+            None => self.default_style(types::STStyleType::Table),
+        }
+    }
+
+    /// The `basedOn` chain of a style, least derived first.
+    fn chain_base_first<'s>(&'s self, style: &'s types::Style) -> Vec<&'s types::Style> {
+        let mut chain = match style.style_id.as_deref() {
+            Some(id) => self.style_chain(id),
+            None => vec![style],
+        };
+        chain.reverse();
+        chain
+    }
+
+    /// Run properties of a style merged over its `basedOn` chain.
+    fn chain_run_properties(&self, style: &types::Style) -> types::RunProperties {
+        let mut r_pr = types::RunProperties::default();
+        for style in self.chain_base_first(style) {
+            if let Some(source) = style.r_pr.as_deref() {
+                merge_run_properties(&mut r_pr, source);
+            }
+        }
+        r_pr
+    }
+
+    /// Properties a table's style contributes to a cell, in order: each
+    /// region that applies, over the style's chain.
+    fn table_style_layers<'s>(
+        &'s self,
+        table: &types::Table,
+        position: CellPosition,
+    ) -> Vec<TableStyleLayer<'s>> {
+        let Some(style) = self.table_style(table) else {
+            return Vec::new();
+        };
+        let chain = self.chain_base_first(style);
+        let mut layers: Vec<_> = chain
+            .iter()
+            .map(|style| TableStyleLayer {
+                p_pr: style.p_pr.as_deref(),
+                r_pr: style.r_pr.as_deref(),
+                tc_pr: style.cell_properties.as_deref(),
+            })
+            .collect();
+        for region in self.table_style_regions(table, position) {
+            for style in &chain {
+                for conditional in style.tbl_style_pr.iter().filter(|pr| pr.r#type == region) {
+                    layers.push(TableStyleLayer {
+                        p_pr: conditional.p_pr.as_deref(),
+                        r_pr: conditional.r_pr.as_deref(),
+                        tc_pr: conditional.cell_properties.as_deref(),
+                    });
+                }
+            }
+        }
+        layers
+    }
+}
+
+/// Properties of a table style or one of its conditional regions.
+#[cfg(all(
+    feature = "wml-styling",
+    feature = "wml-tables",
+    feature = "wml-numbering"
+))]
+struct TableStyleLayer<'s> {
+    p_pr: Option<&'s types::CTPPrGeneral>,
+    r_pr: Option<&'s types::RunProperties>,
+    tc_pr: Option<&'s types::TableCellProperties>,
+}
+
+/// `tblLook` flags as the bits of its legacy `val` (§17.4.56).
+#[cfg(all(
+    feature = "wml-styling",
+    feature = "wml-tables",
+    feature = "wml-numbering"
+))]
+fn table_look(look: &types::CTTblLook) -> u16 {
+    let mut bits = match look.value.as_deref() {
+        Some([high, low]) => u16::from_be_bytes([*high, *low]),
+        Some([low]) => u16::from(*low),
+        _ => 0,
+    };
+    for (flag, mask) in [
+        (&look.first_row, 0x0020),
+        (&look.last_row, 0x0040),
+        (&look.first_column, 0x0080),
+        (&look.last_column, 0x0100),
+        (&look.no_h_band, 0x0200),
+        (&look.no_v_band, 0x0400),
+    ] {
+        match flag.as_deref() {
+            Some("1" | "true" | "on") => bits |= mask,
+            Some(_) => bits &= !mask,
+            None => {}
+        }
+    }
+    bits
+}
+
+/// Copy the fields set in `$source` over `$target`.
+#[cfg(all(
+    feature = "wml-styling",
+    feature = "wml-tables",
+    feature = "wml-numbering"
+))]
+macro_rules! overlay {
+    ($target:expr, $source:expr; $($field:ident),* $(,)?) => {
+        $(if let Some(value) = &$source.$field {
+            $target.$field = Some(value.clone());
+        })*
+    };
+}
+
+/// Merge boxed properties attribute by attribute, starting from the
+/// default when the target has none.
+#[cfg(all(
+    feature = "wml-styling",
+    feature = "wml-tables",
+    feature = "wml-numbering"
+))]
+macro_rules! overlay_fields {
+    ($target:expr, $source:expr; $($field:ident),* $(,)?) => {
+        if let Some(source) = $source.as_deref() {
+            let target = $target.get_or_insert_with(|| Box::new(source.clone()));
+            overlay!(target, source; $($field),*);
+        }
+    };
+}
+
+#[cfg(all(
+    feature = "wml-styling",
+    feature = "wml-tables",
+    feature = "wml-numbering"
+))]
+fn merge_paragraph_properties(target: &mut types::CTPPrGeneral, source: &types::CTPPrGeneral) {
+    overlay!(target, source;
+        paragraph_style,
+        keep_next,
+        keep_lines,
+        page_break_before,
+        frame_pr,
+        widow_control,
+        num_pr,
+        suppress_line_numbers,
+        shading,
+        suppress_auto_hyphens,
+        kinsoku,
+        word_wrap,
+        overflow_punct,
+        top_line_punct,
+        auto_space_d_e,
+        auto_space_d_n,
+        bidi,
+        adjust_right_ind,
+        snap_to_grid,
+        contextual_spacing,
+        mirror_indents,
+        suppress_overlap,
+        justification,
+        text_direction,
+        text_alignment,
+        textbox_tight_wrap,
+        outline_lvl,
+        div_id,
+    );
+    overlay_fields!(target.spacing, source.spacing;
+        before,
+        before_lines,
+        before_autospacing,
+        after,
+        after_lines,
+        after_autospacing,
+        line,
+        line_rule,
+    );
+    if let Some(ind) = source.indentation.as_deref() {
+        let target = target.indentation.get_or_insert_default();
+        // start/left and end/right are the same edge
+        let start = ind.start.clone().or_else(|| ind.left.clone());
+        let end = ind.end.clone().or_else(|| ind.right.clone());
+        if start.is_some() {
+            target.start = start.clone();
+            target.left = start;
+        }
+        if end.is_some() {
+            target.end = end.clone();
+            target.right = end;
+        }
+        // A first line indent replaces a hanging one and vice versa
+        if ind.first_line.is_some() || ind.first_line_chars.is_some() {
+            target.hanging = None;
+            target.hanging_chars = None;
+        }
+        if ind.hanging.is_some() || ind.hanging_chars.is_some() {
+            target.first_line = None;
+            target.first_line_chars = None;
+        }
+        overlay!(target, ind;
+            start_chars,
+            end_chars,
+            left_chars,
+            right_chars,
+            hanging,
+            hanging_chars,
+            first_line,
+            first_line_chars,
+        );
+    }
+    overlay_fields!(target.paragraph_border, source.paragraph_border;
+        top,
+        left,
+        bottom,
+        right,
+        between,
+        bar,
+    );
+    if let Some(tabs) = source.tabs.as_deref() {
+        let target = &mut target.tabs.get_or_insert_default().tab;
+        for tab in &tabs.tab {
+            target.retain(|existing| existing.pos != tab.pos);
+            if tab.value != types::STTabJc::Clear {
+                target.push(tab.clone());
+            }
+        }
+    }
+}
+
+#[cfg(all(
+    feature = "wml-styling",
+    feature = "wml-tables",
+    feature = "wml-numbering"
+))]
+fn merge_run_properties(target: &mut types::RunProperties, source: &types::RunProperties) {
+    overlay!(target, source;
+        run_style,
+        bold,
+        b_cs,
+        italic,
+        i_cs,
+        caps,
+        small_caps,
+        strikethrough,
+        dstrike,
+        outline,
+        shadow,
+        emboss,
+        imprint,
+        no_proof,
+        snap_to_grid,
+        vanish,
+        web_hidden,
+        color,
+        spacing,
+        width,
+        kern,
+        position,
+        size,
+        size_complex_script,
+        highlight,
+        underline,
+        effect,
+        bdr,
+        shading,
+        fit_text,
+        vert_align,
+        rtl,
+        cs,
+        em,
+        east_asian_layout,
+        spec_vanish,
+        o_math,
+    );
+    overlay_fields!(target.fonts, source.fonts;
+        hint,
+        ascii,
+        h_ansi,
+        east_asia,
+        cs,
+        ascii_theme,
+        h_ansi_theme,
+        east_asia_theme,
+        cstheme,
+    );
+    overlay_fields!(target.lang, source.lang; value, east_asia, bidi);
+}
+
+#[cfg(all(
+    feature = "wml-styling",
+    feature = "wml-tables",
+    feature = "wml-numbering"
+))]
+fn merge_table_properties(target: &mut types::CTTblPrBase, source: &types::CTTblPrBase) {
+    overlay!(target, source;
+        tbl_style,
+        tblp_pr,
+        tbl_overlap,
+        bidi_visual,
+        tbl_style_row_band_size,
+        tbl_style_col_band_size,
+        tbl_w,
+        justification,
+        tbl_cell_spacing,
+        tbl_ind,
+        shading,
+        tbl_layout,
+        tbl_look,
+        tbl_caption,
+        tbl_description,
+    );
+    overlay_fields!(target.tbl_borders, source.tbl_borders;
+        top,
+        start,
+        left,
+        bottom,
+        end,
+        right,
+        inside_h,
+        inside_v,
+    );
+    overlay_fields!(target.tbl_cell_mar, source.tbl_cell_mar;
+        top,
+        start,
+        left,
+        bottom,
+        end,
+        right,
+    );
+}
+
+#[cfg(all(
+    feature = "wml-styling",
+    feature = "wml-tables",
+    feature = "wml-numbering"
+))]
+fn merge_cell_properties(
+    target: &mut types::TableCellProperties,
+    source: &types::TableCellProperties,
+) {
+    overlay!(target, source;
+        cnf_style,
+        tc_w,
+        grid_span,
+        horizontal_merge,
+        vertical_merge,
+        shading,
+        no_wrap,
+        text_direction,
+        tc_fit_text,
+        v_align,
+        hide_mark,
+        headers,
+    );
+    overlay_fields!(target.tc_borders, source.tc_borders;
+        top,
+        start,
+        left,
+        bottom,
+        end,
+        right,
+        inside_h,
+        inside_v,
+        tl2br,
+        tr2bl,
+    );
+    overlay_fields!(target.tc_mar, source.tc_mar;
+        top,
+        start,
+        left,
+        bottom,
+        end,
+        right,
+    );
+}
+
+/// Direct paragraph properties in the shape of style paragraph properties.
+#[cfg(all(
+    feature = "wml-styling",
+    feature = "wml-tables",
+    feature = "wml-numbering"
+))]
+fn general_paragraph_properties(direct: &types::ParagraphProperties) -> types::CTPPrGeneral {
+    types::CTPPrGeneral {
+        paragraph_style: direct.paragraph_style.clone(),
+        #[cfg(feature = "wml-layout")]
+        keep_next: direct.keep_next.clone(),
+        #[cfg(feature = "wml-layout")]
+        keep_lines: direct.keep_lines.clone(),
+        #[cfg(feature = "wml-layout")]
+        page_break_before: direct.page_break_before.clone(),
+        #[cfg(feature = "wml-layout")]
+        frame_pr: direct.frame_pr.clone(),
+        #[cfg(feature = "wml-layout")]
+        widow_control: direct.widow_control.clone(),
+        num_pr: direct.num_pr.clone(),
+        #[cfg(feature = "wml-layout")]
+        suppress_line_numbers: direct.suppress_line_numbers.clone(),
+        paragraph_border: direct.paragraph_border.clone(),
+        shading: direct.shading.clone(),
+        tabs: direct.tabs.clone(),
+        suppress_auto_hyphens: direct.suppress_auto_hyphens.clone(),
+        kinsoku: direct.kinsoku.clone(),
+        word_wrap: direct.word_wrap.clone(),
+        overflow_punct: direct.overflow_punct.clone(),
+        top_line_punct: direct.top_line_punct.clone(),
+        auto_space_d_e: direct.auto_space_d_e.clone(),
+        auto_space_d_n: direct.auto_space_d_n.clone(),
+        bidi: direct.bidi.clone(),
+        adjust_right_ind: direct.adjust_right_ind.clone(),
+        #[cfg(feature = "wml-layout")]
+        snap_to_grid: direct.snap_to_grid.clone(),
+        spacing: direct.spacing.clone(),
+        indentation: direct.indentation.clone(),
+        contextual_spacing: direct.contextual_spacing.clone(),
+        mirror_indents: direct.mirror_indents.clone(),
+        #[cfg(feature = "wml-layout")]
+        suppress_overlap: direct.suppress_overlap.clone(),
+        justification: direct.justification.clone(),
+        text_direction: direct.text_direction.clone(),
+        text_alignment: direct.text_alignment.clone(),
+        textbox_tight_wrap: direct.textbox_tight_wrap.clone(),
+        outline_lvl: direct.outline_lvl.clone(),
+        div_id: direct.div_id.clone(),
+        cnf_style: direct.cnf_style.clone(),
+        ..Default::default()
+    }
+}
+
 // =============================================================================
 // Parsing Functions
 // =============================================================================
 
+/// Namespaces whose markup this crate reads, used to select the branches of
+/// `mc:AlternateContent` (ECMA-376 Part 3, §10.2).
+///
+/// Text boxes in `wps`, `wpg` and `wpc` shapes are read through
+/// `DrawingTextBoxExt`, so the modern branches are preferred over VML
+/// fallbacks.
+pub const UNDERSTOOD_NAMESPACES: &[&str] = &[
+    crate::writer::NS_W,
+    crate::writer::NS_R,
+    crate::writer::NS_WP,
+    crate::writer::NS_A,
+    crate::writer::NS_PIC,
+    crate::writer::NS_WPS,
+    "http://schemas.microsoft.com/office/word/2010/wordprocessingGroup",
+    "http://schemas.microsoft.com/office/word/2010/wordprocessingCanvas",
+    "http://schemas.openxmlformats.org/officeDocument/2006/math",
+];
+
 /// Parse a `Document` from XML bytes using the generated `FromXml` parser.
 ///
-/// This is the recommended way to parse document.xml content.
+/// This is the recommended way to parse document.xml content. Errors carry
+/// the line, column and element path of the markup the parser failed on; see
+/// [`ooxml_xml::parse_root`].
 pub fn parse_document(xml: &[u8]) -> Result<types::Document, ParseError> {
-    let mut reader = Reader::from_reader(Cursor::new(xml));
-    let mut buf = Vec::new();
-
-    loop {
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Start(e)) => return types::Document::from_xml(&mut reader, &e, false),
-            Ok(Event::Empty(e)) => return types::Document::from_xml(&mut reader, &e, true),
-            Ok(Event::Eof) => break,
-            Err(e) => return Err(ParseError::Xml(e)),
-            _ => {}
-        }
-        buf.clear();
-    }
-    Err(ParseError::UnexpectedElement(
-        "no document element found".to_string(),
-    ))
+    ooxml_xml::parse_root(xml)
 }
 
 /// Parse a `Styles` document from XML bytes using the generated `FromXml` parser.
 ///
 /// This is the recommended way to parse styles.xml content.
 pub fn parse_styles(xml: &[u8]) -> Result<types::Styles, ParseError> {
-    let mut reader = Reader::from_reader(Cursor::new(xml));
-    let mut buf = Vec::new();
-
-    loop {
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Start(e)) => return types::Styles::from_xml(&mut reader, &e, false),
-            Ok(Event::Empty(e)) => return types::Styles::from_xml(&mut reader, &e, true),
-            Ok(Event::Eof) => break,
-            Err(e) => return Err(ParseError::Xml(e)),
-            _ => {}
-        }
-        buf.clear();
-    }
-    Err(ParseError::UnexpectedElement(
-        "no styles element found".to_string(),
-    ))
+    ooxml_xml::parse_root(xml)
 }
 
 /// Parse a header or footer from XML bytes using the generated `FromXml` parser.
 pub fn parse_hdr_ftr(xml: &[u8]) -> Result<types::HeaderFooter, ParseError> {
-    let mut reader = Reader::from_reader(Cursor::new(xml));
-    let mut buf = Vec::new();
-
-    loop {
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Start(e)) => return types::HeaderFooter::from_xml(&mut reader, &e, false),
-            Ok(Event::Empty(e)) => return types::HeaderFooter::from_xml(&mut reader, &e, true),
-            Ok(Event::Eof) => break,
-            Err(e) => return Err(ParseError::Xml(e)),
-            _ => {}
-        }
-        buf.clear();
-    }
-    Err(ParseError::UnexpectedElement(
-        "no header/footer element found".to_string(),
-    ))
+    ooxml_xml::parse_root(xml)
 }
 
 /// Parse footnotes from XML bytes using the generated `FromXml` parser.
 pub fn parse_footnotes(xml: &[u8]) -> Result<types::Footnotes, ParseError> {
-    let mut reader = Reader::from_reader(Cursor::new(xml));
-    let mut buf = Vec::new();
-
-    loop {
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Start(e)) => return types::Footnotes::from_xml(&mut reader, &e, false),
-            Ok(Event::Empty(e)) => return types::Footnotes::from_xml(&mut reader, &e, true),
-            Ok(Event::Eof) => break,
-            Err(e) => return Err(ParseError::Xml(e)),
-            _ => {}
-        }
-        buf.clear();
-    }
-    Err(ParseError::UnexpectedElement(
-        "no footnotes element found".to_string(),
-    ))
+    ooxml_xml::parse_root(xml)
 }
 
 /// Parse endnotes from XML bytes using the generated `FromXml` parser.
 pub fn parse_endnotes(xml: &[u8]) -> Result<types::Endnotes, ParseError> {
-    let mut reader = Reader::from_reader(Cursor::new(xml));
-    let mut buf = Vec::new();
-
-    loop {
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Start(e)) => return types::Endnotes::from_xml(&mut reader, &e, false),
-            Ok(Event::Empty(e)) => return types::Endnotes::from_xml(&mut reader, &e, true),
-            Ok(Event::Eof) => break,
-            Err(e) => return Err(ParseError::Xml(e)),
-            _ => {}
-        }
-        buf.clear();
-    }
-    Err(ParseError::UnexpectedElement(
-        "no endnotes element found".to_string(),
-    ))
+    ooxml_xml::parse_root(xml)
 }
 
 /// Parse comments from XML bytes using the generated `FromXml` parser.
 pub fn parse_comments(xml: &[u8]) -> Result<types::Comments, ParseError> {
-    let mut reader = Reader::from_reader(Cursor::new(xml));
-    let mut buf = Vec::new();
-
-    loop {
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Start(e)) => return types::Comments::from_xml(&mut reader, &e, false),
-            Ok(Event::Empty(e)) => return types::Comments::from_xml(&mut reader, &e, true),
-            Ok(Event::Eof) => break,
-            Err(e) => return Err(ParseError::Xml(e)),
-            _ => {}
-        }
-        buf.clear();
-    }
-    Err(ParseError::UnexpectedElement(
-        "no comments element found".to_string(),
-    ))
+    ooxml_xml::parse_root(xml)
+}
+
+/// Parse numbering definitions from XML bytes using the generated `FromXml` parser.
+pub fn parse_numbering(xml: &[u8]) -> Result<types::Numbering, ParseError> {
+    ooxml_xml::parse_root(xml)
 }
 
 /// Parse a chart part from XML bytes using the `ooxml_dml` generated `FromXml` parser.
@@ -2065,29 +3337,7 @@ pub fn parse_comments(xml: &[u8]) -> Result<types::Comments, ParseError> {
 /// ECMA-376 Part 1, §21.2.2.27 (chartSpace).
 #[cfg(feature = "wml-charts")]
 pub(crate) fn parse_chart(xml: &[u8]) -> Result<ooxml_dml::types::ChartSpace, ParseError> {
-    use ooxml_dml::parsers::FromXml as DmlFromXml;
-    let mut reader = Reader::from_reader(Cursor::new(xml));
-    let mut buf = Vec::new();
-
-    loop {
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Start(e)) => {
-                return ooxml_dml::types::ChartSpace::from_xml(&mut reader, &e, false)
-                    .map_err(|e| ParseError::UnexpectedElement(e.to_string()));
-            }
-            Ok(Event::Empty(e)) => {
-                return ooxml_dml::types::ChartSpace::from_xml(&mut reader, &e, true)
-                    .map_err(|e| ParseError::UnexpectedElement(e.to_string()));
-            }
-            Ok(Event::Eof) => break,
-            Err(e) => return Err(ParseError::Xml(e)),
-            _ => {}
-        }
-        buf.clear();
-    }
-    Err(ParseError::UnexpectedElement(
-        "no chartSpace element found".to_string(),
-    ))
+    ooxml_xml::parse_root(xml)
 }
 
 // =============================================================================
@@ -2286,13 +3536,13 @@ fn text_from_run_track_change(tc: &types::CTRunTrackChange) -> String {
                     }
                     types::RunContent::Tab(_) => out.push('\t'),
                     types::RunContent::Cr(_) => out.push('\n'),
-                    types::RunContent::Br(br) => {
+                    types::RunContent::Br(br)
                         if !matches!(
                             br.r#type,
                             Some(types::STBrType::Page) | Some(types::STBrType::Column)
-                        ) {
-                            out.push('\n');
-                        }
+                        ) =>
+                    {
+                        out.push('\n');
                     }
                     // Also capture del-text for deletion change content
                     types::RunContent::DelText(t) => {
@@ -3651,6 +4901,560 @@ mod tests {
         assert!(!resolved.is_italic(runs[0]));
     }
 
+    // -------------------------------------------------------------------------
+    // NumberingContext tests
+    // -------------------------------------------------------------------------
+
+    #[cfg(feature = "wml-numbering")]
+    fn numbering_context(body: &str) -> NumberingContext {
+        let xml = format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <numbering xmlns="http://schemas.openxmlformats.org/wordprocessingml/2006/main">{body}</numbering>"#
+        );
+        NumberingContext::from_numbering(&parse_numbering(xml.as_bytes()).expect("parse"))
+    }
+
+    #[cfg(feature = "wml-numbering")]
+    const OUTLINE: &str = concat!(
+        r#"
+        <abstractNum abstractNumId="0">
+            <lvl ilvl="0"><start val="1"/><numFmt val="decimal"/><lvlText val="%1."/></lvl>
+            <lvl ilvl="1"><start val="1"/><numFmt val="decimal"/><lvlText val="%1.%2."/></lvl>
+            <lvl ilvl="2"><start val="1"/><numFmt val="lowerLetter"/><lvlText val="%1.%2.%3)"/></lvl>
+            <lvl ilvl="3"><start val="1"/><numFmt val="bullet"/><lvlText val=""#,
+        // Symbol font bullet, as Word writes it
+        "\u{F0B7}",
+        r#""/></lvl>
+        </abstractNum>"#
+    );
+
+    #[test]
+    #[cfg(feature = "wml-numbering")]
+    fn test_format_number() {
+        use types::STNumberFormat as Fmt;
+        let cases = [
+            (4, Fmt::Decimal, "4"),
+            (4, Fmt::DecimalZero, "04"),
+            (1994, Fmt::UpperRoman, "MCMXCIV"),
+            (14, Fmt::LowerRoman, "xiv"),
+            (3, Fmt::LowerLetter, "c"),
+            (28, Fmt::UpperLetter, "BB"),
+            (12, Fmt::Ordinal, "12th"),
+            (22, Fmt::Ordinal, "22nd"),
+            (21, Fmt::CardinalText, "Twenty-one"),
+            (112, Fmt::CardinalText, "One hundred twelve"),
+            (12, Fmt::OrdinalText, "Twelfth"),
+            (40, Fmt::OrdinalText, "Fortieth"),
+            (6, Fmt::Chicago, "††"),
+            (781, Fmt::Chicago, "781"),
+            (781, Fmt::LowerLetter, "781"),
+            (255, Fmt::Hex, "FF"),
+            (3, Fmt::DecimalEnclosedCircle, "③"),
+            (12, Fmt::DecimalFullWidth, "１２"),
+            (3, Fmt::None, ""),
+            (0, Fmt::LowerLetter, "0"),
+        ];
+        for (value, format, expected) in cases {
+            assert_eq!(format_number(value, format), expected, "{value} {format:?}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "wml-numbering")]
+    fn test_numbering_counters() {
+        let mut ctx = numbering_context(&format!(
+            r#"{OUTLINE}<num numId="1"><abstractNumId val="0"/></num>"#
+        ));
+        let labels: Vec<_> = [0, 1, 1, 2, 2, 1, 2, 3, 0, 2]
+            .into_iter()
+            .map(|ilvl| ctx.next_label(1, ilvl).unwrap())
+            .collect();
+        assert_eq!(
+            labels,
+            [
+                "1.", "1.1.", "1.2.", "1.2.a)", "1.2.b)", "1.3.", "1.3.a)", "•", "2.", "2.1.a)"
+            ]
+        );
+        assert_eq!(ctx.next_label(2, 0), None);
+        assert_eq!(ctx.next_label(1, 9), None);
+
+        ctx.reset();
+        assert_eq!(ctx.next_label(1, 1).unwrap(), "1.1.");
+    }
+
+    #[test]
+    #[cfg(feature = "wml-numbering")]
+    fn test_numbering_overrides() {
+        let mut ctx = numbering_context(&format!(
+            r#"{OUTLINE}
+            <num numId="1"><abstractNumId val="0"/></num>
+            <num numId="2"><abstractNumId val="0"/></num>
+            <num numId="3">
+                <abstractNumId val="0"/>
+                <lvlOverride ilvl="0"><startOverride val="5"/></lvlOverride>
+            </num>
+            <num numId="4">
+                <abstractNumId val="0"/>
+                <lvlOverride ilvl="0">
+                    <lvl ilvl="0"><start val="1"/><numFmt val="upperRoman"/><lvlText val="Article %1"/></lvl>
+                </lvlOverride>
+            </num>"#
+        ));
+        // Instances of one abstract definition continue its sequence
+        assert_eq!(ctx.next_label(1, 0).unwrap(), "1.");
+        assert_eq!(ctx.next_label(2, 0).unwrap(), "2.");
+        assert_eq!(ctx.next_label(1, 1).unwrap(), "2.1.");
+        // A start override makes a list of its own
+        assert_eq!(ctx.next_label(3, 0).unwrap(), "5.");
+        assert_eq!(ctx.next_label(3, 0).unwrap(), "6.");
+        assert_eq!(ctx.next_label(2, 0).unwrap(), "3.");
+        // A level override replaces the definition but keeps the sequence
+        assert_eq!(ctx.next_label(4, 0).unwrap(), "Article IV");
+        assert_eq!(ctx.start(3, 0), 5);
+        assert_eq!(ctx.start(3, 1), 1);
+
+        // Counters saturate rather than overflow
+        let mut ctx = numbering_context(&format!(
+            r#"{OUTLINE}
+            <num numId="1">
+                <abstractNumId val="0"/>
+                <lvlOverride ilvl="0"><startOverride val="9223372036854775807"/></lvlOverride>
+            </num>"#
+        ));
+        assert_eq!(ctx.next(1, 0), Some(i64::MAX));
+        assert_eq!(ctx.next(1, 0), Some(i64::MAX));
+    }
+
+    #[test]
+    #[cfg(feature = "wml-numbering")]
+    fn test_numbering_restart_and_legal() {
+        let mut ctx = numbering_context(
+            r#"
+            <abstractNum abstractNumId="0">
+                <lvl ilvl="0"><start val="1"/><numFmt val="upperRoman"/><lvlText val="%1."/></lvl>
+                <lvl ilvl="1"><start val="1"/><numFmt val="upperLetter"/><lvlRestart val="0"/><lvlText val="%2."/></lvl>
+                <lvl ilvl="2"><start val="1"/><numFmt val="decimal"/><lvlRestart val="1"/><isLgl/><lvlText val="%1.%2.%3"/></lvl>
+            </abstractNum>
+            <num numId="1"><abstractNumId val="0"/></num>"#,
+        );
+        let labels: Vec<_> = [0, 1, 2, 0, 1, 2, 1, 2]
+            .into_iter()
+            .map(|ilvl| ctx.next_label(1, ilvl).unwrap())
+            .collect();
+        // Level 1 never restarts; level 2 restarts only after level 0
+        assert_eq!(
+            labels,
+            ["I.", "A.", "1.1.1", "II.", "B.", "2.2.1", "C.", "2.3.2"]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "wml-numbering")]
+    fn test_numbering_style_link() {
+        let ctx = numbering_context(&format!(
+            r#"{OUTLINE}
+            <abstractNum abstractNumId="1"><numStyleLink val="LegalList"/></abstractNum>
+            <abstractNum abstractNumId="2">
+                <styleLink val="LegalList"/>
+                <lvl ilvl="0"><start val="3"/><numFmt val="lowerRoman"/><lvlText val="(%1)"/></lvl>
+            </abstractNum>
+            <num numId="1"><abstractNumId val="1"/></num>"#
+        ));
+        assert_eq!(ctx.abstract_num(1).unwrap().abstract_num_id, 2);
+        assert_eq!(ctx.label(1, 0).unwrap(), "(iii)");
+    }
+
+    #[test]
+    #[cfg(all(feature = "wml-numbering", feature = "wml-styling"))]
+    fn test_numbering_paragraph_labels() {
+        let mut numbering = numbering_context(&format!(
+            r#"
+            <abstractNum abstractNumId="5">
+                <lvl ilvl="0"><start val="1"/><numFmt val="decimal"/><pStyle val="Heading1"/><lvlText val="%1"/></lvl>
+                <lvl ilvl="1"><start val="1"/><numFmt val="decimal"/><pStyle val="Heading2"/><lvlText val="%1.%2"/></lvl>
+            </abstractNum>
+            {OUTLINE}
+            <num numId="1"><abstractNumId val="5"/></num>
+            <num numId="2"><abstractNumId val="0"/></num>"#
+        ));
+        let styles = parse_styles(
+            br#"<?xml version="1.0" encoding="UTF-8"?>
+            <styles xmlns="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+                <style type="paragraph" styleId="Heading1">
+                    <pPr><numPr><numId val="1"/></numPr></pPr>
+                </style>
+                <style type="paragraph" styleId="Heading2">
+                    <basedOn val="Heading1"/>
+                </style>
+            </styles>"#,
+        )
+        .expect("parse");
+        let styles = StyleContext::from_styles(&styles);
+        let document = parse_document(
+            br#"<?xml version="1.0" encoding="UTF-8"?>
+            <document xmlns="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+                <body>
+                    <p><pPr><pStyle val="Heading1"/></pPr></p>
+                    <p><pPr><pStyle val="Heading2"/></pPr></p>
+                    <p/>
+                    <p><pPr><pStyle val="Heading2"/></pPr></p>
+                    <p><pPr><pStyle val="Heading2"/><numPr><ilvl val="0"/><numId val="2"/></numPr></pPr></p>
+                    <p><pPr><pStyle val="Heading1"/><numPr><ilvl val="1"/></numPr></pPr></p>
+                    <p><pPr><pStyle val="Heading1"/><numPr><numId val="0"/></numPr></pPr></p>
+                </body>
+            </document>"#,
+        )
+        .expect("parse");
+
+        let labels: Vec<_> = document
+            .body()
+            .unwrap()
+            .paragraphs()
+            .into_iter()
+            .map(|para| numbering.paragraph_label(para, &styles))
+            .collect();
+        assert_eq!(
+            labels,
+            [
+                Some("1".to_string()),
+                Some("1.1".to_string()),
+                None,
+                Some("1.2".to_string()),
+                Some("1.".to_string()),
+                Some("1.3".to_string()),
+                None,
+            ]
+        );
+    }
+
+    // -------------------------------------------------------------------------
+    // Effective formatting tests
+    // -------------------------------------------------------------------------
+
+    #[cfg(all(
+        feature = "wml-styling",
+        feature = "wml-tables",
+        feature = "wml-numbering"
+    ))]
+    fn style_context(body: &str) -> StyleContext {
+        let xml = format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <styles xmlns="http://schemas.openxmlformats.org/wordprocessingml/2006/main">{body}</styles>"#
+        );
+        StyleContext::from_styles(&parse_styles(xml.as_bytes()).expect("parse"))
+    }
+
+    #[cfg(all(
+        feature = "wml-styling",
+        feature = "wml-tables",
+        feature = "wml-numbering"
+    ))]
+    fn document_body(body: &str) -> types::Document {
+        let xml = format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <document xmlns="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><body>{body}</body></document>"#
+        );
+        parse_document(xml.as_bytes()).expect("parse")
+    }
+
+    #[cfg(all(
+        feature = "wml-styling",
+        feature = "wml-tables",
+        feature = "wml-numbering"
+    ))]
+    const GRID_STYLE: &str = r#"
+        <style type="table" styleId="Grid">
+            <tblPr>
+                <tblBorders>
+                    <top val="single" sz="8"/><bottom val="single" sz="8"/>
+                    <insideH val="dotted" sz="4"/><insideV val="dotted" sz="4"/>
+                </tblBorders>
+            </tblPr><tblGrid/>
+            <tblStylePr type="firstRow">
+                <rPr><b/></rPr>
+                <tcPr><tcBorders><bottom val="double" sz="6"/></tcBorders></tcPr>
+            </tblStylePr>
+            <tblStylePr type="band1Horz">
+                <tcPr><shd val="clear" fill="EEEEEE"/></tcPr>
+            </tblStylePr>
+            <tblStylePr type="lastCol">
+                <pPr><jc val="right"/></pPr>
+                <rPr><b/></rPr>
+            </tblStylePr>
+        </style>"#;
+
+    #[test]
+    #[cfg(all(
+        feature = "wml-styling",
+        feature = "wml-tables",
+        feature = "wml-numbering"
+    ))]
+    fn test_resolve_paragraph_properties() {
+        let styles = style_context(
+            r#"
+            <docDefaults>
+                <pPrDefault><pPr><spacing after="160" line="259" lineRule="auto"/></pPr></pPrDefault>
+            </docDefaults>
+            <style type="paragraph" default="1" styleId="Normal">
+                <pPr><tabs><tab val="left" pos="720"/></tabs></pPr>
+            </style>
+            <style type="paragraph" styleId="ListParagraph">
+                <basedOn val="Normal"/>
+                <pPr><ind left="720"/><contextualSpacing/></pPr>
+            </style>
+            <style type="paragraph" styleId="Note">
+                <basedOn val="ListParagraph"/>
+                <pPr>
+                    <spacing before="120"/>
+                    <pBdr><top val="single" sz="4"/></pBdr>
+                    <tabs><tab val="clear" pos="720"/><tab val="right" pos="9360"/></tabs>
+                </pPr>
+            </style>"#,
+        );
+        let numbering = numbering_context(
+            r#"
+            <abstractNum abstractNumId="0">
+                <lvl ilvl="0"><start val="1"/><numFmt val="decimal"/><lvlText val="%1."/>
+                    <pPr><ind left="360" hanging="360"/></pPr></lvl>
+            </abstractNum>
+            <num numId="1"><abstractNumId val="0"/></num>"#,
+        );
+        let document = document_body(
+            r#"
+            <p/>
+            <p><pPr><pStyle val="Note"/><numPr><ilvl val="0"/><numId val="1"/></numPr>
+                <pBdr><bottom val="single" sz="4"/></pBdr><ind firstLine="200"/><jc val="center"/></pPr></p>"#,
+        );
+        let paras = document.body().unwrap().paragraphs();
+        let scope = ParagraphScope {
+            numbering: Some(&numbering),
+            ..Default::default()
+        };
+
+        // Defaults and the default paragraph style
+        let plain = styles.resolve_paragraph_properties(paras[0], &scope);
+        assert_eq!(plain.paragraph_style.unwrap().value, "Normal");
+        let spacing = plain.spacing.unwrap();
+        assert_eq!(spacing.after.as_deref(), Some("160"));
+        assert_eq!(spacing.line.as_deref(), Some("259"));
+        assert_eq!(plain.tabs.unwrap().tab.len(), 1);
+
+        let note = styles.resolve_paragraph_properties(paras[1], &scope);
+        assert_eq!(note.paragraph_style.unwrap().value, "Note");
+        let spacing = note.spacing.unwrap();
+        assert_eq!(spacing.before.as_deref(), Some("120"));
+        assert_eq!(spacing.after.as_deref(), Some("160"));
+        // The style's indent beats the numbering level's; the direct first
+        // line indent replaces the level's hanging one
+        let ind = note.indentation.unwrap();
+        assert_eq!(ind.left.as_deref(), Some("720"));
+        assert_eq!(ind.first_line.as_deref(), Some("200"));
+        assert_eq!(ind.hanging, None);
+        assert!(note.contextual_spacing.is_some());
+        let borders = note.paragraph_border.unwrap();
+        assert!(borders.top.is_some() && borders.bottom.is_some());
+        let tabs: Vec<_> = note
+            .tabs
+            .unwrap()
+            .tab
+            .iter()
+            .map(|t| t.pos.clone())
+            .collect();
+        assert_eq!(tabs, ["9360"]);
+        assert_eq!(note.justification.unwrap().value, types::STJc::Center);
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "wml-styling",
+        feature = "wml-tables",
+        feature = "wml-numbering"
+    ))]
+    fn test_resolve_run_properties() {
+        let styles = style_context(&format!(
+            r#"
+            <docDefaults>
+                <rPrDefault><rPr><rFonts ascii="Calibri" hAnsi="Calibri"/><sz val="22"/></rPr></rPrDefault>
+            </docDefaults>
+            <style type="paragraph" styleId="Heading1">
+                <rPr><rFonts ascii="Cambria"/><b/><sz val="32"/></rPr>
+            </style>
+            <style type="character" styleId="Strong">
+                <rPr><b/><i/></rPr>
+            </style>
+            <style type="character" styleId="Emphasis">
+                <basedOn val="Strong"/>
+                <rPr><color val="FF0000"/></rPr>
+            </style>
+            {GRID_STYLE}"#
+        ));
+        let document = document_body(
+            r#"
+            <p><pPr><pStyle val="Heading1"/></pPr>
+                <r><t>heading</t></r>
+                <r><rPr><rStyle val="Strong"/></rPr><t>strong</t></r>
+                <r><rPr><rStyle val="Emphasis"/><i w:val="0" xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"/></rPr><t>red</t></r>
+            </p>
+            <tbl><tblPr><tblStyle val="Grid"/><tblLook firstRow="1" lastColumn="1"/></tblPr><tblGrid/>
+                <tr><tc><p><r><t>a</t></r></p></tc><tc><p><r><t>b</t></r></p></tc></tr>
+            </tbl>"#,
+        );
+        let body = document.body().unwrap();
+        let para = body.paragraphs()[0];
+        let runs = para.runs();
+        let scope = ParagraphScope::default();
+
+        let heading = styles.resolve_run_properties(para, runs[0], &scope);
+        assert!(heading.bold.is_some());
+        assert_eq!(heading.size.unwrap().value, "32");
+        let fonts = heading.fonts.unwrap();
+        assert_eq!(fonts.ascii.as_deref(), Some("Cambria"));
+        assert_eq!(fonts.h_ansi.as_deref(), Some("Calibri"));
+
+        // Bold from both the paragraph and the character style cancels out
+        let strong = styles.resolve_run_properties(para, runs[1], &scope);
+        assert!(strong.bold.is_none());
+        assert!(strong.italic.is_some());
+
+        let red = styles.resolve_run_properties(para, runs[2], &scope);
+        assert_eq!(red.color.unwrap().value, "FF0000");
+        assert!(red.italic.is_none());
+
+        // The first row's and the last column's bold cancel in the corner
+        let table = body.tables()[0];
+        let bold: Vec<_> = [0, 1]
+            .map(|column| {
+                let position = CellPosition::of(table, 0, column).unwrap();
+                let para = &position.cell(table).unwrap().paragraphs()[0];
+                let scope = ParagraphScope {
+                    cell: Some((table, position)),
+                    ..Default::default()
+                };
+                styles
+                    .resolve_run_properties(para, para.runs()[0], &scope)
+                    .bold
+                    .is_some()
+            })
+            .into();
+        assert_eq!(bold, [true, true]);
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "wml-styling",
+        feature = "wml-tables",
+        feature = "wml-numbering"
+    ))]
+    fn test_table_style_regions() {
+        use types::STTblStyleOverrideType as Region;
+        let styles = style_context(GRID_STYLE);
+        let row = "<tr><tc><p/></tc><tc><p/></tc><tc><p/></tc></tr>";
+        let rows = row.repeat(5);
+        let document = document_body(&format!(
+            r#"
+            <tbl><tblPr><tblStyle val="Grid"/><tblStyleRowBandSize val="2"/>
+                <tblLook w:val="04A0" xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"/></tblPr><tblGrid/>{rows}</tbl>
+            <tbl><tblPr><tblStyle val="Grid"/></tblPr><tblGrid/>{rows}</tbl>"#
+        ));
+        let tables = document.body().unwrap().tables();
+        let regions = |table, row, column| {
+            styles.table_style_regions(table, CellPosition::of(table, row, column).unwrap())
+        };
+
+        // 04A0: first row, first column, no vertical banding
+        assert_eq!(
+            regions(tables[0], 0, 0),
+            [
+                Region::WholeTable,
+                Region::FirstRow,
+                Region::FirstCol,
+                Region::NwCell
+            ]
+        );
+        assert_eq!(
+            regions(tables[0], 0, 2),
+            [Region::WholeTable, Region::FirstRow]
+        );
+        assert_eq!(
+            regions(tables[0], 2, 1),
+            [Region::WholeTable, Region::Band1Horz]
+        );
+        assert_eq!(
+            regions(tables[0], 3, 1),
+            [Region::WholeTable, Region::Band2Horz]
+        );
+        assert_eq!(
+            regions(tables[0], 4, 0),
+            [Region::WholeTable, Region::Band2Horz, Region::FirstCol]
+        );
+
+        // Without tblLook, banding only
+        assert_eq!(
+            regions(tables[1], 0, 0),
+            [Region::WholeTable, Region::Band1Vert, Region::Band1Horz]
+        );
+        assert_eq!(
+            regions(tables[1], 1, 1),
+            [Region::WholeTable, Region::Band2Vert, Region::Band2Horz]
+        );
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "wml-styling",
+        feature = "wml-tables",
+        feature = "wml-numbering"
+    ))]
+    fn test_resolve_cell_properties() {
+        let styles = style_context(GRID_STYLE);
+        let document = document_body(
+            r#"
+            <tbl><tblPr><tblStyle val="Grid"/><tblLook firstRow="1" noVBand="1"/>
+                <tblCellMar><left w="108" type="dxa"/></tblCellMar></tblPr><tblGrid/>
+                <tr><tc><p/></tc><tc><p/></tc></tr>
+                <tr><tc><p/></tc><tc><tcPr><shd val="clear" fill="FFFF00"/></tcPr><p/></tc></tr>
+                <tr><tc><p/></tc><tc><p/></tc></tr>
+            </tbl>"#,
+        );
+        let table = document.body().unwrap().tables()[0];
+        let cell = |row, column| {
+            styles.resolve_cell_properties(table, CellPosition::of(table, row, column).unwrap())
+        };
+        // Top, bottom, left and right border styles
+        let borders = |props: &types::TableCellProperties| {
+            let borders = props.tc_borders.as_deref().unwrap();
+            [&borders.top, &borders.bottom, &borders.left, &borders.right]
+                .map(|border| border.as_deref().map(|border| border.value))
+        };
+
+        use types::STBorder::{Dotted, Double, Single};
+        let header = cell(0, 0);
+        assert_eq!(
+            borders(&header),
+            [Some(Single), Some(Double), None, Some(Dotted)]
+        );
+        assert_eq!(
+            header.tc_mar.unwrap().left.unwrap().width.as_deref(),
+            Some("108")
+        );
+        assert!(header.shading.is_none());
+
+        let banded = cell(1, 0);
+        assert_eq!(
+            borders(&banded),
+            [Some(Dotted), Some(Dotted), None, Some(Dotted)]
+        );
+        assert_eq!(banded.shading.unwrap().fill.as_deref(), Some("EEEEEE"));
+        assert_eq!(cell(1, 1).shading.unwrap().fill.as_deref(), Some("FFFF00"));
+
+        let last = cell(2, 1);
+        assert_eq!(
+            borders(&last),
+            [Some(Dotted), Some(Single), Some(Dotted), None]
+        );
+        assert!(last.shading.is_none());
+    }
+
     // -------------------------------------------------------------------------
     // DrawingChartExt tests
     // -------------------------------------------------------------------------
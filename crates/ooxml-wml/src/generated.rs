@@ -1,6 +1,7 @@
 // Generated from ECMA-376 RELAX NG schema.
 // Do not edit manually.
 
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// XML namespace URIs used in this schema.
@@ -33,35 +34,36 @@ pub type HexColorRgb = Vec<u8>;
 
 pub type Panose = Vec<u8>;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CalendarType {
-    #[serde(rename = "gregorian")]
+    #[cfg_attr(feature = "serde", serde(rename = "gregorian"))]
     Gregorian,
-    #[serde(rename = "gregorianUs")]
+    #[cfg_attr(feature = "serde", serde(rename = "gregorianUs"))]
     GregorianUs,
-    #[serde(rename = "gregorianMeFrench")]
+    #[cfg_attr(feature = "serde", serde(rename = "gregorianMeFrench"))]
     GregorianMeFrench,
-    #[serde(rename = "gregorianArabic")]
+    #[cfg_attr(feature = "serde", serde(rename = "gregorianArabic"))]
     GregorianArabic,
-    #[serde(rename = "hijri")]
+    #[cfg_attr(feature = "serde", serde(rename = "hijri"))]
     Hijri,
-    #[serde(rename = "hebrew")]
+    #[cfg_attr(feature = "serde", serde(rename = "hebrew"))]
     Hebrew,
-    #[serde(rename = "taiwan")]
+    #[cfg_attr(feature = "serde", serde(rename = "taiwan"))]
     Taiwan,
-    #[serde(rename = "japan")]
+    #[cfg_attr(feature = "serde", serde(rename = "japan"))]
     Japan,
-    #[serde(rename = "thai")]
+    #[cfg_attr(feature = "serde", serde(rename = "thai"))]
     Thai,
-    #[serde(rename = "korea")]
+    #[cfg_attr(feature = "serde", serde(rename = "korea"))]
     Korea,
-    #[serde(rename = "saka")]
+    #[cfg_attr(feature = "serde", serde(rename = "saka"))]
     Saka,
-    #[serde(rename = "gregorianXlitEnglish")]
+    #[cfg_attr(feature = "serde", serde(rename = "gregorianXlitEnglish"))]
     GregorianXlitEnglish,
-    #[serde(rename = "gregorianXlitFrench")]
+    #[cfg_attr(feature = "serde", serde(rename = "gregorianXlitFrench"))]
     GregorianXlitFrench,
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
 }
 
@@ -110,11 +112,12 @@ impl std::str::FromStr for CalendarType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STAlgClass {
-    #[serde(rename = "hash")]
+    #[cfg_attr(feature = "serde", serde(rename = "hash"))]
     Hash,
-    #[serde(rename = "custom")]
+    #[cfg_attr(feature = "serde", serde(rename = "custom"))]
     Custom,
 }
 
@@ -139,13 +142,14 @@ impl std::str::FromStr for STAlgClass {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STCryptProv {
-    #[serde(rename = "rsaAES")]
+    #[cfg_attr(feature = "serde", serde(rename = "rsaAES"))]
     RsaAES,
-    #[serde(rename = "rsaFull")]
+    #[cfg_attr(feature = "serde", serde(rename = "rsaFull"))]
     RsaFull,
-    #[serde(rename = "custom")]
+    #[cfg_attr(feature = "serde", serde(rename = "custom"))]
     Custom,
 }
 
@@ -172,11 +176,12 @@ impl std::str::FromStr for STCryptProv {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STAlgType {
-    #[serde(rename = "typeAny")]
+    #[cfg_attr(feature = "serde", serde(rename = "typeAny"))]
     TypeAny,
-    #[serde(rename = "custom")]
+    #[cfg_attr(feature = "serde", serde(rename = "custom"))]
     Custom,
 }
 
@@ -207,11 +212,12 @@ pub type Guid = String;
 
 pub type OnOff = String;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STOnOff1 {
-    #[serde(rename = "on")]
+    #[cfg_attr(feature = "serde", serde(rename = "on"))]
     On,
-    #[serde(rename = "off")]
+    #[cfg_attr(feature = "serde", serde(rename = "off"))]
     Off,
 }
 
@@ -240,15 +246,16 @@ pub type STString = String;
 
 pub type STXmlName = String;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum TrueFalse {
-    #[serde(rename = "t")]
+    #[cfg_attr(feature = "serde", serde(rename = "t"))]
     T,
-    #[serde(rename = "f")]
+    #[cfg_attr(feature = "serde", serde(rename = "f"))]
     F,
-    #[serde(rename = "true")]
+    #[cfg_attr(feature = "serde", serde(rename = "true"))]
     True,
-    #[serde(rename = "false")]
+    #[cfg_attr(feature = "serde", serde(rename = "false"))]
     False,
 }
 
@@ -277,17 +284,18 @@ impl std::str::FromStr for TrueFalse {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTrueFalseBlank {
-    #[serde(rename = "t")]
+    #[cfg_attr(feature = "serde", serde(rename = "t"))]
     T,
-    #[serde(rename = "f")]
+    #[cfg_attr(feature = "serde", serde(rename = "f"))]
     F,
-    #[serde(rename = "true")]
+    #[cfg_attr(feature = "serde", serde(rename = "true"))]
     True,
-    #[serde(rename = "false")]
+    #[cfg_attr(feature = "serde", serde(rename = "false"))]
     False,
-    #[serde(rename = "")]
+    #[cfg_attr(feature = "serde", serde(rename = ""))]
     Empty,
 }
 
@@ -324,13 +332,14 @@ pub type STUnsignedDecimalNumber = u64;
 
 pub type STTwipsMeasure = String;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STVerticalAlignRun {
-    #[serde(rename = "baseline")]
+    #[cfg_attr(feature = "serde", serde(rename = "baseline"))]
     Baseline,
-    #[serde(rename = "superscript")]
+    #[cfg_attr(feature = "serde", serde(rename = "superscript"))]
     Superscript,
-    #[serde(rename = "subscript")]
+    #[cfg_attr(feature = "serde", serde(rename = "subscript"))]
     Subscript,
 }
 
@@ -359,17 +368,18 @@ impl std::str::FromStr for STVerticalAlignRun {
 
 pub type XmlString = String;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STXAlign {
-    #[serde(rename = "left")]
+    #[cfg_attr(feature = "serde", serde(rename = "left"))]
     Left,
-    #[serde(rename = "center")]
+    #[cfg_attr(feature = "serde", serde(rename = "center"))]
     Center,
-    #[serde(rename = "right")]
+    #[cfg_attr(feature = "serde", serde(rename = "right"))]
     Right,
-    #[serde(rename = "inside")]
+    #[cfg_attr(feature = "serde", serde(rename = "inside"))]
     Inside,
-    #[serde(rename = "outside")]
+    #[cfg_attr(feature = "serde", serde(rename = "outside"))]
     Outside,
 }
 
@@ -400,19 +410,20 @@ impl std::str::FromStr for STXAlign {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STYAlign {
-    #[serde(rename = "inline")]
+    #[cfg_attr(feature = "serde", serde(rename = "inline"))]
     Inline,
-    #[serde(rename = "top")]
+    #[cfg_attr(feature = "serde", serde(rename = "top"))]
     Top,
-    #[serde(rename = "center")]
+    #[cfg_attr(feature = "serde", serde(rename = "center"))]
     Center,
-    #[serde(rename = "bottom")]
+    #[cfg_attr(feature = "serde", serde(rename = "bottom"))]
     Bottom,
-    #[serde(rename = "inside")]
+    #[cfg_attr(feature = "serde", serde(rename = "inside"))]
     Inside,
-    #[serde(rename = "outside")]
+    #[cfg_attr(feature = "serde", serde(rename = "outside"))]
     Outside,
 }
 
@@ -445,11 +456,12 @@ impl std::str::FromStr for STYAlign {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STConformanceClass {
-    #[serde(rename = "strict")]
+    #[cfg_attr(feature = "serde", serde(rename = "strict"))]
     Strict,
-    #[serde(rename = "transitional")]
+    #[cfg_attr(feature = "serde", serde(rename = "transitional"))]
     Transitional,
 }
 
@@ -522,41 +534,42 @@ pub type STTextScalePercent = String;
 
 pub type STTextScaleDecimal = i64;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STHighlightColor {
-    #[serde(rename = "black")]
+    #[cfg_attr(feature = "serde", serde(rename = "black"))]
     Black,
-    #[serde(rename = "blue")]
+    #[cfg_attr(feature = "serde", serde(rename = "blue"))]
     Blue,
-    #[serde(rename = "cyan")]
+    #[cfg_attr(feature = "serde", serde(rename = "cyan"))]
     Cyan,
-    #[serde(rename = "green")]
+    #[cfg_attr(feature = "serde", serde(rename = "green"))]
     Green,
-    #[serde(rename = "magenta")]
+    #[cfg_attr(feature = "serde", serde(rename = "magenta"))]
     Magenta,
-    #[serde(rename = "red")]
+    #[cfg_attr(feature = "serde", serde(rename = "red"))]
     Red,
-    #[serde(rename = "yellow")]
+    #[cfg_attr(feature = "serde", serde(rename = "yellow"))]
     Yellow,
-    #[serde(rename = "white")]
+    #[cfg_attr(feature = "serde", serde(rename = "white"))]
     White,
-    #[serde(rename = "darkBlue")]
+    #[cfg_attr(feature = "serde", serde(rename = "darkBlue"))]
     DarkBlue,
-    #[serde(rename = "darkCyan")]
+    #[cfg_attr(feature = "serde", serde(rename = "darkCyan"))]
     DarkCyan,
-    #[serde(rename = "darkGreen")]
+    #[cfg_attr(feature = "serde", serde(rename = "darkGreen"))]
     DarkGreen,
-    #[serde(rename = "darkMagenta")]
+    #[cfg_attr(feature = "serde", serde(rename = "darkMagenta"))]
     DarkMagenta,
-    #[serde(rename = "darkRed")]
+    #[cfg_attr(feature = "serde", serde(rename = "darkRed"))]
     DarkRed,
-    #[serde(rename = "darkYellow")]
+    #[cfg_attr(feature = "serde", serde(rename = "darkYellow"))]
     DarkYellow,
-    #[serde(rename = "darkGray")]
+    #[cfg_attr(feature = "serde", serde(rename = "darkGray"))]
     DarkGray,
-    #[serde(rename = "lightGray")]
+    #[cfg_attr(feature = "serde", serde(rename = "lightGray"))]
     LightGray,
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
 }
 
@@ -613,43 +626,44 @@ impl std::str::FromStr for STHighlightColor {
 
 pub type STHexColor = String;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STUnderline {
-    #[serde(rename = "single")]
+    #[cfg_attr(feature = "serde", serde(rename = "single"))]
     Single,
-    #[serde(rename = "words")]
+    #[cfg_attr(feature = "serde", serde(rename = "words"))]
     Words,
-    #[serde(rename = "double")]
+    #[cfg_attr(feature = "serde", serde(rename = "double"))]
     Double,
-    #[serde(rename = "thick")]
+    #[cfg_attr(feature = "serde", serde(rename = "thick"))]
     Thick,
-    #[serde(rename = "dotted")]
+    #[cfg_attr(feature = "serde", serde(rename = "dotted"))]
     Dotted,
-    #[serde(rename = "dottedHeavy")]
+    #[cfg_attr(feature = "serde", serde(rename = "dottedHeavy"))]
     DottedHeavy,
-    #[serde(rename = "dash")]
+    #[cfg_attr(feature = "serde", serde(rename = "dash"))]
     Dash,
-    #[serde(rename = "dashedHeavy")]
+    #[cfg_attr(feature = "serde", serde(rename = "dashedHeavy"))]
     DashedHeavy,
-    #[serde(rename = "dashLong")]
+    #[cfg_attr(feature = "serde", serde(rename = "dashLong"))]
     DashLong,
-    #[serde(rename = "dashLongHeavy")]
+    #[cfg_attr(feature = "serde", serde(rename = "dashLongHeavy"))]
     DashLongHeavy,
-    #[serde(rename = "dotDash")]
+    #[cfg_attr(feature = "serde", serde(rename = "dotDash"))]
     DotDash,
-    #[serde(rename = "dashDotHeavy")]
+    #[cfg_attr(feature = "serde", serde(rename = "dashDotHeavy"))]
     DashDotHeavy,
-    #[serde(rename = "dotDotDash")]
+    #[cfg_attr(feature = "serde", serde(rename = "dotDotDash"))]
     DotDotDash,
-    #[serde(rename = "dashDotDotHeavy")]
+    #[cfg_attr(feature = "serde", serde(rename = "dashDotDotHeavy"))]
     DashDotDotHeavy,
-    #[serde(rename = "wave")]
+    #[cfg_attr(feature = "serde", serde(rename = "wave"))]
     Wave,
-    #[serde(rename = "wavyHeavy")]
+    #[cfg_attr(feature = "serde", serde(rename = "wavyHeavy"))]
     WavyHeavy,
-    #[serde(rename = "wavyDouble")]
+    #[cfg_attr(feature = "serde", serde(rename = "wavyDouble"))]
     WavyDouble,
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
 }
 
@@ -706,21 +720,22 @@ impl std::str::FromStr for STUnderline {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTextEffect {
-    #[serde(rename = "blinkBackground")]
+    #[cfg_attr(feature = "serde", serde(rename = "blinkBackground"))]
     BlinkBackground,
-    #[serde(rename = "lights")]
+    #[cfg_attr(feature = "serde", serde(rename = "lights"))]
     Lights,
-    #[serde(rename = "antsBlack")]
+    #[cfg_attr(feature = "serde", serde(rename = "antsBlack"))]
     AntsBlack,
-    #[serde(rename = "antsRed")]
+    #[cfg_attr(feature = "serde", serde(rename = "antsRed"))]
     AntsRed,
-    #[serde(rename = "shimmer")]
+    #[cfg_attr(feature = "serde", serde(rename = "shimmer"))]
     Shimmer,
-    #[serde(rename = "sparkle")]
+    #[cfg_attr(feature = "serde", serde(rename = "sparkle"))]
     Sparkle,
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
 }
 
@@ -755,393 +770,394 @@ impl std::str::FromStr for STTextEffect {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STBorder {
-    #[serde(rename = "nil")]
+    #[cfg_attr(feature = "serde", serde(rename = "nil"))]
     Nil,
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
-    #[serde(rename = "single")]
+    #[cfg_attr(feature = "serde", serde(rename = "single"))]
     Single,
-    #[serde(rename = "thick")]
+    #[cfg_attr(feature = "serde", serde(rename = "thick"))]
     Thick,
-    #[serde(rename = "double")]
+    #[cfg_attr(feature = "serde", serde(rename = "double"))]
     Double,
-    #[serde(rename = "dotted")]
+    #[cfg_attr(feature = "serde", serde(rename = "dotted"))]
     Dotted,
-    #[serde(rename = "dashed")]
+    #[cfg_attr(feature = "serde", serde(rename = "dashed"))]
     Dashed,
-    #[serde(rename = "dotDash")]
+    #[cfg_attr(feature = "serde", serde(rename = "dotDash"))]
     DotDash,
-    #[serde(rename = "dotDotDash")]
+    #[cfg_attr(feature = "serde", serde(rename = "dotDotDash"))]
     DotDotDash,
-    #[serde(rename = "triple")]
+    #[cfg_attr(feature = "serde", serde(rename = "triple"))]
     Triple,
-    #[serde(rename = "thinThickSmallGap")]
+    #[cfg_attr(feature = "serde", serde(rename = "thinThickSmallGap"))]
     ThinThickSmallGap,
-    #[serde(rename = "thickThinSmallGap")]
+    #[cfg_attr(feature = "serde", serde(rename = "thickThinSmallGap"))]
     ThickThinSmallGap,
-    #[serde(rename = "thinThickThinSmallGap")]
+    #[cfg_attr(feature = "serde", serde(rename = "thinThickThinSmallGap"))]
     ThinThickThinSmallGap,
-    #[serde(rename = "thinThickMediumGap")]
+    #[cfg_attr(feature = "serde", serde(rename = "thinThickMediumGap"))]
     ThinThickMediumGap,
-    #[serde(rename = "thickThinMediumGap")]
+    #[cfg_attr(feature = "serde", serde(rename = "thickThinMediumGap"))]
     ThickThinMediumGap,
-    #[serde(rename = "thinThickThinMediumGap")]
+    #[cfg_attr(feature = "serde", serde(rename = "thinThickThinMediumGap"))]
     ThinThickThinMediumGap,
-    #[serde(rename = "thinThickLargeGap")]
+    #[cfg_attr(feature = "serde", serde(rename = "thinThickLargeGap"))]
     ThinThickLargeGap,
-    #[serde(rename = "thickThinLargeGap")]
+    #[cfg_attr(feature = "serde", serde(rename = "thickThinLargeGap"))]
     ThickThinLargeGap,
-    #[serde(rename = "thinThickThinLargeGap")]
+    #[cfg_attr(feature = "serde", serde(rename = "thinThickThinLargeGap"))]
     ThinThickThinLargeGap,
-    #[serde(rename = "wave")]
+    #[cfg_attr(feature = "serde", serde(rename = "wave"))]
     Wave,
-    #[serde(rename = "doubleWave")]
+    #[cfg_attr(feature = "serde", serde(rename = "doubleWave"))]
     DoubleWave,
-    #[serde(rename = "dashSmallGap")]
+    #[cfg_attr(feature = "serde", serde(rename = "dashSmallGap"))]
     DashSmallGap,
-    #[serde(rename = "dashDotStroked")]
+    #[cfg_attr(feature = "serde", serde(rename = "dashDotStroked"))]
     DashDotStroked,
-    #[serde(rename = "threeDEmboss")]
+    #[cfg_attr(feature = "serde", serde(rename = "threeDEmboss"))]
     ThreeDEmboss,
-    #[serde(rename = "threeDEngrave")]
+    #[cfg_attr(feature = "serde", serde(rename = "threeDEngrave"))]
     ThreeDEngrave,
-    #[serde(rename = "outset")]
+    #[cfg_attr(feature = "serde", serde(rename = "outset"))]
     Outset,
-    #[serde(rename = "inset")]
+    #[cfg_attr(feature = "serde", serde(rename = "inset"))]
     Inset,
-    #[serde(rename = "apples")]
+    #[cfg_attr(feature = "serde", serde(rename = "apples"))]
     Apples,
-    #[serde(rename = "archedScallops")]
+    #[cfg_attr(feature = "serde", serde(rename = "archedScallops"))]
     ArchedScallops,
-    #[serde(rename = "babyPacifier")]
+    #[cfg_attr(feature = "serde", serde(rename = "babyPacifier"))]
     BabyPacifier,
-    #[serde(rename = "babyRattle")]
+    #[cfg_attr(feature = "serde", serde(rename = "babyRattle"))]
     BabyRattle,
-    #[serde(rename = "balloons3Colors")]
+    #[cfg_attr(feature = "serde", serde(rename = "balloons3Colors"))]
     Balloons3Colors,
-    #[serde(rename = "balloonsHotAir")]
+    #[cfg_attr(feature = "serde", serde(rename = "balloonsHotAir"))]
     BalloonsHotAir,
-    #[serde(rename = "basicBlackDashes")]
+    #[cfg_attr(feature = "serde", serde(rename = "basicBlackDashes"))]
     BasicBlackDashes,
-    #[serde(rename = "basicBlackDots")]
+    #[cfg_attr(feature = "serde", serde(rename = "basicBlackDots"))]
     BasicBlackDots,
-    #[serde(rename = "basicBlackSquares")]
+    #[cfg_attr(feature = "serde", serde(rename = "basicBlackSquares"))]
     BasicBlackSquares,
-    #[serde(rename = "basicThinLines")]
+    #[cfg_attr(feature = "serde", serde(rename = "basicThinLines"))]
     BasicThinLines,
-    #[serde(rename = "basicWhiteDashes")]
+    #[cfg_attr(feature = "serde", serde(rename = "basicWhiteDashes"))]
     BasicWhiteDashes,
-    #[serde(rename = "basicWhiteDots")]
+    #[cfg_attr(feature = "serde", serde(rename = "basicWhiteDots"))]
     BasicWhiteDots,
-    #[serde(rename = "basicWhiteSquares")]
+    #[cfg_attr(feature = "serde", serde(rename = "basicWhiteSquares"))]
     BasicWhiteSquares,
-    #[serde(rename = "basicWideInline")]
+    #[cfg_attr(feature = "serde", serde(rename = "basicWideInline"))]
     BasicWideInline,
-    #[serde(rename = "basicWideMidline")]
+    #[cfg_attr(feature = "serde", serde(rename = "basicWideMidline"))]
     BasicWideMidline,
-    #[serde(rename = "basicWideOutline")]
+    #[cfg_attr(feature = "serde", serde(rename = "basicWideOutline"))]
     BasicWideOutline,
-    #[serde(rename = "bats")]
+    #[cfg_attr(feature = "serde", serde(rename = "bats"))]
     Bats,
-    #[serde(rename = "birds")]
+    #[cfg_attr(feature = "serde", serde(rename = "birds"))]
     Birds,
-    #[serde(rename = "birdsFlight")]
+    #[cfg_attr(feature = "serde", serde(rename = "birdsFlight"))]
     BirdsFlight,
-    #[serde(rename = "cabins")]
+    #[cfg_attr(feature = "serde", serde(rename = "cabins"))]
     Cabins,
-    #[serde(rename = "cakeSlice")]
+    #[cfg_attr(feature = "serde", serde(rename = "cakeSlice"))]
     CakeSlice,
-    #[serde(rename = "candyCorn")]
+    #[cfg_attr(feature = "serde", serde(rename = "candyCorn"))]
     CandyCorn,
-    #[serde(rename = "celticKnotwork")]
+    #[cfg_attr(feature = "serde", serde(rename = "celticKnotwork"))]
     CelticKnotwork,
-    #[serde(rename = "certificateBanner")]
+    #[cfg_attr(feature = "serde", serde(rename = "certificateBanner"))]
     CertificateBanner,
-    #[serde(rename = "chainLink")]
+    #[cfg_attr(feature = "serde", serde(rename = "chainLink"))]
     ChainLink,
-    #[serde(rename = "champagneBottle")]
+    #[cfg_attr(feature = "serde", serde(rename = "champagneBottle"))]
     ChampagneBottle,
-    #[serde(rename = "checkedBarBlack")]
+    #[cfg_attr(feature = "serde", serde(rename = "checkedBarBlack"))]
     CheckedBarBlack,
-    #[serde(rename = "checkedBarColor")]
+    #[cfg_attr(feature = "serde", serde(rename = "checkedBarColor"))]
     CheckedBarColor,
-    #[serde(rename = "checkered")]
+    #[cfg_attr(feature = "serde", serde(rename = "checkered"))]
     Checkered,
-    #[serde(rename = "christmasTree")]
+    #[cfg_attr(feature = "serde", serde(rename = "christmasTree"))]
     ChristmasTree,
-    #[serde(rename = "circlesLines")]
+    #[cfg_attr(feature = "serde", serde(rename = "circlesLines"))]
     CirclesLines,
-    #[serde(rename = "circlesRectangles")]
+    #[cfg_attr(feature = "serde", serde(rename = "circlesRectangles"))]
     CirclesRectangles,
-    #[serde(rename = "classicalWave")]
+    #[cfg_attr(feature = "serde", serde(rename = "classicalWave"))]
     ClassicalWave,
-    #[serde(rename = "clocks")]
+    #[cfg_attr(feature = "serde", serde(rename = "clocks"))]
     Clocks,
-    #[serde(rename = "compass")]
+    #[cfg_attr(feature = "serde", serde(rename = "compass"))]
     Compass,
-    #[serde(rename = "confetti")]
+    #[cfg_attr(feature = "serde", serde(rename = "confetti"))]
     Confetti,
-    #[serde(rename = "confettiGrays")]
+    #[cfg_attr(feature = "serde", serde(rename = "confettiGrays"))]
     ConfettiGrays,
-    #[serde(rename = "confettiOutline")]
+    #[cfg_attr(feature = "serde", serde(rename = "confettiOutline"))]
     ConfettiOutline,
-    #[serde(rename = "confettiStreamers")]
+    #[cfg_attr(feature = "serde", serde(rename = "confettiStreamers"))]
     ConfettiStreamers,
-    #[serde(rename = "confettiWhite")]
+    #[cfg_attr(feature = "serde", serde(rename = "confettiWhite"))]
     ConfettiWhite,
-    #[serde(rename = "cornerTriangles")]
+    #[cfg_attr(feature = "serde", serde(rename = "cornerTriangles"))]
     CornerTriangles,
-    #[serde(rename = "couponCutoutDashes")]
+    #[cfg_attr(feature = "serde", serde(rename = "couponCutoutDashes"))]
     CouponCutoutDashes,
-    #[serde(rename = "couponCutoutDots")]
+    #[cfg_attr(feature = "serde", serde(rename = "couponCutoutDots"))]
     CouponCutoutDots,
-    #[serde(rename = "crazyMaze")]
+    #[cfg_attr(feature = "serde", serde(rename = "crazyMaze"))]
     CrazyMaze,
-    #[serde(rename = "creaturesButterfly")]
+    #[cfg_attr(feature = "serde", serde(rename = "creaturesButterfly"))]
     CreaturesButterfly,
-    #[serde(rename = "creaturesFish")]
+    #[cfg_attr(feature = "serde", serde(rename = "creaturesFish"))]
     CreaturesFish,
-    #[serde(rename = "creaturesInsects")]
+    #[cfg_attr(feature = "serde", serde(rename = "creaturesInsects"))]
     CreaturesInsects,
-    #[serde(rename = "creaturesLadyBug")]
+    #[cfg_attr(feature = "serde", serde(rename = "creaturesLadyBug"))]
     CreaturesLadyBug,
-    #[serde(rename = "crossStitch")]
+    #[cfg_attr(feature = "serde", serde(rename = "crossStitch"))]
     CrossStitch,
-    #[serde(rename = "cup")]
+    #[cfg_attr(feature = "serde", serde(rename = "cup"))]
     Cup,
-    #[serde(rename = "decoArch")]
+    #[cfg_attr(feature = "serde", serde(rename = "decoArch"))]
     DecoArch,
-    #[serde(rename = "decoArchColor")]
+    #[cfg_attr(feature = "serde", serde(rename = "decoArchColor"))]
     DecoArchColor,
-    #[serde(rename = "decoBlocks")]
+    #[cfg_attr(feature = "serde", serde(rename = "decoBlocks"))]
     DecoBlocks,
-    #[serde(rename = "diamondsGray")]
+    #[cfg_attr(feature = "serde", serde(rename = "diamondsGray"))]
     DiamondsGray,
-    #[serde(rename = "doubleD")]
+    #[cfg_attr(feature = "serde", serde(rename = "doubleD"))]
     DoubleD,
-    #[serde(rename = "doubleDiamonds")]
+    #[cfg_attr(feature = "serde", serde(rename = "doubleDiamonds"))]
     DoubleDiamonds,
-    #[serde(rename = "earth1")]
+    #[cfg_attr(feature = "serde", serde(rename = "earth1"))]
     Earth1,
-    #[serde(rename = "earth2")]
+    #[cfg_attr(feature = "serde", serde(rename = "earth2"))]
     Earth2,
-    #[serde(rename = "earth3")]
+    #[cfg_attr(feature = "serde", serde(rename = "earth3"))]
     Earth3,
-    #[serde(rename = "eclipsingSquares1")]
+    #[cfg_attr(feature = "serde", serde(rename = "eclipsingSquares1"))]
     EclipsingSquares1,
-    #[serde(rename = "eclipsingSquares2")]
+    #[cfg_attr(feature = "serde", serde(rename = "eclipsingSquares2"))]
     EclipsingSquares2,
-    #[serde(rename = "eggsBlack")]
+    #[cfg_attr(feature = "serde", serde(rename = "eggsBlack"))]
     EggsBlack,
-    #[serde(rename = "fans")]
+    #[cfg_attr(feature = "serde", serde(rename = "fans"))]
     Fans,
-    #[serde(rename = "film")]
+    #[cfg_attr(feature = "serde", serde(rename = "film"))]
     Film,
-    #[serde(rename = "firecrackers")]
+    #[cfg_attr(feature = "serde", serde(rename = "firecrackers"))]
     Firecrackers,
-    #[serde(rename = "flowersBlockPrint")]
+    #[cfg_attr(feature = "serde", serde(rename = "flowersBlockPrint"))]
     FlowersBlockPrint,
-    #[serde(rename = "flowersDaisies")]
+    #[cfg_attr(feature = "serde", serde(rename = "flowersDaisies"))]
     FlowersDaisies,
-    #[serde(rename = "flowersModern1")]
+    #[cfg_attr(feature = "serde", serde(rename = "flowersModern1"))]
     FlowersModern1,
-    #[serde(rename = "flowersModern2")]
+    #[cfg_attr(feature = "serde", serde(rename = "flowersModern2"))]
     FlowersModern2,
-    #[serde(rename = "flowersPansy")]
+    #[cfg_attr(feature = "serde", serde(rename = "flowersPansy"))]
     FlowersPansy,
-    #[serde(rename = "flowersRedRose")]
+    #[cfg_attr(feature = "serde", serde(rename = "flowersRedRose"))]
     FlowersRedRose,
-    #[serde(rename = "flowersRoses")]
+    #[cfg_attr(feature = "serde", serde(rename = "flowersRoses"))]
     FlowersRoses,
-    #[serde(rename = "flowersTeacup")]
+    #[cfg_attr(feature = "serde", serde(rename = "flowersTeacup"))]
     FlowersTeacup,
-    #[serde(rename = "flowersTiny")]
+    #[cfg_attr(feature = "serde", serde(rename = "flowersTiny"))]
     FlowersTiny,
-    #[serde(rename = "gems")]
+    #[cfg_attr(feature = "serde", serde(rename = "gems"))]
     Gems,
-    #[serde(rename = "gingerbreadMan")]
+    #[cfg_attr(feature = "serde", serde(rename = "gingerbreadMan"))]
     GingerbreadMan,
-    #[serde(rename = "gradient")]
+    #[cfg_attr(feature = "serde", serde(rename = "gradient"))]
     Gradient,
-    #[serde(rename = "handmade1")]
+    #[cfg_attr(feature = "serde", serde(rename = "handmade1"))]
     Handmade1,
-    #[serde(rename = "handmade2")]
+    #[cfg_attr(feature = "serde", serde(rename = "handmade2"))]
     Handmade2,
-    #[serde(rename = "heartBalloon")]
+    #[cfg_attr(feature = "serde", serde(rename = "heartBalloon"))]
     HeartBalloon,
-    #[serde(rename = "heartGray")]
+    #[cfg_attr(feature = "serde", serde(rename = "heartGray"))]
     HeartGray,
-    #[serde(rename = "hearts")]
+    #[cfg_attr(feature = "serde", serde(rename = "hearts"))]
     Hearts,
-    #[serde(rename = "heebieJeebies")]
+    #[cfg_attr(feature = "serde", serde(rename = "heebieJeebies"))]
     HeebieJeebies,
-    #[serde(rename = "holly")]
+    #[cfg_attr(feature = "serde", serde(rename = "holly"))]
     Holly,
-    #[serde(rename = "houseFunky")]
+    #[cfg_attr(feature = "serde", serde(rename = "houseFunky"))]
     HouseFunky,
-    #[serde(rename = "hypnotic")]
+    #[cfg_attr(feature = "serde", serde(rename = "hypnotic"))]
     Hypnotic,
-    #[serde(rename = "iceCreamCones")]
+    #[cfg_attr(feature = "serde", serde(rename = "iceCreamCones"))]
     IceCreamCones,
-    #[serde(rename = "lightBulb")]
+    #[cfg_attr(feature = "serde", serde(rename = "lightBulb"))]
     LightBulb,
-    #[serde(rename = "lightning1")]
+    #[cfg_attr(feature = "serde", serde(rename = "lightning1"))]
     Lightning1,
-    #[serde(rename = "lightning2")]
+    #[cfg_attr(feature = "serde", serde(rename = "lightning2"))]
     Lightning2,
-    #[serde(rename = "mapPins")]
+    #[cfg_attr(feature = "serde", serde(rename = "mapPins"))]
     MapPins,
-    #[serde(rename = "mapleLeaf")]
+    #[cfg_attr(feature = "serde", serde(rename = "mapleLeaf"))]
     MapleLeaf,
-    #[serde(rename = "mapleMuffins")]
+    #[cfg_attr(feature = "serde", serde(rename = "mapleMuffins"))]
     MapleMuffins,
-    #[serde(rename = "marquee")]
+    #[cfg_attr(feature = "serde", serde(rename = "marquee"))]
     Marquee,
-    #[serde(rename = "marqueeToothed")]
+    #[cfg_attr(feature = "serde", serde(rename = "marqueeToothed"))]
     MarqueeToothed,
-    #[serde(rename = "moons")]
+    #[cfg_attr(feature = "serde", serde(rename = "moons"))]
     Moons,
-    #[serde(rename = "mosaic")]
+    #[cfg_attr(feature = "serde", serde(rename = "mosaic"))]
     Mosaic,
-    #[serde(rename = "musicNotes")]
+    #[cfg_attr(feature = "serde", serde(rename = "musicNotes"))]
     MusicNotes,
-    #[serde(rename = "northwest")]
+    #[cfg_attr(feature = "serde", serde(rename = "northwest"))]
     Northwest,
-    #[serde(rename = "ovals")]
+    #[cfg_attr(feature = "serde", serde(rename = "ovals"))]
     Ovals,
-    #[serde(rename = "packages")]
+    #[cfg_attr(feature = "serde", serde(rename = "packages"))]
     Packages,
-    #[serde(rename = "palmsBlack")]
+    #[cfg_attr(feature = "serde", serde(rename = "palmsBlack"))]
     PalmsBlack,
-    #[serde(rename = "palmsColor")]
+    #[cfg_attr(feature = "serde", serde(rename = "palmsColor"))]
     PalmsColor,
-    #[serde(rename = "paperClips")]
+    #[cfg_attr(feature = "serde", serde(rename = "paperClips"))]
     PaperClips,
-    #[serde(rename = "papyrus")]
+    #[cfg_attr(feature = "serde", serde(rename = "papyrus"))]
     Papyrus,
-    #[serde(rename = "partyFavor")]
+    #[cfg_attr(feature = "serde", serde(rename = "partyFavor"))]
     PartyFavor,
-    #[serde(rename = "partyGlass")]
+    #[cfg_attr(feature = "serde", serde(rename = "partyGlass"))]
     PartyGlass,
-    #[serde(rename = "pencils")]
+    #[cfg_attr(feature = "serde", serde(rename = "pencils"))]
     Pencils,
-    #[serde(rename = "people")]
+    #[cfg_attr(feature = "serde", serde(rename = "people"))]
     People,
-    #[serde(rename = "peopleWaving")]
+    #[cfg_attr(feature = "serde", serde(rename = "peopleWaving"))]
     PeopleWaving,
-    #[serde(rename = "peopleHats")]
+    #[cfg_attr(feature = "serde", serde(rename = "peopleHats"))]
     PeopleHats,
-    #[serde(rename = "poinsettias")]
+    #[cfg_attr(feature = "serde", serde(rename = "poinsettias"))]
     Poinsettias,
-    #[serde(rename = "postageStamp")]
+    #[cfg_attr(feature = "serde", serde(rename = "postageStamp"))]
     PostageStamp,
-    #[serde(rename = "pumpkin1")]
+    #[cfg_attr(feature = "serde", serde(rename = "pumpkin1"))]
     Pumpkin1,
-    #[serde(rename = "pushPinNote2")]
+    #[cfg_attr(feature = "serde", serde(rename = "pushPinNote2"))]
     PushPinNote2,
-    #[serde(rename = "pushPinNote1")]
+    #[cfg_attr(feature = "serde", serde(rename = "pushPinNote1"))]
     PushPinNote1,
-    #[serde(rename = "pyramids")]
+    #[cfg_attr(feature = "serde", serde(rename = "pyramids"))]
     Pyramids,
-    #[serde(rename = "pyramidsAbove")]
+    #[cfg_attr(feature = "serde", serde(rename = "pyramidsAbove"))]
     PyramidsAbove,
-    #[serde(rename = "quadrants")]
+    #[cfg_attr(feature = "serde", serde(rename = "quadrants"))]
     Quadrants,
-    #[serde(rename = "rings")]
+    #[cfg_attr(feature = "serde", serde(rename = "rings"))]
     Rings,
-    #[serde(rename = "safari")]
+    #[cfg_attr(feature = "serde", serde(rename = "safari"))]
     Safari,
-    #[serde(rename = "sawtooth")]
+    #[cfg_attr(feature = "serde", serde(rename = "sawtooth"))]
     Sawtooth,
-    #[serde(rename = "sawtoothGray")]
+    #[cfg_attr(feature = "serde", serde(rename = "sawtoothGray"))]
     SawtoothGray,
-    #[serde(rename = "scaredCat")]
+    #[cfg_attr(feature = "serde", serde(rename = "scaredCat"))]
     ScaredCat,
-    #[serde(rename = "seattle")]
+    #[cfg_attr(feature = "serde", serde(rename = "seattle"))]
     Seattle,
-    #[serde(rename = "shadowedSquares")]
+    #[cfg_attr(feature = "serde", serde(rename = "shadowedSquares"))]
     ShadowedSquares,
-    #[serde(rename = "sharksTeeth")]
+    #[cfg_attr(feature = "serde", serde(rename = "sharksTeeth"))]
     SharksTeeth,
-    #[serde(rename = "shorebirdTracks")]
+    #[cfg_attr(feature = "serde", serde(rename = "shorebirdTracks"))]
     ShorebirdTracks,
-    #[serde(rename = "skyrocket")]
+    #[cfg_attr(feature = "serde", serde(rename = "skyrocket"))]
     Skyrocket,
-    #[serde(rename = "snowflakeFancy")]
+    #[cfg_attr(feature = "serde", serde(rename = "snowflakeFancy"))]
     SnowflakeFancy,
-    #[serde(rename = "snowflakes")]
+    #[cfg_attr(feature = "serde", serde(rename = "snowflakes"))]
     Snowflakes,
-    #[serde(rename = "sombrero")]
+    #[cfg_attr(feature = "serde", serde(rename = "sombrero"))]
     Sombrero,
-    #[serde(rename = "southwest")]
+    #[cfg_attr(feature = "serde", serde(rename = "southwest"))]
     Southwest,
-    #[serde(rename = "stars")]
+    #[cfg_attr(feature = "serde", serde(rename = "stars"))]
     Stars,
-    #[serde(rename = "starsTop")]
+    #[cfg_attr(feature = "serde", serde(rename = "starsTop"))]
     StarsTop,
-    #[serde(rename = "stars3d")]
+    #[cfg_attr(feature = "serde", serde(rename = "stars3d"))]
     Stars3d,
-    #[serde(rename = "starsBlack")]
+    #[cfg_attr(feature = "serde", serde(rename = "starsBlack"))]
     StarsBlack,
-    #[serde(rename = "starsShadowed")]
+    #[cfg_attr(feature = "serde", serde(rename = "starsShadowed"))]
     StarsShadowed,
-    #[serde(rename = "sun")]
+    #[cfg_attr(feature = "serde", serde(rename = "sun"))]
     Sun,
-    #[serde(rename = "swirligig")]
+    #[cfg_attr(feature = "serde", serde(rename = "swirligig"))]
     Swirligig,
-    #[serde(rename = "tornPaper")]
+    #[cfg_attr(feature = "serde", serde(rename = "tornPaper"))]
     TornPaper,
-    #[serde(rename = "tornPaperBlack")]
+    #[cfg_attr(feature = "serde", serde(rename = "tornPaperBlack"))]
     TornPaperBlack,
-    #[serde(rename = "trees")]
+    #[cfg_attr(feature = "serde", serde(rename = "trees"))]
     Trees,
-    #[serde(rename = "triangleParty")]
+    #[cfg_attr(feature = "serde", serde(rename = "triangleParty"))]
     TriangleParty,
-    #[serde(rename = "triangles")]
+    #[cfg_attr(feature = "serde", serde(rename = "triangles"))]
     Triangles,
-    #[serde(rename = "triangle1")]
+    #[cfg_attr(feature = "serde", serde(rename = "triangle1"))]
     Triangle1,
-    #[serde(rename = "triangle2")]
+    #[cfg_attr(feature = "serde", serde(rename = "triangle2"))]
     Triangle2,
-    #[serde(rename = "triangleCircle1")]
+    #[cfg_attr(feature = "serde", serde(rename = "triangleCircle1"))]
     TriangleCircle1,
-    #[serde(rename = "triangleCircle2")]
+    #[cfg_attr(feature = "serde", serde(rename = "triangleCircle2"))]
     TriangleCircle2,
-    #[serde(rename = "shapes1")]
+    #[cfg_attr(feature = "serde", serde(rename = "shapes1"))]
     Shapes1,
-    #[serde(rename = "shapes2")]
+    #[cfg_attr(feature = "serde", serde(rename = "shapes2"))]
     Shapes2,
-    #[serde(rename = "twistedLines1")]
+    #[cfg_attr(feature = "serde", serde(rename = "twistedLines1"))]
     TwistedLines1,
-    #[serde(rename = "twistedLines2")]
+    #[cfg_attr(feature = "serde", serde(rename = "twistedLines2"))]
     TwistedLines2,
-    #[serde(rename = "vine")]
+    #[cfg_attr(feature = "serde", serde(rename = "vine"))]
     Vine,
-    #[serde(rename = "waveline")]
+    #[cfg_attr(feature = "serde", serde(rename = "waveline"))]
     Waveline,
-    #[serde(rename = "weavingAngles")]
+    #[cfg_attr(feature = "serde", serde(rename = "weavingAngles"))]
     WeavingAngles,
-    #[serde(rename = "weavingBraid")]
+    #[cfg_attr(feature = "serde", serde(rename = "weavingBraid"))]
     WeavingBraid,
-    #[serde(rename = "weavingRibbon")]
+    #[cfg_attr(feature = "serde", serde(rename = "weavingRibbon"))]
     WeavingRibbon,
-    #[serde(rename = "weavingStrips")]
+    #[cfg_attr(feature = "serde", serde(rename = "weavingStrips"))]
     WeavingStrips,
-    #[serde(rename = "whiteFlowers")]
+    #[cfg_attr(feature = "serde", serde(rename = "whiteFlowers"))]
     WhiteFlowers,
-    #[serde(rename = "woodwork")]
+    #[cfg_attr(feature = "serde", serde(rename = "woodwork"))]
     Woodwork,
-    #[serde(rename = "xIllusions")]
+    #[cfg_attr(feature = "serde", serde(rename = "xIllusions"))]
     XIllusions,
-    #[serde(rename = "zanyTriangles")]
+    #[cfg_attr(feature = "serde", serde(rename = "zanyTriangles"))]
     ZanyTriangles,
-    #[serde(rename = "zigZag")]
+    #[cfg_attr(feature = "serde", serde(rename = "zigZag"))]
     ZigZag,
-    #[serde(rename = "zigZagStitch")]
+    #[cfg_attr(feature = "serde", serde(rename = "zigZagStitch"))]
     ZigZagStitch,
-    #[serde(rename = "custom")]
+    #[cfg_attr(feature = "serde", serde(rename = "custom"))]
     Custom,
 }
 
@@ -1548,83 +1564,84 @@ impl std::str::FromStr for STBorder {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STShd {
-    #[serde(rename = "nil")]
+    #[cfg_attr(feature = "serde", serde(rename = "nil"))]
     Nil,
-    #[serde(rename = "clear")]
+    #[cfg_attr(feature = "serde", serde(rename = "clear"))]
     Clear,
-    #[serde(rename = "solid")]
+    #[cfg_attr(feature = "serde", serde(rename = "solid"))]
     Solid,
-    #[serde(rename = "horzStripe")]
+    #[cfg_attr(feature = "serde", serde(rename = "horzStripe"))]
     HorzStripe,
-    #[serde(rename = "vertStripe")]
+    #[cfg_attr(feature = "serde", serde(rename = "vertStripe"))]
     VertStripe,
-    #[serde(rename = "reverseDiagStripe")]
+    #[cfg_attr(feature = "serde", serde(rename = "reverseDiagStripe"))]
     ReverseDiagStripe,
-    #[serde(rename = "diagStripe")]
+    #[cfg_attr(feature = "serde", serde(rename = "diagStripe"))]
     DiagStripe,
-    #[serde(rename = "horzCross")]
+    #[cfg_attr(feature = "serde", serde(rename = "horzCross"))]
     HorzCross,
-    #[serde(rename = "diagCross")]
+    #[cfg_attr(feature = "serde", serde(rename = "diagCross"))]
     DiagCross,
-    #[serde(rename = "thinHorzStripe")]
+    #[cfg_attr(feature = "serde", serde(rename = "thinHorzStripe"))]
     ThinHorzStripe,
-    #[serde(rename = "thinVertStripe")]
+    #[cfg_attr(feature = "serde", serde(rename = "thinVertStripe"))]
     ThinVertStripe,
-    #[serde(rename = "thinReverseDiagStripe")]
+    #[cfg_attr(feature = "serde", serde(rename = "thinReverseDiagStripe"))]
     ThinReverseDiagStripe,
-    #[serde(rename = "thinDiagStripe")]
+    #[cfg_attr(feature = "serde", serde(rename = "thinDiagStripe"))]
     ThinDiagStripe,
-    #[serde(rename = "thinHorzCross")]
+    #[cfg_attr(feature = "serde", serde(rename = "thinHorzCross"))]
     ThinHorzCross,
-    #[serde(rename = "thinDiagCross")]
+    #[cfg_attr(feature = "serde", serde(rename = "thinDiagCross"))]
     ThinDiagCross,
-    #[serde(rename = "pct5")]
+    #[cfg_attr(feature = "serde", serde(rename = "pct5"))]
     Pct5,
-    #[serde(rename = "pct10")]
+    #[cfg_attr(feature = "serde", serde(rename = "pct10"))]
     Pct10,
-    #[serde(rename = "pct12")]
+    #[cfg_attr(feature = "serde", serde(rename = "pct12"))]
     Pct12,
-    #[serde(rename = "pct15")]
+    #[cfg_attr(feature = "serde", serde(rename = "pct15"))]
     Pct15,
-    #[serde(rename = "pct20")]
+    #[cfg_attr(feature = "serde", serde(rename = "pct20"))]
     Pct20,
-    #[serde(rename = "pct25")]
+    #[cfg_attr(feature = "serde", serde(rename = "pct25"))]
     Pct25,
-    #[serde(rename = "pct30")]
+    #[cfg_attr(feature = "serde", serde(rename = "pct30"))]
     Pct30,
-    #[serde(rename = "pct35")]
+    #[cfg_attr(feature = "serde", serde(rename = "pct35"))]
     Pct35,
-    #[serde(rename = "pct37")]
+    #[cfg_attr(feature = "serde", serde(rename = "pct37"))]
     Pct37,
-    #[serde(rename = "pct40")]
+    #[cfg_attr(feature = "serde", serde(rename = "pct40"))]
     Pct40,
-    #[serde(rename = "pct45")]
+    #[cfg_attr(feature = "serde", serde(rename = "pct45"))]
     Pct45,
-    #[serde(rename = "pct50")]
+    #[cfg_attr(feature = "serde", serde(rename = "pct50"))]
     Pct50,
-    #[serde(rename = "pct55")]
+    #[cfg_attr(feature = "serde", serde(rename = "pct55"))]
     Pct55,
-    #[serde(rename = "pct60")]
+    #[cfg_attr(feature = "serde", serde(rename = "pct60"))]
     Pct60,
-    #[serde(rename = "pct62")]
+    #[cfg_attr(feature = "serde", serde(rename = "pct62"))]
     Pct62,
-    #[serde(rename = "pct65")]
+    #[cfg_attr(feature = "serde", serde(rename = "pct65"))]
     Pct65,
-    #[serde(rename = "pct70")]
+    #[cfg_attr(feature = "serde", serde(rename = "pct70"))]
     Pct70,
-    #[serde(rename = "pct75")]
+    #[cfg_attr(feature = "serde", serde(rename = "pct75"))]
     Pct75,
-    #[serde(rename = "pct80")]
+    #[cfg_attr(feature = "serde", serde(rename = "pct80"))]
     Pct80,
-    #[serde(rename = "pct85")]
+    #[cfg_attr(feature = "serde", serde(rename = "pct85"))]
     Pct85,
-    #[serde(rename = "pct87")]
+    #[cfg_attr(feature = "serde", serde(rename = "pct87"))]
     Pct87,
-    #[serde(rename = "pct90")]
+    #[cfg_attr(feature = "serde", serde(rename = "pct90"))]
     Pct90,
-    #[serde(rename = "pct95")]
+    #[cfg_attr(feature = "serde", serde(rename = "pct95"))]
     Pct95,
 }
 
@@ -1721,17 +1738,18 @@ impl std::str::FromStr for STShd {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STEm {
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
-    #[serde(rename = "dot")]
+    #[cfg_attr(feature = "serde", serde(rename = "dot"))]
     Dot,
-    #[serde(rename = "comma")]
+    #[cfg_attr(feature = "serde", serde(rename = "comma"))]
     Comma,
-    #[serde(rename = "circle")]
+    #[cfg_attr(feature = "serde", serde(rename = "circle"))]
     Circle,
-    #[serde(rename = "underDot")]
+    #[cfg_attr(feature = "serde", serde(rename = "underDot"))]
     UnderDot,
 }
 
@@ -1762,17 +1780,18 @@ impl std::str::FromStr for STEm {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STCombineBrackets {
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
-    #[serde(rename = "round")]
+    #[cfg_attr(feature = "serde", serde(rename = "round"))]
     Round,
-    #[serde(rename = "square")]
+    #[cfg_attr(feature = "serde", serde(rename = "square"))]
     Square,
-    #[serde(rename = "angle")]
+    #[cfg_attr(feature = "serde", serde(rename = "angle"))]
     Angle,
-    #[serde(rename = "curly")]
+    #[cfg_attr(feature = "serde", serde(rename = "curly"))]
     Curly,
 }
 
@@ -1803,13 +1822,14 @@ impl std::str::FromStr for STCombineBrackets {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STHeightRule {
-    #[serde(rename = "auto")]
+    #[cfg_attr(feature = "serde", serde(rename = "auto"))]
     Auto,
-    #[serde(rename = "exact")]
+    #[cfg_attr(feature = "serde", serde(rename = "exact"))]
     Exact,
-    #[serde(rename = "atLeast")]
+    #[cfg_attr(feature = "serde", serde(rename = "atLeast"))]
     AtLeast,
 }
 
@@ -1836,19 +1856,20 @@ impl std::str::FromStr for STHeightRule {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STWrap {
-    #[serde(rename = "auto")]
+    #[cfg_attr(feature = "serde", serde(rename = "auto"))]
     Auto,
-    #[serde(rename = "notBeside")]
+    #[cfg_attr(feature = "serde", serde(rename = "notBeside"))]
     NotBeside,
-    #[serde(rename = "around")]
+    #[cfg_attr(feature = "serde", serde(rename = "around"))]
     Around,
-    #[serde(rename = "tight")]
+    #[cfg_attr(feature = "serde", serde(rename = "tight"))]
     Tight,
-    #[serde(rename = "through")]
+    #[cfg_attr(feature = "serde", serde(rename = "through"))]
     Through,
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
 }
 
@@ -1881,13 +1902,14 @@ impl std::str::FromStr for STWrap {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STVAnchor {
-    #[serde(rename = "text")]
+    #[cfg_attr(feature = "serde", serde(rename = "text"))]
     Text,
-    #[serde(rename = "margin")]
+    #[cfg_attr(feature = "serde", serde(rename = "margin"))]
     Margin,
-    #[serde(rename = "page")]
+    #[cfg_attr(feature = "serde", serde(rename = "page"))]
     Page,
 }
 
@@ -1914,13 +1936,14 @@ impl std::str::FromStr for STVAnchor {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STHAnchor {
-    #[serde(rename = "text")]
+    #[cfg_attr(feature = "serde", serde(rename = "text"))]
     Text,
-    #[serde(rename = "margin")]
+    #[cfg_attr(feature = "serde", serde(rename = "margin"))]
     Margin,
-    #[serde(rename = "page")]
+    #[cfg_attr(feature = "serde", serde(rename = "page"))]
     Page,
 }
 
@@ -1947,13 +1970,14 @@ impl std::str::FromStr for STHAnchor {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STDropCap {
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
-    #[serde(rename = "drop")]
+    #[cfg_attr(feature = "serde", serde(rename = "drop"))]
     Drop,
-    #[serde(rename = "margin")]
+    #[cfg_attr(feature = "serde", serde(rename = "margin"))]
     Margin,
 }
 
@@ -1980,25 +2004,26 @@ impl std::str::FromStr for STDropCap {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTabJc {
-    #[serde(rename = "clear")]
+    #[cfg_attr(feature = "serde", serde(rename = "clear"))]
     Clear,
-    #[serde(rename = "start")]
+    #[cfg_attr(feature = "serde", serde(rename = "start"))]
     Start,
-    #[serde(rename = "center")]
+    #[cfg_attr(feature = "serde", serde(rename = "center"))]
     Center,
-    #[serde(rename = "end")]
+    #[cfg_attr(feature = "serde", serde(rename = "end"))]
     End,
-    #[serde(rename = "decimal")]
+    #[cfg_attr(feature = "serde", serde(rename = "decimal"))]
     Decimal,
-    #[serde(rename = "bar")]
+    #[cfg_attr(feature = "serde", serde(rename = "bar"))]
     Bar,
-    #[serde(rename = "num")]
+    #[cfg_attr(feature = "serde", serde(rename = "num"))]
     Num,
-    #[serde(rename = "left")]
+    #[cfg_attr(feature = "serde", serde(rename = "left"))]
     Left,
-    #[serde(rename = "right")]
+    #[cfg_attr(feature = "serde", serde(rename = "right"))]
     Right,
 }
 
@@ -2037,19 +2062,20 @@ impl std::str::FromStr for STTabJc {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTabTlc {
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
-    #[serde(rename = "dot")]
+    #[cfg_attr(feature = "serde", serde(rename = "dot"))]
     Dot,
-    #[serde(rename = "hyphen")]
+    #[cfg_attr(feature = "serde", serde(rename = "hyphen"))]
     Hyphen,
-    #[serde(rename = "underscore")]
+    #[cfg_attr(feature = "serde", serde(rename = "underscore"))]
     Underscore,
-    #[serde(rename = "heavy")]
+    #[cfg_attr(feature = "serde", serde(rename = "heavy"))]
     Heavy,
-    #[serde(rename = "middleDot")]
+    #[cfg_attr(feature = "serde", serde(rename = "middleDot"))]
     MiddleDot,
 }
 
@@ -2082,13 +2108,14 @@ impl std::str::FromStr for STTabTlc {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STLineSpacingRule {
-    #[serde(rename = "auto")]
+    #[cfg_attr(feature = "serde", serde(rename = "auto"))]
     Auto,
-    #[serde(rename = "exact")]
+    #[cfg_attr(feature = "serde", serde(rename = "exact"))]
     Exact,
-    #[serde(rename = "atLeast")]
+    #[cfg_attr(feature = "serde", serde(rename = "atLeast"))]
     AtLeast,
 }
 
@@ -2115,31 +2142,32 @@ impl std::str::FromStr for STLineSpacingRule {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STJc {
-    #[serde(rename = "start")]
+    #[cfg_attr(feature = "serde", serde(rename = "start"))]
     Start,
-    #[serde(rename = "center")]
+    #[cfg_attr(feature = "serde", serde(rename = "center"))]
     Center,
-    #[serde(rename = "end")]
+    #[cfg_attr(feature = "serde", serde(rename = "end"))]
     End,
-    #[serde(rename = "both")]
+    #[cfg_attr(feature = "serde", serde(rename = "both"))]
     Both,
-    #[serde(rename = "mediumKashida")]
+    #[cfg_attr(feature = "serde", serde(rename = "mediumKashida"))]
     MediumKashida,
-    #[serde(rename = "distribute")]
+    #[cfg_attr(feature = "serde", serde(rename = "distribute"))]
     Distribute,
-    #[serde(rename = "numTab")]
+    #[cfg_attr(feature = "serde", serde(rename = "numTab"))]
     NumTab,
-    #[serde(rename = "highKashida")]
+    #[cfg_attr(feature = "serde", serde(rename = "highKashida"))]
     HighKashida,
-    #[serde(rename = "lowKashida")]
+    #[cfg_attr(feature = "serde", serde(rename = "lowKashida"))]
     LowKashida,
-    #[serde(rename = "thaiDistribute")]
+    #[cfg_attr(feature = "serde", serde(rename = "thaiDistribute"))]
     ThaiDistribute,
-    #[serde(rename = "left")]
+    #[cfg_attr(feature = "serde", serde(rename = "left"))]
     Left,
-    #[serde(rename = "right")]
+    #[cfg_attr(feature = "serde", serde(rename = "right"))]
     Right,
 }
 
@@ -2184,17 +2212,18 @@ impl std::str::FromStr for STJc {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STJcTable {
-    #[serde(rename = "center")]
+    #[cfg_attr(feature = "serde", serde(rename = "center"))]
     Center,
-    #[serde(rename = "end")]
+    #[cfg_attr(feature = "serde", serde(rename = "end"))]
     End,
-    #[serde(rename = "left")]
+    #[cfg_attr(feature = "serde", serde(rename = "left"))]
     Left,
-    #[serde(rename = "right")]
+    #[cfg_attr(feature = "serde", serde(rename = "right"))]
     Right,
-    #[serde(rename = "start")]
+    #[cfg_attr(feature = "serde", serde(rename = "start"))]
     Start,
 }
 
@@ -2225,19 +2254,20 @@ impl std::str::FromStr for STJcTable {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STView {
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
-    #[serde(rename = "print")]
+    #[cfg_attr(feature = "serde", serde(rename = "print"))]
     Print,
-    #[serde(rename = "outline")]
+    #[cfg_attr(feature = "serde", serde(rename = "outline"))]
     Outline,
-    #[serde(rename = "masterPages")]
+    #[cfg_attr(feature = "serde", serde(rename = "masterPages"))]
     MasterPages,
-    #[serde(rename = "normal")]
+    #[cfg_attr(feature = "serde", serde(rename = "normal"))]
     Normal,
-    #[serde(rename = "web")]
+    #[cfg_attr(feature = "serde", serde(rename = "web"))]
     Web,
 }
 
@@ -2270,15 +2300,16 @@ impl std::str::FromStr for STView {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STZoom {
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
-    #[serde(rename = "fullPage")]
+    #[cfg_attr(feature = "serde", serde(rename = "fullPage"))]
     FullPage,
-    #[serde(rename = "bestFit")]
+    #[cfg_attr(feature = "serde", serde(rename = "bestFit"))]
     BestFit,
-    #[serde(rename = "textFit")]
+    #[cfg_attr(feature = "serde", serde(rename = "textFit"))]
     TextFit,
 }
 
@@ -2307,11 +2338,12 @@ impl std::str::FromStr for STZoom {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STProof {
-    #[serde(rename = "clean")]
+    #[cfg_attr(feature = "serde", serde(rename = "clean"))]
     Clean,
-    #[serde(rename = "dirty")]
+    #[cfg_attr(feature = "serde", serde(rename = "dirty"))]
     Dirty,
 }
 
@@ -2338,17 +2370,18 @@ impl std::str::FromStr for STProof {
 
 pub type STDocType = String;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STDocProtect {
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
-    #[serde(rename = "readOnly")]
+    #[cfg_attr(feature = "serde", serde(rename = "readOnly"))]
     ReadOnly,
-    #[serde(rename = "comments")]
+    #[cfg_attr(feature = "serde", serde(rename = "comments"))]
     Comments,
-    #[serde(rename = "trackedChanges")]
+    #[cfg_attr(feature = "serde", serde(rename = "trackedChanges"))]
     TrackedChanges,
-    #[serde(rename = "forms")]
+    #[cfg_attr(feature = "serde", serde(rename = "forms"))]
     Forms,
 }
 
@@ -2379,19 +2412,20 @@ impl std::str::FromStr for STDocProtect {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STMailMergeDocType {
-    #[serde(rename = "catalog")]
+    #[cfg_attr(feature = "serde", serde(rename = "catalog"))]
     Catalog,
-    #[serde(rename = "envelopes")]
+    #[cfg_attr(feature = "serde", serde(rename = "envelopes"))]
     Envelopes,
-    #[serde(rename = "mailingLabels")]
+    #[cfg_attr(feature = "serde", serde(rename = "mailingLabels"))]
     MailingLabels,
-    #[serde(rename = "formLetters")]
+    #[cfg_attr(feature = "serde", serde(rename = "formLetters"))]
     FormLetters,
-    #[serde(rename = "email")]
+    #[cfg_attr(feature = "serde", serde(rename = "email"))]
     Email,
-    #[serde(rename = "fax")]
+    #[cfg_attr(feature = "serde", serde(rename = "fax"))]
     Fax,
 }
 
@@ -2426,15 +2460,16 @@ impl std::str::FromStr for STMailMergeDocType {
 
 pub type STMailMergeDataType = String;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STMailMergeDest {
-    #[serde(rename = "newDocument")]
+    #[cfg_attr(feature = "serde", serde(rename = "newDocument"))]
     NewDocument,
-    #[serde(rename = "printer")]
+    #[cfg_attr(feature = "serde", serde(rename = "printer"))]
     Printer,
-    #[serde(rename = "email")]
+    #[cfg_attr(feature = "serde", serde(rename = "email"))]
     Email,
-    #[serde(rename = "fax")]
+    #[cfg_attr(feature = "serde", serde(rename = "fax"))]
     Fax,
 }
 
@@ -2463,11 +2498,12 @@ impl std::str::FromStr for STMailMergeDest {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STMailMergeOdsoFMDFieldType {
-    #[serde(rename = "null")]
+    #[cfg_attr(feature = "serde", serde(rename = "null"))]
     Null,
-    #[serde(rename = "dbColumn")]
+    #[cfg_attr(feature = "serde", serde(rename = "dbColumn"))]
     DbColumn,
 }
 
@@ -2492,31 +2528,32 @@ impl std::str::FromStr for STMailMergeOdsoFMDFieldType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTextDirection {
-    #[serde(rename = "tb")]
+    #[cfg_attr(feature = "serde", serde(rename = "tb"))]
     Tb,
-    #[serde(rename = "rl")]
+    #[cfg_attr(feature = "serde", serde(rename = "rl"))]
     Rl,
-    #[serde(rename = "lr")]
+    #[cfg_attr(feature = "serde", serde(rename = "lr"))]
     Lr,
-    #[serde(rename = "tbV")]
+    #[cfg_attr(feature = "serde", serde(rename = "tbV"))]
     TbV,
-    #[serde(rename = "rlV")]
+    #[cfg_attr(feature = "serde", serde(rename = "rlV"))]
     RlV,
-    #[serde(rename = "lrV")]
+    #[cfg_attr(feature = "serde", serde(rename = "lrV"))]
     LrV,
-    #[serde(rename = "btLr")]
+    #[cfg_attr(feature = "serde", serde(rename = "btLr"))]
     BtLr,
-    #[serde(rename = "lrTb")]
+    #[cfg_attr(feature = "serde", serde(rename = "lrTb"))]
     LrTb,
-    #[serde(rename = "lrTbV")]
+    #[cfg_attr(feature = "serde", serde(rename = "lrTbV"))]
     LrTbV,
-    #[serde(rename = "tbLrV")]
+    #[cfg_attr(feature = "serde", serde(rename = "tbLrV"))]
     TbLrV,
-    #[serde(rename = "tbRl")]
+    #[cfg_attr(feature = "serde", serde(rename = "tbRl"))]
     TbRl,
-    #[serde(rename = "tbRlV")]
+    #[cfg_attr(feature = "serde", serde(rename = "tbRlV"))]
     TbRlV,
 }
 
@@ -2561,17 +2598,18 @@ impl std::str::FromStr for STTextDirection {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTextAlignment {
-    #[serde(rename = "top")]
+    #[cfg_attr(feature = "serde", serde(rename = "top"))]
     Top,
-    #[serde(rename = "center")]
+    #[cfg_attr(feature = "serde", serde(rename = "center"))]
     Center,
-    #[serde(rename = "baseline")]
+    #[cfg_attr(feature = "serde", serde(rename = "baseline"))]
     Baseline,
-    #[serde(rename = "bottom")]
+    #[cfg_attr(feature = "serde", serde(rename = "bottom"))]
     Bottom,
-    #[serde(rename = "auto")]
+    #[cfg_attr(feature = "serde", serde(rename = "auto"))]
     Auto,
 }
 
@@ -2602,11 +2640,12 @@ impl std::str::FromStr for STTextAlignment {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STDisplacedByCustomXml {
-    #[serde(rename = "next")]
+    #[cfg_attr(feature = "serde", serde(rename = "next"))]
     Next,
-    #[serde(rename = "prev")]
+    #[cfg_attr(feature = "serde", serde(rename = "prev"))]
     Prev,
 }
 
@@ -2631,11 +2670,12 @@ impl std::str::FromStr for STDisplacedByCustomXml {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STAnnotationVMerge {
-    #[serde(rename = "cont")]
+    #[cfg_attr(feature = "serde", serde(rename = "cont"))]
     Cont,
-    #[serde(rename = "rest")]
+    #[cfg_attr(feature = "serde", serde(rename = "rest"))]
     Rest,
 }
 
@@ -2660,17 +2700,18 @@ impl std::str::FromStr for STAnnotationVMerge {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTextboxTightWrap {
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
-    #[serde(rename = "allLines")]
+    #[cfg_attr(feature = "serde", serde(rename = "allLines"))]
     AllLines,
-    #[serde(rename = "firstAndLastLine")]
+    #[cfg_attr(feature = "serde", serde(rename = "firstAndLastLine"))]
     FirstAndLastLine,
-    #[serde(rename = "firstLineOnly")]
+    #[cfg_attr(feature = "serde", serde(rename = "firstLineOnly"))]
     FirstLineOnly,
-    #[serde(rename = "lastLineOnly")]
+    #[cfg_attr(feature = "serde", serde(rename = "lastLineOnly"))]
     LastLineOnly,
 }
 
@@ -2701,11 +2742,12 @@ impl std::str::FromStr for STTextboxTightWrap {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STObjectDrawAspect {
-    #[serde(rename = "content")]
+    #[cfg_attr(feature = "serde", serde(rename = "content"))]
     Content,
-    #[serde(rename = "icon")]
+    #[cfg_attr(feature = "serde", serde(rename = "icon"))]
     Icon,
 }
 
@@ -2730,11 +2772,12 @@ impl std::str::FromStr for STObjectDrawAspect {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STObjectUpdateMode {
-    #[serde(rename = "always")]
+    #[cfg_attr(feature = "serde", serde(rename = "always"))]
     Always,
-    #[serde(rename = "onCall")]
+    #[cfg_attr(feature = "serde", serde(rename = "onCall"))]
     OnCall,
 }
 
@@ -2759,13 +2802,14 @@ impl std::str::FromStr for STObjectUpdateMode {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STFldCharType {
-    #[serde(rename = "begin")]
+    #[cfg_attr(feature = "serde", serde(rename = "begin"))]
     Begin,
-    #[serde(rename = "separate")]
+    #[cfg_attr(feature = "serde", serde(rename = "separate"))]
     Separate,
-    #[serde(rename = "end")]
+    #[cfg_attr(feature = "serde", serde(rename = "end"))]
     End,
 }
 
@@ -2792,11 +2836,12 @@ impl std::str::FromStr for STFldCharType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STInfoTextType {
-    #[serde(rename = "text")]
+    #[cfg_attr(feature = "serde", serde(rename = "text"))]
     Text,
-    #[serde(rename = "autoText")]
+    #[cfg_attr(feature = "serde", serde(rename = "autoText"))]
     AutoText,
 }
 
@@ -2827,19 +2872,20 @@ pub type STFFStatusTextVal = String;
 
 pub type STFFName = String;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STFFTextType {
-    #[serde(rename = "regular")]
+    #[cfg_attr(feature = "serde", serde(rename = "regular"))]
     Regular,
-    #[serde(rename = "number")]
+    #[cfg_attr(feature = "serde", serde(rename = "number"))]
     Number,
-    #[serde(rename = "date")]
+    #[cfg_attr(feature = "serde", serde(rename = "date"))]
     Date,
-    #[serde(rename = "currentTime")]
+    #[cfg_attr(feature = "serde", serde(rename = "currentTime"))]
     CurrentTime,
-    #[serde(rename = "currentDate")]
+    #[cfg_attr(feature = "serde", serde(rename = "currentDate"))]
     CurrentDate,
-    #[serde(rename = "calculated")]
+    #[cfg_attr(feature = "serde", serde(rename = "calculated"))]
     Calculated,
 }
 
@@ -2872,17 +2918,18 @@ impl std::str::FromStr for STFFTextType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STSectionMark {
-    #[serde(rename = "nextPage")]
+    #[cfg_attr(feature = "serde", serde(rename = "nextPage"))]
     NextPage,
-    #[serde(rename = "nextColumn")]
+    #[cfg_attr(feature = "serde", serde(rename = "nextColumn"))]
     NextColumn,
-    #[serde(rename = "continuous")]
+    #[cfg_attr(feature = "serde", serde(rename = "continuous"))]
     Continuous,
-    #[serde(rename = "evenPage")]
+    #[cfg_attr(feature = "serde", serde(rename = "evenPage"))]
     EvenPage,
-    #[serde(rename = "oddPage")]
+    #[cfg_attr(feature = "serde", serde(rename = "oddPage"))]
     OddPage,
 }
 
@@ -2913,133 +2960,134 @@ impl std::str::FromStr for STSectionMark {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STNumberFormat {
-    #[serde(rename = "decimal")]
+    #[cfg_attr(feature = "serde", serde(rename = "decimal"))]
     Decimal,
-    #[serde(rename = "upperRoman")]
+    #[cfg_attr(feature = "serde", serde(rename = "upperRoman"))]
     UpperRoman,
-    #[serde(rename = "lowerRoman")]
+    #[cfg_attr(feature = "serde", serde(rename = "lowerRoman"))]
     LowerRoman,
-    #[serde(rename = "upperLetter")]
+    #[cfg_attr(feature = "serde", serde(rename = "upperLetter"))]
     UpperLetter,
-    #[serde(rename = "lowerLetter")]
+    #[cfg_attr(feature = "serde", serde(rename = "lowerLetter"))]
     LowerLetter,
-    #[serde(rename = "ordinal")]
+    #[cfg_attr(feature = "serde", serde(rename = "ordinal"))]
     Ordinal,
-    #[serde(rename = "cardinalText")]
+    #[cfg_attr(feature = "serde", serde(rename = "cardinalText"))]
     CardinalText,
-    #[serde(rename = "ordinalText")]
+    #[cfg_attr(feature = "serde", serde(rename = "ordinalText"))]
     OrdinalText,
-    #[serde(rename = "hex")]
+    #[cfg_attr(feature = "serde", serde(rename = "hex"))]
     Hex,
-    #[serde(rename = "chicago")]
+    #[cfg_attr(feature = "serde", serde(rename = "chicago"))]
     Chicago,
-    #[serde(rename = "ideographDigital")]
+    #[cfg_attr(feature = "serde", serde(rename = "ideographDigital"))]
     IdeographDigital,
-    #[serde(rename = "japaneseCounting")]
+    #[cfg_attr(feature = "serde", serde(rename = "japaneseCounting"))]
     JapaneseCounting,
-    #[serde(rename = "aiueo")]
+    #[cfg_attr(feature = "serde", serde(rename = "aiueo"))]
     Aiueo,
-    #[serde(rename = "iroha")]
+    #[cfg_attr(feature = "serde", serde(rename = "iroha"))]
     Iroha,
-    #[serde(rename = "decimalFullWidth")]
+    #[cfg_attr(feature = "serde", serde(rename = "decimalFullWidth"))]
     DecimalFullWidth,
-    #[serde(rename = "decimalHalfWidth")]
+    #[cfg_attr(feature = "serde", serde(rename = "decimalHalfWidth"))]
     DecimalHalfWidth,
-    #[serde(rename = "japaneseLegal")]
+    #[cfg_attr(feature = "serde", serde(rename = "japaneseLegal"))]
     JapaneseLegal,
-    #[serde(rename = "japaneseDigitalTenThousand")]
+    #[cfg_attr(feature = "serde", serde(rename = "japaneseDigitalTenThousand"))]
     JapaneseDigitalTenThousand,
-    #[serde(rename = "decimalEnclosedCircle")]
+    #[cfg_attr(feature = "serde", serde(rename = "decimalEnclosedCircle"))]
     DecimalEnclosedCircle,
-    #[serde(rename = "decimalFullWidth2")]
+    #[cfg_attr(feature = "serde", serde(rename = "decimalFullWidth2"))]
     DecimalFullWidth2,
-    #[serde(rename = "aiueoFullWidth")]
+    #[cfg_attr(feature = "serde", serde(rename = "aiueoFullWidth"))]
     AiueoFullWidth,
-    #[serde(rename = "irohaFullWidth")]
+    #[cfg_attr(feature = "serde", serde(rename = "irohaFullWidth"))]
     IrohaFullWidth,
-    #[serde(rename = "decimalZero")]
+    #[cfg_attr(feature = "serde", serde(rename = "decimalZero"))]
     DecimalZero,
-    #[serde(rename = "bullet")]
+    #[cfg_attr(feature = "serde", serde(rename = "bullet"))]
     Bullet,
-    #[serde(rename = "ganada")]
+    #[cfg_attr(feature = "serde", serde(rename = "ganada"))]
     Ganada,
-    #[serde(rename = "chosung")]
+    #[cfg_attr(feature = "serde", serde(rename = "chosung"))]
     Chosung,
-    #[serde(rename = "decimalEnclosedFullstop")]
+    #[cfg_attr(feature = "serde", serde(rename = "decimalEnclosedFullstop"))]
     DecimalEnclosedFullstop,
-    #[serde(rename = "decimalEnclosedParen")]
+    #[cfg_attr(feature = "serde", serde(rename = "decimalEnclosedParen"))]
     DecimalEnclosedParen,
-    #[serde(rename = "decimalEnclosedCircleChinese")]
+    #[cfg_attr(feature = "serde", serde(rename = "decimalEnclosedCircleChinese"))]
     DecimalEnclosedCircleChinese,
-    #[serde(rename = "ideographEnclosedCircle")]
+    #[cfg_attr(feature = "serde", serde(rename = "ideographEnclosedCircle"))]
     IdeographEnclosedCircle,
-    #[serde(rename = "ideographTraditional")]
+    #[cfg_attr(feature = "serde", serde(rename = "ideographTraditional"))]
     IdeographTraditional,
-    #[serde(rename = "ideographZodiac")]
+    #[cfg_attr(feature = "serde", serde(rename = "ideographZodiac"))]
     IdeographZodiac,
-    #[serde(rename = "ideographZodiacTraditional")]
+    #[cfg_attr(feature = "serde", serde(rename = "ideographZodiacTraditional"))]
     IdeographZodiacTraditional,
-    #[serde(rename = "taiwaneseCounting")]
+    #[cfg_attr(feature = "serde", serde(rename = "taiwaneseCounting"))]
     TaiwaneseCounting,
-    #[serde(rename = "ideographLegalTraditional")]
+    #[cfg_attr(feature = "serde", serde(rename = "ideographLegalTraditional"))]
     IdeographLegalTraditional,
-    #[serde(rename = "taiwaneseCountingThousand")]
+    #[cfg_attr(feature = "serde", serde(rename = "taiwaneseCountingThousand"))]
     TaiwaneseCountingThousand,
-    #[serde(rename = "taiwaneseDigital")]
+    #[cfg_attr(feature = "serde", serde(rename = "taiwaneseDigital"))]
     TaiwaneseDigital,
-    #[serde(rename = "chineseCounting")]
+    #[cfg_attr(feature = "serde", serde(rename = "chineseCounting"))]
     ChineseCounting,
-    #[serde(rename = "chineseLegalSimplified")]
+    #[cfg_attr(feature = "serde", serde(rename = "chineseLegalSimplified"))]
     ChineseLegalSimplified,
-    #[serde(rename = "chineseCountingThousand")]
+    #[cfg_attr(feature = "serde", serde(rename = "chineseCountingThousand"))]
     ChineseCountingThousand,
-    #[serde(rename = "koreanDigital")]
+    #[cfg_attr(feature = "serde", serde(rename = "koreanDigital"))]
     KoreanDigital,
-    #[serde(rename = "koreanCounting")]
+    #[cfg_attr(feature = "serde", serde(rename = "koreanCounting"))]
     KoreanCounting,
-    #[serde(rename = "koreanLegal")]
+    #[cfg_attr(feature = "serde", serde(rename = "koreanLegal"))]
     KoreanLegal,
-    #[serde(rename = "koreanDigital2")]
+    #[cfg_attr(feature = "serde", serde(rename = "koreanDigital2"))]
     KoreanDigital2,
-    #[serde(rename = "vietnameseCounting")]
+    #[cfg_attr(feature = "serde", serde(rename = "vietnameseCounting"))]
     VietnameseCounting,
-    #[serde(rename = "russianLower")]
+    #[cfg_attr(feature = "serde", serde(rename = "russianLower"))]
     RussianLower,
-    #[serde(rename = "russianUpper")]
+    #[cfg_attr(feature = "serde", serde(rename = "russianUpper"))]
     RussianUpper,
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
-    #[serde(rename = "numberInDash")]
+    #[cfg_attr(feature = "serde", serde(rename = "numberInDash"))]
     NumberInDash,
-    #[serde(rename = "hebrew1")]
+    #[cfg_attr(feature = "serde", serde(rename = "hebrew1"))]
     Hebrew1,
-    #[serde(rename = "hebrew2")]
+    #[cfg_attr(feature = "serde", serde(rename = "hebrew2"))]
     Hebrew2,
-    #[serde(rename = "arabicAlpha")]
+    #[cfg_attr(feature = "serde", serde(rename = "arabicAlpha"))]
     ArabicAlpha,
-    #[serde(rename = "arabicAbjad")]
+    #[cfg_attr(feature = "serde", serde(rename = "arabicAbjad"))]
     ArabicAbjad,
-    #[serde(rename = "hindiVowels")]
+    #[cfg_attr(feature = "serde", serde(rename = "hindiVowels"))]
     HindiVowels,
-    #[serde(rename = "hindiConsonants")]
+    #[cfg_attr(feature = "serde", serde(rename = "hindiConsonants"))]
     HindiConsonants,
-    #[serde(rename = "hindiNumbers")]
+    #[cfg_attr(feature = "serde", serde(rename = "hindiNumbers"))]
     HindiNumbers,
-    #[serde(rename = "hindiCounting")]
+    #[cfg_attr(feature = "serde", serde(rename = "hindiCounting"))]
     HindiCounting,
-    #[serde(rename = "thaiLetters")]
+    #[cfg_attr(feature = "serde", serde(rename = "thaiLetters"))]
     ThaiLetters,
-    #[serde(rename = "thaiNumbers")]
+    #[cfg_attr(feature = "serde", serde(rename = "thaiNumbers"))]
     ThaiNumbers,
-    #[serde(rename = "thaiCounting")]
+    #[cfg_attr(feature = "serde", serde(rename = "thaiCounting"))]
     ThaiCounting,
-    #[serde(rename = "bahtText")]
+    #[cfg_attr(feature = "serde", serde(rename = "bahtText"))]
     BahtText,
-    #[serde(rename = "dollarText")]
+    #[cfg_attr(feature = "serde", serde(rename = "dollarText"))]
     DollarText,
-    #[serde(rename = "custom")]
+    #[cfg_attr(feature = "serde", serde(rename = "custom"))]
     Custom,
 }
 
@@ -3186,11 +3234,12 @@ impl std::str::FromStr for STNumberFormat {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STPageOrientation {
-    #[serde(rename = "portrait")]
+    #[cfg_attr(feature = "serde", serde(rename = "portrait"))]
     Portrait,
-    #[serde(rename = "landscape")]
+    #[cfg_attr(feature = "serde", serde(rename = "landscape"))]
     Landscape,
 }
 
@@ -3215,11 +3264,12 @@ impl std::str::FromStr for STPageOrientation {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STPageBorderZOrder {
-    #[serde(rename = "front")]
+    #[cfg_attr(feature = "serde", serde(rename = "front"))]
     Front,
-    #[serde(rename = "back")]
+    #[cfg_attr(feature = "serde", serde(rename = "back"))]
     Back,
 }
 
@@ -3244,13 +3294,14 @@ impl std::str::FromStr for STPageBorderZOrder {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STPageBorderDisplay {
-    #[serde(rename = "allPages")]
+    #[cfg_attr(feature = "serde", serde(rename = "allPages"))]
     AllPages,
-    #[serde(rename = "firstPage")]
+    #[cfg_attr(feature = "serde", serde(rename = "firstPage"))]
     FirstPage,
-    #[serde(rename = "notFirstPage")]
+    #[cfg_attr(feature = "serde", serde(rename = "notFirstPage"))]
     NotFirstPage,
 }
 
@@ -3277,11 +3328,12 @@ impl std::str::FromStr for STPageBorderDisplay {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STPageBorderOffset {
-    #[serde(rename = "page")]
+    #[cfg_attr(feature = "serde", serde(rename = "page"))]
     Page,
-    #[serde(rename = "text")]
+    #[cfg_attr(feature = "serde", serde(rename = "text"))]
     Text,
 }
 
@@ -3306,17 +3358,18 @@ impl std::str::FromStr for STPageBorderOffset {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STChapterSep {
-    #[serde(rename = "hyphen")]
+    #[cfg_attr(feature = "serde", serde(rename = "hyphen"))]
     Hyphen,
-    #[serde(rename = "period")]
+    #[cfg_attr(feature = "serde", serde(rename = "period"))]
     Period,
-    #[serde(rename = "colon")]
+    #[cfg_attr(feature = "serde", serde(rename = "colon"))]
     Colon,
-    #[serde(rename = "emDash")]
+    #[cfg_attr(feature = "serde", serde(rename = "emDash"))]
     EmDash,
-    #[serde(rename = "enDash")]
+    #[cfg_attr(feature = "serde", serde(rename = "enDash"))]
     EnDash,
 }
 
@@ -3347,13 +3400,14 @@ impl std::str::FromStr for STChapterSep {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STLineNumberRestart {
-    #[serde(rename = "newPage")]
+    #[cfg_attr(feature = "serde", serde(rename = "newPage"))]
     NewPage,
-    #[serde(rename = "newSection")]
+    #[cfg_attr(feature = "serde", serde(rename = "newSection"))]
     NewSection,
-    #[serde(rename = "continuous")]
+    #[cfg_attr(feature = "serde", serde(rename = "continuous"))]
     Continuous,
 }
 
@@ -3380,15 +3434,16 @@ impl std::str::FromStr for STLineNumberRestart {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STVerticalJc {
-    #[serde(rename = "top")]
+    #[cfg_attr(feature = "serde", serde(rename = "top"))]
     Top,
-    #[serde(rename = "center")]
+    #[cfg_attr(feature = "serde", serde(rename = "center"))]
     Center,
-    #[serde(rename = "both")]
+    #[cfg_attr(feature = "serde", serde(rename = "both"))]
     Both,
-    #[serde(rename = "bottom")]
+    #[cfg_attr(feature = "serde", serde(rename = "bottom"))]
     Bottom,
 }
 
@@ -3417,15 +3472,16 @@ impl std::str::FromStr for STVerticalJc {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STDocGrid {
-    #[serde(rename = "default")]
+    #[cfg_attr(feature = "serde", serde(rename = "default"))]
     Default,
-    #[serde(rename = "lines")]
+    #[cfg_attr(feature = "serde", serde(rename = "lines"))]
     Lines,
-    #[serde(rename = "linesAndChars")]
+    #[cfg_attr(feature = "serde", serde(rename = "linesAndChars"))]
     LinesAndChars,
-    #[serde(rename = "snapToChars")]
+    #[cfg_attr(feature = "serde", serde(rename = "snapToChars"))]
     SnapToChars,
 }
 
@@ -3454,13 +3510,14 @@ impl std::str::FromStr for STDocGrid {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STHdrFtr {
-    #[serde(rename = "even")]
+    #[cfg_attr(feature = "serde", serde(rename = "even"))]
     Even,
-    #[serde(rename = "default")]
+    #[cfg_attr(feature = "serde", serde(rename = "default"))]
     Default,
-    #[serde(rename = "first")]
+    #[cfg_attr(feature = "serde", serde(rename = "first"))]
     First,
 }
 
@@ -3487,15 +3544,16 @@ impl std::str::FromStr for STHdrFtr {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STFtnEdn {
-    #[serde(rename = "normal")]
+    #[cfg_attr(feature = "serde", serde(rename = "normal"))]
     Normal,
-    #[serde(rename = "separator")]
+    #[cfg_attr(feature = "serde", serde(rename = "separator"))]
     Separator,
-    #[serde(rename = "continuationSeparator")]
+    #[cfg_attr(feature = "serde", serde(rename = "continuationSeparator"))]
     ContinuationSeparator,
-    #[serde(rename = "continuationNotice")]
+    #[cfg_attr(feature = "serde", serde(rename = "continuationNotice"))]
     ContinuationNotice,
 }
 
@@ -3524,13 +3582,14 @@ impl std::str::FromStr for STFtnEdn {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STBrType {
-    #[serde(rename = "page")]
+    #[cfg_attr(feature = "serde", serde(rename = "page"))]
     Page,
-    #[serde(rename = "column")]
+    #[cfg_attr(feature = "serde", serde(rename = "column"))]
     Column,
-    #[serde(rename = "textWrapping")]
+    #[cfg_attr(feature = "serde", serde(rename = "textWrapping"))]
     TextWrapping,
 }
 
@@ -3557,15 +3616,16 @@ impl std::str::FromStr for STBrType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STBrClear {
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
-    #[serde(rename = "left")]
+    #[cfg_attr(feature = "serde", serde(rename = "left"))]
     Left,
-    #[serde(rename = "right")]
+    #[cfg_attr(feature = "serde", serde(rename = "right"))]
     Right,
-    #[serde(rename = "all")]
+    #[cfg_attr(feature = "serde", serde(rename = "all"))]
     All,
 }
 
@@ -3594,13 +3654,14 @@ impl std::str::FromStr for STBrClear {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STPTabAlignment {
-    #[serde(rename = "left")]
+    #[cfg_attr(feature = "serde", serde(rename = "left"))]
     Left,
-    #[serde(rename = "center")]
+    #[cfg_attr(feature = "serde", serde(rename = "center"))]
     Center,
-    #[serde(rename = "right")]
+    #[cfg_attr(feature = "serde", serde(rename = "right"))]
     Right,
 }
 
@@ -3627,11 +3688,12 @@ impl std::str::FromStr for STPTabAlignment {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STPTabRelativeTo {
-    #[serde(rename = "margin")]
+    #[cfg_attr(feature = "serde", serde(rename = "margin"))]
     Margin,
-    #[serde(rename = "indent")]
+    #[cfg_attr(feature = "serde", serde(rename = "indent"))]
     Indent,
 }
 
@@ -3656,17 +3718,18 @@ impl std::str::FromStr for STPTabRelativeTo {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STPTabLeader {
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
-    #[serde(rename = "dot")]
+    #[cfg_attr(feature = "serde", serde(rename = "dot"))]
     Dot,
-    #[serde(rename = "hyphen")]
+    #[cfg_attr(feature = "serde", serde(rename = "hyphen"))]
     Hyphen,
-    #[serde(rename = "underscore")]
+    #[cfg_attr(feature = "serde", serde(rename = "underscore"))]
     Underscore,
-    #[serde(rename = "middleDot")]
+    #[cfg_attr(feature = "serde", serde(rename = "middleDot"))]
     MiddleDot,
 }
 
@@ -3697,15 +3760,16 @@ impl std::str::FromStr for STPTabLeader {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STProofErr {
-    #[serde(rename = "spellStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "spellStart"))]
     SpellStart,
-    #[serde(rename = "spellEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "spellEnd"))]
     SpellEnd,
-    #[serde(rename = "gramStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "gramStart"))]
     GramStart,
-    #[serde(rename = "gramEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "gramEnd"))]
     GramEnd,
 }
 
@@ -3734,21 +3798,22 @@ impl std::str::FromStr for STProofErr {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STEdGrp {
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
-    #[serde(rename = "everyone")]
+    #[cfg_attr(feature = "serde", serde(rename = "everyone"))]
     Everyone,
-    #[serde(rename = "administrators")]
+    #[cfg_attr(feature = "serde", serde(rename = "administrators"))]
     Administrators,
-    #[serde(rename = "contributors")]
+    #[cfg_attr(feature = "serde", serde(rename = "contributors"))]
     Contributors,
-    #[serde(rename = "editors")]
+    #[cfg_attr(feature = "serde", serde(rename = "editors"))]
     Editors,
-    #[serde(rename = "owners")]
+    #[cfg_attr(feature = "serde", serde(rename = "owners"))]
     Owners,
-    #[serde(rename = "current")]
+    #[cfg_attr(feature = "serde", serde(rename = "current"))]
     Current,
 }
 
@@ -3783,11 +3848,12 @@ impl std::str::FromStr for STEdGrp {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STHint {
-    #[serde(rename = "default")]
+    #[cfg_attr(feature = "serde", serde(rename = "default"))]
     Default,
-    #[serde(rename = "eastAsia")]
+    #[cfg_attr(feature = "serde", serde(rename = "eastAsia"))]
     EastAsia,
 }
 
@@ -3812,23 +3878,24 @@ impl std::str::FromStr for STHint {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTheme {
-    #[serde(rename = "majorEastAsia")]
+    #[cfg_attr(feature = "serde", serde(rename = "majorEastAsia"))]
     MajorEastAsia,
-    #[serde(rename = "majorBidi")]
+    #[cfg_attr(feature = "serde", serde(rename = "majorBidi"))]
     MajorBidi,
-    #[serde(rename = "majorAscii")]
+    #[cfg_attr(feature = "serde", serde(rename = "majorAscii"))]
     MajorAscii,
-    #[serde(rename = "majorHAnsi")]
+    #[cfg_attr(feature = "serde", serde(rename = "majorHAnsi"))]
     MajorHAnsi,
-    #[serde(rename = "minorEastAsia")]
+    #[cfg_attr(feature = "serde", serde(rename = "minorEastAsia"))]
     MinorEastAsia,
-    #[serde(rename = "minorBidi")]
+    #[cfg_attr(feature = "serde", serde(rename = "minorBidi"))]
     MinorBidi,
-    #[serde(rename = "minorAscii")]
+    #[cfg_attr(feature = "serde", serde(rename = "minorAscii"))]
     MinorAscii,
-    #[serde(rename = "minorHAnsi")]
+    #[cfg_attr(feature = "serde", serde(rename = "minorHAnsi"))]
     MinorHAnsi,
 }
 
@@ -3865,19 +3932,20 @@ impl std::str::FromStr for STTheme {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STRubyAlign {
-    #[serde(rename = "center")]
+    #[cfg_attr(feature = "serde", serde(rename = "center"))]
     Center,
-    #[serde(rename = "distributeLetter")]
+    #[cfg_attr(feature = "serde", serde(rename = "distributeLetter"))]
     DistributeLetter,
-    #[serde(rename = "distributeSpace")]
+    #[cfg_attr(feature = "serde", serde(rename = "distributeSpace"))]
     DistributeSpace,
-    #[serde(rename = "left")]
+    #[cfg_attr(feature = "serde", serde(rename = "left"))]
     Left,
-    #[serde(rename = "right")]
+    #[cfg_attr(feature = "serde", serde(rename = "right"))]
     Right,
-    #[serde(rename = "rightVertical")]
+    #[cfg_attr(feature = "serde", serde(rename = "rightVertical"))]
     RightVertical,
 }
 
@@ -3910,15 +3978,16 @@ impl std::str::FromStr for STRubyAlign {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STLock {
-    #[serde(rename = "sdtLocked")]
+    #[cfg_attr(feature = "serde", serde(rename = "sdtLocked"))]
     SdtLocked,
-    #[serde(rename = "contentLocked")]
+    #[cfg_attr(feature = "serde", serde(rename = "contentLocked"))]
     ContentLocked,
-    #[serde(rename = "unlocked")]
+    #[cfg_attr(feature = "serde", serde(rename = "unlocked"))]
     Unlocked,
-    #[serde(rename = "sdtContentLocked")]
+    #[cfg_attr(feature = "serde", serde(rename = "sdtContentLocked"))]
     SdtContentLocked,
 }
 
@@ -3947,13 +4016,14 @@ impl std::str::FromStr for STLock {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STSdtDateMappingType {
-    #[serde(rename = "text")]
+    #[cfg_attr(feature = "serde", serde(rename = "text"))]
     Text,
-    #[serde(rename = "date")]
+    #[cfg_attr(feature = "serde", serde(rename = "date"))]
     Date,
-    #[serde(rename = "dateTime")]
+    #[cfg_attr(feature = "serde", serde(rename = "dateTime"))]
     DateTime,
 }
 
@@ -3980,11 +4050,12 @@ impl std::str::FromStr for STSdtDateMappingType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STDirection {
-    #[serde(rename = "ltr")]
+    #[cfg_attr(feature = "serde", serde(rename = "ltr"))]
     Ltr,
-    #[serde(rename = "rtl")]
+    #[cfg_attr(feature = "serde", serde(rename = "rtl"))]
     Rtl,
 }
 
@@ -4009,15 +4080,16 @@ impl std::str::FromStr for STDirection {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTblWidth {
-    #[serde(rename = "nil")]
+    #[cfg_attr(feature = "serde", serde(rename = "nil"))]
     Nil,
-    #[serde(rename = "pct")]
+    #[cfg_attr(feature = "serde", serde(rename = "pct"))]
     Pct,
-    #[serde(rename = "dxa")]
+    #[cfg_attr(feature = "serde", serde(rename = "dxa"))]
     Dxa,
-    #[serde(rename = "auto")]
+    #[cfg_attr(feature = "serde", serde(rename = "auto"))]
     Auto,
 }
 
@@ -4048,11 +4120,12 @@ impl std::str::FromStr for STTblWidth {
 
 pub type STMeasurementOrPercent = String;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STMerge {
-    #[serde(rename = "continue")]
+    #[cfg_attr(feature = "serde", serde(rename = "continue"))]
     Continue,
-    #[serde(rename = "restart")]
+    #[cfg_attr(feature = "serde", serde(rename = "restart"))]
     Restart,
 }
 
@@ -4079,11 +4152,12 @@ impl std::str::FromStr for STMerge {
 
 pub type STCnf = String;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTblLayoutType {
-    #[serde(rename = "fixed")]
+    #[cfg_attr(feature = "serde", serde(rename = "fixed"))]
     Fixed,
-    #[serde(rename = "autofit")]
+    #[cfg_attr(feature = "serde", serde(rename = "autofit"))]
     Autofit,
 }
 
@@ -4108,11 +4182,12 @@ impl std::str::FromStr for STTblLayoutType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTblOverlap {
-    #[serde(rename = "never")]
+    #[cfg_attr(feature = "serde", serde(rename = "never"))]
     Never,
-    #[serde(rename = "overlap")]
+    #[cfg_attr(feature = "serde", serde(rename = "overlap"))]
     Overlap,
 }
 
@@ -4137,15 +4212,16 @@ impl std::str::FromStr for STTblOverlap {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STFtnPos {
-    #[serde(rename = "pageBottom")]
+    #[cfg_attr(feature = "serde", serde(rename = "pageBottom"))]
     PageBottom,
-    #[serde(rename = "beneathText")]
+    #[cfg_attr(feature = "serde", serde(rename = "beneathText"))]
     BeneathText,
-    #[serde(rename = "sectEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "sectEnd"))]
     SectEnd,
-    #[serde(rename = "docEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "docEnd"))]
     DocEnd,
 }
 
@@ -4174,11 +4250,12 @@ impl std::str::FromStr for STFtnPos {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STEdnPos {
-    #[serde(rename = "sectEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "sectEnd"))]
     SectEnd,
-    #[serde(rename = "docEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "docEnd"))]
     DocEnd,
 }
 
@@ -4203,13 +4280,14 @@ impl std::str::FromStr for STEdnPos {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STRestartNumber {
-    #[serde(rename = "continuous")]
+    #[cfg_attr(feature = "serde", serde(rename = "continuous"))]
     Continuous,
-    #[serde(rename = "eachSect")]
+    #[cfg_attr(feature = "serde", serde(rename = "eachSect"))]
     EachSect,
-    #[serde(rename = "eachPage")]
+    #[cfg_attr(feature = "serde", serde(rename = "eachPage"))]
     EachPage,
 }
 
@@ -4236,25 +4314,26 @@ impl std::str::FromStr for STRestartNumber {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STMailMergeSourceType {
-    #[serde(rename = "database")]
+    #[cfg_attr(feature = "serde", serde(rename = "database"))]
     Database,
-    #[serde(rename = "addressBook")]
+    #[cfg_attr(feature = "serde", serde(rename = "addressBook"))]
     AddressBook,
-    #[serde(rename = "document1")]
+    #[cfg_attr(feature = "serde", serde(rename = "document1"))]
     Document1,
-    #[serde(rename = "document2")]
+    #[cfg_attr(feature = "serde", serde(rename = "document2"))]
     Document2,
-    #[serde(rename = "text")]
+    #[cfg_attr(feature = "serde", serde(rename = "text"))]
     Text,
-    #[serde(rename = "email")]
+    #[cfg_attr(feature = "serde", serde(rename = "email"))]
     Email,
-    #[serde(rename = "native")]
+    #[cfg_attr(feature = "serde", serde(rename = "native"))]
     Native,
-    #[serde(rename = "legacy")]
+    #[cfg_attr(feature = "serde", serde(rename = "legacy"))]
     Legacy,
-    #[serde(rename = "master")]
+    #[cfg_attr(feature = "serde", serde(rename = "master"))]
     Master,
 }
 
@@ -4293,29 +4372,30 @@ impl std::str::FromStr for STMailMergeSourceType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTargetScreenSz {
-    #[serde(rename = "544x376")]
+    #[cfg_attr(feature = "serde", serde(rename = "544x376"))]
     _544x376,
-    #[serde(rename = "640x480")]
+    #[cfg_attr(feature = "serde", serde(rename = "640x480"))]
     _640x480,
-    #[serde(rename = "720x512")]
+    #[cfg_attr(feature = "serde", serde(rename = "720x512"))]
     _720x512,
-    #[serde(rename = "800x600")]
+    #[cfg_attr(feature = "serde", serde(rename = "800x600"))]
     _800x600,
-    #[serde(rename = "1024x768")]
+    #[cfg_attr(feature = "serde", serde(rename = "1024x768"))]
     _1024x768,
-    #[serde(rename = "1152x882")]
+    #[cfg_attr(feature = "serde", serde(rename = "1152x882"))]
     _1152x882,
-    #[serde(rename = "1152x900")]
+    #[cfg_attr(feature = "serde", serde(rename = "1152x900"))]
     _1152x900,
-    #[serde(rename = "1280x1024")]
+    #[cfg_attr(feature = "serde", serde(rename = "1280x1024"))]
     _1280x1024,
-    #[serde(rename = "1600x1200")]
+    #[cfg_attr(feature = "serde", serde(rename = "1600x1200"))]
     _1600x1200,
-    #[serde(rename = "1800x1440")]
+    #[cfg_attr(feature = "serde", serde(rename = "1800x1440"))]
     _1800x1440,
-    #[serde(rename = "1920x1200")]
+    #[cfg_attr(feature = "serde", serde(rename = "1920x1200"))]
     _1920x1200,
 }
 
@@ -4358,13 +4438,17 @@ impl std::str::FromStr for STTargetScreenSz {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STCharacterSpacing {
-    #[serde(rename = "doNotCompress")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotCompress"))]
     DoNotCompress,
-    #[serde(rename = "compressPunctuation")]
+    #[cfg_attr(feature = "serde", serde(rename = "compressPunctuation"))]
     CompressPunctuation,
-    #[serde(rename = "compressPunctuationAndJapaneseKana")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "compressPunctuationAndJapaneseKana")
+    )]
     CompressPunctuationAndJapaneseKana,
 }
 
@@ -4393,31 +4477,32 @@ impl std::str::FromStr for STCharacterSpacing {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STWmlColorSchemeIndex {
-    #[serde(rename = "dark1")]
+    #[cfg_attr(feature = "serde", serde(rename = "dark1"))]
     Dark1,
-    #[serde(rename = "light1")]
+    #[cfg_attr(feature = "serde", serde(rename = "light1"))]
     Light1,
-    #[serde(rename = "dark2")]
+    #[cfg_attr(feature = "serde", serde(rename = "dark2"))]
     Dark2,
-    #[serde(rename = "light2")]
+    #[cfg_attr(feature = "serde", serde(rename = "light2"))]
     Light2,
-    #[serde(rename = "accent1")]
+    #[cfg_attr(feature = "serde", serde(rename = "accent1"))]
     Accent1,
-    #[serde(rename = "accent2")]
+    #[cfg_attr(feature = "serde", serde(rename = "accent2"))]
     Accent2,
-    #[serde(rename = "accent3")]
+    #[cfg_attr(feature = "serde", serde(rename = "accent3"))]
     Accent3,
-    #[serde(rename = "accent4")]
+    #[cfg_attr(feature = "serde", serde(rename = "accent4"))]
     Accent4,
-    #[serde(rename = "accent5")]
+    #[cfg_attr(feature = "serde", serde(rename = "accent5"))]
     Accent5,
-    #[serde(rename = "accent6")]
+    #[cfg_attr(feature = "serde", serde(rename = "accent6"))]
     Accent6,
-    #[serde(rename = "hyperlink")]
+    #[cfg_attr(feature = "serde", serde(rename = "hyperlink"))]
     Hyperlink,
-    #[serde(rename = "followedHyperlink")]
+    #[cfg_attr(feature = "serde", serde(rename = "followedHyperlink"))]
     FollowedHyperlink,
 }
 
@@ -4462,31 +4547,32 @@ impl std::str::FromStr for STWmlColorSchemeIndex {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STStyleSort {
-    #[serde(rename = "name")]
+    #[cfg_attr(feature = "serde", serde(rename = "name"))]
     Name,
-    #[serde(rename = "priority")]
+    #[cfg_attr(feature = "serde", serde(rename = "priority"))]
     Priority,
-    #[serde(rename = "default")]
+    #[cfg_attr(feature = "serde", serde(rename = "default"))]
     Default,
-    #[serde(rename = "font")]
+    #[cfg_attr(feature = "serde", serde(rename = "font"))]
     Font,
-    #[serde(rename = "basedOn")]
+    #[cfg_attr(feature = "serde", serde(rename = "basedOn"))]
     BasedOn,
-    #[serde(rename = "type")]
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
     Type,
-    #[serde(rename = "0000")]
+    #[cfg_attr(feature = "serde", serde(rename = "0000"))]
     _0000,
-    #[serde(rename = "0001")]
+    #[cfg_attr(feature = "serde", serde(rename = "0001"))]
     _0001,
-    #[serde(rename = "0002")]
+    #[cfg_attr(feature = "serde", serde(rename = "0002"))]
     _0002,
-    #[serde(rename = "0003")]
+    #[cfg_attr(feature = "serde", serde(rename = "0003"))]
     _0003,
-    #[serde(rename = "0004")]
+    #[cfg_attr(feature = "serde", serde(rename = "0004"))]
     _0004,
-    #[serde(rename = "0005")]
+    #[cfg_attr(feature = "serde", serde(rename = "0005"))]
     _0005,
 }
 
@@ -4531,13 +4617,14 @@ impl std::str::FromStr for STStyleSort {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STFrameScrollbar {
-    #[serde(rename = "on")]
+    #[cfg_attr(feature = "serde", serde(rename = "on"))]
     On,
-    #[serde(rename = "off")]
+    #[cfg_attr(feature = "serde", serde(rename = "off"))]
     Off,
-    #[serde(rename = "auto")]
+    #[cfg_attr(feature = "serde", serde(rename = "auto"))]
     Auto,
 }
 
@@ -4564,13 +4651,14 @@ impl std::str::FromStr for STFrameScrollbar {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STFrameLayout {
-    #[serde(rename = "rows")]
+    #[cfg_attr(feature = "serde", serde(rename = "rows"))]
     Rows,
-    #[serde(rename = "cols")]
+    #[cfg_attr(feature = "serde", serde(rename = "cols"))]
     Cols,
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
 }
 
@@ -4597,13 +4685,14 @@ impl std::str::FromStr for STFrameLayout {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STLevelSuffix {
-    #[serde(rename = "tab")]
+    #[cfg_attr(feature = "serde", serde(rename = "tab"))]
     Tab,
-    #[serde(rename = "space")]
+    #[cfg_attr(feature = "serde", serde(rename = "space"))]
     Space,
-    #[serde(rename = "nothing")]
+    #[cfg_attr(feature = "serde", serde(rename = "nothing"))]
     Nothing,
 }
 
@@ -4630,13 +4719,14 @@ impl std::str::FromStr for STLevelSuffix {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STMultiLevelType {
-    #[serde(rename = "singleLevel")]
+    #[cfg_attr(feature = "serde", serde(rename = "singleLevel"))]
     SingleLevel,
-    #[serde(rename = "multilevel")]
+    #[cfg_attr(feature = "serde", serde(rename = "multilevel"))]
     Multilevel,
-    #[serde(rename = "hybridMultilevel")]
+    #[cfg_attr(feature = "serde", serde(rename = "hybridMultilevel"))]
     HybridMultilevel,
 }
 
@@ -4663,33 +4753,34 @@ impl std::str::FromStr for STMultiLevelType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STTblStyleOverrideType {
-    #[serde(rename = "wholeTable")]
+    #[cfg_attr(feature = "serde", serde(rename = "wholeTable"))]
     WholeTable,
-    #[serde(rename = "firstRow")]
+    #[cfg_attr(feature = "serde", serde(rename = "firstRow"))]
     FirstRow,
-    #[serde(rename = "lastRow")]
+    #[cfg_attr(feature = "serde", serde(rename = "lastRow"))]
     LastRow,
-    #[serde(rename = "firstCol")]
+    #[cfg_attr(feature = "serde", serde(rename = "firstCol"))]
     FirstCol,
-    #[serde(rename = "lastCol")]
+    #[cfg_attr(feature = "serde", serde(rename = "lastCol"))]
     LastCol,
-    #[serde(rename = "band1Vert")]
+    #[cfg_attr(feature = "serde", serde(rename = "band1Vert"))]
     Band1Vert,
-    #[serde(rename = "band2Vert")]
+    #[cfg_attr(feature = "serde", serde(rename = "band2Vert"))]
     Band2Vert,
-    #[serde(rename = "band1Horz")]
+    #[cfg_attr(feature = "serde", serde(rename = "band1Horz"))]
     Band1Horz,
-    #[serde(rename = "band2Horz")]
+    #[cfg_attr(feature = "serde", serde(rename = "band2Horz"))]
     Band2Horz,
-    #[serde(rename = "neCell")]
+    #[cfg_attr(feature = "serde", serde(rename = "neCell"))]
     NeCell,
-    #[serde(rename = "nwCell")]
+    #[cfg_attr(feature = "serde", serde(rename = "nwCell"))]
     NwCell,
-    #[serde(rename = "seCell")]
+    #[cfg_attr(feature = "serde", serde(rename = "seCell"))]
     SeCell,
-    #[serde(rename = "swCell")]
+    #[cfg_attr(feature = "serde", serde(rename = "swCell"))]
     SwCell,
 }
 
@@ -4736,15 +4827,16 @@ impl std::str::FromStr for STTblStyleOverrideType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STStyleType {
-    #[serde(rename = "paragraph")]
+    #[cfg_attr(feature = "serde", serde(rename = "paragraph"))]
     Paragraph,
-    #[serde(rename = "character")]
+    #[cfg_attr(feature = "serde", serde(rename = "character"))]
     Character,
-    #[serde(rename = "table")]
+    #[cfg_attr(feature = "serde", serde(rename = "table"))]
     Table,
-    #[serde(rename = "numbering")]
+    #[cfg_attr(feature = "serde", serde(rename = "numbering"))]
     Numbering,
 }
 
@@ -4773,19 +4865,20 @@ impl std::str::FromStr for STStyleType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STFontFamily {
-    #[serde(rename = "decorative")]
+    #[cfg_attr(feature = "serde", serde(rename = "decorative"))]
     Decorative,
-    #[serde(rename = "modern")]
+    #[cfg_attr(feature = "serde", serde(rename = "modern"))]
     Modern,
-    #[serde(rename = "roman")]
+    #[cfg_attr(feature = "serde", serde(rename = "roman"))]
     Roman,
-    #[serde(rename = "script")]
+    #[cfg_attr(feature = "serde", serde(rename = "script"))]
     Script,
-    #[serde(rename = "swiss")]
+    #[cfg_attr(feature = "serde", serde(rename = "swiss"))]
     Swiss,
-    #[serde(rename = "auto")]
+    #[cfg_attr(feature = "serde", serde(rename = "auto"))]
     Auto,
 }
 
@@ -4818,13 +4911,14 @@ impl std::str::FromStr for STFontFamily {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STPitch {
-    #[serde(rename = "fixed")]
+    #[cfg_attr(feature = "serde", serde(rename = "fixed"))]
     Fixed,
-    #[serde(rename = "variable")]
+    #[cfg_attr(feature = "serde", serde(rename = "variable"))]
     Variable,
-    #[serde(rename = "default")]
+    #[cfg_attr(feature = "serde", serde(rename = "default"))]
     Default,
 }
 
@@ -4851,41 +4945,42 @@ impl std::str::FromStr for STPitch {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STThemeColor {
-    #[serde(rename = "dark1")]
+    #[cfg_attr(feature = "serde", serde(rename = "dark1"))]
     Dark1,
-    #[serde(rename = "light1")]
+    #[cfg_attr(feature = "serde", serde(rename = "light1"))]
     Light1,
-    #[serde(rename = "dark2")]
+    #[cfg_attr(feature = "serde", serde(rename = "dark2"))]
     Dark2,
-    #[serde(rename = "light2")]
+    #[cfg_attr(feature = "serde", serde(rename = "light2"))]
     Light2,
-    #[serde(rename = "accent1")]
+    #[cfg_attr(feature = "serde", serde(rename = "accent1"))]
     Accent1,
-    #[serde(rename = "accent2")]
+    #[cfg_attr(feature = "serde", serde(rename = "accent2"))]
     Accent2,
-    #[serde(rename = "accent3")]
+    #[cfg_attr(feature = "serde", serde(rename = "accent3"))]
     Accent3,
-    #[serde(rename = "accent4")]
+    #[cfg_attr(feature = "serde", serde(rename = "accent4"))]
     Accent4,
-    #[serde(rename = "accent5")]
+    #[cfg_attr(feature = "serde", serde(rename = "accent5"))]
     Accent5,
-    #[serde(rename = "accent6")]
+    #[cfg_attr(feature = "serde", serde(rename = "accent6"))]
     Accent6,
-    #[serde(rename = "hyperlink")]
+    #[cfg_attr(feature = "serde", serde(rename = "hyperlink"))]
     Hyperlink,
-    #[serde(rename = "followedHyperlink")]
+    #[cfg_attr(feature = "serde", serde(rename = "followedHyperlink"))]
     FollowedHyperlink,
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
-    #[serde(rename = "background1")]
+    #[cfg_attr(feature = "serde", serde(rename = "background1"))]
     Background1,
-    #[serde(rename = "text1")]
+    #[cfg_attr(feature = "serde", serde(rename = "text1"))]
     Text1,
-    #[serde(rename = "background2")]
+    #[cfg_attr(feature = "serde", serde(rename = "background2"))]
     Background2,
-    #[serde(rename = "text2")]
+    #[cfg_attr(feature = "serde", serde(rename = "text2"))]
     Text2,
 }
 
@@ -4940,13 +5035,14 @@ impl std::str::FromStr for STThemeColor {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STDocPartBehavior {
-    #[serde(rename = "content")]
+    #[cfg_attr(feature = "serde", serde(rename = "content"))]
     Content,
-    #[serde(rename = "p")]
+    #[cfg_attr(feature = "serde", serde(rename = "p"))]
     P,
-    #[serde(rename = "pg")]
+    #[cfg_attr(feature = "serde", serde(rename = "pg"))]
     Pg,
 }
 
@@ -4973,21 +5069,22 @@ impl std::str::FromStr for STDocPartBehavior {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STDocPartType {
-    #[serde(rename = "none")]
+    #[cfg_attr(feature = "serde", serde(rename = "none"))]
     None,
-    #[serde(rename = "normal")]
+    #[cfg_attr(feature = "serde", serde(rename = "normal"))]
     Normal,
-    #[serde(rename = "autoExp")]
+    #[cfg_attr(feature = "serde", serde(rename = "autoExp"))]
     AutoExp,
-    #[serde(rename = "toolbar")]
+    #[cfg_attr(feature = "serde", serde(rename = "toolbar"))]
     Toolbar,
-    #[serde(rename = "speller")]
+    #[cfg_attr(feature = "serde", serde(rename = "speller"))]
     Speller,
-    #[serde(rename = "formFld")]
+    #[cfg_attr(feature = "serde", serde(rename = "formFld"))]
     FormFld,
-    #[serde(rename = "bbPlcHdr")]
+    #[cfg_attr(feature = "serde", serde(rename = "bbPlcHdr"))]
     BbPlcHdr,
 }
 
@@ -5022,83 +5119,84 @@ impl std::str::FromStr for STDocPartType {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STDocPartGallery {
-    #[serde(rename = "placeholder")]
+    #[cfg_attr(feature = "serde", serde(rename = "placeholder"))]
     Placeholder,
-    #[serde(rename = "any")]
+    #[cfg_attr(feature = "serde", serde(rename = "any"))]
     Any,
-    #[serde(rename = "default")]
+    #[cfg_attr(feature = "serde", serde(rename = "default"))]
     Default,
-    #[serde(rename = "docParts")]
+    #[cfg_attr(feature = "serde", serde(rename = "docParts"))]
     DocParts,
-    #[serde(rename = "coverPg")]
+    #[cfg_attr(feature = "serde", serde(rename = "coverPg"))]
     CoverPg,
-    #[serde(rename = "eq")]
+    #[cfg_attr(feature = "serde", serde(rename = "eq"))]
     Eq,
-    #[serde(rename = "ftrs")]
+    #[cfg_attr(feature = "serde", serde(rename = "ftrs"))]
     Ftrs,
-    #[serde(rename = "hdrs")]
+    #[cfg_attr(feature = "serde", serde(rename = "hdrs"))]
     Hdrs,
-    #[serde(rename = "pgNum")]
+    #[cfg_attr(feature = "serde", serde(rename = "pgNum"))]
     PgNum,
-    #[serde(rename = "tbls")]
+    #[cfg_attr(feature = "serde", serde(rename = "tbls"))]
     Tbls,
-    #[serde(rename = "watermarks")]
+    #[cfg_attr(feature = "serde", serde(rename = "watermarks"))]
     Watermarks,
-    #[serde(rename = "autoTxt")]
+    #[cfg_attr(feature = "serde", serde(rename = "autoTxt"))]
     AutoTxt,
-    #[serde(rename = "txtBox")]
+    #[cfg_attr(feature = "serde", serde(rename = "txtBox"))]
     TxtBox,
-    #[serde(rename = "pgNumT")]
+    #[cfg_attr(feature = "serde", serde(rename = "pgNumT"))]
     PgNumT,
-    #[serde(rename = "pgNumB")]
+    #[cfg_attr(feature = "serde", serde(rename = "pgNumB"))]
     PgNumB,
-    #[serde(rename = "pgNumMargins")]
+    #[cfg_attr(feature = "serde", serde(rename = "pgNumMargins"))]
     PgNumMargins,
-    #[serde(rename = "tblOfContents")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblOfContents"))]
     TblOfContents,
-    #[serde(rename = "bib")]
+    #[cfg_attr(feature = "serde", serde(rename = "bib"))]
     Bib,
-    #[serde(rename = "custQuickParts")]
+    #[cfg_attr(feature = "serde", serde(rename = "custQuickParts"))]
     CustQuickParts,
-    #[serde(rename = "custCoverPg")]
+    #[cfg_attr(feature = "serde", serde(rename = "custCoverPg"))]
     CustCoverPg,
-    #[serde(rename = "custEq")]
+    #[cfg_attr(feature = "serde", serde(rename = "custEq"))]
     CustEq,
-    #[serde(rename = "custFtrs")]
+    #[cfg_attr(feature = "serde", serde(rename = "custFtrs"))]
     CustFtrs,
-    #[serde(rename = "custHdrs")]
+    #[cfg_attr(feature = "serde", serde(rename = "custHdrs"))]
     CustHdrs,
-    #[serde(rename = "custPgNum")]
+    #[cfg_attr(feature = "serde", serde(rename = "custPgNum"))]
     CustPgNum,
-    #[serde(rename = "custTbls")]
+    #[cfg_attr(feature = "serde", serde(rename = "custTbls"))]
     CustTbls,
-    #[serde(rename = "custWatermarks")]
+    #[cfg_attr(feature = "serde", serde(rename = "custWatermarks"))]
     CustWatermarks,
-    #[serde(rename = "custAutoTxt")]
+    #[cfg_attr(feature = "serde", serde(rename = "custAutoTxt"))]
     CustAutoTxt,
-    #[serde(rename = "custTxtBox")]
+    #[cfg_attr(feature = "serde", serde(rename = "custTxtBox"))]
     CustTxtBox,
-    #[serde(rename = "custPgNumT")]
+    #[cfg_attr(feature = "serde", serde(rename = "custPgNumT"))]
     CustPgNumT,
-    #[serde(rename = "custPgNumB")]
+    #[cfg_attr(feature = "serde", serde(rename = "custPgNumB"))]
     CustPgNumB,
-    #[serde(rename = "custPgNumMargins")]
+    #[cfg_attr(feature = "serde", serde(rename = "custPgNumMargins"))]
     CustPgNumMargins,
-    #[serde(rename = "custTblOfContents")]
+    #[cfg_attr(feature = "serde", serde(rename = "custTblOfContents"))]
     CustTblOfContents,
-    #[serde(rename = "custBib")]
+    #[cfg_attr(feature = "serde", serde(rename = "custBib"))]
     CustBib,
-    #[serde(rename = "custom1")]
+    #[cfg_attr(feature = "serde", serde(rename = "custom1"))]
     Custom1,
-    #[serde(rename = "custom2")]
+    #[cfg_attr(feature = "serde", serde(rename = "custom2"))]
     Custom2,
-    #[serde(rename = "custom3")]
+    #[cfg_attr(feature = "serde", serde(rename = "custom3"))]
     Custom3,
-    #[serde(rename = "custom4")]
+    #[cfg_attr(feature = "serde", serde(rename = "custom4"))]
     Custom4,
-    #[serde(rename = "custom5")]
+    #[cfg_attr(feature = "serde", serde(rename = "custom5"))]
     Custom5,
 }
 
@@ -5195,15 +5293,16 @@ impl std::str::FromStr for STDocPartGallery {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum STCaptionPos {
-    #[serde(rename = "above")]
+    #[cfg_attr(feature = "serde", serde(rename = "above"))]
     Above,
-    #[serde(rename = "below")]
+    #[cfg_attr(feature = "serde", serde(rename = "below"))]
     Below,
-    #[serde(rename = "left")]
+    #[cfg_attr(feature = "serde", serde(rename = "left"))]
     Left,
-    #[serde(rename = "right")]
+    #[cfg_attr(feature = "serde", serde(rename = "right"))]
     Right,
 }
 
@@ -5232,9612 +5331,14563 @@ impl std::str::FromStr for STCaptionPos {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ParagraphContentBase {
-    #[serde(rename = "customXml")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXml"))]
     CustomXml(Box<CTCustomXmlRun>),
-    #[serde(rename = "fldSimple")]
+    #[cfg_attr(feature = "serde", serde(rename = "fldSimple"))]
     FldSimple(Box<CTSimpleField>),
-    #[serde(rename = "hyperlink")]
+    #[cfg_attr(feature = "serde", serde(rename = "hyperlink"))]
     Hyperlink(Box<Hyperlink>),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum RunContentBase {
-    #[serde(rename = "smartTag")]
+    #[cfg_attr(feature = "serde", serde(rename = "smartTag"))]
     SmartTag(Box<CTSmartTagRun>),
-    #[serde(rename = "sdt")]
+    #[cfg_attr(feature = "serde", serde(rename = "sdt"))]
     Sdt(Box<CTSdtRun>),
-    #[serde(rename = "proofErr")]
+    #[cfg_attr(feature = "serde", serde(rename = "proofErr"))]
     ProofErr(Box<CTProofErr>),
-    #[serde(rename = "permStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "permStart"))]
     PermStart(Box<CTPermStart>),
-    #[serde(rename = "permEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "permEnd"))]
     PermEnd(Box<CTPerm>),
-    #[serde(rename = "bookmarkStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "bookmarkStart"))]
     BookmarkStart(Box<Bookmark>),
-    #[serde(rename = "bookmarkEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "bookmarkEnd"))]
     BookmarkEnd(Box<CTMarkupRange>),
-    #[serde(rename = "moveFromRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFromRangeStart"))]
     MoveFromRangeStart(Box<CTMoveBookmark>),
-    #[serde(rename = "moveFromRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFromRangeEnd"))]
     MoveFromRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "moveToRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveToRangeStart"))]
     MoveToRangeStart(Box<CTMoveBookmark>),
-    #[serde(rename = "moveToRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveToRangeEnd"))]
     MoveToRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "commentRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "commentRangeStart"))]
     CommentRangeStart(Box<CTMarkupRange>),
-    #[serde(rename = "commentRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "commentRangeEnd"))]
     CommentRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "customXmlInsRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlInsRangeStart"))]
     CustomXmlInsRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlInsRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlInsRangeEnd"))]
     CustomXmlInsRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlDelRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlDelRangeStart"))]
     CustomXmlDelRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlDelRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlDelRangeEnd"))]
     CustomXmlDelRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlMoveFromRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveFromRangeStart"))]
     CustomXmlMoveFromRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlMoveFromRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveFromRangeEnd"))]
     CustomXmlMoveFromRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlMoveToRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveToRangeStart"))]
     CustomXmlMoveToRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlMoveToRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveToRangeEnd"))]
     CustomXmlMoveToRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "ins")]
+    #[cfg_attr(feature = "serde", serde(rename = "ins"))]
     Ins(Box<CTRunTrackChange>),
-    #[serde(rename = "del")]
+    #[cfg_attr(feature = "serde", serde(rename = "del"))]
     Del(Box<CTRunTrackChange>),
-    #[serde(rename = "moveFrom")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFrom"))]
     MoveFrom(Box<CTRunTrackChange>),
-    #[serde(rename = "moveTo")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveTo"))]
     MoveTo(Box<CTRunTrackChange>),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CellMarkup {
-    #[serde(rename = "cellIns")]
+    #[cfg_attr(feature = "serde", serde(rename = "cellIns"))]
     CellIns(Box<CTTrackChange>),
-    #[serde(rename = "cellDel")]
+    #[cfg_attr(feature = "serde", serde(rename = "cellDel"))]
     CellDel(Box<CTTrackChange>),
-    #[serde(rename = "cellMerge")]
+    #[cfg_attr(feature = "serde", serde(rename = "cellMerge"))]
     CellMerge(Box<CTCellMergeTrackChange>),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum RangeMarkup {
-    #[serde(rename = "bookmarkStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "bookmarkStart"))]
     BookmarkStart(Box<Bookmark>),
-    #[serde(rename = "bookmarkEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "bookmarkEnd"))]
     BookmarkEnd(Box<CTMarkupRange>),
-    #[serde(rename = "moveFromRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFromRangeStart"))]
     MoveFromRangeStart(Box<CTMoveBookmark>),
-    #[serde(rename = "moveFromRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFromRangeEnd"))]
     MoveFromRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "moveToRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveToRangeStart"))]
     MoveToRangeStart(Box<CTMoveBookmark>),
-    #[serde(rename = "moveToRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveToRangeEnd"))]
     MoveToRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "commentRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "commentRangeStart"))]
     CommentRangeStart(Box<CTMarkupRange>),
-    #[serde(rename = "commentRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "commentRangeEnd"))]
     CommentRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "customXmlInsRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlInsRangeStart"))]
     CustomXmlInsRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlInsRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlInsRangeEnd"))]
     CustomXmlInsRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlDelRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlDelRangeStart"))]
     CustomXmlDelRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlDelRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlDelRangeEnd"))]
     CustomXmlDelRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlMoveFromRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveFromRangeStart"))]
     CustomXmlMoveFromRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlMoveFromRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveFromRangeEnd"))]
     CustomXmlMoveFromRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlMoveToRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveToRangeStart"))]
     CustomXmlMoveToRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlMoveToRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveToRangeEnd"))]
     CustomXmlMoveToRangeEnd(Box<CTMarkup>),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum HeaderFooterRef {
-    #[serde(rename = "headerReference")]
+    #[cfg_attr(feature = "serde", serde(rename = "headerReference"))]
     HeaderReference(Box<HeaderFooterReference>),
-    #[serde(rename = "footerReference")]
+    #[cfg_attr(feature = "serde", serde(rename = "footerReference"))]
     FooterReference(Box<HeaderFooterReference>),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum RunContent {
-    #[serde(rename = "br")]
+    #[cfg_attr(feature = "serde", serde(rename = "br"))]
     Br(Box<CTBr>),
-    #[serde(rename = "t")]
+    #[cfg_attr(feature = "serde", serde(rename = "t"))]
     T(Box<Text>),
-    #[serde(rename = "contentPart")]
+    #[cfg_attr(feature = "serde", serde(rename = "contentPart"))]
     ContentPart(Box<CTRel>),
-    #[serde(rename = "delText")]
+    #[cfg_attr(feature = "serde", serde(rename = "delText"))]
     DelText(Box<Text>),
-    #[serde(rename = "instrText")]
+    #[cfg_attr(feature = "serde", serde(rename = "instrText"))]
     InstrText(Box<Text>),
-    #[serde(rename = "delInstrText")]
+    #[cfg_attr(feature = "serde", serde(rename = "delInstrText"))]
     DelInstrText(Box<Text>),
-    #[serde(rename = "noBreakHyphen")]
+    #[cfg_attr(feature = "serde", serde(rename = "noBreakHyphen"))]
     NoBreakHyphen(Box<CTEmpty>),
-    #[serde(rename = "softHyphen")]
+    #[cfg_attr(feature = "serde", serde(rename = "softHyphen"))]
     SoftHyphen(Box<CTEmpty>),
-    #[serde(rename = "dayShort")]
+    #[cfg_attr(feature = "serde", serde(rename = "dayShort"))]
     DayShort(Box<CTEmpty>),
-    #[serde(rename = "monthShort")]
+    #[cfg_attr(feature = "serde", serde(rename = "monthShort"))]
     MonthShort(Box<CTEmpty>),
-    #[serde(rename = "yearShort")]
+    #[cfg_attr(feature = "serde", serde(rename = "yearShort"))]
     YearShort(Box<CTEmpty>),
-    #[serde(rename = "dayLong")]
+    #[cfg_attr(feature = "serde", serde(rename = "dayLong"))]
     DayLong(Box<CTEmpty>),
-    #[serde(rename = "monthLong")]
+    #[cfg_attr(feature = "serde", serde(rename = "monthLong"))]
     MonthLong(Box<CTEmpty>),
-    #[serde(rename = "yearLong")]
+    #[cfg_attr(feature = "serde", serde(rename = "yearLong"))]
     YearLong(Box<CTEmpty>),
-    #[serde(rename = "annotationRef")]
+    #[cfg_attr(feature = "serde", serde(rename = "annotationRef"))]
     AnnotationRef(Box<CTEmpty>),
-    #[serde(rename = "footnoteRef")]
+    #[cfg_attr(feature = "serde", serde(rename = "footnoteRef"))]
     FootnoteRef(Box<CTEmpty>),
-    #[serde(rename = "endnoteRef")]
+    #[cfg_attr(feature = "serde", serde(rename = "endnoteRef"))]
     EndnoteRef(Box<CTEmpty>),
-    #[serde(rename = "separator")]
+    #[cfg_attr(feature = "serde", serde(rename = "separator"))]
     Separator(Box<CTEmpty>),
-    #[serde(rename = "continuationSeparator")]
+    #[cfg_attr(feature = "serde", serde(rename = "continuationSeparator"))]
     ContinuationSeparator(Box<CTEmpty>),
-    #[serde(rename = "sym")]
+    #[cfg_attr(feature = "serde", serde(rename = "sym"))]
     Sym(Box<CTSym>),
-    #[serde(rename = "pgNum")]
+    #[cfg_attr(feature = "serde", serde(rename = "pgNum"))]
     PgNum(Box<CTEmpty>),
-    #[serde(rename = "cr")]
+    #[cfg_attr(feature = "serde", serde(rename = "cr"))]
     Cr(Box<CTEmpty>),
-    #[serde(rename = "tab")]
+    #[cfg_attr(feature = "serde", serde(rename = "tab"))]
     Tab(Box<CTEmpty>),
-    #[serde(rename = "object")]
+    #[cfg_attr(feature = "serde", serde(rename = "object"))]
     Object(Box<CTObject>),
-    #[serde(rename = "pict")]
+    #[cfg_attr(feature = "serde", serde(rename = "pict"))]
     Pict(Box<CTPicture>),
-    #[serde(rename = "fldChar")]
+    #[cfg_attr(feature = "serde", serde(rename = "fldChar"))]
     FldChar(Box<CTFldChar>),
-    #[serde(rename = "ruby")]
+    #[cfg_attr(feature = "serde", serde(rename = "ruby"))]
     Ruby(Box<CTRuby>),
-    #[serde(rename = "footnoteReference")]
+    #[cfg_attr(feature = "serde", serde(rename = "footnoteReference"))]
     FootnoteReference(Box<FootnoteEndnoteRef>),
-    #[serde(rename = "endnoteReference")]
+    #[cfg_attr(feature = "serde", serde(rename = "endnoteReference"))]
     EndnoteReference(Box<FootnoteEndnoteRef>),
-    #[serde(rename = "commentReference")]
+    #[cfg_attr(feature = "serde", serde(rename = "commentReference"))]
     CommentReference(Box<CTMarkup>),
-    #[serde(rename = "drawing")]
+    #[cfg_attr(feature = "serde", serde(rename = "drawing"))]
     Drawing(Box<CTDrawing>),
-    #[serde(rename = "ptab")]
+    #[cfg_attr(feature = "serde", serde(rename = "ptab"))]
     Ptab(Box<CTPTab>),
-    #[serde(rename = "lastRenderedPageBreak")]
+    #[cfg_attr(feature = "serde", serde(rename = "lastRenderedPageBreak"))]
     LastRenderedPageBreak(Box<CTEmpty>),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum MathRunProperties {
-    #[serde(rename = "rPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "rPr"))]
     RPr(Box<RunProperties>),
-    #[serde(rename = "ins")]
+    #[cfg_attr(feature = "serde", serde(rename = "ins"))]
     Ins(Box<CTMathCtrlIns>),
-    #[serde(rename = "del")]
+    #[cfg_attr(feature = "serde", serde(rename = "del"))]
     Del(Box<CTMathCtrlDel>),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum RubyContent {
-    #[serde(rename = "r")]
+    #[cfg_attr(feature = "serde", serde(rename = "r"))]
     R(Box<Run>),
-    #[serde(rename = "proofErr")]
+    #[cfg_attr(feature = "serde", serde(rename = "proofErr"))]
     ProofErr(Box<CTProofErr>),
-    #[serde(rename = "permStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "permStart"))]
     PermStart(Box<CTPermStart>),
-    #[serde(rename = "permEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "permEnd"))]
     PermEnd(Box<CTPerm>),
-    #[serde(rename = "bookmarkStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "bookmarkStart"))]
     BookmarkStart(Box<Bookmark>),
-    #[serde(rename = "bookmarkEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "bookmarkEnd"))]
     BookmarkEnd(Box<CTMarkupRange>),
-    #[serde(rename = "moveFromRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFromRangeStart"))]
     MoveFromRangeStart(Box<CTMoveBookmark>),
-    #[serde(rename = "moveFromRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFromRangeEnd"))]
     MoveFromRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "moveToRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveToRangeStart"))]
     MoveToRangeStart(Box<CTMoveBookmark>),
-    #[serde(rename = "moveToRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveToRangeEnd"))]
     MoveToRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "commentRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "commentRangeStart"))]
     CommentRangeStart(Box<CTMarkupRange>),
-    #[serde(rename = "commentRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "commentRangeEnd"))]
     CommentRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "customXmlInsRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlInsRangeStart"))]
     CustomXmlInsRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlInsRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlInsRangeEnd"))]
     CustomXmlInsRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlDelRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlDelRangeStart"))]
     CustomXmlDelRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlDelRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlDelRangeEnd"))]
     CustomXmlDelRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlMoveFromRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveFromRangeStart"))]
     CustomXmlMoveFromRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlMoveFromRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveFromRangeEnd"))]
     CustomXmlMoveFromRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlMoveToRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveToRangeStart"))]
     CustomXmlMoveToRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlMoveToRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveToRangeEnd"))]
     CustomXmlMoveToRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "ins")]
+    #[cfg_attr(feature = "serde", serde(rename = "ins"))]
     Ins(Box<CTRunTrackChange>),
-    #[serde(rename = "del")]
+    #[cfg_attr(feature = "serde", serde(rename = "del"))]
     Del(Box<CTRunTrackChange>),
-    #[serde(rename = "moveFrom")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFrom"))]
     MoveFrom(Box<CTRunTrackChange>),
-    #[serde(rename = "moveTo")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveTo"))]
     MoveTo(Box<CTRunTrackChange>),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum RunContentChoice {
-    #[serde(rename = "customXml")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXml"))]
     CustomXml(Box<CTCustomXmlRun>),
-    #[serde(rename = "smartTag")]
+    #[cfg_attr(feature = "serde", serde(rename = "smartTag"))]
     SmartTag(Box<CTSmartTagRun>),
-    #[serde(rename = "sdt")]
+    #[cfg_attr(feature = "serde", serde(rename = "sdt"))]
     Sdt(Box<CTSdtRun>),
-    #[serde(rename = "dir")]
+    #[cfg_attr(feature = "serde", serde(rename = "dir"))]
     Dir(Box<CTDirContentRun>),
-    #[serde(rename = "bdo")]
+    #[cfg_attr(feature = "serde", serde(rename = "bdo"))]
     Bdo(Box<CTBdoContentRun>),
-    #[serde(rename = "r")]
+    #[cfg_attr(feature = "serde", serde(rename = "r"))]
     R(Box<Run>),
-    #[serde(rename = "proofErr")]
+    #[cfg_attr(feature = "serde", serde(rename = "proofErr"))]
     ProofErr(Box<CTProofErr>),
-    #[serde(rename = "permStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "permStart"))]
     PermStart(Box<CTPermStart>),
-    #[serde(rename = "permEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "permEnd"))]
     PermEnd(Box<CTPerm>),
-    #[serde(rename = "bookmarkStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "bookmarkStart"))]
     BookmarkStart(Box<Bookmark>),
-    #[serde(rename = "bookmarkEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "bookmarkEnd"))]
     BookmarkEnd(Box<CTMarkupRange>),
-    #[serde(rename = "moveFromRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFromRangeStart"))]
     MoveFromRangeStart(Box<CTMoveBookmark>),
-    #[serde(rename = "moveFromRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFromRangeEnd"))]
     MoveFromRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "moveToRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveToRangeStart"))]
     MoveToRangeStart(Box<CTMoveBookmark>),
-    #[serde(rename = "moveToRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveToRangeEnd"))]
     MoveToRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "commentRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "commentRangeStart"))]
     CommentRangeStart(Box<CTMarkupRange>),
-    #[serde(rename = "commentRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "commentRangeEnd"))]
     CommentRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "customXmlInsRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlInsRangeStart"))]
     CustomXmlInsRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlInsRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlInsRangeEnd"))]
     CustomXmlInsRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlDelRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlDelRangeStart"))]
     CustomXmlDelRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlDelRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlDelRangeEnd"))]
     CustomXmlDelRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlMoveFromRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveFromRangeStart"))]
     CustomXmlMoveFromRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlMoveFromRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveFromRangeEnd"))]
     CustomXmlMoveFromRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlMoveToRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveToRangeStart"))]
     CustomXmlMoveToRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlMoveToRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveToRangeEnd"))]
     CustomXmlMoveToRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "ins")]
+    #[cfg_attr(feature = "serde", serde(rename = "ins"))]
     Ins(Box<CTRunTrackChange>),
-    #[serde(rename = "del")]
+    #[cfg_attr(feature = "serde", serde(rename = "del"))]
     Del(Box<CTRunTrackChange>),
-    #[serde(rename = "moveFrom")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFrom"))]
     MoveFrom(Box<CTRunTrackChange>),
-    #[serde(rename = "moveTo")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveTo"))]
     MoveTo(Box<CTRunTrackChange>),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BlockContentChoice {
-    #[serde(rename = "customXml")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXml"))]
     CustomXml(Box<CTCustomXmlBlock>),
-    #[serde(rename = "sdt")]
+    #[cfg_attr(feature = "serde", serde(rename = "sdt"))]
     Sdt(Box<CTSdtBlock>),
-    #[serde(rename = "p")]
+    #[cfg_attr(feature = "serde", serde(rename = "p"))]
     P(Box<Paragraph>),
-    #[serde(rename = "tbl")]
+    #[cfg_attr(feature = "serde", serde(rename = "tbl"))]
     Tbl(Box<Table>),
-    #[serde(rename = "proofErr")]
+    #[cfg_attr(feature = "serde", serde(rename = "proofErr"))]
     ProofErr(Box<CTProofErr>),
-    #[serde(rename = "permStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "permStart"))]
     PermStart(Box<CTPermStart>),
-    #[serde(rename = "permEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "permEnd"))]
     PermEnd(Box<CTPerm>),
-    #[serde(rename = "bookmarkStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "bookmarkStart"))]
     BookmarkStart(Box<Bookmark>),
-    #[serde(rename = "bookmarkEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "bookmarkEnd"))]
     BookmarkEnd(Box<CTMarkupRange>),
-    #[serde(rename = "moveFromRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFromRangeStart"))]
     MoveFromRangeStart(Box<CTMoveBookmark>),
-    #[serde(rename = "moveFromRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFromRangeEnd"))]
     MoveFromRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "moveToRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveToRangeStart"))]
     MoveToRangeStart(Box<CTMoveBookmark>),
-    #[serde(rename = "moveToRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveToRangeEnd"))]
     MoveToRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "commentRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "commentRangeStart"))]
     CommentRangeStart(Box<CTMarkupRange>),
-    #[serde(rename = "commentRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "commentRangeEnd"))]
     CommentRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "customXmlInsRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlInsRangeStart"))]
     CustomXmlInsRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlInsRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlInsRangeEnd"))]
     CustomXmlInsRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlDelRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlDelRangeStart"))]
     CustomXmlDelRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlDelRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlDelRangeEnd"))]
     CustomXmlDelRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlMoveFromRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveFromRangeStart"))]
     CustomXmlMoveFromRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlMoveFromRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveFromRangeEnd"))]
     CustomXmlMoveFromRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlMoveToRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveToRangeStart"))]
     CustomXmlMoveToRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlMoveToRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveToRangeEnd"))]
     CustomXmlMoveToRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "ins")]
+    #[cfg_attr(feature = "serde", serde(rename = "ins"))]
     Ins(Box<CTRunTrackChange>),
-    #[serde(rename = "del")]
+    #[cfg_attr(feature = "serde", serde(rename = "del"))]
     Del(Box<CTRunTrackChange>),
-    #[serde(rename = "moveFrom")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFrom"))]
     MoveFrom(Box<CTRunTrackChange>),
-    #[serde(rename = "moveTo")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveTo"))]
     MoveTo(Box<CTRunTrackChange>),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum RowContent {
-    #[serde(rename = "tr")]
+    #[cfg_attr(feature = "serde", serde(rename = "tr"))]
     Tr(Box<CTRow>),
-    #[serde(rename = "customXml")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXml"))]
     CustomXml(Box<CTCustomXmlRow>),
-    #[serde(rename = "sdt")]
+    #[cfg_attr(feature = "serde", serde(rename = "sdt"))]
     Sdt(Box<CTSdtRow>),
-    #[serde(rename = "proofErr")]
+    #[cfg_attr(feature = "serde", serde(rename = "proofErr"))]
     ProofErr(Box<CTProofErr>),
-    #[serde(rename = "permStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "permStart"))]
     PermStart(Box<CTPermStart>),
-    #[serde(rename = "permEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "permEnd"))]
     PermEnd(Box<CTPerm>),
-    #[serde(rename = "bookmarkStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "bookmarkStart"))]
     BookmarkStart(Box<Bookmark>),
-    #[serde(rename = "bookmarkEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "bookmarkEnd"))]
     BookmarkEnd(Box<CTMarkupRange>),
-    #[serde(rename = "moveFromRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFromRangeStart"))]
     MoveFromRangeStart(Box<CTMoveBookmark>),
-    #[serde(rename = "moveFromRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFromRangeEnd"))]
     MoveFromRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "moveToRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveToRangeStart"))]
     MoveToRangeStart(Box<CTMoveBookmark>),
-    #[serde(rename = "moveToRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveToRangeEnd"))]
     MoveToRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "commentRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "commentRangeStart"))]
     CommentRangeStart(Box<CTMarkupRange>),
-    #[serde(rename = "commentRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "commentRangeEnd"))]
     CommentRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "customXmlInsRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlInsRangeStart"))]
     CustomXmlInsRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlInsRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlInsRangeEnd"))]
     CustomXmlInsRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlDelRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlDelRangeStart"))]
     CustomXmlDelRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlDelRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlDelRangeEnd"))]
     CustomXmlDelRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlMoveFromRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveFromRangeStart"))]
     CustomXmlMoveFromRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlMoveFromRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveFromRangeEnd"))]
     CustomXmlMoveFromRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlMoveToRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveToRangeStart"))]
     CustomXmlMoveToRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlMoveToRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveToRangeEnd"))]
     CustomXmlMoveToRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "ins")]
+    #[cfg_attr(feature = "serde", serde(rename = "ins"))]
     Ins(Box<CTRunTrackChange>),
-    #[serde(rename = "del")]
+    #[cfg_attr(feature = "serde", serde(rename = "del"))]
     Del(Box<CTRunTrackChange>),
-    #[serde(rename = "moveFrom")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFrom"))]
     MoveFrom(Box<CTRunTrackChange>),
-    #[serde(rename = "moveTo")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveTo"))]
     MoveTo(Box<CTRunTrackChange>),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum CellContent {
-    #[serde(rename = "tc")]
+    #[cfg_attr(feature = "serde", serde(rename = "tc"))]
     Tc(Box<TableCell>),
-    #[serde(rename = "customXml")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXml"))]
     CustomXml(Box<CTCustomXmlCell>),
-    #[serde(rename = "sdt")]
+    #[cfg_attr(feature = "serde", serde(rename = "sdt"))]
     Sdt(Box<CTSdtCell>),
-    #[serde(rename = "proofErr")]
+    #[cfg_attr(feature = "serde", serde(rename = "proofErr"))]
     ProofErr(Box<CTProofErr>),
-    #[serde(rename = "permStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "permStart"))]
     PermStart(Box<CTPermStart>),
-    #[serde(rename = "permEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "permEnd"))]
     PermEnd(Box<CTPerm>),
-    #[serde(rename = "bookmarkStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "bookmarkStart"))]
     BookmarkStart(Box<Bookmark>),
-    #[serde(rename = "bookmarkEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "bookmarkEnd"))]
     BookmarkEnd(Box<CTMarkupRange>),
-    #[serde(rename = "moveFromRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFromRangeStart"))]
     MoveFromRangeStart(Box<CTMoveBookmark>),
-    #[serde(rename = "moveFromRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFromRangeEnd"))]
     MoveFromRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "moveToRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveToRangeStart"))]
     MoveToRangeStart(Box<CTMoveBookmark>),
-    #[serde(rename = "moveToRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveToRangeEnd"))]
     MoveToRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "commentRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "commentRangeStart"))]
     CommentRangeStart(Box<CTMarkupRange>),
-    #[serde(rename = "commentRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "commentRangeEnd"))]
     CommentRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "customXmlInsRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlInsRangeStart"))]
     CustomXmlInsRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlInsRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlInsRangeEnd"))]
     CustomXmlInsRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlDelRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlDelRangeStart"))]
     CustomXmlDelRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlDelRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlDelRangeEnd"))]
     CustomXmlDelRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlMoveFromRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveFromRangeStart"))]
     CustomXmlMoveFromRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlMoveFromRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveFromRangeEnd"))]
     CustomXmlMoveFromRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlMoveToRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveToRangeStart"))]
     CustomXmlMoveToRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlMoveToRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveToRangeEnd"))]
     CustomXmlMoveToRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "ins")]
+    #[cfg_attr(feature = "serde", serde(rename = "ins"))]
     Ins(Box<CTRunTrackChange>),
-    #[serde(rename = "del")]
+    #[cfg_attr(feature = "serde", serde(rename = "del"))]
     Del(Box<CTRunTrackChange>),
-    #[serde(rename = "moveFrom")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFrom"))]
     MoveFrom(Box<CTRunTrackChange>),
-    #[serde(rename = "moveTo")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveTo"))]
     MoveTo(Box<CTRunTrackChange>),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ParagraphContent {
-    #[serde(rename = "customXml")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXml"))]
     CustomXml(Box<CTCustomXmlRun>),
-    #[serde(rename = "smartTag")]
+    #[cfg_attr(feature = "serde", serde(rename = "smartTag"))]
     SmartTag(Box<CTSmartTagRun>),
-    #[serde(rename = "sdt")]
+    #[cfg_attr(feature = "serde", serde(rename = "sdt"))]
     Sdt(Box<CTSdtRun>),
-    #[serde(rename = "dir")]
+    #[cfg_attr(feature = "serde", serde(rename = "dir"))]
     Dir(Box<CTDirContentRun>),
-    #[serde(rename = "bdo")]
+    #[cfg_attr(feature = "serde", serde(rename = "bdo"))]
     Bdo(Box<CTBdoContentRun>),
-    #[serde(rename = "r")]
+    #[cfg_attr(feature = "serde", serde(rename = "r"))]
     R(Box<Run>),
-    #[serde(rename = "proofErr")]
+    #[cfg_attr(feature = "serde", serde(rename = "proofErr"))]
     ProofErr(Box<CTProofErr>),
-    #[serde(rename = "permStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "permStart"))]
     PermStart(Box<CTPermStart>),
-    #[serde(rename = "permEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "permEnd"))]
     PermEnd(Box<CTPerm>),
-    #[serde(rename = "bookmarkStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "bookmarkStart"))]
     BookmarkStart(Box<Bookmark>),
-    #[serde(rename = "bookmarkEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "bookmarkEnd"))]
     BookmarkEnd(Box<CTMarkupRange>),
-    #[serde(rename = "moveFromRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFromRangeStart"))]
     MoveFromRangeStart(Box<CTMoveBookmark>),
-    #[serde(rename = "moveFromRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFromRangeEnd"))]
     MoveFromRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "moveToRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveToRangeStart"))]
     MoveToRangeStart(Box<CTMoveBookmark>),
-    #[serde(rename = "moveToRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveToRangeEnd"))]
     MoveToRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "commentRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "commentRangeStart"))]
     CommentRangeStart(Box<CTMarkupRange>),
-    #[serde(rename = "commentRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "commentRangeEnd"))]
     CommentRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "customXmlInsRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlInsRangeStart"))]
     CustomXmlInsRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlInsRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlInsRangeEnd"))]
     CustomXmlInsRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlDelRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlDelRangeStart"))]
     CustomXmlDelRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlDelRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlDelRangeEnd"))]
     CustomXmlDelRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlMoveFromRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveFromRangeStart"))]
     CustomXmlMoveFromRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlMoveFromRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveFromRangeEnd"))]
     CustomXmlMoveFromRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlMoveToRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveToRangeStart"))]
     CustomXmlMoveToRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlMoveToRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveToRangeEnd"))]
     CustomXmlMoveToRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "ins")]
+    #[cfg_attr(feature = "serde", serde(rename = "ins"))]
     Ins(Box<CTRunTrackChange>),
-    #[serde(rename = "del")]
+    #[cfg_attr(feature = "serde", serde(rename = "del"))]
     Del(Box<CTRunTrackChange>),
-    #[serde(rename = "moveFrom")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFrom"))]
     MoveFrom(Box<CTRunTrackChange>),
-    #[serde(rename = "moveTo")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveTo"))]
     MoveTo(Box<CTRunTrackChange>),
-    #[serde(rename = "fldSimple")]
+    #[cfg_attr(feature = "serde", serde(rename = "fldSimple"))]
     FldSimple(Box<CTSimpleField>),
-    #[serde(rename = "hyperlink")]
+    #[cfg_attr(feature = "serde", serde(rename = "hyperlink"))]
     Hyperlink(Box<Hyperlink>),
-    #[serde(rename = "subDoc")]
+    #[cfg_attr(feature = "serde", serde(rename = "subDoc"))]
     SubDoc(Box<CTRel>),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BlockContent {
-    #[serde(rename = "customXml")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXml"))]
     CustomXml(Box<CTCustomXmlBlock>),
-    #[serde(rename = "sdt")]
+    #[cfg_attr(feature = "serde", serde(rename = "sdt"))]
     Sdt(Box<CTSdtBlock>),
-    #[serde(rename = "p")]
+    #[cfg_attr(feature = "serde", serde(rename = "p"))]
     P(Box<Paragraph>),
-    #[serde(rename = "tbl")]
+    #[cfg_attr(feature = "serde", serde(rename = "tbl"))]
     Tbl(Box<Table>),
-    #[serde(rename = "proofErr")]
+    #[cfg_attr(feature = "serde", serde(rename = "proofErr"))]
     ProofErr(Box<CTProofErr>),
-    #[serde(rename = "permStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "permStart"))]
     PermStart(Box<CTPermStart>),
-    #[serde(rename = "permEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "permEnd"))]
     PermEnd(Box<CTPerm>),
-    #[serde(rename = "bookmarkStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "bookmarkStart"))]
     BookmarkStart(Box<Bookmark>),
-    #[serde(rename = "bookmarkEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "bookmarkEnd"))]
     BookmarkEnd(Box<CTMarkupRange>),
-    #[serde(rename = "moveFromRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFromRangeStart"))]
     MoveFromRangeStart(Box<CTMoveBookmark>),
-    #[serde(rename = "moveFromRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFromRangeEnd"))]
     MoveFromRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "moveToRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveToRangeStart"))]
     MoveToRangeStart(Box<CTMoveBookmark>),
-    #[serde(rename = "moveToRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveToRangeEnd"))]
     MoveToRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "commentRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "commentRangeStart"))]
     CommentRangeStart(Box<CTMarkupRange>),
-    #[serde(rename = "commentRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "commentRangeEnd"))]
     CommentRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "customXmlInsRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlInsRangeStart"))]
     CustomXmlInsRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlInsRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlInsRangeEnd"))]
     CustomXmlInsRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlDelRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlDelRangeStart"))]
     CustomXmlDelRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlDelRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlDelRangeEnd"))]
     CustomXmlDelRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlMoveFromRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveFromRangeStart"))]
     CustomXmlMoveFromRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlMoveFromRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveFromRangeEnd"))]
     CustomXmlMoveFromRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlMoveToRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveToRangeStart"))]
     CustomXmlMoveToRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlMoveToRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveToRangeEnd"))]
     CustomXmlMoveToRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "ins")]
+    #[cfg_attr(feature = "serde", serde(rename = "ins"))]
     Ins(Box<CTRunTrackChange>),
-    #[serde(rename = "del")]
+    #[cfg_attr(feature = "serde", serde(rename = "del"))]
     Del(Box<CTRunTrackChange>),
-    #[serde(rename = "moveFrom")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFrom"))]
     MoveFrom(Box<CTRunTrackChange>),
-    #[serde(rename = "moveTo")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveTo"))]
     MoveTo(Box<CTRunTrackChange>),
-    #[serde(rename = "altChunk")]
+    #[cfg_attr(feature = "serde", serde(rename = "altChunk"))]
     AltChunk(Box<CTAltChunk>),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum RunLevelContent {
-    #[serde(rename = "proofErr")]
+    #[cfg_attr(feature = "serde", serde(rename = "proofErr"))]
     ProofErr(Box<CTProofErr>),
-    #[serde(rename = "permStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "permStart"))]
     PermStart(Box<CTPermStart>),
-    #[serde(rename = "permEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "permEnd"))]
     PermEnd(Box<CTPerm>),
-    #[serde(rename = "bookmarkStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "bookmarkStart"))]
     BookmarkStart(Box<Bookmark>),
-    #[serde(rename = "bookmarkEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "bookmarkEnd"))]
     BookmarkEnd(Box<CTMarkupRange>),
-    #[serde(rename = "moveFromRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFromRangeStart"))]
     MoveFromRangeStart(Box<CTMoveBookmark>),
-    #[serde(rename = "moveFromRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFromRangeEnd"))]
     MoveFromRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "moveToRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveToRangeStart"))]
     MoveToRangeStart(Box<CTMoveBookmark>),
-    #[serde(rename = "moveToRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveToRangeEnd"))]
     MoveToRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "commentRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "commentRangeStart"))]
     CommentRangeStart(Box<CTMarkupRange>),
-    #[serde(rename = "commentRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "commentRangeEnd"))]
     CommentRangeEnd(Box<CTMarkupRange>),
-    #[serde(rename = "customXmlInsRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlInsRangeStart"))]
     CustomXmlInsRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlInsRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlInsRangeEnd"))]
     CustomXmlInsRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlDelRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlDelRangeStart"))]
     CustomXmlDelRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlDelRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlDelRangeEnd"))]
     CustomXmlDelRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlMoveFromRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveFromRangeStart"))]
     CustomXmlMoveFromRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlMoveFromRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveFromRangeEnd"))]
     CustomXmlMoveFromRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "customXmlMoveToRangeStart")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveToRangeStart"))]
     CustomXmlMoveToRangeStart(Box<CTTrackChange>),
-    #[serde(rename = "customXmlMoveToRangeEnd")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlMoveToRangeEnd"))]
     CustomXmlMoveToRangeEnd(Box<CTMarkup>),
-    #[serde(rename = "ins")]
+    #[cfg_attr(feature = "serde", serde(rename = "ins"))]
     Ins(Box<CTRunTrackChange>),
-    #[serde(rename = "del")]
+    #[cfg_attr(feature = "serde", serde(rename = "del"))]
     Del(Box<CTRunTrackChange>),
-    #[serde(rename = "moveFrom")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFrom"))]
     MoveFrom(Box<CTRunTrackChange>),
-    #[serde(rename = "moveTo")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveTo"))]
     MoveTo(Box<CTRunTrackChange>),
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTEmpty;
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct OnOffElement {
-    #[serde(rename = "@w:val")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<OnOff>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LongHexNumberElement {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STLongHexNumber,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTCharset {
-    #[serde(rename = "@w:val")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<STUcharHexNumber>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:characterSet")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:characterSet"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub character_set: Option<STString>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTDecimalNumber {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STDecimalNumber,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UnsignedDecimalNumberElement {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STUnsignedDecimalNumber,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTDecimalNumberOrPrecent {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STDecimalNumberOrPercent,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TwipsMeasureElement {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STTwipsMeasure,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SignedTwipsMeasureElement {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STSignedTwipsMeasure,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PixelsMeasureElement {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STPixelsMeasure,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct HpsMeasureElement {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STHpsMeasure,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SignedHpsMeasureElement {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STSignedHpsMeasure,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MacroNameElement {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STMacroName,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTString {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STString,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TextScaleElement {
-    #[serde(rename = "@w:val")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<STTextScale>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTHighlight {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STHighlightColor,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTColor {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STHexColor,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:themeColor")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeColor"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_color: Option<STThemeColor>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:themeTint")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeTint"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_tint: Option<STUcharHexNumber>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:themeShade")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeShade"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_shade: Option<STUcharHexNumber>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTLang {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: Language,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GuidElement {
-    #[serde(rename = "@w:val")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<Guid>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTUnderline {
-    #[serde(rename = "@w:val")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<STUnderline>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:color")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:color"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub color: Option<STHexColor>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:themeColor")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeColor"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_color: Option<STThemeColor>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:themeTint")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeTint"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_tint: Option<STUcharHexNumber>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:themeShade")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeShade"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_shade: Option<STUcharHexNumber>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTextEffect {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STTextEffect,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTBorder {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STBorder,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:color")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:color"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub color: Option<STHexColor>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:themeColor")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeColor"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_color: Option<STThemeColor>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:themeTint")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeTint"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_tint: Option<STUcharHexNumber>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:themeShade")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeShade"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_shade: Option<STUcharHexNumber>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:sz")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:sz"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub size: Option<STEighthPointMeasure>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:space")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:space"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub space: Option<STPointMeasure>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:shadow")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:shadow"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shadow: Option<OnOff>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:frame")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:frame"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub frame: Option<OnOff>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTShd {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STShd,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:color")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:color"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub color: Option<STHexColor>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:themeColor")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeColor"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_color: Option<STThemeColor>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:themeTint")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeTint"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_tint: Option<STUcharHexNumber>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:themeShade")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeShade"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_shade: Option<STUcharHexNumber>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:fill")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:fill"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub fill: Option<STHexColor>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:themeFill")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeFill"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_fill: Option<STThemeColor>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:themeFillTint")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeFillTint"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_fill_tint: Option<STUcharHexNumber>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:themeFillShade")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeFillShade"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_fill_shade: Option<STUcharHexNumber>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTVerticalAlignRun {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STVerticalAlignRun,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTFitText {
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STTwipsMeasure,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:id")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub id: Option<STDecimalNumber>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTEm {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STEm,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LanguageElement {
-    #[serde(rename = "@w:val")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<Language>,
-    #[serde(rename = "@w:eastAsia")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:eastAsia"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub east_asia: Option<Language>,
-    #[serde(rename = "@w:bidi")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:bidi"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bidi: Option<Language>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTEastAsianLayout {
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:id")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub id: Option<STDecimalNumber>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:combine")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:combine"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub combine: Option<OnOff>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:combineBrackets")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:combineBrackets"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub combine_brackets: Option<STCombineBrackets>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:vert")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:vert"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub vert: Option<OnOff>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:vertCompress")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:vertCompress"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub vert_compress: Option<OnOff>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTFramePr {
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:dropCap")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:dropCap"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub drop_cap: Option<STDropCap>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:lines")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:lines"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub lines: Option<STDecimalNumber>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:w")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:w"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub width: Option<STTwipsMeasure>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:h")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:h"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub height: Option<STTwipsMeasure>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:vSpace")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:vSpace"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub v_space: Option<STTwipsMeasure>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:hSpace")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:hSpace"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub h_space: Option<STTwipsMeasure>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:wrap")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:wrap"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub wrap: Option<STWrap>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:hAnchor")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:hAnchor"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub h_anchor: Option<STHAnchor>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:vAnchor")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:vAnchor"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub v_anchor: Option<STVAnchor>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:x")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:x"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub x: Option<STSignedTwipsMeasure>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:xAlign")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:xAlign"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub x_align: Option<STXAlign>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:y")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:y"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub y: Option<STSignedTwipsMeasure>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:yAlign")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:yAlign"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub y_align: Option<STYAlign>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:hRule")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:hRule"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub h_rule: Option<STHeightRule>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:anchorLock")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:anchorLock"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub anchor_lock: Option<OnOff>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTabStop {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STTabJc,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:leader")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:leader"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub leader: Option<STTabTlc>,
-    #[serde(rename = "@w:pos")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:pos"))]
     pub pos: STSignedTwipsMeasure,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSpacing {
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:before")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:before"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub before: Option<STTwipsMeasure>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:beforeLines")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:beforeLines"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub before_lines: Option<STDecimalNumber>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:beforeAutospacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:beforeAutospacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub before_autospacing: Option<OnOff>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:after")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:after"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub after: Option<STTwipsMeasure>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:afterLines")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:afterLines"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub after_lines: Option<STDecimalNumber>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:afterAutospacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:afterAutospacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub after_autospacing: Option<OnOff>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:line")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:line"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub line: Option<STSignedTwipsMeasure>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:lineRule")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:lineRule"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub line_rule: Option<STLineSpacingRule>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTInd {
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:start")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:start"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub start: Option<STSignedTwipsMeasure>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:startChars")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:startChars"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub start_chars: Option<STDecimalNumber>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:end")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:end"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub end: Option<STSignedTwipsMeasure>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:endChars")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:endChars"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub end_chars: Option<STDecimalNumber>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:left")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:left"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub left: Option<STSignedTwipsMeasure>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:leftChars")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:leftChars"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub left_chars: Option<STDecimalNumber>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:right")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:right"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub right: Option<STSignedTwipsMeasure>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:rightChars")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:rightChars"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub right_chars: Option<STDecimalNumber>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:hanging")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:hanging"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hanging: Option<STTwipsMeasure>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:hangingChars")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:hangingChars"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hanging_chars: Option<STDecimalNumber>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:firstLine")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:firstLine"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub first_line: Option<STTwipsMeasure>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:firstLineChars")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:firstLineChars"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub first_line_chars: Option<STDecimalNumber>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTJc {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STJc,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTJcTable {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STJcTable,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTView {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STView,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTZoom {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:val")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<STZoom>,
-    #[serde(rename = "@w:percent")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:percent"))]
     pub percent: STDecimalNumberOrPercent,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTWritingStyle {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:lang")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:lang"))]
     pub lang: Language,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:vendorID")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:vendorID"))]
     pub vendor_i_d: STString,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:dllVersion")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:dllVersion"))]
     pub dll_version: STString,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:nlCheck")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:nlCheck"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub nl_check: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:checkStyle")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:checkStyle"))]
     pub check_style: OnOff,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:appName")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:appName"))]
     pub app_name: STString,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTProof {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:spelling")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:spelling"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spelling: Option<STProof>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:grammar")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:grammar"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub grammar: Option<STProof>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DocTypeElement {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STDocType,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct WAGPassword {
-    #[serde(rename = "@w:algorithmName")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:algorithmName"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub algorithm_name: Option<STString>,
-    #[serde(rename = "@w:hashValue")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:hashValue"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hash_value: Option<Vec<u8>>,
-    #[serde(rename = "@w:saltValue")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:saltValue"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub salt_value: Option<Vec<u8>>,
-    #[serde(rename = "@w:spinCount")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:spinCount"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spin_count: Option<STDecimalNumber>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct WAGTransitionalPassword {
-    #[serde(rename = "@w:cryptProviderType")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:cryptProviderType"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_provider_type: Option<STCryptProv>,
-    #[serde(rename = "@w:cryptAlgorithmClass")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:cryptAlgorithmClass"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_algorithm_class: Option<STAlgClass>,
-    #[serde(rename = "@w:cryptAlgorithmType")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:cryptAlgorithmType"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_algorithm_type: Option<STAlgType>,
-    #[serde(rename = "@w:cryptAlgorithmSid")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:cryptAlgorithmSid"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_algorithm_sid: Option<STDecimalNumber>,
-    #[serde(rename = "@w:cryptSpinCount")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:cryptSpinCount"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_spin_count: Option<STDecimalNumber>,
-    #[serde(rename = "@w:cryptProvider")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:cryptProvider"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_provider: Option<STString>,
-    #[serde(rename = "@w:algIdExt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:algIdExt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub alg_id_ext: Option<STLongHexNumber>,
-    #[serde(rename = "@w:algIdExtSource")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:algIdExtSource"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub alg_id_ext_source: Option<STString>,
-    #[serde(rename = "@w:cryptProviderTypeExt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:cryptProviderTypeExt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_provider_type_ext: Option<STLongHexNumber>,
-    #[serde(rename = "@w:cryptProviderTypeExtSource")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:cryptProviderTypeExtSource"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_provider_type_ext_source: Option<STString>,
-    #[serde(rename = "@w:hash")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:hash"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hash: Option<Vec<u8>>,
-    #[serde(rename = "@w:salt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:salt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub salt: Option<Vec<u8>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTDocProtect {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:edit")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:edit"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub edit: Option<STDocProtect>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:formatting")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:formatting"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub formatting: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:enforcement")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:enforcement"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub enforcement: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:algorithmName")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:algorithmName"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub algorithm_name: Option<STString>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:hashValue")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:hashValue"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hash_value: Option<Vec<u8>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:saltValue")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:saltValue"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub salt_value: Option<Vec<u8>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:spinCount")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:spinCount"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spin_count: Option<STDecimalNumber>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:cryptProviderType")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:cryptProviderType"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_provider_type: Option<STCryptProv>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:cryptAlgorithmClass")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:cryptAlgorithmClass"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_algorithm_class: Option<STAlgClass>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:cryptAlgorithmType")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:cryptAlgorithmType"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_algorithm_type: Option<STAlgType>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:cryptAlgorithmSid")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:cryptAlgorithmSid"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_algorithm_sid: Option<STDecimalNumber>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:cryptSpinCount")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:cryptSpinCount"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_spin_count: Option<STDecimalNumber>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:cryptProvider")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:cryptProvider"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_provider: Option<STString>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:algIdExt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:algIdExt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub alg_id_ext: Option<STLongHexNumber>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:algIdExtSource")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:algIdExtSource"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub alg_id_ext_source: Option<STString>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:cryptProviderTypeExt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:cryptProviderTypeExt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_provider_type_ext: Option<STLongHexNumber>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:cryptProviderTypeExtSource")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:cryptProviderTypeExtSource"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_provider_type_ext_source: Option<STString>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:hash")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:hash"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hash: Option<Vec<u8>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:salt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:salt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub salt: Option<Vec<u8>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTMailMergeDocType {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STMailMergeDocType,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MailMergeDataTypeElement {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STMailMergeDataType,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTMailMergeDest {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STMailMergeDest,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTMailMergeOdsoFMDFieldType {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STMailMergeOdsoFMDFieldType,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTrackChangesView {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:markup")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:markup"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub markup: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:comments")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:comments"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub comments: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:insDel")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:insDel"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ins_del: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:formatting")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:formatting"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub formatting: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:inkAnnotations")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:inkAnnotations"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ink_annotations: Option<OnOff>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTKinsoku {
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:lang")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:lang"))]
     pub lang: Language,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STString,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTextDirection {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STTextDirection,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTextAlignment {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STTextAlignment,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTMarkup {
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STDecimalNumber,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTrackChange {
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STDecimalNumber,
-    #[serde(rename = "@w:author")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:author"))]
     pub author: STString,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:date")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:date"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub date: Option<STDateTime>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTCellMergeTrackChange {
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STDecimalNumber,
-    #[serde(rename = "@w:author")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:author"))]
     pub author: STString,
-    #[serde(rename = "@w:date")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:date"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub date: Option<STDateTime>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:vMerge")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:vMerge"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub vertical_merge: Option<STAnnotationVMerge>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:vMergeOrig")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:vMergeOrig"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub v_merge_orig: Option<STAnnotationVMerge>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTrackChangeRange {
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STDecimalNumber,
-    #[serde(rename = "@w:author")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:author"))]
     pub author: STString,
-    #[serde(rename = "@w:date")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:date"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub date: Option<STDateTime>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:displacedByCustomXml")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:displacedByCustomXml"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub displaced_by_custom_xml: Option<STDisplacedByCustomXml>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTMarkupRange {
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STDecimalNumber,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:displacedByCustomXml")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:displacedByCustomXml"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub displaced_by_custom_xml: Option<STDisplacedByCustomXml>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTBookmarkRange {
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STDecimalNumber,
-    #[serde(rename = "@w:displacedByCustomXml")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:displacedByCustomXml"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub displaced_by_custom_xml: Option<STDisplacedByCustomXml>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "@w:colFirst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:colFirst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub col_first: Option<STDecimalNumber>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "@w:colLast")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:colLast"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub col_last: Option<STDecimalNumber>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Bookmark {
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STDecimalNumber,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:displacedByCustomXml")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:displacedByCustomXml"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub displaced_by_custom_xml: Option<STDisplacedByCustomXml>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "@w:colFirst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:colFirst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub col_first: Option<STDecimalNumber>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "@w:colLast")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:colLast"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub col_last: Option<STDecimalNumber>,
-    #[serde(rename = "@w:name")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:name"))]
     pub name: STString,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTMoveBookmark {
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STDecimalNumber,
-    #[serde(rename = "@w:displacedByCustomXml")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:displacedByCustomXml"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub displaced_by_custom_xml: Option<STDisplacedByCustomXml>,
-    #[serde(rename = "@w:colFirst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:colFirst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub col_first: Option<STDecimalNumber>,
-    #[serde(rename = "@w:colLast")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:colLast"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub col_last: Option<STDecimalNumber>,
-    #[serde(rename = "@w:name")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:name"))]
     pub name: STString,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:author")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:author"))]
     pub author: STString,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:date")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:date"))]
     pub date: STDateTime,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Comment {
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STDecimalNumber,
-    #[serde(rename = "@w:author")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:author"))]
     pub author: STString,
     #[cfg(feature = "wml-comments")]
-    #[serde(rename = "@w:date")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:date"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub date: Option<STDateTime>,
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub block_content: Vec<BlockContent>,
     #[cfg(feature = "wml-comments")]
-    #[serde(rename = "@w:initials")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:initials"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub initials: Option<STString>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTrackChangeNumbering {
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STDecimalNumber,
-    #[serde(rename = "@w:author")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:author"))]
     pub author: STString,
-    #[serde(rename = "@w:date")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:date"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub date: Option<STDateTime>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:original")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:original"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub original: Option<STString>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTblPrExChange {
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STDecimalNumber,
-    #[serde(rename = "@w:author")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:author"))]
     pub author: STString,
-    #[serde(rename = "@w:date")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:date"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub date: Option<STDateTime>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "tblPrEx")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblPrEx"))]
     pub tbl_pr_ex: Box<CTTblPrExBase>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTcPrChange {
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STDecimalNumber,
-    #[serde(rename = "@w:author")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:author"))]
     pub author: STString,
-    #[serde(rename = "@w:date")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:date"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub date: Option<STDateTime>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "tcPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "tcPr"))]
     pub cell_properties: Box<CTTcPrInner>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTrPrChange {
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STDecimalNumber,
-    #[serde(rename = "@w:author")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:author"))]
     pub author: STString,
-    #[serde(rename = "@w:date")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:date"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub date: Option<STDateTime>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "trPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "trPr"))]
     pub row_properties: Box<CTTrPrBase>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTblGridChange {
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STDecimalNumber,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "tblGrid")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblGrid"))]
     pub tbl_grid: Box<CTTblGridBase>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTblPrChange {
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STDecimalNumber,
-    #[serde(rename = "@w:author")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:author"))]
     pub author: STString,
-    #[serde(rename = "@w:date")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:date"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub date: Option<STDateTime>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "tblPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblPr"))]
     pub table_properties: Box<CTTblPrBase>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSectPrChange {
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STDecimalNumber,
-    #[serde(rename = "@w:author")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:author"))]
     pub author: STString,
-    #[serde(rename = "@w:date")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:date"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub date: Option<STDateTime>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "sectPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sectPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sect_pr: Option<Box<CTSectPrBase>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTPPrChange {
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STDecimalNumber,
-    #[serde(rename = "@w:author")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:author"))]
     pub author: STString,
-    #[serde(rename = "@w:date")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:date"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub date: Option<STDateTime>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "pPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "pPr"))]
     pub p_pr: Box<CTPPrBase>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTRPrChange {
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STDecimalNumber,
-    #[serde(rename = "@w:author")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:author"))]
     pub author: STString,
-    #[serde(rename = "@w:date")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:date"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub date: Option<STDateTime>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "rPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "rPr"))]
     pub r_pr: Box<CTRPrOriginal>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTParaRPrChange {
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STDecimalNumber,
-    #[serde(rename = "@w:author")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:author"))]
     pub author: STString,
-    #[serde(rename = "@w:date")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:date"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub date: Option<STDateTime>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "rPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "rPr"))]
     pub r_pr: Box<CTParaRPrOriginal>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTRunTrackChange {
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STDecimalNumber,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:author")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:author"))]
     pub author: STString,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:date")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:date"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub date: Option<STDateTime>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub run_content: Vec<RunContentChoice>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MathContent {
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub p_content_base: Vec<ParagraphContentBase>,
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub content_run_content_base: Vec<RunContentBase>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NumberingProperties {
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "ilvl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "ilvl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ilvl: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "numId")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "numId"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub num_id: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "numberingChange")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "numberingChange"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub numbering_change: Option<Box<CTTrackChangeNumbering>>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "ins")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "ins"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ins: Option<Box<CTTrackChange>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTPBdr {
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "top")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "top"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub top: Option<Box<CTBorder>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "left")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "left"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub left: Option<Box<CTBorder>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "bottom")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bottom"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bottom: Option<Box<CTBorder>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "right")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "right"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub right: Option<Box<CTBorder>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "between")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "between"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub between: Option<Box<CTBorder>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "bar")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bar"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bar: Option<Box<CTBorder>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTabs {
-    #[serde(rename = "tab")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "tab"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub tab: Vec<CTTabStop>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTextboxTightWrap {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STTextboxTightWrap,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParagraphProperties {
-    #[serde(rename = "pStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub paragraph_style: Option<Box<CTString>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "keepNext")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "keepNext"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub keep_next: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "keepLines")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "keepLines"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub keep_lines: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "pageBreakBefore")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pageBreakBefore"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub page_break_before: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "framePr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "framePr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub frame_pr: Option<Box<CTFramePr>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "widowControl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "widowControl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub widow_control: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "numPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "numPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub num_pr: Option<Box<NumberingProperties>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "suppressLineNumbers")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "suppressLineNumbers"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub suppress_line_numbers: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "pBdr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pBdr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub paragraph_border: Option<Box<CTPBdr>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "shd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "shd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shading: Option<Box<CTShd>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "tabs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tabs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tabs: Option<Box<CTTabs>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "suppressAutoHyphens")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "suppressAutoHyphens"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub suppress_auto_hyphens: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "kinsoku")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "kinsoku"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub kinsoku: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "wordWrap")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "wordWrap"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub word_wrap: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "overflowPunct")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "overflowPunct"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub overflow_punct: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "topLinePunct")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "topLinePunct"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub top_line_punct: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "autoSpaceDE")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "autoSpaceDE"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub auto_space_d_e: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "autoSpaceDN")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "autoSpaceDN"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub auto_space_d_n: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "bidi")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bidi"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bidi: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "adjustRightInd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "adjustRightInd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub adjust_right_ind: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "snapToGrid")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "snapToGrid"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub snap_to_grid: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "spacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "spacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spacing: Option<Box<CTSpacing>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "ind")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "ind"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub indentation: Option<Box<CTInd>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "contextualSpacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "contextualSpacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub contextual_spacing: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "mirrorIndents")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "mirrorIndents"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub mirror_indents: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "suppressOverlap")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "suppressOverlap"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub suppress_overlap: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "jc")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "jc"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub justification: Option<Box<CTJc>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "textDirection")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "textDirection"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub text_direction: Option<Box<CTTextDirection>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "textAlignment")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "textAlignment"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub text_alignment: Option<Box<CTTextAlignment>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "textboxTightWrap")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "textboxTightWrap"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub textbox_tight_wrap: Option<Box<CTTextboxTightWrap>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "outlineLvl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "outlineLvl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub outline_lvl: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "divId")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "divId"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub div_id: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "cnfStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "cnfStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cnf_style: Option<Box<CTCnf>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "rPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r_pr: Option<Box<CTParaRPr>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "sectPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sectPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sect_pr: Option<Box<SectionProperties>>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "pPrChange")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pPrChange"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub p_pr_change: Option<Box<CTPPrChange>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTPPrBase {
-    #[serde(rename = "pStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub paragraph_style: Option<Box<CTString>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "keepNext")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "keepNext"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub keep_next: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "keepLines")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "keepLines"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub keep_lines: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "pageBreakBefore")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pageBreakBefore"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub page_break_before: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "framePr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "framePr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub frame_pr: Option<Box<CTFramePr>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "widowControl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "widowControl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub widow_control: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "numPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "numPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub num_pr: Option<Box<NumberingProperties>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "suppressLineNumbers")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "suppressLineNumbers"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub suppress_line_numbers: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "pBdr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pBdr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub paragraph_border: Option<Box<CTPBdr>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "shd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "shd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shading: Option<Box<CTShd>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "tabs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tabs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tabs: Option<Box<CTTabs>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "suppressAutoHyphens")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "suppressAutoHyphens"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub suppress_auto_hyphens: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "kinsoku")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "kinsoku"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub kinsoku: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "wordWrap")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "wordWrap"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub word_wrap: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "overflowPunct")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "overflowPunct"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub overflow_punct: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "topLinePunct")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "topLinePunct"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub top_line_punct: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "autoSpaceDE")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "autoSpaceDE"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub auto_space_d_e: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "autoSpaceDN")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "autoSpaceDN"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub auto_space_d_n: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "bidi")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bidi"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bidi: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "adjustRightInd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "adjustRightInd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub adjust_right_ind: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "snapToGrid")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "snapToGrid"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub snap_to_grid: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "spacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "spacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spacing: Option<Box<CTSpacing>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "ind")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "ind"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub indentation: Option<Box<CTInd>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "contextualSpacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "contextualSpacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub contextual_spacing: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "mirrorIndents")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "mirrorIndents"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub mirror_indents: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "suppressOverlap")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "suppressOverlap"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub suppress_overlap: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "jc")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "jc"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub justification: Option<Box<CTJc>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "textDirection")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "textDirection"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub text_direction: Option<Box<CTTextDirection>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "textAlignment")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "textAlignment"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub text_alignment: Option<Box<CTTextAlignment>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "textboxTightWrap")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "textboxTightWrap"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub textbox_tight_wrap: Option<Box<CTTextboxTightWrap>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "outlineLvl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "outlineLvl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub outline_lvl: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "divId")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "divId"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub div_id: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "cnfStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "cnfStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cnf_style: Option<Box<CTCnf>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTPPrGeneral {
-    #[serde(rename = "pStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub paragraph_style: Option<Box<CTString>>,
-    #[serde(rename = "keepNext")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "keepNext"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub keep_next: Option<Box<OnOffElement>>,
-    #[serde(rename = "keepLines")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "keepLines"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub keep_lines: Option<Box<OnOffElement>>,
-    #[serde(rename = "pageBreakBefore")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pageBreakBefore"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub page_break_before: Option<Box<OnOffElement>>,
-    #[serde(rename = "framePr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "framePr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub frame_pr: Option<Box<CTFramePr>>,
-    #[serde(rename = "widowControl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "widowControl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub widow_control: Option<Box<OnOffElement>>,
-    #[serde(rename = "numPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "numPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub num_pr: Option<Box<NumberingProperties>>,
-    #[serde(rename = "suppressLineNumbers")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "suppressLineNumbers"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub suppress_line_numbers: Option<Box<OnOffElement>>,
-    #[serde(rename = "pBdr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pBdr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub paragraph_border: Option<Box<CTPBdr>>,
-    #[serde(rename = "shd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "shd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shading: Option<Box<CTShd>>,
-    #[serde(rename = "tabs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tabs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tabs: Option<Box<CTTabs>>,
-    #[serde(rename = "suppressAutoHyphens")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "suppressAutoHyphens"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub suppress_auto_hyphens: Option<Box<OnOffElement>>,
-    #[serde(rename = "kinsoku")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "kinsoku"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub kinsoku: Option<Box<OnOffElement>>,
-    #[serde(rename = "wordWrap")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "wordWrap"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub word_wrap: Option<Box<OnOffElement>>,
-    #[serde(rename = "overflowPunct")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "overflowPunct"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub overflow_punct: Option<Box<OnOffElement>>,
-    #[serde(rename = "topLinePunct")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "topLinePunct"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub top_line_punct: Option<Box<OnOffElement>>,
-    #[serde(rename = "autoSpaceDE")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "autoSpaceDE"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub auto_space_d_e: Option<Box<OnOffElement>>,
-    #[serde(rename = "autoSpaceDN")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "autoSpaceDN"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub auto_space_d_n: Option<Box<OnOffElement>>,
-    #[serde(rename = "bidi")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bidi"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bidi: Option<Box<OnOffElement>>,
-    #[serde(rename = "adjustRightInd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "adjustRightInd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub adjust_right_ind: Option<Box<OnOffElement>>,
-    #[serde(rename = "snapToGrid")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "snapToGrid"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub snap_to_grid: Option<Box<OnOffElement>>,
-    #[serde(rename = "spacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "spacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spacing: Option<Box<CTSpacing>>,
-    #[serde(rename = "ind")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "ind"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub indentation: Option<Box<CTInd>>,
-    #[serde(rename = "contextualSpacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "contextualSpacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub contextual_spacing: Option<Box<OnOffElement>>,
-    #[serde(rename = "mirrorIndents")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "mirrorIndents"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub mirror_indents: Option<Box<OnOffElement>>,
-    #[serde(rename = "suppressOverlap")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "suppressOverlap"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub suppress_overlap: Option<Box<OnOffElement>>,
-    #[serde(rename = "jc")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "jc"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub justification: Option<Box<CTJc>>,
-    #[serde(rename = "textDirection")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "textDirection"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub text_direction: Option<Box<CTTextDirection>>,
-    #[serde(rename = "textAlignment")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "textAlignment"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub text_alignment: Option<Box<CTTextAlignment>>,
-    #[serde(rename = "textboxTightWrap")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "textboxTightWrap"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub textbox_tight_wrap: Option<Box<CTTextboxTightWrap>>,
-    #[serde(rename = "outlineLvl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "outlineLvl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub outline_lvl: Option<Box<CTDecimalNumber>>,
-    #[serde(rename = "divId")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "divId"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub div_id: Option<Box<CTDecimalNumber>>,
-    #[serde(rename = "cnfStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "cnfStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cnf_style: Option<Box<CTCnf>>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "pPrChange")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pPrChange"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub p_pr_change: Option<Box<CTPPrChange>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTControl {
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "@w:name")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:name"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub name: Option<STString>,
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "@w:shapeid")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:shapeid"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shapeid: Option<STString>,
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "@r:id")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@r:id"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub id: Option<STRelationshipId>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTBackground {
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:color")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:color"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub color: Option<STHexColor>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:themeColor")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeColor"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_color: Option<STThemeColor>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:themeTint")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeTint"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_tint: Option<STUcharHexNumber>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:themeShade")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeShade"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_shade: Option<STUcharHexNumber>,
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "drawing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "drawing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub drawing: Option<Box<CTDrawing>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTRel {
-    #[serde(rename = "@r:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@r:id"))]
     pub id: STRelationshipId,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTObject {
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "@w:dxaOrig")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:dxaOrig"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub dxa_orig: Option<STTwipsMeasure>,
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "@w:dyaOrig")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:dyaOrig"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub dya_orig: Option<STTwipsMeasure>,
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "drawing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "drawing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub drawing: Option<Box<CTDrawing>>,
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "control")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "control"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub control: Option<Box<CTControl>>,
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "objectLink")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "objectLink"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub object_link: Option<Box<CTObjectLink>>,
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "objectEmbed")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "objectEmbed"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub object_embed: Option<Box<CTObjectEmbed>>,
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "movie")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "movie"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub movie: Option<Box<CTRel>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTPicture {
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "movie")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "movie"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub movie: Option<Box<CTRel>>,
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "control")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "control"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub control: Option<Box<CTControl>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTObjectEmbed {
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "@w:drawAspect")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:drawAspect"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub draw_aspect: Option<STObjectDrawAspect>,
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "@r:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@r:id"))]
     pub id: STRelationshipId,
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "@w:progId")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:progId"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub prog_id: Option<STString>,
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "@w:shapeId")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:shapeId"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shape_id: Option<STString>,
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "@w:fieldCodes")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:fieldCodes"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub field_codes: Option<STString>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTObjectLink {
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "@w:drawAspect")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:drawAspect"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub draw_aspect: Option<STObjectDrawAspect>,
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "@r:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@r:id"))]
     pub id: STRelationshipId,
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "@w:progId")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:progId"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub prog_id: Option<STString>,
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "@w:shapeId")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:shapeId"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shape_id: Option<STString>,
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "@w:fieldCodes")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:fieldCodes"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub field_codes: Option<STString>,
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "@w:updateMode")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:updateMode"))]
     pub update_mode: STObjectUpdateMode,
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "@w:lockedField")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:lockedField"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub locked_field: Option<OnOff>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTDrawing {
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSimpleField {
-    #[serde(rename = "@w:instr")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:instr"))]
     pub instr: STString,
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "@w:fldLock")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:fldLock"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub fld_lock: Option<OnOff>,
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "@w:dirty")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:dirty"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub dirty: Option<OnOff>,
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "fldData")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "fldData"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub fld_data: Option<Box<Text>>,
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub paragraph_content: Vec<ParagraphContent>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTFFTextType {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STFFTextType,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FFNameElement {
-    #[serde(rename = "@w:val")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<STFFName>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTFldChar {
-    #[serde(rename = "@w:fldCharType")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:fldCharType"))]
     pub fld_char_type: STFldCharType,
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "@w:fldLock")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:fldLock"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub fld_lock: Option<OnOff>,
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "@w:dirty")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:dirty"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub dirty: Option<OnOff>,
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "fldData")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "fldData"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub fld_data: Option<Box<Text>>,
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "ffData")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "ffData"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ff_data: Option<Box<CTFFData>>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "numberingChange")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "numberingChange"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub numbering_change: Option<Box<CTTrackChangeNumbering>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Hyperlink {
     #[cfg(feature = "wml-hyperlinks")]
-    #[serde(rename = "@w:tgtFrame")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:tgtFrame"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tgt_frame: Option<STString>,
     #[cfg(feature = "wml-hyperlinks")]
-    #[serde(rename = "@w:tooltip")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:tooltip"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tooltip: Option<STString>,
     #[cfg(feature = "wml-hyperlinks")]
-    #[serde(rename = "@w:docLocation")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:docLocation"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub doc_location: Option<STString>,
     #[cfg(feature = "wml-hyperlinks")]
-    #[serde(rename = "@w:history")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:history"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub history: Option<OnOff>,
     #[cfg(feature = "wml-hyperlinks")]
-    #[serde(rename = "@w:anchor")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:anchor"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub anchor: Option<STString>,
     #[cfg(feature = "wml-hyperlinks")]
-    #[serde(rename = "@r:id")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@r:id"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub id: Option<STRelationshipId>,
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub paragraph_content: Vec<ParagraphContent>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTFFData {
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "name")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "name"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub name: Vec<FFNameElement>,
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "label")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "label"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub label: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "tabIndex")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tabIndex"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tab_index: Option<Box<UnsignedDecimalNumberElement>>,
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "enabled")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "enabled"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub enabled: Vec<OnOffElement>,
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "calcOnExit")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "calcOnExit"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub calc_on_exit: Vec<OnOffElement>,
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "entryMacro")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "entryMacro"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub entry_macro: Option<Box<MacroNameElement>>,
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "exitMacro")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "exitMacro"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub exit_macro: Option<Box<MacroNameElement>>,
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "helpText")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "helpText"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub help_text: Option<Box<CTFFHelpText>>,
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "statusText")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "statusText"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub status_text: Option<Box<CTFFStatusText>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTFFHelpText {
-    #[serde(rename = "@w:type")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:type"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r#type: Option<STInfoTextType>,
-    #[serde(rename = "@w:val")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<STFFHelpTextVal>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTFFStatusText {
-    #[serde(rename = "@w:type")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:type"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r#type: Option<STInfoTextType>,
-    #[serde(rename = "@w:val")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<STFFStatusTextVal>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTFFCheckBox {
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "size")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "size"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub size: Option<Box<HpsMeasureElement>>,
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "sizeAuto")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sizeAuto"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub size_auto: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "default")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "default"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub default: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "checked")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "checked"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub checked: Option<Box<OnOffElement>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTFFDDList {
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "result")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "result"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub result: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "default")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "default"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub default: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "listEntry")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "listEntry"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub list_entry: Vec<CTString>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTFFTextInput {
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "type")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r#type: Option<Box<CTFFTextType>>,
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "default")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "default"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub default: Option<Box<CTString>>,
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "maxLength")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "maxLength"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub max_length: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-fields")]
-    #[serde(rename = "format")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "format"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub format: Option<Box<CTString>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSectType {
-    #[serde(rename = "@w:val")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<STSectionMark>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTPaperSource {
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:first")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:first"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub first: Option<STDecimalNumber>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:other")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:other"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub other: Option<STDecimalNumber>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PageSize {
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:w")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:w"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub width: Option<STTwipsMeasure>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:h")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:h"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub height: Option<STTwipsMeasure>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:orient")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:orient"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub orient: Option<STPageOrientation>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:code")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:code"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub code: Option<STDecimalNumber>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PageMargins {
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:top")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:top"))]
     pub top: STSignedTwipsMeasure,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:right")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:right"))]
     pub right: STTwipsMeasure,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:bottom")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:bottom"))]
     pub bottom: STSignedTwipsMeasure,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:left")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:left"))]
     pub left: STTwipsMeasure,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:header")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:header"))]
     pub header: STTwipsMeasure,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:footer")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:footer"))]
     pub footer: STTwipsMeasure,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:gutter")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:gutter"))]
     pub gutter: STTwipsMeasure,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTPageBorders {
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:zOrder")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:zOrder"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub z_order: Option<STPageBorderZOrder>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:display")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:display"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub display: Option<STPageBorderDisplay>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:offsetFrom")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:offsetFrom"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub offset_from: Option<STPageBorderOffset>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "top")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "top"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub top: Option<Box<CTTopPageBorder>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "left")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "left"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub left: Option<Box<CTPageBorder>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "bottom")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bottom"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bottom: Option<Box<CTBottomPageBorder>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "right")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "right"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub right: Option<Box<CTPageBorder>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTPageBorder {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STBorder,
-    #[serde(rename = "@w:color")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:color"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub color: Option<STHexColor>,
-    #[serde(rename = "@w:themeColor")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeColor"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_color: Option<STThemeColor>,
-    #[serde(rename = "@w:themeTint")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeTint"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_tint: Option<STUcharHexNumber>,
-    #[serde(rename = "@w:themeShade")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeShade"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_shade: Option<STUcharHexNumber>,
-    #[serde(rename = "@w:sz")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:sz"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub size: Option<STEighthPointMeasure>,
-    #[serde(rename = "@w:space")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:space"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub space: Option<STPointMeasure>,
-    #[serde(rename = "@w:shadow")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:shadow"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shadow: Option<OnOff>,
-    #[serde(rename = "@w:frame")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:frame"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub frame: Option<OnOff>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@r:id")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@r:id"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub id: Option<STRelationshipId>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTBottomPageBorder {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STBorder,
-    #[serde(rename = "@w:color")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:color"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub color: Option<STHexColor>,
-    #[serde(rename = "@w:themeColor")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeColor"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_color: Option<STThemeColor>,
-    #[serde(rename = "@w:themeTint")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeTint"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_tint: Option<STUcharHexNumber>,
-    #[serde(rename = "@w:themeShade")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeShade"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_shade: Option<STUcharHexNumber>,
-    #[serde(rename = "@w:sz")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:sz"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub size: Option<STEighthPointMeasure>,
-    #[serde(rename = "@w:space")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:space"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub space: Option<STPointMeasure>,
-    #[serde(rename = "@w:shadow")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:shadow"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shadow: Option<OnOff>,
-    #[serde(rename = "@w:frame")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:frame"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub frame: Option<OnOff>,
-    #[serde(rename = "@r:id")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@r:id"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub id: Option<STRelationshipId>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@r:bottomLeft")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@r:bottomLeft"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bottom_left: Option<STRelationshipId>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@r:bottomRight")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@r:bottomRight"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bottom_right: Option<STRelationshipId>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTopPageBorder {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STBorder,
-    #[serde(rename = "@w:color")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:color"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub color: Option<STHexColor>,
-    #[serde(rename = "@w:themeColor")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeColor"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_color: Option<STThemeColor>,
-    #[serde(rename = "@w:themeTint")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeTint"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_tint: Option<STUcharHexNumber>,
-    #[serde(rename = "@w:themeShade")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:themeShade"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_shade: Option<STUcharHexNumber>,
-    #[serde(rename = "@w:sz")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:sz"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub size: Option<STEighthPointMeasure>,
-    #[serde(rename = "@w:space")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:space"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub space: Option<STPointMeasure>,
-    #[serde(rename = "@w:shadow")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:shadow"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shadow: Option<OnOff>,
-    #[serde(rename = "@w:frame")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:frame"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub frame: Option<OnOff>,
-    #[serde(rename = "@r:id")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@r:id"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub id: Option<STRelationshipId>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@r:topLeft")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@r:topLeft"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub top_left: Option<STRelationshipId>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@r:topRight")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@r:topRight"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub top_right: Option<STRelationshipId>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTLineNumber {
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:countBy")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:countBy"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub count_by: Option<STDecimalNumber>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:start")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:start"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub start: Option<STDecimalNumber>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:distance")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:distance"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub distance: Option<STTwipsMeasure>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:restart")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:restart"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub restart: Option<STLineNumberRestart>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTPageNumber {
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:fmt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:fmt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub fmt: Option<STNumberFormat>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:start")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:start"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub start: Option<STDecimalNumber>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:chapStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:chapStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub chap_style: Option<STDecimalNumber>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:chapSep")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:chapSep"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub chap_sep: Option<STChapterSep>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTColumn {
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:w")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:w"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub width: Option<STTwipsMeasure>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:space")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:space"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub space: Option<STTwipsMeasure>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Columns {
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:equalWidth")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:equalWidth"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub equal_width: Option<OnOff>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:space")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:space"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub space: Option<STTwipsMeasure>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:num")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:num"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub num: Option<STDecimalNumber>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:sep")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:sep"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sep: Option<OnOff>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "col")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "col"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub col: Vec<CTColumn>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTVerticalJc {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STVerticalJc,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DocumentGrid {
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:type")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:type"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r#type: Option<STDocGrid>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:linePitch")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:linePitch"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub line_pitch: Option<STDecimalNumber>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "@w:charSpace")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:charSpace"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub char_space: Option<STDecimalNumber>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct HeaderFooterReference {
-    #[serde(rename = "@r:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@r:id"))]
     pub id: STRelationshipId,
-    #[serde(rename = "@w:type")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:type"))]
     pub r#type: STHdrFtr,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct HeaderFooter {
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub block_content: Vec<BlockContent>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EGSectPrContents {
-    #[serde(rename = "footnotePr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "footnotePr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub footnote_pr: Option<Box<CTFtnProps>>,
-    #[serde(rename = "endnotePr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "endnotePr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub endnote_pr: Option<Box<CTEdnProps>>,
-    #[serde(rename = "type")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r#type: Option<Box<CTSectType>>,
-    #[serde(rename = "pgSz")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pgSz"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub pg_sz: Option<Box<PageSize>>,
-    #[serde(rename = "pgMar")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pgMar"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub pg_mar: Option<Box<PageMargins>>,
-    #[serde(rename = "paperSrc")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "paperSrc"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub paper_src: Option<Box<CTPaperSource>>,
-    #[serde(rename = "pgBorders")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pgBorders"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub pg_borders: Option<Box<CTPageBorders>>,
-    #[serde(rename = "lnNumType")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "lnNumType"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ln_num_type: Option<Box<CTLineNumber>>,
-    #[serde(rename = "pgNumType")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pgNumType"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub pg_num_type: Option<Box<CTPageNumber>>,
-    #[serde(rename = "cols")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "cols"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cols: Option<Box<Columns>>,
-    #[serde(rename = "formProt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "formProt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub form_prot: Option<Box<OnOffElement>>,
-    #[serde(rename = "vAlign")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "vAlign"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub v_align: Option<Box<CTVerticalJc>>,
-    #[serde(rename = "noEndnote")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "noEndnote"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub no_endnote: Option<Box<OnOffElement>>,
-    #[serde(rename = "titlePg")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "titlePg"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub title_pg: Option<Box<OnOffElement>>,
-    #[serde(rename = "textDirection")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "textDirection"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub text_direction: Option<Box<CTTextDirection>>,
-    #[serde(rename = "bidi")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bidi"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bidi: Option<Box<OnOffElement>>,
-    #[serde(rename = "rtlGutter")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rtlGutter"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rtl_gutter: Option<Box<OnOffElement>>,
-    #[serde(rename = "docGrid")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "docGrid"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub doc_grid: Option<Box<DocumentGrid>>,
-    #[serde(rename = "printerSettings")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "printerSettings"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub printer_settings: Option<Box<CTRel>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct WAGSectPrAttributes {
-    #[serde(rename = "@w:rsidRPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:rsidRPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsid_r_pr: Option<STLongHexNumber>,
-    #[serde(rename = "@w:rsidDel")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:rsidDel"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsid_del: Option<STLongHexNumber>,
-    #[serde(rename = "@w:rsidR")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:rsidR"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsid_r: Option<STLongHexNumber>,
-    #[serde(rename = "@w:rsidSect")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:rsidSect"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsid_sect: Option<STLongHexNumber>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSectPrBase {
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:rsidRPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:rsidRPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsid_r_pr: Option<STLongHexNumber>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:rsidDel")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:rsidDel"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsid_del: Option<STLongHexNumber>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:rsidR")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:rsidR"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsid_r: Option<STLongHexNumber>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:rsidSect")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:rsidSect"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsid_sect: Option<STLongHexNumber>,
-    #[serde(rename = "footnotePr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "footnotePr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub footnote_pr: Option<Box<CTFtnProps>>,
-    #[serde(rename = "endnotePr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "endnotePr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub endnote_pr: Option<Box<CTEdnProps>>,
-    #[serde(rename = "type")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r#type: Option<Box<CTSectType>>,
-    #[serde(rename = "pgSz")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pgSz"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub pg_sz: Option<Box<PageSize>>,
-    #[serde(rename = "pgMar")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pgMar"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub pg_mar: Option<Box<PageMargins>>,
-    #[serde(rename = "paperSrc")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "paperSrc"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub paper_src: Option<Box<CTPaperSource>>,
-    #[serde(rename = "pgBorders")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pgBorders"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub pg_borders: Option<Box<CTPageBorders>>,
-    #[serde(rename = "lnNumType")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "lnNumType"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ln_num_type: Option<Box<CTLineNumber>>,
-    #[serde(rename = "pgNumType")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pgNumType"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub pg_num_type: Option<Box<CTPageNumber>>,
-    #[serde(rename = "cols")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "cols"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cols: Option<Box<Columns>>,
-    #[serde(rename = "formProt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "formProt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub form_prot: Option<Box<OnOffElement>>,
-    #[serde(rename = "vAlign")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "vAlign"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub v_align: Option<Box<CTVerticalJc>>,
-    #[serde(rename = "noEndnote")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "noEndnote"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub no_endnote: Option<Box<OnOffElement>>,
-    #[serde(rename = "titlePg")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "titlePg"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub title_pg: Option<Box<OnOffElement>>,
-    #[serde(rename = "textDirection")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "textDirection"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub text_direction: Option<Box<CTTextDirection>>,
-    #[serde(rename = "bidi")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bidi"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bidi: Option<Box<OnOffElement>>,
-    #[serde(rename = "rtlGutter")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rtlGutter"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rtl_gutter: Option<Box<OnOffElement>>,
-    #[serde(rename = "docGrid")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "docGrid"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub doc_grid: Option<Box<DocumentGrid>>,
-    #[serde(rename = "printerSettings")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "printerSettings"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub printer_settings: Option<Box<CTRel>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SectionProperties {
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:rsidRPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:rsidRPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsid_r_pr: Option<STLongHexNumber>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:rsidDel")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:rsidDel"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsid_del: Option<STLongHexNumber>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:rsidR")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:rsidR"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsid_r: Option<STLongHexNumber>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:rsidSect")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:rsidSect"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsid_sect: Option<STLongHexNumber>,
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub header_footer_refs: Vec<HeaderFooterRef>,
     #[cfg(feature = "wml-comments")]
-    #[serde(rename = "footnotePr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "footnotePr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub footnote_pr: Option<Box<CTFtnProps>>,
     #[cfg(feature = "wml-comments")]
-    #[serde(rename = "endnotePr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "endnotePr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub endnote_pr: Option<Box<CTEdnProps>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "type")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r#type: Option<Box<CTSectType>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "pgSz")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pgSz"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub pg_sz: Option<Box<PageSize>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "pgMar")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pgMar"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub pg_mar: Option<Box<PageMargins>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "paperSrc")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "paperSrc"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub paper_src: Option<Box<CTPaperSource>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "pgBorders")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pgBorders"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub pg_borders: Option<Box<CTPageBorders>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "lnNumType")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "lnNumType"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ln_num_type: Option<Box<CTLineNumber>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "pgNumType")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pgNumType"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub pg_num_type: Option<Box<CTPageNumber>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "cols")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "cols"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cols: Option<Box<Columns>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "formProt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "formProt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub form_prot: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "vAlign")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "vAlign"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub v_align: Option<Box<CTVerticalJc>>,
     #[cfg(feature = "wml-comments")]
-    #[serde(rename = "noEndnote")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "noEndnote"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub no_endnote: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "titlePg")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "titlePg"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub title_pg: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "textDirection")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "textDirection"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub text_direction: Option<Box<CTTextDirection>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "bidi")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bidi"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bidi: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "rtlGutter")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rtlGutter"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rtl_gutter: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "docGrid")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "docGrid"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub doc_grid: Option<Box<DocumentGrid>>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "printerSettings")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "printerSettings"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub printer_settings: Option<Box<CTRel>>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "sectPrChange")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sectPrChange"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sect_pr_change: Option<Box<CTSectPrChange>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTBr {
-    #[serde(rename = "@w:type")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:type"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r#type: Option<STBrType>,
-    #[serde(rename = "@w:clear")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:clear"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub clear: Option<STBrClear>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTPTab {
-    #[serde(rename = "@w:alignment")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:alignment"))]
     pub alignment: STPTabAlignment,
-    #[serde(rename = "@w:relativeTo")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:relativeTo"))]
     pub relative_to: STPTabRelativeTo,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:leader")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:leader"))]
     pub leader: STPTabLeader,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSym {
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:font")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:font"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub font: Option<STString>,
-    #[serde(rename = "@w:char")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:char"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub char: Option<STShortHexNumber>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTProofErr {
-    #[serde(rename = "@w:type")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:type"))]
     pub r#type: STProofErr,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTPerm {
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STString,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:displacedByCustomXml")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:displacedByCustomXml"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub displaced_by_custom_xml: Option<STDisplacedByCustomXml>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTPermStart {
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STString,
-    #[serde(rename = "@w:displacedByCustomXml")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:displacedByCustomXml"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub displaced_by_custom_xml: Option<STDisplacedByCustomXml>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:edGrp")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:edGrp"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ed_grp: Option<STEdGrp>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:ed")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:ed"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ed: Option<STString>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "@w:colFirst")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:colFirst"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub col_first: Option<STDecimalNumber>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "@w:colLast")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:colLast"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub col_last: Option<STDecimalNumber>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Text {
-    #[serde(rename = "$text")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "$text"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub text: Option<String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Run {
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:rsidRPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:rsidRPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsid_r_pr: Option<STLongHexNumber>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:rsidDel")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:rsidDel"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsid_del: Option<STLongHexNumber>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:rsidR")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:rsidR"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsid_r: Option<STLongHexNumber>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "rPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r_pr: Option<Box<RunProperties>>,
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub run_content: Vec<RunContent>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Fonts {
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:hint")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:hint"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hint: Option<STHint>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:ascii")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:ascii"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ascii: Option<STString>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:hAnsi")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:hAnsi"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub h_ansi: Option<STString>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:eastAsia")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:eastAsia"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub east_asia: Option<STString>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:cs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:cs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cs: Option<STString>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:asciiTheme")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:asciiTheme"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ascii_theme: Option<STTheme>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:hAnsiTheme")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:hAnsiTheme"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub h_ansi_theme: Option<STTheme>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:eastAsiaTheme")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:eastAsiaTheme"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub east_asia_theme: Option<STTheme>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:cstheme")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:cstheme"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cstheme: Option<STTheme>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EGRPrBase {
-    #[serde(rename = "rStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub run_style: Option<Box<CTString>>,
-    #[serde(rename = "rFonts")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rFonts"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub fonts: Option<Box<Fonts>>,
-    #[serde(rename = "b")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "b"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bold: Option<Box<OnOffElement>>,
-    #[serde(rename = "bCs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bCs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub b_cs: Option<Box<OnOffElement>>,
-    #[serde(rename = "i")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "i"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub italic: Option<Box<OnOffElement>>,
-    #[serde(rename = "iCs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "iCs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub i_cs: Option<Box<OnOffElement>>,
-    #[serde(rename = "caps")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "caps"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub caps: Option<Box<OnOffElement>>,
-    #[serde(rename = "smallCaps")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "smallCaps"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub small_caps: Option<Box<OnOffElement>>,
-    #[serde(rename = "strike")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "strike"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub strikethrough: Option<Box<OnOffElement>>,
-    #[serde(rename = "dstrike")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "dstrike"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub dstrike: Option<Box<OnOffElement>>,
-    #[serde(rename = "outline")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "outline"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub outline: Option<Box<OnOffElement>>,
-    #[serde(rename = "shadow")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "shadow"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shadow: Option<Box<OnOffElement>>,
-    #[serde(rename = "emboss")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "emboss"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub emboss: Option<Box<OnOffElement>>,
-    #[serde(rename = "imprint")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "imprint"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub imprint: Option<Box<OnOffElement>>,
-    #[serde(rename = "noProof")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "noProof"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub no_proof: Option<Box<OnOffElement>>,
-    #[serde(rename = "snapToGrid")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "snapToGrid"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub snap_to_grid: Option<Box<OnOffElement>>,
-    #[serde(rename = "vanish")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "vanish"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub vanish: Option<Box<OnOffElement>>,
-    #[serde(rename = "webHidden")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "webHidden"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub web_hidden: Option<Box<OnOffElement>>,
-    #[serde(rename = "color")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "color"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub color: Option<Box<CTColor>>,
-    #[serde(rename = "spacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "spacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spacing: Option<Box<SignedTwipsMeasureElement>>,
-    #[serde(rename = "w")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "w"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub width: Option<Box<TextScaleElement>>,
-    #[serde(rename = "kern")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "kern"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub kern: Option<Box<HpsMeasureElement>>,
-    #[serde(rename = "position")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "position"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub position: Option<Box<SignedHpsMeasureElement>>,
-    #[serde(rename = "sz")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sz"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub size: Option<Box<HpsMeasureElement>>,
-    #[serde(rename = "szCs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "szCs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub size_complex_script: Option<Box<HpsMeasureElement>>,
-    #[serde(rename = "highlight")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "highlight"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub highlight: Option<Box<CTHighlight>>,
-    #[serde(rename = "u")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "u"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub underline: Option<Box<CTUnderline>>,
-    #[serde(rename = "effect")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "effect"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub effect: Option<Box<CTTextEffect>>,
-    #[serde(rename = "bdr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bdr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bdr: Option<Box<CTBorder>>,
-    #[serde(rename = "shd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "shd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shading: Option<Box<CTShd>>,
-    #[serde(rename = "fitText")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "fitText"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub fit_text: Option<Box<CTFitText>>,
-    #[serde(rename = "vertAlign")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "vertAlign"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub vert_align: Option<Box<CTVerticalAlignRun>>,
-    #[serde(rename = "rtl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rtl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rtl: Option<Box<OnOffElement>>,
-    #[serde(rename = "cs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "cs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cs: Option<Box<OnOffElement>>,
-    #[serde(rename = "em")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "em"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub em: Option<Box<CTEm>>,
-    #[serde(rename = "lang")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "lang"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub lang: Option<Box<LanguageElement>>,
-    #[serde(rename = "eastAsianLayout")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "eastAsianLayout"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub east_asian_layout: Option<Box<CTEastAsianLayout>>,
-    #[serde(rename = "specVanish")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "specVanish"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spec_vanish: Option<Box<OnOffElement>>,
-    #[serde(rename = "oMath")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "oMath"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub o_math: Option<Box<OnOffElement>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EGRPrContent {
-    #[serde(rename = "rStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub run_style: Option<Box<CTString>>,
-    #[serde(rename = "rFonts")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rFonts"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub fonts: Option<Box<Fonts>>,
-    #[serde(rename = "b")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "b"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bold: Option<Box<OnOffElement>>,
-    #[serde(rename = "bCs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bCs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub b_cs: Option<Box<OnOffElement>>,
-    #[serde(rename = "i")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "i"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub italic: Option<Box<OnOffElement>>,
-    #[serde(rename = "iCs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "iCs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub i_cs: Option<Box<OnOffElement>>,
-    #[serde(rename = "caps")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "caps"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub caps: Option<Box<OnOffElement>>,
-    #[serde(rename = "smallCaps")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "smallCaps"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub small_caps: Option<Box<OnOffElement>>,
-    #[serde(rename = "strike")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "strike"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub strikethrough: Option<Box<OnOffElement>>,
-    #[serde(rename = "dstrike")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "dstrike"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub dstrike: Option<Box<OnOffElement>>,
-    #[serde(rename = "outline")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "outline"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub outline: Option<Box<OnOffElement>>,
-    #[serde(rename = "shadow")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "shadow"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shadow: Option<Box<OnOffElement>>,
-    #[serde(rename = "emboss")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "emboss"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub emboss: Option<Box<OnOffElement>>,
-    #[serde(rename = "imprint")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "imprint"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub imprint: Option<Box<OnOffElement>>,
-    #[serde(rename = "noProof")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "noProof"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub no_proof: Option<Box<OnOffElement>>,
-    #[serde(rename = "snapToGrid")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "snapToGrid"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub snap_to_grid: Option<Box<OnOffElement>>,
-    #[serde(rename = "vanish")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "vanish"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub vanish: Option<Box<OnOffElement>>,
-    #[serde(rename = "webHidden")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "webHidden"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub web_hidden: Option<Box<OnOffElement>>,
-    #[serde(rename = "color")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "color"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub color: Option<Box<CTColor>>,
-    #[serde(rename = "spacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "spacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spacing: Option<Box<SignedTwipsMeasureElement>>,
-    #[serde(rename = "w")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "w"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub width: Option<Box<TextScaleElement>>,
-    #[serde(rename = "kern")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "kern"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub kern: Option<Box<HpsMeasureElement>>,
-    #[serde(rename = "position")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "position"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub position: Option<Box<SignedHpsMeasureElement>>,
-    #[serde(rename = "sz")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sz"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub size: Option<Box<HpsMeasureElement>>,
-    #[serde(rename = "szCs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "szCs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub size_complex_script: Option<Box<HpsMeasureElement>>,
-    #[serde(rename = "highlight")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "highlight"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub highlight: Option<Box<CTHighlight>>,
-    #[serde(rename = "u")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "u"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub underline: Option<Box<CTUnderline>>,
-    #[serde(rename = "effect")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "effect"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub effect: Option<Box<CTTextEffect>>,
-    #[serde(rename = "bdr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bdr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bdr: Option<Box<CTBorder>>,
-    #[serde(rename = "shd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "shd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shading: Option<Box<CTShd>>,
-    #[serde(rename = "fitText")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "fitText"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub fit_text: Option<Box<CTFitText>>,
-    #[serde(rename = "vertAlign")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "vertAlign"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub vert_align: Option<Box<CTVerticalAlignRun>>,
-    #[serde(rename = "rtl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rtl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rtl: Option<Box<OnOffElement>>,
-    #[serde(rename = "cs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "cs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cs: Option<Box<OnOffElement>>,
-    #[serde(rename = "em")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "em"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub em: Option<Box<CTEm>>,
-    #[serde(rename = "lang")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "lang"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub lang: Option<Box<LanguageElement>>,
-    #[serde(rename = "eastAsianLayout")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "eastAsianLayout"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub east_asian_layout: Option<Box<CTEastAsianLayout>>,
-    #[serde(rename = "specVanish")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "specVanish"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spec_vanish: Option<Box<OnOffElement>>,
-    #[serde(rename = "oMath")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "oMath"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub o_math: Option<Box<OnOffElement>>,
-    #[serde(rename = "rPrChange")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rPrChange"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r_pr_change: Option<Box<CTRPrChange>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RunProperties {
-    #[serde(rename = "rStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub run_style: Option<Box<CTString>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "rFonts")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rFonts"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub fonts: Option<Box<Fonts>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "b")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "b"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bold: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "bCs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bCs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub b_cs: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "i")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "i"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub italic: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "iCs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "iCs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub i_cs: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "caps")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "caps"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub caps: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "smallCaps")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "smallCaps"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub small_caps: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "strike")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "strike"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub strikethrough: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "dstrike")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "dstrike"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub dstrike: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "outline")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "outline"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub outline: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "shadow")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "shadow"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shadow: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "emboss")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "emboss"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub emboss: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "imprint")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "imprint"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub imprint: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "noProof")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "noProof"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub no_proof: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "snapToGrid")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "snapToGrid"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub snap_to_grid: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "vanish")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "vanish"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub vanish: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "webHidden")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "webHidden"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub web_hidden: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "color")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "color"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub color: Option<Box<CTColor>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "spacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "spacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spacing: Option<Box<SignedTwipsMeasureElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "w")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "w"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub width: Option<Box<TextScaleElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "kern")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "kern"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub kern: Option<Box<HpsMeasureElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "position")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "position"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub position: Option<Box<SignedHpsMeasureElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "sz")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sz"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub size: Option<Box<HpsMeasureElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "szCs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "szCs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub size_complex_script: Option<Box<HpsMeasureElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "highlight")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "highlight"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub highlight: Option<Box<CTHighlight>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "u")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "u"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub underline: Option<Box<CTUnderline>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "effect")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "effect"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub effect: Option<Box<CTTextEffect>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "bdr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bdr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bdr: Option<Box<CTBorder>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "shd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "shd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shading: Option<Box<CTShd>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "fitText")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "fitText"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub fit_text: Option<Box<CTFitText>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "vertAlign")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "vertAlign"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub vert_align: Option<Box<CTVerticalAlignRun>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "rtl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rtl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rtl: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "cs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "cs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cs: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "em")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "em"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub em: Option<Box<CTEm>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "lang")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "lang"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub lang: Option<Box<LanguageElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "eastAsianLayout")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "eastAsianLayout"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub east_asian_layout: Option<Box<CTEastAsianLayout>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "specVanish")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "specVanish"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spec_vanish: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "oMath")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "oMath"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub o_math: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "rPrChange")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rPrChange"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r_pr_change: Option<Box<CTRPrChange>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EGRPr {
-    #[serde(rename = "rPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r_pr: Option<Box<RunProperties>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTMathCtrlIns {
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STDecimalNumber,
-    #[serde(rename = "@w:author")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:author"))]
     pub author: STString,
-    #[serde(rename = "@w:date")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:date"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub date: Option<STDateTime>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "del")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "del"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub del: Option<Box<CTRPrChange>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "rPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r_pr: Option<Box<RunProperties>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTMathCtrlDel {
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STDecimalNumber,
-    #[serde(rename = "@w:author")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:author"))]
     pub author: STString,
-    #[serde(rename = "@w:date")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:date"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub date: Option<STDateTime>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "rPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r_pr: Option<Box<RunProperties>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTRPrOriginal {
-    #[serde(rename = "rStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub run_style: Option<Box<CTString>>,
-    #[serde(rename = "rFonts")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rFonts"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub fonts: Option<Box<Fonts>>,
-    #[serde(rename = "b")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "b"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bold: Option<Box<OnOffElement>>,
-    #[serde(rename = "bCs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bCs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub b_cs: Option<Box<OnOffElement>>,
-    #[serde(rename = "i")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "i"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub italic: Option<Box<OnOffElement>>,
-    #[serde(rename = "iCs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "iCs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub i_cs: Option<Box<OnOffElement>>,
-    #[serde(rename = "caps")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "caps"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub caps: Option<Box<OnOffElement>>,
-    #[serde(rename = "smallCaps")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "smallCaps"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub small_caps: Option<Box<OnOffElement>>,
-    #[serde(rename = "strike")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "strike"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub strikethrough: Option<Box<OnOffElement>>,
-    #[serde(rename = "dstrike")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "dstrike"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub dstrike: Option<Box<OnOffElement>>,
-    #[serde(rename = "outline")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "outline"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub outline: Option<Box<OnOffElement>>,
-    #[serde(rename = "shadow")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "shadow"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shadow: Option<Box<OnOffElement>>,
-    #[serde(rename = "emboss")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "emboss"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub emboss: Option<Box<OnOffElement>>,
-    #[serde(rename = "imprint")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "imprint"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub imprint: Option<Box<OnOffElement>>,
-    #[serde(rename = "noProof")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "noProof"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub no_proof: Option<Box<OnOffElement>>,
-    #[serde(rename = "snapToGrid")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "snapToGrid"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub snap_to_grid: Option<Box<OnOffElement>>,
-    #[serde(rename = "vanish")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "vanish"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub vanish: Option<Box<OnOffElement>>,
-    #[serde(rename = "webHidden")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "webHidden"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub web_hidden: Option<Box<OnOffElement>>,
-    #[serde(rename = "color")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "color"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub color: Option<Box<CTColor>>,
-    #[serde(rename = "spacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "spacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spacing: Option<Box<SignedTwipsMeasureElement>>,
-    #[serde(rename = "w")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "w"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub width: Option<Box<TextScaleElement>>,
-    #[serde(rename = "kern")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "kern"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub kern: Option<Box<HpsMeasureElement>>,
-    #[serde(rename = "position")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "position"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub position: Option<Box<SignedHpsMeasureElement>>,
-    #[serde(rename = "sz")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sz"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub size: Option<Box<HpsMeasureElement>>,
-    #[serde(rename = "szCs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "szCs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub size_complex_script: Option<Box<HpsMeasureElement>>,
-    #[serde(rename = "highlight")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "highlight"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub highlight: Option<Box<CTHighlight>>,
-    #[serde(rename = "u")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "u"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub underline: Option<Box<CTUnderline>>,
-    #[serde(rename = "effect")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "effect"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub effect: Option<Box<CTTextEffect>>,
-    #[serde(rename = "bdr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bdr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bdr: Option<Box<CTBorder>>,
-    #[serde(rename = "shd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "shd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shading: Option<Box<CTShd>>,
-    #[serde(rename = "fitText")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "fitText"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub fit_text: Option<Box<CTFitText>>,
-    #[serde(rename = "vertAlign")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "vertAlign"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub vert_align: Option<Box<CTVerticalAlignRun>>,
-    #[serde(rename = "rtl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rtl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rtl: Option<Box<OnOffElement>>,
-    #[serde(rename = "cs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "cs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cs: Option<Box<OnOffElement>>,
-    #[serde(rename = "em")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "em"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub em: Option<Box<CTEm>>,
-    #[serde(rename = "lang")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "lang"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub lang: Option<Box<LanguageElement>>,
-    #[serde(rename = "eastAsianLayout")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "eastAsianLayout"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub east_asian_layout: Option<Box<CTEastAsianLayout>>,
-    #[serde(rename = "specVanish")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "specVanish"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spec_vanish: Option<Box<OnOffElement>>,
-    #[serde(rename = "oMath")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "oMath"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub o_math: Option<Box<OnOffElement>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTParaRPrOriginal {
-    #[serde(rename = "ins")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "ins"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ins: Option<Box<CTTrackChange>>,
-    #[serde(rename = "del")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "del"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub del: Option<Box<CTTrackChange>>,
-    #[serde(rename = "moveFrom")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFrom"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub move_from: Option<Box<CTTrackChange>>,
-    #[serde(rename = "moveTo")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveTo"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub move_to: Option<Box<CTTrackChange>>,
-    #[serde(rename = "rStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub run_style: Option<Box<CTString>>,
-    #[serde(rename = "rFonts")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rFonts"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub fonts: Option<Box<Fonts>>,
-    #[serde(rename = "b")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "b"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bold: Option<Box<OnOffElement>>,
-    #[serde(rename = "bCs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bCs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub b_cs: Option<Box<OnOffElement>>,
-    #[serde(rename = "i")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "i"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub italic: Option<Box<OnOffElement>>,
-    #[serde(rename = "iCs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "iCs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub i_cs: Option<Box<OnOffElement>>,
-    #[serde(rename = "caps")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "caps"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub caps: Option<Box<OnOffElement>>,
-    #[serde(rename = "smallCaps")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "smallCaps"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub small_caps: Option<Box<OnOffElement>>,
-    #[serde(rename = "strike")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "strike"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub strikethrough: Option<Box<OnOffElement>>,
-    #[serde(rename = "dstrike")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "dstrike"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub dstrike: Option<Box<OnOffElement>>,
-    #[serde(rename = "outline")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "outline"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub outline: Option<Box<OnOffElement>>,
-    #[serde(rename = "shadow")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "shadow"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shadow: Option<Box<OnOffElement>>,
-    #[serde(rename = "emboss")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "emboss"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub emboss: Option<Box<OnOffElement>>,
-    #[serde(rename = "imprint")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "imprint"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub imprint: Option<Box<OnOffElement>>,
-    #[serde(rename = "noProof")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "noProof"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub no_proof: Option<Box<OnOffElement>>,
-    #[serde(rename = "snapToGrid")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "snapToGrid"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub snap_to_grid: Option<Box<OnOffElement>>,
-    #[serde(rename = "vanish")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "vanish"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub vanish: Option<Box<OnOffElement>>,
-    #[serde(rename = "webHidden")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "webHidden"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub web_hidden: Option<Box<OnOffElement>>,
-    #[serde(rename = "color")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "color"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub color: Option<Box<CTColor>>,
-    #[serde(rename = "spacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "spacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spacing: Option<Box<SignedTwipsMeasureElement>>,
-    #[serde(rename = "w")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "w"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub width: Option<Box<TextScaleElement>>,
-    #[serde(rename = "kern")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "kern"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub kern: Option<Box<HpsMeasureElement>>,
-    #[serde(rename = "position")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "position"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub position: Option<Box<SignedHpsMeasureElement>>,
-    #[serde(rename = "sz")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sz"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub size: Option<Box<HpsMeasureElement>>,
-    #[serde(rename = "szCs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "szCs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub size_complex_script: Option<Box<HpsMeasureElement>>,
-    #[serde(rename = "highlight")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "highlight"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub highlight: Option<Box<CTHighlight>>,
-    #[serde(rename = "u")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "u"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub underline: Option<Box<CTUnderline>>,
-    #[serde(rename = "effect")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "effect"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub effect: Option<Box<CTTextEffect>>,
-    #[serde(rename = "bdr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bdr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bdr: Option<Box<CTBorder>>,
-    #[serde(rename = "shd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "shd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shading: Option<Box<CTShd>>,
-    #[serde(rename = "fitText")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "fitText"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub fit_text: Option<Box<CTFitText>>,
-    #[serde(rename = "vertAlign")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "vertAlign"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub vert_align: Option<Box<CTVerticalAlignRun>>,
-    #[serde(rename = "rtl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rtl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rtl: Option<Box<OnOffElement>>,
-    #[serde(rename = "cs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "cs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cs: Option<Box<OnOffElement>>,
-    #[serde(rename = "em")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "em"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub em: Option<Box<CTEm>>,
-    #[serde(rename = "lang")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "lang"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub lang: Option<Box<LanguageElement>>,
-    #[serde(rename = "eastAsianLayout")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "eastAsianLayout"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub east_asian_layout: Option<Box<CTEastAsianLayout>>,
-    #[serde(rename = "specVanish")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "specVanish"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spec_vanish: Option<Box<OnOffElement>>,
-    #[serde(rename = "oMath")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "oMath"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub o_math: Option<Box<OnOffElement>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTParaRPr {
-    #[serde(rename = "ins")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "ins"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ins: Option<Box<CTTrackChange>>,
-    #[serde(rename = "del")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "del"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub del: Option<Box<CTTrackChange>>,
-    #[serde(rename = "moveFrom")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFrom"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub move_from: Option<Box<CTTrackChange>>,
-    #[serde(rename = "moveTo")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveTo"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub move_to: Option<Box<CTTrackChange>>,
-    #[serde(rename = "rStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub run_style: Option<Box<CTString>>,
-    #[serde(rename = "rFonts")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rFonts"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub fonts: Option<Box<Fonts>>,
-    #[serde(rename = "b")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "b"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bold: Option<Box<OnOffElement>>,
-    #[serde(rename = "bCs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bCs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub b_cs: Option<Box<OnOffElement>>,
-    #[serde(rename = "i")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "i"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub italic: Option<Box<OnOffElement>>,
-    #[serde(rename = "iCs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "iCs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub i_cs: Option<Box<OnOffElement>>,
-    #[serde(rename = "caps")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "caps"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub caps: Option<Box<OnOffElement>>,
-    #[serde(rename = "smallCaps")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "smallCaps"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub small_caps: Option<Box<OnOffElement>>,
-    #[serde(rename = "strike")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "strike"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub strikethrough: Option<Box<OnOffElement>>,
-    #[serde(rename = "dstrike")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "dstrike"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub dstrike: Option<Box<OnOffElement>>,
-    #[serde(rename = "outline")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "outline"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub outline: Option<Box<OnOffElement>>,
-    #[serde(rename = "shadow")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "shadow"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shadow: Option<Box<OnOffElement>>,
-    #[serde(rename = "emboss")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "emboss"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub emboss: Option<Box<OnOffElement>>,
-    #[serde(rename = "imprint")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "imprint"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub imprint: Option<Box<OnOffElement>>,
-    #[serde(rename = "noProof")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "noProof"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub no_proof: Option<Box<OnOffElement>>,
-    #[serde(rename = "snapToGrid")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "snapToGrid"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub snap_to_grid: Option<Box<OnOffElement>>,
-    #[serde(rename = "vanish")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "vanish"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub vanish: Option<Box<OnOffElement>>,
-    #[serde(rename = "webHidden")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "webHidden"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub web_hidden: Option<Box<OnOffElement>>,
-    #[serde(rename = "color")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "color"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub color: Option<Box<CTColor>>,
-    #[serde(rename = "spacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "spacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spacing: Option<Box<SignedTwipsMeasureElement>>,
-    #[serde(rename = "w")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "w"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub width: Option<Box<TextScaleElement>>,
-    #[serde(rename = "kern")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "kern"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub kern: Option<Box<HpsMeasureElement>>,
-    #[serde(rename = "position")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "position"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub position: Option<Box<SignedHpsMeasureElement>>,
-    #[serde(rename = "sz")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sz"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub size: Option<Box<HpsMeasureElement>>,
-    #[serde(rename = "szCs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "szCs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub size_complex_script: Option<Box<HpsMeasureElement>>,
-    #[serde(rename = "highlight")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "highlight"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub highlight: Option<Box<CTHighlight>>,
-    #[serde(rename = "u")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "u"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub underline: Option<Box<CTUnderline>>,
-    #[serde(rename = "effect")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "effect"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub effect: Option<Box<CTTextEffect>>,
-    #[serde(rename = "bdr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bdr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bdr: Option<Box<CTBorder>>,
-    #[serde(rename = "shd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "shd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shading: Option<Box<CTShd>>,
-    #[serde(rename = "fitText")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "fitText"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub fit_text: Option<Box<CTFitText>>,
-    #[serde(rename = "vertAlign")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "vertAlign"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub vert_align: Option<Box<CTVerticalAlignRun>>,
-    #[serde(rename = "rtl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rtl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rtl: Option<Box<OnOffElement>>,
-    #[serde(rename = "cs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "cs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cs: Option<Box<OnOffElement>>,
-    #[serde(rename = "em")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "em"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub em: Option<Box<CTEm>>,
-    #[serde(rename = "lang")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "lang"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub lang: Option<Box<LanguageElement>>,
-    #[serde(rename = "eastAsianLayout")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "eastAsianLayout"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub east_asian_layout: Option<Box<CTEastAsianLayout>>,
-    #[serde(rename = "specVanish")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "specVanish"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spec_vanish: Option<Box<OnOffElement>>,
-    #[serde(rename = "oMath")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "oMath"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub o_math: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "rPrChange")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rPrChange"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r_pr_change: Option<Box<CTParaRPrChange>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EGParaRPrTrackChanges {
-    #[serde(rename = "ins")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "ins"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ins: Option<Box<CTTrackChange>>,
-    #[serde(rename = "del")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "del"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub del: Option<Box<CTTrackChange>>,
-    #[serde(rename = "moveFrom")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveFrom"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub move_from: Option<Box<CTTrackChange>>,
-    #[serde(rename = "moveTo")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "moveTo"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub move_to: Option<Box<CTTrackChange>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTAltChunk {
-    #[serde(rename = "@r:id")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@r:id"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub id: Option<STRelationshipId>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "altChunkPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "altChunkPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub alt_chunk_pr: Option<Box<CTAltChunkPr>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTAltChunkPr {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "matchSrc")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "matchSrc"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub match_src: Option<Box<OnOffElement>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTRubyAlign {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STRubyAlign,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTRubyPr {
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "rubyAlign")]
+    #[cfg_attr(feature = "serde", serde(rename = "rubyAlign"))]
     pub ruby_align: Box<CTRubyAlign>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "hps")]
+    #[cfg_attr(feature = "serde", serde(rename = "hps"))]
     pub hps: Box<HpsMeasureElement>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "hpsRaise")]
+    #[cfg_attr(feature = "serde", serde(rename = "hpsRaise"))]
     pub hps_raise: Box<HpsMeasureElement>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "hpsBaseText")]
+    #[cfg_attr(feature = "serde", serde(rename = "hpsBaseText"))]
     pub hps_base_text: Box<HpsMeasureElement>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "lid")]
+    #[cfg_attr(feature = "serde", serde(rename = "lid"))]
     pub lid: Box<CTLang>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "dirty")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "dirty"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub dirty: Option<Box<OnOffElement>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTRubyContent {
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub ruby_content: Vec<RubyContent>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTRuby {
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "rubyPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "rubyPr"))]
     pub ruby_pr: Box<CTRubyPr>,
-    #[serde(rename = "rt")]
+    #[cfg_attr(feature = "serde", serde(rename = "rt"))]
     pub rt: Box<CTRubyContent>,
-    #[serde(rename = "rubyBase")]
+    #[cfg_attr(feature = "serde", serde(rename = "rubyBase"))]
     pub ruby_base: Box<CTRubyContent>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTLock {
-    #[serde(rename = "@w:val")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<STLock>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSdtListItem {
-    #[serde(rename = "@w:displayText")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:displayText"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub display_text: Option<STString>,
-    #[serde(rename = "@w:value")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:value"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<STString>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSdtDateMappingType {
-    #[serde(rename = "@w:val")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<STSdtDateMappingType>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTCalendarType {
-    #[serde(rename = "@w:val")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<CalendarType>,
-    /// Unknown attributes captured for roundtrip fidelity.
-    #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
+    /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSdtDate {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:fullDate")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:fullDate"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub full_date: Option<STDateTime>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "dateFormat")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "dateFormat"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub date_format: Option<Box<CTString>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "lid")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "lid"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub lid: Option<Box<CTLang>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "storeMappedDataAs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "storeMappedDataAs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub store_mapped_data_as: Option<Box<CTSdtDateMappingType>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "calendar")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "calendar"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub calendar: Option<Box<CTCalendarType>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSdtComboBox {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:lastValue")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:lastValue"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub last_value: Option<STString>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "listItem")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "listItem"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub list_item: Vec<CTSdtListItem>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSdtDocPart {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "docPartGallery")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "docPartGallery"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub doc_part_gallery: Option<Box<CTString>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "docPartCategory")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "docPartCategory"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub doc_part_category: Option<Box<CTString>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "docPartUnique")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "docPartUnique"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub doc_part_unique: Option<Box<OnOffElement>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSdtDropDownList {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:lastValue")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:lastValue"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub last_value: Option<STString>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "listItem")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "listItem"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub list_item: Vec<CTSdtListItem>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
 pub type PlaceholderElement = Box<CTString>;
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSdtText {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:multiLine")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:multiLine"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub multi_line: Option<OnOff>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTDataBinding {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:prefixMappings")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:prefixMappings"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub prefix_mappings: Option<STString>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:xpath")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:xpath"))]
     pub xpath: STString,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:storeItemID")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:storeItemID"))]
     pub store_item_i_d: STString,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSdtPr {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "rPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r_pr: Option<Box<RunProperties>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "alias")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "alias"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub alias: Option<Box<CTString>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "tag")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tag"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tag: Option<Box<CTString>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "id")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "id"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub id: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "lock")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "lock"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub lock: Option<Box<CTLock>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "placeholder")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "placeholder"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub placeholder: Option<PlaceholderElement>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "temporary")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "temporary"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub temporary: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "showingPlcHdr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "showingPlcHdr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub showing_plc_hdr: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "dataBinding")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "dataBinding"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub data_binding: Option<Box<CTDataBinding>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "label")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "label"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub label: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "tabIndex")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tabIndex"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tab_index: Option<Box<UnsignedDecimalNumberElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "equation")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "equation"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub equation: Option<Box<CTEmpty>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "comboBox")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "comboBox"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub combo_box: Option<Box<CTSdtComboBox>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "date")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "date"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub date: Option<Box<CTSdtDate>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "docPartObj")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "docPartObj"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub doc_part_obj: Option<Box<CTSdtDocPart>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "docPartList")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "docPartList"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub doc_part_list: Option<Box<CTSdtDocPart>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "dropDownList")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "dropDownList"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub drop_down_list: Option<Box<CTSdtDropDownList>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "picture")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "picture"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub picture: Option<Box<CTEmpty>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "richText")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "richText"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rich_text: Option<Box<CTEmpty>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "text")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "text"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub text: Option<Box<CTSdtText>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "citation")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "citation"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub citation: Option<Box<CTEmpty>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "group")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "group"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub group: Option<Box<CTEmpty>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "bibliography")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bibliography"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bibliography: Option<Box<CTEmpty>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSdtEndPr {
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "rPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r_pr: Option<Box<RunProperties>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTDirContentRun {
-    #[serde(rename = "@w:val")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<STDirection>,
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub paragraph_content: Vec<ParagraphContent>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTBdoContentRun {
-    #[serde(rename = "@w:val")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<STDirection>,
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub paragraph_content: Vec<ParagraphContent>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSdtContentRun {
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub paragraph_content: Vec<ParagraphContent>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSdtContentBlock {
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub block_content: Vec<BlockContentChoice>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSdtContentRow {
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub rows: Vec<RowContent>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSdtContentCell {
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub cells: Vec<CellContent>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSdtBlock {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "sdtPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sdtPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sdt_pr: Option<Box<CTSdtPr>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "sdtEndPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sdtEndPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sdt_end_pr: Option<Box<CTSdtEndPr>>,
-    #[serde(rename = "sdtContent")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sdtContent"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sdt_content: Option<Box<CTSdtContentBlock>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSdtRun {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "sdtPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sdtPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sdt_pr: Option<Box<CTSdtPr>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "sdtEndPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sdtEndPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sdt_end_pr: Option<Box<CTSdtEndPr>>,
-    #[serde(rename = "sdtContent")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sdtContent"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sdt_content: Option<Box<CTSdtContentRun>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSdtCell {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "sdtPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sdtPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sdt_pr: Option<Box<CTSdtPr>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "sdtEndPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sdtEndPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sdt_end_pr: Option<Box<CTSdtEndPr>>,
-    #[serde(rename = "sdtContent")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sdtContent"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sdt_content: Option<Box<CTSdtContentCell>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSdtRow {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "sdtPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sdtPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sdt_pr: Option<Box<CTSdtPr>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "sdtEndPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sdtEndPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sdt_end_pr: Option<Box<CTSdtEndPr>>,
-    #[serde(rename = "sdtContent")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sdtContent"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sdt_content: Option<Box<CTSdtContentRow>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTAttr {
-    #[serde(rename = "@w:uri")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:uri"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub uri: Option<STString>,
-    #[serde(rename = "@w:name")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:name"))]
     pub name: STString,
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STString,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTCustomXmlRun {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:uri")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:uri"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub uri: Option<STString>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:element")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:element"))]
     pub element: STXmlName,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "customXmlPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub custom_xml_pr: Option<Box<CTCustomXmlPr>>,
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub paragraph_content: Vec<ParagraphContent>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSmartTagRun {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:uri")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:uri"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub uri: Option<STString>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:element")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:element"))]
     pub element: STXmlName,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "smartTagPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "smartTagPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub smart_tag_pr: Option<Box<CTSmartTagPr>>,
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub paragraph_content: Vec<ParagraphContent>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTCustomXmlBlock {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:uri")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:uri"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub uri: Option<STString>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:element")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:element"))]
     pub element: STXmlName,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "customXmlPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub custom_xml_pr: Option<Box<CTCustomXmlPr>>,
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub block_content: Vec<BlockContentChoice>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTCustomXmlPr {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "placeholder")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "placeholder"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub placeholder: Option<Box<CTString>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "attr")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "attr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub attr: Vec<CTAttr>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTCustomXmlRow {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:uri")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:uri"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub uri: Option<STString>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:element")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:element"))]
     pub element: STXmlName,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "customXmlPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub custom_xml_pr: Option<Box<CTCustomXmlPr>>,
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub rows: Vec<RowContent>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTCustomXmlCell {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:uri")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:uri"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub uri: Option<STString>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:element")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:element"))]
     pub element: STXmlName,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "customXmlPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "customXmlPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub custom_xml_pr: Option<Box<CTCustomXmlPr>>,
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub cells: Vec<CellContent>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSmartTagPr {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "attr")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "attr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub attr: Vec<CTAttr>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Paragraph {
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:rsidRPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:rsidRPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsid_r_pr: Option<STLongHexNumber>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:rsidR")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:rsidR"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsid_r: Option<STLongHexNumber>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:rsidDel")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:rsidDel"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsid_del: Option<STLongHexNumber>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:rsidP")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:rsidP"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsid_p: Option<STLongHexNumber>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:rsidRDefault")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:rsidRDefault"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsid_r_default: Option<STLongHexNumber>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "pPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub p_pr: Option<Box<ParagraphProperties>>,
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub paragraph_content: Vec<ParagraphContent>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTHeight {
-    #[serde(rename = "@w:val")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<STTwipsMeasure>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "@w:hRule")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:hRule"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub h_rule: Option<STHeightRule>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTblWidth {
-    #[serde(rename = "@w:w")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:w"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub width: Option<STMeasurementOrPercent>,
-    #[serde(rename = "@w:type")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:type"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r#type: Option<STTblWidth>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TableGridColumn {
-    #[serde(rename = "@w:w")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:w"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub width: Option<STTwipsMeasure>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTblGridBase {
-    #[serde(rename = "gridCol")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "gridCol"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub grid_col: Vec<TableGridColumn>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TableGrid {
-    #[serde(rename = "gridCol")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "gridCol"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub grid_col: Vec<TableGridColumn>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "tblGridChange")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblGridChange"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_grid_change: Option<Box<CTTblGridChange>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTcBorders {
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "top")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "top"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub top: Option<Box<CTBorder>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "start")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "start"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub start: Option<Box<CTBorder>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "left")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "left"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub left: Option<Box<CTBorder>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "bottom")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bottom"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bottom: Option<Box<CTBorder>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "end")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "end"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub end: Option<Box<CTBorder>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "right")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "right"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub right: Option<Box<CTBorder>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "insideH")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "insideH"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub inside_h: Option<Box<CTBorder>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "insideV")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "insideV"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub inside_v: Option<Box<CTBorder>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tl2br")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tl2br"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tl2br: Option<Box<CTBorder>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tr2bl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tr2bl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tr2bl: Option<Box<CTBorder>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTcMar {
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "top")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "top"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub top: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "start")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "start"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub start: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "left")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "left"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub left: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "bottom")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bottom"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bottom: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "end")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "end"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub end: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "right")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "right"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub right: Option<Box<CTTblWidth>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTVMerge {
-    #[serde(rename = "@w:val")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<STMerge>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTHMerge {
-    #[serde(rename = "@w:val")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<STMerge>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTcPrBase {
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "cnfStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "cnfStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cnf_style: Option<Box<CTCnf>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tcW")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tcW"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tc_w: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "gridSpan")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "gridSpan"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub grid_span: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "hMerge")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "hMerge"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub horizontal_merge: Option<Box<CTHMerge>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "vMerge")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "vMerge"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub vertical_merge: Option<Box<CTVMerge>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tcBorders")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tcBorders"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tc_borders: Option<Box<CTTcBorders>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "shd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "shd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shading: Option<Box<CTShd>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "noWrap")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "noWrap"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub no_wrap: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tcMar")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tcMar"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tc_mar: Option<Box<CTTcMar>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "textDirection")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "textDirection"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub text_direction: Option<Box<CTTextDirection>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tcFitText")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tcFitText"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tc_fit_text: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "vAlign")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "vAlign"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub v_align: Option<Box<CTVerticalJc>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "hideMark")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "hideMark"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hide_mark: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "headers")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "headers"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub headers: Option<Box<CTHeaders>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TableCellProperties {
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "cnfStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "cnfStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cnf_style: Option<Box<CTCnf>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tcW")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tcW"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tc_w: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "gridSpan")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "gridSpan"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub grid_span: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "hMerge")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "hMerge"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub horizontal_merge: Option<Box<CTHMerge>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "vMerge")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "vMerge"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub vertical_merge: Option<Box<CTVMerge>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tcBorders")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tcBorders"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tc_borders: Option<Box<CTTcBorders>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "shd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "shd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shading: Option<Box<CTShd>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "noWrap")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "noWrap"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub no_wrap: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tcMar")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tcMar"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tc_mar: Option<Box<CTTcMar>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "textDirection")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "textDirection"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub text_direction: Option<Box<CTTextDirection>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tcFitText")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tcFitText"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tc_fit_text: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "vAlign")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "vAlign"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub v_align: Option<Box<CTVerticalJc>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "hideMark")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "hideMark"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hide_mark: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "headers")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "headers"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub headers: Option<Box<CTHeaders>>,
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cell_markup: Option<Box<CellMarkup>>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "tcPrChange")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tcPrChange"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tc_pr_change: Option<Box<CTTcPrChange>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTcPrInner {
-    #[serde(rename = "cnfStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "cnfStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cnf_style: Option<Box<CTCnf>>,
-    #[serde(rename = "tcW")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tcW"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tc_w: Option<Box<CTTblWidth>>,
-    #[serde(rename = "gridSpan")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "gridSpan"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub grid_span: Option<Box<CTDecimalNumber>>,
-    #[serde(rename = "hMerge")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "hMerge"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub horizontal_merge: Option<Box<CTHMerge>>,
-    #[serde(rename = "vMerge")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "vMerge"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub vertical_merge: Option<Box<CTVMerge>>,
-    #[serde(rename = "tcBorders")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tcBorders"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tc_borders: Option<Box<CTTcBorders>>,
-    #[serde(rename = "shd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "shd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shading: Option<Box<CTShd>>,
-    #[serde(rename = "noWrap")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "noWrap"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub no_wrap: Option<Box<OnOffElement>>,
-    #[serde(rename = "tcMar")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tcMar"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tc_mar: Option<Box<CTTcMar>>,
-    #[serde(rename = "textDirection")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "textDirection"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub text_direction: Option<Box<CTTextDirection>>,
-    #[serde(rename = "tcFitText")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tcFitText"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tc_fit_text: Option<Box<OnOffElement>>,
-    #[serde(rename = "vAlign")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "vAlign"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub v_align: Option<Box<CTVerticalJc>>,
-    #[serde(rename = "hideMark")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "hideMark"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hide_mark: Option<Box<OnOffElement>>,
-    #[serde(rename = "headers")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "headers"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub headers: Option<Box<CTHeaders>>,
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cell_markup: Option<Box<CellMarkup>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TableCell {
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "@w:id")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub id: Option<STString>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tcPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tcPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cell_properties: Option<Box<TableCellProperties>>,
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub block_content: Vec<BlockContent>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTCnf {
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:val")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<STCnf>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:firstRow")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:firstRow"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub first_row: Option<OnOff>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:lastRow")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:lastRow"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub last_row: Option<OnOff>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:firstColumn")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:firstColumn"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub first_column: Option<OnOff>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:lastColumn")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:lastColumn"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub last_column: Option<OnOff>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:oddVBand")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:oddVBand"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub odd_v_band: Option<OnOff>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:evenVBand")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:evenVBand"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub even_v_band: Option<OnOff>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:oddHBand")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:oddHBand"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub odd_h_band: Option<OnOff>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:evenHBand")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:evenHBand"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub even_h_band: Option<OnOff>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:firstRowFirstColumn")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:firstRowFirstColumn"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub first_row_first_column: Option<OnOff>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:firstRowLastColumn")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:firstRowLastColumn"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub first_row_last_column: Option<OnOff>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:lastRowFirstColumn")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:lastRowFirstColumn"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub last_row_first_column: Option<OnOff>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:lastRowLastColumn")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:lastRowLastColumn"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub last_row_last_column: Option<OnOff>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTHeaders {
-    #[serde(rename = "header")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "header"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub header: Vec<CTString>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTrPrBase {
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "cnfStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "cnfStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cnf_style: Option<Box<CTCnf>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "divId")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "divId"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub div_id: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "gridBefore")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "gridBefore"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub grid_before: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "gridAfter")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "gridAfter"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub grid_after: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "wBefore")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "wBefore"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub w_before: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "wAfter")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "wAfter"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub w_after: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "cantSplit")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "cantSplit"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cant_split: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "trHeight")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "trHeight"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tr_height: Option<Box<CTHeight>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblHeader")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblHeader"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_header: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblCellSpacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblCellSpacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_cell_spacing: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "jc")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "jc"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub justification: Option<Box<CTJcTable>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "hidden")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "hidden"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hidden: Option<Box<OnOffElement>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TableRowProperties {
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "cnfStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "cnfStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cnf_style: Option<Box<CTCnf>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "divId")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "divId"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub div_id: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "gridBefore")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "gridBefore"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub grid_before: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "gridAfter")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "gridAfter"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub grid_after: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "wBefore")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "wBefore"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub w_before: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "wAfter")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "wAfter"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub w_after: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "cantSplit")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "cantSplit"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cant_split: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "trHeight")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "trHeight"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tr_height: Option<Box<CTHeight>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblHeader")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblHeader"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_header: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblCellSpacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblCellSpacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_cell_spacing: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "jc")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "jc"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub justification: Option<Box<CTJcTable>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "hidden")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "hidden"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hidden: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "ins")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "ins"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ins: Option<Box<CTTrackChange>>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "del")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "del"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub del: Option<Box<CTTrackChange>>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "trPrChange")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "trPrChange"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tr_pr_change: Option<Box<CTTrPrChange>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTRow {
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:rsidRPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:rsidRPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsid_r_pr: Option<STLongHexNumber>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:rsidR")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:rsidR"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsid_r: Option<STLongHexNumber>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:rsidDel")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:rsidDel"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsid_del: Option<STLongHexNumber>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "@w:rsidTr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:rsidTr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsid_tr: Option<STLongHexNumber>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblPrEx")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblPrEx"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_pr_ex: Option<Box<CTTblPrEx>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "trPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "trPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub row_properties: Option<Box<TableRowProperties>>,
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub cells: Vec<CellContent>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTblLayoutType {
-    #[serde(rename = "@w:type")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:type"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r#type: Option<STTblLayoutType>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTblOverlap {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STTblOverlap,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTblPPr {
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "@w:leftFromText")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:leftFromText"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub left_from_text: Option<STTwipsMeasure>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "@w:rightFromText")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:rightFromText"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub right_from_text: Option<STTwipsMeasure>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "@w:topFromText")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:topFromText"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub top_from_text: Option<STTwipsMeasure>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "@w:bottomFromText")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:bottomFromText"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bottom_from_text: Option<STTwipsMeasure>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "@w:vertAnchor")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:vertAnchor"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub vert_anchor: Option<STVAnchor>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "@w:horzAnchor")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:horzAnchor"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub horz_anchor: Option<STHAnchor>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "@w:tblpXSpec")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:tblpXSpec"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tblp_x_spec: Option<STXAlign>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "@w:tblpX")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:tblpX"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tblp_x: Option<STSignedTwipsMeasure>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "@w:tblpYSpec")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:tblpYSpec"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tblp_y_spec: Option<STYAlign>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "@w:tblpY")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:tblpY"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tblp_y: Option<STSignedTwipsMeasure>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTblCellMar {
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "top")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "top"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub top: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "start")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "start"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub start: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "left")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "left"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub left: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "bottom")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bottom"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bottom: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "end")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "end"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub end: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "right")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "right"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub right: Option<Box<CTTblWidth>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTblBorders {
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "top")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "top"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub top: Option<Box<CTBorder>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "start")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "start"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub start: Option<Box<CTBorder>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "left")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "left"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub left: Option<Box<CTBorder>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "bottom")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bottom"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bottom: Option<Box<CTBorder>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "end")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "end"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub end: Option<Box<CTBorder>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "right")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "right"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub right: Option<Box<CTBorder>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "insideH")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "insideH"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub inside_h: Option<Box<CTBorder>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "insideV")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "insideV"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub inside_v: Option<Box<CTBorder>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTblPrBase {
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "tblStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_style: Option<Box<CTString>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblpPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblpPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tblp_pr: Option<Box<CTTblPPr>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblOverlap")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblOverlap"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_overlap: Option<Box<CTTblOverlap>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "bidiVisual")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bidiVisual"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bidi_visual: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "tblStyleRowBandSize")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblStyleRowBandSize"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_style_row_band_size: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "tblStyleColBandSize")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblStyleColBandSize"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_style_col_band_size: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblW")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblW"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_w: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "jc")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "jc"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub justification: Option<Box<CTJcTable>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblCellSpacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblCellSpacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_cell_spacing: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblInd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblInd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_ind: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblBorders")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblBorders"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_borders: Option<Box<CTTblBorders>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "shd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "shd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shading: Option<Box<CTShd>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblLayout")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblLayout"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_layout: Option<Box<CTTblLayoutType>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblCellMar")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblCellMar"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_cell_mar: Option<Box<CTTblCellMar>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblLook")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblLook"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_look: Option<Box<CTTblLook>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblCaption")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblCaption"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_caption: Option<Box<CTString>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblDescription")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblDescription"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_description: Option<Box<CTString>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TableProperties {
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "tblStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_style: Option<Box<CTString>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblpPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblpPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tblp_pr: Option<Box<CTTblPPr>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblOverlap")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblOverlap"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_overlap: Option<Box<CTTblOverlap>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "bidiVisual")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bidiVisual"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bidi_visual: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "tblStyleRowBandSize")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblStyleRowBandSize"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_style_row_band_size: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "tblStyleColBandSize")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblStyleColBandSize"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_style_col_band_size: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblW")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblW"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_w: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "jc")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "jc"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub justification: Option<Box<CTJcTable>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblCellSpacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblCellSpacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_cell_spacing: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblInd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblInd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_ind: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblBorders")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblBorders"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_borders: Option<Box<CTTblBorders>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "shd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "shd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shading: Option<Box<CTShd>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblLayout")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblLayout"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_layout: Option<Box<CTTblLayoutType>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblCellMar")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblCellMar"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_cell_mar: Option<Box<CTTblCellMar>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblLook")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblLook"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_look: Option<Box<CTTblLook>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblCaption")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblCaption"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_caption: Option<Box<CTString>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblDescription")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblDescription"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_description: Option<Box<CTString>>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "tblPrChange")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblPrChange"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_pr_change: Option<Box<CTTblPrChange>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTblPrExBase {
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblW")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblW"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_w: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "jc")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "jc"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub justification: Option<Box<CTJcTable>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblCellSpacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblCellSpacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_cell_spacing: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblInd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblInd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_ind: Option<Box<CTTblWidth>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblBorders")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblBorders"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_borders: Option<Box<CTTblBorders>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "shd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "shd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shading: Option<Box<CTShd>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblLayout")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblLayout"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_layout: Option<Box<CTTblLayoutType>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblCellMar")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblCellMar"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_cell_mar: Option<Box<CTTblCellMar>>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "tblLook")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblLook"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_look: Option<Box<CTTblLook>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTblPrEx {
-    #[serde(rename = "tblW")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblW"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_w: Option<Box<CTTblWidth>>,
-    #[serde(rename = "jc")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "jc"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub justification: Option<Box<CTJcTable>>,
-    #[serde(rename = "tblCellSpacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblCellSpacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_cell_spacing: Option<Box<CTTblWidth>>,
-    #[serde(rename = "tblInd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblInd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_ind: Option<Box<CTTblWidth>>,
-    #[serde(rename = "tblBorders")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblBorders"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_borders: Option<Box<CTTblBorders>>,
-    #[serde(rename = "shd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "shd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shading: Option<Box<CTShd>>,
-    #[serde(rename = "tblLayout")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblLayout"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_layout: Option<Box<CTTblLayoutType>>,
-    #[serde(rename = "tblCellMar")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblCellMar"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_cell_mar: Option<Box<CTTblCellMar>>,
-    #[serde(rename = "tblLook")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblLook"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_look: Option<Box<CTTblLook>>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "tblPrExChange")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblPrExChange"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tbl_pr_ex_change: Option<Box<CTTblPrExChange>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Table {
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub range_markup: Vec<RangeMarkup>,
-    #[serde(rename = "tblPr")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblPr"))]
     pub table_properties: Box<TableProperties>,
-    #[serde(rename = "tblGrid")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblGrid"))]
     pub tbl_grid: Box<TableGrid>,
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub rows: Vec<RowContent>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTblLook {
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "@w:firstRow")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:firstRow"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub first_row: Option<OnOff>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "@w:lastRow")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:lastRow"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub last_row: Option<OnOff>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "@w:firstColumn")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:firstColumn"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub first_column: Option<OnOff>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "@w:lastColumn")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:lastColumn"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub last_column: Option<OnOff>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "@w:noHBand")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:noHBand"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub no_h_band: Option<OnOff>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "@w:noVBand")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:noVBand"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub no_v_band: Option<OnOff>,
     #[cfg(feature = "wml-tables")]
-    #[serde(rename = "@w:val")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<STShortHexNumber>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTFtnPos {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STFtnPos,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTEdnPos {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STEdnPos,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTNumFmt {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STNumberFormat,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "@w:format")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:format"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub format: Option<STString>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTNumRestart {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STRestartNumber,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FootnoteEndnoteRef {
     #[cfg(feature = "wml-comments")]
-    #[serde(rename = "@w:customMarkFollows")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:customMarkFollows"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub custom_mark_follows: Option<OnOff>,
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STDecimalNumber,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTFtnEdnSepRef {
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STDecimalNumber,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FootnoteEndnote {
     #[cfg(feature = "wml-comments")]
-    #[serde(rename = "@w:type")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:type"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r#type: Option<STFtnEdn>,
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STDecimalNumber,
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub block_content: Vec<BlockContent>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EGFtnEdnNumProps {
-    #[serde(rename = "numStart")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "numStart"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub num_start: Option<Box<CTDecimalNumber>>,
-    #[serde(rename = "numRestart")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "numRestart"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub num_restart: Option<Box<CTNumRestart>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTFtnProps {
     #[cfg(feature = "wml-comments")]
-    #[serde(rename = "pos")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pos"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub pos: Option<Box<CTFtnPos>>,
     #[cfg(feature = "wml-comments")]
-    #[serde(rename = "numFmt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "numFmt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub num_fmt: Option<Box<CTNumFmt>>,
-    #[serde(rename = "numStart")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "numStart"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub num_start: Option<Box<CTDecimalNumber>>,
-    #[serde(rename = "numRestart")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "numRestart"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub num_restart: Option<Box<CTNumRestart>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTEdnProps {
     #[cfg(feature = "wml-comments")]
-    #[serde(rename = "pos")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pos"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub pos: Option<Box<CTEdnPos>>,
     #[cfg(feature = "wml-comments")]
-    #[serde(rename = "numFmt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "numFmt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub num_fmt: Option<Box<CTNumFmt>>,
-    #[serde(rename = "numStart")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "numStart"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub num_start: Option<Box<CTDecimalNumber>>,
-    #[serde(rename = "numRestart")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "numRestart"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub num_restart: Option<Box<CTNumRestart>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTFtnDocProps {
-    #[serde(rename = "pos")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pos"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub pos: Option<Box<CTFtnPos>>,
-    #[serde(rename = "numFmt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "numFmt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub num_fmt: Option<Box<CTNumFmt>>,
-    #[serde(rename = "numStart")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "numStart"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub num_start: Option<Box<CTDecimalNumber>>,
-    #[serde(rename = "numRestart")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "numRestart"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub num_restart: Option<Box<CTNumRestart>>,
     #[cfg(feature = "wml-comments")]
-    #[serde(rename = "footnote")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "footnote"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub footnote: Vec<CTFtnEdnSepRef>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTEdnDocProps {
-    #[serde(rename = "pos")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pos"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub pos: Option<Box<CTEdnPos>>,
-    #[serde(rename = "numFmt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "numFmt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub num_fmt: Option<Box<CTNumFmt>>,
-    #[serde(rename = "numStart")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "numStart"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub num_start: Option<Box<CTDecimalNumber>>,
-    #[serde(rename = "numRestart")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "numRestart"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub num_restart: Option<Box<CTNumRestart>>,
     #[cfg(feature = "wml-comments")]
-    #[serde(rename = "endnote")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "endnote"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub endnote: Vec<CTFtnEdnSepRef>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTRecipientData {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "active")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "active"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub active: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "column")]
+    #[cfg_attr(feature = "serde", serde(rename = "column"))]
     pub column: Box<CTDecimalNumber>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "uniqueTag")]
+    #[cfg_attr(feature = "serde", serde(rename = "uniqueTag"))]
     pub unique_tag: Box<CTBase64Binary>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTBase64Binary {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: Vec<u8>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTRecipients {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "recipientData")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "recipientData"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub recipient_data: Vec<CTRecipientData>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
 pub type WRecipients = Box<CTRecipients>;
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTOdsoFieldMapData {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "type")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r#type: Option<Box<CTMailMergeOdsoFMDFieldType>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "name")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "name"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub name: Option<Box<CTString>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "mappedName")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "mappedName"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub mapped_name: Option<Box<CTString>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "column")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "column"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub column: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "lid")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "lid"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub lid: Option<Box<CTLang>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "dynamicAddress")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "dynamicAddress"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub dynamic_address: Option<Box<OnOffElement>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTMailMergeSourceType {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STMailMergeSourceType,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTOdso {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "udl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "udl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub udl: Option<Box<CTString>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "table")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "table"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub table: Option<Box<CTString>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "src")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "src"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub src: Option<Box<CTRel>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "colDelim")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "colDelim"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub col_delim: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "type")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r#type: Option<Box<CTMailMergeSourceType>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "fHdr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "fHdr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub f_hdr: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "fieldMapData")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "fieldMapData"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub field_map_data: Vec<CTOdsoFieldMapData>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "recipientData")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "recipientData"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub recipient_data: Vec<CTRel>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTMailMerge {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "mainDocumentType")]
+    #[cfg_attr(feature = "serde", serde(rename = "mainDocumentType"))]
     pub main_document_type: Box<CTMailMergeDocType>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "linkToQuery")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "linkToQuery"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub link_to_query: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "dataType")]
+    #[cfg_attr(feature = "serde", serde(rename = "dataType"))]
     pub data_type: Box<MailMergeDataTypeElement>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "connectString")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "connectString"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub connect_string: Option<Box<CTString>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "query")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "query"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub query: Option<Box<CTString>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "dataSource")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "dataSource"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub data_source: Option<Box<CTRel>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "headerSource")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "headerSource"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub header_source: Option<Box<CTRel>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotSuppressBlankLines")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotSuppressBlankLines"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_suppress_blank_lines: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "destination")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "destination"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub destination: Option<Box<CTMailMergeDest>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "addressFieldName")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "addressFieldName"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub address_field_name: Option<Box<CTString>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "mailSubject")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "mailSubject"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub mail_subject: Option<Box<CTString>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "mailAsAttachment")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "mailAsAttachment"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub mail_as_attachment: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "viewMergedData")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "viewMergedData"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub view_merged_data: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "activeRecord")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "activeRecord"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub active_record: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "checkErrors")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "checkErrors"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub check_errors: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "odso")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "odso"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub odso: Option<Box<CTOdso>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTargetScreenSz {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STTargetScreenSz,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Compatibility {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "useSingleBorderforContiguousCells")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "useSingleBorderforContiguousCells"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub use_single_borderfor_contiguous_cells: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "wpJustification")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "wpJustification"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub wp_justification: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "noTabHangInd")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "noTabHangInd"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub no_tab_hang_ind: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "noLeading")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "noLeading"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub no_leading: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "spaceForUL")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "spaceForUL"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub space_for_u_l: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "noColumnBalance")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "noColumnBalance"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub no_column_balance: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "balanceSingleByteDoubleByteWidth")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "balanceSingleByteDoubleByteWidth"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub balance_single_byte_double_byte_width: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "noExtraLineSpacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "noExtraLineSpacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub no_extra_line_spacing: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotLeaveBackslashAlone")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotLeaveBackslashAlone"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_leave_backslash_alone: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "ulTrailSpace")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "ulTrailSpace"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ul_trail_space: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotExpandShiftReturn")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotExpandShiftReturn"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_expand_shift_return: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "spacingInWholePoints")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "spacingInWholePoints"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spacing_in_whole_points: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "lineWrapLikeWord6")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "lineWrapLikeWord6"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub line_wrap_like_word6: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "printBodyTextBeforeHeader")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "printBodyTextBeforeHeader"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub print_body_text_before_header: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "printColBlack")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "printColBlack"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub print_col_black: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "wpSpaceWidth")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "wpSpaceWidth"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub wp_space_width: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "showBreaksInFrames")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "showBreaksInFrames"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub show_breaks_in_frames: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "subFontBySize")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "subFontBySize"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sub_font_by_size: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "suppressBottomSpacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "suppressBottomSpacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub suppress_bottom_spacing: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "suppressTopSpacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "suppressTopSpacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub suppress_top_spacing: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "suppressSpacingAtTopOfPage")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "suppressSpacingAtTopOfPage"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub suppress_spacing_at_top_of_page: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "suppressTopSpacingWP")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "suppressTopSpacingWP"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub suppress_top_spacing_w_p: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "suppressSpBfAfterPgBrk")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "suppressSpBfAfterPgBrk"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub suppress_sp_bf_after_pg_brk: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "swapBordersFacingPages")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "swapBordersFacingPages"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub swap_borders_facing_pages: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "convMailMergeEsc")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "convMailMergeEsc"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub conv_mail_merge_esc: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "truncateFontHeightsLikeWP6")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "truncateFontHeightsLikeWP6"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub truncate_font_heights_like_w_p6: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "mwSmallCaps")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "mwSmallCaps"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub mw_small_caps: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "usePrinterMetrics")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "usePrinterMetrics"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub use_printer_metrics: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotSuppressParagraphBorders")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotSuppressParagraphBorders"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_suppress_paragraph_borders: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "wrapTrailSpaces")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "wrapTrailSpaces"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub wrap_trail_spaces: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "footnoteLayoutLikeWW8")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "footnoteLayoutLikeWW8"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub footnote_layout_like_w_w8: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "shapeLayoutLikeWW8")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "shapeLayoutLikeWW8"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shape_layout_like_w_w8: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "alignTablesRowByRow")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "alignTablesRowByRow"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub align_tables_row_by_row: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "forgetLastTabAlignment")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "forgetLastTabAlignment"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub forget_last_tab_alignment: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "adjustLineHeightInTable")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "adjustLineHeightInTable"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub adjust_line_height_in_table: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "autoSpaceLikeWord95")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "autoSpaceLikeWord95"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub auto_space_like_word95: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "noSpaceRaiseLower")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "noSpaceRaiseLower"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub no_space_raise_lower: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotUseHTMLParagraphAutoSpacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotUseHTMLParagraphAutoSpacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_use_h_t_m_l_paragraph_auto_spacing: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "layoutRawTableWidth")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "layoutRawTableWidth"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub layout_raw_table_width: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "layoutTableRowsApart")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "layoutTableRowsApart"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub layout_table_rows_apart: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "useWord97LineBreakRules")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "useWord97LineBreakRules"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub use_word97_line_break_rules: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotBreakWrappedTables")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotBreakWrappedTables"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_break_wrapped_tables: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotSnapToGridInCell")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotSnapToGridInCell"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_snap_to_grid_in_cell: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "selectFldWithFirstOrLastChar")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "selectFldWithFirstOrLastChar"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub select_fld_with_first_or_last_char: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "applyBreakingRules")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "applyBreakingRules"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub apply_breaking_rules: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotWrapTextWithPunct")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotWrapTextWithPunct"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_wrap_text_with_punct: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotUseEastAsianBreakRules")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotUseEastAsianBreakRules"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_use_east_asian_break_rules: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "useWord2002TableStyleRules")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "useWord2002TableStyleRules"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub use_word2002_table_style_rules: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "growAutofit")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "growAutofit"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub grow_autofit: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "useFELayout")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "useFELayout"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub use_f_e_layout: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "useNormalStyleForList")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "useNormalStyleForList"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub use_normal_style_for_list: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotUseIndentAsNumberingTabStop")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotUseIndentAsNumberingTabStop"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_use_indent_as_numbering_tab_stop: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "useAltKinsokuLineBreakRules")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "useAltKinsokuLineBreakRules"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub use_alt_kinsoku_line_break_rules: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "allowSpaceOfSameStyleInTable")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "allowSpaceOfSameStyleInTable"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub allow_space_of_same_style_in_table: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotSuppressIndentation")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotSuppressIndentation"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_suppress_indentation: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotAutofitConstrainedTables")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotAutofitConstrainedTables"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_autofit_constrained_tables: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "autofitToFirstFixedWidthCell")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "autofitToFirstFixedWidthCell"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub autofit_to_first_fixed_width_cell: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "underlineTabInNumList")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "underlineTabInNumList"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub underline_tab_in_num_list: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "displayHangulFixedWidth")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "displayHangulFixedWidth"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub display_hangul_fixed_width: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "splitPgBreakAndParaMark")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "splitPgBreakAndParaMark"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub split_pg_break_and_para_mark: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotVertAlignCellWithSp")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotVertAlignCellWithSp"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_vert_align_cell_with_sp: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotBreakConstrainedForcedTable")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotBreakConstrainedForcedTable"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_break_constrained_forced_table: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotVertAlignInTxbx")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotVertAlignInTxbx"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_vert_align_in_txbx: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "useAnsiKerningPairs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "useAnsiKerningPairs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub use_ansi_kerning_pairs: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "cachedColBalance")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "cachedColBalance"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cached_col_balance: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "compatSetting")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "compatSetting"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub compat_setting: Vec<CTCompatSetting>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTCompatSetting {
-    #[serde(rename = "@w:name")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:name"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub name: Option<STString>,
-    #[serde(rename = "@w:uri")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:uri"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub uri: Option<STString>,
-    #[serde(rename = "@w:val")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<STString>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTDocVar {
-    #[serde(rename = "@w:name")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:name"))]
     pub name: STString,
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STString,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTDocVars {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "docVar")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "docVar"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub doc_var: Vec<CTDocVar>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTDocRsids {
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "rsidRoot")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rsidRoot"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsid_root: Option<Box<LongHexNumberElement>>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "rsid")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "rsid"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub rsid: Vec<LongHexNumberElement>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTCharacterSpacing {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STCharacterSpacing,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSaveThroughXslt {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@r:id")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@r:id"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub id: Option<STRelationshipId>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:solutionID")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:solutionID"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub solution_i_d: Option<STString>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RunPropertiesDefault {
-    #[serde(rename = "rPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r_pr: Option<Box<RunProperties>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ParagraphPropertiesDefault {
-    #[serde(rename = "pPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub p_pr: Option<Box<CTPPrGeneral>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DocumentDefaults {
-    #[serde(rename = "rPrDefault")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rPrDefault"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r_pr_default: Option<Box<RunPropertiesDefault>>,
-    #[serde(rename = "pPrDefault")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pPrDefault"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub p_pr_default: Option<Box<ParagraphPropertiesDefault>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTColorSchemeMapping {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:bg1")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:bg1"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bg1: Option<STWmlColorSchemeIndex>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:t1")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:t1"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub t1: Option<STWmlColorSchemeIndex>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:bg2")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:bg2"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bg2: Option<STWmlColorSchemeIndex>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:t2")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:t2"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub t2: Option<STWmlColorSchemeIndex>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:accent1")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:accent1"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub accent1: Option<STWmlColorSchemeIndex>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:accent2")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:accent2"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub accent2: Option<STWmlColorSchemeIndex>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:accent3")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:accent3"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub accent3: Option<STWmlColorSchemeIndex>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:accent4")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:accent4"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub accent4: Option<STWmlColorSchemeIndex>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:accent5")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:accent5"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub accent5: Option<STWmlColorSchemeIndex>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:accent6")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:accent6"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub accent6: Option<STWmlColorSchemeIndex>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:hyperlink")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:hyperlink"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hyperlink: Option<STWmlColorSchemeIndex>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:followedHyperlink")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:followedHyperlink"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub followed_hyperlink: Option<STWmlColorSchemeIndex>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTReadingModeInkLockDown {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:actualPg")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:actualPg"))]
     pub actual_pg: OnOff,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:w")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:w"))]
     pub width: STPixelsMeasure,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:h")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:h"))]
     pub height: STPixelsMeasure,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:fontSz")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:fontSz"))]
     pub font_sz: STDecimalNumberOrPercent,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTWriteProtection {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:recommended")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:recommended"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub recommended: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:algorithmName")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:algorithmName"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub algorithm_name: Option<STString>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:hashValue")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:hashValue"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hash_value: Option<Vec<u8>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:saltValue")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:saltValue"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub salt_value: Option<Vec<u8>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:spinCount")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:spinCount"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub spin_count: Option<STDecimalNumber>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:cryptProviderType")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:cryptProviderType"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_provider_type: Option<STCryptProv>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:cryptAlgorithmClass")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:cryptAlgorithmClass"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_algorithm_class: Option<STAlgClass>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:cryptAlgorithmType")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:cryptAlgorithmType"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_algorithm_type: Option<STAlgType>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:cryptAlgorithmSid")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:cryptAlgorithmSid"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_algorithm_sid: Option<STDecimalNumber>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:cryptSpinCount")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:cryptSpinCount"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_spin_count: Option<STDecimalNumber>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:cryptProvider")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:cryptProvider"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_provider: Option<STString>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:algIdExt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:algIdExt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub alg_id_ext: Option<STLongHexNumber>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:algIdExtSource")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:algIdExtSource"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub alg_id_ext_source: Option<STString>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:cryptProviderTypeExt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:cryptProviderTypeExt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_provider_type_ext: Option<STLongHexNumber>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:cryptProviderTypeExtSource")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:cryptProviderTypeExtSource"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub crypt_provider_type_ext_source: Option<STString>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:hash")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:hash"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hash: Option<Vec<u8>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:salt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:salt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub salt: Option<Vec<u8>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Settings {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "writeProtection")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "writeProtection"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub write_protection: Option<Box<CTWriteProtection>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "view")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "view"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub view: Option<Box<CTView>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "zoom")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "zoom"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub zoom: Option<Box<CTZoom>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "removePersonalInformation")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "removePersonalInformation"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub remove_personal_information: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "removeDateAndTime")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "removeDateAndTime"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub remove_date_and_time: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotDisplayPageBoundaries")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotDisplayPageBoundaries"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_display_page_boundaries: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "displayBackgroundShape")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "displayBackgroundShape"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub display_background_shape: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "printPostScriptOverText")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "printPostScriptOverText"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub print_post_script_over_text: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "printFractionalCharacterWidth")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "printFractionalCharacterWidth"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub print_fractional_character_width: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "printFormsData")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "printFormsData"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub print_forms_data: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "embedTrueTypeFonts")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "embedTrueTypeFonts"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub embed_true_type_fonts: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "embedSystemFonts")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "embedSystemFonts"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub embed_system_fonts: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "saveSubsetFonts")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "saveSubsetFonts"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub save_subset_fonts: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "saveFormsData")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "saveFormsData"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub save_forms_data: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "mirrorMargins")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "mirrorMargins"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub mirror_margins: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "alignBordersAndEdges")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "alignBordersAndEdges"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub align_borders_and_edges: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "bordersDoNotSurroundHeader")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bordersDoNotSurroundHeader"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub borders_do_not_surround_header: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "bordersDoNotSurroundFooter")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bordersDoNotSurroundFooter"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub borders_do_not_surround_footer: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "gutterAtTop")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "gutterAtTop"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub gutter_at_top: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "hideSpellingErrors")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "hideSpellingErrors"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hide_spelling_errors: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "hideGrammaticalErrors")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "hideGrammaticalErrors"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hide_grammatical_errors: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "activeWritingStyle")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "activeWritingStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub active_writing_style: Vec<CTWritingStyle>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "proofState")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "proofState"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub proof_state: Option<Box<CTProof>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "formsDesign")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "formsDesign"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub forms_design: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "attachedTemplate")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "attachedTemplate"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub attached_template: Option<Box<CTRel>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "linkStyles")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "linkStyles"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub link_styles: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "stylePaneFormatFilter")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "stylePaneFormatFilter"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub style_pane_format_filter: Option<Box<CTStylePaneFilter>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "stylePaneSortMethod")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "stylePaneSortMethod"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub style_pane_sort_method: Option<Box<CTStyleSort>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "documentType")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "documentType"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub document_type: Option<Box<DocTypeElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "mailMerge")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "mailMerge"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub mail_merge: Option<Box<CTMailMerge>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "revisionView")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "revisionView"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub revision_view: Option<Box<CTTrackChangesView>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "trackRevisions")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "trackRevisions"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub track_revisions: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "doNotTrackMoves")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotTrackMoves"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_track_moves: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "doNotTrackFormatting")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotTrackFormatting"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_track_formatting: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "documentProtection")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "documentProtection"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub document_protection: Option<Box<CTDocProtect>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "autoFormatOverride")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "autoFormatOverride"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub auto_format_override: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "styleLockTheme")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "styleLockTheme"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub style_lock_theme: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "styleLockQFSet")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "styleLockQFSet"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub style_lock_q_f_set: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "defaultTabStop")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "defaultTabStop"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub default_tab_stop: Option<Box<TwipsMeasureElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "autoHyphenation")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "autoHyphenation"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub auto_hyphenation: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "consecutiveHyphenLimit")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "consecutiveHyphenLimit"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub consecutive_hyphen_limit: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "hyphenationZone")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "hyphenationZone"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hyphenation_zone: Option<Box<TwipsMeasureElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotHyphenateCaps")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotHyphenateCaps"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_hyphenate_caps: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "showEnvelope")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "showEnvelope"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub show_envelope: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "summaryLength")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "summaryLength"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub summary_length: Option<Box<CTDecimalNumberOrPrecent>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "clickAndTypeStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "clickAndTypeStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub click_and_type_style: Option<Box<CTString>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "defaultTableStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "defaultTableStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub default_table_style: Option<Box<CTString>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "evenAndOddHeaders")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "evenAndOddHeaders"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub even_and_odd_headers: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "bookFoldRevPrinting")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bookFoldRevPrinting"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub book_fold_rev_printing: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "bookFoldPrinting")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bookFoldPrinting"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub book_fold_printing: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "bookFoldPrintingSheets")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bookFoldPrintingSheets"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub book_fold_printing_sheets: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "drawingGridHorizontalSpacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "drawingGridHorizontalSpacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub drawing_grid_horizontal_spacing: Option<Box<TwipsMeasureElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "drawingGridVerticalSpacing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "drawingGridVerticalSpacing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub drawing_grid_vertical_spacing: Option<Box<TwipsMeasureElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "displayHorizontalDrawingGridEvery")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "displayHorizontalDrawingGridEvery"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub display_horizontal_drawing_grid_every: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "displayVerticalDrawingGridEvery")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "displayVerticalDrawingGridEvery"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub display_vertical_drawing_grid_every: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotUseMarginsForDrawingGridOrigin")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "doNotUseMarginsForDrawingGridOrigin")
+    )]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_use_margins_for_drawing_grid_origin: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "drawingGridHorizontalOrigin")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "drawingGridHorizontalOrigin"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub drawing_grid_horizontal_origin: Option<Box<TwipsMeasureElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "drawingGridVerticalOrigin")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "drawingGridVerticalOrigin"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub drawing_grid_vertical_origin: Option<Box<TwipsMeasureElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotShadeFormData")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotShadeFormData"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_shade_form_data: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "noPunctuationKerning")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "noPunctuationKerning"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub no_punctuation_kerning: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "characterSpacingControl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "characterSpacingControl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub character_spacing_control: Option<Box<CTCharacterSpacing>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "printTwoOnOne")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "printTwoOnOne"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub print_two_on_one: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "strictFirstAndLastChars")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "strictFirstAndLastChars"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub strict_first_and_last_chars: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "noLineBreaksAfter")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "noLineBreaksAfter"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub no_line_breaks_after: Option<Box<CTKinsoku>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "noLineBreaksBefore")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "noLineBreaksBefore"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub no_line_breaks_before: Option<Box<CTKinsoku>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "savePreviewPicture")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "savePreviewPicture"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub save_preview_picture: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotValidateAgainstSchema")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotValidateAgainstSchema"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_validate_against_schema: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "saveInvalidXml")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "saveInvalidXml"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub save_invalid_xml: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "ignoreMixedContent")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "ignoreMixedContent"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ignore_mixed_content: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "alwaysShowPlaceholderText")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "alwaysShowPlaceholderText"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub always_show_placeholder_text: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotDemarcateInvalidXml")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotDemarcateInvalidXml"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_demarcate_invalid_xml: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "saveXmlDataOnly")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "saveXmlDataOnly"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub save_xml_data_only: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "useXSLTWhenSaving")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "useXSLTWhenSaving"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub use_x_s_l_t_when_saving: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "saveThroughXslt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "saveThroughXslt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub save_through_xslt: Option<Box<CTSaveThroughXslt>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "showXMLTags")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "showXMLTags"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub show_x_m_l_tags: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "alwaysMergeEmptyNamespace")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "alwaysMergeEmptyNamespace"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub always_merge_empty_namespace: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "updateFields")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "updateFields"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub update_fields: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "hdrShapeDefaults")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "hdrShapeDefaults"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hdr_shape_defaults: Option<Box<CTShapeDefaults>>,
     #[cfg(feature = "wml-comments")]
-    #[serde(rename = "footnotePr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "footnotePr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub footnote_pr: Option<Box<CTFtnDocProps>>,
     #[cfg(feature = "wml-comments")]
-    #[serde(rename = "endnotePr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "endnotePr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub endnote_pr: Option<Box<CTEdnDocProps>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "compat")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "compat"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub compat: Option<Box<Compatibility>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "docVars")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "docVars"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub doc_vars: Option<Box<CTDocVars>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "rsids")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rsids"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsids: Option<Box<CTDocRsids>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "attachedSchema")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "attachedSchema"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub attached_schema: Vec<CTString>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "themeFontLang")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "themeFontLang"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub theme_font_lang: Option<Box<LanguageElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "clrSchemeMapping")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "clrSchemeMapping"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub clr_scheme_mapping: Option<Box<CTColorSchemeMapping>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotIncludeSubdocsInStats")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotIncludeSubdocsInStats"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_include_subdocs_in_stats: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotAutoCompressPictures")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotAutoCompressPictures"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_auto_compress_pictures: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "forceUpgrade")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "forceUpgrade"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub force_upgrade: Option<Box<CTEmpty>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "captions")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "captions"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub captions: Option<Box<CTCaptions>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "readModeInkLockDown")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "readModeInkLockDown"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub read_mode_ink_lock_down: Option<Box<CTReadingModeInkLockDown>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "smartTagType")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "smartTagType"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub smart_tag_type: Vec<CTSmartTagType>,
     #[cfg(feature = "wml-drawings")]
-    #[serde(rename = "shapeDefaults")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "shapeDefaults"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub shape_defaults: Option<Box<CTShapeDefaults>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotEmbedSmartTags")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotEmbedSmartTags"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_embed_smart_tags: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "decimalSymbol")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "decimalSymbol"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub decimal_symbol: Option<Box<CTString>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "listSeparator")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "listSeparator"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub list_separator: Option<Box<CTString>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTStyleSort {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STStyleSort,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTStylePaneFilter {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:allStyles")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:allStyles"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub all_styles: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:customStyles")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:customStyles"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub custom_styles: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:latentStyles")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:latentStyles"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub latent_styles: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:stylesInUse")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:stylesInUse"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub styles_in_use: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:headingStyles")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:headingStyles"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub heading_styles: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:numberingStyles")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:numberingStyles"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub numbering_styles: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:tableStyles")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:tableStyles"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub table_styles: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:directFormattingOnRuns")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:directFormattingOnRuns"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub direct_formatting_on_runs: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:directFormattingOnParagraphs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:directFormattingOnParagraphs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub direct_formatting_on_paragraphs: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:directFormattingOnNumbering")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:directFormattingOnNumbering"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub direct_formatting_on_numbering: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:directFormattingOnTables")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:directFormattingOnTables"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub direct_formatting_on_tables: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:clearFormatting")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:clearFormatting"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub clear_formatting: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:top3HeadingStyles")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:top3HeadingStyles"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub top3_heading_styles: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:visibleStyles")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:visibleStyles"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub visible_styles: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:alternateStyleNames")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:alternateStyleNames"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub alternate_style_names: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:val")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<STShortHexNumber>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTWebSettings {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "frameset")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "frameset"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub frameset: Option<Box<CTFrameset>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "divs")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "divs"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub divs: Option<Box<CTDivs>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "encoding")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "encoding"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub encoding: Option<Box<CTString>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "optimizeForBrowser")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "optimizeForBrowser"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub optimize_for_browser: Option<Box<CTOptimizeForBrowser>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "relyOnVML")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "relyOnVML"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rely_on_v_m_l: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "allowPNG")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "allowPNG"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub allow_p_n_g: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotRelyOnCSS")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotRelyOnCSS"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_rely_on_c_s_s: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotSaveAsSingleFile")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotSaveAsSingleFile"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_save_as_single_file: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotOrganizeInFolder")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotOrganizeInFolder"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_organize_in_folder: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "doNotUseLongFileNames")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "doNotUseLongFileNames"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub do_not_use_long_file_names: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "pixelsPerInch")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pixelsPerInch"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub pixels_per_inch: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "targetScreenSz")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "targetScreenSz"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub target_screen_sz: Option<Box<CTTargetScreenSz>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "saveSmartTagsAsXml")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "saveSmartTagsAsXml"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub save_smart_tags_as_xml: Option<Box<OnOffElement>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTFrameScrollbar {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STFrameScrollbar,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTOptimizeForBrowser {
-    #[serde(rename = "@w:val")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:target")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:target"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub target: Option<STString>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTFrame {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "sz")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sz"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub size: Option<Box<CTString>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "name")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "name"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub name: Option<Box<CTString>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "title")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "title"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub title: Option<Box<CTString>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "longDesc")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "longDesc"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub long_desc: Option<Box<CTRel>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "sourceFileName")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sourceFileName"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub source_file_name: Option<Box<CTRel>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "marW")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "marW"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub mar_w: Option<Box<PixelsMeasureElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "marH")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "marH"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub mar_h: Option<Box<PixelsMeasureElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "scrollbar")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "scrollbar"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub scrollbar: Option<Box<CTFrameScrollbar>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "noResizeAllowed")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "noResizeAllowed"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub no_resize_allowed: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "linkedToFile")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "linkedToFile"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub linked_to_file: Option<Box<OnOffElement>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTFrameLayout {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STFrameLayout,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTFramesetSplitbar {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "w")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "w"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub width: Option<Box<TwipsMeasureElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "color")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "color"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub color: Option<Box<CTColor>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "noBorder")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "noBorder"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub no_border: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "flatBorders")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "flatBorders"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub flat_borders: Option<Box<OnOffElement>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTFrameset {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "sz")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sz"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub size: Option<Box<CTString>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "framesetSplitbar")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "framesetSplitbar"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub frameset_splitbar: Option<Box<CTFramesetSplitbar>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "frameLayout")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "frameLayout"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub frame_layout: Option<Box<CTFrameLayout>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "title")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "title"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub title: Option<Box<CTString>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTNumPicBullet {
-    #[serde(rename = "@w:numPicBulletId")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:numPicBulletId"))]
     pub num_pic_bullet_id: STDecimalNumber,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "pict")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pict"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub pict: Option<Box<CTPicture>>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "drawing")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "drawing"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub drawing: Option<Box<CTDrawing>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTLevelSuffix {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STLevelSuffix,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTLevelText {
-    #[serde(rename = "@w:val")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub value: Option<STString>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "@w:null")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:null"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub null: Option<OnOff>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTLvlLegacy {
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "@w:legacy")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:legacy"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub legacy: Option<OnOff>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "@w:legacySpace")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:legacySpace"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub legacy_space: Option<STTwipsMeasure>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "@w:legacyIndent")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:legacyIndent"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub legacy_indent: Option<STSignedTwipsMeasure>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Level {
-    #[serde(rename = "@w:ilvl")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:ilvl"))]
     pub ilvl: STDecimalNumber,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "@w:tplc")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:tplc"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tplc: Option<STLongHexNumber>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "@w:tentative")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:tentative"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tentative: Option<OnOff>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "start")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "start"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub start: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "numFmt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "numFmt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub num_fmt: Option<Box<CTNumFmt>>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "lvlRestart")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "lvlRestart"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub lvl_restart: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "pStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub paragraph_style: Option<Box<CTString>>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "isLgl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "isLgl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub is_lgl: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "suff")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "suff"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub suff: Option<Box<CTLevelSuffix>>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "lvlText")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "lvlText"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub lvl_text: Option<Box<CTLevelText>>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "lvlPicBulletId")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "lvlPicBulletId"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub lvl_pic_bullet_id: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "legacy")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "legacy"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub legacy: Option<Box<CTLvlLegacy>>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "lvlJc")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "lvlJc"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub lvl_jc: Option<Box<CTJc>>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "pPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub p_pr: Option<Box<CTPPrGeneral>>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "rPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r_pr: Option<Box<RunProperties>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTMultiLevelType {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STMultiLevelType,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AbstractNumbering {
-    #[serde(rename = "@w:abstractNumId")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:abstractNumId"))]
     pub abstract_num_id: STDecimalNumber,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "nsid")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "nsid"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub nsid: Option<Box<LongHexNumberElement>>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "multiLevelType")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "multiLevelType"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub multi_level_type: Option<Box<CTMultiLevelType>>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "tmpl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tmpl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub tmpl: Option<Box<LongHexNumberElement>>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "name")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "name"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub name: Option<Box<CTString>>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "styleLink")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "styleLink"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub style_link: Option<Box<CTString>>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "numStyleLink")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "numStyleLink"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub num_style_link: Option<Box<CTString>>,
-    #[serde(rename = "lvl")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "lvl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub lvl: Vec<Level>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTNumLvl {
-    #[serde(rename = "@w:ilvl")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:ilvl"))]
     pub ilvl: STDecimalNumber,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "startOverride")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "startOverride"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub start_override: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "lvl")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "lvl"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub lvl: Option<Box<Level>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct NumberingInstance {
-    #[serde(rename = "@w:numId")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:numId"))]
     pub num_id: STDecimalNumber,
-    #[serde(rename = "abstractNumId")]
+    #[cfg_attr(feature = "serde", serde(rename = "abstractNumId"))]
     pub abstract_num_id: Box<CTDecimalNumber>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "lvlOverride")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "lvlOverride"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub lvl_override: Vec<CTNumLvl>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Numbering {
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "numPicBullet")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "numPicBullet"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub num_pic_bullet: Vec<CTNumPicBullet>,
-    #[serde(rename = "abstractNum")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "abstractNum"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub abstract_num: Vec<AbstractNumbering>,
-    #[serde(rename = "num")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "num"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub num: Vec<NumberingInstance>,
     #[cfg(feature = "wml-numbering")]
-    #[serde(rename = "numIdMacAtCleanup")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "numIdMacAtCleanup"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub num_id_mac_at_cleanup: Option<Box<CTDecimalNumber>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TableStyleProperties {
-    #[serde(rename = "@w:type")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:type"))]
     pub r#type: STTblStyleOverrideType,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "pPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub p_pr: Option<Box<CTPPrGeneral>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "rPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r_pr: Option<Box<RunProperties>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "tblPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub table_properties: Option<Box<CTTblPrBase>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "trPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "trPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub row_properties: Option<Box<TableRowProperties>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "tcPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tcPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cell_properties: Option<Box<TableCellProperties>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Style {
-    #[serde(rename = "@w:type")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:type"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r#type: Option<STStyleType>,
-    #[serde(rename = "@w:styleId")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:styleId"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub style_id: Option<STString>,
-    #[serde(rename = "@w:default")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:default"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub default: Option<OnOff>,
-    #[serde(rename = "@w:customStyle")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:customStyle"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub custom_style: Option<OnOff>,
-    #[serde(rename = "name")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "name"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub name: Option<Box<CTString>>,
-    #[serde(rename = "aliases")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "aliases"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub aliases: Option<Box<CTString>>,
-    #[serde(rename = "basedOn")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "basedOn"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub based_on: Option<Box<CTString>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "next")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "next"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub next: Option<Box<CTString>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "link")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "link"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub link: Option<Box<CTString>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "autoRedefine")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "autoRedefine"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub auto_redefine: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "hidden")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "hidden"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub hidden: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "uiPriority")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "uiPriority"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ui_priority: Option<Box<CTDecimalNumber>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "semiHidden")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "semiHidden"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub semi_hidden: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "unhideWhenUsed")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "unhideWhenUsed"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub unhide_when_used: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "qFormat")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "qFormat"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub q_format: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "locked")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "locked"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub locked: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "personal")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "personal"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub personal: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "personalCompose")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "personalCompose"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub personal_compose: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "personalReply")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "personalReply"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub personal_reply: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-track-changes")]
-    #[serde(rename = "rsid")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rsid"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub rsid: Option<Box<LongHexNumberElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "pPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub p_pr: Option<Box<CTPPrGeneral>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "rPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "rPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub r_pr: Option<Box<RunProperties>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "tblPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub table_properties: Option<Box<CTTblPrBase>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "trPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "trPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub row_properties: Option<Box<TableRowProperties>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "tcPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "tcPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub cell_properties: Option<Box<TableCellProperties>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "tblStylePr")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "tblStylePr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub tbl_style_pr: Vec<TableStyleProperties>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LatentStyleException {
-    #[serde(rename = "@w:name")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:name"))]
     pub name: STString,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:locked")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:locked"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub locked: Option<OnOff>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:uiPriority")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:uiPriority"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub ui_priority: Option<STDecimalNumber>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:semiHidden")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:semiHidden"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub semi_hidden: Option<OnOff>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:unhideWhenUsed")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:unhideWhenUsed"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub unhide_when_used: Option<OnOff>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:qFormat")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:qFormat"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub q_format: Option<OnOff>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LatentStyles {
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:defLockedState")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:defLockedState"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub def_locked_state: Option<OnOff>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:defUIPriority")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:defUIPriority"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub def_u_i_priority: Option<STDecimalNumber>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:defSemiHidden")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:defSemiHidden"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub def_semi_hidden: Option<OnOff>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:defUnhideWhenUsed")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:defUnhideWhenUsed"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub def_unhide_when_used: Option<OnOff>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:defQFormat")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:defQFormat"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub def_q_format: Option<OnOff>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:count")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:count"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub count: Option<STDecimalNumber>,
-    #[serde(rename = "lsdException")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "lsdException"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub lsd_exception: Vec<LatentStyleException>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Styles {
-    #[serde(rename = "docDefaults")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "docDefaults"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub doc_defaults: Option<Box<DocumentDefaults>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "latentStyles")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "latentStyles"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub latent_styles: Option<Box<LatentStyles>>,
-    #[serde(rename = "style")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "style"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub style: Vec<Style>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PanoseElement {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: Panose,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTFontFamily {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STFontFamily,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTPitch {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STPitch,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTFontSig {
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:usb0")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:usb0"))]
     pub usb0: STLongHexNumber,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:usb1")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:usb1"))]
     pub usb1: STLongHexNumber,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:usb2")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:usb2"))]
     pub usb2: STLongHexNumber,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:usb3")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:usb3"))]
     pub usb3: STLongHexNumber,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:csb0")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:csb0"))]
     pub csb0: STLongHexNumber,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:csb1")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:csb1"))]
     pub csb1: STLongHexNumber,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTFontRel {
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@r:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@r:id"))]
     pub id: STRelationshipId,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:fontKey")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:fontKey"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub font_key: Option<Guid>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "@w:subsetted")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:subsetted"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub subsetted: Option<OnOff>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Font {
-    #[serde(rename = "@w:name")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:name"))]
     pub name: STString,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "altName")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "altName"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub alt_name: Option<Box<CTString>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "panose1")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "panose1"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub panose1: Option<Box<PanoseElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "charset")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "charset"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub charset: Option<Box<CTCharset>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "family")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "family"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub family: Option<Box<CTFontFamily>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "notTrueType")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "notTrueType"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub not_true_type: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "pitch")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "pitch"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub pitch: Option<Box<CTPitch>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "sig")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sig"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sig: Option<Box<CTFontSig>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "embedRegular")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "embedRegular"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub embed_regular: Option<Box<CTFontRel>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "embedBold")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "embedBold"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub embed_bold: Option<Box<CTFontRel>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "embedItalic")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "embedItalic"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub embed_italic: Option<Box<CTFontRel>>,
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "embedBoldItalic")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "embedBoldItalic"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub embed_bold_italic: Option<Box<CTFontRel>>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTFontsList {
-    #[serde(rename = "font")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "font"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub font: Vec<Font>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTDivBdr {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "top")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "top"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub top: Option<Box<CTBorder>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "left")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "left"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub left: Option<Box<CTBorder>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "bottom")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bottom"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub bottom: Option<Box<CTBorder>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "right")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "right"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub right: Option<Box<CTBorder>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTDiv {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:id")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:id"))]
     pub id: STDecimalNumber,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "blockQuote")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "blockQuote"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub block_quote: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "bodyDiv")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "bodyDiv"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub body_div: Option<Box<OnOffElement>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "marLeft")]
+    #[cfg_attr(feature = "serde", serde(rename = "marLeft"))]
     pub mar_left: Box<SignedTwipsMeasureElement>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "marRight")]
+    #[cfg_attr(feature = "serde", serde(rename = "marRight"))]
     pub mar_right: Box<SignedTwipsMeasureElement>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "marTop")]
+    #[cfg_attr(feature = "serde", serde(rename = "marTop"))]
     pub mar_top: Box<SignedTwipsMeasureElement>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "marBottom")]
+    #[cfg_attr(feature = "serde", serde(rename = "marBottom"))]
     pub mar_bottom: Box<SignedTwipsMeasureElement>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "divBdr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "divBdr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub div_bdr: Option<Box<CTDivBdr>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "divsChild")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "divsChild"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub divs_child: Vec<CTDivs>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTDivs {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "div")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "div"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub div: Vec<CTDiv>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTTxbxContent {
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub block_content: Vec<BlockContent>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
 pub type WTxbxContent = Box<CTTxbxContent>;
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EGMathContent {
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct EGBlockLevelChunkElts {
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub block_content: Vec<BlockContentChoice>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Body {
-    #[serde(skip)]
-    #[serde(default)]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub block_content: Vec<BlockContent>,
     #[cfg(feature = "wml-layout")]
-    #[serde(rename = "sectPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "sectPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sect_pr: Option<Box<SectionProperties>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTShapeDefaults {
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Comments {
-    #[serde(rename = "comment")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "comment"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub comment: Vec<Comment>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
 pub type WComments = Box<Comments>;
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Footnotes {
-    #[serde(rename = "footnote")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "footnote"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub footnote: Vec<FootnoteEndnote>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
 pub type WFootnotes = Box<Footnotes>;
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Endnotes {
-    #[serde(rename = "endnote")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "endnote"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub endnote: Vec<FootnoteEndnote>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
@@ -14847,199 +19897,270 @@ pub type WHdr = Box<HeaderFooter>;
 
 pub type WFtr = Box<HeaderFooter>;
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTSmartTagType {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:namespaceuri")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:namespaceuri"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub namespaceuri: Option<STString>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:name")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:name"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub name: Option<STString>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:url")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:url"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub url: Option<STString>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTDocPartBehavior {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STDocPartBehavior,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTDocPartBehaviors {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "behavior")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "behavior"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub behavior: Vec<CTDocPartBehavior>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTDocPartType {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STDocPartType,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTDocPartTypes {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:all")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:all"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub all: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "type")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub r#type: Vec<CTDocPartType>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTDocPartGallery {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STDocPartGallery,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTDocPartCategory {
-    #[serde(rename = "name")]
+    #[cfg_attr(feature = "serde", serde(rename = "name"))]
     pub name: Box<CTString>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "gallery")]
+    #[cfg_attr(feature = "serde", serde(rename = "gallery"))]
     pub gallery: Box<CTDocPartGallery>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTDocPartName {
-    #[serde(rename = "@w:val")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:val"))]
     pub value: STString,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:decorated")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:decorated"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub decorated: Option<OnOff>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTDocPartPr {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "name")]
+    #[cfg_attr(feature = "serde", serde(rename = "name"))]
     pub name: Box<CTDocPartName>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "style")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "style"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub style: Option<Box<CTString>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "category")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "category"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub category: Option<Box<CTDocPartCategory>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "types")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "types"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub types: Option<Box<CTDocPartTypes>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "behaviors")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "behaviors"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub behaviors: Option<Box<CTDocPartBehaviors>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "description")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "description"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub description: Option<Box<CTString>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "guid")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "guid"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub guid: Option<Box<GuidElement>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTDocPart {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "docPartPr")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "docPartPr"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub doc_part_pr: Option<Box<CTDocPartPr>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "docPartBody")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "docPartBody"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub doc_part_body: Option<Box<Body>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTDocParts {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "docPart")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "docPart"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub doc_part: Vec<CTDocPart>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
@@ -15053,143 +20174,205 @@ pub type WNumbering = Box<Numbering>;
 
 pub type WStyles = Box<Styles>;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTCaption {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:name")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:name"))]
     pub name: STString,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:pos")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:pos"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub pos: Option<STCaptionPos>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:chapNum")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:chapNum"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub chap_num: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:heading")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:heading"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub heading: Option<STDecimalNumber>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:noLabel")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:noLabel"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub no_label: Option<OnOff>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:numFmt")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:numFmt"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub num_fmt: Option<STNumberFormat>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:sep")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:sep"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub sep: Option<STChapterSep>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTAutoCaption {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:name")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:name"))]
     pub name: STString,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "@w:caption")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:caption"))]
     pub caption: STString,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTAutoCaptions {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "autoCaption")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "autoCaption"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub auto_caption: Vec<CTAutoCaption>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTCaptions {
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "caption")]
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[cfg_attr(feature = "serde", serde(rename = "caption"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub caption: Vec<CTCaption>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "autoCaptions")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "autoCaptions"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub auto_captions: Option<Box<CTAutoCaptions>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTDocumentBase {
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "background")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "background"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub background: Option<Box<CTBackground>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Document {
     #[cfg(feature = "wml-styling")]
-    #[serde(rename = "background")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "background"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub background: Option<Box<CTBackground>>,
-    #[serde(rename = "body")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "body"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub body: Option<Box<Body>>,
-    #[serde(rename = "@w:conformance")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "@w:conformance"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub conformance: Option<STConformanceClass>,
     /// Unknown attributes captured for roundtrip fidelity.
     #[cfg(feature = "extra-attrs")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-attrs")]
-    #[serde(default)]
-    #[cfg(feature = "extra-attrs")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")
+    )]
     pub extra_attrs: std::collections::HashMap<String, String>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CTGlossaryDocument {
-    #[serde(rename = "background")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "background"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub background: Option<Box<CTBackground>>,
     #[cfg(feature = "wml-settings")]
-    #[serde(rename = "docParts")]
-    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "serde", serde(rename = "docParts"))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     pub doc_parts: Option<Box<CTDocParts>>,
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
@@ -15197,20 +20380,26 @@ pub type WDocument = Box<Document>;
 
 pub type WGlossaryDocument = Box<CTGlossaryDocument>;
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct WAnyVmlOffice {
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct WAnyVmlVml {
     /// Unknown child elements captured for roundtrip fidelity.
     #[cfg(feature = "extra-children")]
-    #[serde(skip)]
-    #[cfg(feature = "extra-children")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
     pub extra_children: Vec<ooxml_xml::PositionedNode>,
 }
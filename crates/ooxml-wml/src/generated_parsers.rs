@@ -8,16 +8,23 @@
 #![allow(clippy::manual_is_multiple_of)]
 
 use super::generated::*;
+use ooxml_xml::local_name_in;
 pub use ooxml_xml::{FromXml, ParseError};
 #[cfg(feature = "extra-children")]
 use ooxml_xml::{PositionedNode, RawXmlElement, RawXmlNode};
-use quick_xml::Reader;
+use quick_xml::NsReader;
 use quick_xml::events::{BytesStart, Event};
 use std::io::BufRead;
 
+/// Namespace URIs of the elements these parsers read.
+const NAMESPACES: &[&str] = &[
+    "http://schemas.openxmlformats.org/wordprocessingml/2006/main",
+    "http://purl.oclc.org/ooxml/wordprocessingml/main",
+];
+
 #[allow(dead_code)]
 /// Skip an element and all its children.
-fn skip_element<R: BufRead>(reader: &mut Reader<R>) -> Result<(), ParseError> {
+fn skip_element<R: BufRead>(reader: &mut NsReader<R>) -> Result<(), ParseError> {
     let mut depth = 1u32;
     let mut buf = Vec::new();
     loop {
@@ -39,7 +46,7 @@ fn skip_element<R: BufRead>(reader: &mut Reader<R>) -> Result<(), ParseError> {
 
 #[allow(dead_code)]
 /// Read the text content of an element until its end tag.
-fn read_text_content<R: BufRead>(reader: &mut Reader<R>) -> Result<String, ParseError> {
+fn read_text_content<R: BufRead>(reader: &mut NsReader<R>) -> Result<String, ParseError> {
     let mut text = String::new();
     let mut buf = Vec::new();
     loop {
@@ -85,7 +92,7 @@ fn decode_base64(s: &str) -> Option<Vec<u8>> {
 
 impl FromXml for CTEmpty {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         _start: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -113,7 +120,7 @@ impl FromXml for CTEmpty {
 
 impl FromXml for OnOffElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -160,7 +167,7 @@ impl FromXml for OnOffElement {
 
 impl FromXml for LongHexNumberElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -207,7 +214,7 @@ impl FromXml for LongHexNumberElement {
 
 impl FromXml for CTCharset {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -262,7 +269,7 @@ impl FromXml for CTCharset {
 
 impl FromXml for CTDecimalNumber {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -309,7 +316,7 @@ impl FromXml for CTDecimalNumber {
 
 impl FromXml for UnsignedDecimalNumberElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -356,7 +363,7 @@ impl FromXml for UnsignedDecimalNumberElement {
 
 impl FromXml for CTDecimalNumberOrPrecent {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -403,7 +410,7 @@ impl FromXml for CTDecimalNumberOrPrecent {
 
 impl FromXml for TwipsMeasureElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -450,7 +457,7 @@ impl FromXml for TwipsMeasureElement {
 
 impl FromXml for SignedTwipsMeasureElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -497,7 +504,7 @@ impl FromXml for SignedTwipsMeasureElement {
 
 impl FromXml for PixelsMeasureElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -544,7 +551,7 @@ impl FromXml for PixelsMeasureElement {
 
 impl FromXml for HpsMeasureElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -591,7 +598,7 @@ impl FromXml for HpsMeasureElement {
 
 impl FromXml for SignedHpsMeasureElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -638,7 +645,7 @@ impl FromXml for SignedHpsMeasureElement {
 
 impl FromXml for MacroNameElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -685,7 +692,7 @@ impl FromXml for MacroNameElement {
 
 impl FromXml for CTString {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -732,7 +739,7 @@ impl FromXml for CTString {
 
 impl FromXml for TextScaleElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -779,7 +786,7 @@ impl FromXml for TextScaleElement {
 
 impl FromXml for CTHighlight {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -826,7 +833,7 @@ impl FromXml for CTHighlight {
 
 impl FromXml for CTColor {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -897,7 +904,7 @@ impl FromXml for CTColor {
 
 impl FromXml for CTLang {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -944,7 +951,7 @@ impl FromXml for CTLang {
 
 impl FromXml for GuidElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -991,7 +998,7 @@ impl FromXml for GuidElement {
 
 impl FromXml for CTUnderline {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1070,7 +1077,7 @@ impl FromXml for CTUnderline {
 
 impl FromXml for CTTextEffect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1117,7 +1124,7 @@ impl FromXml for CTTextEffect {
 
 impl FromXml for CTBorder {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1228,7 +1235,7 @@ impl FromXml for CTBorder {
 
 impl FromXml for CTShd {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1339,7 +1346,7 @@ impl FromXml for CTShd {
 
 impl FromXml for CTVerticalAlignRun {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1386,7 +1393,7 @@ impl FromXml for CTVerticalAlignRun {
 
 impl FromXml for CTFitText {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1444,7 +1451,7 @@ impl FromXml for CTFitText {
 
 impl FromXml for CTEm {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1491,7 +1498,7 @@ impl FromXml for CTEm {
 
 impl FromXml for LanguageElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1548,7 +1555,7 @@ impl FromXml for LanguageElement {
 
 impl FromXml for CTEastAsianLayout {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1630,7 +1637,7 @@ impl FromXml for CTEastAsianLayout {
 
 impl FromXml for CTFramePr {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1792,7 +1799,7 @@ impl FromXml for CTFramePr {
 
 impl FromXml for CTTabStop {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1852,7 +1859,7 @@ impl FromXml for CTTabStop {
 
 impl FromXml for CTSpacing {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -1958,7 +1965,7 @@ impl FromXml for CTSpacing {
 
 impl FromXml for CTInd {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2096,7 +2103,7 @@ impl FromXml for CTInd {
 
 impl FromXml for CTJc {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2143,7 +2150,7 @@ impl FromXml for CTJc {
 
 impl FromXml for CTJcTable {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2190,7 +2197,7 @@ impl FromXml for CTJcTable {
 
 impl FromXml for CTView {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2237,7 +2244,7 @@ impl FromXml for CTView {
 
 impl FromXml for CTZoom {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2293,7 +2300,7 @@ impl FromXml for CTZoom {
 
 impl FromXml for CTWritingStyle {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2387,7 +2394,7 @@ impl FromXml for CTWritingStyle {
 
 impl FromXml for CTProof {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2445,7 +2452,7 @@ impl FromXml for CTProof {
 
 impl FromXml for DocTypeElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2492,7 +2499,7 @@ impl FromXml for DocTypeElement {
 
 impl FromXml for WAGPassword {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2554,7 +2561,7 @@ impl FromXml for WAGPassword {
 
 impl FromXml for WAGTransitionalPassword {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2656,7 +2663,7 @@ impl FromXml for WAGTransitionalPassword {
 
 impl FromXml for CTDocProtect {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2850,7 +2857,7 @@ impl FromXml for CTDocProtect {
 
 impl FromXml for CTMailMergeDocType {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2897,7 +2904,7 @@ impl FromXml for CTMailMergeDocType {
 
 impl FromXml for MailMergeDataTypeElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2944,7 +2951,7 @@ impl FromXml for MailMergeDataTypeElement {
 
 impl FromXml for CTMailMergeDest {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -2991,7 +2998,7 @@ impl FromXml for CTMailMergeDest {
 
 impl FromXml for CTMailMergeOdsoFMDFieldType {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3038,7 +3045,7 @@ impl FromXml for CTMailMergeOdsoFMDFieldType {
 
 impl FromXml for CTTrackChangesView {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3120,7 +3127,7 @@ impl FromXml for CTTrackChangesView {
 
 impl FromXml for CTKinsoku {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3178,7 +3185,7 @@ impl FromXml for CTKinsoku {
 
 impl FromXml for CTTextDirection {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3225,7 +3232,7 @@ impl FromXml for CTTextDirection {
 
 impl FromXml for CTTextAlignment {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3272,7 +3279,7 @@ impl FromXml for CTTextAlignment {
 
 impl FromXml for CTMarkup {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3319,7 +3326,7 @@ impl FromXml for CTMarkup {
 
 impl FromXml for CTTrackChange {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3379,7 +3386,7 @@ impl FromXml for CTTrackChange {
 
 impl FromXml for CTCellMergeTrackChange {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3452,7 +3459,7 @@ impl FromXml for CTCellMergeTrackChange {
 
 impl FromXml for CTTrackChangeRange {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3517,7 +3524,7 @@ impl FromXml for CTTrackChangeRange {
 
 impl FromXml for CTMarkupRange {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3572,7 +3579,7 @@ impl FromXml for CTMarkupRange {
 
 impl FromXml for CTBookmarkRange {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3640,7 +3647,7 @@ impl FromXml for CTBookmarkRange {
 
 impl FromXml for Bookmark {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3716,7 +3723,7 @@ impl FromXml for Bookmark {
 
 impl FromXml for CTMoveBookmark {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3799,7 +3806,7 @@ impl FromXml for CTMoveBookmark {
 
 impl FromXml for Comment {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -3851,7 +3858,7 @@ impl FromXml for Comment {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customXml"
                             | b"sdt"
                             | b"p"
@@ -3904,7 +3911,7 @@ impl FromXml for Comment {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customXml"
                             | b"sdt"
                             | b"p"
@@ -3979,7 +3986,7 @@ impl FromXml for Comment {
 
 impl FromXml for CTTrackChangeNumbering {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4044,7 +4051,7 @@ impl FromXml for CTTrackChangeNumbering {
 
 impl FromXml for CTTblPrExChange {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4089,7 +4096,7 @@ impl FromXml for CTTblPrExChange {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-track-changes")]
                             b"tblPrEx" => {
                                 f_tbl_pr_ex =
@@ -4117,7 +4124,7 @@ impl FromXml for CTTblPrExChange {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-track-changes")]
                             b"tblPrEx" => {
                                 f_tbl_pr_ex =
@@ -4166,7 +4173,7 @@ impl FromXml for CTTblPrExChange {
 
 impl FromXml for CTTcPrChange {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4211,7 +4218,7 @@ impl FromXml for CTTcPrChange {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-track-changes")]
                             b"tcPr" => {
                                 f_cell_properties =
@@ -4239,7 +4246,7 @@ impl FromXml for CTTcPrChange {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-track-changes")]
                             b"tcPr" => {
                                 f_cell_properties =
@@ -4288,7 +4295,7 @@ impl FromXml for CTTcPrChange {
 
 impl FromXml for CTTrPrChange {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4333,7 +4340,7 @@ impl FromXml for CTTrPrChange {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-track-changes")]
                             b"trPr" => {
                                 f_row_properties =
@@ -4361,7 +4368,7 @@ impl FromXml for CTTrPrChange {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-track-changes")]
                             b"trPr" => {
                                 f_row_properties =
@@ -4410,7 +4417,7 @@ impl FromXml for CTTrPrChange {
 
 impl FromXml for CTTblGridChange {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4447,7 +4454,7 @@ impl FromXml for CTTblGridChange {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-track-changes")]
                             b"tblGrid" => {
                                 f_tbl_grid =
@@ -4475,7 +4482,7 @@ impl FromXml for CTTblGridChange {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-track-changes")]
                             b"tblGrid" => {
                                 f_tbl_grid =
@@ -4522,7 +4529,7 @@ impl FromXml for CTTblGridChange {
 
 impl FromXml for CTTblPrChange {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4567,7 +4574,7 @@ impl FromXml for CTTblPrChange {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-track-changes")]
                             b"tblPr" => {
                                 f_table_properties =
@@ -4595,7 +4602,7 @@ impl FromXml for CTTblPrChange {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-track-changes")]
                             b"tblPr" => {
                                 f_table_properties =
@@ -4644,7 +4651,7 @@ impl FromXml for CTTblPrChange {
 
 impl FromXml for CTSectPrChange {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4689,7 +4696,7 @@ impl FromXml for CTSectPrChange {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-track-changes")]
                             b"sectPr" => {
                                 f_sect_pr =
@@ -4717,7 +4724,7 @@ impl FromXml for CTSectPrChange {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-track-changes")]
                             b"sectPr" => {
                                 f_sect_pr =
@@ -4765,7 +4772,7 @@ impl FromXml for CTSectPrChange {
 
 impl FromXml for CTPPrChange {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4810,7 +4817,7 @@ impl FromXml for CTPPrChange {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-track-changes")]
                             b"pPr" => {
                                 f_p_pr = Some(Box::new(CTPPrBase::from_xml(reader, &e, false)?));
@@ -4837,7 +4844,7 @@ impl FromXml for CTPPrChange {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-track-changes")]
                             b"pPr" => {
                                 f_p_pr = Some(Box::new(CTPPrBase::from_xml(reader, &e, true)?));
@@ -4884,7 +4891,7 @@ impl FromXml for CTPPrChange {
 
 impl FromXml for CTRPrChange {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -4929,7 +4936,7 @@ impl FromXml for CTRPrChange {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-track-changes")]
                             b"rPr" => {
                                 f_r_pr =
@@ -4957,7 +4964,7 @@ impl FromXml for CTRPrChange {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-track-changes")]
                             b"rPr" => {
                                 f_r_pr = Some(Box::new(CTRPrOriginal::from_xml(reader, &e, true)?));
@@ -5004,7 +5011,7 @@ impl FromXml for CTRPrChange {
 
 impl FromXml for CTParaRPrChange {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5049,7 +5056,7 @@ impl FromXml for CTParaRPrChange {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-track-changes")]
                             b"rPr" => {
                                 f_r_pr =
@@ -5077,7 +5084,7 @@ impl FromXml for CTParaRPrChange {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-track-changes")]
                             b"rPr" => {
                                 f_r_pr =
@@ -5125,7 +5132,7 @@ impl FromXml for CTParaRPrChange {
 
 impl FromXml for CTRunTrackChange {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5176,7 +5183,7 @@ impl FromXml for CTRunTrackChange {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-track-changes")]
                             b"customXml"
                             | b"smartTag"
@@ -5231,7 +5238,7 @@ impl FromXml for CTRunTrackChange {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-track-changes")]
                             b"customXml"
                             | b"smartTag"
@@ -5309,7 +5316,7 @@ impl FromXml for CTRunTrackChange {
 
 impl FromXml for MathContent {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5326,7 +5333,7 @@ impl FromXml for MathContent {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customXml" | b"fldSimple" | b"hyperlink" => {
                                 f_p_content_base
                                     .push(ParagraphContentBase::from_xml(reader, &e, false)?);
@@ -5385,7 +5392,7 @@ impl FromXml for MathContent {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customXml" | b"fldSimple" | b"hyperlink" => {
                                 f_p_content_base
                                     .push(ParagraphContentBase::from_xml(reader, &e, true)?);
@@ -5459,7 +5466,7 @@ impl FromXml for MathContent {
 
 impl FromXml for ParagraphContentBase {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5486,7 +5493,7 @@ impl FromXml for ParagraphContentBase {
 
 impl FromXml for RunContentBase {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5601,7 +5608,7 @@ impl FromXml for RunContentBase {
 
 impl FromXml for CellMarkup {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5628,7 +5635,7 @@ impl FromXml for CellMarkup {
 
 impl FromXml for RangeMarkup {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5707,7 +5714,7 @@ impl FromXml for RangeMarkup {
 
 impl FromXml for NumberingProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5730,7 +5737,7 @@ impl FromXml for NumberingProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-numbering")]
                             b"ilvl" => {
                                 f_ilvl =
@@ -5785,7 +5792,7 @@ impl FromXml for NumberingProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-numbering")]
                             b"ilvl" => {
                                 f_ilvl =
@@ -5861,7 +5868,7 @@ impl FromXml for NumberingProperties {
 
 impl FromXml for CTPBdr {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -5888,7 +5895,7 @@ impl FromXml for CTPBdr {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"top" => {
                                 f_top = Some(Box::new(CTBorder::from_xml(reader, &e, false)?));
@@ -5955,7 +5962,7 @@ impl FromXml for CTPBdr {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"top" => {
                                 f_top = Some(Box::new(CTBorder::from_xml(reader, &e, true)?));
@@ -6047,7 +6054,7 @@ impl FromXml for CTPBdr {
 
 impl FromXml for CTTabs {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6063,7 +6070,7 @@ impl FromXml for CTTabs {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tab" => {
                                 f_tab.push(CTTabStop::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -6089,7 +6096,7 @@ impl FromXml for CTTabs {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tab" => {
                                 f_tab.push(CTTabStop::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -6129,7 +6136,7 @@ impl FromXml for CTTabs {
 
 impl FromXml for CTTextboxTightWrap {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6176,7 +6183,7 @@ impl FromXml for CTTextboxTightWrap {
 
 impl FromXml for ParagraphProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -6262,7 +6269,7 @@ impl FromXml for ParagraphProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pStyle" => {
                                 f_paragraph_style =
                                     Some(Box::new(CTString::from_xml(reader, &e, false)?));
@@ -6599,7 +6606,7 @@ impl FromXml for ParagraphProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pStyle" => {
                                 f_paragraph_style =
                                     Some(Box::new(CTString::from_xml(reader, &e, true)?));
@@ -7017,7 +7024,7 @@ impl FromXml for ParagraphProperties {
 
 impl FromXml for CTPPrBase {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7097,7 +7104,7 @@ impl FromXml for CTPPrBase {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pStyle" => {
                                 f_paragraph_style =
                                     Some(Box::new(CTString::from_xml(reader, &e, false)?));
@@ -7408,7 +7415,7 @@ impl FromXml for CTPPrBase {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pStyle" => {
                                 f_paragraph_style =
                                     Some(Box::new(CTString::from_xml(reader, &e, true)?));
@@ -7794,7 +7801,7 @@ impl FromXml for CTPPrBase {
 
 impl FromXml for CTPPrGeneral {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -7844,7 +7851,7 @@ impl FromXml for CTPPrGeneral {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pStyle" => {
                                 f_paragraph_style =
                                     Some(Box::new(CTString::from_xml(reader, &e, false)?));
@@ -8132,7 +8139,7 @@ impl FromXml for CTPPrGeneral {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pStyle" => {
                                 f_paragraph_style =
                                     Some(Box::new(CTString::from_xml(reader, &e, true)?));
@@ -8465,7 +8472,7 @@ impl FromXml for CTPPrGeneral {
 
 impl FromXml for CTControl {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8531,7 +8538,7 @@ impl FromXml for CTControl {
 
 impl FromXml for CTBackground {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8588,7 +8595,7 @@ impl FromXml for CTBackground {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-drawings")]
                             b"drawing" => {
                                 f_drawing = Some(Box::new(CTDrawing::from_xml(reader, &e, false)?));
@@ -8615,7 +8622,7 @@ impl FromXml for CTBackground {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-drawings")]
                             b"drawing" => {
                                 f_drawing = Some(Box::new(CTDrawing::from_xml(reader, &e, true)?));
@@ -8667,7 +8674,7 @@ impl FromXml for CTBackground {
 
 impl FromXml for CTRel {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8714,7 +8721,7 @@ impl FromXml for CTRel {
 
 impl FromXml for CTObject {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8767,7 +8774,7 @@ impl FromXml for CTObject {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-drawings")]
                             b"drawing" => {
                                 f_drawing = Some(Box::new(CTDrawing::from_xml(reader, &e, false)?));
@@ -8828,7 +8835,7 @@ impl FromXml for CTObject {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-drawings")]
                             b"drawing" => {
                                 f_drawing = Some(Box::new(CTDrawing::from_xml(reader, &e, true)?));
@@ -8918,7 +8925,7 @@ impl FromXml for CTObject {
 
 impl FromXml for CTPicture {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -8937,7 +8944,7 @@ impl FromXml for CTPicture {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-drawings")]
                             b"movie" => {
                                 f_movie = Some(Box::new(CTRel::from_xml(reader, &e, false)?));
@@ -8972,7 +8979,7 @@ impl FromXml for CTPicture {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-drawings")]
                             b"movie" => {
                                 f_movie = Some(Box::new(CTRel::from_xml(reader, &e, true)?));
@@ -9024,7 +9031,7 @@ impl FromXml for CTPicture {
 
 impl FromXml for CTObjectEmbed {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9106,7 +9113,7 @@ impl FromXml for CTObjectEmbed {
 
 impl FromXml for CTObjectLink {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9205,7 +9212,7 @@ impl FromXml for CTObjectLink {
 
 impl FromXml for CTDrawing {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9253,7 +9260,7 @@ impl FromXml for CTDrawing {
 
 impl FromXml for CTSimpleField {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9303,7 +9310,7 @@ impl FromXml for CTSimpleField {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-fields")]
                             b"fldData" => {
                                 f_fld_data = Some(Box::new(Text::from_xml(reader, &e, false)?));
@@ -9369,7 +9376,7 @@ impl FromXml for CTSimpleField {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-fields")]
                             b"fldData" => {
                                 f_fld_data = Some(Box::new(Text::from_xml(reader, &e, true)?));
@@ -9458,7 +9465,7 @@ impl FromXml for CTSimpleField {
 
 impl FromXml for CTFFTextType {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9505,7 +9512,7 @@ impl FromXml for CTFFTextType {
 
 impl FromXml for FFNameElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9552,7 +9559,7 @@ impl FromXml for FFNameElement {
 
 impl FromXml for CTFldChar {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9605,7 +9612,7 @@ impl FromXml for CTFldChar {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-fields")]
                             b"fldData" => {
                                 f_fld_data = Some(Box::new(Text::from_xml(reader, &e, false)?));
@@ -9650,7 +9657,7 @@ impl FromXml for CTFldChar {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-fields")]
                             b"fldData" => {
                                 f_fld_data = Some(Box::new(Text::from_xml(reader, &e, true)?));
@@ -9722,7 +9729,7 @@ impl FromXml for CTFldChar {
 
 impl FromXml for Hyperlink {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9790,7 +9797,7 @@ impl FromXml for Hyperlink {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customXml"
                             | b"smartTag"
                             | b"sdt"
@@ -9848,7 +9855,7 @@ impl FromXml for Hyperlink {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customXml"
                             | b"smartTag"
                             | b"sdt"
@@ -9934,7 +9941,7 @@ impl FromXml for Hyperlink {
 
 impl FromXml for CTFFData {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -9967,7 +9974,7 @@ impl FromXml for CTFFData {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-fields")]
                             b"name" => {
                                 f_name.push(FFNameElement::from_xml(reader, &e, false)?);
@@ -10065,7 +10072,7 @@ impl FromXml for CTFFData {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-fields")]
                             b"name" => {
                                 f_name.push(FFNameElement::from_xml(reader, &e, true)?);
@@ -10194,7 +10201,7 @@ impl FromXml for CTFFData {
 
 impl FromXml for CTFFHelpText {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10246,7 +10253,7 @@ impl FromXml for CTFFHelpText {
 
 impl FromXml for CTFFStatusText {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10298,7 +10305,7 @@ impl FromXml for CTFFStatusText {
 
 impl FromXml for CTFFCheckBox {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10321,7 +10328,7 @@ impl FromXml for CTFFCheckBox {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-fields")]
                             b"size" => {
                                 f_size =
@@ -10376,7 +10383,7 @@ impl FromXml for CTFFCheckBox {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-fields")]
                             b"size" => {
                                 f_size =
@@ -10452,7 +10459,7 @@ impl FromXml for CTFFCheckBox {
 
 impl FromXml for CTFFDDList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10473,7 +10480,7 @@ impl FromXml for CTFFDDList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-fields")]
                             b"result" => {
                                 f_result =
@@ -10518,7 +10525,7 @@ impl FromXml for CTFFDDList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-fields")]
                             b"result" => {
                                 f_result =
@@ -10582,7 +10589,7 @@ impl FromXml for CTFFDDList {
 
 impl FromXml for CTFFTextInput {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10605,7 +10612,7 @@ impl FromXml for CTFFTextInput {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-fields")]
                             b"type" => {
                                 f_type = Some(Box::new(CTFFTextType::from_xml(reader, &e, false)?));
@@ -10657,7 +10664,7 @@ impl FromXml for CTFFTextInput {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-fields")]
                             b"type" => {
                                 f_type = Some(Box::new(CTFFTextType::from_xml(reader, &e, true)?));
@@ -10730,7 +10737,7 @@ impl FromXml for CTFFTextInput {
 
 impl FromXml for CTSectType {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10777,7 +10784,7 @@ impl FromXml for CTSectType {
 
 impl FromXml for CTPaperSource {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10835,7 +10842,7 @@ impl FromXml for CTPaperSource {
 
 impl FromXml for PageSize {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -10909,7 +10916,7 @@ impl FromXml for PageSize {
 
 impl FromXml for PageMargins {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11007,7 +11014,7 @@ impl FromXml for PageMargins {
 
 impl FromXml for CTPageBorders {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11064,7 +11071,7 @@ impl FromXml for CTPageBorders {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-layout")]
                             b"top" => {
                                 f_top =
@@ -11119,7 +11126,7 @@ impl FromXml for CTPageBorders {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-layout")]
                             b"top" => {
                                 f_top =
@@ -11201,7 +11208,7 @@ impl FromXml for CTPageBorders {
 
 impl FromXml for CTPageBorder {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11296,7 +11303,7 @@ impl FromXml for CTPageBorder {
 
 impl FromXml for CTBottomPageBorder {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11404,7 +11411,7 @@ impl FromXml for CTBottomPageBorder {
 
 impl FromXml for CTTopPageBorder {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11512,7 +11519,7 @@ impl FromXml for CTTopPageBorder {
 
 impl FromXml for CTLineNumber {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11586,7 +11593,7 @@ impl FromXml for CTLineNumber {
 
 impl FromXml for CTPageNumber {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11660,7 +11667,7 @@ impl FromXml for CTPageNumber {
 
 impl FromXml for CTColumn {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11718,7 +11725,7 @@ impl FromXml for CTColumn {
 
 impl FromXml for Columns {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11775,7 +11782,7 @@ impl FromXml for Columns {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-layout")]
                             b"col" => {
                                 f_col.push(CTColumn::from_xml(reader, &e, false)?);
@@ -11802,7 +11809,7 @@ impl FromXml for Columns {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-layout")]
                             b"col" => {
                                 f_col.push(CTColumn::from_xml(reader, &e, true)?);
@@ -11854,7 +11861,7 @@ impl FromXml for Columns {
 
 impl FromXml for CTVerticalJc {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11901,7 +11908,7 @@ impl FromXml for CTVerticalJc {
 
 impl FromXml for DocumentGrid {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -11967,7 +11974,7 @@ impl FromXml for DocumentGrid {
 
 impl FromXml for HeaderFooterReference {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12019,7 +12026,7 @@ impl FromXml for HeaderFooterReference {
 
 impl FromXml for HeaderFooterRef {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12042,7 +12049,7 @@ impl FromXml for HeaderFooterRef {
 
 impl FromXml for HeaderFooter {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12058,7 +12065,7 @@ impl FromXml for HeaderFooter {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customXml"
                             | b"sdt"
                             | b"p"
@@ -12111,7 +12118,7 @@ impl FromXml for HeaderFooter {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customXml"
                             | b"sdt"
                             | b"p"
@@ -12178,7 +12185,7 @@ impl FromXml for HeaderFooter {
 
 impl FromXml for EGSectPrContents {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12212,7 +12219,7 @@ impl FromXml for EGSectPrContents {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"footnotePr" => {
                                 f_footnote_pr =
                                     Some(Box::new(CTFtnProps::from_xml(reader, &e, false)?));
@@ -12379,7 +12386,7 @@ impl FromXml for EGSectPrContents {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"footnotePr" => {
                                 f_footnote_pr =
                                     Some(Box::new(CTFtnProps::from_xml(reader, &e, true)?));
@@ -12577,7 +12584,7 @@ impl FromXml for EGSectPrContents {
 
 impl FromXml for WAGSectPrAttributes {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12639,7 +12646,7 @@ impl FromXml for WAGSectPrAttributes {
 
 impl FromXml for CTSectPrBase {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -12713,7 +12720,7 @@ impl FromXml for CTSectPrBase {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"footnotePr" => {
                                 f_footnote_pr =
                                     Some(Box::new(CTFtnProps::from_xml(reader, &e, false)?));
@@ -12880,7 +12887,7 @@ impl FromXml for CTSectPrBase {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"footnotePr" => {
                                 f_footnote_pr =
                                     Some(Box::new(CTFtnProps::from_xml(reader, &e, true)?));
@@ -13088,7 +13095,7 @@ impl FromXml for CTSectPrBase {
 
 impl FromXml for SectionProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13184,7 +13191,7 @@ impl FromXml for SectionProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"headerReference" | b"footerReference" => {
                                 f_header_footer_refs
                                     .push(HeaderFooterRef::from_xml(reader, &e, false)?);
@@ -13387,7 +13394,7 @@ impl FromXml for SectionProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"headerReference" | b"footerReference" => {
                                 f_header_footer_refs
                                     .push(HeaderFooterRef::from_xml(reader, &e, true)?);
@@ -13653,7 +13660,7 @@ impl FromXml for SectionProperties {
 
 impl FromXml for CTBr {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13705,7 +13712,7 @@ impl FromXml for CTBr {
 
 impl FromXml for CTPTab {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13767,7 +13774,7 @@ impl FromXml for CTPTab {
 
 impl FromXml for CTSym {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13822,7 +13829,7 @@ impl FromXml for CTSym {
 
 impl FromXml for CTProofErr {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13869,7 +13876,7 @@ impl FromXml for CTProofErr {
 
 impl FromXml for CTPerm {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -13924,7 +13931,7 @@ impl FromXml for CTPerm {
 
 impl FromXml for CTPermStart {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14008,7 +14015,7 @@ impl FromXml for CTPermStart {
 
 impl FromXml for Text {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14024,7 +14031,7 @@ impl FromXml for Text {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "extra-children")]
                             _ => {
                                 // Capture unknown element for roundtrip
@@ -14043,7 +14050,7 @@ impl FromXml for Text {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "extra-children")]
                             _ => {
                                 // Capture unknown empty element for roundtrip
@@ -14086,7 +14093,7 @@ impl FromXml for Text {
 
 impl FromXml for RunContent {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14233,7 +14240,7 @@ impl FromXml for RunContent {
 
 impl FromXml for Run {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14285,7 +14292,7 @@ impl FromXml for Run {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"rPr" => {
                                 f_r_pr =
@@ -14352,7 +14359,7 @@ impl FromXml for Run {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"rPr" => {
                                 f_r_pr = Some(Box::new(RunProperties::from_xml(reader, &e, true)?));
@@ -14442,7 +14449,7 @@ impl FromXml for Run {
 
 impl FromXml for Fonts {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14556,7 +14563,7 @@ impl FromXml for Fonts {
 
 impl FromXml for EGRPrBase {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -14610,7 +14617,7 @@ impl FromXml for EGRPrBase {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rStyle" => {
                                 f_run_style =
                                     Some(Box::new(CTString::from_xml(reader, &e, false)?));
@@ -14933,7 +14940,7 @@ impl FromXml for EGRPrBase {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rStyle" => {
                                 f_run_style = Some(Box::new(CTString::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -15305,7 +15312,7 @@ impl FromXml for EGRPrBase {
 
 impl FromXml for EGRPrContent {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -15360,7 +15367,7 @@ impl FromXml for EGRPrContent {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rStyle" => {
                                 f_run_style =
                                     Some(Box::new(CTString::from_xml(reader, &e, false)?));
@@ -15691,7 +15698,7 @@ impl FromXml for EGRPrContent {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rStyle" => {
                                 f_run_style = Some(Box::new(CTString::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -16072,7 +16079,7 @@ impl FromXml for EGRPrContent {
 
 impl FromXml for RunProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -16166,7 +16173,7 @@ impl FromXml for RunProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rStyle" => {
                                 f_run_style =
                                     Some(Box::new(CTString::from_xml(reader, &e, false)?));
@@ -16536,7 +16543,7 @@ impl FromXml for RunProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rStyle" => {
                                 f_run_style = Some(Box::new(CTString::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -16995,7 +17002,7 @@ impl FromXml for RunProperties {
 
 impl FromXml for EGRPr {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -17011,7 +17018,7 @@ impl FromXml for EGRPr {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rPr" => {
                                 f_r_pr =
                                     Some(Box::new(RunProperties::from_xml(reader, &e, false)?));
@@ -17038,7 +17045,7 @@ impl FromXml for EGRPr {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rPr" => {
                                 f_r_pr = Some(Box::new(RunProperties::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -17078,7 +17085,7 @@ impl FromXml for EGRPr {
 
 impl FromXml for MathRunProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -17105,7 +17112,7 @@ impl FromXml for MathRunProperties {
 
 impl FromXml for CTMathCtrlIns {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -17152,7 +17159,7 @@ impl FromXml for CTMathCtrlIns {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-track-changes")]
                             b"del" => {
                                 f_del = Some(Box::new(CTRPrChange::from_xml(reader, &e, false)?));
@@ -17188,7 +17195,7 @@ impl FromXml for CTMathCtrlIns {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-track-changes")]
                             b"del" => {
                                 f_del = Some(Box::new(CTRPrChange::from_xml(reader, &e, true)?));
@@ -17245,7 +17252,7 @@ impl FromXml for CTMathCtrlIns {
 
 impl FromXml for CTMathCtrlDel {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -17290,7 +17297,7 @@ impl FromXml for CTMathCtrlDel {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"rPr" => {
                                 f_r_pr =
@@ -17318,7 +17325,7 @@ impl FromXml for CTMathCtrlDel {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"rPr" => {
                                 f_r_pr = Some(Box::new(RunProperties::from_xml(reader, &e, true)?));
@@ -17365,7 +17372,7 @@ impl FromXml for CTMathCtrlDel {
 
 impl FromXml for CTRPrOriginal {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -17419,7 +17426,7 @@ impl FromXml for CTRPrOriginal {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rStyle" => {
                                 f_run_style =
                                     Some(Box::new(CTString::from_xml(reader, &e, false)?));
@@ -17742,7 +17749,7 @@ impl FromXml for CTRPrOriginal {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rStyle" => {
                                 f_run_style = Some(Box::new(CTString::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -18114,7 +18121,7 @@ impl FromXml for CTRPrOriginal {
 
 impl FromXml for CTParaRPrOriginal {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -18172,7 +18179,7 @@ impl FromXml for CTParaRPrOriginal {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ins" => {
                                 f_ins = Some(Box::new(CTTrackChange::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -18525,7 +18532,7 @@ impl FromXml for CTParaRPrOriginal {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ins" => {
                                 f_ins = Some(Box::new(CTTrackChange::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -18931,7 +18938,7 @@ impl FromXml for CTParaRPrOriginal {
 
 impl FromXml for CTParaRPr {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -18991,7 +18998,7 @@ impl FromXml for CTParaRPr {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ins" => {
                                 f_ins = Some(Box::new(CTTrackChange::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -19353,7 +19360,7 @@ impl FromXml for CTParaRPr {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ins" => {
                                 f_ins = Some(Box::new(CTTrackChange::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -19770,7 +19777,7 @@ impl FromXml for CTParaRPr {
 
 impl FromXml for EGParaRPrTrackChanges {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -19789,7 +19796,7 @@ impl FromXml for EGParaRPrTrackChanges {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ins" => {
                                 f_ins = Some(Box::new(CTTrackChange::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -19838,7 +19845,7 @@ impl FromXml for EGParaRPrTrackChanges {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"ins" => {
                                 f_ins = Some(Box::new(CTTrackChange::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -19904,7 +19911,7 @@ impl FromXml for EGParaRPrTrackChanges {
 
 impl FromXml for CTAltChunk {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -19941,7 +19948,7 @@ impl FromXml for CTAltChunk {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"altChunkPr" => {
                                 f_alt_chunk_pr =
@@ -19969,7 +19976,7 @@ impl FromXml for CTAltChunk {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"altChunkPr" => {
                                 f_alt_chunk_pr =
@@ -20015,7 +20022,7 @@ impl FromXml for CTAltChunk {
 
 impl FromXml for CTAltChunkPr {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -20032,7 +20039,7 @@ impl FromXml for CTAltChunkPr {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"matchSrc" => {
                                 f_match_src =
@@ -20060,7 +20067,7 @@ impl FromXml for CTAltChunkPr {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"matchSrc" => {
                                 f_match_src =
@@ -20103,7 +20110,7 @@ impl FromXml for CTAltChunkPr {
 
 impl FromXml for CTRubyAlign {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -20150,7 +20157,7 @@ impl FromXml for CTRubyAlign {
 
 impl FromXml for CTRubyPr {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -20177,7 +20184,7 @@ impl FromXml for CTRubyPr {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"rubyAlign" => {
                                 f_ruby_align =
@@ -20249,7 +20256,7 @@ impl FromXml for CTRubyPr {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"rubyAlign" => {
                                 f_ruby_align =
@@ -20348,7 +20355,7 @@ impl FromXml for CTRubyPr {
 
 impl FromXml for RubyContent {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -20459,7 +20466,7 @@ impl FromXml for RubyContent {
 
 impl FromXml for CTRubyContent {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -20475,7 +20482,7 @@ impl FromXml for CTRubyContent {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"r"
                             | b"proofErr"
                             | b"permStart"
@@ -20524,7 +20531,7 @@ impl FromXml for CTRubyContent {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"r"
                             | b"proofErr"
                             | b"permStart"
@@ -20587,7 +20594,7 @@ impl FromXml for CTRubyContent {
 
 impl FromXml for CTRuby {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -20606,7 +20613,7 @@ impl FromXml for CTRuby {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"rubyPr" => {
                                 f_ruby_pr = Some(Box::new(CTRubyPr::from_xml(reader, &e, false)?));
@@ -20648,7 +20655,7 @@ impl FromXml for CTRuby {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"rubyPr" => {
                                 f_ruby_pr = Some(Box::new(CTRubyPr::from_xml(reader, &e, true)?));
@@ -20708,7 +20715,7 @@ impl FromXml for CTRuby {
 
 impl FromXml for CTLock {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -20755,7 +20762,7 @@ impl FromXml for CTLock {
 
 impl FromXml for CTSdtListItem {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -20807,7 +20814,7 @@ impl FromXml for CTSdtListItem {
 
 impl FromXml for CTSdtDateMappingType {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -20854,7 +20861,7 @@ impl FromXml for CTSdtDateMappingType {
 
 impl FromXml for CTCalendarType {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -20901,7 +20908,7 @@ impl FromXml for CTCalendarType {
 
 impl FromXml for CTSdtDate {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -20946,7 +20953,7 @@ impl FromXml for CTSdtDate {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"dateFormat" => {
                                 f_date_format =
@@ -21001,7 +21008,7 @@ impl FromXml for CTSdtDate {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"dateFormat" => {
                                 f_date_format =
@@ -21081,7 +21088,7 @@ impl FromXml for CTSdtDate {
 
 impl FromXml for CTSdtComboBox {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -21120,7 +21127,7 @@ impl FromXml for CTSdtComboBox {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"listItem" => {
                                 f_list_item.push(CTSdtListItem::from_xml(reader, &e, false)?);
@@ -21147,7 +21154,7 @@ impl FromXml for CTSdtComboBox {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"listItem" => {
                                 f_list_item.push(CTSdtListItem::from_xml(reader, &e, true)?);
@@ -21193,7 +21200,7 @@ impl FromXml for CTSdtComboBox {
 
 impl FromXml for CTSdtDocPart {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -21214,7 +21221,7 @@ impl FromXml for CTSdtDocPart {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"docPartGallery" => {
                                 f_doc_part_gallery =
@@ -21260,7 +21267,7 @@ impl FromXml for CTSdtDocPart {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"docPartGallery" => {
                                 f_doc_part_gallery =
@@ -21325,7 +21332,7 @@ impl FromXml for CTSdtDocPart {
 
 impl FromXml for CTSdtDropDownList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -21364,7 +21371,7 @@ impl FromXml for CTSdtDropDownList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"listItem" => {
                                 f_list_item.push(CTSdtListItem::from_xml(reader, &e, false)?);
@@ -21391,7 +21398,7 @@ impl FromXml for CTSdtDropDownList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"listItem" => {
                                 f_list_item.push(CTSdtListItem::from_xml(reader, &e, true)?);
@@ -21437,7 +21444,7 @@ impl FromXml for CTSdtDropDownList {
 
 impl FromXml for CTSdtText {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -21487,7 +21494,7 @@ impl FromXml for CTSdtText {
 
 impl FromXml for CTDataBinding {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -21554,7 +21561,7 @@ impl FromXml for CTDataBinding {
 
 impl FromXml for CTSdtPr {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -21615,7 +21622,7 @@ impl FromXml for CTSdtPr {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"rPr" => {
                                 f_r_pr =
@@ -21832,7 +21839,7 @@ impl FromXml for CTSdtPr {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"rPr" => {
                                 f_r_pr = Some(Box::new(RunProperties::from_xml(reader, &e, true)?));
@@ -22106,7 +22113,7 @@ impl FromXml for CTSdtPr {
 
 impl FromXml for CTSdtEndPr {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -22123,7 +22130,7 @@ impl FromXml for CTSdtEndPr {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"rPr" => {
                                 f_r_pr =
@@ -22151,7 +22158,7 @@ impl FromXml for CTSdtEndPr {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"rPr" => {
                                 f_r_pr = Some(Box::new(RunProperties::from_xml(reader, &e, true)?));
@@ -22193,7 +22200,7 @@ impl FromXml for CTSdtEndPr {
 
 impl FromXml for RunContentChoice {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -22324,7 +22331,7 @@ impl FromXml for RunContentChoice {
 
 impl FromXml for CTDirContentRun {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -22360,7 +22367,7 @@ impl FromXml for CTDirContentRun {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customXml"
                             | b"smartTag"
                             | b"sdt"
@@ -22418,7 +22425,7 @@ impl FromXml for CTDirContentRun {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customXml"
                             | b"smartTag"
                             | b"sdt"
@@ -22493,7 +22500,7 @@ impl FromXml for CTDirContentRun {
 
 impl FromXml for CTBdoContentRun {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -22529,7 +22536,7 @@ impl FromXml for CTBdoContentRun {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customXml"
                             | b"smartTag"
                             | b"sdt"
@@ -22587,7 +22594,7 @@ impl FromXml for CTBdoContentRun {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customXml"
                             | b"smartTag"
                             | b"sdt"
@@ -22662,7 +22669,7 @@ impl FromXml for CTBdoContentRun {
 
 impl FromXml for CTSdtContentRun {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -22678,7 +22685,7 @@ impl FromXml for CTSdtContentRun {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customXml"
                             | b"smartTag"
                             | b"sdt"
@@ -22736,7 +22743,7 @@ impl FromXml for CTSdtContentRun {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customXml"
                             | b"smartTag"
                             | b"sdt"
@@ -22808,7 +22815,7 @@ impl FromXml for CTSdtContentRun {
 
 impl FromXml for BlockContentChoice {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -22931,7 +22938,7 @@ impl FromXml for BlockContentChoice {
 
 impl FromXml for CTSdtContentBlock {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -22947,7 +22954,7 @@ impl FromXml for CTSdtContentBlock {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customXml"
                             | b"sdt"
                             | b"p"
@@ -23000,7 +23007,7 @@ impl FromXml for CTSdtContentBlock {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customXml"
                             | b"sdt"
                             | b"p"
@@ -23067,7 +23074,7 @@ impl FromXml for CTSdtContentBlock {
 
 impl FromXml for RowContent {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23186,7 +23193,7 @@ impl FromXml for RowContent {
 
 impl FromXml for CTSdtContentRow {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23202,7 +23209,7 @@ impl FromXml for CTSdtContentRow {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tr"
                             | b"customXml"
                             | b"sdt"
@@ -23253,7 +23260,7 @@ impl FromXml for CTSdtContentRow {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tr"
                             | b"customXml"
                             | b"sdt"
@@ -23318,7 +23325,7 @@ impl FromXml for CTSdtContentRow {
 
 impl FromXml for CellContent {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23437,7 +23444,7 @@ impl FromXml for CellContent {
 
 impl FromXml for CTSdtContentCell {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23453,7 +23460,7 @@ impl FromXml for CTSdtContentCell {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tc"
                             | b"customXml"
                             | b"sdt"
@@ -23504,7 +23511,7 @@ impl FromXml for CTSdtContentCell {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tc"
                             | b"customXml"
                             | b"sdt"
@@ -23569,7 +23576,7 @@ impl FromXml for CTSdtContentCell {
 
 impl FromXml for CTSdtBlock {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23589,7 +23596,7 @@ impl FromXml for CTSdtBlock {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"sdtPr" => {
                                 f_sdt_pr = Some(Box::new(CTSdtPr::from_xml(reader, &e, false)?));
@@ -23633,7 +23640,7 @@ impl FromXml for CTSdtBlock {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"sdtPr" => {
                                 f_sdt_pr = Some(Box::new(CTSdtPr::from_xml(reader, &e, true)?));
@@ -23695,7 +23702,7 @@ impl FromXml for CTSdtBlock {
 
 impl FromXml for CTSdtRun {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23715,7 +23722,7 @@ impl FromXml for CTSdtRun {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"sdtPr" => {
                                 f_sdt_pr = Some(Box::new(CTSdtPr::from_xml(reader, &e, false)?));
@@ -23759,7 +23766,7 @@ impl FromXml for CTSdtRun {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"sdtPr" => {
                                 f_sdt_pr = Some(Box::new(CTSdtPr::from_xml(reader, &e, true)?));
@@ -23821,7 +23828,7 @@ impl FromXml for CTSdtRun {
 
 impl FromXml for CTSdtCell {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23841,7 +23848,7 @@ impl FromXml for CTSdtCell {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"sdtPr" => {
                                 f_sdt_pr = Some(Box::new(CTSdtPr::from_xml(reader, &e, false)?));
@@ -23885,7 +23892,7 @@ impl FromXml for CTSdtCell {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"sdtPr" => {
                                 f_sdt_pr = Some(Box::new(CTSdtPr::from_xml(reader, &e, true)?));
@@ -23947,7 +23954,7 @@ impl FromXml for CTSdtCell {
 
 impl FromXml for CTSdtRow {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -23967,7 +23974,7 @@ impl FromXml for CTSdtRow {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"sdtPr" => {
                                 f_sdt_pr = Some(Box::new(CTSdtPr::from_xml(reader, &e, false)?));
@@ -24011,7 +24018,7 @@ impl FromXml for CTSdtRow {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"sdtPr" => {
                                 f_sdt_pr = Some(Box::new(CTSdtPr::from_xml(reader, &e, true)?));
@@ -24073,7 +24080,7 @@ impl FromXml for CTSdtRow {
 
 impl FromXml for CTAttr {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24130,7 +24137,7 @@ impl FromXml for CTAttr {
 
 impl FromXml for CTCustomXmlRun {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24176,7 +24183,7 @@ impl FromXml for CTCustomXmlRun {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"customXmlPr" => {
                                 f_custom_xml_pr =
@@ -24243,7 +24250,7 @@ impl FromXml for CTCustomXmlRun {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"customXmlPr" => {
                                 f_custom_xml_pr =
@@ -24333,7 +24340,7 @@ impl FromXml for CTCustomXmlRun {
 
 impl FromXml for CTSmartTagRun {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24379,7 +24386,7 @@ impl FromXml for CTSmartTagRun {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"smartTagPr" => {
                                 f_smart_tag_pr =
@@ -24446,7 +24453,7 @@ impl FromXml for CTSmartTagRun {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"smartTagPr" => {
                                 f_smart_tag_pr =
@@ -24536,7 +24543,7 @@ impl FromXml for CTSmartTagRun {
 
 impl FromXml for CTCustomXmlBlock {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24582,7 +24589,7 @@ impl FromXml for CTCustomXmlBlock {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"customXmlPr" => {
                                 f_custom_xml_pr =
@@ -24644,7 +24651,7 @@ impl FromXml for CTCustomXmlBlock {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"customXmlPr" => {
                                 f_custom_xml_pr =
@@ -24729,7 +24736,7 @@ impl FromXml for CTCustomXmlBlock {
 
 impl FromXml for CTCustomXmlPr {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24748,7 +24755,7 @@ impl FromXml for CTCustomXmlPr {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"placeholder" => {
                                 f_placeholder =
@@ -24784,7 +24791,7 @@ impl FromXml for CTCustomXmlPr {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"placeholder" => {
                                 f_placeholder =
@@ -24837,7 +24844,7 @@ impl FromXml for CTCustomXmlPr {
 
 impl FromXml for CTCustomXmlRow {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -24883,7 +24890,7 @@ impl FromXml for CTCustomXmlRow {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"customXmlPr" => {
                                 f_custom_xml_pr =
@@ -24943,7 +24950,7 @@ impl FromXml for CTCustomXmlRow {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"customXmlPr" => {
                                 f_custom_xml_pr =
@@ -25026,7 +25033,7 @@ impl FromXml for CTCustomXmlRow {
 
 impl FromXml for CTCustomXmlCell {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25072,7 +25079,7 @@ impl FromXml for CTCustomXmlCell {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"customXmlPr" => {
                                 f_custom_xml_pr =
@@ -25132,7 +25139,7 @@ impl FromXml for CTCustomXmlCell {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"customXmlPr" => {
                                 f_custom_xml_pr =
@@ -25215,7 +25222,7 @@ impl FromXml for CTCustomXmlCell {
 
 impl FromXml for CTSmartTagPr {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25232,7 +25239,7 @@ impl FromXml for CTSmartTagPr {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"attr" => {
                                 f_attr.push(CTAttr::from_xml(reader, &e, false)?);
@@ -25259,7 +25266,7 @@ impl FromXml for CTSmartTagPr {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"attr" => {
                                 f_attr.push(CTAttr::from_xml(reader, &e, true)?);
@@ -25301,7 +25308,7 @@ impl FromXml for CTSmartTagPr {
 
 impl FromXml for ParagraphContent {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25444,7 +25451,7 @@ impl FromXml for ParagraphContent {
 
 impl FromXml for Paragraph {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25508,7 +25515,7 @@ impl FromXml for Paragraph {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"pPr" => {
                                 f_p_pr = Some(Box::new(ParagraphProperties::from_xml(
@@ -25576,7 +25583,7 @@ impl FromXml for Paragraph {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"pPr" => {
                                 f_p_pr = Some(Box::new(ParagraphProperties::from_xml(
@@ -25672,7 +25679,7 @@ impl FromXml for Paragraph {
 
 impl FromXml for CTHeight {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25727,7 +25734,7 @@ impl FromXml for CTHeight {
 
 impl FromXml for CTTblWidth {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25779,7 +25786,7 @@ impl FromXml for CTTblWidth {
 
 impl FromXml for TableGridColumn {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25826,7 +25833,7 @@ impl FromXml for TableGridColumn {
 
 impl FromXml for CTTblGridBase {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25842,7 +25849,7 @@ impl FromXml for CTTblGridBase {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"gridCol" => {
                                 f_grid_col.push(TableGridColumn::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -25868,7 +25875,7 @@ impl FromXml for CTTblGridBase {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"gridCol" => {
                                 f_grid_col.push(TableGridColumn::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -25908,7 +25915,7 @@ impl FromXml for CTTblGridBase {
 
 impl FromXml for TableGrid {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -25926,7 +25933,7 @@ impl FromXml for TableGrid {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"gridCol" => {
                                 f_grid_col.push(TableGridColumn::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -25961,7 +25968,7 @@ impl FromXml for TableGrid {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"gridCol" => {
                                 f_grid_col.push(TableGridColumn::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -26012,7 +26019,7 @@ impl FromXml for TableGrid {
 
 impl FromXml for CTTcBorders {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -26047,7 +26054,7 @@ impl FromXml for CTTcBorders {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-tables")]
                             b"top" => {
                                 f_top = Some(Box::new(CTBorder::from_xml(reader, &e, false)?));
@@ -26146,7 +26153,7 @@ impl FromXml for CTTcBorders {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-tables")]
                             b"top" => {
                                 f_top = Some(Box::new(CTBorder::from_xml(reader, &e, true)?));
@@ -26278,7 +26285,7 @@ impl FromXml for CTTcBorders {
 
 impl FromXml for CTTcMar {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -26305,7 +26312,7 @@ impl FromXml for CTTcMar {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-tables")]
                             b"top" => {
                                 f_top = Some(Box::new(CTTblWidth::from_xml(reader, &e, false)?));
@@ -26372,7 +26379,7 @@ impl FromXml for CTTcMar {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-tables")]
                             b"top" => {
                                 f_top = Some(Box::new(CTTblWidth::from_xml(reader, &e, true)?));
@@ -26464,7 +26471,7 @@ impl FromXml for CTTcMar {
 
 impl FromXml for CTVMerge {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -26511,7 +26518,7 @@ impl FromXml for CTVMerge {
 
 impl FromXml for CTHMerge {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -26558,7 +26565,7 @@ impl FromXml for CTHMerge {
 
 impl FromXml for CTTcPrBase {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -26601,7 +26608,7 @@ impl FromXml for CTTcPrBase {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"cnfStyle" => {
                                 f_cnf_style = Some(Box::new(CTCnf::from_xml(reader, &e, false)?));
@@ -26741,7 +26748,7 @@ impl FromXml for CTTcPrBase {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"cnfStyle" => {
                                 f_cnf_style = Some(Box::new(CTCnf::from_xml(reader, &e, true)?));
@@ -26922,7 +26929,7 @@ impl FromXml for CTTcPrBase {
 
 impl FromXml for TableCellProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -26968,7 +26975,7 @@ impl FromXml for TableCellProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"cnfStyle" => {
                                 f_cnf_style = Some(Box::new(CTCnf::from_xml(reader, &e, false)?));
@@ -27125,7 +27132,7 @@ impl FromXml for TableCellProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"cnfStyle" => {
                                 f_cnf_style = Some(Box::new(CTCnf::from_xml(reader, &e, true)?));
@@ -27326,7 +27333,7 @@ impl FromXml for TableCellProperties {
 
 impl FromXml for CTTcPrInner {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -27356,7 +27363,7 @@ impl FromXml for CTTcPrInner {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cnfStyle" => {
                                 f_cnf_style = Some(Box::new(CTCnf::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -27490,7 +27497,7 @@ impl FromXml for CTTcPrInner {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"cnfStyle" => {
                                 f_cnf_style = Some(Box::new(CTCnf::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -27652,7 +27659,7 @@ impl FromXml for CTTcPrInner {
 
 impl FromXml for TableCell {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -27692,7 +27699,7 @@ impl FromXml for TableCell {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-tables")]
                             b"tcPr" => {
                                 f_cell_properties = Some(Box::new(TableCellProperties::from_xml(
@@ -27755,7 +27762,7 @@ impl FromXml for TableCell {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-tables")]
                             b"tcPr" => {
                                 f_cell_properties = Some(Box::new(TableCellProperties::from_xml(
@@ -27838,7 +27845,7 @@ impl FromXml for TableCell {
 
 impl FromXml for CTCnf {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -27984,7 +27991,7 @@ impl FromXml for CTCnf {
 
 impl FromXml for CTHeaders {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -28000,7 +28007,7 @@ impl FromXml for CTHeaders {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"header" => {
                                 f_header.push(CTString::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -28026,7 +28033,7 @@ impl FromXml for CTHeaders {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"header" => {
                                 f_header.push(CTString::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -28066,7 +28073,7 @@ impl FromXml for CTHeaders {
 
 impl FromXml for CTTrPrBase {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -28105,7 +28112,7 @@ impl FromXml for CTTrPrBase {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"cnfStyle" => {
                                 f_cnf_style = Some(Box::new(CTCnf::from_xml(reader, &e, false)?));
@@ -28231,7 +28238,7 @@ impl FromXml for CTTrPrBase {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"cnfStyle" => {
                                 f_cnf_style = Some(Box::new(CTCnf::from_xml(reader, &e, true)?));
@@ -28392,7 +28399,7 @@ impl FromXml for CTTrPrBase {
 
 impl FromXml for TableRowProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -28437,7 +28444,7 @@ impl FromXml for TableRowProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"cnfStyle" => {
                                 f_cnf_style = Some(Box::new(CTCnf::from_xml(reader, &e, false)?));
@@ -28588,7 +28595,7 @@ impl FromXml for TableRowProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"cnfStyle" => {
                                 f_cnf_style = Some(Box::new(CTCnf::from_xml(reader, &e, true)?));
@@ -28780,7 +28787,7 @@ impl FromXml for TableRowProperties {
 
 impl FromXml for CTRow {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -28840,7 +28847,7 @@ impl FromXml for CTRow {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-tables")]
                             b"tblPrEx" => {
                                 f_tbl_pr_ex =
@@ -28910,7 +28917,7 @@ impl FromXml for CTRow {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-tables")]
                             b"tblPrEx" => {
                                 f_tbl_pr_ex =
@@ -29007,7 +29014,7 @@ impl FromXml for CTRow {
 
 impl FromXml for CTTblLayoutType {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29054,7 +29061,7 @@ impl FromXml for CTTblLayoutType {
 
 impl FromXml for CTTblOverlap {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29101,7 +29108,7 @@ impl FromXml for CTTblOverlap {
 
 impl FromXml for CTTblPPr {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29223,7 +29230,7 @@ impl FromXml for CTTblPPr {
 
 impl FromXml for CTTblCellMar {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29250,7 +29257,7 @@ impl FromXml for CTTblCellMar {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-tables")]
                             b"top" => {
                                 f_top = Some(Box::new(CTTblWidth::from_xml(reader, &e, false)?));
@@ -29317,7 +29324,7 @@ impl FromXml for CTTblCellMar {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-tables")]
                             b"top" => {
                                 f_top = Some(Box::new(CTTblWidth::from_xml(reader, &e, true)?));
@@ -29409,7 +29416,7 @@ impl FromXml for CTTblCellMar {
 
 impl FromXml for CTTblBorders {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29440,7 +29447,7 @@ impl FromXml for CTTblBorders {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-tables")]
                             b"top" => {
                                 f_top = Some(Box::new(CTBorder::from_xml(reader, &e, false)?));
@@ -29523,7 +29530,7 @@ impl FromXml for CTTblBorders {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-tables")]
                             b"top" => {
                                 f_top = Some(Box::new(CTBorder::from_xml(reader, &e, true)?));
@@ -29635,7 +29642,7 @@ impl FromXml for CTTblBorders {
 
 impl FromXml for CTTblPrBase {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -29684,7 +29691,7 @@ impl FromXml for CTTblPrBase {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"tblStyle" => {
                                 f_tbl_style =
@@ -29853,7 +29860,7 @@ impl FromXml for CTTblPrBase {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"tblStyle" => {
                                 f_tbl_style = Some(Box::new(CTString::from_xml(reader, &e, true)?));
@@ -30066,7 +30073,7 @@ impl FromXml for CTTblPrBase {
 
 impl FromXml for TableProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -30117,7 +30124,7 @@ impl FromXml for TableProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"tblStyle" => {
                                 f_tbl_style =
@@ -30295,7 +30302,7 @@ impl FromXml for TableProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"tblStyle" => {
                                 f_tbl_style = Some(Box::new(CTString::from_xml(reader, &e, true)?));
@@ -30519,7 +30526,7 @@ impl FromXml for TableProperties {
 
 impl FromXml for CTTblPrExBase {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -30552,7 +30559,7 @@ impl FromXml for CTTblPrExBase {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-tables")]
                             b"tblW" => {
                                 f_tbl_w = Some(Box::new(CTTblWidth::from_xml(reader, &e, false)?));
@@ -30650,7 +30657,7 @@ impl FromXml for CTTblPrExBase {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-tables")]
                             b"tblW" => {
                                 f_tbl_w = Some(Box::new(CTTblWidth::from_xml(reader, &e, true)?));
@@ -30777,7 +30784,7 @@ impl FromXml for CTTblPrExBase {
 
 impl FromXml for CTTblPrEx {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -30803,7 +30810,7 @@ impl FromXml for CTTblPrEx {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tblW" => {
                                 f_tbl_w = Some(Box::new(CTTblWidth::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -30901,7 +30908,7 @@ impl FromXml for CTTblPrEx {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"tblW" => {
                                 f_tbl_w = Some(Box::new(CTTblWidth::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -31021,7 +31028,7 @@ impl FromXml for CTTblPrEx {
 
 impl FromXml for Table {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31040,7 +31047,7 @@ impl FromXml for Table {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"bookmarkStart"
                             | b"bookmarkEnd"
                             | b"moveFromRangeStart"
@@ -31105,7 +31112,7 @@ impl FromXml for Table {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"bookmarkStart"
                             | b"bookmarkEnd"
                             | b"moveFromRangeStart"
@@ -31188,7 +31195,7 @@ impl FromXml for Table {
 
 impl FromXml for CTTblLook {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31286,7 +31293,7 @@ impl FromXml for CTTblLook {
 
 impl FromXml for CTFtnPos {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31333,7 +31340,7 @@ impl FromXml for CTFtnPos {
 
 impl FromXml for CTEdnPos {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31380,7 +31387,7 @@ impl FromXml for CTEdnPos {
 
 impl FromXml for CTNumFmt {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31435,7 +31442,7 @@ impl FromXml for CTNumFmt {
 
 impl FromXml for CTNumRestart {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31482,7 +31489,7 @@ impl FromXml for CTNumRestart {
 
 impl FromXml for FootnoteEndnoteRef {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31537,7 +31544,7 @@ impl FromXml for FootnoteEndnoteRef {
 
 impl FromXml for CTFtnEdnSepRef {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31584,7 +31591,7 @@ impl FromXml for CTFtnEdnSepRef {
 
 impl FromXml for FootnoteEndnote {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31626,7 +31633,7 @@ impl FromXml for FootnoteEndnote {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customXml"
                             | b"sdt"
                             | b"p"
@@ -31679,7 +31686,7 @@ impl FromXml for FootnoteEndnote {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customXml"
                             | b"sdt"
                             | b"p"
@@ -31751,7 +31758,7 @@ impl FromXml for FootnoteEndnote {
 
 impl FromXml for EGFtnEdnNumProps {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31768,7 +31775,7 @@ impl FromXml for EGFtnEdnNumProps {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"numStart" => {
                                 f_num_start =
                                     Some(Box::new(CTDecimalNumber::from_xml(reader, &e, false)?));
@@ -31803,7 +31810,7 @@ impl FromXml for EGFtnEdnNumProps {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"numStart" => {
                                 f_num_start =
                                     Some(Box::new(CTDecimalNumber::from_xml(reader, &e, true)?));
@@ -31853,7 +31860,7 @@ impl FromXml for EGFtnEdnNumProps {
 
 impl FromXml for CTFtnProps {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -31874,7 +31881,7 @@ impl FromXml for CTFtnProps {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-comments")]
                             b"pos" => {
                                 f_pos = Some(Box::new(CTFtnPos::from_xml(reader, &e, false)?));
@@ -31925,7 +31932,7 @@ impl FromXml for CTFtnProps {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-comments")]
                             b"pos" => {
                                 f_pos = Some(Box::new(CTFtnPos::from_xml(reader, &e, true)?));
@@ -31995,7 +32002,7 @@ impl FromXml for CTFtnProps {
 
 impl FromXml for CTEdnProps {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -32016,7 +32023,7 @@ impl FromXml for CTEdnProps {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-comments")]
                             b"pos" => {
                                 f_pos = Some(Box::new(CTEdnPos::from_xml(reader, &e, false)?));
@@ -32067,7 +32074,7 @@ impl FromXml for CTEdnProps {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-comments")]
                             b"pos" => {
                                 f_pos = Some(Box::new(CTEdnPos::from_xml(reader, &e, true)?));
@@ -32137,7 +32144,7 @@ impl FromXml for CTEdnProps {
 
 impl FromXml for CTFtnDocProps {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -32158,7 +32165,7 @@ impl FromXml for CTFtnDocProps {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pos" => {
                                 f_pos = Some(Box::new(CTFtnPos::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -32215,7 +32222,7 @@ impl FromXml for CTFtnDocProps {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pos" => {
                                 f_pos = Some(Box::new(CTFtnPos::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -32291,7 +32298,7 @@ impl FromXml for CTFtnDocProps {
 
 impl FromXml for CTEdnDocProps {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -32312,7 +32319,7 @@ impl FromXml for CTEdnDocProps {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pos" => {
                                 f_pos = Some(Box::new(CTEdnPos::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -32369,7 +32376,7 @@ impl FromXml for CTEdnDocProps {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pos" => {
                                 f_pos = Some(Box::new(CTEdnPos::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -32445,7 +32452,7 @@ impl FromXml for CTEdnDocProps {
 
 impl FromXml for CTRecipientData {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -32466,7 +32473,7 @@ impl FromXml for CTRecipientData {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"active" => {
                                 f_active =
@@ -32512,7 +32519,7 @@ impl FromXml for CTRecipientData {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"active" => {
                                 f_active =
@@ -32578,7 +32585,7 @@ impl FromXml for CTRecipientData {
 
 impl FromXml for CTBase64Binary {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -32625,7 +32632,7 @@ impl FromXml for CTBase64Binary {
 
 impl FromXml for CTRecipients {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -32642,7 +32649,7 @@ impl FromXml for CTRecipients {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"recipientData" => {
                                 f_recipient_data
@@ -32670,7 +32677,7 @@ impl FromXml for CTRecipients {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"recipientData" => {
                                 f_recipient_data.push(CTRecipientData::from_xml(reader, &e, true)?);
@@ -32712,7 +32719,7 @@ impl FromXml for CTRecipients {
 
 impl FromXml for CTOdsoFieldMapData {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -32739,7 +32746,7 @@ impl FromXml for CTOdsoFieldMapData {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"type" => {
                                 f_type = Some(Box::new(CTMailMergeOdsoFMDFieldType::from_xml(
@@ -32811,7 +32818,7 @@ impl FromXml for CTOdsoFieldMapData {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"type" => {
                                 f_type = Some(Box::new(CTMailMergeOdsoFMDFieldType::from_xml(
@@ -32908,7 +32915,7 @@ impl FromXml for CTOdsoFieldMapData {
 
 impl FromXml for CTMailMergeSourceType {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -32955,7 +32962,7 @@ impl FromXml for CTMailMergeSourceType {
 
 impl FromXml for CTOdso {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -32986,7 +32993,7 @@ impl FromXml for CTOdso {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"udl" => {
                                 f_udl = Some(Box::new(CTString::from_xml(reader, &e, false)?));
@@ -33074,7 +33081,7 @@ impl FromXml for CTOdso {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"udl" => {
                                 f_udl = Some(Box::new(CTString::from_xml(reader, &e, true)?));
@@ -33190,7 +33197,7 @@ impl FromXml for CTOdso {
 
 impl FromXml for CTMailMerge {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -33237,7 +33244,7 @@ impl FromXml for CTMailMerge {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"mainDocumentType" => {
                                 f_main_document_type = Some(Box::new(
@@ -33399,7 +33406,7 @@ impl FromXml for CTMailMerge {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"mainDocumentType" => {
                                 f_main_document_type =
@@ -33607,7 +33614,7 @@ impl FromXml for CTMailMerge {
 
 impl FromXml for CTTargetScreenSz {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -33654,7 +33661,7 @@ impl FromXml for CTTargetScreenSz {
 
 impl FromXml for Compatibility {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -33801,7 +33808,7 @@ impl FromXml for Compatibility {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"useSingleBorderforContiguousCells" => {
                                 f_use_single_borderfor_contiguous_cells =
@@ -34414,7 +34421,7 @@ impl FromXml for Compatibility {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"useSingleBorderforContiguousCells" => {
                                 f_use_single_borderfor_contiguous_cells =
@@ -35171,7 +35178,7 @@ impl FromXml for Compatibility {
 
 impl FromXml for CTCompatSetting {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -35228,7 +35235,7 @@ impl FromXml for CTCompatSetting {
 
 impl FromXml for CTDocVar {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -35280,7 +35287,7 @@ impl FromXml for CTDocVar {
 
 impl FromXml for CTDocVars {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -35297,7 +35304,7 @@ impl FromXml for CTDocVars {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"docVar" => {
                                 f_doc_var.push(CTDocVar::from_xml(reader, &e, false)?);
@@ -35324,7 +35331,7 @@ impl FromXml for CTDocVars {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"docVar" => {
                                 f_doc_var.push(CTDocVar::from_xml(reader, &e, true)?);
@@ -35366,7 +35373,7 @@ impl FromXml for CTDocVars {
 
 impl FromXml for CTDocRsids {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -35385,7 +35392,7 @@ impl FromXml for CTDocRsids {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-track-changes")]
                             b"rsidRoot" => {
                                 f_rsid_root = Some(Box::new(LongHexNumberElement::from_xml(
@@ -35422,7 +35429,7 @@ impl FromXml for CTDocRsids {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-track-changes")]
                             b"rsidRoot" => {
                                 f_rsid_root = Some(Box::new(LongHexNumberElement::from_xml(
@@ -35476,7 +35483,7 @@ impl FromXml for CTDocRsids {
 
 impl FromXml for CTCharacterSpacing {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -35523,7 +35530,7 @@ impl FromXml for CTCharacterSpacing {
 
 impl FromXml for CTSaveThroughXslt {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -35581,7 +35588,7 @@ impl FromXml for CTSaveThroughXslt {
 
 impl FromXml for RunPropertiesDefault {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -35597,7 +35604,7 @@ impl FromXml for RunPropertiesDefault {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rPr" => {
                                 f_r_pr =
                                     Some(Box::new(RunProperties::from_xml(reader, &e, false)?));
@@ -35624,7 +35631,7 @@ impl FromXml for RunPropertiesDefault {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rPr" => {
                                 f_r_pr = Some(Box::new(RunProperties::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -35664,7 +35671,7 @@ impl FromXml for RunPropertiesDefault {
 
 impl FromXml for ParagraphPropertiesDefault {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -35680,7 +35687,7 @@ impl FromXml for ParagraphPropertiesDefault {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pPr" => {
                                 f_p_pr = Some(Box::new(CTPPrGeneral::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -35706,7 +35713,7 @@ impl FromXml for ParagraphPropertiesDefault {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"pPr" => {
                                 f_p_pr = Some(Box::new(CTPPrGeneral::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -35746,7 +35753,7 @@ impl FromXml for ParagraphPropertiesDefault {
 
 impl FromXml for DocumentDefaults {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -35763,7 +35770,7 @@ impl FromXml for DocumentDefaults {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rPrDefault" => {
                                 f_r_pr_default = Some(Box::new(RunPropertiesDefault::from_xml(
                                     reader, &e, false,
@@ -35800,7 +35807,7 @@ impl FromXml for DocumentDefaults {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"rPrDefault" => {
                                 f_r_pr_default = Some(Box::new(RunPropertiesDefault::from_xml(
                                     reader, &e, true,
@@ -35852,7 +35859,7 @@ impl FromXml for DocumentDefaults {
 
 impl FromXml for CTColorSchemeMapping {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -35990,7 +35997,7 @@ impl FromXml for CTColorSchemeMapping {
 
 impl FromXml for CTReadingModeInkLockDown {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -36065,7 +36072,7 @@ impl FromXml for CTReadingModeInkLockDown {
 
 impl FromXml for CTWriteProtection {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -36243,7 +36250,7 @@ impl FromXml for CTWriteProtection {
 
 impl FromXml for Settings {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -36450,7 +36457,7 @@ impl FromXml for Settings {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"writeProtection" => {
                                 f_write_protection =
@@ -37339,7 +37346,7 @@ impl FromXml for Settings {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"writeProtection" => {
                                 f_write_protection =
@@ -38431,7 +38438,7 @@ impl FromXml for Settings {
 
 impl FromXml for CTStyleSort {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -38478,7 +38485,7 @@ impl FromXml for CTStyleSort {
 
 impl FromXml for CTStylePaneFilter {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -38648,7 +38655,7 @@ impl FromXml for CTStylePaneFilter {
 
 impl FromXml for CTWebSettings {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -38689,7 +38696,7 @@ impl FromXml for CTWebSettings {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"frameset" => {
                                 f_frameset =
@@ -38824,7 +38831,7 @@ impl FromXml for CTWebSettings {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"frameset" => {
                                 f_frameset =
@@ -38998,7 +39005,7 @@ impl FromXml for CTWebSettings {
 
 impl FromXml for CTFrameScrollbar {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -39045,7 +39052,7 @@ impl FromXml for CTFrameScrollbar {
 
 impl FromXml for CTOptimizeForBrowser {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -39100,7 +39107,7 @@ impl FromXml for CTOptimizeForBrowser {
 
 impl FromXml for CTFrame {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -39135,7 +39142,7 @@ impl FromXml for CTFrame {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"sz" => {
                                 f_size = Some(Box::new(CTString::from_xml(reader, &e, false)?));
@@ -39242,7 +39249,7 @@ impl FromXml for CTFrame {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"sz" => {
                                 f_size = Some(Box::new(CTString::from_xml(reader, &e, true)?));
@@ -39382,7 +39389,7 @@ impl FromXml for CTFrame {
 
 impl FromXml for CTFrameLayout {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -39429,7 +39436,7 @@ impl FromXml for CTFrameLayout {
 
 impl FromXml for CTFramesetSplitbar {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -39452,7 +39459,7 @@ impl FromXml for CTFramesetSplitbar {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"w" => {
                                 f_width = Some(Box::new(TwipsMeasureElement::from_xml(
@@ -39507,7 +39514,7 @@ impl FromXml for CTFramesetSplitbar {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"w" => {
                                 f_width = Some(Box::new(TwipsMeasureElement::from_xml(
@@ -39583,7 +39590,7 @@ impl FromXml for CTFramesetSplitbar {
 
 impl FromXml for CTFrameset {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -39606,7 +39613,7 @@ impl FromXml for CTFrameset {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"sz" => {
                                 f_size = Some(Box::new(CTString::from_xml(reader, &e, false)?));
@@ -39660,7 +39667,7 @@ impl FromXml for CTFrameset {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"sz" => {
                                 f_size = Some(Box::new(CTString::from_xml(reader, &e, true)?));
@@ -39734,7 +39741,7 @@ impl FromXml for CTFrameset {
 
 impl FromXml for CTNumPicBullet {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -39773,7 +39780,7 @@ impl FromXml for CTNumPicBullet {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-numbering")]
                             b"pict" => {
                                 f_pict = Some(Box::new(CTPicture::from_xml(reader, &e, false)?));
@@ -39808,7 +39815,7 @@ impl FromXml for CTNumPicBullet {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-numbering")]
                             b"pict" => {
                                 f_pict = Some(Box::new(CTPicture::from_xml(reader, &e, true)?));
@@ -39864,7 +39871,7 @@ impl FromXml for CTNumPicBullet {
 
 impl FromXml for CTLevelSuffix {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -39911,7 +39918,7 @@ impl FromXml for CTLevelSuffix {
 
 impl FromXml for CTLevelText {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -39966,7 +39973,7 @@ impl FromXml for CTLevelText {
 
 impl FromXml for CTLvlLegacy {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -40032,7 +40039,7 @@ impl FromXml for CTLvlLegacy {
 
 impl FromXml for Level {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -40103,7 +40110,7 @@ impl FromXml for Level {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-numbering")]
                             b"start" => {
                                 f_start =
@@ -40227,7 +40234,7 @@ impl FromXml for Level {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-numbering")]
                             b"start" => {
                                 f_start =
@@ -40392,7 +40399,7 @@ impl FromXml for Level {
 
 impl FromXml for CTMultiLevelType {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -40439,7 +40446,7 @@ impl FromXml for CTMultiLevelType {
 
 impl FromXml for AbstractNumbering {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -40487,7 +40494,7 @@ impl FromXml for AbstractNumbering {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-numbering")]
                             b"nsid" => {
                                 f_nsid = Some(Box::new(LongHexNumberElement::from_xml(
@@ -40568,7 +40575,7 @@ impl FromXml for AbstractNumbering {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-numbering")]
                             b"nsid" => {
                                 f_nsid = Some(Box::new(LongHexNumberElement::from_xml(
@@ -40679,7 +40686,7 @@ impl FromXml for AbstractNumbering {
 
 impl FromXml for CTNumLvl {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -40718,7 +40725,7 @@ impl FromXml for CTNumLvl {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-numbering")]
                             b"startOverride" => {
                                 f_start_override =
@@ -40754,7 +40761,7 @@ impl FromXml for CTNumLvl {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-numbering")]
                             b"startOverride" => {
                                 f_start_override =
@@ -40810,7 +40817,7 @@ impl FromXml for CTNumLvl {
 
 impl FromXml for NumberingInstance {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -40848,7 +40855,7 @@ impl FromXml for NumberingInstance {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"abstractNumId" => {
                                 f_abstract_num_id =
                                     Some(Box::new(CTDecimalNumber::from_xml(reader, &e, false)?));
@@ -40883,7 +40890,7 @@ impl FromXml for NumberingInstance {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"abstractNumId" => {
                                 f_abstract_num_id =
                                     Some(Box::new(CTDecimalNumber::from_xml(reader, &e, true)?));
@@ -40938,7 +40945,7 @@ impl FromXml for NumberingInstance {
 
 impl FromXml for Numbering {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -40959,7 +40966,7 @@ impl FromXml for Numbering {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-numbering")]
                             b"numPicBullet" => {
                                 f_num_pic_bullet.push(CTNumPicBullet::from_xml(reader, &e, false)?);
@@ -41010,7 +41017,7 @@ impl FromXml for Numbering {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-numbering")]
                             b"numPicBullet" => {
                                 f_num_pic_bullet.push(CTNumPicBullet::from_xml(reader, &e, true)?);
@@ -41079,7 +41086,7 @@ impl FromXml for Numbering {
 
 impl FromXml for TableStyleProperties {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -41124,7 +41131,7 @@ impl FromXml for TableStyleProperties {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"pPr" => {
                                 f_p_pr = Some(Box::new(CTPPrGeneral::from_xml(reader, &e, false)?));
@@ -41189,7 +41196,7 @@ impl FromXml for TableStyleProperties {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"pPr" => {
                                 f_p_pr = Some(Box::new(CTPPrGeneral::from_xml(reader, &e, true)?));
@@ -41278,7 +41285,7 @@ impl FromXml for TableStyleProperties {
 
 impl FromXml for Style {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -41366,7 +41373,7 @@ impl FromXml for Style {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"name" => {
                                 f_name = Some(Box::new(CTString::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -41577,7 +41584,7 @@ impl FromXml for Style {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"name" => {
                                 f_name = Some(Box::new(CTString::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -41846,7 +41853,7 @@ impl FromXml for Style {
 
 impl FromXml for LatentStyleException {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -41933,7 +41940,7 @@ impl FromXml for LatentStyleException {
 
 impl FromXml for LatentStyles {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -42001,7 +42008,7 @@ impl FromXml for LatentStyles {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"lsdException" => {
                                 f_lsd_exception
                                     .push(LatentStyleException::from_xml(reader, &e, false)?);
@@ -42028,7 +42035,7 @@ impl FromXml for LatentStyles {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"lsdException" => {
                                 f_lsd_exception
                                     .push(LatentStyleException::from_xml(reader, &e, true)?);
@@ -42083,7 +42090,7 @@ impl FromXml for LatentStyles {
 
 impl FromXml for Styles {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -42102,7 +42109,7 @@ impl FromXml for Styles {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"docDefaults" => {
                                 f_doc_defaults =
                                     Some(Box::new(DocumentDefaults::from_xml(reader, &e, false)?));
@@ -42145,7 +42152,7 @@ impl FromXml for Styles {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"docDefaults" => {
                                 f_doc_defaults =
                                     Some(Box::new(DocumentDefaults::from_xml(reader, &e, true)?));
@@ -42205,7 +42212,7 @@ impl FromXml for Styles {
 
 impl FromXml for PanoseElement {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -42252,7 +42259,7 @@ impl FromXml for PanoseElement {
 
 impl FromXml for CTFontFamily {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -42299,7 +42306,7 @@ impl FromXml for CTFontFamily {
 
 impl FromXml for CTPitch {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -42346,7 +42353,7 @@ impl FromXml for CTPitch {
 
 impl FromXml for CTFontSig {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -42436,7 +42443,7 @@ impl FromXml for CTFontSig {
 
 impl FromXml for CTFontRel {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -42502,7 +42509,7 @@ impl FromXml for CTFontRel {
 
 impl FromXml for Font {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -42559,7 +42566,7 @@ impl FromXml for Font {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"altName" => {
                                 f_alt_name = Some(Box::new(CTString::from_xml(reader, &e, false)?));
@@ -42673,7 +42680,7 @@ impl FromXml for Font {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"altName" => {
                                 f_alt_name = Some(Box::new(CTString::from_xml(reader, &e, true)?));
@@ -42825,7 +42832,7 @@ impl FromXml for Font {
 
 impl FromXml for CTFontsList {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -42841,7 +42848,7 @@ impl FromXml for CTFontsList {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"font" => {
                                 f_font.push(Font::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -42867,7 +42874,7 @@ impl FromXml for CTFontsList {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"font" => {
                                 f_font.push(Font::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -42907,7 +42914,7 @@ impl FromXml for CTFontsList {
 
 impl FromXml for CTDivBdr {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -42930,7 +42937,7 @@ impl FromXml for CTDivBdr {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"top" => {
                                 f_top = Some(Box::new(CTBorder::from_xml(reader, &e, false)?));
@@ -42981,7 +42988,7 @@ impl FromXml for CTDivBdr {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"top" => {
                                 f_top = Some(Box::new(CTBorder::from_xml(reader, &e, true)?));
@@ -43053,7 +43060,7 @@ impl FromXml for CTDivBdr {
 
 impl FromXml for CTDiv {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -43106,7 +43113,7 @@ impl FromXml for CTDiv {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"blockQuote" => {
                                 f_block_quote =
@@ -43199,7 +43206,7 @@ impl FromXml for CTDiv {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"blockQuote" => {
                                 f_block_quote =
@@ -43328,7 +43335,7 @@ impl FromXml for CTDiv {
 
 impl FromXml for CTDivs {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -43345,7 +43352,7 @@ impl FromXml for CTDivs {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"div" => {
                                 f_div.push(CTDiv::from_xml(reader, &e, false)?);
@@ -43372,7 +43379,7 @@ impl FromXml for CTDivs {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"div" => {
                                 f_div.push(CTDiv::from_xml(reader, &e, true)?);
@@ -43414,7 +43421,7 @@ impl FromXml for CTDivs {
 
 impl FromXml for CTTxbxContent {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -43430,7 +43437,7 @@ impl FromXml for CTTxbxContent {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customXml"
                             | b"sdt"
                             | b"p"
@@ -43483,7 +43490,7 @@ impl FromXml for CTTxbxContent {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customXml"
                             | b"sdt"
                             | b"p"
@@ -43550,7 +43557,7 @@ impl FromXml for CTTxbxContent {
 
 impl FromXml for EGMathContent {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -43598,7 +43605,7 @@ impl FromXml for EGMathContent {
 
 impl FromXml for EGBlockLevelChunkElts {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -43614,7 +43621,7 @@ impl FromXml for EGBlockLevelChunkElts {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customXml"
                             | b"sdt"
                             | b"p"
@@ -43667,7 +43674,7 @@ impl FromXml for EGBlockLevelChunkElts {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customXml"
                             | b"sdt"
                             | b"p"
@@ -43734,7 +43741,7 @@ impl FromXml for EGBlockLevelChunkElts {
 
 impl FromXml for BlockContent {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -43861,7 +43868,7 @@ impl FromXml for BlockContent {
 
 impl FromXml for RunLevelContent {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -43968,7 +43975,7 @@ impl FromXml for RunLevelContent {
 
 impl FromXml for Body {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -43986,7 +43993,7 @@ impl FromXml for Body {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customXml"
                             | b"sdt"
                             | b"p"
@@ -44048,7 +44055,7 @@ impl FromXml for Body {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"customXml"
                             | b"sdt"
                             | b"p"
@@ -44126,7 +44133,7 @@ impl FromXml for Body {
 
 impl FromXml for CTShapeDefaults {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -44174,7 +44181,7 @@ impl FromXml for CTShapeDefaults {
 
 impl FromXml for Comments {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -44190,7 +44197,7 @@ impl FromXml for Comments {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"comment" => {
                                 f_comment.push(Comment::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -44216,7 +44223,7 @@ impl FromXml for Comments {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"comment" => {
                                 f_comment.push(Comment::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -44256,7 +44263,7 @@ impl FromXml for Comments {
 
 impl FromXml for Footnotes {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -44272,7 +44279,7 @@ impl FromXml for Footnotes {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"footnote" => {
                                 f_footnote.push(FootnoteEndnote::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -44298,7 +44305,7 @@ impl FromXml for Footnotes {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"footnote" => {
                                 f_footnote.push(FootnoteEndnote::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -44338,7 +44345,7 @@ impl FromXml for Footnotes {
 
 impl FromXml for Endnotes {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -44354,7 +44361,7 @@ impl FromXml for Endnotes {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"endnote" => {
                                 f_endnote.push(FootnoteEndnote::from_xml(reader, &e, false)?);
                                 #[cfg(feature = "extra-children")]
@@ -44380,7 +44387,7 @@ impl FromXml for Endnotes {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"endnote" => {
                                 f_endnote.push(FootnoteEndnote::from_xml(reader, &e, true)?);
                                 #[cfg(feature = "extra-children")]
@@ -44420,7 +44427,7 @@ impl FromXml for Endnotes {
 
 impl FromXml for CTSmartTagType {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -44486,7 +44493,7 @@ impl FromXml for CTSmartTagType {
 
 impl FromXml for CTDocPartBehavior {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -44533,7 +44540,7 @@ impl FromXml for CTDocPartBehavior {
 
 impl FromXml for CTDocPartBehaviors {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -44550,7 +44557,7 @@ impl FromXml for CTDocPartBehaviors {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"behavior" => {
                                 f_behavior.push(CTDocPartBehavior::from_xml(reader, &e, false)?);
@@ -44577,7 +44584,7 @@ impl FromXml for CTDocPartBehaviors {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"behavior" => {
                                 f_behavior.push(CTDocPartBehavior::from_xml(reader, &e, true)?);
@@ -44619,7 +44626,7 @@ impl FromXml for CTDocPartBehaviors {
 
 impl FromXml for CTDocPartType {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -44666,7 +44673,7 @@ impl FromXml for CTDocPartType {
 
 impl FromXml for CTDocPartTypes {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -44705,7 +44712,7 @@ impl FromXml for CTDocPartTypes {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"type" => {
                                 f_type.push(CTDocPartType::from_xml(reader, &e, false)?);
@@ -44732,7 +44739,7 @@ impl FromXml for CTDocPartTypes {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"type" => {
                                 f_type.push(CTDocPartType::from_xml(reader, &e, true)?);
@@ -44778,7 +44785,7 @@ impl FromXml for CTDocPartTypes {
 
 impl FromXml for CTDocPartGallery {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -44825,7 +44832,7 @@ impl FromXml for CTDocPartGallery {
 
 impl FromXml for CTDocPartCategory {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -44843,7 +44850,7 @@ impl FromXml for CTDocPartCategory {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"name" => {
                                 f_name = Some(Box::new(CTString::from_xml(reader, &e, false)?));
                                 #[cfg(feature = "extra-children")]
@@ -44878,7 +44885,7 @@ impl FromXml for CTDocPartCategory {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"name" => {
                                 f_name = Some(Box::new(CTString::from_xml(reader, &e, true)?));
                                 #[cfg(feature = "extra-children")]
@@ -44930,7 +44937,7 @@ impl FromXml for CTDocPartCategory {
 
 impl FromXml for CTDocPartName {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -44985,7 +44992,7 @@ impl FromXml for CTDocPartName {
 
 impl FromXml for CTDocPartPr {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -45014,7 +45021,7 @@ impl FromXml for CTDocPartPr {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"name" => {
                                 f_name =
@@ -45095,7 +45102,7 @@ impl FromXml for CTDocPartPr {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"name" => {
                                 f_name = Some(Box::new(CTDocPartName::from_xml(reader, &e, true)?));
@@ -45201,7 +45208,7 @@ impl FromXml for CTDocPartPr {
 
 impl FromXml for CTDocPart {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -45220,7 +45227,7 @@ impl FromXml for CTDocPart {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"docPartPr" => {
                                 f_doc_part_pr =
@@ -45257,7 +45264,7 @@ impl FromXml for CTDocPart {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"docPartPr" => {
                                 f_doc_part_pr =
@@ -45310,7 +45317,7 @@ impl FromXml for CTDocPart {
 
 impl FromXml for CTDocParts {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -45327,7 +45334,7 @@ impl FromXml for CTDocParts {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"docPart" => {
                                 f_doc_part.push(CTDocPart::from_xml(reader, &e, false)?);
@@ -45354,7 +45361,7 @@ impl FromXml for CTDocParts {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"docPart" => {
                                 f_doc_part.push(CTDocPart::from_xml(reader, &e, true)?);
@@ -45396,7 +45403,7 @@ impl FromXml for CTDocParts {
 
 impl FromXml for CTCaption {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -45494,7 +45501,7 @@ impl FromXml for CTCaption {
 
 impl FromXml for CTAutoCaption {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -45553,7 +45560,7 @@ impl FromXml for CTAutoCaption {
 
 impl FromXml for CTAutoCaptions {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -45570,7 +45577,7 @@ impl FromXml for CTAutoCaptions {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"autoCaption" => {
                                 f_auto_caption.push(CTAutoCaption::from_xml(reader, &e, false)?);
@@ -45597,7 +45604,7 @@ impl FromXml for CTAutoCaptions {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"autoCaption" => {
                                 f_auto_caption.push(CTAutoCaption::from_xml(reader, &e, true)?);
@@ -45639,7 +45646,7 @@ impl FromXml for CTAutoCaptions {
 
 impl FromXml for CTCaptions {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -45658,7 +45665,7 @@ impl FromXml for CTCaptions {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"caption" => {
                                 f_caption.push(CTCaption::from_xml(reader, &e, false)?);
@@ -45694,7 +45701,7 @@ impl FromXml for CTCaptions {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-settings")]
                             b"caption" => {
                                 f_caption.push(CTCaption::from_xml(reader, &e, true)?);
@@ -45747,7 +45754,7 @@ impl FromXml for CTCaptions {
 
 impl FromXml for CTDocumentBase {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -45764,7 +45771,7 @@ impl FromXml for CTDocumentBase {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"background" => {
                                 f_background =
@@ -45792,7 +45799,7 @@ impl FromXml for CTDocumentBase {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"background" => {
                                 f_background =
@@ -45835,7 +45842,7 @@ impl FromXml for CTDocumentBase {
 
 impl FromXml for Document {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -45873,7 +45880,7 @@ impl FromXml for Document {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"background" => {
                                 f_background =
@@ -45908,7 +45915,7 @@ impl FromXml for Document {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             #[cfg(feature = "wml-styling")]
                             b"background" => {
                                 f_background =
@@ -45962,7 +45969,7 @@ impl FromXml for Document {
 
 impl FromXml for CTGlossaryDocument {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -45980,7 +45987,7 @@ impl FromXml for CTGlossaryDocument {
             loop {
                 match reader.read_event_into(&mut buf)? {
                     Event::Start(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"background" => {
                                 f_background =
                                     Some(Box::new(CTBackground::from_xml(reader, &e, false)?));
@@ -46016,7 +46023,7 @@ impl FromXml for CTGlossaryDocument {
                         }
                     }
                     Event::Empty(e) => {
-                        match e.local_name().as_ref() {
+                        match local_name_in(reader, &e, NAMESPACES) {
                             b"background" => {
                                 f_background =
                                     Some(Box::new(CTBackground::from_xml(reader, &e, true)?));
@@ -46068,7 +46075,7 @@ impl FromXml for CTGlossaryDocument {
 
 impl FromXml for WAnyVmlOffice {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
@@ -46116,7 +46123,7 @@ impl FromXml for WAnyVmlOffice {
 
 impl FromXml for WAnyVmlVml {
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> Result<Self, ParseError> {
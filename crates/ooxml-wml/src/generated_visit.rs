@@ -0,0 +1,30827 @@
+// Visitors for the generated types, derived from generated.rs.
+// Do not edit manually.
+
+#![allow(unused_variables)]
+#![allow(clippy::single_match)]
+#![allow(clippy::match_single_binding)]
+use super::generated::*;
+use ooxml_xml::{RawXmlElement, RawXmlNode};
+
+#[doc = r" Read-only traversal of the generated types."]
+#[doc = r""]
+#[doc = r" Every method defaults to visiting the node's children; override"]
+#[doc = r" the ones of interest and call the matching `walk_*` function to"]
+#[doc = r" keep descending."]
+pub trait Visit<'ast> {
+    fn visit_abstract_numbering(&mut self, node: &'ast AbstractNumbering) {
+        walk_abstract_numbering(self, node)
+    }
+    fn visit_block_content(&mut self, node: &'ast BlockContent) {
+        walk_block_content(self, node)
+    }
+    fn visit_block_content_choice(&mut self, node: &'ast BlockContentChoice) {
+        walk_block_content_choice(self, node)
+    }
+    fn visit_body(&mut self, node: &'ast Body) {
+        walk_body(self, node)
+    }
+    fn visit_bookmark(&mut self, node: &'ast Bookmark) {
+        walk_bookmark(self, node)
+    }
+    fn visit_ct_alt_chunk(&mut self, node: &'ast CTAltChunk) {
+        walk_ct_alt_chunk(self, node)
+    }
+    fn visit_ct_alt_chunk_pr(&mut self, node: &'ast CTAltChunkPr) {
+        walk_ct_alt_chunk_pr(self, node)
+    }
+    fn visit_ct_attr(&mut self, _node: &'ast CTAttr) {}
+    fn visit_ct_auto_caption(&mut self, _node: &'ast CTAutoCaption) {}
+    fn visit_ct_auto_captions(&mut self, node: &'ast CTAutoCaptions) {
+        walk_ct_auto_captions(self, node)
+    }
+    fn visit_ct_background(&mut self, node: &'ast CTBackground) {
+        walk_ct_background(self, node)
+    }
+    fn visit_ct_base64_binary(&mut self, _node: &'ast CTBase64Binary) {}
+    fn visit_ct_bdo_content_run(&mut self, node: &'ast CTBdoContentRun) {
+        walk_ct_bdo_content_run(self, node)
+    }
+    fn visit_ct_bookmark_range(&mut self, node: &'ast CTBookmarkRange) {
+        walk_ct_bookmark_range(self, node)
+    }
+    fn visit_ct_border(&mut self, node: &'ast CTBorder) {
+        walk_ct_border(self, node)
+    }
+    fn visit_ct_bottom_page_border(&mut self, node: &'ast CTBottomPageBorder) {
+        walk_ct_bottom_page_border(self, node)
+    }
+    fn visit_ct_br(&mut self, node: &'ast CTBr) {
+        walk_ct_br(self, node)
+    }
+    fn visit_ct_calendar_type(&mut self, node: &'ast CTCalendarType) {
+        walk_ct_calendar_type(self, node)
+    }
+    fn visit_ct_caption(&mut self, node: &'ast CTCaption) {
+        walk_ct_caption(self, node)
+    }
+    fn visit_ct_captions(&mut self, node: &'ast CTCaptions) {
+        walk_ct_captions(self, node)
+    }
+    fn visit_ct_cell_merge_track_change(&mut self, node: &'ast CTCellMergeTrackChange) {
+        walk_ct_cell_merge_track_change(self, node)
+    }
+    fn visit_ct_character_spacing(&mut self, node: &'ast CTCharacterSpacing) {
+        walk_ct_character_spacing(self, node)
+    }
+    fn visit_ct_charset(&mut self, _node: &'ast CTCharset) {}
+    fn visit_ct_cnf(&mut self, _node: &'ast CTCnf) {}
+    fn visit_ct_color(&mut self, node: &'ast CTColor) {
+        walk_ct_color(self, node)
+    }
+    fn visit_ct_color_scheme_mapping(&mut self, node: &'ast CTColorSchemeMapping) {
+        walk_ct_color_scheme_mapping(self, node)
+    }
+    fn visit_ct_column(&mut self, _node: &'ast CTColumn) {}
+    fn visit_ct_compat_setting(&mut self, _node: &'ast CTCompatSetting) {}
+    fn visit_ct_control(&mut self, _node: &'ast CTControl) {}
+    fn visit_ct_custom_xml_block(&mut self, node: &'ast CTCustomXmlBlock) {
+        walk_ct_custom_xml_block(self, node)
+    }
+    fn visit_ct_custom_xml_cell(&mut self, node: &'ast CTCustomXmlCell) {
+        walk_ct_custom_xml_cell(self, node)
+    }
+    fn visit_ct_custom_xml_pr(&mut self, node: &'ast CTCustomXmlPr) {
+        walk_ct_custom_xml_pr(self, node)
+    }
+    fn visit_ct_custom_xml_row(&mut self, node: &'ast CTCustomXmlRow) {
+        walk_ct_custom_xml_row(self, node)
+    }
+    fn visit_ct_custom_xml_run(&mut self, node: &'ast CTCustomXmlRun) {
+        walk_ct_custom_xml_run(self, node)
+    }
+    fn visit_ct_data_binding(&mut self, _node: &'ast CTDataBinding) {}
+    fn visit_ct_decimal_number(&mut self, _node: &'ast CTDecimalNumber) {}
+    fn visit_ct_decimal_number_or_precent(&mut self, _node: &'ast CTDecimalNumberOrPrecent) {}
+    fn visit_ct_dir_content_run(&mut self, node: &'ast CTDirContentRun) {
+        walk_ct_dir_content_run(self, node)
+    }
+    fn visit_ct_div(&mut self, node: &'ast CTDiv) {
+        walk_ct_div(self, node)
+    }
+    fn visit_ct_div_bdr(&mut self, node: &'ast CTDivBdr) {
+        walk_ct_div_bdr(self, node)
+    }
+    fn visit_ct_divs(&mut self, node: &'ast CTDivs) {
+        walk_ct_divs(self, node)
+    }
+    fn visit_ct_doc_part(&mut self, node: &'ast CTDocPart) {
+        walk_ct_doc_part(self, node)
+    }
+    fn visit_ct_doc_part_behavior(&mut self, node: &'ast CTDocPartBehavior) {
+        walk_ct_doc_part_behavior(self, node)
+    }
+    fn visit_ct_doc_part_behaviors(&mut self, node: &'ast CTDocPartBehaviors) {
+        walk_ct_doc_part_behaviors(self, node)
+    }
+    fn visit_ct_doc_part_category(&mut self, node: &'ast CTDocPartCategory) {
+        walk_ct_doc_part_category(self, node)
+    }
+    fn visit_ct_doc_part_gallery(&mut self, node: &'ast CTDocPartGallery) {
+        walk_ct_doc_part_gallery(self, node)
+    }
+    fn visit_ct_doc_part_name(&mut self, _node: &'ast CTDocPartName) {}
+    fn visit_ct_doc_part_pr(&mut self, node: &'ast CTDocPartPr) {
+        walk_ct_doc_part_pr(self, node)
+    }
+    fn visit_ct_doc_part_type(&mut self, node: &'ast CTDocPartType) {
+        walk_ct_doc_part_type(self, node)
+    }
+    fn visit_ct_doc_part_types(&mut self, node: &'ast CTDocPartTypes) {
+        walk_ct_doc_part_types(self, node)
+    }
+    fn visit_ct_doc_parts(&mut self, node: &'ast CTDocParts) {
+        walk_ct_doc_parts(self, node)
+    }
+    fn visit_ct_doc_protect(&mut self, node: &'ast CTDocProtect) {
+        walk_ct_doc_protect(self, node)
+    }
+    fn visit_ct_doc_rsids(&mut self, node: &'ast CTDocRsids) {
+        walk_ct_doc_rsids(self, node)
+    }
+    fn visit_ct_doc_var(&mut self, _node: &'ast CTDocVar) {}
+    fn visit_ct_doc_vars(&mut self, node: &'ast CTDocVars) {
+        walk_ct_doc_vars(self, node)
+    }
+    fn visit_ct_document_base(&mut self, node: &'ast CTDocumentBase) {
+        walk_ct_document_base(self, node)
+    }
+    fn visit_ct_drawing(&mut self, node: &'ast CTDrawing) {
+        walk_ct_drawing(self, node)
+    }
+    fn visit_ct_east_asian_layout(&mut self, node: &'ast CTEastAsianLayout) {
+        walk_ct_east_asian_layout(self, node)
+    }
+    fn visit_ct_edn_doc_props(&mut self, node: &'ast CTEdnDocProps) {
+        walk_ct_edn_doc_props(self, node)
+    }
+    fn visit_ct_edn_pos(&mut self, node: &'ast CTEdnPos) {
+        walk_ct_edn_pos(self, node)
+    }
+    fn visit_ct_edn_props(&mut self, node: &'ast CTEdnProps) {
+        walk_ct_edn_props(self, node)
+    }
+    fn visit_ct_em(&mut self, node: &'ast CTEm) {
+        walk_ct_em(self, node)
+    }
+    fn visit_ct_empty(&mut self, _node: &'ast CTEmpty) {}
+    fn visit_ctff_check_box(&mut self, node: &'ast CTFFCheckBox) {
+        walk_ctff_check_box(self, node)
+    }
+    fn visit_ctffdd_list(&mut self, node: &'ast CTFFDDList) {
+        walk_ctffdd_list(self, node)
+    }
+    fn visit_ctff_data(&mut self, node: &'ast CTFFData) {
+        walk_ctff_data(self, node)
+    }
+    fn visit_ctff_help_text(&mut self, node: &'ast CTFFHelpText) {
+        walk_ctff_help_text(self, node)
+    }
+    fn visit_ctff_status_text(&mut self, node: &'ast CTFFStatusText) {
+        walk_ctff_status_text(self, node)
+    }
+    fn visit_ctff_text_input(&mut self, node: &'ast CTFFTextInput) {
+        walk_ctff_text_input(self, node)
+    }
+    fn visit_ctff_text_type(&mut self, node: &'ast CTFFTextType) {
+        walk_ctff_text_type(self, node)
+    }
+    fn visit_ct_fit_text(&mut self, _node: &'ast CTFitText) {}
+    fn visit_ct_fld_char(&mut self, node: &'ast CTFldChar) {
+        walk_ct_fld_char(self, node)
+    }
+    fn visit_ct_font_family(&mut self, node: &'ast CTFontFamily) {
+        walk_ct_font_family(self, node)
+    }
+    fn visit_ct_font_rel(&mut self, _node: &'ast CTFontRel) {}
+    fn visit_ct_font_sig(&mut self, _node: &'ast CTFontSig) {}
+    fn visit_ct_fonts_list(&mut self, node: &'ast CTFontsList) {
+        walk_ct_fonts_list(self, node)
+    }
+    fn visit_ct_frame(&mut self, node: &'ast CTFrame) {
+        walk_ct_frame(self, node)
+    }
+    fn visit_ct_frame_layout(&mut self, node: &'ast CTFrameLayout) {
+        walk_ct_frame_layout(self, node)
+    }
+    fn visit_ct_frame_pr(&mut self, node: &'ast CTFramePr) {
+        walk_ct_frame_pr(self, node)
+    }
+    fn visit_ct_frame_scrollbar(&mut self, node: &'ast CTFrameScrollbar) {
+        walk_ct_frame_scrollbar(self, node)
+    }
+    fn visit_ct_frameset(&mut self, node: &'ast CTFrameset) {
+        walk_ct_frameset(self, node)
+    }
+    fn visit_ct_frameset_splitbar(&mut self, node: &'ast CTFramesetSplitbar) {
+        walk_ct_frameset_splitbar(self, node)
+    }
+    fn visit_ct_ftn_doc_props(&mut self, node: &'ast CTFtnDocProps) {
+        walk_ct_ftn_doc_props(self, node)
+    }
+    fn visit_ct_ftn_edn_sep_ref(&mut self, _node: &'ast CTFtnEdnSepRef) {}
+    fn visit_ct_ftn_pos(&mut self, node: &'ast CTFtnPos) {
+        walk_ct_ftn_pos(self, node)
+    }
+    fn visit_ct_ftn_props(&mut self, node: &'ast CTFtnProps) {
+        walk_ct_ftn_props(self, node)
+    }
+    fn visit_ct_glossary_document(&mut self, node: &'ast CTGlossaryDocument) {
+        walk_ct_glossary_document(self, node)
+    }
+    fn visit_cth_merge(&mut self, node: &'ast CTHMerge) {
+        walk_cth_merge(self, node)
+    }
+    fn visit_ct_headers(&mut self, node: &'ast CTHeaders) {
+        walk_ct_headers(self, node)
+    }
+    fn visit_ct_height(&mut self, node: &'ast CTHeight) {
+        walk_ct_height(self, node)
+    }
+    fn visit_ct_highlight(&mut self, node: &'ast CTHighlight) {
+        walk_ct_highlight(self, node)
+    }
+    fn visit_ct_ind(&mut self, _node: &'ast CTInd) {}
+    fn visit_ct_jc(&mut self, node: &'ast CTJc) {
+        walk_ct_jc(self, node)
+    }
+    fn visit_ct_jc_table(&mut self, node: &'ast CTJcTable) {
+        walk_ct_jc_table(self, node)
+    }
+    fn visit_ct_kinsoku(&mut self, _node: &'ast CTKinsoku) {}
+    fn visit_ct_lang(&mut self, _node: &'ast CTLang) {}
+    fn visit_ct_level_suffix(&mut self, node: &'ast CTLevelSuffix) {
+        walk_ct_level_suffix(self, node)
+    }
+    fn visit_ct_level_text(&mut self, _node: &'ast CTLevelText) {}
+    fn visit_ct_line_number(&mut self, node: &'ast CTLineNumber) {
+        walk_ct_line_number(self, node)
+    }
+    fn visit_ct_lock(&mut self, node: &'ast CTLock) {
+        walk_ct_lock(self, node)
+    }
+    fn visit_ct_lvl_legacy(&mut self, _node: &'ast CTLvlLegacy) {}
+    fn visit_ct_mail_merge(&mut self, node: &'ast CTMailMerge) {
+        walk_ct_mail_merge(self, node)
+    }
+    fn visit_ct_mail_merge_dest(&mut self, node: &'ast CTMailMergeDest) {
+        walk_ct_mail_merge_dest(self, node)
+    }
+    fn visit_ct_mail_merge_doc_type(&mut self, node: &'ast CTMailMergeDocType) {
+        walk_ct_mail_merge_doc_type(self, node)
+    }
+    fn visit_ct_mail_merge_odso_fmd_field_type(&mut self, node: &'ast CTMailMergeOdsoFMDFieldType) {
+        walk_ct_mail_merge_odso_fmd_field_type(self, node)
+    }
+    fn visit_ct_mail_merge_source_type(&mut self, node: &'ast CTMailMergeSourceType) {
+        walk_ct_mail_merge_source_type(self, node)
+    }
+    fn visit_ct_markup(&mut self, _node: &'ast CTMarkup) {}
+    fn visit_ct_markup_range(&mut self, node: &'ast CTMarkupRange) {
+        walk_ct_markup_range(self, node)
+    }
+    fn visit_ct_math_ctrl_del(&mut self, node: &'ast CTMathCtrlDel) {
+        walk_ct_math_ctrl_del(self, node)
+    }
+    fn visit_ct_math_ctrl_ins(&mut self, node: &'ast CTMathCtrlIns) {
+        walk_ct_math_ctrl_ins(self, node)
+    }
+    fn visit_ct_move_bookmark(&mut self, node: &'ast CTMoveBookmark) {
+        walk_ct_move_bookmark(self, node)
+    }
+    fn visit_ct_multi_level_type(&mut self, node: &'ast CTMultiLevelType) {
+        walk_ct_multi_level_type(self, node)
+    }
+    fn visit_ct_num_fmt(&mut self, node: &'ast CTNumFmt) {
+        walk_ct_num_fmt(self, node)
+    }
+    fn visit_ct_num_lvl(&mut self, node: &'ast CTNumLvl) {
+        walk_ct_num_lvl(self, node)
+    }
+    fn visit_ct_num_pic_bullet(&mut self, node: &'ast CTNumPicBullet) {
+        walk_ct_num_pic_bullet(self, node)
+    }
+    fn visit_ct_num_restart(&mut self, node: &'ast CTNumRestart) {
+        walk_ct_num_restart(self, node)
+    }
+    fn visit_ct_object(&mut self, node: &'ast CTObject) {
+        walk_ct_object(self, node)
+    }
+    fn visit_ct_object_embed(&mut self, node: &'ast CTObjectEmbed) {
+        walk_ct_object_embed(self, node)
+    }
+    fn visit_ct_object_link(&mut self, node: &'ast CTObjectLink) {
+        walk_ct_object_link(self, node)
+    }
+    fn visit_ct_odso(&mut self, node: &'ast CTOdso) {
+        walk_ct_odso(self, node)
+    }
+    fn visit_ct_odso_field_map_data(&mut self, node: &'ast CTOdsoFieldMapData) {
+        walk_ct_odso_field_map_data(self, node)
+    }
+    fn visit_ct_optimize_for_browser(&mut self, _node: &'ast CTOptimizeForBrowser) {}
+    fn visit_ctp_bdr(&mut self, node: &'ast CTPBdr) {
+        walk_ctp_bdr(self, node)
+    }
+    fn visit_ctp_pr_base(&mut self, node: &'ast CTPPrBase) {
+        walk_ctp_pr_base(self, node)
+    }
+    fn visit_ctp_pr_change(&mut self, node: &'ast CTPPrChange) {
+        walk_ctp_pr_change(self, node)
+    }
+    fn visit_ctp_pr_general(&mut self, node: &'ast CTPPrGeneral) {
+        walk_ctp_pr_general(self, node)
+    }
+    fn visit_ctp_tab(&mut self, node: &'ast CTPTab) {
+        walk_ctp_tab(self, node)
+    }
+    fn visit_ct_page_border(&mut self, node: &'ast CTPageBorder) {
+        walk_ct_page_border(self, node)
+    }
+    fn visit_ct_page_borders(&mut self, node: &'ast CTPageBorders) {
+        walk_ct_page_borders(self, node)
+    }
+    fn visit_ct_page_number(&mut self, node: &'ast CTPageNumber) {
+        walk_ct_page_number(self, node)
+    }
+    fn visit_ct_paper_source(&mut self, _node: &'ast CTPaperSource) {}
+    fn visit_ct_para_r_pr(&mut self, node: &'ast CTParaRPr) {
+        walk_ct_para_r_pr(self, node)
+    }
+    fn visit_ct_para_r_pr_change(&mut self, node: &'ast CTParaRPrChange) {
+        walk_ct_para_r_pr_change(self, node)
+    }
+    fn visit_ct_para_r_pr_original(&mut self, node: &'ast CTParaRPrOriginal) {
+        walk_ct_para_r_pr_original(self, node)
+    }
+    fn visit_ct_perm(&mut self, node: &'ast CTPerm) {
+        walk_ct_perm(self, node)
+    }
+    fn visit_ct_perm_start(&mut self, node: &'ast CTPermStart) {
+        walk_ct_perm_start(self, node)
+    }
+    fn visit_ct_picture(&mut self, node: &'ast CTPicture) {
+        walk_ct_picture(self, node)
+    }
+    fn visit_ct_pitch(&mut self, node: &'ast CTPitch) {
+        walk_ct_pitch(self, node)
+    }
+    fn visit_ct_proof(&mut self, node: &'ast CTProof) {
+        walk_ct_proof(self, node)
+    }
+    fn visit_ct_proof_err(&mut self, node: &'ast CTProofErr) {
+        walk_ct_proof_err(self, node)
+    }
+    fn visit_ctr_pr_change(&mut self, node: &'ast CTRPrChange) {
+        walk_ctr_pr_change(self, node)
+    }
+    fn visit_ctr_pr_original(&mut self, node: &'ast CTRPrOriginal) {
+        walk_ctr_pr_original(self, node)
+    }
+    fn visit_ct_reading_mode_ink_lock_down(&mut self, _node: &'ast CTReadingModeInkLockDown) {}
+    fn visit_ct_recipient_data(&mut self, node: &'ast CTRecipientData) {
+        walk_ct_recipient_data(self, node)
+    }
+    fn visit_ct_recipients(&mut self, node: &'ast CTRecipients) {
+        walk_ct_recipients(self, node)
+    }
+    fn visit_ct_rel(&mut self, _node: &'ast CTRel) {}
+    fn visit_ct_row(&mut self, node: &'ast CTRow) {
+        walk_ct_row(self, node)
+    }
+    fn visit_ct_ruby(&mut self, node: &'ast CTRuby) {
+        walk_ct_ruby(self, node)
+    }
+    fn visit_ct_ruby_align(&mut self, node: &'ast CTRubyAlign) {
+        walk_ct_ruby_align(self, node)
+    }
+    fn visit_ct_ruby_content(&mut self, node: &'ast CTRubyContent) {
+        walk_ct_ruby_content(self, node)
+    }
+    fn visit_ct_ruby_pr(&mut self, node: &'ast CTRubyPr) {
+        walk_ct_ruby_pr(self, node)
+    }
+    fn visit_ct_run_track_change(&mut self, node: &'ast CTRunTrackChange) {
+        walk_ct_run_track_change(self, node)
+    }
+    fn visit_ct_save_through_xslt(&mut self, _node: &'ast CTSaveThroughXslt) {}
+    fn visit_ct_sdt_block(&mut self, node: &'ast CTSdtBlock) {
+        walk_ct_sdt_block(self, node)
+    }
+    fn visit_ct_sdt_cell(&mut self, node: &'ast CTSdtCell) {
+        walk_ct_sdt_cell(self, node)
+    }
+    fn visit_ct_sdt_combo_box(&mut self, node: &'ast CTSdtComboBox) {
+        walk_ct_sdt_combo_box(self, node)
+    }
+    fn visit_ct_sdt_content_block(&mut self, node: &'ast CTSdtContentBlock) {
+        walk_ct_sdt_content_block(self, node)
+    }
+    fn visit_ct_sdt_content_cell(&mut self, node: &'ast CTSdtContentCell) {
+        walk_ct_sdt_content_cell(self, node)
+    }
+    fn visit_ct_sdt_content_row(&mut self, node: &'ast CTSdtContentRow) {
+        walk_ct_sdt_content_row(self, node)
+    }
+    fn visit_ct_sdt_content_run(&mut self, node: &'ast CTSdtContentRun) {
+        walk_ct_sdt_content_run(self, node)
+    }
+    fn visit_ct_sdt_date(&mut self, node: &'ast CTSdtDate) {
+        walk_ct_sdt_date(self, node)
+    }
+    fn visit_ct_sdt_date_mapping_type(&mut self, node: &'ast CTSdtDateMappingType) {
+        walk_ct_sdt_date_mapping_type(self, node)
+    }
+    fn visit_ct_sdt_doc_part(&mut self, node: &'ast CTSdtDocPart) {
+        walk_ct_sdt_doc_part(self, node)
+    }
+    fn visit_ct_sdt_drop_down_list(&mut self, node: &'ast CTSdtDropDownList) {
+        walk_ct_sdt_drop_down_list(self, node)
+    }
+    fn visit_ct_sdt_end_pr(&mut self, node: &'ast CTSdtEndPr) {
+        walk_ct_sdt_end_pr(self, node)
+    }
+    fn visit_ct_sdt_list_item(&mut self, _node: &'ast CTSdtListItem) {}
+    fn visit_ct_sdt_pr(&mut self, node: &'ast CTSdtPr) {
+        walk_ct_sdt_pr(self, node)
+    }
+    fn visit_ct_sdt_row(&mut self, node: &'ast CTSdtRow) {
+        walk_ct_sdt_row(self, node)
+    }
+    fn visit_ct_sdt_run(&mut self, node: &'ast CTSdtRun) {
+        walk_ct_sdt_run(self, node)
+    }
+    fn visit_ct_sdt_text(&mut self, _node: &'ast CTSdtText) {}
+    fn visit_ct_sect_pr_base(&mut self, node: &'ast CTSectPrBase) {
+        walk_ct_sect_pr_base(self, node)
+    }
+    fn visit_ct_sect_pr_change(&mut self, node: &'ast CTSectPrChange) {
+        walk_ct_sect_pr_change(self, node)
+    }
+    fn visit_ct_sect_type(&mut self, node: &'ast CTSectType) {
+        walk_ct_sect_type(self, node)
+    }
+    fn visit_ct_shape_defaults(&mut self, node: &'ast CTShapeDefaults) {
+        walk_ct_shape_defaults(self, node)
+    }
+    fn visit_ct_shd(&mut self, node: &'ast CTShd) {
+        walk_ct_shd(self, node)
+    }
+    fn visit_ct_simple_field(&mut self, node: &'ast CTSimpleField) {
+        walk_ct_simple_field(self, node)
+    }
+    fn visit_ct_smart_tag_pr(&mut self, node: &'ast CTSmartTagPr) {
+        walk_ct_smart_tag_pr(self, node)
+    }
+    fn visit_ct_smart_tag_run(&mut self, node: &'ast CTSmartTagRun) {
+        walk_ct_smart_tag_run(self, node)
+    }
+    fn visit_ct_smart_tag_type(&mut self, _node: &'ast CTSmartTagType) {}
+    fn visit_ct_spacing(&mut self, node: &'ast CTSpacing) {
+        walk_ct_spacing(self, node)
+    }
+    fn visit_ct_string(&mut self, _node: &'ast CTString) {}
+    fn visit_ct_style_pane_filter(&mut self, _node: &'ast CTStylePaneFilter) {}
+    fn visit_ct_style_sort(&mut self, node: &'ast CTStyleSort) {
+        walk_ct_style_sort(self, node)
+    }
+    fn visit_ct_sym(&mut self, _node: &'ast CTSym) {}
+    fn visit_ct_tab_stop(&mut self, node: &'ast CTTabStop) {
+        walk_ct_tab_stop(self, node)
+    }
+    fn visit_ct_tabs(&mut self, node: &'ast CTTabs) {
+        walk_ct_tabs(self, node)
+    }
+    fn visit_ct_target_screen_sz(&mut self, node: &'ast CTTargetScreenSz) {
+        walk_ct_target_screen_sz(self, node)
+    }
+    fn visit_ct_tbl_borders(&mut self, node: &'ast CTTblBorders) {
+        walk_ct_tbl_borders(self, node)
+    }
+    fn visit_ct_tbl_cell_mar(&mut self, node: &'ast CTTblCellMar) {
+        walk_ct_tbl_cell_mar(self, node)
+    }
+    fn visit_ct_tbl_grid_base(&mut self, node: &'ast CTTblGridBase) {
+        walk_ct_tbl_grid_base(self, node)
+    }
+    fn visit_ct_tbl_grid_change(&mut self, node: &'ast CTTblGridChange) {
+        walk_ct_tbl_grid_change(self, node)
+    }
+    fn visit_ct_tbl_layout_type(&mut self, node: &'ast CTTblLayoutType) {
+        walk_ct_tbl_layout_type(self, node)
+    }
+    fn visit_ct_tbl_look(&mut self, _node: &'ast CTTblLook) {}
+    fn visit_ct_tbl_overlap(&mut self, node: &'ast CTTblOverlap) {
+        walk_ct_tbl_overlap(self, node)
+    }
+    fn visit_ct_tbl_p_pr(&mut self, node: &'ast CTTblPPr) {
+        walk_ct_tbl_p_pr(self, node)
+    }
+    fn visit_ct_tbl_pr_base(&mut self, node: &'ast CTTblPrBase) {
+        walk_ct_tbl_pr_base(self, node)
+    }
+    fn visit_ct_tbl_pr_change(&mut self, node: &'ast CTTblPrChange) {
+        walk_ct_tbl_pr_change(self, node)
+    }
+    fn visit_ct_tbl_pr_ex(&mut self, node: &'ast CTTblPrEx) {
+        walk_ct_tbl_pr_ex(self, node)
+    }
+    fn visit_ct_tbl_pr_ex_base(&mut self, node: &'ast CTTblPrExBase) {
+        walk_ct_tbl_pr_ex_base(self, node)
+    }
+    fn visit_ct_tbl_pr_ex_change(&mut self, node: &'ast CTTblPrExChange) {
+        walk_ct_tbl_pr_ex_change(self, node)
+    }
+    fn visit_ct_tbl_width(&mut self, node: &'ast CTTblWidth) {
+        walk_ct_tbl_width(self, node)
+    }
+    fn visit_ct_tc_borders(&mut self, node: &'ast CTTcBorders) {
+        walk_ct_tc_borders(self, node)
+    }
+    fn visit_ct_tc_mar(&mut self, node: &'ast CTTcMar) {
+        walk_ct_tc_mar(self, node)
+    }
+    fn visit_ct_tc_pr_base(&mut self, node: &'ast CTTcPrBase) {
+        walk_ct_tc_pr_base(self, node)
+    }
+    fn visit_ct_tc_pr_change(&mut self, node: &'ast CTTcPrChange) {
+        walk_ct_tc_pr_change(self, node)
+    }
+    fn visit_ct_tc_pr_inner(&mut self, node: &'ast CTTcPrInner) {
+        walk_ct_tc_pr_inner(self, node)
+    }
+    fn visit_ct_text_alignment(&mut self, node: &'ast CTTextAlignment) {
+        walk_ct_text_alignment(self, node)
+    }
+    fn visit_ct_text_direction(&mut self, node: &'ast CTTextDirection) {
+        walk_ct_text_direction(self, node)
+    }
+    fn visit_ct_text_effect(&mut self, node: &'ast CTTextEffect) {
+        walk_ct_text_effect(self, node)
+    }
+    fn visit_ct_textbox_tight_wrap(&mut self, node: &'ast CTTextboxTightWrap) {
+        walk_ct_textbox_tight_wrap(self, node)
+    }
+    fn visit_ct_top_page_border(&mut self, node: &'ast CTTopPageBorder) {
+        walk_ct_top_page_border(self, node)
+    }
+    fn visit_ct_tr_pr_base(&mut self, node: &'ast CTTrPrBase) {
+        walk_ct_tr_pr_base(self, node)
+    }
+    fn visit_ct_tr_pr_change(&mut self, node: &'ast CTTrPrChange) {
+        walk_ct_tr_pr_change(self, node)
+    }
+    fn visit_ct_track_change(&mut self, _node: &'ast CTTrackChange) {}
+    fn visit_ct_track_change_numbering(&mut self, _node: &'ast CTTrackChangeNumbering) {}
+    fn visit_ct_track_change_range(&mut self, node: &'ast CTTrackChangeRange) {
+        walk_ct_track_change_range(self, node)
+    }
+    fn visit_ct_track_changes_view(&mut self, _node: &'ast CTTrackChangesView) {}
+    fn visit_ct_txbx_content(&mut self, node: &'ast CTTxbxContent) {
+        walk_ct_txbx_content(self, node)
+    }
+    fn visit_ct_underline(&mut self, node: &'ast CTUnderline) {
+        walk_ct_underline(self, node)
+    }
+    fn visit_ctv_merge(&mut self, node: &'ast CTVMerge) {
+        walk_ctv_merge(self, node)
+    }
+    fn visit_ct_vertical_align_run(&mut self, node: &'ast CTVerticalAlignRun) {
+        walk_ct_vertical_align_run(self, node)
+    }
+    fn visit_ct_vertical_jc(&mut self, node: &'ast CTVerticalJc) {
+        walk_ct_vertical_jc(self, node)
+    }
+    fn visit_ct_view(&mut self, node: &'ast CTView) {
+        walk_ct_view(self, node)
+    }
+    fn visit_ct_web_settings(&mut self, node: &'ast CTWebSettings) {
+        walk_ct_web_settings(self, node)
+    }
+    fn visit_ct_write_protection(&mut self, node: &'ast CTWriteProtection) {
+        walk_ct_write_protection(self, node)
+    }
+    fn visit_ct_writing_style(&mut self, _node: &'ast CTWritingStyle) {}
+    fn visit_ct_zoom(&mut self, node: &'ast CTZoom) {
+        walk_ct_zoom(self, node)
+    }
+    fn visit_calendar_type(&mut self, _node: &'ast CalendarType) {}
+    fn visit_cell_content(&mut self, node: &'ast CellContent) {
+        walk_cell_content(self, node)
+    }
+    fn visit_cell_markup(&mut self, node: &'ast CellMarkup) {
+        walk_cell_markup(self, node)
+    }
+    fn visit_columns(&mut self, node: &'ast Columns) {
+        walk_columns(self, node)
+    }
+    fn visit_comment(&mut self, node: &'ast Comment) {
+        walk_comment(self, node)
+    }
+    fn visit_comments(&mut self, node: &'ast Comments) {
+        walk_comments(self, node)
+    }
+    fn visit_compatibility(&mut self, node: &'ast Compatibility) {
+        walk_compatibility(self, node)
+    }
+    fn visit_doc_type_element(&mut self, _node: &'ast DocTypeElement) {}
+    fn visit_document(&mut self, node: &'ast Document) {
+        walk_document(self, node)
+    }
+    fn visit_document_defaults(&mut self, node: &'ast DocumentDefaults) {
+        walk_document_defaults(self, node)
+    }
+    fn visit_document_grid(&mut self, node: &'ast DocumentGrid) {
+        walk_document_grid(self, node)
+    }
+    fn visit_eg_block_level_chunk_elts(&mut self, node: &'ast EGBlockLevelChunkElts) {
+        walk_eg_block_level_chunk_elts(self, node)
+    }
+    fn visit_eg_ftn_edn_num_props(&mut self, node: &'ast EGFtnEdnNumProps) {
+        walk_eg_ftn_edn_num_props(self, node)
+    }
+    fn visit_eg_math_content(&mut self, node: &'ast EGMathContent) {
+        walk_eg_math_content(self, node)
+    }
+    fn visit_eg_para_r_pr_track_changes(&mut self, node: &'ast EGParaRPrTrackChanges) {
+        walk_eg_para_r_pr_track_changes(self, node)
+    }
+    fn visit_egr_pr(&mut self, node: &'ast EGRPr) {
+        walk_egr_pr(self, node)
+    }
+    fn visit_egr_pr_base(&mut self, node: &'ast EGRPrBase) {
+        walk_egr_pr_base(self, node)
+    }
+    fn visit_egr_pr_content(&mut self, node: &'ast EGRPrContent) {
+        walk_egr_pr_content(self, node)
+    }
+    fn visit_eg_sect_pr_contents(&mut self, node: &'ast EGSectPrContents) {
+        walk_eg_sect_pr_contents(self, node)
+    }
+    fn visit_endnotes(&mut self, node: &'ast Endnotes) {
+        walk_endnotes(self, node)
+    }
+    fn visit_ff_name_element(&mut self, _node: &'ast FFNameElement) {}
+    fn visit_font(&mut self, node: &'ast Font) {
+        walk_font(self, node)
+    }
+    fn visit_fonts(&mut self, node: &'ast Fonts) {
+        walk_fonts(self, node)
+    }
+    fn visit_footnote_endnote(&mut self, node: &'ast FootnoteEndnote) {
+        walk_footnote_endnote(self, node)
+    }
+    fn visit_footnote_endnote_ref(&mut self, _node: &'ast FootnoteEndnoteRef) {}
+    fn visit_footnotes(&mut self, node: &'ast Footnotes) {
+        walk_footnotes(self, node)
+    }
+    fn visit_guid_element(&mut self, _node: &'ast GuidElement) {}
+    fn visit_header_footer(&mut self, node: &'ast HeaderFooter) {
+        walk_header_footer(self, node)
+    }
+    fn visit_header_footer_ref(&mut self, node: &'ast HeaderFooterRef) {
+        walk_header_footer_ref(self, node)
+    }
+    fn visit_header_footer_reference(&mut self, node: &'ast HeaderFooterReference) {
+        walk_header_footer_reference(self, node)
+    }
+    fn visit_hps_measure_element(&mut self, _node: &'ast HpsMeasureElement) {}
+    fn visit_hyperlink(&mut self, node: &'ast Hyperlink) {
+        walk_hyperlink(self, node)
+    }
+    fn visit_language_element(&mut self, _node: &'ast LanguageElement) {}
+    fn visit_latent_style_exception(&mut self, _node: &'ast LatentStyleException) {}
+    fn visit_latent_styles(&mut self, node: &'ast LatentStyles) {
+        walk_latent_styles(self, node)
+    }
+    fn visit_level(&mut self, node: &'ast Level) {
+        walk_level(self, node)
+    }
+    fn visit_long_hex_number_element(&mut self, _node: &'ast LongHexNumberElement) {}
+    fn visit_macro_name_element(&mut self, _node: &'ast MacroNameElement) {}
+    fn visit_mail_merge_data_type_element(&mut self, _node: &'ast MailMergeDataTypeElement) {}
+    fn visit_math_content(&mut self, node: &'ast MathContent) {
+        walk_math_content(self, node)
+    }
+    fn visit_math_run_properties(&mut self, node: &'ast MathRunProperties) {
+        walk_math_run_properties(self, node)
+    }
+    fn visit_numbering(&mut self, node: &'ast Numbering) {
+        walk_numbering(self, node)
+    }
+    fn visit_numbering_instance(&mut self, node: &'ast NumberingInstance) {
+        walk_numbering_instance(self, node)
+    }
+    fn visit_numbering_properties(&mut self, node: &'ast NumberingProperties) {
+        walk_numbering_properties(self, node)
+    }
+    fn visit_on_off_element(&mut self, _node: &'ast OnOffElement) {}
+    fn visit_page_margins(&mut self, _node: &'ast PageMargins) {}
+    fn visit_page_size(&mut self, node: &'ast PageSize) {
+        walk_page_size(self, node)
+    }
+    fn visit_panose_element(&mut self, _node: &'ast PanoseElement) {}
+    fn visit_paragraph(&mut self, node: &'ast Paragraph) {
+        walk_paragraph(self, node)
+    }
+    fn visit_paragraph_content(&mut self, node: &'ast ParagraphContent) {
+        walk_paragraph_content(self, node)
+    }
+    fn visit_paragraph_content_base(&mut self, node: &'ast ParagraphContentBase) {
+        walk_paragraph_content_base(self, node)
+    }
+    fn visit_paragraph_properties(&mut self, node: &'ast ParagraphProperties) {
+        walk_paragraph_properties(self, node)
+    }
+    fn visit_paragraph_properties_default(&mut self, node: &'ast ParagraphPropertiesDefault) {
+        walk_paragraph_properties_default(self, node)
+    }
+    fn visit_pixels_measure_element(&mut self, _node: &'ast PixelsMeasureElement) {}
+    fn visit_range_markup(&mut self, node: &'ast RangeMarkup) {
+        walk_range_markup(self, node)
+    }
+    fn visit_row_content(&mut self, node: &'ast RowContent) {
+        walk_row_content(self, node)
+    }
+    fn visit_ruby_content(&mut self, node: &'ast RubyContent) {
+        walk_ruby_content(self, node)
+    }
+    fn visit_run(&mut self, node: &'ast Run) {
+        walk_run(self, node)
+    }
+    fn visit_run_content(&mut self, node: &'ast RunContent) {
+        walk_run_content(self, node)
+    }
+    fn visit_run_content_base(&mut self, node: &'ast RunContentBase) {
+        walk_run_content_base(self, node)
+    }
+    fn visit_run_content_choice(&mut self, node: &'ast RunContentChoice) {
+        walk_run_content_choice(self, node)
+    }
+    fn visit_run_level_content(&mut self, node: &'ast RunLevelContent) {
+        walk_run_level_content(self, node)
+    }
+    fn visit_run_properties(&mut self, node: &'ast RunProperties) {
+        walk_run_properties(self, node)
+    }
+    fn visit_run_properties_default(&mut self, node: &'ast RunPropertiesDefault) {
+        walk_run_properties_default(self, node)
+    }
+    fn visit_st_alg_class(&mut self, _node: &'ast STAlgClass) {}
+    fn visit_st_alg_type(&mut self, _node: &'ast STAlgType) {}
+    fn visit_st_annotation_v_merge(&mut self, _node: &'ast STAnnotationVMerge) {}
+    fn visit_st_border(&mut self, _node: &'ast STBorder) {}
+    fn visit_st_br_clear(&mut self, _node: &'ast STBrClear) {}
+    fn visit_st_br_type(&mut self, _node: &'ast STBrType) {}
+    fn visit_st_caption_pos(&mut self, _node: &'ast STCaptionPos) {}
+    fn visit_st_chapter_sep(&mut self, _node: &'ast STChapterSep) {}
+    fn visit_st_character_spacing(&mut self, _node: &'ast STCharacterSpacing) {}
+    fn visit_st_combine_brackets(&mut self, _node: &'ast STCombineBrackets) {}
+    fn visit_st_conformance_class(&mut self, _node: &'ast STConformanceClass) {}
+    fn visit_st_crypt_prov(&mut self, _node: &'ast STCryptProv) {}
+    fn visit_st_direction(&mut self, _node: &'ast STDirection) {}
+    fn visit_st_displaced_by_custom_xml(&mut self, _node: &'ast STDisplacedByCustomXml) {}
+    fn visit_st_doc_grid(&mut self, _node: &'ast STDocGrid) {}
+    fn visit_st_doc_part_behavior(&mut self, _node: &'ast STDocPartBehavior) {}
+    fn visit_st_doc_part_gallery(&mut self, _node: &'ast STDocPartGallery) {}
+    fn visit_st_doc_part_type(&mut self, _node: &'ast STDocPartType) {}
+    fn visit_st_doc_protect(&mut self, _node: &'ast STDocProtect) {}
+    fn visit_st_drop_cap(&mut self, _node: &'ast STDropCap) {}
+    fn visit_st_ed_grp(&mut self, _node: &'ast STEdGrp) {}
+    fn visit_st_edn_pos(&mut self, _node: &'ast STEdnPos) {}
+    fn visit_st_em(&mut self, _node: &'ast STEm) {}
+    fn visit_stff_text_type(&mut self, _node: &'ast STFFTextType) {}
+    fn visit_st_fld_char_type(&mut self, _node: &'ast STFldCharType) {}
+    fn visit_st_font_family(&mut self, _node: &'ast STFontFamily) {}
+    fn visit_st_frame_layout(&mut self, _node: &'ast STFrameLayout) {}
+    fn visit_st_frame_scrollbar(&mut self, _node: &'ast STFrameScrollbar) {}
+    fn visit_st_ftn_edn(&mut self, _node: &'ast STFtnEdn) {}
+    fn visit_st_ftn_pos(&mut self, _node: &'ast STFtnPos) {}
+    fn visit_sth_anchor(&mut self, _node: &'ast STHAnchor) {}
+    fn visit_st_hdr_ftr(&mut self, _node: &'ast STHdrFtr) {}
+    fn visit_st_height_rule(&mut self, _node: &'ast STHeightRule) {}
+    fn visit_st_highlight_color(&mut self, _node: &'ast STHighlightColor) {}
+    fn visit_st_hint(&mut self, _node: &'ast STHint) {}
+    fn visit_st_info_text_type(&mut self, _node: &'ast STInfoTextType) {}
+    fn visit_st_jc(&mut self, _node: &'ast STJc) {}
+    fn visit_st_jc_table(&mut self, _node: &'ast STJcTable) {}
+    fn visit_st_level_suffix(&mut self, _node: &'ast STLevelSuffix) {}
+    fn visit_st_line_number_restart(&mut self, _node: &'ast STLineNumberRestart) {}
+    fn visit_st_line_spacing_rule(&mut self, _node: &'ast STLineSpacingRule) {}
+    fn visit_st_lock(&mut self, _node: &'ast STLock) {}
+    fn visit_st_mail_merge_dest(&mut self, _node: &'ast STMailMergeDest) {}
+    fn visit_st_mail_merge_doc_type(&mut self, _node: &'ast STMailMergeDocType) {}
+    fn visit_st_mail_merge_odso_fmd_field_type(
+        &mut self,
+        _node: &'ast STMailMergeOdsoFMDFieldType,
+    ) {
+    }
+    fn visit_st_mail_merge_source_type(&mut self, _node: &'ast STMailMergeSourceType) {}
+    fn visit_st_merge(&mut self, _node: &'ast STMerge) {}
+    fn visit_st_multi_level_type(&mut self, _node: &'ast STMultiLevelType) {}
+    fn visit_st_number_format(&mut self, _node: &'ast STNumberFormat) {}
+    fn visit_st_object_draw_aspect(&mut self, _node: &'ast STObjectDrawAspect) {}
+    fn visit_st_object_update_mode(&mut self, _node: &'ast STObjectUpdateMode) {}
+    fn visit_st_on_off1(&mut self, _node: &'ast STOnOff1) {}
+    fn visit_stp_tab_alignment(&mut self, _node: &'ast STPTabAlignment) {}
+    fn visit_stp_tab_leader(&mut self, _node: &'ast STPTabLeader) {}
+    fn visit_stp_tab_relative_to(&mut self, _node: &'ast STPTabRelativeTo) {}
+    fn visit_st_page_border_display(&mut self, _node: &'ast STPageBorderDisplay) {}
+    fn visit_st_page_border_offset(&mut self, _node: &'ast STPageBorderOffset) {}
+    fn visit_st_page_border_z_order(&mut self, _node: &'ast STPageBorderZOrder) {}
+    fn visit_st_page_orientation(&mut self, _node: &'ast STPageOrientation) {}
+    fn visit_st_pitch(&mut self, _node: &'ast STPitch) {}
+    fn visit_st_proof(&mut self, _node: &'ast STProof) {}
+    fn visit_st_proof_err(&mut self, _node: &'ast STProofErr) {}
+    fn visit_st_restart_number(&mut self, _node: &'ast STRestartNumber) {}
+    fn visit_st_ruby_align(&mut self, _node: &'ast STRubyAlign) {}
+    fn visit_st_sdt_date_mapping_type(&mut self, _node: &'ast STSdtDateMappingType) {}
+    fn visit_st_section_mark(&mut self, _node: &'ast STSectionMark) {}
+    fn visit_st_shd(&mut self, _node: &'ast STShd) {}
+    fn visit_st_style_sort(&mut self, _node: &'ast STStyleSort) {}
+    fn visit_st_style_type(&mut self, _node: &'ast STStyleType) {}
+    fn visit_st_tab_jc(&mut self, _node: &'ast STTabJc) {}
+    fn visit_st_tab_tlc(&mut self, _node: &'ast STTabTlc) {}
+    fn visit_st_target_screen_sz(&mut self, _node: &'ast STTargetScreenSz) {}
+    fn visit_st_tbl_layout_type(&mut self, _node: &'ast STTblLayoutType) {}
+    fn visit_st_tbl_overlap(&mut self, _node: &'ast STTblOverlap) {}
+    fn visit_st_tbl_style_override_type(&mut self, _node: &'ast STTblStyleOverrideType) {}
+    fn visit_st_tbl_width(&mut self, _node: &'ast STTblWidth) {}
+    fn visit_st_text_alignment(&mut self, _node: &'ast STTextAlignment) {}
+    fn visit_st_text_direction(&mut self, _node: &'ast STTextDirection) {}
+    fn visit_st_text_effect(&mut self, _node: &'ast STTextEffect) {}
+    fn visit_st_textbox_tight_wrap(&mut self, _node: &'ast STTextboxTightWrap) {}
+    fn visit_st_theme(&mut self, _node: &'ast STTheme) {}
+    fn visit_st_theme_color(&mut self, _node: &'ast STThemeColor) {}
+    fn visit_st_true_false_blank(&mut self, _node: &'ast STTrueFalseBlank) {}
+    fn visit_st_underline(&mut self, _node: &'ast STUnderline) {}
+    fn visit_stv_anchor(&mut self, _node: &'ast STVAnchor) {}
+    fn visit_st_vertical_align_run(&mut self, _node: &'ast STVerticalAlignRun) {}
+    fn visit_st_vertical_jc(&mut self, _node: &'ast STVerticalJc) {}
+    fn visit_st_view(&mut self, _node: &'ast STView) {}
+    fn visit_st_wml_color_scheme_index(&mut self, _node: &'ast STWmlColorSchemeIndex) {}
+    fn visit_st_wrap(&mut self, _node: &'ast STWrap) {}
+    fn visit_stx_align(&mut self, _node: &'ast STXAlign) {}
+    fn visit_sty_align(&mut self, _node: &'ast STYAlign) {}
+    fn visit_st_zoom(&mut self, _node: &'ast STZoom) {}
+    fn visit_section_properties(&mut self, node: &'ast SectionProperties) {
+        walk_section_properties(self, node)
+    }
+    fn visit_settings(&mut self, node: &'ast Settings) {
+        walk_settings(self, node)
+    }
+    fn visit_signed_hps_measure_element(&mut self, _node: &'ast SignedHpsMeasureElement) {}
+    fn visit_signed_twips_measure_element(&mut self, _node: &'ast SignedTwipsMeasureElement) {}
+    fn visit_style(&mut self, node: &'ast Style) {
+        walk_style(self, node)
+    }
+    fn visit_styles(&mut self, node: &'ast Styles) {
+        walk_styles(self, node)
+    }
+    fn visit_table(&mut self, node: &'ast Table) {
+        walk_table(self, node)
+    }
+    fn visit_table_cell(&mut self, node: &'ast TableCell) {
+        walk_table_cell(self, node)
+    }
+    fn visit_table_cell_properties(&mut self, node: &'ast TableCellProperties) {
+        walk_table_cell_properties(self, node)
+    }
+    fn visit_table_grid(&mut self, node: &'ast TableGrid) {
+        walk_table_grid(self, node)
+    }
+    fn visit_table_grid_column(&mut self, _node: &'ast TableGridColumn) {}
+    fn visit_table_properties(&mut self, node: &'ast TableProperties) {
+        walk_table_properties(self, node)
+    }
+    fn visit_table_row_properties(&mut self, node: &'ast TableRowProperties) {
+        walk_table_row_properties(self, node)
+    }
+    fn visit_table_style_properties(&mut self, node: &'ast TableStyleProperties) {
+        walk_table_style_properties(self, node)
+    }
+    fn visit_text(&mut self, node: &'ast Text) {
+        walk_text(self, node)
+    }
+    fn visit_text_scale_element(&mut self, _node: &'ast TextScaleElement) {}
+    fn visit_true_false(&mut self, _node: &'ast TrueFalse) {}
+    fn visit_twips_measure_element(&mut self, _node: &'ast TwipsMeasureElement) {}
+    fn visit_unsigned_decimal_number_element(&mut self, _node: &'ast UnsignedDecimalNumberElement) {
+    }
+    fn visit_wag_password(&mut self, _node: &'ast WAGPassword) {}
+    fn visit_wag_sect_pr_attributes(&mut self, _node: &'ast WAGSectPrAttributes) {}
+    fn visit_wag_transitional_password(&mut self, node: &'ast WAGTransitionalPassword) {
+        walk_wag_transitional_password(self, node)
+    }
+    fn visit_w_any_vml_office(&mut self, node: &'ast WAnyVmlOffice) {
+        walk_w_any_vml_office(self, node)
+    }
+    fn visit_w_any_vml_vml(&mut self, node: &'ast WAnyVmlVml) {
+        walk_w_any_vml_vml(self, node)
+    }
+    fn visit_raw_xml_node(&mut self, node: &'ast RawXmlNode) {
+        walk_raw_xml_node(self, node)
+    }
+    fn visit_raw_xml_element(&mut self, node: &'ast RawXmlElement) {
+        walk_raw_xml_element(self, node)
+    }
+}
+#[doc = r" Mutable traversal of the generated types."]
+#[doc = r""]
+#[doc = r" Every method defaults to visiting the node's children; override"]
+#[doc = r" the ones of interest and call the matching `walk_*_mut` function"]
+#[doc = r" to keep descending."]
+pub trait VisitMut {
+    fn visit_abstract_numbering_mut(&mut self, node: &mut AbstractNumbering) {
+        walk_abstract_numbering_mut(self, node)
+    }
+    fn visit_block_content_mut(&mut self, node: &mut BlockContent) {
+        walk_block_content_mut(self, node)
+    }
+    fn visit_block_content_choice_mut(&mut self, node: &mut BlockContentChoice) {
+        walk_block_content_choice_mut(self, node)
+    }
+    fn visit_body_mut(&mut self, node: &mut Body) {
+        walk_body_mut(self, node)
+    }
+    fn visit_bookmark_mut(&mut self, node: &mut Bookmark) {
+        walk_bookmark_mut(self, node)
+    }
+    fn visit_ct_alt_chunk_mut(&mut self, node: &mut CTAltChunk) {
+        walk_ct_alt_chunk_mut(self, node)
+    }
+    fn visit_ct_alt_chunk_pr_mut(&mut self, node: &mut CTAltChunkPr) {
+        walk_ct_alt_chunk_pr_mut(self, node)
+    }
+    fn visit_ct_attr_mut(&mut self, _node: &mut CTAttr) {}
+    fn visit_ct_auto_caption_mut(&mut self, _node: &mut CTAutoCaption) {}
+    fn visit_ct_auto_captions_mut(&mut self, node: &mut CTAutoCaptions) {
+        walk_ct_auto_captions_mut(self, node)
+    }
+    fn visit_ct_background_mut(&mut self, node: &mut CTBackground) {
+        walk_ct_background_mut(self, node)
+    }
+    fn visit_ct_base64_binary_mut(&mut self, _node: &mut CTBase64Binary) {}
+    fn visit_ct_bdo_content_run_mut(&mut self, node: &mut CTBdoContentRun) {
+        walk_ct_bdo_content_run_mut(self, node)
+    }
+    fn visit_ct_bookmark_range_mut(&mut self, node: &mut CTBookmarkRange) {
+        walk_ct_bookmark_range_mut(self, node)
+    }
+    fn visit_ct_border_mut(&mut self, node: &mut CTBorder) {
+        walk_ct_border_mut(self, node)
+    }
+    fn visit_ct_bottom_page_border_mut(&mut self, node: &mut CTBottomPageBorder) {
+        walk_ct_bottom_page_border_mut(self, node)
+    }
+    fn visit_ct_br_mut(&mut self, node: &mut CTBr) {
+        walk_ct_br_mut(self, node)
+    }
+    fn visit_ct_calendar_type_mut(&mut self, node: &mut CTCalendarType) {
+        walk_ct_calendar_type_mut(self, node)
+    }
+    fn visit_ct_caption_mut(&mut self, node: &mut CTCaption) {
+        walk_ct_caption_mut(self, node)
+    }
+    fn visit_ct_captions_mut(&mut self, node: &mut CTCaptions) {
+        walk_ct_captions_mut(self, node)
+    }
+    fn visit_ct_cell_merge_track_change_mut(&mut self, node: &mut CTCellMergeTrackChange) {
+        walk_ct_cell_merge_track_change_mut(self, node)
+    }
+    fn visit_ct_character_spacing_mut(&mut self, node: &mut CTCharacterSpacing) {
+        walk_ct_character_spacing_mut(self, node)
+    }
+    fn visit_ct_charset_mut(&mut self, _node: &mut CTCharset) {}
+    fn visit_ct_cnf_mut(&mut self, _node: &mut CTCnf) {}
+    fn visit_ct_color_mut(&mut self, node: &mut CTColor) {
+        walk_ct_color_mut(self, node)
+    }
+    fn visit_ct_color_scheme_mapping_mut(&mut self, node: &mut CTColorSchemeMapping) {
+        walk_ct_color_scheme_mapping_mut(self, node)
+    }
+    fn visit_ct_column_mut(&mut self, _node: &mut CTColumn) {}
+    fn visit_ct_compat_setting_mut(&mut self, _node: &mut CTCompatSetting) {}
+    fn visit_ct_control_mut(&mut self, _node: &mut CTControl) {}
+    fn visit_ct_custom_xml_block_mut(&mut self, node: &mut CTCustomXmlBlock) {
+        walk_ct_custom_xml_block_mut(self, node)
+    }
+    fn visit_ct_custom_xml_cell_mut(&mut self, node: &mut CTCustomXmlCell) {
+        walk_ct_custom_xml_cell_mut(self, node)
+    }
+    fn visit_ct_custom_xml_pr_mut(&mut self, node: &mut CTCustomXmlPr) {
+        walk_ct_custom_xml_pr_mut(self, node)
+    }
+    fn visit_ct_custom_xml_row_mut(&mut self, node: &mut CTCustomXmlRow) {
+        walk_ct_custom_xml_row_mut(self, node)
+    }
+    fn visit_ct_custom_xml_run_mut(&mut self, node: &mut CTCustomXmlRun) {
+        walk_ct_custom_xml_run_mut(self, node)
+    }
+    fn visit_ct_data_binding_mut(&mut self, _node: &mut CTDataBinding) {}
+    fn visit_ct_decimal_number_mut(&mut self, _node: &mut CTDecimalNumber) {}
+    fn visit_ct_decimal_number_or_precent_mut(&mut self, _node: &mut CTDecimalNumberOrPrecent) {}
+    fn visit_ct_dir_content_run_mut(&mut self, node: &mut CTDirContentRun) {
+        walk_ct_dir_content_run_mut(self, node)
+    }
+    fn visit_ct_div_mut(&mut self, node: &mut CTDiv) {
+        walk_ct_div_mut(self, node)
+    }
+    fn visit_ct_div_bdr_mut(&mut self, node: &mut CTDivBdr) {
+        walk_ct_div_bdr_mut(self, node)
+    }
+    fn visit_ct_divs_mut(&mut self, node: &mut CTDivs) {
+        walk_ct_divs_mut(self, node)
+    }
+    fn visit_ct_doc_part_mut(&mut self, node: &mut CTDocPart) {
+        walk_ct_doc_part_mut(self, node)
+    }
+    fn visit_ct_doc_part_behavior_mut(&mut self, node: &mut CTDocPartBehavior) {
+        walk_ct_doc_part_behavior_mut(self, node)
+    }
+    fn visit_ct_doc_part_behaviors_mut(&mut self, node: &mut CTDocPartBehaviors) {
+        walk_ct_doc_part_behaviors_mut(self, node)
+    }
+    fn visit_ct_doc_part_category_mut(&mut self, node: &mut CTDocPartCategory) {
+        walk_ct_doc_part_category_mut(self, node)
+    }
+    fn visit_ct_doc_part_gallery_mut(&mut self, node: &mut CTDocPartGallery) {
+        walk_ct_doc_part_gallery_mut(self, node)
+    }
+    fn visit_ct_doc_part_name_mut(&mut self, _node: &mut CTDocPartName) {}
+    fn visit_ct_doc_part_pr_mut(&mut self, node: &mut CTDocPartPr) {
+        walk_ct_doc_part_pr_mut(self, node)
+    }
+    fn visit_ct_doc_part_type_mut(&mut self, node: &mut CTDocPartType) {
+        walk_ct_doc_part_type_mut(self, node)
+    }
+    fn visit_ct_doc_part_types_mut(&mut self, node: &mut CTDocPartTypes) {
+        walk_ct_doc_part_types_mut(self, node)
+    }
+    fn visit_ct_doc_parts_mut(&mut self, node: &mut CTDocParts) {
+        walk_ct_doc_parts_mut(self, node)
+    }
+    fn visit_ct_doc_protect_mut(&mut self, node: &mut CTDocProtect) {
+        walk_ct_doc_protect_mut(self, node)
+    }
+    fn visit_ct_doc_rsids_mut(&mut self, node: &mut CTDocRsids) {
+        walk_ct_doc_rsids_mut(self, node)
+    }
+    fn visit_ct_doc_var_mut(&mut self, _node: &mut CTDocVar) {}
+    fn visit_ct_doc_vars_mut(&mut self, node: &mut CTDocVars) {
+        walk_ct_doc_vars_mut(self, node)
+    }
+    fn visit_ct_document_base_mut(&mut self, node: &mut CTDocumentBase) {
+        walk_ct_document_base_mut(self, node)
+    }
+    fn visit_ct_drawing_mut(&mut self, node: &mut CTDrawing) {
+        walk_ct_drawing_mut(self, node)
+    }
+    fn visit_ct_east_asian_layout_mut(&mut self, node: &mut CTEastAsianLayout) {
+        walk_ct_east_asian_layout_mut(self, node)
+    }
+    fn visit_ct_edn_doc_props_mut(&mut self, node: &mut CTEdnDocProps) {
+        walk_ct_edn_doc_props_mut(self, node)
+    }
+    fn visit_ct_edn_pos_mut(&mut self, node: &mut CTEdnPos) {
+        walk_ct_edn_pos_mut(self, node)
+    }
+    fn visit_ct_edn_props_mut(&mut self, node: &mut CTEdnProps) {
+        walk_ct_edn_props_mut(self, node)
+    }
+    fn visit_ct_em_mut(&mut self, node: &mut CTEm) {
+        walk_ct_em_mut(self, node)
+    }
+    fn visit_ct_empty_mut(&mut self, _node: &mut CTEmpty) {}
+    fn visit_ctff_check_box_mut(&mut self, node: &mut CTFFCheckBox) {
+        walk_ctff_check_box_mut(self, node)
+    }
+    fn visit_ctffdd_list_mut(&mut self, node: &mut CTFFDDList) {
+        walk_ctffdd_list_mut(self, node)
+    }
+    fn visit_ctff_data_mut(&mut self, node: &mut CTFFData) {
+        walk_ctff_data_mut(self, node)
+    }
+    fn visit_ctff_help_text_mut(&mut self, node: &mut CTFFHelpText) {
+        walk_ctff_help_text_mut(self, node)
+    }
+    fn visit_ctff_status_text_mut(&mut self, node: &mut CTFFStatusText) {
+        walk_ctff_status_text_mut(self, node)
+    }
+    fn visit_ctff_text_input_mut(&mut self, node: &mut CTFFTextInput) {
+        walk_ctff_text_input_mut(self, node)
+    }
+    fn visit_ctff_text_type_mut(&mut self, node: &mut CTFFTextType) {
+        walk_ctff_text_type_mut(self, node)
+    }
+    fn visit_ct_fit_text_mut(&mut self, _node: &mut CTFitText) {}
+    fn visit_ct_fld_char_mut(&mut self, node: &mut CTFldChar) {
+        walk_ct_fld_char_mut(self, node)
+    }
+    fn visit_ct_font_family_mut(&mut self, node: &mut CTFontFamily) {
+        walk_ct_font_family_mut(self, node)
+    }
+    fn visit_ct_font_rel_mut(&mut self, _node: &mut CTFontRel) {}
+    fn visit_ct_font_sig_mut(&mut self, _node: &mut CTFontSig) {}
+    fn visit_ct_fonts_list_mut(&mut self, node: &mut CTFontsList) {
+        walk_ct_fonts_list_mut(self, node)
+    }
+    fn visit_ct_frame_mut(&mut self, node: &mut CTFrame) {
+        walk_ct_frame_mut(self, node)
+    }
+    fn visit_ct_frame_layout_mut(&mut self, node: &mut CTFrameLayout) {
+        walk_ct_frame_layout_mut(self, node)
+    }
+    fn visit_ct_frame_pr_mut(&mut self, node: &mut CTFramePr) {
+        walk_ct_frame_pr_mut(self, node)
+    }
+    fn visit_ct_frame_scrollbar_mut(&mut self, node: &mut CTFrameScrollbar) {
+        walk_ct_frame_scrollbar_mut(self, node)
+    }
+    fn visit_ct_frameset_mut(&mut self, node: &mut CTFrameset) {
+        walk_ct_frameset_mut(self, node)
+    }
+    fn visit_ct_frameset_splitbar_mut(&mut self, node: &mut CTFramesetSplitbar) {
+        walk_ct_frameset_splitbar_mut(self, node)
+    }
+    fn visit_ct_ftn_doc_props_mut(&mut self, node: &mut CTFtnDocProps) {
+        walk_ct_ftn_doc_props_mut(self, node)
+    }
+    fn visit_ct_ftn_edn_sep_ref_mut(&mut self, _node: &mut CTFtnEdnSepRef) {}
+    fn visit_ct_ftn_pos_mut(&mut self, node: &mut CTFtnPos) {
+        walk_ct_ftn_pos_mut(self, node)
+    }
+    fn visit_ct_ftn_props_mut(&mut self, node: &mut CTFtnProps) {
+        walk_ct_ftn_props_mut(self, node)
+    }
+    fn visit_ct_glossary_document_mut(&mut self, node: &mut CTGlossaryDocument) {
+        walk_ct_glossary_document_mut(self, node)
+    }
+    fn visit_cth_merge_mut(&mut self, node: &mut CTHMerge) {
+        walk_cth_merge_mut(self, node)
+    }
+    fn visit_ct_headers_mut(&mut self, node: &mut CTHeaders) {
+        walk_ct_headers_mut(self, node)
+    }
+    fn visit_ct_height_mut(&mut self, node: &mut CTHeight) {
+        walk_ct_height_mut(self, node)
+    }
+    fn visit_ct_highlight_mut(&mut self, node: &mut CTHighlight) {
+        walk_ct_highlight_mut(self, node)
+    }
+    fn visit_ct_ind_mut(&mut self, _node: &mut CTInd) {}
+    fn visit_ct_jc_mut(&mut self, node: &mut CTJc) {
+        walk_ct_jc_mut(self, node)
+    }
+    fn visit_ct_jc_table_mut(&mut self, node: &mut CTJcTable) {
+        walk_ct_jc_table_mut(self, node)
+    }
+    fn visit_ct_kinsoku_mut(&mut self, _node: &mut CTKinsoku) {}
+    fn visit_ct_lang_mut(&mut self, _node: &mut CTLang) {}
+    fn visit_ct_level_suffix_mut(&mut self, node: &mut CTLevelSuffix) {
+        walk_ct_level_suffix_mut(self, node)
+    }
+    fn visit_ct_level_text_mut(&mut self, _node: &mut CTLevelText) {}
+    fn visit_ct_line_number_mut(&mut self, node: &mut CTLineNumber) {
+        walk_ct_line_number_mut(self, node)
+    }
+    fn visit_ct_lock_mut(&mut self, node: &mut CTLock) {
+        walk_ct_lock_mut(self, node)
+    }
+    fn visit_ct_lvl_legacy_mut(&mut self, _node: &mut CTLvlLegacy) {}
+    fn visit_ct_mail_merge_mut(&mut self, node: &mut CTMailMerge) {
+        walk_ct_mail_merge_mut(self, node)
+    }
+    fn visit_ct_mail_merge_dest_mut(&mut self, node: &mut CTMailMergeDest) {
+        walk_ct_mail_merge_dest_mut(self, node)
+    }
+    fn visit_ct_mail_merge_doc_type_mut(&mut self, node: &mut CTMailMergeDocType) {
+        walk_ct_mail_merge_doc_type_mut(self, node)
+    }
+    fn visit_ct_mail_merge_odso_fmd_field_type_mut(
+        &mut self,
+        node: &mut CTMailMergeOdsoFMDFieldType,
+    ) {
+        walk_ct_mail_merge_odso_fmd_field_type_mut(self, node)
+    }
+    fn visit_ct_mail_merge_source_type_mut(&mut self, node: &mut CTMailMergeSourceType) {
+        walk_ct_mail_merge_source_type_mut(self, node)
+    }
+    fn visit_ct_markup_mut(&mut self, _node: &mut CTMarkup) {}
+    fn visit_ct_markup_range_mut(&mut self, node: &mut CTMarkupRange) {
+        walk_ct_markup_range_mut(self, node)
+    }
+    fn visit_ct_math_ctrl_del_mut(&mut self, node: &mut CTMathCtrlDel) {
+        walk_ct_math_ctrl_del_mut(self, node)
+    }
+    fn visit_ct_math_ctrl_ins_mut(&mut self, node: &mut CTMathCtrlIns) {
+        walk_ct_math_ctrl_ins_mut(self, node)
+    }
+    fn visit_ct_move_bookmark_mut(&mut self, node: &mut CTMoveBookmark) {
+        walk_ct_move_bookmark_mut(self, node)
+    }
+    fn visit_ct_multi_level_type_mut(&mut self, node: &mut CTMultiLevelType) {
+        walk_ct_multi_level_type_mut(self, node)
+    }
+    fn visit_ct_num_fmt_mut(&mut self, node: &mut CTNumFmt) {
+        walk_ct_num_fmt_mut(self, node)
+    }
+    fn visit_ct_num_lvl_mut(&mut self, node: &mut CTNumLvl) {
+        walk_ct_num_lvl_mut(self, node)
+    }
+    fn visit_ct_num_pic_bullet_mut(&mut self, node: &mut CTNumPicBullet) {
+        walk_ct_num_pic_bullet_mut(self, node)
+    }
+    fn visit_ct_num_restart_mut(&mut self, node: &mut CTNumRestart) {
+        walk_ct_num_restart_mut(self, node)
+    }
+    fn visit_ct_object_mut(&mut self, node: &mut CTObject) {
+        walk_ct_object_mut(self, node)
+    }
+    fn visit_ct_object_embed_mut(&mut self, node: &mut CTObjectEmbed) {
+        walk_ct_object_embed_mut(self, node)
+    }
+    fn visit_ct_object_link_mut(&mut self, node: &mut CTObjectLink) {
+        walk_ct_object_link_mut(self, node)
+    }
+    fn visit_ct_odso_mut(&mut self, node: &mut CTOdso) {
+        walk_ct_odso_mut(self, node)
+    }
+    fn visit_ct_odso_field_map_data_mut(&mut self, node: &mut CTOdsoFieldMapData) {
+        walk_ct_odso_field_map_data_mut(self, node)
+    }
+    fn visit_ct_optimize_for_browser_mut(&mut self, _node: &mut CTOptimizeForBrowser) {}
+    fn visit_ctp_bdr_mut(&mut self, node: &mut CTPBdr) {
+        walk_ctp_bdr_mut(self, node)
+    }
+    fn visit_ctp_pr_base_mut(&mut self, node: &mut CTPPrBase) {
+        walk_ctp_pr_base_mut(self, node)
+    }
+    fn visit_ctp_pr_change_mut(&mut self, node: &mut CTPPrChange) {
+        walk_ctp_pr_change_mut(self, node)
+    }
+    fn visit_ctp_pr_general_mut(&mut self, node: &mut CTPPrGeneral) {
+        walk_ctp_pr_general_mut(self, node)
+    }
+    fn visit_ctp_tab_mut(&mut self, node: &mut CTPTab) {
+        walk_ctp_tab_mut(self, node)
+    }
+    fn visit_ct_page_border_mut(&mut self, node: &mut CTPageBorder) {
+        walk_ct_page_border_mut(self, node)
+    }
+    fn visit_ct_page_borders_mut(&mut self, node: &mut CTPageBorders) {
+        walk_ct_page_borders_mut(self, node)
+    }
+    fn visit_ct_page_number_mut(&mut self, node: &mut CTPageNumber) {
+        walk_ct_page_number_mut(self, node)
+    }
+    fn visit_ct_paper_source_mut(&mut self, _node: &mut CTPaperSource) {}
+    fn visit_ct_para_r_pr_mut(&mut self, node: &mut CTParaRPr) {
+        walk_ct_para_r_pr_mut(self, node)
+    }
+    fn visit_ct_para_r_pr_change_mut(&mut self, node: &mut CTParaRPrChange) {
+        walk_ct_para_r_pr_change_mut(self, node)
+    }
+    fn visit_ct_para_r_pr_original_mut(&mut self, node: &mut CTParaRPrOriginal) {
+        walk_ct_para_r_pr_original_mut(self, node)
+    }
+    fn visit_ct_perm_mut(&mut self, node: &mut CTPerm) {
+        walk_ct_perm_mut(self, node)
+    }
+    fn visit_ct_perm_start_mut(&mut self, node: &mut CTPermStart) {
+        walk_ct_perm_start_mut(self, node)
+    }
+    fn visit_ct_picture_mut(&mut self, node: &mut CTPicture) {
+        walk_ct_picture_mut(self, node)
+    }
+    fn visit_ct_pitch_mut(&mut self, node: &mut CTPitch) {
+        walk_ct_pitch_mut(self, node)
+    }
+    fn visit_ct_proof_mut(&mut self, node: &mut CTProof) {
+        walk_ct_proof_mut(self, node)
+    }
+    fn visit_ct_proof_err_mut(&mut self, node: &mut CTProofErr) {
+        walk_ct_proof_err_mut(self, node)
+    }
+    fn visit_ctr_pr_change_mut(&mut self, node: &mut CTRPrChange) {
+        walk_ctr_pr_change_mut(self, node)
+    }
+    fn visit_ctr_pr_original_mut(&mut self, node: &mut CTRPrOriginal) {
+        walk_ctr_pr_original_mut(self, node)
+    }
+    fn visit_ct_reading_mode_ink_lock_down_mut(&mut self, _node: &mut CTReadingModeInkLockDown) {}
+    fn visit_ct_recipient_data_mut(&mut self, node: &mut CTRecipientData) {
+        walk_ct_recipient_data_mut(self, node)
+    }
+    fn visit_ct_recipients_mut(&mut self, node: &mut CTRecipients) {
+        walk_ct_recipients_mut(self, node)
+    }
+    fn visit_ct_rel_mut(&mut self, _node: &mut CTRel) {}
+    fn visit_ct_row_mut(&mut self, node: &mut CTRow) {
+        walk_ct_row_mut(self, node)
+    }
+    fn visit_ct_ruby_mut(&mut self, node: &mut CTRuby) {
+        walk_ct_ruby_mut(self, node)
+    }
+    fn visit_ct_ruby_align_mut(&mut self, node: &mut CTRubyAlign) {
+        walk_ct_ruby_align_mut(self, node)
+    }
+    fn visit_ct_ruby_content_mut(&mut self, node: &mut CTRubyContent) {
+        walk_ct_ruby_content_mut(self, node)
+    }
+    fn visit_ct_ruby_pr_mut(&mut self, node: &mut CTRubyPr) {
+        walk_ct_ruby_pr_mut(self, node)
+    }
+    fn visit_ct_run_track_change_mut(&mut self, node: &mut CTRunTrackChange) {
+        walk_ct_run_track_change_mut(self, node)
+    }
+    fn visit_ct_save_through_xslt_mut(&mut self, _node: &mut CTSaveThroughXslt) {}
+    fn visit_ct_sdt_block_mut(&mut self, node: &mut CTSdtBlock) {
+        walk_ct_sdt_block_mut(self, node)
+    }
+    fn visit_ct_sdt_cell_mut(&mut self, node: &mut CTSdtCell) {
+        walk_ct_sdt_cell_mut(self, node)
+    }
+    fn visit_ct_sdt_combo_box_mut(&mut self, node: &mut CTSdtComboBox) {
+        walk_ct_sdt_combo_box_mut(self, node)
+    }
+    fn visit_ct_sdt_content_block_mut(&mut self, node: &mut CTSdtContentBlock) {
+        walk_ct_sdt_content_block_mut(self, node)
+    }
+    fn visit_ct_sdt_content_cell_mut(&mut self, node: &mut CTSdtContentCell) {
+        walk_ct_sdt_content_cell_mut(self, node)
+    }
+    fn visit_ct_sdt_content_row_mut(&mut self, node: &mut CTSdtContentRow) {
+        walk_ct_sdt_content_row_mut(self, node)
+    }
+    fn visit_ct_sdt_content_run_mut(&mut self, node: &mut CTSdtContentRun) {
+        walk_ct_sdt_content_run_mut(self, node)
+    }
+    fn visit_ct_sdt_date_mut(&mut self, node: &mut CTSdtDate) {
+        walk_ct_sdt_date_mut(self, node)
+    }
+    fn visit_ct_sdt_date_mapping_type_mut(&mut self, node: &mut CTSdtDateMappingType) {
+        walk_ct_sdt_date_mapping_type_mut(self, node)
+    }
+    fn visit_ct_sdt_doc_part_mut(&mut self, node: &mut CTSdtDocPart) {
+        walk_ct_sdt_doc_part_mut(self, node)
+    }
+    fn visit_ct_sdt_drop_down_list_mut(&mut self, node: &mut CTSdtDropDownList) {
+        walk_ct_sdt_drop_down_list_mut(self, node)
+    }
+    fn visit_ct_sdt_end_pr_mut(&mut self, node: &mut CTSdtEndPr) {
+        walk_ct_sdt_end_pr_mut(self, node)
+    }
+    fn visit_ct_sdt_list_item_mut(&mut self, _node: &mut CTSdtListItem) {}
+    fn visit_ct_sdt_pr_mut(&mut self, node: &mut CTSdtPr) {
+        walk_ct_sdt_pr_mut(self, node)
+    }
+    fn visit_ct_sdt_row_mut(&mut self, node: &mut CTSdtRow) {
+        walk_ct_sdt_row_mut(self, node)
+    }
+    fn visit_ct_sdt_run_mut(&mut self, node: &mut CTSdtRun) {
+        walk_ct_sdt_run_mut(self, node)
+    }
+    fn visit_ct_sdt_text_mut(&mut self, _node: &mut CTSdtText) {}
+    fn visit_ct_sect_pr_base_mut(&mut self, node: &mut CTSectPrBase) {
+        walk_ct_sect_pr_base_mut(self, node)
+    }
+    fn visit_ct_sect_pr_change_mut(&mut self, node: &mut CTSectPrChange) {
+        walk_ct_sect_pr_change_mut(self, node)
+    }
+    fn visit_ct_sect_type_mut(&mut self, node: &mut CTSectType) {
+        walk_ct_sect_type_mut(self, node)
+    }
+    fn visit_ct_shape_defaults_mut(&mut self, node: &mut CTShapeDefaults) {
+        walk_ct_shape_defaults_mut(self, node)
+    }
+    fn visit_ct_shd_mut(&mut self, node: &mut CTShd) {
+        walk_ct_shd_mut(self, node)
+    }
+    fn visit_ct_simple_field_mut(&mut self, node: &mut CTSimpleField) {
+        walk_ct_simple_field_mut(self, node)
+    }
+    fn visit_ct_smart_tag_pr_mut(&mut self, node: &mut CTSmartTagPr) {
+        walk_ct_smart_tag_pr_mut(self, node)
+    }
+    fn visit_ct_smart_tag_run_mut(&mut self, node: &mut CTSmartTagRun) {
+        walk_ct_smart_tag_run_mut(self, node)
+    }
+    fn visit_ct_smart_tag_type_mut(&mut self, _node: &mut CTSmartTagType) {}
+    fn visit_ct_spacing_mut(&mut self, node: &mut CTSpacing) {
+        walk_ct_spacing_mut(self, node)
+    }
+    fn visit_ct_string_mut(&mut self, _node: &mut CTString) {}
+    fn visit_ct_style_pane_filter_mut(&mut self, _node: &mut CTStylePaneFilter) {}
+    fn visit_ct_style_sort_mut(&mut self, node: &mut CTStyleSort) {
+        walk_ct_style_sort_mut(self, node)
+    }
+    fn visit_ct_sym_mut(&mut self, _node: &mut CTSym) {}
+    fn visit_ct_tab_stop_mut(&mut self, node: &mut CTTabStop) {
+        walk_ct_tab_stop_mut(self, node)
+    }
+    fn visit_ct_tabs_mut(&mut self, node: &mut CTTabs) {
+        walk_ct_tabs_mut(self, node)
+    }
+    fn visit_ct_target_screen_sz_mut(&mut self, node: &mut CTTargetScreenSz) {
+        walk_ct_target_screen_sz_mut(self, node)
+    }
+    fn visit_ct_tbl_borders_mut(&mut self, node: &mut CTTblBorders) {
+        walk_ct_tbl_borders_mut(self, node)
+    }
+    fn visit_ct_tbl_cell_mar_mut(&mut self, node: &mut CTTblCellMar) {
+        walk_ct_tbl_cell_mar_mut(self, node)
+    }
+    fn visit_ct_tbl_grid_base_mut(&mut self, node: &mut CTTblGridBase) {
+        walk_ct_tbl_grid_base_mut(self, node)
+    }
+    fn visit_ct_tbl_grid_change_mut(&mut self, node: &mut CTTblGridChange) {
+        walk_ct_tbl_grid_change_mut(self, node)
+    }
+    fn visit_ct_tbl_layout_type_mut(&mut self, node: &mut CTTblLayoutType) {
+        walk_ct_tbl_layout_type_mut(self, node)
+    }
+    fn visit_ct_tbl_look_mut(&mut self, _node: &mut CTTblLook) {}
+    fn visit_ct_tbl_overlap_mut(&mut self, node: &mut CTTblOverlap) {
+        walk_ct_tbl_overlap_mut(self, node)
+    }
+    fn visit_ct_tbl_p_pr_mut(&mut self, node: &mut CTTblPPr) {
+        walk_ct_tbl_p_pr_mut(self, node)
+    }
+    fn visit_ct_tbl_pr_base_mut(&mut self, node: &mut CTTblPrBase) {
+        walk_ct_tbl_pr_base_mut(self, node)
+    }
+    fn visit_ct_tbl_pr_change_mut(&mut self, node: &mut CTTblPrChange) {
+        walk_ct_tbl_pr_change_mut(self, node)
+    }
+    fn visit_ct_tbl_pr_ex_mut(&mut self, node: &mut CTTblPrEx) {
+        walk_ct_tbl_pr_ex_mut(self, node)
+    }
+    fn visit_ct_tbl_pr_ex_base_mut(&mut self, node: &mut CTTblPrExBase) {
+        walk_ct_tbl_pr_ex_base_mut(self, node)
+    }
+    fn visit_ct_tbl_pr_ex_change_mut(&mut self, node: &mut CTTblPrExChange) {
+        walk_ct_tbl_pr_ex_change_mut(self, node)
+    }
+    fn visit_ct_tbl_width_mut(&mut self, node: &mut CTTblWidth) {
+        walk_ct_tbl_width_mut(self, node)
+    }
+    fn visit_ct_tc_borders_mut(&mut self, node: &mut CTTcBorders) {
+        walk_ct_tc_borders_mut(self, node)
+    }
+    fn visit_ct_tc_mar_mut(&mut self, node: &mut CTTcMar) {
+        walk_ct_tc_mar_mut(self, node)
+    }
+    fn visit_ct_tc_pr_base_mut(&mut self, node: &mut CTTcPrBase) {
+        walk_ct_tc_pr_base_mut(self, node)
+    }
+    fn visit_ct_tc_pr_change_mut(&mut self, node: &mut CTTcPrChange) {
+        walk_ct_tc_pr_change_mut(self, node)
+    }
+    fn visit_ct_tc_pr_inner_mut(&mut self, node: &mut CTTcPrInner) {
+        walk_ct_tc_pr_inner_mut(self, node)
+    }
+    fn visit_ct_text_alignment_mut(&mut self, node: &mut CTTextAlignment) {
+        walk_ct_text_alignment_mut(self, node)
+    }
+    fn visit_ct_text_direction_mut(&mut self, node: &mut CTTextDirection) {
+        walk_ct_text_direction_mut(self, node)
+    }
+    fn visit_ct_text_effect_mut(&mut self, node: &mut CTTextEffect) {
+        walk_ct_text_effect_mut(self, node)
+    }
+    fn visit_ct_textbox_tight_wrap_mut(&mut self, node: &mut CTTextboxTightWrap) {
+        walk_ct_textbox_tight_wrap_mut(self, node)
+    }
+    fn visit_ct_top_page_border_mut(&mut self, node: &mut CTTopPageBorder) {
+        walk_ct_top_page_border_mut(self, node)
+    }
+    fn visit_ct_tr_pr_base_mut(&mut self, node: &mut CTTrPrBase) {
+        walk_ct_tr_pr_base_mut(self, node)
+    }
+    fn visit_ct_tr_pr_change_mut(&mut self, node: &mut CTTrPrChange) {
+        walk_ct_tr_pr_change_mut(self, node)
+    }
+    fn visit_ct_track_change_mut(&mut self, _node: &mut CTTrackChange) {}
+    fn visit_ct_track_change_numbering_mut(&mut self, _node: &mut CTTrackChangeNumbering) {}
+    fn visit_ct_track_change_range_mut(&mut self, node: &mut CTTrackChangeRange) {
+        walk_ct_track_change_range_mut(self, node)
+    }
+    fn visit_ct_track_changes_view_mut(&mut self, _node: &mut CTTrackChangesView) {}
+    fn visit_ct_txbx_content_mut(&mut self, node: &mut CTTxbxContent) {
+        walk_ct_txbx_content_mut(self, node)
+    }
+    fn visit_ct_underline_mut(&mut self, node: &mut CTUnderline) {
+        walk_ct_underline_mut(self, node)
+    }
+    fn visit_ctv_merge_mut(&mut self, node: &mut CTVMerge) {
+        walk_ctv_merge_mut(self, node)
+    }
+    fn visit_ct_vertical_align_run_mut(&mut self, node: &mut CTVerticalAlignRun) {
+        walk_ct_vertical_align_run_mut(self, node)
+    }
+    fn visit_ct_vertical_jc_mut(&mut self, node: &mut CTVerticalJc) {
+        walk_ct_vertical_jc_mut(self, node)
+    }
+    fn visit_ct_view_mut(&mut self, node: &mut CTView) {
+        walk_ct_view_mut(self, node)
+    }
+    fn visit_ct_web_settings_mut(&mut self, node: &mut CTWebSettings) {
+        walk_ct_web_settings_mut(self, node)
+    }
+    fn visit_ct_write_protection_mut(&mut self, node: &mut CTWriteProtection) {
+        walk_ct_write_protection_mut(self, node)
+    }
+    fn visit_ct_writing_style_mut(&mut self, _node: &mut CTWritingStyle) {}
+    fn visit_ct_zoom_mut(&mut self, node: &mut CTZoom) {
+        walk_ct_zoom_mut(self, node)
+    }
+    fn visit_calendar_type_mut(&mut self, _node: &mut CalendarType) {}
+    fn visit_cell_content_mut(&mut self, node: &mut CellContent) {
+        walk_cell_content_mut(self, node)
+    }
+    fn visit_cell_markup_mut(&mut self, node: &mut CellMarkup) {
+        walk_cell_markup_mut(self, node)
+    }
+    fn visit_columns_mut(&mut self, node: &mut Columns) {
+        walk_columns_mut(self, node)
+    }
+    fn visit_comment_mut(&mut self, node: &mut Comment) {
+        walk_comment_mut(self, node)
+    }
+    fn visit_comments_mut(&mut self, node: &mut Comments) {
+        walk_comments_mut(self, node)
+    }
+    fn visit_compatibility_mut(&mut self, node: &mut Compatibility) {
+        walk_compatibility_mut(self, node)
+    }
+    fn visit_doc_type_element_mut(&mut self, _node: &mut DocTypeElement) {}
+    fn visit_document_mut(&mut self, node: &mut Document) {
+        walk_document_mut(self, node)
+    }
+    fn visit_document_defaults_mut(&mut self, node: &mut DocumentDefaults) {
+        walk_document_defaults_mut(self, node)
+    }
+    fn visit_document_grid_mut(&mut self, node: &mut DocumentGrid) {
+        walk_document_grid_mut(self, node)
+    }
+    fn visit_eg_block_level_chunk_elts_mut(&mut self, node: &mut EGBlockLevelChunkElts) {
+        walk_eg_block_level_chunk_elts_mut(self, node)
+    }
+    fn visit_eg_ftn_edn_num_props_mut(&mut self, node: &mut EGFtnEdnNumProps) {
+        walk_eg_ftn_edn_num_props_mut(self, node)
+    }
+    fn visit_eg_math_content_mut(&mut self, node: &mut EGMathContent) {
+        walk_eg_math_content_mut(self, node)
+    }
+    fn visit_eg_para_r_pr_track_changes_mut(&mut self, node: &mut EGParaRPrTrackChanges) {
+        walk_eg_para_r_pr_track_changes_mut(self, node)
+    }
+    fn visit_egr_pr_mut(&mut self, node: &mut EGRPr) {
+        walk_egr_pr_mut(self, node)
+    }
+    fn visit_egr_pr_base_mut(&mut self, node: &mut EGRPrBase) {
+        walk_egr_pr_base_mut(self, node)
+    }
+    fn visit_egr_pr_content_mut(&mut self, node: &mut EGRPrContent) {
+        walk_egr_pr_content_mut(self, node)
+    }
+    fn visit_eg_sect_pr_contents_mut(&mut self, node: &mut EGSectPrContents) {
+        walk_eg_sect_pr_contents_mut(self, node)
+    }
+    fn visit_endnotes_mut(&mut self, node: &mut Endnotes) {
+        walk_endnotes_mut(self, node)
+    }
+    fn visit_ff_name_element_mut(&mut self, _node: &mut FFNameElement) {}
+    fn visit_font_mut(&mut self, node: &mut Font) {
+        walk_font_mut(self, node)
+    }
+    fn visit_fonts_mut(&mut self, node: &mut Fonts) {
+        walk_fonts_mut(self, node)
+    }
+    fn visit_footnote_endnote_mut(&mut self, node: &mut FootnoteEndnote) {
+        walk_footnote_endnote_mut(self, node)
+    }
+    fn visit_footnote_endnote_ref_mut(&mut self, _node: &mut FootnoteEndnoteRef) {}
+    fn visit_footnotes_mut(&mut self, node: &mut Footnotes) {
+        walk_footnotes_mut(self, node)
+    }
+    fn visit_guid_element_mut(&mut self, _node: &mut GuidElement) {}
+    fn visit_header_footer_mut(&mut self, node: &mut HeaderFooter) {
+        walk_header_footer_mut(self, node)
+    }
+    fn visit_header_footer_ref_mut(&mut self, node: &mut HeaderFooterRef) {
+        walk_header_footer_ref_mut(self, node)
+    }
+    fn visit_header_footer_reference_mut(&mut self, node: &mut HeaderFooterReference) {
+        walk_header_footer_reference_mut(self, node)
+    }
+    fn visit_hps_measure_element_mut(&mut self, _node: &mut HpsMeasureElement) {}
+    fn visit_hyperlink_mut(&mut self, node: &mut Hyperlink) {
+        walk_hyperlink_mut(self, node)
+    }
+    fn visit_language_element_mut(&mut self, _node: &mut LanguageElement) {}
+    fn visit_latent_style_exception_mut(&mut self, _node: &mut LatentStyleException) {}
+    fn visit_latent_styles_mut(&mut self, node: &mut LatentStyles) {
+        walk_latent_styles_mut(self, node)
+    }
+    fn visit_level_mut(&mut self, node: &mut Level) {
+        walk_level_mut(self, node)
+    }
+    fn visit_long_hex_number_element_mut(&mut self, _node: &mut LongHexNumberElement) {}
+    fn visit_macro_name_element_mut(&mut self, _node: &mut MacroNameElement) {}
+    fn visit_mail_merge_data_type_element_mut(&mut self, _node: &mut MailMergeDataTypeElement) {}
+    fn visit_math_content_mut(&mut self, node: &mut MathContent) {
+        walk_math_content_mut(self, node)
+    }
+    fn visit_math_run_properties_mut(&mut self, node: &mut MathRunProperties) {
+        walk_math_run_properties_mut(self, node)
+    }
+    fn visit_numbering_mut(&mut self, node: &mut Numbering) {
+        walk_numbering_mut(self, node)
+    }
+    fn visit_numbering_instance_mut(&mut self, node: &mut NumberingInstance) {
+        walk_numbering_instance_mut(self, node)
+    }
+    fn visit_numbering_properties_mut(&mut self, node: &mut NumberingProperties) {
+        walk_numbering_properties_mut(self, node)
+    }
+    fn visit_on_off_element_mut(&mut self, _node: &mut OnOffElement) {}
+    fn visit_page_margins_mut(&mut self, _node: &mut PageMargins) {}
+    fn visit_page_size_mut(&mut self, node: &mut PageSize) {
+        walk_page_size_mut(self, node)
+    }
+    fn visit_panose_element_mut(&mut self, _node: &mut PanoseElement) {}
+    fn visit_paragraph_mut(&mut self, node: &mut Paragraph) {
+        walk_paragraph_mut(self, node)
+    }
+    fn visit_paragraph_content_mut(&mut self, node: &mut ParagraphContent) {
+        walk_paragraph_content_mut(self, node)
+    }
+    fn visit_paragraph_content_base_mut(&mut self, node: &mut ParagraphContentBase) {
+        walk_paragraph_content_base_mut(self, node)
+    }
+    fn visit_paragraph_properties_mut(&mut self, node: &mut ParagraphProperties) {
+        walk_paragraph_properties_mut(self, node)
+    }
+    fn visit_paragraph_properties_default_mut(&mut self, node: &mut ParagraphPropertiesDefault) {
+        walk_paragraph_properties_default_mut(self, node)
+    }
+    fn visit_pixels_measure_element_mut(&mut self, _node: &mut PixelsMeasureElement) {}
+    fn visit_range_markup_mut(&mut self, node: &mut RangeMarkup) {
+        walk_range_markup_mut(self, node)
+    }
+    fn visit_row_content_mut(&mut self, node: &mut RowContent) {
+        walk_row_content_mut(self, node)
+    }
+    fn visit_ruby_content_mut(&mut self, node: &mut RubyContent) {
+        walk_ruby_content_mut(self, node)
+    }
+    fn visit_run_mut(&mut self, node: &mut Run) {
+        walk_run_mut(self, node)
+    }
+    fn visit_run_content_mut(&mut self, node: &mut RunContent) {
+        walk_run_content_mut(self, node)
+    }
+    fn visit_run_content_base_mut(&mut self, node: &mut RunContentBase) {
+        walk_run_content_base_mut(self, node)
+    }
+    fn visit_run_content_choice_mut(&mut self, node: &mut RunContentChoice) {
+        walk_run_content_choice_mut(self, node)
+    }
+    fn visit_run_level_content_mut(&mut self, node: &mut RunLevelContent) {
+        walk_run_level_content_mut(self, node)
+    }
+    fn visit_run_properties_mut(&mut self, node: &mut RunProperties) {
+        walk_run_properties_mut(self, node)
+    }
+    fn visit_run_properties_default_mut(&mut self, node: &mut RunPropertiesDefault) {
+        walk_run_properties_default_mut(self, node)
+    }
+    fn visit_st_alg_class_mut(&mut self, _node: &mut STAlgClass) {}
+    fn visit_st_alg_type_mut(&mut self, _node: &mut STAlgType) {}
+    fn visit_st_annotation_v_merge_mut(&mut self, _node: &mut STAnnotationVMerge) {}
+    fn visit_st_border_mut(&mut self, _node: &mut STBorder) {}
+    fn visit_st_br_clear_mut(&mut self, _node: &mut STBrClear) {}
+    fn visit_st_br_type_mut(&mut self, _node: &mut STBrType) {}
+    fn visit_st_caption_pos_mut(&mut self, _node: &mut STCaptionPos) {}
+    fn visit_st_chapter_sep_mut(&mut self, _node: &mut STChapterSep) {}
+    fn visit_st_character_spacing_mut(&mut self, _node: &mut STCharacterSpacing) {}
+    fn visit_st_combine_brackets_mut(&mut self, _node: &mut STCombineBrackets) {}
+    fn visit_st_conformance_class_mut(&mut self, _node: &mut STConformanceClass) {}
+    fn visit_st_crypt_prov_mut(&mut self, _node: &mut STCryptProv) {}
+    fn visit_st_direction_mut(&mut self, _node: &mut STDirection) {}
+    fn visit_st_displaced_by_custom_xml_mut(&mut self, _node: &mut STDisplacedByCustomXml) {}
+    fn visit_st_doc_grid_mut(&mut self, _node: &mut STDocGrid) {}
+    fn visit_st_doc_part_behavior_mut(&mut self, _node: &mut STDocPartBehavior) {}
+    fn visit_st_doc_part_gallery_mut(&mut self, _node: &mut STDocPartGallery) {}
+    fn visit_st_doc_part_type_mut(&mut self, _node: &mut STDocPartType) {}
+    fn visit_st_doc_protect_mut(&mut self, _node: &mut STDocProtect) {}
+    fn visit_st_drop_cap_mut(&mut self, _node: &mut STDropCap) {}
+    fn visit_st_ed_grp_mut(&mut self, _node: &mut STEdGrp) {}
+    fn visit_st_edn_pos_mut(&mut self, _node: &mut STEdnPos) {}
+    fn visit_st_em_mut(&mut self, _node: &mut STEm) {}
+    fn visit_stff_text_type_mut(&mut self, _node: &mut STFFTextType) {}
+    fn visit_st_fld_char_type_mut(&mut self, _node: &mut STFldCharType) {}
+    fn visit_st_font_family_mut(&mut self, _node: &mut STFontFamily) {}
+    fn visit_st_frame_layout_mut(&mut self, _node: &mut STFrameLayout) {}
+    fn visit_st_frame_scrollbar_mut(&mut self, _node: &mut STFrameScrollbar) {}
+    fn visit_st_ftn_edn_mut(&mut self, _node: &mut STFtnEdn) {}
+    fn visit_st_ftn_pos_mut(&mut self, _node: &mut STFtnPos) {}
+    fn visit_sth_anchor_mut(&mut self, _node: &mut STHAnchor) {}
+    fn visit_st_hdr_ftr_mut(&mut self, _node: &mut STHdrFtr) {}
+    fn visit_st_height_rule_mut(&mut self, _node: &mut STHeightRule) {}
+    fn visit_st_highlight_color_mut(&mut self, _node: &mut STHighlightColor) {}
+    fn visit_st_hint_mut(&mut self, _node: &mut STHint) {}
+    fn visit_st_info_text_type_mut(&mut self, _node: &mut STInfoTextType) {}
+    fn visit_st_jc_mut(&mut self, _node: &mut STJc) {}
+    fn visit_st_jc_table_mut(&mut self, _node: &mut STJcTable) {}
+    fn visit_st_level_suffix_mut(&mut self, _node: &mut STLevelSuffix) {}
+    fn visit_st_line_number_restart_mut(&mut self, _node: &mut STLineNumberRestart) {}
+    fn visit_st_line_spacing_rule_mut(&mut self, _node: &mut STLineSpacingRule) {}
+    fn visit_st_lock_mut(&mut self, _node: &mut STLock) {}
+    fn visit_st_mail_merge_dest_mut(&mut self, _node: &mut STMailMergeDest) {}
+    fn visit_st_mail_merge_doc_type_mut(&mut self, _node: &mut STMailMergeDocType) {}
+    fn visit_st_mail_merge_odso_fmd_field_type_mut(
+        &mut self,
+        _node: &mut STMailMergeOdsoFMDFieldType,
+    ) {
+    }
+    fn visit_st_mail_merge_source_type_mut(&mut self, _node: &mut STMailMergeSourceType) {}
+    fn visit_st_merge_mut(&mut self, _node: &mut STMerge) {}
+    fn visit_st_multi_level_type_mut(&mut self, _node: &mut STMultiLevelType) {}
+    fn visit_st_number_format_mut(&mut self, _node: &mut STNumberFormat) {}
+    fn visit_st_object_draw_aspect_mut(&mut self, _node: &mut STObjectDrawAspect) {}
+    fn visit_st_object_update_mode_mut(&mut self, _node: &mut STObjectUpdateMode) {}
+    fn visit_st_on_off1_mut(&mut self, _node: &mut STOnOff1) {}
+    fn visit_stp_tab_alignment_mut(&mut self, _node: &mut STPTabAlignment) {}
+    fn visit_stp_tab_leader_mut(&mut self, _node: &mut STPTabLeader) {}
+    fn visit_stp_tab_relative_to_mut(&mut self, _node: &mut STPTabRelativeTo) {}
+    fn visit_st_page_border_display_mut(&mut self, _node: &mut STPageBorderDisplay) {}
+    fn visit_st_page_border_offset_mut(&mut self, _node: &mut STPageBorderOffset) {}
+    fn visit_st_page_border_z_order_mut(&mut self, _node: &mut STPageBorderZOrder) {}
+    fn visit_st_page_orientation_mut(&mut self, _node: &mut STPageOrientation) {}
+    fn visit_st_pitch_mut(&mut self, _node: &mut STPitch) {}
+    fn visit_st_proof_mut(&mut self, _node: &mut STProof) {}
+    fn visit_st_proof_err_mut(&mut self, _node: &mut STProofErr) {}
+    fn visit_st_restart_number_mut(&mut self, _node: &mut STRestartNumber) {}
+    fn visit_st_ruby_align_mut(&mut self, _node: &mut STRubyAlign) {}
+    fn visit_st_sdt_date_mapping_type_mut(&mut self, _node: &mut STSdtDateMappingType) {}
+    fn visit_st_section_mark_mut(&mut self, _node: &mut STSectionMark) {}
+    fn visit_st_shd_mut(&mut self, _node: &mut STShd) {}
+    fn visit_st_style_sort_mut(&mut self, _node: &mut STStyleSort) {}
+    fn visit_st_style_type_mut(&mut self, _node: &mut STStyleType) {}
+    fn visit_st_tab_jc_mut(&mut self, _node: &mut STTabJc) {}
+    fn visit_st_tab_tlc_mut(&mut self, _node: &mut STTabTlc) {}
+    fn visit_st_target_screen_sz_mut(&mut self, _node: &mut STTargetScreenSz) {}
+    fn visit_st_tbl_layout_type_mut(&mut self, _node: &mut STTblLayoutType) {}
+    fn visit_st_tbl_overlap_mut(&mut self, _node: &mut STTblOverlap) {}
+    fn visit_st_tbl_style_override_type_mut(&mut self, _node: &mut STTblStyleOverrideType) {}
+    fn visit_st_tbl_width_mut(&mut self, _node: &mut STTblWidth) {}
+    fn visit_st_text_alignment_mut(&mut self, _node: &mut STTextAlignment) {}
+    fn visit_st_text_direction_mut(&mut self, _node: &mut STTextDirection) {}
+    fn visit_st_text_effect_mut(&mut self, _node: &mut STTextEffect) {}
+    fn visit_st_textbox_tight_wrap_mut(&mut self, _node: &mut STTextboxTightWrap) {}
+    fn visit_st_theme_mut(&mut self, _node: &mut STTheme) {}
+    fn visit_st_theme_color_mut(&mut self, _node: &mut STThemeColor) {}
+    fn visit_st_true_false_blank_mut(&mut self, _node: &mut STTrueFalseBlank) {}
+    fn visit_st_underline_mut(&mut self, _node: &mut STUnderline) {}
+    fn visit_stv_anchor_mut(&mut self, _node: &mut STVAnchor) {}
+    fn visit_st_vertical_align_run_mut(&mut self, _node: &mut STVerticalAlignRun) {}
+    fn visit_st_vertical_jc_mut(&mut self, _node: &mut STVerticalJc) {}
+    fn visit_st_view_mut(&mut self, _node: &mut STView) {}
+    fn visit_st_wml_color_scheme_index_mut(&mut self, _node: &mut STWmlColorSchemeIndex) {}
+    fn visit_st_wrap_mut(&mut self, _node: &mut STWrap) {}
+    fn visit_stx_align_mut(&mut self, _node: &mut STXAlign) {}
+    fn visit_sty_align_mut(&mut self, _node: &mut STYAlign) {}
+    fn visit_st_zoom_mut(&mut self, _node: &mut STZoom) {}
+    fn visit_section_properties_mut(&mut self, node: &mut SectionProperties) {
+        walk_section_properties_mut(self, node)
+    }
+    fn visit_settings_mut(&mut self, node: &mut Settings) {
+        walk_settings_mut(self, node)
+    }
+    fn visit_signed_hps_measure_element_mut(&mut self, _node: &mut SignedHpsMeasureElement) {}
+    fn visit_signed_twips_measure_element_mut(&mut self, _node: &mut SignedTwipsMeasureElement) {}
+    fn visit_style_mut(&mut self, node: &mut Style) {
+        walk_style_mut(self, node)
+    }
+    fn visit_styles_mut(&mut self, node: &mut Styles) {
+        walk_styles_mut(self, node)
+    }
+    fn visit_table_mut(&mut self, node: &mut Table) {
+        walk_table_mut(self, node)
+    }
+    fn visit_table_cell_mut(&mut self, node: &mut TableCell) {
+        walk_table_cell_mut(self, node)
+    }
+    fn visit_table_cell_properties_mut(&mut self, node: &mut TableCellProperties) {
+        walk_table_cell_properties_mut(self, node)
+    }
+    fn visit_table_grid_mut(&mut self, node: &mut TableGrid) {
+        walk_table_grid_mut(self, node)
+    }
+    fn visit_table_grid_column_mut(&mut self, _node: &mut TableGridColumn) {}
+    fn visit_table_properties_mut(&mut self, node: &mut TableProperties) {
+        walk_table_properties_mut(self, node)
+    }
+    fn visit_table_row_properties_mut(&mut self, node: &mut TableRowProperties) {
+        walk_table_row_properties_mut(self, node)
+    }
+    fn visit_table_style_properties_mut(&mut self, node: &mut TableStyleProperties) {
+        walk_table_style_properties_mut(self, node)
+    }
+    fn visit_text_mut(&mut self, node: &mut Text) {
+        walk_text_mut(self, node)
+    }
+    fn visit_text_scale_element_mut(&mut self, _node: &mut TextScaleElement) {}
+    fn visit_true_false_mut(&mut self, _node: &mut TrueFalse) {}
+    fn visit_twips_measure_element_mut(&mut self, _node: &mut TwipsMeasureElement) {}
+    fn visit_unsigned_decimal_number_element_mut(
+        &mut self,
+        _node: &mut UnsignedDecimalNumberElement,
+    ) {
+    }
+    fn visit_wag_password_mut(&mut self, _node: &mut WAGPassword) {}
+    fn visit_wag_sect_pr_attributes_mut(&mut self, _node: &mut WAGSectPrAttributes) {}
+    fn visit_wag_transitional_password_mut(&mut self, node: &mut WAGTransitionalPassword) {
+        walk_wag_transitional_password_mut(self, node)
+    }
+    fn visit_w_any_vml_office_mut(&mut self, node: &mut WAnyVmlOffice) {
+        walk_w_any_vml_office_mut(self, node)
+    }
+    fn visit_w_any_vml_vml_mut(&mut self, node: &mut WAnyVmlVml) {
+        walk_w_any_vml_vml_mut(self, node)
+    }
+    fn visit_raw_xml_node_mut(&mut self, node: &mut RawXmlNode) {
+        walk_raw_xml_node_mut(self, node)
+    }
+    fn visit_raw_xml_element_mut(&mut self, node: &mut RawXmlElement) {
+        walk_raw_xml_element_mut(self, node)
+    }
+}
+pub fn walk_raw_xml_node<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast RawXmlNode) {
+    if let RawXmlNode::Element(element) = node {
+        Visit::visit_raw_xml_element(v, element);
+    }
+}
+pub fn walk_raw_xml_node_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut RawXmlNode) {
+    if let RawXmlNode::Element(element) = node {
+        VisitMut::visit_raw_xml_element_mut(v, element);
+    }
+}
+pub fn walk_raw_xml_element<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast RawXmlElement) {
+    for child in &node.children {
+        Visit::visit_raw_xml_node(v, child);
+    }
+}
+pub fn walk_raw_xml_element_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut RawXmlElement) {
+    for child in &mut node.children {
+        VisitMut::visit_raw_xml_node_mut(v, child);
+    }
+}
+
+pub fn walk_abstract_numbering<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast AbstractNumbering,
+) {
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.nsid {
+            Visit::visit_long_hex_number_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.multi_level_type {
+            Visit::visit_ct_multi_level_type(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.tmpl {
+            Visit::visit_long_hex_number_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.name {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.style_link {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.num_style_link {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    for it0 in &node.lvl {
+        Visit::visit_level(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_abstract_numbering_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut AbstractNumbering) {
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.nsid {
+            VisitMut::visit_long_hex_number_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.multi_level_type {
+            VisitMut::visit_ct_multi_level_type_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.tmpl {
+            VisitMut::visit_long_hex_number_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.name {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.style_link {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.num_style_link {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    for it0 in &mut node.lvl {
+        VisitMut::visit_level_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_block_content<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast BlockContent) {
+    match node {
+        BlockContent::CustomXml(it) => {
+            Visit::visit_ct_custom_xml_block(v, it);
+        }
+        BlockContent::Sdt(it) => {
+            Visit::visit_ct_sdt_block(v, it);
+        }
+        BlockContent::P(it) => {
+            Visit::visit_paragraph(v, it);
+        }
+        BlockContent::Tbl(it) => {
+            Visit::visit_table(v, it);
+        }
+        BlockContent::ProofErr(it) => {
+            Visit::visit_ct_proof_err(v, it);
+        }
+        BlockContent::PermStart(it) => {
+            Visit::visit_ct_perm_start(v, it);
+        }
+        BlockContent::PermEnd(it) => {
+            Visit::visit_ct_perm(v, it);
+        }
+        BlockContent::BookmarkStart(it) => {
+            Visit::visit_bookmark(v, it);
+        }
+        BlockContent::BookmarkEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        BlockContent::MoveFromRangeStart(it) => {
+            Visit::visit_ct_move_bookmark(v, it);
+        }
+        BlockContent::MoveFromRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        BlockContent::MoveToRangeStart(it) => {
+            Visit::visit_ct_move_bookmark(v, it);
+        }
+        BlockContent::MoveToRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        BlockContent::CommentRangeStart(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        BlockContent::CommentRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        BlockContent::CustomXmlInsRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        BlockContent::CustomXmlInsRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        BlockContent::CustomXmlDelRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        BlockContent::CustomXmlDelRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        BlockContent::CustomXmlMoveFromRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        BlockContent::CustomXmlMoveFromRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        BlockContent::CustomXmlMoveToRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        BlockContent::CustomXmlMoveToRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        BlockContent::Ins(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        BlockContent::Del(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        BlockContent::MoveFrom(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        BlockContent::MoveTo(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        BlockContent::AltChunk(it) => {
+            Visit::visit_ct_alt_chunk(v, it);
+        }
+    }
+}
+
+pub fn walk_block_content_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut BlockContent) {
+    match node {
+        BlockContent::CustomXml(it) => {
+            VisitMut::visit_ct_custom_xml_block_mut(v, it);
+        }
+        BlockContent::Sdt(it) => {
+            VisitMut::visit_ct_sdt_block_mut(v, it);
+        }
+        BlockContent::P(it) => {
+            VisitMut::visit_paragraph_mut(v, it);
+        }
+        BlockContent::Tbl(it) => {
+            VisitMut::visit_table_mut(v, it);
+        }
+        BlockContent::ProofErr(it) => {
+            VisitMut::visit_ct_proof_err_mut(v, it);
+        }
+        BlockContent::PermStart(it) => {
+            VisitMut::visit_ct_perm_start_mut(v, it);
+        }
+        BlockContent::PermEnd(it) => {
+            VisitMut::visit_ct_perm_mut(v, it);
+        }
+        BlockContent::BookmarkStart(it) => {
+            VisitMut::visit_bookmark_mut(v, it);
+        }
+        BlockContent::BookmarkEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        BlockContent::MoveFromRangeStart(it) => {
+            VisitMut::visit_ct_move_bookmark_mut(v, it);
+        }
+        BlockContent::MoveFromRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        BlockContent::MoveToRangeStart(it) => {
+            VisitMut::visit_ct_move_bookmark_mut(v, it);
+        }
+        BlockContent::MoveToRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        BlockContent::CommentRangeStart(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        BlockContent::CommentRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        BlockContent::CustomXmlInsRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        BlockContent::CustomXmlInsRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        BlockContent::CustomXmlDelRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        BlockContent::CustomXmlDelRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        BlockContent::CustomXmlMoveFromRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        BlockContent::CustomXmlMoveFromRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        BlockContent::CustomXmlMoveToRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        BlockContent::CustomXmlMoveToRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        BlockContent::Ins(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        BlockContent::Del(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        BlockContent::MoveFrom(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        BlockContent::MoveTo(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        BlockContent::AltChunk(it) => {
+            VisitMut::visit_ct_alt_chunk_mut(v, it);
+        }
+    }
+}
+
+pub fn walk_block_content_choice<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast BlockContentChoice,
+) {
+    match node {
+        BlockContentChoice::CustomXml(it) => {
+            Visit::visit_ct_custom_xml_block(v, it);
+        }
+        BlockContentChoice::Sdt(it) => {
+            Visit::visit_ct_sdt_block(v, it);
+        }
+        BlockContentChoice::P(it) => {
+            Visit::visit_paragraph(v, it);
+        }
+        BlockContentChoice::Tbl(it) => {
+            Visit::visit_table(v, it);
+        }
+        BlockContentChoice::ProofErr(it) => {
+            Visit::visit_ct_proof_err(v, it);
+        }
+        BlockContentChoice::PermStart(it) => {
+            Visit::visit_ct_perm_start(v, it);
+        }
+        BlockContentChoice::PermEnd(it) => {
+            Visit::visit_ct_perm(v, it);
+        }
+        BlockContentChoice::BookmarkStart(it) => {
+            Visit::visit_bookmark(v, it);
+        }
+        BlockContentChoice::BookmarkEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        BlockContentChoice::MoveFromRangeStart(it) => {
+            Visit::visit_ct_move_bookmark(v, it);
+        }
+        BlockContentChoice::MoveFromRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        BlockContentChoice::MoveToRangeStart(it) => {
+            Visit::visit_ct_move_bookmark(v, it);
+        }
+        BlockContentChoice::MoveToRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        BlockContentChoice::CommentRangeStart(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        BlockContentChoice::CommentRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        BlockContentChoice::CustomXmlInsRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        BlockContentChoice::CustomXmlInsRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        BlockContentChoice::CustomXmlDelRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        BlockContentChoice::CustomXmlDelRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        BlockContentChoice::CustomXmlMoveFromRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        BlockContentChoice::CustomXmlMoveFromRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        BlockContentChoice::CustomXmlMoveToRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        BlockContentChoice::CustomXmlMoveToRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        BlockContentChoice::Ins(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        BlockContentChoice::Del(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        BlockContentChoice::MoveFrom(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        BlockContentChoice::MoveTo(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+    }
+}
+
+pub fn walk_block_content_choice_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut BlockContentChoice,
+) {
+    match node {
+        BlockContentChoice::CustomXml(it) => {
+            VisitMut::visit_ct_custom_xml_block_mut(v, it);
+        }
+        BlockContentChoice::Sdt(it) => {
+            VisitMut::visit_ct_sdt_block_mut(v, it);
+        }
+        BlockContentChoice::P(it) => {
+            VisitMut::visit_paragraph_mut(v, it);
+        }
+        BlockContentChoice::Tbl(it) => {
+            VisitMut::visit_table_mut(v, it);
+        }
+        BlockContentChoice::ProofErr(it) => {
+            VisitMut::visit_ct_proof_err_mut(v, it);
+        }
+        BlockContentChoice::PermStart(it) => {
+            VisitMut::visit_ct_perm_start_mut(v, it);
+        }
+        BlockContentChoice::PermEnd(it) => {
+            VisitMut::visit_ct_perm_mut(v, it);
+        }
+        BlockContentChoice::BookmarkStart(it) => {
+            VisitMut::visit_bookmark_mut(v, it);
+        }
+        BlockContentChoice::BookmarkEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        BlockContentChoice::MoveFromRangeStart(it) => {
+            VisitMut::visit_ct_move_bookmark_mut(v, it);
+        }
+        BlockContentChoice::MoveFromRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        BlockContentChoice::MoveToRangeStart(it) => {
+            VisitMut::visit_ct_move_bookmark_mut(v, it);
+        }
+        BlockContentChoice::MoveToRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        BlockContentChoice::CommentRangeStart(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        BlockContentChoice::CommentRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        BlockContentChoice::CustomXmlInsRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        BlockContentChoice::CustomXmlInsRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        BlockContentChoice::CustomXmlDelRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        BlockContentChoice::CustomXmlDelRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        BlockContentChoice::CustomXmlMoveFromRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        BlockContentChoice::CustomXmlMoveFromRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        BlockContentChoice::CustomXmlMoveToRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        BlockContentChoice::CustomXmlMoveToRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        BlockContentChoice::Ins(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        BlockContentChoice::Del(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        BlockContentChoice::MoveFrom(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        BlockContentChoice::MoveTo(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+    }
+}
+
+pub fn walk_body<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Body) {
+    for it0 in &node.block_content {
+        Visit::visit_block_content(v, it0);
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.sect_pr {
+            Visit::visit_section_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_body_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Body) {
+    for it0 in &mut node.block_content {
+        VisitMut::visit_block_content_mut(v, it0);
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.sect_pr {
+            VisitMut::visit_section_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_bookmark<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Bookmark) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.displaced_by_custom_xml {
+            Visit::visit_st_displaced_by_custom_xml(v, it0);
+        }
+    }
+}
+
+pub fn walk_bookmark_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Bookmark) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.displaced_by_custom_xml {
+            VisitMut::visit_st_displaced_by_custom_xml_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_alt_chunk<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTAltChunk) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.alt_chunk_pr {
+            Visit::visit_ct_alt_chunk_pr(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_alt_chunk_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTAltChunk) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.alt_chunk_pr {
+            VisitMut::visit_ct_alt_chunk_pr_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_alt_chunk_pr<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTAltChunkPr) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.match_src {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_alt_chunk_pr_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTAltChunkPr) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.match_src {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_auto_captions<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTAutoCaptions) {
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &node.auto_caption {
+            Visit::visit_ct_auto_caption(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_auto_captions_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTAutoCaptions) {
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &mut node.auto_caption {
+            VisitMut::visit_ct_auto_caption_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_background<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTBackground) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.theme_color {
+            Visit::visit_st_theme_color(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-drawings")]
+    {
+        if let Some(it0) = &node.drawing {
+            Visit::visit_ct_drawing(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_background_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTBackground) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.theme_color {
+            VisitMut::visit_st_theme_color_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-drawings")]
+    {
+        if let Some(it0) = &mut node.drawing {
+            VisitMut::visit_ct_drawing_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_bdo_content_run<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTBdoContentRun,
+) {
+    if let Some(it0) = &node.value {
+        Visit::visit_st_direction(v, it0);
+    }
+    for it0 in &node.paragraph_content {
+        Visit::visit_paragraph_content(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_bdo_content_run_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTBdoContentRun) {
+    if let Some(it0) = &mut node.value {
+        VisitMut::visit_st_direction_mut(v, it0);
+    }
+    for it0 in &mut node.paragraph_content {
+        VisitMut::visit_paragraph_content_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_bookmark_range<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTBookmarkRange,
+) {
+    if let Some(it0) = &node.displaced_by_custom_xml {
+        Visit::visit_st_displaced_by_custom_xml(v, it0);
+    }
+}
+
+pub fn walk_ct_bookmark_range_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTBookmarkRange) {
+    if let Some(it0) = &mut node.displaced_by_custom_xml {
+        VisitMut::visit_st_displaced_by_custom_xml_mut(v, it0);
+    }
+}
+
+pub fn walk_ct_border<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTBorder) {
+    Visit::visit_st_border(v, &node.value);
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.theme_color {
+            Visit::visit_st_theme_color(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_border_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTBorder) {
+    VisitMut::visit_st_border_mut(v, &mut node.value);
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.theme_color {
+            VisitMut::visit_st_theme_color_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_bottom_page_border<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTBottomPageBorder,
+) {
+    Visit::visit_st_border(v, &node.value);
+    if let Some(it0) = &node.theme_color {
+        Visit::visit_st_theme_color(v, it0);
+    }
+}
+
+pub fn walk_ct_bottom_page_border_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTBottomPageBorder,
+) {
+    VisitMut::visit_st_border_mut(v, &mut node.value);
+    if let Some(it0) = &mut node.theme_color {
+        VisitMut::visit_st_theme_color_mut(v, it0);
+    }
+}
+
+pub fn walk_ct_br<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTBr) {
+    if let Some(it0) = &node.r#type {
+        Visit::visit_st_br_type(v, it0);
+    }
+    if let Some(it0) = &node.clear {
+        Visit::visit_st_br_clear(v, it0);
+    }
+}
+
+pub fn walk_ct_br_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTBr) {
+    if let Some(it0) = &mut node.r#type {
+        VisitMut::visit_st_br_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.clear {
+        VisitMut::visit_st_br_clear_mut(v, it0);
+    }
+}
+
+pub fn walk_ct_calendar_type<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTCalendarType) {
+    if let Some(it0) = &node.value {
+        Visit::visit_calendar_type(v, it0);
+    }
+}
+
+pub fn walk_ct_calendar_type_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTCalendarType) {
+    if let Some(it0) = &mut node.value {
+        VisitMut::visit_calendar_type_mut(v, it0);
+    }
+}
+
+pub fn walk_ct_caption<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTCaption) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.pos {
+            Visit::visit_st_caption_pos(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.num_fmt {
+            Visit::visit_st_number_format(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.sep {
+            Visit::visit_st_chapter_sep(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_caption_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTCaption) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.pos {
+            VisitMut::visit_st_caption_pos_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.num_fmt {
+            VisitMut::visit_st_number_format_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.sep {
+            VisitMut::visit_st_chapter_sep_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_captions<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTCaptions) {
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &node.caption {
+            Visit::visit_ct_caption(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.auto_captions {
+            Visit::visit_ct_auto_captions(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_captions_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTCaptions) {
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &mut node.caption {
+            VisitMut::visit_ct_caption_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.auto_captions {
+            VisitMut::visit_ct_auto_captions_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_cell_merge_track_change<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTCellMergeTrackChange,
+) {
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &node.vertical_merge {
+            Visit::visit_st_annotation_v_merge(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &node.v_merge_orig {
+            Visit::visit_st_annotation_v_merge(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_cell_merge_track_change_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTCellMergeTrackChange,
+) {
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &mut node.vertical_merge {
+            VisitMut::visit_st_annotation_v_merge_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &mut node.v_merge_orig {
+            VisitMut::visit_st_annotation_v_merge_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_character_spacing<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTCharacterSpacing,
+) {
+    Visit::visit_st_character_spacing(v, &node.value);
+}
+
+pub fn walk_ct_character_spacing_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTCharacterSpacing,
+) {
+    VisitMut::visit_st_character_spacing_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_color<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTColor) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.theme_color {
+            Visit::visit_st_theme_color(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_color_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTColor) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.theme_color {
+            VisitMut::visit_st_theme_color_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_color_scheme_mapping<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTColorSchemeMapping,
+) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.bg1 {
+            Visit::visit_st_wml_color_scheme_index(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.t1 {
+            Visit::visit_st_wml_color_scheme_index(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.bg2 {
+            Visit::visit_st_wml_color_scheme_index(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.t2 {
+            Visit::visit_st_wml_color_scheme_index(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.accent1 {
+            Visit::visit_st_wml_color_scheme_index(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.accent2 {
+            Visit::visit_st_wml_color_scheme_index(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.accent3 {
+            Visit::visit_st_wml_color_scheme_index(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.accent4 {
+            Visit::visit_st_wml_color_scheme_index(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.accent5 {
+            Visit::visit_st_wml_color_scheme_index(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.accent6 {
+            Visit::visit_st_wml_color_scheme_index(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.hyperlink {
+            Visit::visit_st_wml_color_scheme_index(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.followed_hyperlink {
+            Visit::visit_st_wml_color_scheme_index(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_color_scheme_mapping_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTColorSchemeMapping,
+) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.bg1 {
+            VisitMut::visit_st_wml_color_scheme_index_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.t1 {
+            VisitMut::visit_st_wml_color_scheme_index_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.bg2 {
+            VisitMut::visit_st_wml_color_scheme_index_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.t2 {
+            VisitMut::visit_st_wml_color_scheme_index_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.accent1 {
+            VisitMut::visit_st_wml_color_scheme_index_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.accent2 {
+            VisitMut::visit_st_wml_color_scheme_index_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.accent3 {
+            VisitMut::visit_st_wml_color_scheme_index_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.accent4 {
+            VisitMut::visit_st_wml_color_scheme_index_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.accent5 {
+            VisitMut::visit_st_wml_color_scheme_index_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.accent6 {
+            VisitMut::visit_st_wml_color_scheme_index_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.hyperlink {
+            VisitMut::visit_st_wml_color_scheme_index_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.followed_hyperlink {
+            VisitMut::visit_st_wml_color_scheme_index_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_custom_xml_block<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTCustomXmlBlock,
+) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.custom_xml_pr {
+            Visit::visit_ct_custom_xml_pr(v, it0);
+        }
+    }
+    for it0 in &node.block_content {
+        Visit::visit_block_content_choice(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_custom_xml_block_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTCustomXmlBlock) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.custom_xml_pr {
+            VisitMut::visit_ct_custom_xml_pr_mut(v, it0);
+        }
+    }
+    for it0 in &mut node.block_content {
+        VisitMut::visit_block_content_choice_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_custom_xml_cell<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTCustomXmlCell,
+) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.custom_xml_pr {
+            Visit::visit_ct_custom_xml_pr(v, it0);
+        }
+    }
+    for it0 in &node.cells {
+        Visit::visit_cell_content(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_custom_xml_cell_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTCustomXmlCell) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.custom_xml_pr {
+            VisitMut::visit_ct_custom_xml_pr_mut(v, it0);
+        }
+    }
+    for it0 in &mut node.cells {
+        VisitMut::visit_cell_content_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_custom_xml_pr<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTCustomXmlPr) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.placeholder {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &node.attr {
+            Visit::visit_ct_attr(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_custom_xml_pr_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTCustomXmlPr) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.placeholder {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &mut node.attr {
+            VisitMut::visit_ct_attr_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_custom_xml_row<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTCustomXmlRow,
+) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.custom_xml_pr {
+            Visit::visit_ct_custom_xml_pr(v, it0);
+        }
+    }
+    for it0 in &node.rows {
+        Visit::visit_row_content(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_custom_xml_row_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTCustomXmlRow) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.custom_xml_pr {
+            VisitMut::visit_ct_custom_xml_pr_mut(v, it0);
+        }
+    }
+    for it0 in &mut node.rows {
+        VisitMut::visit_row_content_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_custom_xml_run<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTCustomXmlRun,
+) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.custom_xml_pr {
+            Visit::visit_ct_custom_xml_pr(v, it0);
+        }
+    }
+    for it0 in &node.paragraph_content {
+        Visit::visit_paragraph_content(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_custom_xml_run_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTCustomXmlRun) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.custom_xml_pr {
+            VisitMut::visit_ct_custom_xml_pr_mut(v, it0);
+        }
+    }
+    for it0 in &mut node.paragraph_content {
+        VisitMut::visit_paragraph_content_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_dir_content_run<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTDirContentRun,
+) {
+    if let Some(it0) = &node.value {
+        Visit::visit_st_direction(v, it0);
+    }
+    for it0 in &node.paragraph_content {
+        Visit::visit_paragraph_content(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_dir_content_run_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTDirContentRun) {
+    if let Some(it0) = &mut node.value {
+        VisitMut::visit_st_direction_mut(v, it0);
+    }
+    for it0 in &mut node.paragraph_content {
+        VisitMut::visit_paragraph_content_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_div<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTDiv) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.block_quote {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.body_div {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        Visit::visit_signed_twips_measure_element(v, &node.mar_left);
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        Visit::visit_signed_twips_measure_element(v, &node.mar_right);
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        Visit::visit_signed_twips_measure_element(v, &node.mar_top);
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        Visit::visit_signed_twips_measure_element(v, &node.mar_bottom);
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.div_bdr {
+            Visit::visit_ct_div_bdr(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &node.divs_child {
+            Visit::visit_ct_divs(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_div_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTDiv) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.block_quote {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.body_div {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        VisitMut::visit_signed_twips_measure_element_mut(v, &mut node.mar_left);
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        VisitMut::visit_signed_twips_measure_element_mut(v, &mut node.mar_right);
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        VisitMut::visit_signed_twips_measure_element_mut(v, &mut node.mar_top);
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        VisitMut::visit_signed_twips_measure_element_mut(v, &mut node.mar_bottom);
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.div_bdr {
+            VisitMut::visit_ct_div_bdr_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &mut node.divs_child {
+            VisitMut::visit_ct_divs_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_div_bdr<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTDivBdr) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.top {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.left {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.bottom {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.right {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_div_bdr_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTDivBdr) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.top {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.left {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.bottom {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.right {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_divs<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTDivs) {
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &node.div {
+            Visit::visit_ct_div(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_divs_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTDivs) {
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &mut node.div {
+            VisitMut::visit_ct_div_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_doc_part<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTDocPart) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.doc_part_pr {
+            Visit::visit_ct_doc_part_pr(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.doc_part_body {
+            Visit::visit_body(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_doc_part_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTDocPart) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.doc_part_pr {
+            VisitMut::visit_ct_doc_part_pr_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.doc_part_body {
+            VisitMut::visit_body_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_doc_part_behavior<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTDocPartBehavior,
+) {
+    Visit::visit_st_doc_part_behavior(v, &node.value);
+}
+
+pub fn walk_ct_doc_part_behavior_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTDocPartBehavior,
+) {
+    VisitMut::visit_st_doc_part_behavior_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_doc_part_behaviors<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTDocPartBehaviors,
+) {
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &node.behavior {
+            Visit::visit_ct_doc_part_behavior(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_doc_part_behaviors_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTDocPartBehaviors,
+) {
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &mut node.behavior {
+            VisitMut::visit_ct_doc_part_behavior_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_doc_part_category<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTDocPartCategory,
+) {
+    Visit::visit_ct_string(v, &node.name);
+    #[cfg(feature = "wml-settings")]
+    {
+        Visit::visit_ct_doc_part_gallery(v, &node.gallery);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_doc_part_category_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTDocPartCategory,
+) {
+    VisitMut::visit_ct_string_mut(v, &mut node.name);
+    #[cfg(feature = "wml-settings")]
+    {
+        VisitMut::visit_ct_doc_part_gallery_mut(v, &mut node.gallery);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_doc_part_gallery<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTDocPartGallery,
+) {
+    Visit::visit_st_doc_part_gallery(v, &node.value);
+}
+
+pub fn walk_ct_doc_part_gallery_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTDocPartGallery) {
+    VisitMut::visit_st_doc_part_gallery_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_doc_part_pr<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTDocPartPr) {
+    #[cfg(feature = "wml-settings")]
+    {
+        Visit::visit_ct_doc_part_name(v, &node.name);
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.style {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.category {
+            Visit::visit_ct_doc_part_category(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.types {
+            Visit::visit_ct_doc_part_types(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.behaviors {
+            Visit::visit_ct_doc_part_behaviors(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.description {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.guid {
+            Visit::visit_guid_element(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_doc_part_pr_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTDocPartPr) {
+    #[cfg(feature = "wml-settings")]
+    {
+        VisitMut::visit_ct_doc_part_name_mut(v, &mut node.name);
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.style {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.category {
+            VisitMut::visit_ct_doc_part_category_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.types {
+            VisitMut::visit_ct_doc_part_types_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.behaviors {
+            VisitMut::visit_ct_doc_part_behaviors_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.description {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.guid {
+            VisitMut::visit_guid_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_doc_part_type<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTDocPartType) {
+    Visit::visit_st_doc_part_type(v, &node.value);
+}
+
+pub fn walk_ct_doc_part_type_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTDocPartType) {
+    VisitMut::visit_st_doc_part_type_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_doc_part_types<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTDocPartTypes,
+) {
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &node.r#type {
+            Visit::visit_ct_doc_part_type(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_doc_part_types_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTDocPartTypes) {
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &mut node.r#type {
+            VisitMut::visit_ct_doc_part_type_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_doc_parts<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTDocParts) {
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &node.doc_part {
+            Visit::visit_ct_doc_part(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_doc_parts_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTDocParts) {
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &mut node.doc_part {
+            VisitMut::visit_ct_doc_part_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_doc_protect<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTDocProtect) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.edit {
+            Visit::visit_st_doc_protect(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.crypt_provider_type {
+            Visit::visit_st_crypt_prov(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.crypt_algorithm_class {
+            Visit::visit_st_alg_class(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.crypt_algorithm_type {
+            Visit::visit_st_alg_type(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_doc_protect_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTDocProtect) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.edit {
+            VisitMut::visit_st_doc_protect_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.crypt_provider_type {
+            VisitMut::visit_st_crypt_prov_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.crypt_algorithm_class {
+            VisitMut::visit_st_alg_class_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.crypt_algorithm_type {
+            VisitMut::visit_st_alg_type_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_doc_rsids<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTDocRsids) {
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &node.rsid_root {
+            Visit::visit_long_hex_number_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        for it0 in &node.rsid {
+            Visit::visit_long_hex_number_element(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_doc_rsids_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTDocRsids) {
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &mut node.rsid_root {
+            VisitMut::visit_long_hex_number_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        for it0 in &mut node.rsid {
+            VisitMut::visit_long_hex_number_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_doc_vars<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTDocVars) {
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &node.doc_var {
+            Visit::visit_ct_doc_var(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_doc_vars_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTDocVars) {
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &mut node.doc_var {
+            VisitMut::visit_ct_doc_var_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_document_base<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTDocumentBase) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.background {
+            Visit::visit_ct_background(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_document_base_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTDocumentBase) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.background {
+            VisitMut::visit_ct_background_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_drawing<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTDrawing) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_drawing_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTDrawing) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_east_asian_layout<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTEastAsianLayout,
+) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.combine_brackets {
+            Visit::visit_st_combine_brackets(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_east_asian_layout_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTEastAsianLayout,
+) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.combine_brackets {
+            VisitMut::visit_st_combine_brackets_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_edn_doc_props<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTEdnDocProps) {
+    if let Some(it0) = &node.pos {
+        Visit::visit_ct_edn_pos(v, it0);
+    }
+    if let Some(it0) = &node.num_fmt {
+        Visit::visit_ct_num_fmt(v, it0);
+    }
+    if let Some(it0) = &node.num_start {
+        Visit::visit_ct_decimal_number(v, it0);
+    }
+    if let Some(it0) = &node.num_restart {
+        Visit::visit_ct_num_restart(v, it0);
+    }
+    #[cfg(feature = "wml-comments")]
+    {
+        for it0 in &node.endnote {
+            Visit::visit_ct_ftn_edn_sep_ref(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_edn_doc_props_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTEdnDocProps) {
+    if let Some(it0) = &mut node.pos {
+        VisitMut::visit_ct_edn_pos_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.num_fmt {
+        VisitMut::visit_ct_num_fmt_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.num_start {
+        VisitMut::visit_ct_decimal_number_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.num_restart {
+        VisitMut::visit_ct_num_restart_mut(v, it0);
+    }
+    #[cfg(feature = "wml-comments")]
+    {
+        for it0 in &mut node.endnote {
+            VisitMut::visit_ct_ftn_edn_sep_ref_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_edn_pos<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTEdnPos) {
+    Visit::visit_st_edn_pos(v, &node.value);
+}
+
+pub fn walk_ct_edn_pos_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTEdnPos) {
+    VisitMut::visit_st_edn_pos_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_edn_props<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTEdnProps) {
+    #[cfg(feature = "wml-comments")]
+    {
+        if let Some(it0) = &node.pos {
+            Visit::visit_ct_edn_pos(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-comments")]
+    {
+        if let Some(it0) = &node.num_fmt {
+            Visit::visit_ct_num_fmt(v, it0);
+        }
+    }
+    if let Some(it0) = &node.num_start {
+        Visit::visit_ct_decimal_number(v, it0);
+    }
+    if let Some(it0) = &node.num_restart {
+        Visit::visit_ct_num_restart(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_edn_props_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTEdnProps) {
+    #[cfg(feature = "wml-comments")]
+    {
+        if let Some(it0) = &mut node.pos {
+            VisitMut::visit_ct_edn_pos_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-comments")]
+    {
+        if let Some(it0) = &mut node.num_fmt {
+            VisitMut::visit_ct_num_fmt_mut(v, it0);
+        }
+    }
+    if let Some(it0) = &mut node.num_start {
+        VisitMut::visit_ct_decimal_number_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.num_restart {
+        VisitMut::visit_ct_num_restart_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_em<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTEm) {
+    Visit::visit_st_em(v, &node.value);
+}
+
+pub fn walk_ct_em_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTEm) {
+    VisitMut::visit_st_em_mut(v, &mut node.value);
+}
+
+pub fn walk_ctff_check_box<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTFFCheckBox) {
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &node.size {
+            Visit::visit_hps_measure_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &node.size_auto {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &node.default {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &node.checked {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ctff_check_box_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTFFCheckBox) {
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &mut node.size {
+            VisitMut::visit_hps_measure_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &mut node.size_auto {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &mut node.default {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &mut node.checked {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ctffdd_list<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTFFDDList) {
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &node.result {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &node.default {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        for it0 in &node.list_entry {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ctffdd_list_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTFFDDList) {
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &mut node.result {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &mut node.default {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        for it0 in &mut node.list_entry {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ctff_data<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTFFData) {
+    #[cfg(feature = "wml-fields")]
+    {
+        for it0 in &node.name {
+            Visit::visit_ff_name_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &node.label {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &node.tab_index {
+            Visit::visit_unsigned_decimal_number_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        for it0 in &node.enabled {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        for it0 in &node.calc_on_exit {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &node.entry_macro {
+            Visit::visit_macro_name_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &node.exit_macro {
+            Visit::visit_macro_name_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &node.help_text {
+            Visit::visit_ctff_help_text(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &node.status_text {
+            Visit::visit_ctff_status_text(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ctff_data_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTFFData) {
+    #[cfg(feature = "wml-fields")]
+    {
+        for it0 in &mut node.name {
+            VisitMut::visit_ff_name_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &mut node.label {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &mut node.tab_index {
+            VisitMut::visit_unsigned_decimal_number_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        for it0 in &mut node.enabled {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        for it0 in &mut node.calc_on_exit {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &mut node.entry_macro {
+            VisitMut::visit_macro_name_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &mut node.exit_macro {
+            VisitMut::visit_macro_name_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &mut node.help_text {
+            VisitMut::visit_ctff_help_text_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &mut node.status_text {
+            VisitMut::visit_ctff_status_text_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ctff_help_text<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTFFHelpText) {
+    if let Some(it0) = &node.r#type {
+        Visit::visit_st_info_text_type(v, it0);
+    }
+}
+
+pub fn walk_ctff_help_text_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTFFHelpText) {
+    if let Some(it0) = &mut node.r#type {
+        VisitMut::visit_st_info_text_type_mut(v, it0);
+    }
+}
+
+pub fn walk_ctff_status_text<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTFFStatusText) {
+    if let Some(it0) = &node.r#type {
+        Visit::visit_st_info_text_type(v, it0);
+    }
+}
+
+pub fn walk_ctff_status_text_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTFFStatusText) {
+    if let Some(it0) = &mut node.r#type {
+        VisitMut::visit_st_info_text_type_mut(v, it0);
+    }
+}
+
+pub fn walk_ctff_text_input<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTFFTextInput) {
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &node.r#type {
+            Visit::visit_ctff_text_type(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &node.default {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &node.max_length {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &node.format {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ctff_text_input_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTFFTextInput) {
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &mut node.r#type {
+            VisitMut::visit_ctff_text_type_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &mut node.default {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &mut node.max_length {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &mut node.format {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ctff_text_type<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTFFTextType) {
+    Visit::visit_stff_text_type(v, &node.value);
+}
+
+pub fn walk_ctff_text_type_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTFFTextType) {
+    VisitMut::visit_stff_text_type_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_fld_char<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTFldChar) {
+    Visit::visit_st_fld_char_type(v, &node.fld_char_type);
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &node.fld_data {
+            Visit::visit_text(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &node.ff_data {
+            Visit::visit_ctff_data(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &node.numbering_change {
+            Visit::visit_ct_track_change_numbering(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_fld_char_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTFldChar) {
+    VisitMut::visit_st_fld_char_type_mut(v, &mut node.fld_char_type);
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &mut node.fld_data {
+            VisitMut::visit_text_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &mut node.ff_data {
+            VisitMut::visit_ctff_data_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &mut node.numbering_change {
+            VisitMut::visit_ct_track_change_numbering_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_font_family<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTFontFamily) {
+    Visit::visit_st_font_family(v, &node.value);
+}
+
+pub fn walk_ct_font_family_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTFontFamily) {
+    VisitMut::visit_st_font_family_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_fonts_list<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTFontsList) {
+    for it0 in &node.font {
+        Visit::visit_font(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_fonts_list_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTFontsList) {
+    for it0 in &mut node.font {
+        VisitMut::visit_font_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_frame<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTFrame) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.size {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.name {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.title {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.long_desc {
+            Visit::visit_ct_rel(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.source_file_name {
+            Visit::visit_ct_rel(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.mar_w {
+            Visit::visit_pixels_measure_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.mar_h {
+            Visit::visit_pixels_measure_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.scrollbar {
+            Visit::visit_ct_frame_scrollbar(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.no_resize_allowed {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.linked_to_file {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_frame_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTFrame) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.size {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.name {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.title {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.long_desc {
+            VisitMut::visit_ct_rel_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.source_file_name {
+            VisitMut::visit_ct_rel_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.mar_w {
+            VisitMut::visit_pixels_measure_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.mar_h {
+            VisitMut::visit_pixels_measure_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.scrollbar {
+            VisitMut::visit_ct_frame_scrollbar_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.no_resize_allowed {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.linked_to_file {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_frame_layout<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTFrameLayout) {
+    Visit::visit_st_frame_layout(v, &node.value);
+}
+
+pub fn walk_ct_frame_layout_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTFrameLayout) {
+    VisitMut::visit_st_frame_layout_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_frame_pr<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTFramePr) {
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.drop_cap {
+            Visit::visit_st_drop_cap(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.wrap {
+            Visit::visit_st_wrap(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.h_anchor {
+            Visit::visit_sth_anchor(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.v_anchor {
+            Visit::visit_stv_anchor(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.x_align {
+            Visit::visit_stx_align(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.y_align {
+            Visit::visit_sty_align(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.h_rule {
+            Visit::visit_st_height_rule(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_frame_pr_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTFramePr) {
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.drop_cap {
+            VisitMut::visit_st_drop_cap_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.wrap {
+            VisitMut::visit_st_wrap_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.h_anchor {
+            VisitMut::visit_sth_anchor_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.v_anchor {
+            VisitMut::visit_stv_anchor_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.x_align {
+            VisitMut::visit_stx_align_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.y_align {
+            VisitMut::visit_sty_align_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.h_rule {
+            VisitMut::visit_st_height_rule_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_frame_scrollbar<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTFrameScrollbar,
+) {
+    Visit::visit_st_frame_scrollbar(v, &node.value);
+}
+
+pub fn walk_ct_frame_scrollbar_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTFrameScrollbar) {
+    VisitMut::visit_st_frame_scrollbar_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_frameset<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTFrameset) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.size {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.frameset_splitbar {
+            Visit::visit_ct_frameset_splitbar(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.frame_layout {
+            Visit::visit_ct_frame_layout(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.title {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_frameset_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTFrameset) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.size {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.frameset_splitbar {
+            VisitMut::visit_ct_frameset_splitbar_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.frame_layout {
+            VisitMut::visit_ct_frame_layout_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.title {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_frameset_splitbar<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTFramesetSplitbar,
+) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.width {
+            Visit::visit_twips_measure_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.color {
+            Visit::visit_ct_color(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.no_border {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.flat_borders {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_frameset_splitbar_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTFramesetSplitbar,
+) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.width {
+            VisitMut::visit_twips_measure_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.color {
+            VisitMut::visit_ct_color_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.no_border {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.flat_borders {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_ftn_doc_props<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTFtnDocProps) {
+    if let Some(it0) = &node.pos {
+        Visit::visit_ct_ftn_pos(v, it0);
+    }
+    if let Some(it0) = &node.num_fmt {
+        Visit::visit_ct_num_fmt(v, it0);
+    }
+    if let Some(it0) = &node.num_start {
+        Visit::visit_ct_decimal_number(v, it0);
+    }
+    if let Some(it0) = &node.num_restart {
+        Visit::visit_ct_num_restart(v, it0);
+    }
+    #[cfg(feature = "wml-comments")]
+    {
+        for it0 in &node.footnote {
+            Visit::visit_ct_ftn_edn_sep_ref(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_ftn_doc_props_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTFtnDocProps) {
+    if let Some(it0) = &mut node.pos {
+        VisitMut::visit_ct_ftn_pos_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.num_fmt {
+        VisitMut::visit_ct_num_fmt_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.num_start {
+        VisitMut::visit_ct_decimal_number_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.num_restart {
+        VisitMut::visit_ct_num_restart_mut(v, it0);
+    }
+    #[cfg(feature = "wml-comments")]
+    {
+        for it0 in &mut node.footnote {
+            VisitMut::visit_ct_ftn_edn_sep_ref_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_ftn_pos<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTFtnPos) {
+    Visit::visit_st_ftn_pos(v, &node.value);
+}
+
+pub fn walk_ct_ftn_pos_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTFtnPos) {
+    VisitMut::visit_st_ftn_pos_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_ftn_props<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTFtnProps) {
+    #[cfg(feature = "wml-comments")]
+    {
+        if let Some(it0) = &node.pos {
+            Visit::visit_ct_ftn_pos(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-comments")]
+    {
+        if let Some(it0) = &node.num_fmt {
+            Visit::visit_ct_num_fmt(v, it0);
+        }
+    }
+    if let Some(it0) = &node.num_start {
+        Visit::visit_ct_decimal_number(v, it0);
+    }
+    if let Some(it0) = &node.num_restart {
+        Visit::visit_ct_num_restart(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_ftn_props_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTFtnProps) {
+    #[cfg(feature = "wml-comments")]
+    {
+        if let Some(it0) = &mut node.pos {
+            VisitMut::visit_ct_ftn_pos_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-comments")]
+    {
+        if let Some(it0) = &mut node.num_fmt {
+            VisitMut::visit_ct_num_fmt_mut(v, it0);
+        }
+    }
+    if let Some(it0) = &mut node.num_start {
+        VisitMut::visit_ct_decimal_number_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.num_restart {
+        VisitMut::visit_ct_num_restart_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_glossary_document<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTGlossaryDocument,
+) {
+    if let Some(it0) = &node.background {
+        Visit::visit_ct_background(v, it0);
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.doc_parts {
+            Visit::visit_ct_doc_parts(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_glossary_document_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTGlossaryDocument,
+) {
+    if let Some(it0) = &mut node.background {
+        VisitMut::visit_ct_background_mut(v, it0);
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.doc_parts {
+            VisitMut::visit_ct_doc_parts_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_cth_merge<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTHMerge) {
+    if let Some(it0) = &node.value {
+        Visit::visit_st_merge(v, it0);
+    }
+}
+
+pub fn walk_cth_merge_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTHMerge) {
+    if let Some(it0) = &mut node.value {
+        VisitMut::visit_st_merge_mut(v, it0);
+    }
+}
+
+pub fn walk_ct_headers<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTHeaders) {
+    for it0 in &node.header {
+        Visit::visit_ct_string(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_headers_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTHeaders) {
+    for it0 in &mut node.header {
+        VisitMut::visit_ct_string_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_height<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTHeight) {
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.h_rule {
+            Visit::visit_st_height_rule(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_height_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTHeight) {
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.h_rule {
+            VisitMut::visit_st_height_rule_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_highlight<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTHighlight) {
+    Visit::visit_st_highlight_color(v, &node.value);
+}
+
+pub fn walk_ct_highlight_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTHighlight) {
+    VisitMut::visit_st_highlight_color_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_jc<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTJc) {
+    Visit::visit_st_jc(v, &node.value);
+}
+
+pub fn walk_ct_jc_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTJc) {
+    VisitMut::visit_st_jc_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_jc_table<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTJcTable) {
+    Visit::visit_st_jc_table(v, &node.value);
+}
+
+pub fn walk_ct_jc_table_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTJcTable) {
+    VisitMut::visit_st_jc_table_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_level_suffix<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTLevelSuffix) {
+    Visit::visit_st_level_suffix(v, &node.value);
+}
+
+pub fn walk_ct_level_suffix_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTLevelSuffix) {
+    VisitMut::visit_st_level_suffix_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_line_number<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTLineNumber) {
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.restart {
+            Visit::visit_st_line_number_restart(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_line_number_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTLineNumber) {
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.restart {
+            VisitMut::visit_st_line_number_restart_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_lock<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTLock) {
+    if let Some(it0) = &node.value {
+        Visit::visit_st_lock(v, it0);
+    }
+}
+
+pub fn walk_ct_lock_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTLock) {
+    if let Some(it0) = &mut node.value {
+        VisitMut::visit_st_lock_mut(v, it0);
+    }
+}
+
+pub fn walk_ct_mail_merge<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTMailMerge) {
+    #[cfg(feature = "wml-settings")]
+    {
+        Visit::visit_ct_mail_merge_doc_type(v, &node.main_document_type);
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.link_to_query {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        Visit::visit_mail_merge_data_type_element(v, &node.data_type);
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.connect_string {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.query {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.data_source {
+            Visit::visit_ct_rel(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.header_source {
+            Visit::visit_ct_rel(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_suppress_blank_lines {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.destination {
+            Visit::visit_ct_mail_merge_dest(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.address_field_name {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.mail_subject {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.mail_as_attachment {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.view_merged_data {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.active_record {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.check_errors {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.odso {
+            Visit::visit_ct_odso(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_mail_merge_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTMailMerge) {
+    #[cfg(feature = "wml-settings")]
+    {
+        VisitMut::visit_ct_mail_merge_doc_type_mut(v, &mut node.main_document_type);
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.link_to_query {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        VisitMut::visit_mail_merge_data_type_element_mut(v, &mut node.data_type);
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.connect_string {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.query {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.data_source {
+            VisitMut::visit_ct_rel_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.header_source {
+            VisitMut::visit_ct_rel_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_suppress_blank_lines {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.destination {
+            VisitMut::visit_ct_mail_merge_dest_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.address_field_name {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.mail_subject {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.mail_as_attachment {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.view_merged_data {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.active_record {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.check_errors {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.odso {
+            VisitMut::visit_ct_odso_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_mail_merge_dest<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTMailMergeDest,
+) {
+    Visit::visit_st_mail_merge_dest(v, &node.value);
+}
+
+pub fn walk_ct_mail_merge_dest_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTMailMergeDest) {
+    VisitMut::visit_st_mail_merge_dest_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_mail_merge_doc_type<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTMailMergeDocType,
+) {
+    Visit::visit_st_mail_merge_doc_type(v, &node.value);
+}
+
+pub fn walk_ct_mail_merge_doc_type_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTMailMergeDocType,
+) {
+    VisitMut::visit_st_mail_merge_doc_type_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_mail_merge_odso_fmd_field_type<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTMailMergeOdsoFMDFieldType,
+) {
+    Visit::visit_st_mail_merge_odso_fmd_field_type(v, &node.value);
+}
+
+pub fn walk_ct_mail_merge_odso_fmd_field_type_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTMailMergeOdsoFMDFieldType,
+) {
+    VisitMut::visit_st_mail_merge_odso_fmd_field_type_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_mail_merge_source_type<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTMailMergeSourceType,
+) {
+    Visit::visit_st_mail_merge_source_type(v, &node.value);
+}
+
+pub fn walk_ct_mail_merge_source_type_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTMailMergeSourceType,
+) {
+    VisitMut::visit_st_mail_merge_source_type_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_markup_range<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTMarkupRange) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.displaced_by_custom_xml {
+            Visit::visit_st_displaced_by_custom_xml(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_markup_range_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTMarkupRange) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.displaced_by_custom_xml {
+            VisitMut::visit_st_displaced_by_custom_xml_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_math_ctrl_del<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTMathCtrlDel) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.r_pr {
+            Visit::visit_run_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_math_ctrl_del_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTMathCtrlDel) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.r_pr {
+            VisitMut::visit_run_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_math_ctrl_ins<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTMathCtrlIns) {
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &node.del {
+            Visit::visit_ctr_pr_change(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.r_pr {
+            Visit::visit_run_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_math_ctrl_ins_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTMathCtrlIns) {
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &mut node.del {
+            VisitMut::visit_ctr_pr_change_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.r_pr {
+            VisitMut::visit_run_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_move_bookmark<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTMoveBookmark) {
+    if let Some(it0) = &node.displaced_by_custom_xml {
+        Visit::visit_st_displaced_by_custom_xml(v, it0);
+    }
+}
+
+pub fn walk_ct_move_bookmark_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTMoveBookmark) {
+    if let Some(it0) = &mut node.displaced_by_custom_xml {
+        VisitMut::visit_st_displaced_by_custom_xml_mut(v, it0);
+    }
+}
+
+pub fn walk_ct_multi_level_type<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTMultiLevelType,
+) {
+    Visit::visit_st_multi_level_type(v, &node.value);
+}
+
+pub fn walk_ct_multi_level_type_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTMultiLevelType) {
+    VisitMut::visit_st_multi_level_type_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_num_fmt<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTNumFmt) {
+    Visit::visit_st_number_format(v, &node.value);
+}
+
+pub fn walk_ct_num_fmt_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTNumFmt) {
+    VisitMut::visit_st_number_format_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_num_lvl<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTNumLvl) {
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.start_override {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.lvl {
+            Visit::visit_level(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_num_lvl_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTNumLvl) {
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.start_override {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.lvl {
+            VisitMut::visit_level_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_num_pic_bullet<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTNumPicBullet,
+) {
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.pict {
+            Visit::visit_ct_picture(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.drawing {
+            Visit::visit_ct_drawing(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_num_pic_bullet_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTNumPicBullet) {
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.pict {
+            VisitMut::visit_ct_picture_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.drawing {
+            VisitMut::visit_ct_drawing_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_num_restart<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTNumRestart) {
+    Visit::visit_st_restart_number(v, &node.value);
+}
+
+pub fn walk_ct_num_restart_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTNumRestart) {
+    VisitMut::visit_st_restart_number_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_object<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTObject) {
+    #[cfg(feature = "wml-drawings")]
+    {
+        if let Some(it0) = &node.drawing {
+            Visit::visit_ct_drawing(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-drawings")]
+    {
+        if let Some(it0) = &node.control {
+            Visit::visit_ct_control(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-drawings")]
+    {
+        if let Some(it0) = &node.object_link {
+            Visit::visit_ct_object_link(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-drawings")]
+    {
+        if let Some(it0) = &node.object_embed {
+            Visit::visit_ct_object_embed(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-drawings")]
+    {
+        if let Some(it0) = &node.movie {
+            Visit::visit_ct_rel(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_object_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTObject) {
+    #[cfg(feature = "wml-drawings")]
+    {
+        if let Some(it0) = &mut node.drawing {
+            VisitMut::visit_ct_drawing_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-drawings")]
+    {
+        if let Some(it0) = &mut node.control {
+            VisitMut::visit_ct_control_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-drawings")]
+    {
+        if let Some(it0) = &mut node.object_link {
+            VisitMut::visit_ct_object_link_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-drawings")]
+    {
+        if let Some(it0) = &mut node.object_embed {
+            VisitMut::visit_ct_object_embed_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-drawings")]
+    {
+        if let Some(it0) = &mut node.movie {
+            VisitMut::visit_ct_rel_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_object_embed<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTObjectEmbed) {
+    #[cfg(feature = "wml-drawings")]
+    {
+        if let Some(it0) = &node.draw_aspect {
+            Visit::visit_st_object_draw_aspect(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_object_embed_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTObjectEmbed) {
+    #[cfg(feature = "wml-drawings")]
+    {
+        if let Some(it0) = &mut node.draw_aspect {
+            VisitMut::visit_st_object_draw_aspect_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_object_link<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTObjectLink) {
+    #[cfg(feature = "wml-drawings")]
+    {
+        if let Some(it0) = &node.draw_aspect {
+            Visit::visit_st_object_draw_aspect(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-drawings")]
+    {
+        Visit::visit_st_object_update_mode(v, &node.update_mode);
+    }
+}
+
+pub fn walk_ct_object_link_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTObjectLink) {
+    #[cfg(feature = "wml-drawings")]
+    {
+        if let Some(it0) = &mut node.draw_aspect {
+            VisitMut::visit_st_object_draw_aspect_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-drawings")]
+    {
+        VisitMut::visit_st_object_update_mode_mut(v, &mut node.update_mode);
+    }
+}
+
+pub fn walk_ct_odso<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTOdso) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.udl {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.table {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.src {
+            Visit::visit_ct_rel(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.col_delim {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.r#type {
+            Visit::visit_ct_mail_merge_source_type(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.f_hdr {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &node.field_map_data {
+            Visit::visit_ct_odso_field_map_data(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &node.recipient_data {
+            Visit::visit_ct_rel(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_odso_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTOdso) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.udl {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.table {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.src {
+            VisitMut::visit_ct_rel_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.col_delim {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.r#type {
+            VisitMut::visit_ct_mail_merge_source_type_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.f_hdr {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &mut node.field_map_data {
+            VisitMut::visit_ct_odso_field_map_data_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &mut node.recipient_data {
+            VisitMut::visit_ct_rel_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_odso_field_map_data<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTOdsoFieldMapData,
+) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.r#type {
+            Visit::visit_ct_mail_merge_odso_fmd_field_type(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.name {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.mapped_name {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.column {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.lid {
+            Visit::visit_ct_lang(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.dynamic_address {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_odso_field_map_data_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTOdsoFieldMapData,
+) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.r#type {
+            VisitMut::visit_ct_mail_merge_odso_fmd_field_type_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.name {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.mapped_name {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.column {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.lid {
+            VisitMut::visit_ct_lang_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.dynamic_address {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ctp_bdr<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTPBdr) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.top {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.left {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.bottom {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.right {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.between {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.bar {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ctp_bdr_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTPBdr) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.top {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.left {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.bottom {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.right {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.between {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.bar {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ctp_pr_base<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTPPrBase) {
+    if let Some(it0) = &node.paragraph_style {
+        Visit::visit_ct_string(v, it0);
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.keep_next {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.keep_lines {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.page_break_before {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.frame_pr {
+            Visit::visit_ct_frame_pr(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.widow_control {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.num_pr {
+            Visit::visit_numbering_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.suppress_line_numbers {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.paragraph_border {
+            Visit::visit_ctp_bdr(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.shading {
+            Visit::visit_ct_shd(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.tabs {
+            Visit::visit_ct_tabs(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.suppress_auto_hyphens {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.kinsoku {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.word_wrap {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.overflow_punct {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.top_line_punct {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.auto_space_d_e {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.auto_space_d_n {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.bidi {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.adjust_right_ind {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.snap_to_grid {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.spacing {
+            Visit::visit_ct_spacing(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.indentation {
+            Visit::visit_ct_ind(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.contextual_spacing {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.mirror_indents {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.suppress_overlap {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.justification {
+            Visit::visit_ct_jc(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.text_direction {
+            Visit::visit_ct_text_direction(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.text_alignment {
+            Visit::visit_ct_text_alignment(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.textbox_tight_wrap {
+            Visit::visit_ct_textbox_tight_wrap(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.outline_lvl {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.div_id {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.cnf_style {
+            Visit::visit_ct_cnf(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ctp_pr_base_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTPPrBase) {
+    if let Some(it0) = &mut node.paragraph_style {
+        VisitMut::visit_ct_string_mut(v, it0);
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.keep_next {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.keep_lines {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.page_break_before {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.frame_pr {
+            VisitMut::visit_ct_frame_pr_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.widow_control {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.num_pr {
+            VisitMut::visit_numbering_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.suppress_line_numbers {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.paragraph_border {
+            VisitMut::visit_ctp_bdr_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.shading {
+            VisitMut::visit_ct_shd_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.tabs {
+            VisitMut::visit_ct_tabs_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.suppress_auto_hyphens {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.kinsoku {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.word_wrap {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.overflow_punct {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.top_line_punct {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.auto_space_d_e {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.auto_space_d_n {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.bidi {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.adjust_right_ind {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.snap_to_grid {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.spacing {
+            VisitMut::visit_ct_spacing_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.indentation {
+            VisitMut::visit_ct_ind_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.contextual_spacing {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.mirror_indents {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.suppress_overlap {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.justification {
+            VisitMut::visit_ct_jc_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.text_direction {
+            VisitMut::visit_ct_text_direction_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.text_alignment {
+            VisitMut::visit_ct_text_alignment_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.textbox_tight_wrap {
+            VisitMut::visit_ct_textbox_tight_wrap_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.outline_lvl {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.div_id {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.cnf_style {
+            VisitMut::visit_ct_cnf_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ctp_pr_change<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTPPrChange) {
+    #[cfg(feature = "wml-track-changes")]
+    {
+        Visit::visit_ctp_pr_base(v, &node.p_pr);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ctp_pr_change_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTPPrChange) {
+    #[cfg(feature = "wml-track-changes")]
+    {
+        VisitMut::visit_ctp_pr_base_mut(v, &mut node.p_pr);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ctp_pr_general<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTPPrGeneral) {
+    if let Some(it0) = &node.paragraph_style {
+        Visit::visit_ct_string(v, it0);
+    }
+    if let Some(it0) = &node.keep_next {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.keep_lines {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.page_break_before {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.frame_pr {
+        Visit::visit_ct_frame_pr(v, it0);
+    }
+    if let Some(it0) = &node.widow_control {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.num_pr {
+        Visit::visit_numbering_properties(v, it0);
+    }
+    if let Some(it0) = &node.suppress_line_numbers {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.paragraph_border {
+        Visit::visit_ctp_bdr(v, it0);
+    }
+    if let Some(it0) = &node.shading {
+        Visit::visit_ct_shd(v, it0);
+    }
+    if let Some(it0) = &node.tabs {
+        Visit::visit_ct_tabs(v, it0);
+    }
+    if let Some(it0) = &node.suppress_auto_hyphens {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.kinsoku {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.word_wrap {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.overflow_punct {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.top_line_punct {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.auto_space_d_e {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.auto_space_d_n {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.bidi {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.adjust_right_ind {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.snap_to_grid {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.spacing {
+        Visit::visit_ct_spacing(v, it0);
+    }
+    if let Some(it0) = &node.indentation {
+        Visit::visit_ct_ind(v, it0);
+    }
+    if let Some(it0) = &node.contextual_spacing {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.mirror_indents {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.suppress_overlap {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.justification {
+        Visit::visit_ct_jc(v, it0);
+    }
+    if let Some(it0) = &node.text_direction {
+        Visit::visit_ct_text_direction(v, it0);
+    }
+    if let Some(it0) = &node.text_alignment {
+        Visit::visit_ct_text_alignment(v, it0);
+    }
+    if let Some(it0) = &node.textbox_tight_wrap {
+        Visit::visit_ct_textbox_tight_wrap(v, it0);
+    }
+    if let Some(it0) = &node.outline_lvl {
+        Visit::visit_ct_decimal_number(v, it0);
+    }
+    if let Some(it0) = &node.div_id {
+        Visit::visit_ct_decimal_number(v, it0);
+    }
+    if let Some(it0) = &node.cnf_style {
+        Visit::visit_ct_cnf(v, it0);
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &node.p_pr_change {
+            Visit::visit_ctp_pr_change(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ctp_pr_general_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTPPrGeneral) {
+    if let Some(it0) = &mut node.paragraph_style {
+        VisitMut::visit_ct_string_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.keep_next {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.keep_lines {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.page_break_before {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.frame_pr {
+        VisitMut::visit_ct_frame_pr_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.widow_control {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.num_pr {
+        VisitMut::visit_numbering_properties_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.suppress_line_numbers {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.paragraph_border {
+        VisitMut::visit_ctp_bdr_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.shading {
+        VisitMut::visit_ct_shd_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.tabs {
+        VisitMut::visit_ct_tabs_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.suppress_auto_hyphens {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.kinsoku {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.word_wrap {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.overflow_punct {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.top_line_punct {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.auto_space_d_e {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.auto_space_d_n {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.bidi {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.adjust_right_ind {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.snap_to_grid {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.spacing {
+        VisitMut::visit_ct_spacing_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.indentation {
+        VisitMut::visit_ct_ind_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.contextual_spacing {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.mirror_indents {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.suppress_overlap {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.justification {
+        VisitMut::visit_ct_jc_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.text_direction {
+        VisitMut::visit_ct_text_direction_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.text_alignment {
+        VisitMut::visit_ct_text_alignment_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.textbox_tight_wrap {
+        VisitMut::visit_ct_textbox_tight_wrap_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.outline_lvl {
+        VisitMut::visit_ct_decimal_number_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.div_id {
+        VisitMut::visit_ct_decimal_number_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.cnf_style {
+        VisitMut::visit_ct_cnf_mut(v, it0);
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &mut node.p_pr_change {
+            VisitMut::visit_ctp_pr_change_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ctp_tab<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTPTab) {
+    Visit::visit_stp_tab_alignment(v, &node.alignment);
+    Visit::visit_stp_tab_relative_to(v, &node.relative_to);
+    #[cfg(feature = "wml-styling")]
+    {
+        Visit::visit_stp_tab_leader(v, &node.leader);
+    }
+}
+
+pub fn walk_ctp_tab_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTPTab) {
+    VisitMut::visit_stp_tab_alignment_mut(v, &mut node.alignment);
+    VisitMut::visit_stp_tab_relative_to_mut(v, &mut node.relative_to);
+    #[cfg(feature = "wml-styling")]
+    {
+        VisitMut::visit_stp_tab_leader_mut(v, &mut node.leader);
+    }
+}
+
+pub fn walk_ct_page_border<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTPageBorder) {
+    Visit::visit_st_border(v, &node.value);
+    if let Some(it0) = &node.theme_color {
+        Visit::visit_st_theme_color(v, it0);
+    }
+}
+
+pub fn walk_ct_page_border_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTPageBorder) {
+    VisitMut::visit_st_border_mut(v, &mut node.value);
+    if let Some(it0) = &mut node.theme_color {
+        VisitMut::visit_st_theme_color_mut(v, it0);
+    }
+}
+
+pub fn walk_ct_page_borders<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTPageBorders) {
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.z_order {
+            Visit::visit_st_page_border_z_order(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.display {
+            Visit::visit_st_page_border_display(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.offset_from {
+            Visit::visit_st_page_border_offset(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.top {
+            Visit::visit_ct_top_page_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.left {
+            Visit::visit_ct_page_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.bottom {
+            Visit::visit_ct_bottom_page_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.right {
+            Visit::visit_ct_page_border(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_page_borders_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTPageBorders) {
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.z_order {
+            VisitMut::visit_st_page_border_z_order_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.display {
+            VisitMut::visit_st_page_border_display_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.offset_from {
+            VisitMut::visit_st_page_border_offset_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.top {
+            VisitMut::visit_ct_top_page_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.left {
+            VisitMut::visit_ct_page_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.bottom {
+            VisitMut::visit_ct_bottom_page_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.right {
+            VisitMut::visit_ct_page_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_page_number<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTPageNumber) {
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.fmt {
+            Visit::visit_st_number_format(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.chap_sep {
+            Visit::visit_st_chapter_sep(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_page_number_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTPageNumber) {
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.fmt {
+            VisitMut::visit_st_number_format_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.chap_sep {
+            VisitMut::visit_st_chapter_sep_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_para_r_pr<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTParaRPr) {
+    if let Some(it0) = &node.ins {
+        Visit::visit_ct_track_change(v, it0);
+    }
+    if let Some(it0) = &node.del {
+        Visit::visit_ct_track_change(v, it0);
+    }
+    if let Some(it0) = &node.move_from {
+        Visit::visit_ct_track_change(v, it0);
+    }
+    if let Some(it0) = &node.move_to {
+        Visit::visit_ct_track_change(v, it0);
+    }
+    if let Some(it0) = &node.run_style {
+        Visit::visit_ct_string(v, it0);
+    }
+    if let Some(it0) = &node.fonts {
+        Visit::visit_fonts(v, it0);
+    }
+    if let Some(it0) = &node.bold {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.b_cs {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.italic {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.i_cs {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.caps {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.small_caps {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.strikethrough {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.dstrike {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.outline {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.shadow {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.emboss {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.imprint {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.no_proof {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.snap_to_grid {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.vanish {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.web_hidden {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.color {
+        Visit::visit_ct_color(v, it0);
+    }
+    if let Some(it0) = &node.spacing {
+        Visit::visit_signed_twips_measure_element(v, it0);
+    }
+    if let Some(it0) = &node.width {
+        Visit::visit_text_scale_element(v, it0);
+    }
+    if let Some(it0) = &node.kern {
+        Visit::visit_hps_measure_element(v, it0);
+    }
+    if let Some(it0) = &node.position {
+        Visit::visit_signed_hps_measure_element(v, it0);
+    }
+    if let Some(it0) = &node.size {
+        Visit::visit_hps_measure_element(v, it0);
+    }
+    if let Some(it0) = &node.size_complex_script {
+        Visit::visit_hps_measure_element(v, it0);
+    }
+    if let Some(it0) = &node.highlight {
+        Visit::visit_ct_highlight(v, it0);
+    }
+    if let Some(it0) = &node.underline {
+        Visit::visit_ct_underline(v, it0);
+    }
+    if let Some(it0) = &node.effect {
+        Visit::visit_ct_text_effect(v, it0);
+    }
+    if let Some(it0) = &node.bdr {
+        Visit::visit_ct_border(v, it0);
+    }
+    if let Some(it0) = &node.shading {
+        Visit::visit_ct_shd(v, it0);
+    }
+    if let Some(it0) = &node.fit_text {
+        Visit::visit_ct_fit_text(v, it0);
+    }
+    if let Some(it0) = &node.vert_align {
+        Visit::visit_ct_vertical_align_run(v, it0);
+    }
+    if let Some(it0) = &node.rtl {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.cs {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.em {
+        Visit::visit_ct_em(v, it0);
+    }
+    if let Some(it0) = &node.lang {
+        Visit::visit_language_element(v, it0);
+    }
+    if let Some(it0) = &node.east_asian_layout {
+        Visit::visit_ct_east_asian_layout(v, it0);
+    }
+    if let Some(it0) = &node.spec_vanish {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.o_math {
+        Visit::visit_on_off_element(v, it0);
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &node.r_pr_change {
+            Visit::visit_ct_para_r_pr_change(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_para_r_pr_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTParaRPr) {
+    if let Some(it0) = &mut node.ins {
+        VisitMut::visit_ct_track_change_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.del {
+        VisitMut::visit_ct_track_change_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.move_from {
+        VisitMut::visit_ct_track_change_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.move_to {
+        VisitMut::visit_ct_track_change_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.run_style {
+        VisitMut::visit_ct_string_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.fonts {
+        VisitMut::visit_fonts_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.bold {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.b_cs {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.italic {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.i_cs {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.caps {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.small_caps {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.strikethrough {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.dstrike {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.outline {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.shadow {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.emboss {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.imprint {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.no_proof {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.snap_to_grid {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.vanish {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.web_hidden {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.color {
+        VisitMut::visit_ct_color_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.spacing {
+        VisitMut::visit_signed_twips_measure_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.width {
+        VisitMut::visit_text_scale_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.kern {
+        VisitMut::visit_hps_measure_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.position {
+        VisitMut::visit_signed_hps_measure_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.size {
+        VisitMut::visit_hps_measure_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.size_complex_script {
+        VisitMut::visit_hps_measure_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.highlight {
+        VisitMut::visit_ct_highlight_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.underline {
+        VisitMut::visit_ct_underline_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.effect {
+        VisitMut::visit_ct_text_effect_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.bdr {
+        VisitMut::visit_ct_border_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.shading {
+        VisitMut::visit_ct_shd_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.fit_text {
+        VisitMut::visit_ct_fit_text_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.vert_align {
+        VisitMut::visit_ct_vertical_align_run_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.rtl {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.cs {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.em {
+        VisitMut::visit_ct_em_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.lang {
+        VisitMut::visit_language_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.east_asian_layout {
+        VisitMut::visit_ct_east_asian_layout_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.spec_vanish {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.o_math {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &mut node.r_pr_change {
+            VisitMut::visit_ct_para_r_pr_change_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_para_r_pr_change<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTParaRPrChange,
+) {
+    #[cfg(feature = "wml-track-changes")]
+    {
+        Visit::visit_ct_para_r_pr_original(v, &node.r_pr);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_para_r_pr_change_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTParaRPrChange) {
+    #[cfg(feature = "wml-track-changes")]
+    {
+        VisitMut::visit_ct_para_r_pr_original_mut(v, &mut node.r_pr);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_para_r_pr_original<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTParaRPrOriginal,
+) {
+    if let Some(it0) = &node.ins {
+        Visit::visit_ct_track_change(v, it0);
+    }
+    if let Some(it0) = &node.del {
+        Visit::visit_ct_track_change(v, it0);
+    }
+    if let Some(it0) = &node.move_from {
+        Visit::visit_ct_track_change(v, it0);
+    }
+    if let Some(it0) = &node.move_to {
+        Visit::visit_ct_track_change(v, it0);
+    }
+    if let Some(it0) = &node.run_style {
+        Visit::visit_ct_string(v, it0);
+    }
+    if let Some(it0) = &node.fonts {
+        Visit::visit_fonts(v, it0);
+    }
+    if let Some(it0) = &node.bold {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.b_cs {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.italic {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.i_cs {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.caps {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.small_caps {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.strikethrough {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.dstrike {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.outline {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.shadow {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.emboss {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.imprint {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.no_proof {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.snap_to_grid {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.vanish {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.web_hidden {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.color {
+        Visit::visit_ct_color(v, it0);
+    }
+    if let Some(it0) = &node.spacing {
+        Visit::visit_signed_twips_measure_element(v, it0);
+    }
+    if let Some(it0) = &node.width {
+        Visit::visit_text_scale_element(v, it0);
+    }
+    if let Some(it0) = &node.kern {
+        Visit::visit_hps_measure_element(v, it0);
+    }
+    if let Some(it0) = &node.position {
+        Visit::visit_signed_hps_measure_element(v, it0);
+    }
+    if let Some(it0) = &node.size {
+        Visit::visit_hps_measure_element(v, it0);
+    }
+    if let Some(it0) = &node.size_complex_script {
+        Visit::visit_hps_measure_element(v, it0);
+    }
+    if let Some(it0) = &node.highlight {
+        Visit::visit_ct_highlight(v, it0);
+    }
+    if let Some(it0) = &node.underline {
+        Visit::visit_ct_underline(v, it0);
+    }
+    if let Some(it0) = &node.effect {
+        Visit::visit_ct_text_effect(v, it0);
+    }
+    if let Some(it0) = &node.bdr {
+        Visit::visit_ct_border(v, it0);
+    }
+    if let Some(it0) = &node.shading {
+        Visit::visit_ct_shd(v, it0);
+    }
+    if let Some(it0) = &node.fit_text {
+        Visit::visit_ct_fit_text(v, it0);
+    }
+    if let Some(it0) = &node.vert_align {
+        Visit::visit_ct_vertical_align_run(v, it0);
+    }
+    if let Some(it0) = &node.rtl {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.cs {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.em {
+        Visit::visit_ct_em(v, it0);
+    }
+    if let Some(it0) = &node.lang {
+        Visit::visit_language_element(v, it0);
+    }
+    if let Some(it0) = &node.east_asian_layout {
+        Visit::visit_ct_east_asian_layout(v, it0);
+    }
+    if let Some(it0) = &node.spec_vanish {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.o_math {
+        Visit::visit_on_off_element(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_para_r_pr_original_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTParaRPrOriginal,
+) {
+    if let Some(it0) = &mut node.ins {
+        VisitMut::visit_ct_track_change_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.del {
+        VisitMut::visit_ct_track_change_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.move_from {
+        VisitMut::visit_ct_track_change_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.move_to {
+        VisitMut::visit_ct_track_change_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.run_style {
+        VisitMut::visit_ct_string_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.fonts {
+        VisitMut::visit_fonts_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.bold {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.b_cs {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.italic {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.i_cs {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.caps {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.small_caps {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.strikethrough {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.dstrike {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.outline {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.shadow {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.emboss {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.imprint {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.no_proof {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.snap_to_grid {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.vanish {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.web_hidden {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.color {
+        VisitMut::visit_ct_color_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.spacing {
+        VisitMut::visit_signed_twips_measure_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.width {
+        VisitMut::visit_text_scale_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.kern {
+        VisitMut::visit_hps_measure_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.position {
+        VisitMut::visit_signed_hps_measure_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.size {
+        VisitMut::visit_hps_measure_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.size_complex_script {
+        VisitMut::visit_hps_measure_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.highlight {
+        VisitMut::visit_ct_highlight_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.underline {
+        VisitMut::visit_ct_underline_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.effect {
+        VisitMut::visit_ct_text_effect_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.bdr {
+        VisitMut::visit_ct_border_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.shading {
+        VisitMut::visit_ct_shd_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.fit_text {
+        VisitMut::visit_ct_fit_text_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.vert_align {
+        VisitMut::visit_ct_vertical_align_run_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.rtl {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.cs {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.em {
+        VisitMut::visit_ct_em_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.lang {
+        VisitMut::visit_language_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.east_asian_layout {
+        VisitMut::visit_ct_east_asian_layout_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.spec_vanish {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.o_math {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_perm<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTPerm) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.displaced_by_custom_xml {
+            Visit::visit_st_displaced_by_custom_xml(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_perm_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTPerm) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.displaced_by_custom_xml {
+            VisitMut::visit_st_displaced_by_custom_xml_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_perm_start<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTPermStart) {
+    if let Some(it0) = &node.displaced_by_custom_xml {
+        Visit::visit_st_displaced_by_custom_xml(v, it0);
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.ed_grp {
+            Visit::visit_st_ed_grp(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_perm_start_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTPermStart) {
+    if let Some(it0) = &mut node.displaced_by_custom_xml {
+        VisitMut::visit_st_displaced_by_custom_xml_mut(v, it0);
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.ed_grp {
+            VisitMut::visit_st_ed_grp_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_picture<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTPicture) {
+    #[cfg(feature = "wml-drawings")]
+    {
+        if let Some(it0) = &node.movie {
+            Visit::visit_ct_rel(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-drawings")]
+    {
+        if let Some(it0) = &node.control {
+            Visit::visit_ct_control(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_picture_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTPicture) {
+    #[cfg(feature = "wml-drawings")]
+    {
+        if let Some(it0) = &mut node.movie {
+            VisitMut::visit_ct_rel_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-drawings")]
+    {
+        if let Some(it0) = &mut node.control {
+            VisitMut::visit_ct_control_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_pitch<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTPitch) {
+    Visit::visit_st_pitch(v, &node.value);
+}
+
+pub fn walk_ct_pitch_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTPitch) {
+    VisitMut::visit_st_pitch_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_proof<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTProof) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.spelling {
+            Visit::visit_st_proof(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.grammar {
+            Visit::visit_st_proof(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_proof_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTProof) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.spelling {
+            VisitMut::visit_st_proof_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.grammar {
+            VisitMut::visit_st_proof_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_proof_err<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTProofErr) {
+    Visit::visit_st_proof_err(v, &node.r#type);
+}
+
+pub fn walk_ct_proof_err_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTProofErr) {
+    VisitMut::visit_st_proof_err_mut(v, &mut node.r#type);
+}
+
+pub fn walk_ctr_pr_change<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTRPrChange) {
+    #[cfg(feature = "wml-track-changes")]
+    {
+        Visit::visit_ctr_pr_original(v, &node.r_pr);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ctr_pr_change_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTRPrChange) {
+    #[cfg(feature = "wml-track-changes")]
+    {
+        VisitMut::visit_ctr_pr_original_mut(v, &mut node.r_pr);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ctr_pr_original<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTRPrOriginal) {
+    if let Some(it0) = &node.run_style {
+        Visit::visit_ct_string(v, it0);
+    }
+    if let Some(it0) = &node.fonts {
+        Visit::visit_fonts(v, it0);
+    }
+    if let Some(it0) = &node.bold {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.b_cs {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.italic {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.i_cs {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.caps {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.small_caps {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.strikethrough {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.dstrike {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.outline {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.shadow {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.emboss {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.imprint {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.no_proof {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.snap_to_grid {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.vanish {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.web_hidden {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.color {
+        Visit::visit_ct_color(v, it0);
+    }
+    if let Some(it0) = &node.spacing {
+        Visit::visit_signed_twips_measure_element(v, it0);
+    }
+    if let Some(it0) = &node.width {
+        Visit::visit_text_scale_element(v, it0);
+    }
+    if let Some(it0) = &node.kern {
+        Visit::visit_hps_measure_element(v, it0);
+    }
+    if let Some(it0) = &node.position {
+        Visit::visit_signed_hps_measure_element(v, it0);
+    }
+    if let Some(it0) = &node.size {
+        Visit::visit_hps_measure_element(v, it0);
+    }
+    if let Some(it0) = &node.size_complex_script {
+        Visit::visit_hps_measure_element(v, it0);
+    }
+    if let Some(it0) = &node.highlight {
+        Visit::visit_ct_highlight(v, it0);
+    }
+    if let Some(it0) = &node.underline {
+        Visit::visit_ct_underline(v, it0);
+    }
+    if let Some(it0) = &node.effect {
+        Visit::visit_ct_text_effect(v, it0);
+    }
+    if let Some(it0) = &node.bdr {
+        Visit::visit_ct_border(v, it0);
+    }
+    if let Some(it0) = &node.shading {
+        Visit::visit_ct_shd(v, it0);
+    }
+    if let Some(it0) = &node.fit_text {
+        Visit::visit_ct_fit_text(v, it0);
+    }
+    if let Some(it0) = &node.vert_align {
+        Visit::visit_ct_vertical_align_run(v, it0);
+    }
+    if let Some(it0) = &node.rtl {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.cs {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.em {
+        Visit::visit_ct_em(v, it0);
+    }
+    if let Some(it0) = &node.lang {
+        Visit::visit_language_element(v, it0);
+    }
+    if let Some(it0) = &node.east_asian_layout {
+        Visit::visit_ct_east_asian_layout(v, it0);
+    }
+    if let Some(it0) = &node.spec_vanish {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.o_math {
+        Visit::visit_on_off_element(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ctr_pr_original_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTRPrOriginal) {
+    if let Some(it0) = &mut node.run_style {
+        VisitMut::visit_ct_string_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.fonts {
+        VisitMut::visit_fonts_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.bold {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.b_cs {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.italic {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.i_cs {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.caps {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.small_caps {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.strikethrough {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.dstrike {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.outline {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.shadow {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.emboss {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.imprint {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.no_proof {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.snap_to_grid {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.vanish {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.web_hidden {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.color {
+        VisitMut::visit_ct_color_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.spacing {
+        VisitMut::visit_signed_twips_measure_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.width {
+        VisitMut::visit_text_scale_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.kern {
+        VisitMut::visit_hps_measure_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.position {
+        VisitMut::visit_signed_hps_measure_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.size {
+        VisitMut::visit_hps_measure_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.size_complex_script {
+        VisitMut::visit_hps_measure_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.highlight {
+        VisitMut::visit_ct_highlight_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.underline {
+        VisitMut::visit_ct_underline_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.effect {
+        VisitMut::visit_ct_text_effect_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.bdr {
+        VisitMut::visit_ct_border_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.shading {
+        VisitMut::visit_ct_shd_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.fit_text {
+        VisitMut::visit_ct_fit_text_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.vert_align {
+        VisitMut::visit_ct_vertical_align_run_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.rtl {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.cs {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.em {
+        VisitMut::visit_ct_em_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.lang {
+        VisitMut::visit_language_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.east_asian_layout {
+        VisitMut::visit_ct_east_asian_layout_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.spec_vanish {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.o_math {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_recipient_data<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTRecipientData,
+) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.active {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        Visit::visit_ct_decimal_number(v, &node.column);
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        Visit::visit_ct_base64_binary(v, &node.unique_tag);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_recipient_data_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTRecipientData) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.active {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        VisitMut::visit_ct_decimal_number_mut(v, &mut node.column);
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        VisitMut::visit_ct_base64_binary_mut(v, &mut node.unique_tag);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_recipients<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTRecipients) {
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &node.recipient_data {
+            Visit::visit_ct_recipient_data(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_recipients_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTRecipients) {
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &mut node.recipient_data {
+            VisitMut::visit_ct_recipient_data_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_row<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTRow) {
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_pr_ex {
+            Visit::visit_ct_tbl_pr_ex(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.row_properties {
+            Visit::visit_table_row_properties(v, it0);
+        }
+    }
+    for it0 in &node.cells {
+        Visit::visit_cell_content(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_row_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTRow) {
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_pr_ex {
+            VisitMut::visit_ct_tbl_pr_ex_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.row_properties {
+            VisitMut::visit_table_row_properties_mut(v, it0);
+        }
+    }
+    for it0 in &mut node.cells {
+        VisitMut::visit_cell_content_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_ruby<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTRuby) {
+    #[cfg(feature = "wml-styling")]
+    {
+        Visit::visit_ct_ruby_pr(v, &node.ruby_pr);
+    }
+    Visit::visit_ct_ruby_content(v, &node.rt);
+    Visit::visit_ct_ruby_content(v, &node.ruby_base);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_ruby_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTRuby) {
+    #[cfg(feature = "wml-styling")]
+    {
+        VisitMut::visit_ct_ruby_pr_mut(v, &mut node.ruby_pr);
+    }
+    VisitMut::visit_ct_ruby_content_mut(v, &mut node.rt);
+    VisitMut::visit_ct_ruby_content_mut(v, &mut node.ruby_base);
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_ruby_align<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTRubyAlign) {
+    Visit::visit_st_ruby_align(v, &node.value);
+}
+
+pub fn walk_ct_ruby_align_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTRubyAlign) {
+    VisitMut::visit_st_ruby_align_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_ruby_content<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTRubyContent) {
+    for it0 in &node.ruby_content {
+        Visit::visit_ruby_content(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_ruby_content_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTRubyContent) {
+    for it0 in &mut node.ruby_content {
+        VisitMut::visit_ruby_content_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_ruby_pr<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTRubyPr) {
+    #[cfg(feature = "wml-styling")]
+    {
+        Visit::visit_ct_ruby_align(v, &node.ruby_align);
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        Visit::visit_hps_measure_element(v, &node.hps);
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        Visit::visit_hps_measure_element(v, &node.hps_raise);
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        Visit::visit_hps_measure_element(v, &node.hps_base_text);
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        Visit::visit_ct_lang(v, &node.lid);
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.dirty {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_ruby_pr_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTRubyPr) {
+    #[cfg(feature = "wml-styling")]
+    {
+        VisitMut::visit_ct_ruby_align_mut(v, &mut node.ruby_align);
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        VisitMut::visit_hps_measure_element_mut(v, &mut node.hps);
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        VisitMut::visit_hps_measure_element_mut(v, &mut node.hps_raise);
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        VisitMut::visit_hps_measure_element_mut(v, &mut node.hps_base_text);
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        VisitMut::visit_ct_lang_mut(v, &mut node.lid);
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.dirty {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_run_track_change<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTRunTrackChange,
+) {
+    #[cfg(feature = "wml-track-changes")]
+    {
+        for it0 in &node.run_content {
+            Visit::visit_run_content_choice(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_run_track_change_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTRunTrackChange) {
+    #[cfg(feature = "wml-track-changes")]
+    {
+        for it0 in &mut node.run_content {
+            VisitMut::visit_run_content_choice_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_block<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTSdtBlock) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.sdt_pr {
+            Visit::visit_ct_sdt_pr(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.sdt_end_pr {
+            Visit::visit_ct_sdt_end_pr(v, it0);
+        }
+    }
+    if let Some(it0) = &node.sdt_content {
+        Visit::visit_ct_sdt_content_block(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_block_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSdtBlock) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.sdt_pr {
+            VisitMut::visit_ct_sdt_pr_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.sdt_end_pr {
+            VisitMut::visit_ct_sdt_end_pr_mut(v, it0);
+        }
+    }
+    if let Some(it0) = &mut node.sdt_content {
+        VisitMut::visit_ct_sdt_content_block_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_cell<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTSdtCell) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.sdt_pr {
+            Visit::visit_ct_sdt_pr(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.sdt_end_pr {
+            Visit::visit_ct_sdt_end_pr(v, it0);
+        }
+    }
+    if let Some(it0) = &node.sdt_content {
+        Visit::visit_ct_sdt_content_cell(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_cell_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSdtCell) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.sdt_pr {
+            VisitMut::visit_ct_sdt_pr_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.sdt_end_pr {
+            VisitMut::visit_ct_sdt_end_pr_mut(v, it0);
+        }
+    }
+    if let Some(it0) = &mut node.sdt_content {
+        VisitMut::visit_ct_sdt_content_cell_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_combo_box<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTSdtComboBox) {
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &node.list_item {
+            Visit::visit_ct_sdt_list_item(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_combo_box_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSdtComboBox) {
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &mut node.list_item {
+            VisitMut::visit_ct_sdt_list_item_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_content_block<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTSdtContentBlock,
+) {
+    for it0 in &node.block_content {
+        Visit::visit_block_content_choice(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_content_block_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTSdtContentBlock,
+) {
+    for it0 in &mut node.block_content {
+        VisitMut::visit_block_content_choice_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_content_cell<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTSdtContentCell,
+) {
+    for it0 in &node.cells {
+        Visit::visit_cell_content(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_content_cell_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSdtContentCell) {
+    for it0 in &mut node.cells {
+        VisitMut::visit_cell_content_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_content_row<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTSdtContentRow,
+) {
+    for it0 in &node.rows {
+        Visit::visit_row_content(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_content_row_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSdtContentRow) {
+    for it0 in &mut node.rows {
+        VisitMut::visit_row_content_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_content_run<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTSdtContentRun,
+) {
+    for it0 in &node.paragraph_content {
+        Visit::visit_paragraph_content(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_content_run_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSdtContentRun) {
+    for it0 in &mut node.paragraph_content {
+        VisitMut::visit_paragraph_content_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_date<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTSdtDate) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.date_format {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.lid {
+            Visit::visit_ct_lang(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.store_mapped_data_as {
+            Visit::visit_ct_sdt_date_mapping_type(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.calendar {
+            Visit::visit_ct_calendar_type(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_date_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSdtDate) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.date_format {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.lid {
+            VisitMut::visit_ct_lang_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.store_mapped_data_as {
+            VisitMut::visit_ct_sdt_date_mapping_type_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.calendar {
+            VisitMut::visit_ct_calendar_type_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_date_mapping_type<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTSdtDateMappingType,
+) {
+    if let Some(it0) = &node.value {
+        Visit::visit_st_sdt_date_mapping_type(v, it0);
+    }
+}
+
+pub fn walk_ct_sdt_date_mapping_type_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTSdtDateMappingType,
+) {
+    if let Some(it0) = &mut node.value {
+        VisitMut::visit_st_sdt_date_mapping_type_mut(v, it0);
+    }
+}
+
+pub fn walk_ct_sdt_doc_part<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTSdtDocPart) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.doc_part_gallery {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.doc_part_category {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.doc_part_unique {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_doc_part_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSdtDocPart) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.doc_part_gallery {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.doc_part_category {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.doc_part_unique {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_drop_down_list<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTSdtDropDownList,
+) {
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &node.list_item {
+            Visit::visit_ct_sdt_list_item(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_drop_down_list_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTSdtDropDownList,
+) {
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &mut node.list_item {
+            VisitMut::visit_ct_sdt_list_item_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_end_pr<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTSdtEndPr) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.r_pr {
+            Visit::visit_run_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_end_pr_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSdtEndPr) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.r_pr {
+            VisitMut::visit_run_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_pr<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTSdtPr) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.r_pr {
+            Visit::visit_run_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.alias {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.tag {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.id {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.lock {
+            Visit::visit_ct_lock(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.placeholder {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.temporary {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.showing_plc_hdr {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.data_binding {
+            Visit::visit_ct_data_binding(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.label {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.tab_index {
+            Visit::visit_unsigned_decimal_number_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.equation {
+            Visit::visit_ct_empty(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.combo_box {
+            Visit::visit_ct_sdt_combo_box(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.date {
+            Visit::visit_ct_sdt_date(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.doc_part_obj {
+            Visit::visit_ct_sdt_doc_part(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.doc_part_list {
+            Visit::visit_ct_sdt_doc_part(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.drop_down_list {
+            Visit::visit_ct_sdt_drop_down_list(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.picture {
+            Visit::visit_ct_empty(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.rich_text {
+            Visit::visit_ct_empty(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.text {
+            Visit::visit_ct_sdt_text(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.citation {
+            Visit::visit_ct_empty(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.group {
+            Visit::visit_ct_empty(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.bibliography {
+            Visit::visit_ct_empty(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_pr_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSdtPr) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.r_pr {
+            VisitMut::visit_run_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.alias {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.tag {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.id {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.lock {
+            VisitMut::visit_ct_lock_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.placeholder {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.temporary {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.showing_plc_hdr {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.data_binding {
+            VisitMut::visit_ct_data_binding_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.label {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.tab_index {
+            VisitMut::visit_unsigned_decimal_number_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.equation {
+            VisitMut::visit_ct_empty_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.combo_box {
+            VisitMut::visit_ct_sdt_combo_box_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.date {
+            VisitMut::visit_ct_sdt_date_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.doc_part_obj {
+            VisitMut::visit_ct_sdt_doc_part_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.doc_part_list {
+            VisitMut::visit_ct_sdt_doc_part_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.drop_down_list {
+            VisitMut::visit_ct_sdt_drop_down_list_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.picture {
+            VisitMut::visit_ct_empty_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.rich_text {
+            VisitMut::visit_ct_empty_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.text {
+            VisitMut::visit_ct_sdt_text_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.citation {
+            VisitMut::visit_ct_empty_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.group {
+            VisitMut::visit_ct_empty_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.bibliography {
+            VisitMut::visit_ct_empty_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_row<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTSdtRow) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.sdt_pr {
+            Visit::visit_ct_sdt_pr(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.sdt_end_pr {
+            Visit::visit_ct_sdt_end_pr(v, it0);
+        }
+    }
+    if let Some(it0) = &node.sdt_content {
+        Visit::visit_ct_sdt_content_row(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_row_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSdtRow) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.sdt_pr {
+            VisitMut::visit_ct_sdt_pr_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.sdt_end_pr {
+            VisitMut::visit_ct_sdt_end_pr_mut(v, it0);
+        }
+    }
+    if let Some(it0) = &mut node.sdt_content {
+        VisitMut::visit_ct_sdt_content_row_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_run<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTSdtRun) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.sdt_pr {
+            Visit::visit_ct_sdt_pr(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.sdt_end_pr {
+            Visit::visit_ct_sdt_end_pr(v, it0);
+        }
+    }
+    if let Some(it0) = &node.sdt_content {
+        Visit::visit_ct_sdt_content_run(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sdt_run_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSdtRun) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.sdt_pr {
+            VisitMut::visit_ct_sdt_pr_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.sdt_end_pr {
+            VisitMut::visit_ct_sdt_end_pr_mut(v, it0);
+        }
+    }
+    if let Some(it0) = &mut node.sdt_content {
+        VisitMut::visit_ct_sdt_content_run_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sect_pr_base<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTSectPrBase) {
+    if let Some(it0) = &node.footnote_pr {
+        Visit::visit_ct_ftn_props(v, it0);
+    }
+    if let Some(it0) = &node.endnote_pr {
+        Visit::visit_ct_edn_props(v, it0);
+    }
+    if let Some(it0) = &node.r#type {
+        Visit::visit_ct_sect_type(v, it0);
+    }
+    if let Some(it0) = &node.pg_sz {
+        Visit::visit_page_size(v, it0);
+    }
+    if let Some(it0) = &node.pg_mar {
+        Visit::visit_page_margins(v, it0);
+    }
+    if let Some(it0) = &node.paper_src {
+        Visit::visit_ct_paper_source(v, it0);
+    }
+    if let Some(it0) = &node.pg_borders {
+        Visit::visit_ct_page_borders(v, it0);
+    }
+    if let Some(it0) = &node.ln_num_type {
+        Visit::visit_ct_line_number(v, it0);
+    }
+    if let Some(it0) = &node.pg_num_type {
+        Visit::visit_ct_page_number(v, it0);
+    }
+    if let Some(it0) = &node.cols {
+        Visit::visit_columns(v, it0);
+    }
+    if let Some(it0) = &node.form_prot {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.v_align {
+        Visit::visit_ct_vertical_jc(v, it0);
+    }
+    if let Some(it0) = &node.no_endnote {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.title_pg {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.text_direction {
+        Visit::visit_ct_text_direction(v, it0);
+    }
+    if let Some(it0) = &node.bidi {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.rtl_gutter {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.doc_grid {
+        Visit::visit_document_grid(v, it0);
+    }
+    if let Some(it0) = &node.printer_settings {
+        Visit::visit_ct_rel(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sect_pr_base_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSectPrBase) {
+    if let Some(it0) = &mut node.footnote_pr {
+        VisitMut::visit_ct_ftn_props_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.endnote_pr {
+        VisitMut::visit_ct_edn_props_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.r#type {
+        VisitMut::visit_ct_sect_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.pg_sz {
+        VisitMut::visit_page_size_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.pg_mar {
+        VisitMut::visit_page_margins_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.paper_src {
+        VisitMut::visit_ct_paper_source_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.pg_borders {
+        VisitMut::visit_ct_page_borders_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.ln_num_type {
+        VisitMut::visit_ct_line_number_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.pg_num_type {
+        VisitMut::visit_ct_page_number_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.cols {
+        VisitMut::visit_columns_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.form_prot {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.v_align {
+        VisitMut::visit_ct_vertical_jc_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.no_endnote {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.title_pg {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.text_direction {
+        VisitMut::visit_ct_text_direction_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.bidi {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.rtl_gutter {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.doc_grid {
+        VisitMut::visit_document_grid_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.printer_settings {
+        VisitMut::visit_ct_rel_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sect_pr_change<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTSectPrChange,
+) {
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &node.sect_pr {
+            Visit::visit_ct_sect_pr_base(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sect_pr_change_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSectPrChange) {
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &mut node.sect_pr {
+            VisitMut::visit_ct_sect_pr_base_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_sect_type<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTSectType) {
+    if let Some(it0) = &node.value {
+        Visit::visit_st_section_mark(v, it0);
+    }
+}
+
+pub fn walk_ct_sect_type_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSectType) {
+    if let Some(it0) = &mut node.value {
+        VisitMut::visit_st_section_mark_mut(v, it0);
+    }
+}
+
+pub fn walk_ct_shape_defaults<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTShapeDefaults,
+) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_shape_defaults_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTShapeDefaults) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_shd<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTShd) {
+    Visit::visit_st_shd(v, &node.value);
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.theme_color {
+            Visit::visit_st_theme_color(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.theme_fill {
+            Visit::visit_st_theme_color(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_shd_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTShd) {
+    VisitMut::visit_st_shd_mut(v, &mut node.value);
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.theme_color {
+            VisitMut::visit_st_theme_color_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.theme_fill {
+            VisitMut::visit_st_theme_color_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_simple_field<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTSimpleField) {
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &node.fld_data {
+            Visit::visit_text(v, it0);
+        }
+    }
+    for it0 in &node.paragraph_content {
+        Visit::visit_paragraph_content(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_simple_field_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSimpleField) {
+    #[cfg(feature = "wml-fields")]
+    {
+        if let Some(it0) = &mut node.fld_data {
+            VisitMut::visit_text_mut(v, it0);
+        }
+    }
+    for it0 in &mut node.paragraph_content {
+        VisitMut::visit_paragraph_content_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_smart_tag_pr<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTSmartTagPr) {
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &node.attr {
+            Visit::visit_ct_attr(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_smart_tag_pr_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSmartTagPr) {
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &mut node.attr {
+            VisitMut::visit_ct_attr_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_smart_tag_run<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTSmartTagRun) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.smart_tag_pr {
+            Visit::visit_ct_smart_tag_pr(v, it0);
+        }
+    }
+    for it0 in &node.paragraph_content {
+        Visit::visit_paragraph_content(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_smart_tag_run_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSmartTagRun) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.smart_tag_pr {
+            VisitMut::visit_ct_smart_tag_pr_mut(v, it0);
+        }
+    }
+    for it0 in &mut node.paragraph_content {
+        VisitMut::visit_paragraph_content_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_spacing<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTSpacing) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.line_rule {
+            Visit::visit_st_line_spacing_rule(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_spacing_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTSpacing) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.line_rule {
+            VisitMut::visit_st_line_spacing_rule_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_style_sort<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTStyleSort) {
+    Visit::visit_st_style_sort(v, &node.value);
+}
+
+pub fn walk_ct_style_sort_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTStyleSort) {
+    VisitMut::visit_st_style_sort_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_tab_stop<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTTabStop) {
+    Visit::visit_st_tab_jc(v, &node.value);
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.leader {
+            Visit::visit_st_tab_tlc(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_tab_stop_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTabStop) {
+    VisitMut::visit_st_tab_jc_mut(v, &mut node.value);
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.leader {
+            VisitMut::visit_st_tab_tlc_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_tabs<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTTabs) {
+    for it0 in &node.tab {
+        Visit::visit_ct_tab_stop(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tabs_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTabs) {
+    for it0 in &mut node.tab {
+        VisitMut::visit_ct_tab_stop_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_target_screen_sz<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTargetScreenSz,
+) {
+    Visit::visit_st_target_screen_sz(v, &node.value);
+}
+
+pub fn walk_ct_target_screen_sz_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTargetScreenSz) {
+    VisitMut::visit_st_target_screen_sz_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_tbl_borders<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTTblBorders) {
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.top {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.start {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.left {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.bottom {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.end {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.right {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.inside_h {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.inside_v {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tbl_borders_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTblBorders) {
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.top {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.start {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.left {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.bottom {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.end {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.right {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.inside_h {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.inside_v {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tbl_cell_mar<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTTblCellMar) {
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.top {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.start {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.left {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.bottom {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.end {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.right {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tbl_cell_mar_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTblCellMar) {
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.top {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.start {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.left {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.bottom {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.end {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.right {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tbl_grid_base<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTTblGridBase) {
+    for it0 in &node.grid_col {
+        Visit::visit_table_grid_column(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tbl_grid_base_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTblGridBase) {
+    for it0 in &mut node.grid_col {
+        VisitMut::visit_table_grid_column_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tbl_grid_change<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTblGridChange,
+) {
+    #[cfg(feature = "wml-track-changes")]
+    {
+        Visit::visit_ct_tbl_grid_base(v, &node.tbl_grid);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tbl_grid_change_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTblGridChange) {
+    #[cfg(feature = "wml-track-changes")]
+    {
+        VisitMut::visit_ct_tbl_grid_base_mut(v, &mut node.tbl_grid);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tbl_layout_type<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTblLayoutType,
+) {
+    if let Some(it0) = &node.r#type {
+        Visit::visit_st_tbl_layout_type(v, it0);
+    }
+}
+
+pub fn walk_ct_tbl_layout_type_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTblLayoutType) {
+    if let Some(it0) = &mut node.r#type {
+        VisitMut::visit_st_tbl_layout_type_mut(v, it0);
+    }
+}
+
+pub fn walk_ct_tbl_overlap<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTTblOverlap) {
+    Visit::visit_st_tbl_overlap(v, &node.value);
+}
+
+pub fn walk_ct_tbl_overlap_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTblOverlap) {
+    VisitMut::visit_st_tbl_overlap_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_tbl_p_pr<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTTblPPr) {
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.vert_anchor {
+            Visit::visit_stv_anchor(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.horz_anchor {
+            Visit::visit_sth_anchor(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tblp_x_spec {
+            Visit::visit_stx_align(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tblp_y_spec {
+            Visit::visit_sty_align(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_tbl_p_pr_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTblPPr) {
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.vert_anchor {
+            VisitMut::visit_stv_anchor_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.horz_anchor {
+            VisitMut::visit_sth_anchor_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tblp_x_spec {
+            VisitMut::visit_stx_align_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tblp_y_spec {
+            VisitMut::visit_sty_align_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_tbl_pr_base<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTTblPrBase) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.tbl_style {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tblp_pr {
+            Visit::visit_ct_tbl_p_pr(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_overlap {
+            Visit::visit_ct_tbl_overlap(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.bidi_visual {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.tbl_style_row_band_size {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.tbl_style_col_band_size {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_w {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.justification {
+            Visit::visit_ct_jc_table(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_cell_spacing {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_ind {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_borders {
+            Visit::visit_ct_tbl_borders(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.shading {
+            Visit::visit_ct_shd(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_layout {
+            Visit::visit_ct_tbl_layout_type(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_cell_mar {
+            Visit::visit_ct_tbl_cell_mar(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_look {
+            Visit::visit_ct_tbl_look(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_caption {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_description {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tbl_pr_base_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTblPrBase) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.tbl_style {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tblp_pr {
+            VisitMut::visit_ct_tbl_p_pr_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_overlap {
+            VisitMut::visit_ct_tbl_overlap_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.bidi_visual {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.tbl_style_row_band_size {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.tbl_style_col_band_size {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_w {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.justification {
+            VisitMut::visit_ct_jc_table_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_cell_spacing {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_ind {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_borders {
+            VisitMut::visit_ct_tbl_borders_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.shading {
+            VisitMut::visit_ct_shd_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_layout {
+            VisitMut::visit_ct_tbl_layout_type_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_cell_mar {
+            VisitMut::visit_ct_tbl_cell_mar_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_look {
+            VisitMut::visit_ct_tbl_look_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_caption {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_description {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tbl_pr_change<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTTblPrChange) {
+    #[cfg(feature = "wml-track-changes")]
+    {
+        Visit::visit_ct_tbl_pr_base(v, &node.table_properties);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tbl_pr_change_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTblPrChange) {
+    #[cfg(feature = "wml-track-changes")]
+    {
+        VisitMut::visit_ct_tbl_pr_base_mut(v, &mut node.table_properties);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tbl_pr_ex<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTTblPrEx) {
+    if let Some(it0) = &node.tbl_w {
+        Visit::visit_ct_tbl_width(v, it0);
+    }
+    if let Some(it0) = &node.justification {
+        Visit::visit_ct_jc_table(v, it0);
+    }
+    if let Some(it0) = &node.tbl_cell_spacing {
+        Visit::visit_ct_tbl_width(v, it0);
+    }
+    if let Some(it0) = &node.tbl_ind {
+        Visit::visit_ct_tbl_width(v, it0);
+    }
+    if let Some(it0) = &node.tbl_borders {
+        Visit::visit_ct_tbl_borders(v, it0);
+    }
+    if let Some(it0) = &node.shading {
+        Visit::visit_ct_shd(v, it0);
+    }
+    if let Some(it0) = &node.tbl_layout {
+        Visit::visit_ct_tbl_layout_type(v, it0);
+    }
+    if let Some(it0) = &node.tbl_cell_mar {
+        Visit::visit_ct_tbl_cell_mar(v, it0);
+    }
+    if let Some(it0) = &node.tbl_look {
+        Visit::visit_ct_tbl_look(v, it0);
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &node.tbl_pr_ex_change {
+            Visit::visit_ct_tbl_pr_ex_change(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tbl_pr_ex_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTblPrEx) {
+    if let Some(it0) = &mut node.tbl_w {
+        VisitMut::visit_ct_tbl_width_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.justification {
+        VisitMut::visit_ct_jc_table_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.tbl_cell_spacing {
+        VisitMut::visit_ct_tbl_width_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.tbl_ind {
+        VisitMut::visit_ct_tbl_width_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.tbl_borders {
+        VisitMut::visit_ct_tbl_borders_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.shading {
+        VisitMut::visit_ct_shd_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.tbl_layout {
+        VisitMut::visit_ct_tbl_layout_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.tbl_cell_mar {
+        VisitMut::visit_ct_tbl_cell_mar_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.tbl_look {
+        VisitMut::visit_ct_tbl_look_mut(v, it0);
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &mut node.tbl_pr_ex_change {
+            VisitMut::visit_ct_tbl_pr_ex_change_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tbl_pr_ex_base<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTTblPrExBase) {
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_w {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.justification {
+            Visit::visit_ct_jc_table(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_cell_spacing {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_ind {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_borders {
+            Visit::visit_ct_tbl_borders(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.shading {
+            Visit::visit_ct_shd(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_layout {
+            Visit::visit_ct_tbl_layout_type(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_cell_mar {
+            Visit::visit_ct_tbl_cell_mar(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_look {
+            Visit::visit_ct_tbl_look(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tbl_pr_ex_base_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTblPrExBase) {
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_w {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.justification {
+            VisitMut::visit_ct_jc_table_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_cell_spacing {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_ind {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_borders {
+            VisitMut::visit_ct_tbl_borders_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.shading {
+            VisitMut::visit_ct_shd_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_layout {
+            VisitMut::visit_ct_tbl_layout_type_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_cell_mar {
+            VisitMut::visit_ct_tbl_cell_mar_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_look {
+            VisitMut::visit_ct_tbl_look_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tbl_pr_ex_change<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTblPrExChange,
+) {
+    #[cfg(feature = "wml-track-changes")]
+    {
+        Visit::visit_ct_tbl_pr_ex_base(v, &node.tbl_pr_ex);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tbl_pr_ex_change_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTblPrExChange) {
+    #[cfg(feature = "wml-track-changes")]
+    {
+        VisitMut::visit_ct_tbl_pr_ex_base_mut(v, &mut node.tbl_pr_ex);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tbl_width<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTTblWidth) {
+    if let Some(it0) = &node.r#type {
+        Visit::visit_st_tbl_width(v, it0);
+    }
+}
+
+pub fn walk_ct_tbl_width_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTblWidth) {
+    if let Some(it0) = &mut node.r#type {
+        VisitMut::visit_st_tbl_width_mut(v, it0);
+    }
+}
+
+pub fn walk_ct_tc_borders<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTTcBorders) {
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.top {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.start {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.left {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.bottom {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.end {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.right {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.inside_h {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.inside_v {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tl2br {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tr2bl {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tc_borders_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTcBorders) {
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.top {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.start {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.left {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.bottom {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.end {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.right {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.inside_h {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.inside_v {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tl2br {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tr2bl {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tc_mar<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTTcMar) {
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.top {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.start {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.left {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.bottom {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.end {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.right {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tc_mar_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTcMar) {
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.top {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.start {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.left {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.bottom {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.end {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.right {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tc_pr_base<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTTcPrBase) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.cnf_style {
+            Visit::visit_ct_cnf(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tc_w {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.grid_span {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.horizontal_merge {
+            Visit::visit_cth_merge(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.vertical_merge {
+            Visit::visit_ctv_merge(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tc_borders {
+            Visit::visit_ct_tc_borders(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.shading {
+            Visit::visit_ct_shd(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.no_wrap {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tc_mar {
+            Visit::visit_ct_tc_mar(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.text_direction {
+            Visit::visit_ct_text_direction(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tc_fit_text {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.v_align {
+            Visit::visit_ct_vertical_jc(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.hide_mark {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.headers {
+            Visit::visit_ct_headers(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tc_pr_base_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTcPrBase) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.cnf_style {
+            VisitMut::visit_ct_cnf_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tc_w {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.grid_span {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.horizontal_merge {
+            VisitMut::visit_cth_merge_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.vertical_merge {
+            VisitMut::visit_ctv_merge_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tc_borders {
+            VisitMut::visit_ct_tc_borders_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.shading {
+            VisitMut::visit_ct_shd_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.no_wrap {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tc_mar {
+            VisitMut::visit_ct_tc_mar_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.text_direction {
+            VisitMut::visit_ct_text_direction_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tc_fit_text {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.v_align {
+            VisitMut::visit_ct_vertical_jc_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.hide_mark {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.headers {
+            VisitMut::visit_ct_headers_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tc_pr_change<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTTcPrChange) {
+    #[cfg(feature = "wml-track-changes")]
+    {
+        Visit::visit_ct_tc_pr_inner(v, &node.cell_properties);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tc_pr_change_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTcPrChange) {
+    #[cfg(feature = "wml-track-changes")]
+    {
+        VisitMut::visit_ct_tc_pr_inner_mut(v, &mut node.cell_properties);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tc_pr_inner<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTTcPrInner) {
+    if let Some(it0) = &node.cnf_style {
+        Visit::visit_ct_cnf(v, it0);
+    }
+    if let Some(it0) = &node.tc_w {
+        Visit::visit_ct_tbl_width(v, it0);
+    }
+    if let Some(it0) = &node.grid_span {
+        Visit::visit_ct_decimal_number(v, it0);
+    }
+    if let Some(it0) = &node.horizontal_merge {
+        Visit::visit_cth_merge(v, it0);
+    }
+    if let Some(it0) = &node.vertical_merge {
+        Visit::visit_ctv_merge(v, it0);
+    }
+    if let Some(it0) = &node.tc_borders {
+        Visit::visit_ct_tc_borders(v, it0);
+    }
+    if let Some(it0) = &node.shading {
+        Visit::visit_ct_shd(v, it0);
+    }
+    if let Some(it0) = &node.no_wrap {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.tc_mar {
+        Visit::visit_ct_tc_mar(v, it0);
+    }
+    if let Some(it0) = &node.text_direction {
+        Visit::visit_ct_text_direction(v, it0);
+    }
+    if let Some(it0) = &node.tc_fit_text {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.v_align {
+        Visit::visit_ct_vertical_jc(v, it0);
+    }
+    if let Some(it0) = &node.hide_mark {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.headers {
+        Visit::visit_ct_headers(v, it0);
+    }
+    if let Some(it0) = &node.cell_markup {
+        Visit::visit_cell_markup(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tc_pr_inner_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTcPrInner) {
+    if let Some(it0) = &mut node.cnf_style {
+        VisitMut::visit_ct_cnf_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.tc_w {
+        VisitMut::visit_ct_tbl_width_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.grid_span {
+        VisitMut::visit_ct_decimal_number_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.horizontal_merge {
+        VisitMut::visit_cth_merge_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.vertical_merge {
+        VisitMut::visit_ctv_merge_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.tc_borders {
+        VisitMut::visit_ct_tc_borders_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.shading {
+        VisitMut::visit_ct_shd_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.no_wrap {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.tc_mar {
+        VisitMut::visit_ct_tc_mar_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.text_direction {
+        VisitMut::visit_ct_text_direction_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.tc_fit_text {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.v_align {
+        VisitMut::visit_ct_vertical_jc_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.hide_mark {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.headers {
+        VisitMut::visit_ct_headers_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.cell_markup {
+        VisitMut::visit_cell_markup_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_text_alignment<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTextAlignment,
+) {
+    Visit::visit_st_text_alignment(v, &node.value);
+}
+
+pub fn walk_ct_text_alignment_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTextAlignment) {
+    VisitMut::visit_st_text_alignment_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_text_direction<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTextDirection,
+) {
+    Visit::visit_st_text_direction(v, &node.value);
+}
+
+pub fn walk_ct_text_direction_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTextDirection) {
+    VisitMut::visit_st_text_direction_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_text_effect<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTTextEffect) {
+    Visit::visit_st_text_effect(v, &node.value);
+}
+
+pub fn walk_ct_text_effect_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTextEffect) {
+    VisitMut::visit_st_text_effect_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_textbox_tight_wrap<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTextboxTightWrap,
+) {
+    Visit::visit_st_textbox_tight_wrap(v, &node.value);
+}
+
+pub fn walk_ct_textbox_tight_wrap_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTextboxTightWrap,
+) {
+    VisitMut::visit_st_textbox_tight_wrap_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_top_page_border<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTopPageBorder,
+) {
+    Visit::visit_st_border(v, &node.value);
+    if let Some(it0) = &node.theme_color {
+        Visit::visit_st_theme_color(v, it0);
+    }
+}
+
+pub fn walk_ct_top_page_border_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTopPageBorder) {
+    VisitMut::visit_st_border_mut(v, &mut node.value);
+    if let Some(it0) = &mut node.theme_color {
+        VisitMut::visit_st_theme_color_mut(v, it0);
+    }
+}
+
+pub fn walk_ct_tr_pr_base<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTTrPrBase) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.cnf_style {
+            Visit::visit_ct_cnf(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.div_id {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.grid_before {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.grid_after {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.w_before {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.w_after {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.cant_split {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tr_height {
+            Visit::visit_ct_height(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_header {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_cell_spacing {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.justification {
+            Visit::visit_ct_jc_table(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.hidden {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tr_pr_base_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTrPrBase) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.cnf_style {
+            VisitMut::visit_ct_cnf_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.div_id {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.grid_before {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.grid_after {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.w_before {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.w_after {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.cant_split {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tr_height {
+            VisitMut::visit_ct_height_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_header {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_cell_spacing {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.justification {
+            VisitMut::visit_ct_jc_table_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.hidden {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tr_pr_change<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTTrPrChange) {
+    #[cfg(feature = "wml-track-changes")]
+    {
+        Visit::visit_ct_tr_pr_base(v, &node.row_properties);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_tr_pr_change_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTrPrChange) {
+    #[cfg(feature = "wml-track-changes")]
+    {
+        VisitMut::visit_ct_tr_pr_base_mut(v, &mut node.row_properties);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_track_change_range<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTTrackChangeRange,
+) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.displaced_by_custom_xml {
+            Visit::visit_st_displaced_by_custom_xml(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_track_change_range_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTTrackChangeRange,
+) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.displaced_by_custom_xml {
+            VisitMut::visit_st_displaced_by_custom_xml_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_txbx_content<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTTxbxContent) {
+    for it0 in &node.block_content {
+        Visit::visit_block_content(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_txbx_content_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTTxbxContent) {
+    for it0 in &mut node.block_content {
+        VisitMut::visit_block_content_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_underline<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTUnderline) {
+    if let Some(it0) = &node.value {
+        Visit::visit_st_underline(v, it0);
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.theme_color {
+            Visit::visit_st_theme_color(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_underline_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTUnderline) {
+    if let Some(it0) = &mut node.value {
+        VisitMut::visit_st_underline_mut(v, it0);
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.theme_color {
+            VisitMut::visit_st_theme_color_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_ctv_merge<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTVMerge) {
+    if let Some(it0) = &node.value {
+        Visit::visit_st_merge(v, it0);
+    }
+}
+
+pub fn walk_ctv_merge_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTVMerge) {
+    if let Some(it0) = &mut node.value {
+        VisitMut::visit_st_merge_mut(v, it0);
+    }
+}
+
+pub fn walk_ct_vertical_align_run<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTVerticalAlignRun,
+) {
+    Visit::visit_st_vertical_align_run(v, &node.value);
+}
+
+pub fn walk_ct_vertical_align_run_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CTVerticalAlignRun,
+) {
+    VisitMut::visit_st_vertical_align_run_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_vertical_jc<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTVerticalJc) {
+    Visit::visit_st_vertical_jc(v, &node.value);
+}
+
+pub fn walk_ct_vertical_jc_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTVerticalJc) {
+    VisitMut::visit_st_vertical_jc_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_view<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTView) {
+    Visit::visit_st_view(v, &node.value);
+}
+
+pub fn walk_ct_view_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTView) {
+    VisitMut::visit_st_view_mut(v, &mut node.value);
+}
+
+pub fn walk_ct_web_settings<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTWebSettings) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.frameset {
+            Visit::visit_ct_frameset(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.divs {
+            Visit::visit_ct_divs(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.encoding {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.optimize_for_browser {
+            Visit::visit_ct_optimize_for_browser(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.rely_on_v_m_l {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.allow_p_n_g {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_rely_on_c_s_s {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_save_as_single_file {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_organize_in_folder {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_use_long_file_names {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.pixels_per_inch {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.target_screen_sz {
+            Visit::visit_ct_target_screen_sz(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.save_smart_tags_as_xml {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_web_settings_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTWebSettings) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.frameset {
+            VisitMut::visit_ct_frameset_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.divs {
+            VisitMut::visit_ct_divs_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.encoding {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.optimize_for_browser {
+            VisitMut::visit_ct_optimize_for_browser_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.rely_on_v_m_l {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.allow_p_n_g {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_rely_on_c_s_s {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_save_as_single_file {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_organize_in_folder {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_use_long_file_names {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.pixels_per_inch {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.target_screen_sz {
+            VisitMut::visit_ct_target_screen_sz_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.save_smart_tags_as_xml {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_ct_write_protection<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast CTWriteProtection,
+) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.crypt_provider_type {
+            Visit::visit_st_crypt_prov(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.crypt_algorithm_class {
+            Visit::visit_st_alg_class(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.crypt_algorithm_type {
+            Visit::visit_st_alg_type(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_write_protection_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTWriteProtection) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.crypt_provider_type {
+            VisitMut::visit_st_crypt_prov_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.crypt_algorithm_class {
+            VisitMut::visit_st_alg_class_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.crypt_algorithm_type {
+            VisitMut::visit_st_alg_type_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_zoom<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CTZoom) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.value {
+            Visit::visit_st_zoom(v, it0);
+        }
+    }
+}
+
+pub fn walk_ct_zoom_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CTZoom) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.value {
+            VisitMut::visit_st_zoom_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_cell_content<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CellContent) {
+    match node {
+        CellContent::Tc(it) => {
+            Visit::visit_table_cell(v, it);
+        }
+        CellContent::CustomXml(it) => {
+            Visit::visit_ct_custom_xml_cell(v, it);
+        }
+        CellContent::Sdt(it) => {
+            Visit::visit_ct_sdt_cell(v, it);
+        }
+        CellContent::ProofErr(it) => {
+            Visit::visit_ct_proof_err(v, it);
+        }
+        CellContent::PermStart(it) => {
+            Visit::visit_ct_perm_start(v, it);
+        }
+        CellContent::PermEnd(it) => {
+            Visit::visit_ct_perm(v, it);
+        }
+        CellContent::BookmarkStart(it) => {
+            Visit::visit_bookmark(v, it);
+        }
+        CellContent::BookmarkEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        CellContent::MoveFromRangeStart(it) => {
+            Visit::visit_ct_move_bookmark(v, it);
+        }
+        CellContent::MoveFromRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        CellContent::MoveToRangeStart(it) => {
+            Visit::visit_ct_move_bookmark(v, it);
+        }
+        CellContent::MoveToRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        CellContent::CommentRangeStart(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        CellContent::CommentRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        CellContent::CustomXmlInsRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        CellContent::CustomXmlInsRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        CellContent::CustomXmlDelRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        CellContent::CustomXmlDelRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        CellContent::CustomXmlMoveFromRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        CellContent::CustomXmlMoveFromRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        CellContent::CustomXmlMoveToRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        CellContent::CustomXmlMoveToRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        CellContent::Ins(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        CellContent::Del(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        CellContent::MoveFrom(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        CellContent::MoveTo(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+    }
+}
+
+pub fn walk_cell_content_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CellContent) {
+    match node {
+        CellContent::Tc(it) => {
+            VisitMut::visit_table_cell_mut(v, it);
+        }
+        CellContent::CustomXml(it) => {
+            VisitMut::visit_ct_custom_xml_cell_mut(v, it);
+        }
+        CellContent::Sdt(it) => {
+            VisitMut::visit_ct_sdt_cell_mut(v, it);
+        }
+        CellContent::ProofErr(it) => {
+            VisitMut::visit_ct_proof_err_mut(v, it);
+        }
+        CellContent::PermStart(it) => {
+            VisitMut::visit_ct_perm_start_mut(v, it);
+        }
+        CellContent::PermEnd(it) => {
+            VisitMut::visit_ct_perm_mut(v, it);
+        }
+        CellContent::BookmarkStart(it) => {
+            VisitMut::visit_bookmark_mut(v, it);
+        }
+        CellContent::BookmarkEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        CellContent::MoveFromRangeStart(it) => {
+            VisitMut::visit_ct_move_bookmark_mut(v, it);
+        }
+        CellContent::MoveFromRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        CellContent::MoveToRangeStart(it) => {
+            VisitMut::visit_ct_move_bookmark_mut(v, it);
+        }
+        CellContent::MoveToRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        CellContent::CommentRangeStart(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        CellContent::CommentRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        CellContent::CustomXmlInsRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        CellContent::CustomXmlInsRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        CellContent::CustomXmlDelRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        CellContent::CustomXmlDelRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        CellContent::CustomXmlMoveFromRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        CellContent::CustomXmlMoveFromRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        CellContent::CustomXmlMoveToRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        CellContent::CustomXmlMoveToRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        CellContent::Ins(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        CellContent::Del(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        CellContent::MoveFrom(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        CellContent::MoveTo(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+    }
+}
+
+pub fn walk_cell_markup<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast CellMarkup) {
+    match node {
+        CellMarkup::CellIns(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        CellMarkup::CellDel(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        CellMarkup::CellMerge(it) => {
+            Visit::visit_ct_cell_merge_track_change(v, it);
+        }
+    }
+}
+
+pub fn walk_cell_markup_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CellMarkup) {
+    match node {
+        CellMarkup::CellIns(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        CellMarkup::CellDel(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        CellMarkup::CellMerge(it) => {
+            VisitMut::visit_ct_cell_merge_track_change_mut(v, it);
+        }
+    }
+}
+
+pub fn walk_columns<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Columns) {
+    #[cfg(feature = "wml-layout")]
+    {
+        for it0 in &node.col {
+            Visit::visit_ct_column(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_columns_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Columns) {
+    #[cfg(feature = "wml-layout")]
+    {
+        for it0 in &mut node.col {
+            VisitMut::visit_ct_column_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_comment<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Comment) {
+    for it0 in &node.block_content {
+        Visit::visit_block_content(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_comment_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Comment) {
+    for it0 in &mut node.block_content {
+        VisitMut::visit_block_content_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_comments<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Comments) {
+    for it0 in &node.comment {
+        Visit::visit_comment(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_comments_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Comments) {
+    for it0 in &mut node.comment {
+        VisitMut::visit_comment_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_compatibility<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Compatibility) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.use_single_borderfor_contiguous_cells {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.wp_justification {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.no_tab_hang_ind {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.no_leading {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.space_for_u_l {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.no_column_balance {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.balance_single_byte_double_byte_width {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.no_extra_line_spacing {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_leave_backslash_alone {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.ul_trail_space {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_expand_shift_return {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.spacing_in_whole_points {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.line_wrap_like_word6 {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.print_body_text_before_header {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.print_col_black {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.wp_space_width {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.show_breaks_in_frames {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.sub_font_by_size {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.suppress_bottom_spacing {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.suppress_top_spacing {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.suppress_spacing_at_top_of_page {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.suppress_top_spacing_w_p {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.suppress_sp_bf_after_pg_brk {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.swap_borders_facing_pages {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.conv_mail_merge_esc {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.truncate_font_heights_like_w_p6 {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.mw_small_caps {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.use_printer_metrics {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_suppress_paragraph_borders {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.wrap_trail_spaces {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.footnote_layout_like_w_w8 {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.shape_layout_like_w_w8 {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.align_tables_row_by_row {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.forget_last_tab_alignment {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.adjust_line_height_in_table {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.auto_space_like_word95 {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.no_space_raise_lower {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_use_h_t_m_l_paragraph_auto_spacing {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.layout_raw_table_width {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.layout_table_rows_apart {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.use_word97_line_break_rules {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_break_wrapped_tables {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_snap_to_grid_in_cell {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.select_fld_with_first_or_last_char {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.apply_breaking_rules {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_wrap_text_with_punct {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_use_east_asian_break_rules {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.use_word2002_table_style_rules {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.grow_autofit {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.use_f_e_layout {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.use_normal_style_for_list {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_use_indent_as_numbering_tab_stop {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.use_alt_kinsoku_line_break_rules {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.allow_space_of_same_style_in_table {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_suppress_indentation {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_autofit_constrained_tables {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.autofit_to_first_fixed_width_cell {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.underline_tab_in_num_list {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.display_hangul_fixed_width {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.split_pg_break_and_para_mark {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_vert_align_cell_with_sp {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_break_constrained_forced_table {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_vert_align_in_txbx {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.use_ansi_kerning_pairs {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.cached_col_balance {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &node.compat_setting {
+            Visit::visit_ct_compat_setting(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_compatibility_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Compatibility) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.use_single_borderfor_contiguous_cells {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.wp_justification {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.no_tab_hang_ind {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.no_leading {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.space_for_u_l {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.no_column_balance {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.balance_single_byte_double_byte_width {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.no_extra_line_spacing {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_leave_backslash_alone {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.ul_trail_space {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_expand_shift_return {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.spacing_in_whole_points {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.line_wrap_like_word6 {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.print_body_text_before_header {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.print_col_black {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.wp_space_width {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.show_breaks_in_frames {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.sub_font_by_size {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.suppress_bottom_spacing {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.suppress_top_spacing {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.suppress_spacing_at_top_of_page {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.suppress_top_spacing_w_p {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.suppress_sp_bf_after_pg_brk {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.swap_borders_facing_pages {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.conv_mail_merge_esc {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.truncate_font_heights_like_w_p6 {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.mw_small_caps {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.use_printer_metrics {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_suppress_paragraph_borders {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.wrap_trail_spaces {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.footnote_layout_like_w_w8 {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.shape_layout_like_w_w8 {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.align_tables_row_by_row {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.forget_last_tab_alignment {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.adjust_line_height_in_table {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.auto_space_like_word95 {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.no_space_raise_lower {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_use_h_t_m_l_paragraph_auto_spacing {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.layout_raw_table_width {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.layout_table_rows_apart {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.use_word97_line_break_rules {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_break_wrapped_tables {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_snap_to_grid_in_cell {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.select_fld_with_first_or_last_char {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.apply_breaking_rules {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_wrap_text_with_punct {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_use_east_asian_break_rules {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.use_word2002_table_style_rules {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.grow_autofit {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.use_f_e_layout {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.use_normal_style_for_list {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_use_indent_as_numbering_tab_stop {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.use_alt_kinsoku_line_break_rules {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.allow_space_of_same_style_in_table {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_suppress_indentation {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_autofit_constrained_tables {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.autofit_to_first_fixed_width_cell {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.underline_tab_in_num_list {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.display_hangul_fixed_width {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.split_pg_break_and_para_mark {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_vert_align_cell_with_sp {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_break_constrained_forced_table {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_vert_align_in_txbx {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.use_ansi_kerning_pairs {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.cached_col_balance {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &mut node.compat_setting {
+            VisitMut::visit_ct_compat_setting_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_document<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Document) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.background {
+            Visit::visit_ct_background(v, it0);
+        }
+    }
+    if let Some(it0) = &node.body {
+        Visit::visit_body(v, it0);
+    }
+    if let Some(it0) = &node.conformance {
+        Visit::visit_st_conformance_class(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_document_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Document) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.background {
+            VisitMut::visit_ct_background_mut(v, it0);
+        }
+    }
+    if let Some(it0) = &mut node.body {
+        VisitMut::visit_body_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.conformance {
+        VisitMut::visit_st_conformance_class_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_document_defaults<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast DocumentDefaults,
+) {
+    if let Some(it0) = &node.r_pr_default {
+        Visit::visit_run_properties_default(v, it0);
+    }
+    if let Some(it0) = &node.p_pr_default {
+        Visit::visit_paragraph_properties_default(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_document_defaults_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DocumentDefaults) {
+    if let Some(it0) = &mut node.r_pr_default {
+        VisitMut::visit_run_properties_default_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.p_pr_default {
+        VisitMut::visit_paragraph_properties_default_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_document_grid<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast DocumentGrid) {
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.r#type {
+            Visit::visit_st_doc_grid(v, it0);
+        }
+    }
+}
+
+pub fn walk_document_grid_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DocumentGrid) {
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.r#type {
+            VisitMut::visit_st_doc_grid_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_eg_block_level_chunk_elts<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast EGBlockLevelChunkElts,
+) {
+    for it0 in &node.block_content {
+        Visit::visit_block_content_choice(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_eg_block_level_chunk_elts_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut EGBlockLevelChunkElts,
+) {
+    for it0 in &mut node.block_content {
+        VisitMut::visit_block_content_choice_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_eg_ftn_edn_num_props<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast EGFtnEdnNumProps,
+) {
+    if let Some(it0) = &node.num_start {
+        Visit::visit_ct_decimal_number(v, it0);
+    }
+    if let Some(it0) = &node.num_restart {
+        Visit::visit_ct_num_restart(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_eg_ftn_edn_num_props_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut EGFtnEdnNumProps) {
+    if let Some(it0) = &mut node.num_start {
+        VisitMut::visit_ct_decimal_number_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.num_restart {
+        VisitMut::visit_ct_num_restart_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_eg_math_content<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast EGMathContent) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_eg_math_content_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut EGMathContent) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_eg_para_r_pr_track_changes<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast EGParaRPrTrackChanges,
+) {
+    if let Some(it0) = &node.ins {
+        Visit::visit_ct_track_change(v, it0);
+    }
+    if let Some(it0) = &node.del {
+        Visit::visit_ct_track_change(v, it0);
+    }
+    if let Some(it0) = &node.move_from {
+        Visit::visit_ct_track_change(v, it0);
+    }
+    if let Some(it0) = &node.move_to {
+        Visit::visit_ct_track_change(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_eg_para_r_pr_track_changes_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut EGParaRPrTrackChanges,
+) {
+    if let Some(it0) = &mut node.ins {
+        VisitMut::visit_ct_track_change_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.del {
+        VisitMut::visit_ct_track_change_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.move_from {
+        VisitMut::visit_ct_track_change_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.move_to {
+        VisitMut::visit_ct_track_change_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_egr_pr<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast EGRPr) {
+    if let Some(it0) = &node.r_pr {
+        Visit::visit_run_properties(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_egr_pr_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut EGRPr) {
+    if let Some(it0) = &mut node.r_pr {
+        VisitMut::visit_run_properties_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_egr_pr_base<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast EGRPrBase) {
+    if let Some(it0) = &node.run_style {
+        Visit::visit_ct_string(v, it0);
+    }
+    if let Some(it0) = &node.fonts {
+        Visit::visit_fonts(v, it0);
+    }
+    if let Some(it0) = &node.bold {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.b_cs {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.italic {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.i_cs {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.caps {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.small_caps {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.strikethrough {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.dstrike {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.outline {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.shadow {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.emboss {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.imprint {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.no_proof {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.snap_to_grid {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.vanish {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.web_hidden {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.color {
+        Visit::visit_ct_color(v, it0);
+    }
+    if let Some(it0) = &node.spacing {
+        Visit::visit_signed_twips_measure_element(v, it0);
+    }
+    if let Some(it0) = &node.width {
+        Visit::visit_text_scale_element(v, it0);
+    }
+    if let Some(it0) = &node.kern {
+        Visit::visit_hps_measure_element(v, it0);
+    }
+    if let Some(it0) = &node.position {
+        Visit::visit_signed_hps_measure_element(v, it0);
+    }
+    if let Some(it0) = &node.size {
+        Visit::visit_hps_measure_element(v, it0);
+    }
+    if let Some(it0) = &node.size_complex_script {
+        Visit::visit_hps_measure_element(v, it0);
+    }
+    if let Some(it0) = &node.highlight {
+        Visit::visit_ct_highlight(v, it0);
+    }
+    if let Some(it0) = &node.underline {
+        Visit::visit_ct_underline(v, it0);
+    }
+    if let Some(it0) = &node.effect {
+        Visit::visit_ct_text_effect(v, it0);
+    }
+    if let Some(it0) = &node.bdr {
+        Visit::visit_ct_border(v, it0);
+    }
+    if let Some(it0) = &node.shading {
+        Visit::visit_ct_shd(v, it0);
+    }
+    if let Some(it0) = &node.fit_text {
+        Visit::visit_ct_fit_text(v, it0);
+    }
+    if let Some(it0) = &node.vert_align {
+        Visit::visit_ct_vertical_align_run(v, it0);
+    }
+    if let Some(it0) = &node.rtl {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.cs {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.em {
+        Visit::visit_ct_em(v, it0);
+    }
+    if let Some(it0) = &node.lang {
+        Visit::visit_language_element(v, it0);
+    }
+    if let Some(it0) = &node.east_asian_layout {
+        Visit::visit_ct_east_asian_layout(v, it0);
+    }
+    if let Some(it0) = &node.spec_vanish {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.o_math {
+        Visit::visit_on_off_element(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_egr_pr_base_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut EGRPrBase) {
+    if let Some(it0) = &mut node.run_style {
+        VisitMut::visit_ct_string_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.fonts {
+        VisitMut::visit_fonts_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.bold {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.b_cs {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.italic {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.i_cs {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.caps {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.small_caps {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.strikethrough {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.dstrike {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.outline {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.shadow {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.emboss {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.imprint {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.no_proof {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.snap_to_grid {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.vanish {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.web_hidden {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.color {
+        VisitMut::visit_ct_color_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.spacing {
+        VisitMut::visit_signed_twips_measure_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.width {
+        VisitMut::visit_text_scale_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.kern {
+        VisitMut::visit_hps_measure_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.position {
+        VisitMut::visit_signed_hps_measure_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.size {
+        VisitMut::visit_hps_measure_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.size_complex_script {
+        VisitMut::visit_hps_measure_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.highlight {
+        VisitMut::visit_ct_highlight_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.underline {
+        VisitMut::visit_ct_underline_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.effect {
+        VisitMut::visit_ct_text_effect_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.bdr {
+        VisitMut::visit_ct_border_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.shading {
+        VisitMut::visit_ct_shd_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.fit_text {
+        VisitMut::visit_ct_fit_text_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.vert_align {
+        VisitMut::visit_ct_vertical_align_run_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.rtl {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.cs {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.em {
+        VisitMut::visit_ct_em_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.lang {
+        VisitMut::visit_language_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.east_asian_layout {
+        VisitMut::visit_ct_east_asian_layout_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.spec_vanish {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.o_math {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_egr_pr_content<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast EGRPrContent) {
+    if let Some(it0) = &node.run_style {
+        Visit::visit_ct_string(v, it0);
+    }
+    if let Some(it0) = &node.fonts {
+        Visit::visit_fonts(v, it0);
+    }
+    if let Some(it0) = &node.bold {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.b_cs {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.italic {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.i_cs {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.caps {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.small_caps {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.strikethrough {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.dstrike {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.outline {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.shadow {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.emboss {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.imprint {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.no_proof {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.snap_to_grid {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.vanish {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.web_hidden {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.color {
+        Visit::visit_ct_color(v, it0);
+    }
+    if let Some(it0) = &node.spacing {
+        Visit::visit_signed_twips_measure_element(v, it0);
+    }
+    if let Some(it0) = &node.width {
+        Visit::visit_text_scale_element(v, it0);
+    }
+    if let Some(it0) = &node.kern {
+        Visit::visit_hps_measure_element(v, it0);
+    }
+    if let Some(it0) = &node.position {
+        Visit::visit_signed_hps_measure_element(v, it0);
+    }
+    if let Some(it0) = &node.size {
+        Visit::visit_hps_measure_element(v, it0);
+    }
+    if let Some(it0) = &node.size_complex_script {
+        Visit::visit_hps_measure_element(v, it0);
+    }
+    if let Some(it0) = &node.highlight {
+        Visit::visit_ct_highlight(v, it0);
+    }
+    if let Some(it0) = &node.underline {
+        Visit::visit_ct_underline(v, it0);
+    }
+    if let Some(it0) = &node.effect {
+        Visit::visit_ct_text_effect(v, it0);
+    }
+    if let Some(it0) = &node.bdr {
+        Visit::visit_ct_border(v, it0);
+    }
+    if let Some(it0) = &node.shading {
+        Visit::visit_ct_shd(v, it0);
+    }
+    if let Some(it0) = &node.fit_text {
+        Visit::visit_ct_fit_text(v, it0);
+    }
+    if let Some(it0) = &node.vert_align {
+        Visit::visit_ct_vertical_align_run(v, it0);
+    }
+    if let Some(it0) = &node.rtl {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.cs {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.em {
+        Visit::visit_ct_em(v, it0);
+    }
+    if let Some(it0) = &node.lang {
+        Visit::visit_language_element(v, it0);
+    }
+    if let Some(it0) = &node.east_asian_layout {
+        Visit::visit_ct_east_asian_layout(v, it0);
+    }
+    if let Some(it0) = &node.spec_vanish {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.o_math {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.r_pr_change {
+        Visit::visit_ctr_pr_change(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_egr_pr_content_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut EGRPrContent) {
+    if let Some(it0) = &mut node.run_style {
+        VisitMut::visit_ct_string_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.fonts {
+        VisitMut::visit_fonts_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.bold {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.b_cs {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.italic {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.i_cs {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.caps {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.small_caps {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.strikethrough {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.dstrike {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.outline {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.shadow {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.emboss {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.imprint {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.no_proof {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.snap_to_grid {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.vanish {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.web_hidden {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.color {
+        VisitMut::visit_ct_color_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.spacing {
+        VisitMut::visit_signed_twips_measure_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.width {
+        VisitMut::visit_text_scale_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.kern {
+        VisitMut::visit_hps_measure_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.position {
+        VisitMut::visit_signed_hps_measure_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.size {
+        VisitMut::visit_hps_measure_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.size_complex_script {
+        VisitMut::visit_hps_measure_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.highlight {
+        VisitMut::visit_ct_highlight_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.underline {
+        VisitMut::visit_ct_underline_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.effect {
+        VisitMut::visit_ct_text_effect_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.bdr {
+        VisitMut::visit_ct_border_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.shading {
+        VisitMut::visit_ct_shd_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.fit_text {
+        VisitMut::visit_ct_fit_text_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.vert_align {
+        VisitMut::visit_ct_vertical_align_run_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.rtl {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.cs {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.em {
+        VisitMut::visit_ct_em_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.lang {
+        VisitMut::visit_language_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.east_asian_layout {
+        VisitMut::visit_ct_east_asian_layout_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.spec_vanish {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.o_math {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.r_pr_change {
+        VisitMut::visit_ctr_pr_change_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_eg_sect_pr_contents<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast EGSectPrContents,
+) {
+    if let Some(it0) = &node.footnote_pr {
+        Visit::visit_ct_ftn_props(v, it0);
+    }
+    if let Some(it0) = &node.endnote_pr {
+        Visit::visit_ct_edn_props(v, it0);
+    }
+    if let Some(it0) = &node.r#type {
+        Visit::visit_ct_sect_type(v, it0);
+    }
+    if let Some(it0) = &node.pg_sz {
+        Visit::visit_page_size(v, it0);
+    }
+    if let Some(it0) = &node.pg_mar {
+        Visit::visit_page_margins(v, it0);
+    }
+    if let Some(it0) = &node.paper_src {
+        Visit::visit_ct_paper_source(v, it0);
+    }
+    if let Some(it0) = &node.pg_borders {
+        Visit::visit_ct_page_borders(v, it0);
+    }
+    if let Some(it0) = &node.ln_num_type {
+        Visit::visit_ct_line_number(v, it0);
+    }
+    if let Some(it0) = &node.pg_num_type {
+        Visit::visit_ct_page_number(v, it0);
+    }
+    if let Some(it0) = &node.cols {
+        Visit::visit_columns(v, it0);
+    }
+    if let Some(it0) = &node.form_prot {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.v_align {
+        Visit::visit_ct_vertical_jc(v, it0);
+    }
+    if let Some(it0) = &node.no_endnote {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.title_pg {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.text_direction {
+        Visit::visit_ct_text_direction(v, it0);
+    }
+    if let Some(it0) = &node.bidi {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.rtl_gutter {
+        Visit::visit_on_off_element(v, it0);
+    }
+    if let Some(it0) = &node.doc_grid {
+        Visit::visit_document_grid(v, it0);
+    }
+    if let Some(it0) = &node.printer_settings {
+        Visit::visit_ct_rel(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_eg_sect_pr_contents_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut EGSectPrContents) {
+    if let Some(it0) = &mut node.footnote_pr {
+        VisitMut::visit_ct_ftn_props_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.endnote_pr {
+        VisitMut::visit_ct_edn_props_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.r#type {
+        VisitMut::visit_ct_sect_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.pg_sz {
+        VisitMut::visit_page_size_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.pg_mar {
+        VisitMut::visit_page_margins_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.paper_src {
+        VisitMut::visit_ct_paper_source_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.pg_borders {
+        VisitMut::visit_ct_page_borders_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.ln_num_type {
+        VisitMut::visit_ct_line_number_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.pg_num_type {
+        VisitMut::visit_ct_page_number_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.cols {
+        VisitMut::visit_columns_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.form_prot {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.v_align {
+        VisitMut::visit_ct_vertical_jc_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.no_endnote {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.title_pg {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.text_direction {
+        VisitMut::visit_ct_text_direction_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.bidi {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.rtl_gutter {
+        VisitMut::visit_on_off_element_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.doc_grid {
+        VisitMut::visit_document_grid_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.printer_settings {
+        VisitMut::visit_ct_rel_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_endnotes<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Endnotes) {
+    for it0 in &node.endnote {
+        Visit::visit_footnote_endnote(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_endnotes_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Endnotes) {
+    for it0 in &mut node.endnote {
+        VisitMut::visit_footnote_endnote_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_font<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Font) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.alt_name {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.panose1 {
+            Visit::visit_panose_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.charset {
+            Visit::visit_ct_charset(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.family {
+            Visit::visit_ct_font_family(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.not_true_type {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.pitch {
+            Visit::visit_ct_pitch(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.sig {
+            Visit::visit_ct_font_sig(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.embed_regular {
+            Visit::visit_ct_font_rel(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.embed_bold {
+            Visit::visit_ct_font_rel(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.embed_italic {
+            Visit::visit_ct_font_rel(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.embed_bold_italic {
+            Visit::visit_ct_font_rel(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_font_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Font) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.alt_name {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.panose1 {
+            VisitMut::visit_panose_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.charset {
+            VisitMut::visit_ct_charset_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.family {
+            VisitMut::visit_ct_font_family_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.not_true_type {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.pitch {
+            VisitMut::visit_ct_pitch_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.sig {
+            VisitMut::visit_ct_font_sig_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.embed_regular {
+            VisitMut::visit_ct_font_rel_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.embed_bold {
+            VisitMut::visit_ct_font_rel_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.embed_italic {
+            VisitMut::visit_ct_font_rel_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.embed_bold_italic {
+            VisitMut::visit_ct_font_rel_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_fonts<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Fonts) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.hint {
+            Visit::visit_st_hint(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.ascii_theme {
+            Visit::visit_st_theme(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.h_ansi_theme {
+            Visit::visit_st_theme(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.east_asia_theme {
+            Visit::visit_st_theme(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.cstheme {
+            Visit::visit_st_theme(v, it0);
+        }
+    }
+}
+
+pub fn walk_fonts_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Fonts) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.hint {
+            VisitMut::visit_st_hint_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.ascii_theme {
+            VisitMut::visit_st_theme_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.h_ansi_theme {
+            VisitMut::visit_st_theme_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.east_asia_theme {
+            VisitMut::visit_st_theme_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.cstheme {
+            VisitMut::visit_st_theme_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_footnote_endnote<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast FootnoteEndnote,
+) {
+    #[cfg(feature = "wml-comments")]
+    {
+        if let Some(it0) = &node.r#type {
+            Visit::visit_st_ftn_edn(v, it0);
+        }
+    }
+    for it0 in &node.block_content {
+        Visit::visit_block_content(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_footnote_endnote_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut FootnoteEndnote) {
+    #[cfg(feature = "wml-comments")]
+    {
+        if let Some(it0) = &mut node.r#type {
+            VisitMut::visit_st_ftn_edn_mut(v, it0);
+        }
+    }
+    for it0 in &mut node.block_content {
+        VisitMut::visit_block_content_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_footnotes<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Footnotes) {
+    for it0 in &node.footnote {
+        Visit::visit_footnote_endnote(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_footnotes_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Footnotes) {
+    for it0 in &mut node.footnote {
+        VisitMut::visit_footnote_endnote_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_header_footer<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast HeaderFooter) {
+    for it0 in &node.block_content {
+        Visit::visit_block_content(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_header_footer_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut HeaderFooter) {
+    for it0 in &mut node.block_content {
+        VisitMut::visit_block_content_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_header_footer_ref<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast HeaderFooterRef,
+) {
+    match node {
+        HeaderFooterRef::HeaderReference(it) => {
+            Visit::visit_header_footer_reference(v, it);
+        }
+        HeaderFooterRef::FooterReference(it) => {
+            Visit::visit_header_footer_reference(v, it);
+        }
+    }
+}
+
+pub fn walk_header_footer_ref_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut HeaderFooterRef) {
+    match node {
+        HeaderFooterRef::HeaderReference(it) => {
+            VisitMut::visit_header_footer_reference_mut(v, it);
+        }
+        HeaderFooterRef::FooterReference(it) => {
+            VisitMut::visit_header_footer_reference_mut(v, it);
+        }
+    }
+}
+
+pub fn walk_header_footer_reference<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast HeaderFooterReference,
+) {
+    Visit::visit_st_hdr_ftr(v, &node.r#type);
+}
+
+pub fn walk_header_footer_reference_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut HeaderFooterReference,
+) {
+    VisitMut::visit_st_hdr_ftr_mut(v, &mut node.r#type);
+}
+
+pub fn walk_hyperlink<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Hyperlink) {
+    for it0 in &node.paragraph_content {
+        Visit::visit_paragraph_content(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_hyperlink_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Hyperlink) {
+    for it0 in &mut node.paragraph_content {
+        VisitMut::visit_paragraph_content_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_latent_styles<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast LatentStyles) {
+    for it0 in &node.lsd_exception {
+        Visit::visit_latent_style_exception(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_latent_styles_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut LatentStyles) {
+    for it0 in &mut node.lsd_exception {
+        VisitMut::visit_latent_style_exception_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_level<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Level) {
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.start {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.num_fmt {
+            Visit::visit_ct_num_fmt(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.lvl_restart {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.paragraph_style {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.is_lgl {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.suff {
+            Visit::visit_ct_level_suffix(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.lvl_text {
+            Visit::visit_ct_level_text(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.lvl_pic_bullet_id {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.legacy {
+            Visit::visit_ct_lvl_legacy(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.lvl_jc {
+            Visit::visit_ct_jc(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.p_pr {
+            Visit::visit_ctp_pr_general(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.r_pr {
+            Visit::visit_run_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_level_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Level) {
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.start {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.num_fmt {
+            VisitMut::visit_ct_num_fmt_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.lvl_restart {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.paragraph_style {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.is_lgl {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.suff {
+            VisitMut::visit_ct_level_suffix_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.lvl_text {
+            VisitMut::visit_ct_level_text_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.lvl_pic_bullet_id {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.legacy {
+            VisitMut::visit_ct_lvl_legacy_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.lvl_jc {
+            VisitMut::visit_ct_jc_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.p_pr {
+            VisitMut::visit_ctp_pr_general_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.r_pr {
+            VisitMut::visit_run_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_math_content<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast MathContent) {
+    for it0 in &node.p_content_base {
+        Visit::visit_paragraph_content_base(v, it0);
+    }
+    for it0 in &node.content_run_content_base {
+        Visit::visit_run_content_base(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_math_content_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut MathContent) {
+    for it0 in &mut node.p_content_base {
+        VisitMut::visit_paragraph_content_base_mut(v, it0);
+    }
+    for it0 in &mut node.content_run_content_base {
+        VisitMut::visit_run_content_base_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_math_run_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast MathRunProperties,
+) {
+    match node {
+        MathRunProperties::RPr(it) => {
+            Visit::visit_run_properties(v, it);
+        }
+        MathRunProperties::Ins(it) => {
+            Visit::visit_ct_math_ctrl_ins(v, it);
+        }
+        MathRunProperties::Del(it) => {
+            Visit::visit_ct_math_ctrl_del(v, it);
+        }
+    }
+}
+
+pub fn walk_math_run_properties_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut MathRunProperties) {
+    match node {
+        MathRunProperties::RPr(it) => {
+            VisitMut::visit_run_properties_mut(v, it);
+        }
+        MathRunProperties::Ins(it) => {
+            VisitMut::visit_ct_math_ctrl_ins_mut(v, it);
+        }
+        MathRunProperties::Del(it) => {
+            VisitMut::visit_ct_math_ctrl_del_mut(v, it);
+        }
+    }
+}
+
+pub fn walk_numbering<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Numbering) {
+    #[cfg(feature = "wml-numbering")]
+    {
+        for it0 in &node.num_pic_bullet {
+            Visit::visit_ct_num_pic_bullet(v, it0);
+        }
+    }
+    for it0 in &node.abstract_num {
+        Visit::visit_abstract_numbering(v, it0);
+    }
+    for it0 in &node.num {
+        Visit::visit_numbering_instance(v, it0);
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.num_id_mac_at_cleanup {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_numbering_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Numbering) {
+    #[cfg(feature = "wml-numbering")]
+    {
+        for it0 in &mut node.num_pic_bullet {
+            VisitMut::visit_ct_num_pic_bullet_mut(v, it0);
+        }
+    }
+    for it0 in &mut node.abstract_num {
+        VisitMut::visit_abstract_numbering_mut(v, it0);
+    }
+    for it0 in &mut node.num {
+        VisitMut::visit_numbering_instance_mut(v, it0);
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.num_id_mac_at_cleanup {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_numbering_instance<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast NumberingInstance,
+) {
+    Visit::visit_ct_decimal_number(v, &node.abstract_num_id);
+    #[cfg(feature = "wml-numbering")]
+    {
+        for it0 in &node.lvl_override {
+            Visit::visit_ct_num_lvl(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_numbering_instance_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut NumberingInstance) {
+    VisitMut::visit_ct_decimal_number_mut(v, &mut node.abstract_num_id);
+    #[cfg(feature = "wml-numbering")]
+    {
+        for it0 in &mut node.lvl_override {
+            VisitMut::visit_ct_num_lvl_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_numbering_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast NumberingProperties,
+) {
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.ilvl {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.num_id {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &node.numbering_change {
+            Visit::visit_ct_track_change_numbering(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &node.ins {
+            Visit::visit_ct_track_change(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_numbering_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut NumberingProperties,
+) {
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.ilvl {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.num_id {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &mut node.numbering_change {
+            VisitMut::visit_ct_track_change_numbering_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &mut node.ins {
+            VisitMut::visit_ct_track_change_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_page_size<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast PageSize) {
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.orient {
+            Visit::visit_st_page_orientation(v, it0);
+        }
+    }
+}
+
+pub fn walk_page_size_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut PageSize) {
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.orient {
+            VisitMut::visit_st_page_orientation_mut(v, it0);
+        }
+    }
+}
+
+pub fn walk_paragraph<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Paragraph) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.p_pr {
+            Visit::visit_paragraph_properties(v, it0);
+        }
+    }
+    for it0 in &node.paragraph_content {
+        Visit::visit_paragraph_content(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_paragraph_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Paragraph) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.p_pr {
+            VisitMut::visit_paragraph_properties_mut(v, it0);
+        }
+    }
+    for it0 in &mut node.paragraph_content {
+        VisitMut::visit_paragraph_content_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_paragraph_content<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast ParagraphContent,
+) {
+    match node {
+        ParagraphContent::CustomXml(it) => {
+            Visit::visit_ct_custom_xml_run(v, it);
+        }
+        ParagraphContent::SmartTag(it) => {
+            Visit::visit_ct_smart_tag_run(v, it);
+        }
+        ParagraphContent::Sdt(it) => {
+            Visit::visit_ct_sdt_run(v, it);
+        }
+        ParagraphContent::Dir(it) => {
+            Visit::visit_ct_dir_content_run(v, it);
+        }
+        ParagraphContent::Bdo(it) => {
+            Visit::visit_ct_bdo_content_run(v, it);
+        }
+        ParagraphContent::R(it) => {
+            Visit::visit_run(v, it);
+        }
+        ParagraphContent::ProofErr(it) => {
+            Visit::visit_ct_proof_err(v, it);
+        }
+        ParagraphContent::PermStart(it) => {
+            Visit::visit_ct_perm_start(v, it);
+        }
+        ParagraphContent::PermEnd(it) => {
+            Visit::visit_ct_perm(v, it);
+        }
+        ParagraphContent::BookmarkStart(it) => {
+            Visit::visit_bookmark(v, it);
+        }
+        ParagraphContent::BookmarkEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        ParagraphContent::MoveFromRangeStart(it) => {
+            Visit::visit_ct_move_bookmark(v, it);
+        }
+        ParagraphContent::MoveFromRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        ParagraphContent::MoveToRangeStart(it) => {
+            Visit::visit_ct_move_bookmark(v, it);
+        }
+        ParagraphContent::MoveToRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        ParagraphContent::CommentRangeStart(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        ParagraphContent::CommentRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        ParagraphContent::CustomXmlInsRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        ParagraphContent::CustomXmlInsRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        ParagraphContent::CustomXmlDelRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        ParagraphContent::CustomXmlDelRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        ParagraphContent::CustomXmlMoveFromRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        ParagraphContent::CustomXmlMoveFromRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        ParagraphContent::CustomXmlMoveToRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        ParagraphContent::CustomXmlMoveToRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        ParagraphContent::Ins(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        ParagraphContent::Del(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        ParagraphContent::MoveFrom(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        ParagraphContent::MoveTo(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        ParagraphContent::FldSimple(it) => {
+            Visit::visit_ct_simple_field(v, it);
+        }
+        ParagraphContent::Hyperlink(it) => {
+            Visit::visit_hyperlink(v, it);
+        }
+        ParagraphContent::SubDoc(it) => {
+            Visit::visit_ct_rel(v, it);
+        }
+    }
+}
+
+pub fn walk_paragraph_content_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ParagraphContent) {
+    match node {
+        ParagraphContent::CustomXml(it) => {
+            VisitMut::visit_ct_custom_xml_run_mut(v, it);
+        }
+        ParagraphContent::SmartTag(it) => {
+            VisitMut::visit_ct_smart_tag_run_mut(v, it);
+        }
+        ParagraphContent::Sdt(it) => {
+            VisitMut::visit_ct_sdt_run_mut(v, it);
+        }
+        ParagraphContent::Dir(it) => {
+            VisitMut::visit_ct_dir_content_run_mut(v, it);
+        }
+        ParagraphContent::Bdo(it) => {
+            VisitMut::visit_ct_bdo_content_run_mut(v, it);
+        }
+        ParagraphContent::R(it) => {
+            VisitMut::visit_run_mut(v, it);
+        }
+        ParagraphContent::ProofErr(it) => {
+            VisitMut::visit_ct_proof_err_mut(v, it);
+        }
+        ParagraphContent::PermStart(it) => {
+            VisitMut::visit_ct_perm_start_mut(v, it);
+        }
+        ParagraphContent::PermEnd(it) => {
+            VisitMut::visit_ct_perm_mut(v, it);
+        }
+        ParagraphContent::BookmarkStart(it) => {
+            VisitMut::visit_bookmark_mut(v, it);
+        }
+        ParagraphContent::BookmarkEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        ParagraphContent::MoveFromRangeStart(it) => {
+            VisitMut::visit_ct_move_bookmark_mut(v, it);
+        }
+        ParagraphContent::MoveFromRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        ParagraphContent::MoveToRangeStart(it) => {
+            VisitMut::visit_ct_move_bookmark_mut(v, it);
+        }
+        ParagraphContent::MoveToRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        ParagraphContent::CommentRangeStart(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        ParagraphContent::CommentRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        ParagraphContent::CustomXmlInsRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        ParagraphContent::CustomXmlInsRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        ParagraphContent::CustomXmlDelRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        ParagraphContent::CustomXmlDelRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        ParagraphContent::CustomXmlMoveFromRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        ParagraphContent::CustomXmlMoveFromRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        ParagraphContent::CustomXmlMoveToRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        ParagraphContent::CustomXmlMoveToRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        ParagraphContent::Ins(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        ParagraphContent::Del(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        ParagraphContent::MoveFrom(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        ParagraphContent::MoveTo(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        ParagraphContent::FldSimple(it) => {
+            VisitMut::visit_ct_simple_field_mut(v, it);
+        }
+        ParagraphContent::Hyperlink(it) => {
+            VisitMut::visit_hyperlink_mut(v, it);
+        }
+        ParagraphContent::SubDoc(it) => {
+            VisitMut::visit_ct_rel_mut(v, it);
+        }
+    }
+}
+
+pub fn walk_paragraph_content_base<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast ParagraphContentBase,
+) {
+    match node {
+        ParagraphContentBase::CustomXml(it) => {
+            Visit::visit_ct_custom_xml_run(v, it);
+        }
+        ParagraphContentBase::FldSimple(it) => {
+            Visit::visit_ct_simple_field(v, it);
+        }
+        ParagraphContentBase::Hyperlink(it) => {
+            Visit::visit_hyperlink(v, it);
+        }
+    }
+}
+
+pub fn walk_paragraph_content_base_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut ParagraphContentBase,
+) {
+    match node {
+        ParagraphContentBase::CustomXml(it) => {
+            VisitMut::visit_ct_custom_xml_run_mut(v, it);
+        }
+        ParagraphContentBase::FldSimple(it) => {
+            VisitMut::visit_ct_simple_field_mut(v, it);
+        }
+        ParagraphContentBase::Hyperlink(it) => {
+            VisitMut::visit_hyperlink_mut(v, it);
+        }
+    }
+}
+
+pub fn walk_paragraph_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast ParagraphProperties,
+) {
+    if let Some(it0) = &node.paragraph_style {
+        Visit::visit_ct_string(v, it0);
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.keep_next {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.keep_lines {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.page_break_before {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.frame_pr {
+            Visit::visit_ct_frame_pr(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.widow_control {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &node.num_pr {
+            Visit::visit_numbering_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.suppress_line_numbers {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.paragraph_border {
+            Visit::visit_ctp_bdr(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.shading {
+            Visit::visit_ct_shd(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.tabs {
+            Visit::visit_ct_tabs(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.suppress_auto_hyphens {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.kinsoku {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.word_wrap {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.overflow_punct {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.top_line_punct {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.auto_space_d_e {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.auto_space_d_n {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.bidi {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.adjust_right_ind {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.snap_to_grid {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.spacing {
+            Visit::visit_ct_spacing(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.indentation {
+            Visit::visit_ct_ind(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.contextual_spacing {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.mirror_indents {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.suppress_overlap {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.justification {
+            Visit::visit_ct_jc(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.text_direction {
+            Visit::visit_ct_text_direction(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.text_alignment {
+            Visit::visit_ct_text_alignment(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.textbox_tight_wrap {
+            Visit::visit_ct_textbox_tight_wrap(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.outline_lvl {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.div_id {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.cnf_style {
+            Visit::visit_ct_cnf(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.r_pr {
+            Visit::visit_ct_para_r_pr(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.sect_pr {
+            Visit::visit_section_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &node.p_pr_change {
+            Visit::visit_ctp_pr_change(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_paragraph_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut ParagraphProperties,
+) {
+    if let Some(it0) = &mut node.paragraph_style {
+        VisitMut::visit_ct_string_mut(v, it0);
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.keep_next {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.keep_lines {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.page_break_before {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.frame_pr {
+            VisitMut::visit_ct_frame_pr_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.widow_control {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-numbering")]
+    {
+        if let Some(it0) = &mut node.num_pr {
+            VisitMut::visit_numbering_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.suppress_line_numbers {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.paragraph_border {
+            VisitMut::visit_ctp_bdr_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.shading {
+            VisitMut::visit_ct_shd_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.tabs {
+            VisitMut::visit_ct_tabs_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.suppress_auto_hyphens {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.kinsoku {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.word_wrap {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.overflow_punct {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.top_line_punct {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.auto_space_d_e {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.auto_space_d_n {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.bidi {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.adjust_right_ind {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.snap_to_grid {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.spacing {
+            VisitMut::visit_ct_spacing_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.indentation {
+            VisitMut::visit_ct_ind_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.contextual_spacing {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.mirror_indents {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.suppress_overlap {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.justification {
+            VisitMut::visit_ct_jc_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.text_direction {
+            VisitMut::visit_ct_text_direction_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.text_alignment {
+            VisitMut::visit_ct_text_alignment_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.textbox_tight_wrap {
+            VisitMut::visit_ct_textbox_tight_wrap_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.outline_lvl {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.div_id {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.cnf_style {
+            VisitMut::visit_ct_cnf_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.r_pr {
+            VisitMut::visit_ct_para_r_pr_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.sect_pr {
+            VisitMut::visit_section_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &mut node.p_pr_change {
+            VisitMut::visit_ctp_pr_change_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_paragraph_properties_default<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast ParagraphPropertiesDefault,
+) {
+    if let Some(it0) = &node.p_pr {
+        Visit::visit_ctp_pr_general(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_paragraph_properties_default_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut ParagraphPropertiesDefault,
+) {
+    if let Some(it0) = &mut node.p_pr {
+        VisitMut::visit_ctp_pr_general_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_range_markup<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast RangeMarkup) {
+    match node {
+        RangeMarkup::BookmarkStart(it) => {
+            Visit::visit_bookmark(v, it);
+        }
+        RangeMarkup::BookmarkEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RangeMarkup::MoveFromRangeStart(it) => {
+            Visit::visit_ct_move_bookmark(v, it);
+        }
+        RangeMarkup::MoveFromRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RangeMarkup::MoveToRangeStart(it) => {
+            Visit::visit_ct_move_bookmark(v, it);
+        }
+        RangeMarkup::MoveToRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RangeMarkup::CommentRangeStart(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RangeMarkup::CommentRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RangeMarkup::CustomXmlInsRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        RangeMarkup::CustomXmlInsRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        RangeMarkup::CustomXmlDelRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        RangeMarkup::CustomXmlDelRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        RangeMarkup::CustomXmlMoveFromRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        RangeMarkup::CustomXmlMoveFromRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        RangeMarkup::CustomXmlMoveToRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        RangeMarkup::CustomXmlMoveToRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+    }
+}
+
+pub fn walk_range_markup_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut RangeMarkup) {
+    match node {
+        RangeMarkup::BookmarkStart(it) => {
+            VisitMut::visit_bookmark_mut(v, it);
+        }
+        RangeMarkup::BookmarkEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RangeMarkup::MoveFromRangeStart(it) => {
+            VisitMut::visit_ct_move_bookmark_mut(v, it);
+        }
+        RangeMarkup::MoveFromRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RangeMarkup::MoveToRangeStart(it) => {
+            VisitMut::visit_ct_move_bookmark_mut(v, it);
+        }
+        RangeMarkup::MoveToRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RangeMarkup::CommentRangeStart(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RangeMarkup::CommentRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RangeMarkup::CustomXmlInsRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        RangeMarkup::CustomXmlInsRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        RangeMarkup::CustomXmlDelRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        RangeMarkup::CustomXmlDelRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        RangeMarkup::CustomXmlMoveFromRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        RangeMarkup::CustomXmlMoveFromRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        RangeMarkup::CustomXmlMoveToRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        RangeMarkup::CustomXmlMoveToRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+    }
+}
+
+pub fn walk_row_content<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast RowContent) {
+    match node {
+        RowContent::Tr(it) => {
+            Visit::visit_ct_row(v, it);
+        }
+        RowContent::CustomXml(it) => {
+            Visit::visit_ct_custom_xml_row(v, it);
+        }
+        RowContent::Sdt(it) => {
+            Visit::visit_ct_sdt_row(v, it);
+        }
+        RowContent::ProofErr(it) => {
+            Visit::visit_ct_proof_err(v, it);
+        }
+        RowContent::PermStart(it) => {
+            Visit::visit_ct_perm_start(v, it);
+        }
+        RowContent::PermEnd(it) => {
+            Visit::visit_ct_perm(v, it);
+        }
+        RowContent::BookmarkStart(it) => {
+            Visit::visit_bookmark(v, it);
+        }
+        RowContent::BookmarkEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RowContent::MoveFromRangeStart(it) => {
+            Visit::visit_ct_move_bookmark(v, it);
+        }
+        RowContent::MoveFromRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RowContent::MoveToRangeStart(it) => {
+            Visit::visit_ct_move_bookmark(v, it);
+        }
+        RowContent::MoveToRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RowContent::CommentRangeStart(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RowContent::CommentRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RowContent::CustomXmlInsRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        RowContent::CustomXmlInsRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        RowContent::CustomXmlDelRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        RowContent::CustomXmlDelRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        RowContent::CustomXmlMoveFromRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        RowContent::CustomXmlMoveFromRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        RowContent::CustomXmlMoveToRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        RowContent::CustomXmlMoveToRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        RowContent::Ins(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        RowContent::Del(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        RowContent::MoveFrom(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        RowContent::MoveTo(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+    }
+}
+
+pub fn walk_row_content_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut RowContent) {
+    match node {
+        RowContent::Tr(it) => {
+            VisitMut::visit_ct_row_mut(v, it);
+        }
+        RowContent::CustomXml(it) => {
+            VisitMut::visit_ct_custom_xml_row_mut(v, it);
+        }
+        RowContent::Sdt(it) => {
+            VisitMut::visit_ct_sdt_row_mut(v, it);
+        }
+        RowContent::ProofErr(it) => {
+            VisitMut::visit_ct_proof_err_mut(v, it);
+        }
+        RowContent::PermStart(it) => {
+            VisitMut::visit_ct_perm_start_mut(v, it);
+        }
+        RowContent::PermEnd(it) => {
+            VisitMut::visit_ct_perm_mut(v, it);
+        }
+        RowContent::BookmarkStart(it) => {
+            VisitMut::visit_bookmark_mut(v, it);
+        }
+        RowContent::BookmarkEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RowContent::MoveFromRangeStart(it) => {
+            VisitMut::visit_ct_move_bookmark_mut(v, it);
+        }
+        RowContent::MoveFromRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RowContent::MoveToRangeStart(it) => {
+            VisitMut::visit_ct_move_bookmark_mut(v, it);
+        }
+        RowContent::MoveToRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RowContent::CommentRangeStart(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RowContent::CommentRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RowContent::CustomXmlInsRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        RowContent::CustomXmlInsRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        RowContent::CustomXmlDelRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        RowContent::CustomXmlDelRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        RowContent::CustomXmlMoveFromRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        RowContent::CustomXmlMoveFromRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        RowContent::CustomXmlMoveToRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        RowContent::CustomXmlMoveToRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        RowContent::Ins(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        RowContent::Del(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        RowContent::MoveFrom(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        RowContent::MoveTo(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+    }
+}
+
+pub fn walk_ruby_content<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast RubyContent) {
+    match node {
+        RubyContent::R(it) => {
+            Visit::visit_run(v, it);
+        }
+        RubyContent::ProofErr(it) => {
+            Visit::visit_ct_proof_err(v, it);
+        }
+        RubyContent::PermStart(it) => {
+            Visit::visit_ct_perm_start(v, it);
+        }
+        RubyContent::PermEnd(it) => {
+            Visit::visit_ct_perm(v, it);
+        }
+        RubyContent::BookmarkStart(it) => {
+            Visit::visit_bookmark(v, it);
+        }
+        RubyContent::BookmarkEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RubyContent::MoveFromRangeStart(it) => {
+            Visit::visit_ct_move_bookmark(v, it);
+        }
+        RubyContent::MoveFromRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RubyContent::MoveToRangeStart(it) => {
+            Visit::visit_ct_move_bookmark(v, it);
+        }
+        RubyContent::MoveToRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RubyContent::CommentRangeStart(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RubyContent::CommentRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RubyContent::CustomXmlInsRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        RubyContent::CustomXmlInsRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        RubyContent::CustomXmlDelRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        RubyContent::CustomXmlDelRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        RubyContent::CustomXmlMoveFromRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        RubyContent::CustomXmlMoveFromRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        RubyContent::CustomXmlMoveToRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        RubyContent::CustomXmlMoveToRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        RubyContent::Ins(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        RubyContent::Del(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        RubyContent::MoveFrom(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        RubyContent::MoveTo(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+    }
+}
+
+pub fn walk_ruby_content_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut RubyContent) {
+    match node {
+        RubyContent::R(it) => {
+            VisitMut::visit_run_mut(v, it);
+        }
+        RubyContent::ProofErr(it) => {
+            VisitMut::visit_ct_proof_err_mut(v, it);
+        }
+        RubyContent::PermStart(it) => {
+            VisitMut::visit_ct_perm_start_mut(v, it);
+        }
+        RubyContent::PermEnd(it) => {
+            VisitMut::visit_ct_perm_mut(v, it);
+        }
+        RubyContent::BookmarkStart(it) => {
+            VisitMut::visit_bookmark_mut(v, it);
+        }
+        RubyContent::BookmarkEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RubyContent::MoveFromRangeStart(it) => {
+            VisitMut::visit_ct_move_bookmark_mut(v, it);
+        }
+        RubyContent::MoveFromRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RubyContent::MoveToRangeStart(it) => {
+            VisitMut::visit_ct_move_bookmark_mut(v, it);
+        }
+        RubyContent::MoveToRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RubyContent::CommentRangeStart(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RubyContent::CommentRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RubyContent::CustomXmlInsRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        RubyContent::CustomXmlInsRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        RubyContent::CustomXmlDelRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        RubyContent::CustomXmlDelRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        RubyContent::CustomXmlMoveFromRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        RubyContent::CustomXmlMoveFromRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        RubyContent::CustomXmlMoveToRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        RubyContent::CustomXmlMoveToRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        RubyContent::Ins(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        RubyContent::Del(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        RubyContent::MoveFrom(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        RubyContent::MoveTo(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+    }
+}
+
+pub fn walk_run<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Run) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.r_pr {
+            Visit::visit_run_properties(v, it0);
+        }
+    }
+    for it0 in &node.run_content {
+        Visit::visit_run_content(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_run_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Run) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.r_pr {
+            VisitMut::visit_run_properties_mut(v, it0);
+        }
+    }
+    for it0 in &mut node.run_content {
+        VisitMut::visit_run_content_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_run_content<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast RunContent) {
+    match node {
+        RunContent::Br(it) => {
+            Visit::visit_ct_br(v, it);
+        }
+        RunContent::T(it) => {
+            Visit::visit_text(v, it);
+        }
+        RunContent::ContentPart(it) => {
+            Visit::visit_ct_rel(v, it);
+        }
+        RunContent::DelText(it) => {
+            Visit::visit_text(v, it);
+        }
+        RunContent::InstrText(it) => {
+            Visit::visit_text(v, it);
+        }
+        RunContent::DelInstrText(it) => {
+            Visit::visit_text(v, it);
+        }
+        RunContent::NoBreakHyphen(it) => {
+            Visit::visit_ct_empty(v, it);
+        }
+        RunContent::SoftHyphen(it) => {
+            Visit::visit_ct_empty(v, it);
+        }
+        RunContent::DayShort(it) => {
+            Visit::visit_ct_empty(v, it);
+        }
+        RunContent::MonthShort(it) => {
+            Visit::visit_ct_empty(v, it);
+        }
+        RunContent::YearShort(it) => {
+            Visit::visit_ct_empty(v, it);
+        }
+        RunContent::DayLong(it) => {
+            Visit::visit_ct_empty(v, it);
+        }
+        RunContent::MonthLong(it) => {
+            Visit::visit_ct_empty(v, it);
+        }
+        RunContent::YearLong(it) => {
+            Visit::visit_ct_empty(v, it);
+        }
+        RunContent::AnnotationRef(it) => {
+            Visit::visit_ct_empty(v, it);
+        }
+        RunContent::FootnoteRef(it) => {
+            Visit::visit_ct_empty(v, it);
+        }
+        RunContent::EndnoteRef(it) => {
+            Visit::visit_ct_empty(v, it);
+        }
+        RunContent::Separator(it) => {
+            Visit::visit_ct_empty(v, it);
+        }
+        RunContent::ContinuationSeparator(it) => {
+            Visit::visit_ct_empty(v, it);
+        }
+        RunContent::Sym(it) => {
+            Visit::visit_ct_sym(v, it);
+        }
+        RunContent::PgNum(it) => {
+            Visit::visit_ct_empty(v, it);
+        }
+        RunContent::Cr(it) => {
+            Visit::visit_ct_empty(v, it);
+        }
+        RunContent::Tab(it) => {
+            Visit::visit_ct_empty(v, it);
+        }
+        RunContent::Object(it) => {
+            Visit::visit_ct_object(v, it);
+        }
+        RunContent::Pict(it) => {
+            Visit::visit_ct_picture(v, it);
+        }
+        RunContent::FldChar(it) => {
+            Visit::visit_ct_fld_char(v, it);
+        }
+        RunContent::Ruby(it) => {
+            Visit::visit_ct_ruby(v, it);
+        }
+        RunContent::FootnoteReference(it) => {
+            Visit::visit_footnote_endnote_ref(v, it);
+        }
+        RunContent::EndnoteReference(it) => {
+            Visit::visit_footnote_endnote_ref(v, it);
+        }
+        RunContent::CommentReference(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        RunContent::Drawing(it) => {
+            Visit::visit_ct_drawing(v, it);
+        }
+        RunContent::Ptab(it) => {
+            Visit::visit_ctp_tab(v, it);
+        }
+        RunContent::LastRenderedPageBreak(it) => {
+            Visit::visit_ct_empty(v, it);
+        }
+    }
+}
+
+pub fn walk_run_content_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut RunContent) {
+    match node {
+        RunContent::Br(it) => {
+            VisitMut::visit_ct_br_mut(v, it);
+        }
+        RunContent::T(it) => {
+            VisitMut::visit_text_mut(v, it);
+        }
+        RunContent::ContentPart(it) => {
+            VisitMut::visit_ct_rel_mut(v, it);
+        }
+        RunContent::DelText(it) => {
+            VisitMut::visit_text_mut(v, it);
+        }
+        RunContent::InstrText(it) => {
+            VisitMut::visit_text_mut(v, it);
+        }
+        RunContent::DelInstrText(it) => {
+            VisitMut::visit_text_mut(v, it);
+        }
+        RunContent::NoBreakHyphen(it) => {
+            VisitMut::visit_ct_empty_mut(v, it);
+        }
+        RunContent::SoftHyphen(it) => {
+            VisitMut::visit_ct_empty_mut(v, it);
+        }
+        RunContent::DayShort(it) => {
+            VisitMut::visit_ct_empty_mut(v, it);
+        }
+        RunContent::MonthShort(it) => {
+            VisitMut::visit_ct_empty_mut(v, it);
+        }
+        RunContent::YearShort(it) => {
+            VisitMut::visit_ct_empty_mut(v, it);
+        }
+        RunContent::DayLong(it) => {
+            VisitMut::visit_ct_empty_mut(v, it);
+        }
+        RunContent::MonthLong(it) => {
+            VisitMut::visit_ct_empty_mut(v, it);
+        }
+        RunContent::YearLong(it) => {
+            VisitMut::visit_ct_empty_mut(v, it);
+        }
+        RunContent::AnnotationRef(it) => {
+            VisitMut::visit_ct_empty_mut(v, it);
+        }
+        RunContent::FootnoteRef(it) => {
+            VisitMut::visit_ct_empty_mut(v, it);
+        }
+        RunContent::EndnoteRef(it) => {
+            VisitMut::visit_ct_empty_mut(v, it);
+        }
+        RunContent::Separator(it) => {
+            VisitMut::visit_ct_empty_mut(v, it);
+        }
+        RunContent::ContinuationSeparator(it) => {
+            VisitMut::visit_ct_empty_mut(v, it);
+        }
+        RunContent::Sym(it) => {
+            VisitMut::visit_ct_sym_mut(v, it);
+        }
+        RunContent::PgNum(it) => {
+            VisitMut::visit_ct_empty_mut(v, it);
+        }
+        RunContent::Cr(it) => {
+            VisitMut::visit_ct_empty_mut(v, it);
+        }
+        RunContent::Tab(it) => {
+            VisitMut::visit_ct_empty_mut(v, it);
+        }
+        RunContent::Object(it) => {
+            VisitMut::visit_ct_object_mut(v, it);
+        }
+        RunContent::Pict(it) => {
+            VisitMut::visit_ct_picture_mut(v, it);
+        }
+        RunContent::FldChar(it) => {
+            VisitMut::visit_ct_fld_char_mut(v, it);
+        }
+        RunContent::Ruby(it) => {
+            VisitMut::visit_ct_ruby_mut(v, it);
+        }
+        RunContent::FootnoteReference(it) => {
+            VisitMut::visit_footnote_endnote_ref_mut(v, it);
+        }
+        RunContent::EndnoteReference(it) => {
+            VisitMut::visit_footnote_endnote_ref_mut(v, it);
+        }
+        RunContent::CommentReference(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        RunContent::Drawing(it) => {
+            VisitMut::visit_ct_drawing_mut(v, it);
+        }
+        RunContent::Ptab(it) => {
+            VisitMut::visit_ctp_tab_mut(v, it);
+        }
+        RunContent::LastRenderedPageBreak(it) => {
+            VisitMut::visit_ct_empty_mut(v, it);
+        }
+    }
+}
+
+pub fn walk_run_content_base<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast RunContentBase) {
+    match node {
+        RunContentBase::SmartTag(it) => {
+            Visit::visit_ct_smart_tag_run(v, it);
+        }
+        RunContentBase::Sdt(it) => {
+            Visit::visit_ct_sdt_run(v, it);
+        }
+        RunContentBase::ProofErr(it) => {
+            Visit::visit_ct_proof_err(v, it);
+        }
+        RunContentBase::PermStart(it) => {
+            Visit::visit_ct_perm_start(v, it);
+        }
+        RunContentBase::PermEnd(it) => {
+            Visit::visit_ct_perm(v, it);
+        }
+        RunContentBase::BookmarkStart(it) => {
+            Visit::visit_bookmark(v, it);
+        }
+        RunContentBase::BookmarkEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RunContentBase::MoveFromRangeStart(it) => {
+            Visit::visit_ct_move_bookmark(v, it);
+        }
+        RunContentBase::MoveFromRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RunContentBase::MoveToRangeStart(it) => {
+            Visit::visit_ct_move_bookmark(v, it);
+        }
+        RunContentBase::MoveToRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RunContentBase::CommentRangeStart(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RunContentBase::CommentRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RunContentBase::CustomXmlInsRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        RunContentBase::CustomXmlInsRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        RunContentBase::CustomXmlDelRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        RunContentBase::CustomXmlDelRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        RunContentBase::CustomXmlMoveFromRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        RunContentBase::CustomXmlMoveFromRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        RunContentBase::CustomXmlMoveToRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        RunContentBase::CustomXmlMoveToRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        RunContentBase::Ins(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        RunContentBase::Del(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        RunContentBase::MoveFrom(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        RunContentBase::MoveTo(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+    }
+}
+
+pub fn walk_run_content_base_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut RunContentBase) {
+    match node {
+        RunContentBase::SmartTag(it) => {
+            VisitMut::visit_ct_smart_tag_run_mut(v, it);
+        }
+        RunContentBase::Sdt(it) => {
+            VisitMut::visit_ct_sdt_run_mut(v, it);
+        }
+        RunContentBase::ProofErr(it) => {
+            VisitMut::visit_ct_proof_err_mut(v, it);
+        }
+        RunContentBase::PermStart(it) => {
+            VisitMut::visit_ct_perm_start_mut(v, it);
+        }
+        RunContentBase::PermEnd(it) => {
+            VisitMut::visit_ct_perm_mut(v, it);
+        }
+        RunContentBase::BookmarkStart(it) => {
+            VisitMut::visit_bookmark_mut(v, it);
+        }
+        RunContentBase::BookmarkEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RunContentBase::MoveFromRangeStart(it) => {
+            VisitMut::visit_ct_move_bookmark_mut(v, it);
+        }
+        RunContentBase::MoveFromRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RunContentBase::MoveToRangeStart(it) => {
+            VisitMut::visit_ct_move_bookmark_mut(v, it);
+        }
+        RunContentBase::MoveToRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RunContentBase::CommentRangeStart(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RunContentBase::CommentRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RunContentBase::CustomXmlInsRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        RunContentBase::CustomXmlInsRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        RunContentBase::CustomXmlDelRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        RunContentBase::CustomXmlDelRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        RunContentBase::CustomXmlMoveFromRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        RunContentBase::CustomXmlMoveFromRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        RunContentBase::CustomXmlMoveToRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        RunContentBase::CustomXmlMoveToRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        RunContentBase::Ins(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        RunContentBase::Del(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        RunContentBase::MoveFrom(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        RunContentBase::MoveTo(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+    }
+}
+
+pub fn walk_run_content_choice<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast RunContentChoice,
+) {
+    match node {
+        RunContentChoice::CustomXml(it) => {
+            Visit::visit_ct_custom_xml_run(v, it);
+        }
+        RunContentChoice::SmartTag(it) => {
+            Visit::visit_ct_smart_tag_run(v, it);
+        }
+        RunContentChoice::Sdt(it) => {
+            Visit::visit_ct_sdt_run(v, it);
+        }
+        RunContentChoice::Dir(it) => {
+            Visit::visit_ct_dir_content_run(v, it);
+        }
+        RunContentChoice::Bdo(it) => {
+            Visit::visit_ct_bdo_content_run(v, it);
+        }
+        RunContentChoice::R(it) => {
+            Visit::visit_run(v, it);
+        }
+        RunContentChoice::ProofErr(it) => {
+            Visit::visit_ct_proof_err(v, it);
+        }
+        RunContentChoice::PermStart(it) => {
+            Visit::visit_ct_perm_start(v, it);
+        }
+        RunContentChoice::PermEnd(it) => {
+            Visit::visit_ct_perm(v, it);
+        }
+        RunContentChoice::BookmarkStart(it) => {
+            Visit::visit_bookmark(v, it);
+        }
+        RunContentChoice::BookmarkEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RunContentChoice::MoveFromRangeStart(it) => {
+            Visit::visit_ct_move_bookmark(v, it);
+        }
+        RunContentChoice::MoveFromRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RunContentChoice::MoveToRangeStart(it) => {
+            Visit::visit_ct_move_bookmark(v, it);
+        }
+        RunContentChoice::MoveToRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RunContentChoice::CommentRangeStart(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RunContentChoice::CommentRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RunContentChoice::CustomXmlInsRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        RunContentChoice::CustomXmlInsRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        RunContentChoice::CustomXmlDelRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        RunContentChoice::CustomXmlDelRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        RunContentChoice::CustomXmlMoveFromRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        RunContentChoice::CustomXmlMoveFromRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        RunContentChoice::CustomXmlMoveToRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        RunContentChoice::CustomXmlMoveToRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        RunContentChoice::Ins(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        RunContentChoice::Del(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        RunContentChoice::MoveFrom(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        RunContentChoice::MoveTo(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+    }
+}
+
+pub fn walk_run_content_choice_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut RunContentChoice) {
+    match node {
+        RunContentChoice::CustomXml(it) => {
+            VisitMut::visit_ct_custom_xml_run_mut(v, it);
+        }
+        RunContentChoice::SmartTag(it) => {
+            VisitMut::visit_ct_smart_tag_run_mut(v, it);
+        }
+        RunContentChoice::Sdt(it) => {
+            VisitMut::visit_ct_sdt_run_mut(v, it);
+        }
+        RunContentChoice::Dir(it) => {
+            VisitMut::visit_ct_dir_content_run_mut(v, it);
+        }
+        RunContentChoice::Bdo(it) => {
+            VisitMut::visit_ct_bdo_content_run_mut(v, it);
+        }
+        RunContentChoice::R(it) => {
+            VisitMut::visit_run_mut(v, it);
+        }
+        RunContentChoice::ProofErr(it) => {
+            VisitMut::visit_ct_proof_err_mut(v, it);
+        }
+        RunContentChoice::PermStart(it) => {
+            VisitMut::visit_ct_perm_start_mut(v, it);
+        }
+        RunContentChoice::PermEnd(it) => {
+            VisitMut::visit_ct_perm_mut(v, it);
+        }
+        RunContentChoice::BookmarkStart(it) => {
+            VisitMut::visit_bookmark_mut(v, it);
+        }
+        RunContentChoice::BookmarkEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RunContentChoice::MoveFromRangeStart(it) => {
+            VisitMut::visit_ct_move_bookmark_mut(v, it);
+        }
+        RunContentChoice::MoveFromRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RunContentChoice::MoveToRangeStart(it) => {
+            VisitMut::visit_ct_move_bookmark_mut(v, it);
+        }
+        RunContentChoice::MoveToRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RunContentChoice::CommentRangeStart(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RunContentChoice::CommentRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RunContentChoice::CustomXmlInsRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        RunContentChoice::CustomXmlInsRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        RunContentChoice::CustomXmlDelRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        RunContentChoice::CustomXmlDelRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        RunContentChoice::CustomXmlMoveFromRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        RunContentChoice::CustomXmlMoveFromRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        RunContentChoice::CustomXmlMoveToRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        RunContentChoice::CustomXmlMoveToRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        RunContentChoice::Ins(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        RunContentChoice::Del(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        RunContentChoice::MoveFrom(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        RunContentChoice::MoveTo(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+    }
+}
+
+pub fn walk_run_level_content<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast RunLevelContent,
+) {
+    match node {
+        RunLevelContent::ProofErr(it) => {
+            Visit::visit_ct_proof_err(v, it);
+        }
+        RunLevelContent::PermStart(it) => {
+            Visit::visit_ct_perm_start(v, it);
+        }
+        RunLevelContent::PermEnd(it) => {
+            Visit::visit_ct_perm(v, it);
+        }
+        RunLevelContent::BookmarkStart(it) => {
+            Visit::visit_bookmark(v, it);
+        }
+        RunLevelContent::BookmarkEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RunLevelContent::MoveFromRangeStart(it) => {
+            Visit::visit_ct_move_bookmark(v, it);
+        }
+        RunLevelContent::MoveFromRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RunLevelContent::MoveToRangeStart(it) => {
+            Visit::visit_ct_move_bookmark(v, it);
+        }
+        RunLevelContent::MoveToRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RunLevelContent::CommentRangeStart(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RunLevelContent::CommentRangeEnd(it) => {
+            Visit::visit_ct_markup_range(v, it);
+        }
+        RunLevelContent::CustomXmlInsRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        RunLevelContent::CustomXmlInsRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        RunLevelContent::CustomXmlDelRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        RunLevelContent::CustomXmlDelRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        RunLevelContent::CustomXmlMoveFromRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        RunLevelContent::CustomXmlMoveFromRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        RunLevelContent::CustomXmlMoveToRangeStart(it) => {
+            Visit::visit_ct_track_change(v, it);
+        }
+        RunLevelContent::CustomXmlMoveToRangeEnd(it) => {
+            Visit::visit_ct_markup(v, it);
+        }
+        RunLevelContent::Ins(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        RunLevelContent::Del(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        RunLevelContent::MoveFrom(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+        RunLevelContent::MoveTo(it) => {
+            Visit::visit_ct_run_track_change(v, it);
+        }
+    }
+}
+
+pub fn walk_run_level_content_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut RunLevelContent) {
+    match node {
+        RunLevelContent::ProofErr(it) => {
+            VisitMut::visit_ct_proof_err_mut(v, it);
+        }
+        RunLevelContent::PermStart(it) => {
+            VisitMut::visit_ct_perm_start_mut(v, it);
+        }
+        RunLevelContent::PermEnd(it) => {
+            VisitMut::visit_ct_perm_mut(v, it);
+        }
+        RunLevelContent::BookmarkStart(it) => {
+            VisitMut::visit_bookmark_mut(v, it);
+        }
+        RunLevelContent::BookmarkEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RunLevelContent::MoveFromRangeStart(it) => {
+            VisitMut::visit_ct_move_bookmark_mut(v, it);
+        }
+        RunLevelContent::MoveFromRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RunLevelContent::MoveToRangeStart(it) => {
+            VisitMut::visit_ct_move_bookmark_mut(v, it);
+        }
+        RunLevelContent::MoveToRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RunLevelContent::CommentRangeStart(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RunLevelContent::CommentRangeEnd(it) => {
+            VisitMut::visit_ct_markup_range_mut(v, it);
+        }
+        RunLevelContent::CustomXmlInsRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        RunLevelContent::CustomXmlInsRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        RunLevelContent::CustomXmlDelRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        RunLevelContent::CustomXmlDelRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        RunLevelContent::CustomXmlMoveFromRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        RunLevelContent::CustomXmlMoveFromRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        RunLevelContent::CustomXmlMoveToRangeStart(it) => {
+            VisitMut::visit_ct_track_change_mut(v, it);
+        }
+        RunLevelContent::CustomXmlMoveToRangeEnd(it) => {
+            VisitMut::visit_ct_markup_mut(v, it);
+        }
+        RunLevelContent::Ins(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        RunLevelContent::Del(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        RunLevelContent::MoveFrom(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+        RunLevelContent::MoveTo(it) => {
+            VisitMut::visit_ct_run_track_change_mut(v, it);
+        }
+    }
+}
+
+pub fn walk_run_properties<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast RunProperties) {
+    if let Some(it0) = &node.run_style {
+        Visit::visit_ct_string(v, it0);
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.fonts {
+            Visit::visit_fonts(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.bold {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.b_cs {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.italic {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.i_cs {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.caps {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.small_caps {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.strikethrough {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.dstrike {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.outline {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.shadow {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.emboss {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.imprint {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.no_proof {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.snap_to_grid {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.vanish {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.web_hidden {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.color {
+            Visit::visit_ct_color(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.spacing {
+            Visit::visit_signed_twips_measure_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.width {
+            Visit::visit_text_scale_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.kern {
+            Visit::visit_hps_measure_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.position {
+            Visit::visit_signed_hps_measure_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.size {
+            Visit::visit_hps_measure_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.size_complex_script {
+            Visit::visit_hps_measure_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.highlight {
+            Visit::visit_ct_highlight(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.underline {
+            Visit::visit_ct_underline(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.effect {
+            Visit::visit_ct_text_effect(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.bdr {
+            Visit::visit_ct_border(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.shading {
+            Visit::visit_ct_shd(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.fit_text {
+            Visit::visit_ct_fit_text(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.vert_align {
+            Visit::visit_ct_vertical_align_run(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.rtl {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.cs {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.em {
+            Visit::visit_ct_em(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.lang {
+            Visit::visit_language_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.east_asian_layout {
+            Visit::visit_ct_east_asian_layout(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.spec_vanish {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.o_math {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &node.r_pr_change {
+            Visit::visit_ctr_pr_change(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_run_properties_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut RunProperties) {
+    if let Some(it0) = &mut node.run_style {
+        VisitMut::visit_ct_string_mut(v, it0);
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.fonts {
+            VisitMut::visit_fonts_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.bold {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.b_cs {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.italic {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.i_cs {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.caps {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.small_caps {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.strikethrough {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.dstrike {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.outline {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.shadow {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.emboss {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.imprint {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.no_proof {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.snap_to_grid {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.vanish {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.web_hidden {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.color {
+            VisitMut::visit_ct_color_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.spacing {
+            VisitMut::visit_signed_twips_measure_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.width {
+            VisitMut::visit_text_scale_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.kern {
+            VisitMut::visit_hps_measure_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.position {
+            VisitMut::visit_signed_hps_measure_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.size {
+            VisitMut::visit_hps_measure_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.size_complex_script {
+            VisitMut::visit_hps_measure_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.highlight {
+            VisitMut::visit_ct_highlight_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.underline {
+            VisitMut::visit_ct_underline_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.effect {
+            VisitMut::visit_ct_text_effect_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.bdr {
+            VisitMut::visit_ct_border_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.shading {
+            VisitMut::visit_ct_shd_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.fit_text {
+            VisitMut::visit_ct_fit_text_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.vert_align {
+            VisitMut::visit_ct_vertical_align_run_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.rtl {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.cs {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.em {
+            VisitMut::visit_ct_em_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.lang {
+            VisitMut::visit_language_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.east_asian_layout {
+            VisitMut::visit_ct_east_asian_layout_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.spec_vanish {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.o_math {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &mut node.r_pr_change {
+            VisitMut::visit_ctr_pr_change_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_run_properties_default<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast RunPropertiesDefault,
+) {
+    if let Some(it0) = &node.r_pr {
+        Visit::visit_run_properties(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_run_properties_default_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut RunPropertiesDefault,
+) {
+    if let Some(it0) = &mut node.r_pr {
+        VisitMut::visit_run_properties_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_section_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast SectionProperties,
+) {
+    for it0 in &node.header_footer_refs {
+        Visit::visit_header_footer_ref(v, it0);
+    }
+    #[cfg(feature = "wml-comments")]
+    {
+        if let Some(it0) = &node.footnote_pr {
+            Visit::visit_ct_ftn_props(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-comments")]
+    {
+        if let Some(it0) = &node.endnote_pr {
+            Visit::visit_ct_edn_props(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.r#type {
+            Visit::visit_ct_sect_type(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.pg_sz {
+            Visit::visit_page_size(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.pg_mar {
+            Visit::visit_page_margins(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.paper_src {
+            Visit::visit_ct_paper_source(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.pg_borders {
+            Visit::visit_ct_page_borders(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.ln_num_type {
+            Visit::visit_ct_line_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.pg_num_type {
+            Visit::visit_ct_page_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.cols {
+            Visit::visit_columns(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.form_prot {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.v_align {
+            Visit::visit_ct_vertical_jc(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-comments")]
+    {
+        if let Some(it0) = &node.no_endnote {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.title_pg {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.text_direction {
+            Visit::visit_ct_text_direction(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.bidi {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.rtl_gutter {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.doc_grid {
+            Visit::visit_document_grid(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &node.printer_settings {
+            Visit::visit_ct_rel(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &node.sect_pr_change {
+            Visit::visit_ct_sect_pr_change(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_section_properties_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SectionProperties) {
+    for it0 in &mut node.header_footer_refs {
+        VisitMut::visit_header_footer_ref_mut(v, it0);
+    }
+    #[cfg(feature = "wml-comments")]
+    {
+        if let Some(it0) = &mut node.footnote_pr {
+            VisitMut::visit_ct_ftn_props_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-comments")]
+    {
+        if let Some(it0) = &mut node.endnote_pr {
+            VisitMut::visit_ct_edn_props_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.r#type {
+            VisitMut::visit_ct_sect_type_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.pg_sz {
+            VisitMut::visit_page_size_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.pg_mar {
+            VisitMut::visit_page_margins_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.paper_src {
+            VisitMut::visit_ct_paper_source_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.pg_borders {
+            VisitMut::visit_ct_page_borders_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.ln_num_type {
+            VisitMut::visit_ct_line_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.pg_num_type {
+            VisitMut::visit_ct_page_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.cols {
+            VisitMut::visit_columns_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.form_prot {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.v_align {
+            VisitMut::visit_ct_vertical_jc_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-comments")]
+    {
+        if let Some(it0) = &mut node.no_endnote {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.title_pg {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.text_direction {
+            VisitMut::visit_ct_text_direction_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.bidi {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.rtl_gutter {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.doc_grid {
+            VisitMut::visit_document_grid_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-layout")]
+    {
+        if let Some(it0) = &mut node.printer_settings {
+            VisitMut::visit_ct_rel_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &mut node.sect_pr_change {
+            VisitMut::visit_ct_sect_pr_change_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_settings<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Settings) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.write_protection {
+            Visit::visit_ct_write_protection(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.view {
+            Visit::visit_ct_view(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.zoom {
+            Visit::visit_ct_zoom(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.remove_personal_information {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.remove_date_and_time {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_display_page_boundaries {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.display_background_shape {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.print_post_script_over_text {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.print_fractional_character_width {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.print_forms_data {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.embed_true_type_fonts {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.embed_system_fonts {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.save_subset_fonts {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.save_forms_data {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.mirror_margins {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.align_borders_and_edges {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.borders_do_not_surround_header {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.borders_do_not_surround_footer {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.gutter_at_top {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.hide_spelling_errors {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.hide_grammatical_errors {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &node.active_writing_style {
+            Visit::visit_ct_writing_style(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.proof_state {
+            Visit::visit_ct_proof(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.forms_design {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.attached_template {
+            Visit::visit_ct_rel(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.link_styles {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.style_pane_format_filter {
+            Visit::visit_ct_style_pane_filter(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.style_pane_sort_method {
+            Visit::visit_ct_style_sort(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.document_type {
+            Visit::visit_doc_type_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.mail_merge {
+            Visit::visit_ct_mail_merge(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.revision_view {
+            Visit::visit_ct_track_changes_view(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.track_revisions {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &node.do_not_track_moves {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &node.do_not_track_formatting {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.document_protection {
+            Visit::visit_ct_doc_protect(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.auto_format_override {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.style_lock_theme {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.style_lock_q_f_set {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.default_tab_stop {
+            Visit::visit_twips_measure_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.auto_hyphenation {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.consecutive_hyphen_limit {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.hyphenation_zone {
+            Visit::visit_twips_measure_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_hyphenate_caps {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.show_envelope {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.summary_length {
+            Visit::visit_ct_decimal_number_or_precent(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.click_and_type_style {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.default_table_style {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.even_and_odd_headers {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.book_fold_rev_printing {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.book_fold_printing {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.book_fold_printing_sheets {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.drawing_grid_horizontal_spacing {
+            Visit::visit_twips_measure_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.drawing_grid_vertical_spacing {
+            Visit::visit_twips_measure_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.display_horizontal_drawing_grid_every {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.display_vertical_drawing_grid_every {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_use_margins_for_drawing_grid_origin {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.drawing_grid_horizontal_origin {
+            Visit::visit_twips_measure_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.drawing_grid_vertical_origin {
+            Visit::visit_twips_measure_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_shade_form_data {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.no_punctuation_kerning {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.character_spacing_control {
+            Visit::visit_ct_character_spacing(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.print_two_on_one {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.strict_first_and_last_chars {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.no_line_breaks_after {
+            Visit::visit_ct_kinsoku(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.no_line_breaks_before {
+            Visit::visit_ct_kinsoku(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.save_preview_picture {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_validate_against_schema {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.save_invalid_xml {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.ignore_mixed_content {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.always_show_placeholder_text {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_demarcate_invalid_xml {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.save_xml_data_only {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.use_x_s_l_t_when_saving {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.save_through_xslt {
+            Visit::visit_ct_save_through_xslt(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.show_x_m_l_tags {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.always_merge_empty_namespace {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.update_fields {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-drawings")]
+    {
+        if let Some(it0) = &node.hdr_shape_defaults {
+            Visit::visit_ct_shape_defaults(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-comments")]
+    {
+        if let Some(it0) = &node.footnote_pr {
+            Visit::visit_ct_ftn_doc_props(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-comments")]
+    {
+        if let Some(it0) = &node.endnote_pr {
+            Visit::visit_ct_edn_doc_props(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.compat {
+            Visit::visit_compatibility(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.doc_vars {
+            Visit::visit_ct_doc_vars(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.rsids {
+            Visit::visit_ct_doc_rsids(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &node.attached_schema {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.theme_font_lang {
+            Visit::visit_language_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.clr_scheme_mapping {
+            Visit::visit_ct_color_scheme_mapping(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_include_subdocs_in_stats {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_auto_compress_pictures {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.force_upgrade {
+            Visit::visit_ct_empty(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.captions {
+            Visit::visit_ct_captions(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.read_mode_ink_lock_down {
+            Visit::visit_ct_reading_mode_ink_lock_down(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &node.smart_tag_type {
+            Visit::visit_ct_smart_tag_type(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-drawings")]
+    {
+        if let Some(it0) = &node.shape_defaults {
+            Visit::visit_ct_shape_defaults(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.do_not_embed_smart_tags {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.decimal_symbol {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.list_separator {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_settings_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Settings) {
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.write_protection {
+            VisitMut::visit_ct_write_protection_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.view {
+            VisitMut::visit_ct_view_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.zoom {
+            VisitMut::visit_ct_zoom_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.remove_personal_information {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.remove_date_and_time {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_display_page_boundaries {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.display_background_shape {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.print_post_script_over_text {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.print_fractional_character_width {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.print_forms_data {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.embed_true_type_fonts {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.embed_system_fonts {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.save_subset_fonts {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.save_forms_data {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.mirror_margins {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.align_borders_and_edges {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.borders_do_not_surround_header {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.borders_do_not_surround_footer {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.gutter_at_top {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.hide_spelling_errors {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.hide_grammatical_errors {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &mut node.active_writing_style {
+            VisitMut::visit_ct_writing_style_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.proof_state {
+            VisitMut::visit_ct_proof_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.forms_design {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.attached_template {
+            VisitMut::visit_ct_rel_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.link_styles {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.style_pane_format_filter {
+            VisitMut::visit_ct_style_pane_filter_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.style_pane_sort_method {
+            VisitMut::visit_ct_style_sort_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.document_type {
+            VisitMut::visit_doc_type_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.mail_merge {
+            VisitMut::visit_ct_mail_merge_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.revision_view {
+            VisitMut::visit_ct_track_changes_view_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.track_revisions {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &mut node.do_not_track_moves {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &mut node.do_not_track_formatting {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.document_protection {
+            VisitMut::visit_ct_doc_protect_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.auto_format_override {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.style_lock_theme {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.style_lock_q_f_set {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.default_tab_stop {
+            VisitMut::visit_twips_measure_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.auto_hyphenation {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.consecutive_hyphen_limit {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.hyphenation_zone {
+            VisitMut::visit_twips_measure_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_hyphenate_caps {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.show_envelope {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.summary_length {
+            VisitMut::visit_ct_decimal_number_or_precent_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.click_and_type_style {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.default_table_style {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.even_and_odd_headers {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.book_fold_rev_printing {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.book_fold_printing {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.book_fold_printing_sheets {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.drawing_grid_horizontal_spacing {
+            VisitMut::visit_twips_measure_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.drawing_grid_vertical_spacing {
+            VisitMut::visit_twips_measure_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.display_horizontal_drawing_grid_every {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.display_vertical_drawing_grid_every {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_use_margins_for_drawing_grid_origin {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.drawing_grid_horizontal_origin {
+            VisitMut::visit_twips_measure_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.drawing_grid_vertical_origin {
+            VisitMut::visit_twips_measure_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_shade_form_data {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.no_punctuation_kerning {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.character_spacing_control {
+            VisitMut::visit_ct_character_spacing_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.print_two_on_one {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.strict_first_and_last_chars {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.no_line_breaks_after {
+            VisitMut::visit_ct_kinsoku_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.no_line_breaks_before {
+            VisitMut::visit_ct_kinsoku_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.save_preview_picture {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_validate_against_schema {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.save_invalid_xml {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.ignore_mixed_content {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.always_show_placeholder_text {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_demarcate_invalid_xml {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.save_xml_data_only {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.use_x_s_l_t_when_saving {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.save_through_xslt {
+            VisitMut::visit_ct_save_through_xslt_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.show_x_m_l_tags {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.always_merge_empty_namespace {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.update_fields {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-drawings")]
+    {
+        if let Some(it0) = &mut node.hdr_shape_defaults {
+            VisitMut::visit_ct_shape_defaults_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-comments")]
+    {
+        if let Some(it0) = &mut node.footnote_pr {
+            VisitMut::visit_ct_ftn_doc_props_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-comments")]
+    {
+        if let Some(it0) = &mut node.endnote_pr {
+            VisitMut::visit_ct_edn_doc_props_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.compat {
+            VisitMut::visit_compatibility_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.doc_vars {
+            VisitMut::visit_ct_doc_vars_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.rsids {
+            VisitMut::visit_ct_doc_rsids_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &mut node.attached_schema {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.theme_font_lang {
+            VisitMut::visit_language_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.clr_scheme_mapping {
+            VisitMut::visit_ct_color_scheme_mapping_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_include_subdocs_in_stats {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_auto_compress_pictures {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.force_upgrade {
+            VisitMut::visit_ct_empty_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.captions {
+            VisitMut::visit_ct_captions_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.read_mode_ink_lock_down {
+            VisitMut::visit_ct_reading_mode_ink_lock_down_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        for it0 in &mut node.smart_tag_type {
+            VisitMut::visit_ct_smart_tag_type_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-drawings")]
+    {
+        if let Some(it0) = &mut node.shape_defaults {
+            VisitMut::visit_ct_shape_defaults_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.do_not_embed_smart_tags {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.decimal_symbol {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.list_separator {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_style<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Style) {
+    if let Some(it0) = &node.r#type {
+        Visit::visit_st_style_type(v, it0);
+    }
+    if let Some(it0) = &node.name {
+        Visit::visit_ct_string(v, it0);
+    }
+    if let Some(it0) = &node.aliases {
+        Visit::visit_ct_string(v, it0);
+    }
+    if let Some(it0) = &node.based_on {
+        Visit::visit_ct_string(v, it0);
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.next {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.link {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.auto_redefine {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.hidden {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.ui_priority {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.semi_hidden {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.unhide_when_used {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.q_format {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.locked {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.personal {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.personal_compose {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.personal_reply {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &node.rsid {
+            Visit::visit_long_hex_number_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.p_pr {
+            Visit::visit_ctp_pr_general(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.r_pr {
+            Visit::visit_run_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.table_properties {
+            Visit::visit_ct_tbl_pr_base(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.row_properties {
+            Visit::visit_table_row_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.cell_properties {
+            Visit::visit_table_cell_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        for it0 in &node.tbl_style_pr {
+            Visit::visit_table_style_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_style_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Style) {
+    if let Some(it0) = &mut node.r#type {
+        VisitMut::visit_st_style_type_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.name {
+        VisitMut::visit_ct_string_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.aliases {
+        VisitMut::visit_ct_string_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.based_on {
+        VisitMut::visit_ct_string_mut(v, it0);
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.next {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.link {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.auto_redefine {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.hidden {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.ui_priority {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.semi_hidden {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.unhide_when_used {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.q_format {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.locked {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.personal {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.personal_compose {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.personal_reply {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &mut node.rsid {
+            VisitMut::visit_long_hex_number_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.p_pr {
+            VisitMut::visit_ctp_pr_general_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.r_pr {
+            VisitMut::visit_run_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.table_properties {
+            VisitMut::visit_ct_tbl_pr_base_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.row_properties {
+            VisitMut::visit_table_row_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.cell_properties {
+            VisitMut::visit_table_cell_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        for it0 in &mut node.tbl_style_pr {
+            VisitMut::visit_table_style_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_styles<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Styles) {
+    if let Some(it0) = &node.doc_defaults {
+        Visit::visit_document_defaults(v, it0);
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.latent_styles {
+            Visit::visit_latent_styles(v, it0);
+        }
+    }
+    for it0 in &node.style {
+        Visit::visit_style(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_styles_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Styles) {
+    if let Some(it0) = &mut node.doc_defaults {
+        VisitMut::visit_document_defaults_mut(v, it0);
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.latent_styles {
+            VisitMut::visit_latent_styles_mut(v, it0);
+        }
+    }
+    for it0 in &mut node.style {
+        VisitMut::visit_style_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_table<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Table) {
+    for it0 in &node.range_markup {
+        Visit::visit_range_markup(v, it0);
+    }
+    Visit::visit_table_properties(v, &node.table_properties);
+    Visit::visit_table_grid(v, &node.tbl_grid);
+    for it0 in &node.rows {
+        Visit::visit_row_content(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_table_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Table) {
+    for it0 in &mut node.range_markup {
+        VisitMut::visit_range_markup_mut(v, it0);
+    }
+    VisitMut::visit_table_properties_mut(v, &mut node.table_properties);
+    VisitMut::visit_table_grid_mut(v, &mut node.tbl_grid);
+    for it0 in &mut node.rows {
+        VisitMut::visit_row_content_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_table_cell<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast TableCell) {
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.cell_properties {
+            Visit::visit_table_cell_properties(v, it0);
+        }
+    }
+    for it0 in &node.block_content {
+        Visit::visit_block_content(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_table_cell_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut TableCell) {
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.cell_properties {
+            VisitMut::visit_table_cell_properties_mut(v, it0);
+        }
+    }
+    for it0 in &mut node.block_content {
+        VisitMut::visit_block_content_mut(v, it0);
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_table_cell_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast TableCellProperties,
+) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.cnf_style {
+            Visit::visit_ct_cnf(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tc_w {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.grid_span {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.horizontal_merge {
+            Visit::visit_cth_merge(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.vertical_merge {
+            Visit::visit_ctv_merge(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tc_borders {
+            Visit::visit_ct_tc_borders(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.shading {
+            Visit::visit_ct_shd(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.no_wrap {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tc_mar {
+            Visit::visit_ct_tc_mar(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.text_direction {
+            Visit::visit_ct_text_direction(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tc_fit_text {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.v_align {
+            Visit::visit_ct_vertical_jc(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.hide_mark {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.headers {
+            Visit::visit_ct_headers(v, it0);
+        }
+    }
+    if let Some(it0) = &node.cell_markup {
+        Visit::visit_cell_markup(v, it0);
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &node.tc_pr_change {
+            Visit::visit_ct_tc_pr_change(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_table_cell_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut TableCellProperties,
+) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.cnf_style {
+            VisitMut::visit_ct_cnf_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tc_w {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.grid_span {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.horizontal_merge {
+            VisitMut::visit_cth_merge_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.vertical_merge {
+            VisitMut::visit_ctv_merge_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tc_borders {
+            VisitMut::visit_ct_tc_borders_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.shading {
+            VisitMut::visit_ct_shd_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.no_wrap {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tc_mar {
+            VisitMut::visit_ct_tc_mar_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.text_direction {
+            VisitMut::visit_ct_text_direction_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tc_fit_text {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.v_align {
+            VisitMut::visit_ct_vertical_jc_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.hide_mark {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.headers {
+            VisitMut::visit_ct_headers_mut(v, it0);
+        }
+    }
+    if let Some(it0) = &mut node.cell_markup {
+        VisitMut::visit_cell_markup_mut(v, it0);
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &mut node.tc_pr_change {
+            VisitMut::visit_ct_tc_pr_change_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_table_grid<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast TableGrid) {
+    for it0 in &node.grid_col {
+        Visit::visit_table_grid_column(v, it0);
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &node.tbl_grid_change {
+            Visit::visit_ct_tbl_grid_change(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_table_grid_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut TableGrid) {
+    for it0 in &mut node.grid_col {
+        VisitMut::visit_table_grid_column_mut(v, it0);
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &mut node.tbl_grid_change {
+            VisitMut::visit_ct_tbl_grid_change_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_table_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast TableProperties,
+) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.tbl_style {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tblp_pr {
+            Visit::visit_ct_tbl_p_pr(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_overlap {
+            Visit::visit_ct_tbl_overlap(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.bidi_visual {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.tbl_style_row_band_size {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.tbl_style_col_band_size {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_w {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.justification {
+            Visit::visit_ct_jc_table(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_cell_spacing {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_ind {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_borders {
+            Visit::visit_ct_tbl_borders(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.shading {
+            Visit::visit_ct_shd(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_layout {
+            Visit::visit_ct_tbl_layout_type(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_cell_mar {
+            Visit::visit_ct_tbl_cell_mar(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_look {
+            Visit::visit_ct_tbl_look(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_caption {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_description {
+            Visit::visit_ct_string(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &node.tbl_pr_change {
+            Visit::visit_ct_tbl_pr_change(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_table_properties_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut TableProperties) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.tbl_style {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tblp_pr {
+            VisitMut::visit_ct_tbl_p_pr_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_overlap {
+            VisitMut::visit_ct_tbl_overlap_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.bidi_visual {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.tbl_style_row_band_size {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.tbl_style_col_band_size {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_w {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.justification {
+            VisitMut::visit_ct_jc_table_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_cell_spacing {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_ind {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_borders {
+            VisitMut::visit_ct_tbl_borders_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.shading {
+            VisitMut::visit_ct_shd_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_layout {
+            VisitMut::visit_ct_tbl_layout_type_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_cell_mar {
+            VisitMut::visit_ct_tbl_cell_mar_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_look {
+            VisitMut::visit_ct_tbl_look_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_caption {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_description {
+            VisitMut::visit_ct_string_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &mut node.tbl_pr_change {
+            VisitMut::visit_ct_tbl_pr_change_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_table_row_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast TableRowProperties,
+) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.cnf_style {
+            Visit::visit_ct_cnf(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &node.div_id {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.grid_before {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.grid_after {
+            Visit::visit_ct_decimal_number(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.w_before {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.w_after {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.cant_split {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tr_height {
+            Visit::visit_ct_height(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_header {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.tbl_cell_spacing {
+            Visit::visit_ct_tbl_width(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.justification {
+            Visit::visit_ct_jc_table(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &node.hidden {
+            Visit::visit_on_off_element(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &node.ins {
+            Visit::visit_ct_track_change(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &node.del {
+            Visit::visit_ct_track_change(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &node.tr_pr_change {
+            Visit::visit_ct_tr_pr_change(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_table_row_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut TableRowProperties,
+) {
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.cnf_style {
+            VisitMut::visit_ct_cnf_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-settings")]
+    {
+        if let Some(it0) = &mut node.div_id {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.grid_before {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.grid_after {
+            VisitMut::visit_ct_decimal_number_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.w_before {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.w_after {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.cant_split {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tr_height {
+            VisitMut::visit_ct_height_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_header {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.tbl_cell_spacing {
+            VisitMut::visit_ct_tbl_width_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.justification {
+            VisitMut::visit_ct_jc_table_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-tables")]
+    {
+        if let Some(it0) = &mut node.hidden {
+            VisitMut::visit_on_off_element_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &mut node.ins {
+            VisitMut::visit_ct_track_change_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &mut node.del {
+            VisitMut::visit_ct_track_change_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-track-changes")]
+    {
+        if let Some(it0) = &mut node.tr_pr_change {
+            VisitMut::visit_ct_tr_pr_change_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_table_style_properties<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast TableStyleProperties,
+) {
+    Visit::visit_st_tbl_style_override_type(v, &node.r#type);
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.p_pr {
+            Visit::visit_ctp_pr_general(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.r_pr {
+            Visit::visit_run_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.table_properties {
+            Visit::visit_ct_tbl_pr_base(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.row_properties {
+            Visit::visit_table_row_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &node.cell_properties {
+            Visit::visit_table_cell_properties(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_table_style_properties_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut TableStyleProperties,
+) {
+    VisitMut::visit_st_tbl_style_override_type_mut(v, &mut node.r#type);
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.p_pr {
+            VisitMut::visit_ctp_pr_general_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.r_pr {
+            VisitMut::visit_run_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.table_properties {
+            VisitMut::visit_ct_tbl_pr_base_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.row_properties {
+            VisitMut::visit_table_row_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "wml-styling")]
+    {
+        if let Some(it0) = &mut node.cell_properties {
+            VisitMut::visit_table_cell_properties_mut(v, it0);
+        }
+    }
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_text<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast Text) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_text_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Text) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_wag_transitional_password<'ast, V: Visit<'ast> + ?Sized>(
+    v: &mut V,
+    node: &'ast WAGTransitionalPassword,
+) {
+    if let Some(it0) = &node.crypt_provider_type {
+        Visit::visit_st_crypt_prov(v, it0);
+    }
+    if let Some(it0) = &node.crypt_algorithm_class {
+        Visit::visit_st_alg_class(v, it0);
+    }
+    if let Some(it0) = &node.crypt_algorithm_type {
+        Visit::visit_st_alg_type(v, it0);
+    }
+}
+
+pub fn walk_wag_transitional_password_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut WAGTransitionalPassword,
+) {
+    if let Some(it0) = &mut node.crypt_provider_type {
+        VisitMut::visit_st_crypt_prov_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.crypt_algorithm_class {
+        VisitMut::visit_st_alg_class_mut(v, it0);
+    }
+    if let Some(it0) = &mut node.crypt_algorithm_type {
+        VisitMut::visit_st_alg_type_mut(v, it0);
+    }
+}
+
+pub fn walk_w_any_vml_office<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast WAnyVmlOffice) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_w_any_vml_office_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut WAnyVmlOffice) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+pub fn walk_w_any_vml_vml<'ast, V: Visit<'ast> + ?Sized>(v: &mut V, node: &'ast WAnyVmlVml) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &node.extra_children {
+            Visit::visit_raw_xml_node(v, &it0.node);
+        }
+    }
+}
+
+pub fn walk_w_any_vml_vml_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut WAnyVmlVml) {
+    #[cfg(feature = "extra-children")]
+    {
+        for it0 in &mut node.extra_children {
+            VisitMut::visit_raw_xml_node_mut(v, &mut it0.node);
+        }
+    }
+}
+
+impl ooxml_xml::QueryTree for AbstractNumbering {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.nsid {
+                it0.visit_element("w:nsid", v);
+            }
+        }
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.multi_level_type {
+                it0.visit_element("w:multiLevelType", v);
+            }
+        }
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.tmpl {
+                it0.visit_element("w:tmpl", v);
+            }
+        }
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.name {
+                it0.visit_element("w:name", v);
+            }
+        }
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.style_link {
+                it0.visit_element("w:styleLink", v);
+            }
+        }
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.num_style_link {
+                it0.visit_element("w:numStyleLink", v);
+            }
+        }
+        for it0 in &self.lvl {
+            it0.visit_element("w:lvl", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for BlockContent {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::CustomXml(it0) => {
+                it0.visit_element("w:customXml", v);
+            }
+            Self::Sdt(it0) => {
+                it0.visit_element("w:sdt", v);
+            }
+            Self::P(it0) => {
+                it0.visit_element("w:p", v);
+            }
+            Self::Tbl(it0) => {
+                it0.visit_element("w:tbl", v);
+            }
+            Self::ProofErr(it0) => {
+                it0.visit_element("w:proofErr", v);
+            }
+            Self::PermStart(it0) => {
+                it0.visit_element("w:permStart", v);
+            }
+            Self::PermEnd(it0) => {
+                it0.visit_element("w:permEnd", v);
+            }
+            Self::BookmarkStart(it0) => {
+                it0.visit_element("w:bookmarkStart", v);
+            }
+            Self::BookmarkEnd(it0) => {
+                it0.visit_element("w:bookmarkEnd", v);
+            }
+            Self::MoveFromRangeStart(it0) => {
+                it0.visit_element("w:moveFromRangeStart", v);
+            }
+            Self::MoveFromRangeEnd(it0) => {
+                it0.visit_element("w:moveFromRangeEnd", v);
+            }
+            Self::MoveToRangeStart(it0) => {
+                it0.visit_element("w:moveToRangeStart", v);
+            }
+            Self::MoveToRangeEnd(it0) => {
+                it0.visit_element("w:moveToRangeEnd", v);
+            }
+            Self::CommentRangeStart(it0) => {
+                it0.visit_element("w:commentRangeStart", v);
+            }
+            Self::CommentRangeEnd(it0) => {
+                it0.visit_element("w:commentRangeEnd", v);
+            }
+            Self::CustomXmlInsRangeStart(it0) => {
+                it0.visit_element("w:customXmlInsRangeStart", v);
+            }
+            Self::CustomXmlInsRangeEnd(it0) => {
+                it0.visit_element("w:customXmlInsRangeEnd", v);
+            }
+            Self::CustomXmlDelRangeStart(it0) => {
+                it0.visit_element("w:customXmlDelRangeStart", v);
+            }
+            Self::CustomXmlDelRangeEnd(it0) => {
+                it0.visit_element("w:customXmlDelRangeEnd", v);
+            }
+            Self::CustomXmlMoveFromRangeStart(it0) => {
+                it0.visit_element("w:customXmlMoveFromRangeStart", v);
+            }
+            Self::CustomXmlMoveFromRangeEnd(it0) => {
+                it0.visit_element("w:customXmlMoveFromRangeEnd", v);
+            }
+            Self::CustomXmlMoveToRangeStart(it0) => {
+                it0.visit_element("w:customXmlMoveToRangeStart", v);
+            }
+            Self::CustomXmlMoveToRangeEnd(it0) => {
+                it0.visit_element("w:customXmlMoveToRangeEnd", v);
+            }
+            Self::Ins(it0) => {
+                it0.visit_element("w:ins", v);
+            }
+            Self::Del(it0) => {
+                it0.visit_element("w:del", v);
+            }
+            Self::MoveFrom(it0) => {
+                it0.visit_element("w:moveFrom", v);
+            }
+            Self::MoveTo(it0) => {
+                it0.visit_element("w:moveTo", v);
+            }
+            Self::AltChunk(it0) => {
+                it0.visit_element("w:altChunk", v);
+            }
+        }
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::CustomXml(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Sdt(it0) => {
+                it0.visit_content(v);
+            }
+            Self::P(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Tbl(it0) => {
+                it0.visit_content(v);
+            }
+            Self::ProofErr(it0) => {
+                it0.visit_content(v);
+            }
+            Self::PermStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::PermEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::BookmarkStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::BookmarkEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFromRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFromRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveToRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveToRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CommentRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CommentRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlInsRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlInsRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlDelRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlDelRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveFromRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveFromRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveToRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveToRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Ins(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Del(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFrom(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveTo(it0) => {
+                it0.visit_content(v);
+            }
+            Self::AltChunk(it0) => {
+                it0.visit_content(v);
+            }
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for BlockContentChoice {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::CustomXml(it0) => {
+                it0.visit_element("w:customXml", v);
+            }
+            Self::Sdt(it0) => {
+                it0.visit_element("w:sdt", v);
+            }
+            Self::P(it0) => {
+                it0.visit_element("w:p", v);
+            }
+            Self::Tbl(it0) => {
+                it0.visit_element("w:tbl", v);
+            }
+            Self::ProofErr(it0) => {
+                it0.visit_element("w:proofErr", v);
+            }
+            Self::PermStart(it0) => {
+                it0.visit_element("w:permStart", v);
+            }
+            Self::PermEnd(it0) => {
+                it0.visit_element("w:permEnd", v);
+            }
+            Self::BookmarkStart(it0) => {
+                it0.visit_element("w:bookmarkStart", v);
+            }
+            Self::BookmarkEnd(it0) => {
+                it0.visit_element("w:bookmarkEnd", v);
+            }
+            Self::MoveFromRangeStart(it0) => {
+                it0.visit_element("w:moveFromRangeStart", v);
+            }
+            Self::MoveFromRangeEnd(it0) => {
+                it0.visit_element("w:moveFromRangeEnd", v);
+            }
+            Self::MoveToRangeStart(it0) => {
+                it0.visit_element("w:moveToRangeStart", v);
+            }
+            Self::MoveToRangeEnd(it0) => {
+                it0.visit_element("w:moveToRangeEnd", v);
+            }
+            Self::CommentRangeStart(it0) => {
+                it0.visit_element("w:commentRangeStart", v);
+            }
+            Self::CommentRangeEnd(it0) => {
+                it0.visit_element("w:commentRangeEnd", v);
+            }
+            Self::CustomXmlInsRangeStart(it0) => {
+                it0.visit_element("w:customXmlInsRangeStart", v);
+            }
+            Self::CustomXmlInsRangeEnd(it0) => {
+                it0.visit_element("w:customXmlInsRangeEnd", v);
+            }
+            Self::CustomXmlDelRangeStart(it0) => {
+                it0.visit_element("w:customXmlDelRangeStart", v);
+            }
+            Self::CustomXmlDelRangeEnd(it0) => {
+                it0.visit_element("w:customXmlDelRangeEnd", v);
+            }
+            Self::CustomXmlMoveFromRangeStart(it0) => {
+                it0.visit_element("w:customXmlMoveFromRangeStart", v);
+            }
+            Self::CustomXmlMoveFromRangeEnd(it0) => {
+                it0.visit_element("w:customXmlMoveFromRangeEnd", v);
+            }
+            Self::CustomXmlMoveToRangeStart(it0) => {
+                it0.visit_element("w:customXmlMoveToRangeStart", v);
+            }
+            Self::CustomXmlMoveToRangeEnd(it0) => {
+                it0.visit_element("w:customXmlMoveToRangeEnd", v);
+            }
+            Self::Ins(it0) => {
+                it0.visit_element("w:ins", v);
+            }
+            Self::Del(it0) => {
+                it0.visit_element("w:del", v);
+            }
+            Self::MoveFrom(it0) => {
+                it0.visit_element("w:moveFrom", v);
+            }
+            Self::MoveTo(it0) => {
+                it0.visit_element("w:moveTo", v);
+            }
+        }
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::CustomXml(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Sdt(it0) => {
+                it0.visit_content(v);
+            }
+            Self::P(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Tbl(it0) => {
+                it0.visit_content(v);
+            }
+            Self::ProofErr(it0) => {
+                it0.visit_content(v);
+            }
+            Self::PermStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::PermEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::BookmarkStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::BookmarkEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFromRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFromRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveToRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveToRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CommentRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CommentRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlInsRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlInsRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlDelRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlDelRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveFromRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveFromRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveToRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveToRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Ins(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Del(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFrom(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveTo(it0) => {
+                it0.visit_content(v);
+            }
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Body {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.block_content {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.sect_pr {
+                it0.visit_element("w:sectPr", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Bookmark {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTAltChunk {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.alt_chunk_pr {
+                it0.visit_element("w:altChunkPr", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTAltChunkPr {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.match_src {
+                it0.visit_element("w:matchSrc", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTAttr {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTAutoCaption {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTAutoCaptions {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            for it0 in &self.auto_caption {
+                it0.visit_element("w:autoCaption", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTBackground {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-drawings")]
+        {
+            if let Some(it0) = &self.drawing {
+                it0.visit_element("w:drawing", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTBase64Binary {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTBdoContentRun {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.paragraph_content {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTBookmarkRange {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTBorder {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTBottomPageBorder {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTBr {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCalendarType {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCaption {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCaptions {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            for it0 in &self.caption {
+                it0.visit_element("w:caption", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.auto_captions {
+                it0.visit_element("w:autoCaptions", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCellMergeTrackChange {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCharacterSpacing {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCharset {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCnf {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTColor {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTColorSchemeMapping {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTColumn {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCompatSetting {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTControl {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCustomXmlBlock {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.custom_xml_pr {
+                it0.visit_element("w:customXmlPr", v);
+            }
+        }
+        for it0 in &self.block_content {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCustomXmlCell {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.custom_xml_pr {
+                it0.visit_element("w:customXmlPr", v);
+            }
+        }
+        for it0 in &self.cells {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCustomXmlPr {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.placeholder {
+                it0.visit_element("w:placeholder", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            for it0 in &self.attr {
+                it0.visit_element("w:attr", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCustomXmlRow {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.custom_xml_pr {
+                it0.visit_element("w:customXmlPr", v);
+            }
+        }
+        for it0 in &self.rows {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTCustomXmlRun {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.custom_xml_pr {
+                it0.visit_element("w:customXmlPr", v);
+            }
+        }
+        for it0 in &self.paragraph_content {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDataBinding {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDecimalNumber {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDecimalNumberOrPrecent {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDirContentRun {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.paragraph_content {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDiv {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.block_quote {
+                it0.visit_element("w:blockQuote", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.body_div {
+                it0.visit_element("w:bodyDiv", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            self.mar_left.visit_element("w:marLeft", v);
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            self.mar_right.visit_element("w:marRight", v);
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            self.mar_top.visit_element("w:marTop", v);
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            self.mar_bottom.visit_element("w:marBottom", v);
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.div_bdr {
+                it0.visit_element("w:divBdr", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            for it0 in &self.divs_child {
+                it0.visit_element("w:divsChild", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDivBdr {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.top {
+                it0.visit_element("w:top", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.left {
+                it0.visit_element("w:left", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.bottom {
+                it0.visit_element("w:bottom", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.right {
+                it0.visit_element("w:right", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDivs {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            for it0 in &self.div {
+                it0.visit_element("w:div", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDocPart {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.doc_part_pr {
+                it0.visit_element("w:docPartPr", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.doc_part_body {
+                it0.visit_element("w:docPartBody", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDocPartBehavior {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDocPartBehaviors {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            for it0 in &self.behavior {
+                it0.visit_element("w:behavior", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDocPartCategory {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.name.visit_element("w:name", v);
+        #[cfg(feature = "wml-settings")]
+        {
+            self.gallery.visit_element("w:gallery", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDocPartGallery {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDocPartName {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDocPartPr {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            self.name.visit_element("w:name", v);
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.style {
+                it0.visit_element("w:style", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.category {
+                it0.visit_element("w:category", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.types {
+                it0.visit_element("w:types", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.behaviors {
+                it0.visit_element("w:behaviors", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.description {
+                it0.visit_element("w:description", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.guid {
+                it0.visit_element("w:guid", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDocPartType {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDocPartTypes {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            for it0 in &self.r#type {
+                it0.visit_element("w:type", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDocParts {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            for it0 in &self.doc_part {
+                it0.visit_element("w:docPart", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDocProtect {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDocRsids {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-track-changes")]
+        {
+            if let Some(it0) = &self.rsid_root {
+                it0.visit_element("w:rsidRoot", v);
+            }
+        }
+        #[cfg(feature = "wml-track-changes")]
+        {
+            for it0 in &self.rsid {
+                it0.visit_element("w:rsid", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDocVar {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDocVars {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            for it0 in &self.doc_var {
+                it0.visit_element("w:docVar", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDocumentBase {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.background {
+                it0.visit_element("w:background", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTDrawing {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTEastAsianLayout {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTEdnDocProps {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.pos {
+            it0.visit_element("w:pos", v);
+        }
+        if let Some(it0) = &self.num_fmt {
+            it0.visit_element("w:numFmt", v);
+        }
+        if let Some(it0) = &self.num_start {
+            it0.visit_element("w:numStart", v);
+        }
+        if let Some(it0) = &self.num_restart {
+            it0.visit_element("w:numRestart", v);
+        }
+        #[cfg(feature = "wml-comments")]
+        {
+            for it0 in &self.endnote {
+                it0.visit_element("w:endnote", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTEdnPos {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTEdnProps {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-comments")]
+        {
+            if let Some(it0) = &self.pos {
+                it0.visit_element("w:pos", v);
+            }
+        }
+        #[cfg(feature = "wml-comments")]
+        {
+            if let Some(it0) = &self.num_fmt {
+                it0.visit_element("w:numFmt", v);
+            }
+        }
+        if let Some(it0) = &self.num_start {
+            it0.visit_element("w:numStart", v);
+        }
+        if let Some(it0) = &self.num_restart {
+            it0.visit_element("w:numRestart", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTEm {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTEmpty {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFFCheckBox {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-fields")]
+        {
+            if let Some(it0) = &self.size {
+                it0.visit_element("w:size", v);
+            }
+        }
+        #[cfg(feature = "wml-fields")]
+        {
+            if let Some(it0) = &self.size_auto {
+                it0.visit_element("w:sizeAuto", v);
+            }
+        }
+        #[cfg(feature = "wml-fields")]
+        {
+            if let Some(it0) = &self.default {
+                it0.visit_element("w:default", v);
+            }
+        }
+        #[cfg(feature = "wml-fields")]
+        {
+            if let Some(it0) = &self.checked {
+                it0.visit_element("w:checked", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFFDDList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-fields")]
+        {
+            if let Some(it0) = &self.result {
+                it0.visit_element("w:result", v);
+            }
+        }
+        #[cfg(feature = "wml-fields")]
+        {
+            if let Some(it0) = &self.default {
+                it0.visit_element("w:default", v);
+            }
+        }
+        #[cfg(feature = "wml-fields")]
+        {
+            for it0 in &self.list_entry {
+                it0.visit_element("w:listEntry", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFFData {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-fields")]
+        {
+            for it0 in &self.name {
+                it0.visit_element("w:name", v);
+            }
+        }
+        #[cfg(feature = "wml-fields")]
+        {
+            if let Some(it0) = &self.label {
+                it0.visit_element("w:label", v);
+            }
+        }
+        #[cfg(feature = "wml-fields")]
+        {
+            if let Some(it0) = &self.tab_index {
+                it0.visit_element("w:tabIndex", v);
+            }
+        }
+        #[cfg(feature = "wml-fields")]
+        {
+            for it0 in &self.enabled {
+                it0.visit_element("w:enabled", v);
+            }
+        }
+        #[cfg(feature = "wml-fields")]
+        {
+            for it0 in &self.calc_on_exit {
+                it0.visit_element("w:calcOnExit", v);
+            }
+        }
+        #[cfg(feature = "wml-fields")]
+        {
+            if let Some(it0) = &self.entry_macro {
+                it0.visit_element("w:entryMacro", v);
+            }
+        }
+        #[cfg(feature = "wml-fields")]
+        {
+            if let Some(it0) = &self.exit_macro {
+                it0.visit_element("w:exitMacro", v);
+            }
+        }
+        #[cfg(feature = "wml-fields")]
+        {
+            if let Some(it0) = &self.help_text {
+                it0.visit_element("w:helpText", v);
+            }
+        }
+        #[cfg(feature = "wml-fields")]
+        {
+            if let Some(it0) = &self.status_text {
+                it0.visit_element("w:statusText", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFFHelpText {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFFStatusText {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFFTextInput {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-fields")]
+        {
+            if let Some(it0) = &self.r#type {
+                it0.visit_element("w:type", v);
+            }
+        }
+        #[cfg(feature = "wml-fields")]
+        {
+            if let Some(it0) = &self.default {
+                it0.visit_element("w:default", v);
+            }
+        }
+        #[cfg(feature = "wml-fields")]
+        {
+            if let Some(it0) = &self.max_length {
+                it0.visit_element("w:maxLength", v);
+            }
+        }
+        #[cfg(feature = "wml-fields")]
+        {
+            if let Some(it0) = &self.format {
+                it0.visit_element("w:format", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFFTextType {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFitText {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFldChar {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-fields")]
+        {
+            if let Some(it0) = &self.fld_data {
+                it0.visit_element("w:fldData", v);
+            }
+        }
+        #[cfg(feature = "wml-fields")]
+        {
+            if let Some(it0) = &self.ff_data {
+                it0.visit_element("w:ffData", v);
+            }
+        }
+        #[cfg(feature = "wml-track-changes")]
+        {
+            if let Some(it0) = &self.numbering_change {
+                it0.visit_element("w:numberingChange", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFontFamily {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFontRel {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFontSig {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFontsList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.font {
+            it0.visit_element("w:font", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFrame {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.size {
+                it0.visit_element("w:sz", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.name {
+                it0.visit_element("w:name", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.title {
+                it0.visit_element("w:title", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.long_desc {
+                it0.visit_element("w:longDesc", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.source_file_name {
+                it0.visit_element("w:sourceFileName", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.mar_w {
+                it0.visit_element("w:marW", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.mar_h {
+                it0.visit_element("w:marH", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.scrollbar {
+                it0.visit_element("w:scrollbar", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.no_resize_allowed {
+                it0.visit_element("w:noResizeAllowed", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.linked_to_file {
+                it0.visit_element("w:linkedToFile", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFrameLayout {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFramePr {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFrameScrollbar {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFrameset {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.size {
+                it0.visit_element("w:sz", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.frameset_splitbar {
+                it0.visit_element("w:framesetSplitbar", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.frame_layout {
+                it0.visit_element("w:frameLayout", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.title {
+                it0.visit_element("w:title", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFramesetSplitbar {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.width {
+                it0.visit_element("w:w", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.color {
+                it0.visit_element("w:color", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.no_border {
+                it0.visit_element("w:noBorder", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.flat_borders {
+                it0.visit_element("w:flatBorders", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFtnDocProps {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.pos {
+            it0.visit_element("w:pos", v);
+        }
+        if let Some(it0) = &self.num_fmt {
+            it0.visit_element("w:numFmt", v);
+        }
+        if let Some(it0) = &self.num_start {
+            it0.visit_element("w:numStart", v);
+        }
+        if let Some(it0) = &self.num_restart {
+            it0.visit_element("w:numRestart", v);
+        }
+        #[cfg(feature = "wml-comments")]
+        {
+            for it0 in &self.footnote {
+                it0.visit_element("w:footnote", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFtnEdnSepRef {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFtnPos {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTFtnProps {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-comments")]
+        {
+            if let Some(it0) = &self.pos {
+                it0.visit_element("w:pos", v);
+            }
+        }
+        #[cfg(feature = "wml-comments")]
+        {
+            if let Some(it0) = &self.num_fmt {
+                it0.visit_element("w:numFmt", v);
+            }
+        }
+        if let Some(it0) = &self.num_start {
+            it0.visit_element("w:numStart", v);
+        }
+        if let Some(it0) = &self.num_restart {
+            it0.visit_element("w:numRestart", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTGlossaryDocument {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.background {
+            it0.visit_element("w:background", v);
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.doc_parts {
+                it0.visit_element("w:docParts", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTHMerge {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTHeaders {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.header {
+            it0.visit_element("w:header", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTHeight {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTHighlight {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTInd {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTJc {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTJcTable {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTKinsoku {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTLang {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTLevelSuffix {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTLevelText {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTLineNumber {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTLock {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTLvlLegacy {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMailMerge {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            self.main_document_type
+                .visit_element("w:mainDocumentType", v);
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.link_to_query {
+                it0.visit_element("w:linkToQuery", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            self.data_type.visit_element("w:dataType", v);
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.connect_string {
+                it0.visit_element("w:connectString", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.query {
+                it0.visit_element("w:query", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.data_source {
+                it0.visit_element("w:dataSource", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.header_source {
+                it0.visit_element("w:headerSource", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_suppress_blank_lines {
+                it0.visit_element("w:doNotSuppressBlankLines", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.destination {
+                it0.visit_element("w:destination", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.address_field_name {
+                it0.visit_element("w:addressFieldName", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.mail_subject {
+                it0.visit_element("w:mailSubject", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.mail_as_attachment {
+                it0.visit_element("w:mailAsAttachment", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.view_merged_data {
+                it0.visit_element("w:viewMergedData", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.active_record {
+                it0.visit_element("w:activeRecord", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.check_errors {
+                it0.visit_element("w:checkErrors", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.odso {
+                it0.visit_element("w:odso", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMailMergeDest {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMailMergeDocType {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMailMergeOdsoFMDFieldType {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMailMergeSourceType {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMarkup {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMarkupRange {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMathCtrlDel {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.r_pr {
+                it0.visit_element("w:rPr", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMathCtrlIns {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-track-changes")]
+        {
+            if let Some(it0) = &self.del {
+                it0.visit_element("w:del", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.r_pr {
+                it0.visit_element("w:rPr", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMoveBookmark {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTMultiLevelType {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTNumFmt {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTNumLvl {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.start_override {
+                it0.visit_element("w:startOverride", v);
+            }
+        }
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.lvl {
+                it0.visit_element("w:lvl", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTNumPicBullet {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.pict {
+                it0.visit_element("w:pict", v);
+            }
+        }
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.drawing {
+                it0.visit_element("w:drawing", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTNumRestart {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTObject {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-drawings")]
+        {
+            if let Some(it0) = &self.drawing {
+                it0.visit_element("w:drawing", v);
+            }
+        }
+        #[cfg(feature = "wml-drawings")]
+        {
+            if let Some(it0) = &self.control {
+                it0.visit_element("w:control", v);
+            }
+        }
+        #[cfg(feature = "wml-drawings")]
+        {
+            if let Some(it0) = &self.object_link {
+                it0.visit_element("w:objectLink", v);
+            }
+        }
+        #[cfg(feature = "wml-drawings")]
+        {
+            if let Some(it0) = &self.object_embed {
+                it0.visit_element("w:objectEmbed", v);
+            }
+        }
+        #[cfg(feature = "wml-drawings")]
+        {
+            if let Some(it0) = &self.movie {
+                it0.visit_element("w:movie", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTObjectEmbed {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTObjectLink {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTOdso {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.udl {
+                it0.visit_element("w:udl", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.table {
+                it0.visit_element("w:table", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.src {
+                it0.visit_element("w:src", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.col_delim {
+                it0.visit_element("w:colDelim", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.r#type {
+                it0.visit_element("w:type", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.f_hdr {
+                it0.visit_element("w:fHdr", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            for it0 in &self.field_map_data {
+                it0.visit_element("w:fieldMapData", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            for it0 in &self.recipient_data {
+                it0.visit_element("w:recipientData", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTOdsoFieldMapData {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.r#type {
+                it0.visit_element("w:type", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.name {
+                it0.visit_element("w:name", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.mapped_name {
+                it0.visit_element("w:mappedName", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.column {
+                it0.visit_element("w:column", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.lid {
+                it0.visit_element("w:lid", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.dynamic_address {
+                it0.visit_element("w:dynamicAddress", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTOptimizeForBrowser {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPBdr {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.top {
+                it0.visit_element("w:top", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.left {
+                it0.visit_element("w:left", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.bottom {
+                it0.visit_element("w:bottom", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.right {
+                it0.visit_element("w:right", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.between {
+                it0.visit_element("w:between", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.bar {
+                it0.visit_element("w:bar", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPPrBase {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.paragraph_style {
+            it0.visit_element("w:pStyle", v);
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.keep_next {
+                it0.visit_element("w:keepNext", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.keep_lines {
+                it0.visit_element("w:keepLines", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.page_break_before {
+                it0.visit_element("w:pageBreakBefore", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.frame_pr {
+                it0.visit_element("w:framePr", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.widow_control {
+                it0.visit_element("w:widowControl", v);
+            }
+        }
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.num_pr {
+                it0.visit_element("w:numPr", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.suppress_line_numbers {
+                it0.visit_element("w:suppressLineNumbers", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.paragraph_border {
+                it0.visit_element("w:pBdr", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.shading {
+                it0.visit_element("w:shd", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.tabs {
+                it0.visit_element("w:tabs", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.suppress_auto_hyphens {
+                it0.visit_element("w:suppressAutoHyphens", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.kinsoku {
+                it0.visit_element("w:kinsoku", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.word_wrap {
+                it0.visit_element("w:wordWrap", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.overflow_punct {
+                it0.visit_element("w:overflowPunct", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.top_line_punct {
+                it0.visit_element("w:topLinePunct", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.auto_space_d_e {
+                it0.visit_element("w:autoSpaceDE", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.auto_space_d_n {
+                it0.visit_element("w:autoSpaceDN", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.bidi {
+                it0.visit_element("w:bidi", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.adjust_right_ind {
+                it0.visit_element("w:adjustRightInd", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.snap_to_grid {
+                it0.visit_element("w:snapToGrid", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.spacing {
+                it0.visit_element("w:spacing", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.indentation {
+                it0.visit_element("w:ind", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.contextual_spacing {
+                it0.visit_element("w:contextualSpacing", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.mirror_indents {
+                it0.visit_element("w:mirrorIndents", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.suppress_overlap {
+                it0.visit_element("w:suppressOverlap", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.justification {
+                it0.visit_element("w:jc", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.text_direction {
+                it0.visit_element("w:textDirection", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.text_alignment {
+                it0.visit_element("w:textAlignment", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.textbox_tight_wrap {
+                it0.visit_element("w:textboxTightWrap", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.outline_lvl {
+                it0.visit_element("w:outlineLvl", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.div_id {
+                it0.visit_element("w:divId", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.cnf_style {
+                it0.visit_element("w:cnfStyle", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPPrChange {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-track-changes")]
+        {
+            self.p_pr.visit_element("w:pPr", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPPrGeneral {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.paragraph_style {
+            it0.visit_element("w:pStyle", v);
+        }
+        if let Some(it0) = &self.keep_next {
+            it0.visit_element("w:keepNext", v);
+        }
+        if let Some(it0) = &self.keep_lines {
+            it0.visit_element("w:keepLines", v);
+        }
+        if let Some(it0) = &self.page_break_before {
+            it0.visit_element("w:pageBreakBefore", v);
+        }
+        if let Some(it0) = &self.frame_pr {
+            it0.visit_element("w:framePr", v);
+        }
+        if let Some(it0) = &self.widow_control {
+            it0.visit_element("w:widowControl", v);
+        }
+        if let Some(it0) = &self.num_pr {
+            it0.visit_element("w:numPr", v);
+        }
+        if let Some(it0) = &self.suppress_line_numbers {
+            it0.visit_element("w:suppressLineNumbers", v);
+        }
+        if let Some(it0) = &self.paragraph_border {
+            it0.visit_element("w:pBdr", v);
+        }
+        if let Some(it0) = &self.shading {
+            it0.visit_element("w:shd", v);
+        }
+        if let Some(it0) = &self.tabs {
+            it0.visit_element("w:tabs", v);
+        }
+        if let Some(it0) = &self.suppress_auto_hyphens {
+            it0.visit_element("w:suppressAutoHyphens", v);
+        }
+        if let Some(it0) = &self.kinsoku {
+            it0.visit_element("w:kinsoku", v);
+        }
+        if let Some(it0) = &self.word_wrap {
+            it0.visit_element("w:wordWrap", v);
+        }
+        if let Some(it0) = &self.overflow_punct {
+            it0.visit_element("w:overflowPunct", v);
+        }
+        if let Some(it0) = &self.top_line_punct {
+            it0.visit_element("w:topLinePunct", v);
+        }
+        if let Some(it0) = &self.auto_space_d_e {
+            it0.visit_element("w:autoSpaceDE", v);
+        }
+        if let Some(it0) = &self.auto_space_d_n {
+            it0.visit_element("w:autoSpaceDN", v);
+        }
+        if let Some(it0) = &self.bidi {
+            it0.visit_element("w:bidi", v);
+        }
+        if let Some(it0) = &self.adjust_right_ind {
+            it0.visit_element("w:adjustRightInd", v);
+        }
+        if let Some(it0) = &self.snap_to_grid {
+            it0.visit_element("w:snapToGrid", v);
+        }
+        if let Some(it0) = &self.spacing {
+            it0.visit_element("w:spacing", v);
+        }
+        if let Some(it0) = &self.indentation {
+            it0.visit_element("w:ind", v);
+        }
+        if let Some(it0) = &self.contextual_spacing {
+            it0.visit_element("w:contextualSpacing", v);
+        }
+        if let Some(it0) = &self.mirror_indents {
+            it0.visit_element("w:mirrorIndents", v);
+        }
+        if let Some(it0) = &self.suppress_overlap {
+            it0.visit_element("w:suppressOverlap", v);
+        }
+        if let Some(it0) = &self.justification {
+            it0.visit_element("w:jc", v);
+        }
+        if let Some(it0) = &self.text_direction {
+            it0.visit_element("w:textDirection", v);
+        }
+        if let Some(it0) = &self.text_alignment {
+            it0.visit_element("w:textAlignment", v);
+        }
+        if let Some(it0) = &self.textbox_tight_wrap {
+            it0.visit_element("w:textboxTightWrap", v);
+        }
+        if let Some(it0) = &self.outline_lvl {
+            it0.visit_element("w:outlineLvl", v);
+        }
+        if let Some(it0) = &self.div_id {
+            it0.visit_element("w:divId", v);
+        }
+        if let Some(it0) = &self.cnf_style {
+            it0.visit_element("w:cnfStyle", v);
+        }
+        #[cfg(feature = "wml-track-changes")]
+        {
+            if let Some(it0) = &self.p_pr_change {
+                it0.visit_element("w:pPrChange", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPTab {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPageBorder {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPageBorders {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.top {
+                it0.visit_element("w:top", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.left {
+                it0.visit_element("w:left", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.bottom {
+                it0.visit_element("w:bottom", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.right {
+                it0.visit_element("w:right", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPageNumber {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPaperSource {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTParaRPr {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.ins {
+            it0.visit_element("w:ins", v);
+        }
+        if let Some(it0) = &self.del {
+            it0.visit_element("w:del", v);
+        }
+        if let Some(it0) = &self.move_from {
+            it0.visit_element("w:moveFrom", v);
+        }
+        if let Some(it0) = &self.move_to {
+            it0.visit_element("w:moveTo", v);
+        }
+        if let Some(it0) = &self.run_style {
+            it0.visit_element("w:rStyle", v);
+        }
+        if let Some(it0) = &self.fonts {
+            it0.visit_element("w:rFonts", v);
+        }
+        if let Some(it0) = &self.bold {
+            it0.visit_element("w:b", v);
+        }
+        if let Some(it0) = &self.b_cs {
+            it0.visit_element("w:bCs", v);
+        }
+        if let Some(it0) = &self.italic {
+            it0.visit_element("w:i", v);
+        }
+        if let Some(it0) = &self.i_cs {
+            it0.visit_element("w:iCs", v);
+        }
+        if let Some(it0) = &self.caps {
+            it0.visit_element("w:caps", v);
+        }
+        if let Some(it0) = &self.small_caps {
+            it0.visit_element("w:smallCaps", v);
+        }
+        if let Some(it0) = &self.strikethrough {
+            it0.visit_element("w:strike", v);
+        }
+        if let Some(it0) = &self.dstrike {
+            it0.visit_element("w:dstrike", v);
+        }
+        if let Some(it0) = &self.outline {
+            it0.visit_element("w:outline", v);
+        }
+        if let Some(it0) = &self.shadow {
+            it0.visit_element("w:shadow", v);
+        }
+        if let Some(it0) = &self.emboss {
+            it0.visit_element("w:emboss", v);
+        }
+        if let Some(it0) = &self.imprint {
+            it0.visit_element("w:imprint", v);
+        }
+        if let Some(it0) = &self.no_proof {
+            it0.visit_element("w:noProof", v);
+        }
+        if let Some(it0) = &self.snap_to_grid {
+            it0.visit_element("w:snapToGrid", v);
+        }
+        if let Some(it0) = &self.vanish {
+            it0.visit_element("w:vanish", v);
+        }
+        if let Some(it0) = &self.web_hidden {
+            it0.visit_element("w:webHidden", v);
+        }
+        if let Some(it0) = &self.color {
+            it0.visit_element("w:color", v);
+        }
+        if let Some(it0) = &self.spacing {
+            it0.visit_element("w:spacing", v);
+        }
+        if let Some(it0) = &self.width {
+            it0.visit_element("w:w", v);
+        }
+        if let Some(it0) = &self.kern {
+            it0.visit_element("w:kern", v);
+        }
+        if let Some(it0) = &self.position {
+            it0.visit_element("w:position", v);
+        }
+        if let Some(it0) = &self.size {
+            it0.visit_element("w:sz", v);
+        }
+        if let Some(it0) = &self.size_complex_script {
+            it0.visit_element("w:szCs", v);
+        }
+        if let Some(it0) = &self.highlight {
+            it0.visit_element("w:highlight", v);
+        }
+        if let Some(it0) = &self.underline {
+            it0.visit_element("w:u", v);
+        }
+        if let Some(it0) = &self.effect {
+            it0.visit_element("w:effect", v);
+        }
+        if let Some(it0) = &self.bdr {
+            it0.visit_element("w:bdr", v);
+        }
+        if let Some(it0) = &self.shading {
+            it0.visit_element("w:shd", v);
+        }
+        if let Some(it0) = &self.fit_text {
+            it0.visit_element("w:fitText", v);
+        }
+        if let Some(it0) = &self.vert_align {
+            it0.visit_element("w:vertAlign", v);
+        }
+        if let Some(it0) = &self.rtl {
+            it0.visit_element("w:rtl", v);
+        }
+        if let Some(it0) = &self.cs {
+            it0.visit_element("w:cs", v);
+        }
+        if let Some(it0) = &self.em {
+            it0.visit_element("w:em", v);
+        }
+        if let Some(it0) = &self.lang {
+            it0.visit_element("w:lang", v);
+        }
+        if let Some(it0) = &self.east_asian_layout {
+            it0.visit_element("w:eastAsianLayout", v);
+        }
+        if let Some(it0) = &self.spec_vanish {
+            it0.visit_element("w:specVanish", v);
+        }
+        if let Some(it0) = &self.o_math {
+            it0.visit_element("w:oMath", v);
+        }
+        #[cfg(feature = "wml-track-changes")]
+        {
+            if let Some(it0) = &self.r_pr_change {
+                it0.visit_element("w:rPrChange", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTParaRPrChange {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-track-changes")]
+        {
+            self.r_pr.visit_element("w:rPr", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTParaRPrOriginal {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.ins {
+            it0.visit_element("w:ins", v);
+        }
+        if let Some(it0) = &self.del {
+            it0.visit_element("w:del", v);
+        }
+        if let Some(it0) = &self.move_from {
+            it0.visit_element("w:moveFrom", v);
+        }
+        if let Some(it0) = &self.move_to {
+            it0.visit_element("w:moveTo", v);
+        }
+        if let Some(it0) = &self.run_style {
+            it0.visit_element("w:rStyle", v);
+        }
+        if let Some(it0) = &self.fonts {
+            it0.visit_element("w:rFonts", v);
+        }
+        if let Some(it0) = &self.bold {
+            it0.visit_element("w:b", v);
+        }
+        if let Some(it0) = &self.b_cs {
+            it0.visit_element("w:bCs", v);
+        }
+        if let Some(it0) = &self.italic {
+            it0.visit_element("w:i", v);
+        }
+        if let Some(it0) = &self.i_cs {
+            it0.visit_element("w:iCs", v);
+        }
+        if let Some(it0) = &self.caps {
+            it0.visit_element("w:caps", v);
+        }
+        if let Some(it0) = &self.small_caps {
+            it0.visit_element("w:smallCaps", v);
+        }
+        if let Some(it0) = &self.strikethrough {
+            it0.visit_element("w:strike", v);
+        }
+        if let Some(it0) = &self.dstrike {
+            it0.visit_element("w:dstrike", v);
+        }
+        if let Some(it0) = &self.outline {
+            it0.visit_element("w:outline", v);
+        }
+        if let Some(it0) = &self.shadow {
+            it0.visit_element("w:shadow", v);
+        }
+        if let Some(it0) = &self.emboss {
+            it0.visit_element("w:emboss", v);
+        }
+        if let Some(it0) = &self.imprint {
+            it0.visit_element("w:imprint", v);
+        }
+        if let Some(it0) = &self.no_proof {
+            it0.visit_element("w:noProof", v);
+        }
+        if let Some(it0) = &self.snap_to_grid {
+            it0.visit_element("w:snapToGrid", v);
+        }
+        if let Some(it0) = &self.vanish {
+            it0.visit_element("w:vanish", v);
+        }
+        if let Some(it0) = &self.web_hidden {
+            it0.visit_element("w:webHidden", v);
+        }
+        if let Some(it0) = &self.color {
+            it0.visit_element("w:color", v);
+        }
+        if let Some(it0) = &self.spacing {
+            it0.visit_element("w:spacing", v);
+        }
+        if let Some(it0) = &self.width {
+            it0.visit_element("w:w", v);
+        }
+        if let Some(it0) = &self.kern {
+            it0.visit_element("w:kern", v);
+        }
+        if let Some(it0) = &self.position {
+            it0.visit_element("w:position", v);
+        }
+        if let Some(it0) = &self.size {
+            it0.visit_element("w:sz", v);
+        }
+        if let Some(it0) = &self.size_complex_script {
+            it0.visit_element("w:szCs", v);
+        }
+        if let Some(it0) = &self.highlight {
+            it0.visit_element("w:highlight", v);
+        }
+        if let Some(it0) = &self.underline {
+            it0.visit_element("w:u", v);
+        }
+        if let Some(it0) = &self.effect {
+            it0.visit_element("w:effect", v);
+        }
+        if let Some(it0) = &self.bdr {
+            it0.visit_element("w:bdr", v);
+        }
+        if let Some(it0) = &self.shading {
+            it0.visit_element("w:shd", v);
+        }
+        if let Some(it0) = &self.fit_text {
+            it0.visit_element("w:fitText", v);
+        }
+        if let Some(it0) = &self.vert_align {
+            it0.visit_element("w:vertAlign", v);
+        }
+        if let Some(it0) = &self.rtl {
+            it0.visit_element("w:rtl", v);
+        }
+        if let Some(it0) = &self.cs {
+            it0.visit_element("w:cs", v);
+        }
+        if let Some(it0) = &self.em {
+            it0.visit_element("w:em", v);
+        }
+        if let Some(it0) = &self.lang {
+            it0.visit_element("w:lang", v);
+        }
+        if let Some(it0) = &self.east_asian_layout {
+            it0.visit_element("w:eastAsianLayout", v);
+        }
+        if let Some(it0) = &self.spec_vanish {
+            it0.visit_element("w:specVanish", v);
+        }
+        if let Some(it0) = &self.o_math {
+            it0.visit_element("w:oMath", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPerm {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPermStart {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPicture {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-drawings")]
+        {
+            if let Some(it0) = &self.movie {
+                it0.visit_element("w:movie", v);
+            }
+        }
+        #[cfg(feature = "wml-drawings")]
+        {
+            if let Some(it0) = &self.control {
+                it0.visit_element("w:control", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTPitch {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTProof {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTProofErr {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTRPrChange {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-track-changes")]
+        {
+            self.r_pr.visit_element("w:rPr", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTRPrOriginal {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.run_style {
+            it0.visit_element("w:rStyle", v);
+        }
+        if let Some(it0) = &self.fonts {
+            it0.visit_element("w:rFonts", v);
+        }
+        if let Some(it0) = &self.bold {
+            it0.visit_element("w:b", v);
+        }
+        if let Some(it0) = &self.b_cs {
+            it0.visit_element("w:bCs", v);
+        }
+        if let Some(it0) = &self.italic {
+            it0.visit_element("w:i", v);
+        }
+        if let Some(it0) = &self.i_cs {
+            it0.visit_element("w:iCs", v);
+        }
+        if let Some(it0) = &self.caps {
+            it0.visit_element("w:caps", v);
+        }
+        if let Some(it0) = &self.small_caps {
+            it0.visit_element("w:smallCaps", v);
+        }
+        if let Some(it0) = &self.strikethrough {
+            it0.visit_element("w:strike", v);
+        }
+        if let Some(it0) = &self.dstrike {
+            it0.visit_element("w:dstrike", v);
+        }
+        if let Some(it0) = &self.outline {
+            it0.visit_element("w:outline", v);
+        }
+        if let Some(it0) = &self.shadow {
+            it0.visit_element("w:shadow", v);
+        }
+        if let Some(it0) = &self.emboss {
+            it0.visit_element("w:emboss", v);
+        }
+        if let Some(it0) = &self.imprint {
+            it0.visit_element("w:imprint", v);
+        }
+        if let Some(it0) = &self.no_proof {
+            it0.visit_element("w:noProof", v);
+        }
+        if let Some(it0) = &self.snap_to_grid {
+            it0.visit_element("w:snapToGrid", v);
+        }
+        if let Some(it0) = &self.vanish {
+            it0.visit_element("w:vanish", v);
+        }
+        if let Some(it0) = &self.web_hidden {
+            it0.visit_element("w:webHidden", v);
+        }
+        if let Some(it0) = &self.color {
+            it0.visit_element("w:color", v);
+        }
+        if let Some(it0) = &self.spacing {
+            it0.visit_element("w:spacing", v);
+        }
+        if let Some(it0) = &self.width {
+            it0.visit_element("w:w", v);
+        }
+        if let Some(it0) = &self.kern {
+            it0.visit_element("w:kern", v);
+        }
+        if let Some(it0) = &self.position {
+            it0.visit_element("w:position", v);
+        }
+        if let Some(it0) = &self.size {
+            it0.visit_element("w:sz", v);
+        }
+        if let Some(it0) = &self.size_complex_script {
+            it0.visit_element("w:szCs", v);
+        }
+        if let Some(it0) = &self.highlight {
+            it0.visit_element("w:highlight", v);
+        }
+        if let Some(it0) = &self.underline {
+            it0.visit_element("w:u", v);
+        }
+        if let Some(it0) = &self.effect {
+            it0.visit_element("w:effect", v);
+        }
+        if let Some(it0) = &self.bdr {
+            it0.visit_element("w:bdr", v);
+        }
+        if let Some(it0) = &self.shading {
+            it0.visit_element("w:shd", v);
+        }
+        if let Some(it0) = &self.fit_text {
+            it0.visit_element("w:fitText", v);
+        }
+        if let Some(it0) = &self.vert_align {
+            it0.visit_element("w:vertAlign", v);
+        }
+        if let Some(it0) = &self.rtl {
+            it0.visit_element("w:rtl", v);
+        }
+        if let Some(it0) = &self.cs {
+            it0.visit_element("w:cs", v);
+        }
+        if let Some(it0) = &self.em {
+            it0.visit_element("w:em", v);
+        }
+        if let Some(it0) = &self.lang {
+            it0.visit_element("w:lang", v);
+        }
+        if let Some(it0) = &self.east_asian_layout {
+            it0.visit_element("w:eastAsianLayout", v);
+        }
+        if let Some(it0) = &self.spec_vanish {
+            it0.visit_element("w:specVanish", v);
+        }
+        if let Some(it0) = &self.o_math {
+            it0.visit_element("w:oMath", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTReadingModeInkLockDown {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTRecipientData {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.active {
+                it0.visit_element("w:active", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            self.column.visit_element("w:column", v);
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            self.unique_tag.visit_element("w:uniqueTag", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTRecipients {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            for it0 in &self.recipient_data {
+                it0.visit_element("w:recipientData", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTRel {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTRow {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_pr_ex {
+                it0.visit_element("w:tblPrEx", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.row_properties {
+                it0.visit_element("w:trPr", v);
+            }
+        }
+        for it0 in &self.cells {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTRuby {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-styling")]
+        {
+            self.ruby_pr.visit_element("w:rubyPr", v);
+        }
+        self.rt.visit_element("w:rt", v);
+        self.ruby_base.visit_element("w:rubyBase", v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTRubyAlign {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTRubyContent {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.ruby_content {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTRubyPr {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-styling")]
+        {
+            self.ruby_align.visit_element("w:rubyAlign", v);
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            self.hps.visit_element("w:hps", v);
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            self.hps_raise.visit_element("w:hpsRaise", v);
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            self.hps_base_text.visit_element("w:hpsBaseText", v);
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            self.lid.visit_element("w:lid", v);
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.dirty {
+                it0.visit_element("w:dirty", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTRunTrackChange {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-track-changes")]
+        {
+            for it0 in &self.run_content {
+                it0.visit_element("", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSaveThroughXslt {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSdtBlock {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.sdt_pr {
+                it0.visit_element("w:sdtPr", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.sdt_end_pr {
+                it0.visit_element("w:sdtEndPr", v);
+            }
+        }
+        if let Some(it0) = &self.sdt_content {
+            it0.visit_element("w:sdtContent", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSdtCell {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.sdt_pr {
+                it0.visit_element("w:sdtPr", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.sdt_end_pr {
+                it0.visit_element("w:sdtEndPr", v);
+            }
+        }
+        if let Some(it0) = &self.sdt_content {
+            it0.visit_element("w:sdtContent", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSdtComboBox {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            for it0 in &self.list_item {
+                it0.visit_element("w:listItem", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSdtContentBlock {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.block_content {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSdtContentCell {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.cells {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSdtContentRow {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.rows {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSdtContentRun {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.paragraph_content {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSdtDate {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.date_format {
+                it0.visit_element("w:dateFormat", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.lid {
+                it0.visit_element("w:lid", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.store_mapped_data_as {
+                it0.visit_element("w:storeMappedDataAs", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.calendar {
+                it0.visit_element("w:calendar", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSdtDateMappingType {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSdtDocPart {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.doc_part_gallery {
+                it0.visit_element("w:docPartGallery", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.doc_part_category {
+                it0.visit_element("w:docPartCategory", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.doc_part_unique {
+                it0.visit_element("w:docPartUnique", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSdtDropDownList {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            for it0 in &self.list_item {
+                it0.visit_element("w:listItem", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSdtEndPr {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.r_pr {
+                it0.visit_element("w:rPr", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSdtListItem {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSdtPr {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.r_pr {
+                it0.visit_element("w:rPr", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.alias {
+                it0.visit_element("w:alias", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.tag {
+                it0.visit_element("w:tag", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.id {
+                it0.visit_element("w:id", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.lock {
+                it0.visit_element("w:lock", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.placeholder {
+                it0.visit_element("w:placeholder", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.temporary {
+                it0.visit_element("w:temporary", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.showing_plc_hdr {
+                it0.visit_element("w:showingPlcHdr", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.data_binding {
+                it0.visit_element("w:dataBinding", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.label {
+                it0.visit_element("w:label", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.tab_index {
+                it0.visit_element("w:tabIndex", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.equation {
+                it0.visit_element("w:equation", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.combo_box {
+                it0.visit_element("w:comboBox", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.date {
+                it0.visit_element("w:date", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.doc_part_obj {
+                it0.visit_element("w:docPartObj", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.doc_part_list {
+                it0.visit_element("w:docPartList", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.drop_down_list {
+                it0.visit_element("w:dropDownList", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.picture {
+                it0.visit_element("w:picture", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.rich_text {
+                it0.visit_element("w:richText", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.text {
+                it0.visit_element("w:text", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.citation {
+                it0.visit_element("w:citation", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.group {
+                it0.visit_element("w:group", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.bibliography {
+                it0.visit_element("w:bibliography", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSdtRow {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.sdt_pr {
+                it0.visit_element("w:sdtPr", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.sdt_end_pr {
+                it0.visit_element("w:sdtEndPr", v);
+            }
+        }
+        if let Some(it0) = &self.sdt_content {
+            it0.visit_element("w:sdtContent", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSdtRun {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.sdt_pr {
+                it0.visit_element("w:sdtPr", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.sdt_end_pr {
+                it0.visit_element("w:sdtEndPr", v);
+            }
+        }
+        if let Some(it0) = &self.sdt_content {
+            it0.visit_element("w:sdtContent", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSdtText {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSectPrBase {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.footnote_pr {
+            it0.visit_element("w:footnotePr", v);
+        }
+        if let Some(it0) = &self.endnote_pr {
+            it0.visit_element("w:endnotePr", v);
+        }
+        if let Some(it0) = &self.r#type {
+            it0.visit_element("w:type", v);
+        }
+        if let Some(it0) = &self.pg_sz {
+            it0.visit_element("w:pgSz", v);
+        }
+        if let Some(it0) = &self.pg_mar {
+            it0.visit_element("w:pgMar", v);
+        }
+        if let Some(it0) = &self.paper_src {
+            it0.visit_element("w:paperSrc", v);
+        }
+        if let Some(it0) = &self.pg_borders {
+            it0.visit_element("w:pgBorders", v);
+        }
+        if let Some(it0) = &self.ln_num_type {
+            it0.visit_element("w:lnNumType", v);
+        }
+        if let Some(it0) = &self.pg_num_type {
+            it0.visit_element("w:pgNumType", v);
+        }
+        if let Some(it0) = &self.cols {
+            it0.visit_element("w:cols", v);
+        }
+        if let Some(it0) = &self.form_prot {
+            it0.visit_element("w:formProt", v);
+        }
+        if let Some(it0) = &self.v_align {
+            it0.visit_element("w:vAlign", v);
+        }
+        if let Some(it0) = &self.no_endnote {
+            it0.visit_element("w:noEndnote", v);
+        }
+        if let Some(it0) = &self.title_pg {
+            it0.visit_element("w:titlePg", v);
+        }
+        if let Some(it0) = &self.text_direction {
+            it0.visit_element("w:textDirection", v);
+        }
+        if let Some(it0) = &self.bidi {
+            it0.visit_element("w:bidi", v);
+        }
+        if let Some(it0) = &self.rtl_gutter {
+            it0.visit_element("w:rtlGutter", v);
+        }
+        if let Some(it0) = &self.doc_grid {
+            it0.visit_element("w:docGrid", v);
+        }
+        if let Some(it0) = &self.printer_settings {
+            it0.visit_element("w:printerSettings", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSectPrChange {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-track-changes")]
+        {
+            if let Some(it0) = &self.sect_pr {
+                it0.visit_element("w:sectPr", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSectType {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTShapeDefaults {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTShd {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSimpleField {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-fields")]
+        {
+            if let Some(it0) = &self.fld_data {
+                it0.visit_element("w:fldData", v);
+            }
+        }
+        for it0 in &self.paragraph_content {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSmartTagPr {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            for it0 in &self.attr {
+                it0.visit_element("w:attr", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSmartTagRun {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.smart_tag_pr {
+                it0.visit_element("w:smartTagPr", v);
+            }
+        }
+        for it0 in &self.paragraph_content {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSmartTagType {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSpacing {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTString {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTStylePaneFilter {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTStyleSort {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTSym {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTabStop {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTabs {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.tab {
+            it0.visit_element("w:tab", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTargetScreenSz {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTblBorders {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.top {
+                it0.visit_element("w:top", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.start {
+                it0.visit_element("w:start", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.left {
+                it0.visit_element("w:left", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.bottom {
+                it0.visit_element("w:bottom", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.end {
+                it0.visit_element("w:end", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.right {
+                it0.visit_element("w:right", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.inside_h {
+                it0.visit_element("w:insideH", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.inside_v {
+                it0.visit_element("w:insideV", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTblCellMar {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.top {
+                it0.visit_element("w:top", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.start {
+                it0.visit_element("w:start", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.left {
+                it0.visit_element("w:left", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.bottom {
+                it0.visit_element("w:bottom", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.end {
+                it0.visit_element("w:end", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.right {
+                it0.visit_element("w:right", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTblGridBase {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.grid_col {
+            it0.visit_element("w:gridCol", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTblGridChange {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-track-changes")]
+        {
+            self.tbl_grid.visit_element("w:tblGrid", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTblLayoutType {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTblLook {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTblOverlap {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTblPPr {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTblPrBase {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.tbl_style {
+                it0.visit_element("w:tblStyle", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tblp_pr {
+                it0.visit_element("w:tblpPr", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_overlap {
+                it0.visit_element("w:tblOverlap", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.bidi_visual {
+                it0.visit_element("w:bidiVisual", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.tbl_style_row_band_size {
+                it0.visit_element("w:tblStyleRowBandSize", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.tbl_style_col_band_size {
+                it0.visit_element("w:tblStyleColBandSize", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_w {
+                it0.visit_element("w:tblW", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.justification {
+                it0.visit_element("w:jc", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_cell_spacing {
+                it0.visit_element("w:tblCellSpacing", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_ind {
+                it0.visit_element("w:tblInd", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_borders {
+                it0.visit_element("w:tblBorders", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.shading {
+                it0.visit_element("w:shd", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_layout {
+                it0.visit_element("w:tblLayout", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_cell_mar {
+                it0.visit_element("w:tblCellMar", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_look {
+                it0.visit_element("w:tblLook", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_caption {
+                it0.visit_element("w:tblCaption", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_description {
+                it0.visit_element("w:tblDescription", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTblPrChange {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-track-changes")]
+        {
+            self.table_properties.visit_element("w:tblPr", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTblPrEx {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.tbl_w {
+            it0.visit_element("w:tblW", v);
+        }
+        if let Some(it0) = &self.justification {
+            it0.visit_element("w:jc", v);
+        }
+        if let Some(it0) = &self.tbl_cell_spacing {
+            it0.visit_element("w:tblCellSpacing", v);
+        }
+        if let Some(it0) = &self.tbl_ind {
+            it0.visit_element("w:tblInd", v);
+        }
+        if let Some(it0) = &self.tbl_borders {
+            it0.visit_element("w:tblBorders", v);
+        }
+        if let Some(it0) = &self.shading {
+            it0.visit_element("w:shd", v);
+        }
+        if let Some(it0) = &self.tbl_layout {
+            it0.visit_element("w:tblLayout", v);
+        }
+        if let Some(it0) = &self.tbl_cell_mar {
+            it0.visit_element("w:tblCellMar", v);
+        }
+        if let Some(it0) = &self.tbl_look {
+            it0.visit_element("w:tblLook", v);
+        }
+        #[cfg(feature = "wml-track-changes")]
+        {
+            if let Some(it0) = &self.tbl_pr_ex_change {
+                it0.visit_element("w:tblPrExChange", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTblPrExBase {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_w {
+                it0.visit_element("w:tblW", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.justification {
+                it0.visit_element("w:jc", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_cell_spacing {
+                it0.visit_element("w:tblCellSpacing", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_ind {
+                it0.visit_element("w:tblInd", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_borders {
+                it0.visit_element("w:tblBorders", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.shading {
+                it0.visit_element("w:shd", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_layout {
+                it0.visit_element("w:tblLayout", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_cell_mar {
+                it0.visit_element("w:tblCellMar", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_look {
+                it0.visit_element("w:tblLook", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTblPrExChange {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-track-changes")]
+        {
+            self.tbl_pr_ex.visit_element("w:tblPrEx", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTblWidth {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTcBorders {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.top {
+                it0.visit_element("w:top", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.start {
+                it0.visit_element("w:start", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.left {
+                it0.visit_element("w:left", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.bottom {
+                it0.visit_element("w:bottom", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.end {
+                it0.visit_element("w:end", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.right {
+                it0.visit_element("w:right", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.inside_h {
+                it0.visit_element("w:insideH", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.inside_v {
+                it0.visit_element("w:insideV", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tl2br {
+                it0.visit_element("w:tl2br", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tr2bl {
+                it0.visit_element("w:tr2bl", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTcMar {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.top {
+                it0.visit_element("w:top", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.start {
+                it0.visit_element("w:start", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.left {
+                it0.visit_element("w:left", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.bottom {
+                it0.visit_element("w:bottom", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.end {
+                it0.visit_element("w:end", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.right {
+                it0.visit_element("w:right", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTcPrBase {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.cnf_style {
+                it0.visit_element("w:cnfStyle", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tc_w {
+                it0.visit_element("w:tcW", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.grid_span {
+                it0.visit_element("w:gridSpan", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.horizontal_merge {
+                it0.visit_element("w:hMerge", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.vertical_merge {
+                it0.visit_element("w:vMerge", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tc_borders {
+                it0.visit_element("w:tcBorders", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.shading {
+                it0.visit_element("w:shd", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.no_wrap {
+                it0.visit_element("w:noWrap", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tc_mar {
+                it0.visit_element("w:tcMar", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.text_direction {
+                it0.visit_element("w:textDirection", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tc_fit_text {
+                it0.visit_element("w:tcFitText", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.v_align {
+                it0.visit_element("w:vAlign", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.hide_mark {
+                it0.visit_element("w:hideMark", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.headers {
+                it0.visit_element("w:headers", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTcPrChange {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-track-changes")]
+        {
+            self.cell_properties.visit_element("w:tcPr", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTcPrInner {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.cnf_style {
+            it0.visit_element("w:cnfStyle", v);
+        }
+        if let Some(it0) = &self.tc_w {
+            it0.visit_element("w:tcW", v);
+        }
+        if let Some(it0) = &self.grid_span {
+            it0.visit_element("w:gridSpan", v);
+        }
+        if let Some(it0) = &self.horizontal_merge {
+            it0.visit_element("w:hMerge", v);
+        }
+        if let Some(it0) = &self.vertical_merge {
+            it0.visit_element("w:vMerge", v);
+        }
+        if let Some(it0) = &self.tc_borders {
+            it0.visit_element("w:tcBorders", v);
+        }
+        if let Some(it0) = &self.shading {
+            it0.visit_element("w:shd", v);
+        }
+        if let Some(it0) = &self.no_wrap {
+            it0.visit_element("w:noWrap", v);
+        }
+        if let Some(it0) = &self.tc_mar {
+            it0.visit_element("w:tcMar", v);
+        }
+        if let Some(it0) = &self.text_direction {
+            it0.visit_element("w:textDirection", v);
+        }
+        if let Some(it0) = &self.tc_fit_text {
+            it0.visit_element("w:tcFitText", v);
+        }
+        if let Some(it0) = &self.v_align {
+            it0.visit_element("w:vAlign", v);
+        }
+        if let Some(it0) = &self.hide_mark {
+            it0.visit_element("w:hideMark", v);
+        }
+        if let Some(it0) = &self.headers {
+            it0.visit_element("w:headers", v);
+        }
+        if let Some(it0) = &self.cell_markup {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTextAlignment {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTextDirection {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTextEffect {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTextboxTightWrap {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTopPageBorder {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTrPrBase {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.cnf_style {
+                it0.visit_element("w:cnfStyle", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.div_id {
+                it0.visit_element("w:divId", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.grid_before {
+                it0.visit_element("w:gridBefore", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.grid_after {
+                it0.visit_element("w:gridAfter", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.w_before {
+                it0.visit_element("w:wBefore", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.w_after {
+                it0.visit_element("w:wAfter", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.cant_split {
+                it0.visit_element("w:cantSplit", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tr_height {
+                it0.visit_element("w:trHeight", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_header {
+                it0.visit_element("w:tblHeader", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_cell_spacing {
+                it0.visit_element("w:tblCellSpacing", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.justification {
+                it0.visit_element("w:jc", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.hidden {
+                it0.visit_element("w:hidden", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTrPrChange {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-track-changes")]
+        {
+            self.row_properties.visit_element("w:trPr", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTrackChange {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTrackChangeNumbering {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTrackChangeRange {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTrackChangesView {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTTxbxContent {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.block_content {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTUnderline {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTVMerge {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTVerticalAlignRun {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTVerticalJc {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTView {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTWebSettings {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.frameset {
+                it0.visit_element("w:frameset", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.divs {
+                it0.visit_element("w:divs", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.encoding {
+                it0.visit_element("w:encoding", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.optimize_for_browser {
+                it0.visit_element("w:optimizeForBrowser", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.rely_on_v_m_l {
+                it0.visit_element("w:relyOnVML", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.allow_p_n_g {
+                it0.visit_element("w:allowPNG", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_rely_on_c_s_s {
+                it0.visit_element("w:doNotRelyOnCSS", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_save_as_single_file {
+                it0.visit_element("w:doNotSaveAsSingleFile", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_organize_in_folder {
+                it0.visit_element("w:doNotOrganizeInFolder", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_use_long_file_names {
+                it0.visit_element("w:doNotUseLongFileNames", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.pixels_per_inch {
+                it0.visit_element("w:pixelsPerInch", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.target_screen_sz {
+                it0.visit_element("w:targetScreenSz", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.save_smart_tags_as_xml {
+                it0.visit_element("w:saveSmartTagsAsXml", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTWriteProtection {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTWritingStyle {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CTZoom {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CellContent {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::Tc(it0) => {
+                it0.visit_element("w:tc", v);
+            }
+            Self::CustomXml(it0) => {
+                it0.visit_element("w:customXml", v);
+            }
+            Self::Sdt(it0) => {
+                it0.visit_element("w:sdt", v);
+            }
+            Self::ProofErr(it0) => {
+                it0.visit_element("w:proofErr", v);
+            }
+            Self::PermStart(it0) => {
+                it0.visit_element("w:permStart", v);
+            }
+            Self::PermEnd(it0) => {
+                it0.visit_element("w:permEnd", v);
+            }
+            Self::BookmarkStart(it0) => {
+                it0.visit_element("w:bookmarkStart", v);
+            }
+            Self::BookmarkEnd(it0) => {
+                it0.visit_element("w:bookmarkEnd", v);
+            }
+            Self::MoveFromRangeStart(it0) => {
+                it0.visit_element("w:moveFromRangeStart", v);
+            }
+            Self::MoveFromRangeEnd(it0) => {
+                it0.visit_element("w:moveFromRangeEnd", v);
+            }
+            Self::MoveToRangeStart(it0) => {
+                it0.visit_element("w:moveToRangeStart", v);
+            }
+            Self::MoveToRangeEnd(it0) => {
+                it0.visit_element("w:moveToRangeEnd", v);
+            }
+            Self::CommentRangeStart(it0) => {
+                it0.visit_element("w:commentRangeStart", v);
+            }
+            Self::CommentRangeEnd(it0) => {
+                it0.visit_element("w:commentRangeEnd", v);
+            }
+            Self::CustomXmlInsRangeStart(it0) => {
+                it0.visit_element("w:customXmlInsRangeStart", v);
+            }
+            Self::CustomXmlInsRangeEnd(it0) => {
+                it0.visit_element("w:customXmlInsRangeEnd", v);
+            }
+            Self::CustomXmlDelRangeStart(it0) => {
+                it0.visit_element("w:customXmlDelRangeStart", v);
+            }
+            Self::CustomXmlDelRangeEnd(it0) => {
+                it0.visit_element("w:customXmlDelRangeEnd", v);
+            }
+            Self::CustomXmlMoveFromRangeStart(it0) => {
+                it0.visit_element("w:customXmlMoveFromRangeStart", v);
+            }
+            Self::CustomXmlMoveFromRangeEnd(it0) => {
+                it0.visit_element("w:customXmlMoveFromRangeEnd", v);
+            }
+            Self::CustomXmlMoveToRangeStart(it0) => {
+                it0.visit_element("w:customXmlMoveToRangeStart", v);
+            }
+            Self::CustomXmlMoveToRangeEnd(it0) => {
+                it0.visit_element("w:customXmlMoveToRangeEnd", v);
+            }
+            Self::Ins(it0) => {
+                it0.visit_element("w:ins", v);
+            }
+            Self::Del(it0) => {
+                it0.visit_element("w:del", v);
+            }
+            Self::MoveFrom(it0) => {
+                it0.visit_element("w:moveFrom", v);
+            }
+            Self::MoveTo(it0) => {
+                it0.visit_element("w:moveTo", v);
+            }
+        }
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::Tc(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXml(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Sdt(it0) => {
+                it0.visit_content(v);
+            }
+            Self::ProofErr(it0) => {
+                it0.visit_content(v);
+            }
+            Self::PermStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::PermEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::BookmarkStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::BookmarkEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFromRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFromRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveToRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveToRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CommentRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CommentRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlInsRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlInsRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlDelRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlDelRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveFromRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveFromRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveToRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveToRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Ins(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Del(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFrom(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveTo(it0) => {
+                it0.visit_content(v);
+            }
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for CellMarkup {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::CellIns(it0) => {
+                it0.visit_element("w:cellIns", v);
+            }
+            Self::CellDel(it0) => {
+                it0.visit_element("w:cellDel", v);
+            }
+            Self::CellMerge(it0) => {
+                it0.visit_element("w:cellMerge", v);
+            }
+        }
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::CellIns(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CellDel(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CellMerge(it0) => {
+                it0.visit_content(v);
+            }
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Columns {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-layout")]
+        {
+            for it0 in &self.col {
+                it0.visit_element("w:col", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Comment {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.block_content {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Comments {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.comment {
+            it0.visit_element("w:comment", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Compatibility {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.use_single_borderfor_contiguous_cells {
+                it0.visit_element("w:useSingleBorderforContiguousCells", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.wp_justification {
+                it0.visit_element("w:wpJustification", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.no_tab_hang_ind {
+                it0.visit_element("w:noTabHangInd", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.no_leading {
+                it0.visit_element("w:noLeading", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.space_for_u_l {
+                it0.visit_element("w:spaceForUL", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.no_column_balance {
+                it0.visit_element("w:noColumnBalance", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.balance_single_byte_double_byte_width {
+                it0.visit_element("w:balanceSingleByteDoubleByteWidth", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.no_extra_line_spacing {
+                it0.visit_element("w:noExtraLineSpacing", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_leave_backslash_alone {
+                it0.visit_element("w:doNotLeaveBackslashAlone", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.ul_trail_space {
+                it0.visit_element("w:ulTrailSpace", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_expand_shift_return {
+                it0.visit_element("w:doNotExpandShiftReturn", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.spacing_in_whole_points {
+                it0.visit_element("w:spacingInWholePoints", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.line_wrap_like_word6 {
+                it0.visit_element("w:lineWrapLikeWord6", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.print_body_text_before_header {
+                it0.visit_element("w:printBodyTextBeforeHeader", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.print_col_black {
+                it0.visit_element("w:printColBlack", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.wp_space_width {
+                it0.visit_element("w:wpSpaceWidth", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.show_breaks_in_frames {
+                it0.visit_element("w:showBreaksInFrames", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.sub_font_by_size {
+                it0.visit_element("w:subFontBySize", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.suppress_bottom_spacing {
+                it0.visit_element("w:suppressBottomSpacing", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.suppress_top_spacing {
+                it0.visit_element("w:suppressTopSpacing", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.suppress_spacing_at_top_of_page {
+                it0.visit_element("w:suppressSpacingAtTopOfPage", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.suppress_top_spacing_w_p {
+                it0.visit_element("w:suppressTopSpacingWP", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.suppress_sp_bf_after_pg_brk {
+                it0.visit_element("w:suppressSpBfAfterPgBrk", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.swap_borders_facing_pages {
+                it0.visit_element("w:swapBordersFacingPages", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.conv_mail_merge_esc {
+                it0.visit_element("w:convMailMergeEsc", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.truncate_font_heights_like_w_p6 {
+                it0.visit_element("w:truncateFontHeightsLikeWP6", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.mw_small_caps {
+                it0.visit_element("w:mwSmallCaps", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.use_printer_metrics {
+                it0.visit_element("w:usePrinterMetrics", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_suppress_paragraph_borders {
+                it0.visit_element("w:doNotSuppressParagraphBorders", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.wrap_trail_spaces {
+                it0.visit_element("w:wrapTrailSpaces", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.footnote_layout_like_w_w8 {
+                it0.visit_element("w:footnoteLayoutLikeWW8", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.shape_layout_like_w_w8 {
+                it0.visit_element("w:shapeLayoutLikeWW8", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.align_tables_row_by_row {
+                it0.visit_element("w:alignTablesRowByRow", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.forget_last_tab_alignment {
+                it0.visit_element("w:forgetLastTabAlignment", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.adjust_line_height_in_table {
+                it0.visit_element("w:adjustLineHeightInTable", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.auto_space_like_word95 {
+                it0.visit_element("w:autoSpaceLikeWord95", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.no_space_raise_lower {
+                it0.visit_element("w:noSpaceRaiseLower", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_use_h_t_m_l_paragraph_auto_spacing {
+                it0.visit_element("w:doNotUseHTMLParagraphAutoSpacing", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.layout_raw_table_width {
+                it0.visit_element("w:layoutRawTableWidth", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.layout_table_rows_apart {
+                it0.visit_element("w:layoutTableRowsApart", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.use_word97_line_break_rules {
+                it0.visit_element("w:useWord97LineBreakRules", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_break_wrapped_tables {
+                it0.visit_element("w:doNotBreakWrappedTables", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_snap_to_grid_in_cell {
+                it0.visit_element("w:doNotSnapToGridInCell", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.select_fld_with_first_or_last_char {
+                it0.visit_element("w:selectFldWithFirstOrLastChar", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.apply_breaking_rules {
+                it0.visit_element("w:applyBreakingRules", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_wrap_text_with_punct {
+                it0.visit_element("w:doNotWrapTextWithPunct", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_use_east_asian_break_rules {
+                it0.visit_element("w:doNotUseEastAsianBreakRules", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.use_word2002_table_style_rules {
+                it0.visit_element("w:useWord2002TableStyleRules", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.grow_autofit {
+                it0.visit_element("w:growAutofit", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.use_f_e_layout {
+                it0.visit_element("w:useFELayout", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.use_normal_style_for_list {
+                it0.visit_element("w:useNormalStyleForList", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_use_indent_as_numbering_tab_stop {
+                it0.visit_element("w:doNotUseIndentAsNumberingTabStop", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.use_alt_kinsoku_line_break_rules {
+                it0.visit_element("w:useAltKinsokuLineBreakRules", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.allow_space_of_same_style_in_table {
+                it0.visit_element("w:allowSpaceOfSameStyleInTable", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_suppress_indentation {
+                it0.visit_element("w:doNotSuppressIndentation", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_autofit_constrained_tables {
+                it0.visit_element("w:doNotAutofitConstrainedTables", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.autofit_to_first_fixed_width_cell {
+                it0.visit_element("w:autofitToFirstFixedWidthCell", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.underline_tab_in_num_list {
+                it0.visit_element("w:underlineTabInNumList", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.display_hangul_fixed_width {
+                it0.visit_element("w:displayHangulFixedWidth", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.split_pg_break_and_para_mark {
+                it0.visit_element("w:splitPgBreakAndParaMark", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_vert_align_cell_with_sp {
+                it0.visit_element("w:doNotVertAlignCellWithSp", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_break_constrained_forced_table {
+                it0.visit_element("w:doNotBreakConstrainedForcedTable", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_vert_align_in_txbx {
+                it0.visit_element("w:doNotVertAlignInTxbx", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.use_ansi_kerning_pairs {
+                it0.visit_element("w:useAnsiKerningPairs", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.cached_col_balance {
+                it0.visit_element("w:cachedColBalance", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            for it0 in &self.compat_setting {
+                it0.visit_element("w:compatSetting", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for DocTypeElement {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Document {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.background {
+                it0.visit_element("w:background", v);
+            }
+        }
+        if let Some(it0) = &self.body {
+            it0.visit_element("w:body", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for DocumentDefaults {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.r_pr_default {
+            it0.visit_element("w:rPrDefault", v);
+        }
+        if let Some(it0) = &self.p_pr_default {
+            it0.visit_element("w:pPrDefault", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for DocumentGrid {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for EGBlockLevelChunkElts {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.block_content {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for EGFtnEdnNumProps {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.num_start {
+            it0.visit_element("w:numStart", v);
+        }
+        if let Some(it0) = &self.num_restart {
+            it0.visit_element("w:numRestart", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for EGMathContent {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for EGParaRPrTrackChanges {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.ins {
+            it0.visit_element("w:ins", v);
+        }
+        if let Some(it0) = &self.del {
+            it0.visit_element("w:del", v);
+        }
+        if let Some(it0) = &self.move_from {
+            it0.visit_element("w:moveFrom", v);
+        }
+        if let Some(it0) = &self.move_to {
+            it0.visit_element("w:moveTo", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for EGRPr {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.r_pr {
+            it0.visit_element("w:rPr", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for EGRPrBase {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.run_style {
+            it0.visit_element("w:rStyle", v);
+        }
+        if let Some(it0) = &self.fonts {
+            it0.visit_element("w:rFonts", v);
+        }
+        if let Some(it0) = &self.bold {
+            it0.visit_element("w:b", v);
+        }
+        if let Some(it0) = &self.b_cs {
+            it0.visit_element("w:bCs", v);
+        }
+        if let Some(it0) = &self.italic {
+            it0.visit_element("w:i", v);
+        }
+        if let Some(it0) = &self.i_cs {
+            it0.visit_element("w:iCs", v);
+        }
+        if let Some(it0) = &self.caps {
+            it0.visit_element("w:caps", v);
+        }
+        if let Some(it0) = &self.small_caps {
+            it0.visit_element("w:smallCaps", v);
+        }
+        if let Some(it0) = &self.strikethrough {
+            it0.visit_element("w:strike", v);
+        }
+        if let Some(it0) = &self.dstrike {
+            it0.visit_element("w:dstrike", v);
+        }
+        if let Some(it0) = &self.outline {
+            it0.visit_element("w:outline", v);
+        }
+        if let Some(it0) = &self.shadow {
+            it0.visit_element("w:shadow", v);
+        }
+        if let Some(it0) = &self.emboss {
+            it0.visit_element("w:emboss", v);
+        }
+        if let Some(it0) = &self.imprint {
+            it0.visit_element("w:imprint", v);
+        }
+        if let Some(it0) = &self.no_proof {
+            it0.visit_element("w:noProof", v);
+        }
+        if let Some(it0) = &self.snap_to_grid {
+            it0.visit_element("w:snapToGrid", v);
+        }
+        if let Some(it0) = &self.vanish {
+            it0.visit_element("w:vanish", v);
+        }
+        if let Some(it0) = &self.web_hidden {
+            it0.visit_element("w:webHidden", v);
+        }
+        if let Some(it0) = &self.color {
+            it0.visit_element("w:color", v);
+        }
+        if let Some(it0) = &self.spacing {
+            it0.visit_element("w:spacing", v);
+        }
+        if let Some(it0) = &self.width {
+            it0.visit_element("w:w", v);
+        }
+        if let Some(it0) = &self.kern {
+            it0.visit_element("w:kern", v);
+        }
+        if let Some(it0) = &self.position {
+            it0.visit_element("w:position", v);
+        }
+        if let Some(it0) = &self.size {
+            it0.visit_element("w:sz", v);
+        }
+        if let Some(it0) = &self.size_complex_script {
+            it0.visit_element("w:szCs", v);
+        }
+        if let Some(it0) = &self.highlight {
+            it0.visit_element("w:highlight", v);
+        }
+        if let Some(it0) = &self.underline {
+            it0.visit_element("w:u", v);
+        }
+        if let Some(it0) = &self.effect {
+            it0.visit_element("w:effect", v);
+        }
+        if let Some(it0) = &self.bdr {
+            it0.visit_element("w:bdr", v);
+        }
+        if let Some(it0) = &self.shading {
+            it0.visit_element("w:shd", v);
+        }
+        if let Some(it0) = &self.fit_text {
+            it0.visit_element("w:fitText", v);
+        }
+        if let Some(it0) = &self.vert_align {
+            it0.visit_element("w:vertAlign", v);
+        }
+        if let Some(it0) = &self.rtl {
+            it0.visit_element("w:rtl", v);
+        }
+        if let Some(it0) = &self.cs {
+            it0.visit_element("w:cs", v);
+        }
+        if let Some(it0) = &self.em {
+            it0.visit_element("w:em", v);
+        }
+        if let Some(it0) = &self.lang {
+            it0.visit_element("w:lang", v);
+        }
+        if let Some(it0) = &self.east_asian_layout {
+            it0.visit_element("w:eastAsianLayout", v);
+        }
+        if let Some(it0) = &self.spec_vanish {
+            it0.visit_element("w:specVanish", v);
+        }
+        if let Some(it0) = &self.o_math {
+            it0.visit_element("w:oMath", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for EGRPrContent {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.run_style {
+            it0.visit_element("w:rStyle", v);
+        }
+        if let Some(it0) = &self.fonts {
+            it0.visit_element("w:rFonts", v);
+        }
+        if let Some(it0) = &self.bold {
+            it0.visit_element("w:b", v);
+        }
+        if let Some(it0) = &self.b_cs {
+            it0.visit_element("w:bCs", v);
+        }
+        if let Some(it0) = &self.italic {
+            it0.visit_element("w:i", v);
+        }
+        if let Some(it0) = &self.i_cs {
+            it0.visit_element("w:iCs", v);
+        }
+        if let Some(it0) = &self.caps {
+            it0.visit_element("w:caps", v);
+        }
+        if let Some(it0) = &self.small_caps {
+            it0.visit_element("w:smallCaps", v);
+        }
+        if let Some(it0) = &self.strikethrough {
+            it0.visit_element("w:strike", v);
+        }
+        if let Some(it0) = &self.dstrike {
+            it0.visit_element("w:dstrike", v);
+        }
+        if let Some(it0) = &self.outline {
+            it0.visit_element("w:outline", v);
+        }
+        if let Some(it0) = &self.shadow {
+            it0.visit_element("w:shadow", v);
+        }
+        if let Some(it0) = &self.emboss {
+            it0.visit_element("w:emboss", v);
+        }
+        if let Some(it0) = &self.imprint {
+            it0.visit_element("w:imprint", v);
+        }
+        if let Some(it0) = &self.no_proof {
+            it0.visit_element("w:noProof", v);
+        }
+        if let Some(it0) = &self.snap_to_grid {
+            it0.visit_element("w:snapToGrid", v);
+        }
+        if let Some(it0) = &self.vanish {
+            it0.visit_element("w:vanish", v);
+        }
+        if let Some(it0) = &self.web_hidden {
+            it0.visit_element("w:webHidden", v);
+        }
+        if let Some(it0) = &self.color {
+            it0.visit_element("w:color", v);
+        }
+        if let Some(it0) = &self.spacing {
+            it0.visit_element("w:spacing", v);
+        }
+        if let Some(it0) = &self.width {
+            it0.visit_element("w:w", v);
+        }
+        if let Some(it0) = &self.kern {
+            it0.visit_element("w:kern", v);
+        }
+        if let Some(it0) = &self.position {
+            it0.visit_element("w:position", v);
+        }
+        if let Some(it0) = &self.size {
+            it0.visit_element("w:sz", v);
+        }
+        if let Some(it0) = &self.size_complex_script {
+            it0.visit_element("w:szCs", v);
+        }
+        if let Some(it0) = &self.highlight {
+            it0.visit_element("w:highlight", v);
+        }
+        if let Some(it0) = &self.underline {
+            it0.visit_element("w:u", v);
+        }
+        if let Some(it0) = &self.effect {
+            it0.visit_element("w:effect", v);
+        }
+        if let Some(it0) = &self.bdr {
+            it0.visit_element("w:bdr", v);
+        }
+        if let Some(it0) = &self.shading {
+            it0.visit_element("w:shd", v);
+        }
+        if let Some(it0) = &self.fit_text {
+            it0.visit_element("w:fitText", v);
+        }
+        if let Some(it0) = &self.vert_align {
+            it0.visit_element("w:vertAlign", v);
+        }
+        if let Some(it0) = &self.rtl {
+            it0.visit_element("w:rtl", v);
+        }
+        if let Some(it0) = &self.cs {
+            it0.visit_element("w:cs", v);
+        }
+        if let Some(it0) = &self.em {
+            it0.visit_element("w:em", v);
+        }
+        if let Some(it0) = &self.lang {
+            it0.visit_element("w:lang", v);
+        }
+        if let Some(it0) = &self.east_asian_layout {
+            it0.visit_element("w:eastAsianLayout", v);
+        }
+        if let Some(it0) = &self.spec_vanish {
+            it0.visit_element("w:specVanish", v);
+        }
+        if let Some(it0) = &self.o_math {
+            it0.visit_element("w:oMath", v);
+        }
+        if let Some(it0) = &self.r_pr_change {
+            it0.visit_element("w:rPrChange", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for EGSectPrContents {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.footnote_pr {
+            it0.visit_element("w:footnotePr", v);
+        }
+        if let Some(it0) = &self.endnote_pr {
+            it0.visit_element("w:endnotePr", v);
+        }
+        if let Some(it0) = &self.r#type {
+            it0.visit_element("w:type", v);
+        }
+        if let Some(it0) = &self.pg_sz {
+            it0.visit_element("w:pgSz", v);
+        }
+        if let Some(it0) = &self.pg_mar {
+            it0.visit_element("w:pgMar", v);
+        }
+        if let Some(it0) = &self.paper_src {
+            it0.visit_element("w:paperSrc", v);
+        }
+        if let Some(it0) = &self.pg_borders {
+            it0.visit_element("w:pgBorders", v);
+        }
+        if let Some(it0) = &self.ln_num_type {
+            it0.visit_element("w:lnNumType", v);
+        }
+        if let Some(it0) = &self.pg_num_type {
+            it0.visit_element("w:pgNumType", v);
+        }
+        if let Some(it0) = &self.cols {
+            it0.visit_element("w:cols", v);
+        }
+        if let Some(it0) = &self.form_prot {
+            it0.visit_element("w:formProt", v);
+        }
+        if let Some(it0) = &self.v_align {
+            it0.visit_element("w:vAlign", v);
+        }
+        if let Some(it0) = &self.no_endnote {
+            it0.visit_element("w:noEndnote", v);
+        }
+        if let Some(it0) = &self.title_pg {
+            it0.visit_element("w:titlePg", v);
+        }
+        if let Some(it0) = &self.text_direction {
+            it0.visit_element("w:textDirection", v);
+        }
+        if let Some(it0) = &self.bidi {
+            it0.visit_element("w:bidi", v);
+        }
+        if let Some(it0) = &self.rtl_gutter {
+            it0.visit_element("w:rtlGutter", v);
+        }
+        if let Some(it0) = &self.doc_grid {
+            it0.visit_element("w:docGrid", v);
+        }
+        if let Some(it0) = &self.printer_settings {
+            it0.visit_element("w:printerSettings", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Endnotes {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.endnote {
+            it0.visit_element("w:endnote", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for FFNameElement {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Font {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.alt_name {
+                it0.visit_element("w:altName", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.panose1 {
+                it0.visit_element("w:panose1", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.charset {
+                it0.visit_element("w:charset", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.family {
+                it0.visit_element("w:family", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.not_true_type {
+                it0.visit_element("w:notTrueType", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.pitch {
+                it0.visit_element("w:pitch", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.sig {
+                it0.visit_element("w:sig", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.embed_regular {
+                it0.visit_element("w:embedRegular", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.embed_bold {
+                it0.visit_element("w:embedBold", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.embed_italic {
+                it0.visit_element("w:embedItalic", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.embed_bold_italic {
+                it0.visit_element("w:embedBoldItalic", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Fonts {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for FootnoteEndnote {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.block_content {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for FootnoteEndnoteRef {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Footnotes {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.footnote {
+            it0.visit_element("w:footnote", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for GuidElement {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for HeaderFooter {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.block_content {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for HeaderFooterRef {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::HeaderReference(it0) => {
+                it0.visit_element("w:headerReference", v);
+            }
+            Self::FooterReference(it0) => {
+                it0.visit_element("w:footerReference", v);
+            }
+        }
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::HeaderReference(it0) => {
+                it0.visit_content(v);
+            }
+            Self::FooterReference(it0) => {
+                it0.visit_content(v);
+            }
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for HeaderFooterReference {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for HpsMeasureElement {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Hyperlink {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.paragraph_content {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for LanguageElement {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for LatentStyleException {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for LatentStyles {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.lsd_exception {
+            it0.visit_element("w:lsdException", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Level {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.start {
+                it0.visit_element("w:start", v);
+            }
+        }
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.num_fmt {
+                it0.visit_element("w:numFmt", v);
+            }
+        }
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.lvl_restart {
+                it0.visit_element("w:lvlRestart", v);
+            }
+        }
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.paragraph_style {
+                it0.visit_element("w:pStyle", v);
+            }
+        }
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.is_lgl {
+                it0.visit_element("w:isLgl", v);
+            }
+        }
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.suff {
+                it0.visit_element("w:suff", v);
+            }
+        }
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.lvl_text {
+                it0.visit_element("w:lvlText", v);
+            }
+        }
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.lvl_pic_bullet_id {
+                it0.visit_element("w:lvlPicBulletId", v);
+            }
+        }
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.legacy {
+                it0.visit_element("w:legacy", v);
+            }
+        }
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.lvl_jc {
+                it0.visit_element("w:lvlJc", v);
+            }
+        }
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.p_pr {
+                it0.visit_element("w:pPr", v);
+            }
+        }
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.r_pr {
+                it0.visit_element("w:rPr", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for LongHexNumberElement {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for MacroNameElement {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for MailMergeDataTypeElement {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for MathContent {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.p_content_base {
+            it0.visit_element("", v);
+        }
+        for it0 in &self.content_run_content_base {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for MathRunProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::RPr(it0) => {
+                it0.visit_element("w:rPr", v);
+            }
+            Self::Ins(it0) => {
+                it0.visit_element("w:ins", v);
+            }
+            Self::Del(it0) => {
+                it0.visit_element("w:del", v);
+            }
+        }
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::RPr(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Ins(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Del(it0) => {
+                it0.visit_content(v);
+            }
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Numbering {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-numbering")]
+        {
+            for it0 in &self.num_pic_bullet {
+                it0.visit_element("w:numPicBullet", v);
+            }
+        }
+        for it0 in &self.abstract_num {
+            it0.visit_element("w:abstractNum", v);
+        }
+        for it0 in &self.num {
+            it0.visit_element("w:num", v);
+        }
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.num_id_mac_at_cleanup {
+                it0.visit_element("w:numIdMacAtCleanup", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for NumberingInstance {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        self.abstract_num_id.visit_element("w:abstractNumId", v);
+        #[cfg(feature = "wml-numbering")]
+        {
+            for it0 in &self.lvl_override {
+                it0.visit_element("w:lvlOverride", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for NumberingProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.ilvl {
+                it0.visit_element("w:ilvl", v);
+            }
+        }
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.num_id {
+                it0.visit_element("w:numId", v);
+            }
+        }
+        #[cfg(feature = "wml-track-changes")]
+        {
+            if let Some(it0) = &self.numbering_change {
+                it0.visit_element("w:numberingChange", v);
+            }
+        }
+        #[cfg(feature = "wml-track-changes")]
+        {
+            if let Some(it0) = &self.ins {
+                it0.visit_element("w:ins", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for OnOffElement {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PageMargins {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PageSize {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PanoseElement {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Paragraph {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.p_pr {
+                it0.visit_element("w:pPr", v);
+            }
+        }
+        for it0 in &self.paragraph_content {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ParagraphContent {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::CustomXml(it0) => {
+                it0.visit_element("w:customXml", v);
+            }
+            Self::SmartTag(it0) => {
+                it0.visit_element("w:smartTag", v);
+            }
+            Self::Sdt(it0) => {
+                it0.visit_element("w:sdt", v);
+            }
+            Self::Dir(it0) => {
+                it0.visit_element("w:dir", v);
+            }
+            Self::Bdo(it0) => {
+                it0.visit_element("w:bdo", v);
+            }
+            Self::R(it0) => {
+                it0.visit_element("w:r", v);
+            }
+            Self::ProofErr(it0) => {
+                it0.visit_element("w:proofErr", v);
+            }
+            Self::PermStart(it0) => {
+                it0.visit_element("w:permStart", v);
+            }
+            Self::PermEnd(it0) => {
+                it0.visit_element("w:permEnd", v);
+            }
+            Self::BookmarkStart(it0) => {
+                it0.visit_element("w:bookmarkStart", v);
+            }
+            Self::BookmarkEnd(it0) => {
+                it0.visit_element("w:bookmarkEnd", v);
+            }
+            Self::MoveFromRangeStart(it0) => {
+                it0.visit_element("w:moveFromRangeStart", v);
+            }
+            Self::MoveFromRangeEnd(it0) => {
+                it0.visit_element("w:moveFromRangeEnd", v);
+            }
+            Self::MoveToRangeStart(it0) => {
+                it0.visit_element("w:moveToRangeStart", v);
+            }
+            Self::MoveToRangeEnd(it0) => {
+                it0.visit_element("w:moveToRangeEnd", v);
+            }
+            Self::CommentRangeStart(it0) => {
+                it0.visit_element("w:commentRangeStart", v);
+            }
+            Self::CommentRangeEnd(it0) => {
+                it0.visit_element("w:commentRangeEnd", v);
+            }
+            Self::CustomXmlInsRangeStart(it0) => {
+                it0.visit_element("w:customXmlInsRangeStart", v);
+            }
+            Self::CustomXmlInsRangeEnd(it0) => {
+                it0.visit_element("w:customXmlInsRangeEnd", v);
+            }
+            Self::CustomXmlDelRangeStart(it0) => {
+                it0.visit_element("w:customXmlDelRangeStart", v);
+            }
+            Self::CustomXmlDelRangeEnd(it0) => {
+                it0.visit_element("w:customXmlDelRangeEnd", v);
+            }
+            Self::CustomXmlMoveFromRangeStart(it0) => {
+                it0.visit_element("w:customXmlMoveFromRangeStart", v);
+            }
+            Self::CustomXmlMoveFromRangeEnd(it0) => {
+                it0.visit_element("w:customXmlMoveFromRangeEnd", v);
+            }
+            Self::CustomXmlMoveToRangeStart(it0) => {
+                it0.visit_element("w:customXmlMoveToRangeStart", v);
+            }
+            Self::CustomXmlMoveToRangeEnd(it0) => {
+                it0.visit_element("w:customXmlMoveToRangeEnd", v);
+            }
+            Self::Ins(it0) => {
+                it0.visit_element("w:ins", v);
+            }
+            Self::Del(it0) => {
+                it0.visit_element("w:del", v);
+            }
+            Self::MoveFrom(it0) => {
+                it0.visit_element("w:moveFrom", v);
+            }
+            Self::MoveTo(it0) => {
+                it0.visit_element("w:moveTo", v);
+            }
+            Self::FldSimple(it0) => {
+                it0.visit_element("w:fldSimple", v);
+            }
+            Self::Hyperlink(it0) => {
+                it0.visit_element("w:hyperlink", v);
+            }
+            Self::SubDoc(it0) => {
+                it0.visit_element("w:subDoc", v);
+            }
+        }
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::CustomXml(it0) => {
+                it0.visit_content(v);
+            }
+            Self::SmartTag(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Sdt(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Dir(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Bdo(it0) => {
+                it0.visit_content(v);
+            }
+            Self::R(it0) => {
+                it0.visit_content(v);
+            }
+            Self::ProofErr(it0) => {
+                it0.visit_content(v);
+            }
+            Self::PermStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::PermEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::BookmarkStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::BookmarkEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFromRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFromRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveToRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveToRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CommentRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CommentRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlInsRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlInsRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlDelRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlDelRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveFromRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveFromRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveToRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveToRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Ins(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Del(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFrom(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveTo(it0) => {
+                it0.visit_content(v);
+            }
+            Self::FldSimple(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Hyperlink(it0) => {
+                it0.visit_content(v);
+            }
+            Self::SubDoc(it0) => {
+                it0.visit_content(v);
+            }
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ParagraphContentBase {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::CustomXml(it0) => {
+                it0.visit_element("w:customXml", v);
+            }
+            Self::FldSimple(it0) => {
+                it0.visit_element("w:fldSimple", v);
+            }
+            Self::Hyperlink(it0) => {
+                it0.visit_element("w:hyperlink", v);
+            }
+        }
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::CustomXml(it0) => {
+                it0.visit_content(v);
+            }
+            Self::FldSimple(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Hyperlink(it0) => {
+                it0.visit_content(v);
+            }
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ParagraphProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.paragraph_style {
+            it0.visit_element("w:pStyle", v);
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.keep_next {
+                it0.visit_element("w:keepNext", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.keep_lines {
+                it0.visit_element("w:keepLines", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.page_break_before {
+                it0.visit_element("w:pageBreakBefore", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.frame_pr {
+                it0.visit_element("w:framePr", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.widow_control {
+                it0.visit_element("w:widowControl", v);
+            }
+        }
+        #[cfg(feature = "wml-numbering")]
+        {
+            if let Some(it0) = &self.num_pr {
+                it0.visit_element("w:numPr", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.suppress_line_numbers {
+                it0.visit_element("w:suppressLineNumbers", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.paragraph_border {
+                it0.visit_element("w:pBdr", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.shading {
+                it0.visit_element("w:shd", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.tabs {
+                it0.visit_element("w:tabs", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.suppress_auto_hyphens {
+                it0.visit_element("w:suppressAutoHyphens", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.kinsoku {
+                it0.visit_element("w:kinsoku", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.word_wrap {
+                it0.visit_element("w:wordWrap", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.overflow_punct {
+                it0.visit_element("w:overflowPunct", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.top_line_punct {
+                it0.visit_element("w:topLinePunct", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.auto_space_d_e {
+                it0.visit_element("w:autoSpaceDE", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.auto_space_d_n {
+                it0.visit_element("w:autoSpaceDN", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.bidi {
+                it0.visit_element("w:bidi", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.adjust_right_ind {
+                it0.visit_element("w:adjustRightInd", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.snap_to_grid {
+                it0.visit_element("w:snapToGrid", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.spacing {
+                it0.visit_element("w:spacing", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.indentation {
+                it0.visit_element("w:ind", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.contextual_spacing {
+                it0.visit_element("w:contextualSpacing", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.mirror_indents {
+                it0.visit_element("w:mirrorIndents", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.suppress_overlap {
+                it0.visit_element("w:suppressOverlap", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.justification {
+                it0.visit_element("w:jc", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.text_direction {
+                it0.visit_element("w:textDirection", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.text_alignment {
+                it0.visit_element("w:textAlignment", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.textbox_tight_wrap {
+                it0.visit_element("w:textboxTightWrap", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.outline_lvl {
+                it0.visit_element("w:outlineLvl", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.div_id {
+                it0.visit_element("w:divId", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.cnf_style {
+                it0.visit_element("w:cnfStyle", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.r_pr {
+                it0.visit_element("w:rPr", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.sect_pr {
+                it0.visit_element("w:sectPr", v);
+            }
+        }
+        #[cfg(feature = "wml-track-changes")]
+        {
+            if let Some(it0) = &self.p_pr_change {
+                it0.visit_element("w:pPrChange", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for ParagraphPropertiesDefault {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.p_pr {
+            it0.visit_element("w:pPr", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for PixelsMeasureElement {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RangeMarkup {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::BookmarkStart(it0) => {
+                it0.visit_element("w:bookmarkStart", v);
+            }
+            Self::BookmarkEnd(it0) => {
+                it0.visit_element("w:bookmarkEnd", v);
+            }
+            Self::MoveFromRangeStart(it0) => {
+                it0.visit_element("w:moveFromRangeStart", v);
+            }
+            Self::MoveFromRangeEnd(it0) => {
+                it0.visit_element("w:moveFromRangeEnd", v);
+            }
+            Self::MoveToRangeStart(it0) => {
+                it0.visit_element("w:moveToRangeStart", v);
+            }
+            Self::MoveToRangeEnd(it0) => {
+                it0.visit_element("w:moveToRangeEnd", v);
+            }
+            Self::CommentRangeStart(it0) => {
+                it0.visit_element("w:commentRangeStart", v);
+            }
+            Self::CommentRangeEnd(it0) => {
+                it0.visit_element("w:commentRangeEnd", v);
+            }
+            Self::CustomXmlInsRangeStart(it0) => {
+                it0.visit_element("w:customXmlInsRangeStart", v);
+            }
+            Self::CustomXmlInsRangeEnd(it0) => {
+                it0.visit_element("w:customXmlInsRangeEnd", v);
+            }
+            Self::CustomXmlDelRangeStart(it0) => {
+                it0.visit_element("w:customXmlDelRangeStart", v);
+            }
+            Self::CustomXmlDelRangeEnd(it0) => {
+                it0.visit_element("w:customXmlDelRangeEnd", v);
+            }
+            Self::CustomXmlMoveFromRangeStart(it0) => {
+                it0.visit_element("w:customXmlMoveFromRangeStart", v);
+            }
+            Self::CustomXmlMoveFromRangeEnd(it0) => {
+                it0.visit_element("w:customXmlMoveFromRangeEnd", v);
+            }
+            Self::CustomXmlMoveToRangeStart(it0) => {
+                it0.visit_element("w:customXmlMoveToRangeStart", v);
+            }
+            Self::CustomXmlMoveToRangeEnd(it0) => {
+                it0.visit_element("w:customXmlMoveToRangeEnd", v);
+            }
+        }
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::BookmarkStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::BookmarkEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFromRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFromRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveToRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveToRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CommentRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CommentRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlInsRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlInsRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlDelRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlDelRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveFromRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveFromRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveToRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveToRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RowContent {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::Tr(it0) => {
+                it0.visit_element("w:tr", v);
+            }
+            Self::CustomXml(it0) => {
+                it0.visit_element("w:customXml", v);
+            }
+            Self::Sdt(it0) => {
+                it0.visit_element("w:sdt", v);
+            }
+            Self::ProofErr(it0) => {
+                it0.visit_element("w:proofErr", v);
+            }
+            Self::PermStart(it0) => {
+                it0.visit_element("w:permStart", v);
+            }
+            Self::PermEnd(it0) => {
+                it0.visit_element("w:permEnd", v);
+            }
+            Self::BookmarkStart(it0) => {
+                it0.visit_element("w:bookmarkStart", v);
+            }
+            Self::BookmarkEnd(it0) => {
+                it0.visit_element("w:bookmarkEnd", v);
+            }
+            Self::MoveFromRangeStart(it0) => {
+                it0.visit_element("w:moveFromRangeStart", v);
+            }
+            Self::MoveFromRangeEnd(it0) => {
+                it0.visit_element("w:moveFromRangeEnd", v);
+            }
+            Self::MoveToRangeStart(it0) => {
+                it0.visit_element("w:moveToRangeStart", v);
+            }
+            Self::MoveToRangeEnd(it0) => {
+                it0.visit_element("w:moveToRangeEnd", v);
+            }
+            Self::CommentRangeStart(it0) => {
+                it0.visit_element("w:commentRangeStart", v);
+            }
+            Self::CommentRangeEnd(it0) => {
+                it0.visit_element("w:commentRangeEnd", v);
+            }
+            Self::CustomXmlInsRangeStart(it0) => {
+                it0.visit_element("w:customXmlInsRangeStart", v);
+            }
+            Self::CustomXmlInsRangeEnd(it0) => {
+                it0.visit_element("w:customXmlInsRangeEnd", v);
+            }
+            Self::CustomXmlDelRangeStart(it0) => {
+                it0.visit_element("w:customXmlDelRangeStart", v);
+            }
+            Self::CustomXmlDelRangeEnd(it0) => {
+                it0.visit_element("w:customXmlDelRangeEnd", v);
+            }
+            Self::CustomXmlMoveFromRangeStart(it0) => {
+                it0.visit_element("w:customXmlMoveFromRangeStart", v);
+            }
+            Self::CustomXmlMoveFromRangeEnd(it0) => {
+                it0.visit_element("w:customXmlMoveFromRangeEnd", v);
+            }
+            Self::CustomXmlMoveToRangeStart(it0) => {
+                it0.visit_element("w:customXmlMoveToRangeStart", v);
+            }
+            Self::CustomXmlMoveToRangeEnd(it0) => {
+                it0.visit_element("w:customXmlMoveToRangeEnd", v);
+            }
+            Self::Ins(it0) => {
+                it0.visit_element("w:ins", v);
+            }
+            Self::Del(it0) => {
+                it0.visit_element("w:del", v);
+            }
+            Self::MoveFrom(it0) => {
+                it0.visit_element("w:moveFrom", v);
+            }
+            Self::MoveTo(it0) => {
+                it0.visit_element("w:moveTo", v);
+            }
+        }
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::Tr(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXml(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Sdt(it0) => {
+                it0.visit_content(v);
+            }
+            Self::ProofErr(it0) => {
+                it0.visit_content(v);
+            }
+            Self::PermStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::PermEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::BookmarkStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::BookmarkEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFromRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFromRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveToRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveToRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CommentRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CommentRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlInsRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlInsRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlDelRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlDelRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveFromRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveFromRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveToRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveToRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Ins(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Del(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFrom(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveTo(it0) => {
+                it0.visit_content(v);
+            }
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RubyContent {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::R(it0) => {
+                it0.visit_element("w:r", v);
+            }
+            Self::ProofErr(it0) => {
+                it0.visit_element("w:proofErr", v);
+            }
+            Self::PermStart(it0) => {
+                it0.visit_element("w:permStart", v);
+            }
+            Self::PermEnd(it0) => {
+                it0.visit_element("w:permEnd", v);
+            }
+            Self::BookmarkStart(it0) => {
+                it0.visit_element("w:bookmarkStart", v);
+            }
+            Self::BookmarkEnd(it0) => {
+                it0.visit_element("w:bookmarkEnd", v);
+            }
+            Self::MoveFromRangeStart(it0) => {
+                it0.visit_element("w:moveFromRangeStart", v);
+            }
+            Self::MoveFromRangeEnd(it0) => {
+                it0.visit_element("w:moveFromRangeEnd", v);
+            }
+            Self::MoveToRangeStart(it0) => {
+                it0.visit_element("w:moveToRangeStart", v);
+            }
+            Self::MoveToRangeEnd(it0) => {
+                it0.visit_element("w:moveToRangeEnd", v);
+            }
+            Self::CommentRangeStart(it0) => {
+                it0.visit_element("w:commentRangeStart", v);
+            }
+            Self::CommentRangeEnd(it0) => {
+                it0.visit_element("w:commentRangeEnd", v);
+            }
+            Self::CustomXmlInsRangeStart(it0) => {
+                it0.visit_element("w:customXmlInsRangeStart", v);
+            }
+            Self::CustomXmlInsRangeEnd(it0) => {
+                it0.visit_element("w:customXmlInsRangeEnd", v);
+            }
+            Self::CustomXmlDelRangeStart(it0) => {
+                it0.visit_element("w:customXmlDelRangeStart", v);
+            }
+            Self::CustomXmlDelRangeEnd(it0) => {
+                it0.visit_element("w:customXmlDelRangeEnd", v);
+            }
+            Self::CustomXmlMoveFromRangeStart(it0) => {
+                it0.visit_element("w:customXmlMoveFromRangeStart", v);
+            }
+            Self::CustomXmlMoveFromRangeEnd(it0) => {
+                it0.visit_element("w:customXmlMoveFromRangeEnd", v);
+            }
+            Self::CustomXmlMoveToRangeStart(it0) => {
+                it0.visit_element("w:customXmlMoveToRangeStart", v);
+            }
+            Self::CustomXmlMoveToRangeEnd(it0) => {
+                it0.visit_element("w:customXmlMoveToRangeEnd", v);
+            }
+            Self::Ins(it0) => {
+                it0.visit_element("w:ins", v);
+            }
+            Self::Del(it0) => {
+                it0.visit_element("w:del", v);
+            }
+            Self::MoveFrom(it0) => {
+                it0.visit_element("w:moveFrom", v);
+            }
+            Self::MoveTo(it0) => {
+                it0.visit_element("w:moveTo", v);
+            }
+        }
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::R(it0) => {
+                it0.visit_content(v);
+            }
+            Self::ProofErr(it0) => {
+                it0.visit_content(v);
+            }
+            Self::PermStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::PermEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::BookmarkStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::BookmarkEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFromRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFromRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveToRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveToRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CommentRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CommentRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlInsRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlInsRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlDelRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlDelRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveFromRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveFromRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveToRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveToRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Ins(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Del(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFrom(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveTo(it0) => {
+                it0.visit_content(v);
+            }
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Run {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.r_pr {
+                it0.visit_element("w:rPr", v);
+            }
+        }
+        for it0 in &self.run_content {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RunContent {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::Br(it0) => {
+                it0.visit_element("w:br", v);
+            }
+            Self::T(it0) => {
+                it0.visit_element("w:t", v);
+            }
+            Self::ContentPart(it0) => {
+                it0.visit_element("w:contentPart", v);
+            }
+            Self::DelText(it0) => {
+                it0.visit_element("w:delText", v);
+            }
+            Self::InstrText(it0) => {
+                it0.visit_element("w:instrText", v);
+            }
+            Self::DelInstrText(it0) => {
+                it0.visit_element("w:delInstrText", v);
+            }
+            Self::NoBreakHyphen(it0) => {
+                it0.visit_element("w:noBreakHyphen", v);
+            }
+            Self::SoftHyphen(it0) => {
+                it0.visit_element("w:softHyphen", v);
+            }
+            Self::DayShort(it0) => {
+                it0.visit_element("w:dayShort", v);
+            }
+            Self::MonthShort(it0) => {
+                it0.visit_element("w:monthShort", v);
+            }
+            Self::YearShort(it0) => {
+                it0.visit_element("w:yearShort", v);
+            }
+            Self::DayLong(it0) => {
+                it0.visit_element("w:dayLong", v);
+            }
+            Self::MonthLong(it0) => {
+                it0.visit_element("w:monthLong", v);
+            }
+            Self::YearLong(it0) => {
+                it0.visit_element("w:yearLong", v);
+            }
+            Self::AnnotationRef(it0) => {
+                it0.visit_element("w:annotationRef", v);
+            }
+            Self::FootnoteRef(it0) => {
+                it0.visit_element("w:footnoteRef", v);
+            }
+            Self::EndnoteRef(it0) => {
+                it0.visit_element("w:endnoteRef", v);
+            }
+            Self::Separator(it0) => {
+                it0.visit_element("w:separator", v);
+            }
+            Self::ContinuationSeparator(it0) => {
+                it0.visit_element("w:continuationSeparator", v);
+            }
+            Self::Sym(it0) => {
+                it0.visit_element("w:sym", v);
+            }
+            Self::PgNum(it0) => {
+                it0.visit_element("w:pgNum", v);
+            }
+            Self::Cr(it0) => {
+                it0.visit_element("w:cr", v);
+            }
+            Self::Tab(it0) => {
+                it0.visit_element("w:tab", v);
+            }
+            Self::Object(it0) => {
+                it0.visit_element("w:object", v);
+            }
+            Self::Pict(it0) => {
+                it0.visit_element("w:pict", v);
+            }
+            Self::FldChar(it0) => {
+                it0.visit_element("w:fldChar", v);
+            }
+            Self::Ruby(it0) => {
+                it0.visit_element("w:ruby", v);
+            }
+            Self::FootnoteReference(it0) => {
+                it0.visit_element("w:footnoteReference", v);
+            }
+            Self::EndnoteReference(it0) => {
+                it0.visit_element("w:endnoteReference", v);
+            }
+            Self::CommentReference(it0) => {
+                it0.visit_element("w:commentReference", v);
+            }
+            Self::Drawing(it0) => {
+                it0.visit_element("w:drawing", v);
+            }
+            Self::Ptab(it0) => {
+                it0.visit_element("w:ptab", v);
+            }
+            Self::LastRenderedPageBreak(it0) => {
+                it0.visit_element("w:lastRenderedPageBreak", v);
+            }
+        }
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::Br(it0) => {
+                it0.visit_content(v);
+            }
+            Self::T(it0) => {
+                it0.visit_content(v);
+            }
+            Self::ContentPart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::DelText(it0) => {
+                it0.visit_content(v);
+            }
+            Self::InstrText(it0) => {
+                it0.visit_content(v);
+            }
+            Self::DelInstrText(it0) => {
+                it0.visit_content(v);
+            }
+            Self::NoBreakHyphen(it0) => {
+                it0.visit_content(v);
+            }
+            Self::SoftHyphen(it0) => {
+                it0.visit_content(v);
+            }
+            Self::DayShort(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MonthShort(it0) => {
+                it0.visit_content(v);
+            }
+            Self::YearShort(it0) => {
+                it0.visit_content(v);
+            }
+            Self::DayLong(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MonthLong(it0) => {
+                it0.visit_content(v);
+            }
+            Self::YearLong(it0) => {
+                it0.visit_content(v);
+            }
+            Self::AnnotationRef(it0) => {
+                it0.visit_content(v);
+            }
+            Self::FootnoteRef(it0) => {
+                it0.visit_content(v);
+            }
+            Self::EndnoteRef(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Separator(it0) => {
+                it0.visit_content(v);
+            }
+            Self::ContinuationSeparator(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Sym(it0) => {
+                it0.visit_content(v);
+            }
+            Self::PgNum(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Cr(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Tab(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Object(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Pict(it0) => {
+                it0.visit_content(v);
+            }
+            Self::FldChar(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Ruby(it0) => {
+                it0.visit_content(v);
+            }
+            Self::FootnoteReference(it0) => {
+                it0.visit_content(v);
+            }
+            Self::EndnoteReference(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CommentReference(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Drawing(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Ptab(it0) => {
+                it0.visit_content(v);
+            }
+            Self::LastRenderedPageBreak(it0) => {
+                it0.visit_content(v);
+            }
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RunContentBase {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::SmartTag(it0) => {
+                it0.visit_element("w:smartTag", v);
+            }
+            Self::Sdt(it0) => {
+                it0.visit_element("w:sdt", v);
+            }
+            Self::ProofErr(it0) => {
+                it0.visit_element("w:proofErr", v);
+            }
+            Self::PermStart(it0) => {
+                it0.visit_element("w:permStart", v);
+            }
+            Self::PermEnd(it0) => {
+                it0.visit_element("w:permEnd", v);
+            }
+            Self::BookmarkStart(it0) => {
+                it0.visit_element("w:bookmarkStart", v);
+            }
+            Self::BookmarkEnd(it0) => {
+                it0.visit_element("w:bookmarkEnd", v);
+            }
+            Self::MoveFromRangeStart(it0) => {
+                it0.visit_element("w:moveFromRangeStart", v);
+            }
+            Self::MoveFromRangeEnd(it0) => {
+                it0.visit_element("w:moveFromRangeEnd", v);
+            }
+            Self::MoveToRangeStart(it0) => {
+                it0.visit_element("w:moveToRangeStart", v);
+            }
+            Self::MoveToRangeEnd(it0) => {
+                it0.visit_element("w:moveToRangeEnd", v);
+            }
+            Self::CommentRangeStart(it0) => {
+                it0.visit_element("w:commentRangeStart", v);
+            }
+            Self::CommentRangeEnd(it0) => {
+                it0.visit_element("w:commentRangeEnd", v);
+            }
+            Self::CustomXmlInsRangeStart(it0) => {
+                it0.visit_element("w:customXmlInsRangeStart", v);
+            }
+            Self::CustomXmlInsRangeEnd(it0) => {
+                it0.visit_element("w:customXmlInsRangeEnd", v);
+            }
+            Self::CustomXmlDelRangeStart(it0) => {
+                it0.visit_element("w:customXmlDelRangeStart", v);
+            }
+            Self::CustomXmlDelRangeEnd(it0) => {
+                it0.visit_element("w:customXmlDelRangeEnd", v);
+            }
+            Self::CustomXmlMoveFromRangeStart(it0) => {
+                it0.visit_element("w:customXmlMoveFromRangeStart", v);
+            }
+            Self::CustomXmlMoveFromRangeEnd(it0) => {
+                it0.visit_element("w:customXmlMoveFromRangeEnd", v);
+            }
+            Self::CustomXmlMoveToRangeStart(it0) => {
+                it0.visit_element("w:customXmlMoveToRangeStart", v);
+            }
+            Self::CustomXmlMoveToRangeEnd(it0) => {
+                it0.visit_element("w:customXmlMoveToRangeEnd", v);
+            }
+            Self::Ins(it0) => {
+                it0.visit_element("w:ins", v);
+            }
+            Self::Del(it0) => {
+                it0.visit_element("w:del", v);
+            }
+            Self::MoveFrom(it0) => {
+                it0.visit_element("w:moveFrom", v);
+            }
+            Self::MoveTo(it0) => {
+                it0.visit_element("w:moveTo", v);
+            }
+        }
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::SmartTag(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Sdt(it0) => {
+                it0.visit_content(v);
+            }
+            Self::ProofErr(it0) => {
+                it0.visit_content(v);
+            }
+            Self::PermStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::PermEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::BookmarkStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::BookmarkEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFromRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFromRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveToRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveToRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CommentRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CommentRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlInsRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlInsRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlDelRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlDelRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveFromRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveFromRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveToRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveToRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Ins(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Del(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFrom(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveTo(it0) => {
+                it0.visit_content(v);
+            }
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RunContentChoice {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::CustomXml(it0) => {
+                it0.visit_element("w:customXml", v);
+            }
+            Self::SmartTag(it0) => {
+                it0.visit_element("w:smartTag", v);
+            }
+            Self::Sdt(it0) => {
+                it0.visit_element("w:sdt", v);
+            }
+            Self::Dir(it0) => {
+                it0.visit_element("w:dir", v);
+            }
+            Self::Bdo(it0) => {
+                it0.visit_element("w:bdo", v);
+            }
+            Self::R(it0) => {
+                it0.visit_element("w:r", v);
+            }
+            Self::ProofErr(it0) => {
+                it0.visit_element("w:proofErr", v);
+            }
+            Self::PermStart(it0) => {
+                it0.visit_element("w:permStart", v);
+            }
+            Self::PermEnd(it0) => {
+                it0.visit_element("w:permEnd", v);
+            }
+            Self::BookmarkStart(it0) => {
+                it0.visit_element("w:bookmarkStart", v);
+            }
+            Self::BookmarkEnd(it0) => {
+                it0.visit_element("w:bookmarkEnd", v);
+            }
+            Self::MoveFromRangeStart(it0) => {
+                it0.visit_element("w:moveFromRangeStart", v);
+            }
+            Self::MoveFromRangeEnd(it0) => {
+                it0.visit_element("w:moveFromRangeEnd", v);
+            }
+            Self::MoveToRangeStart(it0) => {
+                it0.visit_element("w:moveToRangeStart", v);
+            }
+            Self::MoveToRangeEnd(it0) => {
+                it0.visit_element("w:moveToRangeEnd", v);
+            }
+            Self::CommentRangeStart(it0) => {
+                it0.visit_element("w:commentRangeStart", v);
+            }
+            Self::CommentRangeEnd(it0) => {
+                it0.visit_element("w:commentRangeEnd", v);
+            }
+            Self::CustomXmlInsRangeStart(it0) => {
+                it0.visit_element("w:customXmlInsRangeStart", v);
+            }
+            Self::CustomXmlInsRangeEnd(it0) => {
+                it0.visit_element("w:customXmlInsRangeEnd", v);
+            }
+            Self::CustomXmlDelRangeStart(it0) => {
+                it0.visit_element("w:customXmlDelRangeStart", v);
+            }
+            Self::CustomXmlDelRangeEnd(it0) => {
+                it0.visit_element("w:customXmlDelRangeEnd", v);
+            }
+            Self::CustomXmlMoveFromRangeStart(it0) => {
+                it0.visit_element("w:customXmlMoveFromRangeStart", v);
+            }
+            Self::CustomXmlMoveFromRangeEnd(it0) => {
+                it0.visit_element("w:customXmlMoveFromRangeEnd", v);
+            }
+            Self::CustomXmlMoveToRangeStart(it0) => {
+                it0.visit_element("w:customXmlMoveToRangeStart", v);
+            }
+            Self::CustomXmlMoveToRangeEnd(it0) => {
+                it0.visit_element("w:customXmlMoveToRangeEnd", v);
+            }
+            Self::Ins(it0) => {
+                it0.visit_element("w:ins", v);
+            }
+            Self::Del(it0) => {
+                it0.visit_element("w:del", v);
+            }
+            Self::MoveFrom(it0) => {
+                it0.visit_element("w:moveFrom", v);
+            }
+            Self::MoveTo(it0) => {
+                it0.visit_element("w:moveTo", v);
+            }
+        }
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::CustomXml(it0) => {
+                it0.visit_content(v);
+            }
+            Self::SmartTag(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Sdt(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Dir(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Bdo(it0) => {
+                it0.visit_content(v);
+            }
+            Self::R(it0) => {
+                it0.visit_content(v);
+            }
+            Self::ProofErr(it0) => {
+                it0.visit_content(v);
+            }
+            Self::PermStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::PermEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::BookmarkStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::BookmarkEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFromRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFromRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveToRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveToRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CommentRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CommentRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlInsRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlInsRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlDelRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlDelRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveFromRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveFromRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveToRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveToRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Ins(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Del(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFrom(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveTo(it0) => {
+                it0.visit_content(v);
+            }
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RunLevelContent {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::ProofErr(it0) => {
+                it0.visit_element("w:proofErr", v);
+            }
+            Self::PermStart(it0) => {
+                it0.visit_element("w:permStart", v);
+            }
+            Self::PermEnd(it0) => {
+                it0.visit_element("w:permEnd", v);
+            }
+            Self::BookmarkStart(it0) => {
+                it0.visit_element("w:bookmarkStart", v);
+            }
+            Self::BookmarkEnd(it0) => {
+                it0.visit_element("w:bookmarkEnd", v);
+            }
+            Self::MoveFromRangeStart(it0) => {
+                it0.visit_element("w:moveFromRangeStart", v);
+            }
+            Self::MoveFromRangeEnd(it0) => {
+                it0.visit_element("w:moveFromRangeEnd", v);
+            }
+            Self::MoveToRangeStart(it0) => {
+                it0.visit_element("w:moveToRangeStart", v);
+            }
+            Self::MoveToRangeEnd(it0) => {
+                it0.visit_element("w:moveToRangeEnd", v);
+            }
+            Self::CommentRangeStart(it0) => {
+                it0.visit_element("w:commentRangeStart", v);
+            }
+            Self::CommentRangeEnd(it0) => {
+                it0.visit_element("w:commentRangeEnd", v);
+            }
+            Self::CustomXmlInsRangeStart(it0) => {
+                it0.visit_element("w:customXmlInsRangeStart", v);
+            }
+            Self::CustomXmlInsRangeEnd(it0) => {
+                it0.visit_element("w:customXmlInsRangeEnd", v);
+            }
+            Self::CustomXmlDelRangeStart(it0) => {
+                it0.visit_element("w:customXmlDelRangeStart", v);
+            }
+            Self::CustomXmlDelRangeEnd(it0) => {
+                it0.visit_element("w:customXmlDelRangeEnd", v);
+            }
+            Self::CustomXmlMoveFromRangeStart(it0) => {
+                it0.visit_element("w:customXmlMoveFromRangeStart", v);
+            }
+            Self::CustomXmlMoveFromRangeEnd(it0) => {
+                it0.visit_element("w:customXmlMoveFromRangeEnd", v);
+            }
+            Self::CustomXmlMoveToRangeStart(it0) => {
+                it0.visit_element("w:customXmlMoveToRangeStart", v);
+            }
+            Self::CustomXmlMoveToRangeEnd(it0) => {
+                it0.visit_element("w:customXmlMoveToRangeEnd", v);
+            }
+            Self::Ins(it0) => {
+                it0.visit_element("w:ins", v);
+            }
+            Self::Del(it0) => {
+                it0.visit_element("w:del", v);
+            }
+            Self::MoveFrom(it0) => {
+                it0.visit_element("w:moveFrom", v);
+            }
+            Self::MoveTo(it0) => {
+                it0.visit_element("w:moveTo", v);
+            }
+        }
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        match self {
+            Self::ProofErr(it0) => {
+                it0.visit_content(v);
+            }
+            Self::PermStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::PermEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::BookmarkStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::BookmarkEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFromRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFromRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveToRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveToRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CommentRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CommentRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlInsRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlInsRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlDelRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlDelRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveFromRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveFromRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveToRangeStart(it0) => {
+                it0.visit_content(v);
+            }
+            Self::CustomXmlMoveToRangeEnd(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Ins(it0) => {
+                it0.visit_content(v);
+            }
+            Self::Del(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveFrom(it0) => {
+                it0.visit_content(v);
+            }
+            Self::MoveTo(it0) => {
+                it0.visit_content(v);
+            }
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RunProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.run_style {
+            it0.visit_element("w:rStyle", v);
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.fonts {
+                it0.visit_element("w:rFonts", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.bold {
+                it0.visit_element("w:b", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.b_cs {
+                it0.visit_element("w:bCs", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.italic {
+                it0.visit_element("w:i", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.i_cs {
+                it0.visit_element("w:iCs", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.caps {
+                it0.visit_element("w:caps", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.small_caps {
+                it0.visit_element("w:smallCaps", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.strikethrough {
+                it0.visit_element("w:strike", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.dstrike {
+                it0.visit_element("w:dstrike", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.outline {
+                it0.visit_element("w:outline", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.shadow {
+                it0.visit_element("w:shadow", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.emboss {
+                it0.visit_element("w:emboss", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.imprint {
+                it0.visit_element("w:imprint", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.no_proof {
+                it0.visit_element("w:noProof", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.snap_to_grid {
+                it0.visit_element("w:snapToGrid", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.vanish {
+                it0.visit_element("w:vanish", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.web_hidden {
+                it0.visit_element("w:webHidden", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.color {
+                it0.visit_element("w:color", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.spacing {
+                it0.visit_element("w:spacing", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.width {
+                it0.visit_element("w:w", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.kern {
+                it0.visit_element("w:kern", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.position {
+                it0.visit_element("w:position", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.size {
+                it0.visit_element("w:sz", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.size_complex_script {
+                it0.visit_element("w:szCs", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.highlight {
+                it0.visit_element("w:highlight", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.underline {
+                it0.visit_element("w:u", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.effect {
+                it0.visit_element("w:effect", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.bdr {
+                it0.visit_element("w:bdr", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.shading {
+                it0.visit_element("w:shd", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.fit_text {
+                it0.visit_element("w:fitText", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.vert_align {
+                it0.visit_element("w:vertAlign", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.rtl {
+                it0.visit_element("w:rtl", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.cs {
+                it0.visit_element("w:cs", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.em {
+                it0.visit_element("w:em", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.lang {
+                it0.visit_element("w:lang", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.east_asian_layout {
+                it0.visit_element("w:eastAsianLayout", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.spec_vanish {
+                it0.visit_element("w:specVanish", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.o_math {
+                it0.visit_element("w:oMath", v);
+            }
+        }
+        #[cfg(feature = "wml-track-changes")]
+        {
+            if let Some(it0) = &self.r_pr_change {
+                it0.visit_element("w:rPrChange", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for RunPropertiesDefault {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.r_pr {
+            it0.visit_element("w:rPr", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SectionProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.header_footer_refs {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "wml-comments")]
+        {
+            if let Some(it0) = &self.footnote_pr {
+                it0.visit_element("w:footnotePr", v);
+            }
+        }
+        #[cfg(feature = "wml-comments")]
+        {
+            if let Some(it0) = &self.endnote_pr {
+                it0.visit_element("w:endnotePr", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.r#type {
+                it0.visit_element("w:type", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.pg_sz {
+                it0.visit_element("w:pgSz", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.pg_mar {
+                it0.visit_element("w:pgMar", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.paper_src {
+                it0.visit_element("w:paperSrc", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.pg_borders {
+                it0.visit_element("w:pgBorders", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.ln_num_type {
+                it0.visit_element("w:lnNumType", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.pg_num_type {
+                it0.visit_element("w:pgNumType", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.cols {
+                it0.visit_element("w:cols", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.form_prot {
+                it0.visit_element("w:formProt", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.v_align {
+                it0.visit_element("w:vAlign", v);
+            }
+        }
+        #[cfg(feature = "wml-comments")]
+        {
+            if let Some(it0) = &self.no_endnote {
+                it0.visit_element("w:noEndnote", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.title_pg {
+                it0.visit_element("w:titlePg", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.text_direction {
+                it0.visit_element("w:textDirection", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.bidi {
+                it0.visit_element("w:bidi", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.rtl_gutter {
+                it0.visit_element("w:rtlGutter", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.doc_grid {
+                it0.visit_element("w:docGrid", v);
+            }
+        }
+        #[cfg(feature = "wml-layout")]
+        {
+            if let Some(it0) = &self.printer_settings {
+                it0.visit_element("w:printerSettings", v);
+            }
+        }
+        #[cfg(feature = "wml-track-changes")]
+        {
+            if let Some(it0) = &self.sect_pr_change {
+                it0.visit_element("w:sectPrChange", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Settings {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.write_protection {
+                it0.visit_element("w:writeProtection", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.view {
+                it0.visit_element("w:view", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.zoom {
+                it0.visit_element("w:zoom", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.remove_personal_information {
+                it0.visit_element("w:removePersonalInformation", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.remove_date_and_time {
+                it0.visit_element("w:removeDateAndTime", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_display_page_boundaries {
+                it0.visit_element("w:doNotDisplayPageBoundaries", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.display_background_shape {
+                it0.visit_element("w:displayBackgroundShape", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.print_post_script_over_text {
+                it0.visit_element("w:printPostScriptOverText", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.print_fractional_character_width {
+                it0.visit_element("w:printFractionalCharacterWidth", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.print_forms_data {
+                it0.visit_element("w:printFormsData", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.embed_true_type_fonts {
+                it0.visit_element("w:embedTrueTypeFonts", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.embed_system_fonts {
+                it0.visit_element("w:embedSystemFonts", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.save_subset_fonts {
+                it0.visit_element("w:saveSubsetFonts", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.save_forms_data {
+                it0.visit_element("w:saveFormsData", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.mirror_margins {
+                it0.visit_element("w:mirrorMargins", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.align_borders_and_edges {
+                it0.visit_element("w:alignBordersAndEdges", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.borders_do_not_surround_header {
+                it0.visit_element("w:bordersDoNotSurroundHeader", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.borders_do_not_surround_footer {
+                it0.visit_element("w:bordersDoNotSurroundFooter", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.gutter_at_top {
+                it0.visit_element("w:gutterAtTop", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.hide_spelling_errors {
+                it0.visit_element("w:hideSpellingErrors", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.hide_grammatical_errors {
+                it0.visit_element("w:hideGrammaticalErrors", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            for it0 in &self.active_writing_style {
+                it0.visit_element("w:activeWritingStyle", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.proof_state {
+                it0.visit_element("w:proofState", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.forms_design {
+                it0.visit_element("w:formsDesign", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.attached_template {
+                it0.visit_element("w:attachedTemplate", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.link_styles {
+                it0.visit_element("w:linkStyles", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.style_pane_format_filter {
+                it0.visit_element("w:stylePaneFormatFilter", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.style_pane_sort_method {
+                it0.visit_element("w:stylePaneSortMethod", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.document_type {
+                it0.visit_element("w:documentType", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.mail_merge {
+                it0.visit_element("w:mailMerge", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.revision_view {
+                it0.visit_element("w:revisionView", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.track_revisions {
+                it0.visit_element("w:trackRevisions", v);
+            }
+        }
+        #[cfg(feature = "wml-track-changes")]
+        {
+            if let Some(it0) = &self.do_not_track_moves {
+                it0.visit_element("w:doNotTrackMoves", v);
+            }
+        }
+        #[cfg(feature = "wml-track-changes")]
+        {
+            if let Some(it0) = &self.do_not_track_formatting {
+                it0.visit_element("w:doNotTrackFormatting", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.document_protection {
+                it0.visit_element("w:documentProtection", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.auto_format_override {
+                it0.visit_element("w:autoFormatOverride", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.style_lock_theme {
+                it0.visit_element("w:styleLockTheme", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.style_lock_q_f_set {
+                it0.visit_element("w:styleLockQFSet", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.default_tab_stop {
+                it0.visit_element("w:defaultTabStop", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.auto_hyphenation {
+                it0.visit_element("w:autoHyphenation", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.consecutive_hyphen_limit {
+                it0.visit_element("w:consecutiveHyphenLimit", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.hyphenation_zone {
+                it0.visit_element("w:hyphenationZone", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_hyphenate_caps {
+                it0.visit_element("w:doNotHyphenateCaps", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.show_envelope {
+                it0.visit_element("w:showEnvelope", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.summary_length {
+                it0.visit_element("w:summaryLength", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.click_and_type_style {
+                it0.visit_element("w:clickAndTypeStyle", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.default_table_style {
+                it0.visit_element("w:defaultTableStyle", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.even_and_odd_headers {
+                it0.visit_element("w:evenAndOddHeaders", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.book_fold_rev_printing {
+                it0.visit_element("w:bookFoldRevPrinting", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.book_fold_printing {
+                it0.visit_element("w:bookFoldPrinting", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.book_fold_printing_sheets {
+                it0.visit_element("w:bookFoldPrintingSheets", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.drawing_grid_horizontal_spacing {
+                it0.visit_element("w:drawingGridHorizontalSpacing", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.drawing_grid_vertical_spacing {
+                it0.visit_element("w:drawingGridVerticalSpacing", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.display_horizontal_drawing_grid_every {
+                it0.visit_element("w:displayHorizontalDrawingGridEvery", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.display_vertical_drawing_grid_every {
+                it0.visit_element("w:displayVerticalDrawingGridEvery", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_use_margins_for_drawing_grid_origin {
+                it0.visit_element("w:doNotUseMarginsForDrawingGridOrigin", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.drawing_grid_horizontal_origin {
+                it0.visit_element("w:drawingGridHorizontalOrigin", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.drawing_grid_vertical_origin {
+                it0.visit_element("w:drawingGridVerticalOrigin", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_shade_form_data {
+                it0.visit_element("w:doNotShadeFormData", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.no_punctuation_kerning {
+                it0.visit_element("w:noPunctuationKerning", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.character_spacing_control {
+                it0.visit_element("w:characterSpacingControl", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.print_two_on_one {
+                it0.visit_element("w:printTwoOnOne", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.strict_first_and_last_chars {
+                it0.visit_element("w:strictFirstAndLastChars", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.no_line_breaks_after {
+                it0.visit_element("w:noLineBreaksAfter", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.no_line_breaks_before {
+                it0.visit_element("w:noLineBreaksBefore", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.save_preview_picture {
+                it0.visit_element("w:savePreviewPicture", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_validate_against_schema {
+                it0.visit_element("w:doNotValidateAgainstSchema", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.save_invalid_xml {
+                it0.visit_element("w:saveInvalidXml", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.ignore_mixed_content {
+                it0.visit_element("w:ignoreMixedContent", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.always_show_placeholder_text {
+                it0.visit_element("w:alwaysShowPlaceholderText", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_demarcate_invalid_xml {
+                it0.visit_element("w:doNotDemarcateInvalidXml", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.save_xml_data_only {
+                it0.visit_element("w:saveXmlDataOnly", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.use_x_s_l_t_when_saving {
+                it0.visit_element("w:useXSLTWhenSaving", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.save_through_xslt {
+                it0.visit_element("w:saveThroughXslt", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.show_x_m_l_tags {
+                it0.visit_element("w:showXMLTags", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.always_merge_empty_namespace {
+                it0.visit_element("w:alwaysMergeEmptyNamespace", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.update_fields {
+                it0.visit_element("w:updateFields", v);
+            }
+        }
+        #[cfg(feature = "wml-drawings")]
+        {
+            if let Some(it0) = &self.hdr_shape_defaults {
+                it0.visit_element("w:hdrShapeDefaults", v);
+            }
+        }
+        #[cfg(feature = "wml-comments")]
+        {
+            if let Some(it0) = &self.footnote_pr {
+                it0.visit_element("w:footnotePr", v);
+            }
+        }
+        #[cfg(feature = "wml-comments")]
+        {
+            if let Some(it0) = &self.endnote_pr {
+                it0.visit_element("w:endnotePr", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.compat {
+                it0.visit_element("w:compat", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.doc_vars {
+                it0.visit_element("w:docVars", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.rsids {
+                it0.visit_element("w:rsids", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            for it0 in &self.attached_schema {
+                it0.visit_element("w:attachedSchema", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.theme_font_lang {
+                it0.visit_element("w:themeFontLang", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.clr_scheme_mapping {
+                it0.visit_element("w:clrSchemeMapping", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_include_subdocs_in_stats {
+                it0.visit_element("w:doNotIncludeSubdocsInStats", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_auto_compress_pictures {
+                it0.visit_element("w:doNotAutoCompressPictures", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.force_upgrade {
+                it0.visit_element("w:forceUpgrade", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.captions {
+                it0.visit_element("w:captions", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.read_mode_ink_lock_down {
+                it0.visit_element("w:readModeInkLockDown", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            for it0 in &self.smart_tag_type {
+                it0.visit_element("w:smartTagType", v);
+            }
+        }
+        #[cfg(feature = "wml-drawings")]
+        {
+            if let Some(it0) = &self.shape_defaults {
+                it0.visit_element("w:shapeDefaults", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.do_not_embed_smart_tags {
+                it0.visit_element("w:doNotEmbedSmartTags", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.decimal_symbol {
+                it0.visit_element("w:decimalSymbol", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.list_separator {
+                it0.visit_element("w:listSeparator", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SignedHpsMeasureElement {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for SignedTwipsMeasureElement {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Style {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.name {
+            it0.visit_element("w:name", v);
+        }
+        if let Some(it0) = &self.aliases {
+            it0.visit_element("w:aliases", v);
+        }
+        if let Some(it0) = &self.based_on {
+            it0.visit_element("w:basedOn", v);
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.next {
+                it0.visit_element("w:next", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.link {
+                it0.visit_element("w:link", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.auto_redefine {
+                it0.visit_element("w:autoRedefine", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.hidden {
+                it0.visit_element("w:hidden", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.ui_priority {
+                it0.visit_element("w:uiPriority", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.semi_hidden {
+                it0.visit_element("w:semiHidden", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.unhide_when_used {
+                it0.visit_element("w:unhideWhenUsed", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.q_format {
+                it0.visit_element("w:qFormat", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.locked {
+                it0.visit_element("w:locked", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.personal {
+                it0.visit_element("w:personal", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.personal_compose {
+                it0.visit_element("w:personalCompose", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.personal_reply {
+                it0.visit_element("w:personalReply", v);
+            }
+        }
+        #[cfg(feature = "wml-track-changes")]
+        {
+            if let Some(it0) = &self.rsid {
+                it0.visit_element("w:rsid", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.p_pr {
+                it0.visit_element("w:pPr", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.r_pr {
+                it0.visit_element("w:rPr", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.table_properties {
+                it0.visit_element("w:tblPr", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.row_properties {
+                it0.visit_element("w:trPr", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.cell_properties {
+                it0.visit_element("w:tcPr", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            for it0 in &self.tbl_style_pr {
+                it0.visit_element("w:tblStylePr", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Styles {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.doc_defaults {
+            it0.visit_element("w:docDefaults", v);
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.latent_styles {
+                it0.visit_element("w:latentStyles", v);
+            }
+        }
+        for it0 in &self.style {
+            it0.visit_element("w:style", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Table {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.range_markup {
+            it0.visit_element("", v);
+        }
+        self.table_properties.visit_element("w:tblPr", v);
+        self.tbl_grid.visit_element("w:tblGrid", v);
+        for it0 in &self.rows {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for TableCell {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.cell_properties {
+                it0.visit_element("w:tcPr", v);
+            }
+        }
+        for it0 in &self.block_content {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for TableCellProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.cnf_style {
+                it0.visit_element("w:cnfStyle", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tc_w {
+                it0.visit_element("w:tcW", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.grid_span {
+                it0.visit_element("w:gridSpan", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.horizontal_merge {
+                it0.visit_element("w:hMerge", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.vertical_merge {
+                it0.visit_element("w:vMerge", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tc_borders {
+                it0.visit_element("w:tcBorders", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.shading {
+                it0.visit_element("w:shd", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.no_wrap {
+                it0.visit_element("w:noWrap", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tc_mar {
+                it0.visit_element("w:tcMar", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.text_direction {
+                it0.visit_element("w:textDirection", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tc_fit_text {
+                it0.visit_element("w:tcFitText", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.v_align {
+                it0.visit_element("w:vAlign", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.hide_mark {
+                it0.visit_element("w:hideMark", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.headers {
+                it0.visit_element("w:headers", v);
+            }
+        }
+        if let Some(it0) = &self.cell_markup {
+            it0.visit_element("", v);
+        }
+        #[cfg(feature = "wml-track-changes")]
+        {
+            if let Some(it0) = &self.tc_pr_change {
+                it0.visit_element("w:tcPrChange", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for TableGrid {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        for it0 in &self.grid_col {
+            it0.visit_element("w:gridCol", v);
+        }
+        #[cfg(feature = "wml-track-changes")]
+        {
+            if let Some(it0) = &self.tbl_grid_change {
+                it0.visit_element("w:tblGridChange", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for TableGridColumn {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for TableProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.tbl_style {
+                it0.visit_element("w:tblStyle", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tblp_pr {
+                it0.visit_element("w:tblpPr", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_overlap {
+                it0.visit_element("w:tblOverlap", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.bidi_visual {
+                it0.visit_element("w:bidiVisual", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.tbl_style_row_band_size {
+                it0.visit_element("w:tblStyleRowBandSize", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.tbl_style_col_band_size {
+                it0.visit_element("w:tblStyleColBandSize", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_w {
+                it0.visit_element("w:tblW", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.justification {
+                it0.visit_element("w:jc", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_cell_spacing {
+                it0.visit_element("w:tblCellSpacing", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_ind {
+                it0.visit_element("w:tblInd", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_borders {
+                it0.visit_element("w:tblBorders", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.shading {
+                it0.visit_element("w:shd", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_layout {
+                it0.visit_element("w:tblLayout", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_cell_mar {
+                it0.visit_element("w:tblCellMar", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_look {
+                it0.visit_element("w:tblLook", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_caption {
+                it0.visit_element("w:tblCaption", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_description {
+                it0.visit_element("w:tblDescription", v);
+            }
+        }
+        #[cfg(feature = "wml-track-changes")]
+        {
+            if let Some(it0) = &self.tbl_pr_change {
+                it0.visit_element("w:tblPrChange", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for TableRowProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.cnf_style {
+                it0.visit_element("w:cnfStyle", v);
+            }
+        }
+        #[cfg(feature = "wml-settings")]
+        {
+            if let Some(it0) = &self.div_id {
+                it0.visit_element("w:divId", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.grid_before {
+                it0.visit_element("w:gridBefore", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.grid_after {
+                it0.visit_element("w:gridAfter", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.w_before {
+                it0.visit_element("w:wBefore", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.w_after {
+                it0.visit_element("w:wAfter", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.cant_split {
+                it0.visit_element("w:cantSplit", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tr_height {
+                it0.visit_element("w:trHeight", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_header {
+                it0.visit_element("w:tblHeader", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.tbl_cell_spacing {
+                it0.visit_element("w:tblCellSpacing", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.justification {
+                it0.visit_element("w:jc", v);
+            }
+        }
+        #[cfg(feature = "wml-tables")]
+        {
+            if let Some(it0) = &self.hidden {
+                it0.visit_element("w:hidden", v);
+            }
+        }
+        #[cfg(feature = "wml-track-changes")]
+        {
+            if let Some(it0) = &self.ins {
+                it0.visit_element("w:ins", v);
+            }
+        }
+        #[cfg(feature = "wml-track-changes")]
+        {
+            if let Some(it0) = &self.del {
+                it0.visit_element("w:del", v);
+            }
+        }
+        #[cfg(feature = "wml-track-changes")]
+        {
+            if let Some(it0) = &self.tr_pr_change {
+                it0.visit_element("w:trPrChange", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for TableStyleProperties {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.p_pr {
+                it0.visit_element("w:pPr", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.r_pr {
+                it0.visit_element("w:rPr", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.table_properties {
+                it0.visit_element("w:tblPr", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.row_properties {
+                it0.visit_element("w:trPr", v);
+            }
+        }
+        #[cfg(feature = "wml-styling")]
+        {
+            if let Some(it0) = &self.cell_properties {
+                it0.visit_element("w:tcPr", v);
+            }
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for Text {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        if let Some(it0) = &self.text {
+            v.text(it0);
+        }
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for TextScaleElement {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for TwipsMeasureElement {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for UnsignedDecimalNumberElement {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for WAGPassword {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for WAGSectPrAttributes {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for WAGTransitionalPassword {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for WAnyVmlOffice {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+impl ooxml_xml::QueryTree for WAnyVmlVml {
+    fn visit_element<'a>(&'a self, name: &str, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        v.element(name, self);
+    }
+    fn visit_content<'a>(&'a self, v: &mut dyn ooxml_xml::TreeVisitor<'a>) {
+        ooxml_xml::visit_attributes(self, v);
+        #[cfg(feature = "extra-children")]
+        for it0 in &self.extra_children {
+            v.raw(&it0.node);
+        }
+    }
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
@@ -0,0 +1,1077 @@
+//! HTML export.
+//!
+//! [`Document::to_html`] renders a document as semantic HTML with a
+//! stylesheet, e.g. to preview it in a browser:
+//!
+//! - Paragraphs with an outline level, set directly or by their style, become
+//!   `<h1>`–`<h6>`; other paragraphs become `<p>`.
+//! - Numbered paragraphs become items of `<ul>` (bullet levels) or `<ol>`
+//!   (other number formats) lists, nested by list level.
+//! - Tables become `<table>`, with `gridSpan` and `vMerge` as `colspan` and
+//!   `rowspan`.
+//! - Run formatting is resolved through [`StyleContext`] into `<strong>`,
+//!   `<em>`, `<u>`, `<s>`, `<sup>` and `<sub>`, with colors, highlighting,
+//!   capitals, fonts and sizes that differ from the document defaults as
+//!   inline styles. Paragraph styles become classes with rules in the
+//!   stylesheet.
+//! - Hyperlinks link to their relationship target or bookmark.
+//! - Images are embedded as `data:` URIs or referenced as files, see
+//!   [`ImageMode`].
+//! - Footnotes, endnotes and comments are listed in sections after the body,
+//!   numbered in order of reference and linked both ways.
+//!
+//! Tracked changes are shown accepted. Hidden text, field codes, math, charts
+//! and text boxes are left out, as are hyperlinks and images inside notes and
+//! comments, whose relationships live in other parts.
+//!
+//! ```ignore
+//! use ooxml_wml::Document;
+//! use ooxml_wml::html::HtmlOptions;
+//!
+//! let mut doc = Document::open("input.docx")?;
+//! let export = doc.to_html(&HtmlOptions::default())?;
+//! std::fs::write("output.html", export.html)?;
+//! ```
+
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write as _;
+use std::io::{Read, Seek};
+
+use base64::Engine;
+use ooxml_opc::Relationships;
+
+use crate::document::{Document, ImageData};
+use crate::error::Result;
+use crate::export::{
+    self, Block, DRAWING_DESCRIPTION, DRAWING_HEIGHT, DRAWING_WIDTH, drawing_value, heading_level,
+    optional_part, reference_number, row_span, symbol_char, table_grid,
+};
+use crate::ext::{DrawingExt, NumberingContext, RunResolveExt, StyleContext, check_toggle};
+use crate::types;
+
+/// Options for [`Document::to_html`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HtmlOptions {
+    /// Write a complete HTML document with the stylesheet in its `<head>`,
+    /// rather than the contents of `<body>` for a page that includes
+    /// [`HtmlExport::css`] itself.
+    pub standalone: bool,
+    /// How images are included.
+    pub images: ImageMode,
+    /// List footnotes and endnotes after the body.
+    pub notes: bool,
+    /// List comments after the body.
+    pub comments: bool,
+}
+
+impl Default for HtmlOptions {
+    fn default() -> Self {
+        Self {
+            standalone: true,
+            images: ImageMode::DataUri,
+            notes: true,
+            comments: true,
+        }
+    }
+}
+
+impl HtmlOptions {
+    /// A complete HTML document with embedded images (the default).
+    pub fn standalone() -> Self {
+        Self::default()
+    }
+
+    /// The contents of `<body>`, to be placed in a page along with
+    /// [`HtmlExport::css`].
+    pub fn fragment() -> Self {
+        Self {
+            standalone: false,
+            ..Self::default()
+        }
+    }
+}
+
+/// How [`Document::to_html`] includes images.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ImageMode {
+    /// Embed images in the HTML as `data:` URIs.
+    #[default]
+    DataUri,
+    /// Reference images by their file name in the package, after `prefix`
+    /// (e.g. `"images/"`), and return them in [`HtmlExport::images`] to be
+    /// written alongside the HTML. Names are reduced to safe characters and
+    /// numbered when different images share one.
+    Files {
+        /// Prepended to file names in `src` attributes.
+        prefix: String,
+    },
+    /// Leave images out.
+    Omit,
+}
+
+/// A document rendered by [`Document::to_html`].
+#[derive(Debug, Clone, Default)]
+pub struct HtmlExport {
+    /// The HTML: a complete document if [`HtmlOptions::standalone`] is set,
+    /// otherwise the contents of `<body>`.
+    pub html: String,
+    /// The stylesheet, also included in `html` when it is standalone.
+    pub css: String,
+    /// Images referenced as files, with [`ImageMode::Files`].
+    pub images: Vec<ExportedImage>,
+}
+
+/// An image referenced as a file by exported HTML.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportedImage {
+    /// File name, as referenced after [`ImageMode::Files`]'s prefix.
+    pub name: String,
+    /// MIME content type (e.g., "image/png").
+    pub content_type: String,
+    /// Raw image bytes.
+    pub data: Vec<u8>,
+}
+
+impl<R: Read + Seek> Document<R> {
+    /// Render the document as HTML.
+    ///
+    /// Reads the numbering, footnotes, endnotes and comments parts as needed;
+    /// a document without them renders without lists or notes. See the
+    /// [`html`](crate::html) module for what is rendered.
+    pub fn to_html(&mut self, options: &HtmlOptions) -> Result<HtmlExport> {
+        let numbering = self.numbering_context()?;
+        let footnotes = match options.notes {
+            true => optional_part(self.get_footnotes())?,
+            false => None,
+        };
+        let endnotes = match options.notes {
+            true => optional_part(self.get_endnotes())?,
+            false => None,
+        };
+        let comments = match options.comments {
+            true => optional_part(self.get_comments())?,
+            false => None,
+        };
+        let styles = StyleContext::from_styles(self.styles());
+        let title = self.core_properties().and_then(|props| props.title.clone());
+
+        let (body, rels, mut load_image) = self.body_with_images();
+        let mut renderer = Renderer::new(options, styles, numbering, rels, &mut load_image);
+        renderer.blocks(&body.block_content);
+        renderer.close_lists();
+        if let Some(footnotes) = &footnotes {
+            renderer.notes(NoteKind::Footnote, &footnotes.footnote);
+        }
+        if let Some(endnotes) = &endnotes {
+            renderer.notes(NoteKind::Endnote, &endnotes.endnote);
+        }
+        if let Some(comments) = &comments {
+            renderer.comments(&comments.comment);
+        }
+
+        let css = renderer.css();
+        let html = if options.standalone {
+            let mut html =
+                String::from("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+            if let Some(title) = title.filter(|title| !title.is_empty()) {
+                html.push_str("<title>");
+                escape_into(&mut html, &title);
+                html.push_str("</title>\n");
+            }
+            let _ = write!(
+                html,
+                "<style>\n{css}</style>\n</head>\n<body>\n{}</body>\n</html>\n",
+                renderer.out
+            );
+            html
+        } else {
+            renderer.out
+        };
+        Ok(HtmlExport {
+            html,
+            css,
+            images: renderer.exported,
+        })
+    }
+}
+
+/// Rules applied whatever the document.
+const BASE_CSS: &str = "\
+p:empty { min-height: 1em; }
+table { border-collapse: collapse; }
+td { border: 1px solid #bfbfbf; padding: 0.2em 0.4em; vertical-align: top; }
+td > p { margin: 0; }
+.tab { white-space: pre; }
+.footnotes, .endnotes, .comments { border-top: 1px solid #bfbfbf; margin-top: 2em; font-size: 0.9em; }
+.comment-author { font-weight: bold; margin-bottom: 0; }
+";
+
+/// EMUs per CSS pixel (914400 per inch, 96 pixels per inch).
+const EMU_PER_PX: i64 = 9525;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NoteKind {
+    Footnote,
+    Endnote,
+}
+
+impl NoteKind {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Footnote => "footnote",
+            Self::Endnote => "endnote",
+        }
+    }
+}
+
+/// A list opened for a numbering level, with its last item still open.
+struct OpenList {
+    num_id: i64,
+    ilvl: i64,
+    tag: &'static str,
+}
+
+/// How a numbered paragraph is rendered.
+struct ListItem {
+    num_id: i64,
+    ilvl: i64,
+    tag: &'static str,
+    /// `type` attribute of an `<ol>`.
+    list_type: Option<&'static str>,
+}
+
+/// Formatting of a run, resolved through the style chain.
+struct RunFormat {
+    bold: bool,
+    italic: bool,
+    underline: bool,
+    strike: bool,
+    vertical: Option<types::STVerticalAlignRun>,
+    /// CSS declarations for formatting without an element of its own.
+    style: String,
+}
+
+struct Renderer<'a> {
+    options: &'a HtmlOptions,
+    styles: StyleContext,
+    numbering: NumberingContext,
+    rels: &'a Relationships,
+    load_image: &'a mut dyn FnMut(&str) -> Result<ImageData>,
+    out: String,
+    lists: Vec<OpenList>,
+    /// Paragraph styles used, to write rules for.
+    paragraph_styles: BTreeSet<String>,
+    /// Note and comment IDs in order of first reference.
+    footnote_refs: Vec<i64>,
+    endnote_refs: Vec<i64>,
+    comment_refs: Vec<i64>,
+    /// `src` of each image by relationship ID, or `None` if unreadable.
+    image_srcs: HashMap<String, Option<String>>,
+    /// File name of each exported image by relationship target.
+    image_names: HashMap<String, String>,
+    exported: Vec<ExportedImage>,
+    /// Whether document part relationships apply to the content rendered.
+    in_body: bool,
+}
+
+impl<'a> Renderer<'a> {
+    fn new(
+        options: &'a HtmlOptions,
+        styles: StyleContext,
+        numbering: NumberingContext,
+        rels: &'a Relationships,
+        load_image: &'a mut dyn FnMut(&str) -> Result<ImageData>,
+    ) -> Self {
+        Self {
+            options,
+            styles,
+            numbering,
+            rels,
+            load_image,
+            out: String::new(),
+            lists: Vec::new(),
+            paragraph_styles: BTreeSet::new(),
+            footnote_refs: Vec::new(),
+            endnote_refs: Vec::new(),
+            comment_refs: Vec::new(),
+            image_srcs: HashMap::new(),
+            image_names: HashMap::new(),
+            exported: Vec::new(),
+            in_body: true,
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Blocks
+    // -------------------------------------------------------------------------
+
+    fn blocks(&mut self, blocks: &[types::BlockContent]) {
+        for block in export::blocks(blocks) {
+            match block {
+                Block::Paragraph(para) => self.paragraph(para),
+                Block::Table(table) => self.table(table),
+            }
+        }
+    }
+
+    fn paragraph(&mut self, para: &types::Paragraph) {
+        if let Some(item) = self.list_item(para) {
+            self.open_list_item(&item);
+            self.paragraph_content(&para.paragraph_content);
+            return;
+        }
+        self.close_lists();
+
+        let tag = match heading_level(&self.styles, para) {
+            Some(level) => ["h1", "h2", "h3", "h4", "h5", "h6"][level - 1],
+            None => "p",
+        };
+        let p_pr = para.p_pr.as_deref();
+        self.out.push('<');
+        self.out.push_str(tag);
+        if let Some(style_id) = p_pr.and_then(|p| p.paragraph_style.as_deref())
+            && !self.is_default_style(&style_id.value)
+        {
+            let _ = write!(self.out, " class=\"{}\"", style_class(&style_id.value));
+            self.paragraph_styles.insert(style_id.value.clone());
+        }
+        if let Some(align) = p_pr
+            .and_then(|p| p.justification.as_deref())
+            .map(|jc| text_align(jc.value))
+        {
+            let _ = write!(self.out, " style=\"text-align: {align}\"");
+        }
+        self.out.push('>');
+        self.paragraph_content(&para.paragraph_content);
+        let _ = writeln!(self.out, "</{tag}>");
+    }
+
+    fn is_default_style(&self, style_id: &str) -> bool {
+        self.styles
+            .style(style_id)
+            .and_then(|style| style.default.as_ref())
+            .is_some_and(|default| matches!(default.as_str(), "1" | "true" | "on"))
+    }
+
+    // -------------------------------------------------------------------------
+    // Lists
+    // -------------------------------------------------------------------------
+
+    /// How a paragraph is rendered as a list item, if it is numbered directly
+    /// or by its style.
+    fn list_item(&self, para: &types::Paragraph) -> Option<ListItem> {
+        let (num_id, ilvl) = self.numbering.paragraph_numbering(para, &self.styles)?;
+        let level = self.numbering.level(num_id, ilvl);
+        let format = level
+            .and_then(|lvl| lvl.num_fmt.as_deref())
+            .map(|fmt| fmt.value);
+        let (tag, list_type) = match format {
+            None | Some(types::STNumberFormat::Bullet) | Some(types::STNumberFormat::None) => {
+                ("ul", None)
+            }
+            Some(types::STNumberFormat::LowerLetter) => ("ol", Some("a")),
+            Some(types::STNumberFormat::UpperLetter) => ("ol", Some("A")),
+            Some(types::STNumberFormat::LowerRoman) => ("ol", Some("i")),
+            Some(types::STNumberFormat::UpperRoman) => ("ol", Some("I")),
+            Some(_) => ("ol", None),
+        };
+        Some(ListItem {
+            num_id,
+            ilvl,
+            tag,
+            list_type,
+        })
+    }
+
+    fn open_list_item(&mut self, item: &ListItem) {
+        while self.lists.last().is_some_and(|list| list.ilvl > item.ilvl) {
+            self.close_list();
+        }
+        if let Some(list) = self.lists.last()
+            && list.ilvl == item.ilvl
+        {
+            if list.num_id == item.num_id && list.tag == item.tag {
+                self.out.push_str("</li>\n");
+            } else {
+                self.close_list();
+            }
+        }
+
+        let number = self.numbering.next(item.num_id, item.ilvl).unwrap_or(1);
+
+        if self.lists.last().is_none_or(|list| list.ilvl < item.ilvl) {
+            let _ = write!(self.out, "<{}", item.tag);
+            if let Some(list_type) = item.list_type {
+                let _ = write!(self.out, " type=\"{list_type}\"");
+            }
+            if item.tag == "ol" && number != 1 {
+                let _ = write!(self.out, " start=\"{number}\"");
+            }
+            self.out.push_str(">\n");
+            self.lists.push(OpenList {
+                num_id: item.num_id,
+                ilvl: item.ilvl,
+                tag: item.tag,
+            });
+        }
+        self.out.push_str("<li>");
+    }
+
+    fn close_list(&mut self) {
+        if let Some(list) = self.lists.pop() {
+            let _ = writeln!(self.out, "</li>\n</{}>", list.tag);
+        }
+    }
+
+    fn close_lists(&mut self) {
+        while !self.lists.is_empty() {
+            self.close_list();
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Tables
+    // -------------------------------------------------------------------------
+
+    fn table(&mut self, table: &types::Table) {
+        self.close_lists();
+
+        let grid = table_grid(table);
+        self.out.push_str("<table>\n");
+        for (r, row) in grid.iter().enumerate() {
+            self.out.push_str("<tr>\n");
+            for cell in row {
+                if cell.merge == Some(types::STMerge::Continue) {
+                    continue;
+                }
+                self.out.push_str("<td");
+                if cell.span > 1 {
+                    let _ = write!(self.out, " colspan=\"{}\"", cell.span);
+                }
+                let rowspan = row_span(&grid, r, cell);
+                if rowspan > 1 {
+                    let _ = write!(self.out, " rowspan=\"{rowspan}\"");
+                }
+                self.out.push('>');
+                self.blocks(&cell.cell.block_content);
+                self.close_lists();
+                self.out.push_str("</td>\n");
+            }
+            self.out.push_str("</tr>\n");
+        }
+        self.out.push_str("</table>\n");
+    }
+
+    // -------------------------------------------------------------------------
+    // Inline content
+    // -------------------------------------------------------------------------
+
+    fn paragraph_content(&mut self, content: &[types::ParagraphContent]) {
+        for item in content {
+            match item {
+                types::ParagraphContent::R(run) => self.run(run),
+                types::ParagraphContent::Hyperlink(link) => self.hyperlink(link),
+                types::ParagraphContent::FldSimple(field) => {
+                    self.paragraph_content(&field.paragraph_content)
+                }
+                types::ParagraphContent::Sdt(sdt) => {
+                    if let Some(content) = &sdt.sdt_content {
+                        self.paragraph_content(&content.paragraph_content);
+                    }
+                }
+                types::ParagraphContent::CustomXml(custom) => {
+                    self.paragraph_content(&custom.paragraph_content)
+                }
+                types::ParagraphContent::SmartTag(tag) => {
+                    self.paragraph_content(&tag.paragraph_content)
+                }
+                types::ParagraphContent::Dir(dir) => self.paragraph_content(&dir.paragraph_content),
+                types::ParagraphContent::Bdo(bdo) => self.paragraph_content(&bdo.paragraph_content),
+                // Tracked changes are shown accepted
+                types::ParagraphContent::Ins(change) | types::ParagraphContent::MoveTo(change) => {
+                    for item in &change.run_content {
+                        if let types::RunContentChoice::R(run) = item {
+                            self.run(run);
+                        }
+                    }
+                }
+                types::ParagraphContent::BookmarkStart(bookmark) if bookmark.name != "_GoBack" => {
+                    self.out.push_str("<a id=\"");
+                    escape_into(&mut self.out, &bookmark.name);
+                    self.out.push_str("\"></a>");
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn hyperlink(&mut self, link: &types::Hyperlink) {
+        let href = match (&link.id, &link.anchor) {
+            (Some(id), _) if self.in_body => self
+                .rels
+                .get(id)
+                .map(|rel| rel.target.clone())
+                .or_else(|| link.anchor.as_ref().map(|anchor| format!("#{anchor}"))),
+            (_, Some(anchor)) => Some(format!("#{anchor}")),
+            _ => None,
+        };
+        match href.filter(|href| is_safe_href(href)) {
+            Some(href) => {
+                self.out.push_str("<a href=\"");
+                escape_into(&mut self.out, &href);
+                self.out.push_str("\">");
+                self.paragraph_content(&link.paragraph_content);
+                self.out.push_str("</a>");
+            }
+            None => self.paragraph_content(&link.paragraph_content),
+        }
+    }
+
+    fn run(&mut self, run: &types::Run) {
+        if run.resolved_is_hidden(&self.styles) {
+            return;
+        }
+        let format = self.run_format(run);
+        let mut text = String::new();
+        for content in &run.run_content {
+            match content {
+                types::RunContent::T(t) => {
+                    if let Some(s) = &t.text {
+                        escape_into(&mut text, s);
+                    }
+                }
+                types::RunContent::Tab(_) | types::RunContent::Ptab(_) => {
+                    text.push_str("<span class=\"tab\">\t</span>");
+                }
+                types::RunContent::Br(br)
+                    if !matches!(
+                        br.r#type,
+                        Some(types::STBrType::Page) | Some(types::STBrType::Column)
+                    ) =>
+                {
+                    text.push_str("<br>");
+                }
+                types::RunContent::Cr(_) => text.push_str("<br>"),
+                types::RunContent::NoBreakHyphen(_) => text.push('\u{2011}'),
+                types::RunContent::SoftHyphen(_) => text.push_str("&shy;"),
+                types::RunContent::Sym(sym) => {
+                    if let Some(c) = symbol_char(sym) {
+                        escape_into(&mut text, c.encode_utf8(&mut [0; 4]));
+                    }
+                }
+                types::RunContent::Drawing(drawing) => self.drawing(drawing, &mut text),
+                types::RunContent::FootnoteReference(note) => {
+                    self.formatted(&format, &mut text);
+                    self.note_reference(NoteKind::Footnote, note.id);
+                }
+                types::RunContent::EndnoteReference(note) => {
+                    self.formatted(&format, &mut text);
+                    self.note_reference(NoteKind::Endnote, note.id);
+                }
+                types::RunContent::CommentReference(comment) if self.options.comments => {
+                    self.formatted(&format, &mut text);
+                    self.comment_reference(comment.id);
+                }
+                _ => {}
+            }
+        }
+        self.formatted(&format, &mut text);
+    }
+
+    /// Write run content wrapped in the run's formatting, and clear it.
+    fn formatted(&mut self, format: &RunFormat, content: &mut String) {
+        if content.is_empty() {
+            return;
+        }
+        let mut close = Vec::new();
+        for (on, tag) in [
+            (format.bold, "strong"),
+            (format.italic, "em"),
+            (format.underline, "u"),
+            (format.strike, "s"),
+            (
+                format.vertical == Some(types::STVerticalAlignRun::Superscript),
+                "sup",
+            ),
+            (
+                format.vertical == Some(types::STVerticalAlignRun::Subscript),
+                "sub",
+            ),
+        ] {
+            if on {
+                let _ = write!(self.out, "<{tag}>");
+                close.push(tag);
+            }
+        }
+        if !format.style.is_empty() {
+            self.out.push_str("<span style=\"");
+            escape_into(&mut self.out, &format.style);
+            self.out.push_str("\">");
+            close.push("span");
+        }
+        self.out.push_str(content);
+        for tag in close.iter().rev() {
+            let _ = write!(self.out, "</{tag}>");
+        }
+        content.clear();
+    }
+
+    fn run_format(&self, run: &types::Run) -> RunFormat {
+        let ctx = &self.styles;
+        let defaults = ctx.default_run_properties.as_ref();
+        let mut style = String::new();
+        if let Some(color) = run.resolved_color_hex(ctx)
+            && let Some(color) = css_color(&color)
+            && defaults
+                .and_then(|d| d.color.as_deref())
+                .map(|c| c.value.as_str())
+                != Some(color)
+        {
+            let _ = write!(style, "color: #{color}; ");
+        }
+        if let Some(color) = run.resolved_highlight_color(ctx).and_then(highlight_color) {
+            let _ = write!(style, "background-color: {color}; ");
+        }
+        if let Some(font) = run.resolved_font_ascii(ctx)
+            && defaults
+                .and_then(|d| d.fonts.as_deref())
+                .and_then(|f| f.ascii.as_ref())
+                != Some(&font)
+            && let Some(family) = css_font_family(&font)
+        {
+            let _ = write!(style, "font-family: {family}; ");
+        }
+        if let Some(size) = run.resolved_font_size_half_points(ctx)
+            && defaults
+                .and_then(|d| d.size.as_deref())
+                .map(|s| s.value.as_str())
+                != Some(size.to_string().as_str())
+        {
+            let _ = write!(style, "font-size: {}pt; ", f64::from(size) / 2.0);
+        }
+        if run.resolved_is_all_caps(ctx) {
+            style.push_str("text-transform: uppercase; ");
+        }
+        if run.resolved_is_small_caps(ctx) {
+            style.push_str("font-variant: small-caps; ");
+        }
+        style.truncate(style.trim_end().len());
+        RunFormat {
+            bold: run.resolved_is_bold(ctx),
+            italic: run.resolved_is_italic(ctx),
+            underline: run.resolved_is_underline(ctx),
+            strike: run.resolved_is_strikethrough(ctx) || run.resolved_is_double_strikethrough(ctx),
+            vertical: run.resolved_vertical_alignment(ctx),
+            style,
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Images
+    // -------------------------------------------------------------------------
+
+    fn drawing(&mut self, drawing: &types::CTDrawing, out: &mut String) {
+        if !self.in_body || self.options.images == ImageMode::Omit {
+            return;
+        }
+        let alt = drawing_value(drawing, &DRAWING_DESCRIPTION).unwrap_or_default();
+        let extent = |query| {
+            drawing_value(drawing, query)
+                .and_then(|emu| emu.parse::<i64>().ok())
+                .map(|emu| emu / EMU_PER_PX)
+        };
+        let (width, height) = (extent(&DRAWING_WIDTH), extent(&DRAWING_HEIGHT));
+        for rel_id in drawing.all_image_rel_ids() {
+            let Some(src) = self.image_src(rel_id) else {
+                continue;
+            };
+            out.push_str("<img src=\"");
+            escape_into(out, &src);
+            out.push_str("\" alt=\"");
+            escape_into(out, alt);
+            out.push('"');
+            if let Some(width) = width {
+                let _ = write!(out, " width=\"{width}\"");
+            }
+            if let Some(height) = height {
+                let _ = write!(out, " height=\"{height}\"");
+            }
+            out.push('>');
+        }
+    }
+
+    /// The `src` of an image, loading it on first use.
+    fn image_src(&mut self, rel_id: &str) -> Option<String> {
+        if let Some(src) = self.image_srcs.get(rel_id) {
+            return src.clone();
+        }
+        let src = (self.load_image)(rel_id)
+            .ok()
+            .map(|image| match &self.options.images {
+                ImageMode::Files { prefix } => {
+                    let target = self
+                        .rels
+                        .get(rel_id)
+                        .map_or(rel_id, |rel| rel.target.as_str());
+                    let name = match self.image_names.get(target) {
+                        Some(name) => name.clone(),
+                        None => {
+                            let name = unique_file_name(image_file_name(target), &self.exported);
+                            self.image_names.insert(target.to_string(), name.clone());
+                            self.exported.push(ExportedImage {
+                                name: name.clone(),
+                                content_type: image.content_type,
+                                data: image.data,
+                            });
+                            name
+                        }
+                    };
+                    format!("{prefix}{name}")
+                }
+                _ => format!(
+                    "data:{};base64,{}",
+                    image.content_type,
+                    base64::engine::general_purpose::STANDARD.encode(&image.data)
+                ),
+            });
+        self.image_srcs.insert(rel_id.to_string(), src.clone());
+        src
+    }
+
+    // -------------------------------------------------------------------------
+    // Notes and comments
+    // -------------------------------------------------------------------------
+
+    fn note_reference(&mut self, kind: NoteKind, id: i64) {
+        if !self.options.notes {
+            return;
+        }
+        let refs = match kind {
+            NoteKind::Footnote => &mut self.footnote_refs,
+            NoteKind::Endnote => &mut self.endnote_refs,
+        };
+        let number = reference_number(refs, id);
+        let name = kind.name();
+        let _ = write!(
+            self.out,
+            "<sup class=\"{name}-ref\"><a href=\"#{name}-{number}\" id=\"{name}-ref-{number}\">{number}</a></sup>"
+        );
+    }
+
+    fn comment_reference(&mut self, id: i64) {
+        let number = reference_number(&mut self.comment_refs, id);
+        let _ = write!(
+            self.out,
+            "<sup class=\"comment-ref\"><a href=\"#comment-{number}\" id=\"comment-ref-{number}\">[{number}]</a></sup>"
+        );
+    }
+
+    /// List the referenced notes of a kind, in order of reference.
+    fn notes(&mut self, kind: NoteKind, notes: &[types::FootnoteEndnote]) {
+        let refs = match kind {
+            NoteKind::Footnote => self.footnote_refs.clone(),
+            NoteKind::Endnote => self.endnote_refs.clone(),
+        };
+        if refs.is_empty() {
+            return;
+        }
+        let name = kind.name();
+        let _ = writeln!(self.out, "<section class=\"{name}s\">\n<ol>");
+        self.in_body = false;
+        for (i, id) in refs.iter().enumerate() {
+            let number = i + 1;
+            let _ = writeln!(self.out, "<li id=\"{name}-{number}\">");
+            if let Some(note) = notes.iter().find(|note| note.id == *id) {
+                self.blocks(&note.block_content);
+                self.close_lists();
+            }
+            let _ = writeln!(
+                self.out,
+                "<a class=\"backref\" href=\"#{name}-ref-{number}\">\u{21a9}</a>\n</li>"
+            );
+        }
+        self.in_body = true;
+        self.out.push_str("</ol>\n</section>\n");
+    }
+
+    /// List the referenced comments, in order of reference.
+    fn comments(&mut self, comments: &[types::Comment]) {
+        if self.comment_refs.is_empty() {
+            return;
+        }
+        self.out.push_str("<section class=\"comments\">\n<ol>\n");
+        self.in_body = false;
+        for (i, id) in self.comment_refs.clone().iter().enumerate() {
+            let number = i + 1;
+            let _ = writeln!(self.out, "<li id=\"comment-{number}\">");
+            if let Some(comment) = comments.iter().find(|comment| comment.id == *id) {
+                self.out.push_str("<p class=\"comment-author\">");
+                escape_into(&mut self.out, &comment.author);
+                self.out.push_str("</p>\n");
+                self.blocks(&comment.block_content);
+                self.close_lists();
+            }
+            let _ = writeln!(
+                self.out,
+                "<a class=\"backref\" href=\"#comment-ref-{number}\">\u{21a9}</a>\n</li>"
+            );
+        }
+        self.in_body = true;
+        self.out.push_str("</ol>\n</section>\n");
+    }
+
+    // -------------------------------------------------------------------------
+    // Stylesheet
+    // -------------------------------------------------------------------------
+
+    /// The stylesheet: document defaults, base rules and a rule per
+    /// paragraph style used.
+    fn css(&self) -> String {
+        let mut css = String::new();
+        if let Some(defaults) = &self.styles.default_run_properties {
+            let declarations = run_properties_css(defaults);
+            if !declarations.is_empty() {
+                let _ = writeln!(css, "body {{ {declarations} }}");
+            }
+        }
+        css.push_str(BASE_CSS);
+        for style_id in &self.paragraph_styles {
+            let declarations = self.paragraph_style_css(style_id);
+            if !declarations.is_empty() {
+                let _ = writeln!(css, ".{} {{ {declarations} }}", style_class(style_id));
+            }
+        }
+        css
+    }
+
+    /// CSS declarations for a paragraph style, with properties inherited
+    /// through its `basedOn` chain.
+    fn paragraph_style_css(&self, style_id: &str) -> String {
+        let chain = self.styles.style_chain(style_id);
+        let mut declarations = Vec::new();
+        if let Some(jc) = chain
+            .iter()
+            .find_map(|style| style.p_pr.as_deref()?.justification.as_deref())
+        {
+            declarations.push(format!("text-align: {};", text_align(jc.value)));
+        }
+        // Apply least derived first so that derived styles win
+        let mut merged = types::RunProperties::default();
+        for rpr in chain.iter().rev().filter_map(|style| style.r_pr.as_deref()) {
+            merge_run_properties(&mut merged, rpr);
+        }
+        let run = run_properties_css(&merged);
+        if !run.is_empty() {
+            declarations.push(run);
+        }
+        declarations.join(" ")
+    }
+}
+
+/// Overlay the run properties CSS is generated for.
+fn merge_run_properties(into: &mut types::RunProperties, from: &types::RunProperties) {
+    macro_rules! overlay {
+        ($($field:ident),*) => {
+            $(if from.$field.is_some() {
+                into.$field.clone_from(&from.$field);
+            })*
+        };
+    }
+    overlay!(
+        bold,
+        italic,
+        underline,
+        strikethrough,
+        dstrike,
+        caps,
+        small_caps,
+        color,
+        size,
+        fonts
+    );
+}
+
+/// CSS declarations for run properties, ignoring style references.
+fn run_properties_css(rpr: &types::RunProperties) -> String {
+    let mut declarations = Vec::new();
+    if let Some(fonts) = &rpr.fonts
+        && let Some(family) = fonts.ascii.as_deref().and_then(css_font_family)
+    {
+        declarations.push(format!("font-family: {family};"));
+    }
+    if let Some(size) = rpr
+        .size
+        .as_deref()
+        .and_then(|s| s.value.parse::<u32>().ok())
+    {
+        declarations.push(format!("font-size: {}pt;", f64::from(size) / 2.0));
+    }
+    if let Some(bold) = check_toggle(&rpr.bold) {
+        declarations.push(format!(
+            "font-weight: {};",
+            if bold { "bold" } else { "normal" }
+        ));
+    }
+    if let Some(italic) = check_toggle(&rpr.italic) {
+        declarations.push(format!(
+            "font-style: {};",
+            if italic { "italic" } else { "normal" }
+        ));
+    }
+    let underline = rpr
+        .underline
+        .as_deref()
+        .map(|u| !matches!(u.value, Some(types::STUnderline::None)));
+    let strike = check_toggle(&rpr.strikethrough).or(check_toggle(&rpr.dstrike));
+    let decorations: Vec<&str> = [
+        (underline == Some(true), "underline"),
+        (strike == Some(true), "line-through"),
+    ]
+    .into_iter()
+    .filter_map(|(on, decoration)| on.then_some(decoration))
+    .collect();
+    if !decorations.is_empty() {
+        declarations.push(format!("text-decoration: {};", decorations.join(" ")));
+    }
+    if let Some(color) = rpr.color.as_deref().and_then(|c| css_color(&c.value)) {
+        declarations.push(format!("color: #{color};"));
+    }
+    if check_toggle(&rpr.caps) == Some(true) {
+        declarations.push("text-transform: uppercase;".to_string());
+    }
+    if check_toggle(&rpr.small_caps) == Some(true) {
+        declarations.push("font-variant: small-caps;".to_string());
+    }
+    declarations.join(" ")
+}
+
+/// A `w:color` value that can be written after `#` in CSS: six hex digits.
+/// `auto` and malformed values give `None`.
+fn css_color(value: &str) -> Option<&str> {
+    (value.len() == 6 && value.bytes().all(|b| b.is_ascii_hexdigit())).then_some(value)
+}
+
+/// A quoted CSS `font-family` value for a font name. Names with characters
+/// that could end the string, the declaration or the `<style>` element are
+/// rejected.
+fn css_font_family(name: &str) -> Option<String> {
+    let name = name.replace('\'', "");
+    let unsafe_char =
+        |c: char| matches!(c, '<' | '>' | ';' | '{' | '}' | '\\' | '"') || c.is_control();
+    (!name.trim().is_empty() && !name.contains(unsafe_char)).then(|| format!("'{name}'"))
+}
+
+/// CSS `text-align` for a paragraph justification.
+fn text_align(jc: types::STJc) -> &'static str {
+    match jc {
+        types::STJc::Center => "center",
+        types::STJc::End | types::STJc::Right => "right",
+        types::STJc::Start | types::STJc::Left | types::STJc::NumTab => "left",
+        types::STJc::Both
+        | types::STJc::Distribute
+        | types::STJc::MediumKashida
+        | types::STJc::HighKashida
+        | types::STJc::LowKashida
+        | types::STJc::ThaiDistribute => "justify",
+    }
+}
+
+/// CSS color of a text highlight (§17.18.40).
+fn highlight_color(color: types::STHighlightColor) -> Option<&'static str> {
+    use types::STHighlightColor as H;
+    Some(match color {
+        H::Black => "#000000",
+        H::Blue => "#0000ff",
+        H::Cyan => "#00ffff",
+        H::Green => "#00ff00",
+        H::Magenta => "#ff00ff",
+        H::Red => "#ff0000",
+        H::Yellow => "#ffff00",
+        H::White => "#ffffff",
+        H::DarkBlue => "#000080",
+        H::DarkCyan => "#008080",
+        H::DarkGreen => "#008000",
+        H::DarkMagenta => "#800080",
+        H::DarkRed => "#800000",
+        H::DarkYellow => "#808000",
+        H::DarkGray => "#808080",
+        H::LightGray => "#c0c0c0",
+        H::None => return None,
+    })
+}
+
+/// CSS class for a paragraph style.
+fn style_class(style_id: &str) -> String {
+    let id: String = style_id
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' => c,
+            _ => '_',
+        })
+        .collect();
+    format!("style-{id}")
+}
+
+/// Whether a link target can be rendered as a link: a web or mail address,
+/// or an anchor in the page. Other schemes, such as `javascript:` and
+/// `data:`, could run script in the page.
+fn is_safe_href(href: &str) -> bool {
+    let href = href.trim_start_matches(|c: char| c.is_ascii_whitespace() || c.is_ascii_control());
+    if href.starts_with('#') {
+        return true;
+    }
+    href.split_once(':').is_some_and(|(scheme, _)| {
+        ["http", "https", "mailto"]
+            .iter()
+            .any(|safe| scheme.eq_ignore_ascii_case(safe))
+    })
+}
+
+/// The file name to export an image as: the last segment of its target,
+/// limited to letters, digits, `-`, `_` and `.`, and not starting with a dot.
+fn image_file_name(target: &str) -> String {
+    let last = target.rsplit(['/', '\\']).next().unwrap_or(target);
+    let name: String = last
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' | '.' => c,
+            _ => '_',
+        })
+        .collect();
+    match name.trim_start_matches('.') {
+        "" => "image".to_string(),
+        name => name.to_string(),
+    }
+}
+
+/// `name`, or `name` numbered before its extension if an exported image
+/// already has it.
+fn unique_file_name(name: String, exported: &[ExportedImage]) -> String {
+    let taken = |candidate: &str| exported.iter().any(|image| image.name == candidate);
+    if !taken(&name) {
+        return name;
+    }
+    let (stem, extension) = name
+        .rsplit_once('.')
+        .map_or((name.as_str(), String::new()), |(stem, extension)| {
+            (stem, format!(".{extension}"))
+        });
+    (2..)
+        .map(|n| format!("{stem}-{n}{extension}"))
+        .find(|candidate| !taken(candidate))
+        .expect("unused name")
+}
+
+/// Append text escaped for HTML content and attribute values.
+fn escape_into(out: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+}
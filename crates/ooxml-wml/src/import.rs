@@ -0,0 +1,1808 @@
+//! Markdown and HTML import.
+//!
+//! [`DocumentBuilder::import_markdown`] and [`DocumentBuilder::import_html`]
+//! append content to the body of a document being built, using the built-in
+//! Word styles a user would pick for each construct:
+//!
+//! - Headings use "heading 1" to "heading 6". They get a bookmark when they
+//!   have an explicit id (`# Usage {#usage}`, `<h1 id="usage">`) or when a
+//!   link in the content points at their GitHub-style slug (`[see](#usage)`).
+//! - Lists become numbered "List Paragraph" paragraphs, with a numbering
+//!   definition per list (bullets, or decimal numbers from the list's start)
+//!   and nested lists on deeper levels.
+//! - Block quotes use "Quote", code blocks "HTML Preformatted" (a paragraph
+//!   per line) and code spans "HTML Code".
+//! - Tables use "Table Grid", with the header row in bold and the column
+//!   alignment applied to cell paragraphs.
+//! - Links become hyperlinks in the "Hyperlink" style, to a relationship for
+//!   URLs or to a bookmark for `#fragment`s.
+//! - Images are embedded, sized from their pixel dimensions at 96 DPI and
+//!   scaled down to the text width.
+//! - Footnotes (`[^1]`) become Word footnotes.
+//!
+//! Definitions of the styles used are added unless the builder already has
+//! styles with those IDs, so a template's styles take precedence.
+//!
+//! Markdown is read as CommonMark with the GitHub Flavored Markdown tables,
+//! strikethrough, task lists and footnotes. Raw HTML in it is read like
+//! imported HTML: HTML blocks are imported as such, and inline tags for
+//! formatting (`<u>`, `<sup>`, `<br>`, ...) apply to the text between them.
+//!
+//! HTML is read leniently, as a fragment or a whole page. Headings,
+//! paragraphs, lists, block quotes, `<pre>`, tables, `<hr>`, links, images
+//! and inline formatting are imported; other elements such as `<div>` and
+//! `<span>` contribute their content only. Scripts, style sheets and the
+//! document title are ignored.
+//!
+//! Images are read from `data:` URIs and, with [`ImportOptions::base_dir`],
+//! from files relative to it. Absolute paths and paths with `..` segments,
+//! which could reach files outside it, are not read. Remote images are not
+//! fetched; they, and
+//! images in formats other than PNG, JPEG, GIF, BMP and TIFF, are replaced by
+//! their alt text. So are images and links in footnotes, whose relationships
+//! would belong to the footnotes part.
+//!
+//! ```ignore
+//! use ooxml_wml::DocumentBuilder;
+//! use ooxml_wml::import::ImportOptions;
+//!
+//! let markdown = std::fs::read_to_string("report.md")?;
+//! let mut builder = DocumentBuilder::new();
+//! builder.import_markdown(&markdown, &ImportOptions {
+//!     base_dir: Some("report".into()),
+//!     ..ImportOptions::default()
+//! });
+//! builder.save("report.docx")?;
+//! ```
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::{Component, Path, PathBuf};
+
+use base64::Engine;
+use pulldown_cmark::{
+    Alignment, CodeBlockKind, CowStr, Event, HeadingLevel, LinkType, Options, Parser, Tag, TagEnd,
+};
+
+use crate::convenience::TableWidthUnit;
+use crate::types;
+use crate::writer::{DocumentBuilder, Drawing, NumberingLevel};
+
+/// Options for [`DocumentBuilder::import_markdown`] and
+/// [`DocumentBuilder::import_html`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportOptions {
+    /// Directory to read images with relative paths from. Without one, only
+    /// images in `data:` URIs are embedded. Paths must stay inside it.
+    pub base_dir: Option<PathBuf>,
+    /// Add definitions of the built-in styles used that the builder does not
+    /// have yet.
+    pub styles: bool,
+}
+
+impl Default for ImportOptions {
+    fn default() -> Self {
+        Self {
+            base_dir: None,
+            styles: true,
+        }
+    }
+}
+
+impl DocumentBuilder {
+    /// Append CommonMark content, with GitHub Flavored Markdown tables,
+    /// strikethrough, task lists and footnotes, to the document body.
+    ///
+    /// See the [`import`](crate::import) module for how content is mapped.
+    pub fn import_markdown(&mut self, markdown: &str, options: &ImportOptions) -> &mut Self {
+        let parser = Parser::new_ext(
+            markdown,
+            Options::ENABLE_TABLES
+                | Options::ENABLE_FOOTNOTES
+                | Options::ENABLE_STRIKETHROUGH
+                | Options::ENABLE_TASKLISTS
+                | Options::ENABLE_HEADING_ATTRIBUTES,
+        );
+        let mut importer = Importer::new(self, options);
+        let mut events = Vec::new();
+        // Footnote definitions are imported when first referenced
+        let mut note: Option<(String, Vec<Event<'static>>)> = None;
+        for event in parser {
+            match event {
+                Event::Start(Tag::FootnoteDefinition(label)) => {
+                    note = Some((label.to_string(), Vec::new()))
+                }
+                Event::End(TagEnd::FootnoteDefinition) => {
+                    if let Some((label, events)) = note.take() {
+                        importer.notes.entry(label).or_insert(events);
+                    }
+                }
+                event => match &mut note {
+                    Some((_, events)) => events.push(event.into_static()),
+                    None => events.push(event),
+                },
+            }
+        }
+        importer.run(events);
+        self
+    }
+
+    /// Append HTML content to the document body.
+    ///
+    /// See the [`import`](crate::import) module for the elements supported.
+    pub fn import_html(&mut self, html: &str, options: &ImportOptions) -> &mut Self {
+        let events = html_events(html);
+        Importer::new(self, options).run(events);
+        self
+    }
+}
+
+/// Width of the text on a Letter page with 1" margins, in twips.
+const TEXT_WIDTH_TWIPS: u32 = 9360;
+/// Width of the text on a Letter page with 1" margins, in EMUs.
+const TEXT_WIDTH_EMU: i64 = 5_943_600;
+/// EMUs per pixel at 96 DPI.
+const EMU_PER_PIXEL: i64 = 9525;
+/// Indentation per list or quote level, in twips.
+const INDENT_TWIPS: u32 = 720;
+/// Bullet characters, by list level.
+const BULLETS: [&str; 3] = ["\u{2022}", "\u{25E6}", "\u{25AA}"];
+/// Font sizes of headings 1 to 6, in half-points.
+const HEADING_SIZES: [i64; 6] = [32, 28, 26, 24, 22, 22];
+
+const LIST_STYLE: &str = "ListParagraph";
+const QUOTE_STYLE: &str = "Quote";
+const CODE_BLOCK_STYLE: &str = "HTMLPreformatted";
+const CODE_STYLE: &str = "HTMLCode";
+const LINK_STYLE: &str = "Hyperlink";
+const TABLE_STYLE: &str = "TableGrid";
+const NOTE_STYLE: &str = "FootnoteText";
+const NOTE_REFERENCE_STYLE: &str = "FootnoteReference";
+const HEADING_STYLES: [&str; 6] = [
+    "Heading1", "Heading2", "Heading3", "Heading4", "Heading5", "Heading6",
+];
+
+/// Nesting depth of inline formatting.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct Format {
+    bold: u32,
+    italic: u32,
+    underline: u32,
+    strike: u32,
+    superscript: u32,
+    subscript: u32,
+    code: u32,
+}
+
+impl Format {
+    /// Counter for an inline HTML formatting element.
+    fn counter(&mut self, element: &str) -> Option<&mut u32> {
+        Some(match element {
+            "b" | "strong" => &mut self.bold,
+            "i" | "em" | "cite" | "var" | "dfn" => &mut self.italic,
+            "u" | "ins" => &mut self.underline,
+            "s" | "del" | "strike" => &mut self.strike,
+            "sup" => &mut self.superscript,
+            "sub" => &mut self.subscript,
+            "code" | "kbd" | "samp" | "tt" => &mut self.code,
+            _ => return None,
+        })
+    }
+}
+
+enum LinkTarget {
+    Relationship(String),
+    Anchor(String),
+}
+
+struct Heading {
+    level: usize,
+    id: Option<String>,
+    text: String,
+}
+
+struct List {
+    num_id: u32,
+    /// Whether the next paragraph starts a list item.
+    number_next: bool,
+}
+
+struct TableState {
+    table: types::Table,
+    alignments: Vec<Alignment>,
+    /// The open row, and whether it is the header.
+    row: Option<(types::CTRow, bool)>,
+}
+
+/// Builds document content from Markdown parser events.
+struct Importer<'a> {
+    builder: &'a mut DocumentBuilder,
+    options: &'a ImportOptions,
+    /// Footnote definitions not imported yet, by label.
+    notes: HashMap<String, Vec<Event<'static>>>,
+    /// Footnotes imported so far, by label.
+    note_ids: HashMap<String, i64>,
+    /// Whether this imports a footnote.
+    in_note: bool,
+    /// Targets of internal links, to bookmark headings for.
+    anchors: HashSet<String>,
+    /// Styles used, to add definitions for.
+    styles: BTreeSet<&'static str>,
+    next_bookmark: i64,
+    /// Finished blocks of the body and of open table cells, innermost last.
+    blocks: Vec<Vec<types::BlockContent>>,
+    paragraph: Option<types::Paragraph>,
+    /// Format of the last run, while text can be added to it.
+    text_run: Option<Format>,
+    format: Format,
+    link: Option<LinkTarget>,
+    /// Whether a hyperlink for `link` has been added to the paragraph.
+    link_open: bool,
+    heading: Option<Heading>,
+    /// URL and alt text of the open image.
+    image: Option<(String, String)>,
+    code_block: Option<String>,
+    html_block: Option<String>,
+    lists: Vec<List>,
+    quotes: u32,
+    tables: Vec<TableState>,
+}
+
+impl<'a> Importer<'a> {
+    fn new(builder: &'a mut DocumentBuilder, options: &'a ImportOptions) -> Self {
+        // Continue after bookmarks of earlier content
+        let next_bookmark = builder
+            .body_mut()
+            .block_content
+            .iter()
+            .filter_map(|block| match block {
+                types::BlockContent::P(para) => Some(para),
+                _ => None,
+            })
+            .flat_map(|para| &para.paragraph_content)
+            .filter_map(|content| match content {
+                types::ParagraphContent::BookmarkStart(bookmark) => Some(bookmark.id + 1),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0);
+        Self {
+            builder,
+            options,
+            notes: HashMap::new(),
+            note_ids: HashMap::new(),
+            in_note: false,
+            anchors: HashSet::new(),
+            styles: BTreeSet::new(),
+            next_bookmark,
+            blocks: vec![Vec::new()],
+            paragraph: None,
+            text_run: None,
+            format: Format::default(),
+            link: None,
+            link_open: false,
+            heading: None,
+            image: None,
+            code_block: None,
+            html_block: None,
+            lists: Vec::new(),
+            quotes: 0,
+            tables: Vec::new(),
+        }
+    }
+
+    /// Import the events and append the result to the document body.
+    fn run(mut self, events: Vec<Event<'_>>) {
+        let mut anchors = HashSet::new();
+        let mut add_anchor = |event: &Event<'_>| {
+            if let Event::Start(Tag::Link { dest_url, .. }) = event
+                && let Some(anchor) = dest_url.strip_prefix('#')
+            {
+                anchors.insert(anchor.to_string());
+            }
+        };
+        events.iter().for_each(&mut add_anchor);
+        self.notes.values().flatten().for_each(&mut add_anchor);
+        self.anchors = anchors;
+        for event in events {
+            self.event(event);
+        }
+        let blocks = self.finish();
+        self.builder.body_mut().block_content.extend(blocks);
+        if self.options.styles {
+            for &id in &self.styles {
+                if !self.builder.has_style(id)
+                    && let Some(style) = builtin_style(id)
+                {
+                    self.builder.add_style(style);
+                }
+            }
+        }
+    }
+
+    /// Close what is open and return the blocks imported.
+    fn finish(&mut self) -> Vec<types::BlockContent> {
+        self.close_paragraph();
+        while let Some(table) = self.tables.pop() {
+            self.tables.push(table);
+            self.end(TagEnd::Table);
+        }
+        let mut blocks = self.blocks.drain(..).flatten().collect::<Vec<_>>();
+        blocks.shrink_to_fit();
+        blocks
+    }
+
+    fn event(&mut self, event: Event<'_>) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => self.text(&text),
+            Event::Code(code) => {
+                self.format.code += 1;
+                self.text(&code);
+                self.format.code -= 1;
+            }
+            Event::InlineMath(math) | Event::DisplayMath(math) => self.text(&math),
+            Event::Html(html) => match &mut self.html_block {
+                Some(block) => block.push_str(&html),
+                None => self.inline_html(&html),
+            },
+            Event::InlineHtml(html) => self.inline_html(&html),
+            Event::FootnoteReference(label) => self.footnote_reference(&label),
+            Event::SoftBreak => self.text(" "),
+            Event::HardBreak => self.hard_break(),
+            Event::Rule => self.rule(),
+            Event::TaskListMarker(checked) => {
+                self.text(if checked { "\u{2612} " } else { "\u{2610} " })
+            }
+        }
+    }
+
+    fn start(&mut self, tag: Tag<'_>) {
+        match tag {
+            Tag::Paragraph => self.close_paragraph(),
+            Tag::Heading { level, id, .. } => {
+                self.close_paragraph();
+                self.heading = Some(Heading {
+                    level: level as usize,
+                    id: id.map(|id| id.to_string()),
+                    text: String::new(),
+                });
+            }
+            Tag::BlockQuote(_) => {
+                self.close_paragraph();
+                self.quotes += 1;
+            }
+            Tag::CodeBlock(_) => {
+                self.close_paragraph();
+                self.code_block = Some(String::new());
+            }
+            Tag::HtmlBlock => self.html_block = Some(String::new()),
+            Tag::List(start) => {
+                self.close_paragraph();
+                self.start_list(start);
+            }
+            Tag::Item => {
+                self.close_paragraph();
+                if let Some(list) = self.lists.last_mut() {
+                    list.number_next = true;
+                }
+            }
+            Tag::Table(alignments) => {
+                self.close_paragraph();
+                self.tables.push(TableState {
+                    table: types::Table {
+                        range_markup: Vec::new(),
+                        table_properties: Box::default(),
+                        tbl_grid: Box::default(),
+                        rows: Vec::new(),
+                        #[cfg(feature = "extra-children")]
+                        extra_children: Vec::new(),
+                    },
+                    alignments,
+                    row: None,
+                });
+            }
+            Tag::TableHead | Tag::TableRow => {
+                let header = matches!(tag, Tag::TableHead);
+                if let Some(table) = self.tables.last_mut() {
+                    table.row = Some((types::CTRow::default(), header));
+                    if header {
+                        self.format.bold += 1;
+                    }
+                }
+            }
+            Tag::TableCell => {
+                self.close_paragraph();
+                self.blocks.push(Vec::new());
+            }
+            Tag::Emphasis => self.format.italic += 1,
+            Tag::Strong => self.format.bold += 1,
+            Tag::Strikethrough => self.format.strike += 1,
+            Tag::Superscript => self.format.superscript += 1,
+            Tag::Subscript => self.format.subscript += 1,
+            Tag::Link { dest_url, .. } => {
+                self.text_run = None;
+                self.link = match dest_url.strip_prefix('#') {
+                    _ if self.in_note || dest_url.is_empty() => None,
+                    Some(anchor) => Some(LinkTarget::Anchor(anchor.to_string())),
+                    None => Some(LinkTarget::Relationship(
+                        self.builder.add_hyperlink(&dest_url),
+                    )),
+                };
+            }
+            Tag::Image { dest_url, .. } => self.image = Some((dest_url.to_string(), String::new())),
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => self.close_paragraph(),
+            TagEnd::Heading(_) => self.end_heading(),
+            TagEnd::BlockQuote(_) => {
+                self.close_paragraph();
+                self.quotes = self.quotes.saturating_sub(1);
+            }
+            TagEnd::CodeBlock => self.end_code_block(),
+            TagEnd::HtmlBlock => {
+                for event in html_events(&self.html_block.take().unwrap_or_default()) {
+                    self.event(event);
+                }
+            }
+            TagEnd::List(_) => {
+                self.close_paragraph();
+                self.lists.pop();
+            }
+            TagEnd::Item => {
+                self.close_paragraph();
+                // An empty item still gets its number
+                if self.lists.last().is_some_and(|list| list.number_next) {
+                    let para = self.new_paragraph(None);
+                    self.push_block(types::BlockContent::P(Box::new(para)));
+                }
+            }
+            TagEnd::Table => self.end_table(),
+            TagEnd::TableHead | TagEnd::TableRow => {
+                if let Some(table) = self.tables.last_mut()
+                    && let Some((row, header)) = table.row.take()
+                {
+                    table.table.rows.push(types::RowContent::Tr(Box::new(row)));
+                    if header {
+                        self.format.bold = self.format.bold.saturating_sub(1);
+                    }
+                }
+            }
+            TagEnd::TableCell => self.end_cell(),
+            TagEnd::Emphasis => self.format.italic = self.format.italic.saturating_sub(1),
+            TagEnd::Strong => self.format.bold = self.format.bold.saturating_sub(1),
+            TagEnd::Strikethrough => self.format.strike = self.format.strike.saturating_sub(1),
+            TagEnd::Superscript => {
+                self.format.superscript = self.format.superscript.saturating_sub(1)
+            }
+            TagEnd::Subscript => self.format.subscript = self.format.subscript.saturating_sub(1),
+            TagEnd::Link => {
+                self.text_run = None;
+                self.link = None;
+                self.link_open = false;
+            }
+            TagEnd::Image => {
+                if let Some((url, alt)) = self.image.take() {
+                    self.add_image(&url, &alt);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if let Some((_, alt)) = &mut self.image {
+            alt.push_str(text);
+        } else if let Some(code) = &mut self.code_block {
+            code.push_str(text);
+        } else {
+            if let Some(heading) = &mut self.heading {
+                heading.text.push_str(text);
+            }
+            self.add_text(text);
+        }
+    }
+
+    /// Apply inline HTML formatting tags.
+    fn inline_html(&mut self, html: &str) {
+        for token in tokenize(html) {
+            match token {
+                Token::Start { name, .. } if name == "br" => self.hard_break(),
+                Token::Start { name, .. } => {
+                    if let Some(counter) = self.format.counter(&name) {
+                        *counter += 1;
+                    }
+                }
+                Token::End(name) => {
+                    if let Some(counter) = self.format.counter(&name) {
+                        *counter = counter.saturating_sub(1);
+                    }
+                }
+                Token::Text(_) => {}
+            }
+        }
+    }
+
+    fn start_list(&mut self, start: Option<u64>) {
+        let ilvl = self.lists.len() as u32;
+        let level = match start {
+            Some(start) => NumberingLevel {
+                start: start.min(u64::from(u32::MAX)) as u32,
+                ..NumberingLevel::decimal(ilvl)
+            },
+            None => NumberingLevel {
+                text: BULLETS[ilvl as usize % BULLETS.len()].to_string(),
+                ..NumberingLevel::bullet(ilvl)
+            },
+        };
+        // Each list gets its own numbering, so ordered lists restart
+        let levels = (0..ilvl)
+            .map(NumberingLevel::bullet)
+            .chain([level])
+            .collect();
+        let num_id = self.builder.add_custom_list(levels);
+        self.lists.push(List {
+            num_id,
+            number_next: false,
+        });
+    }
+
+    /// A paragraph with the properties of the current context: list, quote,
+    /// footnote or table column.
+    fn new_paragraph(&mut self, style: Option<&'static str>) -> types::Paragraph {
+        let mut para = types::Paragraph::default();
+        let mut style = style;
+        let depth = self.lists.len() as u32;
+        if let Some(list) = self.lists.last_mut() {
+            style = style.or(Some(LIST_STYLE));
+            if std::mem::take(&mut list.number_next) {
+                para.set_numbering(list.num_id, depth - 1);
+            } else {
+                para.set_indent(Some(INDENT_TWIPS * depth), None);
+            }
+        } else if self.quotes > 0 {
+            style = style.or(Some(QUOTE_STYLE));
+            if self.quotes > 1 {
+                para.set_indent(Some(INDENT_TWIPS * self.quotes), None);
+            }
+        } else if self.in_note {
+            style = style.or(Some(NOTE_STYLE));
+        }
+        if let Some(table) = self.tables.last()
+            && let Some((row, _)) = &table.row
+        {
+            let justification = match table.alignments.get(row.cells.len()) {
+                Some(Alignment::Left) => Some(types::STJc::Left),
+                Some(Alignment::Center) => Some(types::STJc::Center),
+                Some(Alignment::Right) => Some(types::STJc::Right),
+                _ => None,
+            };
+            if let Some(justification) = justification {
+                para.set_alignment(justification);
+            }
+        }
+        if let Some(style) = style {
+            self.styles.insert(style);
+            para.p_pr.get_or_insert_with(Box::default).paragraph_style = string(style);
+        }
+        para
+    }
+
+    fn ensure_paragraph(&mut self) {
+        if self.paragraph.is_none() {
+            let style = self
+                .heading
+                .as_ref()
+                .map(|heading| HEADING_STYLES[heading.level.clamp(1, 6) - 1]);
+            self.paragraph = Some(self.new_paragraph(style));
+        }
+    }
+
+    fn close_paragraph(&mut self) {
+        self.text_run = None;
+        self.link_open = false;
+        if let Some(para) = self.paragraph.take() {
+            self.push_block(types::BlockContent::P(Box::new(para)));
+        }
+    }
+
+    fn push_block(&mut self, block: types::BlockContent) {
+        self.blocks
+            .last_mut()
+            .expect("body blocks are never popped")
+            .push(block);
+    }
+
+    fn run_properties(&mut self) -> Option<types::RunProperties> {
+        let format = self.format;
+        let style = match () {
+            _ if format.code > 0 => Some(CODE_STYLE),
+            _ if self.link.is_some() => Some(LINK_STYLE),
+            _ => None,
+        };
+        if style.is_none() && format == Format::default() {
+            return None;
+        }
+        let mut props = types::RunProperties::default();
+        if let Some(style) = style {
+            self.styles.insert(style);
+            props.run_style = string(style);
+        }
+        if format.bold > 0 {
+            props.bold = on();
+        }
+        if format.italic > 0 {
+            props.italic = on();
+        }
+        if format.strike > 0 {
+            props.strikethrough = on();
+        }
+        if format.underline > 0 {
+            props.underline = single_underline();
+        }
+        let vert_align = match () {
+            _ if format.superscript > 0 => Some(types::STVerticalAlignRun::Superscript),
+            _ if format.subscript > 0 => Some(types::STVerticalAlignRun::Subscript),
+            _ => None,
+        };
+        props.vert_align = vert_align.map(|value| {
+            Box::new(types::CTVerticalAlignRun {
+                value,
+                #[cfg(feature = "extra-attrs")]
+                extra_attrs: Default::default(),
+            })
+        });
+        Some(props)
+    }
+
+    fn add_text(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        // Continue the last run if the format is the same
+        if self.text_run == Some(self.format)
+            && let Some(run) = self.last_run()
+            && let Some(types::RunContent::T(t)) = run.run_content.last_mut()
+        {
+            t.text.get_or_insert_default().push_str(text);
+            return;
+        }
+        let mut run = types::Run {
+            r_pr: self.run_properties().map(Box::new),
+            ..Default::default()
+        };
+        run.set_text(text);
+        self.push_run(run);
+        self.text_run = Some(self.format);
+    }
+
+    fn push_run(&mut self, run: types::Run) {
+        self.ensure_paragraph();
+        self.text_run = None;
+        let para = self.paragraph.as_mut().expect("paragraph is open");
+        let Some(target) = &self.link else {
+            para.paragraph_content
+                .push(types::ParagraphContent::R(Box::new(run)));
+            return;
+        };
+        if !self.link_open {
+            let mut link = types::Hyperlink::default();
+            match target {
+                LinkTarget::Relationship(rel_id) => link.set_rel_id(rel_id),
+                LinkTarget::Anchor(anchor) => link.set_anchor(anchor),
+            }
+            para.paragraph_content
+                .push(types::ParagraphContent::Hyperlink(Box::new(link)));
+            self.link_open = true;
+        }
+        if let Some(types::ParagraphContent::Hyperlink(link)) = para.paragraph_content.last_mut() {
+            link.paragraph_content
+                .push(types::ParagraphContent::R(Box::new(run)));
+        }
+    }
+
+    fn last_run(&mut self) -> Option<&mut types::Run> {
+        let mut content = self.paragraph.as_mut()?.paragraph_content.last_mut()?;
+        if let types::ParagraphContent::Hyperlink(link) = content {
+            content = link.paragraph_content.last_mut()?;
+        }
+        match content {
+            types::ParagraphContent::R(run) => Some(run),
+            _ => None,
+        }
+    }
+
+    fn hard_break(&mut self) {
+        let mut run = types::Run::default();
+        run.run_content
+            .push(types::RunContent::Br(Box::new(types::CTBr {
+                r#type: None,
+                clear: None,
+                #[cfg(feature = "extra-attrs")]
+                extra_attrs: Default::default(),
+            })));
+        self.push_run(run);
+    }
+
+    /// A thematic break, as a paragraph with a bottom border.
+    fn rule(&mut self) {
+        self.close_paragraph();
+        let mut para = self.new_paragraph(None);
+        para.p_pr.get_or_insert_with(Box::default).paragraph_border =
+            Some(Box::new(types::CTPBdr {
+                bottom: Some(Box::new(types::CTBorder {
+                    value: types::STBorder::Single,
+                    color: Some("auto".to_string()),
+                    theme_color: None,
+                    theme_tint: None,
+                    theme_shade: None,
+                    size: Some(6),
+                    space: Some(1),
+                    shadow: None,
+                    frame: None,
+                    #[cfg(feature = "extra-attrs")]
+                    extra_attrs: Default::default(),
+                })),
+                ..Default::default()
+            }));
+        self.push_block(types::BlockContent::P(Box::new(para)));
+    }
+
+    fn end_heading(&mut self) {
+        let Some(heading) = &self.heading else {
+            return;
+        };
+        let name = heading.id.clone().or_else(|| {
+            let slug = slug(&heading.text);
+            self.anchors.contains(&slug).then_some(slug)
+        });
+        self.ensure_paragraph();
+        self.heading = None;
+        if let Some(name) = name
+            && let Some(para) = &mut self.paragraph
+        {
+            let id = self.next_bookmark;
+            self.next_bookmark += 1;
+            para.add_bookmark_start(id, &name);
+            para.paragraph_content.rotate_right(1);
+            para.add_bookmark_end(id);
+        }
+        self.close_paragraph();
+    }
+
+    /// A paragraph per line of the code block.
+    fn end_code_block(&mut self) {
+        let code = self.code_block.take().unwrap_or_default();
+        let code = code.strip_suffix('\n').unwrap_or(&code);
+        for line in code.split('\n') {
+            let mut para = self.new_paragraph(Some(CODE_BLOCK_STYLE));
+            if !line.is_empty() {
+                para.add_run().set_text(line);
+            }
+            self.push_block(types::BlockContent::P(Box::new(para)));
+        }
+    }
+
+    fn end_cell(&mut self) {
+        self.close_paragraph();
+        if self.blocks.len() < 2 {
+            return;
+        }
+        let mut content = self.blocks.pop().unwrap_or_default();
+        let Some((row, _)) = self.tables.last_mut().and_then(|table| table.row.as_mut()) else {
+            // A cell outside a row keeps its content
+            self.blocks.last_mut().into_iter().for_each(|blocks| {
+                blocks.append(&mut content);
+            });
+            return;
+        };
+        let mut cell = types::TableCell {
+            block_content: content,
+            ..Default::default()
+        };
+        // A cell needs at least one paragraph
+        if cell.block_content.is_empty() {
+            cell.add_paragraph();
+        }
+        row.cells.push(types::CellContent::Tc(Box::new(cell)));
+    }
+
+    fn end_table(&mut self) {
+        self.close_paragraph();
+        let Some(TableState { mut table, row, .. }) = self.tables.pop() else {
+            return;
+        };
+        if let Some((row, _)) = row {
+            table.rows.push(types::RowContent::Tr(Box::new(row)));
+        }
+        let columns = table
+            .rows
+            .iter()
+            .filter_map(|row| match row {
+                types::RowContent::Tr(row) => Some(row.cells.len()),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0);
+        if columns == 0 {
+            return;
+        }
+        // Fill short rows so the grid is rectangular
+        for row in &mut table.rows {
+            if let types::RowContent::Tr(row) = row {
+                while row.cells.len() < columns {
+                    row.add_cell().add_paragraph();
+                }
+            }
+        }
+        self.styles.insert(TABLE_STYLE);
+        table.table_properties.tbl_style = string(TABLE_STYLE);
+        table.set_width(5000, TableWidthUnit::Pct);
+        let width = TEXT_WIDTH_TWIPS / columns as u32;
+        table.tbl_grid.grid_col = (0..columns)
+            .map(|_| types::TableGridColumn {
+                width: Some(width.to_string()),
+                #[cfg(feature = "extra-attrs")]
+                extra_attrs: Default::default(),
+            })
+            .collect();
+        self.push_block(types::BlockContent::Tbl(Box::new(table)));
+    }
+
+    /// Embed an image, or fall back to its alt text.
+    fn add_image(&mut self, url: &str, alt: &str) {
+        let data = match self.in_note {
+            true => None,
+            false => self.load_image(url),
+        };
+        let Some((data, content_type)) = data else {
+            self.add_text(alt);
+            return;
+        };
+        let size = image_size(&data);
+        let rel_id = self.builder.add_image(data, content_type);
+        let mut drawing = Drawing::new();
+        let image = drawing.add_image(&rel_id);
+        if !alt.is_empty() {
+            image.set_description(alt);
+        }
+        if let Some((width, height)) = size {
+            let mut width = i64::from(width) * EMU_PER_PIXEL;
+            let mut height = i64::from(height) * EMU_PER_PIXEL;
+            if width > TEXT_WIDTH_EMU {
+                height = height * TEXT_WIDTH_EMU / width;
+                width = TEXT_WIDTH_EMU;
+            }
+            image.set_width_emu(width).set_height_emu(height);
+        }
+        let drawing = self.builder.build_drawing(drawing);
+        let mut run = types::Run::default();
+        run.add_drawing(drawing);
+        self.push_run(run);
+    }
+
+    /// Data and content type of an image from a `data:` URI or a file.
+    fn load_image(&self, url: &str) -> Option<(Vec<u8>, &'static str)> {
+        let data = if let Some(uri) = url.strip_prefix("data:") {
+            let (header, payload) = uri.split_once(',')?;
+            if !header.ends_with(";base64") {
+                return None;
+            }
+            let payload: String = payload.split_ascii_whitespace().collect();
+            base64::engine::general_purpose::STANDARD
+                .decode(payload)
+                .ok()?
+        } else if url.contains("://") || url.starts_with("//") {
+            return None;
+        } else {
+            let base_dir = self.options.base_dir.as_ref()?;
+            let path = percent_decode(url);
+            let path = Path::new(&path);
+            // Only plain relative paths, so content can't reach files
+            // outside the base directory
+            if !path
+                .components()
+                .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+            {
+                return None;
+            }
+            std::fs::read(base_dir.join(path)).ok()?
+        };
+        let content_type = image_content_type(&data)?;
+        Some((data, content_type))
+    }
+
+    fn footnote_reference(&mut self, label: &str) {
+        let Some(id) = self.footnote(label) else {
+            self.text(&format!("[^{label}]"));
+            return;
+        };
+        self.styles.insert(NOTE_REFERENCE_STYLE);
+        let mut run = types::Run {
+            r_pr: Some(Box::new(types::RunProperties {
+                run_style: string(NOTE_REFERENCE_STYLE),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+        run.add_footnote_ref(id);
+        self.push_run(run);
+    }
+
+    /// ID of the footnote for a label, importing its definition on first
+    /// reference.
+    fn footnote(&mut self, label: &str) -> Option<i64> {
+        if let Some(&id) = self.note_ids.get(label) {
+            return Some(id);
+        }
+        let events = self.notes.remove(label)?;
+        let mut note = Importer::new(&mut *self.builder, self.options);
+        note.in_note = true;
+        note.next_bookmark = self.next_bookmark;
+        for event in events {
+            note.event(event);
+        }
+        let mut blocks = note.finish();
+        self.styles.append(&mut note.styles);
+        self.next_bookmark = note.next_bookmark;
+
+        // The note starts with its reference mark
+        if !matches!(blocks.first(), Some(types::BlockContent::P(_))) {
+            let para = note.new_paragraph(None);
+            blocks.insert(0, types::BlockContent::P(Box::new(para)));
+        }
+        if let Some(types::BlockContent::P(para)) = blocks.first_mut() {
+            let mut mark = types::Run {
+                r_pr: Some(Box::new(types::RunProperties {
+                    run_style: string(NOTE_REFERENCE_STYLE),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            };
+            mark.run_content
+                .push(types::RunContent::FootnoteRef(Box::new(types::CTEmpty)));
+            let mut space = types::Run::default();
+            space.set_text(" ");
+            para.paragraph_content.splice(
+                0..0,
+                [mark, space].map(|run| types::ParagraphContent::R(Box::new(run))),
+            );
+        }
+        self.styles.insert(NOTE_REFERENCE_STYLE);
+        self.styles.insert(NOTE_STYLE);
+
+        let mut footnote = self.builder.add_footnote();
+        footnote.body_mut().block_content = blocks;
+        let id = i64::from(footnote.id());
+        self.note_ids.insert(label.to_string(), id);
+        Some(id)
+    }
+}
+
+/// GitHub-style anchor of a heading: lowercase, punctuation removed, spaces
+/// as hyphens.
+fn slug(text: &str) -> String {
+    text.trim()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            '-' | '_' => Some(c),
+            c if c.is_alphanumeric() => Some(c),
+            _ => None,
+        })
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn percent_decode(url: &str) -> String {
+    let bytes = url.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = (bytes[i] == b'%')
+            .then(|| url.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match hex {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Content type of image data Word can show, from its signature.
+fn image_content_type(data: &[u8]) -> Option<&'static str> {
+    Some(match data {
+        [0x89, b'P', b'N', b'G', ..] => "image/png",
+        [0xFF, 0xD8, 0xFF, ..] => "image/jpeg",
+        [b'G', b'I', b'F', b'8', ..] => "image/gif",
+        [b'B', b'M', ..] => "image/bmp",
+        [b'I', b'I', 42, 0, ..] | [b'M', b'M', 0, 42, ..] => "image/tiff",
+        _ => return None,
+    })
+}
+
+/// Width and height of an image in pixels, from its header.
+fn image_size(data: &[u8]) -> Option<(u32, u32)> {
+    let be16 = |at: usize| {
+        Some(u32::from(u16::from_be_bytes(
+            data.get(at..at + 2)?.try_into().ok()?,
+        )))
+    };
+    let le16 = |at: usize| {
+        Some(u32::from(u16::from_le_bytes(
+            data.get(at..at + 2)?.try_into().ok()?,
+        )))
+    };
+    let be32 = |at: usize| Some(u32::from_be_bytes(data.get(at..at + 4)?.try_into().ok()?));
+    let le32 = |at: usize| Some(i32::from_le_bytes(data.get(at..at + 4)?.try_into().ok()?));
+    let size = match image_content_type(data)? {
+        "image/png" => Some((be32(16)?, be32(20)?)),
+        "image/gif" => Some((le16(6)?, le16(8)?)),
+        "image/bmp" => Some((le32(18)?.unsigned_abs(), le32(22)?.unsigned_abs())),
+        "image/jpeg" => {
+            // Walk the segments to the start of frame
+            let mut at = 2;
+            loop {
+                if *data.get(at)? != 0xFF {
+                    return None;
+                }
+                let marker = *data.get(at + 1)?;
+                if (0xC0..=0xCF).contains(&marker) && ![0xC4, 0xC8, 0xCC].contains(&marker) {
+                    return Some((be16(at + 7)?, be16(at + 5)?));
+                }
+                at += 2 + be16(at + 2)? as usize;
+            }
+        }
+        _ => None,
+    };
+    size.filter(|&(width, height)| width > 0 && height > 0)
+}
+
+fn on() -> Option<Box<types::OnOffElement>> {
+    Some(Box::default())
+}
+
+fn string(value: &str) -> Option<Box<types::CTString>> {
+    Some(Box::new(types::CTString {
+        value: value.to_string(),
+        #[cfg(feature = "extra-attrs")]
+        extra_attrs: Default::default(),
+    }))
+}
+
+fn half_points(value: i64) -> Option<Box<types::HpsMeasureElement>> {
+    Some(Box::new(types::HpsMeasureElement {
+        value: value.to_string(),
+        #[cfg(feature = "extra-attrs")]
+        extra_attrs: Default::default(),
+    }))
+}
+
+fn color(value: &str) -> Option<Box<types::CTColor>> {
+    Some(Box::new(types::CTColor {
+        value: value.to_string(),
+        theme_color: None,
+        theme_tint: None,
+        theme_shade: None,
+        #[cfg(feature = "extra-attrs")]
+        extra_attrs: Default::default(),
+    }))
+}
+
+fn single_underline() -> Option<Box<types::CTUnderline>> {
+    Some(Box::new(types::CTUnderline {
+        value: Some(types::STUnderline::Single),
+        color: None,
+        theme_color: None,
+        theme_tint: None,
+        theme_shade: None,
+        #[cfg(feature = "extra-attrs")]
+        extra_attrs: Default::default(),
+    }))
+}
+
+fn spacing(before: u32, after: u32) -> Option<Box<types::CTSpacing>> {
+    Some(Box::new(types::CTSpacing {
+        before: Some(before.to_string()),
+        after: Some(after.to_string()),
+        ..Default::default()
+    }))
+}
+
+fn indentation(left: u32, right: u32) -> Option<Box<types::CTInd>> {
+    Some(Box::new(types::CTInd {
+        left: Some(left.to_string()),
+        right: (right > 0).then(|| right.to_string()),
+        ..Default::default()
+    }))
+}
+
+fn monospace() -> Option<Box<types::Fonts>> {
+    let font = Some("Courier New".to_string());
+    Some(Box::new(types::Fonts {
+        ascii: font.clone(),
+        h_ansi: font.clone(),
+        cs: font,
+        ..Default::default()
+    }))
+}
+
+fn table_border() -> Option<Box<types::CTBorder>> {
+    Some(Box::new(types::CTBorder {
+        value: types::STBorder::Single,
+        color: Some("auto".to_string()),
+        theme_color: None,
+        theme_tint: None,
+        theme_shade: None,
+        size: Some(4),
+        space: Some(0),
+        shadow: None,
+        frame: None,
+        #[cfg(feature = "extra-attrs")]
+        extra_attrs: Default::default(),
+    }))
+}
+
+/// Definition of a built-in style the importer uses, modelled on Word's.
+fn builtin_style(id: &str) -> Option<types::Style> {
+    use types::STStyleType::{Character, Paragraph, Table};
+    let mut table_properties = None;
+    let (kind, name, p_pr, r_pr) = match id {
+        _ if HEADING_STYLES.contains(&id) => {
+            let level = HEADING_STYLES.iter().position(|&style| style == id)?;
+            let p_pr = types::CTPPrGeneral {
+                keep_next: on(),
+                spacing: spacing(240, 80),
+                outline_lvl: Some(Box::new(types::CTDecimalNumber {
+                    value: level as i64,
+                    #[cfg(feature = "extra-attrs")]
+                    extra_attrs: Default::default(),
+                })),
+                ..Default::default()
+            };
+            let r_pr = types::RunProperties {
+                bold: on(),
+                size: half_points(HEADING_SIZES[level]),
+                ..Default::default()
+            };
+            let name = format!("heading {}", level + 1);
+            (Paragraph, name, Some(p_pr), Some(r_pr))
+        }
+        LIST_STYLE => {
+            let p_pr = types::CTPPrGeneral {
+                indentation: indentation(INDENT_TWIPS, 0),
+                contextual_spacing: on(),
+                ..Default::default()
+            };
+            (Paragraph, "List Paragraph".into(), Some(p_pr), None)
+        }
+        QUOTE_STYLE => {
+            let p_pr = types::CTPPrGeneral {
+                indentation: indentation(INDENT_TWIPS, INDENT_TWIPS),
+                ..Default::default()
+            };
+            let r_pr = types::RunProperties {
+                italic: on(),
+                color: color("404040"),
+                ..Default::default()
+            };
+            (Paragraph, "Quote".into(), Some(p_pr), Some(r_pr))
+        }
+        CODE_BLOCK_STYLE => {
+            let p_pr = types::CTPPrGeneral {
+                spacing: spacing(0, 0),
+                ..Default::default()
+            };
+            let r_pr = types::RunProperties {
+                fonts: monospace(),
+                size: half_points(20),
+                ..Default::default()
+            };
+            (
+                Paragraph,
+                "HTML Preformatted".into(),
+                Some(p_pr),
+                Some(r_pr),
+            )
+        }
+        NOTE_STYLE => {
+            let p_pr = types::CTPPrGeneral {
+                spacing: spacing(0, 0),
+                ..Default::default()
+            };
+            let r_pr = types::RunProperties {
+                size: half_points(20),
+                ..Default::default()
+            };
+            (Paragraph, "footnote text".into(), Some(p_pr), Some(r_pr))
+        }
+        CODE_STYLE => {
+            let r_pr = types::RunProperties {
+                fonts: monospace(),
+                size: half_points(20),
+                ..Default::default()
+            };
+            (Character, "HTML Code".into(), None, Some(r_pr))
+        }
+        LINK_STYLE => {
+            let r_pr = types::RunProperties {
+                color: color("0563C1"),
+                underline: single_underline(),
+                ..Default::default()
+            };
+            (Character, "Hyperlink".into(), None, Some(r_pr))
+        }
+        NOTE_REFERENCE_STYLE => {
+            let r_pr = types::RunProperties {
+                vert_align: Some(Box::new(types::CTVerticalAlignRun {
+                    value: types::STVerticalAlignRun::Superscript,
+                    #[cfg(feature = "extra-attrs")]
+                    extra_attrs: Default::default(),
+                })),
+                ..Default::default()
+            };
+            (Character, "footnote reference".into(), None, Some(r_pr))
+        }
+        TABLE_STYLE => {
+            table_properties = Some(Box::new(types::CTTblPrBase {
+                tbl_borders: Some(Box::new(types::CTTblBorders {
+                    top: table_border(),
+                    left: table_border(),
+                    bottom: table_border(),
+                    right: table_border(),
+                    inside_h: table_border(),
+                    inside_v: table_border(),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            }));
+            (Table, "Table Grid".into(), None, None)
+        }
+        _ => return None,
+    };
+    Some(types::Style {
+        r#type: Some(kind),
+        style_id: Some(id.to_string()),
+        name: string(&name),
+        q_format: on(),
+        p_pr: p_pr.map(Box::new),
+        r_pr: r_pr.map(Box::new),
+        table_properties,
+        ..Default::default()
+    })
+}
+
+/// A token of HTML.
+enum Token {
+    Start {
+        name: String,
+        attrs: Vec<(String, String)>,
+    },
+    End(String),
+    Text(String),
+}
+
+impl Token {
+    fn attr(&self, attr: &str) -> Option<&str> {
+        match self {
+            Token::Start { attrs, .. } => attrs
+                .iter()
+                .find(|(name, _)| name == attr)
+                .map(|(_, value)| value.as_str()),
+            _ => None,
+        }
+    }
+}
+
+/// Elements whose content is not imported.
+const SKIPPED_ELEMENTS: [&str; 4] = ["script", "style", "title", "textarea"];
+
+/// Split HTML into tags and text, with element and attribute names
+/// lowercased and entities decoded. Comments, doctypes and skipped elements
+/// are dropped.
+fn tokenize(html: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+    let starts_name = |s: &str| s.starts_with(|c: char| c.is_ascii_alphabetic());
+    while !rest.is_empty() {
+        if let Some(tail) = rest.strip_prefix("<!--") {
+            rest = tail.find("-->").map_or("", |end| &tail[end + 3..]);
+        } else if rest.starts_with("<!") || rest.starts_with("<?") {
+            rest = rest.find('>').map_or("", |end| &rest[end + 1..]);
+        } else if let Some(tail) = rest.strip_prefix("</")
+            && starts_name(tail)
+        {
+            let end = tail.find('>').unwrap_or(tail.len());
+            tokens.push(Token::End(tail[..end].trim().to_ascii_lowercase()));
+            rest = tail.get(end + 1..).unwrap_or("");
+        } else if let Some(tail) = rest.strip_prefix('<')
+            && starts_name(tail)
+        {
+            let (token, tail) = start_tag(tail);
+            rest = tail;
+            if let Token::Start { name, .. } = &token
+                && SKIPPED_ELEMENTS.contains(&name.as_str())
+            {
+                let close = format!("</{name}");
+                rest = match rest.to_ascii_lowercase().find(&close) {
+                    Some(at) => rest[at..].find('>').map_or("", |end| &rest[at + end + 1..]),
+                    None => "",
+                };
+            } else {
+                tokens.push(token);
+            }
+        } else {
+            let end = rest
+                .char_indices()
+                .skip(1)
+                .find(|&(_, c)| c == '<')
+                .map_or(rest.len(), |(at, _)| at);
+            tokens.push(Token::Text(decode_entities(&rest[..end])));
+            rest = &rest[end..];
+        }
+    }
+    tokens
+}
+
+/// Parse a start tag after its `<`, returning the rest of the input.
+fn start_tag(tag: &str) -> (Token, &str) {
+    let end = tag
+        .find(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/')
+        .unwrap_or(tag.len());
+    let name = tag[..end].to_ascii_lowercase();
+    let mut rest = &tag[end..];
+    let mut attrs = Vec::new();
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_ascii_whitespace() || c == '/');
+        if let Some(tail) = rest.strip_prefix('>') {
+            rest = tail;
+            break;
+        }
+        let Some(first) = rest.chars().next() else {
+            break;
+        };
+        let end = rest
+            .find(|c: char| c.is_ascii_whitespace() || "=>/".contains(c))
+            .unwrap_or(rest.len());
+        if end == 0 {
+            rest = &rest[first.len_utf8()..];
+            continue;
+        }
+        let attr = rest[..end].to_ascii_lowercase();
+        rest = rest[end..].trim_start();
+        let mut value = String::new();
+        if let Some(tail) = rest.strip_prefix('=') {
+            let tail = tail.trim_start();
+            let (raw, tail) = match tail.chars().next() {
+                Some(quote @ ('"' | '\'')) => {
+                    let body = &tail[1..];
+                    let end = body.find(quote).unwrap_or(body.len());
+                    (&body[..end], body.get(end + 1..).unwrap_or(""))
+                }
+                _ => {
+                    let end = tail
+                        .find(|c: char| c.is_ascii_whitespace() || c == '>')
+                        .unwrap_or(tail.len());
+                    tail.split_at(end)
+                }
+            };
+            value = decode_entities(raw);
+            rest = tail;
+        }
+        attrs.push((attr, value));
+    }
+    (Token::Start { name, attrs }, rest)
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let decoded = rest[1..]
+            .find(';')
+            .filter(|&end| end <= 32)
+            .and_then(|end| Some((entity(&rest[1..end + 1])?, end + 2)));
+        match decoded {
+            Some((c, len)) => {
+                out.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn entity(name: &str) -> Option<char> {
+    if let Some(number) = name.strip_prefix('#') {
+        let code = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => number.parse().ok()?,
+        };
+        return char::from_u32(code);
+    }
+    Some(match name {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => '\u{A0}',
+        "ndash" => '\u{2013}',
+        "mdash" => '\u{2014}',
+        "hellip" => '\u{2026}',
+        "lsquo" => '\u{2018}',
+        "rsquo" => '\u{2019}',
+        "ldquo" => '\u{201C}',
+        "rdquo" => '\u{201D}',
+        "bull" => '\u{2022}',
+        "middot" => '\u{B7}',
+        "times" => '\u{D7}',
+        "deg" => '\u{B0}',
+        "copy" => '\u{A9}',
+        "reg" => '\u{AE}',
+        "trade" => '\u{2122}',
+        "euro" => '\u{20AC}',
+        _ => return None,
+    })
+}
+
+/// Elements that separate blocks without being imported themselves.
+const CONTAINER_ELEMENTS: [&str; 22] = [
+    "html",
+    "body",
+    "div",
+    "section",
+    "article",
+    "header",
+    "footer",
+    "main",
+    "nav",
+    "aside",
+    "figure",
+    "figcaption",
+    "address",
+    "center",
+    "details",
+    "summary",
+    "dl",
+    "dt",
+    "dd",
+    "form",
+    "fieldset",
+    "caption",
+];
+
+/// How an open HTML element is closed.
+enum Close {
+    /// A block element, with the event ending it.
+    Block(Option<TagEnd>),
+    /// An inline element, with the event ending it.
+    Inline(Event<'static>),
+}
+
+/// Converts HTML tokens to the Markdown parser's events, so both go through
+/// the same importer.
+#[derive(Default)]
+struct HtmlConverter {
+    events: Vec<Event<'static>>,
+    /// Open elements, innermost last.
+    open: Vec<(String, Close)>,
+    /// End of the open paragraph or heading.
+    block: Option<TagEnd>,
+    /// Whether the open block has content.
+    started: bool,
+    /// Whitespace waiting for more content in the block.
+    space: bool,
+    /// Depth of `<pre>` elements.
+    pre: usize,
+    /// Whether `<pre>` content has started.
+    pre_started: bool,
+    thead: bool,
+}
+
+fn html_events(html: &str) -> Vec<Event<'static>> {
+    let tokens = tokenize(html);
+    let mut converter = HtmlConverter::default();
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Start { name, .. } => converter.start(name, token, &tokens[i + 1..]),
+            Token::End(name) => converter.end(name),
+            Token::Text(text) => converter.text(text),
+        }
+    }
+    converter.close_to(0);
+    converter.end_block();
+    converter.events
+}
+
+impl HtmlConverter {
+    fn start(&mut self, name: &str, token: &Token, rest: &[Token]) {
+        if self.pre > 0 {
+            return;
+        }
+        let heading = match name {
+            "h1" => Some(HeadingLevel::H1),
+            "h2" => Some(HeadingLevel::H2),
+            "h3" => Some(HeadingLevel::H3),
+            "h4" => Some(HeadingLevel::H4),
+            "h5" => Some(HeadingLevel::H5),
+            "h6" => Some(HeadingLevel::H6),
+            _ => None,
+        };
+        if let Some(level) = heading {
+            self.end_block();
+            self.events.push(Event::Start(Tag::Heading {
+                level,
+                id: token.attr("id").map(|id| CowStr::from(id.to_string())),
+                classes: Vec::new(),
+                attrs: Vec::new(),
+            }));
+            self.block = Some(TagEnd::Heading(level));
+            self.started = false;
+            return;
+        }
+        match name {
+            "p" => {
+                self.end_block();
+                self.events.push(Event::Start(Tag::Paragraph));
+                self.block = Some(TagEnd::Paragraph);
+                self.started = false;
+            }
+            "blockquote" => self.container(name, Tag::BlockQuote(None)),
+            "ul" => self.container(name, Tag::List(None)),
+            "ol" => {
+                let start = token.attr("start").and_then(|s| s.trim().parse().ok());
+                self.container(name, Tag::List(Some(start.unwrap_or(1))));
+            }
+            "li" => {
+                self.close_open(&["li"], &["ul", "ol"]);
+                self.container(name, Tag::Item);
+            }
+            "pre" => {
+                self.container(name, Tag::CodeBlock(CodeBlockKind::Indented));
+                self.pre += 1;
+                self.pre_started = false;
+            }
+            "table" => self.container(name, Tag::Table(Vec::new())),
+            "thead" => {
+                self.close_open(&["tr"], &["table"]);
+                self.thead = true;
+                self.open.push((name.to_string(), Close::Block(None)));
+            }
+            "tbody" | "tfoot" => {
+                self.close_open(&["thead", "tr"], &["table"]);
+                self.open.push((name.to_string(), Close::Block(None)));
+            }
+            "tr" => {
+                self.close_open(&["tr"], &["table", "thead", "tbody", "tfoot"]);
+                // A row is the header in <thead> or when it starts with <th>
+                let header = self.thead
+                    || rest
+                        .iter()
+                        .find_map(|token| match token {
+                            Token::Start { name, .. } if name == "th" || name == "td" => {
+                                Some(name == "th")
+                            }
+                            Token::Start { name, .. } | Token::End(name) if name == "tr" => {
+                                Some(false)
+                            }
+                            _ => None,
+                        })
+                        .unwrap_or(false);
+                let tag = if header {
+                    Tag::TableHead
+                } else {
+                    Tag::TableRow
+                };
+                self.container(name, tag);
+            }
+            "td" | "th" => {
+                self.close_open(&["td", "th"], &["tr", "table"]);
+                self.container(name, Tag::TableCell);
+            }
+            "hr" => {
+                self.end_block();
+                self.events.push(Event::Rule);
+            }
+            "br" => {
+                self.ensure_block();
+                self.events.push(Event::HardBreak);
+                self.started = false;
+                self.space = false;
+            }
+            "img" => {
+                self.ensure_block();
+                self.flush_space();
+                self.events.push(Event::Start(Tag::Image {
+                    link_type: LinkType::Inline,
+                    dest_url: token.attr("src").unwrap_or_default().to_string().into(),
+                    title: CowStr::Borrowed(""),
+                    id: CowStr::Borrowed(""),
+                }));
+                if let Some(alt) = token.attr("alt") {
+                    self.events.push(Event::Text(alt.to_string().into()));
+                }
+                self.events.push(Event::End(TagEnd::Image));
+                self.started = true;
+            }
+            "a" => {
+                if let Some(href) = token.attr("href") {
+                    let tag = Tag::Link {
+                        link_type: LinkType::Inline,
+                        dest_url: href.to_string().into(),
+                        title: CowStr::Borrowed(""),
+                        id: CowStr::Borrowed(""),
+                    };
+                    self.inline(name, Event::Start(tag), Event::End(TagEnd::Link));
+                }
+            }
+            "strong" | "b" => self.inline_tag(name, Tag::Strong),
+            "em" | "i" | "cite" | "var" | "dfn" => self.inline_tag(name, Tag::Emphasis),
+            "s" | "del" | "strike" => self.inline_tag(name, Tag::Strikethrough),
+            "sup" => self.inline_tag(name, Tag::Superscript),
+            "sub" => self.inline_tag(name, Tag::Subscript),
+            // Formatting without a Markdown equivalent goes through inline HTML
+            "u" | "ins" | "code" | "kbd" | "samp" | "tt" => self.inline(
+                name,
+                Event::InlineHtml(format!("<{name}>").into()),
+                Event::InlineHtml(format!("</{name}>").into()),
+            ),
+            _ if CONTAINER_ELEMENTS.contains(&name) => {
+                self.end_block();
+                self.open.push((name.to_string(), Close::Block(None)));
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, name: &str) {
+        match name {
+            "p" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => self.end_block(),
+            _ => {
+                if let Some(at) = self.open.iter().rposition(|(open, _)| open == name) {
+                    self.close_to(at);
+                }
+            }
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if self.pre > 0 {
+            let text = match self.pre_started {
+                false => text.strip_prefix('\n').unwrap_or(text),
+                true => text,
+            };
+            self.pre_started = true;
+            self.events.push(Event::Text(text.to_string().into()));
+            return;
+        }
+        let mut collapsed = String::with_capacity(text.len());
+        for c in text.chars() {
+            match c.is_ascii_whitespace() {
+                true if collapsed.ends_with(' ') => {}
+                true => collapsed.push(' '),
+                false => collapsed.push(c),
+            }
+        }
+        let trimmed = collapsed.trim_matches(' ');
+        if trimmed.is_empty() {
+            if self.block.is_some() && !collapsed.is_empty() {
+                self.space = true;
+            }
+            return;
+        }
+        self.ensure_block();
+        if collapsed.starts_with(' ') {
+            self.space = true;
+        }
+        self.flush_space();
+        self.events.push(Event::Text(trimmed.to_string().into()));
+        self.started = true;
+        self.space = collapsed.ends_with(' ');
+    }
+
+    /// Emit whitespace held back, unless at the start of the block.
+    fn flush_space(&mut self) {
+        if std::mem::take(&mut self.space) && self.started {
+            self.events.push(Event::Text(" ".into()));
+        }
+    }
+
+    fn ensure_block(&mut self) {
+        if self.block.is_none() {
+            self.events.push(Event::Start(Tag::Paragraph));
+            self.block = Some(TagEnd::Paragraph);
+            self.started = false;
+            self.space = false;
+        }
+    }
+
+    /// End the open paragraph or heading, with inline elements in it.
+    fn end_block(&mut self) {
+        while let Some((_, Close::Inline(_))) = self.open.last() {
+            if let Some((_, Close::Inline(end))) = self.open.pop() {
+                self.events.push(end);
+            }
+        }
+        if let Some(end) = self.block.take() {
+            self.events.push(Event::End(end));
+        }
+        self.space = false;
+    }
+
+    fn container(&mut self, name: &str, tag: Tag<'static>) {
+        self.end_block();
+        let end = tag.to_end();
+        self.events.push(Event::Start(tag));
+        self.open.push((name.to_string(), Close::Block(Some(end))));
+    }
+
+    fn inline_tag(&mut self, name: &str, tag: Tag<'static>) {
+        let end = Event::End(tag.to_end());
+        self.inline(name, Event::Start(tag), end);
+    }
+
+    fn inline(&mut self, name: &str, start: Event<'static>, end: Event<'static>) {
+        self.ensure_block();
+        self.flush_space();
+        self.events.push(start);
+        self.open.push((name.to_string(), Close::Inline(end)));
+    }
+
+    /// Close the innermost of `names` left open, unless one of `boundaries`
+    /// is more inner.
+    fn close_open(&mut self, names: &[&str], boundaries: &[&str]) {
+        for at in (0..self.open.len()).rev() {
+            let name = self.open[at].0.as_str();
+            if names.contains(&name) {
+                self.close_to(at);
+                return;
+            }
+            if boundaries.contains(&name) {
+                return;
+            }
+        }
+    }
+
+    /// Close the open elements from `at` inwards.
+    fn close_to(&mut self, at: usize) {
+        while self.open.len() > at {
+            let Some((name, close)) = self.open.pop() else {
+                break;
+            };
+            match close {
+                Close::Inline(end) => self.events.push(end),
+                Close::Block(end) => {
+                    self.end_block();
+                    if let Some(end) = end {
+                        self.events.push(Event::End(end));
+                    }
+                }
+            }
+            match name.as_str() {
+                "pre" => self.pre = self.pre.saturating_sub(1),
+                "thead" => self.thead = false,
+                _ => {}
+            }
+        }
+    }
+}
@@ -27,7 +27,15 @@
 pub mod convenience;
 pub mod document;
 pub mod error;
+#[cfg(any(feature = "html", feature = "markdown"))]
+mod export;
 pub mod ext;
+#[cfg(feature = "html")]
+pub mod html;
+#[cfg(feature = "import")]
+pub mod import;
+#[cfg(feature = "markdown")]
+pub mod markdown;
 pub mod writer;
 
 /// Generated types from the ECMA-376 WordprocessingML schema.
@@ -57,11 +65,50 @@ pub mod generated_serializers;
 /// Serializers for the generated ECMA-376 types. See [`generated_serializers`] for details.
 pub use generated_serializers as serializers;
 
+/// Borrowed views of the generated types, parsed without copying strings.
+///
+/// Every type containing text is redefined with a lifetime, its strings as
+/// `Cow<'a, str>` slices of the input. Parse them with
+/// [`ooxml_xml::parse_borrowed`] from a part read into memory, and convert
+/// them with [`ooxml_xml::IntoOwned`] when they must outlive it. Unknown
+/// elements and attributes are skipped rather than captured. Derived from
+/// [`generated`] and [`generated_parsers`] by `ooxml-codegen`.
+///
+/// Only available with the opt-in `borrowed` feature, which is not part of
+/// `full`. The other format crates have no borrowed views yet.
+///
+/// Re-exported as [`borrowed`].
+#[cfg(feature = "borrowed")]
+pub mod generated_borrowed;
+/// Borrowed views of the generated ECMA-376 types. See [`generated_borrowed`] for details.
+#[cfg(feature = "borrowed")]
+pub use generated_borrowed as borrowed;
+
+/// `Visit`/`VisitMut` traversal of the generated types.
+///
+/// Each trait has a method per generated type that defaults to visiting its
+/// fields, variants and unknown children (as raw XML) in declaration order;
+/// override the methods of interest and call the matching `walk_*` function
+/// to keep descending.
+///
+/// The module also implements [`ooxml_xml::QueryTree`] for the generated
+/// types, so [`ooxml_xml::Query::select_tree`] can run path queries over them
+/// as the XML they serialize to. Derived from [`generated`] and
+/// [`generated_serializers`] by `ooxml-codegen`.
+///
+/// Re-exported as [`visit`].
+#[cfg(feature = "visit")]
+pub mod generated_visit;
+/// Traversal of the generated ECMA-376 types. See [`generated_visit`] for details.
+#[cfg(feature = "visit")]
+pub use generated_visit as visit;
+
 // Metadata types from document.rs (OPC, not WML — not generated).
 pub use document::{AppProperties, CoreProperties, Document, DocumentSettings, ImageData};
 
 // Error types — always available.
 pub use error::{Error, ParseContext, Result, position_to_line_col};
+pub use ooxml_opc::{Conformance, PackageLimits, ParseLimits, SerializeOptions, VbaProject};
 pub use ooxml_xml::{PositionedAttr, PositionedNode, RawXmlElement, RawXmlNode};
 
 // Writer types.
@@ -0,0 +1,1007 @@
+//! Markdown export.
+//!
+//! [`Document::to_markdown`] renders a document as CommonMark with GitHub
+//! Flavored Markdown extensions, keeping the structure that plain
+//! [`Document::text`] loses, e.g. for wiki import or as input to language
+//! models:
+//!
+//! - Paragraphs with an outline level, set directly or by their style, become
+//!   ATX headings (`#` to `######`). Paragraphs whose style is named like a
+//!   code style ("Source Code", "HTML Preformatted") become fenced code
+//!   blocks.
+//! - Numbered paragraphs become `-` (bullet levels) or `1.` (other number
+//!   formats) list items, nested by list level.
+//! - Bold, italic and strikethrough runs become `**`, `*` and `~~`; runs in a
+//!   code character style or a monospace font become code spans.
+//! - Tables become GFM pipe tables, with the first row as the header.
+//! - Hyperlinks become links to their relationship target or `#bookmark`,
+//!   and bookmarks become `<a id>` anchors.
+//! - Footnotes and endnotes become GFM footnotes (`[^1]`), numbered in order
+//!   of reference and defined after the body.
+//! - Equations become `$...$`, or `$$...$$` for display math, in LaTeX (see
+//!   [`MathZone::to_latex`](ooxml_omml::MathZone::to_latex)).
+//! - Images become `![description](target)`, with the target relative to the
+//!   document part (e.g. `media/image1.png`).
+//!
+//! What Markdown cannot express degrades predictably:
+//!
+//! - Merged cells keep their content in their first grid cell; the other
+//!   cells they cover are left empty. Content of a cell is joined into one
+//!   line with `<br>`, and tables nested in cells are flattened into it.
+//! - Floating content — anchored images and text boxes — follows the
+//!   paragraph it is anchored in, as paragraphs of its own.
+//! - Letter and roman numbering is written with decimal numbers.
+//!
+//! Tracked changes are shown accepted. Hidden text, field codes, charts and
+//! comments are left out, as are hyperlinks and images inside notes, whose
+//! relationships live in other parts.
+//!
+//! ```ignore
+//! use ooxml_wml::Document;
+//! use ooxml_wml::markdown::MarkdownOptions;
+//!
+//! let mut doc = Document::open("input.docx")?;
+//! let markdown = doc.to_markdown(&MarkdownOptions::default())?;
+//! std::fs::write("output.md", markdown)?;
+//! ```
+
+use std::fmt::Write as _;
+use std::io::{Read, Seek};
+
+use ooxml_opc::Relationships;
+
+use crate::document::Document;
+use crate::error::Result;
+use crate::export::{
+    self, Block, DRAWING_DESCRIPTION, drawing_value, heading_level, optional_part, symbol_char,
+    table_grid,
+};
+use crate::ext::{
+    DrawingExt, DrawingTextBoxExt, NumberingContext, PictExt, RunResolveExt, StyleContext,
+    collect_math_from_raw,
+};
+use crate::types;
+
+/// Options for [`Document::to_markdown`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownOptions {
+    /// Render footnotes and endnotes as GFM footnotes. When disabled, note
+    /// references are left out.
+    pub notes: bool,
+    /// Render images as `![description](target)`.
+    pub images: bool,
+    /// Render bookmarks as `<a id="name"></a>`, so that internal links have a
+    /// target.
+    pub anchors: bool,
+}
+
+impl Default for MarkdownOptions {
+    fn default() -> Self {
+        Self {
+            notes: true,
+            images: true,
+            anchors: true,
+        }
+    }
+}
+
+impl MarkdownOptions {
+    /// Only text and its structure: no notes, images or anchors.
+    pub fn text_only() -> Self {
+        Self {
+            notes: false,
+            images: false,
+            anchors: false,
+        }
+    }
+}
+
+impl<R: Read + Seek> Document<R> {
+    /// Render the document as Markdown.
+    ///
+    /// Reads the numbering, footnotes and endnotes parts as needed; a
+    /// document without them renders without lists or notes. See the
+    /// [`markdown`](crate::markdown) module for what is rendered.
+    pub fn to_markdown(&mut self, options: &MarkdownOptions) -> Result<String> {
+        let numbering = self.numbering_context()?;
+        let footnotes = match options.notes {
+            true => optional_part(self.get_footnotes())?,
+            false => None,
+        };
+        let endnotes = match options.notes {
+            true => optional_part(self.get_endnotes())?,
+            false => None,
+        };
+        let styles = StyleContext::from_styles(self.styles());
+
+        let mut renderer = Renderer::new(options, styles, numbering, self.doc_relationships());
+        renderer.blocks(&self.body().block_content);
+        renderer.end_blocks();
+        renderer.notes(
+            footnotes.as_ref().map_or(&[], |notes| &notes.footnote),
+            endnotes.as_ref().map_or(&[], |notes| &notes.endnote),
+        );
+        Ok(renderer.out)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NoteKind {
+    Footnote,
+    Endnote,
+}
+
+/// Inline formatting with a Markdown equivalent.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Format {
+    bold: bool,
+    italic: bool,
+    strike: bool,
+    code: bool,
+}
+
+impl Format {
+    /// Emphasis delimiters, outermost first.
+    fn delimiters(self) -> Vec<&'static str> {
+        [(self.bold, "**"), (self.italic, "*"), (self.strike, "~~")]
+            .into_iter()
+            .filter_map(|(on, delimiter)| on.then_some(delimiter))
+            .collect()
+    }
+}
+
+/// Paragraph content, before it is written as Markdown.
+enum Inline {
+    /// Text to escape, with its formatting.
+    Text(Format, String),
+    /// Markdown written as is.
+    Markup(String),
+    /// A line break within the paragraph.
+    Break,
+    Math {
+        latex: String,
+        display: bool,
+    },
+}
+
+/// A list opened for a numbering level.
+struct OpenList {
+    num_id: i64,
+    ilvl: i64,
+    ordered: bool,
+    /// Column at which the content of its items starts.
+    indent: usize,
+    /// Whether the list uses the alternative marker (`*` or `)`), to keep it
+    /// apart from an adjacent list at the same level.
+    alternate: bool,
+}
+
+struct Renderer<'a> {
+    options: &'a MarkdownOptions,
+    styles: StyleContext,
+    numbering: NumberingContext,
+    rels: &'a Relationships,
+    out: String,
+    lists: Vec<OpenList>,
+    /// Lines of the code block being collected.
+    code: Option<String>,
+    /// Blocks to write after the current paragraph.
+    floating: Vec<String>,
+    /// Note IDs in order of first reference, footnotes and endnotes together.
+    note_refs: Vec<(NoteKind, i64)>,
+    /// Whether document part relationships apply to the content rendered.
+    in_body: bool,
+}
+
+impl<'a> Renderer<'a> {
+    fn new(
+        options: &'a MarkdownOptions,
+        styles: StyleContext,
+        numbering: NumberingContext,
+        rels: &'a Relationships,
+    ) -> Self {
+        Self {
+            options,
+            styles,
+            numbering,
+            rels,
+            out: String::new(),
+            lists: Vec::new(),
+            code: None,
+            floating: Vec::new(),
+            note_refs: Vec::new(),
+            in_body: true,
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Blocks
+    // -------------------------------------------------------------------------
+
+    fn blocks(&mut self, blocks: &[types::BlockContent]) {
+        for block in export::blocks(blocks) {
+            match block {
+                Block::Paragraph(para) => self.paragraph(para),
+                Block::Table(table) => self.table(table),
+            }
+        }
+    }
+
+    /// Close whatever is still open after the last block.
+    fn end_blocks(&mut self) {
+        self.end_code();
+        self.lists.clear();
+    }
+
+    /// Start a block, separated from the previous one by a blank line.
+    fn start_block(&mut self) {
+        self.end_code();
+        if !self.out.is_empty() {
+            self.out.push('\n');
+        }
+    }
+
+    /// Write a block, indented to `indent` columns.
+    fn write_block(&mut self, text: &str, indent: usize) {
+        self.start_block();
+        for line in text.lines() {
+            if !line.is_empty() {
+                self.out.extend(std::iter::repeat_n(' ', indent));
+            }
+            self.out.push_str(line);
+            self.out.push('\n');
+        }
+    }
+
+    fn paragraph(&mut self, para: &types::Paragraph) {
+        if self.is_code_paragraph(para) {
+            self.lists.clear();
+            let code = self.code_text(&para.paragraph_content);
+            let block = self.code.get_or_insert_with(String::new);
+            block.push_str(&code);
+            block.push('\n');
+            return;
+        }
+
+        let inlines = self.paragraph_inlines(para);
+        if let Some((num_id, ilvl)) = self.numbering.paragraph_numbering(para, &self.styles) {
+            let text = write_inlines(&inlines, false);
+            self.list_item(num_id, ilvl, &text);
+        } else {
+            self.lists.clear();
+            match (heading_level(&self.styles, para), inlines.as_slice()) {
+                (
+                    _,
+                    [
+                        Inline::Math {
+                            latex,
+                            display: true,
+                        },
+                    ],
+                ) => {
+                    let block = format!("$$\n{latex}\n$$");
+                    self.write_block(&block, 0);
+                }
+                (Some(level), _) => {
+                    let text = write_inlines(&inlines, false).replace("\\\n", " ");
+                    if !text.is_empty() {
+                        let block = format!("{} {text}", "#".repeat(level));
+                        self.write_block(&block, 0);
+                    }
+                }
+                (None, _) => {
+                    let text = escape_line_starts(&write_inlines(&inlines, false));
+                    if !text.is_empty() {
+                        self.write_block(&text, 0);
+                    }
+                }
+            }
+        }
+
+        let indent = self.lists.last().map_or(0, |list| list.indent);
+        for block in std::mem::take(&mut self.floating) {
+            self.write_block(&block, indent);
+        }
+    }
+
+    /// Whether a paragraph's style is named like a code style.
+    fn is_code_paragraph(&self, para: &types::Paragraph) -> bool {
+        para.p_pr
+            .as_deref()
+            .and_then(|p| p.paragraph_style.as_deref())
+            .is_some_and(|style| is_code_style(&self.styles, &style.value))
+    }
+
+    /// Write the code block being collected, if any.
+    fn end_code(&mut self) {
+        let Some(code) = self.code.take() else {
+            return;
+        };
+        // Use a fence longer than any run of backticks in the code
+        let mut fence = "```".to_string();
+        while code.contains(fence.as_str()) {
+            fence.push('`');
+        }
+        if !self.out.is_empty() {
+            self.out.push('\n');
+        }
+        let _ = write!(self.out, "{fence}\n{code}{fence}\n");
+    }
+
+    // -------------------------------------------------------------------------
+    // Lists
+    // -------------------------------------------------------------------------
+
+    fn list_item(&mut self, num_id: i64, ilvl: i64, text: &str) {
+        let level = self.numbering.level(num_id, ilvl);
+        let ordered = !matches!(
+            level
+                .and_then(|lvl| lvl.num_fmt.as_deref())
+                .map(|fmt| fmt.value),
+            None | Some(types::STNumberFormat::Bullet) | Some(types::STNumberFormat::None)
+        );
+        if self.lists.is_empty() {
+            self.start_block();
+        }
+        while self.lists.last().is_some_and(|list| list.ilvl > ilvl) {
+            self.lists.pop();
+        }
+        let mut alternate = false;
+        if let Some(list) = self.lists.last()
+            && list.ilvl == ilvl
+            && (list.num_id != num_id || list.ordered != ordered)
+        {
+            // A different list at the same level: switching the marker
+            // starts a new Markdown list
+            alternate = !list.alternate;
+            self.lists.pop();
+        }
+        if self.lists.last().is_none_or(|list| list.ilvl < ilvl) {
+            self.lists.push(OpenList {
+                num_id,
+                ilvl,
+                ordered,
+                indent: 0,
+                alternate,
+            });
+        }
+
+        let number = self.numbering.next(num_id, ilvl).unwrap_or(1);
+
+        let alternate = self.lists.last().is_some_and(|list| list.alternate);
+        let marker = match (ordered, alternate) {
+            (true, false) => format!("{number}."),
+            (true, true) => format!("{number})"),
+            (false, false) => "-".to_string(),
+            (false, true) => "*".to_string(),
+        };
+        let depth = self.lists.len();
+        let parent_indent = match depth {
+            1 => 0,
+            _ => self.lists[depth - 2].indent,
+        };
+        let indent = parent_indent + marker.len() + 1;
+        if let Some(list) = self.lists.last_mut() {
+            list.indent = indent;
+        }
+
+        let pad = " ".repeat(parent_indent);
+        let _ = write!(self.out, "{pad}{marker}");
+        let text = escape_line_starts(text);
+        for (i, line) in text.lines().enumerate() {
+            if i == 0 {
+                self.out.push(' ');
+            } else {
+                self.out.push_str(&" ".repeat(indent));
+            }
+            self.out.push_str(line);
+            self.out.push('\n');
+        }
+        if text.is_empty() {
+            self.out.push('\n');
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Tables
+    // -------------------------------------------------------------------------
+
+    fn table(&mut self, table: &types::Table) {
+        self.lists.clear();
+        let grid = table_grid(table);
+        let columns = grid
+            .iter()
+            .filter_map(|row| row.last().map(|cell| cell.col + cell.span))
+            .max()
+            .unwrap_or(0);
+        if columns == 0 {
+            return;
+        }
+
+        let mut lines = Vec::new();
+        for (r, row) in grid.iter().enumerate() {
+            let mut cells = vec![String::new(); columns];
+            for cell in row {
+                // Merged cells keep their content in their first grid cell
+                if cell.merge != Some(types::STMerge::Continue) {
+                    cells[cell.col] = self.cell_text(&cell.cell.block_content);
+                }
+            }
+            lines.push(format!("| {} |", cells.join(" | ")));
+            if r == 0 {
+                lines.push(format!("|{}", " --- |".repeat(columns)));
+            }
+        }
+        self.write_block(&lines.join("\n"), 0);
+        for block in std::mem::take(&mut self.floating) {
+            self.write_block(&block, 0);
+        }
+    }
+
+    /// Content of a table cell on one line.
+    fn cell_text(&mut self, blocks: &[types::BlockContent]) -> String {
+        let mut lines = Vec::new();
+        for block in export::blocks(blocks) {
+            match block {
+                Block::Paragraph(para) => {
+                    let inlines = self.paragraph_inlines(para);
+                    let text = write_inlines(&inlines, true);
+                    if !text.is_empty() {
+                        lines.push(text);
+                    }
+                }
+                Block::Table(table) => {
+                    for row in table_grid(table) {
+                        for cell in row {
+                            let text = self.cell_text(&cell.cell.block_content);
+                            if !text.is_empty() {
+                                lines.push(text);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        lines.join("<br>")
+    }
+
+    // -------------------------------------------------------------------------
+    // Inline content
+    // -------------------------------------------------------------------------
+
+    /// Content of a paragraph, with equations in place.
+    fn paragraph_inlines(&mut self, para: &types::Paragraph) -> Vec<Inline> {
+        let mut inlines = Vec::new();
+        // Equations are unknown children, kept apart from the content with
+        // their position among all children, `pPr` included
+        let mut extras = para.extra_children.iter().peekable();
+        let mut position = usize::from(para.p_pr.is_some());
+        for item in &para.paragraph_content {
+            while let Some(extra) = extras.next_if(|extra| extra.position <= position) {
+                math_inlines(&extra.node, &mut inlines);
+                position += 1;
+            }
+            self.paragraph_content(std::slice::from_ref(item), &mut inlines);
+            position += 1;
+        }
+        for extra in extras {
+            math_inlines(&extra.node, &mut inlines);
+        }
+        inlines
+    }
+
+    fn paragraph_content(&mut self, content: &[types::ParagraphContent], out: &mut Vec<Inline>) {
+        for item in content {
+            match item {
+                types::ParagraphContent::R(run) => self.run(run, out),
+                types::ParagraphContent::Hyperlink(link) => self.hyperlink(link, out),
+                types::ParagraphContent::FldSimple(field) => {
+                    self.paragraph_content(&field.paragraph_content, out)
+                }
+                types::ParagraphContent::Sdt(sdt) => {
+                    if let Some(content) = &sdt.sdt_content {
+                        self.paragraph_content(&content.paragraph_content, out);
+                    }
+                }
+                types::ParagraphContent::CustomXml(custom) => {
+                    self.paragraph_content(&custom.paragraph_content, out)
+                }
+                types::ParagraphContent::SmartTag(tag) => {
+                    self.paragraph_content(&tag.paragraph_content, out)
+                }
+                types::ParagraphContent::Dir(dir) => {
+                    self.paragraph_content(&dir.paragraph_content, out)
+                }
+                types::ParagraphContent::Bdo(bdo) => {
+                    self.paragraph_content(&bdo.paragraph_content, out)
+                }
+                // Tracked changes are shown accepted
+                types::ParagraphContent::Ins(change) | types::ParagraphContent::MoveTo(change) => {
+                    for item in &change.run_content {
+                        if let types::RunContentChoice::R(run) = item {
+                            self.run(run, out);
+                        }
+                    }
+                }
+                types::ParagraphContent::BookmarkStart(bookmark)
+                    if self.options.anchors && bookmark.name != "_GoBack" =>
+                {
+                    out.push(Inline::Markup(format!(
+                        "<a id=\"{}\"></a>",
+                        escape_html(&bookmark.name)
+                    )));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn hyperlink(&mut self, link: &types::Hyperlink, out: &mut Vec<Inline>) {
+        let href = match (&link.id, &link.anchor) {
+            (Some(id), _) if self.in_body => self
+                .rels
+                .get(id)
+                .map(|rel| rel.target.clone())
+                .or_else(|| link.anchor.as_ref().map(|anchor| format!("#{anchor}"))),
+            (_, Some(anchor)) => Some(format!("#{anchor}")),
+            _ => None,
+        };
+        let mut content = Vec::new();
+        self.paragraph_content(&link.paragraph_content, &mut content);
+        match href {
+            Some(href) => {
+                let text = write_inlines(&content, false).replace("\\\n", " ");
+                let text = if text.is_empty() {
+                    escape_text(&href, false)
+                } else {
+                    text
+                };
+                out.push(Inline::Markup(format!(
+                    "[{text}]({})",
+                    link_destination(&href)
+                )));
+            }
+            None => out.extend(content),
+        }
+    }
+
+    fn run(&mut self, run: &types::Run, out: &mut Vec<Inline>) {
+        if run.resolved_is_hidden(&self.styles) {
+            return;
+        }
+        let format = self.run_format(run);
+        for content in &run.run_content {
+            match content {
+                types::RunContent::T(t) => {
+                    if let Some(text) = &t.text {
+                        out.push(Inline::Text(format, text.clone()));
+                    }
+                }
+                types::RunContent::Tab(_) | types::RunContent::Ptab(_) => {
+                    out.push(Inline::Text(format, " ".to_string()));
+                }
+                types::RunContent::Br(br)
+                    if !matches!(
+                        br.r#type,
+                        Some(types::STBrType::Page) | Some(types::STBrType::Column)
+                    ) =>
+                {
+                    out.push(Inline::Break)
+                }
+                types::RunContent::Cr(_) => out.push(Inline::Break),
+                types::RunContent::NoBreakHyphen(_) => {
+                    out.push(Inline::Text(format, "\u{2011}".to_string()))
+                }
+                types::RunContent::Sym(sym) => {
+                    if let Some(c) = symbol_char(sym) {
+                        out.push(Inline::Text(format, c.to_string()));
+                    }
+                }
+                types::RunContent::Drawing(drawing) => self.drawing(drawing, out),
+                types::RunContent::Pict(pict) => {
+                    for text in pict.text_box_texts() {
+                        self.floating.push(text_box_block(&text));
+                    }
+                }
+                types::RunContent::FootnoteReference(note) => {
+                    self.note_reference(NoteKind::Footnote, note.id, out)
+                }
+                types::RunContent::EndnoteReference(note) => {
+                    self.note_reference(NoteKind::Endnote, note.id, out)
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn run_format(&self, run: &types::Run) -> Format {
+        let ctx = &self.styles;
+        let character_style = run
+            .r_pr
+            .as_deref()
+            .and_then(|rpr| rpr.run_style.as_deref())
+            .is_some_and(|style| is_code_style(ctx, &style.value));
+        let default_font = ctx
+            .default_run_properties
+            .as_ref()
+            .and_then(|d| d.fonts.as_deref())
+            .and_then(|f| f.ascii.clone());
+        let monospace = run
+            .resolved_font_ascii(ctx)
+            .filter(|font| Some(font) != default_font.as_ref())
+            .is_some_and(|font| is_monospace_font(&font));
+        Format {
+            bold: run.resolved_is_bold(ctx),
+            italic: run.resolved_is_italic(ctx),
+            strike: run.resolved_is_strikethrough(ctx) || run.resolved_is_double_strikethrough(ctx),
+            code: character_style || monospace,
+        }
+    }
+
+    /// Plain text of a code paragraph.
+    fn code_text(&self, content: &[types::ParagraphContent]) -> String {
+        let mut text = String::new();
+        for item in content {
+            let runs: Vec<&types::Run> = match item {
+                types::ParagraphContent::R(run) => vec![run],
+                types::ParagraphContent::Ins(change) | types::ParagraphContent::MoveTo(change) => {
+                    change
+                        .run_content
+                        .iter()
+                        .filter_map(|item| match item {
+                            types::RunContentChoice::R(run) => Some(run.as_ref()),
+                            _ => None,
+                        })
+                        .collect()
+                }
+                types::ParagraphContent::Hyperlink(link) => {
+                    text.push_str(&self.code_text(&link.paragraph_content));
+                    continue;
+                }
+                types::ParagraphContent::Sdt(sdt) => {
+                    if let Some(content) = &sdt.sdt_content {
+                        text.push_str(&self.code_text(&content.paragraph_content));
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+            for run in runs {
+                if run.resolved_is_hidden(&self.styles) {
+                    continue;
+                }
+                for content in &run.run_content {
+                    match content {
+                        types::RunContent::T(t) => text.push_str(t.text.as_deref().unwrap_or("")),
+                        types::RunContent::Tab(_) => text.push('\t'),
+                        types::RunContent::Br(_) | types::RunContent::Cr(_) => text.push('\n'),
+                        _ => {}
+                    }
+                }
+            }
+        }
+        text
+    }
+
+    // -------------------------------------------------------------------------
+    // Images and floating content
+    // -------------------------------------------------------------------------
+
+    fn drawing(&mut self, drawing: &types::CTDrawing, out: &mut Vec<Inline>) {
+        let floating = drawing.extra_children.iter().any(|child| {
+            matches!(&child.node, ooxml_xml::RawXmlNode::Element(elem)
+                if elem.name.rsplit(':').next() == Some("anchor"))
+        });
+        if self.in_body && self.options.images {
+            let alt = drawing_value(drawing, &DRAWING_DESCRIPTION).unwrap_or_default();
+            for rel_id in drawing.all_image_rel_ids() {
+                let Some(rel) = self.rels.get(rel_id) else {
+                    continue;
+                };
+                let image = format!(
+                    "![{}]({})",
+                    escape_text(alt, false),
+                    link_destination(&rel.target)
+                );
+                match floating {
+                    true => self.floating.push(image),
+                    false => out.push(Inline::Markup(image)),
+                }
+            }
+        }
+        for text in drawing.text_box_texts() {
+            self.floating.push(text_box_block(&text));
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Notes
+    // -------------------------------------------------------------------------
+
+    fn note_reference(&mut self, kind: NoteKind, id: i64, out: &mut Vec<Inline>) {
+        if !self.options.notes {
+            return;
+        }
+        let position = self.note_refs.iter().position(|&r| r == (kind, id));
+        let number = match position {
+            Some(i) => i + 1,
+            None => {
+                self.note_refs.push((kind, id));
+                self.note_refs.len()
+            }
+        };
+        out.push(Inline::Markup(format!("[^{number}]")));
+    }
+
+    /// Define the referenced notes, in order of reference.
+    fn notes(&mut self, footnotes: &[types::FootnoteEndnote], endnotes: &[types::FootnoteEndnote]) {
+        self.in_body = false;
+        // Notes may reference further notes, which are appended as they go
+        let mut i = 0;
+        while let Some(&(kind, id)) = self.note_refs.get(i) {
+            i += 1;
+            let notes = match kind {
+                NoteKind::Footnote => footnotes,
+                NoteKind::Endnote => endnotes,
+            };
+            let Some(note) = notes.iter().find(|note| note.id == id) else {
+                continue;
+            };
+            let body = std::mem::take(&mut self.out);
+            self.blocks(&note.block_content);
+            self.end_blocks();
+            let content = std::mem::replace(&mut self.out, body);
+
+            self.start_block();
+            let _ = write!(self.out, "[^{i}]:");
+            for (n, line) in content.trim().lines().enumerate() {
+                match (n, line.is_empty()) {
+                    (_, true) => {}
+                    (0, false) => self.out.push(' '),
+                    (_, false) => self.out.push_str("    "),
+                }
+                self.out.push_str(line);
+                self.out.push('\n');
+            }
+            if content.trim().is_empty() {
+                self.out.push('\n');
+            }
+        }
+        self.in_body = true;
+    }
+}
+
+/// Append the equations in an unknown paragraph child.
+fn math_inlines(node: &ooxml_xml::RawXmlNode, out: &mut Vec<Inline>) {
+    let ooxml_xml::RawXmlNode::Element(elem) = node else {
+        return;
+    };
+    let mut expressions = Vec::new();
+    collect_math_from_raw(elem, &mut expressions);
+    for expression in expressions {
+        let latex = expression.zone.to_latex();
+        if !latex.is_empty() {
+            out.push(Inline::Math {
+                latex,
+                display: expression.is_display,
+            });
+        }
+    }
+}
+
+/// Write paragraph content as Markdown.
+///
+/// `table` writes line breaks as `<br>` and escapes `|`, for table cells.
+fn write_inlines(inlines: &[Inline], table: bool) -> String {
+    let mut out = String::new();
+    // Emphasis delimiters open, outermost first
+    let mut open: Vec<&'static str> = Vec::new();
+    // Whitespace held back until delimiters around it are settled, since
+    // delimiters cannot sit next to the whitespace they enclose
+    let mut pending = String::new();
+
+    // Merge adjacent text with the same formatting
+    let mut merged: Vec<(Option<Format>, &Inline, String)> = Vec::new();
+    for inline in inlines {
+        if let Inline::Text(format, text) = inline {
+            if let Some((Some(last), _, last_text)) = merged.last_mut()
+                && last == format
+            {
+                last_text.push_str(text);
+                continue;
+            }
+            merged.push((Some(*format), inline, text.clone()));
+        } else {
+            merged.push((None, inline, String::new()));
+        }
+    }
+
+    let mut transition = |out: &mut String, pending: &mut String, target: &[&'static str]| {
+        let common = open.iter().zip(target).take_while(|(a, b)| a == b).count();
+        while open.len() > common {
+            out.push_str(open.pop().unwrap_or_default());
+        }
+        out.push_str(pending);
+        pending.clear();
+        for delimiter in &target[common..] {
+            out.push_str(delimiter);
+            open.push(delimiter);
+        }
+    };
+
+    for (format, inline, text) in &merged {
+        match (format, inline) {
+            (Some(format), _) => {
+                let core = text.trim();
+                if core.is_empty() {
+                    pending.push_str(text);
+                    continue;
+                }
+                let leading = &text[..text.len() - text.trim_start().len()];
+                pending.push_str(leading);
+                transition(&mut out, &mut pending, &format.delimiters());
+                if format.code {
+                    out.push_str(&code_span(core, table));
+                } else {
+                    out.push_str(&escape_text(core, table));
+                }
+                pending.push_str(&text[text.trim_end().len()..]);
+            }
+            (None, Inline::Markup(markup)) => {
+                out.push_str(&pending);
+                pending.clear();
+                out.push_str(markup);
+            }
+            (None, Inline::Break) => {
+                pending.clear();
+                out.push_str(if table { "<br>" } else { "\\\n" });
+            }
+            (None, Inline::Math { latex, display }) => {
+                out.push_str(&pending);
+                pending.clear();
+                let latex = match table {
+                    true => latex.replace('|', "\\vert "),
+                    false => latex.clone(),
+                };
+                let delimiter = if *display { "$$" } else { "$" };
+                let _ = write!(out, "{delimiter}{latex}{delimiter}");
+            }
+            (None, Inline::Text(..)) => {}
+        }
+    }
+    pending.clear();
+    transition(&mut out, &mut pending, &[]);
+    let out = out.trim_end_matches("\\\n");
+    out.trim().to_string()
+}
+
+/// Markdown for the text of a text box, a paragraph per line.
+fn text_box_block(text: &str) -> String {
+    let paragraphs: Vec<String> = text
+        .lines()
+        .map(|line| escape_line_starts(&escape_text(line, false)))
+        .filter(|line| !line.is_empty())
+        .collect();
+    paragraphs.join("\n\n")
+}
+
+/// Whether a style is named like a code style, e.g. "Source Code", "HTML
+/// Code" or "HTML Preformatted".
+fn is_code_style(styles: &StyleContext, style_id: &str) -> bool {
+    styles.style_chain(style_id).iter().any(|style| {
+        let name = style
+            .name
+            .as_deref()
+            .map_or(style_id.to_ascii_lowercase(), |name| {
+                name.value.to_ascii_lowercase()
+            });
+        ["code", "preformatted", "verbatim"]
+            .iter()
+            .any(|word| name.contains(word))
+    })
+}
+
+fn is_monospace_font(font: &str) -> bool {
+    let font = font.to_ascii_lowercase();
+    [
+        "mono",
+        "courier",
+        "consolas",
+        "menlo",
+        "monaco",
+        "lucida console",
+        "code",
+    ]
+    .iter()
+    .any(|word| font.contains(word))
+}
+
+/// A code span for `code`, delimited by more backticks than it contains.
+fn code_span(code: &str, table: bool) -> String {
+    let mut longest = 0;
+    let mut run = 0;
+    for c in code.chars() {
+        run = if c == '`' { run + 1 } else { 0 };
+        longest = longest.max(run);
+    }
+    let fence = "`".repeat(longest + 1);
+    let code = match table {
+        true => code.replace('|', "\\|"),
+        false => code.to_string(),
+    };
+    // Pad code starting or ending with a backtick, which would merge with
+    // the fence
+    if code.starts_with('`') || code.ends_with('`') {
+        format!("{fence} {code} {fence}")
+    } else {
+        format!("{fence}{code}{fence}")
+    }
+}
+
+/// Escape characters with a meaning inside Markdown paragraphs.
+fn escape_text(text: &str, table: bool) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '~' | '$' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '|' if table => out.push_str("\\|"),
+            '\n' => out.push_str(if table { "<br>" } else { "\\\n" }),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Escape what would turn the start of a line into a heading, quote, list
+/// item, thematic break or code block.
+fn escape_line_starts(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for (i, line) in text.lines().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        let line = line.trim_start();
+        // Markers only count when followed by a space or the end of the line
+        let marker_at = |at: usize| {
+            line[at..].starts_with(['.', ')'])
+                && line[at + 1..].chars().next().is_none_or(|c| c == ' ')
+        };
+        let hashes = line.len() - line.trim_start_matches('#').len();
+        let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        let rule = line.starts_with(['-', '=']) && line.chars().all(|c| "-= ".contains(c));
+        if line.starts_with('>')
+            || rule
+            || (1..=6).contains(&hashes) && line[hashes..].chars().next().is_none_or(|c| c == ' ')
+            || line.starts_with(['-', '+']) && line[1..].chars().next().is_none_or(|c| c == ' ')
+        {
+            out.push('\\');
+            out.push_str(line);
+        } else if (1..=9).contains(&digits) && marker_at(digits) {
+            out.push_str(&line[..digits]);
+            out.push('\\');
+            out.push_str(&line[digits..]);
+        } else {
+            out.push_str(line);
+        }
+    }
+    out
+}
+
+/// A link destination, with characters that would end it percent-encoded.
+fn link_destination(href: &str) -> String {
+    href.replace(' ', "%20")
+        .replace('(', "%28")
+        .replace(')', "%29")
+        .replace('<', "%3C")
+        .replace('>', "%3E")
+}
+
+/// Escape text for an HTML attribute value.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+}
@@ -9,7 +9,10 @@ use crate::document::{
 use crate::error::Result;
 use crate::generated_serializers::ToXml;
 use crate::types;
-use ooxml_opc::{PackageWriter, Relationship, Relationships, content_type, rel_type};
+use ooxml_opc::{
+    Conformance, PackageWriter, Relationship, Relationships, SerializeOptions, VbaProject,
+    content_type, rel_type,
+};
 use ooxml_xml::{PositionedNode, RawXmlElement, RawXmlNode};
 use std::collections::HashMap;
 use std::fs::File;
@@ -932,6 +935,12 @@ pub struct DocumentBuilder {
     next_comment_id: i32,
     /// Counter for generating unique drawing/image IDs.
     next_drawing_id: usize,
+    /// Conformance class to write.
+    conformance: Conformance,
+    /// Options to reformat XML parts with, if any.
+    serialize_options: Option<SerializeOptions>,
+    /// VBA project, making the document macro-enabled.
+    vba_project: Option<VbaProject>,
 }
 
 impl Default for DocumentBuilder {
@@ -981,6 +990,9 @@ impl DocumentBuilder {
             next_endnote_id: 1,
             next_comment_id: 0,
             next_drawing_id: 1,
+            conformance: Conformance::Transitional,
+            serialize_options: None,
+            vba_project: None,
         }
     }
 
@@ -1123,6 +1135,16 @@ impl DocumentBuilder {
         self
     }
 
+    /// Whether a style with the given ID has been added.
+    pub fn has_style(&self, style_id: &str) -> bool {
+        self.styles.as_ref().is_some_and(|styles| {
+            styles
+                .style
+                .iter()
+                .any(|style| style.style_id.as_deref() == Some(style_id))
+        })
+    }
+
     /// Add a header and return a builder for its content.
     ///
     /// The header will be automatically linked to the document's section properties.
@@ -1276,6 +1298,33 @@ impl DocumentBuilder {
         self
     }
 
+    /// Set the conformance class to write (ISO/IEC 29500-1, §2).
+    ///
+    /// Strict documents use the `http://purl.oclc.org/ooxml/...` namespaces and
+    /// relationship types. Defaults to Transitional.
+    pub fn set_conformance(&mut self, conformance: Conformance) -> &mut Self {
+        self.conformance = conformance;
+        self
+    }
+
+    /// Set the output options for the XML parts.
+    ///
+    /// Defaults to compact output; use [`SerializeOptions::pretty`] for
+    /// indented, deterministic parts that diff well under version control.
+    pub fn set_serialize_options(&mut self, options: SerializeOptions) -> &mut Self {
+        self.serialize_options = Some(options);
+        self
+    }
+
+    /// Attach a VBA project, making the document macro-enabled.
+    ///
+    /// The document is written with the `.docm` content type, so it should be
+    /// saved with a `.docm` extension.
+    pub fn set_vba_project(&mut self, project: VbaProject) -> &mut Self {
+        self.vba_project = Some(project);
+        self
+    }
+
     /// Set the extended application properties (word count, page count, etc.).
     ///
     /// The properties will be written to `docProps/app.xml` when saved.
@@ -1356,6 +1405,13 @@ impl DocumentBuilder {
     /// Write the document to a writer.
     pub fn write<W: Write + Seek>(mut self, writer: W) -> Result<()> {
         let mut pkg = PackageWriter::new(writer);
+        if self.conformance == Conformance::Strict {
+            pkg.set_conformance(Conformance::Strict);
+            self.document.conformance = Some(types::STConformanceClass::Strict);
+        }
+        if let Some(options) = self.serialize_options {
+            pkg.set_serialize_options(options);
+        }
 
         // Add default content types
         pkg.add_default_content_type("rels", content_type::RELATIONSHIPS);
@@ -1429,11 +1485,11 @@ impl DocumentBuilder {
 
         // Write document.xml
         let doc_xml = serialize_to_xml_bytes(&self.document, "w:document")?;
-        pkg.add_part(
-            "word/document.xml",
-            content_type::WORDPROCESSING_DOCUMENT,
-            &doc_xml,
-        )?;
+        let doc_content_type = match &self.vba_project {
+            Some(_) => content_type::WORDPROCESSING_DOCUMENT_MACRO_ENABLED,
+            None => content_type::WORDPROCESSING_DOCUMENT,
+        };
+        pkg.add_part("word/document.xml", doc_content_type, &doc_xml)?;
 
         // Write package relationships
         let mut pkg_rels = Relationships::new();
@@ -1664,6 +1720,20 @@ impl DocumentBuilder {
             ));
         }
 
+        // Write the VBA project of macro-enabled documents
+        if let Some(ref project) = self.vba_project {
+            pkg.add_part(
+                "word/vbaProject.bin",
+                content_type::VBA_PROJECT,
+                project.data(),
+            )?;
+            doc_rels.add(Relationship::new(
+                doc_rels.next_id(),
+                rel_type::VBA_PROJECT,
+                "vbaProject.bin",
+            ));
+        }
+
         pkg.add_part(
             "word/_rels/document.xml.rels",
             content_type::RELATIONSHIPS,
@@ -0,0 +1,494 @@
+//! Tests for HTML export (`Document::to_html`).
+//!
+//! Documents are built with `DocumentBuilder`, read back and rendered, and
+//! the HTML is checked for the markup each feature maps to.
+
+#![cfg(feature = "html")]
+
+use ooxml_opc::{Package, PackageEditor, content_type};
+use ooxml_wml::convenience::VMergeType;
+use ooxml_wml::html::{HtmlOptions, ImageMode};
+use ooxml_wml::types;
+use ooxml_wml::{Document, DocumentBuilder, Drawing, ListType, NumberingLevel};
+use std::io::Cursor;
+
+/// Build a document, write to memory, read back.
+fn roundtrip(builder: DocumentBuilder) -> Document<Cursor<Vec<u8>>> {
+    let mut buffer = Cursor::new(Vec::new());
+    builder.write(&mut buffer).unwrap();
+    buffer.set_position(0);
+    Document::from_reader(buffer).unwrap()
+}
+
+/// Render a built document as an HTML fragment.
+fn render(builder: DocumentBuilder) -> String {
+    roundtrip(builder)
+        .to_html(&HtmlOptions::fragment())
+        .unwrap()
+        .html
+}
+
+fn string(value: &str) -> Option<Box<types::CTString>> {
+    Some(Box::new(types::CTString {
+        value: value.to_string(),
+        #[cfg(feature = "extra-attrs")]
+        extra_attrs: Default::default(),
+    }))
+}
+
+fn paragraph_style(id: &str, name: &str, p_pr: types::CTPPrGeneral) -> types::Style {
+    types::Style {
+        r#type: Some(types::STStyleType::Paragraph),
+        style_id: Some(id.to_string()),
+        name: string(name),
+        p_pr: Some(Box::new(p_pr)),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_headings_and_paragraphs() {
+    let mut builder = DocumentBuilder::new();
+    builder.add_style(paragraph_style(
+        "Title1",
+        "Chapter",
+        types::CTPPrGeneral {
+            outline_lvl: Some(Box::new(types::CTDecimalNumber {
+                value: 0,
+                #[cfg(feature = "extra-attrs")]
+                extra_attrs: Default::default(),
+            })),
+            ..Default::default()
+        },
+    ));
+    builder.add_style(paragraph_style(
+        "Heading3",
+        "heading 3",
+        types::CTPPrGeneral::default(),
+    ));
+    {
+        let body = builder.body_mut();
+        let para = body.add_paragraph();
+        para.set_properties(types::ParagraphProperties {
+            paragraph_style: string("Title1"),
+            ..Default::default()
+        });
+        para.add_run().set_text("Chapter one");
+
+        let para = body.add_paragraph();
+        para.set_outline_level(1);
+        para.add_run().set_text("Section");
+
+        let para = body.add_paragraph();
+        para.set_properties(types::ParagraphProperties {
+            paragraph_style: string("Heading3"),
+            ..Default::default()
+        });
+        para.add_run().set_text("By name");
+
+        let para = body.add_paragraph();
+        para.set_alignment(types::STJc::Center);
+        para.add_run().set_text("Plain <text> & more");
+    }
+
+    let html = render(builder);
+    assert!(
+        html.contains("<h1 class=\"style-Title1\">Chapter one</h1>"),
+        "{html}"
+    );
+    assert!(html.contains("<h2>Section</h2>"), "{html}");
+    assert!(
+        html.contains("<h3 class=\"style-Heading3\">By name</h3>"),
+        "{html}"
+    );
+    assert!(
+        html.contains("<p style=\"text-align: center\">Plain &lt;text&gt; &amp; more</p>"),
+        "{html}"
+    );
+}
+
+#[test]
+fn test_run_formatting() {
+    let mut builder = DocumentBuilder::new();
+    {
+        let para = builder.body_mut().add_paragraph();
+        let run = para.add_run();
+        run.set_text("bold");
+        run.set_bold(true);
+        let run = para.add_run();
+        run.set_text("both");
+        run.set_bold(true);
+        run.set_italic(true);
+        let run = para.add_run();
+        run.set_text("red");
+        run.set_color("FF0000");
+        let run = para.add_run();
+        run.set_text("hidden");
+        run.set_vanish(true);
+    }
+
+    let html = render(builder);
+    assert!(
+        html.contains(
+            "<p><strong>bold</strong><strong><em>both</em></strong>\
+             <span style=\"color: #FF0000;\">red</span></p>"
+        ),
+        "{html}"
+    );
+    assert!(!html.contains("hidden"));
+}
+
+#[test]
+fn test_lists() {
+    let mut builder = DocumentBuilder::new();
+    let outline = builder.add_custom_list(vec![
+        NumberingLevel::bullet(0),
+        NumberingLevel {
+            format: ListType::LowerRoman,
+            start: 3,
+            text: "%2.".to_string(),
+            ..NumberingLevel::decimal(1)
+        },
+    ]);
+    let numbers = builder.add_list(ListType::Decimal);
+    {
+        let body = builder.body_mut();
+        for (text, num_id, ilvl) in [
+            ("one", outline, 0),
+            ("nested", outline, 1),
+            ("nested too", outline, 1),
+            ("two", outline, 0),
+            ("first", numbers, 0),
+        ] {
+            let para = body.add_paragraph();
+            para.set_numbering(num_id, ilvl);
+            para.add_run().set_text(text);
+        }
+        body.add_paragraph().add_run().set_text("after");
+    }
+
+    let html = render(builder);
+    assert_eq!(
+        html,
+        "<ul>\n<li>one<ol type=\"i\" start=\"3\">\n<li>nested</li>\n<li>nested too</li>\n</ol>\n</li>\n\
+         <li>two</li>\n</ul>\n<ol>\n<li>first</li>\n</ol>\n<p>after</p>\n"
+    );
+}
+
+#[test]
+fn test_table_spans() {
+    let mut builder = DocumentBuilder::new();
+    {
+        let table = builder.body_mut().add_table();
+        let row = table.add_row();
+        let cell = row.add_cell();
+        cell.set_grid_span(2);
+        cell.add_paragraph().add_run().set_text("wide");
+        let cell = row.add_cell();
+        cell.set_vertical_merge(VMergeType::Restart);
+        cell.add_paragraph().add_run().set_text("tall");
+
+        let row = table.add_row();
+        row.add_cell().add_paragraph().add_run().set_text("a");
+        row.add_cell().add_paragraph().add_run().set_text("b");
+        let cell = row.add_cell();
+        cell.set_vertical_merge(VMergeType::Continue);
+        cell.add_paragraph();
+    }
+
+    let html = render(builder);
+    assert!(
+        html.contains(
+            "<table>\n<tr>\n<td colspan=\"2\"><p>wide</p>\n</td>\n\
+             <td rowspan=\"2\"><p>tall</p>\n</td>\n</tr>\n\
+             <tr>\n<td><p>a</p>\n</td>\n<td><p>b</p>\n</td>\n</tr>\n</table>"
+        ),
+        "{html}"
+    );
+}
+
+#[test]
+fn test_hyperlinks_and_bookmarks() {
+    let mut builder = DocumentBuilder::new();
+    let rel_id = builder.add_hyperlink("https://example.com/?a=1&b=2");
+    {
+        let para = builder.body_mut().add_paragraph();
+        let link = para.add_hyperlink();
+        link.set_rel_id(&rel_id);
+        link.add_run().set_text("external");
+        let link = para.add_hyperlink();
+        link.set_anchor("target");
+        link.add_run().set_text("internal");
+        for url in [
+            "javascript:alert(1)",
+            " JavaScript:alert(1)",
+            "data:text/html,x",
+        ] {
+            let rel_id = builder.add_hyperlink(url);
+            let link = builder.body_mut().add_paragraph().add_hyperlink();
+            link.set_rel_id(&rel_id);
+            link.add_run().set_text("unsafe");
+        }
+
+        let para = builder.body_mut().add_paragraph();
+        para.add_bookmark_start(1, "target");
+        para.add_run().set_text("here");
+        para.add_bookmark_end(1);
+    }
+
+    let html = render(builder);
+    assert!(
+        html.contains("<a href=\"https://example.com/?a=1&amp;b=2\">external</a>"),
+        "{html}"
+    );
+    assert!(html.contains("<a href=\"#target\">internal</a>"), "{html}");
+    assert!(html.contains("<p><a id=\"target\"></a>here</p>"), "{html}");
+    // Links that could run script are left as text
+    assert_eq!(html.matches("<p>unsafe</p>").count(), 3, "{html}");
+}
+
+#[test]
+fn test_images() {
+    let png = b"\x89PNG\r\n\x1a\nnot really".to_vec();
+    let mut builder = DocumentBuilder::new();
+    let rel_id = builder.add_image(png.clone(), "image/png");
+    {
+        let run = builder.body_mut().add_paragraph().add_run();
+        let mut drawing = Drawing::new();
+        drawing
+            .add_image(&rel_id)
+            .set_width_emu(952500)
+            .set_height_emu(476250)
+            .set_description("A chart");
+        let mut doc_id = 1usize;
+        run.add_drawing(drawing.build(&mut doc_id));
+    }
+    let mut doc = roundtrip(builder);
+
+    let html = doc.to_html(&HtmlOptions::fragment()).unwrap().html;
+    assert!(
+        html.contains(
+            "<img src=\"data:image/png;base64,iVBORw0KGgpub3QgcmVhbGx5\" alt=\"A chart\" \
+             width=\"100\" height=\"50\">"
+        ),
+        "{html}"
+    );
+
+    let export = doc
+        .to_html(&HtmlOptions {
+            images: ImageMode::Files {
+                prefix: "media/".to_string(),
+            },
+            ..HtmlOptions::fragment()
+        })
+        .unwrap();
+    assert!(
+        export.html.contains("<img src=\"media/image"),
+        "{}",
+        export.html
+    );
+    assert_eq!(export.images.len(), 1);
+    assert_eq!(export.images[0].content_type, "image/png");
+    assert_eq!(export.images[0].data, png);
+    assert!(
+        export
+            .html
+            .contains(&format!("src=\"media/{}\"", export.images[0].name))
+    );
+
+    let export = doc
+        .to_html(&HtmlOptions {
+            images: ImageMode::Omit,
+            ..HtmlOptions::fragment()
+        })
+        .unwrap();
+    assert!(!export.html.contains("<img"));
+}
+
+#[test]
+fn test_image_file_names() {
+    let mut builder = DocumentBuilder::new();
+    for n in 0..3u8 {
+        let rel_id = builder.add_image(vec![n; 4], "image/png");
+        let run = builder.body_mut().add_paragraph().add_run();
+        let mut drawing = Drawing::new();
+        drawing.add_image(&rel_id);
+        let mut doc_id = 1usize + n as usize;
+        run.add_drawing(drawing.build(&mut doc_id));
+    }
+    let mut buffer = Cursor::new(Vec::new());
+    builder.write(&mut buffer).unwrap();
+
+    // Images in different folders with the same name, and a name starting
+    // with dots
+    buffer.set_position(0);
+    let mut editor = PackageEditor::new(Package::open(buffer).unwrap()).unwrap();
+    let mut media: Vec<String> = editor
+        .parts()
+        .filter(|part| part.starts_with("word/media/"))
+        .map(str::to_string)
+        .collect();
+    media.sort();
+    for (from, to) in media.iter().zip([
+        "word/media/a/chart.png",
+        "word/media/b/chart.png",
+        "word/media/..chart.png",
+    ]) {
+        editor.rename_part(from, to).unwrap();
+    }
+    let mut buffer = Cursor::new(Vec::new());
+    editor.write(&mut buffer).unwrap();
+    buffer.set_position(0);
+    let mut doc = Document::from_reader(buffer).unwrap();
+
+    let export = doc
+        .to_html(&HtmlOptions {
+            images: ImageMode::Files {
+                prefix: "media/".to_string(),
+            },
+            ..HtmlOptions::fragment()
+        })
+        .unwrap();
+    let names: Vec<_> = export
+        .images
+        .iter()
+        .map(|image| (image.name.as_str(), image.data[0]))
+        .collect();
+    assert_eq!(
+        names,
+        [("chart.png", 0), ("chart-2.png", 1), ("chart-3.png", 2)]
+    );
+    assert!(
+        export.html.contains("src=\"media/chart-2.png\""),
+        "{}",
+        export.html
+    );
+}
+
+#[test]
+fn test_hostile_style_values() {
+    let mut builder = DocumentBuilder::new();
+    builder.add_style(paragraph_style(
+        "Hostile",
+        "Hostile",
+        types::CTPPrGeneral::default(),
+    ));
+    {
+        let para = builder.body_mut().add_paragraph();
+        para.set_properties(types::ParagraphProperties {
+            paragraph_style: string("Hostile"),
+            ..Default::default()
+        });
+        let run = para.add_run();
+        run.set_text("direct");
+        run.set_color("00F; position: fixed");
+        run.set_fonts(types::Fonts {
+            ascii: Some("Arial'; position: fixed; x: '".to_string()),
+            ..Default::default()
+        });
+        let run = para.add_run();
+        run.set_text("green");
+        run.set_color("00ff00");
+    }
+    let mut buffer = Cursor::new(Vec::new());
+    builder.write(&mut buffer).unwrap();
+
+    // Values a crafted styles.xml uses to leave the declaration, the rule
+    // or the <style> element
+    let styles = r#"<w:styles xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+        <w:docDefaults><w:rPrDefault><w:rPr>
+            <w:rFonts w:ascii="Calibri}&lt;/style&gt;&lt;script&gt;alert(1)&lt;/script&gt;"/>
+            <w:color w:val="000;}&lt;/style&gt;&lt;script&gt;alert(2)&lt;/script&gt;"/>
+        </w:rPr></w:rPrDefault></w:docDefaults>
+        <w:style w:type="paragraph" w:styleId="Hostile">
+            <w:name w:val="Hostile"/>
+            <w:rPr><w:rFonts w:ascii="Arial\} body { display: none"/><w:color w:val="red; background: url(x)"/></w:rPr>
+        </w:style>
+    </w:styles>"#;
+    buffer.set_position(0);
+    let mut editor = PackageEditor::new(Package::open(buffer).unwrap()).unwrap();
+    editor.set_part(
+        "word/styles.xml",
+        content_type::WORDPROCESSING_STYLES,
+        styles.as_bytes().to_vec(),
+    );
+    let mut buffer = Cursor::new(Vec::new());
+    editor.write(&mut buffer).unwrap();
+    buffer.set_position(0);
+    let mut doc = Document::from_reader(buffer).unwrap();
+
+    let export = doc.to_html(&HtmlOptions::default()).unwrap();
+    for injected in ["script", "alert", "display", "url(", "position"] {
+        assert!(!export.html.contains(injected), "{}", export.html);
+    }
+    assert!(
+        export
+            .html
+            .contains("<span style=\"color: #00ff00;\">green</span>")
+    );
+}
+
+#[test]
+fn test_notes_and_comments() {
+    let mut builder = DocumentBuilder::new();
+    let first = builder.add_footnote().add_paragraph("First note").id();
+    let second = builder.add_footnote().add_paragraph("Second note").id();
+    let comment = {
+        let mut comment = builder.add_comment();
+        comment.set_author("Reviewer").add_paragraph("Check this");
+        comment.id()
+    };
+    {
+        let para = builder.body_mut().add_paragraph();
+        para.add_run().set_text("Text");
+        para.add_run().add_footnote_ref(second as i64);
+        para.add_run().add_footnote_ref(first as i64);
+        para.add_run().add_comment_ref(comment as i64);
+    }
+
+    let mut doc = roundtrip(builder);
+    let html = doc.to_html(&HtmlOptions::fragment()).unwrap().html;
+    // Numbered in order of reference
+    assert!(
+        html.contains(
+            "<p>Text<sup class=\"footnote-ref\"><a href=\"#footnote-1\" id=\"footnote-ref-1\">1</a></sup>\
+             <sup class=\"footnote-ref\"><a href=\"#footnote-2\" id=\"footnote-ref-2\">2</a></sup>\
+             <sup class=\"comment-ref\"><a href=\"#comment-1\" id=\"comment-ref-1\">[1]</a></sup></p>"
+        ),
+        "{html}"
+    );
+    let second_note = html.find("Second note").unwrap();
+    let first_note = html.find("First note").unwrap();
+    assert!(second_note < first_note, "{html}");
+    assert!(html.contains("<section class=\"footnotes\">\n<ol>\n<li id=\"footnote-1\">"));
+    assert!(html.contains("<a class=\"backref\" href=\"#footnote-ref-2\">"));
+    assert!(html.contains(
+        "<li id=\"comment-1\">\n<p class=\"comment-author\">Reviewer</p>\n<p>Check this</p>"
+    ));
+
+    let html = doc
+        .to_html(&HtmlOptions {
+            notes: false,
+            comments: false,
+            ..HtmlOptions::fragment()
+        })
+        .unwrap()
+        .html;
+    assert_eq!(html, "<p>Text</p>\n");
+}
+
+#[test]
+fn test_standalone_document() {
+    let mut builder = DocumentBuilder::new();
+    builder.add_paragraph("Hello");
+
+    let export = roundtrip(builder).to_html(&HtmlOptions::default()).unwrap();
+    assert!(export.html.starts_with("<!DOCTYPE html>\n<html>\n<head>\n"));
+    assert!(
+        export
+            .html
+            .contains(&format!("<style>\n{}</style>", export.css))
+    );
+    assert!(export.html.contains("<body>\n<p>Hello</p>\n</body>"));
+    assert!(export.css.contains("table { border-collapse: collapse; }"));
+}
@@ -0,0 +1,352 @@
+//! Tests for Markdown and HTML import (`DocumentBuilder::import_markdown`,
+//! `DocumentBuilder::import_html`).
+//!
+//! Content is imported, written and read back, and mostly checked through
+//! its Markdown export, which maps the same styles back.
+
+#![cfg(all(feature = "import", feature = "markdown"))]
+
+use ooxml_wml::import::ImportOptions;
+use ooxml_wml::markdown::MarkdownOptions;
+use ooxml_wml::types;
+use ooxml_wml::{Document, DocumentBuilder};
+use std::io::Cursor;
+
+/// Build a document, write to memory, read back.
+fn roundtrip(builder: DocumentBuilder) -> Document<Cursor<Vec<u8>>> {
+    let mut buffer = Cursor::new(Vec::new());
+    builder.write(&mut buffer).unwrap();
+    buffer.set_position(0);
+    Document::from_reader(buffer).unwrap()
+}
+
+fn markdown(doc: &mut Document<Cursor<Vec<u8>>>) -> String {
+    doc.to_markdown(&MarkdownOptions::default()).unwrap()
+}
+
+fn import_markdown(source: &str) -> Document<Cursor<Vec<u8>>> {
+    let mut builder = DocumentBuilder::new();
+    builder.import_markdown(source, &ImportOptions::default());
+    roundtrip(builder)
+}
+
+fn import_html(source: &str) -> Document<Cursor<Vec<u8>>> {
+    let mut builder = DocumentBuilder::new();
+    builder.import_html(source, &ImportOptions::default());
+    roundtrip(builder)
+}
+
+fn paragraphs(doc: &Document<Cursor<Vec<u8>>>) -> Vec<&types::Paragraph> {
+    doc.body()
+        .block_content
+        .iter()
+        .filter_map(|block| match block {
+            types::BlockContent::P(para) => Some(para.as_ref()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn style_id(para: &types::Paragraph) -> Option<&str> {
+    Some(
+        para.p_pr
+            .as_deref()?
+            .paragraph_style
+            .as_deref()?
+            .value
+            .as_str(),
+    )
+}
+
+/// A PNG header for an image of the given size.
+fn png(width: u32, height: u32) -> Vec<u8> {
+    let mut data = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+    data.extend(width.to_be_bytes());
+    data.extend(height.to_be_bytes());
+    data.extend([8, 6, 0, 0, 0]);
+    data
+}
+
+#[test]
+fn test_markdown_blocks() {
+    let mut doc = import_markdown(
+        "# Title\n\nIntro text.\n\n## Steps\n\n\
+         - one\n- two\n  1. nested\n  2. more\n\n\
+         ```rust\nfn main() {}\n\nlet x = 1;\n```\n\n\
+         | Name | Count |\n|:-----|------:|\n| a | 1 |\n| b |\n\n\
+         > Quoted\n",
+    );
+
+    assert_eq!(
+        markdown(&mut doc),
+        "# Title\n\nIntro text.\n\n## Steps\n\n\
+         - one\n- two\n  1. nested\n  2. more\n\n\
+         ```\nfn main() {}\n\nlet x = 1;\n```\n\n\
+         | **Name** | **Count** |\n| --- | --- |\n| a | 1 |\n| b |  |\n\nQuoted\n"
+    );
+    let styles: Vec<_> = paragraphs(&doc).into_iter().map(style_id).collect();
+    assert_eq!(styles[0], Some("Heading1"));
+    assert_eq!(styles[3], Some("ListParagraph"));
+    assert_eq!(styles[7], Some("HTMLPreformatted"));
+    assert_eq!(styles.last().unwrap(), &Some("Quote"));
+
+    // The count column is right-aligned
+    let table = doc
+        .body()
+        .block_content
+        .iter()
+        .find_map(|block| match block {
+            types::BlockContent::Tbl(table) => Some(table),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(table.tbl_grid.grid_col.len(), 2);
+    let types::RowContent::Tr(row) = &table.rows[1] else {
+        panic!("expected a row");
+    };
+    let types::CellContent::Tc(cell) = &row.cells[1] else {
+        panic!("expected a cell");
+    };
+    let types::BlockContent::P(para) = &cell.block_content[0] else {
+        panic!("expected a paragraph");
+    };
+    let justification = para.p_pr.as_deref().unwrap().justification.as_deref();
+    assert_eq!(justification.unwrap().value, types::STJc::Right);
+}
+
+#[test]
+fn test_markdown_inline() {
+    let mut doc = import_markdown(
+        "Some *emph*, **strong**, ~~gone~~ and `code`, \
+         <u>under</u> H<sub>2</sub>O,  \nsee [the site](https://example.com/a).\n\n\
+         - [ ] todo\n- [x] done\n",
+    );
+
+    assert_eq!(
+        markdown(&mut doc),
+        "Some *emph*, **strong**, ~~gone~~ and `code`, under H2O,\\\n\
+         see [the site](https://example.com/a).\n\n- ☐ todo\n- ☒ done\n"
+    );
+    let runs: Vec<_> = paragraphs(&doc)[0]
+        .paragraph_content
+        .iter()
+        .filter_map(|content| match content {
+            types::ParagraphContent::R(run) => run.r_pr.as_deref(),
+            _ => None,
+        })
+        .collect();
+    assert!(runs.iter().any(|props| props.underline.is_some()));
+    assert!(runs.iter().any(|props| {
+        props.vert_align.as_deref().map(|align| align.value)
+            == Some(types::STVerticalAlignRun::Subscript)
+    }));
+}
+
+#[test]
+fn test_markdown_footnotes() {
+    let mut doc = import_markdown("Text[^a] and more[^b].\n\n[^b]: Second.\n[^a]: First *note*.\n");
+
+    assert_eq!(
+        markdown(&mut doc),
+        "Text[^1] and more[^2].\n\n[^1]: First *note*.\n\n[^2]: Second.\n"
+    );
+    let footnotes = doc.get_footnotes().unwrap();
+    let first = footnotes.footnote.iter().find(|note| note.id == 1).unwrap();
+    let types::BlockContent::P(para) = &first.block_content[0] else {
+        panic!("expected a paragraph");
+    };
+    assert_eq!(style_id(para), Some("FootnoteText"));
+    let types::ParagraphContent::R(mark) = &para.paragraph_content[0] else {
+        panic!("expected the reference mark");
+    };
+    assert!(matches!(
+        mark.run_content[0],
+        types::RunContent::FootnoteRef(_)
+    ));
+}
+
+#[test]
+fn test_heading_bookmarks() {
+    let mut doc = import_markdown(
+        "See [usage](#usage-notes) and [setup](#install).\n\n\
+         ## Usage notes\n\n## Setup {#install}\n\n## Other\n",
+    );
+
+    assert_eq!(
+        markdown(&mut doc),
+        "See [usage](#usage-notes) and [setup](#install).\n\n\
+         ## <a id=\"usage-notes\"></a>Usage notes\n\n\
+         ## <a id=\"install\"></a>Setup\n\n## Other\n"
+    );
+}
+
+#[test]
+fn test_styles() {
+    let mut builder = DocumentBuilder::new();
+    builder.add_style(types::Style {
+        r#type: Some(types::STStyleType::Paragraph),
+        style_id: Some("Quote".to_string()),
+        name: Some(Box::new(types::CTString {
+            value: "Company Quote".to_string(),
+            #[cfg(feature = "extra-attrs")]
+            extra_attrs: Default::default(),
+        })),
+        ..Default::default()
+    });
+    builder.import_markdown(
+        "# Title\n\n> Quoted [link](https://example.com)\n",
+        &ImportOptions::default(),
+    );
+    let doc = roundtrip(builder);
+
+    let names: Vec<_> = doc
+        .styles()
+        .style
+        .iter()
+        .map(|style| {
+            let name = style.name.as_deref().unwrap().value.as_str();
+            (style.style_id.as_deref().unwrap(), name)
+        })
+        .collect();
+    assert_eq!(
+        names,
+        [
+            ("Quote", "Company Quote"),
+            ("Heading1", "heading 1"),
+            ("Hyperlink", "Hyperlink"),
+        ]
+    );
+
+    let mut builder = DocumentBuilder::new();
+    let options = ImportOptions {
+        styles: false,
+        ..ImportOptions::default()
+    };
+    builder.import_markdown("# Title\n", &options);
+    assert!(!builder.has_style("Heading1"));
+}
+
+#[test]
+fn test_images() {
+    let dir = std::env::temp_dir().join(format!("ooxml-import-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("media")).unwrap();
+    std::fs::write(dir.join("media/wide chart.png"), png(1200, 300)).unwrap();
+
+    let mut builder = DocumentBuilder::new();
+    builder.import_markdown(
+        "![wide](media/wide%20chart.png) ![missing](media/none.png) \
+         ![remote](https://example.com/a.png)\n",
+        &ImportOptions {
+            base_dir: Some(dir.clone()),
+            ..ImportOptions::default()
+        },
+    );
+    let base64 = "iVBORw0KGgoAAAANSUhEUgAAAAIAAAABCAYAAAD0In+KAAAAC0lEQVR4nGNgAAIAAAUAAXpeqz8AAAAASUVORK5CYII=";
+    builder.import_html(
+        &format!("<p><img src=\"data:image/png;base64,{base64}\" alt=\"tiny\"></p>"),
+        &ImportOptions::default(),
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+    let mut doc = roundtrip(builder);
+
+    let markdown = markdown(&mut doc);
+    assert!(markdown.starts_with("![wide](media/image"), "{markdown}");
+    assert!(
+        markdown.contains(") missing remote\n\n![tiny](media/image"),
+        "{markdown}"
+    );
+
+    let extents: Vec<_> = paragraphs(&doc)
+        .iter()
+        .flat_map(|para| &para.paragraph_content)
+        .filter_map(|content| match content {
+            types::ParagraphContent::R(run) => Some(&run.run_content),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|content| match content {
+            types::RunContent::Drawing(drawing) => Some(drawing),
+            _ => None,
+        })
+        .map(|drawing| {
+            let extent = |query| {
+                ooxml_xml::Query::new(query)
+                    .unwrap()
+                    .select_nodes(drawing.extra_children.iter().map(|child| &child.node))
+                    .iter()
+                    .find_map(ooxml_xml::QueryMatch::value)
+                    .unwrap()
+                    .to_string()
+            };
+            (extent("*/extent/@cx"), extent("*/extent/@cy"))
+        })
+        .collect();
+    // Scaled down to the text width; 96 DPI otherwise
+    assert_eq!(
+        extents,
+        [
+            ("5943600".to_string(), "1485900".to_string()),
+            ("19050".to_string(), "9525".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_images_outside_base_dir() {
+    let dir = std::env::temp_dir().join(format!("ooxml-import-outside-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("docs")).unwrap();
+    let secret = dir.join("secret.png");
+    std::fs::write(&secret, png(10, 10)).unwrap();
+
+    let mut builder = DocumentBuilder::new();
+    builder.import_markdown(
+        &format!(
+            "![parent](../secret.png) ![encoded](%2E%2E/secret.png) ![absolute]({})\n",
+            secret.display()
+        ),
+        &ImportOptions {
+            base_dir: Some(dir.join("docs")),
+            ..ImportOptions::default()
+        },
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+    let mut doc = roundtrip(builder);
+
+    assert_eq!(markdown(&mut doc), "parent encoded absolute\n");
+}
+
+#[test]
+fn test_html() {
+    let mut doc = import_html(
+        "<!DOCTYPE html><html><head><title>Ignored</title><style>p { }</style></head>\n\
+         <body>\n<h1 id=\"top\">Fish &amp; chips</h1>\n\
+         <div>Loose   text <b>bold</b> <i>it</i>\n\
+         <p>Line<br>break and <a href=\"#top\">back</a>&nbsp;up</p></div>\n\
+         <ul><li>one<li>two<ol start=\"3\"><li>three</ol></ul>\n\
+         <table><tr><th>Key<th>Value<tr><td>a<td><p>b</p><p>c</p></table>\n\
+         <pre><code>line 1\n  line 2\n</code></pre>\n\
+         <blockquote><p>Quoted</p></blockquote>\n\
+         <p><u>u</u> <code>x</code><sup>2</sup> <script>alert(1)</script><span>done</span></p>\n\
+         </body></html>",
+    );
+
+    assert_eq!(
+        markdown(&mut doc),
+        "# <a id=\"top\"></a>Fish & chips\n\n\
+         Loose text **bold** *it*\n\n\
+         Line\\\nbreak and [back](#top)\u{a0}up\n\n\
+         - one\n- two\n  3. three\n\n\
+         | **Key** | **Value** |\n| --- | --- |\n| a | b<br>c |\n\n\
+         ```\nline 1\n  line 2\n```\n\n\
+         Quoted\n\nu `x`2 done\n"
+    );
+}
+
+#[test]
+fn test_non_ascii_text() {
+    let mut doc = import_html("<p>Über</p><p>日本 <b>語</b>é</p>");
+    assert_eq!(markdown(&mut doc), "Über\n\n日本 **語**é\n");
+
+    let mut doc = import_markdown("Ärger\n\n<div>Über <b>größe</b></div>\n\n<span>ñ</span>é\n");
+    assert_eq!(markdown(&mut doc), "Ärger\n\nÜber **größe**\n\nñé\n");
+}
@@ -294,6 +294,37 @@ fn test_roundtrip_numbered_list() {
     assert_eq!(paras[2].text(), "Third item");
 }
 
+/// Test computing the labels of a numbered list read back.
+#[test]
+fn test_numbered_list_labels() {
+    use ooxml_wml::ListType;
+
+    let mut builder = DocumentBuilder::new();
+    let num_id = builder.add_list(ListType::Decimal);
+    for item in &["First item", "Second item", "Third item"] {
+        let para = builder.body_mut().add_paragraph();
+        para.set_numbering(num_id, 0);
+        para.add_run().set_text(*item);
+    }
+
+    let mut buffer = Cursor::new(Vec::new());
+    builder.write(&mut buffer).unwrap();
+    buffer.set_position(0);
+    let mut doc = Document::from_reader(buffer).unwrap();
+
+    let mut numbering = doc.numbering_context().unwrap();
+    let labels: Vec<_> = doc
+        .body()
+        .paragraphs()
+        .iter()
+        .map(|para| {
+            let (num_id, ilvl) = para.numbering().unwrap();
+            numbering.next_label(num_id, ilvl).unwrap()
+        })
+        .collect();
+    assert_eq!(labels, ["1.", "2.", "3."]);
+}
+
 /// Test creating a document with a bullet list.
 #[test]
 fn test_roundtrip_bullet_list() {
@@ -654,3 +685,188 @@ fn test_document_save_with_body_modification() {
 
     assert_eq!(doc2.text(), "Original text\nAdded paragraph");
 }
+
+/// Test that a Strict document can be written, read back and edited.
+#[test]
+fn test_roundtrip_strict_document() {
+    let mut builder = DocumentBuilder::new();
+    builder.set_conformance(ooxml_wml::Conformance::Strict);
+    builder.add_paragraph("Strict text");
+
+    let mut buffer = Cursor::new(Vec::new());
+    builder.write(&mut buffer).unwrap();
+
+    let mut package = ooxml_opc::Package::open(Cursor::new(buffer.get_ref().clone())).unwrap();
+    assert_eq!(
+        package.conformance().unwrap(),
+        ooxml_wml::Conformance::Strict
+    );
+    let xml = package.read_part_string("word/document.xml").unwrap();
+    assert!(xml.contains("http://purl.oclc.org/ooxml/wordprocessingml/main"));
+
+    buffer.set_position(0);
+    let mut doc = Document::from_reader(buffer).unwrap();
+    assert_eq!(doc.text(), "Strict text");
+
+    let mut out = Cursor::new(Vec::new());
+    doc.write(&mut out).unwrap();
+    out.set_position(0);
+    let mut package = ooxml_opc::Package::open(out).unwrap();
+    assert_eq!(
+        package.conformance().unwrap(),
+        ooxml_wml::Conformance::Strict
+    );
+}
+
+/// Test that pretty serialize options give indented parts that read back and
+/// reformat to the same bytes.
+#[test]
+fn test_roundtrip_pretty_document() {
+    let mut builder = DocumentBuilder::new();
+    builder.set_serialize_options(ooxml_wml::SerializeOptions::pretty());
+    builder.add_paragraph("Pretty text");
+
+    let mut buffer = Cursor::new(Vec::new());
+    builder.write(&mut buffer).unwrap();
+
+    let mut package = ooxml_opc::Package::open(Cursor::new(buffer.get_ref().clone())).unwrap();
+    let xml = package.read_part_string("word/document.xml").unwrap();
+    assert!(
+        xml.starts_with(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n<w:document"
+        )
+    );
+    assert!(xml.contains("\n  <w:body>\n    <w:p>"), "{xml}");
+    let rels = package.read_part_string("_rels/.rels").unwrap();
+    assert!(rels.contains("\n  <Relationship "), "{rels}");
+
+    buffer.set_position(0);
+    let mut doc = Document::from_reader(buffer).unwrap();
+    assert_eq!(doc.text(), "Pretty text");
+
+    doc.set_serialize_options(ooxml_wml::SerializeOptions::pretty());
+    let mut out = Cursor::new(Vec::new());
+    doc.write(&mut out).unwrap();
+    out.set_position(0);
+    let mut package = ooxml_opc::Package::open(out).unwrap();
+    assert_eq!(package.read_part_string("word/document.xml").unwrap(), xml);
+}
+
+/// Test that a macro-enabled document keeps its VBA project and content type.
+#[test]
+fn test_roundtrip_macro_enabled_document() {
+    let project = ooxml_wml::VbaProject::new(b"vbaProject.bin bytes".to_vec());
+    let mut builder = DocumentBuilder::new();
+    builder.set_vba_project(project.clone());
+    builder.add_paragraph("With macros");
+
+    let mut buffer = Cursor::new(Vec::new());
+    builder.write(&mut buffer).unwrap();
+
+    buffer.set_position(0);
+    let mut doc = Document::from_reader(buffer).unwrap();
+    assert!(doc.is_macro_enabled());
+    assert_eq!(doc.vba_project().unwrap(), Some(project.clone()));
+
+    let mut out = Cursor::new(Vec::new());
+    doc.write(&mut out).unwrap();
+    out.set_position(0);
+    let mut doc = Document::from_reader(out).unwrap();
+    assert!(doc.is_macro_enabled());
+    assert_eq!(doc.vba_project().unwrap(), Some(project));
+    assert_eq!(
+        doc.package().content_type("word/vbaProject.bin"),
+        Some(ooxml_opc::content_type::VBA_PROJECT)
+    );
+}
+
+/// Test that `mc:AlternateContent` is resolved for reading and preserved on save.
+#[cfg(all(feature = "wml-drawings", feature = "extra-children"))]
+#[test]
+fn test_markup_compatibility() {
+    use ooxml_opc::{Package, PackageEditor};
+
+    let document = br#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"
+    xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006"
+    xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml"
+    xmlns:wps="http://schemas.microsoft.com/office/word/2010/wordprocessingShape"
+    xmlns:v="urn:schemas-microsoft-com:vml"
+    mc:Ignorable="w14">
+  <w:body>
+    <w:p w14:paraId="1A2B3C4D"><w:r><w:t>Before</w:t></w:r></w:p>
+    <w:p>
+      <w:r>
+        <mc:AlternateContent>
+          <mc:Choice Requires="wps">
+            <w:drawing>
+              <wps:wsp><wps:txbx><w:txbxContent>
+                <w:p><w:r><w:t>Modern box</w:t></w:r></w:p>
+              </w:txbxContent></wps:txbx></wps:wsp>
+            </w:drawing>
+          </mc:Choice>
+          <mc:Fallback>
+            <w:pict>
+              <v:shape><v:textbox><w:txbxContent>
+                <w:p><w:r><w:t>Legacy box</w:t></w:r></w:p>
+              </w:txbxContent></v:textbox></v:shape>
+            </w:pict>
+          </mc:Fallback>
+        </mc:AlternateContent>
+      </w:r>
+    </w:p>
+  </w:body>
+</w:document>"#;
+
+    let mut builder = DocumentBuilder::new();
+    builder.add_paragraph("Placeholder");
+    let mut buffer = Cursor::new(Vec::new());
+    builder.write(&mut buffer).unwrap();
+    buffer.set_position(0);
+    let mut editor = PackageEditor::new(Package::open(buffer).unwrap()).unwrap();
+    let content_type = editor
+        .content_type("word/document.xml")
+        .unwrap()
+        .to_string();
+    editor.set_part("word/document.xml", &content_type, document.to_vec());
+    let mut buffer = Cursor::new(Vec::new());
+    editor.write(&mut buffer).unwrap();
+
+    buffer.set_position(0);
+    let mut doc = Document::from_reader(buffer).unwrap();
+    let text = doc.body().text();
+    assert!(text.contains("Modern box"), "text: {text:?}");
+    assert!(!text.contains("Legacy box"), "text: {text:?}");
+
+    // An edited document keeps the alternatives and the ignorable markup
+    doc.body_mut().add_paragraph().add_run().set_text("After");
+    let mut out = Cursor::new(Vec::new());
+    doc.write(&mut out).unwrap();
+    let out = out.into_inner();
+    let mut package = Package::open(Cursor::new(out.clone())).unwrap();
+    let written = String::from_utf8(package.read_part("word/document.xml").unwrap()).unwrap();
+    for kept in [
+        r#"w14:paraId="1A2B3C4D""#,
+        r#"mc:Ignorable="w14""#,
+        r#"<mc:Choice Requires="wps">"#,
+        "Modern box",
+        "Legacy box",
+        "After",
+    ] {
+        assert!(written.contains(kept), "{kept} missing from {written}");
+    }
+    assert!(!written.contains(r#"mc:AlternateContent=""#), "{written}");
+    assert!(
+        written.contains("<w:r><mc:AlternateContent>")
+            && written.contains("</mc:AlternateContent></w:r>"),
+        "{written}"
+    );
+
+    let doc = Document::from_reader(Cursor::new(out)).unwrap();
+    let text = doc.body().text();
+    assert!(
+        text.contains("Modern box") && text.contains("After"),
+        "text: {text:?}"
+    );
+    assert!(!text.contains("Legacy box"), "text: {text:?}");
+}
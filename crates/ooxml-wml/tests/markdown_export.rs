@@ -0,0 +1,351 @@
+//! Tests for Markdown export (`Document::to_markdown`).
+//!
+//! Documents are built with `DocumentBuilder`, read back and rendered, and
+//! the Markdown is compared with what each feature maps to.
+
+#![cfg(feature = "markdown")]
+
+use ooxml_wml::convenience::{NS_M, VMergeType};
+use ooxml_wml::markdown::MarkdownOptions;
+use ooxml_wml::types;
+use ooxml_wml::{Document, DocumentBuilder, Drawing, ListType, NumberingLevel};
+use ooxml_xml::{PositionedNode, RawXmlElement, RawXmlNode};
+use std::io::Cursor;
+
+/// Build a document, write to memory, read back.
+fn roundtrip(builder: DocumentBuilder) -> Document<Cursor<Vec<u8>>> {
+    let mut buffer = Cursor::new(Vec::new());
+    builder.write(&mut buffer).unwrap();
+    buffer.set_position(0);
+    Document::from_reader(buffer).unwrap()
+}
+
+/// Render a built document as Markdown.
+fn render(builder: DocumentBuilder) -> String {
+    roundtrip(builder)
+        .to_markdown(&MarkdownOptions::default())
+        .unwrap()
+}
+
+fn string(value: &str) -> Option<Box<types::CTString>> {
+    Some(Box::new(types::CTString {
+        value: value.to_string(),
+        #[cfg(feature = "extra-attrs")]
+        extra_attrs: Default::default(),
+    }))
+}
+
+#[test]
+fn test_headings_and_paragraphs() {
+    let mut builder = DocumentBuilder::new();
+    builder.add_style(types::Style {
+        r#type: Some(types::STStyleType::Paragraph),
+        style_id: Some("Heading2".to_string()),
+        name: string("heading 2"),
+        ..Default::default()
+    });
+    {
+        let body = builder.body_mut();
+        let para = body.add_paragraph();
+        para.set_outline_level(0);
+        para.add_run().set_text("Title");
+
+        let para = body.add_paragraph();
+        para.set_properties(types::ParagraphProperties {
+            paragraph_style: string("Heading2"),
+            ..Default::default()
+        });
+        para.add_run().set_text("Section");
+
+        body.add_paragraph()
+            .add_run()
+            .set_text("Stars *and* [brackets] cost $5");
+        body.add_paragraph().add_run().set_text("# not a heading");
+        body.add_paragraph().add_run().set_text("1. not a list");
+        body.add_paragraph();
+    }
+
+    assert_eq!(
+        render(builder),
+        "# Title\n\n## Section\n\n\
+         Stars \\*and\\* \\[brackets\\] cost \\$5\n\n\
+         \\# not a heading\n\n\
+         1\\. not a list\n"
+    );
+}
+
+#[test]
+fn test_run_formatting() {
+    let mut builder = DocumentBuilder::new();
+    {
+        let para = builder.body_mut().add_paragraph();
+        para.add_run().set_text("Plain ");
+        let run = para.add_run();
+        run.set_text("bold ");
+        run.set_bold(true);
+        let run = para.add_run();
+        run.set_text("both");
+        run.set_bold(true);
+        run.set_italic(true);
+        para.add_run().set_text(", ");
+        let run = para.add_run();
+        run.set_text("gone");
+        run.set_strikethrough(true);
+        para.add_run().set_text(" and ");
+        let run = para.add_run();
+        run.set_text("x = `y`");
+        run.set_fonts(types::Fonts {
+            ascii: Some("Courier New".to_string()),
+            ..Default::default()
+        });
+        let run = para.add_run();
+        run.set_text("hidden");
+        run.set_vanish(true);
+    }
+
+    assert_eq!(
+        render(builder),
+        "Plain **bold *both***, ~~gone~~ and `` x = `y` ``\n"
+    );
+}
+
+#[test]
+fn test_lists() {
+    let mut builder = DocumentBuilder::new();
+    let outline = builder.add_custom_list(vec![
+        NumberingLevel::bullet(0),
+        NumberingLevel {
+            start: 3,
+            ..NumberingLevel::decimal(1)
+        },
+    ]);
+    let numbers = builder.add_list(ListType::Decimal);
+    {
+        let body = builder.body_mut();
+        for (text, num_id, ilvl) in [
+            ("one", outline, 0),
+            ("nested", outline, 1),
+            ("nested too", outline, 1),
+            ("two", outline, 0),
+            ("again", outline, 1),
+            ("first", numbers, 0),
+            ("second", numbers, 0),
+        ] {
+            let para = body.add_paragraph();
+            para.set_numbering(num_id, ilvl);
+            para.add_run().set_text(text);
+        }
+        body.add_paragraph().add_run().set_text("after");
+    }
+
+    assert_eq!(
+        render(builder),
+        "- one\n  3. nested\n  4. nested too\n- two\n  3. again\n\
+         1) first\n2) second\n\nafter\n"
+    );
+}
+
+#[test]
+fn test_tables() {
+    let mut builder = DocumentBuilder::new();
+    {
+        let table = builder.body_mut().add_table();
+        let row = table.add_row();
+        let cell = row.add_cell();
+        cell.set_grid_span(2);
+        cell.add_paragraph().add_run().set_text("wide");
+        let cell = row.add_cell();
+        cell.set_vertical_merge(VMergeType::Restart);
+        cell.add_paragraph().add_run().set_text("tall");
+
+        let row = table.add_row();
+        row.add_cell().add_paragraph().add_run().set_text("a|b");
+        let cell = row.add_cell();
+        cell.add_paragraph().add_run().set_text("two");
+        cell.add_paragraph().add_run().set_text("lines");
+        let cell = row.add_cell();
+        cell.set_vertical_merge(VMergeType::Continue);
+        cell.add_paragraph();
+    }
+
+    assert_eq!(
+        render(builder),
+        "| wide |  | tall |\n| --- | --- | --- |\n| a\\|b | two<br>lines |  |\n"
+    );
+}
+
+#[test]
+fn test_links_and_anchors() {
+    let mut builder = DocumentBuilder::new();
+    let rel_id = builder.add_hyperlink("https://example.com/a b");
+    {
+        let para = builder.body_mut().add_paragraph();
+        let link = para.add_hyperlink();
+        link.set_rel_id(&rel_id);
+        let run = link.add_run();
+        run.set_text("external");
+        run.set_bold(true);
+        para.add_run().set_text(" and ");
+        let link = para.add_hyperlink();
+        link.set_anchor("target");
+        link.add_run().set_text("internal");
+
+        let para = builder.body_mut().add_paragraph();
+        para.add_bookmark_start(1, "target");
+        para.add_run().set_text("here");
+        para.add_bookmark_end(1);
+    }
+    let mut doc = roundtrip(builder);
+
+    assert_eq!(
+        doc.to_markdown(&MarkdownOptions::default()).unwrap(),
+        "[**external**](https://example.com/a%20b) and [internal](#target)\n\n\
+         <a id=\"target\"></a>here\n"
+    );
+    assert_eq!(
+        doc.to_markdown(&MarkdownOptions::text_only()).unwrap(),
+        "[**external**](https://example.com/a%20b) and [internal](#target)\n\nhere\n"
+    );
+}
+
+#[test]
+fn test_footnotes() {
+    let mut builder = DocumentBuilder::new();
+    let first = builder.add_footnote().add_paragraph("First note").id();
+    let second = builder.add_footnote().add_paragraph("Second note").id();
+    let endnote = builder.add_endnote().add_paragraph("An endnote").id();
+    {
+        let para = builder.body_mut().add_paragraph();
+        para.add_run().set_text("Text");
+        para.add_run().add_footnote_ref(second as i64);
+        para.add_run().set_text(" more");
+        para.add_run().add_footnote_ref(first as i64);
+        para.add_run().add_endnote_ref(endnote as i64);
+    }
+    let mut doc = roundtrip(builder);
+
+    assert_eq!(
+        doc.to_markdown(&MarkdownOptions::default()).unwrap(),
+        "Text[^1] more[^2][^3]\n\n\
+         [^1]: Second note\n\n[^2]: First note\n\n[^3]: An endnote\n"
+    );
+    assert_eq!(
+        doc.to_markdown(&MarkdownOptions::text_only()).unwrap(),
+        "Text more\n"
+    );
+}
+
+/// Append an equation to a paragraph, wrapped in `m:oMath` and, for display
+/// math, `m:oMathPara`.
+fn add_math(para: &mut types::Paragraph, content: &str, display: bool) {
+    let math = format!("<m:oMath>{content}</m:oMath>");
+    let xml = match display {
+        true => format!("<m:oMathPara xmlns:m=\"{NS_M}\">{math}</m:oMathPara>"),
+        false => math.replace("<m:oMath>", &format!("<m:oMath xmlns:m=\"{NS_M}\">")),
+    };
+    // Serialized after the content so far, counting the `pPr` slot
+    let position = para.paragraph_content.len() + 1;
+    para.extra_children.push(PositionedNode::new(
+        position,
+        RawXmlNode::Element(RawXmlElement::parse(xml.as_bytes()).unwrap()),
+    ));
+}
+
+#[test]
+fn test_math() {
+    let mut builder = DocumentBuilder::new();
+    {
+        let body = builder.body_mut();
+        let para = body.add_paragraph();
+        para.add_run().set_text("Half is ");
+        add_math(
+            para,
+            "<m:f><m:num><m:r><m:t>1</m:t></m:r></m:num>\
+             <m:den><m:r><m:t>2</m:t></m:r></m:den></m:f>",
+            false,
+        );
+        para.add_run().set_text(" and squares are ");
+        add_math(
+            para,
+            "<m:sSup><m:e><m:r><m:t>x</m:t></m:r></m:e>\
+             <m:sup><m:r><m:t>2</m:t></m:r></m:sup></m:sSup>",
+            false,
+        );
+
+        add_math(
+            body.add_paragraph(),
+            "<m:rad><m:deg/><m:e><m:r><m:t>π</m:t></m:r></m:e></m:rad>",
+            true,
+        );
+    }
+
+    assert_eq!(
+        render(builder),
+        "Half is $\\frac{1}{2}$ and squares are $x^{2}$\n\n$$\n\\sqrt{\\pi}\n$$\n"
+    );
+}
+
+#[test]
+fn test_code_blocks() {
+    let mut builder = DocumentBuilder::new();
+    builder.add_style(types::Style {
+        r#type: Some(types::STStyleType::Paragraph),
+        style_id: Some("SourceCode".to_string()),
+        name: string("Source Code"),
+        ..Default::default()
+    });
+    {
+        let body = builder.body_mut();
+        body.add_paragraph().add_run().set_text("Example:");
+        for line in ["fn main() {", "    println!(\"*hi*\");", "}"] {
+            let para = body.add_paragraph();
+            para.set_properties(types::ParagraphProperties {
+                paragraph_style: string("SourceCode"),
+                ..Default::default()
+            });
+            para.add_run().set_text(line);
+        }
+        body.add_paragraph().add_run().set_text("Done.");
+    }
+
+    assert_eq!(
+        render(builder),
+        "Example:\n\n```\nfn main() {\n    println!(\"*hi*\");\n}\n```\n\nDone.\n"
+    );
+}
+
+#[test]
+fn test_images_and_floating_content() {
+    let mut builder = DocumentBuilder::new();
+    let rel_id = builder.add_image(b"\x89PNG\r\n\x1a\n".to_vec(), "image/png");
+    {
+        let para = builder.body_mut().add_paragraph();
+        para.add_run().set_text("Inline ");
+        let mut drawing = Drawing::new();
+        drawing.add_image(&rel_id).set_description("a chart");
+        let mut doc_id = 1usize;
+        para.add_run().add_drawing(drawing.build(&mut doc_id));
+
+        let para = builder.body_mut().add_paragraph();
+        para.add_run().set_text("Anchored");
+        let mut drawing = Drawing::new();
+        drawing
+            .add_anchored_image(&rel_id)
+            .set_description("a photo");
+        para.add_run().add_drawing(drawing.build(&mut doc_id));
+        let mut drawing = Drawing::new();
+        drawing.add_text_box("Boxed *text*");
+        para.add_run().add_drawing(drawing.build(&mut doc_id));
+    }
+    let mut doc = roundtrip(builder);
+
+    let markdown = doc.to_markdown(&MarkdownOptions::default()).unwrap();
+    let target = doc.get_hyperlink_url(&rel_id).unwrap().to_string();
+    assert_eq!(
+        markdown,
+        format!(
+            "Inline ![a chart]({target})\n\nAnchored\n\n![a photo]({target})\n\n\
+             Boxed \\*text\\*\n"
+        )
+    );
+}
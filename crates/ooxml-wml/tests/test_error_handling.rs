@@ -105,3 +105,139 @@ fn test_malformed_xml_in_document() {
         "should fail when document.xml has malformed XML"
     );
 }
+
+/// Build a minimal DOCX around the given document.xml and extra parts.
+fn docx_with(document: &[u8], extra: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut buf = Cursor::new(Vec::new());
+    {
+        use std::io::Write;
+        let mut writer = zip::ZipWriter::new(&mut buf);
+        let options = zip::write::SimpleFileOptions::default();
+
+        writer.start_file("[Content_Types].xml", options).unwrap();
+        writer
+            .write_all(
+                br#"<?xml version="1.0" encoding="UTF-8"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+  <Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+  <Default Extension="xml" ContentType="application/xml"/>
+  <Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/>
+</Types>"#,
+            )
+            .unwrap();
+
+        writer.start_file("_rels/.rels", options).unwrap();
+        writer
+            .write_all(
+                br#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+  <Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/>
+</Relationships>"#,
+            )
+            .unwrap();
+
+        writer.start_file("word/document.xml", options).unwrap();
+        writer.write_all(document).unwrap();
+        for (name, data) in extra {
+            writer.start_file(*name, options).unwrap();
+            writer.write_all(data).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+    buf.into_inner()
+}
+
+/// Pathologically nested markup is rejected when limits are enforced.
+#[test]
+fn test_nesting_limit() {
+    let mut document = String::from(
+        r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>"#,
+    );
+    document.push_str(&"<w:customXml>".repeat(1000));
+    document.push_str(&"</w:customXml>".repeat(1000));
+    document.push_str("</w:body></w:document>");
+    let bytes = docx_with(document.as_bytes(), &[]);
+
+    let limits = ooxml_wml::PackageLimits::default();
+    let result = Doc::from_reader_with_limits(Cursor::new(bytes), &limits);
+    assert!(matches!(
+        result,
+        Err(ooxml_wml::Error::Package(ooxml_opc::Error::LimitExceeded(
+            _
+        )))
+    ));
+}
+
+/// A highly compressible part (zip bomb) is rejected before it is inflated.
+#[test]
+fn test_compression_ratio_limit() {
+    let document = br#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body/></w:document>"#;
+    let padding = vec![b' '; 16 * 1024 * 1024];
+    let bytes = docx_with(document, &[("word/padding.xml", &padding)]);
+
+    let limits = ooxml_wml::PackageLimits::default();
+    let result = Doc::from_reader_with_limits(Cursor::new(bytes.clone()), &limits);
+    assert!(matches!(
+        result,
+        Err(ooxml_wml::Error::Package(ooxml_opc::Error::LimitExceeded(
+            _
+        )))
+    ));
+    assert!(Doc::from_reader(Cursor::new(bytes)).is_ok());
+}
+
+/// Errors from the generated parsers name the part, line, column and
+/// element path of the offending markup.
+#[test]
+fn test_parse_error_location() {
+    let document = br#"<?xml version="1.0" encoding="UTF-8"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main">
+  <w:body>
+    <w:p><w:r><w:t>Fine</w:t></w:r></w:p>
+    <w:p><w:pPr><w:pStyle/></w:pPr></w:p>
+  </w:body>
+</w:document>"#;
+    let bytes = docx_with(document, &[]);
+
+    let Err(err) = Doc::from_reader(Cursor::new(bytes)) else {
+        panic!("a missing attribute should fail to parse");
+    };
+    let location = err.location().expect("parse errors are located");
+    assert_eq!(location.part.as_deref(), Some("word/document.xml"));
+    let position = location.position.unwrap();
+    assert_eq!((position.line, position.column), (5, 17));
+    assert_eq!(
+        location.path,
+        ["w:document", "w:body", "w:p", "w:pPr", "w:pStyle"]
+    );
+    assert_eq!(
+        err.to_string(),
+        "parse error: word/document.xml:5:17 in <w:document/w:body/w:p/w:pPr/w:pStyle>: \
+         missing attribute: val"
+    );
+}
+
+/// Parse errors are located in the part as stored, before ignorable markup
+/// was removed.
+#[test]
+fn test_parse_error_location_with_ignorable_markup() {
+    let document = br#"<?xml version="1.0" encoding="UTF-8"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:x="urn:example" mc:Ignorable="x">
+  <w:body>
+    <x:note/><w:p x:id="1"><w:pPr><w:pStyle/></w:pPr></w:p>
+  </w:body>
+</w:document>"#;
+    let bytes = docx_with(document, &[]);
+
+    let Err(err) = Doc::from_reader(Cursor::new(bytes)) else {
+        panic!("a missing attribute should fail to parse");
+    };
+    let location = err.location().expect("parse errors are located");
+    let position = location.position.unwrap();
+    assert_eq!(&document[position.offset as usize..][..10], b"<w:pStyle/");
+    assert_eq!((position.line, position.column), (4, 35));
+    assert_eq!(
+        location.path,
+        ["w:document", "w:body", "w:p", "w:pPr", "w:pStyle"]
+    );
+}
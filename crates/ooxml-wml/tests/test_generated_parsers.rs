@@ -7,13 +7,13 @@
 use ooxml_wml::RawXmlNode;
 use ooxml_wml::parsers::{FromXml, ParseError};
 use ooxml_wml::types::*;
-use quick_xml::Reader;
+use quick_xml::NsReader;
 use quick_xml::events::Event;
 use std::io::Cursor;
 
 /// Helper to parse an XML string using the FromXml trait.
 fn parse_from_xml<T: FromXml>(xml: &str) -> Result<T, ParseError> {
-    let mut reader = Reader::from_reader(Cursor::new(xml.as_bytes()));
+    let mut reader = NsReader::from_reader(Cursor::new(xml.as_bytes()));
     let mut buf = Vec::new();
     loop {
         match reader.read_event_into(&mut buf)? {
@@ -503,3 +503,75 @@ fn test_unknown_children_empty_element() {
     assert_eq!(rpr.extra_children.len(), 1);
     assert_eq!(rpr.extra_children[0].position, 1);
 }
+
+#[cfg(feature = "extra-children")]
+#[test]
+fn test_foreign_namespace_children() {
+    // Elements in other namespaces are unknown children even when their local
+    // names match WML elements
+    let xml = r#"<w:body xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"
+            xmlns:x="urn:example:custom">
+        <w:p><w:r><w:t>Kept</w:t><x:t>Custom</x:t></w:r></w:p>
+        <x:p/>
+        <p xmlns="urn:example:custom"><r/></p>
+    </w:body>"#;
+    let body: Body = parse_from_xml(xml).expect("should parse body");
+    assert_eq!(body.block_content.len(), 1);
+    assert_eq!(body.extra_children.len(), 2);
+
+    let BlockContent::P(para) = &body.block_content[0] else {
+        panic!("expected paragraph");
+    };
+    let ParagraphContent::R(run) = &para.paragraph_content[0] else {
+        panic!("expected run");
+    };
+    assert_eq!(run.run_content.len(), 1);
+    assert_eq!(run.extra_children.len(), 1);
+    assert_eq!(run.extra_children[0].position, 1);
+    if let RawXmlNode::Element(elem) = &run.extra_children[0].node {
+        // The prefix is kept for serialization
+        assert_eq!(elem.name, "x:t");
+    } else {
+        panic!("expected Element node");
+    }
+}
+
+#[cfg(feature = "borrowed")]
+#[test]
+fn test_parse_borrowed() {
+    use ooxml_wml::borrowed;
+    use ooxml_xml::{IntoOwned, parse_borrowed};
+    use std::borrow::Cow;
+
+    let xml = br#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:x="urn:custom">
+        <w:body><w:p><w:r><w:t>Hello</w:t><x:t>skipped</x:t><w:t>a &amp; b</w:t></w:r></w:p></w:body>
+    </w:document>"#;
+    let doc: borrowed::Document = parse_borrowed(xml).expect("should parse document");
+
+    fn texts<'d, 'a>(doc: &'d borrowed::Document<'a>) -> Vec<&'d Cow<'a, str>> {
+        let body = doc.body.as_ref().expect("should have body");
+        let borrowed::BlockContent::P(p) = &body.block_content[0] else {
+            panic!("expected paragraph");
+        };
+        let borrowed::ParagraphContent::R(r) = &p.paragraph_content[0] else {
+            panic!("expected run");
+        };
+        r.run_content
+            .iter()
+            .filter_map(|c| match c {
+                borrowed::RunContent::T(t) => t.text.as_ref(),
+                _ => None,
+            })
+            .collect()
+    }
+
+    // Plain text points into the input; decoded text is copied
+    let text = texts(&doc);
+    assert!(matches!(text[..], [Cow::Borrowed("Hello"), Cow::Owned(_)]));
+    assert_eq!(text[1], "a & b");
+
+    let owned: borrowed::Document<'static> = doc.into_owned();
+    let text = texts(&owned);
+    assert_eq!(text.len(), 2);
+    assert_eq!(text[0], "Hello");
+}
@@ -0,0 +1,107 @@
+//! Tests for the JSON document model of the WML generated types.
+//!
+//! These tests verify that a parsed document survives a trip through JSON
+//! and still serializes to the same XML.
+
+#![cfg(all(feature = "serde", feature = "extra-attrs", feature = "extra-children"))]
+
+use ooxml_wml::parsers::FromXml;
+use ooxml_wml::serializers::ToXml;
+use ooxml_wml::types::*;
+use quick_xml::events::Event;
+use quick_xml::{NsReader, Writer};
+use serde_json::json;
+
+/// Parse the root element of an XML string using the FromXml trait.
+fn parse_from_xml<T: FromXml>(xml: &str) -> T {
+    let mut reader = NsReader::from_str(xml);
+    loop {
+        match reader.read_event().expect("should read event") {
+            Event::Start(e) => return T::from_xml(&mut reader, &e, false).expect("should parse"),
+            Event::Empty(e) => return T::from_xml(&mut reader, &e, true).expect("should parse"),
+            Event::Eof => panic!("no element found"),
+            _ => {}
+        }
+    }
+}
+
+/// Serialize a value to an XML string using the ToXml trait.
+fn serialize_to_xml<T: ToXml>(value: &T, tag: &str) -> String {
+    let mut writer = Writer::new(Vec::new());
+    value
+        .write_element(tag, &mut writer)
+        .expect("should serialize");
+    String::from_utf8(writer.into_inner()).expect("valid utf-8")
+}
+
+const DOCUMENT: &str = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:x="urn:custom">
+    <w:body>
+        <w:p>
+            <w:pPr><w:jc w:val="center"/></w:pPr>
+            <w:r><w:rPr><w:b/></w:rPr><w:t xml:space="preserve">Hello </w:t></w:r>
+            <w:r x:tag="1"><w:t>world</w:t><x:note>kept</x:note></w:r>
+        </w:p>
+        <w:tbl><w:tblPr/><w:tblGrid><w:gridCol w:w="2000"/></w:tblGrid><w:tr><w:tc><w:p><w:r><w:t>cell</w:t></w:r></w:p></w:tc></w:tr></w:tbl>
+    </w:body>
+</w:document>"#;
+
+#[test]
+fn test_document_json_roundtrip() {
+    let doc: Document = parse_from_xml(DOCUMENT);
+    let json = serde_json::to_string(&doc).expect("should serialize to JSON");
+    let loaded: Document = serde_json::from_str(&json).expect("should deserialize from JSON");
+
+    // Namespace declarations live in a map, so compare the re-parsed XML
+    // rather than the raw strings.
+    let written: Document = parse_from_xml(&serialize_to_xml(&loaded, "w:document"));
+    let original: Document = parse_from_xml(&serialize_to_xml(&doc, "w:document"));
+    assert_eq!(
+        serde_json::to_value(&written).unwrap(),
+        serde_json::to_value(&original).unwrap()
+    );
+}
+
+#[test]
+fn test_document_json_representation() {
+    let doc: Document = parse_from_xml(DOCUMENT);
+    let value = serde_json::to_value(&doc).expect("should serialize to JSON");
+
+    // Content groups keep their field name and tag each item by element name
+    let paragraph = &value["body"]["block_content"][0]["p"];
+    assert_eq!(paragraph["pPr"]["jc"]["@w:val"], "center");
+    let runs = &paragraph["paragraph_content"];
+    assert_eq!(runs[0]["r"]["run_content"][0]["t"]["$text"], "Hello ");
+    assert!(runs[0]["r"].get("extra_attrs").is_none());
+
+    // Unknown attributes and children are kept
+    let run = &runs[1]["r"];
+    assert_eq!(run["extra_attrs"], json!({ "x:tag": "1" }));
+    assert_eq!(
+        run["extra_children"],
+        json!([{
+            "position": 1,
+            "node": {
+                "element": {
+                    "name": "x:note",
+                    "attributes": [],
+                    "children": [{ "text": "kept" }],
+                    "self_closing": false
+                }
+            }
+        }])
+    );
+    assert!(value["body"]["block_content"][1]["tbl"].is_object());
+}
+
+#[test]
+fn test_edited_json_serializes_to_xml() {
+    let doc: Document = parse_from_xml(DOCUMENT);
+    let mut value = serde_json::to_value(&doc).expect("should serialize to JSON");
+    value["body"]["block_content"][0]["p"]["paragraph_content"][1]["r"]["run_content"][0]["t"]["$text"] =
+        json!("there");
+    let edited: Document = serde_json::from_value(value).expect("should deserialize from JSON");
+    let xml = serialize_to_xml(&edited, "w:document");
+    assert!(xml.contains(">there<"));
+    assert!(!xml.contains(">world<"));
+    assert!(xml.contains("<x:note>kept</x:note>"));
+}
@@ -0,0 +1,183 @@
+//! Tests for the generated `Visit`/`VisitMut` traversal of the WML types.
+//!
+//! These tests verify that the default traversal reaches content in nested
+//! structures (tables in tables, SDTs, unknown children), that `VisitMut`
+//! edits survive serialization, and that path queries run over the types.
+
+#![cfg(all(feature = "visit", feature = "extra-children"))]
+
+use ooxml_wml::parsers::FromXml;
+use ooxml_wml::serializers::ToXml;
+use ooxml_wml::types::*;
+use ooxml_wml::visit::{self, Visit, VisitMut};
+use ooxml_xml::{Query, RawXmlElement, RawXmlNode};
+use quick_xml::events::Event;
+use quick_xml::{NsReader, Writer};
+
+/// Parse the root element of an XML string using the FromXml trait.
+fn parse_from_xml<T: FromXml>(xml: &str) -> T {
+    let mut reader = NsReader::from_str(xml);
+    loop {
+        match reader.read_event().expect("should read event") {
+            Event::Start(e) => return T::from_xml(&mut reader, &e, false).expect("should parse"),
+            Event::Empty(e) => return T::from_xml(&mut reader, &e, true).expect("should parse"),
+            Event::Eof => panic!("no element found"),
+            _ => {}
+        }
+    }
+}
+
+/// Serialize a value to an XML string using the ToXml trait.
+fn serialize_to_xml<T: ToXml>(value: &T, tag: &str) -> String {
+    let mut writer = Writer::new(Vec::new());
+    value
+        .write_element(tag, &mut writer)
+        .expect("should serialize");
+    String::from_utf8(writer.into_inner()).expect("valid utf-8")
+}
+
+const DOCUMENT: &str = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:x="urn:custom">
+    <w:body>
+        <w:p><w:r><w:t>top secret</w:t></w:r></w:p>
+        <w:tbl>
+            <w:tblPr/><w:tblGrid><w:gridCol w:w="2000"/></w:tblGrid>
+            <w:tr><w:tc>
+                <w:tbl>
+                    <w:tblPr/><w:tblGrid><w:gridCol w:w="1000"/></w:tblGrid>
+                    <w:tr><w:tc><w:p><w:r><w:t>nested secret</w:t></w:r></w:p></w:tc></w:tr>
+                </w:tbl>
+                <w:p/>
+            </w:tc></w:tr>
+        </w:tbl>
+        <w:sdt>
+            <w:sdtContent><w:p><w:r><w:t>secret in sdt</w:t></w:r></w:p></w:sdtContent>
+        </w:sdt>
+        <w:p><w:r><w:t>public</w:t><x:note><x:inner>secret note</x:inner></x:note></w:r></w:p>
+    </w:body>
+</w:document>"#;
+
+#[derive(Default)]
+struct TextCollector<'ast> {
+    texts: Vec<&'ast str>,
+    tables: usize,
+    raw: Vec<&'ast str>,
+}
+
+impl<'ast> Visit<'ast> for TextCollector<'ast> {
+    fn visit_text(&mut self, node: &'ast Text) {
+        self.texts.extend(node.text.as_deref());
+    }
+
+    fn visit_table(&mut self, node: &'ast Table) {
+        self.tables += 1;
+        visit::walk_table(self, node);
+    }
+
+    fn visit_raw_xml_node(&mut self, node: &'ast RawXmlNode) {
+        if let RawXmlNode::Text(text) = node {
+            self.raw.push(text);
+        }
+        visit::walk_raw_xml_node(self, node);
+    }
+}
+
+#[test]
+fn test_visit_reaches_nested_content() {
+    let doc: Document = parse_from_xml(DOCUMENT);
+    let mut collector = TextCollector::default();
+    collector.visit_document(&doc);
+
+    assert_eq!(
+        collector.texts,
+        ["top secret", "nested secret", "secret in sdt", "public"]
+    );
+    assert_eq!(collector.tables, 2);
+    assert_eq!(collector.raw, ["secret note"]);
+}
+
+#[test]
+fn test_visit_skips_children_without_walk() {
+    struct TopLevelTables(usize);
+
+    impl Visit<'_> for TopLevelTables {
+        fn visit_table(&mut self, _node: &Table) {
+            self.0 += 1;
+        }
+    }
+
+    let doc: Document = parse_from_xml(DOCUMENT);
+    let mut counter = TopLevelTables(0);
+    counter.visit_document(&doc);
+    assert_eq!(counter.0, 1);
+}
+
+struct Redactor;
+
+impl VisitMut for Redactor {
+    fn visit_text_mut(&mut self, node: &mut Text) {
+        if let Some(text) = &mut node.text {
+            *text = text.replace("secret", "[redacted]");
+        }
+    }
+
+    fn visit_raw_xml_element_mut(&mut self, node: &mut RawXmlElement) {
+        for child in &mut node.children {
+            if let RawXmlNode::Text(text) = child {
+                *text = text.replace("secret", "[redacted]");
+            }
+        }
+        visit::walk_raw_xml_element_mut(self, node);
+    }
+}
+
+#[test]
+fn test_visit_mut_rewrites_everywhere() {
+    let mut doc: Document = parse_from_xml(DOCUMENT);
+    Redactor.visit_document_mut(&mut doc);
+
+    let xml = serialize_to_xml(&doc, "w:document");
+    assert!(!xml.contains("secret"), "unredacted text in {xml}");
+    assert_eq!(xml.matches("[redacted]").count(), 4);
+    assert!(xml.contains("public"));
+}
+
+const LINKED: &str = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:wp="http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+    <w:body>
+        <w:p><w:hyperlink r:id="rId4"><w:r><w:t>site</w:t></w:r></w:hyperlink></w:p>
+        <w:p><w:r><w:drawing><wp:inline><a:graphic><a:graphicData>
+            <a:blip r:embed="rId5"/>
+        </a:graphicData></a:graphic></wp:inline></w:drawing></w:r></w:p>
+        <w:p w:rsidR="00AB12CD"><w:r><w:t xml:space="preserve">two </w:t></w:r><w:r><w:t>runs</w:t></w:r></w:p>
+    </w:body>
+</w:document>"#;
+
+#[test]
+#[cfg(feature = "wml-hyperlinks")]
+fn test_query_typed_tree() {
+    let doc: Document = parse_from_xml(LINKED);
+    let query = |expr| Query::new(expr).expect("valid query");
+
+    // Attributes of typed elements and of raw children alike
+    assert_eq!(query("//@r:id").tree_values(&doc), ["rId4"]);
+    assert_eq!(query("//a:blip/@r:embed").tree_values(&doc), ["rId5"]);
+    assert_eq!(query("//@r:*").tree_values(&doc), ["rId4", "rId5"]);
+    assert_eq!(
+        query("w:body/w:p[@w:rsidR='00AB12CD']/w:r/w:t/text()").tree_values(&doc),
+        ["two ", "runs"]
+    );
+
+    // Element matches are the typed nodes themselves
+    let links = query("//w:hyperlink").select_tree(&doc);
+    let link = links[0].downcast::<Hyperlink>().expect("typed hyperlink");
+    assert_eq!(link.id.as_deref(), Some("rId4"));
+    let runs = query("w:body/w:p[3]/w:r[last()]").select_tree(&doc);
+    assert_eq!(runs.len(), 1);
+    assert!(runs[0].downcast::<Run>().is_some());
+
+    // Raw children are matched as raw elements
+    let inline = query("//w:drawing/wp:inline").select_tree(&doc);
+    assert_eq!(
+        inline[0].as_raw().map(|e| e.name.as_str()),
+        Some("wp:inline")
+    );
+}
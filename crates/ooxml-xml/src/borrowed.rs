@@ -0,0 +1,269 @@
+//! Zero-copy parsing into borrowed types.
+//!
+//! Generated types own their strings, so parsing a part allocates for every
+//! attribute value and text node. `ooxml-wml`, built with its `borrowed`
+//! feature, also provides `Foo<'a>` views whose strings are `Cow<'a, str>`
+//! slices of the input, parsed through [`FromXmlBorrowed`] from an in-memory
+//! buffer. The other format crates have no borrowed views yet. Strings
+//! are only copied where the markup needs decoding, such as text split by
+//! entity references. Unknown elements and attributes are skipped rather than
+//! captured, since borrowed views are meant for reading.
+//!
+//! [`IntoOwned`] turns a view into a `'static` value when it has to outlive
+//! the buffer.
+
+use quick_xml::NsReader;
+use quick_xml::events::attributes::Attributes;
+use quick_xml::events::{BytesStart, Event};
+use std::borrow::Cow;
+
+use crate::{ParseError, local_name_in};
+
+/// Reader over an in-memory XML document that yields events borrowing from
+/// the document.
+pub struct BorrowedReader<'a> {
+    input: &'a [u8],
+    reader: NsReader<&'a [u8]>,
+}
+
+impl<'a> BorrowedReader<'a> {
+    /// Create a reader over a complete XML document.
+    pub fn new(input: &'a [u8]) -> Self {
+        Self {
+            input,
+            reader: NsReader::from_reader(input),
+        }
+    }
+
+    /// Read the next event.
+    pub fn read_event(&mut self) -> Result<Event<'a>, ParseError> {
+        Ok(self.reader.read_event()?)
+    }
+
+    /// Attributes of a start tag read by this reader, borrowing from the
+    /// document.
+    pub fn attributes(&self, start: &BytesStart<'a>) -> Result<Attributes<'a>, ParseError> {
+        // Events read from a slice point into it; recover the slice so the
+        // attribute values share its lifetime
+        let offset = (start.as_ptr() as usize).wrapping_sub(self.input.as_ptr() as usize);
+        let content = self
+            .input
+            .get(offset..offset.wrapping_add(start.len()))
+            .filter(|content| content.as_ptr() == start.as_ptr())
+            .ok_or_else(|| {
+                ParseError::InvalidValue("start tag was not read by this reader".to_string())
+            })?;
+        let content = std::str::from_utf8(content)
+            .map_err(|e| ParseError::InvalidValue(format!("invalid UTF-8 in start tag: {}", e)))?;
+        Ok(Attributes::new(content, start.name().as_ref().len()))
+    }
+
+    /// Local name of an element if it is in one of `namespaces`; see
+    /// [`local_name_in`].
+    pub fn local_name_in<'e>(&self, element: &'e BytesStart, namespaces: &[&str]) -> &'e [u8] {
+        local_name_in(&self.reader, element, namespaces)
+    }
+
+    /// Skip the rest of the current element.
+    pub fn skip_element(&mut self) -> Result<(), ParseError> {
+        let mut depth = 1u32;
+        loop {
+            match self.read_event()? {
+                Event::Start(_) => depth += 1,
+                Event::End(_) => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Read the text content of the current element until its end tag.
+    ///
+    /// Text in a single run is borrowed from the document.
+    pub fn read_text(&mut self) -> Result<Cow<'a, str>, ParseError> {
+        let mut text = None;
+        loop {
+            match self.read_event()? {
+                Event::Text(e) => push_text(&mut text, e.decode().unwrap_or_default()),
+                Event::CData(e) => push_text(&mut text, e.decode().unwrap_or_default()),
+                Event::GeneralRef(e) => {
+                    let name = e.decode().unwrap_or_default();
+                    if let Some(s) = quick_xml::escape::resolve_xml_entity(&name) {
+                        push_text(&mut text, s);
+                    }
+                }
+                Event::End(_) | Event::Eof => break,
+                _ => {}
+            }
+        }
+        Ok(text.unwrap_or_default())
+    }
+}
+
+/// Trait for borrowed types that can be parsed from a [`BorrowedReader`].
+pub trait FromXmlBorrowed<'a>: Sized {
+    /// Parse from a reader positioned after the start tag.
+    ///
+    /// - `reader`: The reader that produced `start_tag`
+    /// - `start_tag`: The start tag that was just read
+    /// - `is_empty`: True if this is an empty element (e.g., `<foo/>`)
+    fn from_xml_borrowed(
+        reader: &mut BorrowedReader<'a>,
+        start_tag: &BytesStart<'a>,
+        is_empty: bool,
+    ) -> Result<Self, ParseError>;
+}
+
+/// Parse the root element of an XML document into a borrowed type.
+///
+/// Errors are located as by [`parse_root`](crate::parse_root).
+///
+/// ```ignore
+/// let xml = package.read_part("word/document.xml")?;
+/// let doc: ooxml_wml::borrowed::Document = ooxml_xml::parse_borrowed(&xml)?;
+/// ```
+pub fn parse_borrowed<'a, T: FromXmlBorrowed<'a>>(xml: &'a [u8]) -> Result<T, ParseError> {
+    let mut reader = BorrowedReader::new(xml);
+    let result = loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => break T::from_xml_borrowed(&mut reader, &e, false),
+            Ok(Event::Empty(e)) => break T::from_xml_borrowed(&mut reader, &e, true),
+            Ok(Event::Eof) => {
+                break Err(ParseError::UnexpectedElement(
+                    "no root element found".to_string(),
+                ));
+            }
+            Err(e) => break Err(e),
+            _ => {}
+        }
+    };
+    result.map_err(|e| e.at_reader_position(xml, reader.reader.buffer_position()))
+}
+
+/// Attribute value as a string, borrowed when it is valid UTF-8.
+pub fn borrowed_str(value: Cow<'_, [u8]>) -> Cow<'_, str> {
+    match value {
+        Cow::Borrowed(bytes) => String::from_utf8_lossy(bytes),
+        Cow::Owned(bytes) => Cow::Owned(String::from_utf8_lossy(&bytes).into_owned()),
+    }
+}
+
+/// Append to optional text, borrowing the first piece.
+pub fn push_text<'a>(text: &mut Option<Cow<'a, str>>, s: impl Into<Cow<'a, str>>) {
+    let s = s.into();
+    match text {
+        Some(text) => text.to_mut().push_str(&s),
+        None => *text = Some(s),
+    }
+}
+
+/// Conversion of a borrowed value into one that owns its data.
+pub trait IntoOwned {
+    /// The owned type, usually `Self` with a `'static` lifetime.
+    type Owned: 'static;
+
+    /// Copy any borrowed data.
+    fn into_owned(self) -> Self::Owned;
+}
+
+impl IntoOwned for Cow<'_, str> {
+    type Owned = Cow<'static, str>;
+
+    fn into_owned(self) -> Cow<'static, str> {
+        Cow::Owned(Cow::into_owned(self))
+    }
+}
+
+impl<T: IntoOwned> IntoOwned for Option<T> {
+    type Owned = Option<T::Owned>;
+
+    fn into_owned(self) -> Self::Owned {
+        self.map(IntoOwned::into_owned)
+    }
+}
+
+impl<T: IntoOwned> IntoOwned for Box<T> {
+    type Owned = Box<T::Owned>;
+
+    fn into_owned(self) -> Self::Owned {
+        Box::new((*self).into_owned())
+    }
+}
+
+impl<T: IntoOwned> IntoOwned for Vec<T> {
+    type Owned = Vec<T::Owned>;
+
+    fn into_owned(self) -> Self::Owned {
+        self.into_iter().map(IntoOwned::into_owned).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Element with a `val` attribute and text content.
+    struct Item<'a> {
+        val: Option<Cow<'a, str>>,
+        text: Cow<'a, str>,
+    }
+
+    impl<'a> FromXmlBorrowed<'a> for Item<'a> {
+        fn from_xml_borrowed(
+            reader: &mut BorrowedReader<'a>,
+            start_tag: &BytesStart<'a>,
+            is_empty: bool,
+        ) -> Result<Self, ParseError> {
+            let mut val = None;
+            for attr in reader.attributes(start_tag)?.filter_map(|a| a.ok()) {
+                if attr.key.local_name().as_ref() == b"val" {
+                    val = Some(borrowed_str(attr.value));
+                }
+            }
+            let text = if is_empty {
+                Cow::Borrowed("")
+            } else {
+                reader.read_text()?
+            };
+            Ok(Self { val, text })
+        }
+    }
+
+    #[test]
+    fn test_parse_borrowed() {
+        let xml = br#"<?xml version="1.0"?><item val="v">plain</item>"#;
+        let item: Item = parse_borrowed(xml).unwrap();
+        assert!(matches!(item.val, Some(Cow::Borrowed("v"))));
+        assert!(matches!(item.text, Cow::Borrowed("plain")));
+
+        let xml = br#"<item>a &amp; b</item>"#;
+        let item: Item = parse_borrowed(xml).unwrap();
+        assert_eq!(item.text, "a & b");
+
+        let item: Item = parse_borrowed(b"<item val='x'/>").unwrap();
+        assert_eq!(item.val.as_deref(), Some("x"));
+        assert!(parse_borrowed::<Item>(b"").is_err());
+    }
+
+    #[test]
+    fn test_attributes_of_foreign_tag() {
+        let reader = BorrowedReader::new(b"<a/>");
+        let start = BytesStart::new("a");
+        assert!(reader.attributes(&start).is_err());
+    }
+
+    #[test]
+    fn test_into_owned() {
+        let text = String::from("text");
+        let value: Option<Vec<Box<Cow<str>>>> = Some(vec![Box::new(Cow::Borrowed(&text))]);
+        let owned: Option<Vec<Box<Cow<'static, str>>>> = value.into_owned();
+        drop(text);
+        assert!(matches!(owned.as_deref(), Some([b]) if **b == "text"));
+    }
+}
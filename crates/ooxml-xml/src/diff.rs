@@ -0,0 +1,990 @@
+//! Structural comparison of XML trees.
+//!
+//! [`compare_elements`] walks two [`RawXmlElement`] trees and reports where
+//! they differ, each difference addressed by a path such as
+//! `/w:document/w:body/w:p[2]/@w:rsidR`. [`compare_xml`] and
+//! [`compare_to_xml`] do the same for serialized XML and for any [`ToXml`]
+//! value.
+//!
+//! [`CompareOptions`] decides which differences count. The
+//! [`ooxml`](CompareOptions::ooxml) preset treats equivalent spellings of the
+//! same markup as equal: `<w:b/>` and `<w:b w:val="true"/>`, a different
+//! prefix for the same namespace, or indentation between elements.
+//!
+//! ```
+//! use ooxml_xml::{CompareOptions, DifferenceKind, compare_xml};
+//!
+//! let old = br#"<w:p xmlns:w="urn:w"><w:r><w:rPr><w:b/></w:rPr><w:t>Hi</w:t></w:r></w:p>"#;
+//! let new = br#"<x:p xmlns:x="urn:w">
+//!   <x:r><x:rPr><x:b x:val="true"/></x:rPr><x:t>Hello</x:t></x:r>
+//! </x:p>"#;
+//!
+//! let diffs = compare_xml(old, new, &CompareOptions::ooxml()).unwrap();
+//! assert_eq!(diffs.len(), 1);
+//! assert_eq!(diffs[0].kind, DifferenceKind::TextChanged);
+//! assert_eq!(diffs[0].path, "/w:p/w:r/w:t/text()");
+//! assert_eq!(diffs[0].to_string(), r#"/w:p/w:r/w:t/text(): "Hi" changed to "Hello""#);
+//! ```
+//!
+//! Children are aligned on their longest common subsequence, so an inserted
+//! paragraph is reported once as an extra element rather than as a change to
+//! every paragraph after it.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use crate::{RawXmlElement, RawXmlNode, Result, SerializeError, ToXml};
+
+/// Namespace bound to the reserved `xml` prefix.
+const NS_XML: &str = "http://www.w3.org/XML/1998/namespace";
+
+/// Largest child list product aligned with a full LCS table; longer lists
+/// are only trimmed of their common prefix and suffix.
+const MAX_LCS_CELLS: usize = 1 << 22;
+
+/// Elements whose `val` attribute defaults to true, so that `<w:b/>` equals
+/// `<w:b w:val="1"/>` (`CT_OnOff` in WML, `CT_BooleanProperty` in SML).
+const ON_OFF_ELEMENTS: &[&str] = &[
+    "b",
+    "bCs",
+    "i",
+    "iCs",
+    "caps",
+    "smallCaps",
+    "strike",
+    "dstrike",
+    "outline",
+    "shadow",
+    "emboss",
+    "imprint",
+    "noProof",
+    "snapToGrid",
+    "vanish",
+    "webHidden",
+    "specVanish",
+    "rtl",
+    "cs",
+    "oMath",
+    "condense",
+    "extend",
+    "keepNext",
+    "keepLines",
+    "pageBreakBefore",
+    "widowControl",
+    "suppressLineNumbers",
+    "suppressAutoHyphens",
+    "suppressOverlap",
+    "bidi",
+    "contextualSpacing",
+    "mirrorIndents",
+    "adjustRightInd",
+    "wordWrap",
+    "kinsoku",
+    "overflowPunct",
+    "topLinePunct",
+    "autoSpaceDE",
+    "autoSpaceDN",
+    "titlePg",
+    "noWrap",
+    "tcFitText",
+    "hideMark",
+    "cantSplit",
+    "tblHeader",
+];
+
+/// Options for comparing XML trees.
+///
+/// Start from a preset and adjust fields as needed:
+///
+/// ```
+/// use ooxml_xml::CompareOptions;
+///
+/// let options = CompareOptions {
+///     ignore_attributes: vec!["w:rsidR".to_string(), "w:rsidRPr".to_string()],
+///     ..CompareOptions::ooxml()
+/// };
+/// # let _ = options;
+/// ```
+///
+/// Names in `ignore_attributes`, `ignore_elements` and `default_attributes`
+/// match the qualified name when they contain a prefix (`mc:Ignorable`) and
+/// the local name otherwise (`extLst`).
+#[derive(Debug, Clone, Default)]
+pub struct CompareOptions {
+    /// Ignore whitespace-only text between child elements.
+    pub ignore_whitespace: bool,
+    /// Ignore comments.
+    pub ignore_comments: bool,
+    /// Ignore `xmlns` declarations, comparing names as written.
+    pub ignore_namespace_declarations: bool,
+    /// Compare names by namespace URI rather than prefix (implies
+    /// `ignore_namespace_declarations`). Names with an undeclared prefix are
+    /// compared as written.
+    pub ignore_prefixes: bool,
+    /// Treat `true`/`on` as `1` and `false`/`off` as `0` in attribute values.
+    pub normalize_booleans: bool,
+    /// Attributes to ignore (e.g., `["mc:Ignorable"]`).
+    pub ignore_attributes: Vec<String>,
+    /// Elements to ignore, with their content (e.g., `["extLst"]`).
+    pub ignore_elements: Vec<String>,
+    /// Default attribute values: an attribute with its default value is
+    /// treated as absent.
+    pub default_attributes: Vec<AttributeDefault>,
+}
+
+/// The default value of an attribute on an element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttributeDefault {
+    /// Element name.
+    pub element: String,
+    /// Attribute name.
+    pub attribute: String,
+    /// Value assumed when the attribute is absent.
+    pub value: String,
+}
+
+impl AttributeDefault {
+    /// Create a new attribute default.
+    pub fn new(
+        element: impl Into<String>,
+        attribute: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        Self {
+            element: element.into(),
+            attribute: attribute.into(),
+            value: value.into(),
+        }
+    }
+}
+
+impl CompareOptions {
+    /// Strict comparison: every node, attribute and prefix counts.
+    pub fn strict() -> Self {
+        Self::default()
+    }
+
+    /// Lenient comparison: ignores formatting whitespace, comments and
+    /// namespace declarations.
+    pub fn lenient() -> Self {
+        Self {
+            ignore_whitespace: true,
+            ignore_comments: true,
+            ignore_namespace_declarations: true,
+            ..Self::default()
+        }
+    }
+
+    /// OOXML-aware comparison: [`lenient`](Self::lenient), plus names
+    /// compared by namespace, OOXML boolean spellings, the defaults of
+    /// on/off properties and of the cell type, and `mc:Ignorable`.
+    pub fn ooxml() -> Self {
+        let mut default_attributes: Vec<_> = ON_OFF_ELEMENTS
+            .iter()
+            .map(|element| AttributeDefault::new(*element, "val", "1"))
+            .collect();
+        default_attributes.push(AttributeDefault::new("c", "t", "n"));
+        Self {
+            ignore_prefixes: true,
+            normalize_booleans: true,
+            ignore_attributes: vec!["mc:Ignorable".to_string()],
+            default_attributes,
+            ..Self::lenient()
+        }
+    }
+}
+
+/// A difference between two XML trees.
+///
+/// `old` and `new` hold the values on each side: the attribute value or
+/// text for attribute and text differences, and the element name for
+/// missing and extra elements.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct XmlDifference {
+    /// Path to the node (e.g., `/worksheet/sheetData/row[1]/c[2]/@r`).
+    ///
+    /// Elements are named as in the old tree, except for extra nodes, and
+    /// indexed among same-named siblings when there is more than one.
+    pub path: String,
+    /// Type of difference.
+    pub kind: DifferenceKind,
+    /// Value in the old tree.
+    pub old: Option<String>,
+    /// Value in the new tree.
+    pub new: Option<String>,
+}
+
+/// Types of XML differences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DifferenceKind {
+    /// Element missing in the new tree.
+    MissingElement,
+    /// Extra element in the new tree.
+    ExtraElement,
+    /// Attribute value changed.
+    AttributeChanged,
+    /// Attribute missing in the new tree.
+    MissingAttribute,
+    /// Extra attribute in the new tree.
+    ExtraAttribute,
+    /// Text content changed.
+    TextChanged,
+    /// Text missing in the new tree.
+    MissingText,
+    /// Extra text in the new tree.
+    ExtraText,
+    /// Comment changed.
+    CommentChanged,
+    /// Comment missing in the new tree.
+    MissingComment,
+    /// Extra comment in the new tree.
+    ExtraComment,
+}
+
+impl fmt::Display for XmlDifference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let old = self.old.as_deref().unwrap_or_default();
+        let new = self.new.as_deref().unwrap_or_default();
+        match self.kind {
+            DifferenceKind::MissingElement => write!(f, "{}: element missing", self.path),
+            DifferenceKind::ExtraElement => write!(f, "{}: extra element", self.path),
+            DifferenceKind::AttributeChanged
+            | DifferenceKind::TextChanged
+            | DifferenceKind::CommentChanged => {
+                write!(f, "{}: {:?} changed to {:?}", self.path, old, new)
+            }
+            DifferenceKind::MissingAttribute
+            | DifferenceKind::MissingText
+            | DifferenceKind::MissingComment => {
+                write!(f, "{}: missing (was {:?})", self.path, old)
+            }
+            DifferenceKind::ExtraAttribute
+            | DifferenceKind::ExtraText
+            | DifferenceKind::ExtraComment => write!(f, "{}: extra {:?}", self.path, new),
+        }
+    }
+}
+
+/// Compare two element trees and return their differences in document order.
+pub fn compare_elements(
+    old: &RawXmlElement,
+    new: &RawXmlElement,
+    options: &CompareOptions,
+) -> Vec<XmlDifference> {
+    let old = Normalizer::new(options).element(old);
+    let new = Normalizer::new(options).element(new);
+    let mut differences = Vec::new();
+    if old.key == new.key {
+        compare_element(&old, &new, &format!("/{}", old.name), &mut differences);
+    } else {
+        differences.push(difference(
+            format!("/{}", old.name),
+            DifferenceKind::MissingElement,
+            Some(old.name),
+            None,
+        ));
+        differences.push(difference(
+            format!("/{}", new.name),
+            DifferenceKind::ExtraElement,
+            None,
+            Some(new.name),
+        ));
+    }
+    differences
+}
+
+/// Compare two XML documents by their root elements.
+///
+/// Returns an error if either document is not well-formed.
+pub fn compare_xml(old: &[u8], new: &[u8], options: &CompareOptions) -> Result<Vec<XmlDifference>> {
+    Ok(compare_elements(
+        &RawXmlElement::parse(old)?,
+        &RawXmlElement::parse(new)?,
+        options,
+    ))
+}
+
+/// Compare two values by their XML serialization as `tag` elements.
+pub fn compare_to_xml<A: ToXml, B: ToXml>(
+    old: &A,
+    new: &B,
+    tag: &str,
+    options: &CompareOptions,
+) -> std::result::Result<Vec<XmlDifference>, SerializeError> {
+    Ok(compare_elements(
+        &RawXmlElement::from_value(old, tag)?,
+        &RawXmlElement::from_value(new, tag)?,
+        options,
+    ))
+}
+
+/// An element after normalization.
+struct Element<'a> {
+    /// Qualified name, used in paths.
+    name: String,
+    /// Name compared between trees.
+    key: String,
+    /// Attributes, sorted by key.
+    attributes: Vec<Attribute<'a>>,
+    children: Vec<Node<'a>>,
+    hash: u64,
+}
+
+struct Attribute<'a> {
+    name: &'a str,
+    key: String,
+    /// Value compared between trees.
+    value: Cow<'a, str>,
+    /// Value as written, used in reports.
+    raw: &'a str,
+}
+
+enum Node<'a> {
+    Element(Element<'a>),
+    /// Adjacent text and CDATA, merged.
+    Text(String),
+    Comment(&'a str),
+}
+
+impl Node<'_> {
+    /// Name used to pair nodes and to build paths.
+    fn key(&self) -> &str {
+        match self {
+            Node::Element(e) => &e.key,
+            Node::Text(_) => "text()",
+            Node::Comment(_) => "comment()",
+        }
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            Node::Element(e) => &e.name,
+            Node::Text(_) => "text()",
+            Node::Comment(_) => "comment()",
+        }
+    }
+
+    fn hash(&self) -> u64 {
+        match self {
+            Node::Element(e) => e.hash,
+            Node::Text(text) => hash_of(("text", text)),
+            Node::Comment(text) => hash_of(("comment", text)),
+        }
+    }
+}
+
+fn hash_of(value: impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Builds normalized trees, tracking namespace declarations in scope.
+struct Normalizer<'o, 'a> {
+    options: &'o CompareOptions,
+    /// In-scope `(prefix, namespace)` bindings, innermost last.
+    scope: Vec<(&'a str, &'a str)>,
+}
+
+impl<'o, 'a> Normalizer<'o, 'a> {
+    fn new(options: &'o CompareOptions) -> Self {
+        Self {
+            options,
+            scope: Vec::new(),
+        }
+    }
+
+    fn element(&mut self, element: &'a RawXmlElement) -> Element<'a> {
+        let options = self.options;
+        let mark = self.scope.len();
+        for (name, value) in &element.attributes {
+            if let Some(prefix) = namespace_prefix(name) {
+                self.scope.push((prefix, value));
+            }
+        }
+
+        let mut attributes = Vec::new();
+        for (name, raw) in &element.attributes {
+            if namespace_prefix(name).is_some()
+                && (options.ignore_namespace_declarations || options.ignore_prefixes)
+            {
+                continue;
+            }
+            if options
+                .ignore_attributes
+                .iter()
+                .any(|n| name_matches(n, name))
+            {
+                continue;
+            }
+            let value = self.normalize_value(raw);
+            let is_default = options.default_attributes.iter().any(|d| {
+                name_matches(&d.element, &element.name)
+                    && name_matches(&d.attribute, name)
+                    && self.normalize_value(&d.value) == value
+            });
+            if !is_default {
+                attributes.push(Attribute {
+                    name,
+                    key: self.key(name, true),
+                    value,
+                    raw,
+                });
+            }
+        }
+        attributes.sort_by(|a, b| a.key.cmp(&b.key));
+
+        let mut children = Vec::new();
+        let mut text: Option<String> = None;
+        for child in &element.children {
+            match child {
+                RawXmlNode::Text(t) | RawXmlNode::CData(t) => {
+                    text.get_or_insert_with(String::new).push_str(t);
+                }
+                RawXmlNode::Comment(_) if options.ignore_comments => {}
+                RawXmlNode::Comment(c) => {
+                    children.extend(text.take().map(Node::Text));
+                    children.push(Node::Comment(c));
+                }
+                RawXmlNode::Element(e) => {
+                    children.extend(text.take().map(Node::Text));
+                    if !options
+                        .ignore_elements
+                        .iter()
+                        .any(|n| name_matches(n, &e.name))
+                    {
+                        children.push(Node::Element(self.element(e)));
+                    }
+                }
+            }
+        }
+        children.extend(text.map(Node::Text));
+        if options.ignore_whitespace && children.iter().any(|c| matches!(c, Node::Element(_))) {
+            children.retain(|c| !matches!(c, Node::Text(t) if t.trim().is_empty()));
+        }
+
+        let key = self.key(&element.name, false);
+        self.scope.truncate(mark);
+
+        let hash = hash_of((
+            "element",
+            &key,
+            attributes
+                .iter()
+                .map(|a| (&a.key, &a.value))
+                .collect::<Vec<_>>(),
+            children.iter().map(Node::hash).collect::<Vec<_>>(),
+        ));
+        Element {
+            name: element.name.clone(),
+            key,
+            attributes,
+            children,
+            hash,
+        }
+    }
+
+    /// Name compared between trees: the qualified name, or with
+    /// `ignore_prefixes` the namespace and local name.
+    fn key(&self, name: &str, is_attribute: bool) -> String {
+        if !self.options.ignore_prefixes {
+            return name.to_string();
+        }
+        let (prefix, local) = match name.split_once(':') {
+            Some((prefix, local)) => (prefix, local),
+            // Unprefixed attributes are in no namespace
+            None if is_attribute => return name.to_string(),
+            None => ("", name),
+        };
+        let namespace = if prefix == "xml" {
+            Some(NS_XML)
+        } else {
+            self.scope
+                .iter()
+                .rev()
+                .find(|(p, _)| *p == prefix)
+                .map(|(_, ns)| *ns)
+        };
+        match namespace {
+            Some("") | None if prefix.is_empty() => local.to_string(),
+            Some(ns) => format!("{{{ns}}}{local}"),
+            None => name.to_string(),
+        }
+    }
+
+    fn normalize_value<'v>(&self, value: &'v str) -> Cow<'v, str> {
+        if self.options.normalize_booleans {
+            match value {
+                "true" | "on" => return Cow::Borrowed("1"),
+                "false" | "off" => return Cow::Borrowed("0"),
+                _ => {}
+            }
+        }
+        Cow::Borrowed(value)
+    }
+}
+
+/// Prefix declared by an `xmlns` or `xmlns:prefix` attribute.
+fn namespace_prefix(name: &str) -> Option<&str> {
+    if name == "xmlns" {
+        Some("")
+    } else {
+        name.strip_prefix("xmlns:")
+    }
+}
+
+/// Whether `pattern` names `name`: by qualified name if it has a prefix,
+/// otherwise by local name.
+fn name_matches(pattern: &str, name: &str) -> bool {
+    if pattern.contains(':') {
+        pattern == name
+    } else {
+        pattern == name.rsplit(':').next().unwrap_or(name)
+    }
+}
+
+fn difference(
+    path: String,
+    kind: DifferenceKind,
+    old: Option<String>,
+    new: Option<String>,
+) -> XmlDifference {
+    XmlDifference {
+        path,
+        kind,
+        old,
+        new,
+    }
+}
+
+fn compare_element(old: &Element, new: &Element, path: &str, differences: &mut Vec<XmlDifference>) {
+    if old.hash == new.hash {
+        return;
+    }
+    compare_attributes(&old.attributes, &new.attributes, path, differences);
+
+    let old_paths = child_paths(&old.children, path);
+    let new_paths = child_paths(&new.children, path);
+    for (o, n) in align(&old.children, &new.children) {
+        match (o, n) {
+            (Some(o), Some(n)) => compare_nodes(
+                &old.children[o],
+                &new.children[n],
+                &old_paths[o],
+                differences,
+            ),
+            (Some(o), None) => differences.push(lone_difference(
+                &old.children[o],
+                old_paths[o].clone(),
+                true,
+            )),
+            (None, Some(n)) => differences.push(lone_difference(
+                &new.children[n],
+                new_paths[n].clone(),
+                false,
+            )),
+            (None, None) => {}
+        }
+    }
+}
+
+fn compare_nodes(old: &Node, new: &Node, path: &str, differences: &mut Vec<XmlDifference>) {
+    let kind = match (old, new) {
+        (Node::Element(o), Node::Element(n)) => return compare_element(o, n, path, differences),
+        (Node::Text(o), Node::Text(n)) if o != n => DifferenceKind::TextChanged,
+        (Node::Comment(o), Node::Comment(n)) if o != n => DifferenceKind::CommentChanged,
+        _ => return,
+    };
+    differences.push(XmlDifference {
+        path: path.to_string(),
+        kind,
+        old: node_value(old),
+        new: node_value(new),
+    });
+}
+
+fn compare_attributes(
+    old: &[Attribute],
+    new: &[Attribute],
+    path: &str,
+    differences: &mut Vec<XmlDifference>,
+) {
+    let new_by_key: HashMap<_, _> = new.iter().map(|a| (a.key.as_str(), a)).collect();
+    for o in old {
+        match new_by_key.get(o.key.as_str()) {
+            Some(n) if n.value == o.value => {}
+            Some(n) => differences.push(XmlDifference {
+                path: format!("{}/@{}", path, o.name),
+                kind: DifferenceKind::AttributeChanged,
+                old: Some(o.raw.to_string()),
+                new: Some(n.raw.to_string()),
+            }),
+            None => differences.push(XmlDifference {
+                path: format!("{}/@{}", path, o.name),
+                kind: DifferenceKind::MissingAttribute,
+                old: Some(o.raw.to_string()),
+                new: None,
+            }),
+        }
+    }
+    for n in new {
+        if !old.iter().any(|o| o.key == n.key) {
+            differences.push(XmlDifference {
+                path: format!("{}/@{}", path, n.name),
+                kind: DifferenceKind::ExtraAttribute,
+                old: None,
+                new: Some(n.raw.to_string()),
+            });
+        }
+    }
+}
+
+/// A node present in only one tree.
+fn lone_difference(node: &Node, path: String, missing: bool) -> XmlDifference {
+    let kind = match (node, missing) {
+        (Node::Element(_), true) => DifferenceKind::MissingElement,
+        (Node::Element(_), false) => DifferenceKind::ExtraElement,
+        (Node::Text(_), true) => DifferenceKind::MissingText,
+        (Node::Text(_), false) => DifferenceKind::ExtraText,
+        (Node::Comment(_), true) => DifferenceKind::MissingComment,
+        (Node::Comment(_), false) => DifferenceKind::ExtraComment,
+    };
+    if missing {
+        difference(path, kind, node_value(node), None)
+    } else {
+        difference(path, kind, None, node_value(node))
+    }
+}
+
+fn node_value(node: &Node) -> Option<String> {
+    match node {
+        Node::Element(e) => Some(e.name.clone()),
+        Node::Text(text) => Some(text.clone()),
+        Node::Comment(text) => Some(text.to_string()),
+    }
+}
+
+/// Paths of `children`, indexing names shared by more than one sibling.
+fn child_paths(children: &[Node], parent: &str) -> Vec<String> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for child in children {
+        *counts.entry(child.key()).or_default() += 1;
+    }
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+    children
+        .iter()
+        .map(|child| {
+            let index = seen.entry(child.key()).or_default();
+            *index += 1;
+            if counts[child.key()] > 1 {
+                format!("{}/{}[{}]", parent, child.name(), index)
+            } else {
+                format!("{}/{}", parent, child.name())
+            }
+        })
+        .collect()
+}
+
+/// Align two child lists, returning `(old, new)` index pairs in document
+/// order. Identical subtrees are matched on their longest common
+/// subsequence; between those, remaining nodes with the same name are
+/// paired in order and the rest are left unpaired.
+fn align(old: &[Node], new: &[Node]) -> Vec<(Option<usize>, Option<usize>)> {
+    let old_hashes: Vec<u64> = old.iter().map(Node::hash).collect();
+    let new_hashes: Vec<u64> = new.iter().map(Node::hash).collect();
+
+    let prefix = old_hashes
+        .iter()
+        .zip(&new_hashes)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = old_hashes[prefix..]
+        .iter()
+        .rev()
+        .zip(new_hashes[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_end = old.len() - suffix;
+    let new_end = new.len() - suffix;
+
+    let mut anchors: Vec<(usize, usize)> = (0..prefix).map(|i| (i, i)).collect();
+    anchors.extend(
+        lcs(&old_hashes[prefix..old_end], &new_hashes[prefix..new_end])
+            .into_iter()
+            .map(|(o, n)| (o + prefix, n + prefix)),
+    );
+    anchors.extend((0..suffix).map(|i| (old_end + i, new_end + i)));
+
+    let mut pairs = Vec::new();
+    let (mut o, mut n) = (0, 0);
+    for (anchor_o, anchor_n) in anchors.into_iter().chain([(old.len(), new.len())]) {
+        pair_by_name(&old[o..anchor_o], &new[n..anchor_n], o, n, &mut pairs);
+        if anchor_o < old.len() {
+            pairs.push((Some(anchor_o), Some(anchor_n)));
+        }
+        (o, n) = (anchor_o + 1, anchor_n + 1);
+    }
+    pairs
+}
+
+/// Longest common subsequence of two hash lists, as index pairs.
+fn lcs(old: &[u64], new: &[u64]) -> Vec<(usize, usize)> {
+    if old.is_empty() || new.is_empty() || old.len() * new.len() > MAX_LCS_CELLS {
+        return Vec::new();
+    }
+    // lengths[i][j]: LCS length of old[i..] and new[j..]
+    let width = new.len() + 1;
+    let mut lengths = vec![0u32; (old.len() + 1) * width];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i * width + j] = if old[i] == new[j] {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+    let mut pairs = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            pairs.push((i, j));
+            i += 1;
+            j += 1;
+        } else if lengths[(i + 1) * width + j] >= lengths[i * width + j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    pairs
+}
+
+/// Pair nodes of two unmatched runs by name, in order.
+fn pair_by_name(
+    old: &[Node],
+    new: &[Node],
+    old_start: usize,
+    new_start: usize,
+    pairs: &mut Vec<(Option<usize>, Option<usize>)>,
+) {
+    let mut next = 0;
+    for (i, node) in old.iter().enumerate() {
+        match new[next..].iter().position(|n| n.key() == node.key()) {
+            Some(offset) => {
+                for j in next..next + offset {
+                    pairs.push((None, Some(new_start + j)));
+                }
+                pairs.push((Some(old_start + i), Some(new_start + next + offset)));
+                next += offset + 1;
+            }
+            None => pairs.push((Some(old_start + i), None)),
+        }
+    }
+    for j in next..new.len() {
+        pairs.push((None, Some(new_start + j)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quick_xml::Writer;
+
+    fn diff(old: &str, new: &str, options: &CompareOptions) -> Vec<XmlDifference> {
+        compare_xml(old.as_bytes(), new.as_bytes(), options).expect("should compare")
+    }
+
+    #[test]
+    fn test_compare_identical_xml() {
+        let xml = r#"<root><child attr="value">text</child><!-- note --></root>"#;
+        assert!(diff(xml, xml, &CompareOptions::strict()).is_empty());
+    }
+
+    #[test]
+    fn test_compare_missing_element() {
+        let diffs = diff(
+            "<root><a/><b/></root>",
+            "<root><a/></root>",
+            &CompareOptions::strict(),
+        );
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].kind, DifferenceKind::MissingElement);
+        assert_eq!(diffs[0].path, "/root/b");
+    }
+
+    #[test]
+    fn test_compare_extra_element() {
+        let diffs = diff(
+            "<root><a/></root>",
+            "<root><a/><b/></root>",
+            &CompareOptions::strict(),
+        );
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].kind, DifferenceKind::ExtraElement);
+        assert_eq!(diffs[0].new.as_deref(), Some("b"));
+    }
+
+    #[test]
+    fn test_compare_attribute_changed() {
+        let diffs = diff(
+            r#"<root attr="old"/>"#,
+            r#"<root attr="new"/>"#,
+            &CompareOptions::strict(),
+        );
+        assert_eq!(
+            diffs,
+            [XmlDifference {
+                path: "/root/@attr".to_string(),
+                kind: DifferenceKind::AttributeChanged,
+                old: Some("old".to_string()),
+                new: Some("new".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_compare_attribute_order_ignored() {
+        let diffs = diff(
+            r#"<root a="1" b="2"/>"#,
+            r#"<root b="2" a="1"/>"#,
+            &CompareOptions::strict(),
+        );
+        assert!(diffs.is_empty());
+    }
+
+    #[test]
+    fn test_compare_ignore_whitespace() {
+        let original = "<root>\n    <child/>\n</root>";
+        let roundtrip = "<root><child/></root>";
+        assert!(!diff(original, roundtrip, &CompareOptions::strict()).is_empty());
+        assert!(diff(original, roundtrip, &CompareOptions::lenient()).is_empty());
+
+        // Whitespace that is the whole content of an element is kept
+        let diffs = diff("<t> </t>", "<t/>", &CompareOptions::lenient());
+        assert_eq!(diffs[0].kind, DifferenceKind::MissingText);
+    }
+
+    #[test]
+    fn test_compare_ignore_xmlns() {
+        let original = r#"<root xmlns="http://example.com"><child/></root>"#;
+        let roundtrip = r#"<root><child/></root>"#;
+        assert!(diff(original, roundtrip, &CompareOptions::lenient()).is_empty());
+    }
+
+    #[test]
+    fn test_compare_prefixes_by_namespace() {
+        let original = r#"<a:root xmlns:a="urn:x"><a:child a:v="1"/></a:root>"#;
+        let renamed = r#"<b:root xmlns:b="urn:x"><b:child b:v="1"/></b:root>"#;
+        let moved = r#"<b:root xmlns:b="urn:y"><b:child b:v="1"/></b:root>"#;
+        let options = CompareOptions {
+            ignore_prefixes: true,
+            ..CompareOptions::strict()
+        };
+        assert!(diff(original, renamed, &options).is_empty());
+        assert_eq!(diff(original, moved, &options).len(), 2);
+        assert!(!diff(original, renamed, &CompareOptions::lenient()).is_empty());
+    }
+
+    #[test]
+    fn test_compare_ooxml_booleans_and_defaults() {
+        let w = r#"xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main""#;
+        let original = format!(r#"<w:rPr {w}><w:b/><w:i w:val="0"/></w:rPr>"#);
+        let equivalent = format!(r#"<w:rPr {w}><w:b w:val="true"/><w:i w:val="off"/></w:rPr>"#);
+        let changed = format!(r#"<w:rPr {w}><w:b w:val="false"/><w:i w:val="0"/></w:rPr>"#);
+        assert!(diff(&original, &equivalent, &CompareOptions::ooxml()).is_empty());
+        assert!(!diff(&original, &equivalent, &CompareOptions::lenient()).is_empty());
+
+        let diffs = diff(&original, &changed, &CompareOptions::ooxml());
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].kind, DifferenceKind::ExtraAttribute);
+        assert_eq!(diffs[0].path, "/w:rPr/w:b/@w:val");
+        assert_eq!(diffs[0].new.as_deref(), Some("false"));
+
+        let cell = diff(
+            r#"<c r="A1" t="n"/>"#,
+            r#"<c r="A1"/>"#,
+            &CompareOptions::ooxml(),
+        );
+        assert!(cell.is_empty());
+    }
+
+    #[test]
+    fn test_compare_inserted_element_aligns() {
+        let original = "<body><p>a</p><p>b</p><p>c</p></body>";
+        let inserted = "<body><p>a</p><p>new</p><p>b</p><p>c</p></body>";
+        let diffs = diff(original, inserted, &CompareOptions::strict());
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].kind, DifferenceKind::ExtraElement);
+        assert_eq!(diffs[0].path, "/body/p[2]");
+    }
+
+    #[test]
+    fn test_compare_changed_element_paired_by_name() {
+        let original = "<body><p>a</p><tbl/><p>b</p></body>";
+        let changed = "<body><p>a</p><p>c</p></body>";
+        let diffs = diff(original, changed, &CompareOptions::strict());
+        assert_eq!(diffs.len(), 2);
+        assert_eq!(diffs[0].kind, DifferenceKind::MissingElement);
+        assert_eq!(diffs[0].path, "/body/tbl");
+        assert_eq!(diffs[1].kind, DifferenceKind::TextChanged);
+        assert_eq!(diffs[1].path, "/body/p[2]/text()");
+        assert_eq!(
+            diffs[1].to_string(),
+            r#"/body/p[2]/text(): "b" changed to "c""#
+        );
+    }
+
+    #[test]
+    fn test_compare_ignored_names() {
+        let original = r#"<root mc:Ignorable="x" xmlns:mc="urn:mc"><extLst/><a/></root>"#;
+        let roundtrip = r#"<root><a/></root>"#;
+        let options = CompareOptions {
+            ignore_elements: vec!["extLst".to_string()],
+            ..CompareOptions::ooxml()
+        };
+        assert!(diff(original, roundtrip, &options).is_empty());
+    }
+
+    #[test]
+    fn test_compare_root_mismatch() {
+        let diffs = diff("<a/>", "<b/>", &CompareOptions::strict());
+        let kinds: Vec<_> = diffs.iter().map(|d| d.kind).collect();
+        assert_eq!(
+            kinds,
+            [DifferenceKind::MissingElement, DifferenceKind::ExtraElement]
+        );
+    }
+
+    #[test]
+    fn test_compare_to_xml() {
+        let old = RawXmlElement {
+            attributes: vec![("v".to_string(), "1".to_string())],
+            ..RawXmlElement::new("item")
+        };
+        let new = RawXmlElement::new("item");
+        let diffs = compare_to_xml(&Wrap(old), &Wrap(new), "root", &CompareOptions::strict())
+            .expect("should compare");
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].path, "/root/item/@v");
+    }
+
+    struct Wrap(RawXmlElement);
+
+    impl ToXml for Wrap {
+        fn write_children<W: std::io::Write>(
+            &self,
+            writer: &mut Writer<W>,
+        ) -> std::result::Result<(), SerializeError> {
+            Ok(self.0.write_to(writer)?)
+        }
+    }
+
+    #[test]
+    fn test_compare_malformed_xml() {
+        assert!(compare_xml(b"<a><b></a>", b"<a/>", &CompareOptions::strict()).is_err());
+        assert!(compare_xml(b"", b"<a/>", &CompareOptions::strict()).is_err());
+    }
+}
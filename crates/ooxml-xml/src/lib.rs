@@ -4,14 +4,40 @@
 //! for features like roundtrip fidelity (preserving unknown elements/attributes).
 
 use quick_xml::events::{BytesEnd, BytesStart};
-use quick_xml::{Reader, Writer};
+use quick_xml::name::ResolveResult;
+use quick_xml::{NsReader, Writer};
 use std::io::{BufRead, Write};
 
+mod borrowed;
+mod diff;
+mod limits;
+mod location;
+mod mce;
+mod query;
 mod raw_xml;
+#[cfg(feature = "serde")]
 pub mod serde_helpers;
-
+mod serialize;
+
+pub use borrowed::{
+    BorrowedReader, FromXmlBorrowed, IntoOwned, borrowed_str, parse_borrowed, push_text,
+};
+pub use diff::{
+    AttributeDefault, CompareOptions, DifferenceKind, XmlDifference, compare_elements,
+    compare_to_xml, compare_xml,
+};
+pub use limits::{ParseLimits, parse_with_limits};
+pub use location::{SourceLocation, SourcePosition, parse_root};
+pub use mce::{Alternates, MceProcessor, NS_MC, OffsetMap};
+pub use query::{
+    LazyQuery, Query, QueryMatch, QueryTree, TreeMatch, TreeVisitor, visit_attributes,
+};
 pub use raw_xml::{PositionedAttr, PositionedNode, RawXmlElement, RawXmlNode, RawXmlStreamReader};
+#[cfg(feature = "serde")]
 pub use serde_helpers::{ooxml_bool, ooxml_bool_required};
+pub use serialize::{
+    NamespaceDeclarations, SelfClosing, SerializeOptions, format_xml, root_attributes, to_xml_part,
+};
 
 /// Error type for XML operations.
 #[derive(Debug, thiserror::Error)]
@@ -40,6 +66,14 @@ pub enum ParseError {
     MissingAttribute(String),
     #[error("invalid value: {0}")]
     InvalidValue(String),
+    #[error("limit exceeded: {0}")]
+    LimitExceeded(String),
+    /// An error with the location of the markup it occurred at.
+    #[error("{location}: {source}")]
+    Located {
+        location: Box<SourceLocation>,
+        source: Box<ParseError>,
+    },
 }
 
 /// Error type for XML serialization (used by generated serializers).
@@ -56,19 +90,66 @@ pub enum SerializeError {
 /// Trait for types that can be parsed from XML elements.
 ///
 /// Implemented by generated OOXML types to enable event-based parsing.
+/// Parsers read through an [`NsReader`] so that child elements can be matched
+/// by namespace as well as local name (see [`local_name_in`]).
 pub trait FromXml: Sized {
     /// Parse from an XML reader positioned at the start tag.
     ///
-    /// - `reader`: The XML reader
+    /// - `reader`: The namespace-aware XML reader
     /// - `start_tag`: The start tag that was just read
     /// - `is_empty`: True if this is an empty element (e.g., `<foo/>`)
     fn from_xml<R: BufRead>(
-        reader: &mut Reader<R>,
+        reader: &mut NsReader<R>,
         start_tag: &BytesStart,
         is_empty: bool,
     ) -> std::result::Result<Self, ParseError>;
 }
 
+/// Local name of an element read by `reader`, if the element is in one of
+/// `namespaces`.
+///
+/// Generated parsers match child elements on the returned name. An element
+/// bound to any other namespace yields an empty name, which matches no known
+/// element, so it is kept as an unknown child instead of being misread as a
+/// same-named OOXML element. Elements whose prefix is undeclared, as in
+/// fragments without `xmlns` attributes, are matched by local name alone, as
+/// is every element when `namespaces` is empty.
+///
+/// ```
+/// use ooxml_xml::local_name_in;
+/// use quick_xml::NsReader;
+/// use quick_xml::events::Event;
+///
+/// const W: &str = "http://schemas.openxmlformats.org/wordprocessingml/2006/main";
+/// let xml = format!(r#"<w:r xmlns:w="{W}" xmlns:x="urn:custom"><w:t/><x:t/></w:r>"#);
+/// let mut reader = NsReader::from_str(&xml);
+/// let mut names = Vec::new();
+/// loop {
+///     match reader.read_event().unwrap() {
+///         Event::Empty(e) => names.push(local_name_in(&reader, &e, &[W]).to_vec()),
+///         Event::Eof => break,
+///         _ => {}
+///     }
+/// }
+/// assert_eq!(names, [b"t".to_vec(), Vec::new()]);
+/// ```
+pub fn local_name_in<'a, R>(
+    reader: &NsReader<R>,
+    element: &'a BytesStart,
+    namespaces: &[&str],
+) -> &'a [u8] {
+    let (ns, local) = reader.resolver().resolve_element(element.name());
+    match ns {
+        ResolveResult::Bound(ns)
+            if !namespaces.is_empty()
+                && !namespaces.iter().any(|n| n.as_bytes() == ns.as_ref()) =>
+        {
+            b""
+        }
+        _ => local.into_inner(),
+    }
+}
+
 /// Trait for types that can be serialized to XML elements.
 ///
 /// Implemented by generated OOXML types to enable roundtrip serialization.
@@ -109,6 +190,24 @@ pub trait ToXml {
         }
         Ok(())
     }
+
+    /// Write a complete element with the given [`SerializeOptions`].
+    ///
+    /// With options other than the compact defaults, the element is
+    /// serialized with [`write_element`](Self::write_element) and then
+    /// rewritten, so implementations only need to provide the compact form.
+    fn write_element_with<W: Write>(
+        &self,
+        tag: &str,
+        writer: &mut Writer<W>,
+        options: &SerializeOptions,
+    ) -> std::result::Result<(), SerializeError> {
+        if options.is_verbatim() {
+            return self.write_element(tag, writer);
+        }
+        let element = RawXmlElement::from_value(self, tag)?;
+        Ok(options.write_raw(element, writer)?)
+    }
 }
 
 // Blanket implementation for Box<T> where T: ToXml
@@ -0,0 +1,223 @@
+//! Resource limits for parsing untrusted XML.
+//!
+//! Generated parsers build the whole element tree in memory, so a crafted
+//! part with millions of elements or pathological nesting can exhaust memory
+//! or the stack. [`ParseLimits`] bounds the structure of a part before it is
+//! handed to a parser. Entity references are never expanded by the parsers,
+//! so entity expansion attacks need no separate limit.
+
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
+
+use crate::{FromXml, ParseError, parse_root};
+
+/// Limits on the structure of an XML part.
+///
+/// The default limits are generous for real documents while bounding the
+/// work done on hostile input; use [`ParseLimits::unlimited`] for trusted
+/// input.
+///
+/// ```
+/// use ooxml_xml::ParseLimits;
+///
+/// let limits = ParseLimits { max_depth: 2, ..ParseLimits::default() };
+/// assert!(limits.check(b"<a><b/></a>").is_ok());
+/// assert!(limits.check(b"<a><b><c/></b></a>").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseLimits {
+    /// Maximum element nesting depth.
+    pub max_depth: usize,
+    /// Maximum number of elements in a part.
+    pub max_elements: usize,
+    /// Maximum number of attributes on a single element.
+    pub max_attributes: usize,
+}
+
+impl Default for ParseLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: 256,
+            max_elements: 10_000_000,
+            max_attributes: 256,
+        }
+    }
+}
+
+impl ParseLimits {
+    /// Limits that accept any input.
+    pub fn unlimited() -> Self {
+        Self {
+            max_depth: usize::MAX,
+            max_elements: usize::MAX,
+            max_attributes: usize::MAX,
+        }
+    }
+
+    /// Check that an XML document stays within the limits.
+    ///
+    /// Returns [`ParseError::LimitExceeded`] for the first limit exceeded,
+    /// or [`ParseError::Xml`] if the document is not well-formed.
+    pub fn check(&self, xml: &[u8]) -> Result<(), ParseError> {
+        let mut reader = Reader::from_reader(xml);
+        let mut buf = Vec::new();
+        let mut depth = 0;
+        let mut elements = 0;
+        loop {
+            let empty = match reader.read_event_into(&mut buf)? {
+                Event::Start(e) => {
+                    self.check_element(&e, depth, elements)?;
+                    false
+                }
+                Event::Empty(e) => {
+                    self.check_element(&e, depth, elements)?;
+                    true
+                }
+                Event::End(_) => {
+                    depth = depth.saturating_sub(1);
+                    buf.clear();
+                    continue;
+                }
+                Event::Eof => return Ok(()),
+                _ => {
+                    buf.clear();
+                    continue;
+                }
+            };
+            elements += 1;
+            if !empty {
+                depth += 1;
+            }
+            buf.clear();
+        }
+    }
+
+    fn check_element(
+        &self,
+        e: &BytesStart,
+        depth: usize,
+        elements: usize,
+    ) -> Result<(), ParseError> {
+        if depth >= self.max_depth {
+            return Err(ParseError::LimitExceeded(format!(
+                "element nesting deeper than {}",
+                self.max_depth
+            )));
+        }
+        if elements >= self.max_elements {
+            return Err(ParseError::LimitExceeded(format!(
+                "more than {} elements",
+                self.max_elements
+            )));
+        }
+        if e.attributes().count() > self.max_attributes {
+            return Err(ParseError::LimitExceeded(format!(
+                "more than {} attributes on <{}>",
+                self.max_attributes,
+                String::from_utf8_lossy(e.name().as_ref())
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Parse the root element of an XML document with a generated parser,
+/// after checking the document against the limits.
+pub fn parse_with_limits<T: FromXml>(xml: &[u8], limits: &ParseLimits) -> Result<T, ParseError> {
+    limits.check(xml)?;
+    parse_root(xml)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quick_xml::NsReader;
+    use std::io::BufRead;
+
+    /// Root element that counts its children.
+    struct Root {
+        children: usize,
+    }
+
+    impl FromXml for Root {
+        fn from_xml<R: BufRead>(
+            reader: &mut NsReader<R>,
+            _start_tag: &BytesStart,
+            is_empty: bool,
+        ) -> Result<Self, ParseError> {
+            let mut children = 0;
+            let mut depth = 0;
+            let mut buf = Vec::new();
+            if is_empty {
+                return Ok(Self { children });
+            }
+            loop {
+                match reader.read_event_into(&mut buf)? {
+                    Event::Start(_) => {
+                        children += usize::from(depth == 0);
+                        depth += 1;
+                    }
+                    Event::Empty(_) => children += usize::from(depth == 0),
+                    Event::End(_) if depth == 0 => break,
+                    Event::End(_) => depth -= 1,
+                    Event::Eof => break,
+                    _ => {}
+                }
+                buf.clear();
+            }
+            Ok(Self { children })
+        }
+    }
+
+    #[test]
+    fn test_limits() {
+        let xml = br#"<a x="1" y="2"><b><c/><c/></b><!-- comment --></a>"#;
+        assert!(ParseLimits::default().check(xml).is_ok());
+
+        let depth = ParseLimits {
+            max_depth: 2,
+            ..ParseLimits::default()
+        };
+        assert!(matches!(
+            depth.check(xml),
+            Err(ParseError::LimitExceeded(_))
+        ));
+        let depth = ParseLimits {
+            max_depth: 3,
+            ..ParseLimits::default()
+        };
+        assert!(depth.check(xml).is_ok());
+
+        let elements = ParseLimits {
+            max_elements: 3,
+            ..ParseLimits::default()
+        };
+        assert!(matches!(
+            elements.check(xml),
+            Err(ParseError::LimitExceeded(_))
+        ));
+
+        let attributes = ParseLimits {
+            max_attributes: 1,
+            ..ParseLimits::default()
+        };
+        let err = attributes.check(xml).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "limit exceeded: more than 1 attributes on <a>"
+        );
+    }
+
+    #[test]
+    fn test_parse_with_limits() {
+        let xml = b"<?xml version=\"1.0\"?><a><b/></a>";
+        let root: Root = parse_with_limits(xml, &ParseLimits::default()).unwrap();
+        assert_eq!(root.children, 1);
+        let limits = ParseLimits {
+            max_depth: 1,
+            ..ParseLimits::default()
+        };
+        assert!(parse_with_limits::<Root>(xml, &limits).is_err());
+        assert!(parse_with_limits::<Root>(b"", &ParseLimits::unlimited()).is_err());
+    }
+}
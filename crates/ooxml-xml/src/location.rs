@@ -0,0 +1,425 @@
+//! Source locations for parse errors.
+//!
+//! Generated parsers report what went wrong but not where. Entry points such
+//! as [`parse_root`] wrap a failing parser's error in
+//! [`ParseError::Located`], recording the byte offset, line and column of the
+//! offending markup and the path of elements enclosing it. Format crates add
+//! the package part name when they read a part, so an error logged on its own
+//! is enough to find the markup in the original file.
+//!
+//! Positions refer to the markup handed to the parser. Where markup
+//! compatibility processing rewrote a part before parsing,
+//! [`ParseError::in_original`] moves them back to the part as stored, using
+//! the [`OffsetMap`] from [`MceProcessor::process_with_offsets`].
+//!
+//! [`MceProcessor::process_with_offsets`]: crate::MceProcessor::process_with_offsets
+
+use quick_xml::events::Event;
+use quick_xml::{NsReader, Reader};
+use std::fmt;
+use std::io::Cursor;
+
+use crate::{FromXml, OffsetMap, ParseError};
+
+/// A position in an XML document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourcePosition {
+    /// Byte offset from the start of the document.
+    pub offset: u64,
+    /// Line number, starting at 1.
+    pub line: usize,
+    /// Column in characters, starting at 1.
+    pub column: usize,
+}
+
+impl SourcePosition {
+    /// Position of a byte offset in a document.
+    ///
+    /// Offsets past the end of the document are clamped to its end.
+    ///
+    /// ```
+    /// use ooxml_xml::SourcePosition;
+    ///
+    /// let position = SourcePosition::new("<a>\n  <é/><b/>\n</a>".as_bytes(), 11);
+    /// assert_eq!((position.line, position.column), (2, 7));
+    /// ```
+    pub fn new(xml: &[u8], offset: u64) -> Self {
+        let end = usize::try_from(offset).map_or(xml.len(), |offset| offset.min(xml.len()));
+        let before = &xml[..end];
+        let line_start = before
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .map_or(0, |i| i + 1);
+        Self {
+            offset,
+            line: before.iter().filter(|&&b| b == b'\n').count() + 1,
+            // Count UTF-8 lead bytes so that columns are in characters
+            column: before[line_start..]
+                .iter()
+                .filter(|&&b| b & 0xC0 != 0x80)
+                .count()
+                + 1,
+        }
+    }
+}
+
+/// Where in a package a parse error occurred.
+///
+/// Displays as `part:line:column in <path>`, omitting whatever is unknown,
+/// e.g. `word/document.xml:3:15 in <w:document/w:body/w:p>`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// Name of the package part, e.g. `word/document.xml`.
+    pub part: Option<String>,
+    /// Position of the offending markup within the part.
+    pub position: Option<SourcePosition>,
+    /// Qualified names of the elements enclosing the offending markup, from
+    /// the root element down.
+    pub path: Vec<String>,
+}
+
+impl SourceLocation {
+    /// Locate the markup a parser had just read when it failed.
+    ///
+    /// `reader_position` is the reader's `buffer_position()` at the time of
+    /// the error. The document is scanned again up to that point to recover
+    /// the start of the last event read and the elements enclosing it;
+    /// malformed markup is located where the scan fails.
+    pub fn at_reader_position(xml: &[u8], reader_position: u64) -> Self {
+        let mut reader = Reader::from_reader(xml);
+        let mut buf = Vec::new();
+        let mut path: Vec<String> = Vec::new();
+        let name = |name: &[u8]| String::from_utf8_lossy(name).into_owned();
+        let offset = loop {
+            let start = reader.buffer_position();
+            let event = match reader.read_event_into(&mut buf) {
+                Ok(event) => event,
+                Err(_) => break reader.error_position(),
+            };
+            let reached = reader.buffer_position() >= reader_position;
+            match event {
+                Event::Start(e) => path.push(name(e.name().as_ref())),
+                Event::Empty(e) if reached => path.push(name(e.name().as_ref())),
+                Event::End(_) if !reached => {
+                    path.pop();
+                }
+                Event::Eof => break start,
+                _ => {}
+            }
+            if reached {
+                break start;
+            }
+            buf.clear();
+        };
+        Self {
+            part: None,
+            position: Some(SourcePosition::new(xml, offset)),
+            path,
+        }
+    }
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut written = false;
+        if let Some(part) = &self.part {
+            f.write_str(part)?;
+            written = true;
+        }
+        if let Some(position) = &self.position {
+            if written {
+                f.write_str(":")?;
+            }
+            write!(f, "{}:{}", position.line, position.column)?;
+            written = true;
+        }
+        if !self.path.is_empty() {
+            if written {
+                f.write_str(" ")?;
+            }
+            write!(f, "in <{}>", self.path.join("/"))?;
+            written = true;
+        }
+        if !written {
+            f.write_str("unknown location")?;
+        }
+        Ok(())
+    }
+}
+
+impl ParseError {
+    /// Where the error occurred, if known.
+    pub fn location(&self) -> Option<&SourceLocation> {
+        match self {
+            Self::Located { location, .. } => Some(location),
+            _ => None,
+        }
+    }
+
+    /// The underlying error, without its location.
+    pub fn kind(&self) -> &ParseError {
+        match self {
+            Self::Located { source, .. } => source.kind(),
+            other => other,
+        }
+    }
+
+    /// Record where in `xml` the error occurred, given the reader's
+    /// `buffer_position()` at the time of the error. Errors that already
+    /// have a position are returned unchanged.
+    pub fn at_reader_position(self, xml: &[u8], reader_position: u64) -> Self {
+        let location = SourceLocation::at_reader_position(xml, reader_position);
+        match self {
+            Self::Located {
+                location: mut existing,
+                source,
+            } => {
+                if existing.position.is_none() {
+                    existing.position = location.position;
+                    existing.path = location.path;
+                }
+                Self::Located {
+                    location: existing,
+                    source,
+                }
+            }
+            other => Self::Located {
+                location: Box::new(location),
+                source: Box::new(other),
+            },
+        }
+    }
+
+    /// Locate the error in the original of a part rewritten by markup
+    /// compatibility processing, given the [`OffsetMap`] the processor
+    /// returned. The element path then includes the markup compatibility
+    /// elements enclosing the error.
+    pub fn in_original(self, original: &[u8], offsets: &OffsetMap) -> Self {
+        match self {
+            Self::Located {
+                mut location,
+                source,
+            } => {
+                if let Some(position) = location.position {
+                    let offset = offsets.original_offset(position.offset);
+                    // Rescan up to the first byte of the markup at the offset
+                    let relocated = SourceLocation::at_reader_position(original, offset + 1);
+                    location.position = relocated.position;
+                    location.path = relocated.path;
+                }
+                Self::Located { location, source }
+            }
+            other => other,
+        }
+    }
+
+    /// Record the name of the package part the error occurred in.
+    pub fn in_part(self, part: impl Into<String>) -> Self {
+        match self {
+            Self::Located {
+                mut location,
+                source,
+            } => {
+                location.part = Some(part.into());
+                Self::Located { location, source }
+            }
+            other => Self::Located {
+                location: Box::new(SourceLocation {
+                    part: Some(part.into()),
+                    ..SourceLocation::default()
+                }),
+                source: Box::new(other),
+            },
+        }
+    }
+}
+
+/// Parse the root element of an XML document with a generated parser.
+///
+/// Errors are returned as [`ParseError::Located`], with the position and
+/// element path of the markup the parser failed on.
+///
+/// ```
+/// use ooxml_xml::{FromXml, ParseError, parse_root};
+/// # use quick_xml::NsReader;
+/// # use quick_xml::events::BytesStart;
+/// # use std::io::BufRead;
+///
+/// struct Root;
+///
+/// impl FromXml for Root {
+///     fn from_xml<R: BufRead>(
+///         _reader: &mut NsReader<R>,
+///         _start: &BytesStart,
+///         _is_empty: bool,
+///     ) -> Result<Self, ParseError> {
+///         Err(ParseError::MissingAttribute("id".to_string()))
+///     }
+/// }
+///
+/// let err = parse_root::<Root>(b"<?xml version=\"1.0\"?>\n<root/>").err().unwrap();
+/// assert_eq!(err.to_string(), "2:1 in <root>: missing attribute: id");
+/// ```
+pub fn parse_root<T: FromXml>(xml: &[u8]) -> Result<T, ParseError> {
+    let mut reader = NsReader::from_reader(Cursor::new(xml));
+    let mut buf = Vec::new();
+    let result = loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(e)) => break T::from_xml(&mut reader, &e, false),
+            Ok(Event::Empty(e)) => break T::from_xml(&mut reader, &e, true),
+            Ok(Event::Eof) => {
+                break Err(ParseError::UnexpectedElement(
+                    "no root element found".to_string(),
+                ));
+            }
+            Err(e) => break Err(ParseError::Xml(e)),
+            _ => {}
+        }
+        buf.clear();
+    };
+    result.map_err(|e| e.at_reader_position(xml, reader.buffer_position()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quick_xml::events::BytesStart;
+    use std::io::BufRead;
+
+    /// Root element whose `<item>` children must have a numeric `n`.
+    #[derive(Debug)]
+    struct Items(Vec<u32>);
+
+    impl FromXml for Items {
+        fn from_xml<R: BufRead>(
+            reader: &mut NsReader<R>,
+            _start: &BytesStart,
+            is_empty: bool,
+        ) -> Result<Self, ParseError> {
+            let mut items = Vec::new();
+            let mut buf = Vec::new();
+            let mut depth = 0;
+            if is_empty {
+                return Ok(Items(items));
+            }
+            loop {
+                match reader.read_event_into(&mut buf)? {
+                    Event::Start(e) => {
+                        if e.local_name().as_ref() == b"item" {
+                            items.push(item_value(&e)?);
+                        }
+                        depth += 1;
+                    }
+                    Event::Empty(e) if e.local_name().as_ref() == b"item" => {
+                        items.push(item_value(&e)?);
+                    }
+                    Event::End(_) if depth == 0 => break,
+                    Event::End(_) => depth -= 1,
+                    Event::Eof => break,
+                    _ => {}
+                }
+                buf.clear();
+            }
+            Ok(Items(items))
+        }
+    }
+
+    fn item_value(e: &BytesStart) -> Result<u32, ParseError> {
+        let attr = e
+            .try_get_attribute("n")
+            .map_err(quick_xml::Error::from)?
+            .ok_or_else(|| ParseError::MissingAttribute("n".to_string()))?;
+        String::from_utf8_lossy(&attr.value)
+            .parse()
+            .map_err(|_| ParseError::InvalidValue("n".to_string()))
+    }
+
+    #[test]
+    fn test_parse_root_ok() {
+        let items: Items = parse_root(b"<items><item n=\"1\"/><item n=\"2\"/></items>").unwrap();
+        assert_eq!(items.0, [1, 2]);
+    }
+
+    #[test]
+    fn test_invalid_value_location() {
+        let xml =
+            b"<items>\n  <group>\n    <item n=\"1\"/>\n    <item n=\"x\"/>\n  </group>\n</items>";
+        let err = parse_root::<Items>(xml).unwrap_err();
+        assert!(matches!(err.kind(), ParseError::InvalidValue(_)));
+        let location = err.location().unwrap();
+        let position = location.position.unwrap();
+        assert_eq!((position.line, position.column), (4, 5));
+        assert_eq!(&xml[position.offset as usize..][..5], b"<item");
+        assert_eq!(location.path, ["items", "group", "item"]);
+        assert_eq!(
+            err.to_string(),
+            "4:5 in <items/group/item>: invalid value: n"
+        );
+    }
+
+    #[test]
+    fn test_malformed_xml_location() {
+        let xml = b"<items>\n  <item n=\"1\"></wrong>\n</items>";
+        let err = parse_root::<Items>(xml).unwrap_err();
+        assert!(matches!(err.kind(), ParseError::Xml(_)));
+        let location = err.location().unwrap();
+        assert_eq!(location.position.unwrap().line, 2);
+        assert_eq!(location.path, ["items", "item"]);
+    }
+
+    #[test]
+    fn test_in_part() {
+        let err = parse_root::<Items>(b"<items><item/></items>")
+            .unwrap_err()
+            .in_part("xl/worksheets/sheet1.xml");
+        assert_eq!(
+            err.to_string(),
+            "xl/worksheets/sheet1.xml:1:8 in <items/item>: missing attribute: n"
+        );
+
+        let err = ParseError::InvalidValue("x".to_string()).in_part("word/styles.xml");
+        assert_eq!(err.to_string(), "word/styles.xml: invalid value: x");
+        assert!(err.location().unwrap().position.is_none());
+    }
+
+    #[test]
+    fn test_no_root_element() {
+        let err = parse_root::<Items>(b"<?xml version=\"1.0\"?>\n").unwrap_err();
+        assert!(matches!(err.kind(), ParseError::UnexpectedElement(_)));
+        assert_eq!(err.location().unwrap().path, Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_location_in_original() {
+        let xml = concat!(
+            r#"<items xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" "#,
+            r#"xmlns:x="urn:x" mc:Ignorable="x">"#,
+            "\n  <x:note>ignored</x:note><group x:flag=\"1\"><item n=\"x\"/></group>\n</items>",
+        )
+        .as_bytes();
+        let (processed, offsets) = crate::MceProcessor::new(Vec::<String>::new())
+            .process_with_offsets(xml)
+            .unwrap();
+        let err = parse_root::<Items>(&processed).unwrap_err();
+        let processed_position = err.location().unwrap().position.unwrap();
+        assert_eq!(
+            (processed_position.line, processed_position.column),
+            (2, 10)
+        );
+
+        let err = err.in_original(xml, &offsets);
+        let location = err.location().unwrap();
+        let position = location.position.unwrap();
+        assert_eq!(&xml[position.offset as usize..][..5], b"<item");
+        assert_eq!((position.line, position.column), (2, 45));
+        assert_eq!(location.path, ["items", "group", "item"]);
+    }
+
+    #[test]
+    fn test_position_columns_count_characters() {
+        let xml = "<a>\n  é<b/>".as_bytes();
+        let position = SourcePosition::new(xml, xml.len() as u64 - 4);
+        assert_eq!((position.line, position.column), (2, 4));
+        assert_eq!(SourcePosition::new(xml, 1000).line, 2);
+    }
+}
@@ -0,0 +1,941 @@
+//! Markup Compatibility and Extensibility (ECMA-376 Part 3).
+//!
+//! Office writes newer markup inside `mc:AlternateContent`, with an
+//! `mc:Choice` that requires the namespaces of the newer markup and an
+//! `mc:Fallback` for consumers that do not understand them, and marks
+//! extension namespaces with `mc:Ignorable` so that older consumers can drop
+//! them. [`MceProcessor`] applies these rules to a part before it is parsed:
+//! it replaces each `mc:AlternateContent` with the content of the selected
+//! branch, removes ignorable elements and attributes in namespaces it does
+//! not understand, and removes the markup compatibility attributes
+//! themselves.
+//!
+//! Parts that are edited and written back are processed with
+//! [`MceProcessor::process_for_editing`] instead, which keeps the ignorable
+//! markup and marks the content of each selected branch, so that
+//! [`Alternates::restore`] can put the alternatives back on writing.
+//!
+//! [`MceProcessor::process_with_offsets`] also returns an [`OffsetMap`] back
+//! into the original part, so that parse errors can be located in the part
+//! as stored with [`ParseError::in_original`](crate::ParseError::in_original).
+//!
+//! # Example
+//!
+//! ```
+//! use ooxml_xml::MceProcessor;
+//!
+//! let xml = br#"<w:r xmlns:w="urn:w" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:wps="urn:wps">
+//!   <mc:AlternateContent>
+//!     <mc:Choice Requires="wps"><w:drawing/></mc:Choice>
+//!     <mc:Fallback><w:pict/></mc:Fallback>
+//!   </mc:AlternateContent>
+//! </w:r>"#;
+//!
+//! let modern = MceProcessor::new(["urn:w", "urn:wps"]).process(xml)?;
+//! assert!(String::from_utf8_lossy(&modern).contains("<w:drawing/>"));
+//!
+//! let legacy = MceProcessor::new(["urn:w"]).process(xml)?;
+//! assert!(String::from_utf8_lossy(&legacy).contains("<w:pict/>"));
+//! # Ok::<(), ooxml_xml::Error>(())
+//! ```
+
+use quick_xml::events::{BytesEnd, BytesStart, Event};
+use quick_xml::{Reader, Writer};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+
+use crate::{Error, Result};
+
+/// Markup Compatibility namespace.
+pub const NS_MC: &str = "http://schemas.openxmlformats.org/markup-compatibility/2006";
+
+/// The `xml:` namespace, which is always understood.
+const NS_XML: &str = "http://www.w3.org/XML/1998/namespace";
+
+/// Markup compatibility preprocessor for a set of understood namespaces.
+#[derive(Debug, Clone, Default)]
+pub struct MceProcessor {
+    understood: HashSet<String>,
+}
+
+/// Where the markup of a processed part comes from in the original part.
+///
+/// Records the offsets of each piece of markup the processor wrote. Offsets
+/// within a start tag whose attributes were filtered can be off by the
+/// length of the removed attributes; offsets of whole events are exact.
+#[derive(Debug, Clone, Default)]
+pub struct OffsetMap {
+    /// `(processed, original)` offsets of each event written, in order.
+    events: Vec<(u64, u64)>,
+}
+
+impl OffsetMap {
+    /// Offset in the original part of a byte of the processed part.
+    pub fn original_offset(&self, offset: u64) -> u64 {
+        match self
+            .events
+            .partition_point(|&(processed, _)| processed <= offset)
+        {
+            0 => offset,
+            i => {
+                let (processed, original) = self.events[i - 1];
+                original + (offset - processed)
+            }
+        }
+    }
+}
+
+/// How an element of the input is written to the output.
+enum Action {
+    /// Write the element with its (filtered) attributes.
+    Emit(Vec<u8>),
+    /// Drop the element but keep its content.
+    Unwrap,
+    /// An `mc:AlternateContent`, and whether a branch was selected yet.
+    Alternate { selected: bool },
+    /// The selected branch of an `mc:AlternateContent`, whose content is
+    /// kept.
+    Branch,
+}
+
+/// An open element of the input.
+struct Scope {
+    /// Namespace declarations, as `(prefix, uri)` (`""` for the default).
+    bindings: Vec<(String, String)>,
+    /// Namespaces declared ignorable on this element.
+    ignorable: Vec<String>,
+    /// Elements whose content is kept when ignored, as `(uri, local name)`,
+    /// where the local name may be `*`.
+    process_content: Vec<(String, String)>,
+    action: Action,
+}
+
+/// The `mc:AlternateContent` elements of a part processed for editing, to
+/// put back when the part is written.
+///
+/// See [`MceProcessor::process_for_editing`].
+#[derive(Debug, Clone, Default)]
+pub struct Alternates {
+    /// Indexed by the value of the marker attribute. `None` for elements
+    /// that were kept as they are.
+    entries: Vec<Option<AlternateMarkup>>,
+}
+
+/// The markup of an `mc:AlternateContent` around the content of its
+/// selected branch.
+#[derive(Debug, Clone)]
+struct AlternateMarkup {
+    /// Name of the attribute marking the elements of the selected branch.
+    marker: String,
+    /// From the `mc:AlternateContent` start tag to the end of the start tag
+    /// of the selected branch.
+    before: Vec<u8>,
+    /// From the end tag of the selected branch to the end of the
+    /// `mc:AlternateContent`.
+    after: Vec<u8>,
+}
+
+/// An `mc:AlternateContent` being processed for editing.
+struct Pending {
+    /// Index of its markup in the [`Alternates`].
+    index: usize,
+    /// Name of the attribute marking the elements of the selected branch.
+    marker: String,
+    /// The start tag to write if the element is kept as it is.
+    start_tag: Vec<u8>,
+    /// Length of the output before the element.
+    written: usize,
+    /// Offsets of the element and of the end of its start tag in the input.
+    start: usize,
+    inner: usize,
+    /// Offsets of the end of the start tag and of the end tag of the
+    /// selected branch in the input.
+    branch: (usize, usize),
+    /// Number of elements marked.
+    marked: usize,
+    /// Whether the selected branch has text outside of elements.
+    text: bool,
+}
+
+impl MceProcessor {
+    /// Create a processor that understands the given namespace URIs.
+    pub fn new<I, S>(understood: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            understood: understood.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Add an understood namespace URI.
+    pub fn understand(&mut self, namespace: impl Into<String>) {
+        self.understood.insert(namespace.into());
+    }
+
+    /// Check if a namespace URI is understood.
+    pub fn understands(&self, namespace: &str) -> bool {
+        namespace == NS_MC || namespace == NS_XML || self.understood.contains(namespace)
+    }
+
+    /// Apply the markup compatibility rules to an XML document.
+    ///
+    /// Returns the input unchanged if it does not use the markup
+    /// compatibility namespace. Fails if an element requires a namespace
+    /// that is not understood through `mc:MustUnderstand`.
+    pub fn process<'a>(&self, xml: &'a [u8]) -> Result<Cow<'a, [u8]>> {
+        Ok(self.process_with_offsets(xml)?.0)
+    }
+
+    /// Apply the markup compatibility rules to an XML document, also
+    /// returning where the output came from in the input.
+    pub fn process_with_offsets<'a>(&self, xml: &'a [u8]) -> Result<(Cow<'a, [u8]>, OffsetMap)> {
+        let (output, offsets, _) = self.run(xml, false)?;
+        Ok((output, offsets))
+    }
+
+    /// Apply the markup compatibility rules to a part that will be edited
+    /// and written back.
+    ///
+    /// Unlike [`process_with_offsets`](Self::process_with_offsets), this
+    /// keeps ignorable markup and the markup compatibility attributes, for
+    /// the parser to carry along as unknown content. Each
+    /// `mc:AlternateContent` is still replaced with the elements of its
+    /// selected branch, but these get a marker attribute from which
+    /// [`Alternates::restore`] puts the `mc:AlternateContent` and its other
+    /// branches back around them when the part is written. An
+    /// `mc:AlternateContent` whose selected branch has no elements, or text
+    /// outside of them, is kept as it is.
+    pub fn process_for_editing<'a>(
+        &self,
+        xml: &'a [u8],
+    ) -> Result<(Cow<'a, [u8]>, OffsetMap, Alternates)> {
+        self.run(xml, true)
+    }
+
+    fn run<'a>(
+        &self,
+        xml: &'a [u8],
+        editing: bool,
+    ) -> Result<(Cow<'a, [u8]>, OffsetMap, Alternates)> {
+        let mut offsets = OffsetMap::default();
+        let mut alternates = Alternates::default();
+        if !xml
+            .windows(NS_MC.len())
+            .any(|window| window == NS_MC.as_bytes())
+        {
+            return Ok((Cow::Borrowed(xml), offsets, alternates));
+        }
+
+        let mut reader = Reader::from_reader(xml);
+        let mut writer = Writer::new(Vec::with_capacity(xml.len()));
+        let mut buf = Vec::new();
+        let mut scopes: Vec<Scope> = Vec::new();
+        // Open `mc:AlternateContent` elements when editing
+        let mut pending: Vec<Pending> = Vec::new();
+        // Depth inside a skipped subtree
+        let mut skip = 0usize;
+
+        loop {
+            let start = reader.buffer_position();
+            let event = reader.read_event_into(&mut buf)?;
+            let end = reader.buffer_position() as usize;
+            let mut record = |writer: &Writer<Vec<u8>>| {
+                offsets.events.push((writer.get_ref().len() as u64, start));
+            };
+            if skip > 0 {
+                match event {
+                    Event::Start(_) => skip += 1,
+                    Event::End(_) => skip -= 1,
+                    Event::Eof => break,
+                    _ => {}
+                }
+                buf.clear();
+                continue;
+            }
+
+            match event {
+                Event::Start(e) => match self.open(&mut scopes, &e, editing)? {
+                    Some(scope) => {
+                        match scope.action {
+                            Action::Emit(_) => {
+                                let mut tag = self.filter(&scopes, &scope, &e, editing)?;
+                                let marker = mark(&scopes, &mut pending, &mut tag);
+                                record(&writer);
+                                writer.write_event(Event::Start(tag))?;
+                                if let Some(marker) = marker {
+                                    writer.write_event(Event::Empty(marker))?;
+                                }
+                            }
+                            Action::Alternate { .. } if editing => {
+                                let tag = self.filter(&scopes, &scope, &e, editing)?;
+                                let mut start_tag = Writer::new(Vec::new());
+                                start_tag.write_event(Event::Start(tag))?;
+                                pending.push(Pending {
+                                    index: alternates.entries.len(),
+                                    marker: match e.name().prefix() {
+                                        Some(prefix) => format!(
+                                            "{}:AlternateContent",
+                                            String::from_utf8_lossy(prefix.as_ref())
+                                        ),
+                                        None => "AlternateContent".to_string(),
+                                    },
+                                    start_tag: start_tag.into_inner(),
+                                    written: writer.get_ref().len(),
+                                    start: start as usize,
+                                    inner: end,
+                                    branch: (end, end),
+                                    marked: 0,
+                                    text: false,
+                                });
+                                alternates.entries.push(None);
+                            }
+                            Action::Branch => {
+                                if let Some(alternate) = pending.last_mut() {
+                                    alternate.branch.0 = end;
+                                }
+                            }
+                            _ => {}
+                        }
+                        scopes.push(scope);
+                    }
+                    None => skip = 1,
+                },
+                Event::Empty(e) => {
+                    if let Some(scope) = self.open(&mut scopes, &e, editing)? {
+                        match scope.action {
+                            Action::Emit(_) => {
+                                let mut tag = self.filter(&scopes, &scope, &e, editing)?;
+                                let marker = mark(&scopes, &mut pending, &mut tag);
+                                record(&writer);
+                                match marker {
+                                    Some(marker) => {
+                                        let name = String::from_utf8_lossy(tag.name().as_ref())
+                                            .into_owned();
+                                        writer.write_event(Event::Start(tag))?;
+                                        writer.write_event(Event::Empty(marker))?;
+                                        writer.write_event(Event::End(BytesEnd::new(name)))?;
+                                    }
+                                    None => writer.write_event(Event::Empty(tag))?,
+                                }
+                            }
+                            Action::Alternate { .. } if editing => {
+                                record(&writer);
+                                writer.write_event(Event::Empty(
+                                    self.filter(&scopes, &scope, &e, editing)?,
+                                ))?;
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                Event::End(_) => match scopes.pop() {
+                    Some(Scope {
+                        action: Action::Emit(name),
+                        ..
+                    }) => {
+                        let name = String::from_utf8_lossy(&name).into_owned();
+                        record(&writer);
+                        writer.write_event(Event::End(BytesEnd::new(name)))?;
+                    }
+                    Some(Scope {
+                        action: Action::Branch,
+                        ..
+                    }) => {
+                        if let Some(alternate) = pending.last_mut() {
+                            alternate.branch.1 = start as usize;
+                        }
+                    }
+                    Some(Scope {
+                        action: Action::Alternate { .. },
+                        ..
+                    }) => {
+                        if let Some(alternate) = pending.pop() {
+                            if alternate.marked > 0 && !alternate.text {
+                                alternates.entries[alternate.index] = Some(AlternateMarkup {
+                                    marker: alternate.marker,
+                                    before: xml[alternate.start..alternate.branch.0].to_vec(),
+                                    after: xml[alternate.branch.1..end].to_vec(),
+                                });
+                            } else {
+                                // Nothing to edit, so keep the element as it is
+                                let output = writer.get_mut();
+                                output.truncate(alternate.written);
+                                let kept = offsets.events.partition_point(|&(processed, _)| {
+                                    processed < alternate.written as u64
+                                });
+                                offsets.events.truncate(kept);
+                                offsets
+                                    .events
+                                    .push((alternate.written as u64, alternate.start as u64));
+                                output.extend_from_slice(&alternate.start_tag);
+                                offsets
+                                    .events
+                                    .push((output.len() as u64, alternate.inner as u64));
+                                output.extend_from_slice(&xml[alternate.inner..end]);
+                            }
+                        }
+                    }
+                    _ => {}
+                },
+                Event::Eof => break,
+                event => {
+                    // Only branches are allowed directly in AlternateContent
+                    match scopes.last() {
+                        Some(Scope {
+                            action: Action::Alternate { .. },
+                            ..
+                        }) => {}
+                        parent => {
+                            let text = match &event {
+                                Event::Text(text) => !text.iter().all(|b| b.is_ascii_whitespace()),
+                                Event::CData(_) => true,
+                                _ => false,
+                            };
+                            if text
+                                && let Some(Scope {
+                                    action: Action::Branch,
+                                    ..
+                                }) = parent
+                                && let Some(alternate) = pending.last_mut()
+                            {
+                                alternate.text = true;
+                            }
+                            record(&writer);
+                            writer.write_event(event)?;
+                        }
+                    }
+                }
+            }
+            buf.clear();
+        }
+
+        Ok((Cow::Owned(writer.into_inner()), offsets, alternates))
+    }
+
+    /// Open an element, deciding what to do with it. Returns `None` if the
+    /// element and its content are skipped.
+    fn open(&self, scopes: &mut [Scope], e: &BytesStart, editing: bool) -> Result<Option<Scope>> {
+        let mut scope = Scope {
+            bindings: Vec::new(),
+            ignorable: Vec::new(),
+            process_content: Vec::new(),
+            action: Action::Unwrap,
+        };
+        for attr in e.attributes().with_checks(false) {
+            let attr = attr.map_err(|e| Error::Invalid(e.to_string()))?;
+            let key = attr.key.as_ref();
+            if key == b"xmlns" {
+                scope.bindings.push((String::new(), attr_value(&attr)?));
+            } else if let Some(prefix) = key.strip_prefix(b"xmlns:") {
+                scope.bindings.push((
+                    String::from_utf8_lossy(prefix).into_owned(),
+                    attr_value(&attr)?,
+                ));
+            }
+        }
+
+        for attr in e.attributes().with_checks(false) {
+            let attr = attr.map_err(|e| Error::Invalid(e.to_string()))?;
+            let (uri, local) = resolve(scopes, &scope, attr.key.as_ref(), false);
+            if uri.as_deref() != Some(NS_MC) {
+                continue;
+            }
+            let value = attr_value(&attr)?;
+            match local.as_str() {
+                "Ignorable" => {
+                    for prefix in value.split_whitespace() {
+                        if let Some(uri) = lookup(scopes, &scope, prefix) {
+                            scope.ignorable.push(uri.to_string());
+                        }
+                    }
+                }
+                "ProcessContent" => {
+                    for name in value.split_whitespace() {
+                        let (uri, local) = resolve(scopes, &scope, name.as_bytes(), false);
+                        if let Some(uri) = uri {
+                            scope.process_content.push((uri, local));
+                        }
+                    }
+                }
+                "MustUnderstand" => {
+                    for prefix in value.split_whitespace() {
+                        match lookup(scopes, &scope, prefix) {
+                            Some(uri) if self.understands(uri) => {}
+                            _ => {
+                                return Err(Error::Invalid(format!(
+                                    "namespace {} must be understood",
+                                    prefix
+                                )));
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let (uri, local) = resolve(scopes, &scope, e.name().as_ref(), true);
+        let uri = uri.unwrap_or_default();
+
+        let alternate = match scopes.last() {
+            Some(Scope {
+                action: Action::Alternate { selected },
+                ..
+            }) => Some(*selected),
+            _ => None,
+        };
+        if let Some(selected) = alternate {
+            let take = match (uri.as_str(), local.as_str()) {
+                (NS_MC, "Choice") => !selected && self.requirements_met(scopes, &scope, e)?,
+                (NS_MC, "Fallback") => !selected,
+                _ => false,
+            };
+            if !take {
+                return Ok(None);
+            }
+            if let Some(parent) = scopes.last_mut() {
+                parent.action = Action::Alternate { selected: true };
+            }
+            scope.action = Action::Branch;
+            return Ok(Some(scope));
+        }
+
+        if uri == NS_MC && local == "AlternateContent" {
+            scope.action = Action::Alternate { selected: false };
+        } else if !editing && !self.understands(&uri) && is_ignorable(scopes, &scope, &uri) {
+            if !processes_content(scopes, &scope, &uri, &local) {
+                return Ok(None);
+            }
+        } else {
+            scope.action = Action::Emit(e.name().as_ref().to_vec());
+        }
+        Ok(Some(scope))
+    }
+
+    /// Check whether every namespace in the `Requires` attribute of an
+    /// `mc:Choice` is understood.
+    fn requirements_met(&self, scopes: &[Scope], scope: &Scope, e: &BytesStart) -> Result<bool> {
+        let requires = match e
+            .try_get_attribute("Requires")
+            .map_err(|e| Error::Invalid(e.to_string()))?
+        {
+            Some(attr) => attr_value(&attr)?,
+            None => return Ok(false),
+        };
+        Ok(requires
+            .split_whitespace()
+            .all(|prefix| lookup(scopes, scope, prefix).is_some_and(|uri| self.understands(uri))))
+    }
+
+    /// Build the output start tag of an emitted element, without markup
+    /// compatibility attributes and ignorable attributes that are not
+    /// understood unless editing, and with the namespace declarations of
+    /// unwrapped ancestors.
+    fn filter(
+        &self,
+        scopes: &[Scope],
+        scope: &Scope,
+        e: &BytesStart,
+        editing: bool,
+    ) -> Result<BytesStart<'static>> {
+        let name = String::from_utf8_lossy(e.name().as_ref()).into_owned();
+        let mut start = BytesStart::new(name);
+
+        // Declarations made on elements that are not written
+        let mut inherited: Vec<&(String, String)> = Vec::new();
+        for ancestor in scopes.iter().rev() {
+            if let Action::Emit(_) = ancestor.action {
+                break;
+            }
+            for binding in &ancestor.bindings {
+                let declared = scope.bindings.iter().any(|(p, _)| *p == binding.0)
+                    || inherited.iter().any(|(p, _)| *p == binding.0);
+                if !declared {
+                    inherited.push(binding);
+                }
+            }
+        }
+
+        for attr in e.attributes().with_checks(false) {
+            let attr = attr.map_err(|e| Error::Invalid(e.to_string()))?;
+            let key = attr.key.as_ref();
+            if !editing && key != b"xmlns" && !key.starts_with(b"xmlns:") && key.contains(&b':') {
+                let (uri, _) = resolve(scopes, scope, key, false);
+                let uri = uri.unwrap_or_default();
+                if uri == NS_MC || (!self.understands(&uri) && is_ignorable(scopes, scope, &uri)) {
+                    continue;
+                }
+            }
+            start.push_attribute(attr);
+        }
+        for (prefix, uri) in inherited {
+            let key = if prefix.is_empty() {
+                "xmlns".to_string()
+            } else {
+                format!("xmlns:{}", prefix)
+            };
+            start.push_attribute((key.as_str(), uri.as_str()));
+        }
+        Ok(start)
+    }
+}
+
+impl Alternates {
+    /// Check whether there is nothing to restore.
+    pub fn is_empty(&self) -> bool {
+        self.entries.iter().all(Option::is_none)
+    }
+
+    /// Put the `mc:AlternateContent` elements back into a part serialized
+    /// after editing.
+    ///
+    /// Each run of adjacent sibling elements with the same marker becomes
+    /// the content of the selected branch again, and the markers are
+    /// removed. Marked elements that were deleted take their
+    /// `mc:AlternateContent` with them.
+    pub fn restore<'a>(&self, xml: &'a [u8]) -> Result<Cow<'a, [u8]>> {
+        if self.is_empty() {
+            return Ok(Cow::Borrowed(xml));
+        }
+        let (marked, markers) = self.find_marked(xml)?;
+
+        let mut reader = Reader::from_reader(xml);
+        let mut writer = Writer::new(Vec::with_capacity(xml.len()));
+        let mut buf = Vec::new();
+        // The `mc:AlternateContent` open at each depth
+        let mut open: Vec<Option<&AlternateMarkup>> = vec![None];
+
+        loop {
+            let start = reader.buffer_position() as usize;
+            let event = reader.read_event_into(&mut buf)?;
+            let end = reader.buffer_position() as usize;
+            if markers.contains(&start) {
+                buf.clear();
+                continue;
+            }
+            match event {
+                Event::Start(ref e) | Event::Empty(ref e) => {
+                    let alternate = marked.get(&start).copied();
+                    let level = open.last_mut().expect("open at the root");
+                    if level.map(std::ptr::from_ref) != alternate.map(std::ptr::from_ref) {
+                        if let Some(closed) = level.take() {
+                            writer.get_mut().extend_from_slice(&closed.after);
+                        }
+                        if let Some(alternate) = alternate {
+                            writer.get_mut().extend_from_slice(&alternate.before);
+                        }
+                        *level = alternate;
+                    }
+                    match alternate {
+                        Some(alternate) => {
+                            let name = String::from_utf8_lossy(e.name().as_ref()).into_owned();
+                            let mut tag = BytesStart::new(name);
+                            for attr in e.attributes().with_checks(false) {
+                                let attr = attr.map_err(|e| Error::Invalid(e.to_string()))?;
+                                if attr.key.as_ref() != alternate.marker.as_bytes() {
+                                    tag.push_attribute(attr);
+                                }
+                            }
+                            match event {
+                                Event::Start(_) => writer.write_event(Event::Start(tag))?,
+                                _ => writer.write_event(Event::Empty(tag))?,
+                            }
+                        }
+                        None => writer.get_mut().extend_from_slice(&xml[start..end]),
+                    }
+                    if let Event::Start(_) = event {
+                        open.push(None);
+                    }
+                }
+                Event::End(_) => {
+                    if let Some(Some(alternate)) = open.pop() {
+                        writer.get_mut().extend_from_slice(&alternate.after);
+                    }
+                    writer.get_mut().extend_from_slice(&xml[start..end]);
+                }
+                Event::Eof => break,
+                _ => writer.get_mut().extend_from_slice(&xml[start..end]),
+            }
+            buf.clear();
+        }
+        if let Some(Some(alternate)) = open.pop() {
+            writer.get_mut().extend_from_slice(&alternate.after);
+        }
+
+        Ok(Cow::Owned(writer.into_inner()))
+    }
+
+    /// Find the marked elements of a part, by the offset of their start tag,
+    /// and the offsets of the marker children.
+    fn find_marked(
+        &self,
+        xml: &[u8],
+    ) -> Result<(HashMap<usize, &AlternateMarkup>, HashSet<usize>)> {
+        let mut reader = Reader::from_reader(xml);
+        let mut buf = Vec::new();
+        let mut marked = HashMap::new();
+        let mut markers = HashSet::new();
+        // Offsets of the open elements
+        let mut open = Vec::new();
+
+        loop {
+            let start = reader.buffer_position() as usize;
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) => {
+                    if let Some(alternate) = self.marker(&e)? {
+                        marked.insert(start, alternate);
+                    }
+                    open.push(start);
+                }
+                Event::Empty(e) => {
+                    if let Some(alternate) = self.marker(&e)? {
+                        if e.name().as_ref() == alternate.marker.as_bytes() {
+                            markers.insert(start);
+                            if let Some(&parent) = open.last() {
+                                marked.insert(parent, alternate);
+                            }
+                        } else {
+                            marked.insert(start, alternate);
+                        }
+                    }
+                }
+                Event::End(_) => {
+                    open.pop();
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+            buf.clear();
+        }
+        Ok((marked, markers))
+    }
+
+    /// The `mc:AlternateContent` a marker attribute on an element refers to,
+    /// if any.
+    fn marker(&self, e: &BytesStart) -> Result<Option<&AlternateMarkup>> {
+        for attr in e.attributes().with_checks(false) {
+            let attr = attr.map_err(|e| Error::Invalid(e.to_string()))?;
+            let key = attr.key.as_ref();
+            if !key.ends_with(b"AlternateContent") {
+                continue;
+            }
+            let alternate = std::str::from_utf8(&attr.value)
+                .ok()
+                .and_then(|index| index.parse::<usize>().ok())
+                .and_then(|index| self.entries.get(index)?.as_ref());
+            if let Some(alternate) = alternate
+                && alternate.marker.as_bytes() == key
+            {
+                return Ok(Some(alternate));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Mark an element written directly from the selected branch of an
+/// `mc:AlternateContent` processed for editing, returning the marker child
+/// to write as its first child.
+///
+/// Generated types only keep unknown attributes if they have attributes of
+/// their own, and unknown children if they have content, so the element
+/// gets both.
+fn mark(
+    scopes: &[Scope],
+    pending: &mut [Pending],
+    tag: &mut BytesStart<'static>,
+) -> Option<BytesStart<'static>> {
+    let Some(Scope {
+        action: Action::Branch,
+        ..
+    }) = scopes.last()
+    else {
+        return None;
+    };
+    let alternate = pending.last_mut()?;
+    let index = alternate.index.to_string();
+    tag.push_attribute((alternate.marker.as_str(), index.as_str()));
+    alternate.marked += 1;
+    Some(
+        BytesStart::new(alternate.marker.clone())
+            .with_attributes([(alternate.marker.as_str(), index.as_str())]),
+    )
+}
+
+/// Decode and unescape an attribute value.
+fn attr_value(attr: &quick_xml::events::attributes::Attribute) -> Result<String> {
+    Ok(attr
+        .unescape_value()
+        .map_err(|e| Error::Invalid(e.to_string()))?
+        .into_owned())
+}
+
+/// Look up the namespace URI bound to a prefix.
+fn lookup<'a>(scopes: &'a [Scope], scope: &'a Scope, prefix: &str) -> Option<&'a str> {
+    std::iter::once(scope)
+        .chain(scopes.iter().rev())
+        .flat_map(|s| s.bindings.iter())
+        .find(|(p, _)| p == prefix)
+        .map(|(_, uri)| uri.as_str())
+}
+
+/// Resolve a qualified name to its namespace URI and local name. Unprefixed
+/// attributes are in no namespace.
+fn resolve(
+    scopes: &[Scope],
+    scope: &Scope,
+    qname: &[u8],
+    is_element: bool,
+) -> (Option<String>, String) {
+    let qname = String::from_utf8_lossy(qname);
+    match qname.split_once(':') {
+        Some(("xml", local)) => (Some(NS_XML.to_string()), local.to_string()),
+        Some((prefix, local)) => (
+            lookup(scopes, scope, prefix).map(str::to_string),
+            local.to_string(),
+        ),
+        None if is_element => (
+            lookup(scopes, scope, "").map(str::to_string),
+            qname.into_owned(),
+        ),
+        None => (None, qname.into_owned()),
+    }
+}
+
+/// Check whether a namespace is declared ignorable in scope.
+fn is_ignorable(scopes: &[Scope], scope: &Scope, uri: &str) -> bool {
+    std::iter::once(scope)
+        .chain(scopes.iter())
+        .any(|s| s.ignorable.iter().any(|u| u == uri))
+}
+
+/// Check whether the content of an ignored element is processed.
+fn processes_content(scopes: &[Scope], scope: &Scope, uri: &str, local: &str) -> bool {
+    std::iter::once(scope)
+        .chain(scopes.iter())
+        .flat_map(|s| s.process_content.iter())
+        .any(|(u, l)| u == uri && (l == "*" || l == local))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(processor: &MceProcessor, xml: &str) -> String {
+        String::from_utf8(processor.process(xml.as_bytes()).unwrap().into_owned()).unwrap()
+    }
+
+    #[test]
+    fn test_alternate_content() {
+        let xml = r#"<r xmlns="urn:w" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:v2="urn:v2" xmlns:v3="urn:v3"><mc:AlternateContent>
+  <mc:Choice Requires="v3"><v3:shape/></mc:Choice>
+  <mc:Choice Requires="v2"><v2:shape>a &amp; b</v2:shape></mc:Choice>
+  <mc:Fallback><pict/></mc:Fallback>
+</mc:AlternateContent></r>"#;
+
+        let v2 = MceProcessor::new(["urn:w", "urn:v2"]);
+        assert_eq!(
+            process(&v2, xml),
+            r#"<r xmlns="urn:w" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:v2="urn:v2" xmlns:v3="urn:v3"><v2:shape>a &amp; b</v2:shape></r>"#
+        );
+        let v1 = MceProcessor::new(["urn:w"]);
+        assert!(process(&v1, xml).ends_with("><pict/></r>"));
+        let v3 = MceProcessor::new(["urn:w", "urn:v2", "urn:v3"]);
+        assert!(process(&v3, xml).ends_with("><v3:shape/></r>"));
+    }
+
+    #[test]
+    fn test_declarations_on_branch() {
+        let xml = r#"<r xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006"><mc:AlternateContent><mc:Choice xmlns:x="urn:x" Requires="x"><x:a><x:b/></x:a></mc:Choice></mc:AlternateContent></r>"#;
+        let processor = MceProcessor::new(["urn:x"]);
+        assert_eq!(
+            process(&processor, xml),
+            r#"<r xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006"><x:a xmlns:x="urn:x"><x:b/></x:a></r>"#
+        );
+        // Nothing is selected without a fallback
+        assert_eq!(
+            process(&MceProcessor::default(), xml),
+            r#"<r xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006"></r>"#
+        );
+    }
+
+    #[test]
+    fn test_ignorable() {
+        let xml = r#"<doc xmlns="urn:w" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:x="urn:x" xmlns:y="urn:y" mc:Ignorable="x y" mc:ProcessContent="y:wrap"><p x:id="1" y:id="2" plain="3"><x:ext><p/></x:ext><y:wrap><p/></y:wrap></p></doc>"#;
+        let processor = MceProcessor::new(["urn:w", "urn:x"]);
+        assert_eq!(
+            process(&processor, xml),
+            r#"<doc xmlns="urn:w" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:x="urn:x" xmlns:y="urn:y"><p x:id="1" plain="3"><x:ext><p/></x:ext><p/></p></doc>"#
+        );
+        let processor = MceProcessor::new(["urn:w"]);
+        assert_eq!(
+            process(&processor, xml),
+            r#"<doc xmlns="urn:w" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:x="urn:x" xmlns:y="urn:y"><p plain="3"><p/></p></doc>"#
+        );
+    }
+
+    #[test]
+    fn test_process_for_editing() {
+        let xml = r#"<r xmlns="urn:w" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:v2="urn:v2" xmlns:x="urn:x" mc:Ignorable="x"><p x:id="1"><mc:AlternateContent>
+  <mc:Choice Requires="v2"><v2:shape>a &amp; b</v2:shape><v2:line></v2:line></mc:Choice>
+  <mc:Fallback><pict/></mc:Fallback>
+</mc:AlternateContent></p><x:ext/></r>"#;
+        let processor = MceProcessor::new(["urn:w", "urn:v2"]);
+        let (processed, _, alternates) = processor.process_for_editing(xml.as_bytes()).unwrap();
+        let processed = String::from_utf8(processed.into_owned()).unwrap();
+        assert_eq!(
+            processed,
+            r#"<r xmlns="urn:w" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:v2="urn:v2" xmlns:x="urn:x" mc:Ignorable="x"><p x:id="1"><v2:shape mc:AlternateContent="0"><mc:AlternateContent mc:AlternateContent="0"/>a &amp; b</v2:shape><v2:line mc:AlternateContent="0"><mc:AlternateContent mc:AlternateContent="0"/></v2:line></p><x:ext/></r>"#
+        );
+
+        let restore = |xml: &str| {
+            String::from_utf8(alternates.restore(xml.as_bytes()).unwrap().into_owned()).unwrap()
+        };
+        assert_eq!(restore(&processed), xml);
+        // Edited content goes into the selected branch
+        assert_eq!(
+            restore(&processed.replace("a &amp; b", "c")),
+            xml.replace("a &amp; b", "c")
+        );
+        // Either marker is enough
+        let one_marker = processed
+            .replacen(r#"<mc:AlternateContent mc:AlternateContent="0"/>"#, "", 1)
+            .replacen(r#"<v2:line mc:AlternateContent="0">"#, "<v2:line>", 1);
+        assert_eq!(restore(&one_marker), xml);
+        // Content moved apart is wrapped on its own
+        let split = restore(&processed.replace("</v2:shape><v2:line", "</v2:shape><q/><v2:line"));
+        assert_eq!(split.matches("<pict/>").count(), 2);
+        assert!(split.contains("</mc:AlternateContent><q/><mc:AlternateContent>"));
+        // Deleted content takes the alternatives with it
+        let deleted = restore(r#"<r xmlns="urn:w"><p/></r>"#);
+        assert_eq!(deleted, r#"<r xmlns="urn:w"><p/></r>"#);
+    }
+
+    #[test]
+    fn test_editing_keeps_unselected_alternate() {
+        let xml = r#"<r xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006"><mc:AlternateContent><mc:Choice xmlns:x="urn:x" Requires="x"><x:a/></mc:Choice></mc:AlternateContent><p/></r>"#;
+        let (processed, _, alternates) = MceProcessor::default()
+            .process_for_editing(xml.as_bytes())
+            .unwrap();
+        assert_eq!(processed, xml.as_bytes());
+        assert!(alternates.is_empty());
+    }
+
+    #[test]
+    fn test_must_understand() {
+        let xml = r#"<doc xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:x="urn:x" mc:MustUnderstand="x"/>"#;
+        assert!(MceProcessor::default().process(xml.as_bytes()).is_err());
+        assert!(MceProcessor::new(["urn:x"]).process(xml.as_bytes()).is_ok());
+    }
+
+    #[test]
+    fn test_unchanged_without_mce() {
+        let xml = b"<doc><p/></doc>";
+        assert!(matches!(
+            MceProcessor::default().process(xml).unwrap(),
+            Cow::Borrowed(_)
+        ));
+    }
+}
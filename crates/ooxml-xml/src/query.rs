@@ -0,0 +1,1211 @@
+//! Path queries over raw XML.
+//!
+//! A [`Query`] finds nodes in a [`RawXmlElement`] tree using a small subset
+//! of XPath, instead of a hand-written traversal for every lookup:
+//!
+//! | Syntax | Selects |
+//! |--------|---------|
+//! | `a:blip` | child elements named `a:blip` |
+//! | `blip` | child elements with local name `blip`, whatever their prefix |
+//! | `*`, `a:*` | any child element, any child element with prefix `a` |
+//! | `x//y` | `y` elements anywhere below `x` |
+//! | `/x` | the root element, if it is named `x` |
+//! | `//x` | `x` elements anywhere, including the root |
+//! | `.` | the context element |
+//! | `@r:embed`, `@embed`, `@*` | attributes (last step only) |
+//! | `text()` | text content (last step only) |
+//! | `x[@val]`, `x[@val='1']`, `x[@val!='1']` | `x` elements with a matching attribute |
+//! | `x[a:tbl]` | `x` elements with a matching child element |
+//! | `x[2]`, `x[last()]` | the second or last `x` of each parent |
+//!
+//! Prefixes are matched as written, unless bound to a namespace with
+//! [`Query::with_namespace`], in which case elements and attributes match by
+//! the namespace their own prefix is declared for.
+//!
+//! ```
+//! use ooxml_xml::{Query, RawXmlElement};
+//!
+//! let drawing = RawXmlElement::parse(br#"
+//!     <wp:inline xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"
+//!                xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+//!       <a:graphic><a:graphicData><pic:pic><pic:blipFill>
+//!         <a:blip r:embed="rId5"/>
+//!       </pic:blipFill></pic:pic></a:graphicData></a:graphic>
+//!     </wp:inline>"#).unwrap();
+//!
+//! let query = Query::new("//a:blip/@r:embed").unwrap();
+//! assert_eq!(query.values(&drawing), ["rId5"]);
+//! ```
+//!
+//! Queries run over markup kept as raw XML, such as the `extra_children` of
+//! generated types that hold content the schema leaves open (the DrawingML
+//! inside a `w:drawing`, for example), and over typed trees implementing
+//! [`QueryTree`], which the format crates generate for their types next to
+//! their `Visit` traits. [`Query::select_tree`] runs a query over a typed
+//! tree as the XML it serializes to, descending into raw children as well.
+
+use std::any::Any;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::ops::Deref;
+use std::rc::Rc;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use quick_xml::events::BytesStart;
+
+use crate::{Error, RawXmlElement, RawXmlNode, Result, ToXml};
+
+/// Namespace bound to the reserved `xml` prefix.
+const NS_XML: &str = "http://www.w3.org/XML/1998/namespace";
+
+/// A compiled path query.
+///
+/// Queries are parsed once with [`Query::new`] and can be run against any
+/// number of trees.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Query {
+    /// Whether the query starts with `/` or `//`.
+    absolute: bool,
+    steps: Vec<Step>,
+    /// Namespaces bound with [`Query::with_namespace`], as `(prefix, uri)`.
+    namespaces: Vec<(String, String)>,
+}
+
+/// A node selected by a [`Query`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum QueryMatch<'a> {
+    /// An element.
+    Element(&'a RawXmlElement),
+    /// An attribute, with the element it is on.
+    Attribute {
+        /// The element carrying the attribute.
+        element: &'a RawXmlElement,
+        /// The attribute name, as written.
+        name: &'a str,
+        /// The attribute value.
+        value: &'a str,
+    },
+    /// A text or CDATA node.
+    Text(&'a str),
+}
+
+impl<'a> QueryMatch<'a> {
+    /// The matched element, if this is an element match.
+    pub fn as_element(&self) -> Option<&'a RawXmlElement> {
+        match self {
+            QueryMatch::Element(element) => Some(element),
+            _ => None,
+        }
+    }
+
+    /// The attribute value or text, if this is not an element match.
+    pub fn value(&self) -> Option<&'a str> {
+        match self {
+            QueryMatch::Element(_) => None,
+            QueryMatch::Attribute { value, .. } => Some(value),
+            QueryMatch::Text(text) => Some(text),
+        }
+    }
+}
+
+/// A query compiled on first use, for queries written in the source.
+///
+/// ```
+/// use ooxml_xml::{LazyQuery, RawXmlElement};
+///
+/// static IMAGES: LazyQuery = LazyQuery::new("//blip/@embed");
+///
+/// let root = RawXmlElement::parse(br#"<pic><blip embed="rId2"/></pic>"#).unwrap();
+/// assert_eq!(IMAGES.values(&root), ["rId2"]);
+/// ```
+///
+/// Panics on first use if the expression is not a valid query.
+pub struct LazyQuery {
+    expr: &'static str,
+    query: OnceLock<Query>,
+}
+
+impl LazyQuery {
+    /// A query for `expr`, parsed when first used.
+    pub const fn new(expr: &'static str) -> Self {
+        Self {
+            expr,
+            query: OnceLock::new(),
+        }
+    }
+}
+
+impl Deref for LazyQuery {
+    type Target = Query;
+
+    fn deref(&self) -> &Query {
+        self.query
+            .get_or_init(|| Query::new(self.expr).unwrap_or_else(|e| panic!("{e}")))
+    }
+}
+
+impl fmt::Debug for LazyQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("LazyQuery").field(&self.expr).finish()
+    }
+}
+
+/// A typed tree that queries can run over, as the XML it serializes to.
+///
+/// The format crates implement this for their generated types with their
+/// `visit` feature: each type reports the attributes its serializer writes,
+/// its children under the element names the serializer gives them, its
+/// text, and its unknown children as raw XML (after the typed ones).
+pub trait QueryTree {
+    /// Report this value to `visitor` as an element named `name`. Choice
+    /// types ignore `name` and report the element of their variant.
+    fn visit_element<'a>(&'a self, name: &str, visitor: &mut dyn TreeVisitor<'a>);
+
+    /// Report the attributes and content of this value's element.
+    fn visit_content<'a>(&'a self, visitor: &mut dyn TreeVisitor<'a>);
+
+    /// This value as [`Any`], for [`TreeMatch::downcast`].
+    fn as_any(&self) -> &dyn Any;
+}
+
+/// Receives the content of a [`QueryTree`] element.
+pub trait TreeVisitor<'a> {
+    /// An attribute, with its name as written.
+    fn attribute(&mut self, name: &str, value: &str);
+
+    /// A child element, whose content `node` reports through
+    /// [`QueryTree::visit_content`].
+    fn element(&mut self, name: &str, node: &'a dyn QueryTree);
+
+    /// A text node.
+    fn text(&mut self, text: &str);
+
+    /// A child kept as raw XML.
+    fn raw(&mut self, node: &'a RawXmlNode);
+}
+
+/// Elements holding only text, such as `a:t`, are written from strings.
+impl QueryTree for String {
+    fn visit_element<'a>(&'a self, name: &str, visitor: &mut dyn TreeVisitor<'a>) {
+        visitor.element(name, self);
+    }
+
+    fn visit_content<'a>(&'a self, visitor: &mut dyn TreeVisitor<'a>) {
+        visitor.text(self);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Report the attributes that `node` writes through [`ToXml`] to `visitor`,
+/// for [`QueryTree`] implementations.
+pub fn visit_attributes<'a, T: ToXml + ?Sized>(node: &T, visitor: &mut dyn TreeVisitor<'a>) {
+    let start = node.write_attrs(BytesStart::new(""));
+    for attr in start.attributes().with_checks(false).flatten() {
+        let name = String::from_utf8_lossy(attr.key.as_ref());
+        let value = match attr.unescape_value() {
+            Ok(value) => value.into_owned(),
+            Err(_) => String::from_utf8_lossy(&attr.value).into_owned(),
+        };
+        visitor.attribute(&name, &value);
+    }
+}
+
+/// A node selected from a typed tree by [`Query::select_tree`].
+pub enum TreeMatch<'a> {
+    /// A typed element, such as a generated type.
+    Node(&'a dyn QueryTree),
+    /// An element kept as raw XML.
+    Raw(&'a RawXmlElement),
+    /// An attribute.
+    Attribute {
+        /// The attribute name, as written.
+        name: String,
+        /// The attribute value.
+        value: String,
+    },
+    /// A text node.
+    Text(String),
+}
+
+impl<'a> TreeMatch<'a> {
+    /// The matched typed element, if it is a `T`.
+    pub fn downcast<T: Any>(&self) -> Option<&'a T> {
+        match self {
+            TreeMatch::Node(node) => {
+                let node: &'a dyn QueryTree = *node;
+                node.as_any().downcast_ref()
+            }
+            _ => None,
+        }
+    }
+
+    /// The matched element kept as raw XML, if this is one.
+    pub fn as_raw(&self) -> Option<&'a RawXmlElement> {
+        match self {
+            TreeMatch::Raw(element) => Some(element),
+            _ => None,
+        }
+    }
+
+    /// The attribute value or text, if this is not an element match.
+    pub fn value(&self) -> Option<&str> {
+        match self {
+            TreeMatch::Node(_) | TreeMatch::Raw(_) => None,
+            TreeMatch::Attribute { value, .. } => Some(value),
+            TreeMatch::Text(text) => Some(text),
+        }
+    }
+}
+
+impl fmt::Debug for TreeMatch<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TreeMatch::Node(_) => f.write_str("Node(..)"),
+            TreeMatch::Raw(element) => f.debug_tuple("Raw").field(element).finish(),
+            TreeMatch::Attribute { name, value } => f
+                .debug_struct("Attribute")
+                .field("name", name)
+                .field("value", value)
+                .finish(),
+            TreeMatch::Text(text) => f.debug_tuple("Text").field(text).finish(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Axis {
+    /// `/`: children of the context.
+    Child,
+    /// `//`: children of the context or any of its descendants.
+    Descendant,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Step {
+    axis: Axis,
+    test: Test,
+    predicates: Vec<Predicate>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Test {
+    /// `.`
+    Context,
+    Element(NameTest),
+    Attribute(NameTest),
+    /// `text()`
+    Text,
+}
+
+/// A name test; `None` parts match anything.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct NameTest {
+    prefix: Option<String>,
+    local: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Predicate {
+    /// `[n]`, 1-based.
+    Position(usize),
+    /// `[last()]`
+    Last,
+    /// `[@name]`, `[@name='v']` or `[@name!='v']`.
+    Attribute(NameTest, Option<(bool, String)>),
+    /// `[name]`
+    Child(NameTest),
+}
+
+impl Query {
+    /// Parse a query.
+    ///
+    /// Returns [`Error::Invalid`] if the expression is not a valid query.
+    pub fn new(expr: &str) -> Result<Self> {
+        QueryParser { expr, pos: 0 }.parse()
+    }
+
+    /// Bind a prefix used in the query to a namespace URI.
+    ///
+    /// ```
+    /// use ooxml_xml::{Query, RawXmlElement};
+    ///
+    /// let xml = br#"<root xmlns:x="urn:a" xmlns:y="urn:b"><x:item/><y:item/></root>"#;
+    /// let root = RawXmlElement::parse(xml).unwrap();
+    /// let query = Query::new("a:item").unwrap().with_namespace("a", "urn:b");
+    /// assert_eq!(query.elements(&root)[0].name, "y:item");
+    /// ```
+    pub fn with_namespace(mut self, prefix: impl Into<String>, uri: impl Into<String>) -> Self {
+        self.namespaces.push((prefix.into(), uri.into()));
+        self
+    }
+
+    /// Run the query with `root` as the context element, returning matches
+    /// in document order.
+    pub fn select<'a>(&self, root: &'a RawXmlElement) -> Vec<QueryMatch<'a>> {
+        let start = if self.absolute {
+            Context::top(vec![root], Vec::new())
+        } else {
+            Context::element(root, &None)
+        };
+        let mut order = DocumentOrder::default();
+        order.visit(root);
+        self.evaluate(start, &order)
+    }
+
+    /// Run the query over a list of sibling nodes, such as the
+    /// `extra_children` of a generated type. The context is their (absent)
+    /// parent, so `inline` selects the `inline` elements among `nodes`.
+    pub fn select_nodes<'a>(
+        &self,
+        nodes: impl IntoIterator<Item = &'a RawXmlNode>,
+    ) -> Vec<QueryMatch<'a>> {
+        let mut elements = Vec::new();
+        let mut texts = Vec::new();
+        let mut order = DocumentOrder::default();
+        for node in nodes {
+            match node {
+                RawXmlNode::Element(element) => {
+                    elements.push(element);
+                    order.visit(element);
+                }
+                RawXmlNode::Text(text) | RawXmlNode::CData(text) => {
+                    texts.push(text.as_str());
+                    order.visit_text(text);
+                }
+                RawXmlNode::Comment(_) => {}
+            }
+        }
+        self.evaluate(Context::top(elements, texts), &order)
+    }
+
+    /// The elements selected from `root`.
+    pub fn elements<'a>(&self, root: &'a RawXmlElement) -> Vec<&'a RawXmlElement> {
+        self.select(root)
+            .iter()
+            .filter_map(QueryMatch::as_element)
+            .collect()
+    }
+
+    /// The attribute values and text selected from `root`.
+    pub fn values<'a>(&self, root: &'a RawXmlElement) -> Vec<&'a str> {
+        self.select(root)
+            .iter()
+            .filter_map(QueryMatch::value)
+            .collect()
+    }
+
+    /// Run the query over a typed tree, with `root` as the context element.
+    ///
+    /// The tree is matched as the XML it serializes to, with the prefixes
+    /// the serializer writes. The context element has no name, so absolute
+    /// queries select from its children.
+    ///
+    /// ```
+    /// use ooxml_xml::{Query, QueryTree};
+    ///
+    /// // Strings are written as elements holding their text
+    /// let title = String::from("Results");
+    /// let matches = Query::new("text()").unwrap().select_tree(&title);
+    /// assert_eq!(matches[0].value(), Some("Results"));
+    /// ```
+    pub fn select_tree<'a>(&self, root: &'a dyn QueryTree) -> Vec<TreeMatch<'a>> {
+        let mut builder = TreeBuilder {
+            stack: vec![RawXmlElement::new("")],
+            origins: vec![TreeMatch::Node(root)],
+        };
+        root.visit_content(&mut builder);
+        let copy = builder.stack.pop().expect("root element");
+        let mut elements = Vec::new();
+        preorder(&copy, &mut elements);
+        let mut origins: HashMap<_, _> = elements
+            .into_iter()
+            .map(std::ptr::from_ref)
+            .zip(builder.origins)
+            .collect();
+        self.select(&copy)
+            .into_iter()
+            .filter_map(|m| match m {
+                QueryMatch::Element(element) => origins.remove(&std::ptr::from_ref(element)),
+                QueryMatch::Attribute { name, value, .. } => Some(TreeMatch::Attribute {
+                    name: name.to_string(),
+                    value: value.to_string(),
+                }),
+                QueryMatch::Text(text) => Some(TreeMatch::Text(text.to_string())),
+            })
+            .collect()
+    }
+
+    /// The attribute values and text selected from a typed tree.
+    pub fn tree_values(&self, root: &dyn QueryTree) -> Vec<String> {
+        self.select_tree(root)
+            .into_iter()
+            .filter_map(|m| match m {
+                TreeMatch::Attribute { value, .. } => Some(value),
+                TreeMatch::Text(text) => Some(text),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn evaluate<'a>(&self, start: Context<'a>, order: &DocumentOrder) -> Vec<QueryMatch<'a>> {
+        let mut contexts = vec![start];
+        let mut matches = Vec::new();
+        for step in &self.steps {
+            let parents = match step.axis {
+                Axis::Child => contexts,
+                Axis::Descendant => descendants_or_self(contexts),
+            };
+            contexts = Vec::new();
+            let mut seen = HashSet::new();
+            for parent in &parents {
+                match &step.test {
+                    Test::Context => {
+                        if seen.insert(parent.id()) {
+                            contexts.push(parent.clone());
+                        }
+                    }
+                    Test::Element(test) => {
+                        let candidates = parent
+                            .children()
+                            .map(|child| Context::element(child, &parent.scope))
+                            .filter(|child| {
+                                test.matches(child.name(), false, child, &self.namespaces)
+                            })
+                            .collect();
+                        for child in self.filter(candidates, &step.predicates) {
+                            if seen.insert(child.id()) {
+                                contexts.push(child);
+                            }
+                        }
+                    }
+                    Test::Attribute(test) => {
+                        if let Some(element) = parent.element {
+                            for (name, value) in &element.attributes {
+                                if !is_namespace_declaration(name)
+                                    && test.matches(name, true, parent, &self.namespaces)
+                                {
+                                    matches.push(QueryMatch::Attribute {
+                                        element,
+                                        name,
+                                        value,
+                                    });
+                                }
+                            }
+                        }
+                    }
+                    Test::Text => matches.extend(parent.texts().map(QueryMatch::Text)),
+                }
+            }
+        }
+        matches.extend(
+            contexts
+                .iter()
+                .filter_map(|c| c.element)
+                .map(QueryMatch::Element),
+        );
+        order.sort(&mut matches);
+        matches
+    }
+
+    /// Apply predicates in turn, positions counting the candidates left by
+    /// the previous predicate.
+    fn filter<'a>(
+        &self,
+        mut candidates: Vec<Context<'a>>,
+        predicates: &[Predicate],
+    ) -> Vec<Context<'a>> {
+        for predicate in predicates {
+            let len = candidates.len();
+            candidates = candidates
+                .into_iter()
+                .enumerate()
+                .filter(|(i, candidate)| match predicate {
+                    Predicate::Position(n) => i + 1 == *n,
+                    Predicate::Last => i + 1 == len,
+                    Predicate::Attribute(test, comparison) => {
+                        candidate.attributes().any(|(name, value)| {
+                            test.matches(name, true, candidate, &self.namespaces)
+                                && comparison
+                                    .as_ref()
+                                    .is_none_or(|(equal, v)| (value == v) == *equal)
+                        })
+                    }
+                    Predicate::Child(test) => candidate.children().any(|child| {
+                        let child = Context::element(child, &candidate.scope);
+                        test.matches(child.name(), false, &child, &self.namespaces)
+                    }),
+                })
+                .map(|(_, candidate)| candidate)
+                .collect();
+        }
+        candidates
+    }
+}
+
+impl FromStr for Query {
+    type Err = Error;
+
+    fn from_str(expr: &str) -> Result<Self> {
+        Self::new(expr)
+    }
+}
+
+impl NameTest {
+    /// Whether `name`, read with the declarations of `context` in scope,
+    /// passes this test.
+    fn matches(
+        &self,
+        name: &str,
+        is_attribute: bool,
+        context: &Context,
+        namespaces: &[(String, String)],
+    ) -> bool {
+        let (prefix, local) = match name.split_once(':') {
+            Some((prefix, local)) => (Some(prefix), local),
+            None => (None, name),
+        };
+        if self.local.as_deref().is_some_and(|l| l != local) {
+            return false;
+        }
+        let Some(test_prefix) = &self.prefix else {
+            return true;
+        };
+        match namespaces.iter().find(|(p, _)| p == test_prefix) {
+            Some((_, uri)) => {
+                let namespace = match prefix {
+                    Some("xml") => Some(NS_XML),
+                    Some(prefix) => lookup(&context.scope, prefix),
+                    // Unprefixed attributes are in no namespace
+                    None if is_attribute => None,
+                    None => lookup(&context.scope, ""),
+                };
+                namespace == Some(uri.as_str())
+            }
+            None => prefix == Some(test_prefix.as_str()),
+        }
+    }
+}
+
+/// Copies a typed tree into raw XML, recording the node each element came
+/// from in document order.
+struct TreeBuilder<'a> {
+    stack: Vec<RawXmlElement>,
+    origins: Vec<TreeMatch<'a>>,
+}
+
+impl TreeBuilder<'_> {
+    fn current(&mut self) -> &mut RawXmlElement {
+        self.stack.last_mut().expect("root element")
+    }
+}
+
+impl<'a> TreeVisitor<'a> for TreeBuilder<'a> {
+    fn attribute(&mut self, name: &str, value: &str) {
+        self.current()
+            .attributes
+            .push((name.to_string(), value.to_string()));
+    }
+
+    fn element(&mut self, name: &str, node: &'a dyn QueryTree) {
+        self.origins.push(TreeMatch::Node(node));
+        self.stack.push(RawXmlElement::new(name));
+        node.visit_content(self);
+        let element = self.stack.pop().expect("element");
+        self.current().children.push(RawXmlNode::Element(element));
+    }
+
+    fn text(&mut self, text: &str) {
+        self.current()
+            .children
+            .push(RawXmlNode::Text(text.to_string()));
+    }
+
+    fn raw(&mut self, node: &'a RawXmlNode) {
+        if let RawXmlNode::Element(element) = node {
+            let mut elements = Vec::new();
+            preorder(element, &mut elements);
+            // Elements of a copied tree have the same order as the original
+            self.origins
+                .extend(elements.into_iter().map(TreeMatch::Raw));
+        }
+        self.current().children.push(node.clone());
+    }
+}
+
+/// `element` and all its descendant elements, in document order.
+fn preorder<'e>(element: &'e RawXmlElement, out: &mut Vec<&'e RawXmlElement>) {
+    out.push(element);
+    for child in &element.children {
+        if let RawXmlNode::Element(e) = child {
+            preorder(e, out);
+        }
+    }
+}
+
+/// Namespace declarations in scope, innermost first.
+type Scope<'a> = Option<Rc<Binding<'a>>>;
+
+struct Binding<'a> {
+    prefix: &'a str,
+    namespace: &'a str,
+    parent: Scope<'a>,
+}
+
+fn lookup<'a>(scope: &Scope<'a>, prefix: &str) -> Option<&'a str> {
+    let mut binding = scope.as_deref();
+    while let Some(b) = binding {
+        if b.prefix == prefix {
+            return Some(b.namespace);
+        }
+        binding = b.parent.as_deref();
+    }
+    None
+}
+
+fn is_namespace_declaration(name: &str) -> bool {
+    name == "xmlns" || name.starts_with("xmlns:")
+}
+
+/// A context node: an element, or the parent of the top-level nodes.
+#[derive(Clone)]
+struct Context<'a> {
+    element: Option<&'a RawXmlElement>,
+    /// Children of the top-level context.
+    top: Rc<(Vec<&'a RawXmlElement>, Vec<&'a str>)>,
+    /// Declarations in scope, including the element's own.
+    scope: Scope<'a>,
+}
+
+impl<'a> Context<'a> {
+    fn top(elements: Vec<&'a RawXmlElement>, texts: Vec<&'a str>) -> Self {
+        Self {
+            element: None,
+            top: Rc::new((elements, texts)),
+            scope: None,
+        }
+    }
+
+    fn element(element: &'a RawXmlElement, parent_scope: &Scope<'a>) -> Self {
+        let mut scope = parent_scope.clone();
+        for (name, value) in &element.attributes {
+            let prefix = if name == "xmlns" {
+                ""
+            } else if let Some(prefix) = name.strip_prefix("xmlns:") {
+                prefix
+            } else {
+                continue;
+            };
+            scope = Some(Rc::new(Binding {
+                prefix,
+                namespace: value,
+                parent: scope,
+            }));
+        }
+        Self {
+            element: Some(element),
+            top: Rc::default(),
+            scope,
+        }
+    }
+
+    fn id(&self) -> *const RawXmlElement {
+        self.element.map_or(std::ptr::null(), std::ptr::from_ref)
+    }
+
+    fn name(&self) -> &'a str {
+        self.element.map_or("", |e| e.name.as_str())
+    }
+
+    fn children(&self) -> Box<dyn Iterator<Item = &'a RawXmlElement> + '_> {
+        match self.element {
+            Some(element) => Box::new(element.children.iter().filter_map(|child| match child {
+                RawXmlNode::Element(e) => Some(e),
+                _ => None,
+            })),
+            None => Box::new(self.top.0.iter().copied()),
+        }
+    }
+
+    fn texts(&self) -> Box<dyn Iterator<Item = &'a str> + '_> {
+        match self.element {
+            Some(element) => Box::new(element.children.iter().filter_map(|child| match child {
+                RawXmlNode::Text(t) | RawXmlNode::CData(t) => Some(t.as_str()),
+                _ => None,
+            })),
+            None => Box::new(self.top.1.iter().copied()),
+        }
+    }
+
+    fn attributes(&self) -> impl Iterator<Item = (&'a str, &'a str)> {
+        self.element
+            .into_iter()
+            .flat_map(|e| &e.attributes)
+            .filter(|(name, _)| !is_namespace_declaration(name))
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+}
+
+/// The contexts and all their descendant elements, without duplicates.
+fn descendants_or_self(contexts: Vec<Context<'_>>) -> Vec<Context<'_>> {
+    let mut seen = HashSet::new();
+    let mut out = Vec::new();
+    let mut stack: Vec<Context> = contexts.into_iter().rev().collect();
+    while let Some(context) = stack.pop() {
+        if !seen.insert(context.id()) {
+            continue;
+        }
+        let children: Vec<_> = context
+            .children()
+            .map(|child| Context::element(child, &context.scope))
+            .collect();
+        stack.extend(children.into_iter().rev());
+        out.push(context);
+    }
+    out
+}
+
+/// Positions of nodes in document order, for sorting matches.
+#[derive(Default)]
+struct DocumentOrder {
+    positions: HashMap<*const u8, usize>,
+}
+
+impl DocumentOrder {
+    fn visit(&mut self, element: &RawXmlElement) {
+        self.positions
+            .insert(std::ptr::from_ref(element).cast(), self.positions.len());
+        for child in &element.children {
+            match child {
+                RawXmlNode::Element(e) => self.visit(e),
+                RawXmlNode::Text(t) | RawXmlNode::CData(t) => self.visit_text(t),
+                RawXmlNode::Comment(_) => {}
+            }
+        }
+    }
+
+    fn visit_text(&mut self, text: &str) {
+        self.positions.insert(text.as_ptr(), self.positions.len());
+    }
+
+    fn sort(&self, matches: &mut Vec<QueryMatch>) {
+        let key = |m: &QueryMatch| -> (usize, usize) {
+            match m {
+                QueryMatch::Element(e) => (self.position(std::ptr::from_ref(*e).cast()), 0),
+                QueryMatch::Attribute { element, name, .. } => (
+                    self.position(std::ptr::from_ref(*element).cast()),
+                    1 + element
+                        .attributes
+                        .iter()
+                        .position(|(n, _)| n.as_ptr() == name.as_ptr())
+                        .unwrap_or(0),
+                ),
+                QueryMatch::Text(t) => (self.position(t.as_ptr()), 0),
+            }
+        };
+        matches.sort_by_key(key);
+        matches.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    fn position(&self, ptr: *const u8) -> usize {
+        self.positions.get(&ptr).copied().unwrap_or(usize::MAX)
+    }
+}
+
+struct QueryParser<'e> {
+    expr: &'e str,
+    pos: usize,
+}
+
+impl QueryParser<'_> {
+    fn parse(mut self) -> Result<Query> {
+        let (absolute, mut axis) = if self.eat("//") {
+            (true, Axis::Descendant)
+        } else if self.eat("/") {
+            (true, Axis::Child)
+        } else {
+            (false, Axis::Child)
+        };
+        let mut steps = Vec::new();
+        loop {
+            if matches!(
+                steps.last(),
+                Some(Step {
+                    test: Test::Attribute(_) | Test::Text,
+                    ..
+                })
+            ) {
+                return Err(self.error("attribute and text() steps must come last"));
+            }
+            steps.push(self.step(axis)?);
+            if self.pos == self.expr.len() {
+                break;
+            }
+            axis = if self.eat("//") {
+                Axis::Descendant
+            } else if self.eat("/") {
+                Axis::Child
+            } else {
+                return Err(self.error("expected '/'"));
+            };
+        }
+        Ok(Query {
+            absolute,
+            steps,
+            namespaces: Vec::new(),
+        })
+    }
+
+    fn step(&mut self, axis: Axis) -> Result<Step> {
+        let test = if self.eat("text()") {
+            Test::Text
+        } else if self.eat("@") {
+            Test::Attribute(self.name_test()?)
+        } else if self.eat("..") {
+            return Err(self.error("parent steps are not supported"));
+        } else if self.eat(".") {
+            Test::Context
+        } else {
+            Test::Element(self.name_test()?)
+        };
+        let mut predicates = Vec::new();
+        while self.eat("[") {
+            if !matches!(test, Test::Element(_)) {
+                return Err(self.error("predicates are only allowed on element steps"));
+            }
+            self.skip_whitespace();
+            predicates.push(self.predicate()?);
+            self.skip_whitespace();
+            if !self.eat("]") {
+                return Err(self.error("expected ']'"));
+            }
+        }
+        Ok(Step {
+            axis,
+            test,
+            predicates,
+        })
+    }
+
+    fn predicate(&mut self) -> Result<Predicate> {
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        if !digits.is_empty() {
+            return match digits.parse() {
+                Ok(n) if n > 0 => Ok(Predicate::Position(n)),
+                _ => Err(self.error("positions start at 1")),
+            };
+        }
+        if self.eat("last()") {
+            return Ok(Predicate::Last);
+        }
+        if !self.eat("@") {
+            return Ok(Predicate::Child(self.name_test()?));
+        }
+        let test = self.name_test()?;
+        self.skip_whitespace();
+        let equal = if self.eat("!=") {
+            false
+        } else if self.eat("=") {
+            true
+        } else {
+            return Ok(Predicate::Attribute(test, None));
+        };
+        self.skip_whitespace();
+        let value = self.literal()?;
+        Ok(Predicate::Attribute(test, Some((equal, value))))
+    }
+
+    fn name_test(&mut self) -> Result<NameTest> {
+        if self.eat("*") {
+            return Ok(NameTest {
+                prefix: None,
+                local: None,
+            });
+        }
+        let name = self.name()?;
+        if !self.eat(":") {
+            return Ok(NameTest {
+                prefix: None,
+                local: Some(name),
+            });
+        }
+        let local = if self.eat("*") {
+            None
+        } else {
+            Some(self.name()?)
+        };
+        Ok(NameTest {
+            prefix: Some(name),
+            local,
+        })
+    }
+
+    fn name(&mut self) -> Result<String> {
+        let rest = &self.expr[self.pos..];
+        if !rest.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+            return Err(self.error("expected a name"));
+        }
+        Ok(self
+            .take_while(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'))
+            .to_string())
+    }
+
+    fn literal(&mut self) -> Result<String> {
+        let quote = match self.expr[self.pos..].chars().next() {
+            Some(q @ ('\'' | '"')) => q,
+            _ => return Err(self.error("expected a quoted value")),
+        };
+        self.pos += 1;
+        let value = self.take_while(|c| c != quote).to_string();
+        if !self.eat(&quote.to_string()) {
+            return Err(self.error("unterminated value"));
+        }
+        Ok(value)
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        let found = self.expr[self.pos..].starts_with(token);
+        if found {
+            self.pos += token.len();
+        }
+        found
+    }
+
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> &str {
+        let start = self.pos;
+        let rest = &self.expr[start..];
+        let len = rest.find(|c| !f(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &self.expr[start..self.pos]
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.take_while(char::is_whitespace);
+    }
+
+    fn error(&self, message: &str) -> Error {
+        Error::Invalid(format!(
+            "invalid query {:?} at offset {}: {}",
+            self.expr, self.pos, message
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCUMENT: &[u8] = br#"<w:document xmlns:w="urn:w" xmlns:a="urn:a" xmlns:r="urn:r">
+        <w:body>
+            <w:p><w:r><w:t>one</w:t></w:r></w:p>
+            <w:p w:rsidR="01"><w:r><w:drawing>
+                <a:graphic><a:blip r:embed="rId1"/></a:graphic>
+            </w:drawing></w:r><w:r><w:t>two</w:t></w:r></w:p>
+            <w:tbl><w:tr><w:tc><w:p><w:r><w:drawing>
+                <a:blip r:embed="rId2"/>
+            </w:drawing></w:r></w:p></w:tc></w:tr></w:tbl>
+        </w:body>
+    </w:document>"#;
+
+    fn document() -> RawXmlElement {
+        RawXmlElement::parse(DOCUMENT).expect("should parse")
+    }
+
+    fn query(expr: &str) -> Query {
+        Query::new(expr).expect("should parse query")
+    }
+
+    #[test]
+    fn test_child_steps() {
+        let doc = document();
+        assert_eq!(query("w:body/w:p").elements(&doc).len(), 2);
+        assert_eq!(query("/w:document/w:body/*").elements(&doc).len(), 3);
+        assert!(query("/w:body").elements(&doc).is_empty());
+        assert_eq!(query(".").elements(&doc)[0].name, "w:document");
+    }
+
+    #[test]
+    fn test_descendant_steps() {
+        let doc = document();
+        assert_eq!(query("//a:blip/@r:embed").values(&doc), ["rId1", "rId2"]);
+        assert_eq!(query("//blip/@embed").values(&doc), ["rId1", "rId2"]);
+        assert_eq!(query("w:body//w:p").elements(&doc).len(), 3);
+        assert_eq!(query("//w:t/text()").values(&doc), ["one", "two"]);
+        assert_eq!(query("//w:document").elements(&doc).len(), 1);
+    }
+
+    #[test]
+    fn test_predicates() {
+        let doc = document();
+        let p = query("//w:p[@w:rsidR='01']").elements(&doc);
+        assert_eq!(p.len(), 1);
+        assert_eq!(query("//w:p[@w:rsidR]").elements(&doc), p);
+        assert_eq!(query("//w:p[@w:rsidR!='02']").elements(&doc), p);
+        assert!(query("//w:p[@w:rsidR='02']").elements(&doc).is_empty());
+        assert_eq!(query("w:body/w:p[2]").elements(&doc), p);
+        assert_eq!(query("w:body/w:p[last()]").elements(&doc), p);
+        assert_eq!(query("w:body/w:p[@w:rsidR][1]").elements(&doc), p);
+        assert_eq!(query("//w:r[w:t]/w:t/text()").values(&doc), ["one", "two"]);
+        // Positions count per parent
+        assert_eq!(query("//w:r[1]").elements(&doc).len(), 3);
+    }
+
+    #[test]
+    fn test_namespace_binding() {
+        let xml =
+            br#"<root xmlns="urn:default" xmlns:x="urn:other"><item/><x:item x:id="1"/></root>"#;
+        let root = RawXmlElement::parse(xml).unwrap();
+        let default = query("d:item").with_namespace("d", "urn:default");
+        assert_eq!(default.elements(&root)[0].name, "item");
+        let other = query("o:item/@o:id").with_namespace("o", "urn:other");
+        assert_eq!(other.values(&root), ["1"]);
+        assert!(query("o:item").elements(&root).is_empty());
+        assert_eq!(query("*").elements(&root).len(), 2);
+        assert_eq!(query("x:*").elements(&root).len(), 1);
+    }
+
+    #[test]
+    fn test_attributes_skip_declarations() {
+        let root = RawXmlElement::parse(br#"<a xmlns:x="urn:x" x:v="1" w="2"/>"#).unwrap();
+        assert_eq!(query("@*").values(&root), ["1", "2"]);
+    }
+
+    #[test]
+    fn test_select_nodes() {
+        let doc = document();
+        let body = query("w:body").elements(&doc)[0];
+        let matches = query("w:p//a:blip/@r:embed").select_nodes(&body.children);
+        assert_eq!(matches.len(), 1);
+        assert!(matches!(
+            matches[0],
+            QueryMatch::Attribute {
+                name: "r:embed",
+                value: "rId1",
+                ..
+            }
+        ));
+    }
+
+    /// A typed tree as generated code would implement it.
+    struct Run {
+        style: Option<String>,
+        text: Vec<String>,
+        extra_children: Vec<RawXmlNode>,
+    }
+
+    impl QueryTree for Run {
+        fn visit_element<'a>(&'a self, name: &str, visitor: &mut dyn TreeVisitor<'a>) {
+            visitor.element(name, self);
+        }
+
+        fn visit_content<'a>(&'a self, visitor: &mut dyn TreeVisitor<'a>) {
+            if let Some(style) = &self.style {
+                visitor.attribute("w:style", style);
+            }
+            for text in &self.text {
+                text.visit_element("w:t", visitor);
+            }
+            for child in &self.extra_children {
+                visitor.raw(child);
+            }
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    struct Body {
+        runs: Vec<Run>,
+    }
+
+    impl QueryTree for Body {
+        fn visit_element<'a>(&'a self, name: &str, visitor: &mut dyn TreeVisitor<'a>) {
+            visitor.element(name, self);
+        }
+
+        fn visit_content<'a>(&'a self, visitor: &mut dyn TreeVisitor<'a>) {
+            for run in &self.runs {
+                run.visit_element("w:r", visitor);
+            }
+        }
+
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+
+    fn body() -> Body {
+        let drawing = RawXmlElement::parse(br#"<w:drawing><a:blip r:embed="rId1"/></w:drawing>"#)
+            .expect("should parse");
+        Body {
+            runs: vec![
+                Run {
+                    style: Some("Strong".into()),
+                    text: vec!["one".into(), "two".into()],
+                    extra_children: Vec::new(),
+                },
+                Run {
+                    style: None,
+                    text: Vec::new(),
+                    extra_children: vec![RawXmlNode::Element(drawing)],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_select_tree() {
+        let body = body();
+        let runs = query("w:r").select_tree(&body);
+        assert_eq!(runs.len(), 2);
+        let first = runs[0].downcast::<Run>().expect("typed run");
+        assert!(std::ptr::eq(first, &body.runs[0]));
+        assert!(runs[1].downcast::<Body>().is_none());
+
+        assert_eq!(query("//w:t/text()").tree_values(&body), ["one", "two"]);
+        assert_eq!(
+            query("w:r[@w:style='Strong']/w:t[2]/text()").tree_values(&body),
+            ["two"]
+        );
+        let texts = query("//w:t").select_tree(&body);
+        assert!(std::ptr::eq(
+            texts[1].downcast::<String>().unwrap(),
+            &body.runs[0].text[1]
+        ));
+    }
+
+    #[test]
+    fn test_select_tree_raw_children() {
+        let body = body();
+        assert_eq!(query("//a:blip/@r:embed").tree_values(&body), ["rId1"]);
+        let blips = query("w:r/w:drawing/a:blip").select_tree(&body);
+        let RawXmlNode::Element(drawing) = &body.runs[1].extra_children[0] else {
+            unreachable!()
+        };
+        let RawXmlNode::Element(blip) = &drawing.children[0] else {
+            unreachable!()
+        };
+        assert!(std::ptr::eq(blips[0].as_raw().expect("raw blip"), blip));
+        assert!(blips[0].downcast::<Run>().is_none());
+    }
+
+    #[test]
+    fn test_lazy_query() {
+        static BLIPS: LazyQuery = LazyQuery::new("//a:blip/@r:embed");
+        assert_eq!(BLIPS.values(&document()), ["rId1", "rId2"]);
+    }
+
+    #[test]
+    fn test_invalid_queries() {
+        for expr in [
+            "", "/", "a/", "@x/b", "text()/a", "a[", "a[0]", "a[@x='1]", "..", "@x[1]",
+        ] {
+            assert!(Query::new(expr).is_err(), "{expr:?} should not parse");
+        }
+        assert!("//a:b[@c='d']/text()".parse::<Query>().is_ok());
+    }
+}
@@ -3,18 +3,23 @@
 //! This module provides types for storing unparsed XML elements,
 //! allowing documents to survive read→write cycles without losing
 //! features we don't explicitly understand.
+//!
+//! With the `serde` feature, nodes serialize field by field, and
+//! [`RawXmlNode`] as a single-key object naming its kind, e.g.
+//! `{"position": 2, "node": {"text": "x"}}` for a [`PositionedNode`].
 
 use quick_xml::events::{BytesCData, BytesEnd, BytesStart, BytesText, Event};
-use quick_xml::{Reader, Writer};
+use quick_xml::{NsReader, Writer};
 use std::io::{BufRead, Write};
 
-use crate::{Error, FromXml, ParseError, Result};
+use crate::{Error, FromXml, ParseError, Result, SerializeError, ToXml};
 
 /// A raw XML node with its original position for correct round-trip ordering.
 ///
 /// When unknown elements are captured during parsing, we store their position
 /// among siblings so they can be interleaved correctly during serialization.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PositionedNode {
     /// Original position among sibling elements (0-indexed).
     pub position: usize,
@@ -34,6 +39,7 @@ impl PositionedNode {
 /// When unknown attributes are captured during parsing, we store their position
 /// among sibling attributes so they can be serialized in the original order.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PositionedAttr {
     /// Original position among sibling attributes (0-indexed).
     pub position: usize,
@@ -56,6 +62,8 @@ impl PositionedAttr {
 
 /// A raw XML node that can be preserved during round-trip.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum RawXmlNode {
     /// An XML element with name, attributes, and children.
     Element(RawXmlElement),
@@ -69,6 +77,7 @@ pub enum RawXmlNode {
 
 /// A raw XML element with its name, attributes, and children preserved.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RawXmlElement {
     /// The full element name (including namespace prefix if present).
     pub name: String,
@@ -91,10 +100,48 @@ impl RawXmlElement {
         }
     }
 
+    /// Parse the root element of an XML document.
+    ///
+    /// ```
+    /// use ooxml_xml::RawXmlElement;
+    ///
+    /// let root = RawXmlElement::parse(br#"<?xml version="1.0"?><a x="1"><b/></a>"#).unwrap();
+    /// assert_eq!(root.name, "a");
+    /// assert_eq!(root.children.len(), 1);
+    /// ```
+    pub fn parse(xml: &[u8]) -> Result<Self> {
+        let mut reader = NsReader::from_reader(xml);
+        let mut buf = Vec::new();
+        loop {
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) => return Self::from_reader(&mut reader, &e),
+                Event::Empty(e) => return Ok(Self::from_empty(&e)),
+                Event::Eof => return Err(Error::Invalid("no root element".to_string())),
+                _ => {}
+            }
+            buf.clear();
+        }
+    }
+
+    /// Capture a value as it serializes, as a `tag` element.
+    ///
+    /// This gives generated types the same raw view as preserved markup,
+    /// e.g. to compare them with [`compare_elements`](crate::compare_elements).
+    /// The value is serialized and parsed again, so this costs as much as
+    /// writing it out.
+    pub fn from_value<T: ToXml + ?Sized>(
+        value: &T,
+        tag: &str,
+    ) -> std::result::Result<Self, SerializeError> {
+        let mut writer = Writer::new(Vec::new());
+        value.write_element(tag, &mut writer)?;
+        Ok(Self::parse(&writer.into_inner())?)
+    }
+
     /// Parse a raw XML element from a reader, starting after the opening tag.
     ///
     /// The `start` parameter should be the BytesStart event that opened this element.
-    pub fn from_reader<R: BufRead>(reader: &mut Reader<R>, start: &BytesStart) -> Result<Self> {
+    pub fn from_reader<R: BufRead>(reader: &mut NsReader<R>, start: &BytesStart) -> Result<Self> {
         let name = String::from_utf8_lossy(start.name().as_ref()).to_string();
 
         let attributes = start
@@ -141,7 +188,11 @@ impl RawXmlElement {
                 }
                 Ok(Event::GeneralRef(e)) => {
                     let entity_name = e.decode().unwrap_or_default();
-                    if let Some(resolved) = quick_xml::escape::resolve_xml_entity(&entity_name) {
+                    let char_ref = e.resolve_char_ref().ok().flatten().map(String::from);
+                    let resolved = char_ref
+                        .as_deref()
+                        .or_else(|| quick_xml::escape::resolve_xml_entity(&entity_name));
+                    if let Some(resolved) = resolved {
                         // Append to last text node if possible, otherwise create new one
                         if let Some(RawXmlNode::Text(last)) = element.children.last_mut() {
                             last.push_str(resolved);
@@ -160,10 +211,8 @@ impl RawXmlElement {
                     let text = String::from_utf8_lossy(&e).to_string();
                     element.children.push(RawXmlNode::Comment(text));
                 }
-                Ok(Event::End(e)) => {
-                    if e.name().as_ref() == target_name {
-                        break;
-                    }
+                Ok(Event::End(e)) if e.name().as_ref() == target_name => {
+                    break;
                 }
                 Ok(Event::Eof) => {
                     return Err(Error::Invalid(format!(
@@ -217,7 +266,7 @@ impl RawXmlElement {
     /// ```
     pub fn parse_as<T: FromXml>(&self) -> std::result::Result<T, ParseError> {
         let streaming_reader = RawXmlStreamReader::new(self);
-        let mut reader = Reader::from_reader(streaming_reader);
+        let mut reader = NsReader::from_reader(streaming_reader);
         let mut buf = Vec::new();
 
         loop {
@@ -456,7 +505,7 @@ mod tests {
     #[test]
     fn test_parse_simple_element() {
         let xml = r#"<w:test attr="value">content</w:test>"#;
-        let mut reader = Reader::from_str(xml);
+        let mut reader = NsReader::from_str(xml);
         let mut buf = Vec::new();
 
         if let Ok(Event::Start(e)) = reader.read_event_into(&mut buf) {
@@ -478,7 +527,7 @@ mod tests {
     #[test]
     fn test_parse_nested_elements() {
         let xml = r#"<parent><child1/><child2>text</child2></parent>"#;
-        let mut reader = Reader::from_str(xml);
+        let mut reader = NsReader::from_str(xml);
         let mut buf = Vec::new();
 
         if let Ok(Event::Start(e)) = reader.read_event_into(&mut buf) {
@@ -491,7 +540,7 @@ mod tests {
     #[test]
     fn test_roundtrip() {
         let xml = r#"<w:test attr="value"><w:child>text</w:child></w:test>"#;
-        let mut reader = Reader::from_str(xml);
+        let mut reader = NsReader::from_str(xml);
         let mut buf = Vec::new();
 
         if let Ok(Event::Start(e)) = reader.read_event_into(&mut buf) {
@@ -511,7 +560,7 @@ mod tests {
         use std::io::Read;
 
         let xml = r#"<parent attr="val"><child>text</child></parent>"#;
-        let mut reader = Reader::from_str(xml);
+        let mut reader = NsReader::from_str(xml);
         let mut buf = Vec::new();
 
         if let Ok(Event::Start(e)) = reader.read_event_into(&mut buf) {
@@ -552,7 +601,7 @@ mod tests {
         // Verify that XML entity references (&amp;, &lt;, etc.) survive
         // the parse→store→re-serialize roundtrip through RawXmlElement.
         let xml = r#"<root><t>A &amp; B &lt; C &gt; D &quot;E&quot; &apos;F&apos;</t></root>"#;
-        let mut reader = Reader::from_str(xml);
+        let mut reader = NsReader::from_str(xml);
         let mut buf = Vec::new();
 
         if let Ok(Event::Start(e)) = reader.read_event_into(&mut buf) {
@@ -0,0 +1,510 @@
+//! Output options for serializing XML.
+//!
+//! Serializers write compact XML with attributes in field order, which is what
+//! Office applications expect but makes for poor diffs: a document is a single
+//! line, and attributes captured in `extra_attrs` come out in hash order.
+//! [`SerializeOptions`] controls indentation, the XML declaration, where
+//! namespace declarations go, attribute order and how empty elements are
+//! written, so that the same document always serializes to the same bytes.
+//!
+//! Options are applied to a value with [`ToXml::write_element_with`], or to an
+//! already serialized part with [`format_xml`].
+
+use quick_xml::Reader;
+use quick_xml::Writer;
+use quick_xml::events::attributes::Attribute;
+use quick_xml::events::{BytesCData, BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::name::QName;
+use std::collections::HashMap;
+use std::io::Write;
+
+use crate::{Error, RawXmlElement, RawXmlNode, Result, SerializeError, ToXml};
+
+/// Where namespace declarations are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NamespaceDeclarations {
+    /// On the elements the serializer put them on.
+    #[default]
+    AsWritten,
+    /// On the root element.
+    ///
+    /// Prefixed declarations are moved to the root unless the prefix is bound
+    /// to different URIs in different places. Default namespace declarations
+    /// (`xmlns="..."`) stay where they are, since moving them would change the
+    /// namespace of unprefixed elements.
+    Root,
+}
+
+/// How elements without content are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelfClosing {
+    /// As the serializer wrote them.
+    #[default]
+    AsWritten,
+    /// Always as `<a/>`.
+    Always,
+    /// Always as `<a></a>`.
+    Never,
+}
+
+/// Options for serializing XML.
+///
+/// The default writes compact XML exactly as the serializers produce it;
+/// [`SerializeOptions::pretty`] gives stable, diffable output:
+///
+/// ```
+/// use ooxml_xml::{SerializeOptions, format_xml};
+///
+/// let xml = br#"<a><b xmlns:x="urn:x" y="2" x:z="1"><c/></b></a>"#;
+/// let pretty = format_xml(xml, &SerializeOptions::pretty()).unwrap();
+/// assert_eq!(
+///     String::from_utf8(pretty).unwrap(),
+///     "<a xmlns:x=\"urn:x\">\n  <b x:z=\"1\" y=\"2\">\n    <c/>\n  </b>\n</a>"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SerializeOptions {
+    /// Spaces to indent each level of nested elements by, or 0 for compact
+    /// output.
+    ///
+    /// Only elements whose content is elements alone are indented; text and
+    /// mixed content are written unchanged, since whitespace there is
+    /// significant.
+    pub indent: usize,
+    /// The `standalone` attribute of the XML declaration, or `None` to leave
+    /// it out.
+    pub standalone: Option<bool>,
+    /// Where namespace declarations are written.
+    pub namespace_declarations: NamespaceDeclarations,
+    /// Write attributes sorted by name, namespace declarations first.
+    pub sort_attributes: bool,
+    /// How elements without content are written.
+    pub self_closing: SelfClosing,
+}
+
+impl Default for SerializeOptions {
+    fn default() -> Self {
+        Self {
+            indent: 0,
+            standalone: Some(true),
+            namespace_declarations: NamespaceDeclarations::AsWritten,
+            sort_attributes: false,
+            self_closing: SelfClosing::AsWritten,
+        }
+    }
+}
+
+impl SerializeOptions {
+    /// Compact output as written by the serializers (the default).
+    pub fn compact() -> Self {
+        Self::default()
+    }
+
+    /// Indented, deterministic output for reviewing and diffing parts.
+    ///
+    /// Indents by two spaces, declares namespaces on the root element, sorts
+    /// attributes and writes empty elements as `<a/>`.
+    pub fn pretty() -> Self {
+        Self {
+            indent: 2,
+            namespace_declarations: NamespaceDeclarations::Root,
+            sort_attributes: true,
+            self_closing: SelfClosing::Always,
+            ..Self::default()
+        }
+    }
+
+    /// Whether elements are written exactly as the serializers produce them.
+    pub(crate) fn is_verbatim(&self) -> bool {
+        self.indent == 0
+            && self.namespace_declarations == NamespaceDeclarations::AsWritten
+            && !self.sort_attributes
+            && self.self_closing == SelfClosing::AsWritten
+    }
+
+    /// The XML declaration, including the line break that follows it.
+    fn declaration(&self) -> String {
+        let standalone = match self.standalone {
+            Some(true) => " standalone=\"yes\"",
+            Some(false) => " standalone=\"no\"",
+            None => "",
+        };
+        format!("<?xml version=\"1.0\" encoding=\"UTF-8\"{standalone}?>\n")
+    }
+
+    /// Write an element tree with these options.
+    pub(crate) fn write_raw<W: Write>(
+        &self,
+        mut element: RawXmlElement,
+        writer: &mut Writer<W>,
+    ) -> Result<()> {
+        if self.namespace_declarations == NamespaceDeclarations::Root {
+            hoist_namespaces(&mut element);
+        }
+        self.write_node_element(&element, writer, 0)
+    }
+
+    fn write_node_element<W: Write>(
+        &self,
+        element: &RawXmlElement,
+        writer: &mut Writer<W>,
+        depth: usize,
+    ) -> Result<()> {
+        let mut attributes: Vec<&(String, String)> = element.attributes.iter().collect();
+        if self.sort_attributes {
+            attributes.sort_by_key(|(name, _)| (!is_namespace_declaration(name), name.as_str()));
+        }
+        let mut start = BytesStart::new(element.name.as_str());
+        for (name, value) in attributes {
+            // Values are kept escaped as read
+            start.push_attribute(Attribute {
+                key: QName(name.as_bytes()),
+                value: value.as_bytes().into(),
+            });
+        }
+
+        let empty = match self.self_closing {
+            SelfClosing::AsWritten => element.self_closing && element.children.is_empty(),
+            SelfClosing::Always => element.children.is_empty(),
+            SelfClosing::Never => false,
+        };
+        if empty {
+            writer.write_event(Event::Empty(start))?;
+            return Ok(());
+        }
+        writer.write_event(Event::Start(start))?;
+
+        let indent = self.indent > 0 && is_element_only(element);
+        for child in &element.children {
+            if indent {
+                if matches!(child, RawXmlNode::Text(_)) {
+                    continue;
+                }
+                self.write_newline(writer, depth + 1)?;
+            }
+            match child {
+                RawXmlNode::Element(child) => self.write_node_element(child, writer, depth + 1)?,
+                RawXmlNode::Text(text) => writer.write_event(Event::Text(BytesText::new(text)))?,
+                RawXmlNode::CData(text) => {
+                    writer.write_event(Event::CData(BytesCData::new(text)))?
+                }
+                RawXmlNode::Comment(text) => {
+                    writer.write_event(Event::Comment(BytesText::from_escaped(text.as_str())))?
+                }
+            }
+        }
+        if indent {
+            self.write_newline(writer, depth)?;
+        }
+
+        writer.write_event(Event::End(BytesEnd::new(element.name.as_str())))?;
+        Ok(())
+    }
+
+    fn write_newline<W: Write>(&self, writer: &mut Writer<W>, depth: usize) -> Result<()> {
+        let whitespace = format!("\n{}", " ".repeat(depth * self.indent));
+        writer.write_event(Event::Text(BytesText::from_escaped(whitespace)))?;
+        Ok(())
+    }
+}
+
+/// Reformat a serialized part with the given options.
+///
+/// The XML declaration, if the part has one, is rewritten to match
+/// [`SerializeOptions::standalone`]. Comments and processing instructions
+/// outside the root element are dropped.
+pub fn format_xml(xml: &[u8], options: &SerializeOptions) -> Result<Vec<u8>> {
+    let mut reader = Reader::from_reader(xml);
+    let mut buf = Vec::new();
+    let mut has_declaration = false;
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Decl(_) => has_declaration = true,
+            Event::Start(_) | Event::Empty(_) => break,
+            Event::Eof => return Err(Error::Invalid("no root element".to_string())),
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    let root = RawXmlElement::parse(xml)?;
+    let mut out = Vec::with_capacity(xml.len());
+    if has_declaration {
+        out.extend_from_slice(options.declaration().as_bytes());
+    }
+    let mut writer = Writer::new(out);
+    options.write_raw(root, &mut writer)?;
+    Ok(writer.into_inner())
+}
+
+/// Serialize a value as a complete part: an XML declaration followed by a
+/// `tag` element written with the given options.
+pub fn to_xml_part<T: ToXml + ?Sized>(
+    value: &T,
+    tag: &str,
+    options: &SerializeOptions,
+) -> std::result::Result<Vec<u8>, SerializeError> {
+    let mut writer = Writer::new(options.declaration().into_bytes());
+    value.write_element_with(tag, &mut writer, options)?;
+    Ok(writer.into_inner())
+}
+
+/// Read the attributes of a part's root element, with values unescaped.
+///
+/// Namespace declarations and `mc:Ignorable` live here and are not modeled by
+/// every generated type, so editors restore them when a part parsed into
+/// generated types is written back. Returns an empty list if the part has no
+/// root element.
+///
+/// ```
+/// use ooxml_xml::root_attributes;
+///
+/// let attrs = root_attributes(br#"<?xml version="1.0"?><a xmlns:x="urn:x" v="&amp;"><b/></a>"#);
+/// assert_eq!(attrs, [("xmlns:x".into(), "urn:x".into()), ("v".into(), "&".into())]);
+/// ```
+pub fn root_attributes(xml: &[u8]) -> Vec<(String, String)> {
+    let mut reader = Reader::from_reader(xml);
+    let mut buf = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(e)) | Ok(Event::Empty(e)) => {
+                return e
+                    .attributes()
+                    .filter_map(|a| a.ok())
+                    .filter_map(|a| {
+                        let key = String::from_utf8_lossy(a.key.as_ref()).into_owned();
+                        let value = a.unescape_value().ok()?.into_owned();
+                        Some((key, value))
+                    })
+                    .collect();
+            }
+            Ok(Event::Eof) | Err(_) => return Vec::new(),
+            _ => {}
+        }
+        buf.clear();
+    }
+}
+
+fn is_namespace_declaration(name: &str) -> bool {
+    name == "xmlns" || name.starts_with("xmlns:")
+}
+
+/// Whether an element's content is elements (and comments) separated by
+/// whitespace at most, so that it can be indented.
+fn is_element_only(element: &RawXmlElement) -> bool {
+    let preserve = element
+        .attributes
+        .iter()
+        .any(|(name, value)| name == "xml:space" && value == "preserve");
+    !preserve
+        && element
+            .children
+            .iter()
+            .any(|child| matches!(child, RawXmlNode::Element(_)))
+        && element.children.iter().all(|child| match child {
+            RawXmlNode::Text(text) => text.trim().is_empty(),
+            RawXmlNode::CData(_) => false,
+            _ => true,
+        })
+}
+
+/// Move prefixed namespace declarations to the root element, except for
+/// prefixes bound to more than one URI.
+fn hoist_namespaces(root: &mut RawXmlElement) {
+    let mut bindings: HashMap<String, Option<String>> = HashMap::new();
+    collect_declarations(root, &mut bindings);
+    let hoisted: HashMap<String, String> = bindings
+        .into_iter()
+        .filter_map(|(name, uri)| Some((name, uri?)))
+        .collect();
+
+    for child in &mut root.children {
+        if let RawXmlNode::Element(child) = child {
+            remove_declarations(child, &hoisted);
+        }
+    }
+    let mut missing: Vec<(String, String)> = hoisted
+        .into_iter()
+        .filter(|(name, _)| !root.attributes.iter().any(|(n, _)| n == name))
+        .collect();
+    missing.sort();
+    root.attributes.extend(missing);
+}
+
+/// Record the URI of every prefixed declaration, or `None` for prefixes with
+/// conflicting bindings.
+fn collect_declarations(element: &RawXmlElement, bindings: &mut HashMap<String, Option<String>>) {
+    for (name, value) in &element.attributes {
+        if name.starts_with("xmlns:") {
+            bindings
+                .entry(name.clone())
+                .and_modify(|uri| {
+                    if uri.as_deref() != Some(value.as_str()) {
+                        *uri = None;
+                    }
+                })
+                .or_insert_with(|| Some(value.clone()));
+        }
+    }
+    for child in &element.children {
+        if let RawXmlNode::Element(child) = child {
+            collect_declarations(child, bindings);
+        }
+    }
+}
+
+fn remove_declarations(element: &mut RawXmlElement, hoisted: &HashMap<String, String>) {
+    element
+        .attributes
+        .retain(|(name, _)| !hoisted.contains_key(name));
+    for child in &mut element.children {
+        if let RawXmlNode::Element(child) = child {
+            remove_declarations(child, hoisted);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(xml: &str, options: &SerializeOptions) -> String {
+        String::from_utf8(format_xml(xml.as_bytes(), options).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_compact_is_verbatim() {
+        let xml = r#"<a x="1"><b/><c></c><t xml:space="preserve"> a &amp; b </t></a>"#;
+        assert_eq!(format(xml, &SerializeOptions::compact()), xml);
+    }
+
+    #[test]
+    fn test_declaration() {
+        let xml = "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\r\n<a/>";
+        assert_eq!(
+            format(xml, &SerializeOptions::default()),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n<a/>"
+        );
+        let options = SerializeOptions {
+            standalone: None,
+            ..SerializeOptions::default()
+        };
+        assert_eq!(
+            format(xml, &options),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<a/>"
+        );
+        // Parts without a declaration do not gain one
+        assert_eq!(format("<a/>", &options), "<a/>");
+    }
+
+    #[test]
+    fn test_indent_skips_text_content() {
+        let xml = "<w:p><w:r><w:t xml:space=\"preserve\"> x </w:t></w:r><w:r><w:t>a<!--c-->b</w:t></w:r></w:p>";
+        let options = SerializeOptions {
+            indent: 1,
+            ..SerializeOptions::default()
+        };
+        assert_eq!(
+            format(xml, &options),
+            "<w:p>\n <w:r>\n  <w:t xml:space=\"preserve\"> x </w:t>\n </w:r>\n <w:r>\n  <w:t>a<!--c-->b</w:t>\n </w:r>\n</w:p>"
+        );
+    }
+
+    #[test]
+    fn test_indent_replaces_existing_whitespace() {
+        let xml = "<a>\n\t<b/>\n\t\t<c/>\n</a>";
+        let options = SerializeOptions {
+            indent: 2,
+            ..SerializeOptions::default()
+        };
+        assert_eq!(format(xml, &options), "<a>\n  <b/>\n  <c/>\n</a>");
+    }
+
+    #[test]
+    fn test_sort_attributes() {
+        let xml = r#"<a z="1" xmlns:b="urn:b" b:y="2" xmlns="urn:a" a="3"/>"#;
+        let options = SerializeOptions {
+            sort_attributes: true,
+            ..SerializeOptions::default()
+        };
+        assert_eq!(
+            format(xml, &options),
+            r#"<a xmlns="urn:a" xmlns:b="urn:b" a="3" b:y="2" z="1"/>"#
+        );
+    }
+
+    #[test]
+    fn test_attribute_values_stay_escaped() {
+        let xml = r#"<a v="&quot;x&quot; &amp; &lt;y&gt;"/>"#;
+        assert_eq!(format(xml, &SerializeOptions::pretty()), xml);
+    }
+
+    #[test]
+    fn test_hoist_namespaces() {
+        let xml = r#"<r xmlns="urn:r"><a xmlns:x="urn:x" xmlns="urn:d"/><b xmlns:x="urn:x" xmlns:y="urn:y1"/><c xmlns:y="urn:y2"/></r>"#;
+        let options = SerializeOptions {
+            namespace_declarations: NamespaceDeclarations::Root,
+            ..SerializeOptions::default()
+        };
+        assert_eq!(
+            format(xml, &options),
+            r#"<r xmlns="urn:r" xmlns:x="urn:x"><a xmlns="urn:d"/><b xmlns:y="urn:y1"/><c xmlns:y="urn:y2"/></r>"#
+        );
+    }
+
+    #[test]
+    fn test_self_closing() {
+        let xml = "<a><b/><c></c></a>";
+        let always = SerializeOptions {
+            self_closing: SelfClosing::Always,
+            ..SerializeOptions::default()
+        };
+        let never = SerializeOptions {
+            self_closing: SelfClosing::Never,
+            ..SerializeOptions::default()
+        };
+        assert_eq!(format(xml, &always), "<a><b/><c/></a>");
+        assert_eq!(format(xml, &never), "<a><b></b><c></c></a>");
+    }
+
+    struct Item;
+
+    impl ToXml for Item {
+        fn write_attrs<'a>(&self, mut start: BytesStart<'a>) -> BytesStart<'a> {
+            start.push_attribute(("z", "1"));
+            start.push_attribute(("y", "2"));
+            start
+        }
+
+        fn write_children<W: Write>(
+            &self,
+            writer: &mut Writer<W>,
+        ) -> std::result::Result<(), SerializeError> {
+            writer.write_event(Event::Empty(BytesStart::new("b")))?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_write_element_with() {
+        let mut writer = Writer::new(Vec::new());
+        Item.write_element_with("a", &mut writer, &SerializeOptions::compact())
+            .unwrap();
+        assert_eq!(writer.into_inner(), br#"<a z="1" y="2"><b/></a>"#);
+
+        let part = to_xml_part(&Item, "a", &SerializeOptions::pretty()).unwrap();
+        assert_eq!(
+            String::from_utf8(part).unwrap(),
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\n<a y=\"2\" z=\"1\">\n  <b/>\n</a>"
+        );
+    }
+
+    #[test]
+    fn test_character_references_survive() {
+        let xml = "<a><t>x&#10;y&#x41;</t></a>";
+        assert_eq!(
+            format(xml, &SerializeOptions::pretty()),
+            "<a>\n  <t>x\nyA</t>\n</a>"
+        );
+    }
+}
@@ -0,0 +1,299 @@
+//! ISO/IEC 29500 conformance classes.
+//!
+//! Documents saved as "Strict Open XML" use `http://purl.oclc.org/ooxml/...`
+//! namespaces and relationship types where Transitional documents use
+//! `http://schemas.openxmlformats.org/...`. Markup is otherwise the same for the
+//! features this library supports. Package-level namespaces (content types,
+//! relationships, core properties) are shared by both classes.
+//!
+//! ISO/IEC 29500-1:2016, §8 and Annex A.
+
+use crate::error::Result;
+use quick_xml::events::attributes::Attribute;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::{Reader, Writer};
+use std::borrow::Cow;
+
+/// Namespace pairs as (Transitional, Strict).
+const NAMESPACES: &[(&str, &str)] = &[
+    (
+        "http://schemas.openxmlformats.org/wordprocessingml/2006/main",
+        "http://purl.oclc.org/ooxml/wordprocessingml/main",
+    ),
+    (
+        "http://schemas.openxmlformats.org/spreadsheetml/2006/main",
+        "http://purl.oclc.org/ooxml/spreadsheetml/main",
+    ),
+    (
+        "http://schemas.openxmlformats.org/presentationml/2006/main",
+        "http://purl.oclc.org/ooxml/presentationml/main",
+    ),
+    (
+        "http://schemas.openxmlformats.org/drawingml/2006/main",
+        "http://purl.oclc.org/ooxml/drawingml/main",
+    ),
+    (
+        "http://schemas.openxmlformats.org/drawingml/2006/chart",
+        "http://purl.oclc.org/ooxml/drawingml/chart",
+    ),
+    (
+        "http://schemas.openxmlformats.org/drawingml/2006/chartDrawing",
+        "http://purl.oclc.org/ooxml/drawingml/chartDrawing",
+    ),
+    (
+        "http://schemas.openxmlformats.org/drawingml/2006/diagram",
+        "http://purl.oclc.org/ooxml/drawingml/diagram",
+    ),
+    (
+        "http://schemas.openxmlformats.org/drawingml/2006/picture",
+        "http://purl.oclc.org/ooxml/drawingml/picture",
+    ),
+    (
+        "http://schemas.openxmlformats.org/drawingml/2006/lockedCanvas",
+        "http://purl.oclc.org/ooxml/drawingml/lockedCanvas",
+    ),
+    (
+        "http://schemas.openxmlformats.org/drawingml/2006/compatibility",
+        "http://purl.oclc.org/ooxml/drawingml/compatibility",
+    ),
+    (
+        "http://schemas.openxmlformats.org/drawingml/2006/wordprocessingDrawing",
+        "http://purl.oclc.org/ooxml/drawingml/wordprocessingDrawing",
+    ),
+    (
+        "http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing",
+        "http://purl.oclc.org/ooxml/drawingml/spreadsheetDrawing",
+    ),
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships",
+        "http://purl.oclc.org/ooxml/officeDocument/relationships",
+    ),
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/math",
+        "http://purl.oclc.org/ooxml/officeDocument/math",
+    ),
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/sharedTypes",
+        "http://purl.oclc.org/ooxml/officeDocument/sharedTypes",
+    ),
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/extended-properties",
+        "http://purl.oclc.org/ooxml/officeDocument/extendedProperties",
+    ),
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/custom-properties",
+        "http://purl.oclc.org/ooxml/officeDocument/customProperties",
+    ),
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes",
+        "http://purl.oclc.org/ooxml/officeDocument/docPropsVTypes",
+    ),
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/customXml",
+        "http://purl.oclc.org/ooxml/officeDocument/customXml",
+    ),
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/bibliography",
+        "http://purl.oclc.org/ooxml/officeDocument/bibliography",
+    ),
+    (
+        "http://schemas.openxmlformats.org/schemaLibrary/2006/main",
+        "http://purl.oclc.org/ooxml/schemaLibrary/main",
+    ),
+];
+
+/// Prefix of Transitional relationship types.
+const TRANSITIONAL_REL_PREFIX: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/";
+
+/// Prefix of Strict relationship types.
+const STRICT_REL_PREFIX: &str = "http://purl.oclc.org/ooxml/officeDocument/relationships/";
+
+/// Relationship type names that differ between the classes, as (Transitional, Strict).
+const RENAMED_REL_TYPES: &[(&str, &str)] = &[
+    ("extended-properties", "extendedProperties"),
+    ("custom-properties", "customProperties"),
+];
+
+/// Common prefix of all Strict namespaces and relationship types.
+const STRICT_URI_PREFIX: &str = "http://purl.oclc.org/ooxml/";
+
+/// The conformance class of a document (ISO/IEC 29500-1, §2).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Conformance {
+    /// Transitional conformance, the format written by default.
+    #[default]
+    Transitional,
+    /// Strict conformance.
+    Strict,
+}
+
+impl Conformance {
+    /// Determine the conformance class a namespace or relationship type URI belongs to.
+    pub fn of_uri(uri: &str) -> Self {
+        if uri.starts_with(STRICT_URI_PREFIX) {
+            Self::Strict
+        } else {
+            Self::Transitional
+        }
+    }
+
+    /// Translate a namespace URI into this conformance class.
+    ///
+    /// URIs that are not specific to a class are returned unchanged.
+    pub fn namespace(self, uri: &str) -> &str {
+        let found = NAMESPACES.iter().find_map(|&(transitional, strict)| {
+            if uri == transitional || uri == strict {
+                Some(match self {
+                    Self::Transitional => transitional,
+                    Self::Strict => strict,
+                })
+            } else {
+                None
+            }
+        });
+        found.unwrap_or(uri)
+    }
+
+    /// Translate a relationship type URI into this conformance class.
+    ///
+    /// Package-level relationship types (core properties, thumbnails,
+    /// digital signatures) are shared by both classes and returned unchanged.
+    pub fn relationship_type(self, rel_type: &str) -> Cow<'_, str> {
+        let (from, to) = match self {
+            Self::Transitional => (STRICT_REL_PREFIX, TRANSITIONAL_REL_PREFIX),
+            Self::Strict => (TRANSITIONAL_REL_PREFIX, STRICT_REL_PREFIX),
+        };
+        let Some(name) = rel_type.strip_prefix(from) else {
+            return Cow::Borrowed(rel_type);
+        };
+        let renamed = RENAMED_REL_TYPES
+            .iter()
+            .find_map(|&(transitional, strict)| match self {
+                Self::Transitional if name == strict => Some(transitional),
+                Self::Strict if name == transitional => Some(strict),
+                _ => None,
+            });
+        Cow::Owned(format!("{}{}", to, renamed.unwrap_or(name)))
+    }
+}
+
+/// Check whether two relationship types are the same, treating the Strict and
+/// Transitional forms of a type as equal.
+pub fn rel_types_match(a: &str, b: &str) -> bool {
+    a == b
+        || Conformance::Transitional.relationship_type(a)
+            == Conformance::Transitional.relationship_type(b)
+}
+
+/// Rewrite the namespace declarations and relationship types of an XML part
+/// into the given conformance class.
+///
+/// Only start tags that contain a translated value are re-encoded; everything
+/// else is copied through unchanged.
+pub(crate) fn convert_xml(data: &[u8], conformance: Conformance) -> Result<Vec<u8>> {
+    let mut reader = Reader::from_reader(data);
+    let mut writer = Writer::new(Vec::with_capacity(data.len()));
+    let mut buf = Vec::new();
+
+    loop {
+        let event = reader.read_event_into(&mut buf)?;
+        match event {
+            Event::Start(e) => match convert_start(&e, conformance) {
+                Some(converted) => writer.write_event(Event::Start(converted))?,
+                None => writer.write_event(Event::Start(e))?,
+            },
+            Event::Empty(e) => match convert_start(&e, conformance) {
+                Some(converted) => writer.write_event(Event::Empty(converted))?,
+                None => writer.write_event(Event::Empty(e))?,
+            },
+            Event::Eof => break,
+            other => writer.write_event(other)?,
+        }
+        buf.clear();
+    }
+
+    Ok(writer.into_inner())
+}
+
+/// Translate the attributes of a start tag, returning `None` if nothing changed.
+fn convert_start(start: &BytesStart, conformance: Conformance) -> Option<BytesStart<'static>> {
+    let is_relationship = start.local_name().as_ref() == b"Relationship";
+    let mut changed = false;
+    let mut attributes: Vec<(Vec<u8>, Vec<u8>)> = Vec::new();
+
+    for attr in start.attributes().with_checks(false) {
+        let attr = attr.ok()?;
+        let key = attr.key.as_ref();
+        let value = String::from_utf8_lossy(&attr.value);
+        let converted: Option<Cow<str>> = if key == b"xmlns" || key.starts_with(b"xmlns:") {
+            Some(Cow::Borrowed(conformance.namespace(&value)))
+        } else if is_relationship && key == b"Type" {
+            Some(conformance.relationship_type(&value))
+        } else {
+            None
+        };
+        match converted {
+            Some(new) if new != value => {
+                changed = true;
+                attributes.push((key.to_vec(), new.into_owned().into_bytes()));
+            }
+            _ => attributes.push((key.to_vec(), attr.value.into_owned())),
+        }
+    }
+
+    if !changed {
+        return None;
+    }
+    let name = String::from_utf8_lossy(start.name().as_ref()).into_owned();
+    let mut converted = BytesStart::new(name);
+    for (key, value) in &attributes {
+        converted.push_attribute(Attribute {
+            key: quick_xml::name::QName(key),
+            value: Cow::Borrowed(value),
+        });
+    }
+    Some(converted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relationship_type_translation() {
+        let transitional =
+            "http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument";
+        let strict = "http://purl.oclc.org/ooxml/officeDocument/relationships/officeDocument";
+        assert_eq!(Conformance::Strict.relationship_type(transitional), strict);
+        assert_eq!(
+            Conformance::Transitional.relationship_type(strict),
+            transitional
+        );
+        assert_eq!(
+            Conformance::Strict.relationship_type(
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships/extended-properties"
+            ),
+            "http://purl.oclc.org/ooxml/officeDocument/relationships/extendedProperties"
+        );
+        let core =
+            "http://schemas.openxmlformats.org/package/2006/relationships/metadata/core-properties";
+        assert_eq!(Conformance::Strict.relationship_type(core), core);
+        assert!(rel_types_match(strict, transitional));
+        assert_eq!(Conformance::of_uri(strict), Conformance::Strict);
+    }
+
+    #[test]
+    fn test_convert_xml() {
+        let xml = br#"<?xml version="1.0"?>
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:x="urn:other"><w:body><w:p w:rsidR="00AB">a &amp; b</w:p></w:body></w:document>"#;
+        let strict = convert_xml(xml, Conformance::Strict).unwrap();
+        let strict = String::from_utf8(strict).unwrap();
+        assert!(strict.contains(r#"xmlns:w="http://purl.oclc.org/ooxml/wordprocessingml/main""#));
+        assert!(strict.contains(r#"xmlns:x="urn:other""#));
+        assert!(strict.contains(r#"<w:p w:rsidR="00AB">a &amp; b</w:p>"#));
+
+        let back = convert_xml(strict.as_bytes(), Conformance::Transitional).unwrap();
+        assert_eq!(back, xml);
+    }
+}
@@ -0,0 +1,687 @@
+//! Part-level editing of existing packages.
+//!
+//! [`PackageEditor`] adds, replaces, removes and renames parts while keeping
+//! the package consistent: relationships to removed parts are dropped,
+//! relationships to renamed parts are retargeted, and content types follow
+//! the parts. Parts that are not touched are copied verbatim on save.
+//!
+//! The editor works on the package structure only. Markup that refers to a
+//! relationship by ID (such as `r:embed="rId5"` in a document) is not
+//! rewritten when the relationship is removed.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use ooxml_opc::{Package, PackageEditor};
+//! use std::fs::File;
+//!
+//! let mut editor = PackageEditor::new(Package::open(File::open("report.docx")?)?)?;
+//!
+//! // Drop the comments and every embedded image
+//! for part in editor.parts().map(str::to_string).collect::<Vec<_>>() {
+//!     if part == "word/comments.xml" || part.starts_with("word/media/") {
+//!         editor.remove_part(&part)?;
+//!     }
+//! }
+//! editor.save("report-external.docx")?;
+//! # Ok::<(), ooxml_opc::Error>(())
+//! ```
+
+use crate::conformance::Conformance;
+use crate::error::{Error, Result};
+use crate::packaging::{Package, PackageWriter, content_type};
+use crate::part_graph::{PartGraph, refers_to, strip_fragment};
+use crate::relationships::{
+    Relationship, Relationships, TargetMode, relative_target, rels_path_for, resolve_target,
+    source_for_rels_path,
+};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::File;
+use std::io::{Read, Seek, Write};
+use std::path::Path;
+
+/// Mutable view of a package for adding, removing and renaming parts.
+pub struct PackageEditor<R> {
+    package: Package<R>,
+    /// Parts (without relationships parts), by name.
+    parts: BTreeMap<String, Part>,
+    /// Relationships by source part (`""` for package relationships).
+    relationships: BTreeMap<String, Relationships>,
+    /// Sources whose relationships changed and must be serialized again.
+    edited_relationships: BTreeSet<String>,
+}
+
+/// A part of the edited package.
+struct Part {
+    content_type: String,
+    data: PartData,
+}
+
+enum PartData {
+    /// Unchanged content of a part of the source package.
+    Original(String),
+    /// New content.
+    Data(Vec<u8>),
+}
+
+impl<R: Read + Seek> PackageEditor<R> {
+    /// Start editing a package.
+    pub fn new(mut package: Package<R>) -> Result<Self> {
+        let names: Vec<String> = package
+            .parts()
+            .filter(|name| !name.ends_with('/') && *name != "[Content_Types].xml")
+            .map(str::to_string)
+            .collect();
+
+        let mut parts = BTreeMap::new();
+        let mut relationships = BTreeMap::new();
+        for name in names {
+            if let Some(source) = source_for_rels_path(&name) {
+                let data = package.read_part(&name)?;
+                relationships.insert(source, Relationships::parse(&data[..])?);
+            } else {
+                let content_type = package
+                    .content_type(&name)
+                    .unwrap_or("application/octet-stream")
+                    .to_string();
+                parts.insert(
+                    name.clone(),
+                    Part {
+                        content_type,
+                        data: PartData::Original(name),
+                    },
+                );
+            }
+        }
+
+        Ok(Self {
+            package,
+            parts,
+            relationships,
+            edited_relationships: BTreeSet::new(),
+        })
+    }
+
+    /// List all parts, excluding `[Content_Types].xml` and relationships parts.
+    pub fn parts(&self) -> impl Iterator<Item = &str> {
+        self.parts.keys().map(String::as_str)
+    }
+
+    /// Check if a part exists in the package.
+    pub fn has_part(&self, part: &str) -> bool {
+        self.parts.contains_key(part)
+    }
+
+    /// Get the content type of a part.
+    pub fn content_type(&self, part: &str) -> Option<&str> {
+        self.parts.get(part).map(|p| p.content_type.as_str())
+    }
+
+    /// Read a part's current contents.
+    pub fn read_part(&mut self, part: &str) -> Result<Vec<u8>> {
+        match self.parts.get(part).map(|p| &p.data) {
+            Some(PartData::Original(name)) => self.package.read_part(name),
+            Some(PartData::Data(data)) => Ok(data.clone()),
+            None => Err(Error::MissingPart(part.into())),
+        }
+    }
+
+    /// Get the relationships of a part (`""` for package relationships).
+    pub fn relationships(&self, source: &str) -> Option<&Relationships> {
+        self.relationships.get(source)
+    }
+
+    /// Build the graph of the parts and relationships as currently edited.
+    pub fn graph(&self) -> PartGraph {
+        PartGraph::from_relationships(self.parts.keys().cloned().collect(), &self.relationships)
+    }
+
+    /// Add a part or replace the contents of an existing one, without
+    /// touching relationships.
+    pub fn set_part(&mut self, part: &str, content_type: &str, data: Vec<u8>) {
+        self.parts.insert(
+            part.to_string(),
+            Part {
+                content_type: content_type.to_string(),
+                data: PartData::Data(data),
+            },
+        );
+    }
+
+    /// Add a new part and a relationship to it from `source` (`""` for a
+    /// package relationship), returning the relationship ID.
+    pub fn add_part(
+        &mut self,
+        source: &str,
+        rel_type: &str,
+        part: &str,
+        content_type: &str,
+        data: Vec<u8>,
+    ) -> Result<String> {
+        if self.has_part(part) {
+            return Err(Error::Invalid(format!("part {} already exists", part)));
+        }
+        if !source.is_empty() && !self.has_part(source) {
+            return Err(Error::MissingPart(source.into()));
+        }
+        self.set_part(part, content_type, data);
+        self.add_relationship(source, rel_type, part)
+    }
+
+    /// Add a relationship from `source` (`""` for the package) to an existing
+    /// part, returning the relationship ID.
+    pub fn add_relationship(&mut self, source: &str, rel_type: &str, part: &str) -> Result<String> {
+        if !self.has_part(part) {
+            return Err(Error::MissingPart(part.into()));
+        }
+        let rels = self.relationships.entry(source.to_string()).or_default();
+        let id = rels.next_id();
+        rels.add(Relationship::new(
+            id.clone(),
+            rel_type,
+            relative_target(source, part),
+        ));
+        self.edited_relationships.insert(source.to_string());
+        Ok(id)
+    }
+
+    /// Remove a part, its relationships, and every relationship targeting it.
+    pub fn remove_part(&mut self, part: &str) -> Result<()> {
+        if self.parts.remove(part).is_none() {
+            return Err(Error::MissingPart(part.into()));
+        }
+        if self.relationships.remove(part).is_some() {
+            self.edited_relationships.insert(part.to_string());
+        }
+
+        for (source, rels) in &mut self.relationships {
+            let stale: Vec<String> = rels
+                .iter()
+                .filter(|rel| {
+                    rel.target_mode == TargetMode::Internal && refers_to(source, &rel.target, part)
+                })
+                .map(|rel| rel.id.clone())
+                .collect();
+            for id in &stale {
+                rels.remove(id);
+            }
+            if !stale.is_empty() {
+                self.edited_relationships.insert(source.clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove a part together with the parts only it made reachable, such as
+    /// the images of a removed slide. Returns all removed parts.
+    ///
+    /// Parts still reachable from the package relationships through another
+    /// path are kept.
+    pub fn remove_part_and_orphans(&mut self, part: &str) -> Result<Vec<String>> {
+        let descendants: Vec<String> = self
+            .graph()
+            .reachable_from(part)
+            .into_iter()
+            .filter(|name| *name != part)
+            .map(str::to_string)
+            .collect();
+        self.remove_part(part)?;
+
+        let reachable: BTreeSet<String> = self
+            .graph()
+            .reachable_from("")
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        let mut removed = vec![part.to_string()];
+        for descendant in descendants {
+            if !reachable.contains(&descendant) {
+                self.remove_part(&descendant)?;
+                removed.push(descendant);
+            }
+        }
+        Ok(removed)
+    }
+
+    /// Rename (move) a part, retargeting every relationship that refers to it
+    /// and keeping its own relationships pointing at the same parts.
+    pub fn rename_part(&mut self, from: &str, to: &str) -> Result<()> {
+        if self.has_part(to) {
+            return Err(Error::Invalid(format!("part {} already exists", to)));
+        }
+        let Some(entry) = self.parts.remove(from) else {
+            return Err(Error::MissingPart(from.into()));
+        };
+        self.parts.insert(to.to_string(), entry);
+
+        // Relationships targeting the part
+        for (source, rels) in &mut self.relationships {
+            let retargeted: Vec<Relationship> = rels
+                .iter()
+                .filter(|rel| {
+                    rel.target_mode == TargetMode::Internal && refers_to(source, &rel.target, from)
+                })
+                .map(|rel| Relationship {
+                    // Keep the fragment, e.g. a bookmark in the part
+                    target: format!(
+                        "{}{}",
+                        retarget(source, &rel.target, to),
+                        &rel.target[strip_fragment(&rel.target).len()..]
+                    ),
+                    ..rel.clone()
+                })
+                .collect();
+            if !retargeted.is_empty() {
+                self.edited_relationships.insert(source.clone());
+            }
+            for rel in retargeted {
+                rels.add(rel);
+            }
+        }
+
+        // Relationships of the part, relative to its new location
+        if let Some(rels) = self.relationships.remove(from) {
+            let mut moved = Relationships::new();
+            for rel in rels.iter() {
+                let target = match rel.target_mode {
+                    TargetMode::Internal => {
+                        retarget(to, &rel.target, &resolve_target(from, &rel.target))
+                    }
+                    TargetMode::External => rel.target.clone(),
+                };
+                moved.add(Relationship {
+                    target,
+                    ..rel.clone()
+                });
+            }
+            self.relationships.insert(to.to_string(), moved);
+            self.edited_relationships.insert(from.to_string());
+            self.edited_relationships.insert(to.to_string());
+        }
+        Ok(())
+    }
+
+    /// Save the edited package to a file.
+    pub fn save<P: AsRef<Path>>(&mut self, path: P) -> Result<()> {
+        let file = File::create(path)?;
+        self.write(file)
+    }
+
+    /// Write the edited package to a writer.
+    ///
+    /// Unchanged parts and relationships parts are copied verbatim.
+    pub fn write<W: Write + Seek>(&mut self, writer: W) -> Result<()> {
+        let mut pkg_writer = PackageWriter::new(writer);
+        // Parts added here follow the class of the source document
+        if self.package.conformance()? == Conformance::Strict {
+            pkg_writer.set_conformance(Conformance::Strict);
+        }
+        let defaults: Vec<(String, String)> = self
+            .package
+            .content_types()
+            .defaults()
+            .map(|(ext, ct)| (ext.to_string(), ct.to_string()))
+            .collect();
+        for (ext, ct) in &defaults {
+            pkg_writer.add_default_content_type(ext, ct);
+        }
+
+        // Package relationships first, then each part followed by its
+        // relationships
+        let sources = std::iter::once("").chain(self.parts.keys().map(String::as_str));
+        let sources: Vec<String> = sources.map(str::to_string).collect();
+        for source in &sources {
+            if let Some(part) = self.parts.get(source) {
+                let data = match &part.data {
+                    PartData::Original(name) => self.package.read_part(name)?,
+                    PartData::Data(data) => data.clone(),
+                };
+                pkg_writer.add_part(source, &part.content_type, &data)?;
+            }
+            let Some(rels) = self.relationships.get(source) else {
+                continue;
+            };
+            let rels_path = rels_path_for(source);
+            let data = if !self.edited_relationships.contains(source)
+                && self.package.has_part(&rels_path)
+            {
+                self.package.read_part(&rels_path)?
+            } else if rels.is_empty() {
+                continue;
+            } else {
+                rels.serialize().into_bytes()
+            };
+            pkg_writer.add_part(&rels_path, content_type::RELATIONSHIPS, &data)?;
+        }
+
+        // Relationships of parts that are not in the package
+        for (source, rels) in &self.relationships {
+            if !source.is_empty() && !self.parts.contains_key(source) && !rels.is_empty() {
+                let rels_path = rels_path_for(source);
+                let data = if self.edited_relationships.contains(source) {
+                    rels.serialize().into_bytes()
+                } else {
+                    self.package.read_part(&rels_path)?
+                };
+                pkg_writer.add_part(&rels_path, content_type::RELATIONSHIPS, &data)?;
+            }
+        }
+
+        pkg_writer.finish()?;
+        Ok(())
+    }
+}
+
+/// Point a relationship target at `part`, keeping an absolute target
+/// absolute.
+fn retarget(source: &str, old_target: &str, part: &str) -> String {
+    if old_target.starts_with('/') {
+        format!("/{}", part)
+    } else {
+        relative_target(source, part)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::relationships::rel_type;
+    use std::io::Cursor;
+
+    fn create_test_package() -> Vec<u8> {
+        let mut buf = Cursor::new(Vec::new());
+        let mut writer = PackageWriter::new(&mut buf);
+        writer.add_default_content_type("rels", content_type::RELATIONSHIPS);
+        writer.add_default_content_type("xml", content_type::XML);
+        writer.add_default_content_type("png", "image/png");
+
+        let mut rels = Relationships::new();
+        rels.add(Relationship::new(
+            "rId1",
+            rel_type::OFFICE_DOCUMENT,
+            "word/document.xml",
+        ));
+        writer
+            .add_part(
+                "_rels/.rels",
+                content_type::RELATIONSHIPS,
+                rels.serialize().as_bytes(),
+            )
+            .unwrap();
+
+        let mut rels = Relationships::new();
+        rels.add(Relationship::new(
+            "rId1",
+            rel_type::COMMENTS,
+            "comments.xml",
+        ));
+        rels.add(Relationship::new(
+            "rId2",
+            rel_type::IMAGE,
+            "media/image1.png",
+        ));
+        rels.add(Relationship::new(
+            "rId3",
+            rel_type::IMAGE,
+            "/word/media/shared.png",
+        ));
+        writer
+            .add_part(
+                "word/_rels/document.xml.rels",
+                content_type::RELATIONSHIPS,
+                rels.serialize().as_bytes(),
+            )
+            .unwrap();
+
+        let mut rels = Relationships::new();
+        rels.add(Relationship::new(
+            "rId1",
+            rel_type::IMAGE,
+            "media/comment.png",
+        ));
+        rels.add(Relationship::new(
+            "rId2",
+            rel_type::IMAGE,
+            "media/shared.png",
+        ));
+        writer
+            .add_part(
+                "word/_rels/comments.xml.rels",
+                content_type::RELATIONSHIPS,
+                rels.serialize().as_bytes(),
+            )
+            .unwrap();
+
+        writer
+            .add_part(
+                "word/document.xml",
+                content_type::WORDPROCESSING_DOCUMENT,
+                b"<w:document/>",
+            )
+            .unwrap();
+        writer
+            .add_part(
+                "word/comments.xml",
+                content_type::WORDPROCESSING_COMMENTS,
+                b"<w:comments/>",
+            )
+            .unwrap();
+        for image in ["image1", "comment", "shared"] {
+            writer
+                .add_part(&format!("word/media/{}.png", image), "image/png", b"PNG")
+                .unwrap();
+        }
+        writer.finish().unwrap();
+        buf.into_inner()
+    }
+
+    fn reopen(editor: &mut PackageEditor<Cursor<Vec<u8>>>) -> Package<Cursor<Vec<u8>>> {
+        let mut buf = Cursor::new(Vec::new());
+        editor.write(&mut buf).unwrap();
+        Package::open(Cursor::new(buf.into_inner())).unwrap()
+    }
+
+    #[test]
+    fn test_remove_part_and_orphans() {
+        let package = Package::open(Cursor::new(create_test_package())).unwrap();
+        let mut editor = PackageEditor::new(package).unwrap();
+
+        let removed = editor.remove_part_and_orphans("word/comments.xml").unwrap();
+        assert_eq!(removed, ["word/comments.xml", "word/media/comment.png"]);
+
+        let mut pkg = reopen(&mut editor);
+        assert!(!pkg.has_part("word/comments.xml"));
+        assert!(!pkg.has_part("word/_rels/comments.xml.rels"));
+        assert!(!pkg.has_part("word/media/comment.png"));
+        assert!(pkg.has_part("word/media/shared.png"));
+        assert!(
+            pkg.content_types()
+                .overrides()
+                .all(|(part, _)| part != "word/comments.xml")
+        );
+
+        let rels = pkg.read_part_relationships("word/document.xml").unwrap();
+        assert_eq!(rels.len(), 2);
+        assert!(rels.get_by_type(rel_type::COMMENTS).is_none());
+        assert!(PartGraph::load(&mut pkg).unwrap().dangling().is_empty());
+    }
+
+    #[test]
+    fn test_rename_part() {
+        let package = Package::open(Cursor::new(create_test_package())).unwrap();
+        let mut editor = PackageEditor::new(package).unwrap();
+
+        editor
+            .rename_part("word/media/shared.png", "media/logo.png")
+            .unwrap();
+        editor
+            .rename_part("word/comments.xml", "word/extra/notes.xml")
+            .unwrap();
+        assert!(editor.rename_part("word/missing.xml", "x.xml").is_err());
+        assert!(
+            editor
+                .rename_part("word/document.xml", "media/logo.png")
+                .is_err()
+        );
+
+        let mut pkg = reopen(&mut editor);
+        let rels = pkg.read_part_relationships("word/document.xml").unwrap();
+        assert_eq!(rels.get("rId1").unwrap().target, "extra/notes.xml");
+        assert_eq!(rels.get("rId3").unwrap().target, "/media/logo.png");
+
+        let rels = pkg.read_part_relationships("word/extra/notes.xml").unwrap();
+        assert_eq!(rels.get("rId1").unwrap().target, "../media/comment.png");
+        assert_eq!(rels.get("rId2").unwrap().target, "../../media/logo.png");
+        assert!(!pkg.has_part("word/_rels/comments.xml.rels"));
+        assert_eq!(
+            pkg.content_type("word/extra/notes.xml"),
+            Some(content_type::WORDPROCESSING_COMMENTS)
+        );
+
+        let graph = PartGraph::load(&mut pkg).unwrap();
+        assert!(graph.dangling().is_empty());
+        assert!(graph.orphans().is_empty());
+    }
+
+    /// Build a package whose document refers to one image with targets
+    /// that are percent-encoded, differ in case or have a fragment.
+    fn create_target_variants_package() -> Vec<u8> {
+        let mut buf = Cursor::new(Vec::new());
+        let mut writer = PackageWriter::new(&mut buf);
+        writer.add_default_content_type("rels", content_type::RELATIONSHIPS);
+        writer.add_default_content_type("png", "image/png");
+
+        let mut rels = Relationships::new();
+        rels.add(Relationship::new(
+            "rId1",
+            rel_type::OFFICE_DOCUMENT,
+            "word/document.xml",
+        ));
+        writer
+            .add_part(
+                "_rels/.rels",
+                content_type::RELATIONSHIPS,
+                rels.serialize().as_bytes(),
+            )
+            .unwrap();
+
+        let mut rels = Relationships::new();
+        for (id, target) in [
+            ("rId1", "media/image%201.png"),
+            ("rId2", "Media/IMAGE 1.png"),
+            ("rId3", "media/image%201.png#crop"),
+            ("rId4", "media/other.png"),
+        ] {
+            rels.add(Relationship::new(id, rel_type::IMAGE, target));
+        }
+        writer
+            .add_part(
+                "word/_rels/document.xml.rels",
+                content_type::RELATIONSHIPS,
+                rels.serialize().as_bytes(),
+            )
+            .unwrap();
+
+        writer
+            .add_part(
+                "word/document.xml",
+                content_type::WORDPROCESSING_DOCUMENT,
+                b"<w:document/>",
+            )
+            .unwrap();
+        for image in ["word/media/image 1.png", "word/media/other.png"] {
+            writer.add_part(image, "image/png", b"PNG").unwrap();
+        }
+        writer.finish().unwrap();
+        buf.into_inner()
+    }
+
+    #[test]
+    fn test_target_variants() {
+        let package = Package::open(Cursor::new(create_target_variants_package())).unwrap();
+        let mut editor = PackageEditor::new(package).unwrap();
+        editor
+            .rename_part("word/media/image 1.png", "word/media/photo.png")
+            .unwrap();
+        let mut pkg = reopen(&mut editor);
+        let rels = pkg.read_part_relationships("word/document.xml").unwrap();
+        let targets: Vec<&str> = ["rId1", "rId2", "rId3", "rId4"]
+            .iter()
+            .map(|id| rels.get(id).unwrap().target.as_str())
+            .collect();
+        assert_eq!(
+            targets,
+            [
+                "media/photo.png",
+                "media/photo.png",
+                "media/photo.png#crop",
+                "media/other.png"
+            ]
+        );
+        assert!(PartGraph::load(&mut pkg).unwrap().dangling().is_empty());
+
+        let package = Package::open(Cursor::new(create_target_variants_package())).unwrap();
+        let mut editor = PackageEditor::new(package).unwrap();
+        editor.remove_part("word/media/image 1.png").unwrap();
+        let mut pkg = reopen(&mut editor);
+        let rels = pkg.read_part_relationships("word/document.xml").unwrap();
+        let ids: Vec<&str> = rels.iter().map(|rel| rel.id.as_str()).collect();
+        assert_eq!(ids, ["rId4"]);
+        assert!(PartGraph::load(&mut pkg).unwrap().dangling().is_empty());
+    }
+
+    #[test]
+    fn test_add_part() {
+        let mut package = Package::open(Cursor::new(create_test_package())).unwrap();
+        let comments_rels = package.read_part("word/_rels/comments.xml.rels").unwrap();
+        let mut editor = PackageEditor::new(package).unwrap();
+
+        let id = editor
+            .add_part(
+                "word/document.xml",
+                rel_type::FOOTER,
+                "word/footer1.xml",
+                content_type::WORDPROCESSING_FOOTER,
+                b"<w:ftr/>".to_vec(),
+            )
+            .unwrap();
+        assert_eq!(id, "rId4");
+        let id = editor
+            .add_part(
+                "",
+                rel_type::CORE_PROPERTIES,
+                "docProps/core.xml",
+                content_type::CORE_PROPERTIES,
+                b"<cp:coreProperties/>".to_vec(),
+            )
+            .unwrap();
+        assert_eq!(id, "rId2");
+        assert!(
+            editor
+                .add_part(
+                    "word/missing.xml",
+                    rel_type::IMAGE,
+                    "a.png",
+                    "image/png",
+                    vec![]
+                )
+                .is_err()
+        );
+
+        let mut pkg = reopen(&mut editor);
+        assert_eq!(pkg.read_part("word/footer1.xml").unwrap(), b"<w:ftr/>");
+        assert_eq!(
+            pkg.content_type("word/footer1.xml"),
+            Some(content_type::WORDPROCESSING_FOOTER)
+        );
+        let rels = pkg.read_part_relationships("word/document.xml").unwrap();
+        assert_eq!(rels.get("rId4").unwrap().target, "footer1.xml");
+        let rels = pkg.read_relationships().unwrap();
+        assert_eq!(rels.get("rId2").unwrap().target, "docProps/core.xml");
+        // Untouched relationships parts are copied verbatim
+        assert_eq!(
+            pkg.read_part("word/_rels/comments.xml.rels").unwrap(),
+            comments_rels
+        );
+    }
+}
@@ -0,0 +1,1171 @@
+//! Password-protected (encrypted) packages.
+//!
+//! An encrypted OOXML document is not a ZIP archive but an OLE compound file
+//! (CFB) with an `EncryptionInfo` stream describing how the key is derived
+//! from the password, and an `EncryptedPackage` stream holding the encrypted
+//! ZIP package.
+//!
+//! Decryption supports Agile encryption (Office 2010 and later) and Standard
+//! encryption (Office 2007) with AES. Encryption always writes Agile
+//! encryption with AES-256 and SHA-512, the Office default.
+//!
+//! [MS-OFFCRYPTO] §2.3.4.5 (Standard) and §2.3.4.10 (Agile).
+//!
+//! # Example
+//!
+//! ```no_run
+//! use ooxml_opc::Package;
+//! use std::fs::File;
+//!
+//! let file = File::open("protected.xlsx")?;
+//! let mut pkg = Package::open_encrypted(file, "secret")?;
+//! let workbook = pkg.read_part_string("xl/workbook.xml")?;
+//! # Ok::<(), ooxml_opc::Error>(())
+//! ```
+
+use crate::error::{Error, Result};
+use aes::cipher::generic_array::GenericArray;
+use aes::cipher::{BlockDecrypt, BlockEncrypt, KeyInit};
+use aes::{Aes128, Aes192, Aes256};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use hmac::{Hmac, Mac};
+use quick_xml::Reader;
+use quick_xml::events::Event;
+use sha1::{Digest, Sha1};
+use sha2::{Sha256, Sha384, Sha512};
+use std::io::{Cursor, Read, Seek, Write};
+
+/// Size of the segments the package is encrypted in (Agile encryption).
+const SEGMENT_SIZE: usize = 4096;
+
+/// Block keys used to derive the Agile encryption keys (§2.3.4.11–§2.3.4.14).
+const BLOCK_VERIFIER_INPUT: [u8; 8] = [0xFE, 0xA7, 0xD2, 0x76, 0x3B, 0x4B, 0x9E, 0x79];
+const BLOCK_VERIFIER_VALUE: [u8; 8] = [0xD7, 0xAA, 0x0F, 0x6D, 0x30, 0x61, 0x34, 0x4E];
+const BLOCK_KEY_VALUE: [u8; 8] = [0x14, 0x6E, 0x0B, 0xE7, 0xAB, 0xAC, 0xD0, 0xD6];
+const BLOCK_INTEGRITY_KEY: [u8; 8] = [0x5F, 0xB2, 0xAD, 0x01, 0x0C, 0xB9, 0xE1, 0xF6];
+const BLOCK_INTEGRITY_VALUE: [u8; 8] = [0xA0, 0x67, 0x7F, 0x02, 0xB2, 0x2C, 0x84, 0x33];
+
+/// Namespaces of the Agile encryption descriptor.
+const NS_ENCRYPTION: &str = "http://schemas.microsoft.com/office/2006/encryption";
+const NS_PASSWORD_ENCRYPTOR: &str =
+    "http://schemas.microsoft.com/office/2006/keyEncryptor/password";
+
+/// Spin count used when encrypting, matching Office.
+const SPIN_COUNT: u32 = 100_000;
+
+/// Largest spin count accepted when decrypting (§2.3.4.10). The count comes
+/// from the file, and each iteration is a hash of the password.
+const MAX_SPIN_COUNT: u32 = 10_000_000;
+
+/// Largest `EncryptionInfo` stream read. Descriptors are a few kilobytes.
+const MAX_INFO_SIZE: u64 = 1024 * 1024;
+
+/// Standard encryption algorithm identifiers (§2.3.2).
+const ALG_AES_128: u32 = 0x660E;
+const ALG_AES_192: u32 = 0x660F;
+const ALG_AES_256: u32 = 0x6610;
+const ALG_RC4: u32 = 0x6801;
+
+/// `fAES` flag of the Standard encryption header.
+const FLAG_AES: u32 = 0x20;
+
+/// Check whether the data is an OLE compound file, the container used for
+/// encrypted packages.
+///
+/// The reader is left at the position it started at.
+pub fn is_encrypted<R: Read + Seek>(reader: &mut R) -> Result<bool> {
+    crate::packaging::is_compound_file(reader)
+}
+
+/// Decrypt an encrypted package, returning the bytes of the ZIP package.
+///
+/// Returns [`Error::InvalidPassword`] if the password is wrong.
+pub fn decrypt<R: Read + Seek>(reader: R, password: &str) -> Result<Vec<u8>> {
+    decrypt_with_limit(reader, password, u64::MAX)
+}
+
+/// Decrypt an encrypted package like [`decrypt`], failing with
+/// [`Error::LimitExceeded`] before anything is read if the encrypted package
+/// is larger than `max_size` bytes.
+pub fn decrypt_with_limit<R: Read + Seek>(
+    reader: R,
+    password: &str,
+    max_size: u64,
+) -> Result<Vec<u8>> {
+    let mut compound = cfb::CompoundFile::open(reader)?;
+    let info = read_stream(&mut compound, "EncryptionInfo", MAX_INFO_SIZE)?;
+    let package = read_stream(&mut compound, "EncryptedPackage", max_size)?;
+
+    if info.len() < 8 {
+        return Err(Error::Invalid("EncryptionInfo stream is truncated".into()));
+    }
+    let major = u16::from_le_bytes([info[0], info[1]]);
+    let minor = u16::from_le_bytes([info[2], info[3]]);
+    match (major, minor) {
+        (4, 4) => AgileEncryption::parse(&info[8..])?.decrypt(password, &package),
+        (2..=4, 2) => StandardEncryption::parse(&info[8..])?.decrypt(password, &package),
+        _ => Err(Error::Unsupported(format!(
+            "encryption version {}.{}",
+            major, minor
+        ))),
+    }
+}
+
+/// Encrypt a ZIP package with a password, returning the bytes of the
+/// compound file to save.
+pub fn encrypt(package: &[u8], password: &str) -> Result<Vec<u8>> {
+    let hash = HashAlgorithm::Sha512;
+    let key_data = KeyParams {
+        salt: random_bytes(16)?,
+        block_size: 16,
+        key_bits: 256,
+        hash,
+    };
+    let encryptor = KeyParams {
+        salt: random_bytes(16)?,
+        ..key_data.clone()
+    };
+    let secret_key = random_bytes(key_data.key_bits / 8)?;
+
+    // Encrypted package: its size followed by the encrypted segments.
+    let cipher = Aes::new(&secret_key)?;
+    let mut stream = (package.len() as u64).to_le_bytes().to_vec();
+    for (index, segment) in package.chunks(SEGMENT_SIZE).enumerate() {
+        let mut block = segment.to_vec();
+        block.resize(block.len().next_multiple_of(key_data.block_size), 0);
+        cipher.cbc_encrypt(&key_data.segment_iv(index as u32), &mut block);
+        stream.extend_from_slice(&block);
+    }
+
+    // Password verifier and the encrypted secret key.
+    let password_hash = encryptor.password_hash(password, SPIN_COUNT);
+    let verifier_input = random_bytes(encryptor.salt.len())?;
+    let verifier_value = hash.digest(&[&verifier_input]);
+    let encrypt_with = |block_key: &[u8], data: &[u8]| -> Result<Vec<u8>> {
+        let key = encryptor.derive_key(&password_hash, block_key);
+        encryptor.encrypt(&key, &encryptor.iv(None), data)
+    };
+    let encrypted_verifier_input = encrypt_with(&BLOCK_VERIFIER_INPUT, &verifier_input)?;
+    let encrypted_verifier_value = encrypt_with(&BLOCK_VERIFIER_VALUE, &verifier_value)?;
+    let encrypted_key_value = encrypt_with(&BLOCK_KEY_VALUE, &secret_key)?;
+
+    // Data integrity: an HMAC of the encrypted package.
+    let hmac_key = random_bytes(hash.size())?;
+    let hmac_value = hash.hmac(&hmac_key, &stream);
+    let encrypted_hmac_key = key_data.encrypt(
+        &secret_key,
+        &key_data.iv(Some(&BLOCK_INTEGRITY_KEY)),
+        &hmac_key,
+    )?;
+    let encrypted_hmac_value = key_data.encrypt(
+        &secret_key,
+        &key_data.iv(Some(&BLOCK_INTEGRITY_VALUE)),
+        &hmac_value,
+    )?;
+
+    let descriptor = format!(
+        concat!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+            "\r\n",
+            r#"<encryption xmlns="{ns}" xmlns:p="{ns_password}">"#,
+            r#"<keyData {key_data}/>"#,
+            r#"<dataIntegrity encryptedHmacKey="{hmac_key}" encryptedHmacValue="{hmac_value}"/>"#,
+            r#"<keyEncryptors><keyEncryptor uri="{ns_password}">"#,
+            r#"<p:encryptedKey spinCount="{spin_count}" {encryptor} "#,
+            r#"encryptedVerifierHashInput="{verifier_input}" "#,
+            r#"encryptedVerifierHashValue="{verifier_value}" "#,
+            r#"encryptedKeyValue="{key_value}"/>"#,
+            r#"</keyEncryptor></keyEncryptors></encryption>"#,
+        ),
+        ns = NS_ENCRYPTION,
+        ns_password = NS_PASSWORD_ENCRYPTOR,
+        key_data = key_data.attributes(),
+        hmac_key = BASE64.encode(&encrypted_hmac_key),
+        hmac_value = BASE64.encode(&encrypted_hmac_value),
+        spin_count = SPIN_COUNT,
+        encryptor = encryptor.attributes(),
+        verifier_input = BASE64.encode(&encrypted_verifier_input),
+        verifier_value = BASE64.encode(&encrypted_verifier_value),
+        key_value = BASE64.encode(&encrypted_key_value),
+    );
+
+    // EncryptionInfo: version 4.4, fAgile flag, then the XML descriptor.
+    let mut info = Vec::with_capacity(8 + descriptor.len());
+    info.extend_from_slice(&4u16.to_le_bytes());
+    info.extend_from_slice(&4u16.to_le_bytes());
+    info.extend_from_slice(&0x40u32.to_le_bytes());
+    info.extend_from_slice(descriptor.as_bytes());
+
+    write_compound_file(&info, &stream)
+}
+
+/// Read a stream of at most `max_size` bytes from the root storage of a
+/// compound file.
+fn read_stream<F: Read + Seek>(
+    compound: &mut cfb::CompoundFile<F>,
+    name: &str,
+    max_size: u64,
+) -> Result<Vec<u8>> {
+    let path = format!("/{}", name);
+    if !compound.is_stream(&path) {
+        return Err(Error::MissingPart(name.into()));
+    }
+    let mut stream = compound.open_stream(&path)?;
+    if stream.len() > max_size {
+        return Err(Error::LimitExceeded(format!(
+            "{} is larger than {} bytes",
+            name, max_size
+        )));
+    }
+    let mut data = Vec::new();
+    stream.read_to_end(&mut data)?;
+    Ok(data)
+}
+
+/// Write the compound file for an encrypted package, including the data
+/// spaces that declare the encryption transform (§2.1).
+fn write_compound_file(info: &[u8], package: &[u8]) -> Result<Vec<u8>> {
+    let mut compound =
+        cfb::CompoundFile::create_with_version(cfb::Version::V3, Cursor::new(Vec::new()))?;
+
+    let streams: [(&str, Vec<u8>); 6] = [
+        ("/EncryptionInfo", info.to_vec()),
+        ("/EncryptedPackage", package.to_vec()),
+        ("/\u{6}DataSpaces/Version", data_spaces_version()),
+        ("/\u{6}DataSpaces/DataSpaceMap", data_space_map()),
+        (
+            "/\u{6}DataSpaces/DataSpaceInfo/StrongEncryptionDataSpace",
+            data_space_definition(),
+        ),
+        (
+            "/\u{6}DataSpaces/TransformInfo/StrongEncryptionTransform/\u{6}Primary",
+            transform_info(),
+        ),
+    ];
+    for (path, data) in &streams {
+        if let Some((parent, _)) = path.rsplit_once('/')
+            && !parent.is_empty()
+        {
+            compound.create_storage_all(parent)?;
+        }
+        compound.create_stream(path)?.write_all(data)?;
+    }
+    compound.flush()?;
+
+    Ok(compound.into_inner().into_inner())
+}
+
+/// Append a length-prefixed UTF-16 string padded to 4 bytes (§2.1.2).
+fn push_unicode(out: &mut Vec<u8>, value: &str) {
+    let bytes = utf16_le(value);
+    out.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+    out.extend_from_slice(&bytes);
+    out.resize(out.len().next_multiple_of(4), 0);
+}
+
+/// The `\x06DataSpaces/Version` stream (§2.1.5).
+fn data_spaces_version() -> Vec<u8> {
+    let mut out = Vec::new();
+    push_unicode(&mut out, "Microsoft.Container.DataSpaces");
+    // Reader, updater and writer versions, each 1.0.
+    for _ in 0..3 {
+        out.extend_from_slice(&1u16.to_le_bytes());
+        out.extend_from_slice(&0u16.to_le_bytes());
+    }
+    out
+}
+
+/// The `\x06DataSpaces/DataSpaceMap` stream (§2.1.6).
+fn data_space_map() -> Vec<u8> {
+    let mut entry = Vec::new();
+    entry.extend_from_slice(&1u32.to_le_bytes()); // reference component count
+    entry.extend_from_slice(&0u32.to_le_bytes()); // component type: stream
+    push_unicode(&mut entry, "EncryptedPackage");
+    push_unicode(&mut entry, "StrongEncryptionDataSpace");
+
+    let mut out = Vec::new();
+    out.extend_from_slice(&8u32.to_le_bytes()); // header length
+    out.extend_from_slice(&1u32.to_le_bytes()); // entry count
+    out.extend_from_slice(&(entry.len() as u32 + 4).to_le_bytes());
+    out.extend_from_slice(&entry);
+    out
+}
+
+/// The `StrongEncryptionDataSpace` definition stream (§2.1.7).
+fn data_space_definition() -> Vec<u8> {
+    let mut out = Vec::new();
+    out.extend_from_slice(&8u32.to_le_bytes()); // header length
+    out.extend_from_slice(&1u32.to_le_bytes()); // transform reference count
+    push_unicode(&mut out, "StrongEncryptionTransform");
+    out
+}
+
+/// The `StrongEncryptionTransform/\x06Primary` stream (§2.1.8, §2.2.6).
+fn transform_info() -> Vec<u8> {
+    let mut id = Vec::new();
+    push_unicode(&mut id, "{FF9A3F03-56EF-4613-BDD5-5A41C1D07246}");
+
+    let mut out = Vec::new();
+    // Length of the header up to the transform name.
+    out.extend_from_slice(&(8 + id.len() as u32).to_le_bytes());
+    out.extend_from_slice(&1u32.to_le_bytes()); // transform type
+    out.extend_from_slice(&id);
+    push_unicode(&mut out, "Microsoft.Container.EncryptionTransform");
+    for _ in 0..3 {
+        out.extend_from_slice(&1u16.to_le_bytes());
+        out.extend_from_slice(&0u16.to_le_bytes());
+    }
+    out.extend_from_slice(&0u32.to_le_bytes()); // encryption name (empty)
+    out.extend_from_slice(&0u32.to_le_bytes()); // block size
+    out.extend_from_slice(&0u32.to_le_bytes()); // cipher mode
+    out.extend_from_slice(&4u32.to_le_bytes()); // reserved
+    out
+}
+
+/// Encode a password as UTF-16LE, as used by all key derivations.
+fn utf16_le(value: &str) -> Vec<u8> {
+    value.encode_utf16().flat_map(u16::to_le_bytes).collect()
+}
+
+/// Fill a buffer of the given length from the operating system's random
+/// number generator.
+fn random_bytes(len: usize) -> Result<Vec<u8>> {
+    let mut bytes = vec![0u8; len];
+    getrandom::fill(&mut bytes).map_err(std::io::Error::other)?;
+    Ok(bytes)
+}
+
+/// Resize a derived value, padding with `0x36` as the key derivations require.
+fn resized(mut value: Vec<u8>, len: usize) -> Vec<u8> {
+    value.resize(len, 0x36);
+    value
+}
+
+/// Hash algorithms allowed by Agile encryption.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HashAlgorithm {
+    Sha1,
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl HashAlgorithm {
+    fn from_name(name: &str) -> Result<Self> {
+        match name {
+            "SHA1" | "SHA-1" => Ok(Self::Sha1),
+            "SHA256" => Ok(Self::Sha256),
+            "SHA384" => Ok(Self::Sha384),
+            "SHA512" => Ok(Self::Sha512),
+            _ => Err(Error::Unsupported(format!("hash algorithm {}", name))),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Sha1 => "SHA1",
+            Self::Sha256 => "SHA256",
+            Self::Sha384 => "SHA384",
+            Self::Sha512 => "SHA512",
+        }
+    }
+
+    /// Size of a digest in bytes.
+    fn size(self) -> usize {
+        match self {
+            Self::Sha1 => 20,
+            Self::Sha256 => 32,
+            Self::Sha384 => 48,
+            Self::Sha512 => 64,
+        }
+    }
+
+    /// Hash the concatenation of the given parts.
+    fn digest(self, parts: &[&[u8]]) -> Vec<u8> {
+        fn run<D: Digest>(parts: &[&[u8]]) -> Vec<u8> {
+            let mut hasher = D::new();
+            for part in parts {
+                hasher.update(part);
+            }
+            hasher.finalize().to_vec()
+        }
+        match self {
+            Self::Sha1 => run::<Sha1>(parts),
+            Self::Sha256 => run::<Sha256>(parts),
+            Self::Sha384 => run::<Sha384>(parts),
+            Self::Sha512 => run::<Sha512>(parts),
+        }
+    }
+
+    /// Compute an HMAC of the data.
+    fn hmac(self, key: &[u8], data: &[u8]) -> Vec<u8> {
+        fn run<M: Mac + KeyInit>(key: &[u8], data: &[u8]) -> Vec<u8> {
+            let mut mac = <M as KeyInit>::new_from_slice(key).expect("HMAC accepts any key length");
+            mac.update(data);
+            mac.finalize().into_bytes().to_vec()
+        }
+        match self {
+            Self::Sha1 => run::<Hmac<Sha1>>(key, data),
+            Self::Sha256 => run::<Hmac<Sha256>>(key, data),
+            Self::Sha384 => run::<Hmac<Sha384>>(key, data),
+            Self::Sha512 => run::<Hmac<Sha512>>(key, data),
+        }
+    }
+}
+
+/// AES with a key of any of the supported sizes.
+enum Aes {
+    Aes128(Aes128),
+    Aes192(Aes192),
+    Aes256(Aes256),
+}
+
+impl Aes {
+    fn new(key: &[u8]) -> Result<Self> {
+        let invalid = |_| Error::Invalid(format!("invalid AES key length {}", key.len()));
+        match key.len() {
+            16 => Aes128::new_from_slice(key)
+                .map(Self::Aes128)
+                .map_err(invalid),
+            24 => Aes192::new_from_slice(key)
+                .map(Self::Aes192)
+                .map_err(invalid),
+            32 => Aes256::new_from_slice(key)
+                .map(Self::Aes256)
+                .map_err(invalid),
+            len => Err(Error::Unsupported(format!("AES key length {}", len * 8))),
+        }
+    }
+
+    fn encrypt_block(&self, block: &mut [u8]) {
+        let block = GenericArray::from_mut_slice(block);
+        match self {
+            Self::Aes128(cipher) => cipher.encrypt_block(block),
+            Self::Aes192(cipher) => cipher.encrypt_block(block),
+            Self::Aes256(cipher) => cipher.encrypt_block(block),
+        }
+    }
+
+    fn decrypt_block(&self, block: &mut [u8]) {
+        let block = GenericArray::from_mut_slice(block);
+        match self {
+            Self::Aes128(cipher) => cipher.decrypt_block(block),
+            Self::Aes192(cipher) => cipher.decrypt_block(block),
+            Self::Aes256(cipher) => cipher.decrypt_block(block),
+        }
+    }
+
+    /// Encrypt in CBC mode. The data must be a whole number of blocks.
+    fn cbc_encrypt(&self, iv: &[u8], data: &mut [u8]) {
+        let mut previous = iv.to_vec();
+        for block in data.chunks_exact_mut(16) {
+            block.iter_mut().zip(&previous).for_each(|(b, p)| *b ^= p);
+            self.encrypt_block(block);
+            previous.copy_from_slice(block);
+        }
+    }
+
+    /// Decrypt in CBC mode. The data must be a whole number of blocks.
+    fn cbc_decrypt(&self, iv: &[u8], data: &mut [u8]) {
+        let mut previous = iv.to_vec();
+        for block in data.chunks_exact_mut(16) {
+            let ciphertext = block.to_vec();
+            self.decrypt_block(block);
+            block.iter_mut().zip(&previous).for_each(|(b, p)| *b ^= p);
+            previous = ciphertext;
+        }
+    }
+
+    /// Decrypt in ECB mode. The data must be a whole number of blocks.
+    fn ecb_decrypt(&self, data: &mut [u8]) {
+        data.chunks_exact_mut(16)
+            .for_each(|block| self.decrypt_block(block));
+    }
+}
+
+/// Check that encrypted data is a whole number of AES blocks.
+fn check_blocks(data: &[u8], what: &str) -> Result<()> {
+    if data.len().is_multiple_of(16) {
+        Ok(())
+    } else {
+        Err(Error::Invalid(format!(
+            "{} is not a multiple of the block size",
+            what
+        )))
+    }
+}
+
+/// Check that a key size read from the file is one AES supports.
+fn check_key_bits(key_bits: usize) -> Result<usize> {
+    match key_bits {
+        128 | 192 | 256 => Ok(key_bits),
+        _ => Err(Error::Invalid(format!("invalid AES key size {}", key_bits))),
+    }
+}
+
+/// Split the `EncryptedPackage` stream into the package size and the data.
+fn split_package(stream: &[u8]) -> Result<(usize, &[u8])> {
+    if stream.len() < 8 {
+        return Err(Error::Invalid(
+            "EncryptedPackage stream is truncated".into(),
+        ));
+    }
+    let (size, data) = stream.split_at(8);
+    let size = u64::from_le_bytes(size.try_into().expect("8 bytes")) as usize;
+    if size > data.len() {
+        return Err(Error::Invalid(
+            "EncryptedPackage stream is truncated".into(),
+        ));
+    }
+    Ok((size, data))
+}
+
+/// Cipher parameters shared by `keyData` and the password key encryptor.
+#[derive(Debug, Clone)]
+struct KeyParams {
+    salt: Vec<u8>,
+    block_size: usize,
+    key_bits: usize,
+    hash: HashAlgorithm,
+}
+
+impl KeyParams {
+    /// Read the parameters from the attributes of a `keyData` or
+    /// `encryptedKey` element.
+    fn parse(attrs: &[(String, String)]) -> Result<Self> {
+        let get = |name: &str| {
+            attrs
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.as_str())
+                .ok_or_else(|| Error::Invalid(format!("missing encryption attribute {}", name)))
+        };
+        let number = |name: &str| -> Result<usize> {
+            get(name)?
+                .parse()
+                .map_err(|_| Error::Invalid(format!("invalid encryption attribute {}", name)))
+        };
+
+        let cipher = get("cipherAlgorithm")?;
+        if cipher != "AES" {
+            return Err(Error::Unsupported(format!("cipher algorithm {}", cipher)));
+        }
+        let chaining = get("cipherChaining")?;
+        if chaining != "ChainingModeCBC" {
+            return Err(Error::Unsupported(format!("cipher chaining {}", chaining)));
+        }
+        let block_size = number("blockSize")?;
+        if block_size != 16 {
+            return Err(Error::Invalid(format!(
+                "invalid AES block size {}",
+                block_size
+            )));
+        }
+        let key_bits = check_key_bits(number("keyBits")?)?;
+        let hash = HashAlgorithm::from_name(get("hashAlgorithm")?)?;
+        let hash_size = number("hashSize")?;
+        if hash_size != hash.size() {
+            return Err(Error::Invalid(format!(
+                "invalid hash size {} for {}",
+                hash_size,
+                hash.name()
+            )));
+        }
+        // §2.3.4.10 allows salts of 1 to 65536 bytes.
+        let salt_size = number("saltSize")?;
+        let salt = decode_base64(get("saltValue")?)?;
+        if !(1..=65536).contains(&salt_size) || salt.len() != salt_size {
+            return Err(Error::Invalid(format!("invalid salt size {}", salt_size)));
+        }
+
+        Ok(Self {
+            salt,
+            block_size,
+            key_bits,
+            hash,
+        })
+    }
+
+    /// The attributes describing these parameters, for writing.
+    fn attributes(&self) -> String {
+        format!(
+            concat!(
+                r#"saltSize="{}" blockSize="{}" keyBits="{}" hashSize="{}" "#,
+                r#"cipherAlgorithm="AES" cipherChaining="ChainingModeCBC" "#,
+                r#"hashAlgorithm="{}" saltValue="{}""#,
+            ),
+            self.salt.len(),
+            self.block_size,
+            self.key_bits,
+            self.hash.size(),
+            self.hash.name(),
+            BASE64.encode(&self.salt),
+        )
+    }
+
+    /// Hash the password with the salt, iterated `spin_count` times (§2.3.4.11).
+    fn password_hash(&self, password: &str, spin_count: u32) -> Vec<u8> {
+        let mut hash = self.hash.digest(&[&self.salt, &utf16_le(password)]);
+        for iteration in 0..spin_count {
+            hash = self.hash.digest(&[&iteration.to_le_bytes(), &hash]);
+        }
+        hash
+    }
+
+    /// Derive a key from the password hash for one of the block keys.
+    fn derive_key(&self, password_hash: &[u8], block_key: &[u8]) -> Vec<u8> {
+        resized(
+            self.hash.digest(&[password_hash, block_key]),
+            self.key_bits / 8,
+        )
+    }
+
+    /// The initialization vector: the salt, or the hash of the salt and a
+    /// block key (§2.3.4.12).
+    fn iv(&self, block_key: Option<&[u8]>) -> Vec<u8> {
+        let iv = match block_key {
+            Some(block_key) => self.hash.digest(&[&self.salt, block_key]),
+            None => self.salt.clone(),
+        };
+        resized(iv, self.block_size)
+    }
+
+    /// The initialization vector of a package segment (§2.3.4.15).
+    fn segment_iv(&self, index: u32) -> Vec<u8> {
+        self.iv(Some(&index.to_le_bytes()))
+    }
+
+    fn encrypt(&self, key: &[u8], iv: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+        let mut data = data.to_vec();
+        data.resize(data.len().next_multiple_of(self.block_size), 0);
+        Aes::new(key)?.cbc_encrypt(iv, &mut data);
+        Ok(data)
+    }
+
+    fn decrypt(&self, key: &[u8], iv: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+        check_blocks(data, "encrypted value")?;
+        let mut data = data.to_vec();
+        Aes::new(key)?.cbc_decrypt(iv, &mut data);
+        Ok(data)
+    }
+}
+
+fn decode_base64(value: &str) -> Result<Vec<u8>> {
+    BASE64
+        .decode(value.trim())
+        .map_err(|e| Error::Invalid(format!("invalid base64 in encryption info: {}", e)))
+}
+
+/// The password key encryptor of an Agile encryption descriptor.
+struct PasswordEncryptor {
+    params: KeyParams,
+    spin_count: u32,
+    verifier_input: Vec<u8>,
+    verifier_value: Vec<u8>,
+    key_value: Vec<u8>,
+}
+
+/// Agile encryption (§2.3.4.10).
+struct AgileEncryption {
+    key_data: KeyParams,
+    /// Encrypted HMAC key and value, if the descriptor has `dataIntegrity`.
+    integrity: Option<(Vec<u8>, Vec<u8>)>,
+    password: PasswordEncryptor,
+}
+
+impl AgileEncryption {
+    /// Parse the XML encryption descriptor.
+    fn parse(xml: &[u8]) -> Result<Self> {
+        let mut reader = Reader::from_reader(xml);
+        let mut buf = Vec::new();
+        let mut key_data = None;
+        let mut integrity = None;
+        let mut password = None;
+
+        loop {
+            match reader.read_event_into(&mut buf)? {
+                Event::Start(e) | Event::Empty(e) => {
+                    let attrs: Vec<(String, String)> = e
+                        .attributes()
+                        .filter_map(|a| a.ok())
+                        .map(|a| {
+                            (
+                                String::from_utf8_lossy(a.key.local_name().as_ref()).into_owned(),
+                                String::from_utf8_lossy(&a.value).into_owned(),
+                            )
+                        })
+                        .collect();
+                    let get = |name: &str| {
+                        attrs
+                            .iter()
+                            .find(|(key, _)| key == name)
+                            .map(|(_, value)| value.as_str())
+                    };
+                    match e.local_name().as_ref() {
+                        b"keyData" => key_data = Some(KeyParams::parse(&attrs)?),
+                        b"dataIntegrity" => {
+                            if let (Some(key), Some(value)) =
+                                (get("encryptedHmacKey"), get("encryptedHmacValue"))
+                            {
+                                integrity = Some((decode_base64(key)?, decode_base64(value)?));
+                            }
+                        }
+                        // Certificate key encryptors also use `encryptedKey`,
+                        // but only the password encryptor has a spin count.
+                        b"encryptedKey" if get("spinCount").is_some() => {
+                            let field = |name: &str| {
+                                get(name).ok_or_else(|| {
+                                    Error::Invalid(format!("missing encryption attribute {}", name))
+                                })
+                            };
+                            let spin_count: u32 = field("spinCount")?.parse().map_err(|_| {
+                                Error::Invalid("invalid encryption attribute spinCount".into())
+                            })?;
+                            if spin_count > MAX_SPIN_COUNT {
+                                return Err(Error::Invalid(format!(
+                                    "spinCount {} exceeds the maximum of {}",
+                                    spin_count, MAX_SPIN_COUNT
+                                )));
+                            }
+                            password = Some(PasswordEncryptor {
+                                params: KeyParams::parse(&attrs)?,
+                                spin_count,
+                                verifier_input: decode_base64(field(
+                                    "encryptedVerifierHashInput",
+                                )?)?,
+                                verifier_value: decode_base64(field(
+                                    "encryptedVerifierHashValue",
+                                )?)?,
+                                key_value: decode_base64(field("encryptedKeyValue")?)?,
+                            });
+                        }
+                        _ => {}
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+            buf.clear();
+        }
+
+        Ok(Self {
+            key_data: key_data.ok_or_else(|| Error::Invalid("missing keyData".into()))?,
+            integrity,
+            password: password.ok_or_else(|| {
+                Error::Unsupported("encryption without a password key encryptor".into())
+            })?,
+        })
+    }
+
+    fn decrypt(&self, password: &str, stream: &[u8]) -> Result<Vec<u8>> {
+        let secret_key = self.secret_key(password)?;
+        let key_data = &self.key_data;
+
+        if let Some((encrypted_key, encrypted_value)) = &self.integrity {
+            let size = key_data.hash.size();
+            let iv = key_data.iv(Some(&BLOCK_INTEGRITY_KEY));
+            let hmac_key = key_data.decrypt(&secret_key, &iv, encrypted_key)?;
+            let iv = key_data.iv(Some(&BLOCK_INTEGRITY_VALUE));
+            let expected = key_data.decrypt(&secret_key, &iv, encrypted_value)?;
+            if hmac_key.len() < size
+                || expected.len() < size
+                || key_data.hash.hmac(&hmac_key[..size], stream) != expected[..size]
+            {
+                return Err(Error::Invalid(
+                    "encrypted package failed the data integrity check".into(),
+                ));
+            }
+        }
+
+        let (size, data) = split_package(stream)?;
+        let cipher = Aes::new(&secret_key)?;
+        let mut package = Vec::with_capacity(data.len());
+        for (index, segment) in data.chunks(SEGMENT_SIZE).enumerate() {
+            check_blocks(segment, "encrypted package")?;
+            let mut segment = segment.to_vec();
+            cipher.cbc_decrypt(&key_data.segment_iv(index as u32), &mut segment);
+            package.extend_from_slice(&segment);
+        }
+        package.truncate(size);
+        Ok(package)
+    }
+
+    /// Verify the password and decrypt the secret key (§2.3.4.13).
+    fn secret_key(&self, password: &str) -> Result<Vec<u8>> {
+        let encryptor = &self.password;
+        let params = &encryptor.params;
+        let password_hash = params.password_hash(password, encryptor.spin_count);
+        let decrypt_with = |block_key: &[u8], data: &[u8]| {
+            let key = params.derive_key(&password_hash, block_key);
+            params.decrypt(&key, &params.iv(None), data)
+        };
+
+        let mut verifier_input = decrypt_with(&BLOCK_VERIFIER_INPUT, &encryptor.verifier_input)?;
+        verifier_input.truncate(params.salt.len());
+        let mut verifier_value = decrypt_with(&BLOCK_VERIFIER_VALUE, &encryptor.verifier_value)?;
+        verifier_value.truncate(params.hash.size());
+        if params.hash.digest(&[&verifier_input]) != verifier_value {
+            return Err(Error::InvalidPassword);
+        }
+
+        let mut secret_key = decrypt_with(&BLOCK_KEY_VALUE, &encryptor.key_value)?;
+        secret_key.truncate(self.key_data.key_bits / 8);
+        Ok(secret_key)
+    }
+}
+
+/// Standard encryption (§2.3.4.5).
+struct StandardEncryption {
+    key_bytes: usize,
+    salt: Vec<u8>,
+    verifier: Vec<u8>,
+    verifier_hash: Vec<u8>,
+}
+
+impl StandardEncryption {
+    /// Parse the encryption header and verifier following the version fields.
+    fn parse(data: &[u8]) -> Result<Self> {
+        let truncated = || Error::Invalid("EncryptionInfo stream is truncated".into());
+        let u32_at = |offset: usize| -> Result<u32> {
+            data.get(offset..offset + 4)
+                .map(|b| u32::from_le_bytes(b.try_into().expect("4 bytes")))
+                .ok_or_else(truncated)
+        };
+
+        let header_size = u32_at(0)? as usize;
+        let flags = u32_at(4)?;
+        let alg_id = u32_at(12)?;
+        let key_bits = u32_at(20)? as usize;
+        let key_bits = match alg_id {
+            ALG_AES_128 | ALG_AES_192 | ALG_AES_256 => check_key_bits(key_bits)?,
+            0 if flags & FLAG_AES != 0 => check_key_bits(key_bits.max(128))?,
+            ALG_RC4 | 0 => {
+                return Err(Error::Unsupported("RC4 encryption".into()));
+            }
+            other => {
+                return Err(Error::Unsupported(format!(
+                    "encryption algorithm 0x{:04X}",
+                    other
+                )));
+            }
+        };
+
+        let verifier = 4usize.checked_add(header_size).ok_or_else(truncated)?;
+        // §2.3.4.5 fixes the salt at 16 bytes and the verifier hash (SHA-1)
+        // at 20 bytes.
+        let salt_size = u32_at(verifier)? as usize;
+        if salt_size != 16 {
+            return Err(Error::Invalid(format!("invalid salt size {}", salt_size)));
+        }
+        let salt_end = verifier + 4 + salt_size;
+        let salt = data.get(verifier + 4..salt_end).ok_or_else(truncated)?;
+        let encrypted_verifier = data.get(salt_end..salt_end + 16).ok_or_else(truncated)?;
+        let hash_size = u32_at(salt_end + 16)?;
+        if hash_size != 20 {
+            return Err(Error::Invalid(format!(
+                "invalid verifier hash size {}",
+                hash_size
+            )));
+        }
+        // The verifier hash size field is followed by the hash padded to a
+        // whole number of AES blocks.
+        let hash = data
+            .get(salt_end + 20..salt_end + 52)
+            .ok_or_else(truncated)?;
+
+        Ok(Self {
+            key_bytes: key_bits / 8,
+            salt: salt.to_vec(),
+            verifier: encrypted_verifier.to_vec(),
+            verifier_hash: hash.to_vec(),
+        })
+    }
+
+    fn decrypt(&self, password: &str, stream: &[u8]) -> Result<Vec<u8>> {
+        let cipher = Aes::new(&self.derive_key(password))?;
+
+        let mut verifier = self.verifier.clone();
+        cipher.ecb_decrypt(&mut verifier);
+        let mut verifier_hash = self.verifier_hash.clone();
+        cipher.ecb_decrypt(&mut verifier_hash);
+        if HashAlgorithm::Sha1.digest(&[&verifier]) != verifier_hash[..20] {
+            return Err(Error::InvalidPassword);
+        }
+
+        let (size, data) = split_package(stream)?;
+        let mut package = data.to_vec();
+        package.truncate(package.len() - package.len() % 16);
+        cipher.ecb_decrypt(&mut package);
+        if size > package.len() {
+            return Err(Error::Invalid(
+                "EncryptedPackage stream is truncated".into(),
+            ));
+        }
+        package.truncate(size);
+        Ok(package)
+    }
+
+    /// Derive the encryption key from the password (§2.3.4.7).
+    fn derive_key(&self, password: &str) -> Vec<u8> {
+        let sha1 = HashAlgorithm::Sha1;
+        let mut hash = sha1.digest(&[&self.salt, &utf16_le(password)]);
+        for iteration in 0..50_000u32 {
+            hash = sha1.digest(&[&iteration.to_le_bytes(), &hash]);
+        }
+        let hash = sha1.digest(&[&hash, &0u32.to_le_bytes()]);
+
+        let derive = |fill: u8| {
+            let mut buf = [fill; 64];
+            buf.iter_mut().zip(&hash).for_each(|(b, h)| *b ^= h);
+            sha1.digest(&[&buf])
+        };
+        let mut key = derive(0x36);
+        key.extend(derive(0x5C));
+        key.truncate(self.key_bytes);
+        key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PACKAGE: &[u8] = b"PK\x03\x04 not really a zip, but long enough to span blocks";
+
+    // Known-answer vectors for the tests below were computed independently of
+    // this module, following §2.3.4.7 (Standard) and §2.3.4.10–§2.3.4.15
+    // (Agile), with Python's hashlib, hmac and the `cryptography` package.
+
+    fn hex(value: &str) -> Vec<u8> {
+        (0..value.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&value[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    /// An Agile descriptor as Office writes it: AES-256, SHA-512, with a
+    /// password key encryptor.
+    fn agile_descriptor(spin_count: &str) -> String {
+        format!(
+            concat!(
+                r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+                "\r\n",
+                r#"<encryption xmlns="{ns}" xmlns:p="{ns_password}">"#,
+                r#"<keyData saltSize="16" blockSize="16" keyBits="256" hashSize="64" "#,
+                r#"cipherAlgorithm="AES" cipherChaining="ChainingModeCBC" hashAlgorithm="SHA512" "#,
+                r#"saltValue="EBESExQVFhcYGRobHB0eHw=="/>"#,
+                r#"<dataIntegrity "#,
+                r#"encryptedHmacKey="dFOh1prj0wix1QMikz7Exd9QcXuGGV6/k4BohNFQCHf7MfsumQ0RxiZjPGlSrglI6cURvIPU9qSiiT/vXBkuBg==" "#,
+                r#"encryptedHmacValue="KmK9n/66LL1Khts0kr7QAnh+ox6jvDxTrFpb4CwfbgPdr7DEHyG3VY4nP3dc8iFrdRxg1/5Yk8hFDQeoDJ7c6g=="/>"#,
+                r#"<keyEncryptors><keyEncryptor uri="{ns_password}">"#,
+                r#"<p:encryptedKey spinCount="{spin_count}" saltSize="16" blockSize="16" keyBits="256" "#,
+                r#"hashSize="64" cipherAlgorithm="AES" cipherChaining="ChainingModeCBC" "#,
+                r#"hashAlgorithm="SHA512" saltValue="oKGio6SlpqeoqaqrrK2urw==" "#,
+                r#"encryptedVerifierHashInput="Bcj44MmCThugTp8yAHPviQ==" "#,
+                r#"encryptedVerifierHashValue="xxOgFWuqJtrtfcR2UFinfqG/533bD0ivMKcFyznIrLUL8Ret7lH0oWn8UYnDufjbTOTgsTg9a/SClAT1RgekDg==" "#,
+                r#"encryptedKeyValue="r5btx1TzKPjDGpmX9VjcBNhPaa1h73S7nyNoLNNTygI="/>"#,
+                r#"</keyEncryptor></keyEncryptors></encryption>"#,
+            ),
+            ns = NS_ENCRYPTION,
+            ns_password = NS_PASSWORD_ENCRYPTOR,
+            spin_count = spin_count,
+        )
+    }
+
+    #[test]
+    fn test_agile_roundtrip() {
+        let package: Vec<u8> = PACKAGE.iter().copied().cycle().take(10_000).collect();
+        let encrypted = encrypt(&package, "Pässwörd").unwrap();
+        let mut cursor = Cursor::new(encrypted);
+        assert!(is_encrypted(&mut cursor).unwrap());
+        assert_eq!(cursor.position(), 0);
+
+        let mut compound = cfb::CompoundFile::open(&mut cursor).unwrap();
+        assert!(compound.is_stream("/\u{6}DataSpaces/DataSpaceMap"));
+        let info = read_stream(&mut compound, "EncryptionInfo", u64::MAX).unwrap();
+        assert_eq!(&info[..8], &[4, 0, 4, 0, 0x40, 0, 0, 0]);
+        cursor.set_position(0);
+
+        assert_eq!(decrypt(cursor.clone(), "Pässwörd").unwrap(), package);
+        assert!(matches!(
+            decrypt(cursor, "password"),
+            Err(Error::InvalidPassword)
+        ));
+    }
+
+    #[test]
+    fn test_agile_integrity_check() {
+        let encrypted = encrypt(PACKAGE, "secret").unwrap();
+        let mut compound = cfb::CompoundFile::open(Cursor::new(encrypted)).unwrap();
+        let info = read_stream(&mut compound, "EncryptionInfo", u64::MAX).unwrap();
+        let mut package = read_stream(&mut compound, "EncryptedPackage", u64::MAX).unwrap();
+        let last = package.len() - 1;
+        package[last] ^= 1;
+
+        let tampered = write_compound_file(&info, &package).unwrap();
+        let err = decrypt(Cursor::new(tampered), "secret").unwrap_err();
+        assert!(matches!(err, Error::Invalid(_)), "{:?}", err);
+    }
+
+    #[test]
+    fn test_standard_decrypt() {
+        // Build a Standard-encrypted file with AES-128 the way Office 2007 does.
+        let salt = [7u8; 16];
+        let standard = StandardEncryption {
+            key_bytes: 16,
+            salt: salt.to_vec(),
+            verifier: Vec::new(),
+            verifier_hash: Vec::new(),
+        };
+        let key = standard.derive_key("secret");
+        let cipher = Aes128::new_from_slice(&key).unwrap();
+        let ecb_encrypt = |data: &mut [u8]| {
+            data.chunks_exact_mut(16)
+                .for_each(|b| cipher.encrypt_block(GenericArray::from_mut_slice(b)));
+        };
+
+        let mut verifier = [3u8; 16];
+        let mut verifier_hash = HashAlgorithm::Sha1.digest(&[&verifier]);
+        verifier_hash.resize(32, 0);
+        ecb_encrypt(&mut verifier);
+        ecb_encrypt(&mut verifier_hash);
+
+        let csp = utf16_le("Microsoft Enhanced RSA and AES Cryptographic Provider\0");
+        let mut info = vec![3, 0, 2, 0];
+        info.extend_from_slice(&0x24u32.to_le_bytes());
+        info.extend_from_slice(&(32 + csp.len() as u32).to_le_bytes());
+        for value in [0x24u32, 0, ALG_AES_128, 0x8004, 128, 0x18, 0, 0] {
+            info.extend_from_slice(&value.to_le_bytes());
+        }
+        info.extend_from_slice(&csp);
+        info.extend_from_slice(&16u32.to_le_bytes());
+        info.extend_from_slice(&salt);
+        info.extend_from_slice(&verifier);
+        info.extend_from_slice(&20u32.to_le_bytes());
+        info.extend_from_slice(&verifier_hash);
+
+        let mut stream = (PACKAGE.len() as u64).to_le_bytes().to_vec();
+        let mut data = PACKAGE.to_vec();
+        data.resize(data.len().next_multiple_of(16), 0);
+        ecb_encrypt(&mut data);
+        stream.extend_from_slice(&data);
+
+        let file = write_compound_file(&info, &stream).unwrap();
+        assert_eq!(
+            decrypt(Cursor::new(file.clone()), "secret").unwrap(),
+            PACKAGE
+        );
+        assert!(matches!(
+            decrypt(Cursor::new(file), "wrong"),
+            Err(Error::InvalidPassword)
+        ));
+    }
+
+    #[test]
+    fn test_agile_known_answer() {
+        let agile = AgileEncryption::parse(agile_descriptor("100000").as_bytes()).unwrap();
+        let encryptor = &agile.password.params;
+        let password_hash = encryptor.password_hash("Pässwörd", agile.password.spin_count);
+        assert_eq!(
+            password_hash,
+            hex(concat!(
+                "0c0a5bf50a7d53cd279450bd872377f5efdee612cbe881c2fd5cf10172e1c495",
+                "66acd75e85d0432347356ffa1922422cd35b0d3013639999cbdda883acefa929",
+            ))
+        );
+        assert_eq!(
+            encryptor.derive_key(&password_hash, &BLOCK_KEY_VALUE),
+            hex("dbf13db255320be1adc7793b36b051ddaf7301ee6f177ab3845159f5befe8e1f")
+        );
+        assert_eq!(
+            agile.key_data.segment_iv(1),
+            hex("da22ccba062aff577d207b2dee4e165b")
+        );
+
+        let stream = hex(concat!(
+            "2a00000000000000cee19447a444d04ccccde01a8ecd1132332ceaeb4ec170ba",
+            "69d99bf036c479f66ea8e0d586fb634890582a9c4a0688a6",
+        ));
+        assert_eq!(
+            agile.decrypt("Pässwörd", &stream).unwrap(),
+            b"PK\x03\x04 known-answer package, two AES blocks+"
+        );
+        assert!(matches!(
+            agile.decrypt("Password", &stream),
+            Err(Error::InvalidPassword)
+        ));
+    }
+
+    #[test]
+    fn test_agile_spin_count_limit() {
+        let descriptor = agile_descriptor("4294967295");
+        let err = AgileEncryption::parse(descriptor.as_bytes()).err().unwrap();
+        assert!(matches!(err, Error::Invalid(_)), "{:?}", err);
+    }
+
+    #[test]
+    fn test_agile_key_params_limits() {
+        let descriptor = agile_descriptor("100000");
+        for (from, to) in [
+            (r#"keyBits="256""#, r#"keyBits="99999999999999""#),
+            (r#"keyBits="256""#, r#"keyBits="64""#),
+            (r#"hashSize="64""#, r#"hashSize="4096""#),
+            (r#"saltSize="16""#, r#"saltSize="99999999""#),
+            (r#"blockSize="16""#, r#"blockSize="4096""#),
+        ] {
+            let crafted = descriptor.replacen(from, to, 1);
+            let err = AgileEncryption::parse(crafted.as_bytes()).err().unwrap();
+            assert!(matches!(err, Error::Invalid(_)), "{}: {:?}", to, err);
+        }
+    }
+
+    #[test]
+    fn test_standard_header_limits() {
+        // The header and verifier after the version fields, without a CSP name.
+        let header = |key_bits: u32, salt_size: u32, hash_size: u32| {
+            let mut data = 32u32.to_le_bytes().to_vec();
+            for value in [0x24u32, 0, ALG_AES_128, 0x8004, key_bits, 0x18, 0, 0] {
+                data.extend_from_slice(&value.to_le_bytes());
+            }
+            data.extend_from_slice(&salt_size.to_le_bytes());
+            data.extend_from_slice(&[0; 32]);
+            data.extend_from_slice(&hash_size.to_le_bytes());
+            data.extend_from_slice(&[0; 32]);
+            data
+        };
+        assert!(StandardEncryption::parse(&header(128, 16, 20)).is_ok());
+        for data in [
+            header(4_000_000_000, 16, 20),
+            header(8, 16, 20),
+            header(128, 4_000_000_000, 20),
+            header(128, 16, 4_000_000_000),
+        ] {
+            let err = StandardEncryption::parse(&data).err().unwrap();
+            assert!(matches!(err, Error::Invalid(_)), "{:?}", err);
+        }
+    }
+
+    #[test]
+    fn test_standard_known_answer() {
+        let standard = StandardEncryption {
+            key_bytes: 16,
+            salt: (0x20..0x30).collect(),
+            verifier: hex("82e3e6dfd2ec0cf3c4a2e6e736bac479"),
+            verifier_hash: hex("5262fe924cde2d26845dd8a3746cfe612f9c1a60f92f9bb419ec612f5591250f"),
+        };
+        assert_eq!(
+            standard.derive_key("secret"),
+            hex("d2df3d7c221c5050dc0c76362eb77033")
+        );
+
+        let stream = hex(concat!(
+            "1a000000000000001610e3401f28e87fffeaa47acdacaf9aba7c633388c57d7b",
+            "d1c56c6a0a461c0a",
+        ));
+        assert_eq!(
+            standard.decrypt("secret", &stream).unwrap(),
+            b"PK\x03\x04 standard known-answer"
+        );
+    }
+}
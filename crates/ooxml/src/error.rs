@@ -31,4 +31,26 @@ pub enum Error {
     /// Unsupported feature or element.
     #[error("unsupported: {0}")]
     Unsupported(String),
+
+    /// The package is encrypted and must be opened with a password.
+    #[error("package is encrypted; a password is required to open it")]
+    Encrypted,
+
+    /// The password for an encrypted package is incorrect.
+    #[error("invalid password")]
+    InvalidPassword,
+
+    /// The package exceeds a configured resource limit.
+    #[error("limit exceeded: {0}")]
+    LimitExceeded(String),
+}
+
+impl From<ooxml_xml::Error> for Error {
+    fn from(err: ooxml_xml::Error) -> Self {
+        match err {
+            ooxml_xml::Error::Xml(e) => Error::Xml(e),
+            ooxml_xml::Error::Io(e) => Error::Io(e),
+            ooxml_xml::Error::Invalid(msg) => Error::Invalid(msg),
+        }
+    }
 }
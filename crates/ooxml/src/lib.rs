@@ -1,10 +1,16 @@
 //! Core OOXML library: OPC packaging, relationships, and shared types.
 //!
 //! This crate provides the foundational types for working with Office Open XML files:
-//! - OPC (Open Packaging Conventions) - ZIP-based package format
-//! - Relationships - links between package parts
+//! - OPC (Open Packaging Conventions) - ZIP-based package format, also readable
+//!   as a stream without seeking
+//! - Relationships - links between package parts, and the graph they form
 //! - Content types - MIME type mappings
+//! - Editing - adding, removing and renaming parts of existing packages
 //! - Core/App properties - document metadata
+//! - Encryption - password-protected packages (`encryption` feature)
+//! - Digital signatures - verifying signed packages (`signatures` feature)
+//! - VBA projects - macros of macro-enabled documents
+//! - Resource limits - safeguards for opening untrusted packages
 //!
 //! Format-specific support is in separate crates:
 //! - `ooxml-wml` - WordprocessingML (DOCX)
@@ -32,10 +38,34 @@
 //! # Ok::<(), ooxml_opc::Error>(())
 //! ```
 
+pub mod conformance;
+pub mod editor;
+#[cfg(feature = "encryption")]
+pub mod encryption;
 pub mod error;
+pub mod limits;
 pub mod packaging;
+pub mod part_graph;
 pub mod relationships;
+#[cfg(feature = "signatures")]
+pub mod signatures;
+pub mod streaming;
+pub mod vba;
 
+pub use conformance::Conformance;
+pub use editor::PackageEditor;
 pub use error::{Error, Result};
-pub use packaging::{ContentTypes, Package, PackageWriter, content_type};
-pub use relationships::{Relationship, Relationships, TargetMode, rel_type, rels_path_for};
+pub use limits::{PackageLimits, ParseLimits};
+pub use packaging::{
+    Compression, ContentTypes, NamespaceDeclarations, Package, PackageWriter, PartOptions,
+    SelfClosing, SerializeOptions, content_type,
+};
+pub use part_graph::{PartGraph, ResolvedRelationship};
+pub use relationships::{
+    Relationship, Relationships, TargetMode, rel_type, relative_target, rels_path_for,
+    resolve_target, source_for_rels_path,
+};
+#[cfg(feature = "signatures")]
+pub use signatures::{PackageSignature, SignatureStatus};
+pub use streaming::{StreamedPart, StreamingPackage};
+pub use vba::VbaProject;
@@ -0,0 +1,240 @@
+//! Resource limits for opening untrusted packages.
+//!
+//! A ZIP archive declares the size of each entry, and Deflate can expand data
+//! about a thousandfold, so a small upload can claim or produce gigabytes of
+//! content. A package opened with [`Package::open_with_limits`] is rejected
+//! up front if its declared sizes exceed the limits, and every part read from
+//! it is cut off with [`Error::LimitExceeded`] as soon as it inflates beyond
+//! its declared size. XML parts are also checked against [`ParseLimits`]
+//! before they are returned, which bounds the work of the generated parsers.
+//!
+//! The same limits apply to packages read front to back with
+//! [`StreamingPackage::with_limits`], where sizes are enforced while parts
+//! are inflated, and to password-protected packages opened with
+//! [`Package::open_encrypted_with_limits`], whose encrypted package must fit
+//! in `max_total_size`. Parsing XML from elsewhere with a generated parser
+//! can be bounded with [`ooxml_xml::parse_with_limits`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use ooxml_opc::{Package, PackageLimits};
+//! use std::fs::File;
+//!
+//! let limits = PackageLimits {
+//!     max_total_size: 256 * 1024 * 1024,
+//!     ..PackageLimits::default()
+//! };
+//! let mut pkg = Package::open_with_limits(File::open("upload.xlsx")?, &limits)?;
+//! let workbook = pkg.read_part("xl/workbook.xml")?;
+//! # Ok::<(), ooxml_opc::Error>(())
+//! ```
+//!
+//! [`Package::open_with_limits`]: crate::Package::open_with_limits
+//! [`Package::open_encrypted_with_limits`]: crate::Package::open_encrypted_with_limits
+//! [`StreamingPackage::with_limits`]: crate::StreamingPackage::with_limits
+
+use crate::error::{Error, Result};
+use std::io::Read;
+
+pub use ooxml_xml::ParseLimits;
+
+/// Parts smaller than this are not subject to the compression ratio limit,
+/// since small, repetitive XML parts legitimately compress very well.
+const RATIO_EXEMPT_SIZE: u64 = 1024 * 1024;
+
+/// Limits on the size and structure of a package.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PackageLimits {
+    /// Maximum number of entries in the archive.
+    pub max_parts: usize,
+    /// Maximum uncompressed size of a single part, in bytes.
+    pub max_part_size: u64,
+    /// Maximum uncompressed size of all parts together, in bytes.
+    pub max_total_size: u64,
+    /// Maximum ratio of uncompressed to compressed size of a part larger than
+    /// 1 MiB.
+    pub max_compression_ratio: u64,
+    /// Limits on the structure of XML parts.
+    pub parse: ParseLimits,
+}
+
+impl Default for PackageLimits {
+    fn default() -> Self {
+        Self {
+            max_parts: 10_000,
+            max_part_size: 512 * 1024 * 1024,
+            max_total_size: 1024 * 1024 * 1024,
+            max_compression_ratio: 200,
+            parse: ParseLimits::default(),
+        }
+    }
+}
+
+impl PackageLimits {
+    /// Limits that accept any package.
+    pub fn unlimited() -> Self {
+        Self {
+            max_parts: usize::MAX,
+            max_part_size: u64::MAX,
+            max_total_size: u64::MAX,
+            max_compression_ratio: u64::MAX,
+            parse: ParseLimits::unlimited(),
+        }
+    }
+
+    /// Check the declared sizes of the archive entries, given as
+    /// `(name, uncompressed, compressed)`.
+    pub(crate) fn check_entries<'a>(
+        &self,
+        entries: impl ExactSizeIterator<Item = (&'a str, u64, u64)>,
+    ) -> Result<()> {
+        if entries.len() > self.max_parts {
+            return Err(Error::LimitExceeded(format!(
+                "more than {} parts",
+                self.max_parts
+            )));
+        }
+        let mut total: u64 = 0;
+        for (name, size, compressed) in entries {
+            self.check_entry(name, size, compressed)?;
+            total = total.saturating_add(size);
+            if total > self.max_total_size {
+                return Err(Error::LimitExceeded(format!(
+                    "parts are larger than {} bytes in total",
+                    self.max_total_size
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check the declared uncompressed and compressed size of one entry.
+    pub(crate) fn check_entry(&self, name: &str, size: u64, compressed: u64) -> Result<()> {
+        if size > self.max_part_size {
+            return Err(Error::LimitExceeded(format!(
+                "{} is larger than {} bytes",
+                name, self.max_part_size
+            )));
+        }
+        if size > RATIO_EXEMPT_SIZE && size / compressed.max(1) > self.max_compression_ratio {
+            return Err(Error::LimitExceeded(format!(
+                "{} has a compression ratio above {}",
+                name, self.max_compression_ratio
+            )));
+        }
+        Ok(())
+    }
+
+    /// Check the structure of an XML part. Malformed XML is left for the
+    /// parser to report.
+    pub(crate) fn check_xml(&self, path: &str, xml: &[u8]) -> Result<()> {
+        match self.parse.check(xml) {
+            Err(ooxml_xml::ParseError::LimitExceeded(msg)) => {
+                Err(Error::LimitExceeded(format!("{}: {}", path, msg)))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Whether a part is XML, and so subject to the parse limits: by its
+/// extension, or by an XML content type for parts named otherwise.
+pub(crate) fn is_xml(path: &str, content_type: Option<&str>) -> bool {
+    [".xml", ".rels", ".vml"]
+        .iter()
+        .any(|extension| path.ends_with(extension))
+        || content_type.is_some_and(|content_type| content_type.ends_with("xml"))
+}
+
+/// Reader over a decompressed part that fails once the part produces more
+/// bytes than its entry declared.
+pub(crate) struct BoundedReader<R> {
+    inner: R,
+    remaining: u64,
+}
+
+impl<R> BoundedReader<R> {
+    pub(crate) fn new(inner: R, limit: u64) -> Self {
+        Self {
+            inner,
+            remaining: limit,
+        }
+    }
+}
+
+impl<R: Read> Read for BoundedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.remaining == 0 {
+            // Probe for data beyond the declared size
+            let mut probe = [0u8; 1];
+            return match self.inner.read(&mut probe)? {
+                0 => Ok(0),
+                _ => Err(std::io::Error::other(Error::LimitExceeded(
+                    "part is larger than its declared size".into(),
+                ))),
+            };
+        }
+        let max = buf
+            .len()
+            .min(usize::try_from(self.remaining).unwrap_or(usize::MAX));
+        let n = self.inner.read(&mut buf[..max])?;
+        self.remaining -= n as u64;
+        Ok(n)
+    }
+}
+
+/// Recover a limit error from the I/O error of a [`BoundedReader`].
+pub(crate) fn from_io(e: std::io::Error) -> Error {
+    e.downcast::<Error>().unwrap_or_else(Error::Io)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_entries() {
+        let limits = PackageLimits {
+            max_parts: 2,
+            max_part_size: 4 * RATIO_EXEMPT_SIZE,
+            max_total_size: 5 * RATIO_EXEMPT_SIZE,
+            max_compression_ratio: 10,
+            ..PackageLimits::default()
+        };
+        let mb = RATIO_EXEMPT_SIZE;
+        assert!(limits.check_entries([("a", mb, 1)].into_iter()).is_ok());
+        assert!(
+            limits
+                .check_entries([("a", 2 * mb, mb), ("b", 2 * mb, mb)].into_iter())
+                .is_ok()
+        );
+        for entries in [
+            vec![("a", 1, 1), ("b", 1, 1), ("c", 1, 1)],
+            vec![("a", 5 * mb, mb)],
+            vec![("a", 2 * mb, 1000)],
+            vec![("a", 3 * mb, mb), ("b", 3 * mb, mb)],
+        ] {
+            assert!(matches!(
+                limits.check_entries(entries.into_iter()),
+                Err(Error::LimitExceeded(_))
+            ));
+        }
+        assert!(
+            PackageLimits::unlimited()
+                .check_entries([("a", u64::MAX, 0), ("b", u64::MAX, 0)].into_iter())
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_bounded_reader() {
+        let mut out = Vec::new();
+        let mut reader = BoundedReader::new(&b"12345"[..], 5);
+        reader.read_to_end(&mut out).unwrap();
+        assert_eq!(out, b"12345");
+
+        let mut reader = BoundedReader::new(&b"123456"[..], 5);
+        assert!(reader.read_to_end(&mut Vec::new()).is_err());
+    }
+}
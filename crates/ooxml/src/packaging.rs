@@ -11,39 +11,80 @@
 //! - Various parts (XML files, images, etc.)
 //! - Part-specific relationships in `*/_rels/*.rels`
 
+use crate::conformance::{Conformance, convert_xml};
 use crate::error::{Error, Result};
+use crate::limits::{BoundedReader, PackageLimits};
 use std::collections::HashMap;
-use std::io::{Read, Seek, Write};
+use std::io::{Read, Seek, SeekFrom, Write};
 use zip::read::ZipArchive;
 use zip::write::ZipWriter;
 
+pub use ooxml_xml::{NamespaceDeclarations, SelfClosing, SerializeOptions};
+
 /// An OPC package (ZIP-based container for OOXML files).
 pub struct Package<R> {
     archive: ZipArchive<R>,
     content_types: ContentTypes,
+    /// Limits for untrusted packages, if any.
+    limits: Option<PackageLimits>,
 }
 
 impl<R: Read + Seek> Package<R> {
     /// Open an OPC package from a reader.
+    ///
+    /// Returns [`Error::Encrypted`] for password-protected packages, which
+    /// must be opened with [`Package::open_encrypted`].
+    ///
+    /// No resource limits are applied; use [`Package::open_with_limits`] for
+    /// untrusted input.
     pub fn open(reader: R) -> Result<Self> {
-        let mut archive = ZipArchive::new(reader)?;
-
-        // Parse [Content_Types].xml (required)
-        let content_types = Self::read_content_types(&mut archive)?;
+        Self::open_inner(reader, None)
+    }
 
-        Ok(Self {
-            archive,
-            content_types,
-        })
+    /// Open an untrusted OPC package, enforcing resource limits.
+    ///
+    /// Returns [`Error::LimitExceeded`] if the declared sizes of the parts
+    /// exceed the limits, and from [`read_part`](Self::read_part) if a part
+    /// inflates beyond its declared size or an XML part exceeds the parse
+    /// limits.
+    pub fn open_with_limits(reader: R, limits: &PackageLimits) -> Result<Self> {
+        Self::open_inner(reader, Some(*limits))
     }
 
-    /// Read [Content_Types].xml from the archive.
-    fn read_content_types(archive: &mut ZipArchive<R>) -> Result<ContentTypes> {
-        let file = archive
-            .by_name("[Content_Types].xml")
-            .map_err(|_| Error::MissingPart("[Content_Types].xml".into()))?;
+    fn open_inner(mut reader: R, limits: Option<PackageLimits>) -> Result<Self> {
+        if is_compound_file(&mut reader)? {
+            return Err(Error::Encrypted);
+        }
+        let mut archive = ZipArchive::new(reader)?;
 
-        ContentTypes::parse(file)
+        if let Some(limits) = &limits {
+            let mut entries = Vec::with_capacity(archive.len());
+            for i in 0..archive.len() {
+                let file = archive.by_index_raw(i)?;
+                entries.push((file.name().to_string(), file.size(), file.compressed_size()));
+            }
+            limits.check_entries(
+                entries
+                    .iter()
+                    .map(|(name, size, compressed)| (name.as_str(), *size, *compressed)),
+            )?;
+        }
+
+        let mut package = Self {
+            archive,
+            content_types: ContentTypes::new(),
+            limits,
+        };
+        // Parse [Content_Types].xml (required)
+        let data = package
+            .read_part("[Content_Types].xml")
+            .map_err(|e| match e {
+                Error::MissingPart(_) => Error::MissingPart("[Content_Types].xml".into()),
+                e => e,
+            })?;
+        package.content_types = ContentTypes::parse(&data[..])?;
+
+        Ok(package)
     }
 
     /// Get the content types for this package.
@@ -63,14 +104,37 @@ impl<R: Read + Seek> Package<R> {
 
     /// Read a part's contents as bytes.
     pub fn read_part(&mut self, path: &str) -> Result<Vec<u8>> {
-        let mut file = self
+        let mut contents = Vec::new();
+        self.read_part_stream(path)?
+            .read_to_end(&mut contents)
+            .map_err(crate::limits::from_io)?;
+
+        if let Some(limits) = &self.limits
+            && crate::limits::is_xml(path, self.content_types.get(path))
+        {
+            limits.check_xml(path, &contents)?;
+        }
+        Ok(contents)
+    }
+
+    /// Open a part for reading without loading it into memory.
+    ///
+    /// The reader decompresses the part as it is read, so parts larger than
+    /// the available memory (such as multi-gigabyte worksheets in Zip64
+    /// packages) can be processed incrementally. In a package opened with
+    /// limits, reading fails once the part inflates beyond its declared size
+    /// or `max_part_size`; the XML parse limits, which need the whole part,
+    /// are not applied.
+    pub fn read_part_stream(&mut self, path: &str) -> Result<impl Read + '_> {
+        let file = self
             .archive
             .by_name(path)
             .map_err(|_| Error::MissingPart(path.into()))?;
-
-        let mut contents = Vec::new();
-        file.read_to_end(&mut contents)?;
-        Ok(contents)
+        let limit = match self.limits {
+            Some(limits) => file.size().min(limits.max_part_size),
+            None => u64::MAX,
+        };
+        Ok(BoundedReader::new(file, limit))
     }
 
     /// Read a part's contents as a string.
@@ -90,6 +154,17 @@ impl<R: Read + Seek> Package<R> {
         self.read_part_relationships("")
     }
 
+    /// Determine whether the package is a Strict or Transitional document,
+    /// based on the type of its main part relationship.
+    pub fn conformance(&mut self) -> Result<Conformance> {
+        let rels = self.read_relationships()?;
+        Ok(rels
+            .get_by_type(crate::relationships::rel_type::OFFICE_DOCUMENT)
+            .map_or(Conformance::Transitional, |rel| {
+                Conformance::of_uri(&rel.relationship_type)
+            }))
+    }
+
     /// Copy all parts to a writer, replacing specific parts with new content.
     ///
     /// This is the core mechanism for roundtrip preservation: it copies every part
@@ -103,6 +178,20 @@ impl<R: Read + Seek> Package<R> {
         &mut self,
         writer: &mut PackageWriter<W>,
         replacements: &HashMap<&str, &[u8]>,
+    ) -> Result<()> {
+        self.copy_to_writer_except(writer, replacements, &[])
+    }
+
+    /// Copy all parts to a writer, replacing some parts and dropping others.
+    ///
+    /// Behaves like [`copy_to_writer`](Self::copy_to_writer), except that parts
+    /// listed in `removed` are not written to the output. Callers are responsible
+    /// for removing any relationships that still point at the dropped parts.
+    pub fn copy_to_writer_except<W: Write + Seek>(
+        &mut self,
+        writer: &mut PackageWriter<W>,
+        replacements: &HashMap<&str, &[u8]>,
+        removed: &[&str],
     ) -> Result<()> {
         // Transfer all default content types from original package
         for (ext, ct) in self.content_types.defaults() {
@@ -112,7 +201,7 @@ impl<R: Read + Seek> Package<R> {
         // Collect part names and their content types (excluding [Content_Types].xml)
         let parts_info: Vec<(String, String)> = self
             .parts()
-            .filter(|name| *name != "[Content_Types].xml")
+            .filter(|name| *name != "[Content_Types].xml" && !removed.contains(name))
             .map(|name| {
                 let ct = self
                     .content_types
@@ -125,12 +214,21 @@ impl<R: Read + Seek> Package<R> {
 
         // Copy each part, using replacement bytes when provided
         for (name, ct) in &parts_info {
-            let data = if let Some(replacement) = replacements.get(name.as_str()) {
-                replacement.to_vec()
+            if let Some(replacement) = replacements.get(name.as_str()) {
+                writer.add_part(name, ct, replacement)?;
+            } else if writer.converts(ct) {
+                let data = self.read_part(name)?;
+                writer.add_part(name, ct, &data)?;
             } else {
-                self.read_part(name)?
-            };
-            writer.add_part(name, ct, &data)?;
+                // Copy the compressed data as is, without holding the part
+                // in memory
+                let file = self
+                    .archive
+                    .by_name(name)
+                    .map_err(|_| Error::MissingPart(name.as_str().into()))?;
+                writer.content_types.add_override(name, ct);
+                writer.writer.raw_copy_file(file)?;
+            }
         }
 
         Ok(())
@@ -152,35 +250,160 @@ impl<R: Read + Seek> Package<R> {
     }
 }
 
+#[cfg(feature = "encryption")]
+impl Package<std::io::Cursor<Vec<u8>>> {
+    /// Open a password-protected package.
+    ///
+    /// The package is decrypted into memory. Returns [`Error::InvalidPassword`]
+    /// if the password is wrong.
+    pub fn open_encrypted<R: Read + Seek>(reader: R, password: &str) -> Result<Self> {
+        let data = crate::encryption::decrypt(reader, password)?;
+        Package::open(std::io::Cursor::new(data))
+    }
+
+    /// Open an untrusted password-protected package, enforcing resource
+    /// limits.
+    ///
+    /// Returns [`Error::LimitExceeded`] if the encrypted package is larger
+    /// than `max_total_size`, before it is decrypted; the decrypted package
+    /// is then opened as with [`Package::open_with_limits`].
+    pub fn open_encrypted_with_limits<R: Read + Seek>(
+        reader: R,
+        password: &str,
+        limits: &PackageLimits,
+    ) -> Result<Self> {
+        let data = crate::encryption::decrypt_with_limit(reader, password, limits.max_total_size)?;
+        Package::open_with_limits(std::io::Cursor::new(data), limits)
+    }
+}
+
 /// Builder for creating new OPC packages.
 pub struct PackageWriter<W: Write + Seek> {
-    writer: ZipWriter<W>,
+    writer: ZipWriter<Output<W>>,
     content_types: ContentTypes,
+    /// Conformance class to convert XML parts to, if any.
+    conformance: Option<Conformance>,
+    /// Options to reformat XML parts with, if any.
+    serialize_options: Option<SerializeOptions>,
 }
 
 impl<W: Write + Seek> PackageWriter<W> {
     /// Create a new package writer.
     pub fn new(writer: W) -> Self {
         Self {
-            writer: ZipWriter::new(writer),
+            writer: ZipWriter::new(Output::Plain(writer)),
+            content_types: ContentTypes::new(),
+            conformance: None,
+            serialize_options: None,
+        }
+    }
+
+    /// Create a package writer that encrypts the package with a password.
+    ///
+    /// The package is assembled in memory and written to `writer` as an
+    /// encrypted compound file by [`finish`](Self::finish).
+    #[cfg(feature = "encryption")]
+    pub fn new_encrypted(writer: W, password: &str) -> Self {
+        Self {
+            writer: ZipWriter::new(Output::Encrypted {
+                buffer: std::io::Cursor::new(Vec::new()),
+                writer,
+                password: password.to_string(),
+            }),
             content_types: ContentTypes::new(),
+            conformance: None,
+            serialize_options: None,
         }
     }
 
+    /// Convert the namespaces and relationship types of all XML parts added
+    /// from now on to the given conformance class.
+    ///
+    /// Only namespace URIs and relationship types are translated; markup that
+    /// is not allowed in Strict documents (such as VML) is written unchanged.
+    pub fn set_conformance(&mut self, conformance: Conformance) {
+        self.conformance = Some(conformance);
+    }
+
+    /// Reformat all XML parts added from now on, and `[Content_Types].xml`,
+    /// with the given options.
+    ///
+    /// Use [`SerializeOptions::pretty`] for indented, deterministic parts
+    /// that diff well under version control.
+    pub fn set_serialize_options(&mut self, options: SerializeOptions) {
+        self.serialize_options = Some(options);
+    }
+
     /// Add a part to the package.
     pub fn add_part(&mut self, path: &str, content_type: &str, data: &[u8]) -> Result<()> {
+        self.add_part_with_options(path, content_type, data, PartOptions::default())
+    }
+
+    /// Add a part to the package with the given compression options.
+    ///
+    /// Parts of 4 GB or more are written with Zip64 extensions regardless of
+    /// [`PartOptions::large_file`].
+    pub fn add_part_with_options(
+        &mut self,
+        path: &str,
+        content_type: &str,
+        data: &[u8],
+        options: PartOptions,
+    ) -> Result<()> {
         // Register content type
         self.content_types.add_override(path, content_type);
 
+        let converted;
+        let data = match self.conformance {
+            Some(conformance) if content_type.ends_with("xml") => {
+                converted = convert_xml(data, conformance)?;
+                converted.as_slice()
+            }
+            _ => data,
+        };
+        let formatted;
+        let data = match &self.serialize_options {
+            Some(serialize_options) if content_type.ends_with("xml") => {
+                formatted = ooxml_xml::format_xml(data, serialize_options)?;
+                formatted.as_slice()
+            }
+            _ => data,
+        };
+
         // Write to ZIP
-        let options = zip::write::SimpleFileOptions::default()
-            .compression_method(zip::CompressionMethod::Deflated);
-        self.writer.start_file(path, options)?;
+        let large_file = options.large_file || data.len() as u64 >= u32::MAX as u64;
+        self.writer
+            .start_file(path, options.large_file(large_file).to_zip())?;
         self.writer.write_all(data)?;
 
         Ok(())
     }
 
+    /// Start writing a part incrementally, returning a writer for its
+    /// contents.
+    ///
+    /// The part ends when the next part is added or the package is finished.
+    /// Set [`PartOptions::large_file`] for parts that may reach 4 GB. Parts
+    /// written this way are not converted by [`set_conformance`](Self::set_conformance)
+    /// or reformatted by [`set_serialize_options`](Self::set_serialize_options).
+    pub fn start_part(
+        &mut self,
+        path: &str,
+        content_type: &str,
+        options: PartOptions,
+    ) -> Result<impl Write + '_> {
+        self.content_types.add_override(path, content_type);
+        self.writer.start_file(path, options.to_zip())?;
+        Ok(&mut self.writer)
+    }
+
+    /// Check whether parts of the given content type are converted or
+    /// reformatted when added.
+    fn converts(&self, content_type: &str) -> bool {
+        (self.conformance.is_some() || self.serialize_options.is_some())
+            && content_type.ends_with("xml")
+    }
+
     /// Add a default content type mapping for a file extension.
     pub fn add_default_content_type(&mut self, extension: &str, content_type: &str) {
         self.content_types.add_default(extension, content_type);
@@ -189,13 +412,139 @@ impl<W: Write + Seek> PackageWriter<W> {
     /// Finish writing the package.
     pub fn finish(mut self) -> Result<W> {
         // Write [Content_Types].xml
-        let content_types_xml = self.content_types.serialize();
+        let mut content_types_xml = self.content_types.serialize().into_bytes();
+        if let Some(serialize_options) = &self.serialize_options {
+            content_types_xml = ooxml_xml::format_xml(&content_types_xml, serialize_options)?;
+        }
         let options = zip::write::SimpleFileOptions::default()
             .compression_method(zip::CompressionMethod::Deflated);
         self.writer.start_file("[Content_Types].xml", options)?;
-        self.writer.write_all(content_types_xml.as_bytes())?;
+        self.writer.write_all(&content_types_xml)?;
+
+        match self.writer.finish()? {
+            Output::Plain(writer) => Ok(writer),
+            #[cfg(feature = "encryption")]
+            Output::Encrypted {
+                buffer,
+                mut writer,
+                password,
+            } => {
+                let encrypted = crate::encryption::encrypt(&buffer.into_inner(), &password)?;
+                writer.write_all(&encrypted)?;
+                Ok(writer)
+            }
+        }
+    }
+}
+
+/// How a part is compressed in the package.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Compression {
+    /// Stored without compression, e.g. for already compressed images.
+    Stored,
+    /// Compressed with Deflate.
+    #[default]
+    Deflated,
+}
+
+/// Options for writing a part with [`PackageWriter::add_part_with_options`]
+/// or [`PackageWriter::start_part`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PartOptions {
+    compression: Compression,
+    level: Option<u32>,
+    large_file: bool,
+}
+
+impl PartOptions {
+    /// Create options for a deflated part at the default level.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the compression method.
+    pub fn compression(mut self, compression: Compression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Set the Deflate level, from 0 (fastest) to 9 (smallest).
+    pub fn level(mut self, level: u32) -> Self {
+        self.level = Some(level.min(9));
+        self
+    }
 
-        Ok(self.writer.finish()?)
+    /// Write the part with Zip64 extensions so it may exceed 4 GB.
+    pub fn large_file(mut self, large_file: bool) -> Self {
+        self.large_file = large_file;
+        self
+    }
+
+    fn to_zip(self) -> zip::write::SimpleFileOptions {
+        let options = zip::write::SimpleFileOptions::default().large_file(self.large_file);
+        match self.compression {
+            Compression::Stored => options.compression_method(zip::CompressionMethod::Stored),
+            Compression::Deflated => options
+                .compression_method(zip::CompressionMethod::Deflated)
+                .compression_level(self.level.map(i64::from)),
+        }
+    }
+}
+
+/// Signature at the start of every OLE compound file.
+pub(crate) const CFB_SIGNATURE: [u8; 8] = [0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
+
+/// Check whether the data starts with an OLE compound file signature, leaving
+/// the reader where it started.
+pub(crate) fn is_compound_file<R: Read + Seek>(reader: &mut R) -> Result<bool> {
+    let start = reader.stream_position()?;
+    let mut signature = [0u8; 8];
+    let found = match reader.read_exact(&mut signature) {
+        Ok(()) => signature == CFB_SIGNATURE,
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => false,
+        Err(e) => return Err(e.into()),
+    };
+    reader.seek(SeekFrom::Start(start))?;
+    Ok(found)
+}
+
+/// Destination of a [`PackageWriter`]: the output itself, or an in-memory
+/// buffer that is encrypted into the output when the package is finished.
+enum Output<W> {
+    Plain(W),
+    #[cfg(feature = "encryption")]
+    Encrypted {
+        buffer: std::io::Cursor<Vec<u8>>,
+        writer: W,
+        password: String,
+    },
+}
+
+impl<W: Write> Write for Output<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            Self::Plain(writer) => writer.write(buf),
+            #[cfg(feature = "encryption")]
+            Self::Encrypted { buffer, .. } => buffer.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Self::Plain(writer) => writer.flush(),
+            #[cfg(feature = "encryption")]
+            Self::Encrypted { buffer, .. } => buffer.flush(),
+        }
+    }
+}
+
+impl<W: Seek> Seek for Output<W> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        match self {
+            Self::Plain(writer) => writer.seek(pos),
+            #[cfg(feature = "encryption")]
+            Self::Encrypted { buffer, .. } => buffer.seek(pos),
+        }
     }
 }
 
@@ -374,6 +723,33 @@ pub mod content_type {
     pub const WORDPROCESSING_DOCUMENT: &str =
         "application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml";
 
+    /// Macro-enabled WordprocessingML document (`.docm`).
+    pub const WORDPROCESSING_DOCUMENT_MACRO_ENABLED: &str =
+        "application/vnd.ms-word.document.macroEnabled.main+xml";
+
+    /// Macro-enabled SpreadsheetML workbook (`.xlsm`).
+    pub const SPREADSHEET_WORKBOOK_MACRO_ENABLED: &str =
+        "application/vnd.ms-excel.sheet.macroEnabled.main+xml";
+
+    /// Macro-enabled PresentationML presentation (`.pptm`).
+    pub const PRESENTATION_MACRO_ENABLED: &str =
+        "application/vnd.ms-powerpoint.presentation.macroEnabled.main+xml";
+
+    /// VBA project (`vbaProject.bin`).
+    pub const VBA_PROJECT: &str = "application/vnd.ms-office.vbaProject";
+
+    /// Digital signature origin part (`_xmlsignatures/origin.sigs`).
+    pub const DIGITAL_SIGNATURE_ORIGIN: &str =
+        "application/vnd.openxmlformats-package.digital-signature-origin";
+
+    /// XML digital signature part (`_xmlsignatures/sigN.xml`).
+    pub const DIGITAL_SIGNATURE_XML: &str =
+        "application/vnd.openxmlformats-package.digital-signature-xmlsignature+xml";
+
+    /// DER-encoded X.509 certificate of a digital signature.
+    pub const DIGITAL_SIGNATURE_CERTIFICATE: &str =
+        "application/vnd.openxmlformats-package.digital-signature-certificate";
+
     /// WordprocessingML styles.
     pub const WORDPROCESSING_STYLES: &str =
         "application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml";
@@ -413,6 +789,7 @@ pub mod content_type {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::limits::ParseLimits;
     use std::io::Cursor;
 
     fn create_test_package() -> Vec<u8> {
@@ -491,6 +868,201 @@ mod tests {
         assert_eq!(doc_rel.target, "word/document.xml");
     }
 
+    #[test]
+    fn test_strict_package() {
+        let mut pkg = Package::open(Cursor::new(create_test_package())).unwrap();
+        assert_eq!(pkg.conformance().unwrap(), Conformance::Transitional);
+
+        let mut out = Cursor::new(Vec::new());
+        {
+            let mut writer = PackageWriter::new(&mut out);
+            writer.set_conformance(Conformance::Strict);
+            pkg.copy_to_writer(&mut writer, &HashMap::new()).unwrap();
+            writer.finish().unwrap();
+        }
+
+        out.set_position(0);
+        let mut strict = Package::open(out).unwrap();
+        assert_eq!(strict.conformance().unwrap(), Conformance::Strict);
+        let rels = strict.read_relationships().unwrap();
+        let doc_rel = rels
+            .get_by_type(crate::relationships::rel_type::OFFICE_DOCUMENT)
+            .unwrap();
+        assert_eq!(
+            doc_rel.relationship_type,
+            "http://purl.oclc.org/ooxml/officeDocument/relationships/officeDocument"
+        );
+        let doc = strict.read_part_string("word/document.xml").unwrap();
+        assert!(doc.contains("http://purl.oclc.org/ooxml/wordprocessingml/main"));
+    }
+
+    #[test]
+    fn test_serialize_options() {
+        let mut pkg = Package::open(Cursor::new(create_test_package())).unwrap();
+
+        let write = |pkg: &mut Package<Cursor<Vec<u8>>>| {
+            let mut out = Cursor::new(Vec::new());
+            let mut writer = PackageWriter::new(&mut out);
+            writer.set_serialize_options(SerializeOptions::pretty());
+            pkg.copy_to_writer(&mut writer, &HashMap::new()).unwrap();
+            writer.finish().unwrap();
+            out.set_position(0);
+            Package::open(out).unwrap()
+        };
+        let mut pretty = write(&mut pkg);
+        let doc = pretty.read_part_string("word/document.xml").unwrap();
+        assert!(
+            doc.contains("<w:p>\n      <w:r>\n        <w:t>Hello!</w:t>"),
+            "{doc}"
+        );
+        let content_types = pretty.read_part_string("[Content_Types].xml").unwrap();
+        assert!(content_types.contains("\n  <"), "{content_types}");
+
+        // Reformatting is stable
+        let mut again = write(&mut pretty);
+        assert_eq!(again.read_part_string("word/document.xml").unwrap(), doc);
+    }
+
+    #[test]
+    #[cfg(feature = "encryption")]
+    fn test_encrypted_package() {
+        let mut pkg = Package::open(Cursor::new(create_test_package())).unwrap();
+        let mut out = Cursor::new(Vec::new());
+        {
+            let mut writer = PackageWriter::new_encrypted(&mut out, "secret");
+            pkg.copy_to_writer(&mut writer, &HashMap::new()).unwrap();
+            writer.finish().unwrap();
+        }
+
+        out.set_position(0);
+        assert!(matches!(Package::open(out.clone()), Err(Error::Encrypted)));
+        assert!(matches!(
+            Package::open_encrypted(out.clone(), "wrong"),
+            Err(Error::InvalidPassword)
+        ));
+        // The decrypted package must fit in the total size
+        let limits = PackageLimits {
+            max_total_size: 100,
+            ..PackageLimits::default()
+        };
+        assert!(matches!(
+            Package::open_encrypted_with_limits(out.clone(), "secret", &limits),
+            Err(Error::LimitExceeded(_))
+        ));
+        let mut decrypted =
+            Package::open_encrypted_with_limits(out, "secret", &PackageLimits::default()).unwrap();
+        assert_eq!(
+            decrypted.read_part("word/document.xml").unwrap(),
+            pkg.read_part("word/document.xml").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_copy_to_writer_except() {
+        let mut pkg = Package::open(Cursor::new(create_test_package())).unwrap();
+
+        let mut out = Cursor::new(Vec::new());
+        {
+            let mut writer = PackageWriter::new(&mut out);
+            let replacement = b"<replaced/>".as_slice();
+            let replacements = HashMap::from([("_rels/.rels", replacement)]);
+            pkg.copy_to_writer_except(&mut writer, &replacements, &["word/document.xml"])
+                .unwrap();
+            writer.finish().unwrap();
+        }
+
+        out.set_position(0);
+        let mut copy = Package::open(out).unwrap();
+        assert!(!copy.has_part("word/document.xml"));
+        assert_eq!(copy.read_part_string("_rels/.rels").unwrap(), "<replaced/>");
+    }
+
+    #[test]
+    fn test_part_options_and_streams() {
+        let mut out = Cursor::new(Vec::new());
+        {
+            let mut writer = PackageWriter::new(&mut out);
+            writer.add_default_content_type("png", "image/png");
+            let stored = PartOptions::new().compression(Compression::Stored);
+            writer
+                .add_part_with_options("media/image1.png", "image/png", b"PNG", stored)
+                .unwrap();
+            let large = PartOptions::new().level(1).large_file(true);
+            let mut part = writer
+                .start_part("xl/worksheets/sheet1.xml", content_type::XML, large)
+                .unwrap();
+            part.write_all(b"<worksheet>").unwrap();
+            for _ in 0..1000 {
+                part.write_all(b"<row/>").unwrap();
+            }
+            part.write_all(b"</worksheet>").unwrap();
+            drop(part);
+            writer.finish().unwrap();
+        }
+
+        out.set_position(0);
+        let mut pkg = Package::open(out).unwrap();
+        assert_eq!(
+            pkg.content_type("xl/worksheets/sheet1.xml"),
+            Some(content_type::XML)
+        );
+        let mut sheet = String::new();
+        pkg.read_part_stream("xl/worksheets/sheet1.xml")
+            .unwrap()
+            .read_to_string(&mut sheet)
+            .unwrap();
+        assert_eq!(sheet.len(), 23 + 6 * 1000);
+        assert!(pkg.read_part_stream("missing.xml").is_err());
+
+        // Copied parts keep their compression method
+        let mut copy = Cursor::new(Vec::new());
+        {
+            let mut writer = PackageWriter::new(&mut copy);
+            pkg.copy_to_writer(&mut writer, &HashMap::new()).unwrap();
+            writer.finish().unwrap();
+        }
+        copy.set_position(0);
+        let mut archive = ZipArchive::new(copy).unwrap();
+        let image = archive.by_name("media/image1.png").unwrap();
+        assert_eq!(image.compression(), zip::CompressionMethod::Stored);
+        drop(image);
+        let sheet = archive.by_name("xl/worksheets/sheet1.xml").unwrap();
+        assert_eq!(sheet.compression(), zip::CompressionMethod::Deflated);
+    }
+
+    #[test]
+    fn test_open_with_limits() {
+        let data = create_test_package();
+        let mut pkg =
+            Package::open_with_limits(Cursor::new(data.clone()), &PackageLimits::default())
+                .unwrap();
+        assert!(pkg.read_part("word/document.xml").is_ok());
+
+        let limits = PackageLimits {
+            max_parts: 2,
+            ..PackageLimits::default()
+        };
+        assert!(matches!(
+            Package::open_with_limits(Cursor::new(data.clone()), &limits),
+            Err(Error::LimitExceeded(_))
+        ));
+
+        let limits = PackageLimits {
+            parse: ParseLimits {
+                max_depth: 4,
+                ..ParseLimits::default()
+            },
+            ..PackageLimits::default()
+        };
+        let mut pkg = Package::open_with_limits(Cursor::new(data), &limits).unwrap();
+        assert!(pkg.read_relationships().is_ok());
+        let err = pkg.read_part("word/document.xml").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "limit exceeded: word/document.xml: element nesting deeper than 4"
+        );
+    }
+
     #[test]
     fn test_content_types_roundtrip() {
         let mut ct = ContentTypes::new();
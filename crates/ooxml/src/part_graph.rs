@@ -0,0 +1,386 @@
+//! The graph of parts and relationships of a package.
+//!
+//! [`PartGraph`] loads every relationships part of a package and resolves the
+//! targets to part names, so the package can be audited as a whole: which
+//! parts are reachable from the package relationships, which parts refer to a
+//! given part, which parts nothing refers to (orphans), and which
+//! relationships point at missing parts (dangling) or outside the package
+//! (external).
+//!
+//! # Example
+//!
+//! ```no_run
+//! use ooxml_opc::{Package, PartGraph};
+//! use std::fs::File;
+//!
+//! let mut pkg = Package::open(File::open("upload.docx")?)?;
+//! let graph = PartGraph::load(&mut pkg)?;
+//! for part in graph.orphans() {
+//!     println!("unreferenced: {}", part);
+//! }
+//! for rel in graph.external() {
+//!     println!("external link: {}", rel.target);
+//! }
+//! # Ok::<(), ooxml_opc::Error>(())
+//! ```
+
+use crate::error::Result;
+use crate::packaging::Package;
+use crate::relationships::{
+    Relationships, TargetMode, percent_decode, resolve_target, source_for_rels_path,
+};
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::io::{Read, Seek};
+
+/// A relationship with its target resolved to a part name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedRelationship {
+    /// Part the relationship belongs to (empty for package relationships).
+    pub source: String,
+    /// Relationship ID (e.g., "rId1").
+    pub id: String,
+    /// Relationship type URI.
+    pub relationship_type: String,
+    /// Part name of an internal target, or the URI of an external target.
+    pub target: String,
+    /// Whether the target is internal or external.
+    pub target_mode: TargetMode,
+}
+
+impl ResolvedRelationship {
+    /// Check if this is an external relationship (e.g., hyperlink).
+    pub fn is_external(&self) -> bool {
+        self.target_mode == TargetMode::External
+    }
+}
+
+/// The parts of a package and the relationships between them.
+#[derive(Debug, Clone, Default)]
+pub struct PartGraph {
+    /// All parts, excluding `[Content_Types].xml` and relationships parts.
+    parts: BTreeSet<String>,
+    /// Relationships, by source part and then in ID order.
+    relationships: Vec<ResolvedRelationship>,
+}
+
+impl PartGraph {
+    /// Load the graph of a package, reading every relationships part.
+    pub fn load<R: Read + Seek>(package: &mut Package<R>) -> Result<Self> {
+        let names: Vec<String> = package
+            .parts()
+            .filter(|name| !name.ends_with('/') && *name != "[Content_Types].xml")
+            .map(str::to_string)
+            .collect();
+
+        let mut parts = BTreeSet::new();
+        let mut rels_parts = BTreeMap::new();
+        for name in names {
+            match source_for_rels_path(&name) {
+                Some(source) => {
+                    rels_parts.insert(source, name);
+                }
+                None => {
+                    parts.insert(name);
+                }
+            }
+        }
+
+        let mut relationships = BTreeMap::new();
+        for (source, rels_path) in rels_parts {
+            let data = package.read_part(&rels_path)?;
+            relationships.insert(source, Relationships::parse(&data[..])?);
+        }
+
+        Ok(Self::from_relationships(parts, &relationships))
+    }
+
+    /// Build the graph of a set of parts and their relationships, by source
+    /// part (`""` for package relationships).
+    pub(crate) fn from_relationships(
+        parts: BTreeSet<String>,
+        relationships: &BTreeMap<String, Relationships>,
+    ) -> Self {
+        let mut resolved = Vec::new();
+        for (source, rels) in relationships {
+            let mut rels: Vec<_> = rels.iter().collect();
+            rels.sort_by(|a, b| a.id.cmp(&b.id));
+            for rel in rels {
+                let target = match rel.target_mode {
+                    TargetMode::External => rel.target.clone(),
+                    TargetMode::Internal => canonical_part_name(&parts, source, &rel.target),
+                };
+                resolved.push(ResolvedRelationship {
+                    source: source.clone(),
+                    id: rel.id.clone(),
+                    relationship_type: rel.relationship_type.clone(),
+                    target,
+                    target_mode: rel.target_mode,
+                });
+            }
+        }
+
+        Self {
+            parts,
+            relationships: resolved,
+        }
+    }
+
+    /// List all parts, excluding `[Content_Types].xml` and relationships parts.
+    pub fn parts(&self) -> impl Iterator<Item = &str> {
+        self.parts.iter().map(String::as_str)
+    }
+
+    /// Check if a part exists in the package.
+    pub fn has_part(&self, part: &str) -> bool {
+        self.parts.contains(part)
+    }
+
+    /// List all relationships of the package.
+    pub fn relationships(&self) -> &[ResolvedRelationship] {
+        &self.relationships
+    }
+
+    /// List the relationships of a part (`""` for package relationships).
+    pub fn relationships_from<'a>(
+        &'a self,
+        source: &'a str,
+    ) -> impl Iterator<Item = &'a ResolvedRelationship> {
+        self.relationships
+            .iter()
+            .filter(move |rel| rel.source == source)
+    }
+
+    /// List the internal relationships that target a part.
+    pub fn referrers<'a>(
+        &'a self,
+        part: &'a str,
+    ) -> impl Iterator<Item = &'a ResolvedRelationship> {
+        self.relationships
+            .iter()
+            .filter(move |rel| !rel.is_external() && rel.target == part)
+    }
+
+    /// Get the existing parts reachable from a part (`""` for the package),
+    /// following internal relationships. The part itself is included unless
+    /// it is the package.
+    pub fn reachable_from(&self, root: &str) -> BTreeSet<&str> {
+        let mut reached = BTreeSet::new();
+        let mut queue = VecDeque::from([root]);
+        if let Some(part) = self.parts.get(root) {
+            reached.insert(part.as_str());
+        }
+        while let Some(source) = queue.pop_front() {
+            for rel in self.relationships_from(source) {
+                if !rel.is_external()
+                    && let Some(part) = self.parts.get(&rel.target)
+                    && reached.insert(part.as_str())
+                {
+                    queue.push_back(part.as_str());
+                }
+            }
+        }
+        reached
+    }
+
+    /// List the parts that are not reachable from the package relationships.
+    pub fn orphans(&self) -> Vec<&str> {
+        let reachable = self.reachable_from("");
+        self.parts()
+            .filter(|part| !reachable.contains(part))
+            .collect()
+    }
+
+    /// List the internal relationships whose target part does not exist.
+    pub fn dangling(&self) -> Vec<&ResolvedRelationship> {
+        self.relationships
+            .iter()
+            .filter(|rel| !rel.is_external() && !self.parts.contains(&rel.target))
+            .collect()
+    }
+
+    /// List the relationships with external targets (e.g., hyperlinks,
+    /// linked images and templates).
+    pub fn external(&self) -> Vec<&ResolvedRelationship> {
+        self.relationships
+            .iter()
+            .filter(|rel| rel.is_external())
+            .collect()
+    }
+}
+
+/// Resolve an internal target to the name of an existing part where
+/// possible: part names are compared case-insensitively and targets may be
+/// percent-encoded (ECMA-376 Part 2, §6.2.2).
+fn canonical_part_name(parts: &BTreeSet<String>, source: &str, target: &str) -> String {
+    let resolved = resolve_target(source, strip_fragment(target));
+    if parts.contains(&resolved) {
+        return resolved;
+    }
+    let decoded = percent_decode(&resolved);
+    parts
+        .iter()
+        .find(|part| part.eq_ignore_ascii_case(&decoded))
+        .cloned()
+        .unwrap_or(resolved)
+}
+
+/// Check whether an internal target refers to a part, comparing names the
+/// same way as [`canonical_part_name`].
+pub(crate) fn refers_to(source: &str, target: &str, part: &str) -> bool {
+    let resolved = resolve_target(source, strip_fragment(target));
+    resolved == part || percent_decode(&resolved).eq_ignore_ascii_case(part)
+}
+
+/// Remove the fragment (`#...`) of a target.
+pub(crate) fn strip_fragment(target: &str) -> &str {
+    target.split('#').next().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::packaging::{PackageWriter, content_type};
+    use crate::relationships::{Relationship, rel_type};
+    use std::io::Cursor;
+
+    fn create_test_package() -> Vec<u8> {
+        let mut buf = Cursor::new(Vec::new());
+        let mut writer = PackageWriter::new(&mut buf);
+        writer.add_default_content_type("rels", content_type::RELATIONSHIPS);
+        writer.add_default_content_type("xml", content_type::XML);
+        writer.add_default_content_type("png", "image/png");
+
+        let mut rels = Relationships::new();
+        rels.add(Relationship::new(
+            "rId1",
+            rel_type::OFFICE_DOCUMENT,
+            "word/document.xml",
+        ));
+        writer
+            .add_part(
+                "_rels/.rels",
+                content_type::RELATIONSHIPS,
+                rels.serialize().as_bytes(),
+            )
+            .unwrap();
+
+        let mut rels = Relationships::new();
+        rels.add(Relationship::new(
+            "rId1",
+            rel_type::IMAGE,
+            "media/image1.png",
+        ));
+        rels.add(Relationship::new(
+            "rId2",
+            rel_type::STYLES,
+            "/word/styles.xml",
+        ));
+        rels.add(Relationship::external(
+            "rId3",
+            rel_type::HYPERLINK,
+            "https://example.com/",
+        ));
+        rels.add(Relationship::new("rId4", rel_type::HEADER, "header1.xml"));
+        rels.add(Relationship::new(
+            "rId5",
+            rel_type::IMAGE,
+            "media/Image%202.png",
+        ));
+        rels.add(Relationship::new(
+            "rId6",
+            "http://schemas.openxmlformats.org/officeDocument/2006/relationships/customXml",
+            "../customXml/item1.xml",
+        ));
+        writer
+            .add_part(
+                "word/_rels/document.xml.rels",
+                content_type::RELATIONSHIPS,
+                rels.serialize().as_bytes(),
+            )
+            .unwrap();
+
+        let mut rels = Relationships::new();
+        rels.add(Relationship::new(
+            "rId1",
+            rel_type::IMAGE,
+            "./media/image1.png",
+        ));
+        writer
+            .add_part(
+                "word/_rels/styles.xml.rels",
+                content_type::RELATIONSHIPS,
+                rels.serialize().as_bytes(),
+            )
+            .unwrap();
+
+        for part in [
+            "word/document.xml",
+            "word/styles.xml",
+            "customXml/item1.xml",
+            "word/media/image1.png",
+            "word/media/image 2.png",
+            "word/media/unused.png",
+            "word/embeddings/payload.xml",
+        ] {
+            writer.add_part(part, content_type::XML, b"<x/>").unwrap();
+        }
+        writer.finish().unwrap();
+        buf.into_inner()
+    }
+
+    #[test]
+    fn test_part_graph() {
+        let data = create_test_package();
+        let mut pkg = Package::open(Cursor::new(data)).unwrap();
+        let graph = PartGraph::load(&mut pkg).unwrap();
+
+        assert_eq!(graph.parts().count(), 7);
+        assert!(!graph.has_part("word/_rels/document.xml.rels"));
+
+        let targets: Vec<&str> = graph
+            .relationships_from("word/document.xml")
+            .map(|rel| rel.target.as_str())
+            .collect();
+        assert_eq!(
+            targets,
+            [
+                "word/media/image1.png",
+                "word/styles.xml",
+                "https://example.com/",
+                "word/header1.xml",
+                "word/media/image 2.png",
+                "customXml/item1.xml",
+            ]
+        );
+
+        let referrers: Vec<(&str, &str)> = graph
+            .referrers("word/media/image1.png")
+            .map(|rel| (rel.source.as_str(), rel.id.as_str()))
+            .collect();
+        assert_eq!(
+            referrers,
+            [("word/document.xml", "rId1"), ("word/styles.xml", "rId1")]
+        );
+
+        let reachable = graph.reachable_from("word/styles.xml");
+        assert_eq!(
+            reachable.into_iter().collect::<Vec<_>>(),
+            ["word/media/image1.png", "word/styles.xml"]
+        );
+        assert_eq!(graph.reachable_from("").len(), 5);
+
+        assert_eq!(
+            graph.orphans(),
+            ["word/embeddings/payload.xml", "word/media/unused.png"]
+        );
+
+        let dangling = graph.dangling();
+        assert_eq!(dangling.len(), 1);
+        assert_eq!(dangling[0].id, "rId4");
+        assert_eq!(dangling[0].target, "word/header1.xml");
+
+        let external = graph.external();
+        assert_eq!(external.len(), 1);
+        assert_eq!(external[0].target, "https://example.com/");
+    }
+}
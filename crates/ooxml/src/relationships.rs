@@ -45,47 +45,45 @@ impl Relationships {
 
         loop {
             match xml.read_event_into(&mut buf) {
-                Ok(Event::Empty(e)) | Ok(Event::Start(e)) => {
-                    if e.name().as_ref() == b"Relationship" {
-                        let mut id = None;
-                        let mut rel_type = None;
-                        let mut target = None;
-                        let mut target_mode = TargetMode::Internal;
-
-                        for attr in e.attributes().filter_map(|a| a.ok()) {
-                            match attr.key.as_ref() {
-                                b"Id" => {
-                                    id = Some(String::from_utf8_lossy(&attr.value).into_owned());
-                                }
-                                b"Type" => {
-                                    rel_type =
-                                        Some(String::from_utf8_lossy(&attr.value).into_owned());
-                                }
-                                b"Target" => {
-                                    target =
-                                        Some(String::from_utf8_lossy(&attr.value).into_owned());
-                                }
-                                b"TargetMode" => {
-                                    let mode = String::from_utf8_lossy(&attr.value);
-                                    if mode == "External" {
-                                        target_mode = TargetMode::External;
-                                    }
+                Ok(Event::Empty(e)) | Ok(Event::Start(e))
+                    if e.name().as_ref() == b"Relationship" =>
+                {
+                    let mut id = None;
+                    let mut rel_type = None;
+                    let mut target = None;
+                    let mut target_mode = TargetMode::Internal;
+
+                    for attr in e.attributes().filter_map(|a| a.ok()) {
+                        match attr.key.as_ref() {
+                            b"Id" => {
+                                id = Some(String::from_utf8_lossy(&attr.value).into_owned());
+                            }
+                            b"Type" => {
+                                rel_type = Some(String::from_utf8_lossy(&attr.value).into_owned());
+                            }
+                            b"Target" => {
+                                target = Some(String::from_utf8_lossy(&attr.value).into_owned());
+                            }
+                            b"TargetMode" => {
+                                let mode = String::from_utf8_lossy(&attr.value);
+                                if mode == "External" {
+                                    target_mode = TargetMode::External;
                                 }
-                                _ => {}
                             }
+                            _ => {}
                         }
+                    }
 
-                        if let (Some(id), Some(rel_type), Some(target)) = (id, rel_type, target) {
-                            relationships.relationships.insert(
-                                id.clone(),
-                                Relationship {
-                                    id,
-                                    relationship_type: rel_type,
-                                    target,
-                                    target_mode,
-                                },
-                            );
-                        }
+                    if let (Some(id), Some(rel_type), Some(target)) = (id, rel_type, target) {
+                        relationships.relationships.insert(
+                            id.clone(),
+                            Relationship {
+                                id,
+                                relationship_type: rel_type,
+                                target,
+                                target_mode,
+                            },
+                        );
                     }
                 }
                 Ok(Event::Eof) => break,
@@ -126,23 +124,30 @@ impl Relationships {
         self.relationships.insert(rel.id.clone(), rel);
     }
 
+    /// Remove a relationship by ID, returning it if it existed.
+    pub fn remove(&mut self, id: &str) -> Option<Relationship> {
+        self.relationships.remove(id)
+    }
+
     /// Get a relationship by ID.
     pub fn get(&self, id: &str) -> Option<&Relationship> {
         self.relationships.get(id)
     }
 
     /// Get the first relationship of a given type.
+    ///
+    /// Strict and Transitional forms of the type are treated as equal.
     pub fn get_by_type(&self, rel_type: &str) -> Option<&Relationship> {
-        self.relationships
-            .values()
-            .find(|r| r.relationship_type == rel_type)
+        self.relationships.values().find(|r| r.has_type(rel_type))
     }
 
     /// Get all relationships of a given type.
+    ///
+    /// Strict and Transitional forms of the type are treated as equal.
     pub fn get_all_by_type(&self, rel_type: &str) -> impl Iterator<Item = &Relationship> {
         self.relationships
             .values()
-            .filter(move |r| r.relationship_type == rel_type)
+            .filter(move |r| r.has_type(rel_type))
     }
 
     /// Iterate over all relationships.
@@ -219,6 +224,12 @@ impl Relationship {
     pub fn is_external(&self) -> bool {
         self.target_mode == TargetMode::External
     }
+
+    /// Check if this relationship has the given type, in either its Strict or
+    /// Transitional form.
+    pub fn has_type(&self, rel_type: &str) -> bool {
+        crate::conformance::rel_types_match(&self.relationship_type, rel_type)
+    }
 }
 
 /// Whether a relationship target is internal or external.
@@ -232,6 +243,9 @@ pub enum TargetMode {
 }
 
 /// Common relationship type URIs.
+///
+/// These are the Transitional forms; see [`Conformance`](crate::Conformance)
+/// for translating them to Strict.
 pub mod rel_type {
     /// Office document (main document part).
     pub const OFFICE_DOCUMENT: &str =
@@ -324,6 +338,21 @@ pub mod rel_type {
     /// Pivot cache definition part (workbook relationship).
     pub const PIVOT_CACHE_DEFINITION: &str =
         "http://schemas.openxmlformats.org/officeDocument/2006/relationships/pivotCacheDefinition";
+
+    /// VBA project of a macro-enabled document (main part relationship).
+    pub const VBA_PROJECT: &str =
+        "http://schemas.microsoft.com/office/2006/relationships/vbaProject";
+
+    /// Digital signature origin part (package relationship).
+    pub const DIGITAL_SIGNATURE_ORIGIN: &str =
+        "http://schemas.openxmlformats.org/package/2006/relationships/digital-signature/origin";
+
+    /// XML digital signature part (origin part relationship).
+    pub const DIGITAL_SIGNATURE: &str =
+        "http://schemas.openxmlformats.org/package/2006/relationships/digital-signature/signature";
+
+    /// Signing certificate stored as a separate part (signature part relationship).
+    pub const DIGITAL_SIGNATURE_CERTIFICATE: &str = "http://schemas.openxmlformats.org/package/2006/relationships/digital-signature/certificate";
 }
 
 /// Get the relationships file path for a given part.
@@ -345,6 +374,103 @@ pub fn rels_path_for(part_path: &str) -> String {
     }
 }
 
+/// Get the part a relationships file belongs to; the inverse of
+/// [`rels_path_for`].
+///
+/// Returns `Some("")` for the package relationships (`_rels/.rels`), and
+/// `None` if the path is not a relationships part.
+pub fn source_for_rels_path(rels_path: &str) -> Option<String> {
+    let file = rels_path.strip_suffix(".rels")?;
+    let (dir, file) = match file.rsplit_once('/') {
+        Some((dir, file)) => (dir, file),
+        None => return None,
+    };
+    let dir = if dir == "_rels" {
+        ""
+    } else {
+        dir.strip_suffix("/_rels")?
+    };
+    Some(match (dir, file) {
+        (_, "") => dir.to_string(),
+        ("", _) => file.to_string(),
+        _ => format!("{}/{}", dir, file),
+    })
+}
+
+/// Resolve a relationship target to a part name.
+///
+/// Relative targets are resolved against the directory of `source`, the part
+/// the relationship belongs to (`""` for package relationships). `.` and `..`
+/// segments are normalized and absolute targets lose their leading `/`, so
+/// the result can be passed to [`Package::read_part`](crate::Package::read_part).
+///
+/// For example, `../media/image1.png` from `word/document.xml` resolves to
+/// `media/image1.png`.
+pub fn resolve_target(source: &str, target: &str) -> String {
+    let path = match target.strip_prefix('/') {
+        Some(absolute) => absolute.to_string(),
+        None => match source.rfind('/') {
+            Some(idx) => format!("{}/{}", &source[..idx], target),
+            None => target.to_string(),
+        },
+    };
+
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in path.split('/') {
+        match segment {
+            ".." => {
+                segments.pop();
+            }
+            "." | "" => {}
+            _ => segments.push(segment),
+        }
+    }
+    segments.join("/")
+}
+
+/// Make a relative relationship target pointing from `source` to `part`; the
+/// inverse of [`resolve_target`].
+///
+/// For example, `customXml/item1.xml` from `word/document.xml` becomes
+/// `../customXml/item1.xml`.
+pub fn relative_target(source: &str, part: &str) -> String {
+    let source_dir: Vec<&str> = match source.rfind('/') {
+        Some(idx) => source[..idx].split('/').collect(),
+        None => Vec::new(),
+    };
+    let segments: Vec<&str> = part.split('/').collect();
+    let common = source_dir
+        .iter()
+        .zip(&segments[..segments.len() - 1])
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut relative = vec![".."; source_dir.len() - common];
+    relative.extend(&segments[common..]);
+    relative.join("/")
+}
+
+/// Decode the `%XX` escapes of a part name URI.
+pub(crate) fn percent_decode(path: &str) -> String {
+    let bytes = path.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%'
+            && let Some(byte) = path
+                .get(i + 1..i + 3)
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+        {
+            decoded.push(byte);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -416,6 +542,63 @@ mod tests {
         assert_eq!(rels_path_for("document.xml"), "_rels/document.xml.rels");
     }
 
+    #[test]
+    fn test_source_for_rels_path() {
+        assert_eq!(source_for_rels_path("_rels/.rels").as_deref(), Some(""));
+        assert_eq!(
+            source_for_rels_path("word/_rels/document.xml.rels").as_deref(),
+            Some("word/document.xml")
+        );
+        assert_eq!(
+            source_for_rels_path("_rels/document.xml.rels").as_deref(),
+            Some("document.xml")
+        );
+        assert_eq!(source_for_rels_path("word/document.xml"), None);
+        assert_eq!(source_for_rels_path("word/other.rels"), None);
+    }
+
+    #[test]
+    fn test_resolve_target() {
+        assert_eq!(resolve_target("", "word/document.xml"), "word/document.xml");
+        assert_eq!(
+            resolve_target("word/document.xml", "media/image1.png"),
+            "word/media/image1.png"
+        );
+        assert_eq!(
+            resolve_target("ppt/slides/slide1.xml", "../slideLayouts/slideLayout1.xml"),
+            "ppt/slideLayouts/slideLayout1.xml"
+        );
+        assert_eq!(
+            resolve_target("xl/workbook.xml", "/xl/styles.xml"),
+            "xl/styles.xml"
+        );
+        assert_eq!(
+            resolve_target("word/document.xml", "./a/../b.xml"),
+            "word/b.xml"
+        );
+    }
+
+    #[test]
+    fn test_relative_target() {
+        assert_eq!(
+            relative_target("", "word/document.xml"),
+            "word/document.xml"
+        );
+        assert_eq!(
+            relative_target("word/document.xml", "word/media/image1.png"),
+            "media/image1.png"
+        );
+        assert_eq!(
+            relative_target("word/document.xml", "customXml/item1.xml"),
+            "../customXml/item1.xml"
+        );
+        assert_eq!(
+            relative_target("ppt/slides/slide1.xml", "ppt/slideLayouts/slideLayout1.xml"),
+            "../slideLayouts/slideLayout1.xml"
+        );
+        assert_eq!(relative_target("word/document.xml", "word"), "../word");
+    }
+
     #[test]
     fn test_next_id() {
         let mut rels = Relationships::new();
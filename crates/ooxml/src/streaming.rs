@@ -0,0 +1,830 @@
+//! Forward-only reading of OPC packages.
+//!
+//! [`Package`](crate::Package) needs a seekable reader because it reads the
+//! ZIP central directory at the end of the archive. [`StreamingPackage`]
+//! instead walks the local file headers from front to back, so a package can
+//! be processed straight from a network body, stdin or a pipe without holding
+//! it in memory.
+//!
+//! Parts are exposed as they arrive. Only parts that precede
+//! `[Content_Types].xml` in the archive are buffered, since their content
+//! types are not known yet. Office writes the content types first, so
+//! usually nothing is buffered; streaming ZIP writers often write them last.
+//! Buffering is limited to 64 MiB by default (see
+//! [`set_max_buffered`](StreamingPackage::set_max_buffered)), beyond which
+//! [`next_part`](StreamingPackage::next_part) fails with
+//! [`Error::LimitExceeded`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use ooxml_opc::StreamingPackage;
+//! use std::io::Read;
+//!
+//! let mut pkg = StreamingPackage::new(std::io::stdin().lock());
+//! while let Some(mut part) = pkg.next_part()? {
+//!     if part.name().starts_with("xl/worksheets/") {
+//!         let mut xml = String::new();
+//!         part.read_to_string(&mut xml)?;
+//!         println!("{}: {} bytes", part.name(), xml.len());
+//!     }
+//! }
+//! # Ok::<(), ooxml_opc::Error>(())
+//! ```
+
+use crate::error::{Error, Result};
+use crate::limits::{PackageLimits, from_io, is_xml};
+use crate::packaging::{CFB_SIGNATURE, ContentTypes};
+use crate::relationships::Relationships;
+use flate2::bufread::DeflateDecoder;
+use std::collections::VecDeque;
+use std::io::{self, BufRead, BufReader, Cursor, Read, Take};
+
+/// ZIP record signatures.
+const LOCAL_FILE_HEADER: u32 = 0x0403_4b50;
+const DATA_DESCRIPTOR: u32 = 0x0807_4b50;
+const CENTRAL_DIRECTORY_HEADER: u32 = 0x0201_4b50;
+const END_OF_CENTRAL_DIRECTORY: u32 = 0x0605_4b50;
+
+/// General purpose flags of a local file header.
+const FLAG_ENCRYPTED: u16 = 1;
+const FLAG_DATA_DESCRIPTOR: u16 = 1 << 3;
+
+/// Compression methods.
+const METHOD_STORED: u16 = 0;
+const METHOD_DEFLATED: u16 = 8;
+
+/// Zip64 extended information extra field.
+const ZIP64_EXTRA_FIELD: u16 = 0x0001;
+
+/// Default limit on the data buffered before the content types are known.
+const DEFAULT_MAX_BUFFERED: u64 = 64 * 1024 * 1024;
+
+/// An OPC package read front to back from a non-seekable reader.
+pub struct StreamingPackage<R: Read> {
+    reader: BufReader<R>,
+    content_types: Option<ContentTypes>,
+    relationships: Option<Relationships>,
+    /// Parts read before the content types, in archive order.
+    pending: VecDeque<(String, Vec<u8>)>,
+    /// Total size of the pending parts.
+    pending_size: u64,
+    max_buffered: u64,
+    /// Number of archive entries read.
+    entries: usize,
+    started: bool,
+    finished: bool,
+    state: ReadState,
+}
+
+/// State shared between a [`StreamingPackage`] and the part being read.
+struct ReadState {
+    /// Error hit while skipping the rest of a part that was dropped unread.
+    error: Option<Error>,
+    /// Limits for untrusted packages, if any.
+    limits: Option<PackageLimits>,
+    /// Uncompressed bytes read from the archive so far.
+    total: u64,
+}
+
+impl ReadState {
+    /// The most the next part may inflate to under the limits.
+    fn part_limit(&self) -> u64 {
+        self.limits.map_or(u64::MAX, |limits| {
+            limits
+                .max_part_size
+                .min(limits.max_total_size.saturating_sub(self.total))
+        })
+    }
+}
+
+impl<R: Read> StreamingPackage<R> {
+    /// Start reading a package from a reader.
+    ///
+    /// Nothing is read until [`next_part`](Self::next_part) is called. Apart
+    /// from the [buffer limit](Self::set_max_buffered), no resource limits
+    /// are applied; use [`StreamingPackage::with_limits`] for untrusted input.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            content_types: None,
+            relationships: None,
+            pending: VecDeque::new(),
+            pending_size: 0,
+            max_buffered: DEFAULT_MAX_BUFFERED,
+            entries: 0,
+            started: false,
+            finished: false,
+            state: ReadState {
+                error: None,
+                limits: None,
+                total: 0,
+            },
+        }
+    }
+
+    /// Start reading an untrusted package, enforcing resource limits.
+    ///
+    /// [`next_part`](Self::next_part) returns [`Error::LimitExceeded`] when
+    /// the archive has too many entries or an entry declares sizes beyond
+    /// the limits. Since entries may not declare sizes, parts are also cut
+    /// off once they inflate beyond `max_part_size`, or the parts together
+    /// beyond `max_total_size`, which also caps the buffer. XML parts read
+    /// with [`StreamedPart::read_to_vec`] are checked against the parse
+    /// limits.
+    pub fn with_limits(reader: R, limits: &PackageLimits) -> Self {
+        let mut package = Self::new(reader);
+        package.max_buffered = package.max_buffered.min(limits.max_total_size);
+        package.state.limits = Some(*limits);
+        package
+    }
+
+    /// Limit the uncompressed size of the parts held in memory until
+    /// `[Content_Types].xml` is read, 64 MiB by default.
+    pub fn set_max_buffered(&mut self, bytes: u64) {
+        self.max_buffered = bytes;
+    }
+
+    /// Get the content types, once `[Content_Types].xml` has been read.
+    pub fn content_types(&self) -> Option<&ContentTypes> {
+        self.content_types.as_ref()
+    }
+
+    /// Get the package-level relationships, once `_rels/.rels` has been read.
+    pub fn relationships(&self) -> Option<&Relationships> {
+        self.relationships.as_ref()
+    }
+
+    /// Read the next part of the package.
+    ///
+    /// Returns `None` at the end of the package. The part borrows the
+    /// package; whatever is left unread of it is skipped on the next call.
+    /// `[Content_Types].xml` is not returned as a part (see
+    /// [`content_types`](Self::content_types)).
+    ///
+    /// Returns [`Error::Encrypted`] for password-protected packages,
+    /// [`Error::MissingPart`] if the package has no `[Content_Types].xml`,
+    /// and [`Error::LimitExceeded`] if the parts before it are larger than
+    /// the [buffer limit](Self::set_max_buffered) or the package exceeds its
+    /// [limits](Self::with_limits).
+    pub fn next_part(&mut self) -> Result<Option<StreamedPart<'_, R>>> {
+        if let Some(error) = self.state.error.take() {
+            return Err(error);
+        }
+        if !self.started {
+            self.started = true;
+            if self.reader.fill_buf()?.starts_with(&CFB_SIGNATURE) {
+                return Err(Error::Encrypted);
+            }
+        }
+
+        loop {
+            if let Some(content_types) = &self.content_types
+                && let Some((name, data)) = self.pending.pop_front()
+            {
+                self.pending_size -= data.len() as u64;
+                let content_type = content_types.get(&name).map(str::to_string);
+                return Ok(Some(StreamedPart::new(
+                    name,
+                    content_type,
+                    PartData::Buffered(Cursor::new(data)),
+                    None,
+                    &mut self.state,
+                )));
+            }
+            if self.finished {
+                if self.content_types.is_none() {
+                    return Err(Error::MissingPart("[Content_Types].xml".into()));
+                }
+                return Ok(None);
+            }
+
+            let Some(header) = read_local_header(&mut self.reader)? else {
+                self.finished = true;
+                continue;
+            };
+            self.entries += 1;
+            if let Some(limits) = &self.state.limits {
+                let checked = match self.entries > limits.max_parts {
+                    true => Err(Error::LimitExceeded(format!(
+                        "more than {} parts",
+                        limits.max_parts
+                    ))),
+                    false => limits.check_entry(
+                        &header.name,
+                        header.uncompressed_size,
+                        header.compressed_size,
+                    ),
+                };
+                if let Err(e) = checked {
+                    self.finished = true;
+                    return Err(e);
+                }
+            }
+            if header.name.ends_with('/') {
+                // Directory entry
+                let (data, check) = open_entry(&mut self.reader, &header)?;
+                read_entry(&header.name, data, check, &mut self.state, 0)?;
+                continue;
+            }
+
+            if header.name != "[Content_Types].xml"
+                && header.name != "_rels/.rels"
+                && let Some(content_types) = &self.content_types
+            {
+                let content_type = content_types.get(&header.name).map(str::to_string);
+                let (data, check) = open_entry(&mut self.reader, &header)?;
+                return Ok(Some(StreamedPart::new(
+                    header.name,
+                    content_type,
+                    data,
+                    Some(check),
+                    &mut self.state,
+                )));
+            }
+
+            let (data, check) = open_entry(&mut self.reader, &header)?;
+            let budget = self.max_buffered.saturating_sub(self.pending_size);
+            let data = match read_entry(&header.name, data, check, &mut self.state, budget) {
+                Ok(data) => data,
+                Err(e) => {
+                    // The archive can't be followed past a part left unread
+                    self.finished = true;
+                    return Err(e);
+                }
+            };
+            if let Some(limits) = &self.state.limits
+                && matches!(header.name.as_str(), "[Content_Types].xml" | "_rels/.rels")
+            {
+                limits.check_xml(&header.name, &data)?;
+            }
+            match header.name.as_str() {
+                "[Content_Types].xml" => {
+                    self.content_types = Some(ContentTypes::parse(&data[..])?);
+                    continue;
+                }
+                "_rels/.rels" => self.relationships = Some(Relationships::parse(&data[..])?),
+                _ => {}
+            }
+            self.pending_size += data.len() as u64;
+            self.pending.push_back((header.name, data));
+        }
+    }
+}
+
+/// A part of a [`StreamingPackage`], read through its [`Read`] implementation.
+///
+/// The data is decompressed while reading, and its CRC-32 is checked at the
+/// end.
+pub struct StreamedPart<'a, R: Read> {
+    name: String,
+    content_type: Option<String>,
+    data: PartData<'a, R>,
+    /// Integrity check of a part read from the stream (not buffered).
+    check: Option<EntryCheck>,
+    /// Most bytes the part may inflate to.
+    limit: u64,
+    state: &'a mut ReadState,
+}
+
+impl<'a, R: Read> StreamedPart<'a, R> {
+    fn new(
+        name: String,
+        content_type: Option<String>,
+        data: PartData<'a, R>,
+        check: Option<EntryCheck>,
+        state: &'a mut ReadState,
+    ) -> Self {
+        Self {
+            name,
+            content_type,
+            data,
+            check,
+            limit: state.part_limit(),
+            state,
+        }
+    }
+}
+
+impl<R: Read> StreamedPart<'_, R> {
+    /// Get the part path (e.g., `xl/worksheets/sheet1.xml`).
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the content type of the part.
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// Read the rest of the part into memory.
+    ///
+    /// In a package read [with limits](StreamingPackage::with_limits), XML
+    /// parts are checked against the parse limits.
+    pub fn read_to_vec(&mut self) -> Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.read_to_end(&mut data).map_err(from_io)?;
+        if let Some(limits) = &self.state.limits
+            && is_xml(&self.name, self.content_type.as_deref())
+        {
+            limits.check_xml(&self.name, &data)?;
+        }
+        Ok(data)
+    }
+
+    /// Verify the part once its data is exhausted, reading the data
+    /// descriptor that follows it if there is one.
+    fn finish(&mut self) -> io::Result<()> {
+        let Some(check) = &mut self.check else {
+            return Ok(());
+        };
+        check.done = true;
+        let reader = match &mut self.data {
+            PartData::Buffered(_) => return Ok(()),
+            PartData::Stored(take) => take,
+            PartData::Deflated(decoder) => decoder.get_mut(),
+        };
+
+        if let Some(zip64) = check.descriptor {
+            let mut signature = [0u8; 4];
+            reader.get_mut().read_exact(&mut signature)?;
+            let crc32 = if u32::from_le_bytes(signature) == DATA_DESCRIPTOR {
+                read_u32(reader.get_mut())?
+            } else {
+                u32::from_le_bytes(signature)
+            };
+            let (_, uncompressed_size) = if zip64 {
+                (read_u64(reader.get_mut())?, read_u64(reader.get_mut())?)
+            } else {
+                (
+                    read_u32(reader.get_mut())? as u64,
+                    read_u32(reader.get_mut())? as u64,
+                )
+            };
+            check.crc32 = crc32;
+            check.size = uncompressed_size;
+        } else {
+            // Skip anything after the end of the deflate stream
+            io::copy(reader, &mut io::sink())?;
+        }
+
+        let crc32 = std::mem::take(&mut check.hasher).finalize();
+        if crc32 != check.crc32 || check.read != check.size {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: CRC-32 or size mismatch", self.name),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for StreamedPart<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = match &mut self.data {
+            PartData::Buffered(cursor) => cursor.read(buf)?,
+            PartData::Stored(take) => take.read(buf)?,
+            PartData::Deflated(decoder) => decoder.read(buf)?,
+        };
+        if let Some(check) = &mut self.check {
+            if n > 0 {
+                check.hasher.update(&buf[..n]);
+                check.read += n as u64;
+                self.state.total += n as u64;
+                if check.read > self.limit {
+                    return Err(io::Error::other(Error::LimitExceeded(format!(
+                        "{} inflates beyond the size limits",
+                        self.name
+                    ))));
+                }
+            } else if !check.done && !buf.is_empty() {
+                self.finish()?;
+            }
+        }
+        Ok(n)
+    }
+}
+
+impl<R: Read> Drop for StreamedPart<'_, R> {
+    fn drop(&mut self) {
+        // Skip to the next local file header
+        if self.check.as_ref().is_some_and(|check| !check.done)
+            && let Err(e) = io::copy(self, &mut io::sink())
+        {
+            self.state.error = Some(from_io(e));
+        }
+    }
+}
+
+/// Source of the data of a part.
+enum PartData<'a, R: Read> {
+    Buffered(Cursor<Vec<u8>>),
+    Stored(Take<&'a mut BufReader<R>>),
+    Deflated(DeflateDecoder<Take<&'a mut BufReader<R>>>),
+}
+
+/// Expected checksum and size of a part, and what was read so far.
+struct EntryCheck {
+    hasher: crc32fast::Hasher,
+    read: u64,
+    crc32: u32,
+    size: u64,
+    /// Whether the values are in a data descriptor after the data, and if
+    /// so whether it has 64-bit sizes.
+    descriptor: Option<bool>,
+    done: bool,
+}
+
+/// The fields of a ZIP local file header needed to read the entry.
+struct LocalHeader {
+    name: String,
+    flags: u16,
+    method: u16,
+    crc32: u32,
+    compressed_size: u64,
+    uncompressed_size: u64,
+    zip64: bool,
+}
+
+/// Read the next local file header, or `None` at the central directory.
+fn read_local_header<R: Read>(reader: &mut R) -> Result<Option<LocalHeader>> {
+    match read_u32(reader) {
+        Ok(LOCAL_FILE_HEADER) => {}
+        Ok(CENTRAL_DIRECTORY_HEADER | END_OF_CENTRAL_DIRECTORY) => return Ok(None),
+        Ok(_) => return Err(Error::Invalid("not a ZIP local file header".into())),
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => {
+            return Err(Error::Invalid("truncated ZIP archive".into()));
+        }
+        Err(e) => return Err(e.into()),
+    }
+
+    let mut fixed = [0u8; 26];
+    reader.read_exact(&mut fixed)?;
+    let u16_at = |i: usize| u16::from_le_bytes([fixed[i], fixed[i + 1]]);
+    let u32_at =
+        |i: usize| u32::from_le_bytes([fixed[i], fixed[i + 1], fixed[i + 2], fixed[i + 3]]);
+
+    let mut name = vec![0u8; u16_at(22) as usize];
+    reader.read_exact(&mut name)?;
+    let mut extra = vec![0u8; u16_at(24) as usize];
+    reader.read_exact(&mut extra)?;
+
+    let mut header = LocalHeader {
+        name: String::from_utf8_lossy(&name).into_owned(),
+        flags: u16_at(2),
+        method: u16_at(4),
+        crc32: u32_at(10),
+        compressed_size: u32_at(14) as u64,
+        uncompressed_size: u32_at(18) as u64,
+        zip64: false,
+    };
+
+    // Zip64 sizes replace the 32-bit ones that are set to 0xFFFFFFFF
+    let mut fields = &extra[..];
+    while fields.len() >= 4 {
+        let id = u16::from_le_bytes([fields[0], fields[1]]);
+        let len = (u16::from_le_bytes([fields[2], fields[3]]) as usize).min(fields.len() - 4);
+        let mut data = &fields[4..4 + len];
+        if id == ZIP64_EXTRA_FIELD {
+            header.zip64 = true;
+            if header.uncompressed_size == u32::MAX as u64 && data.len() >= 8 {
+                header.uncompressed_size = read_u64(&mut data)?;
+            }
+            if header.compressed_size == u32::MAX as u64 && data.len() >= 8 {
+                header.compressed_size = read_u64(&mut data)?;
+            }
+        }
+        fields = &fields[4 + len..];
+    }
+
+    Ok(Some(header))
+}
+
+/// Open the data of the entry following a local file header.
+fn open_entry<'a, R: Read>(
+    reader: &'a mut BufReader<R>,
+    header: &LocalHeader,
+) -> Result<(PartData<'a, R>, EntryCheck)> {
+    if header.flags & FLAG_ENCRYPTED != 0 {
+        return Err(Error::Unsupported(format!(
+            "encrypted ZIP entry {}",
+            header.name
+        )));
+    }
+    let descriptor = header.flags & FLAG_DATA_DESCRIPTOR != 0;
+    let check = EntryCheck {
+        hasher: crc32fast::Hasher::new(),
+        read: 0,
+        crc32: header.crc32,
+        size: header.uncompressed_size,
+        descriptor: descriptor.then_some(header.zip64),
+        done: false,
+    };
+
+    let data = match header.method {
+        // Without sizes, only a deflate stream knows where it ends
+        METHOD_STORED if descriptor => {
+            return Err(Error::Unsupported(format!(
+                "stored ZIP entry {} without sizes cannot be streamed",
+                header.name
+            )));
+        }
+        METHOD_STORED => PartData::Stored(reader.take(header.compressed_size)),
+        METHOD_DEFLATED => {
+            let limit = if descriptor {
+                u64::MAX
+            } else {
+                header.compressed_size
+            };
+            PartData::Deflated(DeflateDecoder::new(reader.take(limit)))
+        }
+        method => {
+            return Err(Error::Unsupported(format!(
+                "compression method {} of ZIP entry {}",
+                method, header.name
+            )));
+        }
+    };
+    Ok((data, check))
+}
+
+/// Read a whole entry into memory, failing if it is larger than `limit`.
+fn read_entry<R: Read>(
+    name: &str,
+    data: PartData<'_, R>,
+    check: EntryCheck,
+    state: &mut ReadState,
+    limit: u64,
+) -> Result<Vec<u8>> {
+    let mut part = StreamedPart::new(name.to_string(), None, data, Some(check), state);
+    let mut data = Vec::new();
+    (&mut part)
+        .take(limit.saturating_add(1))
+        .read_to_end(&mut data)
+        .map_err(from_io)?;
+    if data.len() as u64 > limit {
+        // Don't inflate the rest when the part is dropped
+        part.check = None;
+        return Err(Error::LimitExceeded(format!(
+            "{} does not fit in the buffer for parts before [Content_Types].xml",
+            name
+        )));
+    }
+    Ok(data)
+}
+
+fn read_u32<R: Read>(reader: &mut R) -> io::Result<u32> {
+    let mut buf = [0u8; 4];
+    reader.read_exact(&mut buf)?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+fn read_u64<R: Read>(reader: &mut R) -> io::Result<u64> {
+    let mut buf = [0u8; 8];
+    reader.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::limits::ParseLimits;
+    use crate::packaging::{Package, PackageWriter, content_type};
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+
+    const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+  <Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+  <Default Extension="xml" ContentType="application/xml"/>
+  <Default Extension="bin" ContentType="application/octet-stream"/>
+</Types>"#;
+
+    const RELS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+  <Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/>
+</Relationships>"#;
+
+    fn sheet() -> String {
+        let rows: String = (1..=2000)
+            .map(|i| format!(r#"<row r="{i}"><c r="A{i}"><v>{i}</v></c></row>"#))
+            .collect();
+        format!("<worksheet><sheetData>{}</sheetData></worksheet>", rows)
+    }
+
+    #[test]
+    fn test_stream_package() {
+        let mut buf = Cursor::new(Vec::new());
+        let mut writer = PackageWriter::new(&mut buf);
+        writer.add_default_content_type("rels", content_type::RELATIONSHIPS);
+        writer.add_default_content_type("xml", content_type::XML);
+        writer
+            .add_part("_rels/.rels", content_type::RELATIONSHIPS, RELS.as_bytes())
+            .unwrap();
+        writer
+            .add_part("xl/workbook.xml", content_type::XML, b"<workbook/>")
+            .unwrap();
+        writer
+            .add_part(
+                "xl/worksheets/sheet1.xml",
+                content_type::XML,
+                sheet().as_bytes(),
+            )
+            .unwrap();
+        writer.finish().unwrap();
+        let data = buf.into_inner();
+
+        let mut expected = Package::open(Cursor::new(&data[..])).unwrap();
+        // `&[u8]` is `Read` but not `Seek`
+        let mut pkg = StreamingPackage::new(&data[..]);
+        let mut names = Vec::new();
+        while let Some(mut part) = pkg.next_part().unwrap() {
+            let name = part.name().to_string();
+            assert_eq!(part.content_type(), expected.content_type(&name));
+            assert_eq!(
+                part.read_to_vec().unwrap(),
+                expected.read_part(&name).unwrap()
+            );
+            names.push(name);
+        }
+        assert_eq!(
+            names,
+            ["_rels/.rels", "xl/workbook.xml", "xl/worksheets/sheet1.xml"]
+        );
+        assert!(pkg.content_types().is_some());
+        assert_eq!(pkg.relationships().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_stream_written_without_seek() {
+        // A streaming ZIP writer puts sizes in data descriptors after the
+        // data; here the content types also come last
+        let mut writer = zip::ZipWriter::new_stream(Vec::new());
+        let deflated = SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated)
+            .large_file(true);
+        writer
+            .start_file("xl/worksheets/sheet1.xml", deflated)
+            .unwrap();
+        writer.write_all(sheet().as_bytes()).unwrap();
+        writer
+            .start_file("xl/worksheets/sheet2.xml", deflated)
+            .unwrap();
+        writer.write_all(sheet().as_bytes()).unwrap();
+        writer.start_file("xl/media/data.bin", deflated).unwrap();
+        writer.write_all(b"0123456789").unwrap();
+        writer.start_file("[Content_Types].xml", deflated).unwrap();
+        writer.write_all(CONTENT_TYPES.as_bytes()).unwrap();
+        let data = writer.finish().unwrap().into_inner();
+
+        assert_ne!(
+            u16::from_le_bytes([data[6], data[7]]) & FLAG_DATA_DESCRIPTOR,
+            0
+        );
+
+        let mut pkg = StreamingPackage::new(&data[..]);
+        let mut part = pkg.next_part().unwrap().unwrap();
+        assert_eq!(part.name(), "xl/worksheets/sheet1.xml");
+        assert_eq!(part.content_type(), Some(content_type::XML));
+        assert_eq!(part.read_to_vec().unwrap(), sheet().as_bytes());
+        drop(part);
+
+        // Partly read parts are skipped
+        let mut part = pkg.next_part().unwrap().unwrap();
+        let mut start = [0u8; 11];
+        part.read_exact(&mut start).unwrap();
+        assert_eq!(&start, b"<worksheet>");
+        drop(part);
+
+        let mut part = pkg.next_part().unwrap().unwrap();
+        assert_eq!(part.name(), "xl/media/data.bin");
+        assert_eq!(part.content_type(), Some("application/octet-stream"));
+        assert_eq!(part.read_to_vec().unwrap(), b"0123456789");
+        drop(part);
+        assert!(pkg.next_part().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_buffer_limit() {
+        let mut writer = zip::ZipWriter::new_stream(Vec::new());
+        let deflated =
+            SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+        for name in ["xl/worksheets/sheet1.xml", "xl/worksheets/sheet2.xml"] {
+            writer.start_file(name, deflated).unwrap();
+            writer.write_all(sheet().as_bytes()).unwrap();
+        }
+        writer.start_file("[Content_Types].xml", deflated).unwrap();
+        writer.write_all(CONTENT_TYPES.as_bytes()).unwrap();
+        let data = writer.finish().unwrap().into_inner();
+
+        // Room for one sheet, but not both
+        let mut pkg = StreamingPackage::new(&data[..]);
+        pkg.set_max_buffered(sheet().len() as u64 * 3 / 2);
+        assert!(matches!(pkg.next_part(), Err(Error::LimitExceeded(_))));
+
+        let mut pkg = StreamingPackage::new(&data[..]);
+        pkg.set_max_buffered(sheet().len() as u64 * 2 + CONTENT_TYPES.len() as u64);
+        let mut count = 0;
+        while let Some(part) = pkg.next_part().unwrap() {
+            assert_eq!(part.content_type(), Some(content_type::XML));
+            count += 1;
+        }
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn test_with_limits() {
+        let mut writer = zip::ZipWriter::new_stream(Vec::new());
+        let deflated =
+            SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+        writer.start_file("[Content_Types].xml", deflated).unwrap();
+        writer.write_all(CONTENT_TYPES.as_bytes()).unwrap();
+        writer
+            .start_file("xl/worksheets/sheet1.xml", deflated)
+            .unwrap();
+        writer.write_all(sheet().as_bytes()).unwrap();
+        writer.start_file("xl/media/data.bin", deflated).unwrap();
+        writer.write_all(&[0; 4096]).unwrap();
+        let data = writer.finish().unwrap().into_inner();
+
+        let read_all = |limits: &PackageLimits| -> Result<usize> {
+            let mut pkg = StreamingPackage::with_limits(&data[..], limits);
+            let mut count = 0;
+            while let Some(mut part) = pkg.next_part()? {
+                part.read_to_vec()?;
+                count += 1;
+            }
+            Ok(count)
+        };
+        assert_eq!(read_all(&PackageLimits::default()).unwrap(), 2);
+
+        // Sizes in data descriptors are only known once parts are inflated
+        for limits in [
+            PackageLimits {
+                max_part_size: 1000,
+                ..PackageLimits::default()
+            },
+            PackageLimits {
+                max_total_size: sheet().len() as u64 + 1000,
+                ..PackageLimits::default()
+            },
+            PackageLimits {
+                max_parts: 2,
+                ..PackageLimits::default()
+            },
+            PackageLimits {
+                parse: ParseLimits {
+                    max_depth: 2,
+                    ..ParseLimits::default()
+                },
+                ..PackageLimits::default()
+            },
+        ] {
+            assert!(matches!(read_all(&limits), Err(Error::LimitExceeded(_))));
+        }
+
+        // Parts skipped unread count too
+        let limits = PackageLimits {
+            max_part_size: 1000,
+            ..PackageLimits::default()
+        };
+        let mut pkg = StreamingPackage::with_limits(&data[..], &limits);
+        drop(pkg.next_part().unwrap());
+        assert!(matches!(pkg.next_part(), Err(Error::LimitExceeded(_))));
+    }
+
+    #[test]
+    fn test_corrupt_part_is_detected() {
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let stored =
+            SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+        writer.start_file("[Content_Types].xml", stored).unwrap();
+        writer.write_all(CONTENT_TYPES.as_bytes()).unwrap();
+        writer.start_file("data.bin", stored).unwrap();
+        writer.write_all(b"original").unwrap();
+        let mut data = writer.finish().unwrap().into_inner();
+        let offset = data
+            .windows(8)
+            .position(|window| window == b"original")
+            .unwrap();
+        data[offset] = b'O';
+
+        let mut pkg = StreamingPackage::new(&data[..]);
+        let mut part = pkg.next_part().unwrap().unwrap();
+        assert!(part.read_to_vec().is_err());
+    }
+
+    #[test]
+    fn test_not_a_package() {
+        let mut pkg = StreamingPackage::new(&CFB_SIGNATURE[..]);
+        assert!(matches!(pkg.next_part(), Err(Error::Encrypted)));
+
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        writer
+            .start_file("data.bin", SimpleFileOptions::default())
+            .unwrap();
+        let data = writer.finish().unwrap().into_inner();
+        let mut pkg = StreamingPackage::new(&data[..]);
+        assert!(matches!(pkg.next_part(), Err(Error::MissingPart(_))));
+    }
+}
@@ -0,0 +1,401 @@
+//! VBA projects of macro-enabled documents.
+//!
+//! Macro-enabled documents (`.docm`, `.xlsm`, `.pptm`) store their macros in
+//! a `vbaProject.bin` part, an OLE compound file in the format described by
+//! [MS-OVBA]. The part is kept as opaque bytes so it can be carried through
+//! unchanged; with the `vba` feature the module names and source code can be
+//! listed as well.
+//!
+//! [MS-OVBA] §2.3.4 (the `dir` stream) and §2.4.1 (compression).
+
+use crate::error::{Error, Result};
+
+/// Signature byte of a compressed container (§2.4.1.1.1).
+const CONTAINER_SIGNATURE: u8 = 0x01;
+
+/// Size of a decompressed chunk (§2.4.1.1.4).
+const CHUNK_SIZE: usize = 4096;
+
+/// A VBA project (`vbaProject.bin`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VbaProject {
+    data: Vec<u8>,
+}
+
+impl VbaProject {
+    /// Create a VBA project from the bytes of a `vbaProject.bin` part.
+    pub fn new(data: Vec<u8>) -> Self {
+        Self { data }
+    }
+
+    /// The raw bytes of the project.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Consume the project, returning its raw bytes.
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// List the modules of the project with their decompressed source code.
+    ///
+    /// Source code is decoded as Windows-1252, the code page of virtually all
+    /// projects, or as UTF-8 when the project declares code page 65001.
+    #[cfg(feature = "vba")]
+    pub fn modules(&self) -> Result<Vec<VbaModule>> {
+        use std::io::{Cursor, Read};
+
+        let mut compound = cfb::CompoundFile::open(Cursor::new(&self.data))?;
+        let read = |compound: &mut cfb::CompoundFile<_>, path: &str| -> Result<Vec<u8>> {
+            if !compound.is_stream(path) {
+                return Err(Error::MissingPart(path.into()));
+            }
+            let mut data = Vec::new();
+            compound.open_stream(path)?.read_to_end(&mut data)?;
+            Ok(data)
+        };
+
+        let dir = decompress(&read(&mut compound, "/VBA/dir")?)?;
+        let (code_page, entries) = parse_dir(&dir)?;
+        entries
+            .into_iter()
+            .map(|entry| {
+                let stream = read(&mut compound, &format!("/VBA/{}", entry.stream_name))?;
+                let offset = entry.offset as usize;
+                let code = stream.get(offset..).ok_or_else(|| {
+                    Error::Invalid(format!("module offset out of range: {}", entry.name))
+                })?;
+                Ok(VbaModule {
+                    source: decode(&decompress(code)?, code_page),
+                    name: entry.name,
+                    stream_name: entry.stream_name,
+                    kind: entry.kind,
+                })
+            })
+            .collect()
+    }
+}
+
+/// A module of a VBA project.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VbaModule {
+    /// Module name, e.g. `Module1` or `ThisWorkbook`.
+    pub name: String,
+    /// Name of the stream holding the module in the `VBA` storage.
+    pub stream_name: String,
+    /// Kind of module.
+    pub kind: VbaModuleKind,
+    /// Decompressed source code.
+    pub source: String,
+}
+
+/// The kind of a VBA module (§2.3.4.2.3.2.8).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VbaModuleKind {
+    /// A standard (procedural) module.
+    Procedural,
+    /// A class, document (such as `ThisDocument`) or designer (UserForm) module.
+    Class,
+}
+
+/// Decompress a compressed container (§2.4.1.3.1).
+pub fn decompress(data: &[u8]) -> Result<Vec<u8>> {
+    let invalid = || Error::Invalid("invalid VBA compressed container".into());
+    let (&signature, mut rest) = data.split_first().ok_or_else(invalid)?;
+    if signature != CONTAINER_SIGNATURE {
+        return Err(invalid());
+    }
+
+    let mut out = Vec::with_capacity(data.len() * 2);
+    while rest.len() >= 2 {
+        let header = u16::from_le_bytes([rest[0], rest[1]]);
+        let size = ((header & 0x0FFF) as usize + 3).min(rest.len());
+        let compressed = header & 0x8000 != 0;
+        let chunk = &rest[2..size];
+        rest = &rest[size..];
+
+        if !compressed {
+            out.extend_from_slice(chunk);
+            continue;
+        }
+
+        let chunk_start = out.len();
+        let mut pos = 0;
+        while pos < chunk.len() {
+            let flags = chunk[pos];
+            pos += 1;
+            for bit in 0..8 {
+                if pos >= chunk.len() {
+                    break;
+                }
+                // A chunk decompresses to at most CHUNK_SIZE bytes, which
+                // also keeps bit_count below 16.
+                let difference = out.len() - chunk_start;
+                if difference >= CHUNK_SIZE {
+                    return Err(invalid());
+                }
+                if flags & (1 << bit) == 0 {
+                    out.push(chunk[pos]);
+                    pos += 1;
+                    continue;
+                }
+
+                let token =
+                    u16::from_le_bytes([chunk[pos], *chunk.get(pos + 1).ok_or_else(invalid)?]);
+                pos += 2;
+                let mut bit_count = 4;
+                while (1usize << bit_count) < difference {
+                    bit_count += 1;
+                }
+                let length_mask = 0xFFFFu16 >> bit_count;
+                let length = (token & length_mask) as usize + 3;
+                let offset = (token >> (16 - bit_count)) as usize + 1;
+                if offset > difference || difference + length > CHUNK_SIZE {
+                    return Err(invalid());
+                }
+                let start = out.len() - offset;
+                for i in 0..length {
+                    out.push(out[start + i]);
+                }
+            }
+        }
+    }
+
+    Ok(out)
+}
+
+/// A module entry of the `dir` stream.
+#[cfg(feature = "vba")]
+struct DirEntry {
+    name: String,
+    stream_name: String,
+    kind: VbaModuleKind,
+    offset: u32,
+}
+
+/// Parse the decompressed `dir` stream, returning the project code page and
+/// the module entries (§2.3.4.2).
+#[cfg(feature = "vba")]
+fn parse_dir(data: &[u8]) -> Result<(u16, Vec<DirEntry>)> {
+    const PROJECT_CODE_PAGE: u16 = 0x0003;
+    const PROJECT_VERSION: u16 = 0x0009;
+    const MODULE_NAME: u16 = 0x0019;
+    const MODULE_NAME_UNICODE: u16 = 0x0047;
+    const MODULE_STREAM_NAME: u16 = 0x001A;
+    const MODULE_STREAM_NAME_UNICODE: u16 = 0x0032;
+    const MODULE_OFFSET: u16 = 0x0031;
+    const MODULE_TYPE_PROCEDURAL: u16 = 0x0021;
+    const MODULE_TYPE_CLASS: u16 = 0x0022;
+    const MODULE_TERMINATOR: u16 = 0x002B;
+
+    let truncated = || Error::Invalid("VBA dir stream is truncated".into());
+    let mut code_page = 1252;
+    let mut entries = Vec::new();
+    let mut name = None;
+    let mut name_unicode = None;
+    let mut stream_name = None;
+    let mut stream_name_unicode = None;
+    let mut offset = 0;
+    let mut kind = VbaModuleKind::Procedural;
+
+    let mut pos = 0;
+    while pos + 6 <= data.len() {
+        let id = u16::from_le_bytes([data[pos], data[pos + 1]]);
+        let mut size =
+            u32::from_le_bytes(data[pos + 2..pos + 6].try_into().expect("4 bytes")) as usize;
+        // PROJECTVERSION declares a size of 4 but holds 6 bytes.
+        if id == PROJECT_VERSION {
+            size = 6;
+        }
+        let record = data.get(pos + 6..pos + 6 + size).ok_or_else(truncated)?;
+        pos += 6 + size;
+
+        match id {
+            PROJECT_CODE_PAGE if record.len() >= 2 => {
+                code_page = u16::from_le_bytes([record[0], record[1]]);
+            }
+            MODULE_NAME => name = Some(decode(record, code_page)),
+            MODULE_NAME_UNICODE => name_unicode = Some(decode_utf16(record)),
+            MODULE_STREAM_NAME => stream_name = Some(decode(record, code_page)),
+            MODULE_STREAM_NAME_UNICODE => stream_name_unicode = Some(decode_utf16(record)),
+            MODULE_OFFSET if record.len() >= 4 => {
+                offset = u32::from_le_bytes(record[..4].try_into().expect("4 bytes"));
+            }
+            MODULE_TYPE_PROCEDURAL => kind = VbaModuleKind::Procedural,
+            MODULE_TYPE_CLASS => kind = VbaModuleKind::Class,
+            MODULE_TERMINATOR => {
+                let name = name_unicode.take().or(name.take()).unwrap_or_default();
+                let stream_name = stream_name_unicode
+                    .take()
+                    .or(stream_name.take())
+                    .unwrap_or_else(|| name.clone());
+                entries.push(DirEntry {
+                    name,
+                    stream_name,
+                    kind,
+                    offset,
+                });
+                offset = 0;
+                kind = VbaModuleKind::Procedural;
+            }
+            _ => {}
+        }
+    }
+
+    Ok((code_page, entries))
+}
+
+/// Decode text in the project's code page.
+#[cfg(feature = "vba")]
+fn decode(data: &[u8], code_page: u16) -> String {
+    /// Windows-1252 characters for bytes 0x80–0x9F.
+    const CP1252_HIGH: [char; 32] = [
+        '€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8D}', 'Ž',
+        '\u{8F}', '\u{90}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{9D}',
+        'ž', 'Ÿ',
+    ];
+
+    if code_page == 65001 {
+        return String::from_utf8_lossy(data).into_owned();
+    }
+    data.iter()
+        .map(|&b| match b {
+            0x80..=0x9F => CP1252_HIGH[(b - 0x80) as usize],
+            _ => b as char,
+        })
+        .collect()
+}
+
+/// Decode UTF-16LE text.
+#[cfg(feature = "vba")]
+fn decode_utf16(data: &[u8]) -> String {
+    let units: Vec<u16> = data
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .collect();
+    String::from_utf16_lossy(&units)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decompress() {
+        // Examples from [MS-OVBA] §3.2.
+        let literal = [
+            0x01, 0x19, 0xB0, 0x00, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x00, 0x69,
+            0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x00, 0x71, 0x72, 0x73, 0x74, 0x75, 0x76,
+            0x2E,
+        ];
+        assert_eq!(decompress(&literal).unwrap(), b"abcdefghijklmnopqrstuv.");
+
+        let compressed = [
+            0x01, 0x2F, 0xB0, 0x00, 0x23, 0x61, 0x61, 0x61, 0x62, 0x63, 0x64, 0x65, 0x82, 0x66,
+            0x00, 0x70, 0x61, 0x67, 0x68, 0x69, 0x6A, 0x01, 0x38, 0x08, 0x61, 0x6B, 0x6C, 0x00,
+            0x30, 0x6D, 0x6E, 0x6F, 0x70, 0x06, 0x71, 0x02, 0x70, 0x04, 0x10, 0x72, 0x73, 0x74,
+            0x75, 0x76, 0x10, 0x77, 0x78, 0x79, 0x7A, 0x00, 0x3C,
+        ];
+        assert_eq!(
+            decompress(&compressed).unwrap(),
+            b"#aaabcdefaaaaghijaaaaaklaaamnopqaaaaaaaaaaaarstuvwxyzaaa"
+        );
+
+        assert!(decompress(&[0x02, 0x00]).is_err());
+    }
+
+    #[test]
+    fn test_decompress_chunk_limit() {
+        // A literal followed by a copy token repeating it: 4095 copies fill
+        // the chunk, 4098 overflow it.
+        let container = |token: u16| {
+            let mut data = vec![CONTAINER_SIGNATURE, 0x03, 0xB0, 0x02, b'a'];
+            data.extend_from_slice(&token.to_le_bytes());
+            data
+        };
+        assert_eq!(decompress(&container(0x0FFC)).unwrap(), vec![b'a'; 4096]);
+        assert!(decompress(&container(0x0FFF)).is_err());
+
+        // Tokens after a full chunk
+        let mut data = container(0x0FFC);
+        data[1] = 0x05;
+        data.extend_from_slice(&[0x00, 0x00]);
+        assert!(decompress(&data).is_err());
+    }
+
+    /// Build a compressed container using only literal tokens.
+    #[cfg(feature = "vba")]
+    fn compress_literal(data: &[u8]) -> Vec<u8> {
+        let mut out = vec![CONTAINER_SIGNATURE];
+        for chunk in data.chunks(CHUNK_SIZE) {
+            let mut body = Vec::new();
+            for group in chunk.chunks(8) {
+                body.push(0x00);
+                body.extend_from_slice(group);
+            }
+            let header = 0xB000 | (body.len() as u16 + 2 - 3);
+            out.extend_from_slice(&header.to_le_bytes());
+            out.extend_from_slice(&body);
+        }
+        out
+    }
+
+    #[test]
+    #[cfg(feature = "vba")]
+    fn test_modules() {
+        use std::io::{Cursor, Write};
+
+        fn record(dir: &mut Vec<u8>, id: u16, data: &[u8]) {
+            dir.extend_from_slice(&id.to_le_bytes());
+            dir.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            dir.extend_from_slice(data);
+        }
+
+        let mut dir = Vec::new();
+        record(&mut dir, 0x0003, &1252u16.to_le_bytes());
+        // PROJECTVERSION: size 4, followed by six bytes.
+        dir.extend_from_slice(&0x0009u16.to_le_bytes());
+        dir.extend_from_slice(&4u32.to_le_bytes());
+        dir.extend_from_slice(&[1, 0, 0, 0, 1, 0]);
+        for (name, kind, offset) in [("Module1", 0x0021, 0u32), ("ThisWorkbook", 0x0022, 4)] {
+            record(&mut dir, 0x0019, name.as_bytes());
+            record(&mut dir, 0x001A, name.as_bytes());
+            record(&mut dir, 0x0031, &offset.to_le_bytes());
+            record(&mut dir, kind, &[]);
+            record(&mut dir, 0x002B, &[]);
+        }
+        record(&mut dir, 0x0010, &[]);
+
+        let mut compound = cfb::CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+        compound.create_storage("/VBA").unwrap();
+        let streams = [
+            ("/VBA/dir", compress_literal(&dir)),
+            (
+                "/VBA/Module1",
+                compress_literal(b"Sub Hello()\r\nEnd Sub\r\n"),
+            ),
+            (
+                "/VBA/ThisWorkbook",
+                [&[0xFF; 4][..], &compress_literal(b"Caf\xE9 \x80")].concat(),
+            ),
+        ];
+        for (path, data) in &streams {
+            compound
+                .create_stream(path)
+                .unwrap()
+                .write_all(data)
+                .unwrap();
+        }
+        let project = VbaProject::new(compound.into_inner().into_inner());
+
+        let modules = project.modules().unwrap();
+        assert_eq!(modules.len(), 2);
+        assert_eq!(modules[0].name, "Module1");
+        assert_eq!(modules[0].kind, VbaModuleKind::Procedural);
+        assert_eq!(modules[0].source, "Sub Hello()\r\nEnd Sub\r\n");
+        assert_eq!(modules[1].stream_name, "ThisWorkbook");
+        assert_eq!(modules[1].kind, VbaModuleKind::Class);
+        assert_eq!(modules[1].source, "Café €");
+    }
+}
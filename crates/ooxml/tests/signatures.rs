@@ -0,0 +1,72 @@
+//! Tests for signature verification against a package signed outside this
+//! crate.
+//!
+//! `fixtures/signed.docx` follows the layout Office writes: a package object
+//! with the manifest and signing time, an Office details object and XAdES
+//! signed properties, all covered by an RSA-SHA256 signature. Its digests
+//! were computed with libxml2's Canonical XML and it was signed with OpenSSL
+//! (`scripts/make-signed-fixture.py`), so the tests do not depend on this
+//! crate's canonicalizer being right.
+
+#![cfg(feature = "signatures")]
+
+use ooxml_opc::{Package, PackageEditor, SignatureStatus};
+use std::io::Cursor;
+
+const SIGNED: &[u8] = include_bytes!("fixtures/signed.docx");
+
+#[test]
+fn test_external_signature_is_valid() {
+    let mut pkg = Package::open(Cursor::new(SIGNED)).unwrap();
+    let signatures = pkg.verify_signatures().unwrap();
+    assert_eq!(signatures.len(), 1);
+    let signature = &signatures[0];
+    assert_eq!(signature.status, SignatureStatus::Valid);
+    assert_eq!(signature.part_name, "_xmlsignatures/sig1.xml");
+    assert_eq!(
+        signature.signer.as_deref(),
+        Some("O=Contoso,CN=Fixture Signer")
+    );
+    assert_eq!(
+        signature.signed_parts,
+        [
+            "_rels/.rels",
+            "word/_rels/document.xml.rels",
+            "word/document.xml",
+            "word/styles.xml"
+        ]
+    );
+}
+
+#[test]
+fn test_external_signature_detects_changes() {
+    let altered = |part: &str, from: &str, to: &str| {
+        let mut editor = PackageEditor::new(Package::open(Cursor::new(SIGNED)).unwrap()).unwrap();
+        let content_type = editor.content_type(part).unwrap().to_string();
+        let data = String::from_utf8(editor.read_part(part).unwrap()).unwrap();
+        assert!(data.contains(from));
+        editor.set_part(part, &content_type, data.replace(from, to).into_bytes());
+        let mut buffer = Cursor::new(Vec::new());
+        editor.write(&mut buffer).unwrap();
+        buffer.set_position(0);
+        let mut pkg = Package::open(buffer).unwrap();
+        pkg.verify_signatures().unwrap().remove(0).status
+    };
+
+    assert_eq!(
+        altered("word/document.xml", "external", "internal"),
+        SignatureStatus::Altered("word/document.xml".to_string())
+    );
+    assert_eq!(
+        altered("_xmlsignatures/sig1.xml", "Approved", "Rejected"),
+        SignatureStatus::Altered("#idOfficeObject".to_string())
+    );
+    assert_eq!(
+        altered(
+            "_xmlsignatures/sig1.xml",
+            "09:30:00Z</xd:",
+            "10:30:00Z</xd:"
+        ),
+        SignatureStatus::Altered("#idSignedProperties".to_string())
+    );
+}
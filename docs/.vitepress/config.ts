@@ -27,6 +27,11 @@ export default withMermaid(
             items: [
               { text: 'Introduction', link: '/guide/' },
               { text: 'Installation', link: '/guide/installation' },
+              { text: 'JSON Document Model', link: '/guide/json' },
+              { text: 'Borrowed Views', link: '/guide/borrowed' },
+              { text: 'HTML Export', link: '/guide/html' },
+              { text: 'Markdown Export', link: '/guide/markdown' },
+              { text: 'Markdown/HTML Import', link: '/guide/import' },
             ],
           },
         ],
@@ -0,0 +1,116 @@
+#!/usr/bin/env python3
+"""Build crates/ooxml/tests/fixtures/signed.docx.
+
+The package is signed in the layout Office uses, without using the ooxml
+crates: digests use libxml2's Canonical XML (`xmllint --c14n`) and the
+signature and certificate come from the `cryptography` package (OpenSSL).
+"""
+import base64, datetime, hashlib, subprocess, sys, zipfile
+from cryptography import x509
+from cryptography.x509.oid import NameOID
+from cryptography.hazmat.primitives import hashes, serialization
+from cryptography.hazmat.primitives.asymmetric import rsa, padding
+
+DSIG = "http://www.w3.org/2000/09/xmldsig#"
+C14N = "http://www.w3.org/TR/2001/REC-xml-c14n-20010315"
+SHA256 = "http://www.w3.org/2001/04/xmlenc#sha256"
+RT = "http://schemas.openxmlformats.org/package/2006/RelationshipTransform"
+MDSSI = "http://schemas.openxmlformats.org/package/2006/digital-signature"
+
+def c14n(xml):
+    return subprocess.run(["xmllint", "--c14n", "-"], input=xml.encode(), capture_output=True, check=True).stdout
+
+def b64sha(data):
+    return base64.b64encode(hashlib.sha256(data).digest()).decode()
+
+key = rsa.generate_private_key(public_exponent=65537, key_size=2048)
+name = x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "Fixture Signer"), x509.NameAttribute(NameOID.ORGANIZATION_NAME, "Contoso")])
+cert = (x509.CertificateBuilder().subject_name(name).issuer_name(name).public_key(key.public_key())
+        .serial_number(0x1234).not_valid_before(datetime.datetime(2026, 1, 1))
+        .not_valid_after(datetime.datetime(2126, 1, 1)).sign(key, hashes.SHA256()))
+cert_b64 = base64.b64encode(cert.public_bytes(serialization.Encoding.DER)).decode()
+
+content_types = '''<?xml version="1.0" encoding="UTF-8" standalone="yes"?>\r
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Default Extension="sigs" ContentType="application/vnd.openxmlformats-package.digital-signature-origin"/><Override PartName="/word/document.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/><Override PartName="/word/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml"/><Override PartName="/_xmlsignatures/sig1.xml" ContentType="application/vnd.openxmlformats-package.digital-signature-xmlsignature+xml"/></Types>'''
+root_rels = '''<?xml version="1.0" encoding="UTF-8" standalone="yes"?>\r
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="word/document.xml"/><Relationship Id="rId2" Type="http://schemas.openxmlformats.org/package/2006/relationships/digital-signature/origin" Target="_xmlsignatures/origin.sigs"/></Relationships>'''
+document = '''<?xml version="1.0" encoding="UTF-8" standalone="yes"?>\r
+<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body><w:p><w:r><w:t>Signed with an external toolchain</w:t></w:r></w:p><w:sectPr/></w:body></w:document>'''
+styles = '''<?xml version="1.0" encoding="UTF-8" standalone="yes"?>\r
+<w:styles xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:docDefaults/></w:styles>'''
+doc_rels = '''<?xml version="1.0" encoding="UTF-8" standalone="yes"?>\r
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles" Target="styles.xml"/></Relationships>'''
+origin_rels = '''<?xml version="1.0" encoding="UTF-8" standalone="yes"?>\r
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/package/2006/relationships/digital-signature/signature" Target="sig1.xml"/></Relationships>'''
+
+def rel_transform(xml, ids, types=()):
+    # OPC relationship transform (ECMA-376 Part 2, 13.2.4.24), then C14N
+    import xml.etree.ElementTree as ET
+    ns = "{http://schemas.openxmlformats.org/package/2006/relationships}"
+    rels = [r for r in ET.fromstring(xml.split("\n", 1)[1]) if r.get("Id") in ids or r.get("Type") in types]
+    rels.sort(key=lambda r: r.get("Id"))
+    out = '<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">'
+    for r in rels:
+        out += '<Relationship Id="%s" Target="%s" TargetMode="%s" Type="%s"/>' % (r.get("Id"), r.get("Target"), r.get("TargetMode") or "Internal", r.get("Type"))
+    return c14n(out + "</Relationships>")
+
+def ref(uri, digest, transforms=""):
+    return '<Reference URI="%s">%s<DigestMethod Algorithm="%s"/><DigestValue>%s</DigestValue></Reference>' % (uri, transforms, SHA256, digest)
+
+ct = "?ContentType="
+manifest = "".join([
+    ref("/_rels/.rels" + ct + "application/vnd.openxmlformats-package.relationships+xml", b64sha(rel_transform(root_rels, ["rId1"])),
+        '<Transforms><Transform Algorithm="%s"><mdssi:RelationshipReference SourceId="rId1"/></Transform><Transform Algorithm="%s"/></Transforms>' % (RT, C14N)),
+    ref("/word/_rels/document.xml.rels" + ct + "application/vnd.openxmlformats-package.relationships+xml", b64sha(rel_transform(doc_rels, [], ["http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles"])),
+        '<Transforms><Transform Algorithm="%s"><mdssi:RelationshipsGroupReference SourceType="http://schemas.openxmlformats.org/officeDocument/2006/relationships/styles"/></Transform><Transform Algorithm="%s"/></Transforms>' % (RT, C14N)),
+    ref("/word/document.xml" + ct + "application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml", b64sha(document.encode())),
+    ref("/word/styles.xml" + ct + "application/vnd.openxmlformats-officedocument.wordprocessingml.styles+xml", b64sha(styles.encode())),
+])
+
+package_object = ('<Object Id="idPackageObject"><Manifest>%s</Manifest><SignatureProperties><SignatureProperty Id="idSignatureTime" Target="#idPackageSignature">'
+    '<mdssi:SignatureTime xmlns:mdssi="%s"><mdssi:Format>YYYY-MM-DDThh:mm:ssTZD</mdssi:Format><mdssi:Value>2026-10-18T09:30:00Z</mdssi:Value></mdssi:SignatureTime>'
+    '</SignatureProperty></SignatureProperties></Object>') % (manifest, MDSSI)
+# Office declares mdssi on the Object; the relationship references use it
+package_object = package_object.replace('<Object Id="idPackageObject">', '<Object xmlns:mdssi="%s" Id="idPackageObject">' % MDSSI, 1)
+office_object = ('<Object Id="idOfficeObject"><SignatureProperties><SignatureProperty Id="idOfficeV1Details" Target="#idPackageSignature">'
+    '<SignatureInfoV1 xmlns="http://schemas.microsoft.com/office/2006/digsig"><SetupID/><SignatureText/><SignatureImage/><SignatureComments>Approved</SignatureComments>'
+    '<WindowsVersion>10.0</WindowsVersion><OfficeVersion>16.0</OfficeVersion><ApplicationVersion>16.0</ApplicationVersion><Monitors>1</Monitors>'
+    '<HorizontalResolution>1920</HorizontalResolution><VerticalResolution>1080</VerticalResolution><ColorDepth>32</ColorDepth>'
+    '<SignatureProviderId>{00000000-0000-0000-0000-000000000000}</SignatureProviderId><SignatureProviderUrl/><SignatureProviderDetails>9</SignatureProviderDetails>'
+    '<SignatureType>1</SignatureType></SignatureInfoV1></SignatureProperty></SignatureProperties></Object>')
+signed_props = ('<xd:SignedProperties Id="idSignedProperties"><xd:SignedSignatureProperties><xd:SigningTime>2026-10-18T09:30:00Z</xd:SigningTime>'
+    '<xd:SigningCertificate><xd:Cert><xd:CertDigest><DigestMethod Algorithm="%s"/><DigestValue>%s</DigestValue></xd:CertDigest>'
+    '<xd:IssuerSerial><X509IssuerName>O=Contoso, CN=Fixture Signer</X509IssuerName><X509SerialNumber>4660</X509SerialNumber></xd:IssuerSerial></xd:Cert></xd:SigningCertificate>'
+    '<xd:SignaturePolicyIdentifier><xd:SignaturePolicyImplied/></xd:SignaturePolicyIdentifier></xd:SignedSignatureProperties></xd:SignedProperties>') % (
+    SHA256, b64sha(cert.public_bytes(serialization.Encoding.DER)))
+xades_object = '<Object><xd:QualifyingProperties xmlns:xd="http://uri.etsi.org/01903/v1.3.2#" Target="#idPackageSignature">%s</xd:QualifyingProperties></Object>' % signed_props
+
+def subtree(xml, extra_ns=""):
+    # Inclusive C14N of a subtree renders the in-scope namespaces on its apex
+    head, rest = xml.split(">", 1)
+    name = head[1:].split(" ")[0]
+    return c14n("%s xmlns=\"%s\"%s%s>%s" % ("<" + name, DSIG, extra_ns, head[len(name) + 1:], rest))
+
+d_package = b64sha(subtree(package_object))
+d_office = b64sha(subtree(office_object))
+d_props = b64sha(subtree(signed_props, ' xmlns:xd="http://uri.etsi.org/01903/v1.3.2#"'))
+
+signed_info = ('<SignedInfo><CanonicalizationMethod Algorithm="%s"/><SignatureMethod Algorithm="http://www.w3.org/2001/04/xmldsig-more#rsa-sha256"/>'
+    '<Reference Type="http://www.w3.org/2000/09/xmldsig#Object" URI="#idPackageObject"><DigestMethod Algorithm="%s"/><DigestValue>%s</DigestValue></Reference>'
+    '<Reference Type="http://www.w3.org/2000/09/xmldsig#Object" URI="#idOfficeObject"><DigestMethod Algorithm="%s"/><DigestValue>%s</DigestValue></Reference>'
+    '<Reference Type="http://uri.etsi.org/01903#SignedProperties" URI="#idSignedProperties"><Transforms><Transform Algorithm="%s"/></Transforms><DigestMethod Algorithm="%s"/><DigestValue>%s</DigestValue></Reference>'
+    '</SignedInfo>') % (C14N, SHA256, d_package, SHA256, d_office, C14N, SHA256, d_props)
+value = key.sign(subtree(signed_info), padding.PKCS1v15(), hashes.SHA256())
+
+signature = ('<?xml version="1.0" encoding="UTF-8"?>\r\n<Signature xmlns="%s" Id="idPackageSignature">%s<SignatureValue>%s</SignatureValue>'
+    '<KeyInfo><X509Data><X509Certificate>%s</X509Certificate></X509Data></KeyInfo>%s%s%s</Signature>') % (
+    DSIG, signed_info, base64.b64encode(value).decode(), cert_b64, package_object, office_object, xades_object)
+
+with zipfile.ZipFile(sys.argv[1] if len(sys.argv) > 1 else "crates/ooxml/tests/fixtures/signed.docx", "w", zipfile.ZIP_DEFLATED) as z:
+    for path, data in [("[Content_Types].xml", content_types), ("_rels/.rels", root_rels), ("word/document.xml", document),
+                       ("word/_rels/document.xml.rels", doc_rels), ("word/styles.xml", styles),
+                       ("_xmlsignatures/origin.sigs", ""), ("_xmlsignatures/_rels/origin.sigs.rels", origin_rels),
+                       ("_xmlsignatures/sig1.xml", signature)]:
+        info = zipfile.ZipInfo(path, date_time=(2026, 10, 18, 9, 30, 0))
+        info.compress_type = zipfile.ZIP_DEFLATED
+        z.writestr(info, data)